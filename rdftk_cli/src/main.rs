@@ -0,0 +1,134 @@
+/*!
+The `rdftk` command-line tool; see the crate README for the commands it provides.
+
+```text
+rdftk watch <file> [--output <file>] [--canonical] [--shapes <file>] [--interval <ms>] [--once]
+```
+
+*/
+
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::format::Format;
+use rdftk_io::watch::{Report, WatchOptions, Watcher};
+use rdftk_ontology::shacl::validate;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::Duration;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const USAGE: &str = "usage: rdftk watch <file> [--output <file>] [--canonical] [--shapes <file>] \
+[--interval <ms>] [--once]";
+
+#[derive(Debug, Default)]
+struct WatchCommand {
+    path: Option<PathBuf>,
+    shapes: Option<PathBuf>,
+    once: bool,
+    options: WatchOptions,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.split_first() {
+        Some((command, args)) if command == "watch" => parse_watch(args).and_then(watch),
+        _ => Err(ErrorKind::Msg(USAGE.to_string()).into()),
+    };
+    match result {
+        Ok(true) => exit(0),
+        Ok(false) => exit(1),
+        Err(e) => {
+            eprintln!("rdftk: {}", e);
+            exit(2)
+        }
+    }
+}
+
+fn parse_watch(args: &[String]) -> Result<WatchCommand> {
+    let mut command = WatchCommand::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| usage_error(&format!("{} requires a value", arg)))
+        };
+        match arg.as_str() {
+            "--output" => command.options = command.options.with_output(value()?),
+            "--canonical" => command.options = command.options.canonical(),
+            "--shapes" => command.shapes = Some(PathBuf::from(value()?)),
+            "--interval" => {
+                let millis = value()?
+                    .parse()
+                    .map_err(|_| usage_error("--interval requires a number of milliseconds"))?;
+                command.options = command.options.with_interval(Duration::from_millis(millis));
+            }
+            "--once" => command.once = true,
+            _ if arg.starts_with("--") || command.path.is_some() => {
+                return Err(usage_error(&format!("unexpected argument {}", arg)))
+            }
+            _ => command.path = Some(PathBuf::from(arg)),
+        }
+    }
+    if command.path.is_none() {
+        return Err(usage_error("a file to watch is required"));
+    }
+    Ok(command)
+}
+
+///
+/// Run the watch command; with `--once` returning `true` if the report was clean, otherwise
+/// watching until the process is stopped.
+///
+fn watch(command: WatchCommand) -> Result<bool> {
+    let mut watcher = Watcher::new(command.path.as_ref().unwrap(), command.options);
+    if let Some(shapes) = &command.shapes {
+        let shapes = read_graph(shapes)?;
+        watcher = watcher.with_check(move |graph: &dyn Graph| {
+            validate(&*shapes.borrow(), graph)
+                .results()
+                .iter()
+                .map(|result| result.to_string())
+                .collect()
+        });
+    }
+    if command.once {
+        let report = watcher.poll()?.unwrap();
+        println!("{}", report);
+        Ok(report.is_clean())
+    } else {
+        watcher.run(|report: Result<Report>| {
+            match report {
+                Ok(report) => println!("{}", report),
+                Err(e) => eprintln!("rdftk: {}", e),
+            }
+            true
+        });
+        Ok(true)
+    }
+}
+
+fn read_graph(path: &Path) -> Result<GraphRef> {
+    let format = Format::from_path(path)
+        .filter(|format| format.reads_graph())
+        .ok_or_else(|| usage_error(&format!("cannot read a graph from {}", path.display())))?;
+    let mut file = File::open(path).map_err(|e| {
+        rdftk_core::error::Error::with_chain(
+            e,
+            ErrorKind::Msg(format!("cannot read {}", path.display())),
+        )
+    })?;
+    format.read_graph(&mut file, graph_factory())
+}
+
+fn usage_error(message: &str) -> rdftk_core::error::Error {
+    ErrorKind::Msg(format!("{}\n{}", message, USAGE)).into()
+}
@@ -0,0 +1,105 @@
+use std::fs::{read_to_string, remove_dir_all, write};
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn temporary_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("rdftk-cli-{}-{}", name, std::process::id()));
+    let _ = remove_dir_all(&path);
+    std::fs::create_dir_all(&path).unwrap();
+    path
+}
+
+fn rdftk(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rdftk"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+const SHAPES: &str = r#"@prefix sh: <http://www.w3.org/ns/shacl#> .
+@prefix ex: <http://example.org/> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+
+ex:PersonShape a sh:NodeShape ;
+    sh:targetClass foaf:Person ;
+    sh:property [ sh:path foaf:name ; sh:minCount 1 ] .
+"#;
+
+#[test]
+fn watch_once() {
+    let dir = temporary_dir("once");
+    let path = dir.join("people.ttl");
+    let output = dir.join("people.nt");
+    let shapes = dir.join("shapes.ttl");
+    write(&shapes, SHAPES).unwrap();
+    let args = |path: &PathBuf| {
+        vec![
+            "watch".to_string(),
+            path.display().to_string(),
+            "--shapes".to_string(),
+            shapes.display().to_string(),
+            "--output".to_string(),
+            output.display().to_string(),
+            "--once".to_string(),
+        ]
+    };
+    let run = |path: &PathBuf| {
+        let args = args(path);
+        rdftk(&args.iter().map(String::as_str).collect::<Vec<&str>>())
+    };
+
+    write(
+        &path,
+        "@prefix foaf: <http://xmlns.com/foaf/0.1/> .\n<http://example.org/alice> a foaf:Person ;\n    foaf:name .\n",
+    )
+    .unwrap();
+    let result = run(&path);
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert_eq!(result.status.code(), Some(1));
+    assert!(
+        stdout.contains("people.ttl:3:15: expected object"),
+        "{}",
+        stdout
+    );
+    assert!(!output.exists());
+
+    write(
+        &path,
+        "@prefix foaf: <http://xmlns.com/foaf/0.1/> .\n<http://example.org/alice> a foaf:Person .\n",
+    )
+    .unwrap();
+    let result = run(&path);
+    let stdout = String::from_utf8(result.stdout).unwrap();
+    assert_eq!(result.status.code(), Some(1));
+    assert!(
+        stdout.contains("1 statements, 0 errors, 1 problems"),
+        "{}",
+        stdout
+    );
+
+    write(
+        &path,
+        "@prefix foaf: <http://xmlns.com/foaf/0.1/> .\n<http://example.org/alice> a foaf:Person ;\n    foaf:name \"Alice\" .\n",
+    )
+    .unwrap();
+    let result = run(&path);
+    assert_eq!(result.status.code(), Some(0));
+    assert_eq!(read_to_string(&output).unwrap().lines().count(), 2);
+    remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch_usage() {
+    let result = rdftk(&["watch"]);
+    assert_eq!(result.status.code(), Some(2));
+    assert!(String::from_utf8(result.stderr)
+        .unwrap()
+        .contains("usage: rdftk watch <file>"));
+    assert_eq!(rdftk(&["lint", "file.ttl"]).status.code(), Some(2));
+    assert_eq!(
+        rdftk(&["watch", "file.ttl", "--interval", "soon"])
+            .status
+            .code(),
+        Some(2)
+    );
+}
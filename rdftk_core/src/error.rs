@@ -13,6 +13,11 @@ error_chain! {
             description("The String value provided is not a value for it's type.")
             display("The String value `{}` is not a valid value for it's type: '{}'.", value, type_name)
         }
+        #[doc = "A literal's data type does not allow conversion to the requested type."]
+        IncompatibleDataType(data_type: String, type_name: String) {
+            description("A literal's data type does not allow conversion to the requested type.")
+            display("A literal with the data type <{}> cannot be converted to a value of type '{}'.", data_type, type_name)
+        }
         #[doc = "The String value provided is not a valid Blank Node name."]
         InvalidBlankNodeName(s: String) {
             description("The String value provided is not a valid Blank Node name.")
@@ -48,6 +53,36 @@ error_chain! {
             description("A failure occurred reading or writing a graph.")
             display("A failure occurred reading or writing a graph, for representation: '{}'.", repr)
         }
+        #[doc = "A statement would add a second value for a functional or inverse-functional predicate."]
+        CardinalityViolation(predicate: String) {
+            description("A statement would add a second value for a functional or inverse-functional predicate.")
+            display("A statement would add a second value for the functional or inverse-functional predicate <{}>.", predicate)
+        }
+        #[doc = "A change set was not applied as one or more validators reported problems."]
+        ChangeSetRejected(problems: Vec<String>) {
+            description("A change set was not applied as one or more validators reported problems.")
+            display("A change set was not applied as validators reported {} problem(s): {}.", problems.len(), problems.join("; "))
+        }
+        #[doc = "A graph in a data set could not be changed as it is currently borrowed."]
+        GraphInUse(name: String) {
+            description("A graph in a data set could not be changed as it is currently borrowed.")
+            display("The graph {} in a data set could not be changed as it is currently borrowed.", name)
+        }
+        #[doc = "The named graph does not exist."]
+        UnknownGraph(name: String) {
+            description("The named graph does not exist.")
+            display("The named graph {} does not exist.", name)
+        }
+        #[doc = "A quantity cannot be converted between units that are unknown or of different quantity kinds."]
+        IncompatibleUnits(from: String, to: String) {
+            description("A quantity cannot be converted between units that are unknown or of different quantity kinds.")
+            display("A quantity in the unit <{}> cannot be converted to the unit <{}>.", from, to)
+        }
+        #[doc = "A watermark predicate is already used by statements in the graph being marked."]
+        WatermarkPredicateInUse(predicate: String) {
+            description("The watermark predicate is already used by statements in the graph.")
+            display("The watermark predicate <{}> is already used by statements in the graph.", predicate)
+        }
         #[doc = "Some model element was in an invalid state for the requested operation."]
         InvalidState {
             description("Some model element was in an invalid state for the requested operation.")
@@ -63,6 +98,31 @@ error_chain! {
             description("Cited model.formulae, from N3, are not supported by this representation.")
             display("Cited model.formulae, from N3, are not supported by the {:?} representation.", representation)
         }
+        #[doc = "A persistent store failed to read or write statements."]
+        Storage(message: String) {
+            description("A persistent store failed to read or write statements.")
+            display("A persistent store failed to read or write statements: {}.", message)
+        }
+        #[doc = "A configured limit, such as the size of a response or the time allowed to read it, was exceeded."]
+        LimitExceeded(name: String, limit: String) {
+            description("A configured limit was exceeded.")
+            display("The {} limit of {} was exceeded.", name, limit)
+        }
+        #[doc = "The input does not conform to the specification of its representation, as checked by a strict reader."]
+        NonConformant(representation: String, violations: Vec<String>) {
+            description("The input does not conform to the specification of its representation.")
+            display("The input does not conform to the {:?} representation, {} violation(s): {}.", representation, violations.len(), violations.join("; "))
+        }
+        #[doc = "The operation is not supported by this representation, or by the enabled features."]
+        OperationNotSupported(operation: String, representation: String) {
+            description("The operation is not supported by this representation.")
+            display("The operation '{}' is not supported by the {:?} representation.", operation, representation)
+        }
+        #[doc = "A graph does not describe a valid data set manifest."]
+        InvalidManifest(reason: String) {
+            description("A graph does not describe a valid data set manifest.")
+            display("A graph does not describe a valid data set manifest: {}.", reason)
+        }
     }
 
     foreign_links {
@@ -64,6 +64,8 @@
     unused_qualifications,
     unused_results,
 )]
+// the `error_chain!` expansion of `ErrorKind` exceeds the default limit.
+#![recursion_limit = "256"]
 
 #[macro_use]
 extern crate error_chain;
@@ -84,3 +86,5 @@ pub mod error;
 pub mod model;
 
 pub mod simple;
+
+pub mod threaded;
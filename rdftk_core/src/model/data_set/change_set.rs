@@ -0,0 +1,276 @@
+/*!
+Batched edits spanning the graphs of a data set. A `DataSetChangeSet` holds a `ChangeSet` for
+each graph it changes, the default graph or a named graph, and applies them all as a single unit;
+either every graph is changed or, if any change set is rejected by a validator or any graph is
+already borrowed elsewhere, none are.
+
+This allows updates that must be made to more than one graph together, such as moving the
+statements describing a resource from a "draft" graph to a "published" graph, to be made without
+leaving the data set in a partial state. A named graph that does not yet exist is created, by the
+data set's graph factory, when statements are added to it.
+
+# Example
+
+```rust
+use rdftk_core::model::data_set::change_set::DataSetChangeSet;
+use rdftk_core::model::data_set::GraphName;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let iri = |name: &str| IRIRef::from(IRI::from_str(&format!("http://example.org/{}", name)).unwrap());
+let factory = statement_factory();
+let name = factory
+    .statement(
+        factory.named_subject(iri("alice")),
+        foaf::name().clone(),
+        factory.literal_object(literal_factory().literal("Alice")),
+    )
+    .unwrap();
+
+let draft = GraphName::named_ref(iri("draft"));
+let published = GraphName::named_ref(iri("published"));
+let data_set = data_set_factory().data_set(None);
+data_set
+    .borrow_mut()
+    .insert(draft.clone(), graph_factory().graph_from(&[name.clone()], None));
+
+let mut changes = DataSetChangeSet::default();
+let _ = changes.move_statements(Some(&draft), Some(&published), &[name.clone()]);
+changes.apply(&mut *data_set.borrow_mut()).unwrap();
+
+let data_set = data_set.borrow();
+assert!(data_set.graph_named(&draft).unwrap().borrow().is_empty());
+assert!(data_set.graph_named(&published).unwrap().borrow().contains(&name));
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::data_set::{DataSet, GraphNameRef};
+use crate::model::graph::change_set::{ChangeSet, ChangeValidator};
+use crate::model::graph::GraphRef;
+use crate::model::statement::StatementRef;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A change set for each graph, identified by name or `None` for the default graph, to be
+/// applied to a data set as a single unit.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DataSetChangeSet {
+    graphs: Vec<(Option<GraphNameRef>, ChangeSet)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl DataSetChangeSet {
+    ///
+    /// Return the change set for the graph `name`, or the default graph if `None`, creating an
+    /// empty one if necessary.
+    ///
+    pub fn graph(&mut self, name: Option<&GraphNameRef>) -> &mut ChangeSet {
+        let index = match self
+            .graphs
+            .iter()
+            .position(|(graph, _)| graph.as_ref() == name)
+        {
+            Some(index) => index,
+            None => {
+                self.graphs.push((name.cloned(), ChangeSet::default()));
+                self.graphs.len() - 1
+            }
+        };
+        &mut self.graphs[index].1
+    }
+
+    ///
+    /// Append a change that adds `statement` to the graph `name`.
+    ///
+    pub fn add(&mut self, name: Option<&GraphNameRef>, statement: StatementRef) -> &mut Self {
+        let _ = self.graph(name).add(statement);
+        self
+    }
+
+    ///
+    /// Append a change that removes `statement` from the graph `name`.
+    ///
+    pub fn remove(&mut self, name: Option<&GraphNameRef>, statement: StatementRef) -> &mut Self {
+        let _ = self.graph(name).remove(statement);
+        self
+    }
+
+    ///
+    /// Append changes that remove each of `statements` from the graph `from` and add them to the
+    /// graph `to`.
+    ///
+    pub fn move_statements(
+        &mut self,
+        from: Option<&GraphNameRef>,
+        to: Option<&GraphNameRef>,
+        statements: &[StatementRef],
+    ) -> &mut Self {
+        for statement in statements {
+            let _ = self
+                .remove(from, statement.clone())
+                .add(to, statement.clone());
+        }
+        self
+    }
+
+    ///
+    /// Returns `true` if there are no changes to any graph.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.graphs.iter().all(|(_, changes)| changes.is_empty())
+    }
+
+    ///
+    /// Return the number of changes, to all graphs.
+    ///
+    pub fn len(&self) -> usize {
+        self.graphs.iter().map(|(_, changes)| changes.len()).sum()
+    }
+
+    ///
+    /// Return the change set for each graph, in the order each graph was first changed.
+    ///
+    pub fn graphs(&self) -> impl Iterator<Item = (Option<&GraphNameRef>, &ChangeSet)> {
+        self.graphs
+            .iter()
+            .map(|(name, changes)| (name.as_ref(), changes))
+    }
+
+    ///
+    /// Return a data set change set that reverses this one.
+    ///
+    pub fn inverse(&self) -> Self {
+        Self {
+            graphs: self
+                .graphs
+                .iter()
+                .rev()
+                .map(|(name, changes)| (name.clone(), changes.inverse()))
+                .collect(),
+        }
+    }
+
+    ///
+    /// Run each of the `validators` over the changes to each graph as they would be applied to
+    /// `data_set`, returning all the problems found, each prefixed by the name of the graph.
+    ///
+    pub fn validate(
+        &self,
+        data_set: &dyn DataSet,
+        validators: &[&dyn ChangeValidator],
+    ) -> Vec<String> {
+        let mut problems: Vec<String> = Default::default();
+        for (name, changes) in &self.graphs {
+            let graph = target(data_set, name.as_ref())
+                .cloned()
+                .unwrap_or_else(|| data_set.graph_factory().graph());
+            let graph = graph.borrow();
+            problems.extend(
+                changes
+                    .validate(&*graph, validators)
+                    .into_iter()
+                    .map(|problem| format!("{}: {}", display_name(name.as_ref()), problem)),
+            );
+        }
+        problems
+    }
+
+    ///
+    /// Apply the changes to each graph in `data_set`. If any graph to be changed is currently
+    /// borrowed the error `GraphInUse` is returned and no graph is changed.
+    ///
+    pub fn apply(&self, data_set: &mut dyn DataSet) -> Result<()> {
+        for (name, _) in &self.graphs {
+            if let Some(graph) = target(data_set, name.as_ref()) {
+                if graph.try_borrow_mut().is_err() {
+                    return Err(ErrorKind::GraphInUse(display_name(name.as_ref())).into());
+                }
+            }
+        }
+        for (name, changes) in &self.graphs {
+            let graph = match target(data_set, name.as_ref()) {
+                Some(graph) => graph.clone(),
+                None if changes.additions().next().is_some() => {
+                    let graph = data_set.graph_factory().graph();
+                    match name {
+                        Some(name) => data_set.insert(name.clone(), graph.clone()),
+                        None => data_set.set_default_graph(graph.clone()),
+                    }
+                    graph
+                }
+                None => continue,
+            };
+            changes.apply(&mut *graph.borrow_mut());
+        }
+        Ok(())
+    }
+
+    ///
+    /// Validate, and if no validator reports a problem, apply all changes to `data_set`. If any
+    /// problem is reported the error `ChangeSetRejected` is returned and no graph is changed.
+    ///
+    pub fn apply_validated(
+        &self,
+        data_set: &mut dyn DataSet,
+        validators: &[&dyn ChangeValidator],
+    ) -> Result<()> {
+        let problems = self.validate(data_set, validators);
+        if problems.is_empty() {
+            self.apply(data_set)
+        } else {
+            Err(ErrorKind::ChangeSetRejected(problems).into())
+        }
+    }
+
+    ///
+    /// Return these changes as a single RDF Patch transaction, one `A` (add) or `D` (delete) row
+    /// for each change, with the graph name for changes to a named graph.
+    ///
+    pub fn to_rdf_patch(&self) -> String {
+        let mut patch = String::from("TX .\n");
+        for (name, changes) in &self.graphs {
+            for change in changes.changes() {
+                patch.push_str(&format!(
+                    "{} {}{} .\n",
+                    if change.is_add() { "A" } else { "D" },
+                    change.statement(),
+                    name.as_ref()
+                        .map(|name| format!(" {}", name))
+                        .unwrap_or_default()
+                ));
+            }
+        }
+        patch.push_str("TC .\n");
+        patch
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn target<'a>(data_set: &'a dyn DataSet, name: Option<&GraphNameRef>) -> Option<&'a GraphRef> {
+    match name {
+        Some(name) => data_set.graph_named(name),
+        None => data_set.default_graph(),
+    }
+}
+
+fn display_name(name: Option<&GraphNameRef>) -> String {
+    name.map(|name| name.to_string())
+        .unwrap_or_else(|| "default graph".to_string())
+}
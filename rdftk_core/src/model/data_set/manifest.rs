@@ -0,0 +1,570 @@
+/*!
+Manifests recording the integrity of each named graph in a data set, so that the consumer of a
+distribution made up of many graphs may verify each graph independently of the files, or
+representations, used to carry them.
+
+A `Manifest` is created by a `ManifestGenerator`, and holds a `ManifestEntry` for each named graph
+with the graph's canonical hash (see `graph::canonical::canonical_hash`), the number of statements
+in the graph, the source it was taken from, if known, and the time the entry was made. The
+manifest is itself represented as RDF by `to_graph`, and read back by `from_graph`, using the
+properties below; the subject of the manifest, and of each entry, is a blank node.
+
+| Property                      | Value                                                       |
+|-------------------------------|-------------------------------------------------------------|
+| `rdf:type`                    | `<http://rust-rdftk.dev/manifest/Manifest>`, on the manifest |
+| `<.../manifest/graph>`        | each entry, on the manifest                                 |
+| `<.../manifest/name>`         | the name of the graph, an IRI or blank node                 |
+| `<.../manifest/canonicalHash>`| the hex encoded SHA-256 hash of the canonical form          |
+| `void:triples`                | the number of statements, as `xsd:unsignedLong`             |
+| `dcterms:source`              | the IRI of the source, if known                             |
+| `dcterms:created`             | the time the entry was made, as a UTC `xsd:dateTime`        |
+| `<.../manifest/signature>`    | the hex encoded signature, on the manifest                  |
+| `<.../manifest/signatureAlgorithm>` | the name of the signature algorithm, on the manifest  |
+
+A manifest may be signed by any implementation of `Signer`; the message signed is the canonical
+hash of the manifest's graph without the signature statements, so that the signature remains valid
+however the manifest is serialized. A signed manifest is checked with an implementation of
+`Verifier` and `verify_signature`, and the graphs of a data set checked against the manifest with
+`verify`.
+
+# Example
+
+```rust
+use rdftk_core::model::data_set::manifest::{Manifest, ManifestGenerator};
+use rdftk_core::model::data_set::GraphName;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let iri = |name: &str| IRIRef::from(IRI::from_str(&format!("http://example.org/{}", name)).unwrap());
+let factory = statement_factory();
+let name = factory
+    .statement(
+        factory.named_subject(iri("alice")),
+        foaf::name().clone(),
+        factory.literal_object(literal_factory().literal("Alice")),
+    )
+    .unwrap();
+
+let people = GraphName::named_ref(iri("people"));
+let data_set = data_set_factory().data_set(None);
+data_set
+    .borrow_mut()
+    .insert(people.clone(), graph_factory().graph_from(&[name], None));
+
+let manifest = ManifestGenerator::default()
+    .with_source(&people, iri("people.ttl"))
+    .generate(&*data_set.borrow())
+    .unwrap();
+assert_eq!(manifest.entry(&people).unwrap().triples(), 1);
+
+let graph = manifest.to_graph(&graph_factory()).unwrap();
+let manifest = Manifest::from_graph(&*graph.borrow()).unwrap();
+assert!(manifest.verify(&*data_set.borrow()).unwrap().is_empty());
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::data_set::{DataSet, GraphName, GraphNameRef};
+#[cfg(not(feature = "parallel"))]
+use crate::model::graph::canonical::canonical_hash;
+#[cfg(feature = "parallel")]
+use crate::model::graph::canonical::canonical_hash_parallel as canonical_hash;
+use crate::model::graph::{Graph, GraphFactoryRef, GraphRef};
+use crate::model::literal::{system_time_lexical_form, DataType};
+use crate::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::dc::terms;
+use rdftk_names::{rdf, void};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::time::SystemTime;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The namespace of the classes and properties used to represent a manifest.
+///
+pub const NAMESPACE: &str = "http://rust-rdftk.dev/manifest/";
+
+///
+/// Creates a signature for a manifest.
+///
+pub trait Signer {
+    ///
+    /// Return the name of the signature algorithm, recorded in the manifest.
+    ///
+    fn algorithm(&self) -> String;
+
+    ///
+    /// Return the signature of `message`.
+    ///
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>>;
+}
+
+///
+/// Checks the signature of a manifest.
+///
+pub trait Verifier {
+    ///
+    /// Returns `true` if `signature`, made with the named `algorithm`, is a valid signature of
+    /// `message`, else `false`.
+    ///
+    fn verify(&self, algorithm: &str, message: &[u8], signature: &[u8]) -> Result<bool>;
+}
+
+///
+/// Generates a manifest for the named graphs of a data set.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ManifestGenerator {
+    sources: HashMap<GraphNameRef, IRIRef>,
+    default_source: Option<IRIRef>,
+    timestamp: Option<SystemTime>,
+}
+
+///
+/// The record of a single named graph in a manifest.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEntry {
+    name: GraphNameRef,
+    hash: String,
+    triples: u64,
+    source: Option<IRIRef>,
+    created: String,
+}
+
+///
+/// The signature of a manifest, and the algorithm used to create it.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    algorithm: String,
+    value: Vec<u8>,
+}
+
+///
+/// A manifest recording each named graph in a data set.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    entries: Vec<ManifestEntry>,
+    signature: Option<Signature>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ManifestGenerator {
+    ///
+    /// Record `source` as the source of the graph `name`.
+    ///
+    pub fn with_source(mut self, name: &GraphNameRef, source: IRIRef) -> Self {
+        let _ = self.sources.insert(name.clone(), source);
+        self
+    }
+
+    ///
+    /// Record `source` as the source of any graph without its own source.
+    ///
+    pub fn with_default_source(self, source: IRIRef) -> Self {
+        Self {
+            default_source: Some(source),
+            ..self
+        }
+    }
+
+    ///
+    /// Record `timestamp` as the time each entry was made, rather than the time `generate` is
+    /// called.
+    ///
+    pub fn with_timestamp(self, timestamp: SystemTime) -> Self {
+        Self {
+            timestamp: Some(timestamp),
+            ..self
+        }
+    }
+
+    ///
+    /// Create a manifest with an entry for each named graph in `data_set`, in the order of their
+    /// names.
+    ///
+    pub fn generate(&self, data_set: &dyn DataSet) -> Result<Manifest> {
+        let created = system_time_lexical_form(self.timestamp.unwrap_or_else(SystemTime::now));
+        let mut entries = data_set
+            .graphs()
+            .map(|(name, graph)| {
+                let graph = graph.borrow();
+                Ok(ManifestEntry {
+                    name: name.clone(),
+                    hash: canonical_hash(&*graph)?,
+                    triples: graph.len() as u64,
+                    source: self
+                        .sources
+                        .get(name)
+                        .or(self.default_source.as_ref())
+                        .cloned(),
+                    created: created.clone(),
+                })
+            })
+            .collect::<Result<Vec<ManifestEntry>>>()?;
+        entries.sort_by_key(|entry| entry.name.to_string());
+        Ok(Manifest {
+            entries,
+            signature: None,
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ManifestEntry {
+    ///
+    /// Return the name of the graph.
+    ///
+    pub fn name(&self) -> &GraphNameRef {
+        &self.name
+    }
+
+    ///
+    /// Return the hex encoded SHA-256 hash of the canonical form of the graph.
+    ///
+    pub fn hash(&self) -> &String {
+        &self.hash
+    }
+
+    ///
+    /// Return the number of statements in the graph.
+    ///
+    pub fn triples(&self) -> u64 {
+        self.triples
+    }
+
+    ///
+    /// Return the source of the graph, if known.
+    ///
+    pub fn source(&self) -> Option<&IRIRef> {
+        self.source.as_ref()
+    }
+
+    ///
+    /// Return the time this entry was made, as the lexical form of a UTC `xsd:dateTime`.
+    ///
+    pub fn created(&self) -> &String {
+        &self.created
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Signature {
+    ///
+    /// Return the name of the algorithm used to create this signature.
+    ///
+    pub fn algorithm(&self) -> &String {
+        &self.algorithm
+    }
+
+    ///
+    /// Return the value of this signature.
+    ///
+    pub fn value(&self) -> &Vec<u8> {
+        &self.value
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Manifest {
+    ///
+    /// Read a manifest from `graph`, as written by `to_graph`.
+    ///
+    pub fn from_graph(graph: &dyn Graph) -> Result<Self> {
+        let factory = graph.statement_factory();
+        let manifest_class = factory.named_object(iri("Manifest"));
+        let manifest = graph
+            .statements()
+            .find(|st| st.predicate() == rdf::a_type() && st.object() == &manifest_class)
+            .map(|st| st.subject().clone())
+            .ok_or_else(|| invalid("no manifest resource"))?;
+
+        let mut entries: Vec<ManifestEntry> = Default::default();
+        for entry in graph.objects_for(&manifest, &iri("graph")) {
+            let entry = factory
+                .object_as_subject(entry.clone())
+                .ok_or_else(|| invalid("an entry is a literal"))?;
+            let name = match required(graph, &entry, &iri("name"))? {
+                name if name.is_blank() => GraphName::blank_named(name.as_blank().unwrap()).into(),
+                name if name.is_iri() => GraphName::named_ref(name.as_iri().unwrap().clone()),
+                _ => return Err(invalid("a graph name is not an IRI or blank node")),
+            };
+            let triples = required_literal(graph, &entry, void::triples())?;
+            entries.push(ManifestEntry {
+                name,
+                hash: required_literal(graph, &entry, &iri("canonicalHash"))?,
+                triples: u64::from_str(&triples)
+                    .map_err(|_| invalid(&format!("{:?} is not a count", triples)))?,
+                source: optional(graph, &entry, terms::source())
+                    .and_then(|source| source.as_iri().cloned()),
+                created: required_literal(graph, &entry, terms::created())?,
+            });
+        }
+        entries.sort_by_key(|entry| entry.name.to_string());
+
+        let signature = match optional(graph, &manifest, &iri("signature")) {
+            None => None,
+            Some(_) => Some(Signature {
+                algorithm: required_literal(graph, &manifest, &iri("signatureAlgorithm"))?,
+                value: from_hex(&required_literal(graph, &manifest, &iri("signature"))?)
+                    .ok_or_else(|| invalid("the signature is not hex encoded"))?,
+            }),
+        };
+        Ok(Self { entries, signature })
+    }
+
+    ///
+    /// Return the entries of this manifest, in the order of their graph names.
+    ///
+    pub fn entries(&self) -> &[ManifestEntry] {
+        &self.entries
+    }
+
+    ///
+    /// Return the entry for the graph `name`, if any.
+    ///
+    pub fn entry(&self, name: &GraphNameRef) -> Option<&ManifestEntry> {
+        self.entries.iter().find(|entry| &entry.name == name)
+    }
+
+    ///
+    /// Return the signature of this manifest, if it has been signed.
+    ///
+    pub fn signature(&self) -> Option<&Signature> {
+        self.signature.as_ref()
+    }
+
+    ///
+    /// Sign this manifest with `signer`, replacing any existing signature.
+    ///
+    pub fn sign(&mut self, signer: &dyn Signer) -> Result<()> {
+        let message = self.message()?;
+        self.signature = Some(Signature {
+            algorithm: signer.algorithm(),
+            value: signer.sign(message.as_bytes())?,
+        });
+        Ok(())
+    }
+
+    ///
+    /// Returns `true` if this manifest is signed, and `verifier` accepts the signature, else
+    /// `false`.
+    ///
+    pub fn verify_signature(&self, verifier: &dyn Verifier) -> Result<bool> {
+        match &self.signature {
+            None => Ok(false),
+            Some(signature) => verifier.verify(
+                &signature.algorithm,
+                self.message()?.as_bytes(),
+                &signature.value,
+            ),
+        }
+    }
+
+    ///
+    /// Return the names of the graphs that do not match this manifest; those in the manifest but
+    /// not in `data_set`, those in `data_set` but not in the manifest, and those whose hash or
+    /// number of statements differ from the manifest's entry.
+    ///
+    pub fn verify(&self, data_set: &dyn DataSet) -> Result<Vec<GraphNameRef>> {
+        let mut failed: Vec<GraphNameRef> = Default::default();
+        for entry in &self.entries {
+            let matches = match data_set.graph_named(&entry.name) {
+                None => false,
+                Some(graph) => {
+                    let graph = graph.borrow();
+                    graph.len() as u64 == entry.triples && canonical_hash(&*graph)? == entry.hash
+                }
+            };
+            if !matches {
+                failed.push(entry.name.clone());
+            }
+        }
+        failed.extend(
+            data_set
+                .graph_names()
+                .filter(|name| self.entry(name).is_none())
+                .cloned(),
+        );
+        Ok(failed)
+    }
+
+    ///
+    /// Create a new graph, using `factory`, describing this manifest.
+    ///
+    pub fn to_graph(&self, factory: &GraphFactoryRef) -> Result<GraphRef> {
+        let graph = factory.graph();
+        {
+            let mut graph = graph.borrow_mut();
+            let manifest = graph.statement_factory().blank_subject();
+            for statement in self.unsigned_statements(&*graph, &manifest)? {
+                graph.insert(statement);
+            }
+            if let Some(signature) = &self.signature {
+                let statements = graph.statement_factory();
+                let literals = graph.literal_factory();
+                graph.insert(statements.statement(
+                    manifest.clone(),
+                    iri("signature"),
+                    statements.literal_object(literals.literal(&to_hex(&signature.value))),
+                )?);
+                graph.insert(statements.statement(
+                    manifest,
+                    iri("signatureAlgorithm"),
+                    statements.literal_object(literals.literal(&signature.algorithm)),
+                )?);
+            }
+            let mappings = graph.prefix_mappings();
+            let mut mappings = mappings.borrow_mut();
+            mappings.insert("manifest", iri(""));
+            mappings.insert("void", void::namespace_iri().clone());
+            mappings.insert("dcterms", terms::namespace_iri().clone());
+        }
+        Ok(graph)
+    }
+
+    fn unsigned_statements(
+        &self,
+        graph: &dyn Graph,
+        manifest: &SubjectNodeRef,
+    ) -> Result<Vec<StatementRef>> {
+        let statements = graph.statement_factory();
+        let literals = graph.literal_factory();
+        let mut result = vec![statements.statement(
+            manifest.clone(),
+            rdf::a_type().clone(),
+            statements.named_object(iri("Manifest")),
+        )?];
+        for entry in &self.entries {
+            let subject = statements.blank_subject();
+            let name = match entry.name.as_iri() {
+                Some(name) => statements.named_object(name.clone()),
+                None => statements.blank_object_named(entry.name.as_blank().unwrap())?,
+            };
+            result.push(statements.statement(
+                manifest.clone(),
+                iri("graph"),
+                statements.subject_as_object(subject.clone()),
+            )?);
+            result.push(statements.statement(subject.clone(), iri("name"), name)?);
+            result.push(statements.statement(
+                subject.clone(),
+                iri("canonicalHash"),
+                statements.literal_object(literals.literal(&entry.hash)),
+            )?);
+            result.push(statements.statement(
+                subject.clone(),
+                void::triples().clone(),
+                statements.literal_object(literals.unsigned_long(entry.triples)),
+            )?);
+            if let Some(source) = &entry.source {
+                result.push(statements.statement(
+                    subject.clone(),
+                    terms::source().clone(),
+                    statements.named_object(source.clone()),
+                )?);
+            }
+            result.push(
+                statements.statement(
+                    subject,
+                    terms::created().clone(),
+                    statements.literal_object(
+                        literals.with_data_type(&entry.created, DataType::DateTime),
+                    ),
+                )?,
+            );
+        }
+        Ok(result)
+    }
+
+    fn message(&self) -> Result<String> {
+        let graph = crate::simple::graph::graph_factory().graph();
+        {
+            let mut graph = graph.borrow_mut();
+            let manifest = graph.statement_factory().blank_subject();
+            for statement in self.unsigned_statements(&*graph, &manifest)? {
+                graph.insert(statement);
+            }
+        }
+        let hash = canonical_hash(&*graph.borrow())?;
+        Ok(hash)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn iri(local_name: &str) -> IRIRef {
+    IRIRef::from(IRI::from_str(&format!("{}{}", NAMESPACE, local_name)).unwrap())
+}
+
+fn invalid(reason: &str) -> crate::error::Error {
+    ErrorKind::InvalidManifest(reason.to_string()).into()
+}
+
+fn optional(
+    graph: &dyn Graph,
+    subject: &SubjectNodeRef,
+    predicate: &IRIRef,
+) -> Option<ObjectNodeRef> {
+    graph
+        .objects_for(subject, predicate)
+        .into_iter()
+        .next()
+        .cloned()
+}
+
+fn required(
+    graph: &dyn Graph,
+    subject: &SubjectNodeRef,
+    predicate: &IRIRef,
+) -> Result<ObjectNodeRef> {
+    optional(graph, subject, predicate)
+        .ok_or_else(|| invalid(&format!("an entry has no value for <{}>", predicate)))
+}
+
+fn required_literal(
+    graph: &dyn Graph,
+    subject: &SubjectNodeRef,
+    predicate: &IRIRef,
+) -> Result<String> {
+    required(graph, subject, predicate)?
+        .as_literal()
+        .map(|literal| literal.lexical_form().clone())
+        .ok_or_else(|| invalid(&format!("the value of <{}> is not a literal", predicate)))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn from_hex(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .filter(|pair| pair.len() == 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+        })
+        .collect()
+}
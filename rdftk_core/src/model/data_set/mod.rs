@@ -38,6 +38,7 @@ fn simple_graph_writer(graph: &GraphRef)
 
 use crate::model::features::Featured;
 use crate::model::graph::{GraphFactoryRef, GraphRef};
+use crate::model::statement::StatementRef;
 use crate::model::Provided;
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -84,6 +85,12 @@ pub trait DataSetFactory: Debug + Provided {
 ///
 pub type DataSetFactoryRef = Arc<dyn DataSetFactory>;
 
+///
+/// A statement together with the name of the graph that contains it, or `None` for the default
+/// graph.
+///
+pub type Quad = (Option<GraphNameRef>, StatementRef);
+
 ///
 /// A `DataSet` is a mapping from `GraphName` to `Graph`; this introduces the notion of a named graph
 /// although in actuality the graph itself is not named as the name is the key within the data set.
@@ -126,6 +133,61 @@ pub trait DataSet: Debug + Featured {
     ///
     fn graphs<'a>(&'a self) -> Box<dyn Iterator<Item = (&'a GraphNameRef, &'a GraphRef)> + 'a>;
 
+    ///
+    /// Return an iterator over the names of all the named graphs in this data set.
+    ///
+    fn graph_names<'a>(&'a self) -> Box<dyn Iterator<Item = &'a GraphNameRef> + 'a> {
+        Box::new(self.graphs().map(|(name, _)| name))
+    }
+
+    ///
+    /// Return all the statements in this data set, with the name of the graph containing each;
+    /// statements in the default graph are returned first.
+    ///
+    fn quads(&self) -> Vec<Quad> {
+        let mut quads: Vec<Quad> = Default::default();
+        if let Some(graph) = self.default_graph() {
+            quads.extend(
+                graph
+                    .borrow()
+                    .statements()
+                    .map(|statement| (None, statement.clone())),
+            );
+        }
+        for (name, graph) in self.graphs() {
+            quads.extend(
+                graph
+                    .borrow()
+                    .statements()
+                    .map(|statement| (Some(name.clone()), statement.clone())),
+            );
+        }
+        quads
+    }
+
+    ///
+    /// Return a new graph containing the statements of the default graph and of every named
+    /// graph, as used by queries that treat the default graph as the union of the data set.
+    ///
+    fn union_graph(&self) -> GraphRef {
+        let union = self.graph_factory().graph();
+        {
+            let mut union = union.borrow_mut();
+            for graph in self
+                .default_graph()
+                .into_iter()
+                .chain(self.graphs().map(|(_, graph)| graph))
+            {
+                let graph = graph.borrow();
+                for statement in graph.statements() {
+                    union.insert(statement.clone());
+                }
+            }
+            let _ = union.dedup();
+        }
+        union
+    }
+
     ///
     /// Set the provided graph as the default, unnamed graph, for this data set. Only one graph may
     /// be the default.
@@ -179,5 +241,9 @@ pub type DataSetRef = Rc<RefCell<dyn DataSet>>;
 // Modules
 // ------------------------------------------------------------------------------------------------
 
+pub mod change_set;
+
+pub mod manifest;
+
 pub mod name;
 pub use name::{GraphName, GraphNameRef};
@@ -17,7 +17,7 @@ use crate::statement::StatementRef;
 use rdftk_iri::IRIRef;
 use std::collections::BTreeSet;
 use std::fmt::{Display, Formatter};
-use std::rc::Rc;
+use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Hash)]
 pub enum Quantification {
@@ -37,7 +37,7 @@ pub struct Formula {
     statements: BTreeSet<StatementRef>,
 }
 
-pub type FormulaRef = Rc<Formula>;
+pub type FormulaRef = Arc<Formula>;
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
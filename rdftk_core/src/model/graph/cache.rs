@@ -0,0 +1,550 @@
+/*!
+A decorator graph, `CachingGraph`, that keeps a least-recently-used cache of the description of
+each subject, for applications that describe the same subjects many times.
+
+The description of a subject, as returned by `describe`, is its
+[Concise Bounded Description](https://www.w3.org/Submission/CBD/); all the statements with the
+subject, and, recursively, the description of each blank node that is the object of one of these
+statements. Values derived from a description, such as the labels of a subject, may be retrieved
+from the cache with `CachingGraph::described_objects`.
+
+Each cached description is invalidated when a statement with its subject, or with any of the
+blank nodes included in the description, is inserted into or removed from the graph through the
+decorator; other mutations, such as `clear` or `dedup`, invalidate the whole cache. Changes made
+to the wrapped graph directly are not seen by the decorator, an application making such changes
+should call `invalidate` or `invalidate_all` itself.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::cache::CachingGraph;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::SimpleGraph;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+let factory = statement_factory();
+let subject = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/s").unwrap()));
+let label = IRIRef::from(IRI::from_str("http://www.w3.org/2000/01/rdf-schema#label").unwrap());
+
+let mut graph = CachingGraph::new(SimpleGraph::default(), 100);
+graph.insert(
+    factory
+        .statement(
+            subject.clone(),
+            label.clone(),
+            factory.literal_object(literal_factory().literal("S")),
+        )
+        .unwrap(),
+);
+
+assert_eq!(graph.describe(&subject).len(), 1);
+assert_eq!(graph.described_objects(&subject, &label).len(), 1);
+assert_eq!(graph.statistics().hits(), 1);
+assert_eq!(graph.statistics().misses(), 1);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::features::Featured;
+use crate::model::graph::{
+    Graph, GraphFactoryRef, GraphRef, PrefixMappingRef, Snapshot, StatementIter,
+};
+use crate::model::literal::LiteralFactoryRef;
+use crate::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
+};
+use rdftk_iri::IRIRef;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A graph that caches the description of subjects of the graph it wraps; see the module
+/// documentation.
+///
+#[derive(Debug)]
+pub struct CachingGraph<G: Graph> {
+    inner: G,
+    cache: RefCell<DescriptionCache>,
+}
+
+///
+/// The effectiveness of the cache held by a `CachingGraph`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheStatistics {
+    hits: usize,
+    misses: usize,
+    evictions: usize,
+    invalidations: usize,
+    entries: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct DescriptionCache {
+    capacity: usize,
+    entries: HashMap<SubjectNodeRef, CacheEntry>,
+    recency: BTreeMap<u64, SubjectNodeRef>,
+    clock: u64,
+    statistics: CacheStatistics,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    description: StatementList,
+    depends_on: HashSet<SubjectNodeRef>,
+    used: u64,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the Concise Bounded Description of `subject` in `graph`; all the statements with the
+/// subject and, recursively, the description of each blank node object of these statements.
+///
+pub fn describe(graph: &dyn Graph, subject: &SubjectNodeRef) -> StatementList {
+    bounded_description(graph, subject).0
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl CacheStatistics {
+    ///
+    /// Return the number of descriptions returned from the cache.
+    ///
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    ///
+    /// Return the number of descriptions that had to be computed.
+    ///
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    ///
+    /// Return the number of descriptions removed as the cache was full.
+    ///
+    pub fn evictions(&self) -> usize {
+        self.evictions
+    }
+
+    ///
+    /// Return the number of descriptions removed as the graph changed.
+    ///
+    pub fn invalidations(&self) -> usize {
+        self.invalidations
+    }
+
+    ///
+    /// Return the number of descriptions currently cached.
+    ///
+    pub fn entries(&self) -> usize {
+        self.entries
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<G: Graph> Featured for CachingGraph<G> {
+    fn supports_feature(&self, feature: &IRIRef) -> bool {
+        self.inner.supports_feature(feature)
+    }
+}
+
+impl<G: Graph> Graph for CachingGraph<G> {
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn contains_subject(&self, subject: &SubjectNodeRef) -> bool {
+        self.inner.contains_subject(subject)
+    }
+
+    fn contains_individual(&self, subject: &IRIRef) -> bool {
+        self.inner.contains_individual(subject)
+    }
+
+    fn contains(&self, statement: &StatementRef) -> bool {
+        self.inner.contains(statement)
+    }
+
+    fn contains_all(
+        &self,
+        subject: &SubjectNodeRef,
+        predicate: &IRIRef,
+        object: &ObjectNodeRef,
+    ) -> bool {
+        self.inner.contains_all(subject, predicate, object)
+    }
+
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        self.inner.matches(subject, predicate, object)
+    }
+
+    fn statements_matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> StatementList {
+        self.inner.statements_matching(subject, predicate, object)
+    }
+
+    fn statements(&self) -> StatementIter<'_> {
+        self.inner.statements()
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        self.inner.snapshot()
+    }
+
+    fn subjects(&self) -> HashSet<&SubjectNodeRef> {
+        self.inner.subjects()
+    }
+
+    fn predicates(&self) -> HashSet<&IRIRef> {
+        self.inner.predicates()
+    }
+
+    fn predicates_for(&self, subject: &SubjectNodeRef) -> HashSet<&IRIRef> {
+        self.inner.predicates_for(subject)
+    }
+
+    fn objects(&self) -> HashSet<&ObjectNodeRef> {
+        self.inner.objects()
+    }
+
+    fn objects_for(&self, subject: &SubjectNodeRef, predicate: &IRIRef) -> HashSet<&ObjectNodeRef> {
+        self.inner.objects_for(subject, predicate)
+    }
+
+    fn distinct_subjects(&self) -> Box<dyn Iterator<Item = (&SubjectNodeRef, usize)> + '_> {
+        self.inner.distinct_subjects()
+    }
+
+    fn distinct_predicates(&self) -> Box<dyn Iterator<Item = (&IRIRef, usize)> + '_> {
+        self.inner.distinct_predicates()
+    }
+
+    fn distinct_objects(&self) -> Box<dyn Iterator<Item = (&ObjectNodeRef, usize)> + '_> {
+        self.inner.distinct_objects()
+    }
+
+    fn count_by_predicate(&self) -> HashMap<&IRIRef, usize> {
+        self.inner.count_by_predicate()
+    }
+
+    fn skolemize(&self, base: &IRIRef) -> Result<GraphRef> {
+        self.inner.skolemize(base)
+    }
+
+    fn canonicalize(&self) -> Result<GraphRef> {
+        self.inner.canonicalize()
+    }
+
+    fn lean(&self) -> GraphRef {
+        self.inner.lean()
+    }
+
+    fn is_lean(&self) -> bool {
+        self.inner.is_lean()
+    }
+
+    fn union(&self, other: &dyn Graph) -> Result<GraphRef> {
+        self.inner.union(other)
+    }
+
+    fn intersection(&self, other: &dyn Graph) -> GraphRef {
+        self.inner.intersection(other)
+    }
+
+    fn difference(&self, other: &dyn Graph) -> GraphRef {
+        self.inner.difference(other)
+    }
+
+    fn prefix_mappings(&self) -> PrefixMappingRef {
+        self.inner.prefix_mappings()
+    }
+
+    fn set_prefix_mappings(&mut self, mappings: PrefixMappingRef) {
+        self.inner.set_prefix_mappings(mappings)
+    }
+
+    fn factory(&self) -> GraphFactoryRef {
+        self.inner.factory()
+    }
+
+    fn statement_factory(&self) -> StatementFactoryRef {
+        self.inner.statement_factory()
+    }
+
+    fn literal_factory(&self) -> LiteralFactoryRef {
+        self.inner.literal_factory()
+    }
+
+    fn statements_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut StatementRef> + 'a> {
+        self.invalidate_all();
+        self.inner.statements_mut()
+    }
+
+    fn insert(&mut self, statement: StatementRef) {
+        self.invalidate(statement.subject());
+        self.inner.insert(statement)
+    }
+
+    fn extend_from(&mut self, statements: &mut dyn Iterator<Item = StatementRef>) -> usize {
+        let cache = &self.cache;
+        let mut statements =
+            statements.inspect(|statement| cache.borrow_mut().invalidate(statement.subject()));
+        self.inner.extend_from(&mut statements)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    fn merge(&mut self, other: &Self)
+    where
+        Self: Sized,
+    {
+        other
+            .inner
+            .statements()
+            .for_each(|st| self.insert(st.clone()))
+    }
+
+    fn dedup(&mut self) -> StatementList {
+        self.invalidate_all();
+        self.inner.dedup()
+    }
+
+    fn remove(&mut self, statement: &StatementRef) {
+        self.invalidate(statement.subject());
+        self.inner.remove(statement)
+    }
+
+    fn remove_all_for(&mut self, subject: &SubjectNodeRef) -> StatementList {
+        self.invalidate(subject);
+        self.inner.remove_all_for(subject)
+    }
+
+    fn clear(&mut self) {
+        self.invalidate_all();
+        self.inner.clear()
+    }
+}
+
+impl<G: Graph> CachingGraph<G> {
+    ///
+    /// Construct a new decorator caching, at most, `capacity` descriptions of subjects in
+    /// `inner`; a capacity of zero disables the cache.
+    ///
+    pub fn new(inner: G, capacity: usize) -> Self {
+        Self {
+            inner,
+            cache: RefCell::new(DescriptionCache::new(capacity)),
+        }
+    }
+
+    ///
+    /// Return the graph whose descriptions are cached.
+    ///
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    ///
+    /// Return the graph whose descriptions are cached, discarding the cache.
+    ///
+    pub fn into_inner(self) -> G {
+        self.inner
+    }
+
+    ///
+    /// Return the Concise Bounded Description of `subject`, from the cache if present.
+    ///
+    pub fn describe(&self, subject: &SubjectNodeRef) -> StatementList {
+        if let Some(description) = self.cache.borrow_mut().get(subject) {
+            return description;
+        }
+        let (description, depends_on) = bounded_description(&self.inner, subject);
+        self.cache
+            .borrow_mut()
+            .insert(subject.clone(), description.clone(), depends_on);
+        description
+    }
+
+    ///
+    /// Return the objects of the statements with `subject` and `predicate` in the description of
+    /// `subject`, from the cache if present; such as the labels of the subject.
+    ///
+    pub fn described_objects(
+        &self,
+        subject: &SubjectNodeRef,
+        predicate: &IRIRef,
+    ) -> Vec<ObjectNodeRef> {
+        self.describe(subject)
+            .iter()
+            .filter(|st| st.subject() == subject && st.predicate() == predicate)
+            .map(|st| st.object().clone())
+            .collect()
+    }
+
+    ///
+    /// Remove any cached description that includes statements with `subject`.
+    ///
+    pub fn invalidate(&self, subject: &SubjectNodeRef) {
+        self.cache.borrow_mut().invalidate(subject)
+    }
+
+    ///
+    /// Remove all cached descriptions.
+    ///
+    pub fn invalidate_all(&self) {
+        self.cache.borrow_mut().invalidate_all()
+    }
+
+    ///
+    /// Return the statistics for the cache.
+    ///
+    pub fn statistics(&self) -> CacheStatistics {
+        self.cache.borrow().statistics
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl DescriptionCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Default::default(),
+            recency: Default::default(),
+            clock: 0,
+            statistics: Default::default(),
+        }
+    }
+
+    fn get(&mut self, subject: &SubjectNodeRef) -> Option<StatementList> {
+        self.clock += 1;
+        let clock = self.clock;
+        match self.entries.get_mut(subject) {
+            Some(entry) => {
+                let _ = self.recency.remove(&entry.used);
+                entry.used = clock;
+                let _ = self.recency.insert(clock, subject.clone());
+                self.statistics.hits += 1;
+                Some(entry.description.clone())
+            }
+            None => {
+                self.statistics.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(
+        &mut self,
+        subject: SubjectNodeRef,
+        description: StatementList,
+        depends_on: HashSet<SubjectNodeRef>,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            let oldest = *self.recency.keys().next().unwrap();
+            let oldest = self.recency.remove(&oldest).unwrap();
+            let _ = self.entries.remove(&oldest);
+            self.statistics.evictions += 1;
+        }
+        self.clock += 1;
+        let _ = self.recency.insert(self.clock, subject.clone());
+        let _ = self.entries.insert(
+            subject,
+            CacheEntry {
+                description,
+                depends_on,
+                used: self.clock,
+            },
+        );
+        self.statistics.entries = self.entries.len();
+    }
+
+    fn invalidate(&mut self, subject: &SubjectNodeRef) {
+        let stale: Vec<SubjectNodeRef> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.depends_on.contains(subject))
+            .map(|(cached, _)| cached.clone())
+            .collect();
+        for cached in stale {
+            if let Some(entry) = self.entries.remove(&cached) {
+                let _ = self.recency.remove(&entry.used);
+                self.statistics.invalidations += 1;
+            }
+        }
+        self.statistics.entries = self.entries.len();
+    }
+
+    fn invalidate_all(&mut self) {
+        self.statistics.invalidations += self.entries.len();
+        self.entries.clear();
+        self.recency.clear();
+        self.statistics.entries = 0;
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn bounded_description(
+    graph: &dyn Graph,
+    subject: &SubjectNodeRef,
+) -> (StatementList, HashSet<SubjectNodeRef>) {
+    let factory = graph.statement_factory();
+    let mut description: StatementList = Default::default();
+    let mut visited: HashSet<SubjectNodeRef> = Default::default();
+    let mut queue: VecDeque<SubjectNodeRef> = VecDeque::from(vec![subject.clone()]);
+    while let Some(next) = queue.pop_front() {
+        if !visited.insert(next.clone()) {
+            continue;
+        }
+        for statement in graph.statements_matching(Some(&next), None, None) {
+            if statement.object().is_blank() {
+                if let Some(blank) = factory.object_as_subject(statement.object().clone()) {
+                    queue.push_back(blank);
+                }
+            }
+            description.push(statement);
+        }
+    }
+    (description, visited)
+}
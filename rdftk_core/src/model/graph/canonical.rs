@@ -0,0 +1,700 @@
+/*!
+An implementation of the [RDF Dataset Canonicalization](https://www.w3.org/TR/rdf-canon/)
+algorithm, RDFC-1.0, for graphs. Canonicalization assigns deterministic labels, of the form
+`c14n{n}`, to the blank nodes of a graph so that two isomorphic graphs have identical canonical
+forms; the canonical form, a sorted list of N-Triples statements, may then be hashed or signed.
+The same statements may also be listed in the order of any `order::StatementOrder`, with
+`canonical_n_triples_ordered`, for presentation.
+
+Literal lexical forms are written as held by the literal, as for the model's `Display`
+implementation, and the `xsd:string` data type is omitted. Statements used as subjects or
+objects, from RDF-star, are not covered by RDFC-1.0 and result in an error.
+
+Statements without blank nodes are already in canonical form; they are sorted apart from those
+with blank nodes and the two lists merged as the canonical form is produced, so that the hash of a
+large graph is computed without building its complete canonical form.
+
+With the feature `parallel` the functions `canonical_labels_parallel`,
+`canonical_n_triples_parallel`, and `canonical_hash_parallel` spread the work over a thread pool.
+Blank nodes whose first-degree hashes are shared are hashed concurrently where they fall in
+different connected components, while canonical labels are still issued in the order RDFC-1.0
+requires, so that the results are always the same as those of the sequential functions.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::canonical::{canonical_hash, canonical_n_triples};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let alice = IRIRef::from(IRI::from_str("http://example.org/alice").unwrap());
+let knows = |blank: &str| {
+    let factory = statement_factory();
+    graph_factory().graph_from(
+        &[factory
+            .statement(
+                factory.named_subject(alice.clone()),
+                foaf::knows().clone(),
+                factory.blank_object_named(blank).unwrap(),
+            )
+            .unwrap()],
+        None,
+    )
+};
+
+let lhs = knows("B1");
+let rhs = knows("someone");
+assert_eq!(
+    canonical_n_triples(&*lhs.borrow()).unwrap(),
+    vec!["<http://example.org/alice> <http://xmlns.com/foaf/0.1/knows> _:c14n0 .".to_string()]
+);
+assert_eq!(
+    canonical_hash(&*lhs.borrow()).unwrap(),
+    canonical_hash(&*rhs.borrow()).unwrap()
+);
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::graph::order::{sorted_statements, StatementOrder};
+use crate::model::graph::{Graph, GraphRef};
+use crate::model::literal::{DataType, LiteralRef};
+use crate::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The prefix used for canonical blank node labels.
+///
+pub const CANONICAL_PREFIX: &str = "c14n";
+
+///
+/// The maximum number of calls to the Hash N-Degree Quads algorithm before canonicalization is
+/// abandoned; this guards against graphs constructed to make canonicalization prohibitively
+/// expensive.
+///
+pub const MAX_N_DEGREE_CALLS: usize = 10_000;
+
+///
+/// The maximum number of groups of blank nodes, with shared first-degree hashes, considered for
+/// concurrent hashing at each step of `canonical_labels_parallel`.
+///
+#[cfg(feature = "parallel")]
+pub const PARALLEL_GROUP_WINDOW: usize = 1_024;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug)]
+enum Term {
+    Blank(String),
+    Ground(String),
+}
+
+#[derive(Clone, Debug)]
+struct Triple {
+    subject: Term,
+    predicate: String,
+    object: Term,
+}
+
+#[derive(Clone, Debug)]
+struct IdentifierIssuer {
+    prefix: &'static str,
+    issued: Vec<String>,
+    identifiers: HashMap<String, String>,
+}
+
+struct Canonicalizer {
+    ground: Vec<String>,
+    triples: Vec<Triple>,
+    blank_triples: HashMap<String, Vec<usize>>,
+    canonical: IdentifierIssuer,
+    n_degree_calls: AtomicUsize,
+}
+
+type HashPaths = Vec<(String, IdentifierIssuer)>;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the mapping from each blank node label in `graph` to its canonical label.
+///
+pub fn canonical_labels(graph: &(impl Graph + ?Sized)) -> Result<HashMap<String, String>> {
+    Ok(Canonicalizer::new(graph)?.canonicalize()?.identifiers)
+}
+
+///
+/// Return the canonical form of `graph`, the sorted list of its statements in N-Triples form with
+/// blank nodes relabelled canonically; each line excludes the trailing newline.
+///
+pub fn canonical_n_triples(graph: &(impl Graph + ?Sized)) -> Result<Vec<String>> {
+    let (ground, blank) = Canonicalizer::new(graph)?.sorted_lines()?;
+    Ok(merge_sorted(&ground, &blank).cloned().collect())
+}
+
+///
+/// Return the statements of `graph` in N-Triples form with blank nodes relabelled canonically, as
+/// for `canonical_n_triples`, but sorted by `order` rather than by the text of each statement.
+///
+pub fn canonical_n_triples_ordered(
+    graph: &(impl Graph + ?Sized),
+    order: &dyn StatementOrder,
+) -> Result<Vec<String>> {
+    let canonical = canonicalize(graph)?;
+    let canonical = canonical.borrow();
+    let mut seen: HashSet<String> = Default::default();
+    let mut lines: Vec<String> = Default::default();
+    for statement in sorted_statements(&*canonical, order) {
+        let mut line = Triple::from_statement(&statement)?.to_n_triples(|label| label.to_string());
+        let _ = line.pop();
+        if seen.insert(line.clone()) {
+            lines.push(line);
+        }
+    }
+    Ok(lines)
+}
+
+///
+/// Return the lower-case hex encoded SHA-256 hash of the canonical form of `graph`, suitable for
+/// comparing or signing graphs.
+///
+pub fn canonical_hash(graph: &(impl Graph + ?Sized)) -> Result<String> {
+    let (ground, blank) = Canonicalizer::new(graph)?.sorted_lines()?;
+    Ok(hash_lines(merge_sorted(&ground, &blank)))
+}
+
+///
+/// Return the mapping from each blank node label in `graph` to its canonical label, as for
+/// `canonical_labels`, using a thread pool.
+///
+#[cfg(feature = "parallel")]
+pub fn canonical_labels_parallel(graph: &(impl Graph + ?Sized)) -> Result<HashMap<String, String>> {
+    Ok(Canonicalizer::new(graph)?
+        .canonicalize_parallel()?
+        .identifiers)
+}
+
+///
+/// Return the canonical form of `graph`, as for `canonical_n_triples`, using a thread pool.
+///
+#[cfg(feature = "parallel")]
+pub fn canonical_n_triples_parallel(graph: &(impl Graph + ?Sized)) -> Result<Vec<String>> {
+    let (ground, blank) = Canonicalizer::new(graph)?.sorted_lines_parallel()?;
+    Ok(merge_sorted(&ground, &blank).cloned().collect())
+}
+
+///
+/// Return the hash of the canonical form of `graph`, as for `canonical_hash`, using a thread
+/// pool.
+///
+#[cfg(feature = "parallel")]
+pub fn canonical_hash_parallel(graph: &(impl Graph + ?Sized)) -> Result<String> {
+    let (ground, blank) = Canonicalizer::new(graph)?.sorted_lines_parallel()?;
+    Ok(hash_lines(merge_sorted(&ground, &blank)))
+}
+
+///
+/// Create a new graph, using the factory of `graph`, with each blank node relabelled with its
+/// canonical label. The initial graph is unchanged.
+///
+pub fn canonicalize(graph: &(impl Graph + ?Sized)) -> Result<GraphRef> {
+    let labels = canonical_labels(graph)?;
+    let factory = graph.statement_factory();
+    let new_graph = graph.factory().graph();
+    {
+        let mut new_graph = new_graph.borrow_mut();
+        for statement in graph.statements() {
+            let subject = match statement.subject().as_blank() {
+                Some(label) => factory.blank_subject_named(&labels[label])?,
+                None => statement.subject().clone(),
+            };
+            let object = match statement.object().as_blank() {
+                Some(label) => factory.blank_object_named(&labels[label])?,
+                None => statement.object().clone(),
+            };
+            new_graph.insert(factory.statement(subject, statement.predicate().clone(), object)?);
+        }
+    }
+    Ok(new_graph)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Triple {
+    fn from_statement(statement: &StatementRef) -> Result<Self> {
+        Ok(Self {
+            subject: subject_term(statement.subject())?,
+            predicate: format!("<{}>", statement.predicate()),
+            object: object_term(statement.object())?,
+        })
+    }
+
+    fn blank_nodes(&self) -> impl Iterator<Item = (&String, &'static str)> {
+        let subject = match &self.subject {
+            Term::Blank(label) => Some((label, "s")),
+            Term::Ground(_) => None,
+        };
+        let object = match &self.object {
+            Term::Blank(label) => Some((label, "o")),
+            Term::Ground(_) => None,
+        };
+        subject.into_iter().chain(object)
+    }
+
+    fn is_ground(&self) -> bool {
+        self.blank_nodes().next().is_none()
+    }
+
+    fn to_n_triples(&self, label: impl Fn(&str) -> String) -> String {
+        let term = |term: &Term| match term {
+            Term::Blank(name) => format!("_:{}", label(name)),
+            Term::Ground(value) => value.clone(),
+        };
+        format!(
+            "{} {} {} .\n",
+            term(&self.subject),
+            self.predicate,
+            term(&self.object)
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl IdentifierIssuer {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            issued: Default::default(),
+            identifiers: Default::default(),
+        }
+    }
+
+    fn issue(&mut self, existing: &str) -> String {
+        if let Some(identifier) = self.identifiers.get(existing) {
+            identifier.clone()
+        } else {
+            let identifier = format!("{}{}", self.prefix, self.issued.len());
+            self.issued.push(existing.to_string());
+            let _ = self
+                .identifiers
+                .insert(existing.to_string(), identifier.clone());
+            identifier
+        }
+    }
+
+    fn get(&self, existing: &str) -> Option<&String> {
+        self.identifiers.get(existing)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Canonicalizer {
+    fn new(graph: &(impl Graph + ?Sized)) -> Result<Self> {
+        let mut seen: HashSet<String> = Default::default();
+        let mut ground: Vec<String> = Default::default();
+        let mut triples: Vec<Triple> = Default::default();
+        for statement in graph.statements() {
+            let triple = Triple::from_statement(statement)?;
+            let mut line = triple.to_n_triples(|label| label.to_string());
+            if triple.is_ground() {
+                // duplicates are removed once sorted.
+                let _ = line.pop();
+                ground.push(line);
+            } else if seen.insert(line) {
+                triples.push(triple);
+            }
+        }
+        let mut blank_triples: HashMap<String, Vec<usize>> = Default::default();
+        for (index, triple) in triples.iter().enumerate() {
+            for (label, _) in triple.blank_nodes() {
+                let indices = blank_triples.entry(label.clone()).or_default();
+                if !indices.contains(&index) {
+                    indices.push(index);
+                }
+            }
+        }
+        Ok(Self {
+            ground,
+            triples,
+            blank_triples,
+            canonical: IdentifierIssuer::new(CANONICAL_PREFIX),
+            n_degree_calls: Default::default(),
+        })
+    }
+
+    fn sorted_lines(mut self) -> Result<(Vec<String>, Vec<String>)> {
+        let canonical = self.canonicalize()?;
+        let mut blank: Vec<String> = self
+            .triples
+            .iter()
+            .map(|triple| canonical_line(triple, &canonical))
+            .collect();
+        blank.sort_unstable();
+        self.ground.sort_unstable();
+        self.ground.dedup();
+        Ok((self.ground, blank))
+    }
+
+    fn canonicalize(&mut self) -> Result<IdentifierIssuer> {
+        let mut hash_to_blanks: BTreeMap<String, Vec<String>> = Default::default();
+        let mut labels: Vec<&String> = self.blank_triples.keys().collect();
+        labels.sort();
+        for label in labels {
+            hash_to_blanks
+                .entry(self.hash_first_degree(label))
+                .or_default()
+                .push(label.clone());
+        }
+
+        let mut shared: Vec<Vec<String>> = Default::default();
+        for (_, labels) in hash_to_blanks {
+            if labels.len() == 1 {
+                let _ = self.canonical.issue(&labels[0]);
+            } else {
+                shared.push(labels);
+            }
+        }
+
+        for labels in shared {
+            let mut hash_paths: HashPaths = Default::default();
+            for label in labels {
+                if self.canonical.get(&label).is_some() {
+                    continue;
+                }
+                hash_paths.push(self.hash_n_degree_from(&label)?);
+            }
+            self.issue_hash_paths(hash_paths);
+        }
+
+        Ok(self.canonical.clone())
+    }
+
+    fn hash_n_degree_from(&self, label: &str) -> Result<(String, IdentifierIssuer)> {
+        let mut issuer = IdentifierIssuer::new("b");
+        let _ = issuer.issue(label);
+        self.hash_n_degree(label, issuer)
+    }
+
+    fn issue_hash_paths(&mut self, mut hash_paths: HashPaths) {
+        hash_paths.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+        for (_, issuer) in hash_paths {
+            for existing in &issuer.issued {
+                let _ = self.canonical.issue(existing);
+            }
+        }
+    }
+
+    fn hash_first_degree(&self, reference: &str) -> String {
+        let mut n_triples: Vec<String> = self.blank_triples[reference]
+            .iter()
+            .map(|index| {
+                self.triples[*index]
+                    .to_n_triples(|label| if label == reference { "a" } else { "z" }.to_string())
+            })
+            .collect();
+        n_triples.sort();
+        sha256(&n_triples.concat())
+    }
+
+    fn hash_related(
+        &self,
+        related: &str,
+        triple: &Triple,
+        issuer: &IdentifierIssuer,
+        position: &str,
+    ) -> String {
+        let identifier = match self.canonical.get(related).or_else(|| issuer.get(related)) {
+            Some(identifier) => format!("_:{}", identifier),
+            None => self.hash_first_degree(related),
+        };
+        sha256(&format!("{}{}{}", position, triple.predicate, identifier))
+    }
+
+    fn hash_n_degree(
+        &self,
+        identifier: &str,
+        mut issuer: IdentifierIssuer,
+    ) -> Result<(String, IdentifierIssuer)> {
+        if self.n_degree_calls.fetch_add(1, Ordering::Relaxed) >= MAX_N_DEGREE_CALLS {
+            return Err(ErrorKind::InvalidState.into());
+        }
+
+        let mut hash_to_related: BTreeMap<String, Vec<String>> = Default::default();
+        for index in &self.blank_triples[identifier] {
+            let triple = &self.triples[*index];
+            for (related, position) in triple.blank_nodes() {
+                if related != identifier {
+                    hash_to_related
+                        .entry(self.hash_related(related, triple, &issuer, position))
+                        .or_default()
+                        .push(related.clone());
+                }
+            }
+        }
+
+        let mut data_to_hash = String::new();
+        for (related_hash, blank_nodes) in hash_to_related {
+            data_to_hash.push_str(&related_hash);
+            let mut chosen_path = String::new();
+            let mut chosen_issuer: Option<IdentifierIssuer> = None;
+            'permutations: for permutation in
+                permutations(&blank_nodes.iter().collect::<Vec<&String>>())
+            {
+                let mut issuer_copy = issuer.clone();
+                let mut path = String::new();
+                let mut recursion_list: Vec<&String> = Default::default();
+                for related in permutation {
+                    if let Some(canonical) = self.canonical.get(related) {
+                        path.push_str(&format!("_:{}", canonical));
+                    } else {
+                        if issuer_copy.get(related).is_none() {
+                            recursion_list.push(related);
+                        }
+                        path.push_str(&format!("_:{}", issuer_copy.issue(related)));
+                    }
+                    if is_longer_path(&path, &chosen_path) {
+                        continue 'permutations;
+                    }
+                }
+                for related in recursion_list {
+                    let (hash, result_issuer) = self.hash_n_degree(related, issuer_copy.clone())?;
+                    issuer_copy = result_issuer;
+                    path.push_str(&format!("_:{}<{}>", issuer_copy.issue(related), hash));
+                    if is_longer_path(&path, &chosen_path) {
+                        continue 'permutations;
+                    }
+                }
+                if chosen_issuer.is_none() || path < chosen_path {
+                    chosen_path = path;
+                    chosen_issuer = Some(issuer_copy);
+                }
+            }
+            data_to_hash.push_str(&chosen_path);
+            if let Some(chosen_issuer) = chosen_issuer {
+                issuer = chosen_issuer;
+            }
+        }
+
+        Ok((sha256(&data_to_hash), issuer))
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl Canonicalizer {
+    fn sorted_lines_parallel(mut self) -> Result<(Vec<String>, Vec<String>)> {
+        let canonical = self.canonicalize_parallel()?;
+        let mut blank: Vec<String> = self
+            .triples
+            .par_iter()
+            .map(|triple| canonical_line(triple, &canonical))
+            .collect();
+        blank.par_sort_unstable();
+        self.ground.par_sort_unstable();
+        self.ground.dedup();
+        Ok((self.ground, blank))
+    }
+
+    fn canonicalize_parallel(&mut self) -> Result<IdentifierIssuer> {
+        let mut labels: Vec<&String> = self.blank_triples.keys().collect();
+        labels.par_sort_unstable();
+        let first_degree: Vec<(String, String)> = labels
+            .into_par_iter()
+            .map(|label| (self.hash_first_degree(label), label.clone()))
+            .collect();
+        let mut hash_to_blanks: BTreeMap<String, Vec<String>> = Default::default();
+        for (hash, label) in first_degree {
+            hash_to_blanks.entry(hash).or_default().push(label);
+        }
+
+        let mut shared: Vec<Vec<String>> = Default::default();
+        for (_, labels) in hash_to_blanks {
+            if labels.len() == 1 {
+                let _ = self.canonical.issue(&labels[0]);
+            } else {
+                shared.push(labels);
+            }
+        }
+
+        // the hashes of a group only depend on the canonical labels issued in the components of
+        // its blank nodes, so a group may be hashed once all earlier groups sharing any of these
+        // components have been issued.
+        let components = self.components();
+        let group_components: Vec<Vec<usize>> = shared
+            .iter()
+            .map(|labels| {
+                let mut group: Vec<usize> = labels.iter().map(|label| components[label]).collect();
+                group.sort_unstable();
+                group.dedup();
+                group
+            })
+            .collect();
+        let mut hashed: Vec<Option<HashPaths>> = vec![None; shared.len()];
+        let mut next = 0;
+        while next < shared.len() {
+            let mut blocked: HashSet<usize> = Default::default();
+            let mut ready: Vec<usize> = Default::default();
+            for index in (next..shared.len()).take(PARALLEL_GROUP_WINDOW) {
+                let group = &group_components[index];
+                if hashed[index].is_none() && group.iter().all(|c| !blocked.contains(c)) {
+                    ready.push(index);
+                }
+                blocked.extend(group);
+            }
+            let results: Vec<HashPaths> = ready
+                .par_iter()
+                .map(|index| self.hash_group(&shared[*index]))
+                .collect::<Result<Vec<HashPaths>>>()?;
+            for (index, hash_paths) in ready.into_iter().zip(results) {
+                hashed[index] = Some(hash_paths);
+            }
+            while let Some(hash_paths) = hashed.get_mut(next).and_then(Option::take) {
+                self.issue_hash_paths(hash_paths);
+                next += 1;
+            }
+        }
+
+        Ok(self.canonical.clone())
+    }
+
+    fn hash_group(&self, labels: &[String]) -> Result<HashPaths> {
+        labels
+            .par_iter()
+            .filter(|label| self.canonical.get(label).is_none())
+            .map(|label| self.hash_n_degree_from(label))
+            .collect()
+    }
+
+    fn components(&self) -> HashMap<&String, usize> {
+        let labels: Vec<&String> = self.blank_triples.keys().collect();
+        let indices: HashMap<&String, usize> = labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| (*label, index))
+            .collect();
+        let mut parents: Vec<usize> = (0..labels.len()).collect();
+        fn root(parents: &mut [usize], mut index: usize) -> usize {
+            while parents[index] != index {
+                parents[index] = parents[parents[index]];
+                index = parents[index];
+            }
+            index
+        }
+        for triple in &self.triples {
+            if let (Term::Blank(subject), Term::Blank(object)) = (&triple.subject, &triple.object) {
+                let subject = root(&mut parents, indices[subject]);
+                let object = root(&mut parents, indices[object]);
+                parents[subject] = object;
+            }
+        }
+        labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| (*label, root(&mut parents, index)))
+            .collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn canonical_line(triple: &Triple, canonical: &IdentifierIssuer) -> String {
+    let mut line = triple.to_n_triples(|label| canonical.identifiers[label].clone());
+    let _ = line.pop();
+    line
+}
+
+fn merge_sorted<'a>(lhs: &'a [String], rhs: &'a [String]) -> impl Iterator<Item = &'a String> {
+    let mut lhs = lhs.iter().peekable();
+    let mut rhs = rhs.iter().peekable();
+    std::iter::from_fn(move || match (lhs.peek(), rhs.peek()) {
+        (Some(left), Some(right)) if left > right => rhs.next(),
+        (Some(_), _) => lhs.next(),
+        (None, _) => rhs.next(),
+    })
+}
+
+fn hash_lines<'a>(lines: impl Iterator<Item = &'a String>) -> String {
+    let mut hasher = Sha256::new();
+    for line in lines {
+        hasher.update(line.as_bytes());
+        hasher.update(b"\n");
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+fn subject_term(subject: &SubjectNodeRef) -> Result<Term> {
+    if let Some(label) = subject.as_blank() {
+        Ok(Term::Blank(label.clone()))
+    } else if let Some(iri) = subject.as_iri() {
+        Ok(Term::Ground(format!("<{}>", iri)))
+    } else {
+        Err(ErrorKind::RdfStarNotSupported("RDFC-1.0".to_string()).into())
+    }
+}
+
+fn object_term(object: &ObjectNodeRef) -> Result<Term> {
+    if let Some(label) = object.as_blank() {
+        Ok(Term::Blank(label.clone()))
+    } else if let Some(iri) = object.as_iri() {
+        Ok(Term::Ground(format!("<{}>", iri)))
+    } else if let Some(literal) = object.as_literal() {
+        Ok(Term::Ground(literal_string(literal)))
+    } else {
+        Err(ErrorKind::RdfStarNotSupported("RDFC-1.0".to_string()).into())
+    }
+}
+
+fn literal_string(literal: &LiteralRef) -> String {
+    match (literal.data_type(), literal.language()) {
+        (Some(data_type), _) if data_type.as_iri() == DataType::String.as_iri() => {
+            format!("\"{}\"", literal.lexical_form())
+        }
+        (None, None) => format!("\"{}\"", literal.lexical_form()),
+        (Some(data_type), _) => format!("\"{}\"^^<{}>", literal.lexical_form(), data_type.as_iri()),
+        (None, Some(language)) => format!("\"{}\"@{}", literal.lexical_form(), language),
+    }
+}
+
+fn is_longer_path(path: &str, chosen_path: &str) -> bool {
+    !chosen_path.is_empty() && path.len() >= chosen_path.len() && path > chosen_path
+}
+
+fn permutations<T: Clone>(values: &[T]) -> Vec<Vec<T>> {
+    if values.len() <= 1 {
+        return vec![values.to_vec()];
+    }
+    let mut result: Vec<Vec<T>> = Default::default();
+    for index in 0..values.len() {
+        let mut rest = values.to_vec();
+        let first = rest.remove(index);
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, first.clone());
+            result.push(permutation);
+        }
+    }
+    result
+}
+
+fn sha256(value: &str) -> String {
+    format!("{:x}", Sha256::digest(value.as_bytes()))
+}
@@ -0,0 +1,281 @@
+/*!
+Enforcement of single-value semantics for functional and inverse-functional predicates when
+inserting statements into a graph, preventing the accumulation of conflicting values for
+properties such as `foaf:age`.
+
+A functional predicate may have at most one object for any subject, and an inverse-functional
+predicate at most one subject for any object. The set of predicates may be configured directly,
+or derived from an ontology that declares properties to be of type `owl:FunctionalProperty` or
+`owl:InverseFunctionalProperty`. When an insert would introduce a conflicting value the
+`Enforcement` mode determines whether it is rejected, replaces the existing statements, or is
+inserted anyway with the conflict reported back to the caller.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::cardinality::{CardinalityConstraints, Enforcement};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let age = |age: i64| {
+    factory
+        .statement(alice.clone(), foaf::age().clone(), factory.literal_object(literal_factory().long(age)))
+        .unwrap()
+};
+
+let mut constraints = CardinalityConstraints::new(Enforcement::Replace);
+let _ = constraints.functional(foaf::age().clone());
+
+let graph = graph_factory().graph();
+let mut graph = graph.borrow_mut();
+assert!(constraints.insert(&mut *graph, age(33)).unwrap().is_none());
+assert!(constraints.insert(&mut *graph, age(34)).unwrap().is_some());
+assert_eq!(graph.len(), 1);
+
+constraints.set_enforcement(Enforcement::Reject);
+assert!(constraints.insert(&mut *graph, age(35)).is_err());
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::graph::Graph;
+use crate::model::statement::{StatementList, StatementRef};
+use rdftk_iri::IRIRef;
+use rdftk_names::{owl, rdf};
+use std::collections::HashSet;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Determines what happens when an insert would add a conflicting value for a constrained
+/// predicate.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Enforcement {
+    /// The statement is not inserted, and an error is returned.
+    Reject,
+    /// The conflicting statements are removed from the graph, and the statement is inserted.
+    Replace,
+    /// The statement is inserted alongside the conflicting statements, and the conflict reported.
+    Warn,
+}
+
+///
+/// The set of functional and inverse-functional predicates to enforce on insert.
+///
+#[derive(Clone, Debug)]
+pub struct CardinalityConstraints {
+    functional: HashSet<IRIRef>,
+    inverse_functional: HashSet<IRIRef>,
+    enforcement: Enforcement,
+}
+
+///
+/// Describes the existing statements that conflict with a statement being inserted.
+///
+#[derive(Clone, Debug)]
+pub struct Conflict {
+    statement: StatementRef,
+    existing: StatementList,
+    inverse: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for CardinalityConstraints {
+    fn default() -> Self {
+        Self::new(Enforcement::Reject)
+    }
+}
+
+impl CardinalityConstraints {
+    ///
+    /// Construct a new, empty, set of constraints with the provided enforcement mode.
+    ///
+    pub fn new(enforcement: Enforcement) -> Self {
+        Self {
+            functional: Default::default(),
+            inverse_functional: Default::default(),
+            enforcement,
+        }
+    }
+
+    ///
+    /// Construct a new set of constraints from the properties declared as `owl:FunctionalProperty`
+    /// or `owl:InverseFunctionalProperty` in the `ontology` graph.
+    ///
+    pub fn from_ontology(ontology: &dyn Graph, enforcement: Enforcement) -> Self {
+        let mut constraints = Self::new(enforcement);
+        for statement in ontology.statements() {
+            if let (Some(property), Some(class)) =
+                (statement.subject().as_iri(), statement.object().as_iri())
+            {
+                if statement.predicate() == rdf::a_type() {
+                    if class == owl::functional_property() {
+                        let _ = constraints.functional(property.clone());
+                    } else if class == owl::inverse_functional_property() {
+                        let _ = constraints.inverse_functional(property.clone());
+                    }
+                }
+            }
+        }
+        constraints
+    }
+
+    ///
+    /// Declare `predicate` to be functional, having at most one object for any subject.
+    ///
+    pub fn functional(&mut self, predicate: IRIRef) -> &mut Self {
+        let _ = self.functional.insert(predicate);
+        self
+    }
+
+    ///
+    /// Declare `predicate` to be inverse-functional, having at most one subject for any object.
+    ///
+    pub fn inverse_functional(&mut self, predicate: IRIRef) -> &mut Self {
+        let _ = self.inverse_functional.insert(predicate);
+        self
+    }
+
+    ///
+    /// Returns `true` if `predicate` has been declared functional.
+    ///
+    pub fn is_functional(&self, predicate: &IRIRef) -> bool {
+        self.functional.contains(predicate)
+    }
+
+    ///
+    /// Returns `true` if `predicate` has been declared inverse-functional.
+    ///
+    pub fn is_inverse_functional(&self, predicate: &IRIRef) -> bool {
+        self.inverse_functional.contains(predicate)
+    }
+
+    ///
+    /// Returns `true` if no predicates have been constrained.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.functional.is_empty() && self.inverse_functional.is_empty()
+    }
+
+    ///
+    /// Return the current enforcement mode.
+    ///
+    pub fn enforcement(&self) -> Enforcement {
+        self.enforcement
+    }
+
+    ///
+    /// Set the enforcement mode used by subsequent inserts.
+    ///
+    pub fn set_enforcement(&mut self, enforcement: Enforcement) {
+        self.enforcement = enforcement;
+    }
+
+    ///
+    /// Return the statements in `graph` that conflict with `statement`, if any. A statement
+    /// already in the graph never conflicts with itself.
+    ///
+    pub fn conflicts(&self, graph: &dyn Graph, statement: &StatementRef) -> Option<Conflict> {
+        let predicate = statement.predicate();
+        let conflict = |existing: StatementList, inverse: bool| {
+            let existing: StatementList = existing
+                .into_iter()
+                .filter(|existing| existing != statement)
+                .collect();
+            if existing.is_empty() {
+                None
+            } else {
+                Some(Conflict {
+                    statement: statement.clone(),
+                    existing,
+                    inverse,
+                })
+            }
+        };
+        let functional = if self.is_functional(predicate) {
+            conflict(
+                graph.statements_matching(Some(statement.subject()), Some(predicate), None),
+                false,
+            )
+        } else {
+            None
+        };
+        if functional.is_none() && self.is_inverse_functional(predicate) {
+            conflict(
+                graph.statements_matching(None, Some(predicate), Some(statement.object())),
+                true,
+            )
+        } else {
+            functional
+        }
+    }
+
+    ///
+    /// Insert `statement` into `graph`, applying the enforcement mode to any conflict. Returns
+    /// the conflict, if any, that was resolved by replacement or reported as a warning; a
+    /// rejected statement results in an error.
+    ///
+    pub fn insert(
+        &self,
+        graph: &mut dyn Graph,
+        statement: StatementRef,
+    ) -> Result<Option<Conflict>> {
+        let conflict = self.conflicts(graph, &statement);
+        if let Some(conflict) = &conflict {
+            match self.enforcement {
+                Enforcement::Reject => {
+                    return Err(
+                        ErrorKind::CardinalityViolation(statement.predicate().to_string()).into(),
+                    )
+                }
+                Enforcement::Replace => {
+                    for existing in conflict.existing() {
+                        graph.remove(existing);
+                    }
+                }
+                Enforcement::Warn => {}
+            }
+        }
+        graph.insert(statement);
+        Ok(conflict)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Conflict {
+    ///
+    /// The statement being inserted.
+    ///
+    pub fn statement(&self) -> &StatementRef {
+        &self.statement
+    }
+
+    ///
+    /// The statements already in the graph that conflict with `statement`.
+    ///
+    pub fn existing(&self) -> &StatementList {
+        &self.existing
+    }
+
+    ///
+    /// Returns `true` if the conflict is on an inverse-functional predicate, that is the
+    /// existing statements share the object rather than the subject.
+    ///
+    pub fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+}
@@ -0,0 +1,381 @@
+/*!
+Batched edits to a graph. A `ChangeSet` is an ordered list of statements to add and to remove
+which may be checked by any number of `ChangeValidator`s *before* it is applied, applied to a graph
+as a single unit, and serialized as an [RDF Patch](https://afs.github.io/rdf-patch/) for storage or
+transmission.
+
+A validator is shown both the graph as it is and a copy with the changes applied, so that it may
+report only those problems introduced by the changes. Validators are implemented for closures,
+and for `cardinality::CardinalityConstraints`; the SHACL validator in the `rdftk_ontology` crate
+also implements this trait. A change set may also be computed as the difference between two
+versions of a graph with `ChangeSet::between`.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::change_set::ChangeSet;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let name = |name: &str| {
+    factory
+        .statement(alice.clone(), foaf::name().clone(), factory.literal_object(literal_factory().literal(name)))
+        .unwrap()
+};
+
+let graph = graph_factory().graph_from(&[name("Alise")], None);
+
+let mut changes = ChangeSet::default();
+let _ = changes.remove(name("Alise")).add(name("Alice"));
+
+let no_empty_names = |_: &dyn Graph, after: &dyn Graph, _: &ChangeSet| {
+    after
+        .statements()
+        .filter(|st| st.object().as_literal().map(|l| l.lexical_form().is_empty()).unwrap_or(false))
+        .map(|st| format!("empty name for {}", st.subject()))
+        .collect::<Vec<String>>()
+};
+changes.apply_validated(&mut *graph.borrow_mut(), &[&no_empty_names]).unwrap();
+assert!(graph.borrow().contains(&name("Alice")));
+
+assert_eq!(
+    changes.to_rdf_patch(),
+    r#"TX .
+D <http://example.org/alice> <http://xmlns.com/foaf/0.1/name> "Alise" .
+A <http://example.org/alice> <http://xmlns.com/foaf/0.1/name> "Alice" .
+TC .
+"#
+);
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::graph::cardinality::CardinalityConstraints;
+use crate::model::graph::operations::difference;
+use crate::model::graph::order::StatementOrder;
+use crate::model::graph::{Graph, GraphRef};
+use crate::model::statement::StatementRef;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A single change to a graph.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Change {
+    /// Add the statement to the graph.
+    Add(StatementRef),
+    /// Remove the statement from the graph, if present.
+    Remove(StatementRef),
+}
+
+///
+/// An ordered list of changes to be applied to a graph as a single unit.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChangeSet {
+    changes: Vec<Change>,
+}
+
+///
+/// A check performed on a change set before it is applied; the result is a list of messages, one
+/// for each problem found, so that an empty list allows the changes to be applied.
+///
+pub trait ChangeValidator {
+    ///
+    /// Validate `changes`, where `before` is the graph the changes will be applied to and
+    /// `after` is a copy of that graph with the changes applied.
+    ///
+    fn validate(&self, before: &dyn Graph, after: &dyn Graph, changes: &ChangeSet) -> Vec<String>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Change {
+    ///
+    /// Return the statement this change adds or removes.
+    ///
+    pub fn statement(&self) -> &StatementRef {
+        match self {
+            Self::Add(statement) | Self::Remove(statement) => statement,
+        }
+    }
+
+    ///
+    /// Returns `true` if this change adds a statement.
+    ///
+    pub fn is_add(&self) -> bool {
+        matches!(self, Self::Add(_))
+    }
+
+    ///
+    /// Return the change that reverses this one.
+    ///
+    pub fn inverse(&self) -> Self {
+        match self {
+            Self::Add(statement) => Self::Remove(statement.clone()),
+            Self::Remove(statement) => Self::Add(statement.clone()),
+        }
+    }
+
+    fn apply(&self, graph: &mut dyn Graph) {
+        match self {
+            Self::Add(statement) => graph.insert(statement.clone()),
+            Self::Remove(statement) => graph.remove(statement),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<Vec<Change>> for ChangeSet {
+    fn from(changes: Vec<Change>) -> Self {
+        Self { changes }
+    }
+}
+
+impl ChangeSet {
+    ///
+    /// Construct a change set that, when applied to `before`, results in the same statements as
+    /// `after`; all removals precede all additions. Blank nodes are compared by label, see the
+    /// `operations` module.
+    ///
+    pub fn between(before: &dyn Graph, after: &dyn Graph) -> Self {
+        let removed = difference(before, after);
+        let added = difference(after, before);
+        let removed = removed.borrow();
+        let added = added.borrow();
+        Self {
+            changes: removed
+                .statements()
+                .cloned()
+                .map(Change::Remove)
+                .chain(added.statements().cloned().map(Change::Add))
+                .collect(),
+        }
+    }
+
+    ///
+    /// Append a change that adds `statement`.
+    ///
+    pub fn add(&mut self, statement: StatementRef) -> &mut Self {
+        self.changes.push(Change::Add(statement));
+        self
+    }
+
+    ///
+    /// Append a change that removes `statement`.
+    ///
+    pub fn remove(&mut self, statement: StatementRef) -> &mut Self {
+        self.changes.push(Change::Remove(statement));
+        self
+    }
+
+    ///
+    /// Append all the changes in `other`.
+    ///
+    pub fn extend(&mut self, other: &ChangeSet) -> &mut Self {
+        self.changes.extend(other.changes.iter().cloned());
+        self
+    }
+
+    ///
+    /// Returns `true` if there are no changes.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    ///
+    /// Return the number of changes.
+    ///
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    ///
+    /// Return all changes, in the order they will be applied.
+    ///
+    pub fn changes(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter()
+    }
+
+    ///
+    /// Return the statements added by this change set.
+    ///
+    pub fn additions(&self) -> impl Iterator<Item = &StatementRef> {
+        self.changes
+            .iter()
+            .filter(|change| change.is_add())
+            .map(Change::statement)
+    }
+
+    ///
+    /// Return the statements removed by this change set.
+    ///
+    pub fn removals(&self) -> impl Iterator<Item = &StatementRef> {
+        self.changes
+            .iter()
+            .filter(|change| !change.is_add())
+            .map(Change::statement)
+    }
+
+    ///
+    /// Sort each run of consecutive additions, or consecutive removals, by `order`, so that a
+    /// change set computed by `between` is rendered in a stable order. Changes are not moved
+    /// across a change of the other kind, so the result of applying the change set is unchanged.
+    /// Blank nodes are compared by their own labels.
+    ///
+    pub fn sort(&mut self, order: &dyn StatementOrder) -> &mut Self {
+        let labels = Default::default();
+        let mut start = 0;
+        while start < self.changes.len() {
+            let is_add = self.changes[start].is_add();
+            let end = self.changes[start..]
+                .iter()
+                .position(|change| change.is_add() != is_add)
+                .map(|length| start + length)
+                .unwrap_or(self.changes.len());
+            self.changes[start..end]
+                .sort_by(|lhs, rhs| order.compare(lhs.statement(), rhs.statement(), &labels));
+            start = end;
+        }
+        self
+    }
+
+    ///
+    /// Return a change set that reverses this one, the inverse of each change in reverse order.
+    /// Note that removing a statement that was not present, or adding one that was, is not
+    /// reversed exactly.
+    ///
+    pub fn inverse(&self) -> Self {
+        Self {
+            changes: self.changes.iter().rev().map(Change::inverse).collect(),
+        }
+    }
+
+    ///
+    /// Return a new graph, created by the factory of `graph`, containing the statements of
+    /// `graph` with these changes applied; `graph` itself is unchanged.
+    ///
+    pub fn preview(&self, graph: &dyn Graph) -> GraphRef {
+        let result = graph
+            .factory()
+            .graph_from_iter(&mut graph.statements().cloned());
+        {
+            let mut result = result.borrow_mut();
+            result.set_prefix_mappings(graph.prefix_mappings());
+            self.apply(&mut *result);
+        }
+        result
+    }
+
+    ///
+    /// Run each of the `validators` over these changes as they would be applied to `graph`,
+    /// returning all the problems found.
+    ///
+    pub fn validate(&self, graph: &dyn Graph, validators: &[&dyn ChangeValidator]) -> Vec<String> {
+        if validators.is_empty() {
+            return Default::default();
+        }
+        let after = self.preview(graph);
+        let after = after.borrow();
+        validators
+            .iter()
+            .flat_map(|validator| validator.validate(graph, &*after, self))
+            .collect()
+    }
+
+    ///
+    /// Apply all changes, in order, to `graph`.
+    ///
+    pub fn apply(&self, graph: &mut dyn Graph) {
+        for change in &self.changes {
+            change.apply(graph);
+        }
+    }
+
+    ///
+    /// Validate, and if no validator reports a problem, apply all changes to `graph`. If any
+    /// problem is reported the error `ChangeSetRejected` is returned and `graph` is unchanged.
+    ///
+    pub fn apply_validated(
+        &self,
+        graph: &mut dyn Graph,
+        validators: &[&dyn ChangeValidator],
+    ) -> Result<()> {
+        let problems = self.validate(graph, validators);
+        if problems.is_empty() {
+            self.apply(graph);
+            Ok(())
+        } else {
+            Err(ErrorKind::ChangeSetRejected(problems).into())
+        }
+    }
+
+    ///
+    /// Return these changes as a single RDF Patch transaction, one `A` (add) or `D` (delete) row
+    /// for each change.
+    ///
+    pub fn to_rdf_patch(&self) -> String {
+        let mut patch = String::from("TX .\n");
+        for change in &self.changes {
+            patch.push_str(&format!(
+                "{} {} .\n",
+                if change.is_add() { "A" } else { "D" },
+                change.statement()
+            ));
+        }
+        patch.push_str("TC .\n");
+        patch
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F> ChangeValidator for F
+where
+    F: Fn(&dyn Graph, &dyn Graph, &ChangeSet) -> Vec<String>,
+{
+    fn validate(&self, before: &dyn Graph, after: &dyn Graph, changes: &ChangeSet) -> Vec<String> {
+        self(before, after, changes)
+    }
+}
+
+impl ChangeValidator for CardinalityConstraints {
+    ///
+    /// Report each added statement that conflicts with other statements once all changes have
+    /// been applied.
+    ///
+    fn validate(&self, _: &dyn Graph, after: &dyn Graph, changes: &ChangeSet) -> Vec<String> {
+        changes
+            .additions()
+            .filter_map(|statement| self.conflicts(after, statement))
+            .map(|conflict| {
+                format!(
+                    "{} conflicts with {} statement(s) for the {}functional predicate <{}>",
+                    conflict.statement(),
+                    conflict.existing().len(),
+                    if conflict.is_inverse() {
+                        "inverse-"
+                    } else {
+                        ""
+                    },
+                    conflict.statement().predicate()
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,385 @@
+/*!
+A decorator graph, `CollectingGraph`, that gives each statement inserted into it to a set of
+statistics collectors, so that statistics are collected as a graph is read or bulk-loaded rather
+than in a second pass over the data. The decorator is otherwise transparent, each method is
+delegated unchanged to the graph it wraps, and statements removed from the graph are not reported
+to the collectors.
+
+Readers create graphs using a graph factory; `CollectingGraphFactory` creates a new, empty,
+decorated graph for each request, with all such graphs sharing the same collectors. As the
+collectors are shared they should be created in references of their own type, and clones of those
+references coerced to a `StatisticsCollectorRef`, so that the statistics remain available once
+the graph has been read. See the `stats` module for the collectors provided.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::collecting::CollectingGraphFactory;
+use rdftk_core::model::graph::stats::{PredicateHistogram, StatisticsCollectorRef};
+use rdftk_core::simple::graph::SimpleGraph;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_iri::{IRIRef, IRI};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::str::FromStr;
+
+let histogram = Rc::new(RefCell::new(PredicateHistogram::default()));
+let factory = CollectingGraphFactory::<SimpleGraph>::new(vec![
+    histogram.clone() as StatisticsCollectorRef
+])
+.into_ref();
+
+let statements = statement_factory();
+let predicate = IRIRef::from(IRI::from_str("http://example.org/p").unwrap());
+let statement = statements
+    .statement(
+        statements.named_subject(IRIRef::from(IRI::from_str("http://example.org/s").unwrap())),
+        predicate.clone(),
+        statements.literal_object(literal_factory().literal("o")),
+    )
+    .unwrap();
+let graph = factory.graph_from(&[statement], None);
+
+assert_eq!(graph.borrow().len(), 1);
+assert_eq!(histogram.borrow().count(&predicate), 1);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::features::Featured;
+use crate::model::graph::mapping::PrefixMappingFactoryRef;
+use crate::model::graph::stats::StatisticsCollectorRef;
+use crate::model::graph::{
+    Graph, GraphFactory, GraphFactoryRef, GraphRef, PrefixMappingRef, Snapshot, StatementIter,
+};
+use crate::model::literal::LiteralFactoryRef;
+use crate::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
+};
+use crate::model::Provided;
+use rdftk_iri::IRIRef;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A graph decorator giving each statement inserted to a set of statistics collectors.
+///
+pub struct CollectingGraph<G: Graph> {
+    inner: G,
+    collectors: Vec<StatisticsCollectorRef>,
+}
+
+///
+/// A graph factory creating new, empty, graphs of type `G` decorated by `CollectingGraph`, all
+/// sharing the same collectors.
+///
+pub struct CollectingGraphFactory<G: Graph + Default> {
+    collectors: Vec<StatisticsCollectorRef>,
+    graph: PhantomData<G>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<G: Graph> Debug for CollectingGraph<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectingGraph")
+            .field("inner", &self.inner)
+            .field("collectors", &self.collectors.len())
+            .finish()
+    }
+}
+
+impl<G: Graph> Featured for CollectingGraph<G> {
+    fn supports_feature(&self, feature: &IRIRef) -> bool {
+        self.inner.supports_feature(feature)
+    }
+}
+
+impl<G: Graph> Graph for CollectingGraph<G> {
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn contains_subject(&self, subject: &SubjectNodeRef) -> bool {
+        self.inner.contains_subject(subject)
+    }
+
+    fn contains_individual(&self, subject: &IRIRef) -> bool {
+        self.inner.contains_individual(subject)
+    }
+
+    fn contains(&self, statement: &StatementRef) -> bool {
+        self.inner.contains(statement)
+    }
+
+    fn contains_all(
+        &self,
+        subject: &SubjectNodeRef,
+        predicate: &IRIRef,
+        object: &ObjectNodeRef,
+    ) -> bool {
+        self.inner.contains_all(subject, predicate, object)
+    }
+
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        self.inner.matches(subject, predicate, object)
+    }
+
+    fn statements_matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> StatementList {
+        self.inner.statements_matching(subject, predicate, object)
+    }
+
+    fn statements(&self) -> StatementIter<'_> {
+        self.inner.statements()
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        self.inner.snapshot()
+    }
+
+    fn subjects(&self) -> HashSet<&SubjectNodeRef> {
+        self.inner.subjects()
+    }
+
+    fn predicates(&self) -> HashSet<&IRIRef> {
+        self.inner.predicates()
+    }
+
+    fn predicates_for(&self, subject: &SubjectNodeRef) -> HashSet<&IRIRef> {
+        self.inner.predicates_for(subject)
+    }
+
+    fn objects(&self) -> HashSet<&ObjectNodeRef> {
+        self.inner.objects()
+    }
+
+    fn objects_for(&self, subject: &SubjectNodeRef, predicate: &IRIRef) -> HashSet<&ObjectNodeRef> {
+        self.inner.objects_for(subject, predicate)
+    }
+
+    fn distinct_subjects(&self) -> Box<dyn Iterator<Item = (&SubjectNodeRef, usize)> + '_> {
+        self.inner.distinct_subjects()
+    }
+
+    fn distinct_predicates(&self) -> Box<dyn Iterator<Item = (&IRIRef, usize)> + '_> {
+        self.inner.distinct_predicates()
+    }
+
+    fn distinct_objects(&self) -> Box<dyn Iterator<Item = (&ObjectNodeRef, usize)> + '_> {
+        self.inner.distinct_objects()
+    }
+
+    fn count_by_predicate(&self) -> HashMap<&IRIRef, usize> {
+        self.inner.count_by_predicate()
+    }
+
+    fn skolemize(&self, base: &IRIRef) -> Result<GraphRef> {
+        self.inner.skolemize(base)
+    }
+
+    fn canonicalize(&self) -> Result<GraphRef> {
+        self.inner.canonicalize()
+    }
+
+    fn lean(&self) -> GraphRef {
+        self.inner.lean()
+    }
+
+    fn is_lean(&self) -> bool {
+        self.inner.is_lean()
+    }
+
+    fn union(&self, other: &dyn Graph) -> Result<GraphRef> {
+        self.inner.union(other)
+    }
+
+    fn intersection(&self, other: &dyn Graph) -> GraphRef {
+        self.inner.intersection(other)
+    }
+
+    fn difference(&self, other: &dyn Graph) -> GraphRef {
+        self.inner.difference(other)
+    }
+
+    fn prefix_mappings(&self) -> PrefixMappingRef {
+        self.inner.prefix_mappings()
+    }
+
+    fn set_prefix_mappings(&mut self, mappings: PrefixMappingRef) {
+        self.inner.set_prefix_mappings(mappings)
+    }
+
+    fn factory(&self) -> GraphFactoryRef {
+        self.inner.factory()
+    }
+
+    fn statement_factory(&self) -> StatementFactoryRef {
+        self.inner.statement_factory()
+    }
+
+    fn literal_factory(&self) -> LiteralFactoryRef {
+        self.inner.literal_factory()
+    }
+
+    fn statements_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut StatementRef> + 'a> {
+        self.inner.statements_mut()
+    }
+
+    fn insert(&mut self, statement: StatementRef) {
+        for collector in &self.collectors {
+            collector.borrow_mut().observe(&statement);
+        }
+        self.inner.insert(statement)
+    }
+
+    fn merge(&mut self, other: &Self)
+    where
+        Self: Sized,
+    {
+        other
+            .inner
+            .statements()
+            .for_each(|st| self.insert(st.clone()))
+    }
+
+    fn dedup(&mut self) -> StatementList {
+        self.inner.dedup()
+    }
+
+    fn remove(&mut self, statement: &StatementRef) {
+        self.inner.remove(statement)
+    }
+
+    fn remove_all_for(&mut self, subject: &SubjectNodeRef) -> StatementList {
+        self.inner.remove_all_for(subject)
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<G: Graph> CollectingGraph<G> {
+    ///
+    /// Construct a new decorator giving each statement inserted into `inner` to `collectors`;
+    /// statements already in `inner` are not given to the collectors.
+    ///
+    pub fn new(inner: G, collectors: Vec<StatisticsCollectorRef>) -> Self {
+        Self { inner, collectors }
+    }
+
+    ///
+    /// Return the graph being decorated; statements inserted directly into it are not collected.
+    ///
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    ///
+    /// Return the graph being decorated, discarding the collectors.
+    ///
+    pub fn into_inner(self) -> G {
+        self.inner
+    }
+
+    ///
+    /// Return the collectors given each statement inserted.
+    ///
+    pub fn collectors(&self) -> &Vec<StatisticsCollectorRef> {
+        &self.collectors
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<G: Graph + Default> Debug for CollectingGraphFactory<G> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectingGraphFactory")
+            .field("collectors", &self.collectors.len())
+            .finish()
+    }
+}
+
+impl<G: Graph + Default> Provided for CollectingGraphFactory<G> {
+    fn provider_id(&self) -> &'static str {
+        G::default().factory().provider_id()
+    }
+}
+
+impl<G: Graph + Default + 'static> GraphFactory for CollectingGraphFactory<G> {
+    fn graph(&self) -> GraphRef {
+        Rc::new(RefCell::new(CollectingGraph::new(
+            G::default(),
+            self.collectors.clone(),
+        )))
+    }
+
+    fn mapping_factory(&self) -> PrefixMappingFactoryRef {
+        G::default().factory().mapping_factory()
+    }
+
+    fn with_mappings(&self, prefix_mappings: PrefixMappingRef) -> GraphRef {
+        let mut inner = G::default();
+        inner.set_prefix_mappings(prefix_mappings);
+        Rc::new(RefCell::new(CollectingGraph::new(
+            inner,
+            self.collectors.clone(),
+        )))
+    }
+}
+
+impl<G: Graph + Default + 'static> CollectingGraphFactory<G> {
+    ///
+    /// Construct a new factory whose graphs give each statement inserted to `collectors`.
+    ///
+    pub fn new(collectors: Vec<StatisticsCollectorRef>) -> Self {
+        Self {
+            collectors,
+            graph: PhantomData,
+        }
+    }
+
+    ///
+    /// Return the collectors given each statement inserted into a graph from this factory.
+    ///
+    pub fn collectors(&self) -> &Vec<StatisticsCollectorRef> {
+        &self.collectors
+    }
+
+    ///
+    /// Return this factory as a reference, for use with readers and other functions that create
+    /// graphs.
+    ///
+    // The collectors are shared with the caller, and so the factory is neither `Send` nor `Sync`;
+    // it is only ever used with the graphs it creates which are also neither.
+    #[allow(clippy::arc_with_non_send_sync)]
+    pub fn into_ref(self) -> GraphFactoryRef {
+        Arc::new(self)
+    }
+}
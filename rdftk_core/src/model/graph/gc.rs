@@ -0,0 +1,255 @@
+/*!
+Garbage collection of blank nodes; removes the statements describing blank nodes that can no
+longer be reached from the rest of the data, such as the anonymous structures left behind when
+the statement referring to them is removed during an editing session.
+
+Collection is a mark and sweep: every statement whose subject is an IRI, or an RDF-star
+statement, is live, as is every statement describing a blank node that is the object of a live
+statement; all other statements, those describing blank nodes not reachable in this way
+including cycles of blank nodes referring only to each other, are garbage.
+
+Note that `collect_garbage` will also remove the statements of a blank node that has never been
+the object of any statement, such as an anonymous resource written as `[ a ex:Thing ]` at the top
+level of a Turtle document. Where such resources are expected `collect_orphans` only considers
+the blank nodes that were referred to by statements just removed from the graph.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::gc::collect_orphans;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let knows = factory
+    .statement(alice, foaf::knows().clone(), factory.blank_object_named("bob").unwrap())
+    .unwrap();
+let graph = graph_factory().graph_from(
+    &[
+        knows.clone(),
+        factory
+            .statement(
+                factory.blank_subject_named("bob").unwrap(),
+                foaf::name().clone(),
+                factory.literal_object(literal_factory().literal("Bob")),
+            )
+            .unwrap(),
+    ],
+    None,
+);
+
+let mut graph = graph.borrow_mut();
+graph.remove(&knows);
+let collected = collect_orphans(&mut *graph, &[knows]);
+assert_eq!(collected.len(), 1);
+assert!(collected.blank_nodes().contains("bob"));
+assert!(graph.is_empty());
+```
+
+*/
+
+use crate::model::data_set::DataSet;
+use crate::model::graph::Graph;
+use crate::model::statement::{ObjectNodeRef, StatementList, StatementRef, SubjectNodeRef};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The statements removed by a garbage collection, and the blank nodes they described.
+///
+#[derive(Clone, Debug, Default)]
+pub struct GarbageReport {
+    statements: StatementList,
+    blank_nodes: BTreeSet<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Remove, from `graph`, the statements of all blank nodes that are not reachable from a
+/// statement with a non-blank subject.
+///
+pub fn collect_garbage(graph: &mut dyn Graph) -> GarbageReport {
+    let live = live_blank_nodes(graph.statements(), |_| false);
+    sweep(graph, &live, |_| true)
+}
+
+///
+/// Remove, from `graph`, the statements of blank nodes that were referred to by the `removed`
+/// statements, directly or through other blank nodes, and that are no longer reachable from the
+/// rest of the graph. Blank nodes not referred to by `removed` are never collected.
+///
+pub fn collect_orphans(graph: &mut dyn Graph, removed: &[StatementRef]) -> GarbageReport {
+    let mut candidates: HashSet<String> = Default::default();
+    let mut queue: Vec<String> = Default::default();
+    for statement in removed {
+        object_blank_nodes(statement.object(), &mut queue);
+    }
+    let described = blank_subjects(graph.statements());
+    while let Some(label) = queue.pop() {
+        if candidates.insert(label.clone()) {
+            for statement in described.get(&label).into_iter().flatten() {
+                object_blank_nodes(statement.object(), &mut queue);
+            }
+        }
+    }
+    let live = live_blank_nodes(graph.statements(), |label| !candidates.contains(label));
+    sweep(graph, &live, |label| candidates.contains(label))
+}
+
+///
+/// Remove, from every graph in `data_set`, the statements of all blank nodes that are not
+/// reachable from a statement with a non-blank subject in any graph; a blank node shared between
+/// graphs is live if it is reachable in any of them.
+///
+pub fn collect_data_set_garbage(data_set: &dyn DataSet) -> GarbageReport {
+    let graphs: Vec<_> = data_set
+        .default_graph()
+        .into_iter()
+        .chain(data_set.graphs().map(|(_, graph)| graph))
+        .collect();
+    let statements: StatementList = graphs
+        .iter()
+        .flat_map(|graph| graph.borrow().statements().cloned().collect::<Vec<_>>())
+        .collect();
+    let live = live_blank_nodes(statements.iter(), |_| false);
+    let mut report = GarbageReport::default();
+    for graph in graphs {
+        let collected = sweep(&mut *graph.borrow_mut(), &live, |_| true);
+        report.statements.extend(collected.statements);
+        report.blank_nodes.extend(collected.blank_nodes);
+    }
+    report
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl GarbageReport {
+    ///
+    /// Returns `true` if no statements were removed.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    ///
+    /// Return the number of statements removed.
+    ///
+    pub fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    ///
+    /// Return the statements removed.
+    ///
+    pub fn statements(&self) -> &StatementList {
+        &self.statements
+    }
+
+    ///
+    /// Return the labels of the blank nodes whose statements were removed.
+    ///
+    pub fn blank_nodes(&self) -> &BTreeSet<String> {
+        &self.blank_nodes
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn blank_subjects<'a>(
+    statements: impl Iterator<Item = &'a StatementRef>,
+) -> HashMap<String, Vec<&'a StatementRef>> {
+    let mut described: HashMap<String, Vec<&'a StatementRef>> = Default::default();
+    for statement in statements {
+        if let Some(label) = statement.subject().as_blank() {
+            described.entry(label.clone()).or_default().push(statement);
+        }
+    }
+    described
+}
+
+fn live_blank_nodes<'a>(
+    statements: impl Iterator<Item = &'a StatementRef>,
+    is_root: impl Fn(&String) -> bool,
+) -> HashSet<String> {
+    let mut described: HashMap<String, Vec<&'a StatementRef>> = Default::default();
+    let mut queue: Vec<String> = Default::default();
+    for statement in statements {
+        match statement.subject().as_blank() {
+            Some(label) => {
+                if !is_root(label) {
+                    described.entry(label.clone()).or_default().push(statement);
+                    continue;
+                }
+                queue.push(label.clone());
+            }
+            None => subject_blank_nodes(statement.subject(), &mut queue),
+        }
+        object_blank_nodes(statement.object(), &mut queue);
+    }
+    let mut live: HashSet<String> = Default::default();
+    while let Some(label) = queue.pop() {
+        if live.insert(label.clone()) {
+            for statement in described.get(&label).into_iter().flatten() {
+                object_blank_nodes(statement.object(), &mut queue);
+            }
+        }
+    }
+    live
+}
+
+fn sweep(
+    graph: &mut dyn Graph,
+    live: &HashSet<String>,
+    collectable: impl Fn(&String) -> bool,
+) -> GarbageReport {
+    let mut report = GarbageReport::default();
+    for statement in graph.statements() {
+        if let Some(label) = statement.subject().as_blank() {
+            if !live.contains(label) && collectable(label) {
+                report.statements.push(statement.clone());
+                let _ = report.blank_nodes.insert(label.clone());
+            }
+        }
+    }
+    for statement in &report.statements {
+        graph.remove(statement);
+    }
+    report
+}
+
+fn subject_blank_nodes(subject: &SubjectNodeRef, labels: &mut Vec<String>) {
+    if let Some(label) = subject.as_blank() {
+        labels.push(label.clone());
+    } else if let Some(statement) = subject.as_statement() {
+        statement_blank_nodes(statement, labels);
+    }
+}
+
+fn object_blank_nodes(object: &ObjectNodeRef, labels: &mut Vec<String>) {
+    if let Some(label) = object.as_blank() {
+        labels.push(label.clone());
+    } else if let Some(statement) = object.as_statement() {
+        statement_blank_nodes(statement, labels);
+    }
+}
+
+fn statement_blank_nodes(statement: &StatementRef, labels: &mut Vec<String>) {
+    subject_blank_nodes(statement.subject(), labels);
+    object_blank_nodes(statement.object(), labels);
+}
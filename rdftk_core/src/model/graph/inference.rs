@@ -0,0 +1,675 @@
+/*!
+The forward-chaining inference engine shared by the reasoners in `graph::reasoner` and by the
+inference rules of other crates; rules are applied until no new statements are produced, or until
+an `InferenceBudget` is exhausted.
+
+Rules implement the `Rule` trait and are applied incrementally; each rule is called once for each
+statement as it becomes known, first for the statements of the graph and then for each statement
+inferred, so that a statement is never matched against the same rule twice. A rule that joins
+several statements must therefore consider the statement it is given in the place of each of them,
+finding the others through the indexes of the `RuleContext`. This also allows statements added to
+a graph whose closure has already been computed to be reasoned over alone, with
+`materialize_added`, rather than computing the closure again.
+
+Each inferred statement is recorded with an `Explanation`, the name of the rule that produced it
+and the statements it was inferred from, so that unexpected inferences can be traced back to the
+data. Premises may themselves have been inferred, in which case they also have an explanation;
+where a statement may be inferred more than once only the first explanation is recorded.
+
+When the budget is exhausted inference stops, and the returned `Inference` is marked as
+incomplete; when materializing, the statements inferred so far are still added to the graph.
+
+# Example
+
+```rust
+use rdftk_core::error::Result;
+use rdftk_core::model::graph::inference::{
+    materialize, BudgetLimit, InferenceBudget, Rule, RuleContext, RuleRef,
+};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use std::rc::Rc;
+use std::str::FromStr;
+
+let iri = |name: &str| IRIRef::from(IRI::from_str(&format!("http://example.org/{}", name)).unwrap());
+
+#[derive(Debug)]
+struct TransitivePartOf(IRIRef);
+
+impl Rule for TransitivePartOf {
+    fn name(&self) -> &str {
+        "transitive part"
+    }
+
+    fn apply(&self, statement: &StatementRef, context: &mut RuleContext<'_>) -> Result<()> {
+        if statement.predicate() == &self.0 {
+            let factory = context.statement_factory().clone();
+            // the statement as the first part of the chain,
+            if let Some(next) = factory.object_as_subject(statement.object().clone()) {
+                for following in context.values(&self.0, &next).to_vec() {
+                    context.derive(
+                        statement.subject().clone(),
+                        &self.0,
+                        following.object().clone(),
+                        &[statement, &following],
+                    )?;
+                }
+            }
+            // and as the second.
+            let previous = factory.subject_as_object(statement.subject().clone());
+            for preceding in context.referrers(&self.0, &previous).to_vec() {
+                context.derive(
+                    preceding.subject().clone(),
+                    &self.0,
+                    statement.object().clone(),
+                    &[&preceding, statement],
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+let factory = statement_factory();
+let part_of = |lhs: &str, rhs: &str| {
+    factory
+        .statement(factory.named_subject(iri(lhs)), iri("partOf"), factory.named_object(iri(rhs)))
+        .unwrap()
+};
+let graph = graph_factory().graph_from(
+    &[part_of("wheel", "axle"), part_of("axle", "chassis"), part_of("chassis", "car")],
+    None,
+);
+let rules: Vec<RuleRef> = vec![Rc::new(TransitivePartOf(iri("partOf")))];
+
+let inference = materialize(
+    &mut *graph.borrow_mut(),
+    &rules,
+    &InferenceBudget::default().with_max_statements(2),
+)
+.unwrap();
+assert!(!inference.is_complete());
+assert_eq!(inference.exhausted(), Some(BudgetLimit::Statements));
+assert_eq!(inference.len(), 2);
+assert_eq!(graph.borrow().len(), 5);
+
+let explanation = &inference.explanations()[0];
+assert_eq!(explanation.rule(), "transitive part");
+assert_eq!(explanation.premises().len(), 2);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::graph::{statement_matches, Graph};
+use crate::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
+};
+use rdftk_iri::IRIRef;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Limits on the cost of inference; by default inference is unbounded.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InferenceBudget {
+    max_statements: Option<usize>,
+    max_duration: Option<Duration>,
+}
+
+///
+/// The limit in an `InferenceBudget` that stopped inference.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BudgetLimit {
+    /// The maximum number of new statements was inferred.
+    Statements,
+    /// The maximum duration elapsed.
+    Duration,
+}
+
+///
+/// Why a statement was inferred; the rule, and the statements it was inferred from.
+///
+#[derive(Clone, Debug)]
+pub struct Explanation {
+    statement: StatementRef,
+    rule: String,
+    premises: StatementList,
+}
+
+///
+/// The statements inferred, in the order they were inferred, with their explanations.
+///
+#[derive(Clone, Debug, Default)]
+pub struct Inference {
+    explanations: Vec<Explanation>,
+    index: HashMap<StatementRef, usize>,
+    rounds: usize,
+    exhausted: Option<BudgetLimit>,
+    problems: Vec<String>,
+}
+
+///
+/// A rule applied by the inference engine.
+///
+pub trait Rule: Debug {
+    ///
+    /// Return the name of this rule, reported by each `Explanation` it produces.
+    ///
+    fn name(&self) -> &str;
+
+    ///
+    /// Infer any statements entailed by `statement`, together with the statements already known,
+    /// using `context` to find known statements and to record each inference. This is called
+    /// once for each statement as it becomes known; a statement that is already known is not
+    /// recorded again.
+    ///
+    fn apply(&self, statement: &StatementRef, context: &mut RuleContext<'_>) -> Result<()>;
+}
+
+///
+/// The type for a reference to a rule.
+///
+pub type RuleRef = Rc<dyn Rule>;
+
+///
+/// The statements known to the inference engine, provided to a `Rule` as it is applied, and the
+/// inferences recorded by the rule.
+///
+#[derive(Debug)]
+pub struct RuleContext<'a> {
+    known: &'a Known,
+    factory: &'a StatementFactoryRef,
+    rule: &'a str,
+    derived: &'a mut Vec<Explanation>,
+    problems: &'a mut Vec<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+type Index<K> = HashMap<K, Vec<StatementRef>>;
+
+#[derive(Debug, Default)]
+struct Known {
+    statements: HashSet<StatementRef>,
+    subjects: Index<SubjectNodeRef>,
+    predicates: Index<IRIRef>,
+    objects: Index<ObjectNodeRef>,
+    values: Index<(IRIRef, SubjectNodeRef)>,
+    referrers: Index<(IRIRef, ObjectNodeRef)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the statements entailed by `graph` under `rules` that are not already in it, without
+/// modifying the graph.
+///
+pub fn infer(graph: &dyn Graph, rules: &[RuleRef], budget: &InferenceBudget) -> Result<Inference> {
+    let mut known = Known::default();
+    let statements: StatementList = graph.statements().cloned().collect();
+    for statement in &statements {
+        let _ = known.insert(statement);
+    }
+    run(
+        &mut known,
+        statements,
+        rules,
+        budget,
+        &graph.statement_factory(),
+    )
+}
+
+///
+/// Insert, into `graph`, the statements entailed by it under `rules` that are not already in it,
+/// returning the statements inserted.
+///
+pub fn materialize(
+    graph: &mut dyn Graph,
+    rules: &[RuleRef],
+    budget: &InferenceBudget,
+) -> Result<Inference> {
+    let inference = infer(graph, rules, budget)?;
+    insert(graph, &inference);
+    Ok(inference)
+}
+
+///
+/// Insert `added` into `graph`, which already holds the statements entailed by it under `rules`,
+/// and then the statements entailed by the additions; only the statements added, and those
+/// inferred from them, are given to the rules. Returns the statements inferred, not including
+/// `added`.
+///
+pub fn materialize_added(
+    graph: &mut dyn Graph,
+    added: &[StatementRef],
+    rules: &[RuleRef],
+    budget: &InferenceBudget,
+) -> Result<Inference> {
+    let mut known = Known::default();
+    for statement in graph.statements() {
+        let _ = known.insert(statement);
+    }
+    let delta: StatementList = added
+        .iter()
+        .filter(|statement| known.insert(statement))
+        .cloned()
+        .collect();
+    for statement in &delta {
+        graph.insert(statement.clone());
+    }
+    let inference = run(&mut known, delta, rules, budget, &graph.statement_factory())?;
+    insert(graph, &inference);
+    Ok(inference)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl InferenceBudget {
+    ///
+    /// Stop inference once `max_statements` new statements have been inferred.
+    ///
+    pub fn with_max_statements(self, max_statements: usize) -> Self {
+        Self {
+            max_statements: Some(max_statements),
+            ..self
+        }
+    }
+
+    ///
+    /// Stop inference once `max_duration` has elapsed; this is checked before the rules are
+    /// applied to each statement and before each new statement is added.
+    ///
+    pub fn with_max_duration(self, max_duration: Duration) -> Self {
+        Self {
+            max_duration: Some(max_duration),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the maximum number of new statements, if any.
+    ///
+    pub fn max_statements(&self) -> Option<usize> {
+        self.max_statements
+    }
+
+    ///
+    /// Return the maximum duration, if any.
+    ///
+    pub fn max_duration(&self) -> Option<Duration> {
+        self.max_duration
+    }
+
+    fn expired(&self, started: Instant) -> Option<BudgetLimit> {
+        if matches!(self.max_duration, Some(max) if started.elapsed() >= max) {
+            Some(BudgetLimit::Duration)
+        } else {
+            None
+        }
+    }
+
+    fn exceeded(&self, inferred: usize, started: Instant) -> Option<BudgetLimit> {
+        if matches!(self.max_statements, Some(max) if inferred >= max) {
+            Some(BudgetLimit::Statements)
+        } else {
+            self.expired(started)
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <= rule {:?}", self.statement, self.rule)?;
+        for (i, premise) in self.premises.iter().enumerate() {
+            write!(f, "{} {}", if i == 0 { ":" } else { " AND" }, premise)?;
+        }
+        Ok(())
+    }
+}
+
+impl Explanation {
+    ///
+    /// Return the inferred statement.
+    ///
+    pub fn statement(&self) -> &StatementRef {
+        &self.statement
+    }
+
+    ///
+    /// Return the name of the rule that inferred the statement.
+    ///
+    pub fn rule(&self) -> &str {
+        &self.rule
+    }
+
+    ///
+    /// Return the statements the statement was inferred from, in the order defined by the rule.
+    ///
+    pub fn premises(&self) -> &StatementList {
+        &self.premises
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Inference {
+    ///
+    /// Returns `true` if inference ran until no new statements were produced.
+    ///
+    pub fn is_complete(&self) -> bool {
+        self.exhausted.is_none()
+    }
+
+    ///
+    /// Return the budget limit that stopped inference, if any.
+    ///
+    pub fn exhausted(&self) -> Option<BudgetLimit> {
+        self.exhausted
+    }
+
+    ///
+    /// Return the number of rounds of inference; the first applies the rules to the statements
+    /// of the graph, each following round to the statements inferred by the one before.
+    ///
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    ///
+    /// Returns `true` if no statements were inferred.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.explanations.is_empty()
+    }
+
+    ///
+    /// Return the number of statements inferred.
+    ///
+    pub fn len(&self) -> usize {
+        self.explanations.len()
+    }
+
+    ///
+    /// Return the inferred statements, in the order they were inferred.
+    ///
+    pub fn statements(&self) -> impl Iterator<Item = &StatementRef> {
+        self.explanations
+            .iter()
+            .map(|explanation| &explanation.statement)
+    }
+
+    ///
+    /// Return the explanation of every inferred statement, in the order they were inferred.
+    ///
+    pub fn explanations(&self) -> &[Explanation] {
+        &self.explanations
+    }
+
+    ///
+    /// Return the explanation for `statement`, or `None` if it was not inferred.
+    ///
+    pub fn explain(&self, statement: &StatementRef) -> Option<&Explanation> {
+        self.index
+            .get(statement)
+            .map(|index| &self.explanations[*index])
+    }
+
+    ///
+    /// Return any problems reported by rules, such as a match for which a rule could not
+    /// construct a valid statement.
+    ///
+    pub fn problems(&self) -> &[String] {
+        &self.problems
+    }
+
+    fn push(&mut self, explanation: Explanation) {
+        let _ = self
+            .index
+            .insert(explanation.statement.clone(), self.explanations.len());
+        self.explanations.push(explanation);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl RuleContext<'_> {
+    ///
+    /// Return the factory used to create inferred statements.
+    ///
+    pub fn statement_factory(&self) -> &StatementFactoryRef {
+        self.factory
+    }
+
+    ///
+    /// Return the known statements with the provided predicate and subject.
+    ///
+    pub fn values(&self, predicate: &IRIRef, subject: &SubjectNodeRef) -> &[StatementRef] {
+        lookup(&self.known.values, &(predicate.clone(), subject.clone()))
+    }
+
+    ///
+    /// Return the known statements with the provided predicate and object.
+    ///
+    pub fn referrers(&self, predicate: &IRIRef, object: &ObjectNodeRef) -> &[StatementRef] {
+        lookup(&self.known.referrers, &(predicate.clone(), object.clone()))
+    }
+
+    ///
+    /// Return the known statements with the provided predicate.
+    ///
+    pub fn with_predicate(&self, predicate: &IRIRef) -> &[StatementRef] {
+        lookup(&self.known.predicates, predicate)
+    }
+
+    ///
+    /// Return the known statements with the provided subject.
+    ///
+    pub fn with_subject(&self, subject: &SubjectNodeRef) -> &[StatementRef] {
+        lookup(&self.known.subjects, subject)
+    }
+
+    ///
+    /// Return the known statements with the provided object.
+    ///
+    pub fn with_object(&self, object: &ObjectNodeRef) -> &[StatementRef] {
+        lookup(&self.known.objects, object)
+    }
+
+    ///
+    /// Return the known statements that match the provided subject, predicate, and object; as
+    /// for `Graph::matches`, `None` matches any value.
+    ///
+    pub fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        let candidates = match (subject, predicate, object) {
+            (Some(subject), Some(predicate), _) => self.values(predicate, subject),
+            (_, Some(predicate), Some(object)) => self.referrers(predicate, object),
+            (Some(subject), _, _) => self.with_subject(subject),
+            (_, _, Some(object)) => self.with_object(object),
+            (_, Some(predicate), _) => self.with_predicate(predicate),
+            (None, None, None) => return self.known.statements.iter().collect(),
+        };
+        candidates
+            .iter()
+            .filter(|statement| statement_matches(statement, subject, predicate, object))
+            .collect()
+    }
+
+    ///
+    /// Returns `true` if `statement` is known.
+    ///
+    pub fn contains(&self, statement: &StatementRef) -> bool {
+        self.known.statements.contains(statement)
+    }
+
+    ///
+    /// Record the inference of the statement with the provided subject, predicate, and object,
+    /// from `premises`, by the rule being applied.
+    ///
+    pub fn derive(
+        &mut self,
+        subject: SubjectNodeRef,
+        predicate: &IRIRef,
+        object: ObjectNodeRef,
+        premises: &[&StatementRef],
+    ) -> Result<()> {
+        let rule = self.rule;
+        self.derive_as(rule, subject, predicate, object, premises)
+    }
+
+    ///
+    /// Record the inference of `statement`, from `premises`, by the rule being applied.
+    ///
+    pub fn derive_statement(&mut self, statement: StatementRef, premises: StatementList) {
+        self.derived.push(Explanation {
+            statement,
+            rule: self.rule.to_string(),
+            premises,
+        });
+    }
+
+    ///
+    /// Report a problem found by the rule being applied, such as a match for which it could not
+    /// construct a valid statement.
+    ///
+    pub fn problem(&mut self, problem: String) {
+        if !self.problems.contains(&problem) {
+            self.problems.push(problem);
+        }
+    }
+
+    pub(crate) fn derive_as(
+        &mut self,
+        rule: &str,
+        subject: SubjectNodeRef,
+        predicate: &IRIRef,
+        object: ObjectNodeRef,
+        premises: &[&StatementRef],
+    ) -> Result<()> {
+        self.derived.push(Explanation {
+            statement: self.factory.statement(subject, predicate.clone(), object)?,
+            rule: rule.to_string(),
+            premises: premises.iter().map(|premise| (*premise).clone()).collect(),
+        });
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Known {
+    fn insert(&mut self, statement: &StatementRef) -> bool {
+        if !self.statements.insert(statement.clone()) {
+            return false;
+        }
+        let subject = statement.subject();
+        let predicate = statement.predicate();
+        let object = statement.object();
+        add(&mut self.subjects, subject.clone(), statement);
+        add(&mut self.predicates, predicate.clone(), statement);
+        add(&mut self.objects, object.clone(), statement);
+        add(
+            &mut self.values,
+            (predicate.clone(), subject.clone()),
+            statement,
+        );
+        add(
+            &mut self.referrers,
+            (predicate.clone(), object.clone()),
+            statement,
+        );
+        true
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn add<K: Eq + Hash>(index: &mut Index<K>, key: K, statement: &StatementRef) {
+    index.entry(key).or_default().push(statement.clone());
+}
+
+fn lookup<'a, K: Eq + Hash>(index: &'a Index<K>, key: &K) -> &'a [StatementRef] {
+    index
+        .get(key)
+        .map(|statements| statements.as_slice())
+        .unwrap_or_default()
+}
+
+fn run(
+    known: &mut Known,
+    mut delta: StatementList,
+    rules: &[RuleRef],
+    budget: &InferenceBudget,
+    factory: &StatementFactoryRef,
+) -> Result<Inference> {
+    let started = Instant::now();
+    let mut inference = Inference::default();
+    let mut derived: Vec<Explanation> = Default::default();
+    while !delta.is_empty() {
+        inference.rounds += 1;
+        let mut next: StatementList = Default::default();
+        for statement in &delta {
+            if let Some(limit) = budget.expired(started) {
+                inference.exhausted = Some(limit);
+                return Ok(inference);
+            }
+            for rule in rules {
+                let mut context = RuleContext {
+                    known,
+                    factory,
+                    rule: rule.name(),
+                    derived: &mut derived,
+                    problems: &mut inference.problems,
+                };
+                rule.apply(statement, &mut context)?;
+                for explanation in derived.drain(..) {
+                    if known.statements.contains(&explanation.statement) {
+                        continue;
+                    }
+                    if let Some(limit) = budget.exceeded(inference.len(), started) {
+                        inference.exhausted = Some(limit);
+                        return Ok(inference);
+                    }
+                    let _ = known.insert(&explanation.statement);
+                    next.push(explanation.statement.clone());
+                    inference.push(explanation);
+                }
+            }
+        }
+        delta = next;
+    }
+    Ok(inference)
+}
+
+fn insert(graph: &mut dyn Graph, inference: &Inference) {
+    for statement in inference.statements() {
+        graph.insert(statement.clone());
+    }
+}
@@ -0,0 +1,351 @@
+/*!
+Iterator types returned by, and standard iterator trait implementations for, the `Graph` trait.
+
+The `StatementIter` returned by `Graph::statements` borrows the graph, and so the graph may not be
+modified during iteration; for a `GraphRef` any attempt to do so panics as the graph is already
+borrowed. A `Snapshot`, returned by `Graph::snapshot`, holds the statements of the graph at the
+time it was taken and does not borrow the graph; the graph may be freely modified while the
+snapshot is iterated, and these modifications are not seen by the snapshot. The `snapshot`
+function takes, and releases, the borrow of a `GraphRef` for the caller.
+
+Graph implementations may share storage between a graph and its snapshots, copying only when the
+graph is first modified after a snapshot is taken, so that taking a snapshot is cheap; the
+default implementation copies the list of statements.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::StatementRef;
+
+fn last_statement(graph: &GraphRef) -> Option<StatementRef> {
+    let graph = graph.borrow();
+    let mut statements = graph.statements();
+    println!("graph has {} statements", statements.len());
+    statements.next_back().cloned()
+}
+```
+
+Removing statements from a graph while iterating over them.
+
+```rust
+use rdftk_core::model::graph::iter::snapshot;
+use rdftk_core::model::graph::GraphRef;
+
+fn remove_nested(graph: &GraphRef) {
+    for statement in snapshot(graph) {
+        if statement.is_nested() {
+            graph.borrow_mut().remove(&statement);
+        }
+    }
+}
+```
+*/
+
+use crate::model::graph::{Graph, GraphRef};
+use crate::model::statement::{StatementList, StatementRef};
+use std::collections::VecDeque;
+use std::fmt::{Debug, Formatter};
+use std::iter::{Fuse, FusedIterator};
+use std::rc::Rc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An iterator over references to the statements in a graph. This is both double-ended and
+/// exact-sized so that callers may reverse the iteration or pre-allocate storage.
+///
+pub struct StatementIter<'a> {
+    inner: Box<dyn InnerIter<'a> + 'a>,
+}
+
+///
+/// The statements of a graph at the time the snapshot was taken, unaffected by any later changes
+/// to the graph.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Snapshot {
+    statements: Rc<StatementList>,
+}
+
+///
+/// An iterator over the statements in a snapshot, returning owned references so that the graph
+/// may be modified during iteration.
+///
+#[derive(Clone, Debug)]
+pub struct SnapshotIter {
+    statements: Rc<StatementList>,
+    front: usize,
+    back: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return a snapshot of the statements in `graph`, the graph is only borrowed for the duration of
+/// this call and so may be modified while the snapshot is iterated.
+///
+pub fn snapshot(graph: &GraphRef) -> Snapshot {
+    graph.borrow().snapshot()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+trait InnerIter<'a>: DoubleEndedIterator<Item = &'a StatementRef> + ExactSizeIterator {}
+
+///
+/// Adapts a forward-only iterator, with a known length, to `InnerIter`; the remaining statements
+/// are only collected if the iterator is used from the back.
+///
+struct ForwardIter<'a, I: Iterator<Item = &'a StatementRef>> {
+    inner: Fuse<I>,
+    remaining: usize,
+    back: Option<VecDeque<&'a StatementRef>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<'a, T> InnerIter<'a> for T where
+    T: DoubleEndedIterator<Item = &'a StatementRef> + ExactSizeIterator
+{
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for StatementIter<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatementIter")
+            .field("remaining", &self.inner.len())
+            .finish()
+    }
+}
+
+impl<'a> Iterator for StatementIter<'a> {
+    type Item = &'a StatementRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for StatementIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl ExactSizeIterator for StatementIter<'_> {}
+
+impl FusedIterator for StatementIter<'_> {}
+
+impl<'a> StatementIter<'a> {
+    ///
+    /// Construct a new statement iterator wrapping the provided iterator, usually one over a
+    /// graph implementation's own storage.
+    ///
+    pub fn new<I>(iter: I) -> Self
+    where
+        I: DoubleEndedIterator<Item = &'a StatementRef> + ExactSizeIterator + 'a,
+    {
+        Self {
+            inner: Box::new(iter.fuse()),
+        }
+    }
+
+    ///
+    /// Construct a new statement iterator wrapping an iterator that may only be used forward,
+    /// such as one reading statements from a store, and which returns `len` statements. The
+    /// statements are read as they are returned, unless the iterator is used from the back, when
+    /// the remaining statements are first collected.
+    ///
+    pub fn forward<I>(iter: I, len: usize) -> Self
+    where
+        I: Iterator<Item = &'a StatementRef> + 'a,
+    {
+        Self {
+            inner: Box::new(ForwardIter {
+                inner: iter.fuse(),
+                remaining: len,
+                back: None,
+            }),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a, I: Iterator<Item = &'a StatementRef>> Iterator for ForwardIter<'a, I> {
+    type Item = &'a StatementRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = match &mut self.back {
+            None => self.inner.next(),
+            Some(back) => back.pop_front(),
+        };
+        match next {
+            None => self.remaining = 0,
+            Some(_) => self.remaining = self.remaining.saturating_sub(1),
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a StatementRef>> DoubleEndedIterator for ForwardIter<'a, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let inner = &mut self.inner;
+        let next = self.back.get_or_insert_with(|| inner.collect()).pop_back();
+        match next {
+            None => self.remaining = 0,
+            Some(_) => self.remaining = self.remaining.saturating_sub(1),
+        }
+        next
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a StatementRef>> ExactSizeIterator for ForwardIter<'a, I> {}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<StatementList> for Snapshot {
+    fn from(statements: StatementList) -> Self {
+        Self::new(Rc::new(statements))
+    }
+}
+
+impl IntoIterator for Snapshot {
+    type Item = StatementRef;
+    type IntoIter = SnapshotIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        SnapshotIter {
+            front: 0,
+            back: self.statements.len(),
+            statements: self.statements,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Snapshot {
+    type Item = &'a StatementRef;
+    type IntoIter = std::slice::Iter<'a, StatementRef>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.statements.iter()
+    }
+}
+
+impl Snapshot {
+    ///
+    /// Construct a new snapshot sharing `statements`, a graph implementation that shares its
+    /// storage in this way must copy the list, for example with `Rc::make_mut`, before modifying it.
+    ///
+    pub fn new(statements: Rc<StatementList>) -> Self {
+        Self { statements }
+    }
+
+    ///
+    /// Return an iterator over references to the statements in this snapshot.
+    ///
+    pub fn iter(&self) -> std::slice::Iter<'_, StatementRef> {
+        self.statements.iter()
+    }
+
+    ///
+    /// Returns `true` if there were no statements in the graph, else `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    ///
+    /// Return the number of statements in the graph when the snapshot was taken.
+    ///
+    pub fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    ///
+    /// Returns `true` if the graph contained `statement` when the snapshot was taken.
+    ///
+    pub fn contains(&self, statement: &StatementRef) -> bool {
+        self.statements.contains(statement)
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` share the same storage, and so are known to be equal
+    /// without comparing statements.
+    ///
+    pub fn shares_storage(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.statements, &other.statements)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Iterator for SnapshotIter {
+    type Item = StatementRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.front += 1;
+            Some(self.statements[self.front - 1].clone())
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for SnapshotIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.statements[self.back].clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl ExactSizeIterator for SnapshotIter {}
+
+impl FusedIterator for SnapshotIter {}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> IntoIterator for &'a dyn Graph {
+    type Item = &'a StatementRef;
+    type IntoIter = StatementIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.statements()
+    }
+}
+
+impl Extend<StatementRef> for dyn Graph {
+    fn extend<T: IntoIterator<Item = StatementRef>>(&mut self, iter: T) {
+        let mut iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        let _ = self.extend_from(&mut iter);
+    }
+}
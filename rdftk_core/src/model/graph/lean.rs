@@ -0,0 +1,327 @@
+/*!
+Computes the *lean* form of a graph, for details see
+[Simple Entailment](https://www.w3.org/TR/rdf11-mt/#simple-entailment-properties). A graph is lean
+if it has no instance, replacing blank nodes with other terms, that is a proper subgraph of
+itself. For example, the graph
+
+```turtle
+<http://example.org/alice> foaf:knows <http://example.org/bob> .
+<http://example.org/alice> foaf:knows _:someone .
+```
+
+is not lean, as mapping `_:someone` to `<http://example.org/bob>` results in its first statement
+alone; the second statement is redundant as it is simply entailed by the first. Repeated loads of
+the same data, where each load creates new blank nodes, also produce graphs that are not lean.
+
+The lean graph is computed by repeatedly finding, for each set of statements connected by blank
+nodes, a mapping of its blank nodes into the graph that leaves at least one of them unused, and
+replacing the set with its image under that mapping. This is, in the worst case, exponential in
+the number of blank nodes connected together, but is fast for the small anonymous structures
+typical of most data. Blank nodes within RDF-star statements are left unchanged, as are any
+statements that use them.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let graph = graph_factory().graph_from(
+    &[
+        factory
+            .statement(
+                alice.clone(),
+                foaf::knows().clone(),
+                factory.named_object(IRIRef::from(IRI::from_str("http://example.org/bob").unwrap())),
+            )
+            .unwrap(),
+        factory
+            .statement(alice, foaf::knows().clone(), factory.blank_object_named("someone").unwrap())
+            .unwrap(),
+    ],
+    None,
+);
+assert!(!graph.borrow().is_lean());
+
+let lean = graph.borrow().lean();
+assert_eq!(lean.borrow().len(), 1);
+assert!(lean.borrow().is_lean());
+```
+
+*/
+
+use crate::model::graph::{Graph, GraphRef};
+use crate::model::statement::{ObjectNodeRef, StatementRef};
+use rdftk_iri::IRIRef;
+use std::collections::{HashMap, HashSet};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A statement position is either a blank node that may be mapped, or a fixed term.
+///
+#[derive(Clone, Debug)]
+enum Term {
+    Variable(String),
+    Fixed(ObjectNodeRef),
+}
+
+#[derive(Debug)]
+struct Pattern {
+    subject: Term,
+    predicate: IRIRef,
+    object: Term,
+}
+
+#[derive(Debug)]
+struct Component {
+    blanks: HashSet<String>,
+    statements: Vec<usize>,
+}
+
+struct Search<'a> {
+    statements: &'a [StatementRef],
+    terms: Vec<(ObjectNodeRef, ObjectNodeRef)>,
+    by_predicate: HashMap<&'a IRIRef, Vec<usize>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return a new graph, with the same prefix mappings, that is the lean form of `graph`; that is
+/// `graph` without any statements that are simply entailed by its other statements.
+///
+pub fn lean(graph: &(impl Graph + ?Sized)) -> GraphRef {
+    let mut statements: Vec<StatementRef> = graph.statements().cloned().collect();
+    while let Some(redundant) = redundant_statements(graph, &statements) {
+        let mut index = 0;
+        statements.retain(|_| {
+            index += 1;
+            !redundant.contains(&(index - 1))
+        });
+    }
+    graph
+        .factory()
+        .graph_from(&statements, Some(graph.prefix_mappings()))
+}
+
+///
+/// Returns `true` if `graph` is lean, that is it has no statements that are simply entailed by
+/// its other statements.
+///
+pub fn is_lean(graph: &(impl Graph + ?Sized)) -> bool {
+    let statements: Vec<StatementRef> = graph.statements().cloned().collect();
+    redundant_statements(graph, &statements).is_none()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Search<'_> {
+    ///
+    /// Find a mapping of the blank nodes in `component` such that the image of each of its
+    /// statements is in the graph, and at least one of its blank nodes is not in the image.
+    /// Returns the indices of the statements in the image.
+    ///
+    fn retraction(&self, patterns: &[Pattern], component: &Component) -> Option<HashSet<usize>> {
+        let mut mapping: HashMap<String, ObjectNodeRef> = Default::default();
+        let mut image: Vec<usize> = Default::default();
+        if self.extend(patterns, component, &mut mapping, &mut image) {
+            Some(image.into_iter().collect())
+        } else {
+            None
+        }
+    }
+
+    fn extend(
+        &self,
+        patterns: &[Pattern],
+        component: &Component,
+        mapping: &mut HashMap<String, ObjectNodeRef>,
+        image: &mut Vec<usize>,
+    ) -> bool {
+        let pattern = match patterns.get(image.len()) {
+            None => {
+                return component.blanks.iter().any(|blank| {
+                    !mapping
+                        .values()
+                        .any(|value| value.as_blank() == Some(blank))
+                })
+            }
+            Some(pattern) => pattern,
+        };
+        for candidate in self
+            .by_predicate
+            .get(&pattern.predicate)
+            .into_iter()
+            .flatten()
+        {
+            let (subject, object) = &self.terms[*candidate];
+            let mut bound: Vec<String> = Default::default();
+            if bind(&pattern.subject, subject, mapping, &mut bound)
+                && bind(&pattern.object, object, mapping, &mut bound)
+            {
+                image.push(*candidate);
+                if self.extend(patterns, component, mapping, image) {
+                    return true;
+                }
+                let _ = image.pop();
+            }
+            for variable in bound {
+                let _ = mapping.remove(&variable);
+            }
+        }
+        false
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the indices of the statements that may be removed from `statements` in one step, if any.
+///
+fn redundant_statements(
+    graph: &(impl Graph + ?Sized),
+    statements: &[StatementRef],
+) -> Option<HashSet<usize>> {
+    let factory = graph.statement_factory();
+    let mut search = Search {
+        statements,
+        terms: Default::default(),
+        by_predicate: Default::default(),
+    };
+    for (index, statement) in statements.iter().enumerate() {
+        search.terms.push((
+            factory.subject_as_object(statement.subject().clone()),
+            statement.object().clone(),
+        ));
+        search
+            .by_predicate
+            .entry(statement.predicate())
+            .or_default()
+            .push(index);
+    }
+
+    let mut quoted: HashSet<String> = Default::default();
+    for statement in statements {
+        if let Some(nested) = statement.subject().as_statement() {
+            quoted_blanks(nested, &mut quoted);
+        }
+        if let Some(nested) = statement.object().as_statement() {
+            quoted_blanks(nested, &mut quoted);
+        }
+    }
+
+    for component in components(&search, &quoted) {
+        let patterns: Vec<Pattern> = component
+            .statements
+            .iter()
+            .map(|index| {
+                let (subject, object) = &search.terms[*index];
+                Pattern {
+                    subject: term(subject, &component.blanks),
+                    predicate: search.statements[*index].predicate().clone(),
+                    object: term(object, &component.blanks),
+                }
+            })
+            .collect();
+        if let Some(image) = search.retraction(&patterns, &component) {
+            return Some(
+                component
+                    .statements
+                    .into_iter()
+                    .filter(|index| !image.contains(index))
+                    .collect(),
+            );
+        }
+    }
+    None
+}
+
+///
+/// Group the statements that have blank nodes, other than those in `quoted`, into components
+/// where each statement shares a blank node with another in the same component.
+///
+fn components(search: &Search<'_>, quoted: &HashSet<String>) -> Vec<Component> {
+    let mut components: Vec<Component> = Default::default();
+    for (index, (subject, object)) in search.terms.iter().enumerate() {
+        let blanks: HashSet<String> = [subject, object]
+            .iter()
+            .filter_map(|node| node.as_blank())
+            .filter(|blank| !quoted.contains(*blank))
+            .cloned()
+            .collect();
+        if blanks.is_empty() {
+            continue;
+        }
+        let mut merged = Component {
+            blanks,
+            statements: vec![index],
+        };
+        let mut remaining: Vec<Component> = Default::default();
+        for component in components {
+            if component.blanks.is_disjoint(&merged.blanks) {
+                remaining.push(component);
+            } else {
+                merged.blanks.extend(component.blanks);
+                merged.statements.extend(component.statements);
+            }
+        }
+        merged.statements.sort_unstable();
+        remaining.push(merged);
+        components = remaining;
+    }
+    components
+}
+
+fn quoted_blanks(statement: &StatementRef, blanks: &mut HashSet<String>) {
+    if let Some(blank) = statement.subject().as_blank() {
+        let _ = blanks.insert(blank.clone());
+    } else if let Some(nested) = statement.subject().as_statement() {
+        quoted_blanks(nested, blanks);
+    }
+    if let Some(blank) = statement.object().as_blank() {
+        let _ = blanks.insert(blank.clone());
+    } else if let Some(nested) = statement.object().as_statement() {
+        quoted_blanks(nested, blanks);
+    }
+}
+
+fn term(node: &ObjectNodeRef, blanks: &HashSet<String>) -> Term {
+    match node.as_blank() {
+        Some(blank) if blanks.contains(blank) => Term::Variable(blank.clone()),
+        _ => Term::Fixed(node.clone()),
+    }
+}
+
+fn bind(
+    term: &Term,
+    node: &ObjectNodeRef,
+    mapping: &mut HashMap<String, ObjectNodeRef>,
+    bound: &mut Vec<String>,
+) -> bool {
+    match term {
+        Term::Fixed(fixed) => fixed == node,
+        Term::Variable(variable) => match mapping.get(variable) {
+            Some(value) => value == node,
+            None => {
+                let _ = mapping.insert(variable.clone(), node.clone());
+                bound.push(variable.clone());
+                true
+            }
+        },
+    }
+}
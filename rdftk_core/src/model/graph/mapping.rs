@@ -3,12 +3,13 @@ A trait for the prefix mappings required by the `Graph` trait. Prefix mappings c
 graph to provide more readable serialization forms.
 */
 
-use crate::model::qname::QName;
-use rdftk_iri::IRIRef;
+use crate::model::qname::{is_xml_name_char, is_xml_name_start_char, QName};
+use rdftk_iri::{IRIRef, IRI};
 use rdftk_names::{rdf, rdfs, xsd};
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::rc::Rc;
+use std::str::FromStr;
 use std::sync::Arc;
 
 // ------------------------------------------------------------------------------------------------
@@ -154,12 +155,108 @@ pub trait PrefixMappings: Debug {
     fn expand(&self, qname: &QName) -> Option<IRIRef>;
 
     ///
-    /// Compress an IRI into a qname, if possible.
+    /// Compress an IRI into a qname, if possible; that is if it starts with a mapped namespace and
+    /// the remainder is a valid local name. The longest such namespace is used.
     ///
     fn compress(&self, iri: &IRIRef) -> Option<QName>;
+
+    ///
+    /// Split `iri` into a namespace and a local name, after the longest mapped namespace that it
+    /// starts with if the remainder is an XML `NCName`, otherwise as `split_iri` does.
+    ///
+    fn split(&self, iri: &IRIRef) -> (IRIRef, Option<String>) {
+        match mapped_namespace(self.mappings(), iri) {
+            Some((_, namespace, name)) => (namespace.clone(), Some(name)),
+            None => split_iri(iri),
+        }
+    }
+
+    ///
+    /// Expand a string, in the form `prefix:name`, `:name` or `name` for the default namespace, or
+    /// the CURIE form `[prefix:name]`, into an IRI, if it is a valid qname and its prefix is mapped.
+    ///
+    fn expand_qname(&self, qname: &str) -> Option<IRIRef> {
+        let qname = qname
+            .strip_prefix('[')
+            .and_then(|qname| qname.strip_suffix(']'))
+            .unwrap_or(qname);
+        let qname = match qname.strip_prefix(':') {
+            Some(name) => QName::new(name),
+            None => QName::from_str(qname),
+        };
+        qname.ok().and_then(|qname| self.expand(&qname))
+    }
 }
 
 ///
 /// The actual object storage type, reference counted for memory management.
 ///
 pub type PrefixMappingRef = Rc<RefCell<dyn PrefixMappings>>;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Split `iri` into a namespace and a local name, where the local name follows the last `/` or `#`
+/// in the path or fragment of the IRI and is an XML `NCName`. If there is no such local name, or the
+/// remaining namespace is not itself an IRI, the namespace is the whole of `iri` and there is no
+/// local name; an IRI is never split part way through a path segment.
+///
+pub fn split_iri(iri: &IRIRef) -> (IRIRef, Option<String>) {
+    let value = iri.to_string();
+    let tail = match iri.fragment() {
+        Some(fragment) => fragment.value().len(),
+        None if !iri.has_query() => iri.path().value().len(),
+        None => 0,
+    };
+    if let Some(index) = value.rfind(['/', '#']).map(|index| index + 1) {
+        let name = &value[index..];
+        if name.len() <= tail && is_ncname(name) {
+            if let Ok(namespace) = IRI::from_str(&value[..index]) {
+                if namespace.to_string() == value[..index] {
+                    return (IRIRef::from(namespace), Some(name.to_string()));
+                }
+            }
+        }
+    }
+    (iri.clone(), None)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the mapping with the longest namespace that `iri` starts with, where the remainder of the
+/// IRI is an XML `NCName`, and that remainder.
+///
+pub(crate) fn mapped_namespace<'a>(
+    mappings: impl Iterator<Item = (&'a String, &'a IRIRef)>,
+    iri: &IRIRef,
+) -> Option<(&'a String, &'a IRIRef, String)> {
+    let value = iri.to_string();
+    mappings
+        .filter_map(|(prefix, namespace)| {
+            let namespace_str = namespace.to_string();
+            value
+                .strip_prefix(&namespace_str)
+                .filter(|name| is_ncname(name))
+                .map(|name| (namespace_str.len(), (prefix, namespace, name.to_string())))
+        })
+        .max_by_key(|(len, _)| *len)
+        .map(|(_, mapping)| mapping)
+}
+
+fn is_ncname(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().map(is_ncname_start_char).unwrap_or_default() && chars.all(is_ncname_char)
+}
+
+fn is_ncname_start_char(c: char) -> bool {
+    c != ':' && is_xml_name_start_char(c)
+}
+
+fn is_ncname_char(c: char) -> bool {
+    c != ':' && is_xml_name_char(c)
+}
@@ -0,0 +1,422 @@
+/*!
+A merge of graphs from several sources that records which sources contributed each statement
+and reports, rather than silently accepting, statements that contradict each other according to
+an ontology.
+
+The merged graph contains every statement from every source, blank nodes are renamed as for
+`operations::union` so that blank nodes from different sources are never conflated. The
+`Merger` then checks the merged graph for:
+
+* more than one object for a subject and a functional predicate,
+* more than one subject for an object and an inverse-functional predicate,
+* a resource that is an instance of two disjoint classes, and
+* a subject and object related by two disjoint properties.
+
+Each `MergeConflict` lists the conflicting statements with the sources that asserted them, to
+drive manual reconciliation. A conflict may lie within a single source, although most will be
+between sources.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::merge::{ConflictKind, Merger};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let age = |age: i64| {
+    factory
+        .statement(alice.clone(), foaf::age().clone(), factory.literal_object(literal_factory().long(age)))
+        .unwrap()
+};
+let hr = graph_factory().graph_from(&[age(33)], None);
+let crm = graph_factory().graph_from(&[age(34)], None);
+
+let mut merger = Merger::default();
+let _ = merger.functional(foaf::age().clone());
+
+let merged = merger.merge(&[("hr", &*hr.borrow()), ("crm", &*crm.borrow())]).unwrap();
+assert_eq!(merged.graph().borrow().len(), 2);
+assert_eq!(merged.conflicts().len(), 1);
+
+let conflict = &merged.conflicts()[0];
+assert_eq!(conflict.kind(), &ConflictKind::Functional(foaf::age().clone()));
+assert_eq!(conflict.sources(), vec!["hr", "crm"]);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::graph::cardinality::{CardinalityConstraints, Enforcement};
+use crate::model::graph::operations::merge_statements;
+use crate::model::graph::{Graph, GraphRef};
+use crate::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use rdftk_names::{owl, rdf};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Merges graphs from named sources, reporting statements that contradict each other.
+///
+#[derive(Clone, Debug)]
+pub struct Merger {
+    cardinality: CardinalityConstraints,
+    disjoint_classes: Vec<(IRIRef, IRIRef)>,
+    disjoint_properties: Vec<(IRIRef, IRIRef)>,
+}
+
+///
+/// The reason that a set of statements conflict.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConflictKind {
+    /// More than one object for the same subject and this functional predicate.
+    Functional(IRIRef),
+    /// More than one subject for the same object and this inverse-functional predicate.
+    InverseFunctional(IRIRef),
+    /// The same resource is an instance of both of these disjoint classes.
+    DisjointClasses(IRIRef, IRIRef),
+    /// The same subject and object are related by both of these disjoint properties.
+    DisjointProperties(IRIRef, IRIRef),
+}
+
+///
+/// A set of statements, each with the sources that asserted it, that conflict.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct MergeConflict {
+    kind: ConflictKind,
+    statements: Vec<(StatementRef, Vec<String>)>,
+}
+
+///
+/// The result of a merge; the merged graph, the sources of each statement, and any conflicts.
+///
+#[derive(Clone, Debug)]
+pub struct Merged {
+    graph: GraphRef,
+    sources: HashMap<StatementRef, Vec<String>>,
+    conflicts: Vec<MergeConflict>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for Merger {
+    fn default() -> Self {
+        Self {
+            cardinality: CardinalityConstraints::new(Enforcement::Warn),
+            disjoint_classes: Default::default(),
+            disjoint_properties: Default::default(),
+        }
+    }
+}
+
+impl Merger {
+    ///
+    /// Construct a new merger from the functional and inverse-functional properties, and the
+    /// `owl:disjointWith` classes and `owl:propertyDisjointWith` properties, declared in the
+    /// `ontology` graph.
+    ///
+    pub fn from_ontology(ontology: &dyn Graph) -> Self {
+        let mut merger = Self {
+            cardinality: CardinalityConstraints::from_ontology(ontology, Enforcement::Warn),
+            ..Default::default()
+        };
+        for statement in ontology.statements() {
+            if let (Some(lhs), Some(rhs)) =
+                (statement.subject().as_iri(), statement.object().as_iri())
+            {
+                if statement.predicate() == owl::disjoint_with() {
+                    let _ = merger.disjoint_classes(lhs.clone(), rhs.clone());
+                } else if statement.predicate() == owl::property_disjoint_with() {
+                    let _ = merger.disjoint_properties(lhs.clone(), rhs.clone());
+                }
+            }
+        }
+        merger
+    }
+
+    ///
+    /// Declare `predicate` to be functional, having at most one object for any subject.
+    ///
+    pub fn functional(&mut self, predicate: IRIRef) -> &mut Self {
+        let _ = self.cardinality.functional(predicate);
+        self
+    }
+
+    ///
+    /// Declare `predicate` to be inverse-functional, having at most one subject for any object.
+    ///
+    pub fn inverse_functional(&mut self, predicate: IRIRef) -> &mut Self {
+        let _ = self.cardinality.inverse_functional(predicate);
+        self
+    }
+
+    ///
+    /// Declare that no resource may be an instance of both `lhs` and `rhs`.
+    ///
+    pub fn disjoint_classes(&mut self, lhs: IRIRef, rhs: IRIRef) -> &mut Self {
+        self.disjoint_classes.push((lhs, rhs));
+        self
+    }
+
+    ///
+    /// Declare that no subject and object may be related by both `lhs` and `rhs`.
+    ///
+    pub fn disjoint_properties(&mut self, lhs: IRIRef, rhs: IRIRef) -> &mut Self {
+        self.disjoint_properties.push((lhs, rhs));
+        self
+    }
+
+    ///
+    /// Merge the graphs of `sources`, each a name and a graph, into a new graph created by the
+    /// factory of the first graph. The result is an empty graph if there are no sources.
+    ///
+    pub fn merge(&self, sources: &[(&str, &dyn Graph)]) -> Result<Merged> {
+        let graph = match sources.first() {
+            None => crate::simple::graph::graph_factory().graph(),
+            Some((_, first)) => first.factory().graph(),
+        };
+        let graphs: Vec<&dyn Graph> = sources.iter().map(|(_, graph)| *graph).collect();
+        let factory = graph.borrow().statement_factory();
+        let mut attributed: HashMap<StatementRef, Vec<String>> = Default::default();
+        {
+            let mut graph = graph.borrow_mut();
+            for ((name, _), statements) in sources.iter().zip(merge_statements(&graphs, &factory)?)
+            {
+                for statement in statements {
+                    let names = attributed.entry(statement.clone()).or_default();
+                    if names.is_empty() {
+                        graph.insert(statement);
+                    }
+                    if !names.iter().any(|existing| existing == name) {
+                        names.push(name.to_string());
+                    }
+                }
+            }
+        }
+        let conflicts = self.conflicts(&*graph.borrow(), &attributed);
+        Ok(Merged {
+            graph,
+            sources: attributed,
+            conflicts,
+        })
+    }
+
+    fn conflicts(
+        &self,
+        graph: &dyn Graph,
+        sources: &HashMap<StatementRef, Vec<String>>,
+    ) -> Vec<MergeConflict> {
+        let mut conflicts: Vec<MergeConflict> = Default::default();
+        let mut add = |kind: ConflictKind, statements: Vec<StatementRef>| {
+            if statements.len() > 1 {
+                conflicts.push(MergeConflict {
+                    kind,
+                    statements: statements
+                        .into_iter()
+                        .map(|statement| {
+                            let names = sources.get(&statement).cloned().unwrap_or_default();
+                            (statement, names)
+                        })
+                        .collect(),
+                })
+            }
+        };
+
+        let mut by_subject: Vec<((SubjectNodeRef, IRIRef), Vec<StatementRef>)> = Default::default();
+        let mut by_object: Vec<((ObjectNodeRef, IRIRef), Vec<StatementRef>)> = Default::default();
+        for statement in graph.statements() {
+            let predicate = statement.predicate();
+            if self.cardinality.is_functional(predicate) {
+                group(
+                    &mut by_subject,
+                    (statement.subject().clone(), predicate.clone()),
+                    statement,
+                );
+            }
+            if self.cardinality.is_inverse_functional(predicate) {
+                group(
+                    &mut by_object,
+                    (statement.object().clone(), predicate.clone()),
+                    statement,
+                );
+            }
+        }
+        for ((_, predicate), statements) in by_subject {
+            add(ConflictKind::Functional(predicate), statements);
+        }
+        for ((_, predicate), statements) in by_object {
+            add(ConflictKind::InverseFunctional(predicate), statements);
+        }
+
+        for (lhs, rhs) in &self.disjoint_classes {
+            for statement in graph.statements_matching(
+                None,
+                Some(rdf::a_type()),
+                Some(&graph.statement_factory().named_object(lhs.clone())),
+            ) {
+                for other in graph.statements_matching(
+                    Some(statement.subject()),
+                    Some(rdf::a_type()),
+                    Some(&graph.statement_factory().named_object(rhs.clone())),
+                ) {
+                    add(
+                        ConflictKind::DisjointClasses(lhs.clone(), rhs.clone()),
+                        vec![statement.clone(), other],
+                    );
+                }
+            }
+        }
+
+        for (lhs, rhs) in &self.disjoint_properties {
+            for statement in graph.statements_matching(None, Some(lhs), None) {
+                for other in graph.statements_matching(
+                    Some(statement.subject()),
+                    Some(rhs),
+                    Some(statement.object()),
+                ) {
+                    add(
+                        ConflictKind::DisjointProperties(lhs.clone(), rhs.clone()),
+                        vec![statement.clone(), other],
+                    );
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ConflictKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Functional(predicate) => write!(f, "functional predicate <{}>", predicate),
+            Self::InverseFunctional(predicate) => {
+                write!(f, "inverse-functional predicate <{}>", predicate)
+            }
+            Self::DisjointClasses(lhs, rhs) => {
+                write!(f, "disjoint classes <{}> and <{}>", lhs, rhs)
+            }
+            Self::DisjointProperties(lhs, rhs) => {
+                write!(f, "disjoint properties <{}> and <{}>", lhs, rhs)
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for MergeConflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "conflict on {}:", self.kind)?;
+        for (statement, sources) in &self.statements {
+            writeln!(f, "  {} . # from {}", statement, sources.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl MergeConflict {
+    ///
+    /// Return the reason these statements conflict.
+    ///
+    pub fn kind(&self) -> &ConflictKind {
+        &self.kind
+    }
+
+    ///
+    /// Return the conflicting statements, each with the names of the sources that asserted it.
+    ///
+    pub fn statements(&self) -> impl Iterator<Item = (&StatementRef, &Vec<String>)> {
+        self.statements
+            .iter()
+            .map(|(statement, sources)| (statement, sources))
+    }
+
+    ///
+    /// Return the names of all sources involved in this conflict, in merge order.
+    ///
+    pub fn sources(&self) -> Vec<&String> {
+        let mut sources: Vec<&String> = Default::default();
+        for name in self.statements.iter().flat_map(|(_, sources)| sources) {
+            if !sources.contains(&name) {
+                sources.push(name);
+            }
+        }
+        sources
+    }
+
+    ///
+    /// Returns `true` if the conflicting statements came from more than one source.
+    ///
+    pub fn is_between_sources(&self) -> bool {
+        self.sources().len() > 1
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Merged {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for conflict in &self.conflicts {
+            write!(f, "{}", conflict)?;
+        }
+        Ok(())
+    }
+}
+
+impl Merged {
+    ///
+    /// Return the merged graph.
+    ///
+    pub fn graph(&self) -> &GraphRef {
+        &self.graph
+    }
+
+    ///
+    /// Return the names of the sources that asserted `statement`, in merge order; statements
+    /// with renamed blank nodes must be taken from the merged graph.
+    ///
+    pub fn sources_of(&self, statement: &StatementRef) -> Option<&Vec<String>> {
+        self.sources.get(statement)
+    }
+
+    ///
+    /// Return all conflicts found in the merged graph.
+    ///
+    pub fn conflicts(&self) -> &[MergeConflict] {
+        &self.conflicts
+    }
+
+    ///
+    /// Returns `true` if no conflicts were found.
+    ///
+    pub fn is_consistent(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn group<K: PartialEq>(groups: &mut Vec<(K, Vec<StatementRef>)>, key: K, statement: &StatementRef) {
+    match groups.iter_mut().find(|(existing, _)| existing == &key) {
+        Some((_, statements)) => statements.push(statement.clone()),
+        None => groups.push((key, vec![statement.clone()])),
+    }
+}
@@ -18,6 +18,7 @@ fn simple_graph_writer(graph: &impl Graph)
 ```
 */
 
+use crate::error::Result;
 use crate::model::features::Featured;
 use crate::model::graph::mapping::PrefixMappingFactoryRef;
 use crate::model::literal::LiteralFactoryRef;
@@ -25,11 +26,13 @@ use crate::model::statement::{
     ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
 };
 use crate::model::Provided;
+pub use iter::{Snapshot, StatementIter};
 pub use mapping::{PrefixMappingRef, PrefixMappings};
 use rdftk_iri::IRIRef;
 use std::cell::RefCell;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::hash::Hash;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -60,6 +63,15 @@ pub trait GraphFactory: Debug + Provided {
     ///
     fn with_mappings(&self, prefix_mappings: PrefixMappingRef) -> GraphRef;
 
+    ///
+    ///  Create a new graph instance from the statements produced by the given iterator.
+    ///
+    fn graph_from_iter(&self, statements: &mut dyn Iterator<Item = StatementRef>) -> GraphRef {
+        let graph = self.graph();
+        graph.borrow_mut().extend(statements);
+        graph
+    }
+
     ///
     ///  Create a new graph instance from the given statements and prefix mappings.
     ///
@@ -149,15 +161,38 @@ pub trait Graph: Debug + Featured {
     }
 
     ///
-    /// Returns `true` if this graph contains the any statement with the provided subject,
-    /// predicate, and object, else `false`.
+    /// Return the set of statements that match the provided subject, predicate, and object; any
+    /// of these that is `None` acts as a wildcard and matches any value. The default
+    /// implementation scans all statements.
     ///
     fn matches(
         &self,
         subject: Option<&SubjectNodeRef>,
         predicate: Option<&IRIRef>,
         object: Option<&ObjectNodeRef>,
-    ) -> HashSet<&StatementRef>;
+    ) -> HashSet<&StatementRef> {
+        self.statements()
+            .filter(|st| statement_matches(st, subject, predicate, object))
+            .collect()
+    }
+
+    ///
+    /// Return a list of the statements that match the provided subject, predicate, and object,
+    /// in the order they appear in the graph; any of these that is `None` acts as a wildcard. Unlike
+    /// `matches` this retains any duplicate statements. The default implementation scans all
+    /// statements.
+    ///
+    fn statements_matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> StatementList {
+        self.statements()
+            .filter(|st| statement_matches(st, subject, predicate, object))
+            .cloned()
+            .collect()
+    }
 
     // --------------------------------------------------------------------------------------------
     // Iterators
@@ -166,7 +201,15 @@ pub trait Graph: Debug + Featured {
     ///
     /// Return an iterator over all the statements in the graph.
     ///
-    fn statements<'a>(&'a self) -> Box<dyn Iterator<Item = &'a StatementRef> + 'a>;
+    fn statements(&self) -> StatementIter<'_>;
+
+    ///
+    /// Return a snapshot of the statements in the graph, which may be iterated while the graph is
+    /// modified; see `iter::Snapshot`. The default implementation copies the list of statements.
+    ///
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::from(self.statements().cloned().collect::<StatementList>())
+    }
 
     ///
     /// Return a set of all subjects in the graph, note that this is a set so that it removes
@@ -198,6 +241,98 @@ pub trait Graph: Debug + Featured {
     ///
     fn objects_for(&self, subject: &SubjectNodeRef, predicate: &IRIRef) -> HashSet<&ObjectNodeRef>;
 
+    ///
+    /// Return an iterator over the distinct subjects in the graph, each paired with the number of
+    /// statements in which it is the subject. The default implementation scans all statements.
+    ///
+    fn distinct_subjects(&self) -> Box<dyn Iterator<Item = (&SubjectNodeRef, usize)> + '_> {
+        Box::new(count_distinct(self.statements().map(|st| st.subject())).into_iter())
+    }
+
+    ///
+    /// Return an iterator over the distinct predicates in the graph, each paired with the number
+    /// of statements in which it is the predicate. The default implementation scans all
+    /// statements.
+    ///
+    fn distinct_predicates(&self) -> Box<dyn Iterator<Item = (&IRIRef, usize)> + '_> {
+        Box::new(count_distinct(self.statements().map(|st| st.predicate())).into_iter())
+    }
+
+    ///
+    /// Return an iterator over the distinct objects in the graph, each paired with the number of
+    /// statements in which it is the object. The default implementation scans all statements.
+    ///
+    fn distinct_objects(&self) -> Box<dyn Iterator<Item = (&ObjectNodeRef, usize)> + '_> {
+        Box::new(count_distinct(self.statements().map(|st| st.object())).into_iter())
+    }
+
+    ///
+    /// Return a map from each predicate in the graph to the number of statements using it.
+    ///
+    fn count_by_predicate(&self) -> HashMap<&IRIRef, usize> {
+        self.distinct_predicates().collect()
+    }
+
+    // --------------------------------------------------------------------------------------------
+    // Transformations
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Return a new graph with all blank nodes replaced by new, unique, well-known IRIs created
+    /// from `base`; see `skolem::skolemize`.
+    ///
+    fn skolemize(&self, base: &IRIRef) -> Result<GraphRef> {
+        skolem::skolemize(self, base)
+    }
+
+    ///
+    /// Return a new graph with all blank nodes relabelled using the RDFC-1.0 canonicalization
+    /// algorithm; see `canonical::canonicalize`.
+    ///
+    fn canonicalize(&self) -> Result<GraphRef> {
+        canonical::canonicalize(self)
+    }
+
+    ///
+    /// Return a new graph without the statements that are simply entailed by others, merging
+    /// redundant blank node structures; see `lean::lean`.
+    ///
+    fn lean(&self) -> GraphRef {
+        lean::lean(self)
+    }
+
+    ///
+    /// Returns `true` if no statement in this graph is simply entailed by the others; see
+    /// `lean::is_lean`.
+    ///
+    fn is_lean(&self) -> bool {
+        lean::is_lean(self)
+    }
+
+    ///
+    /// Return a new graph containing the statements of both `self` and `other`, renaming blank
+    /// nodes in `other` that would collide with those in `self`; see `operations::union`.
+    ///
+    fn union(&self, other: &dyn Graph) -> Result<GraphRef> {
+        operations::union(self, other)
+    }
+
+    ///
+    /// Return a new graph containing the statements in both `self` and `other`; see
+    /// `operations::intersection`.
+    ///
+    fn intersection(&self, other: &dyn Graph) -> GraphRef {
+        operations::intersection(self, other)
+    }
+
+    ///
+    /// Return a new graph containing the statements in `self` that are not in `other`; see
+    /// `operations::difference`.
+    ///
+    fn difference(&self, other: &dyn Graph) -> GraphRef {
+        operations::difference(self, other)
+    }
+
     // --------------------------------------------------------------------------------------------
     // Namespace Management
     // --------------------------------------------------------------------------------------------
@@ -254,6 +389,35 @@ pub trait Graph: Debug + Featured {
     ///
     fn insert(&mut self, statement: StatementRef);
 
+    ///
+    /// Insert all of `statements` into the graph, skipping any already in the graph or repeated
+    /// in `statements`, and returning the number inserted. Unlike `insert` this never adds a
+    /// duplicate statement, even if the graph supports the feature `FEATURE_GRAPH_DUPLICATES`.
+    ///
+    /// The default implementation calls `contains` and `insert` for each statement,
+    /// implementations should override this to check for duplicates in a single pass. The
+    /// `Extend` implementation for `dyn Graph` calls this, and so also skips duplicates.
+    ///
+    fn extend_from(&mut self, statements: &mut dyn Iterator<Item = StatementRef>) -> usize {
+        let mut inserted = 0;
+        for statement in statements {
+            if !self.contains(&statement) {
+                self.insert(statement);
+                inserted += 1;
+            }
+        }
+        inserted
+    }
+
+    ///
+    /// A hint that at least `additional` more statements are to be inserted into the graph,
+    /// allowing an implementation to allocate space for them in advance. The default
+    /// implementation does nothing.
+    ///
+    fn reserve(&mut self, additional: usize) {
+        let _ = additional;
+    }
+
     ///
     /// Merge another graph into this one. Note that the graphs are required to have the same
     /// implementation type based in the type qualifiers for `StatementIter`.
@@ -293,10 +457,80 @@ pub trait Graph: Debug + Featured {
 ///
 pub type GraphRef = Rc<RefCell<dyn Graph>>;
 
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Returns `true` if `statement` matches each of the provided subject, predicate, and object.
+///
+pub(crate) fn statement_matches(
+    statement: &StatementRef,
+    subject: Option<&SubjectNodeRef>,
+    predicate: Option<&IRIRef>,
+    object: Option<&ObjectNodeRef>,
+) -> bool {
+    subject.map(|s| statement.subject() == s).unwrap_or(true)
+        && predicate
+            .map(|p| statement.predicate() == p)
+            .unwrap_or(true)
+        && object.map(|o| statement.object() == o).unwrap_or(true)
+}
+
+fn count_distinct<'a, T: Eq + Hash + ?Sized + 'a>(
+    values: impl Iterator<Item = &'a T>,
+) -> HashMap<&'a T, usize> {
+    let mut counts: HashMap<&'a T, usize> = Default::default();
+    for value in values {
+        *counts.entry(value).or_default() += 1;
+    }
+    counts
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
 
+pub mod cache;
+
+pub mod canonical;
+
+pub mod change_set;
+
+pub mod cardinality;
+
+pub mod collecting;
+
+pub mod gc;
+
+pub mod inference;
+
+pub mod iter;
+
+pub mod lean;
+
 pub mod mapping;
 
+pub mod merge;
+
+pub mod operations;
+
+pub mod order;
+
+pub mod projection;
+
+pub mod quantity;
+
+pub mod reasoner;
+
 pub mod skolem;
+
+pub mod stats;
+
+pub mod store;
+
+pub mod transaction;
+
+pub mod usage;
+
+pub mod watermark;
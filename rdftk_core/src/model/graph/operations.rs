@@ -0,0 +1,250 @@
+/*!
+Set operations, union, intersection, and difference, over graphs. Each operation creates a new
+graph, using the factory of the left-hand graph, and leaves both operands unchanged; the result
+contains no duplicate statements.
+
+Blank node labels are scoped to the graph that contains them, so `union` performs an RDF
+[merge](https://www.w3.org/TR/rdf11-mt/#shared-blank-nodes-unions-and-merges): any blank node in
+the right-hand graph whose label is also used in the left-hand graph is renamed, so that distinct
+blank nodes are never conflated. The `intersection` and `difference` operations compare
+statements, including blank node labels, as-is, which is meaningful when both graphs share a label
+space, for example when one was derived from the other. Graphs read independently should first
+be relabelled with `Graph::canonicalize` so that equivalent blank nodes share a label.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::operations::{difference, union};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_names::foaf;
+
+let factory = statement_factory();
+let knows = |s: &str, o: &str| {
+    factory
+        .statement(
+            factory.blank_subject_named(s).unwrap(),
+            foaf::knows().clone(),
+            factory.blank_object_named(o).unwrap(),
+        )
+        .unwrap()
+};
+let lhs = graph_factory().graph_from(&[knows("a", "b")], None);
+let rhs = graph_factory().graph_from(&[knows("a", "c")], None);
+
+// the blank node "a" in `rhs` is renamed, it is not the same node as "a" in `lhs`.
+let merged = union(&*lhs.borrow(), &*rhs.borrow()).unwrap();
+assert_eq!(merged.borrow().len(), 2);
+assert_eq!(merged.borrow().subjects().len(), 2);
+
+// neither merged statement is in `rhs`, as its blank nodes are scoped to that graph.
+let remaining = difference(&*merged.borrow(), &*rhs.borrow());
+assert_eq!(remaining.borrow().len(), 2);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::graph::{Graph, GraphRef};
+use crate::model::statement::{ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef};
+use std::collections::{HashMap, HashSet};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+struct Renamer {
+    factory: StatementFactoryRef,
+    used: HashSet<String>,
+    renamed: HashMap<String, String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return a new graph containing the statements of both `lhs` and `rhs`, renaming any blank node
+/// in `rhs` whose label is also used in `lhs`.
+///
+pub fn union(lhs: &(impl Graph + ?Sized), rhs: &(impl Graph + ?Sized)) -> Result<GraphRef> {
+    let lhs_labels = blank_labels(lhs);
+    let mut used = lhs_labels.clone();
+    used.extend(blank_labels(rhs));
+    let mut renamer = Renamer {
+        factory: lhs.statement_factory(),
+        used,
+        renamed: Default::default(),
+    };
+    let mut statements: Vec<StatementRef> = lhs.statements().cloned().collect();
+    for statement in rhs.statements() {
+        statements.push(renamer.statement(statement, &lhs_labels)?);
+    }
+    Ok(new_graph(lhs, statements.into_iter()))
+}
+
+///
+/// Return a new graph containing only those statements of `lhs` that are also in `rhs`.
+///
+pub fn intersection(lhs: &(impl Graph + ?Sized), rhs: &(impl Graph + ?Sized)) -> GraphRef {
+    let rhs: HashSet<&StatementRef> = rhs.statements().collect();
+    new_graph(lhs, lhs.statements().filter(|st| rhs.contains(st)).cloned())
+}
+
+///
+/// Return a new graph containing only those statements of `lhs` that are not in `rhs`.
+///
+pub fn difference(lhs: &(impl Graph + ?Sized), rhs: &(impl Graph + ?Sized)) -> GraphRef {
+    let rhs: HashSet<&StatementRef> = rhs.statements().collect();
+    new_graph(
+        lhs,
+        lhs.statements().filter(|st| !rhs.contains(st)).cloned(),
+    )
+}
+
+///
+/// Return the statements of each of `graphs`, in order, renaming any blank node whose label is
+/// also used in an earlier graph so that blank nodes from different graphs are never conflated.
+///
+pub(super) fn merge_statements(
+    graphs: &[&dyn Graph],
+    factory: &StatementFactoryRef,
+) -> Result<Vec<Vec<StatementRef>>> {
+    let mut used: HashSet<String> = Default::default();
+    for graph in graphs {
+        used.extend(blank_labels(*graph));
+    }
+    let mut reserved: HashSet<String> = Default::default();
+    let mut merged: Vec<Vec<StatementRef>> = Default::default();
+    for graph in graphs {
+        let mut renamer = Renamer {
+            factory: factory.clone(),
+            used,
+            renamed: Default::default(),
+        };
+        let statements = graph
+            .statements()
+            .map(|statement| renamer.statement(statement, &reserved))
+            .collect::<Result<Vec<StatementRef>>>()?;
+        used = renamer.used;
+        for statement in &statements {
+            statement_labels(statement, &mut reserved);
+        }
+        merged.push(statements);
+    }
+    Ok(merged)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Renamer {
+    fn label(&mut self, label: &str, reserved: &HashSet<String>) -> Option<String> {
+        if !reserved.contains(label) {
+            None
+        } else if let Some(renamed) = self.renamed.get(label) {
+            Some(renamed.clone())
+        } else {
+            let renamed = loop {
+                let candidate = self.factory.blank_subject();
+                let candidate = candidate.as_blank().unwrap();
+                if !self.used.contains(candidate) {
+                    break candidate.clone();
+                }
+            };
+            let _ = self.used.insert(renamed.clone());
+            let _ = self.renamed.insert(label.to_string(), renamed.clone());
+            Some(renamed)
+        }
+    }
+
+    fn subject(
+        &mut self,
+        subject: &SubjectNodeRef,
+        reserved: &HashSet<String>,
+    ) -> Result<SubjectNodeRef> {
+        if let Some(label) = subject.as_blank() {
+            match self.label(label, reserved) {
+                Some(renamed) => self.factory.blank_subject_named(&renamed),
+                None => Ok(subject.clone()),
+            }
+        } else if let Some(statement) = subject.as_statement() {
+            let statement = self.statement(statement, reserved)?;
+            Ok(self.factory.statement_subject(statement))
+        } else {
+            Ok(subject.clone())
+        }
+    }
+
+    fn object(
+        &mut self,
+        object: &ObjectNodeRef,
+        reserved: &HashSet<String>,
+    ) -> Result<ObjectNodeRef> {
+        if let Some(label) = object.as_blank() {
+            match self.label(label, reserved) {
+                Some(renamed) => self.factory.blank_object_named(&renamed),
+                None => Ok(object.clone()),
+            }
+        } else if let Some(statement) = object.as_statement() {
+            let statement = self.statement(statement, reserved)?;
+            Ok(self.factory.statement_object(statement))
+        } else {
+            Ok(object.clone())
+        }
+    }
+
+    fn statement(
+        &mut self,
+        statement: &StatementRef,
+        reserved: &HashSet<String>,
+    ) -> Result<StatementRef> {
+        let subject = self.subject(statement.subject(), reserved)?;
+        let object = self.object(statement.object(), reserved)?;
+        self.factory
+            .statement(subject, statement.predicate().clone(), object)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn new_graph(
+    template: &(impl Graph + ?Sized),
+    statements: impl Iterator<Item = StatementRef>,
+) -> GraphRef {
+    let graph = template.factory().graph();
+    {
+        let mut graph = graph.borrow_mut();
+        let mut seen: HashSet<StatementRef> = Default::default();
+        for statement in statements {
+            if seen.insert(statement.clone()) {
+                graph.insert(statement);
+            }
+        }
+    }
+    graph
+}
+
+fn blank_labels(graph: &(impl Graph + ?Sized)) -> HashSet<String> {
+    let mut labels: HashSet<String> = Default::default();
+    for statement in graph.statements() {
+        statement_labels(statement, &mut labels);
+    }
+    labels
+}
+
+fn statement_labels(statement: &StatementRef, labels: &mut HashSet<String>) {
+    if let Some(label) = statement.subject().as_blank() {
+        let _ = labels.insert(label.clone());
+    } else if let Some(statement) = statement.subject().as_statement() {
+        statement_labels(statement, labels);
+    }
+    if let Some(label) = statement.object().as_blank() {
+        let _ = labels.insert(label.clone());
+    } else if let Some(statement) = statement.object().as_statement() {
+        statement_labels(statement, labels);
+    }
+}
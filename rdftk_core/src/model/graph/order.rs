@@ -0,0 +1,204 @@
+/*!
+Orderings of statements, shared by the sorted writers in the `rdftk_io` crate, by
+`ChangeSet::sort`, and by `canonical::canonical_n_triples_ordered`, so that the same layout may be
+used for written files, the differences between them, and their canonical forms.
+
+The default `LexicalOrder` compares subject, predicate, and object, in turn, by their N-Triples
+form; blank nodes are compared using their canonical labels, see the `canonical` module, so that
+the order does not depend on the labels generated when a graph was read. The `PredicateOrder`
+places statements with a given list of predicates first for each subject, for example `rdf:type`
+then `rdfs:label`, for curated layouts. Other orders may be provided by implementing the
+`StatementOrder` trait.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::order::{sorted_statements, PredicateOrder};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{foaf, rdf, rdfs};
+use std::str::FromStr;
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let graph = graph_factory().graph_from(
+    &[
+        factory
+            .statement(alice.clone(), rdfs::label().clone(), factory.literal_object(literal_factory().literal("Alice")))
+            .unwrap(),
+        factory
+            .statement(alice.clone(), foaf::name().clone(), factory.literal_object(literal_factory().literal("Alice")))
+            .unwrap(),
+        factory
+            .statement(alice, rdf::a_type().clone(), factory.named_object(foaf::person().clone()))
+            .unwrap(),
+    ],
+    None,
+);
+
+let order = PredicateOrder::new(&[rdf::a_type().clone(), rdfs::label().clone()]);
+let statements = sorted_statements(&*graph.borrow(), &order);
+assert_eq!(statements[0].predicate(), rdf::a_type());
+assert_eq!(statements[1].predicate(), rdfs::label());
+assert_eq!(statements[2].predicate(), foaf::name());
+```
+
+*/
+
+use crate::model::graph::canonical::canonical_labels;
+use crate::model::graph::Graph;
+use crate::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A total order over the statements of a graph.
+///
+pub trait StatementOrder: Debug {
+    ///
+    /// Compare two statements; `labels` maps blank node labels to their canonical labels, and any
+    /// blank node not in `labels` should be compared using its own label.
+    ///
+    fn compare(
+        &self,
+        lhs: &StatementRef,
+        rhs: &StatementRef,
+        labels: &HashMap<String, String>,
+    ) -> Ordering;
+}
+
+///
+/// The type for a shared reference to a statement order.
+///
+pub type StatementOrderRef = Arc<dyn StatementOrder>;
+
+///
+/// Orders statements by subject, predicate, and object, each compared by their N-Triples form
+/// with blank nodes compared by their canonical labels.
+///
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LexicalOrder;
+
+///
+/// Orders statements by subject, then places statements with each of the listed predicates, in
+/// the order listed, before those with any other predicate; ties are ordered by `LexicalOrder`.
+///
+#[derive(Clone, Debug)]
+pub struct PredicateOrder {
+    predicates: Vec<IRIRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the statements of `graph` sorted by `order`. If the graph cannot be canonicalized,
+/// blank nodes are compared by their own labels.
+///
+pub fn sorted_statements(
+    graph: &(impl Graph + ?Sized),
+    order: &dyn StatementOrder,
+) -> Vec<StatementRef> {
+    let labels = canonical_labels(graph).unwrap_or_default();
+    let mut statements: Vec<StatementRef> = graph.statements().cloned().collect();
+    statements.sort_by(|lhs, rhs| order.compare(lhs, rhs, &labels));
+    statements
+}
+
+///
+/// Return a shared reference to the default, lexical, statement order.
+///
+pub fn lexical_order() -> StatementOrderRef {
+    Arc::new(LexicalOrder)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl StatementOrder for LexicalOrder {
+    fn compare(
+        &self,
+        lhs: &StatementRef,
+        rhs: &StatementRef,
+        labels: &HashMap<String, String>,
+    ) -> Ordering {
+        subject_key(lhs.subject(), labels)
+            .cmp(&subject_key(rhs.subject(), labels))
+            .then_with(|| {
+                lhs.predicate()
+                    .to_string()
+                    .cmp(&rhs.predicate().to_string())
+            })
+            .then_with(|| object_key(lhs.object(), labels).cmp(&object_key(rhs.object(), labels)))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl StatementOrder for PredicateOrder {
+    fn compare(
+        &self,
+        lhs: &StatementRef,
+        rhs: &StatementRef,
+        labels: &HashMap<String, String>,
+    ) -> Ordering {
+        subject_key(lhs.subject(), labels)
+            .cmp(&subject_key(rhs.subject(), labels))
+            .then_with(|| self.rank(lhs.predicate()).cmp(&self.rank(rhs.predicate())))
+            .then_with(|| LexicalOrder.compare(lhs, rhs, labels))
+    }
+}
+
+impl PredicateOrder {
+    ///
+    /// Create a new order that places statements with `predicates`, in this order, first.
+    ///
+    pub fn new(predicates: &[IRIRef]) -> Self {
+        Self {
+            predicates: predicates.to_vec(),
+        }
+    }
+
+    ///
+    /// Return the predicates placed first, in order.
+    ///
+    pub fn predicates(&self) -> &Vec<IRIRef> {
+        &self.predicates
+    }
+
+    fn rank(&self, predicate: &IRIRef) -> usize {
+        self.predicates
+            .iter()
+            .position(|p| p == predicate)
+            .unwrap_or(self.predicates.len())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn subject_key(subject: &SubjectNodeRef, labels: &HashMap<String, String>) -> String {
+    match subject.as_blank() {
+        Some(label) => format!("_:{}", labels.get(label).unwrap_or(label)),
+        None => subject.to_string(),
+    }
+}
+
+fn object_key(object: &ObjectNodeRef, labels: &HashMap<String, String>) -> String {
+    match object.as_blank() {
+        Some(label) => format!("_:{}", labels.get(label).unwrap_or(label)),
+        None => object.to_string(),
+    }
+}
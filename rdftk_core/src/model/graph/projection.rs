@@ -0,0 +1,284 @@
+/*!
+Projection of the subjects in a graph onto Rust values, for read-only pipelines that would
+otherwise walk statements by hand.
+
+The `project` method on `Graph` selects subjects with a `SubjectFilter`, presents each as a
+`ResourceView` over the graph, and calls a mapping closure that may return a value, or `None` to
+skip the subject. Subjects are visited in lexical order so that projections are repeatable.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::projection::SubjectFilter;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{foaf, rdf};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+struct Person {
+    name: String,
+    knows: usize,
+}
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let graph = graph_factory().graph_from(
+    &[
+        factory
+            .statement(alice.clone(), rdf::a_type().clone(), factory.named_object(foaf::person().clone()))
+            .unwrap(),
+        factory
+            .statement(alice, foaf::name().clone(), factory.literal_object(literal_factory().literal("Alice")))
+            .unwrap(),
+    ],
+    None,
+);
+
+let graph = graph.borrow();
+let people: Vec<Person> = graph
+    .project(SubjectFilter::OfType(foaf::person().clone()), |resource| {
+        Some(Person {
+            name: resource.value(foaf::name())?.clone(),
+            knows: resource.objects(foaf::knows()).len(),
+        })
+    })
+    .collect();
+
+assert_eq!(people, vec![Person { name: "Alice".to_string(), knows: 0 }]);
+```
+
+*/
+
+use crate::model::graph::Graph;
+use crate::model::literal::{LanguageTag, LiteralRef};
+use crate::model::statement::{ObjectNodeRef, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use rdftk_names::rdf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Selects the subjects to be projected.
+///
+#[derive(Clone, Debug)]
+pub enum SubjectFilter {
+    /// Every subject in the graph.
+    All,
+    /// Only subjects that are IRIs.
+    Named,
+    /// Only subjects with the given `rdf:type`.
+    OfType(IRIRef),
+    /// Only subjects with at least one of the given `rdf:type`s.
+    OfAnyType(Vec<IRIRef>),
+}
+
+///
+/// A read-only view of a single subject, and its properties, in a graph.
+///
+#[derive(Clone, Debug)]
+pub struct ResourceView<'a> {
+    graph: &'a dyn Graph,
+    subject: SubjectNodeRef,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl dyn Graph {
+    ///
+    /// Return an iterator over the values returned by `mapper` for each subject selected by
+    /// `filter`; subjects for which `mapper` returns `None` are skipped.
+    ///
+    pub fn project<'a, T, F>(
+        &'a self,
+        filter: SubjectFilter,
+        mapper: F,
+    ) -> impl Iterator<Item = T> + 'a
+    where
+        F: Fn(&ResourceView<'a>) -> Option<T> + 'a,
+    {
+        let mut subjects: Vec<&SubjectNodeRef> = self.subjects().into_iter().collect();
+        subjects.sort_by_key(|subject| subject.to_string());
+        subjects
+            .into_iter()
+            .map(move |subject| ResourceView::new(self, subject.clone()))
+            .filter(move |resource| filter.accepts(resource))
+            .filter_map(move |resource| mapper(&resource))
+    }
+
+    ///
+    /// Return a view of `subject` in this graph.
+    ///
+    pub fn resource(&self, subject: &SubjectNodeRef) -> ResourceView<'_> {
+        ResourceView::new(self, subject.clone())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl SubjectFilter {
+    ///
+    /// Returns `true` if the resource is selected by this filter.
+    ///
+    pub fn accepts(&self, resource: &ResourceView<'_>) -> bool {
+        match self {
+            Self::All => true,
+            Self::Named => resource.iri().is_some(),
+            Self::OfType(type_iri) => resource.has_type(type_iri),
+            Self::OfAnyType(type_iris) => type_iris.iter().any(|iri| resource.has_type(iri)),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> ResourceView<'a> {
+    fn new(graph: &'a dyn Graph, subject: SubjectNodeRef) -> Self {
+        Self { graph, subject }
+    }
+
+    ///
+    /// Return the subject node this view presents.
+    ///
+    pub fn subject(&self) -> &SubjectNodeRef {
+        &self.subject
+    }
+
+    ///
+    /// Return the subject's IRI, or `None` if the subject is a blank node.
+    ///
+    pub fn iri(&self) -> Option<&IRIRef> {
+        self.subject.as_iri()
+    }
+
+    ///
+    /// Return the subject's `rdf:type`s, in lexical order.
+    ///
+    pub fn types(&self) -> Vec<&'a IRIRef> {
+        self.objects(rdf::a_type())
+            .into_iter()
+            .filter_map(|object| object.as_iri())
+            .collect()
+    }
+
+    ///
+    /// Returns `true` if the subject has the `rdf:type` `type_iri`.
+    ///
+    pub fn has_type(&self, type_iri: &IRIRef) -> bool {
+        self.graph
+            .objects_for(&self.subject, rdf::a_type())
+            .into_iter()
+            .any(|object| object.as_iri() == Some(type_iri))
+    }
+
+    ///
+    /// Return the predicates used with this subject, in lexical order.
+    ///
+    pub fn predicates(&self) -> Vec<&'a IRIRef> {
+        let mut predicates: Vec<&IRIRef> = self
+            .graph
+            .predicates_for(&self.subject)
+            .into_iter()
+            .collect();
+        predicates.sort();
+        predicates
+    }
+
+    ///
+    /// Return all the objects of `predicate` for this subject, in lexical order.
+    ///
+    pub fn objects(&self, predicate: &IRIRef) -> Vec<&'a ObjectNodeRef> {
+        let mut objects: Vec<&ObjectNodeRef> = self
+            .graph
+            .objects_for(&self.subject, predicate)
+            .into_iter()
+            .collect();
+        objects.sort_by_key(|object| object.to_string());
+        objects
+    }
+
+    ///
+    /// Return all the literal objects of `predicate` for this subject, in lexical order.
+    ///
+    pub fn literals(&self, predicate: &IRIRef) -> Vec<&'a LiteralRef> {
+        self.objects(predicate)
+            .into_iter()
+            .filter_map(|object| object.as_literal())
+            .collect()
+    }
+
+    ///
+    /// Return the first literal object of `predicate`, in lexical order.
+    ///
+    pub fn literal(&self, predicate: &IRIRef) -> Option<&'a LiteralRef> {
+        self.literals(predicate).into_iter().next()
+    }
+
+    ///
+    /// Return the lexical form of the first literal object of `predicate`.
+    ///
+    pub fn value(&self, predicate: &IRIRef) -> Option<&'a String> {
+        self.literal(predicate)
+            .map(|literal| literal.lexical_form())
+    }
+
+    ///
+    /// Return the lexical form of the first literal object of `predicate` in `language`, or
+    /// without a language if there is none in `language`.
+    ///
+    pub fn value_in(&self, predicate: &IRIRef, language: &LanguageTag) -> Option<&'a String> {
+        let literals = self.literals(predicate);
+        literals
+            .iter()
+            .find(|literal| literal.language() == Some(language))
+            .or_else(|| literals.iter().find(|literal| literal.language().is_none()))
+            .map(|literal| literal.lexical_form())
+    }
+
+    ///
+    /// Return the lexical forms of all the literal objects of `predicate`.
+    ///
+    pub fn values(&self, predicate: &IRIRef) -> Vec<&'a String> {
+        self.literals(predicate)
+            .into_iter()
+            .map(|literal| literal.lexical_form())
+            .collect()
+    }
+
+    ///
+    /// Return all the IRI objects of `predicate`, in lexical order.
+    ///
+    pub fn iris(&self, predicate: &IRIRef) -> Vec<&'a IRIRef> {
+        self.objects(predicate)
+            .into_iter()
+            .filter_map(|object| object.as_iri())
+            .collect()
+    }
+
+    ///
+    /// Return views of all the IRI and blank node objects of `predicate`. Objects that are not
+    /// themselves subjects in the graph are included, with no properties.
+    ///
+    pub fn resources(&self, predicate: &IRIRef) -> Vec<ResourceView<'a>> {
+        let factory = self.graph.statement_factory();
+        self.objects(predicate)
+            .into_iter()
+            .filter(|object| !object.is_literal())
+            .filter_map(|object| factory.object_as_subject(object.clone()))
+            .map(|subject| ResourceView::new(self.graph, subject))
+            .collect()
+    }
+
+    ///
+    /// Return a view of the first IRI or blank node object of `predicate`.
+    ///
+    pub fn resource(&self, predicate: &IRIRef) -> Option<ResourceView<'a>> {
+        self.resources(predicate).into_iter().next()
+    }
+}
@@ -0,0 +1,456 @@
+/*!
+Quantity values, a number and its unit of measure, described using the
+[QUDT](https://qudt.org/) ontology, and conversion between compatible units.
+
+A `Quantity` is written to a graph as QUDT describes a quantity value; a blank node, of type
+`qudt:QuantityValue`, with the number as the `qudt:numericValue` and the unit's IRI as the
+`qudt:unit`. It is read back from any node with a numeric `qudt:numericValue`, or `qudt:value`,
+and a `qudt:unit`, or `qudt:hasUnit`.
+
+A `UnitRegistry` describes units by their quantity kind, such as length or pressure, and the
+`qudt:conversionMultiplier` and `qudt:conversionOffset` that convert a value in the unit to one
+in the SI base unit for the same quantity kind. The default registry includes the units named in
+`rdftk_names::qudt::unit`; others may be added directly, or read from a graph that describes them
+as the QUDT units vocabulary does. Two units are compatible if both are known and they share a
+quantity kind.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::quantity::{Quantity, UnitRegistry};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::qudt::unit;
+use std::str::FromStr;
+
+let pipe = statement_factory()
+    .named_subject(IRIRef::from(IRI::from_str("http://example.org/pipe").unwrap()));
+let length = IRIRef::from(IRI::from_str("http://example.org/length").unwrap());
+
+let graph = graph_factory().graph();
+let _ = Quantity::new(2.5, unit::kilo_m().clone())
+    .insert(&mut *graph.borrow_mut(), pipe.clone(), length.clone())
+    .unwrap();
+
+let lengths = Quantity::values_for(&*graph.borrow(), &pipe, &length);
+assert_eq!(lengths.len(), 1);
+
+let in_metres = UnitRegistry::default().convert(&lengths[0], unit::m()).unwrap();
+assert_eq!(in_metres.value(), 2500.0);
+assert_eq!(in_metres.unit(), unit::m());
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::graph::Graph;
+use crate::model::statement::{StatementList, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use rdftk_names::qudt::{quantity_kind as kind, schema as qudt, unit};
+use rdftk_names::rdf;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A numeric value and the IRI of its unit of measure.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quantity {
+    value: f64,
+    unit: IRIRef,
+}
+
+///
+/// A unit of measure; its quantity kind, if known, and the multiplier and offset that convert a
+/// value in this unit to one in the SI base unit, as `(value + offset) * multiplier`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unit {
+    iri: IRIRef,
+    quantity_kind: Option<IRIRef>,
+    multiplier: f64,
+    offset: f64,
+}
+
+///
+/// The set of units known for conversion, by IRI.
+///
+#[derive(Clone, Debug)]
+pub struct UnitRegistry {
+    units: HashMap<IRIRef, Unit>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Quantity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <{}>", self.value, self.unit)
+    }
+}
+
+impl Quantity {
+    ///
+    /// Construct a new quantity of `value` measured in `unit`.
+    ///
+    pub fn new(value: f64, unit: IRIRef) -> Self {
+        Self { value, unit }
+    }
+
+    ///
+    /// Return the numeric value of this quantity.
+    ///
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    ///
+    /// Return the IRI of this quantity's unit.
+    ///
+    pub fn unit(&self) -> &IRIRef {
+        &self.unit
+    }
+
+    ///
+    /// Return the statements describing this quantity as a `qudt:QuantityValue`, and the new
+    /// blank node they describe, using the factories of `graph`.
+    ///
+    pub fn to_statements(&self, graph: &dyn Graph) -> Result<(SubjectNodeRef, StatementList)> {
+        let factory = graph.statement_factory();
+        let node = factory.blank_subject();
+        let statements = vec![
+            factory.statement(
+                node.clone(),
+                rdf::a_type().clone(),
+                factory.named_object(qudt::quantity_value().clone()),
+            )?,
+            factory.statement(
+                node.clone(),
+                qudt::numeric_value().clone(),
+                factory.literal_object(graph.literal_factory().double(self.value)),
+            )?,
+            factory.statement(
+                node.clone(),
+                qudt::value_unit().clone(),
+                factory.named_object(self.unit.clone()),
+            )?,
+        ];
+        Ok((node, statements))
+    }
+
+    ///
+    /// Insert this quantity into `graph` as the value of `predicate` for `subject`, returning the
+    /// blank node describing the quantity.
+    ///
+    pub fn insert(
+        &self,
+        graph: &mut dyn Graph,
+        subject: SubjectNodeRef,
+        predicate: IRIRef,
+    ) -> Result<SubjectNodeRef> {
+        let (node, statements) = self.to_statements(graph)?;
+        let factory = graph.statement_factory();
+        graph.insert(factory.statement(
+            subject,
+            predicate,
+            factory.subject_as_object(node.clone()),
+        )?);
+        for statement in statements {
+            graph.insert(statement);
+        }
+        Ok(node)
+    }
+
+    ///
+    /// Return the quantity described by `node` in `graph`, if it has both a numeric value and a
+    /// unit.
+    ///
+    pub fn from_node(graph: &dyn Graph, node: &SubjectNodeRef) -> Option<Self> {
+        let value = [qudt::numeric_value(), qudt::value()]
+            .iter()
+            .flat_map(|predicate| graph.objects_for(node, predicate))
+            .filter_map(|object| object.as_literal())
+            .find_map(|literal| literal.as_f64().ok())?;
+        let unit = [qudt::value_unit(), qudt::has_unit()]
+            .iter()
+            .flat_map(|predicate| graph.objects_for(node, predicate))
+            .filter_map(|object| object.as_iri())
+            .min()?;
+        Some(Self::new(value, unit.clone()))
+    }
+
+    ///
+    /// Return each quantity that is a value of `predicate` for `subject` in `graph`.
+    ///
+    pub fn values_for(
+        graph: &dyn Graph,
+        subject: &SubjectNodeRef,
+        predicate: &IRIRef,
+    ) -> Vec<Self> {
+        let factory = graph.statement_factory();
+        graph
+            .objects_for(subject, predicate)
+            .into_iter()
+            .filter_map(|object| factory.object_as_subject(object.clone()))
+            .filter_map(|node| Self::from_node(graph, &node))
+            .collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Unit {
+    ///
+    /// Construct a new unit description.
+    ///
+    pub fn new(iri: IRIRef, quantity_kind: Option<IRIRef>, multiplier: f64, offset: f64) -> Self {
+        Self {
+            iri,
+            quantity_kind,
+            multiplier,
+            offset,
+        }
+    }
+
+    ///
+    /// Return the IRI of this unit.
+    ///
+    pub fn iri(&self) -> &IRIRef {
+        &self.iri
+    }
+
+    ///
+    /// Return the IRI of the quantity kind measured by this unit, if known.
+    ///
+    pub fn quantity_kind(&self) -> Option<&IRIRef> {
+        self.quantity_kind.as_ref()
+    }
+
+    ///
+    /// Return the multiplier converting a value in this unit to the SI base unit.
+    ///
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    ///
+    /// Return the offset added to a value in this unit before applying the multiplier.
+    ///
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    fn unit_to_base(&self, value: f64) -> f64 {
+        (value + self.offset) * self.multiplier
+    }
+
+    fn base_to_unit(&self, value: f64) -> f64 {
+        value / self.multiplier - self.offset
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Default for UnitRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for (unit, kind, multiplier, offset) in common_units() {
+            let _ = registry.insert(Unit::new(
+                unit.clone(),
+                Some(kind.clone()),
+                multiplier,
+                offset,
+            ));
+        }
+        registry
+    }
+}
+
+impl UnitRegistry {
+    ///
+    /// Construct a new registry with no units.
+    ///
+    pub fn empty() -> Self {
+        Self {
+            units: Default::default(),
+        }
+    }
+
+    ///
+    /// Returns `true` if no units are known, else `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.units.is_empty()
+    }
+
+    ///
+    /// Return the number of units known.
+    ///
+    pub fn len(&self) -> usize {
+        self.units.len()
+    }
+
+    ///
+    /// Return the description of the unit `iri`, if known.
+    ///
+    pub fn get(&self, iri: &IRIRef) -> Option<&Unit> {
+        self.units.get(iri)
+    }
+
+    ///
+    /// Add the description of a unit, returning any description it replaces.
+    ///
+    pub fn insert(&mut self, unit: Unit) -> Option<Unit> {
+        self.units.insert(unit.iri.clone(), unit)
+    }
+
+    ///
+    /// Add the description of each unit in `graph` with a numeric `qudt:conversionMultiplier`,
+    /// returning the number of units added or replaced.
+    ///
+    pub fn add_graph(&mut self, graph: &dyn Graph) -> usize {
+        let units: Vec<Unit> = graph
+            .statements()
+            .filter(|statement| statement.predicate() == qudt::conversion_multiplier())
+            .filter_map(|statement| {
+                let iri = statement.subject().as_iri()?;
+                let multiplier = statement.object().as_literal()?.as_f64().ok()?;
+                let offset = number_for(graph, statement.subject(), qudt::conversion_offset());
+                let quantity_kind = graph
+                    .objects_for(statement.subject(), qudt::has_quantity_kind())
+                    .into_iter()
+                    .filter_map(|object| object.as_iri())
+                    .min()
+                    .cloned();
+                Some(Unit::new(
+                    iri.clone(),
+                    quantity_kind,
+                    multiplier,
+                    offset.unwrap_or_default(),
+                ))
+            })
+            .collect();
+        let count = units.len();
+        for unit in units {
+            let _ = self.insert(unit);
+        }
+        count
+    }
+
+    ///
+    /// Returns `true` if a quantity in unit `from` may be converted to unit `to`; either they are
+    /// the same unit, or both are known and measure the same quantity kind.
+    ///
+    pub fn is_compatible(&self, from: &IRIRef, to: &IRIRef) -> bool {
+        from == to
+            || match (self.get(from), self.get(to)) {
+                (Some(from), Some(to)) => {
+                    from.quantity_kind.is_some() && from.quantity_kind == to.quantity_kind
+                }
+                _ => false,
+            }
+    }
+
+    ///
+    /// Convert `quantity` to the unit `to`, returning the error `IncompatibleUnits` if the units
+    /// are not compatible.
+    ///
+    pub fn convert(&self, quantity: &Quantity, to: &IRIRef) -> Result<Quantity> {
+        if &quantity.unit == to {
+            Ok(quantity.clone())
+        } else if self.is_compatible(&quantity.unit, to) {
+            let from_unit = self.get(&quantity.unit).unwrap();
+            let to_unit = self.get(to).unwrap();
+            Ok(Quantity::new(
+                to_unit.base_to_unit(from_unit.unit_to_base(quantity.value)),
+                to.clone(),
+            ))
+        } else {
+            Err(ErrorKind::IncompatibleUnits(quantity.unit.to_string(), to.to_string()).into())
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn number_for(graph: &dyn Graph, subject: &SubjectNodeRef, predicate: &IRIRef) -> Option<f64> {
+    graph
+        .objects_for(subject, predicate)
+        .into_iter()
+        .filter_map(|object| object.as_literal())
+        .find_map(|literal| literal.as_f64().ok())
+}
+
+fn common_units() -> Vec<(&'static IRIRef, &'static IRIRef, f64, f64)> {
+    vec![
+        (unit::m(), kind::length(), 1.0, 0.0),
+        (unit::kilo_m(), kind::length(), 1000.0, 0.0),
+        (unit::centi_m(), kind::length(), 0.01, 0.0),
+        (unit::milli_m(), kind::length(), 0.001, 0.0),
+        (unit::micro_m(), kind::length(), 0.000001, 0.0),
+        (unit::inch(), kind::length(), 0.0254, 0.0),
+        (unit::ft(), kind::length(), 0.3048, 0.0),
+        (unit::yd(), kind::length(), 0.9144, 0.0),
+        (unit::mi(), kind::length(), 1609.344, 0.0),
+        (unit::kilo_gm(), kind::mass(), 1.0, 0.0),
+        (unit::gm(), kind::mass(), 0.001, 0.0),
+        (unit::milli_gm(), kind::mass(), 0.000001, 0.0),
+        (unit::tonne(), kind::mass(), 1000.0, 0.0),
+        (unit::lb(), kind::mass(), 0.45359237, 0.0),
+        (unit::sec(), kind::time(), 1.0, 0.0),
+        (unit::min(), kind::time(), 60.0, 0.0),
+        (unit::hr(), kind::time(), 3600.0, 0.0),
+        (unit::day(), kind::time(), 86400.0, 0.0),
+        (unit::k(), kind::thermodynamic_temperature(), 1.0, 0.0),
+        (
+            unit::deg_c(),
+            kind::thermodynamic_temperature(),
+            1.0,
+            273.15,
+        ),
+        (
+            unit::deg_f(),
+            kind::thermodynamic_temperature(),
+            5.0 / 9.0,
+            459.67,
+        ),
+        (unit::pa(), kind::pressure(), 1.0, 0.0),
+        (unit::kilo_pa(), kind::pressure(), 1000.0, 0.0),
+        (unit::mega_pa(), kind::pressure(), 1000000.0, 0.0),
+        (unit::bar(), kind::pressure(), 100000.0, 0.0),
+        (unit::psi(), kind::pressure(), 6894.757293168361, 0.0),
+        (unit::m2(), kind::area(), 1.0, 0.0),
+        (unit::m3(), kind::volume(), 1.0, 0.0),
+        (unit::l(), kind::volume(), 0.001, 0.0),
+        (unit::milli_l(), kind::volume(), 0.000001, 0.0),
+        (unit::j(), kind::energy(), 1.0, 0.0),
+        (unit::kilo_j(), kind::energy(), 1000.0, 0.0),
+        (unit::kilo_w_hr(), kind::energy(), 3600000.0, 0.0),
+        (unit::w(), kind::power(), 1.0, 0.0),
+        (unit::kilo_w(), kind::power(), 1000.0, 0.0),
+        (unit::mega_w(), kind::power(), 1000000.0, 0.0),
+        (unit::n(), kind::force(), 1.0, 0.0),
+        (unit::kilo_n(), kind::force(), 1000.0, 0.0),
+        (unit::m_per_sec(), kind::velocity(), 1.0, 0.0),
+        (
+            unit::kilo_m_per_hr(),
+            kind::velocity(),
+            1000.0 / 3600.0,
+            0.0,
+        ),
+        (unit::mi_per_hr(), kind::velocity(), 0.44704, 0.0),
+        (unit::hz(), kind::frequency(), 1.0, 0.0),
+        (unit::a(), kind::electric_current(), 1.0, 0.0),
+        (unit::v(), kind::voltage(), 1.0, 0.0),
+        (unit::unitless(), kind::dimensionless(), 1.0, 0.0),
+        (unit::percent(), kind::dimensionless(), 0.01, 0.0),
+    ]
+}
@@ -0,0 +1,1089 @@
+/*!
+Forward-chaining materialization of the RDFS, and OWL 2 RL, closure of a graph.
+
+`RdfsReasoner` implements the entailment rules of
+[RDF 1.1 Semantics](https://www.w3.org/TR/rdf11-mt/#patterns-of-rdfs-entailment-informative)
+that follow the schema of a graph; the transitivity of `rdfs:subClassOf` and
+`rdfs:subPropertyOf`, the inheritance of class membership and of property values through these,
+and the typing of subjects and objects by `rdfs:domain` and `rdfs:range`. The rules that only add
+axiomatic statements, such as every resource being an `rdfs:Resource`, are not implemented.
+
+`OwlRlReasoner` adds groups of rules from the
+[OWL 2 RL](https://www.w3.org/TR/owl2-profiles/#Reasoning_in_OWL_2_RL_and_RDF_Graphs_using_Rules)
+profile to these; inverse, symmetric, and transitive properties, property chains, equivalent
+classes and properties, and the equality of resources declared `owl:sameAs`. The rules that detect
+inconsistencies, and those that only add statements about the schema itself, are not implemented.
+
+Applications may add their own rules, implementing the `Rule` trait of `graph::inference`, to
+either reasoner with `with_rule`; these are applied alongside the built-in rules, and report their
+derivations using the name they provide.
+
+In either case the rules selected are applied by the inference engine of `graph::inference`, until
+no new statements are entailed, so that statements entailed by one rule, including those added by
+an application, are available to all others. Each rule is applied incrementally, to each statement
+as it becomes known, so that statements added to a graph whose closure has already been
+materialized can be reasoned over alone with `materialize_added`. Each new statement is returned as
+a `Derivation`, identifying the rule that produced it and the statements it was derived from; where
+a statement may be derived more than once only one derivation is reported.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::reasoner::RdfsReasoner;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, rdfs};
+use std::str::FromStr;
+
+let factory = statement_factory();
+let iri = |name: &str| IRIRef::from(IRI::from_str(&format!("http://example.org/{}", name)).unwrap());
+let graph = graph_factory().graph_from(
+    &[
+        factory
+            .statement(
+                factory.named_subject(iri("Cat")),
+                rdfs::subclass_of().clone(),
+                factory.named_object(iri("Animal")),
+            )
+            .unwrap(),
+        factory
+            .statement(
+                factory.named_subject(iri("tom")),
+                rdf::a_type().clone(),
+                factory.named_object(iri("Cat")),
+            )
+            .unwrap(),
+    ],
+    None,
+);
+
+let inferred = RdfsReasoner::default().materialize(&mut *graph.borrow_mut()).unwrap();
+assert_eq!(inferred.len(), 1);
+assert_eq!(inferred[0].rule(), "rdfs9");
+assert_eq!(inferred[0].premises().len(), 2);
+assert!(graph.borrow().contains_all(
+    &factory.named_subject(iri("tom")),
+    rdf::a_type(),
+    &factory.named_object(iri("Animal")),
+));
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::graph::inference::{infer, materialize, materialize_added, InferenceBudget};
+use crate::model::graph::Graph;
+use crate::model::statement::{ObjectNodeRef, StatementList, StatementRef, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use rdftk_names::{owl, rdf, rdfs};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+pub use crate::model::graph::inference::{Explanation, Rule, RuleContext, RuleRef};
+
+///
+/// The RDFS entailment rules implemented by `RdfsReasoner`, named as in RDF 1.1 Semantics.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RdfsRule {
+    /// `?p rdfs:domain ?c . ?s ?p ?o` entails `?s rdf:type ?c`.
+    Domain,
+    /// `?p rdfs:range ?c . ?s ?p ?o` entails `?o rdf:type ?c`, where `?o` is not a literal.
+    Range,
+    /// `?p rdfs:subPropertyOf ?q . ?q rdfs:subPropertyOf ?r` entails `?p rdfs:subPropertyOf ?r`.
+    SubPropertyTransitivity,
+    /// `?p rdfs:subPropertyOf ?q . ?s ?p ?o` entails `?s ?q ?o`.
+    SubPropertyInheritance,
+    /// `?c rdfs:subClassOf ?d . ?s rdf:type ?c` entails `?s rdf:type ?d`.
+    SubClassMembership,
+    /// `?c rdfs:subClassOf ?d . ?d rdfs:subClassOf ?e` entails `?c rdfs:subClassOf ?e`.
+    SubClassTransitivity,
+}
+
+///
+/// The groups of OWL 2 RL rules implemented by `OwlRlReasoner`, named as in OWL 2 Profiles.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum OwlRlRule {
+    /// `?p owl:inverseOf ?q . ?x ?p ?y` entails `?y ?q ?x`, and `?x ?q ?y` entails `?y ?p ?x`.
+    InverseOf,
+    /// `?p rdf:type owl:SymmetricProperty . ?x ?p ?y` entails `?y ?p ?x`.
+    SymmetricProperty,
+    /// `?p rdf:type owl:TransitiveProperty . ?x ?p ?y . ?y ?p ?z` entails `?x ?p ?z`.
+    TransitiveProperty,
+    /// `?p owl:propertyChainAxiom (?p1 ... ?pn) . ?u0 ?p1 ?u1 ... ?un-1 ?pn ?un` entails
+    /// `?u0 ?p ?un`.
+    PropertyChain,
+    /// `?c owl:equivalentClass ?d . ?x rdf:type ?c` entails `?x rdf:type ?d`, and the reverse.
+    EquivalentClass,
+    /// `?p owl:equivalentProperty ?q . ?x ?p ?y` entails `?x ?q ?y`, and the reverse.
+    EquivalentProperty,
+    /// `owl:sameAs` is symmetric and transitive, and `?x owl:sameAs ?y` entails a copy of each
+    /// statement using `?x`, as subject, predicate, or object, using `?y` instead.
+    SameAs,
+}
+
+///
+/// A statement derived by a reasoner, with the name of the rule that derived it and the
+/// statements it was derived from, schema statements first.
+///
+pub type Derivation = Explanation;
+
+///
+/// A reasoner that computes the closure of a graph under a set of `RdfsRule`s, and any rules added
+/// by an application; by default all `RdfsRule`s are enabled.
+///
+#[derive(Clone, Debug)]
+pub struct RdfsReasoner {
+    rules: HashSet<RdfsRule>,
+    custom: Vec<RuleRef>,
+}
+
+///
+/// A reasoner that computes the closure of a graph under a set of `OwlRlRule`s, as well as the
+/// `RdfsRule`s of an `RdfsReasoner`, and any rules added by an application; by default all
+/// built-in rules are enabled.
+///
+#[derive(Clone, Debug)]
+pub struct OwlRlReasoner {
+    rdfs: RdfsReasoner,
+    rules: HashSet<OwlRlRule>,
+    custom: Vec<RuleRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct RdfsRules(HashSet<RdfsRule>);
+
+#[derive(Debug)]
+struct OwlRlRules(HashSet<OwlRlRule>);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for RdfsRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl RdfsRule {
+    ///
+    /// Return all the rules implemented.
+    ///
+    pub fn all() -> &'static [RdfsRule] {
+        &[
+            Self::Domain,
+            Self::Range,
+            Self::SubPropertyTransitivity,
+            Self::SubPropertyInheritance,
+            Self::SubClassMembership,
+            Self::SubClassTransitivity,
+        ]
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Domain => "rdfs2",
+            Self::Range => "rdfs3",
+            Self::SubPropertyTransitivity => "rdfs5",
+            Self::SubPropertyInheritance => "rdfs7",
+            Self::SubClassMembership => "rdfs9",
+            Self::SubClassTransitivity => "rdfs11",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for OwlRlRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::InverseOf => "prp-inv",
+                Self::SymmetricProperty => "prp-symp",
+                Self::TransitiveProperty => "prp-trp",
+                Self::PropertyChain => "prp-spo2",
+                Self::EquivalentClass => "cax-eqc",
+                Self::EquivalentProperty => "prp-eqp",
+                Self::SameAs => "eq",
+            }
+        )
+    }
+}
+
+impl OwlRlRule {
+    ///
+    /// Return all the rule groups implemented.
+    ///
+    pub fn all() -> &'static [OwlRlRule] {
+        &[
+            Self::InverseOf,
+            Self::SymmetricProperty,
+            Self::TransitiveProperty,
+            Self::PropertyChain,
+            Self::EquivalentClass,
+            Self::EquivalentProperty,
+            Self::SameAs,
+        ]
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Default for RdfsReasoner {
+    fn default() -> Self {
+        Self::with_rules(RdfsRule::all())
+    }
+}
+
+impl PartialEq for RdfsReasoner {
+    fn eq(&self, other: &Self) -> bool {
+        self.rules == other.rules && same_rules(&self.custom, &other.custom)
+    }
+}
+
+impl Eq for RdfsReasoner {}
+
+impl RdfsReasoner {
+    ///
+    /// Construct a new reasoner that applies only the provided rules.
+    ///
+    pub fn with_rules(rules: &[RdfsRule]) -> Self {
+        Self {
+            rules: rules.iter().copied().collect(),
+            custom: Default::default(),
+        }
+    }
+
+    ///
+    /// Add `rule` to the rules applied by this reasoner.
+    ///
+    pub fn with_rule(mut self, rule: RuleRef) -> Self {
+        self.custom.push(rule);
+        self
+    }
+
+    ///
+    /// Returns `true` if `rule` is applied by this reasoner.
+    ///
+    pub fn is_enabled(&self, rule: RdfsRule) -> bool {
+        self.rules.contains(&rule)
+    }
+
+    ///
+    /// Return the rules added to this reasoner by an application.
+    ///
+    pub fn custom_rules(&self) -> &[RuleRef] {
+        &self.custom
+    }
+
+    ///
+    /// Return all the rules applied by this reasoner, built-in rules first, as rules of the
+    /// inference engine.
+    ///
+    pub fn rules(&self) -> Vec<RuleRef> {
+        let mut rules: Vec<RuleRef> = vec![Rc::new(RdfsRules(self.rules.clone()))];
+        rules.extend(self.custom.iter().cloned());
+        rules
+    }
+
+    ///
+    /// Return a derivation for each statement entailed by `graph` that is not already in it,
+    /// without modifying the graph.
+    ///
+    pub fn derivations(&self, graph: &dyn Graph) -> Result<Vec<Derivation>> {
+        derivations(graph, &self.rules())
+    }
+
+    ///
+    /// Return the statements entailed by `graph` that are not already in it, without modifying
+    /// the graph.
+    ///
+    pub fn entailments(&self, graph: &dyn Graph) -> Result<StatementList> {
+        Ok(statements(self.derivations(graph)?))
+    }
+
+    ///
+    /// Insert, into `graph`, the statements entailed by it that are not already in it, returning
+    /// a derivation for each statement inserted.
+    ///
+    pub fn materialize(&self, graph: &mut dyn Graph) -> Result<Vec<Derivation>> {
+        Ok(
+            materialize(graph, &self.rules(), &InferenceBudget::default())?
+                .explanations()
+                .to_vec(),
+        )
+    }
+
+    ///
+    /// Insert `added` into `graph`, whose closure has already been materialized by this reasoner,
+    /// and then the statements entailed by the additions, returning a derivation for each
+    /// statement inferred.
+    ///
+    pub fn materialize_added(
+        &self,
+        graph: &mut dyn Graph,
+        added: &[StatementRef],
+    ) -> Result<Vec<Derivation>> {
+        materialize_derivations(graph, added, &self.rules())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Default for OwlRlReasoner {
+    fn default() -> Self {
+        Self::with_rules(RdfsRule::all(), OwlRlRule::all())
+    }
+}
+
+impl PartialEq for OwlRlReasoner {
+    fn eq(&self, other: &Self) -> bool {
+        self.rdfs == other.rdfs
+            && self.rules == other.rules
+            && same_rules(&self.custom, &other.custom)
+    }
+}
+
+impl Eq for OwlRlReasoner {}
+
+impl OwlRlReasoner {
+    ///
+    /// Construct a new reasoner that applies only the provided RDFS rules and OWL 2 RL rule
+    /// groups.
+    ///
+    pub fn with_rules(rdfs: &[RdfsRule], rules: &[OwlRlRule]) -> Self {
+        Self {
+            rdfs: RdfsReasoner::with_rules(rdfs),
+            rules: rules.iter().copied().collect(),
+            custom: Default::default(),
+        }
+    }
+
+    ///
+    /// Add `rule` to the rules applied by this reasoner.
+    ///
+    pub fn with_rule(mut self, rule: RuleRef) -> Self {
+        self.custom.push(rule);
+        self
+    }
+
+    ///
+    /// Returns `true` if the rule group `rule` is applied by this reasoner.
+    ///
+    pub fn is_enabled(&self, rule: OwlRlRule) -> bool {
+        self.rules.contains(&rule)
+    }
+
+    ///
+    /// Return the rules added to this reasoner by an application.
+    ///
+    pub fn custom_rules(&self) -> &[RuleRef] {
+        &self.custom
+    }
+
+    ///
+    /// Return the reasoner used for the RDFS rules applied by this reasoner.
+    ///
+    pub fn rdfs(&self) -> &RdfsReasoner {
+        &self.rdfs
+    }
+
+    ///
+    /// Return all the rules applied by this reasoner, those of its RDFS reasoner first, as rules
+    /// of the inference engine.
+    ///
+    pub fn rules(&self) -> Vec<RuleRef> {
+        let mut rules = self.rdfs.rules();
+        rules.push(Rc::new(OwlRlRules(self.rules.clone())));
+        rules.extend(self.custom.iter().cloned());
+        rules
+    }
+
+    ///
+    /// Return a derivation for each statement entailed by `graph` that is not already in it,
+    /// without modifying the graph.
+    ///
+    pub fn derivations(&self, graph: &dyn Graph) -> Result<Vec<Derivation>> {
+        derivations(graph, &self.rules())
+    }
+
+    ///
+    /// Return the statements entailed by `graph` that are not already in it, without modifying
+    /// the graph.
+    ///
+    pub fn entailments(&self, graph: &dyn Graph) -> Result<StatementList> {
+        Ok(statements(self.derivations(graph)?))
+    }
+
+    ///
+    /// Insert, into `graph`, the statements entailed by it that are not already in it, returning
+    /// a derivation for each statement inserted.
+    ///
+    pub fn materialize(&self, graph: &mut dyn Graph) -> Result<Vec<Derivation>> {
+        Ok(
+            materialize(graph, &self.rules(), &InferenceBudget::default())?
+                .explanations()
+                .to_vec(),
+        )
+    }
+
+    ///
+    /// Insert `added` into `graph`, whose closure has already been materialized by this reasoner,
+    /// and then the statements entailed by the additions, returning a derivation for each
+    /// statement inferred.
+    ///
+    pub fn materialize_added(
+        &self,
+        graph: &mut dyn Graph,
+        added: &[StatementRef],
+    ) -> Result<Vec<Derivation>> {
+        materialize_derivations(graph, added, &self.rules())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Rule for RdfsRules {
+    fn name(&self) -> &str {
+        "rdfs"
+    }
+
+    fn apply(&self, statement: &StatementRef, context: &mut RuleContext<'_>) -> Result<()> {
+        let factory = context.statement_factory().clone();
+        let subject = statement.subject();
+        let predicate = statement.predicate();
+        let object = statement.object();
+        let subject_object = factory.subject_as_object(subject.clone());
+        let resource = factory.object_as_subject(object.clone());
+        let property = factory.named_subject(predicate.clone());
+        let enabled = |rule: RdfsRule| self.0.contains(&rule);
+
+        if enabled(RdfsRule::Domain) {
+            let rule = RdfsRule::Domain.name();
+            for axiom in context.values(rdfs::domain(), &property).to_vec() {
+                context.derive_as(
+                    rule,
+                    subject.clone(),
+                    rdf::a_type(),
+                    axiom.object().clone(),
+                    &[&axiom, statement],
+                )?;
+            }
+            if let (true, Some(property)) = (predicate == rdfs::domain(), subject.as_iri()) {
+                for data in context.with_predicate(property).to_vec() {
+                    context.derive_as(
+                        rule,
+                        data.subject().clone(),
+                        rdf::a_type(),
+                        object.clone(),
+                        &[statement, &data],
+                    )?;
+                }
+            }
+        }
+        if enabled(RdfsRule::Range) {
+            let rule = RdfsRule::Range.name();
+            if let Some(resource) = &resource {
+                for axiom in context.values(rdfs::range(), &property).to_vec() {
+                    context.derive_as(
+                        rule,
+                        resource.clone(),
+                        rdf::a_type(),
+                        axiom.object().clone(),
+                        &[&axiom, statement],
+                    )?;
+                }
+            }
+            if let (true, Some(property)) = (predicate == rdfs::range(), subject.as_iri()) {
+                for data in context.with_predicate(property).to_vec() {
+                    if let Some(resource) = factory.object_as_subject(data.object().clone()) {
+                        context.derive_as(
+                            rule,
+                            resource,
+                            rdf::a_type(),
+                            object.clone(),
+                            &[statement, &data],
+                        )?;
+                    }
+                }
+            }
+        }
+        if enabled(RdfsRule::SubPropertyInheritance) {
+            let rule = RdfsRule::SubPropertyInheritance.name();
+            for axiom in context.values(rdfs::subproperty_of(), &property).to_vec() {
+                if let Some(super_property) = axiom.object().as_iri() {
+                    context.derive_as(
+                        rule,
+                        subject.clone(),
+                        super_property,
+                        object.clone(),
+                        &[&axiom, statement],
+                    )?;
+                }
+            }
+            if let (true, Some(property), Some(super_property)) = (
+                predicate == rdfs::subproperty_of(),
+                subject.as_iri(),
+                object.as_iri(),
+            ) {
+                for data in context.with_predicate(property).to_vec() {
+                    context.derive_as(
+                        rule,
+                        data.subject().clone(),
+                        super_property,
+                        data.object().clone(),
+                        &[statement, &data],
+                    )?;
+                }
+            }
+        }
+        if enabled(RdfsRule::SubPropertyTransitivity) && predicate == rdfs::subproperty_of() {
+            let rule = RdfsRule::SubPropertyTransitivity.name();
+            if let (Some(_), Some(super_property)) = (subject.as_iri(), object.as_iri()) {
+                let super_property = factory.named_subject(super_property.clone());
+                for axiom in context.values(predicate, &super_property).to_vec() {
+                    if axiom.object().is_iri() {
+                        context.derive_as(
+                            rule,
+                            subject.clone(),
+                            predicate,
+                            axiom.object().clone(),
+                            &[statement, &axiom],
+                        )?;
+                    }
+                }
+                for axiom in context.referrers(predicate, &subject_object).to_vec() {
+                    if axiom.subject().is_iri() {
+                        context.derive_as(
+                            rule,
+                            axiom.subject().clone(),
+                            predicate,
+                            object.clone(),
+                            &[&axiom, statement],
+                        )?;
+                    }
+                }
+            }
+        }
+        if enabled(RdfsRule::SubClassMembership) {
+            let rule = RdfsRule::SubClassMembership.name();
+            if let (true, Some(class)) = (predicate == rdf::a_type(), &resource) {
+                for axiom in context.values(rdfs::subclass_of(), class).to_vec() {
+                    context.derive_as(
+                        rule,
+                        subject.clone(),
+                        predicate,
+                        axiom.object().clone(),
+                        &[&axiom, statement],
+                    )?;
+                }
+            }
+            if predicate == rdfs::subclass_of() {
+                for data in context.referrers(rdf::a_type(), &subject_object).to_vec() {
+                    context.derive_as(
+                        rule,
+                        data.subject().clone(),
+                        rdf::a_type(),
+                        object.clone(),
+                        &[statement, &data],
+                    )?;
+                }
+            }
+        }
+        if enabled(RdfsRule::SubClassTransitivity) && predicate == rdfs::subclass_of() {
+            let rule = RdfsRule::SubClassTransitivity.name();
+            if let Some(class) = &resource {
+                for axiom in context.values(predicate, class).to_vec() {
+                    context.derive_as(
+                        rule,
+                        subject.clone(),
+                        predicate,
+                        axiom.object().clone(),
+                        &[statement, &axiom],
+                    )?;
+                }
+            }
+            for axiom in context.referrers(predicate, &subject_object).to_vec() {
+                context.derive_as(
+                    rule,
+                    axiom.subject().clone(),
+                    predicate,
+                    object.clone(),
+                    &[&axiom, statement],
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Rule for OwlRlRules {
+    fn name(&self) -> &str {
+        "owl-rl"
+    }
+
+    fn apply(&self, statement: &StatementRef, context: &mut RuleContext<'_>) -> Result<()> {
+        let factory = context.statement_factory().clone();
+        let subject = statement.subject();
+        let predicate = statement.predicate();
+        let object = statement.object();
+        let reversed = factory.object_as_subject(object.clone());
+        let subject_object = factory.subject_as_object(subject.clone());
+        let property = factory.named_subject(predicate.clone());
+        let property_object = factory.named_object(predicate.clone());
+        let enabled = |rule: OwlRlRule| self.0.contains(&rule);
+
+        if enabled(OwlRlRule::InverseOf) {
+            if let Some(reversed) = &reversed {
+                let mut axioms: Vec<(StatementRef, Option<&IRIRef>)> = Default::default();
+                let values = context.values(owl::inverse_of(), &property).to_vec();
+                let referrers = context
+                    .referrers(owl::inverse_of(), &property_object)
+                    .to_vec();
+                axioms.extend(
+                    values
+                        .iter()
+                        .map(|axiom| (axiom.clone(), axiom.object().as_iri())),
+                );
+                axioms.extend(
+                    referrers
+                        .iter()
+                        .map(|axiom| (axiom.clone(), axiom.subject().as_iri())),
+                );
+                for (axiom, inverse) in axioms {
+                    if let Some(inverse) = inverse {
+                        context.derive_as(
+                            "prp-inv",
+                            reversed.clone(),
+                            inverse,
+                            subject_object.clone(),
+                            &[&axiom, statement],
+                        )?;
+                    }
+                }
+            }
+            if let (true, Some(lhs), Some(rhs)) = (
+                predicate == owl::inverse_of(),
+                subject.as_iri(),
+                object.as_iri(),
+            ) {
+                for (property, inverse) in [(lhs, rhs), (rhs, lhs)] {
+                    for data in context.with_predicate(property).to_vec() {
+                        if let Some(reversed) = factory.object_as_subject(data.object().clone()) {
+                            context.derive_as(
+                                "prp-inv",
+                                reversed,
+                                inverse,
+                                factory.subject_as_object(data.subject().clone()),
+                                &[statement, &data],
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+        if enabled(OwlRlRule::SymmetricProperty) {
+            if let Some(reversed) = &reversed {
+                for axiom in declarations(context, predicate, owl::symmetric_property()) {
+                    context.derive_as(
+                        "prp-symp",
+                        reversed.clone(),
+                        predicate,
+                        subject_object.clone(),
+                        &[&axiom, statement],
+                    )?;
+                }
+            }
+            if let Some(property) = declared(statement, owl::symmetric_property()) {
+                for data in context.with_predicate(property).to_vec() {
+                    if let Some(reversed) = factory.object_as_subject(data.object().clone()) {
+                        context.derive_as(
+                            "prp-symp",
+                            reversed,
+                            property,
+                            factory.subject_as_object(data.subject().clone()),
+                            &[statement, &data],
+                        )?;
+                    }
+                }
+            }
+        }
+        if enabled(OwlRlRule::TransitiveProperty) {
+            for axiom in declarations(context, predicate, owl::transitive_property()) {
+                if let Some(next) = &reversed {
+                    for following in context.values(predicate, next).to_vec() {
+                        context.derive_as(
+                            "prp-trp",
+                            subject.clone(),
+                            predicate,
+                            following.object().clone(),
+                            &[&axiom, statement, &following],
+                        )?;
+                    }
+                }
+                for preceding in context.referrers(predicate, &subject_object).to_vec() {
+                    context.derive_as(
+                        "prp-trp",
+                        preceding.subject().clone(),
+                        predicate,
+                        object.clone(),
+                        &[&axiom, &preceding, statement],
+                    )?;
+                }
+            }
+            if let Some(property) = declared(statement, owl::transitive_property()) {
+                for first in context.with_predicate(property).to_vec() {
+                    if let Some(next) = factory.object_as_subject(first.object().clone()) {
+                        for following in context.values(property, &next).to_vec() {
+                            context.derive_as(
+                                "prp-trp",
+                                first.subject().clone(),
+                                property,
+                                following.object().clone(),
+                                &[statement, &first, &following],
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+        if enabled(OwlRlRule::PropertyChain) {
+            for axiom in context.with_predicate(owl::property_chain_axiom()).to_vec() {
+                let property = match axiom.subject().as_iri() {
+                    Some(property) => property,
+                    None => continue,
+                };
+                let chain = list(context, axiom.object());
+                let mut paths: Vec<Vec<StatementRef>> = Default::default();
+                if &axiom == statement {
+                    if let Some(first) = chain.first() {
+                        for start in context.with_predicate(first) {
+                            paths.extend(chain_paths(context, &chain, 0, start));
+                        }
+                    }
+                }
+                for (position, link) in chain.iter().enumerate() {
+                    if link == predicate {
+                        paths.extend(chain_paths(context, &chain, position, statement));
+                    }
+                }
+                for path in paths {
+                    let premises: Vec<&StatementRef> =
+                        std::iter::once(&axiom).chain(path.iter()).collect();
+                    context.derive_as(
+                        "prp-spo2",
+                        path.first().unwrap().subject().clone(),
+                        property,
+                        path.last().unwrap().object().clone(),
+                        &premises,
+                    )?;
+                }
+            }
+        }
+        if enabled(OwlRlRule::EquivalentClass) {
+            if let (true, Some(class)) = (predicate == rdf::a_type(), &reversed) {
+                for axiom in context.values(owl::equivalent_class(), class).to_vec() {
+                    context.derive_as(
+                        "cax-eqc",
+                        subject.clone(),
+                        predicate,
+                        axiom.object().clone(),
+                        &[&axiom, statement],
+                    )?;
+                }
+                for axiom in context.referrers(owl::equivalent_class(), object).to_vec() {
+                    context.derive_as(
+                        "cax-eqc",
+                        subject.clone(),
+                        predicate,
+                        factory.subject_as_object(axiom.subject().clone()),
+                        &[&axiom, statement],
+                    )?;
+                }
+            }
+            if predicate == owl::equivalent_class() {
+                for (class, equivalent) in [(&subject_object, object), (object, &subject_object)] {
+                    for data in context.referrers(rdf::a_type(), class).to_vec() {
+                        context.derive_as(
+                            "cax-eqc",
+                            data.subject().clone(),
+                            rdf::a_type(),
+                            equivalent.clone(),
+                            &[statement, &data],
+                        )?;
+                    }
+                }
+            }
+        }
+        if enabled(OwlRlRule::EquivalentProperty) {
+            let values = context
+                .values(owl::equivalent_property(), &property)
+                .to_vec();
+            let referrers = context
+                .referrers(owl::equivalent_property(), &property_object)
+                .to_vec();
+            for (axiom, equivalent) in values
+                .iter()
+                .map(|axiom| (axiom, axiom.object().as_iri()))
+                .chain(
+                    referrers
+                        .iter()
+                        .map(|axiom| (axiom, axiom.subject().as_iri())),
+                )
+            {
+                if let Some(equivalent) = equivalent {
+                    context.derive_as(
+                        "prp-eqp",
+                        subject.clone(),
+                        equivalent,
+                        object.clone(),
+                        &[axiom, statement],
+                    )?;
+                }
+            }
+            if let (true, Some(lhs), Some(rhs)) = (
+                predicate == owl::equivalent_property(),
+                subject.as_iri(),
+                object.as_iri(),
+            ) {
+                for (property, equivalent) in [(lhs, rhs), (rhs, lhs)] {
+                    for data in context.with_predicate(property).to_vec() {
+                        context.derive_as(
+                            "prp-eqp",
+                            data.subject().clone(),
+                            equivalent,
+                            data.object().clone(),
+                            &[statement, &data],
+                        )?;
+                    }
+                }
+            }
+        }
+        if enabled(OwlRlRule::SameAs) {
+            // the statement as one in which a resource is replaced,
+            for axiom in context.values(owl::same_as(), subject).to_vec() {
+                if let Some(same) = factory.object_as_subject(axiom.object().clone()) {
+                    context.derive_as(
+                        "eq-rep-s",
+                        same,
+                        predicate,
+                        object.clone(),
+                        &[&axiom, statement],
+                    )?;
+                }
+            }
+            for axiom in context.values(owl::same_as(), &property).to_vec() {
+                if let Some(same) = axiom.object().as_iri() {
+                    context.derive_as(
+                        "eq-rep-p",
+                        subject.clone(),
+                        same,
+                        object.clone(),
+                        &[&axiom, statement],
+                    )?;
+                }
+            }
+            if let Some(resource) = &reversed {
+                for axiom in context.values(owl::same_as(), resource).to_vec() {
+                    context.derive_as(
+                        replace_object_rule(predicate),
+                        subject.clone(),
+                        predicate,
+                        axiom.object().clone(),
+                        &[statement, &axiom],
+                    )?;
+                }
+            }
+            // and as the declaration of the equality.
+            if predicate == owl::same_as() {
+                if let Some(reversed) = &reversed {
+                    context.derive_as(
+                        "eq-sym",
+                        reversed.clone(),
+                        predicate,
+                        subject_object.clone(),
+                        &[statement],
+                    )?;
+                    for data in context.with_subject(subject).to_vec() {
+                        context.derive_as(
+                            "eq-rep-s",
+                            reversed.clone(),
+                            data.predicate(),
+                            data.object().clone(),
+                            &[statement, &data],
+                        )?;
+                    }
+                }
+                if let (Some(property), Some(same)) = (subject.as_iri(), object.as_iri()) {
+                    for data in context.with_predicate(property).to_vec() {
+                        context.derive_as(
+                            "eq-rep-p",
+                            data.subject().clone(),
+                            same,
+                            data.object().clone(),
+                            &[statement, &data],
+                        )?;
+                    }
+                }
+                for data in context.with_object(&subject_object).to_vec() {
+                    context.derive_as(
+                        replace_object_rule(data.predicate()),
+                        data.subject().clone(),
+                        data.predicate(),
+                        object.clone(),
+                        &[&data, statement],
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn same_rules(lhs: &[RuleRef], rhs: &[RuleRef]) -> bool {
+    lhs.len() == rhs.len() && lhs.iter().zip(rhs).all(|(lhs, rhs)| Rc::ptr_eq(lhs, rhs))
+}
+
+fn derivations(graph: &dyn Graph, rules: &[RuleRef]) -> Result<Vec<Derivation>> {
+    Ok(infer(graph, rules, &InferenceBudget::default())?
+        .explanations()
+        .to_vec())
+}
+
+fn materialize_derivations(
+    graph: &mut dyn Graph,
+    added: &[StatementRef],
+    rules: &[RuleRef],
+) -> Result<Vec<Derivation>> {
+    Ok(
+        materialize_added(graph, added, rules, &InferenceBudget::default())?
+            .explanations()
+            .to_vec(),
+    )
+}
+
+fn statements(derivations: Vec<Derivation>) -> StatementList {
+    derivations
+        .into_iter()
+        .map(|derivation| derivation.statement().clone())
+        .collect()
+}
+
+fn declarations(context: &RuleContext<'_>, property: &IRIRef, class: &IRIRef) -> Vec<StatementRef> {
+    let property = context.statement_factory().named_subject(property.clone());
+    context
+        .values(rdf::a_type(), &property)
+        .iter()
+        .filter(|axiom| axiom.object().as_iri() == Some(class))
+        .cloned()
+        .collect()
+}
+
+fn declared<'a>(statement: &'a StatementRef, class: &IRIRef) -> Option<&'a IRIRef> {
+    if statement.predicate() == rdf::a_type() && statement.object().as_iri() == Some(class) {
+        statement.subject().as_iri()
+    } else {
+        None
+    }
+}
+
+fn replace_object_rule(predicate: &IRIRef) -> &'static str {
+    if predicate == owl::same_as() {
+        "eq-trans"
+    } else {
+        "eq-rep-o"
+    }
+}
+
+fn list(context: &RuleContext<'_>, head: &ObjectNodeRef) -> Vec<IRIRef> {
+    let factory = context.statement_factory();
+    let mut members: Vec<IRIRef> = Default::default();
+    let mut visited: HashSet<SubjectNodeRef> = Default::default();
+    let mut current = factory.object_as_subject(head.clone());
+    while let Some(cell) = current {
+        if !visited.insert(cell.clone()) {
+            break;
+        }
+        match context.values(rdf::first(), &cell).first() {
+            Some(first) => match first.object().as_iri() {
+                Some(member) => members.push(member.clone()),
+                None => return Default::default(),
+            },
+            None => break,
+        }
+        current = context
+            .values(rdf::rest(), &cell)
+            .first()
+            .filter(|rest| rest.object().as_iri() != Some(rdf::nil()))
+            .and_then(|rest| factory.object_as_subject(rest.object().clone()));
+    }
+    members
+}
+
+fn chain_paths(
+    context: &RuleContext<'_>,
+    chain: &[IRIRef],
+    position: usize,
+    statement: &StatementRef,
+) -> Vec<Vec<StatementRef>> {
+    let factory = context.statement_factory();
+    let mut paths: Vec<Vec<StatementRef>> = vec![vec![statement.clone()]];
+    for link in chain[..position].iter().rev() {
+        paths = paths
+            .into_iter()
+            .flat_map(|path| {
+                let start = factory.subject_as_object(path[0].subject().clone());
+                context
+                    .referrers(link, &start)
+                    .iter()
+                    .map(|preceding| {
+                        let mut extended = vec![preceding.clone()];
+                        extended.extend(path.iter().cloned());
+                        extended
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+    }
+    for link in &chain[position + 1..] {
+        paths = paths
+            .into_iter()
+            .flat_map(|path| {
+                match factory.object_as_subject(path[path.len() - 1].object().clone()) {
+                    Some(end) => context
+                        .values(link, &end)
+                        .iter()
+                        .map(|following| {
+                            let mut extended = path.clone();
+                            extended.push(following.clone());
+                            extended
+                        })
+                        .collect::<Vec<_>>(),
+                    None => Default::default(),
+                }
+            })
+            .collect();
+    }
+    paths
+}
@@ -3,14 +3,47 @@ Support for the Skolemization process of a graph. For more details on the proces
 [Skolemization (Informative)](https://www.w3.org/TR/rdf11-mt/#skolemization-informative)
 and [Replacing Blank Nodes with IRIs](https://www.w3.org/TR/rdf11-concepts/#section-skolemization).
 
+# Example
+
+```rust
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let graph = graph_factory().graph_from(
+    &[factory
+        .statement(
+            factory.blank_subject_named("alice").unwrap(),
+            foaf::knows().clone(),
+            factory.blank_object_named("bob").unwrap(),
+        )
+        .unwrap()],
+    None,
+);
+
+let base = IRIRef::from(IRI::from_str("http://example.org/").unwrap());
+let skolemized = graph.borrow().skolemize(&base).unwrap();
+let skolemized = skolemized.borrow();
+let statement = skolemized.statements().next().unwrap();
+assert!(statement
+    .subject()
+    .as_iri()
+    .unwrap()
+    .to_string()
+    .starts_with("http://example.org/.well-known/genid/"));
+assert!(statement.object().is_iri());
+```
+
 */
 
-use crate::error::{Error, ErrorKind};
+use crate::error::Error;
 use crate::model::graph::{Graph, GraphRef};
-use crate::model::statement::StatementRef;
 use rdftk_iri::{IRIRef, IRI};
 use std::collections::HashMap;
-use std::rc::Rc;
 
 // ------------------------------------------------------------------------------------------------
 // Public Functions
@@ -21,38 +54,30 @@ use std::rc::Rc;
 /// graph unchanged. The base IRI is used to create identifiers, it's path will be replaced
 /// entirely by a well-known format.
 ///
-pub fn skolemize(graph: &impl Graph, base: &IRIRef) -> Result<GraphRef, Error> {
+pub fn skolemize(graph: &(impl Graph + ?Sized), base: &IRIRef) -> Result<GraphRef, Error> {
     let mut mapping: HashMap<String, IRIRef> = Default::default();
+    let mut skolem_iri = |blank: &String| -> Result<IRIRef, Error> {
+        if !mapping.contains_key(blank) {
+            let _ = mapping.insert(blank.clone(), IRIRef::new(IRI::new_genid(base)?));
+        }
+        Ok(mapping.get(blank).unwrap().clone())
+    };
 
-    let factory = graph.factory();
+    let factory = graph.statement_factory();
 
-    let new_graph = factory.graph();
+    let new_graph = graph.factory().graph();
 
     for statement in graph.statements() {
-        let factory = graph.statement_factory();
-        let mut new_statement: StatementRef = statement.clone();
-        let mut_statement = match Rc::get_mut(&mut new_statement) {
-            None => return Err(ErrorKind::InvalidState.into()),
-            Some(st) => st,
+        let subject = match statement.subject().as_blank() {
+            Some(blank) => factory.named_subject(skolem_iri(blank)?),
+            None => statement.subject().clone(),
+        };
+        let object = match statement.object().as_blank() {
+            Some(blank) => factory.named_object(skolem_iri(blank)?),
+            None => statement.object().clone(),
         };
-        if let Some(blank) = mut_statement.subject().as_blank() {
-            if !mapping.contains_key(blank) {
-                let _ = mapping.insert(blank.clone(), IRIRef::new(IRI::new_genid(base)?));
-            }
-            let name = mapping.get(blank).unwrap().clone();
-            let subject = factory.named_subject(name);
-            mut_statement.set_subject(subject);
-        }
-        if let Some(blank) = mut_statement.object().as_blank() {
-            if !mapping.contains_key(blank) {
-                let _ = mapping.insert(blank.clone(), IRIRef::new(IRI::new_genid(base)?));
-            }
-            let name = mapping.get(blank).unwrap().clone();
-            let object = factory.named_object(name);
-            mut_statement.set_object(object);
-        }
         let mut mut_graph = new_graph.borrow_mut();
-        mut_graph.insert(new_statement);
+        mut_graph.insert(factory.statement(subject, statement.predicate().clone(), object)?);
     }
 
     Ok(new_graph)
@@ -0,0 +1,542 @@
+/*!
+Statistics describing the content of a graph: the number of statements, the number of distinct
+subjects, predicates, and objects, and histograms of the use of each class and property. These
+may be published as a [VoID](https://www.w3.org/TR/void/) description of the graph, or compared
+over time to monitor the data loaded into a graph.
+
+Class usage is the number of distinct instances of each class, that is the number of statements
+`?s rdf:type C` for each IRI `C`; property usage is the number of statements using each predicate.
+In a VoID description these are written as the `void:entities` of each `void:classPartition`,
+and the `void:triples` of each `void:propertyPartition`, respectively.
+
+Statistics may also be collected as statements are read or loaded, rather than in a second pass
+over a complete graph, by any implementation of `StatisticsCollector`; this module provides
+`PredicateHistogram`, `NamespaceCounts`, and `LiteralLengths`, and any closure taking a statement
+reference is also a collector. Collectors are given statements by `collect_statements`, or by the
+decorator `CollectingGraph` as each statement is inserted, see the `collecting` module.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::stats::GraphStatistics;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{foaf, rdf};
+use std::str::FromStr;
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let graph = graph_factory().graph_from(
+    &[
+        factory
+            .statement(alice.clone(), rdf::a_type().clone(), factory.named_object(foaf::person().clone()))
+            .unwrap(),
+        factory
+            .statement(alice, foaf::name().clone(), factory.literal_object(literal_factory().literal("Alice")))
+            .unwrap(),
+    ],
+    None,
+);
+
+let statistics = GraphStatistics::from(&*graph.borrow());
+assert_eq!(statistics.triples(), 2);
+assert_eq!(statistics.distinct_subjects(), 1);
+assert_eq!(statistics.class_count(foaf::person()), 1);
+assert_eq!(statistics.property_count(foaf::name()), 1);
+
+let dataset = IRIRef::from(IRI::from_str("http://example.org/dataset").unwrap());
+let void = statistics.to_void(&dataset, &graph_factory()).unwrap();
+assert!(!void.borrow().is_empty());
+```
+
+Collecting statistics from statements as they are produced:
+
+```rust
+use rdftk_core::model::graph::stats::{
+    collect_statements, LiteralLengths, NamespaceCounts, PredicateHistogram,
+};
+use rdftk_core::model::statement::StatementRef;
+# use rdftk_core::simple::literal::literal_factory;
+# use rdftk_core::simple::statement::statement_factory;
+# use rdftk_iri::{IRIRef, IRI};
+# use rdftk_names::foaf;
+# use std::str::FromStr;
+# let factory = statement_factory();
+# let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+# let statements = vec![factory
+#     .statement(alice, foaf::name().clone(), factory.literal_object(literal_factory().literal("Alice")))
+#     .unwrap()];
+
+let mut predicates = PredicateHistogram::default();
+let mut namespaces = NamespaceCounts::default();
+let mut lengths = LiteralLengths::default();
+let mut total = 0;
+let mut count = |_: &StatementRef| total += 1;
+collect_statements(
+    &statements,
+    &mut [&mut predicates, &mut namespaces, &mut lengths, &mut count],
+);
+
+assert_eq!(total, 1);
+assert_eq!(predicates.count(foaf::name()), 1);
+assert_eq!(namespaces.count(foaf::namespace_iri()), 1);
+assert_eq!(lengths.max(), Some(5));
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::graph::mapping::split_iri;
+use crate::model::graph::{Graph, GraphFactoryRef, GraphRef};
+use crate::model::literal::DataType;
+use crate::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use rdftk_names::{rdf, void};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Statistics computed from the statements of a graph; see the module documentation.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GraphStatistics {
+    triples: usize,
+    distinct_subjects: usize,
+    distinct_predicates: usize,
+    distinct_objects: usize,
+    classes: BTreeMap<IRIRef, usize>,
+    properties: BTreeMap<IRIRef, usize>,
+}
+
+///
+/// Collects statistics from statements as they are read or loaded; see the module documentation.
+///
+pub trait StatisticsCollector {
+    ///
+    /// Add `statement` to the statistics collected so far.
+    ///
+    fn observe(&mut self, statement: &StatementRef);
+}
+
+///
+/// A shared reference to a collector, so that its statistics remain available to the caller
+/// while statements are given to it by a graph or reader.
+///
+pub type StatisticsCollectorRef = Rc<RefCell<dyn StatisticsCollector>>;
+
+///
+/// The number of statements using each predicate.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PredicateHistogram {
+    counts: BTreeMap<IRIRef, usize>,
+}
+
+///
+/// The number of uses of each namespace, by the IRIs in the subject, predicate, and object of each
+/// statement; the namespace of an IRI is determined by `mapping::split_iri`.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NamespaceCounts {
+    counts: BTreeMap<IRIRef, usize>,
+}
+
+///
+/// The distribution of the lengths, in characters, of the lexical forms of literal objects.
+/// Lengths are counted in buckets by powers of two, the first bucket holding empty literals, the
+/// second those of length 1, the third of lengths 2 and 3, and so on.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LiteralLengths {
+    count: usize,
+    total: usize,
+    min: Option<usize>,
+    max: Option<usize>,
+    buckets: Vec<usize>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Give each of `statements`, in turn, to each of `collectors`.
+///
+pub fn collect_statements<'a>(
+    statements: impl IntoIterator<Item = &'a StatementRef>,
+    collectors: &mut [&mut dyn StatisticsCollector],
+) {
+    for statement in statements {
+        for collector in collectors.iter_mut() {
+            collector.observe(statement);
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<&dyn Graph> for GraphStatistics {
+    fn from(graph: &dyn Graph) -> Self {
+        let mut classes: BTreeMap<IRIRef, usize> = Default::default();
+        for statement in graph.statements() {
+            if statement.predicate() == rdf::a_type() {
+                if let Some(class) = statement.object().as_iri() {
+                    *classes.entry(class.clone()).or_default() += 1;
+                }
+            }
+        }
+        Self {
+            triples: graph.len(),
+            distinct_subjects: graph.distinct_subjects().count(),
+            distinct_predicates: graph.distinct_predicates().count(),
+            distinct_objects: graph.distinct_objects().count(),
+            classes,
+            properties: graph
+                .count_by_predicate()
+                .into_iter()
+                .map(|(predicate, count)| (predicate.clone(), count))
+                .collect(),
+        }
+    }
+}
+
+impl GraphStatistics {
+    ///
+    /// Return the number of statements in the graph.
+    ///
+    pub fn triples(&self) -> usize {
+        self.triples
+    }
+
+    ///
+    /// Return the number of distinct subjects in the graph.
+    ///
+    pub fn distinct_subjects(&self) -> usize {
+        self.distinct_subjects
+    }
+
+    ///
+    /// Return the number of distinct predicates in the graph.
+    ///
+    pub fn distinct_predicates(&self) -> usize {
+        self.distinct_predicates
+    }
+
+    ///
+    /// Return the number of distinct objects in the graph.
+    ///
+    pub fn distinct_objects(&self) -> usize {
+        self.distinct_objects
+    }
+
+    ///
+    /// Return the number of distinct classes with at least one instance in the graph.
+    ///
+    pub fn distinct_classes(&self) -> usize {
+        self.classes.len()
+    }
+
+    ///
+    /// Return the number of instances of `class` in the graph.
+    ///
+    pub fn class_count(&self, class: &IRIRef) -> usize {
+        self.classes.get(class).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Return the number of statements using `property` as their predicate.
+    ///
+    pub fn property_count(&self, property: &IRIRef) -> usize {
+        self.properties.get(property).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Return the class usage histogram, each class with the number of its instances, most used
+    /// first.
+    ///
+    pub fn class_usage(&self) -> Vec<(&IRIRef, usize)> {
+        histogram(&self.classes)
+    }
+
+    ///
+    /// Return the property usage histogram, each property with the number of statements using it,
+    /// most used first.
+    ///
+    pub fn property_usage(&self) -> Vec<(&IRIRef, usize)> {
+        histogram(&self.properties)
+    }
+
+    ///
+    /// Return a new graph, created by `factory`, containing a VoID description of these
+    /// statistics as properties of the `void:Dataset` identified by `dataset`, including a class
+    /// partition for each class and a property partition for each property.
+    ///
+    pub fn to_void(&self, dataset: &IRIRef, factory: &GraphFactoryRef) -> Result<GraphRef> {
+        let graph = factory.graph();
+        {
+            let mut graph = graph.borrow_mut();
+            {
+                let mappings = graph.prefix_mappings();
+                let mut mappings = mappings.borrow_mut();
+                mappings.insert(void::default_prefix(), void::namespace_iri().clone());
+                mappings.include_rdf();
+                mappings.include_xsd();
+            }
+            let statements = graph.statement_factory();
+            let literals = graph.literal_factory();
+            let count = |count: usize| -> ObjectNodeRef {
+                statements
+                    .literal_object(literals.with_data_type(&count.to_string(), DataType::Integer))
+            };
+
+            let subject = statements.named_subject(dataset.clone());
+            let mut add = |subject: &SubjectNodeRef, predicate: &IRIRef, object: ObjectNodeRef| {
+                statements
+                    .statement(subject.clone(), predicate.clone(), object)
+                    .map(|statement| graph.insert(statement))
+            };
+            add(
+                &subject,
+                rdf::a_type(),
+                statements.named_object(void::dataset().clone()),
+            )?;
+            add(&subject, void::triples(), count(self.triples))?;
+            add(
+                &subject,
+                void::distinct_subjects(),
+                count(self.distinct_subjects),
+            )?;
+            add(
+                &subject,
+                void::properties(),
+                count(self.distinct_predicates),
+            )?;
+            add(
+                &subject,
+                void::distinct_objects(),
+                count(self.distinct_objects),
+            )?;
+            add(&subject, void::classes(), count(self.classes.len()))?;
+
+            for (class, entities) in &self.classes {
+                let partition = statements.blank_subject();
+                add(
+                    &subject,
+                    void::class_partition(),
+                    statements.subject_as_object(partition.clone()),
+                )?;
+                add(
+                    &partition,
+                    void::class(),
+                    statements.named_object(class.clone()),
+                )?;
+                add(&partition, void::entities(), count(*entities))?;
+            }
+            for (property, triples) in &self.properties {
+                let partition = statements.blank_subject();
+                add(
+                    &subject,
+                    void::property_partition(),
+                    statements.subject_as_object(partition.clone()),
+                )?;
+                add(
+                    &partition,
+                    void::property(),
+                    statements.named_object(property.clone()),
+                )?;
+                add(&partition, void::triples(), count(*triples))?;
+            }
+        }
+        Ok(graph)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F> StatisticsCollector for F
+where
+    F: FnMut(&StatementRef),
+{
+    fn observe(&mut self, statement: &StatementRef) {
+        self(statement)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl StatisticsCollector for PredicateHistogram {
+    fn observe(&mut self, statement: &StatementRef) {
+        *self
+            .counts
+            .entry(statement.predicate().clone())
+            .or_default() += 1;
+    }
+}
+
+impl PredicateHistogram {
+    ///
+    /// Return the number of statements observed using `predicate`.
+    ///
+    pub fn count(&self, predicate: &IRIRef) -> usize {
+        self.counts.get(predicate).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Return the number of distinct predicates observed.
+    ///
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    ///
+    /// Returns `true` if no statements have been observed.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    ///
+    /// Return each predicate with the number of statements using it, most used first.
+    ///
+    pub fn histogram(&self) -> Vec<(&IRIRef, usize)> {
+        histogram(&self.counts)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl StatisticsCollector for NamespaceCounts {
+    fn observe(&mut self, statement: &StatementRef) {
+        if let Some(subject) = statement.subject().as_iri() {
+            self.add(subject);
+        }
+        self.add(statement.predicate());
+        if let Some(object) = statement.object().as_iri() {
+            self.add(object);
+        }
+    }
+}
+
+impl NamespaceCounts {
+    ///
+    /// Return the number of uses of IRIs in `namespace`.
+    ///
+    pub fn count(&self, namespace: &IRIRef) -> usize {
+        self.counts.get(namespace).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Return the number of distinct namespaces observed.
+    ///
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    ///
+    /// Returns `true` if no IRIs have been observed.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    ///
+    /// Return each namespace with the number of uses of IRIs in it, most used first.
+    ///
+    pub fn histogram(&self) -> Vec<(&IRIRef, usize)> {
+        histogram(&self.counts)
+    }
+
+    fn add(&mut self, iri: &IRIRef) {
+        let (namespace, _) = split_iri(iri);
+        *self.counts.entry(namespace).or_default() += 1;
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl StatisticsCollector for LiteralLengths {
+    fn observe(&mut self, statement: &StatementRef) {
+        if let Some(literal) = statement.object().as_literal() {
+            let length = literal.lexical_form().chars().count();
+            self.count += 1;
+            self.total += length;
+            self.min = Some(self.min.map_or(length, |min| min.min(length)));
+            self.max = Some(self.max.map_or(length, |max| max.max(length)));
+            let bucket = (usize::BITS - length.leading_zeros()) as usize;
+            if self.buckets.len() <= bucket {
+                self.buckets.resize(bucket + 1, 0);
+            }
+            self.buckets[bucket] += 1;
+        }
+    }
+}
+
+impl LiteralLengths {
+    ///
+    /// Return the number of literals observed.
+    ///
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    ///
+    /// Return the length of the shortest literal observed, if any.
+    ///
+    pub fn min(&self) -> Option<usize> {
+        self.min
+    }
+
+    ///
+    /// Return the length of the longest literal observed, if any.
+    ///
+    pub fn max(&self) -> Option<usize> {
+        self.max
+    }
+
+    ///
+    /// Return the mean length of the literals observed, if any.
+    ///
+    pub fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            None
+        } else {
+            Some(self.total as f64 / self.count as f64)
+        }
+    }
+
+    ///
+    /// Return the distribution of lengths, as the inclusive range of lengths in each bucket and
+    /// the number of literals in that range, shortest first; buckets are returned up to the
+    /// longest literal observed.
+    ///
+    pub fn distribution(&self) -> Vec<((usize, usize), usize)> {
+        self.buckets
+            .iter()
+            .enumerate()
+            .map(|(bucket, count)| match bucket {
+                0 => ((0, 0), *count),
+                _ => ((1 << (bucket - 1), (1 << bucket) - 1), *count),
+            })
+            .collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn histogram(counts: &BTreeMap<IRIRef, usize>) -> Vec<(&IRIRef, usize)> {
+    let mut histogram: Vec<(&IRIRef, usize)> =
+        counts.iter().map(|(iri, count)| (iri, *count)).collect();
+    histogram.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    histogram
+}
@@ -0,0 +1,313 @@
+/*!
+A compact, in-memory, `StatementStore` that interns each distinct term.
+
+Each distinct IRI, blank node, literal, and RDF-star statement is held once in a term dictionary
+and identified by a `TermId`; a statement is then held as the three identifiers of its subject,
+predicate, and object, in three ordered indexes, `spo`, `pos`, and `osp`, so that any pattern
+passed to `matching` is answered by a single range of one index. The `StatementRef`s returned are
+created from the dictionary as they are read, and are not retained by the store.
+
+This avoids holding a separate statement, and separate subject and object nodes, for each
+statement, as the `simple` graphs do; for a large graph with many repeated terms the store
+therefore uses several times less memory than the default graph. `simple::interned::InternedGraph`
+is a `Graph` over this store, see `StoreGraph`, for use wherever a graph is expected.
+
+Terms are not removed from the dictionary when the last statement using them is removed; only
+`clear` releases them.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::store::interned::InternedStore;
+use rdftk_core::model::graph::store::StatementStore;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let subject = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/s").unwrap()));
+let store: InternedStore = ["Alice", "Bob", "Alice"]
+    .iter()
+    .map(|name| {
+        factory
+            .statement(
+                subject.clone(),
+                foaf::name().clone(),
+                factory.literal_object(literal_factory().literal(name)),
+            )
+            .unwrap()
+    })
+    .collect();
+
+assert_eq!(store.len().unwrap(), 2);
+assert_eq!(store.term_count(), 4);
+assert_eq!(store.matching(Some(&subject), None, None).unwrap().count(), 2);
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::graph::store::{StatementStore, StoreIter};
+use crate::model::literal::LiteralRef;
+use crate::model::statement::{
+    NodeKind, ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef,
+};
+use crate::simple::statement::statement_factory;
+use rdftk_iri::IRIRef;
+use std::collections::{BTreeSet, HashMap};
+use std::iter::FromIterator;
+use std::rc::Rc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The identifier of a term in the dictionary of an `InternedStore`.
+///
+pub type TermId = u64;
+
+///
+/// A `StatementStore` held in memory with each distinct term interned.
+///
+#[derive(Clone, Debug, Default)]
+pub struct InternedStore {
+    terms: TermDictionary,
+    spo: BTreeSet<(TermId, TermId, TermId)>,
+    pos: BTreeSet<(TermId, TermId, TermId)>,
+    osp: BTreeSet<(TermId, TermId, TermId)>,
+    prefixes: Vec<(String, IRIRef)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Term {
+    Blank(Rc<str>),
+    Iri(IRIRef),
+    Literal(LiteralRef),
+    Statement(TermId, TermId, TermId),
+}
+
+#[derive(Clone, Debug, Default)]
+struct TermDictionary {
+    terms: Vec<Term>,
+    ids: HashMap<Term, TermId>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl InternedStore {
+    ///
+    /// Return the number of distinct terms held in the dictionary of this store.
+    ///
+    pub fn term_count(&self) -> usize {
+        self.terms.terms.len()
+    }
+
+    fn find_statement(&self, statement: &StatementRef) -> Option<(TermId, TermId, TermId)> {
+        Some((
+            self.terms.find(statement.subject().kind())?,
+            self.terms.find_iri(statement.predicate())?,
+            self.terms.find(statement.object().kind())?,
+        ))
+    }
+
+    fn statement(&self, (s, p, o): (TermId, TermId, TermId)) -> Result<StatementRef> {
+        let factory = statement_factory();
+        let subject = match self.terms.get(s)? {
+            Term::Blank(label) => factory.blank_subject_named(label)?,
+            Term::Iri(iri) => factory.named_subject(iri.clone()),
+            Term::Statement(s, p, o) => factory.statement_subject(self.statement((*s, *p, *o))?),
+            Term::Literal(_) => return Err(ErrorKind::InvalidState.into()),
+        };
+        let predicate = match self.terms.get(p)? {
+            Term::Iri(iri) => iri.clone(),
+            _ => return Err(ErrorKind::InvalidState.into()),
+        };
+        factory.statement(subject, predicate, self.object(o, &factory)?)
+    }
+
+    fn object(&self, id: TermId, factory: &StatementFactoryRef) -> Result<ObjectNodeRef> {
+        Ok(match self.terms.get(id)? {
+            Term::Blank(label) => factory.blank_object_named(label)?,
+            Term::Iri(iri) => factory.named_object(iri.clone()),
+            Term::Literal(literal) => factory.literal_object(literal.clone()),
+            Term::Statement(s, p, o) => factory.statement_object(self.statement((*s, *p, *o))?),
+        })
+    }
+}
+
+impl StatementStore for InternedStore {
+    fn len(&self) -> Result<usize> {
+        Ok(self.spo.len())
+    }
+
+    fn contains(&self, statement: &StatementRef) -> Result<bool> {
+        Ok(self
+            .find_statement(statement)
+            .map(|ids| self.spo.contains(&ids))
+            .unwrap_or_default())
+    }
+
+    fn insert(&mut self, statement: &StatementRef) -> Result<bool> {
+        let (s, p, o) = self.terms.intern_statement(statement);
+        let inserted = self.spo.insert((s, p, o));
+        if inserted {
+            let _ = self.pos.insert((p, o, s));
+            let _ = self.osp.insert((o, s, p));
+        }
+        Ok(inserted)
+    }
+
+    fn remove(&mut self, statement: &StatementRef) -> Result<bool> {
+        Ok(match self.find_statement(statement) {
+            Some((s, p, o)) if self.spo.remove(&(s, p, o)) => {
+                let _ = self.pos.remove(&(p, o, s));
+                let _ = self.osp.remove(&(o, s, p));
+                true
+            }
+            _ => false,
+        })
+    }
+
+    fn clear(&mut self) -> Result<()> {
+        self.terms = Default::default();
+        self.spo.clear();
+        self.pos.clear();
+        self.osp.clear();
+        Ok(())
+    }
+
+    fn matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> Result<StoreIter<'_>> {
+        let subject = subject.map(|subject| self.terms.find(subject.kind()));
+        let predicate = predicate.map(|predicate| self.terms.find_iri(predicate));
+        let object = object.map(|object| self.terms.find(object.kind()));
+        let (subject, predicate, object) = match (subject, predicate, object) {
+            // a term not in the dictionary matches no statement.
+            (Some(None), _, _) | (_, Some(None), _) | (_, _, Some(None)) => {
+                return Ok(Box::new(std::iter::empty()))
+            }
+            (s, p, o) => (s.flatten(), p.flatten(), o.flatten()),
+        };
+        let range = |first: TermId, second: Option<TermId>| {
+            (first, second.unwrap_or(TermId::MIN), TermId::MIN)
+                ..=(first, second.unwrap_or(TermId::MAX), TermId::MAX)
+        };
+        let ids: Box<dyn Iterator<Item = (TermId, TermId, TermId)> + '_> =
+            match (subject, predicate, object) {
+                (Some(s), Some(p), Some(o)) => {
+                    Box::new(self.spo.get(&(s, p, o)).into_iter().copied())
+                }
+                (Some(s), p, None) => Box::new(self.spo.range(range(s, p)).copied()),
+                (None, Some(p), o) => {
+                    Box::new(self.pos.range(range(p, o)).map(|(p, o, s)| (*s, *p, *o)))
+                }
+                (s, None, Some(o)) => {
+                    Box::new(self.osp.range(range(o, s)).map(|(o, s, p)| (*s, *p, *o)))
+                }
+                (None, None, None) => Box::new(self.spo.iter().copied()),
+            };
+        Ok(Box::new(ids.map(move |ids| self.statement(ids))))
+    }
+
+    fn prefix_mappings(&self) -> Result<Vec<(String, IRIRef)>> {
+        Ok(self.prefixes.clone())
+    }
+
+    fn set_prefix_mappings(&mut self, mappings: &[(String, IRIRef)]) -> Result<()> {
+        self.prefixes = mappings.to_vec();
+        Ok(())
+    }
+
+    fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Extend<StatementRef> for InternedStore {
+    fn extend<T: IntoIterator<Item = StatementRef>>(&mut self, iter: T) {
+        for statement in iter {
+            let _ = self.insert(&statement);
+        }
+    }
+}
+
+impl FromIterator<StatementRef> for InternedStore {
+    fn from_iter<T: IntoIterator<Item = StatementRef>>(iter: T) -> Self {
+        let mut store = Self::default();
+        store.extend(iter);
+        store
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TermDictionary {
+    fn get(&self, id: TermId) -> Result<&Term> {
+        self.terms
+            .get(id as usize)
+            .ok_or_else(|| ErrorKind::InvalidState.into())
+    }
+
+    fn find(&self, kind: NodeKind<'_>) -> Option<TermId> {
+        let term = match kind {
+            NodeKind::Blank(label) => Term::Blank(Rc::from(label.as_str())),
+            NodeKind::Iri(iri) => Term::Iri(iri.clone()),
+            NodeKind::Literal(literal) => Term::Literal(literal.clone()),
+            NodeKind::Statement(statement) => Term::Statement(
+                self.find(statement.subject().kind())?,
+                self.find_iri(statement.predicate())?,
+                self.find(statement.object().kind())?,
+            ),
+        };
+        self.ids.get(&term).copied()
+    }
+
+    fn find_iri(&self, iri: &IRIRef) -> Option<TermId> {
+        self.ids.get(&Term::Iri(iri.clone())).copied()
+    }
+
+    fn intern(&mut self, kind: NodeKind<'_>) -> TermId {
+        let term = match kind {
+            NodeKind::Blank(label) => Term::Blank(Rc::from(label.as_str())),
+            NodeKind::Iri(iri) => Term::Iri(iri.clone()),
+            NodeKind::Literal(literal) => Term::Literal(literal.clone()),
+            NodeKind::Statement(statement) => {
+                let (s, p, o) = self.intern_statement(statement);
+                Term::Statement(s, p, o)
+            }
+        };
+        self.intern_term(term)
+    }
+
+    fn intern_statement(&mut self, statement: &StatementRef) -> (TermId, TermId, TermId) {
+        (
+            self.intern(statement.subject().kind()),
+            self.intern_term(Term::Iri(statement.predicate().clone())),
+            self.intern(statement.object().kind()),
+        )
+    }
+
+    fn intern_term(&mut self, term: Term) -> TermId {
+        if let Some(id) = self.ids.get(&term) {
+            *id
+        } else {
+            let id = self.terms.len() as TermId;
+            self.terms.push(term.clone());
+            let _ = self.ids.insert(term, id);
+            id
+        }
+    }
+}
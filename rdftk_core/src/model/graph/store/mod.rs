@@ -0,0 +1,695 @@
+/*!
+A storage abstraction, `StatementStore`, for graphs whose statements are held outside of memory,
+and `StoreGraph`, an implementation of the `Graph` trait over any store.
+
+A store keeps a set of statements, without duplicates, and the prefix mappings of the graph. It is
+queried directly, by `matching`, with any combination of subject, predicate, and object; as the
+results are produced one at a time a store may hold more statements than fit in memory. The
+persistent `sled::SledStore`, enabled by the `sled_store` feature, keeps the statements on disk in
+subject-predicate-object, predicate-object-subject, and object-subject-predicate indexes, so that
+each pattern is answered by a single prefix scan, and survives restarts.
+The in-memory `interned::InternedStore` holds each distinct term once, in a term dictionary, and
+each statement as the identifiers of its terms, creating statements only as they are read; it is
+the store behind the graphs of `simple::interned`.
+
+`StoreGraph` answers the methods of `Graph` from the store: counts, `contains`, `matches`, and the
+subject, predicate, and object queries are each a call to `matching`, and inserts and removes are
+written through to the store. `statements` reads from the store as it is iterated, and never loads
+the whole store. As `Graph` returns references, the statements read to answer a query are held by
+the graph until it is next changed, or until `release` is called; `snapshot` and
+`statements_matching` copy the statements without holding them. Changes made through
+`statements_mut`, which reads every statement, are written, as the statements removed and added,
+when the graph is next changed, released, or flushed, and until then the graph is answered from
+the edited statements.
+
+The methods of `Graph` cannot fail, and so panic if the store returns an error; each has a
+fallible counterpart on `StoreGraph`, such as `try_len`, `try_matching`, and `try_insert`, that
+returns the error to the caller instead.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::store::{StatementStore, StoreGraph};
+# #[cfg(feature = "sled_store")]
+use rdftk_core::model::graph::store::sled::SledStore;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+# #[cfg(feature = "sled_store")]
+# {
+let factory = statement_factory();
+let subject = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/s").unwrap()));
+let name = IRIRef::from(IRI::from_str("http://xmlns.com/foaf/0.1/name").unwrap());
+
+let mut graph = StoreGraph::open(SledStore::temporary().unwrap()).unwrap();
+graph.insert(
+    factory
+        .statement(subject.clone(), name, factory.literal_object(literal_factory().literal("S")))
+        .unwrap(),
+);
+graph.flush().unwrap();
+
+assert_eq!(graph.len(), 1);
+assert_eq!(graph.store().matching(Some(&subject), None, None).unwrap().count(), 1);
+# }
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::features::{Featured, FEATURE_RDF_STAR};
+use crate::model::graph::{
+    statement_matches, Graph, GraphFactoryRef, PrefixMappingRef, Snapshot, StatementIter,
+};
+use crate::model::literal::LiteralFactoryRef;
+use crate::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
+};
+use crate::simple::graph::graph_factory;
+use crate::simple::literal::literal_factory;
+use crate::simple::mapping::prefix_mapping_factory;
+use crate::simple::statement::statement_factory;
+use rdftk_iri::IRIRef;
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+use std::hash::Hash;
+use std::ops::Deref;
+use typed_arena::Arena;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An iterator over the statements read from a store.
+///
+pub type StoreIter<'a> = Box<dyn Iterator<Item = Result<StatementRef>> + 'a>;
+
+///
+/// A set of statements, and the prefix mappings of a graph, held outside of memory.
+///
+pub trait StatementStore: Debug {
+    ///
+    /// Return the number of statements in the store.
+    ///
+    fn len(&self) -> Result<usize>;
+
+    ///
+    /// Returns `true` if there are no statements in the store, else `false`.
+    ///
+    fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    ///
+    /// Returns `true` if the store contains `statement`, else `false`.
+    ///
+    fn contains(&self, statement: &StatementRef) -> Result<bool>;
+
+    ///
+    /// Add `statement` to the store, returning `true` if it was not already present.
+    ///
+    fn insert(&mut self, statement: &StatementRef) -> Result<bool>;
+
+    ///
+    /// Remove `statement` from the store, returning `true` if it was present.
+    ///
+    fn remove(&mut self, statement: &StatementRef) -> Result<bool>;
+
+    ///
+    /// Remove all statements from the store; the prefix mappings are retained.
+    ///
+    fn clear(&mut self) -> Result<()>;
+
+    ///
+    /// Return the statements that match the provided subject, predicate, and object; any of these
+    /// that is `None` acts as a wildcard.
+    ///
+    fn matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> Result<StoreIter<'_>>;
+
+    ///
+    /// Return the stored prefix mappings, with the default namespace under the empty prefix.
+    ///
+    fn prefix_mappings(&self) -> Result<Vec<(String, IRIRef)>>;
+
+    ///
+    /// Replace the stored prefix mappings.
+    ///
+    fn set_prefix_mappings(&mut self, mappings: &[(String, IRIRef)]) -> Result<()>;
+
+    ///
+    /// Ensure all changes are durably written.
+    ///
+    fn flush(&self) -> Result<()>;
+}
+
+///
+/// An implementation of the `Graph` trait over a `StatementStore`; see the module documentation.
+///
+pub struct StoreGraph<S: StatementStore> {
+    store: S,
+    mappings: PrefixMappingRef,
+    edits: Option<Edits>,
+    results: Arena<StatementRef>,
+}
+
+///
+/// An iterator over references to the statements read, by a `StoreGraph`, from its store.
+///
+pub type StoreGraphIter<'a> = Box<dyn Iterator<Item = Result<&'a StatementRef>> + 'a>;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The statements of a graph, as read and as changed through `statements_mut`.
+///
+#[derive(Debug)]
+struct Edits {
+    original: StatementList,
+    edited: StatementList,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<S: StatementStore> Debug for StoreGraph<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StoreGraph")
+            .field("store", &self.store)
+            .field("mappings", &self.mappings)
+            .field("edits", &self.edits.is_some())
+            .field("results", &self.results.len())
+            .finish()
+    }
+}
+
+impl<S: StatementStore> StoreGraph<S> {
+    ///
+    /// Open a graph over `store`, loading its prefix mappings; statements are read from the store
+    /// as they are needed.
+    ///
+    pub fn open(store: S) -> Result<Self> {
+        let mappings = prefix_mapping_factory().empty();
+        {
+            let mut mappings = mappings.borrow_mut();
+            for (prefix, namespace) in store.prefix_mappings()? {
+                mappings.insert(&prefix, namespace);
+            }
+        }
+        Ok(Self {
+            store,
+            mappings,
+            edits: None,
+            results: Arena::new(),
+        })
+    }
+
+    ///
+    /// Return the store holding the statements of this graph.
+    ///
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    ///
+    /// Return the number of statements held by this graph to answer earlier queries.
+    ///
+    pub fn held(&self) -> usize {
+        self.results.len()
+    }
+
+    ///
+    /// Write any changes not yet written to the store, including the prefix mappings, and ensure
+    /// they are durable.
+    ///
+    pub fn flush(&mut self) -> Result<()> {
+        self.write_edits()?;
+        let mappings: Vec<(String, IRIRef)> = self
+            .mappings
+            .borrow()
+            .mappings()
+            .map(|(prefix, namespace)| (prefix.clone(), namespace.clone()))
+            .collect();
+        self.store.set_prefix_mappings(&mappings)?;
+        self.store.flush()
+    }
+
+    ///
+    /// Write any changes made through `statements_mut` to the store, and release the statements
+    /// held by this graph to answer earlier queries.
+    ///
+    pub fn release(&mut self) -> Result<()> {
+        self.write_edits()?;
+        self.changed();
+        Ok(())
+    }
+
+    ///
+    /// Close the graph, flushing any changes, and return the store.
+    ///
+    pub fn into_store(mut self) -> Result<S> {
+        self.flush()?;
+        Ok(self.store)
+    }
+
+    ///
+    /// Returns `true` if there are no statements in this graph, else `false`, or the error from
+    /// the store.
+    ///
+    pub fn try_is_empty(&self) -> Result<bool> {
+        match self.edited() {
+            Some(statements) => Ok(statements.is_empty()),
+            None => self.store.is_empty(),
+        }
+    }
+
+    ///
+    /// Return the number of statements in this graph, or the error from the store.
+    ///
+    pub fn try_len(&self) -> Result<usize> {
+        match self.edited() {
+            Some(statements) => Ok(statements.len()),
+            None => self.store.len(),
+        }
+    }
+
+    ///
+    /// Returns `true` if this graph contains `statement`, else `false`, or the error from the
+    /// store.
+    ///
+    pub fn try_contains(&self, statement: &StatementRef) -> Result<bool> {
+        match self.edited() {
+            Some(statements) => Ok(statements.contains(statement)),
+            None => self.store.contains(statement),
+        }
+    }
+
+    ///
+    /// Return the statements that match the provided subject, predicate, and object, any of which
+    /// may be `None` as a wildcard. Statements are read from the store as the iterator is used,
+    /// and held until the graph is next changed, or released, so that references may be returned.
+    ///
+    pub fn try_matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> Result<StoreGraphIter<'_>> {
+        match self.edited() {
+            Some(statements) => {
+                let matched: Vec<&StatementRef> = statements
+                    .iter()
+                    .filter(|st| statement_matches(st, subject, predicate, object))
+                    .collect();
+                Ok(Box::new(matched.into_iter().map(Ok)))
+            }
+            None => {
+                let results = &self.results;
+                Ok(Box::new(
+                    self.store
+                        .matching(subject, predicate, object)?
+                        .map(move |st| st.map(|st| &*results.alloc(st))),
+                ))
+            }
+        }
+    }
+
+    ///
+    /// Return all the statements in this graph; see `try_matching`.
+    ///
+    pub fn try_statements(&self) -> Result<StoreGraphIter<'_>> {
+        self.try_matching(None, None, None)
+    }
+
+    ///
+    /// Insert `statement` into the store, returning `true` if it was not already present, or the
+    /// error from the store.
+    ///
+    pub fn try_insert(&mut self, statement: &StatementRef) -> Result<bool> {
+        self.write_edits()?;
+        let inserted = self.store.insert(statement)?;
+        if inserted {
+            self.changed();
+        }
+        Ok(inserted)
+    }
+
+    ///
+    /// Insert all of `statements` into the store, returning the number that were not already
+    /// present, or the first error from the store.
+    ///
+    pub fn try_extend(
+        &mut self,
+        statements: &mut dyn Iterator<Item = StatementRef>,
+    ) -> Result<usize> {
+        self.write_edits()?;
+        let mut inserted = 0;
+        let mut result = Ok(());
+        for statement in statements {
+            match self.store.insert(&statement) {
+                Ok(true) => inserted += 1,
+                Ok(false) => {}
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        if inserted > 0 {
+            self.changed();
+        }
+        result.map(|_| inserted)
+    }
+
+    ///
+    /// Remove `statement` from the store, returning `true` if it was present, or the error from
+    /// the store.
+    ///
+    pub fn try_remove(&mut self, statement: &StatementRef) -> Result<bool> {
+        self.write_edits()?;
+        let removed = self.store.remove(statement)?;
+        if removed {
+            self.changed();
+        }
+        Ok(removed)
+    }
+
+    ///
+    /// Remove all statements with the subject `subject` from the store, returning them, or the
+    /// error from the store.
+    ///
+    pub fn try_remove_all_for(&mut self, subject: &SubjectNodeRef) -> Result<StatementList> {
+        self.write_edits()?;
+        let removed = self
+            .store
+            .matching(Some(subject), None, None)?
+            .collect::<Result<StatementList>>()?;
+        self.changed();
+        for statement in &removed {
+            let _ = self.store.remove(statement)?;
+        }
+        Ok(removed)
+    }
+
+    ///
+    /// Remove all statements from the store, discarding any changes made through
+    /// `statements_mut`, or return the error from the store.
+    ///
+    pub fn try_clear(&mut self) -> Result<()> {
+        self.edits = None;
+        self.changed();
+        self.store.clear()
+    }
+
+    ///
+    /// Read the statements matching the provided subject, predicate, and object, without holding
+    /// them.
+    ///
+    fn read(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> Result<StatementList> {
+        match self.edited() {
+            Some(statements) => Ok(statements
+                .iter()
+                .filter(|st| statement_matches(st, subject, predicate, object))
+                .cloned()
+                .collect()),
+            None => self.store.matching(subject, predicate, object)?.collect(),
+        }
+    }
+
+    ///
+    /// Read the matching statements, holding the first with each distinct `key` until the graph
+    /// is next changed so that references to them may be returned.
+    ///
+    fn read_distinct<K, F>(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+        key: F,
+    ) -> Result<Vec<&StatementRef>>
+    where
+        K: Eq + Hash,
+        F: Fn(&StatementRef) -> K,
+    {
+        let mut seen: HashSet<K> = Default::default();
+        let mut distinct: Vec<&StatementRef> = Default::default();
+        for statement in self.try_matching(subject, predicate, object)? {
+            let statement = statement?;
+            if seen.insert(key(statement)) {
+                distinct.push(statement);
+            }
+        }
+        Ok(distinct)
+    }
+
+    ///
+    /// The statements edited through `statements_mut`, if they have not yet been written.
+    ///
+    fn edited(&self) -> Option<&StatementList> {
+        self.edits.as_ref().map(|edits| &edits.edited)
+    }
+
+    ///
+    /// Write the statements changed through `statements_mut`; all removals are written before any
+    /// additions so that statements swapped between positions are retained.
+    ///
+    fn write_edits(&mut self) -> Result<()> {
+        if let Some(edits) = self.edits.take() {
+            let changed: Vec<(&StatementRef, &StatementRef)> = edits
+                .original
+                .iter()
+                .zip(edits.edited.iter())
+                .filter(|(before, after)| before != after)
+                .collect();
+            for (before, _) in &changed {
+                let _ = self.store.remove(before)?;
+            }
+            for (_, after) in &changed {
+                let _ = self.store.insert(after)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn changed(&mut self) {
+        if self.results.len() > 0 {
+            self.results = Arena::new();
+        }
+    }
+}
+
+impl<S: StatementStore> Featured for StoreGraph<S> {
+    fn supports_feature(&self, feature: &IRIRef) -> bool {
+        feature == FEATURE_RDF_STAR.deref()
+    }
+}
+
+impl<S: StatementStore> Graph for StoreGraph<S> {
+    fn is_empty(&self) -> bool {
+        checked(self.try_is_empty())
+    }
+
+    fn len(&self) -> usize {
+        checked(self.try_len())
+    }
+
+    fn contains_subject(&self, subject: &SubjectNodeRef) -> bool {
+        match self.edited() {
+            Some(statements) => statements.iter().any(|st| st.subject() == subject),
+            None => checked(
+                self.store
+                    .matching(Some(subject), None, None)
+                    .and_then(|mut statements| statements.next().transpose()),
+            )
+            .is_some(),
+        }
+    }
+
+    fn contains_individual(&self, subject: &IRIRef) -> bool {
+        let subject = self.statement_factory().named_subject(subject.clone());
+        self.contains_subject(&subject)
+    }
+
+    fn contains(&self, statement: &StatementRef) -> bool {
+        checked(self.try_contains(statement))
+    }
+
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        checked(
+            self.try_matching(subject, predicate, object)
+                .and_then(|statements| statements.collect()),
+        )
+    }
+
+    fn statements_matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> StatementList {
+        checked(self.read(subject, predicate, object))
+    }
+
+    fn statements(&self) -> StatementIter<'_> {
+        match self.edited() {
+            Some(statements) => StatementIter::new(statements.iter()),
+            None => StatementIter::forward(
+                checked(self.try_statements()).map(checked),
+                checked(self.store.len()),
+            ),
+        }
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::from(checked(self.read(None, None, None)))
+    }
+
+    fn subjects(&self) -> HashSet<&SubjectNodeRef> {
+        checked(self.read_distinct(None, None, None, |st| st.subject().clone()))
+            .into_iter()
+            .map(|st| st.subject())
+            .collect()
+    }
+
+    fn predicates(&self) -> HashSet<&IRIRef> {
+        checked(self.read_distinct(None, None, None, |st| st.predicate().clone()))
+            .into_iter()
+            .map(|st| st.predicate())
+            .collect()
+    }
+
+    fn predicates_for(&self, subject: &SubjectNodeRef) -> HashSet<&IRIRef> {
+        checked(self.read_distinct(Some(subject), None, None, |st| st.predicate().clone()))
+            .into_iter()
+            .map(|st| st.predicate())
+            .collect()
+    }
+
+    fn objects(&self) -> HashSet<&ObjectNodeRef> {
+        checked(self.read_distinct(None, None, None, |st| st.object().clone()))
+            .into_iter()
+            .map(|st| st.object())
+            .collect()
+    }
+
+    fn objects_for(&self, subject: &SubjectNodeRef, predicate: &IRIRef) -> HashSet<&ObjectNodeRef> {
+        checked(
+            self.read_distinct(Some(subject), Some(predicate), None, |st| {
+                st.object().clone()
+            }),
+        )
+        .into_iter()
+        .map(|st| st.object())
+        .collect()
+    }
+
+    fn prefix_mappings(&self) -> PrefixMappingRef {
+        self.mappings.clone()
+    }
+
+    fn set_prefix_mappings(&mut self, mappings: PrefixMappingRef) {
+        self.mappings = mappings;
+    }
+
+    fn factory(&self) -> GraphFactoryRef {
+        graph_factory()
+    }
+
+    fn statement_factory(&self) -> StatementFactoryRef {
+        statement_factory()
+    }
+
+    fn literal_factory(&self) -> LiteralFactoryRef {
+        literal_factory()
+    }
+
+    fn statements_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut StatementRef> + 'a> {
+        if self.edits.is_none() {
+            let original = checked(self.read(None, None, None));
+            self.edits = Some(Edits {
+                edited: original.clone(),
+                original,
+            });
+        }
+        Box::new(
+            self.edits
+                .iter_mut()
+                .flat_map(|edits| edits.edited.iter_mut()),
+        )
+    }
+
+    fn insert(&mut self, statement: StatementRef) {
+        let _ = checked(self.try_insert(&statement));
+    }
+
+    fn extend_from(&mut self, statements: &mut dyn Iterator<Item = StatementRef>) -> usize {
+        checked(self.try_extend(statements))
+    }
+
+    fn merge(&mut self, other: &Self) {
+        other.statements().for_each(|st| self.insert(st.clone()))
+    }
+
+    fn dedup(&mut self) -> StatementList {
+        // a store holds no duplicate statements.
+        checked(self.write_edits());
+        Default::default()
+    }
+
+    fn remove(&mut self, statement: &StatementRef) {
+        let _ = checked(self.try_remove(statement));
+    }
+
+    fn remove_all_for(&mut self, subject: &SubjectNodeRef) -> StatementList {
+        checked(self.try_remove_all_for(subject))
+    }
+
+    fn clear(&mut self) {
+        checked(self.try_clear())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The methods of `Graph` cannot fail, and an empty or partial answer would be wrong, so they
+/// panic with the error from the store; the `try_` methods of `StoreGraph` return it.
+///
+fn checked<T>(result: Result<T>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => panic!("statement store failed: {}", e),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod interned;
+
+#[cfg(feature = "sled_store")]
+pub mod sled;
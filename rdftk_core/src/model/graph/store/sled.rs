@@ -0,0 +1,631 @@
+/*!
+A persistent `StatementStore` using the [sled](https://crates.io/crates/sled) embedded database.
+
+Each statement is written, as a key with an empty value, to three trees; `spo`, `pos`, and `osp`,
+each holding the encoded subject, predicate, and object in the named order. A pattern with any
+combination of bound positions is then answered by a prefix scan of the tree that begins with
+those positions, and statements are inserted and removed across all three trees in a single
+transaction. Prefix mappings are held in a fourth tree.
+
+Blank node labels are only meaningful to the process that created them, and a new process may
+generate the labels of blank nodes already in the store. So, each blank node inserted is given a
+new label, unique to the store, from a counter held in the database; the labels provided are mapped
+to these for as long as the store is open, and statements read from the store use the labels they
+were inserted with. Blank nodes inserted while the store was previously open are read with their
+stored labels, and may only be found by these labels; a label that was neither provided to, nor
+read from, the store does not match any blank node in it.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::store::sled::SledStore;
+use rdftk_core::model::graph::store::StatementStore;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+let factory = statement_factory();
+let subject = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/s").unwrap()));
+let predicate = IRIRef::from(IRI::from_str("http://example.org/p").unwrap());
+let statement = factory
+    .statement(subject, predicate.clone(), factory.blank_object_named("b1").unwrap())
+    .unwrap();
+
+let mut store = SledStore::temporary().unwrap();
+assert!(store.insert(&statement).unwrap());
+assert!(!store.insert(&statement).unwrap());
+assert_eq!(store.matching(None, Some(&predicate), None).unwrap().count(), 1);
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::graph::store::{StatementStore, StoreIter};
+use crate::model::literal::{DataType, LanguageTag, LiteralRef};
+use crate::model::statement::{
+    NodeKind, ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef,
+};
+use crate::simple::literal::escaped_literal;
+use crate::simple::statement::statement_factory;
+use ::sled::transaction::ConflictableTransactionResult;
+use ::sled::{Db, Transactional, Tree};
+use rdftk_iri::{IRIRef, IRI};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The longest time `SledStore::open` waits for the lock on a store to be released.
+///
+pub const OPEN_LOCK_TIMEOUT: Duration = Duration::from_secs(2);
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A `StatementStore` held on disk by sled.
+///
+#[derive(Clone, Debug)]
+pub struct SledStore {
+    db: Db,
+    spo: Tree,
+    pos: Tree,
+    osp: Tree,
+    prefixes: Tree,
+    label_prefix: String,
+    labels: Arc<Mutex<Labels>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Order {
+    Spo,
+    Pos,
+    Osp,
+}
+
+#[derive(Debug, Default)]
+struct Labels {
+    stored: HashMap<String, String>,
+    provided: HashMap<String, String>,
+}
+
+enum Term {
+    Blank(String),
+    Iri(IRIRef),
+    Literal(LiteralRef),
+    Statement(StatementRef),
+}
+
+const KEY_STORE_ID: &str = "store-id";
+
+const TAG_BLANK: u8 = b'B';
+const TAG_IRI: u8 = b'I';
+const TAG_LITERAL: u8 = b'L';
+const TAG_STATEMENT: u8 = b'S';
+
+const TAG_PLAIN: u8 = b'-';
+const TAG_LANGUAGE: u8 = b'@';
+const TAG_DATA_TYPE: u8 = b'^';
+const TAG_OTHER_DATA_TYPE: u8 = b'~';
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl SledStore {
+    ///
+    /// Open, or create, the store in the directory `path`. If the store was only just dropped by
+    /// this process sled may still hold its lock, so this waits up to `OPEN_LOCK_TIMEOUT` for the
+    /// lock to be released.
+    ///
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let deadline = Instant::now() + OPEN_LOCK_TIMEOUT;
+        loop {
+            match ::sled::open(path.as_ref()) {
+                // sled reports the lock being held only in the message of an I/O error.
+                Err(::sled::Error::Io(e))
+                    if e.to_string().starts_with("could not acquire lock")
+                        && Instant::now() < deadline =>
+                {
+                    sleep(Duration::from_millis(10))
+                }
+                result => return Self::with_db(result.map_err(storage_error)?),
+            }
+        }
+    }
+
+    ///
+    /// Create a store that is removed when it is dropped.
+    ///
+    pub fn temporary() -> Result<Self> {
+        Self::with_db(
+            ::sled::Config::new()
+                .temporary(true)
+                .open()
+                .map_err(storage_error)?,
+        )
+    }
+
+    ///
+    /// Return the space used by the store on disk, in bytes.
+    ///
+    pub fn size_on_disk(&self) -> Result<u64> {
+        self.db.size_on_disk().map_err(storage_error)
+    }
+
+    fn with_db(db: Db) -> Result<Self> {
+        // distinguishes the labels given to blank nodes by this store from any others.
+        let store_id = match db.get(KEY_STORE_ID).map_err(storage_error)? {
+            Some(store_id) => string_from(store_id.to_vec())?,
+            None => {
+                let created = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|since| since.as_nanos())
+                    .unwrap_or_default();
+                let store_id = format!("{:x}{:x}", created, std::process::id());
+                let _ = db
+                    .insert(KEY_STORE_ID, store_id.as_bytes())
+                    .map_err(storage_error)?;
+                store_id
+            }
+        };
+        Ok(Self {
+            spo: db.open_tree("spo").map_err(storage_error)?,
+            pos: db.open_tree("pos").map_err(storage_error)?,
+            osp: db.open_tree("osp").map_err(storage_error)?,
+            prefixes: db.open_tree("prefixes").map_err(storage_error)?,
+            label_prefix: format!("s{}n", store_id),
+            labels: Default::default(),
+            db,
+        })
+    }
+
+    fn stored_label(&self, label: &str, assign: bool) -> Result<Option<String>> {
+        if label.starts_with(&self.label_prefix) {
+            return Ok(Some(label.to_string()));
+        }
+        let mut labels = self.labels.lock().unwrap();
+        if let Some(stored) = labels.stored.get(label) {
+            return Ok(Some(stored.clone()));
+        }
+        if !assign {
+            return Ok(None);
+        }
+        let stored = format!(
+            "{}{}",
+            self.label_prefix,
+            self.db.generate_id().map_err(storage_error)?
+        );
+        let _ = labels.stored.insert(label.to_string(), stored.clone());
+        let _ = labels.provided.insert(stored.clone(), label.to_string());
+        Ok(Some(stored))
+    }
+
+    fn stored_subject(
+        &self,
+        subject: &SubjectNodeRef,
+        assign: bool,
+    ) -> Result<Option<SubjectNodeRef>> {
+        let factory = statement_factory();
+        Ok(match subject.kind() {
+            NodeKind::Blank(label) => match self.stored_label(label, assign)? {
+                Some(label) => Some(factory.blank_subject_named(&label)?),
+                None => None,
+            },
+            NodeKind::Statement(statement) => self
+                .stored_statement(statement, assign)?
+                .map(|statement| factory.statement_subject(statement)),
+            _ => Some(subject.clone()),
+        })
+    }
+
+    fn stored_object(&self, object: &ObjectNodeRef, assign: bool) -> Result<Option<ObjectNodeRef>> {
+        let factory = statement_factory();
+        Ok(match object.kind() {
+            NodeKind::Blank(label) => match self.stored_label(label, assign)? {
+                Some(label) => Some(factory.blank_object_named(&label)?),
+                None => None,
+            },
+            NodeKind::Statement(statement) => self
+                .stored_statement(statement, assign)?
+                .map(|statement| factory.statement_object(statement)),
+            _ => Some(object.clone()),
+        })
+    }
+
+    fn stored_statement(
+        &self,
+        statement: &StatementRef,
+        assign: bool,
+    ) -> Result<Option<StatementRef>> {
+        match (
+            self.stored_subject(statement.subject(), assign)?,
+            self.stored_object(statement.object(), assign)?,
+        ) {
+            (Some(subject), Some(object)) => Ok(Some(statement_factory().statement(
+                subject,
+                statement.predicate().clone(),
+                object,
+            )?)),
+            _ => Ok(None),
+        }
+    }
+
+    fn tree(&self, order: Order) -> &Tree {
+        match order {
+            Order::Spo => &self.spo,
+            Order::Pos => &self.pos,
+            Order::Osp => &self.osp,
+        }
+    }
+}
+
+impl StatementStore for SledStore {
+    fn len(&self) -> Result<usize> {
+        Ok(self.spo.len())
+    }
+
+    fn is_empty(&self) -> Result<bool> {
+        Ok(self.spo.is_empty())
+    }
+
+    fn contains(&self, statement: &StatementRef) -> Result<bool> {
+        match self.stored_statement(statement, false)? {
+            Some(statement) => self
+                .spo
+                .contains_key(statement_key(&statement, Order::Spo))
+                .map_err(storage_error),
+            None => Ok(false),
+        }
+    }
+
+    fn insert(&mut self, statement: &StatementRef) -> Result<bool> {
+        let statement = match self.stored_statement(statement, true)? {
+            Some(statement) => statement,
+            // a label is always assigned to each blank node when inserting.
+            None => unreachable!(),
+        };
+        let keys = all_keys(&statement);
+        (&self.spo, &self.pos, &self.osp)
+            .transaction(|(spo, pos, osp)| -> ConflictableTransactionResult<bool> {
+                let previous = spo.insert(keys[0].as_slice(), &[])?;
+                let _ = pos.insert(keys[1].as_slice(), &[])?;
+                let _ = osp.insert(keys[2].as_slice(), &[])?;
+                Ok(previous.is_none())
+            })
+            .map_err(|e| storage_error(format!("{:?}", e)))
+    }
+
+    fn remove(&mut self, statement: &StatementRef) -> Result<bool> {
+        let keys = match self.stored_statement(statement, false)? {
+            Some(statement) => all_keys(&statement),
+            None => return Ok(false),
+        };
+        (&self.spo, &self.pos, &self.osp)
+            .transaction(|(spo, pos, osp)| -> ConflictableTransactionResult<bool> {
+                let previous = spo.remove(keys[0].as_slice())?;
+                let _ = pos.remove(keys[1].as_slice())?;
+                let _ = osp.remove(keys[2].as_slice())?;
+                Ok(previous.is_some())
+            })
+            .map_err(|e| storage_error(format!("{:?}", e)))
+    }
+
+    fn clear(&mut self) -> Result<()> {
+        self.spo.clear().map_err(storage_error)?;
+        self.pos.clear().map_err(storage_error)?;
+        self.osp.clear().map_err(storage_error)
+    }
+
+    fn matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> Result<StoreIter<'_>> {
+        let subject = match subject {
+            Some(subject) => match self.stored_subject(subject, false)? {
+                Some(subject) => Some(subject),
+                None => return Ok(Box::new(std::iter::empty())),
+            },
+            None => None,
+        };
+        let object = match object {
+            Some(object) => match self.stored_object(object, false)? {
+                Some(object) => Some(object),
+                None => return Ok(Box::new(std::iter::empty())),
+            },
+            None => None,
+        };
+        let (subject, object) = (subject.as_ref(), object.as_ref());
+        let mut prefix = Vec::new();
+        let order = match (subject, predicate, object) {
+            (Some(subject), Some(predicate), Some(object)) => {
+                encode_kind(subject.kind(), &mut prefix);
+                encode_iri(predicate, &mut prefix);
+                encode_kind(object.kind(), &mut prefix);
+                return if self.spo.contains_key(&prefix).map_err(storage_error)? {
+                    let statement = decode_key(&prefix, Order::Spo, &self.labels);
+                    Ok(Box::new(std::iter::once(statement)))
+                } else {
+                    Ok(Box::new(std::iter::empty()))
+                };
+            }
+            (Some(subject), predicate, None) => {
+                encode_kind(subject.kind(), &mut prefix);
+                if let Some(predicate) = predicate {
+                    encode_iri(predicate, &mut prefix);
+                }
+                Order::Spo
+            }
+            (None, Some(predicate), object) => {
+                encode_iri(predicate, &mut prefix);
+                if let Some(object) = object {
+                    encode_kind(object.kind(), &mut prefix);
+                }
+                Order::Pos
+            }
+            (subject, None, Some(object)) => {
+                encode_kind(object.kind(), &mut prefix);
+                if let Some(subject) = subject {
+                    encode_kind(subject.kind(), &mut prefix);
+                }
+                Order::Osp
+            }
+            (None, None, None) => Order::Spo,
+        };
+        Ok(Box::new(self.tree(order).scan_prefix(prefix).map(
+            move |entry| decode_key(&entry.map_err(storage_error)?.0, order, &self.labels),
+        )))
+    }
+
+    fn prefix_mappings(&self) -> Result<Vec<(String, IRIRef)>> {
+        self.prefixes
+            .iter()
+            .map(|entry| {
+                let (prefix, namespace) = entry.map_err(storage_error)?;
+                Ok((
+                    string_from(prefix.to_vec())?,
+                    parse_iri(string_from(namespace.to_vec())?)?,
+                ))
+            })
+            .collect()
+    }
+
+    fn set_prefix_mappings(&mut self, mappings: &[(String, IRIRef)]) -> Result<()> {
+        self.prefixes.clear().map_err(storage_error)?;
+        for (prefix, namespace) in mappings {
+            let _ = self
+                .prefixes
+                .insert(prefix.as_bytes(), namespace.to_string().as_bytes())
+                .map_err(storage_error)?;
+        }
+        Ok(())
+    }
+
+    fn flush(&self) -> Result<()> {
+        let _ = self.db.flush().map_err(storage_error)?;
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn storage_error<E: ToString>(e: E) -> crate::error::Error {
+    ErrorKind::Storage(e.to_string()).into()
+}
+
+fn corrupt() -> crate::error::Error {
+    storage_error("a stored key could not be decoded")
+}
+
+fn all_keys(statement: &StatementRef) -> [Vec<u8>; 3] {
+    [
+        statement_key(statement, Order::Spo),
+        statement_key(statement, Order::Pos),
+        statement_key(statement, Order::Osp),
+    ]
+}
+
+fn statement_key(statement: &StatementRef, order: Order) -> Vec<u8> {
+    let mut key = Vec::new();
+    let subject = statement.subject().kind();
+    let object = statement.object().kind();
+    match order {
+        Order::Spo => {
+            encode_kind(subject, &mut key);
+            encode_iri(statement.predicate(), &mut key);
+            encode_kind(object, &mut key);
+        }
+        Order::Pos => {
+            encode_iri(statement.predicate(), &mut key);
+            encode_kind(object, &mut key);
+            encode_kind(subject, &mut key);
+        }
+        Order::Osp => {
+            encode_kind(object, &mut key);
+            encode_kind(subject, &mut key);
+            encode_iri(statement.predicate(), &mut key);
+        }
+    }
+    key
+}
+
+fn encode_bytes(bytes: &[u8], key: &mut Vec<u8>) {
+    key.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    key.extend_from_slice(bytes);
+}
+
+fn encode_iri(iri: &IRIRef, key: &mut Vec<u8>) {
+    key.push(TAG_IRI);
+    encode_bytes(iri.to_string().as_bytes(), key);
+}
+
+fn encode_kind(kind: NodeKind<'_>, key: &mut Vec<u8>) {
+    match kind {
+        NodeKind::Blank(label) => {
+            key.push(TAG_BLANK);
+            encode_bytes(label.as_bytes(), key);
+        }
+        NodeKind::Iri(iri) => encode_iri(iri, key),
+        NodeKind::Literal(literal) => {
+            key.push(TAG_LITERAL);
+            encode_bytes(literal.lexical_form().as_bytes(), key);
+            match (literal.language(), literal.data_type()) {
+                (Some(language), _) => {
+                    key.push(TAG_LANGUAGE);
+                    encode_bytes(language.to_canonical_format().to_string().as_bytes(), key);
+                }
+                (None, Some(DataType::Other(iri))) => {
+                    key.push(TAG_OTHER_DATA_TYPE);
+                    encode_bytes(iri.to_string().as_bytes(), key);
+                }
+                (None, Some(data_type)) => {
+                    key.push(TAG_DATA_TYPE);
+                    encode_bytes(data_type.as_iri().to_string().as_bytes(), key);
+                }
+                (None, None) => key.push(TAG_PLAIN),
+            }
+        }
+        NodeKind::Statement(statement) => {
+            key.push(TAG_STATEMENT);
+            key.extend(statement_key(statement, Order::Spo));
+        }
+    }
+}
+
+fn decode_key(key: &[u8], order: Order, labels: &Mutex<Labels>) -> Result<StatementRef> {
+    let factory = statement_factory();
+    let mut bytes = key;
+    let statement = decode_statement(&mut bytes, order, &factory, labels)?;
+    if bytes.is_empty() {
+        Ok(statement)
+    } else {
+        Err(corrupt())
+    }
+}
+
+fn decode_statement(
+    bytes: &mut &[u8],
+    order: Order,
+    factory: &StatementFactoryRef,
+    labels: &Mutex<Labels>,
+) -> Result<StatementRef> {
+    let first = decode_term(bytes, factory, labels)?;
+    let second = decode_term(bytes, factory, labels)?;
+    let third = decode_term(bytes, factory, labels)?;
+    let (subject, predicate, object) = match order {
+        Order::Spo => (first, second, third),
+        Order::Pos => (third, first, second),
+        Order::Osp => (second, third, first),
+    };
+    let subject = match subject {
+        Term::Blank(label) => factory.blank_subject_named(&label)?,
+        Term::Iri(iri) => factory.named_subject(iri),
+        Term::Statement(statement) => factory.statement_subject(statement),
+        Term::Literal(_) => return Err(corrupt()),
+    };
+    let predicate = match predicate {
+        Term::Iri(iri) => iri,
+        _ => return Err(corrupt()),
+    };
+    let object = match object {
+        Term::Blank(label) => factory.blank_object_named(&label)?,
+        Term::Iri(iri) => factory.named_object(iri),
+        Term::Literal(literal) => factory.literal_object(literal),
+        Term::Statement(statement) => factory.statement_object(statement),
+    };
+    factory.statement(subject, predicate, object)
+}
+
+fn decode_term(
+    bytes: &mut &[u8],
+    factory: &StatementFactoryRef,
+    labels: &Mutex<Labels>,
+) -> Result<Term> {
+    match take_tag(bytes)? {
+        TAG_BLANK => {
+            let label = take_string(bytes)?;
+            Ok(Term::Blank(
+                labels
+                    .lock()
+                    .unwrap()
+                    .provided
+                    .get(&label)
+                    .cloned()
+                    .unwrap_or(label),
+            ))
+        }
+        TAG_IRI => Ok(Term::Iri(parse_iri(take_string(bytes)?)?)),
+        TAG_LITERAL => {
+            let lexical_form = take_string(bytes)?;
+            let (data_type, language) = match take_tag(bytes)? {
+                TAG_PLAIN => (None, None),
+                TAG_LANGUAGE => (
+                    None,
+                    Some(LanguageTag::from_str(&take_string(bytes)?).map_err(|_| corrupt())?),
+                ),
+                TAG_DATA_TYPE => (Some(DataType::from(parse_iri(take_string(bytes)?)?)), None),
+                TAG_OTHER_DATA_TYPE => {
+                    (Some(DataType::Other(parse_iri(take_string(bytes)?)?)), None)
+                }
+                _ => return Err(corrupt()),
+            };
+            Ok(Term::Literal(escaped_literal(
+                lexical_form,
+                data_type,
+                language,
+            )))
+        }
+        TAG_STATEMENT => Ok(Term::Statement(decode_statement(
+            bytes,
+            Order::Spo,
+            factory,
+            labels,
+        )?)),
+        _ => Err(corrupt()),
+    }
+}
+
+fn take_tag(bytes: &mut &[u8]) -> Result<u8> {
+    let (tag, rest) = bytes.split_first().ok_or_else(corrupt)?;
+    *bytes = rest;
+    Ok(*tag)
+}
+
+fn take_string(bytes: &mut &[u8]) -> Result<String> {
+    if bytes.len() < 4 {
+        return Err(corrupt());
+    }
+    let (length, rest) = bytes.split_at(4);
+    let length = u32::from_be_bytes([length[0], length[1], length[2], length[3]]) as usize;
+    if rest.len() < length {
+        return Err(corrupt());
+    }
+    let (value, rest) = rest.split_at(length);
+    *bytes = rest;
+    string_from(value.to_vec())
+}
+
+fn string_from(bytes: Vec<u8>) -> Result<String> {
+    String::from_utf8(bytes).map_err(|_| corrupt())
+}
+
+fn parse_iri(iri: String) -> Result<IRIRef> {
+    IRI::from_str(&iri).map(IRIRef::from).map_err(|_| corrupt())
+}
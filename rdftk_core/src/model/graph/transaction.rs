@@ -0,0 +1,215 @@
+/*!
+Transactions over a graph. A `Transaction`, started with `Transactional::begin`, buffers the
+statements inserted and removed through it, in a journal of changes, without changing the graph;
+`commit` then applies all the changes together and `rollback`, or dropping the transaction,
+discards them. A bulk update that fails part way, returning early with an error, therefore leaves
+the graph as it was.
+
+Reads through the transaction, with `contains`, see the buffered changes, while the graph itself
+remains visible, unchanged, with `graph`. A transaction may also be committed only if a set of
+`change_set::ChangeValidator`s report no problems with the changes, with `commit_validated`.
+
+The changes returned by `commit` are those that changed the graph; inserting a statement that is
+already present, or removing one that is not, is not recorded. The inverse of these changes will
+therefore restore the graph exactly, and they may be serialized as an RDF Patch.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::transaction::Transactional;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let alice = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap()));
+let name = |name: &str| {
+    factory
+        .statement(alice.clone(), foaf::name().clone(), factory.literal_object(literal_factory().literal(name)))
+        .unwrap()
+};
+
+let graph = graph_factory().graph_from(&[name("Alise")], None);
+let mut graph = graph.borrow_mut();
+
+let mut transaction = graph.begin();
+let _ = transaction.remove(&name("Alise")).insert(name("Alice"));
+assert!(transaction.contains(&name("Alice")));
+assert!(!transaction.graph().contains(&name("Alice")));
+let changes = transaction.commit();
+
+assert_eq!(changes.len(), 2);
+assert!(graph.contains(&name("Alice")));
+assert!(!graph.contains(&name("Alise")));
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::graph::change_set::{Change, ChangeSet, ChangeValidator};
+use crate::model::graph::Graph;
+use crate::model::statement::StatementRef;
+use std::collections::HashSet;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Implemented for all graphs, to begin a transaction.
+///
+pub trait Transactional {
+    ///
+    /// Begin a new transaction over this graph.
+    ///
+    fn begin(&mut self) -> Transaction<'_>;
+}
+
+///
+/// A set of changes to a graph, buffered until they are committed or rolled back.
+///
+#[derive(Debug)]
+pub struct Transaction<'a> {
+    graph: &'a mut dyn Graph,
+    changes: ChangeSet,
+    inserted: HashSet<StatementRef>,
+    removed: HashSet<StatementRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<G: Graph> Transactional for G {
+    fn begin(&mut self) -> Transaction<'_> {
+        Transaction::begin(self)
+    }
+}
+
+impl Transactional for dyn Graph + '_ {
+    fn begin(&mut self) -> Transaction<'_> {
+        Transaction::begin(self)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Transaction<'a> {
+    ///
+    /// Begin a new transaction over `graph`.
+    ///
+    pub fn begin(graph: &'a mut dyn Graph) -> Self {
+        Self {
+            graph,
+            changes: Default::default(),
+            inserted: Default::default(),
+            removed: Default::default(),
+        }
+    }
+
+    ///
+    /// Return the graph, without the changes buffered in this transaction.
+    ///
+    pub fn graph(&self) -> &dyn Graph {
+        self.graph
+    }
+
+    ///
+    /// Return the changes buffered in this transaction, in the order they were made.
+    ///
+    pub fn changes(&self) -> &ChangeSet {
+        &self.changes
+    }
+
+    ///
+    /// Returns `true` if the graph, with the changes buffered in this transaction, contains
+    /// `statement`, else `false`.
+    ///
+    pub fn contains(&self, statement: &StatementRef) -> bool {
+        if self.inserted.contains(statement) {
+            true
+        } else if self.removed.contains(statement) {
+            false
+        } else {
+            self.graph.contains(statement)
+        }
+    }
+
+    ///
+    /// Buffer the insertion of `statement`.
+    ///
+    pub fn insert(&mut self, statement: StatementRef) -> &mut Self {
+        let _ = self.removed.remove(&statement);
+        let _ = self.inserted.insert(statement.clone());
+        let _ = self.changes.add(statement);
+        self
+    }
+
+    ///
+    /// Buffer the removal of `statement`.
+    ///
+    pub fn remove(&mut self, statement: &StatementRef) -> &mut Self {
+        let _ = self.inserted.remove(statement);
+        let _ = self.removed.insert(statement.clone());
+        let _ = self.changes.remove(statement.clone());
+        self
+    }
+
+    ///
+    /// Apply all buffered changes to the graph, returning those that changed it.
+    ///
+    pub fn commit(self) -> ChangeSet {
+        let Self {
+            graph,
+            changes,
+            mut inserted,
+            mut removed,
+        } = self;
+        // only the last change to each statement has any effect, and it is applied only if the
+        // statement is not already in the state the change leaves it in.
+        let mut applied = ChangeSet::default();
+        for change in changes.changes() {
+            match change {
+                Change::Add(statement)
+                    if inserted.remove(statement) && !graph.contains(statement) =>
+                {
+                    graph.insert(statement.clone());
+                    let _ = applied.add(statement.clone());
+                }
+                Change::Remove(statement)
+                    if removed.remove(statement) && graph.contains(statement) =>
+                {
+                    graph.remove(statement);
+                    let _ = applied.remove(statement.clone());
+                }
+                _ => {}
+            }
+        }
+        applied
+    }
+
+    ///
+    /// Run each of the `validators` over the buffered changes and, if no problem is reported,
+    /// commit them. If any problem is reported the error `ChangeSetRejected` is returned and the
+    /// graph is unchanged.
+    ///
+    pub fn commit_validated(self, validators: &[&dyn ChangeValidator]) -> Result<ChangeSet> {
+        let problems = self.changes.validate(self.graph, validators);
+        if problems.is_empty() {
+            Ok(self.commit())
+        } else {
+            Err(ErrorKind::ChangeSetRejected(problems).into())
+        }
+    }
+
+    ///
+    /// Discard all buffered changes, returning them; the graph is unchanged.
+    ///
+    pub fn rollback(self) -> ChangeSet {
+        self.changes
+    }
+}
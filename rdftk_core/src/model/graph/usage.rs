@@ -0,0 +1,521 @@
+/*!
+A decorator graph, `UsageTrackingGraph`, that records how often each subject and predicate is
+queried, and which combinations of subject, predicate, and object are bound in each query, so that
+an application may decide which indices to build and which parts of a large data set to keep in
+memory. The decorator is purely observational, each method is delegated unchanged to the graph it
+wraps, and mutation is not recorded.
+
+Queries are recorded by the methods that look up statements, `contains_subject`,
+`contains_individual`, `contains`, `contains_all`, `matches`, `statements_matching`,
+`predicates_for`, and `objects_for`; each records its `AccessPattern` and the subject and predicate,
+if any, it was given. Methods that must visit every statement, such as `statements` or
+`subjects`, are recorded as scans.
+
+As the decorator owns the graph it wraps, it should be created in a reference of its own type,
+and a clone of that reference coerced to a `GraphRef` for use by the rest of an application, so
+that the usage remains available.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::usage::{AccessPattern, UsageTrackingGraph};
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::simple::graph::SimpleGraph;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::str::FromStr;
+
+let tracked = Rc::new(RefCell::new(UsageTrackingGraph::new(SimpleGraph::default())));
+let graph: GraphRef = tracked.clone();
+
+let factory = statement_factory();
+let subject = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/s").unwrap()));
+let predicate = IRIRef::from(IRI::from_str("http://example.org/p").unwrap());
+graph.borrow_mut().insert(
+    factory
+        .statement(subject.clone(), predicate.clone(), factory.literal_object(
+            rdftk_core::simple::literal::literal_factory().literal("o"),
+        ))
+        .unwrap(),
+);
+
+assert_eq!(graph.borrow().objects_for(&subject, &predicate).len(), 1);
+
+let usage = tracked.borrow().usage();
+assert_eq!(usage.subject_count(&subject), 1);
+assert_eq!(usage.predicate_count(&predicate), 1);
+assert_eq!(usage.pattern_count(AccessPattern::new(true, true, false)), 1);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::features::Featured;
+use crate::model::graph::{
+    Graph, GraphFactoryRef, GraphRef, PrefixMappingRef, Snapshot, StatementIter,
+};
+use crate::model::literal::LiteralFactoryRef;
+use crate::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
+};
+use rdftk_iri::IRIRef;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The combination of subject, predicate, and object bound in a query; each that is `false` was
+/// a wildcard. This is displayed in the style of index names, such as `SP?` for a query with a
+/// subject and predicate but any object.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccessPattern {
+    /// `true` if the subject was bound.
+    pub subject: bool,
+    /// `true` if the predicate was bound.
+    pub predicate: bool,
+    /// `true` if the object was bound.
+    pub object: bool,
+}
+
+///
+/// The usage recorded by a `UsageTrackingGraph`.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    subjects: HashMap<SubjectNodeRef, usize>,
+    predicates: HashMap<IRIRef, usize>,
+    patterns: HashMap<AccessPattern, usize>,
+    scans: usize,
+}
+
+///
+/// A graph that records the usage of the graph it wraps; see the module documentation.
+///
+#[derive(Debug)]
+pub struct UsageTrackingGraph<G: Graph> {
+    inner: G,
+    usage: RefCell<Usage>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for AccessPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            if self.subject { 'S' } else { '?' },
+            if self.predicate { 'P' } else { '?' },
+            if self.object { 'O' } else { '?' },
+        )
+    }
+}
+
+impl AccessPattern {
+    ///
+    /// Construct a new pattern from whether each of the subject, predicate, and object is bound.
+    ///
+    pub fn new(subject: bool, predicate: bool, object: bool) -> Self {
+        Self {
+            subject,
+            predicate,
+            object,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Usage {
+    ///
+    /// Return the number of queries for `subject`.
+    ///
+    pub fn subject_count(&self, subject: &SubjectNodeRef) -> usize {
+        self.subjects.get(subject).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Return the number of queries for `predicate`.
+    ///
+    pub fn predicate_count(&self, predicate: &IRIRef) -> usize {
+        self.predicates.get(predicate).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Return the number of queries with the access pattern `pattern`.
+    ///
+    pub fn pattern_count(&self, pattern: AccessPattern) -> usize {
+        self.patterns.get(&pattern).copied().unwrap_or_default()
+    }
+
+    ///
+    /// Return the number of queries, of any pattern.
+    ///
+    pub fn queries(&self) -> usize {
+        self.patterns.values().sum()
+    }
+
+    ///
+    /// Return the number of operations that visited every statement in the graph.
+    ///
+    pub fn scans(&self) -> usize {
+        self.scans
+    }
+
+    ///
+    /// Return an iterator over each subject queried, with the number of queries for it.
+    ///
+    pub fn subjects(&self) -> impl Iterator<Item = (&SubjectNodeRef, usize)> {
+        self.subjects
+            .iter()
+            .map(|(subject, count)| (subject, *count))
+    }
+
+    ///
+    /// Return an iterator over each predicate queried, with the number of queries for it.
+    ///
+    pub fn predicates(&self) -> impl Iterator<Item = (&IRIRef, usize)> {
+        self.predicates
+            .iter()
+            .map(|(predicate, count)| (predicate, *count))
+    }
+
+    ///
+    /// Return an iterator over each access pattern used, with the number of queries using it.
+    ///
+    pub fn patterns(&self) -> impl Iterator<Item = (AccessPattern, usize)> + '_ {
+        self.patterns
+            .iter()
+            .map(|(pattern, count)| (*pattern, *count))
+    }
+
+    ///
+    /// Return, at most, `limit` subjects with the most queries, most queried first.
+    ///
+    pub fn most_queried_subjects(&self, limit: usize) -> Vec<(&SubjectNodeRef, usize)> {
+        most_queried(&self.subjects, limit)
+    }
+
+    ///
+    /// Return, at most, `limit` predicates with the most queries, most queried first.
+    ///
+    pub fn most_queried_predicates(&self, limit: usize) -> Vec<(&IRIRef, usize)> {
+        most_queried(&self.predicates, limit)
+    }
+
+    fn record(
+        &mut self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) {
+        if let Some(subject) = subject {
+            *self.subjects.entry(subject.clone()).or_default() += 1;
+        }
+        if let Some(predicate) = predicate {
+            *self.predicates.entry(predicate.clone()).or_default() += 1;
+        }
+        let pattern = AccessPattern::new(subject.is_some(), predicate.is_some(), object.is_some());
+        *self.patterns.entry(pattern).or_default() += 1;
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<G: Graph> Featured for UsageTrackingGraph<G> {
+    fn supports_feature(&self, feature: &IRIRef) -> bool {
+        self.inner.supports_feature(feature)
+    }
+}
+
+impl<G: Graph> Graph for UsageTrackingGraph<G> {
+    fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    fn contains_subject(&self, subject: &SubjectNodeRef) -> bool {
+        self.record(Some(subject), None, None);
+        self.inner.contains_subject(subject)
+    }
+
+    fn contains_individual(&self, subject: &IRIRef) -> bool {
+        let node = self
+            .inner
+            .statement_factory()
+            .named_subject(subject.clone());
+        self.record(Some(&node), None, None);
+        self.inner.contains_individual(subject)
+    }
+
+    fn contains(&self, statement: &StatementRef) -> bool {
+        self.record(
+            Some(statement.subject()),
+            Some(statement.predicate()),
+            Some(statement.object()),
+        );
+        self.inner.contains(statement)
+    }
+
+    fn contains_all(
+        &self,
+        subject: &SubjectNodeRef,
+        predicate: &IRIRef,
+        object: &ObjectNodeRef,
+    ) -> bool {
+        self.record(Some(subject), Some(predicate), Some(object));
+        self.inner.contains_all(subject, predicate, object)
+    }
+
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        self.record(subject, predicate, object);
+        self.inner.matches(subject, predicate, object)
+    }
+
+    fn statements_matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> StatementList {
+        self.record(subject, predicate, object);
+        self.inner.statements_matching(subject, predicate, object)
+    }
+
+    fn statements(&self) -> StatementIter<'_> {
+        self.scan();
+        self.inner.statements()
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        self.scan();
+        self.inner.snapshot()
+    }
+
+    fn subjects(&self) -> HashSet<&SubjectNodeRef> {
+        self.scan();
+        self.inner.subjects()
+    }
+
+    fn predicates(&self) -> HashSet<&IRIRef> {
+        self.scan();
+        self.inner.predicates()
+    }
+
+    fn predicates_for(&self, subject: &SubjectNodeRef) -> HashSet<&IRIRef> {
+        self.record(Some(subject), None, None);
+        self.inner.predicates_for(subject)
+    }
+
+    fn objects(&self) -> HashSet<&ObjectNodeRef> {
+        self.scan();
+        self.inner.objects()
+    }
+
+    fn objects_for(&self, subject: &SubjectNodeRef, predicate: &IRIRef) -> HashSet<&ObjectNodeRef> {
+        self.record(Some(subject), Some(predicate), None);
+        self.inner.objects_for(subject, predicate)
+    }
+
+    fn distinct_subjects(&self) -> Box<dyn Iterator<Item = (&SubjectNodeRef, usize)> + '_> {
+        self.scan();
+        self.inner.distinct_subjects()
+    }
+
+    fn distinct_predicates(&self) -> Box<dyn Iterator<Item = (&IRIRef, usize)> + '_> {
+        self.scan();
+        self.inner.distinct_predicates()
+    }
+
+    fn distinct_objects(&self) -> Box<dyn Iterator<Item = (&ObjectNodeRef, usize)> + '_> {
+        self.scan();
+        self.inner.distinct_objects()
+    }
+
+    fn count_by_predicate(&self) -> HashMap<&IRIRef, usize> {
+        self.scan();
+        self.inner.count_by_predicate()
+    }
+
+    fn skolemize(&self, base: &IRIRef) -> Result<GraphRef> {
+        self.scan();
+        self.inner.skolemize(base)
+    }
+
+    fn canonicalize(&self) -> Result<GraphRef> {
+        self.scan();
+        self.inner.canonicalize()
+    }
+
+    fn lean(&self) -> GraphRef {
+        self.scan();
+        self.inner.lean()
+    }
+
+    fn is_lean(&self) -> bool {
+        self.scan();
+        self.inner.is_lean()
+    }
+
+    fn union(&self, other: &dyn Graph) -> Result<GraphRef> {
+        self.scan();
+        self.inner.union(other)
+    }
+
+    fn intersection(&self, other: &dyn Graph) -> GraphRef {
+        self.scan();
+        self.inner.intersection(other)
+    }
+
+    fn difference(&self, other: &dyn Graph) -> GraphRef {
+        self.scan();
+        self.inner.difference(other)
+    }
+
+    fn prefix_mappings(&self) -> PrefixMappingRef {
+        self.inner.prefix_mappings()
+    }
+
+    fn set_prefix_mappings(&mut self, mappings: PrefixMappingRef) {
+        self.inner.set_prefix_mappings(mappings)
+    }
+
+    fn factory(&self) -> GraphFactoryRef {
+        self.inner.factory()
+    }
+
+    fn statement_factory(&self) -> StatementFactoryRef {
+        self.inner.statement_factory()
+    }
+
+    fn literal_factory(&self) -> LiteralFactoryRef {
+        self.inner.literal_factory()
+    }
+
+    fn statements_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut StatementRef> + 'a> {
+        self.inner.statements_mut()
+    }
+
+    fn insert(&mut self, statement: StatementRef) {
+        self.inner.insert(statement)
+    }
+
+    fn extend_from(&mut self, statements: &mut dyn Iterator<Item = StatementRef>) -> usize {
+        self.inner.extend_from(statements)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    fn merge(&mut self, other: &Self)
+    where
+        Self: Sized,
+    {
+        other
+            .inner
+            .statements()
+            .for_each(|st| self.inner.insert(st.clone()))
+    }
+
+    fn dedup(&mut self) -> StatementList {
+        self.inner.dedup()
+    }
+
+    fn remove(&mut self, statement: &StatementRef) {
+        self.inner.remove(statement)
+    }
+
+    fn remove_all_for(&mut self, subject: &SubjectNodeRef) -> StatementList {
+        self.inner.remove_all_for(subject)
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear()
+    }
+}
+
+impl<G: Graph> UsageTrackingGraph<G> {
+    ///
+    /// Construct a new decorator recording the usage of `inner`.
+    ///
+    pub fn new(inner: G) -> Self {
+        Self {
+            inner,
+            usage: Default::default(),
+        }
+    }
+
+    ///
+    /// Return the graph whose usage is recorded; queries made directly on it are not recorded.
+    ///
+    pub fn inner(&self) -> &G {
+        &self.inner
+    }
+
+    ///
+    /// Return the graph whose usage is recorded, discarding the usage.
+    ///
+    pub fn into_inner(self) -> G {
+        self.inner
+    }
+
+    ///
+    /// Return a copy of the usage recorded so far.
+    ///
+    pub fn usage(&self) -> Usage {
+        self.usage.borrow().clone()
+    }
+
+    ///
+    /// Discard the usage recorded so far, returning it.
+    ///
+    pub fn reset_usage(&self) -> Usage {
+        self.usage.take()
+    }
+
+    fn record(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) {
+        self.usage.borrow_mut().record(subject, predicate, object)
+    }
+
+    fn scan(&self) {
+        self.usage.borrow_mut().scans += 1;
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn most_queried<T: Eq + Hash + ToString>(
+    counts: &HashMap<T, usize>,
+    limit: usize,
+) -> Vec<(&T, usize)> {
+    let mut counts: Vec<(&T, usize)> = counts.iter().map(|(key, count)| (key, *count)).collect();
+    counts.sort_by_cached_key(|(key, count)| (std::cmp::Reverse(*count), key.to_string()));
+    counts.truncate(limit);
+    counts
+}
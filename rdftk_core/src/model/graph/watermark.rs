@@ -0,0 +1,305 @@
+/*!
+Support for watermarking a graph before publication, and for detecting that watermark in a
+suspected copy, to track the provenance of a data set.
+
+A watermark is a small number of supplementary statements, each of the form
+`<subject> <predicate> "token"`, where the predicate is chosen by the publisher and the token is
+derived from a secret key and the subject. Only a proportion of the named subjects in a graph,
+also selected using the key, are marked. Embedding a watermark only ever adds statements, it never
+alters or removes the existing statements of the graph, and the predicate used must not already
+appear in the graph being marked. Without the key it is not possible to tell which subjects should
+carry a mark, or to forge the token for a subject.
+
+Detection recomputes, for the named subjects of a suspect graph, which should be marked and which
+token each should carry; the resulting `Detection` reports the proportion of the expected marks
+that are present.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::watermark::Watermark;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let graph = graph_factory().graph();
+for i in 0..100 {
+    graph.borrow_mut().insert(
+        factory
+            .statement(
+                factory.named_subject(IRIRef::from(
+                    IRI::from_str(&format!("http://example.org/people/{}", i)).unwrap(),
+                )),
+                foaf::name().clone(),
+                factory.literal_object(literal_factory().literal(&format!("Person {}", i))),
+            )
+            .unwrap(),
+    );
+}
+
+let mut watermark = Watermark::new(
+    "a secret key",
+    IRIRef::from(IRI::from_str("http://example.org/ns/provenance#mark").unwrap()),
+);
+let _ = watermark.rate(5);
+
+let added = watermark.embed(&mut *graph.borrow_mut()).unwrap();
+assert!(added > 0);
+assert_eq!(graph.borrow().len(), 100 + added);
+
+let detection = watermark.detect(&*graph.borrow());
+assert_eq!(detection.score(), 1.0);
+assert!(detection.is_match(0.5));
+```
+
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::graph::Graph;
+use crate::model::statement::{StatementList, StatementRef};
+use rdftk_iri::IRIRef;
+use std::collections::HashSet;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The configuration used to embed, detect, and remove, a watermark.
+///
+#[derive(Clone, Debug)]
+pub struct Watermark {
+    key: String,
+    predicate: IRIRef,
+    rate: usize,
+}
+
+///
+/// The result of looking for a watermark in a graph.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Detection {
+    expected: usize,
+    found: usize,
+}
+
+///
+/// The default proportion of subjects marked, one in every `DEFAULT_RATE`.
+///
+pub const DEFAULT_RATE: usize = 10;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const SELECT_PURPOSE: u8 = 1;
+
+const TOKEN_PURPOSE: u8 = 2;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Watermark {
+    ///
+    /// Construct a new watermark using the secret `key`, marking subjects with statements using
+    /// `predicate`. The predicate should be one the publisher controls, and that is not otherwise
+    /// used in the graphs to be marked.
+    ///
+    pub fn new(key: &str, predicate: IRIRef) -> Self {
+        Self {
+            key: key.to_string(),
+            predicate,
+            rate: DEFAULT_RATE,
+        }
+    }
+
+    ///
+    /// Set the proportion of subjects to mark, one in every `rate`; a rate of `1` marks every
+    /// named subject.
+    ///
+    pub fn rate(&mut self, rate: usize) -> &mut Self {
+        self.rate = rate.max(1);
+        self
+    }
+
+    ///
+    /// Return the predicate used for watermark statements.
+    ///
+    pub fn predicate(&self) -> &IRIRef {
+        &self.predicate
+    }
+
+    ///
+    /// Returns `true` if `statement` is a watermark statement for this key and predicate.
+    ///
+    pub fn is_mark(&self, statement: &StatementRef) -> bool {
+        statement.predicate() == &self.predicate
+            && match (
+                statement.subject().as_iri(),
+                statement.object().as_literal(),
+            ) {
+                (Some(subject), Some(literal)) => {
+                    literal.data_type().is_none()
+                        && literal.language().is_none()
+                        && literal.lexical_form() == &self.token(subject)
+                }
+                _ => false,
+            }
+    }
+
+    ///
+    /// Return the watermark statements that `embed` would add to `graph`. Subjects that already
+    /// carry their mark are skipped. An error is returned if the watermark predicate is used by
+    /// any statement in `graph` that is not itself a watermark statement.
+    ///
+    pub fn marks(&self, graph: &dyn Graph) -> Result<StatementList> {
+        if graph
+            .statements_matching(None, Some(&self.predicate), None)
+            .iter()
+            .any(|statement| !self.is_mark(statement))
+        {
+            return Err(ErrorKind::WatermarkPredicateInUse(self.predicate.to_string()).into());
+        }
+        let statements = graph.statement_factory();
+        let literals = graph.literal_factory();
+        let mut marks: StatementList = Default::default();
+        for subject in self.selected(graph) {
+            let mark = statements.statement(
+                statements.named_subject(subject.clone()),
+                self.predicate.clone(),
+                statements.literal_object(literals.literal(&self.token(&subject))),
+            )?;
+            if !graph.contains(&mark) {
+                marks.push(mark);
+            }
+        }
+        Ok(marks)
+    }
+
+    ///
+    /// Add the watermark statements to `graph`, returning the number of statements added.
+    ///
+    pub fn embed(&self, graph: &mut dyn Graph) -> Result<usize> {
+        let marks = self.marks(graph)?;
+        let count = marks.len();
+        for mark in marks {
+            graph.insert(mark);
+        }
+        Ok(count)
+    }
+
+    ///
+    /// Look for the watermark in `graph`, which may be a modified copy of a marked graph.
+    ///
+    pub fn detect(&self, graph: &dyn Graph) -> Detection {
+        let expected = self.selected(graph);
+        let found = graph
+            .statements_matching(None, Some(&self.predicate), None)
+            .iter()
+            .filter(|statement| self.is_mark(statement))
+            .filter_map(|statement| statement.subject().as_iri())
+            .filter(|subject| expected.contains(*subject))
+            .collect::<HashSet<&IRIRef>>()
+            .len();
+        Detection {
+            expected: expected.len(),
+            found,
+        }
+    }
+
+    ///
+    /// Remove all watermark statements from `graph`, returning the statements removed.
+    ///
+    pub fn strip(&self, graph: &mut dyn Graph) -> StatementList {
+        let marks: StatementList = graph
+            .statements_matching(None, Some(&self.predicate), None)
+            .into_iter()
+            .filter(|statement| self.is_mark(statement))
+            .collect();
+        for mark in &marks {
+            graph.remove(mark);
+        }
+        marks
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn selected(&self, graph: &dyn Graph) -> HashSet<IRIRef> {
+        graph
+            .statements()
+            .filter(|statement| statement.predicate() != &self.predicate)
+            .filter_map(|statement| statement.subject().as_iri())
+            .filter(|subject| {
+                self.hash(SELECT_PURPOSE, subject)
+                    .is_multiple_of(self.rate as u64)
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn token(&self, subject: &IRIRef) -> String {
+        format!("{:016x}", self.hash(TOKEN_PURPOSE, subject))
+    }
+
+    fn hash(&self, purpose: u8, subject: &IRIRef) -> u64 {
+        // FNV-1a is used, rather than `DefaultHasher`, as its output must be stable across
+        // releases so that published graphs remain detectable.
+        let mut hash = FNV_OFFSET_BASIS;
+        for byte in self
+            .key
+            .bytes()
+            .chain(std::iter::once(purpose))
+            .chain(subject.to_string().bytes())
+        {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(FNV_PRIME);
+        }
+        hash
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Detection {
+    ///
+    /// The number of subjects in the graph that would carry a mark.
+    ///
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    ///
+    /// The number of subjects in the graph that carry their mark.
+    ///
+    pub fn found(&self) -> usize {
+        self.found
+    }
+
+    ///
+    /// The proportion of expected marks found, between `0.0` and `1.0`; a graph with no
+    /// expected marks scores `0.0`.
+    ///
+    pub fn score(&self) -> f64 {
+        if self.expected == 0 {
+            0.0
+        } else {
+            self.found as f64 / self.expected as f64
+        }
+    }
+
+    ///
+    /// Returns `true` if the score is at least `threshold`.
+    ///
+    pub fn is_match(&self, threshold: f64) -> bool {
+        self.expected > 0 && self.score() >= threshold
+    }
+}
@@ -39,8 +39,16 @@ pub enum DataType {
     UnsignedShort,
     /// Denotes a literal of type `xsd::unsignedByte`.
     UnsignedByte,
+    /// Denotes a literal of type `xsd::decimal`, of arbitrary size and precision.
+    Decimal,
+    /// Denotes a literal of type `xsd::integer`, of arbitrary size.
+    Integer,
     /// Denotes a literal of type `xsd::duration`.
     Duration,
+    /// Denotes a literal of type `xsd::date`.
+    Date,
+    /// Denotes a literal of type `xsd::dateTime`.
+    DateTime,
     /// Denotes an escaped string containing XML content.
     XmlLiteral,
     /// Denotes a literal where the type is indicated by the provided `IRI`.
@@ -81,8 +89,16 @@ impl From<IRIRef> for DataType {
             DataType::UnsignedShort
         } else if &iri == xsd::unsigned_byte() {
             DataType::UnsignedByte
+        } else if &iri == xsd::decimal() {
+            DataType::Decimal
+        } else if &iri == xsd::integer() {
+            DataType::Integer
         } else if &iri == xsd::duration() {
             DataType::Duration
+        } else if &iri == xsd::date() {
+            DataType::Date
+        } else if &iri == xsd::date_time() {
+            DataType::DateTime
         } else if &iri == rdf::xml_literal() {
             DataType::XmlLiteral
         } else {
@@ -112,7 +128,11 @@ impl DataType {
             DataType::UnsignedInt => xsd::unsigned_int(),
             DataType::UnsignedShort => xsd::unsigned_short(),
             DataType::UnsignedByte => xsd::unsigned_byte(),
+            DataType::Decimal => xsd::decimal(),
+            DataType::Integer => xsd::integer(),
             DataType::Duration => xsd::duration(),
+            DataType::Date => xsd::date(),
+            DataType::DateTime => xsd::date_time(),
             DataType::XmlLiteral => rdf::xml_literal(),
             DataType::Other(iri) => iri,
         }
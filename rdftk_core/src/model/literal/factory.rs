@@ -5,7 +5,8 @@ for use in the associated graph. It is possible that all graphs provided by some
 a common value factory by store rather than by graph.
 */
 
-use crate::error::Result;
+use crate::error::{ErrorKind, Result};
+use crate::model::literal::value;
 use crate::model::literal::{DataType, LanguageTag, LiteralRef};
 use crate::model::Provided;
 use rdftk_iri::IRIRef;
@@ -37,6 +38,16 @@ pub trait LiteralFactory: Debug + Provided {
     /// Returns a cached literal value with the provided string and data type.
     fn with_data_type(&self, v: &str, data_type: DataType) -> LiteralRef;
 
+    /// Returns a cached literal value with the provided string and data type, or an error if the
+    /// string is not a valid lexical form for the data type.
+    fn with_data_type_checked(&self, v: &str, data_type: DataType) -> Result<LiteralRef> {
+        if value::is_valid_lexical_form(v, &data_type) {
+            Ok(self.with_data_type(v, data_type))
+        } else {
+            Err(ErrorKind::InvalidFromStr(v.to_string(), data_type.as_iri().to_string()).into())
+        }
+    }
+
     /// Returns a cached literal value with the provided string.
     fn string(&self, v: &str) -> LiteralRef {
         self.with_data_type(v, DataType::String)
@@ -107,6 +118,30 @@ pub trait LiteralFactory: Debug + Provided {
         self.with_data_type(&v.to_string(), DataType::UnsignedByte)
     }
 
+    /// Returns a cached literal value with the provided decimal string, which may be of any size
+    /// or precision, or an error if it is not a valid decimal.
+    fn decimal_str(&self, v: &str) -> Result<LiteralRef> {
+        self.with_data_type_checked(v, DataType::Decimal)
+    }
+
+    /// Returns a cached literal value with the provided decimal.
+    #[cfg(feature = "decimal_types")]
+    fn decimal(&self, v: rust_decimal::Decimal) -> LiteralRef {
+        self.with_data_type(&value::decimal_lexical_form(v), DataType::Decimal)
+    }
+
+    /// Returns a cached literal value with the provided integer string, which may be of any size,
+    /// or an error if it is not a valid integer.
+    fn integer_str(&self, v: &str) -> Result<LiteralRef> {
+        self.with_data_type_checked(v, DataType::Integer)
+    }
+
+    /// Returns a cached literal value with the provided integer.
+    #[cfg(feature = "bigint_types")]
+    fn integer(&self, v: num_bigint::BigInt) -> LiteralRef {
+        self.with_data_type(&v.to_string(), DataType::Integer)
+    }
+
     /// Returns a cached literal value with the provided duration.
     fn duration(&self, v: Duration) -> LiteralRef {
         self.chrono_duration(chrono::Duration::from_std(v).unwrap())
@@ -117,6 +152,18 @@ pub trait LiteralFactory: Debug + Provided {
     fn chrono_duration(&self, v: chrono::Duration) -> LiteralRef {
         self.with_data_type(&v.to_string(), DataType::Duration)
     }
+
+    /// Returns a cached literal value with the provided date.
+    #[cfg(feature = "chrono_types")]
+    fn date(&self, v: chrono::NaiveDate) -> LiteralRef {
+        self.with_data_type(&value::date_lexical_form(v), DataType::Date)
+    }
+
+    /// Returns a cached literal value with the provided UTC date and time.
+    #[cfg(feature = "chrono_types")]
+    fn date_time(&self, v: chrono::DateTime<chrono::Utc>) -> LiteralRef {
+        self.with_data_type(&value::date_time_lexical_form(v), DataType::DateTime)
+    }
 }
 
 pub type LiteralFactoryRef = Arc<dyn LiteralFactory>;
@@ -4,8 +4,10 @@ Languages](https://www.rfc-editor.org/rfc/rfc5646.html). It provides a `Language
 the enclosed structs `Tag` and `Extension`.
 
 This implementation does parse correctly according to the RFC, section 2.1 Syntax, and implements
-the correct rules for case-insensitive equality and some content checks. However, it does not
-perform all the described validation or canonicalization.
+the correct rules for case-insensitive equality, some content checks, and the case conventions of
+canonicalization. However, it does not perform all the described validation or canonicalization.
+Tags may also be matched against language ranges using the *basic filtering* scheme of
+[RFC-4647](https://www.rfc-editor.org/rfc/rfc4647.html#section-3.3.1).
 
 # Conformance
 
@@ -24,7 +26,16 @@ TBD
 
 ## Canonicalization
 
-TBD
+The method `to_canonical_format` applies the case conventions of section 2.1.1; language, variant,
+extension, and private use sub-tags are lower case, script sub-tags are title case, and region
+sub-tags are upper case. Extensions are also ordered by their singleton. Replacement of deprecated
+sub-tags, which requires the IANA registry, is not performed. A tag is always displayed in the case
+it was parsed with, so that `zh-Hant` is not displayed as `zh-hant`.
+
+## Matching
+
+The method `matches` implements basic filtering, a range matches a tag if it is equal to the tag,
+or to a prefix of the tag followed by `-`, ignoring case; the range `*` matches any tag.
 
 # Example
 
@@ -45,6 +56,12 @@ if let LanguageTag::Tag(tag) = language_tag {
     assert_eq!(tag.extensions().count(), 0);
     assert_eq!(tag.private_use().count(), 0);
 }
+
+let language_tag = LanguageTag::from_str("ZH-hant-tw").unwrap();
+assert_eq!(language_tag.to_string(), "ZH-hant-tw");
+assert_eq!(language_tag.to_canonical_format().to_string(), "zh-Hant-TW");
+assert!(language_tag.matches("zh-Hant"));
+assert!(!language_tag.matches("zh-Hans"));
 ```
 
 */
@@ -190,6 +207,35 @@ impl LanguageTag {
     pub fn is_private_use(&self) -> bool {
         matches!(self, Self::PrivateUse(_))
     }
+
+    ///
+    /// Return a copy of this tag with the case conventions of
+    /// [section 2.1.1](https://www.rfc-editor.org/rfc/rfc5646.html#section-2.1.1) applied.
+    ///
+    pub fn to_canonical_format(&self) -> Self {
+        match self {
+            Self::Tag(tag) => Self::Tag(tag.to_canonical_format()),
+            Self::PrivateUse(sub_tags) => Self::PrivateUse(to_lowercase(sub_tags)),
+            Self::Grandfathered(tag) => Self::Grandfathered(tag.clone()),
+        }
+    }
+
+    ///
+    /// Returns `true` if this tag matches the language `range` using the *basic filtering*
+    /// scheme of [RFC-4647](https://www.rfc-editor.org/rfc/rfc4647.html#section-3.3.1), else
+    /// `false`. An empty range matches no tag.
+    ///
+    pub fn matches(&self, range: &str) -> bool {
+        let range = range.trim();
+        if range == "*" {
+            return true;
+        }
+        let tag = self.to_string();
+        tag.len() >= range.len()
+            && !range.is_empty()
+            && tag[..range.len()].eq_ignore_ascii_case(range)
+            && (tag.len() == range.len() || tag[range.len()..].starts_with(LANG_SEP))
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -197,6 +243,9 @@ impl LanguageTag {
 impl Display for Tag {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", &self.language)?;
+        if let Some(script) = &self.script {
+            write!(f, "{}{}", LANG_SEP, script)?;
+        }
         if let Some(region) = &self.region {
             write!(f, "{}{}", LANG_SEP, region)?;
         }
@@ -206,8 +255,14 @@ impl Display for Tag {
         for extension in &self.extensions {
             write!(f, "{}{}", LANG_SEP, extension)?;
         }
-        for private_use in &self.private_use {
-            write!(f, "{}{}", LANG_SEP, private_use)?;
+        if !self.private_use.is_empty() {
+            write!(
+                f,
+                "{}x{}{}",
+                LANG_SEP,
+                LANG_SEP,
+                self.private_use.join(LANG_SEP)
+            )?;
         }
         Ok(())
     }
@@ -353,16 +408,24 @@ impl Tag {
         let region = self.region.as_ref().map(|s| s.to_uppercase());
 
         // Extension sequences are ordered into case-insensitive ASCII order by singleton subtag.
-        let mut extensions = self.extensions.clone();
+        let mut extensions: Vec<Extension> = self
+            .extensions
+            .iter()
+            .map(|e| Extension {
+                singleton: e.singleton.to_ascii_lowercase(),
+                sub_tags: to_lowercase(&e.sub_tags),
+            })
+            .collect();
         extensions.sort_by_key(|e| e.singleton);
 
+        // All other subtags, including extension and private use subtags, use lowercase letters.
         Self {
             language,
             script,
             region,
-            variants: self.variants.clone(),
+            variants: to_lowercase(&self.variants),
             extensions,
-            private_use: self.private_use.clone(),
+            private_use: to_lowercase(&self.private_use),
         }
     }
 }
@@ -381,13 +444,14 @@ impl Display for Extension {
 
 impl PartialEq for Extension {
     fn eq(&self, other: &Self) -> bool {
-        self.singleton == other.singleton && vec_eq_ignore_case(&self.sub_tags, &other.sub_tags)
+        self.singleton.eq_ignore_ascii_case(&other.singleton)
+            && vec_eq_ignore_case(&self.sub_tags, &other.sub_tags)
     }
 }
 
 impl Hash for Extension {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.singleton.hash(state);
+        self.singleton.to_ascii_lowercase().hash(state);
         self.sub_tags
             .iter()
             .map(|s| s.to_ascii_lowercase())
@@ -508,6 +572,10 @@ fn private_use(input_pair: Pair<'_, Rule>) -> Result<Vec<String>, Error> {
     Ok(sub_tags.split(LANG_SEP).map(str::to_string).collect())
 }
 
+fn to_lowercase(values: &[String]) -> Vec<String> {
+    values.iter().map(|s| s.to_ascii_lowercase()).collect()
+}
+
 fn option_eq_ignore_case(lhs: &Option<String>, rhs: &Option<String>) -> bool {
     match (lhs, rhs) {
         (None, None) => true,
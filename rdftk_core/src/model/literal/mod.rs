@@ -39,12 +39,36 @@
 * assert_eq!(duration_literal.data_type(), Some(&DataType::Duration));
 * ```
 *
+* Typed values may be retrieved from a literal, the lexical form is validated against the literal's
+* data type and an error is returned if either the data type is not compatible with the requested
+* Rust type or the lexical form is not valid.
+*
+* ```rust
+* use rdftk_core::model::literal::{Literal, DataType};
+* use rdftk_core::simple::literal::literal_factory;
+* use std::time::Duration;
+*
+* let factory = literal_factory();
+*
+* assert_eq!(factory.int(-42).as_i64().unwrap(), -42);
+* assert_eq!(factory.with_data_type(" 1 ", DataType::Boolean).as_bool().unwrap(), true);
+* assert_eq!(factory.duration(Duration::from_secs(63542)).as_duration().unwrap().as_secs(), 63542);
+* assert!(factory.string("42").as_i64().is_err());
+* assert!(factory.with_data_type("300", DataType::Byte).as_i64().is_err());
+*
+* let double_literal = factory.with_data_type("+0100.50", DataType::Double);
+* assert_eq!(double_literal.canonicalize().unwrap(), "1.005E2");
+* ```
+*
 */
 
+use crate::error::Result;
 use crate::model::Equiv;
+use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -53,7 +77,7 @@ use std::rc::Rc;
 ///
 /// This trait describes an RDF literal which may be the object of a statement.
 ///
-pub trait Literal: Debug {
+pub trait Literal: Debug + Send + Sync {
     /// Return the lexical form of this literal.
     fn lexical_form(&self) -> &String;
 
@@ -75,6 +99,16 @@ pub trait Literal: Debug {
     ///
     fn language(&self) -> Option<&LanguageTag>;
 
+    ///
+    /// Returns `true` if this literal has a language tag that matches the language `range`, such
+    /// as `"en"` or `"*"`, see `LanguageTag::matches`.
+    ///
+    fn language_matches(&self, range: &str) -> bool {
+        self.language()
+            .map(|language| language.matches(range))
+            .unwrap_or_default()
+    }
+
     ///
     /// Return the factory that creates literals using the same provider as `self`.
     ///
@@ -82,12 +116,136 @@ pub trait Literal: Debug {
     /// usage.
     ///
     fn factory(&self) -> LiteralFactoryRef;
+
+    ///
+    /// Return the value of this literal as a boolean, this requires the data type `xsd:boolean`.
+    ///
+    fn as_bool(&self) -> Result<bool> {
+        value::as_bool(self)
+    }
+
+    ///
+    /// Return the value of this literal as a signed integer, this requires `xsd:integer` or one
+    /// of its derived data types and that the value fits in an `i64`.
+    ///
+    fn as_i64(&self) -> Result<i64> {
+        value::as_i64(self)
+    }
+
+    ///
+    /// Return the value of this literal as an unsigned integer, this requires `xsd:integer` or
+    /// one of its derived data types and that the value fits in a `u64`.
+    ///
+    fn as_u64(&self) -> Result<u64> {
+        value::as_u64(self)
+    }
+
+    ///
+    /// Return the value of this literal as a double, this requires `xsd:float`, `xsd:double`,
+    /// `xsd:decimal`, or one of the integer data types. Decimal and integer values may lose
+    /// precision.
+    ///
+    fn as_f64(&self) -> Result<f64> {
+        value::as_f64(self)
+    }
+
+    ///
+    /// Return the value of this literal as a decimal, this requires `xsd:decimal`, `xsd:integer`,
+    /// or one of its derived data types, and that the value fits in a `rust_decimal::Decimal`.
+    ///
+    #[cfg(feature = "decimal_types")]
+    fn as_decimal(&self) -> Result<rust_decimal::Decimal> {
+        value::as_decimal(self)
+    }
+
+    ///
+    /// Return the value of this literal as an integer of any size, this requires `xsd:integer` or
+    /// one of its derived data types.
+    ///
+    #[cfg(feature = "bigint_types")]
+    fn as_big_integer(&self) -> Result<num_bigint::BigInt> {
+        value::as_big_integer(self)
+    }
+
+    ///
+    /// Return the value of this literal as a duration, this requires `xsd:duration` or one of its
+    /// derived data types, and that the value is neither negative nor includes years or months.
+    ///
+    fn as_duration(&self) -> Result<Duration> {
+        value::as_duration(self)
+    }
+
+    ///
+    /// Return the value of this literal as a, possibly negative, chrono duration, this requires
+    /// `xsd:duration` or one of its derived data types, and that the value does not include
+    /// years or months.
+    ///
+    #[cfg(feature = "chrono_types")]
+    fn as_chrono_duration(&self) -> Result<chrono::Duration> {
+        value::as_chrono_duration(self)
+    }
+
+    ///
+    /// Return the value of this literal as a date, this requires `xsd:date`; any timezone is
+    /// ignored.
+    ///
+    #[cfg(feature = "chrono_types")]
+    fn as_date(&self) -> Result<chrono::NaiveDate> {
+        value::as_date(self)
+    }
+
+    ///
+    /// Return the value of this literal as a date and time, this requires `xsd:dateTime` or
+    /// `xsd:dateTimeStamp`. A value without a timezone is assumed to be in UTC, `time_zone`
+    /// distinguishes such values.
+    ///
+    #[cfg(feature = "chrono_types")]
+    fn as_date_time(&self) -> Result<chrono::DateTime<chrono::FixedOffset>> {
+        value::as_date_time(self)
+    }
+
+    ///
+    /// Return the timezone of this literal as an offset from UTC in minutes, or `None` if the
+    /// value has no timezone, this requires `xsd:date`, `xsd:dateTime`, or `xsd:dateTimeStamp`.
+    ///
+    fn time_zone(&self) -> Result<Option<i32>> {
+        value::time_zone(self)
+    }
+
+    ///
+    /// Compare the values of two dates, or of two date times, following the XML Schema order on
+    /// the timeline. This returns `None` where the order is indeterminate, where only one value
+    /// has a timezone and they are within 14 hours of each other, and an error if the literals
+    /// are not both dates or both date times.
+    ///
+    fn compare_date_time(&self, other: &dyn Literal) -> Result<Option<Ordering>> {
+        value::compare_date_time(self, other)
+    }
+
+    ///
+    /// Return the canonical lexical form of this literal's value, as defined by XML Schema for
+    /// its data type; an error is returned if the current lexical form is not valid for the data
+    /// type. Literals without a data type, or with a data type that has no canonical form
+    /// defined here, return their lexical form unchanged.
+    ///
+    fn canonicalize(&self) -> Result<String> {
+        value::canonicalize(self)
+    }
+
+    ///
+    /// Return the canonical lexical form of this literal's value, as `canonicalize`, except that
+    /// a date time with a timezone is normalized to UTC, so that equal instants have the same
+    /// form.
+    ///
+    fn canonicalize_utc(&self) -> Result<String> {
+        value::canonicalize_utc(self)
+    }
 }
 
 ///
 /// The actual object storage type, reference counted for memory management.
 ///
-pub type LiteralRef = Rc<dyn Literal>;
+pub type LiteralRef = Arc<dyn Literal>;
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
@@ -148,3 +306,6 @@ pub use factory::*;
 #[doc(hidden)]
 mod lang;
 pub use lang::*;
+
+mod value;
+pub(crate) use value::system_time_lexical_form;
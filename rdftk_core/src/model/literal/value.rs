@@ -0,0 +1,1058 @@
+/*!
+Parsing, validation, and canonicalization of the lexical forms of the XML Schema data types; this
+provides the implementation of the typed value accessors on `Literal`.
+
+Lexical forms follow [XML Schema 1.1 Part 2](https://www.w3.org/TR/xmlschema11-2/), leading and
+trailing whitespace is ignored for all types other than strings. Canonical forms are those defined
+by the XML Schema 1.1 canonical mappings, so that an integral `xsd:decimal` has no decimal point
+and `xsd:float` and `xsd:double` values are always in scientific notation.
+
+Dates and date times are ordered on a timeline, as in section D.2 of XML Schema 1.1 Part 2;
+values with a timezone are compared in UTC and values without one are compared as they are. A
+value with a timezone and one without are ordered only if they differ by more than the 14 hour
+range of timezones, otherwise the comparison is indeterminate.
+*/
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::model::literal::{DataType, Literal};
+use rdftk_names::{rdf, xsd};
+use std::any::type_name;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The value space a data type belongs to, and therefore how its lexical form is parsed.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Boolean,
+    Integer(Option<i128>, Option<i128>),
+    Decimal,
+    Float,
+    Double,
+    Duration,
+    Date,
+    DateTime,
+    Other,
+}
+
+///
+/// A parsed `xsd:integer`, or one of its derived types; the digits have no leading zeros.
+///
+#[derive(Clone, Debug, PartialEq)]
+struct Integer {
+    negative: bool,
+    digits: String,
+}
+
+///
+/// A parsed `xsd:decimal`; neither part has redundant zeros, so that zero has empty parts.
+///
+#[derive(Clone, Debug, PartialEq)]
+struct Decimal {
+    negative: bool,
+    integer: String,
+    fraction: String,
+}
+
+///
+/// A parsed `xsd:duration`, normalized into months and seconds as in the XML Schema value space.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct XsdDuration {
+    negative: bool,
+    months: u64,
+    seconds: u64,
+    nanos: u32,
+}
+
+///
+/// A parsed `xsd:date`, the offset is the timezone in minutes, if present.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct XsdDate {
+    year: i64,
+    month: u32,
+    day: u32,
+    offset: Option<i32>,
+}
+
+///
+/// A parsed `xsd:dateTime`, the offset is the timezone in minutes, if present.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct XsdDateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanos: u32,
+    offset: Option<i32>,
+}
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+const MINUTES_PER_DAY: i64 = 1_440;
+
+/// The largest offset of any timezone, in minutes.
+const MAX_TIME_ZONE: i32 = 14 * 60;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(crate) fn as_bool<L: Literal + ?Sized>(literal: &L) -> Result<bool> {
+    match kind(literal.data_type()) {
+        Kind::Boolean => parse_boolean(trimmed(literal)).ok_or_else(|| invalid_lexical(literal)),
+        _ => Err(incompatible::<bool, L>(literal)),
+    }
+}
+
+pub(crate) fn as_i64<L: Literal + ?Sized>(literal: &L) -> Result<i64> {
+    integer_value(literal)
+}
+
+pub(crate) fn as_u64<L: Literal + ?Sized>(literal: &L) -> Result<u64> {
+    integer_value(literal)
+}
+
+pub(crate) fn as_f64<L: Literal + ?Sized>(literal: &L) -> Result<f64> {
+    let value = trimmed(literal);
+    match kind(literal.data_type()) {
+        Kind::Float => parse_float(value).map(|v| v as f32 as f64),
+        Kind::Double => parse_float(value),
+        Kind::Decimal => parse_decimal(value).and_then(|_| value.parse().ok()),
+        Kind::Integer(min, max) => parse_integer(value, min, max).and_then(|_| value.parse().ok()),
+        _ => return Err(incompatible::<f64, L>(literal)),
+    }
+    .ok_or_else(|| invalid_lexical(literal))
+}
+
+pub(crate) fn as_duration<L: Literal + ?Sized>(literal: &L) -> Result<Duration> {
+    let duration = duration_value::<Duration, L>(literal)?;
+    if duration.months != 0 || (duration.negative && !duration.is_zero()) {
+        Err(out_of_range::<Duration, L>(literal))
+    } else {
+        Ok(Duration::new(duration.seconds, duration.nanos))
+    }
+}
+
+#[cfg(feature = "chrono_types")]
+pub(crate) fn as_chrono_duration<L: Literal + ?Sized>(literal: &L) -> Result<chrono::Duration> {
+    let duration = duration_value::<chrono::Duration, L>(literal)?;
+    if duration.months != 0 {
+        return Err(out_of_range::<chrono::Duration, L>(literal));
+    }
+    let value = i64::try_from(duration.seconds)
+        .ok()
+        .and_then(chrono::Duration::try_seconds)
+        .and_then(|seconds| {
+            seconds.checked_add(&chrono::Duration::nanoseconds(duration.nanos as i64))
+        })
+        .ok_or_else(|| out_of_range::<chrono::Duration, L>(literal))?;
+    Ok(if duration.negative { -value } else { value })
+}
+
+#[cfg(feature = "chrono_types")]
+pub(crate) fn as_date_time<L: Literal + ?Sized>(
+    literal: &L,
+) -> Result<chrono::DateTime<chrono::FixedOffset>> {
+    use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+    let value = match kind(literal.data_type()) {
+        Kind::DateTime => parse_date_time(trimmed(literal))
+            .map(XsdDateTime::normalized)
+            .ok_or_else(|| invalid_lexical(literal))?,
+        _ => return Err(incompatible::<chrono::DateTime<FixedOffset>, L>(literal)),
+    };
+    i32::try_from(value.year)
+        .ok()
+        .and_then(|year| NaiveDate::from_ymd_opt(year, value.month, value.day))
+        .and_then(|date| date.and_hms_nano_opt(value.hour, value.minute, value.second, value.nanos))
+        .and_then(|date_time| {
+            FixedOffset::east_opt(value.offset.unwrap_or_default() * 60)?
+                .from_local_datetime(&date_time)
+                .single()
+        })
+        .ok_or_else(|| out_of_range::<chrono::DateTime<FixedOffset>, L>(literal))
+}
+
+#[cfg(feature = "chrono_types")]
+pub(crate) fn as_date<L: Literal + ?Sized>(literal: &L) -> Result<chrono::NaiveDate> {
+    let value = match kind(literal.data_type()) {
+        Kind::Date => parse_date(trimmed(literal)).ok_or_else(|| invalid_lexical(literal))?,
+        _ => return Err(incompatible::<chrono::NaiveDate, L>(literal)),
+    };
+    i32::try_from(value.year)
+        .ok()
+        .and_then(|year| chrono::NaiveDate::from_ymd_opt(year, value.month, value.day))
+        .ok_or_else(|| out_of_range::<chrono::NaiveDate, L>(literal))
+}
+
+#[cfg(feature = "chrono_types")]
+pub(crate) fn date_lexical_form(value: chrono::NaiveDate) -> String {
+    use chrono::Datelike;
+
+    XsdDate {
+        year: value.year() as i64,
+        month: value.month(),
+        day: value.day(),
+        offset: None,
+    }
+    .to_string()
+}
+
+#[cfg(feature = "chrono_types")]
+pub(crate) fn date_time_lexical_form(value: chrono::DateTime<chrono::Utc>) -> String {
+    use chrono::{Datelike, Timelike};
+
+    XsdDateTime {
+        year: value.year() as i64,
+        month: value.month(),
+        day: value.day(),
+        hour: value.hour(),
+        minute: value.minute(),
+        second: value.second(),
+        // chrono represents a leap second as nanoseconds beyond the last second of the minute.
+        nanos: value.nanosecond().min(999_999_999),
+        offset: Some(0),
+    }
+    .to_string()
+}
+
+pub(crate) fn system_time_lexical_form(value: SystemTime) -> String {
+    let nanos = match value.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_nanos() as i128,
+        Err(e) => -(e.duration().as_nanos() as i128),
+    };
+    let seconds = nanos.div_euclid(NANOS_PER_SECOND) as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY as i64));
+    let seconds = seconds.rem_euclid(SECONDS_PER_DAY as i64) as u32;
+    XsdDateTime {
+        year,
+        month,
+        day,
+        hour: seconds / 3600,
+        minute: seconds / 60 % 60,
+        second: seconds % 60,
+        nanos: nanos.rem_euclid(NANOS_PER_SECOND) as u32,
+        offset: Some(0),
+    }
+    .to_string()
+}
+
+pub(crate) fn time_zone<L: Literal + ?Sized>(literal: &L) -> Result<Option<i32>> {
+    Ok(instant::<Option<i32>, L>(literal)?.1)
+}
+
+pub(crate) fn compare_date_time<L, R>(lhs: &L, rhs: &R) -> Result<Option<Ordering>>
+where
+    L: Literal + ?Sized,
+    R: Literal + ?Sized,
+{
+    let lhs_kind = kind(lhs.data_type());
+    let (lhs, lhs_offset) = instant::<Ordering, L>(lhs)?;
+    if kind(rhs.data_type()) != lhs_kind {
+        return Err(incompatible::<Ordering, R>(rhs));
+    }
+    let (rhs, rhs_offset) = instant::<Ordering, R>(rhs)?;
+    // a value without a timezone lies somewhere in the range of instants it would be at in each
+    // of the possible timezones.
+    let range = MAX_TIME_ZONE as i128 * 60 * NANOS_PER_SECOND;
+    Ok(match (lhs_offset, rhs_offset) {
+        (Some(_), None) if lhs < rhs - range => Some(Ordering::Less),
+        (Some(_), None) if lhs > rhs + range => Some(Ordering::Greater),
+        (None, Some(_)) if lhs + range < rhs => Some(Ordering::Less),
+        (None, Some(_)) if lhs - range > rhs => Some(Ordering::Greater),
+        (Some(_), None) | (None, Some(_)) => None,
+        _ => Some(lhs.cmp(&rhs)),
+    })
+}
+
+pub(crate) fn canonicalize_utc<L: Literal + ?Sized>(literal: &L) -> Result<String> {
+    match kind(literal.data_type()) {
+        Kind::DateTime => parse_date_time(trimmed(literal))
+            .map(|value| value.normalized().to_utc().to_string())
+            .ok_or_else(|| invalid_lexical(literal)),
+        _ => canonicalize(literal),
+    }
+}
+
+#[cfg(feature = "decimal_types")]
+pub(crate) fn as_decimal<L: Literal + ?Sized>(literal: &L) -> Result<rust_decimal::Decimal> {
+    let value = match kind(literal.data_type()) {
+        Kind::Decimal | Kind::Integer(_, _) => {
+            canonical_form(trimmed(literal), kind(literal.data_type()))
+                .ok_or_else(|| invalid_lexical(literal))?
+        }
+        _ => return Err(incompatible::<rust_decimal::Decimal, L>(literal)),
+    };
+    rust_decimal::Decimal::from_str_exact(&value)
+        .map_err(|_| out_of_range::<rust_decimal::Decimal, L>(literal))
+}
+
+#[cfg(feature = "decimal_types")]
+pub(crate) fn decimal_lexical_form(value: rust_decimal::Decimal) -> String {
+    let value = value.to_string();
+    canonical_form(&value, Kind::Decimal).unwrap_or(value)
+}
+
+#[cfg(feature = "bigint_types")]
+pub(crate) fn as_big_integer<L: Literal + ?Sized>(literal: &L) -> Result<num_bigint::BigInt> {
+    match kind(literal.data_type()) {
+        Kind::Integer(min, max) => parse_integer(trimmed(literal), min, max)
+            .ok_or_else(|| invalid_lexical(literal))?
+            .to_string()
+            .parse()
+            .map_err(|_| out_of_range::<num_bigint::BigInt, L>(literal)),
+        _ => Err(incompatible::<num_bigint::BigInt, L>(literal)),
+    }
+}
+
+pub(crate) fn canonicalize<L: Literal + ?Sized>(literal: &L) -> Result<String> {
+    match kind(literal.data_type()) {
+        Kind::Other => Ok(literal.lexical_form().clone()),
+        kind => canonical_form(trimmed(literal), kind).ok_or_else(|| invalid_lexical(literal)),
+    }
+}
+
+pub(crate) fn is_valid_lexical_form(value: &str, data_type: &DataType) -> bool {
+    match kind(Some(data_type)) {
+        Kind::Other => true,
+        kind => canonical_form(value.trim(), kind).is_some(),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for Integer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}{}", if self.negative { "-" } else { "" }, self.digits)
+        }
+    }
+}
+
+impl Integer {
+    fn in_range(&self, min: Option<i128>, max: Option<i128>) -> bool {
+        match self.to_string().parse::<i128>() {
+            Ok(value) => {
+                !matches!(min, Some(min) if value < min) && !matches!(max, Some(max) if value > max)
+            }
+            Err(_) => {
+                if self.negative {
+                    min.is_none()
+                } else {
+                    max.is_none()
+                }
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.integer.is_empty() && self.fraction.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(
+                f,
+                "{}{}",
+                if self.negative { "-" } else { "" },
+                if self.integer.is_empty() {
+                    "0"
+                } else {
+                    &self.integer
+                }
+            )?;
+            if !self.fraction.is_empty() {
+                write!(f, ".{}", self.fraction)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for XsdDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "PT0S");
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        let (years, months) = (self.months / 12, self.months % 12);
+        if years > 0 {
+            write!(f, "{}Y", years)?;
+        }
+        if months > 0 {
+            write!(f, "{}M", months)?;
+        }
+        let days = self.seconds / SECONDS_PER_DAY;
+        let hours = (self.seconds % SECONDS_PER_DAY) / 3600;
+        let minutes = (self.seconds % 3600) / 60;
+        let seconds = self.seconds % 60;
+        if days > 0 {
+            write!(f, "{}D", days)?;
+        }
+        if hours > 0 || minutes > 0 || seconds > 0 || self.nanos > 0 {
+            write!(f, "T")?;
+            if hours > 0 {
+                write!(f, "{}H", hours)?;
+            }
+            if minutes > 0 {
+                write!(f, "{}M", minutes)?;
+            }
+            if seconds > 0 || self.nanos > 0 {
+                write!(f, "{}{}S", seconds, fraction_string(self.nanos))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl XsdDuration {
+    fn is_zero(&self) -> bool {
+        self.months == 0 && self.seconds == 0 && self.nanos == 0
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for XsdDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_date(f, self.year, self.month, self.day)?;
+        write_time_zone(f, self.offset)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl std::fmt::Display for XsdDateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_date(f, self.year, self.month, self.day)?;
+        write!(
+            f,
+            "T{:02}:{:02}:{:02}{}",
+            self.hour,
+            self.minute,
+            self.second,
+            fraction_string(self.nanos)
+        )?;
+        write_time_zone(f, self.offset)
+    }
+}
+
+impl XsdDateTime {
+    ///
+    /// Replace the end of day time, `24:00:00`, with the start of the following day.
+    ///
+    fn normalized(self) -> Self {
+        if self.hour != 24 {
+            return self;
+        }
+        let mut result = Self { hour: 0, ..self };
+        if result.day < days_in_month(result.year, result.month) {
+            result.day += 1;
+        } else if result.month < 12 {
+            result.day = 1;
+            result.month += 1;
+        } else {
+            result.day = 1;
+            result.month = 1;
+            result.year += 1;
+        }
+        result
+    }
+
+    ///
+    /// Return the same instant in UTC; a value without a timezone is returned unchanged.
+    ///
+    fn to_utc(self) -> Self {
+        let offset = match self.offset {
+            None | Some(0) => return self,
+            Some(offset) => offset as i64,
+        };
+        let minutes = (self.hour * 60 + self.minute) as i64 - offset;
+        let (year, month, day) = civil_from_days(
+            days_from_civil(self.year, self.month, self.day) + minutes.div_euclid(MINUTES_PER_DAY),
+        );
+        let minutes = minutes.rem_euclid(MINUTES_PER_DAY);
+        Self {
+            year,
+            month,
+            day,
+            hour: (minutes / 60) as u32,
+            minute: (minutes % 60) as u32,
+            offset: Some(0),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the nanoseconds since the epoch, in UTC if the value has a timezone.
+    ///
+    fn timeline(&self) -> i128 {
+        let minutes = days_from_civil(self.year, self.month, self.day) * MINUTES_PER_DAY
+            + (self.hour * 60 + self.minute) as i64
+            - self.offset.unwrap_or_default() as i64;
+        (minutes as i128 * 60 + self.second as i128) * NANOS_PER_SECOND + self.nanos as i128
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn canonical_form(value: &str, kind: Kind) -> Option<String> {
+    match kind {
+        Kind::Boolean => parse_boolean(value).map(|v| v.to_string()),
+        Kind::Integer(min, max) => parse_integer(value, min, max).map(|v| v.to_string()),
+        Kind::Decimal => parse_decimal(value).map(|v| v.to_string()),
+        Kind::Float => parse_float(value).map(|v| canonical_float(v, format!("{:E}", v as f32))),
+        Kind::Double => parse_float(value).map(|v| canonical_float(v, format!("{:E}", v))),
+        Kind::Duration => parse_duration(value).map(|v| v.to_string()),
+        Kind::Date => parse_date(value).map(|v| v.to_string()),
+        Kind::DateTime => parse_date_time(value).map(|v| v.normalized().to_string()),
+        Kind::Other => Some(value.to_string()),
+    }
+}
+
+fn kind(data_type: Option<&DataType>) -> Kind {
+    match data_type {
+        Some(DataType::Boolean) => Kind::Boolean,
+        Some(DataType::Float) => Kind::Float,
+        Some(DataType::Double) => Kind::Double,
+        Some(DataType::Long) => integer_kind(i64::MIN as i128, i64::MAX as i128),
+        Some(DataType::Int) => integer_kind(i32::MIN as i128, i32::MAX as i128),
+        Some(DataType::Short) => integer_kind(i16::MIN as i128, i16::MAX as i128),
+        Some(DataType::Byte) => integer_kind(i8::MIN as i128, i8::MAX as i128),
+        Some(DataType::UnsignedLong) => integer_kind(0, u64::MAX as i128),
+        Some(DataType::UnsignedInt) => integer_kind(0, u32::MAX as i128),
+        Some(DataType::UnsignedShort) => integer_kind(0, u16::MAX as i128),
+        Some(DataType::UnsignedByte) => integer_kind(0, u8::MAX as i128),
+        Some(DataType::Decimal) => Kind::Decimal,
+        Some(DataType::Integer) => Kind::Integer(None, None),
+        Some(DataType::Duration) => Kind::Duration,
+        Some(DataType::Date) => Kind::Date,
+        Some(DataType::DateTime) => Kind::DateTime,
+        Some(DataType::Other(iri)) => {
+            if iri == xsd::non_negative_integer() {
+                Kind::Integer(Some(0), None)
+            } else if iri == xsd::positive_integer() {
+                Kind::Integer(Some(1), None)
+            } else if iri == xsd::non_positive_integer() {
+                Kind::Integer(None, Some(0))
+            } else if iri == xsd::day_time_duration() || iri == xsd::year_month_duration() {
+                Kind::Duration
+            } else if iri == xsd::date_time_stamp() {
+                Kind::DateTime
+            } else {
+                // readers may construct any data type, including those with their own variant,
+                // from its IRI.
+                match DataType::from(iri.clone()) {
+                    DataType::Other(_) => Kind::Other,
+                    data_type => kind(Some(&data_type)),
+                }
+            }
+        }
+        _ => Kind::Other,
+    }
+}
+
+#[inline]
+fn integer_kind(min: i128, max: i128) -> Kind {
+    Kind::Integer(Some(min), Some(max))
+}
+
+fn integer_value<T, L>(literal: &L) -> Result<T>
+where
+    T: std::str::FromStr,
+    L: Literal + ?Sized,
+{
+    match kind(literal.data_type()) {
+        Kind::Integer(min, max) => parse_integer(trimmed(literal), min, max)
+            .ok_or_else(|| invalid_lexical(literal))?
+            .to_string()
+            .parse()
+            .map_err(|_| out_of_range::<T, L>(literal)),
+        _ => Err(incompatible::<T, L>(literal)),
+    }
+}
+
+///
+/// Return the position of a date, or date time, on the timeline and its timezone offset; a date
+/// is the instant its day starts.
+///
+fn instant<T, L: Literal + ?Sized>(literal: &L) -> Result<(i128, Option<i32>)> {
+    let value = match kind(literal.data_type()) {
+        Kind::DateTime => parse_date_time(trimmed(literal)).map(XsdDateTime::normalized),
+        Kind::Date => parse_date(trimmed(literal)).map(|date| XsdDateTime {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            nanos: 0,
+            offset: date.offset,
+        }),
+        _ => return Err(incompatible::<T, L>(literal)),
+    }
+    .ok_or_else(|| invalid_lexical(literal))?;
+    Ok((value.timeline(), value.offset))
+}
+
+fn duration_value<T, L: Literal + ?Sized>(literal: &L) -> Result<XsdDuration> {
+    match kind(literal.data_type()) {
+        Kind::Duration => parse_duration(trimmed(literal)).ok_or_else(|| invalid_lexical(literal)),
+        _ => Err(incompatible::<T, L>(literal)),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn parse_boolean(s: &str) -> Option<bool> {
+    match s {
+        "true" | "1" => Some(true),
+        "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_integer(s: &str, min: Option<i128>, max: Option<i128>) -> Option<Integer> {
+    let (negative, digits) = split_sign(s);
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let digits = digits.trim_start_matches('0').to_string();
+    let integer = Integer {
+        negative: negative && !digits.is_empty(),
+        digits,
+    };
+    if integer.in_range(min, max) {
+        Some(integer)
+    } else {
+        None
+    }
+}
+
+fn parse_decimal(s: &str) -> Option<Decimal> {
+    let (negative, unsigned) = split_sign(s);
+    let (integer, fraction) = match unsigned.find('.') {
+        None => (unsigned, ""),
+        Some(index) => (&unsigned[..index], &unsigned[index + 1..]),
+    };
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let integer = integer.trim_start_matches('0').to_string();
+    let fraction = fraction.trim_end_matches('0').to_string();
+    Some(Decimal {
+        negative: negative && !(integer.is_empty() && fraction.is_empty()),
+        integer,
+        fraction,
+    })
+}
+
+fn parse_float(s: &str) -> Option<f64> {
+    match s {
+        "INF" | "+INF" => Some(f64::INFINITY),
+        "-INF" => Some(f64::NEG_INFINITY),
+        "NaN" => Some(f64::NAN),
+        _ => {
+            let (mantissa, exponent) = match s.find(['e', 'E']) {
+                None => (s, None),
+                Some(index) => (&s[..index], Some(&s[index + 1..])),
+            };
+            let _ = parse_decimal(mantissa)?;
+            if let Some(exponent) = exponent {
+                let _ = parse_integer(exponent, None, None)?;
+            }
+            s.parse().ok()
+        }
+    }
+}
+
+fn parse_duration(s: &str) -> Option<XsdDuration> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        None => (false, s),
+        Some(unsigned) => (true, unsigned),
+    };
+    let unsigned = unsigned.strip_prefix('P')?;
+    let (date, time) = match unsigned.find('T') {
+        None => (unsigned, None),
+        Some(index) => (&unsigned[..index], Some(&unsigned[index + 1..])),
+    };
+    let date = duration_components(date, &['Y', 'M', 'D'])?;
+    let time = match time {
+        None => Vec::default(),
+        Some(time) => {
+            let time = duration_components(time, &['H', 'M', 'S'])?;
+            if time.is_empty() {
+                return None;
+            }
+            time
+        }
+    };
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+
+    let mut result = XsdDuration {
+        negative,
+        months: 0,
+        seconds: 0,
+        nanos: 0,
+    };
+    for (designator, number) in date {
+        let number: u64 = number.parse().ok()?;
+        match designator {
+            'Y' => result.months = result.months.checked_add(number.checked_mul(12)?)?,
+            'M' => result.months = result.months.checked_add(number)?,
+            _ => result.seconds = number.checked_mul(SECONDS_PER_DAY)?,
+        }
+    }
+    for (designator, number) in time {
+        let seconds = match designator {
+            'H' => number.parse::<u64>().ok()?.checked_mul(3600)?,
+            'M' => number.parse::<u64>().ok()?.checked_mul(60)?,
+            _ => {
+                let (whole, nanos) = parse_seconds(number)?;
+                result.nanos = nanos;
+                whole
+            }
+        };
+        result.seconds = result.seconds.checked_add(seconds)?;
+    }
+    Some(result)
+}
+
+///
+/// Split the body of a duration into number and designator pairs, the designators must appear in
+/// the order given, and only seconds may have a fractional part.
+///
+fn duration_components<'a>(s: &'a str, designators: &[char]) -> Option<Vec<(char, &'a str)>> {
+    let mut components = Vec::default();
+    let mut rest = s;
+    let mut next = 0;
+    while !rest.is_empty() {
+        let end = rest.find(|c: char| !(c.is_ascii_digit() || c == '.'))?;
+        let (number, tail) = rest.split_at(end);
+        let designator = tail.chars().next()?;
+        next += designators
+            .get(next..)?
+            .iter()
+            .position(|d| *d == designator)?;
+        if number.is_empty() || (number.contains('.') && designator != 'S') {
+            return None;
+        }
+        components.push((designator, number));
+        next += 1;
+        rest = &tail[designator.len_utf8()..];
+    }
+    Some(components)
+}
+
+///
+/// Parse seconds, with an optional fraction, fractions beyond nanoseconds are truncated.
+///
+fn parse_seconds(s: &str) -> Option<(u64, u32)> {
+    let (whole, fraction) = match s.find('.') {
+        None => (s, None),
+        Some(index) => (&s[..index], Some(&s[index + 1..])),
+    };
+    if whole.is_empty() || !whole.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let nanos = match fraction {
+        None => 0,
+        Some(fraction) => {
+            if fraction.is_empty() || !fraction.bytes().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            format!("{:0<9}", &fraction[..fraction.len().min(9)])
+                .parse()
+                .ok()?
+        }
+    };
+    Some((whole.parse().ok()?, nanos))
+}
+
+fn parse_date(s: &str) -> Option<XsdDate> {
+    if !s.is_ascii() {
+        return None;
+    }
+    // the year has at least four digits, and the month and day two each.
+    let start = if s.starts_with('-') { 1 } else { 0 };
+    let end = start + s[start..].find('-')? + 6;
+    if s.len() < end {
+        return None;
+    }
+    let (year, month, day) = parse_year_month_day(&s[..end])?;
+    Some(XsdDate {
+        year,
+        month,
+        day,
+        offset: parse_time_zone(&s[end..])?,
+    })
+}
+
+fn parse_date_time(s: &str) -> Option<XsdDateTime> {
+    if !s.is_ascii() {
+        return None;
+    }
+    let (date, time) = s.split_at(s.find('T')?);
+    let time = &time[1..];
+    let (year, month, day) = parse_year_month_day(date)?;
+
+    if time.len() < 8 || &time[2..3] != ":" || &time[5..6] != ":" {
+        return None;
+    }
+    let hour = fixed_digits(&time[0..2], 2, false)? as u32;
+    let minute = fixed_digits(&time[3..5], 2, false)? as u32;
+    let second = fixed_digits(&time[6..8], 2, false)? as u32;
+    let rest = &time[8..];
+    let (nanos, zone) = match rest.strip_prefix('.') {
+        None => (0, rest),
+        Some(fraction) => {
+            let end = fraction
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(fraction.len());
+            let (_, nanos) = parse_seconds(&format!("0.{}", &fraction[..end]))?;
+            (nanos, &fraction[end..])
+        }
+    };
+    let offset = parse_time_zone(zone)?;
+
+    let end_of_day = hour == 24 && minute == 0 && second == 0 && nanos == 0;
+    if (hour > 23 && !end_of_day) || minute > 59 || second > 59 {
+        return None;
+    }
+    Some(XsdDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        nanos,
+        offset,
+    })
+}
+
+fn parse_year_month_day(s: &str) -> Option<(i64, u32, u32)> {
+    let (negative, date) = match s.strip_prefix('-') {
+        None => (false, s),
+        Some(date) => (true, date),
+    };
+    let mut date_parts = date.split('-');
+    let year = fixed_digits(date_parts.next()?, 4, true)?;
+    let month = fixed_digits(date_parts.next()?, 2, false)? as u32;
+    let day = fixed_digits(date_parts.next()?, 2, false)? as u32;
+    if date_parts.next().is_some() {
+        return None;
+    }
+    let year = if negative { -year } else { year };
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+///
+/// Returns `None` if the zone is invalid, `Some(None)` if it is absent.
+///
+fn parse_time_zone(s: &str) -> Option<Option<i32>> {
+    match s {
+        "" => Some(None),
+        "Z" => Some(Some(0)),
+        _ => {
+            let (negative, zone) = split_sign(s);
+            if !(s.starts_with('+') || negative) || zone.len() != 5 || &zone[2..3] != ":" {
+                return None;
+            }
+            let hours = fixed_digits(&zone[0..2], 2, false)? as i32;
+            let minutes = fixed_digits(&zone[3..5], 2, false)? as i32;
+            if minutes > 59 || hours > 14 || (hours == 14 && minutes > 0) {
+                return None;
+            }
+            let offset = hours * 60 + minutes;
+            Some(Some(if negative { -offset } else { offset }))
+        }
+    }
+}
+
+fn fixed_digits(s: &str, width: usize, at_least: bool) -> Option<i64> {
+    let width_ok = if at_least {
+        s.len() == width || (s.len() > width && !s.starts_with('0'))
+    } else {
+        s.len() == width
+    };
+    if width_ok && s.bytes().all(|c| c.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+///
+/// The number of days from 1970-01-01 to the given date in the proleptic Gregorian calendar,
+/// from the algorithms in <http://howardhinnant.github.io/date_algorithms.html>.
+///
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+///
+/// The inverse of `days_from_civil`.
+///
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+fn write_date(
+    f: &mut std::fmt::Formatter<'_>,
+    year: i64,
+    month: u32,
+    day: u32,
+) -> std::fmt::Result {
+    write!(
+        f,
+        "{}{:04}-{:02}-{:02}",
+        if year < 0 { "-" } else { "" },
+        year.abs(),
+        month,
+        day
+    )
+}
+
+fn write_time_zone(f: &mut std::fmt::Formatter<'_>, offset: Option<i32>) -> std::fmt::Result {
+    match offset {
+        None => Ok(()),
+        Some(0) => write!(f, "Z"),
+        Some(offset) => write!(
+            f,
+            "{}{:02}:{:02}",
+            if offset < 0 { "-" } else { "+" },
+            offset.abs() / 60,
+            offset.abs() % 60
+        ),
+    }
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    if let Some(unsigned) = s.strip_prefix('-') {
+        (true, unsigned)
+    } else if let Some(unsigned) = s.strip_prefix('+') {
+        (false, unsigned)
+    } else {
+        (false, s)
+    }
+}
+
+fn fraction_string(nanos: u32) -> String {
+    if nanos == 0 {
+        String::new()
+    } else {
+        format!(".{:09}", nanos).trim_end_matches('0').to_string()
+    }
+}
+
+///
+/// Rust formats `100.0` as `1E2`, where XML Schema requires `1.0E2`, and uses different names
+/// for the special values.
+///
+fn canonical_float(value: f64, formatted: String) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 { "INF" } else { "-INF" }.to_string()
+    } else {
+        match formatted.find('E') {
+            Some(index) if !formatted[..index].contains('.') => {
+                format!("{}.0{}", &formatted[..index], &formatted[index..])
+            }
+            _ => formatted,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+fn trimmed<L: Literal + ?Sized>(literal: &L) -> &str {
+    literal.lexical_form().trim()
+}
+
+fn data_type_name<L: Literal + ?Sized>(literal: &L) -> String {
+    match (literal.data_type(), literal.language()) {
+        (Some(data_type), _) => data_type.as_iri().to_string(),
+        (None, Some(_)) => rdf::lang_string().to_string(),
+        (None, None) => xsd::string().to_string(),
+    }
+}
+
+fn invalid_lexical<L: Literal + ?Sized>(literal: &L) -> Error {
+    ErrorKind::InvalidFromStr(literal.lexical_form().clone(), data_type_name(literal)).into()
+}
+
+fn out_of_range<T, L: Literal + ?Sized>(literal: &L) -> Error {
+    ErrorKind::InvalidFromStr(literal.lexical_form().clone(), type_name::<T>().to_string()).into()
+}
+
+fn incompatible<T, L: Literal + ?Sized>(literal: &L) -> Error {
+    ErrorKind::IncompatibleDataType(data_type_name(literal), type_name::<T>().to_string()).into()
+}
@@ -0,0 +1,132 @@
+/*!
+Provides a side-table that associates metadata with individual statements without changing the
+statements themselves, or the graph that contains them.
+
+Statements are keyed by value, so any statement equal to one in the table, for example the same
+statement read from a different graph, will find the same metadata.
+
+# Example
+
+```rust
+use rdftk_core::model::metadata::StatementMetadata;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::IRI;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let statement = factory.statement(
+    factory.named_subject(IRI::from_str("http://en.wikipedia.org/wiki/Tony_Benn").unwrap().into()),
+    IRI::from_str("http://purl.org/dc/elements/1.1/title").unwrap().into(),
+    factory.literal_object(literal_factory().literal("Tony Benn")),
+).unwrap();
+
+let mut sources: StatementMetadata<String> = Default::default();
+let _ = sources.insert(statement.clone(), "wikipedia".to_string());
+assert_eq!(sources.get(&statement), Some(&"wikipedia".to_string()));
+```
+
+*/
+
+use crate::model::statement::StatementRef;
+use std::collections::HashMap;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A side-table mapping statements to a metadata value of type `T`.
+///
+#[derive(Clone, Debug)]
+pub struct StatementMetadata<T> {
+    table: HashMap<StatementRef, T>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<T> Default for StatementMetadata<T> {
+    fn default() -> Self {
+        Self {
+            table: Default::default(),
+        }
+    }
+}
+
+impl<T> StatementMetadata<T> {
+    ///
+    /// Returns `true` if no statement has associated metadata, else `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    ///
+    /// Returns the number of statements with associated metadata.
+    ///
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    ///
+    /// Returns `true` if the statement has associated metadata, else `false`.
+    ///
+    pub fn contains(&self, statement: &StatementRef) -> bool {
+        self.table.contains_key(statement)
+    }
+
+    ///
+    /// Return the metadata associated with the statement, if any.
+    ///
+    pub fn get(&self, statement: &StatementRef) -> Option<&T> {
+        self.table.get(statement)
+    }
+
+    ///
+    /// Return a mutable reference to the metadata associated with the statement, if any.
+    ///
+    pub fn get_mut(&mut self, statement: &StatementRef) -> Option<&mut T> {
+        self.table.get_mut(statement)
+    }
+
+    ///
+    /// Return a mutable reference to the metadata associated with the statement, inserting the
+    /// default value first if there is none.
+    ///
+    pub fn get_or_default(&mut self, statement: &StatementRef) -> &mut T
+    where
+        T: Default,
+    {
+        self.table.entry(statement.clone()).or_default()
+    }
+
+    ///
+    /// Associate the metadata with the statement, returning any previous value.
+    ///
+    pub fn insert(&mut self, statement: StatementRef, metadata: T) -> Option<T> {
+        self.table.insert(statement, metadata)
+    }
+
+    ///
+    /// Remove, and return, any metadata associated with the statement.
+    ///
+    pub fn remove(&mut self, statement: &StatementRef) -> Option<T> {
+        self.table.remove(statement)
+    }
+
+    ///
+    /// Remove all metadata from this table.
+    ///
+    pub fn clear(&mut self) {
+        self.table.clear()
+    }
+
+    ///
+    /// Return an iterator over all statements and their associated metadata.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&StatementRef, &T)> {
+        self.table.iter()
+    }
+}
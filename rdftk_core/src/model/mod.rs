@@ -65,6 +65,10 @@ pub mod graph;
 
 pub mod literal;
 
+pub mod metadata;
+
 pub mod qname;
 
+pub mod security;
+
 pub mod statement;
@@ -0,0 +1,398 @@
+/*!
+Provides sensitivity labels for statements, and a redaction pass that withholds labeled
+statements from a graph before it is shared.
+
+Labels are held in a `StatementMetadata` side-table, so labeling a statement does not change the
+graph. A `RedactionPolicy` determines, for each label, whether a statement is dropped entirely or
+has its object masked; the `redact` function applies the policy to produce a new graph and a
+`RedactionReport` listing every statement that was withheld, and why.
+
+# Example
+
+```rust
+use rdftk_core::model::security::{redact, RedactionPolicy, SecurityLabel, SecurityLabels};
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::IRI;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let subject = factory.named_subject(IRI::from_str("http://example.org/alice").unwrap().into());
+let name = factory.statement(
+    subject.clone(),
+    IRI::from_str("http://xmlns.com/foaf/0.1/name").unwrap().into(),
+    factory.literal_object(literal_factory().literal("Alice")),
+).unwrap();
+let phone = factory.statement(
+    subject,
+    IRI::from_str("http://xmlns.com/foaf/0.1/phone").unwrap().into(),
+    factory.literal_object(literal_factory().literal("555-1234")),
+).unwrap();
+let graph = graph_factory().graph_from(&[name, phone.clone()], None);
+
+let mut labels = SecurityLabels::default();
+labels.add_label(&phone, SecurityLabel::from("pii"));
+
+let mut policy = RedactionPolicy::default();
+let _ = policy.drop("pii".into());
+
+let (redacted, report) = redact(&graph, &labels, &policy).unwrap();
+assert_eq!(redacted.borrow().len(), 1);
+assert_eq!(report.dropped(), 1);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::graph::GraphRef;
+use crate::model::metadata::StatementMetadata;
+use crate::model::statement::{StatementList, StatementRef};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A sensitivity label, such as "confidential" or "pii", that may be attached to a statement.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SecurityLabel(String);
+
+///
+/// The side-table holding the set of labels attached to each statement.
+///
+pub type SecurityLabels = StatementMetadata<BTreeSet<SecurityLabel>>;
+
+///
+/// The action taken on export for a statement with a given label.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Redaction {
+    /// The object of the statement is replaced with the policy's mask value.
+    Mask,
+    /// The statement is removed entirely.
+    Drop,
+}
+
+///
+/// Maps labels to the `Redaction` to apply; statements with no label in the policy are kept
+/// unchanged. Where a statement has labels with different actions `Drop` takes precedence.
+///
+#[derive(Clone, Debug)]
+pub struct RedactionPolicy {
+    actions: HashMap<SecurityLabel, Redaction>,
+    mask_value: String,
+}
+
+///
+/// A statement withheld by a redaction pass, along with the action taken and the labels that
+/// caused it.
+///
+#[derive(Clone, Debug)]
+pub struct Withheld {
+    statement: StatementRef,
+    action: Redaction,
+    labels: Vec<SecurityLabel>,
+}
+
+///
+/// An audit report of all statements withheld by a redaction pass.
+///
+#[derive(Clone, Debug, Default)]
+pub struct RedactionReport {
+    withheld: Vec<Withheld>,
+}
+
+///
+/// The default value used to replace the object of masked statements.
+///
+pub const DEFAULT_MASK_VALUE: &str = "[REDACTED]";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Apply the redaction policy to the labeled statements in `graph`, returning a new graph, created
+/// by the same factory and sharing the same prefix mappings, and a report of what was withheld.
+///
+pub fn redact(
+    graph: &GraphRef,
+    labels: &SecurityLabels,
+    policy: &RedactionPolicy,
+) -> Result<(GraphRef, RedactionReport)> {
+    let graph = graph.borrow();
+    let mut statements: StatementList = Default::default();
+    let mut report = RedactionReport::default();
+    for statement in graph.statements() {
+        let (action, matched) = match labels.get(statement) {
+            Some(statement_labels) => policy.action_for(statement_labels),
+            None => (None, Vec::new()),
+        };
+        match action {
+            None => statements.push(statement.clone()),
+            Some(Redaction::Mask) => {
+                let factory = graph.statement_factory();
+                let mask = graph.literal_factory().literal(&policy.mask_value);
+                statements.push(factory.statement(
+                    statement.subject().clone(),
+                    statement.predicate().clone(),
+                    factory.literal_object(mask),
+                )?);
+            }
+            Some(Redaction::Drop) => {}
+        }
+        if let Some(action) = action {
+            report.withheld.push(Withheld {
+                statement: statement.clone(),
+                action,
+                labels: matched,
+            });
+        }
+    }
+    let redacted = graph
+        .factory()
+        .graph_from(&statements, Some(graph.prefix_mappings()));
+    Ok((redacted, report))
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for SecurityLabel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for SecurityLabel {
+    fn from(v: &str) -> Self {
+        Self(v.to_string())
+    }
+}
+
+impl From<String> for SecurityLabel {
+    fn from(v: String) -> Self {
+        Self(v)
+    }
+}
+
+impl SecurityLabel {
+    ///
+    /// Return the label's name.
+    ///
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl StatementMetadata<BTreeSet<SecurityLabel>> {
+    ///
+    /// Attach the label to the statement.
+    ///
+    pub fn add_label(&mut self, statement: &StatementRef, label: SecurityLabel) {
+        let _ = self.get_or_default(statement).insert(label);
+    }
+
+    ///
+    /// Detach the label from the statement, the statement is removed from the table when it has
+    /// no remaining labels.
+    ///
+    pub fn remove_label(&mut self, statement: &StatementRef, label: &SecurityLabel) {
+        if let Some(labels) = self.get_mut(statement) {
+            let _ = labels.remove(label);
+            if labels.is_empty() {
+                let _ = self.remove(statement);
+            }
+        }
+    }
+
+    ///
+    /// Returns `true` if the statement has the label attached, else `false`.
+    ///
+    pub fn has_label(&self, statement: &StatementRef, label: &SecurityLabel) -> bool {
+        self.get(statement)
+            .map(|labels| labels.contains(label))
+            .unwrap_or_default()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Redaction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Mask => "masked",
+                Self::Drop => "dropped",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Default for RedactionPolicy {
+    fn default() -> Self {
+        Self {
+            actions: Default::default(),
+            mask_value: DEFAULT_MASK_VALUE.to_string(),
+        }
+    }
+}
+
+impl RedactionPolicy {
+    ///
+    /// Drop all statements with this label.
+    ///
+    pub fn drop(&mut self, label: SecurityLabel) -> &mut Self {
+        let _ = self.actions.insert(label, Redaction::Drop);
+        self
+    }
+
+    ///
+    /// Mask the object of all statements with this label.
+    ///
+    pub fn mask(&mut self, label: SecurityLabel) -> &mut Self {
+        let _ = self.actions.insert(label, Redaction::Mask);
+        self
+    }
+
+    ///
+    /// Set the literal value used to replace the object of masked statements.
+    ///
+    pub fn mask_value(&mut self, mask_value: &str) -> &mut Self {
+        self.mask_value = mask_value.to_string();
+        self
+    }
+
+    ///
+    /// Return the action, if any, for a statement with the provided labels, along with the labels
+    /// that resulted in that action.
+    ///
+    pub fn action_for(
+        &self,
+        labels: &BTreeSet<SecurityLabel>,
+    ) -> (Option<Redaction>, Vec<SecurityLabel>) {
+        let action = labels
+            .iter()
+            .filter_map(|label| self.actions.get(label))
+            .max()
+            .cloned();
+        let matched = match action {
+            None => Vec::new(),
+            Some(action) => labels
+                .iter()
+                .filter(|label| self.actions.get(label) == Some(&action))
+                .cloned()
+                .collect(),
+        };
+        (action, matched)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Withheld {
+    ///
+    /// The original statement that was withheld.
+    ///
+    pub fn statement(&self) -> &StatementRef {
+        &self.statement
+    }
+
+    ///
+    /// The action taken on the statement.
+    ///
+    pub fn action(&self) -> Redaction {
+        self.action
+    }
+
+    ///
+    /// The labels on the statement that resulted in the action.
+    ///
+    pub fn labels(&self) -> &Vec<SecurityLabel> {
+        &self.labels
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for RedactionReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} statement(s) withheld: {} dropped, {} masked.",
+            self.len(),
+            self.dropped(),
+            self.masked()
+        )?;
+        for withheld in &self.withheld {
+            writeln!(
+                f,
+                "{} [{}]: {}",
+                withheld.action,
+                withheld
+                    .labels
+                    .iter()
+                    .map(SecurityLabel::as_str)
+                    .collect::<Vec<&str>>()
+                    .join(", "),
+                withheld.statement
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl RedactionReport {
+    ///
+    /// Returns `true` if no statements were withheld, else `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.withheld.is_empty()
+    }
+
+    ///
+    /// Returns the number of statements withheld.
+    ///
+    pub fn len(&self) -> usize {
+        self.withheld.len()
+    }
+
+    ///
+    /// Returns the number of statements dropped.
+    ///
+    pub fn dropped(&self) -> usize {
+        self.count(Redaction::Drop)
+    }
+
+    ///
+    /// Returns the number of statements masked.
+    ///
+    pub fn masked(&self) -> usize {
+        self.count(Redaction::Mask)
+    }
+
+    ///
+    /// Return an iterator over all withheld statements.
+    ///
+    pub fn withheld(&self) -> impl Iterator<Item = &Withheld> {
+        self.withheld.iter()
+    }
+
+    fn count(&self, action: Redaction) -> usize {
+        self.withheld
+            .iter()
+            .filter(|withheld| withheld.action == action)
+            .count()
+    }
+}
@@ -5,8 +5,11 @@
 // ------------------------------------------------------------------------------------------------
 
 use crate::error::Result;
+use crate::model::graph::Graph;
 use crate::model::literal::LiteralRef;
-use crate::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use crate::model::statement::{
+    reification, ObjectNodeRef, StatementList, StatementRef, SubjectNodeRef,
+};
 use crate::model::Provided;
 use rdftk_iri::IRIRef;
 use std::fmt::Debug;
@@ -120,6 +123,27 @@ pub trait StatementFactory: Debug + Provided {
     /// Return a new object node reference, which refers to this subject.
     ///
     fn subject_as_object(&self, st: SubjectNodeRef) -> ObjectNodeRef;
+
+    // --------------------------------------------------------------------------------------------
+    // Reification
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Reify `statement`, returning a new blank node and the statements that describe the
+    /// original as an `rdf:Statement` with `rdf:subject`, `rdf:predicate`, and `rdf:object`. Any
+    /// RDF-star statement nested within `statement` is also reified.
+    ///
+    fn reify(&self, statement: &StatementRef) -> Result<(SubjectNodeRef, StatementList)> {
+        reification::reify(&**statement, self)
+    }
+
+    ///
+    /// Reconstruct the statements reified in `graph`, returning each with the node that
+    /// reifies it. This is the reverse of `reify`; see `from_reification` for the rules used.
+    ///
+    fn reified_statements(&self, graph: &dyn Graph) -> Vec<(SubjectNodeRef, StatementRef)> {
+        reification::from_reification_with(graph, self)
+    }
 }
 
 pub type StatementFactoryRef = Arc<dyn StatementFactory>;
@@ -38,10 +38,9 @@ use crate::error::Result;
 use crate::model::features::Featured;
 use crate::model::literal::LiteralFactoryRef;
 use rdftk_iri::IRIRef;
-use rdftk_names::rdf;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::rc::Rc;
+use std::sync::Arc;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -50,7 +49,7 @@ use std::rc::Rc;
 ///
 /// This trait models an RDF statement.
 ///
-pub trait Statement: Debug + Featured {
+pub trait Statement: Debug + Featured + Send + Sync {
     ///
     /// Return the subject of this statement.
     ///
@@ -110,12 +109,20 @@ pub trait Statement: Debug + Featured {
     fn is_nested(&self) -> bool {
         self.subject().is_statement() || self.object().is_statement()
     }
+
+    ///
+    /// Reify this statement, using its own factory; see `StatementFactory::reify`.
+    ///
+    fn reify(&self) -> Result<(SubjectNodeRef, StatementList)> {
+        reify(self, &*self.factory())
+    }
 }
 
 ///
-/// The actual statement storage type, reference counted for memory management.
+/// The actual statement storage type, reference counted for memory management; statements may
+/// be shared across threads.
 ///
-pub type StatementRef = Rc<dyn Statement>;
+pub type StatementRef = Arc<dyn Statement>;
 
 ///
 /// A list of statements, this can be used to pass non-graph sets of statements.
@@ -127,62 +134,6 @@ pub type StatementList = Vec<StatementRef>;
 ///
 pub const BLANK_NODE_NAMESPACE: &str = "_";
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
-
-///
-/// Reify a single statement, returning the list of resulting statements.
-///
-pub fn reify_statement(
-    st: &StatementRef,
-    factory: &StatementFactoryRef,
-) -> Result<(SubjectNodeRef, Vec<StatementRef>)> {
-    let mut statements: Vec<StatementRef> = Default::default();
-    let new_subject = factory.blank_subject();
-    statements.push(factory.statement(
-        new_subject.clone(),
-        rdf::a_type().clone(),
-        factory.named_object(rdf::statement().clone()),
-    )?);
-    if st.subject().is_statement() {
-        let nested = reify_statement(st.subject().as_statement().unwrap(), factory)?;
-        statements.extend(nested.1);
-        statements.push(factory.statement(
-            new_subject.clone(),
-            rdf::subject().clone(),
-            factory.subject_as_object(nested.0),
-        )?);
-    } else {
-        statements.push(factory.statement(
-            new_subject.clone(),
-            rdf::subject().clone(),
-            factory.subject_as_object(st.subject().clone()),
-        )?);
-    }
-    statements.push(factory.statement(
-        new_subject.clone(),
-        rdf::predicate().clone(),
-        factory.named_object(st.predicate().clone()),
-    )?);
-    if st.object().is_statement() {
-        let nested = reify_statement(st.object().as_statement().unwrap(), factory)?;
-        statements.extend(nested.1);
-        statements.push(factory.statement(
-            new_subject.clone(),
-            rdf::object().clone(),
-            factory.subject_as_object(nested.0),
-        )?);
-    } else {
-        statements.push(factory.statement(
-            new_subject.clone(),
-            rdf::object().clone(),
-            st.object().clone(),
-        )?);
-    }
-    Ok((new_subject, statements))
-}
-
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -236,3 +187,11 @@ pub use predicate::*;
 #[doc(hidden)]
 mod object;
 pub use object::*;
+
+#[doc(hidden)]
+mod node;
+pub use node::*;
+
+#[doc(hidden)]
+mod reification;
+pub use reification::*;
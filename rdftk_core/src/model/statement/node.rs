@@ -0,0 +1,107 @@
+use crate::model::literal::LiteralRef;
+use crate::model::statement::{StatementRef, BLANK_NODE_NAMESPACE};
+use rdftk_iri::IRIRef;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The kind of a subject or object node, with a reference to its value. This allows a node to be
+/// matched exhaustively, rather than by testing and unwrapping each of the `as_*` accessors in
+/// turn. Subject nodes are never `Literal`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NodeKind<'a> {
+    /// A blank node, with its label.
+    Blank(&'a String),
+    /// A named node, with its IRI.
+    Iri(&'a IRIRef),
+    /// A literal value.
+    Literal(&'a LiteralRef),
+    /// An [RDF-star](https://w3c.github.io/rdf-star/cg-spec/editors_draft.html) statement.
+    Statement(&'a StatementRef),
+}
+
+///
+/// Match a subject or object node by kind, binding its value; each arm names a `NodeKind`
+/// variant, and arms for kinds that do not matter may be replaced by a final `_` arm.
+///
+/// # Example
+///
+/// ```rust
+/// use rdftk_core::match_node;
+/// use rdftk_core::simple::literal::literal_factory;
+/// use rdftk_core::simple::statement::statement_factory;
+///
+/// let object = statement_factory().literal_object(literal_factory().literal("Alice"));
+///
+/// let description = match_node!(object,
+///     Iri(iri) => format!("IRI {}", iri),
+///     Literal(literal) => format!("literal {}", literal.lexical_form()),
+///     _ => "something else".to_string(),
+/// );
+/// assert_eq!(description, "literal Alice");
+/// ```
+///
+#[macro_export]
+macro_rules! match_node {
+    ($node:expr, $($kind:ident($binding:pat) => $arm:expr),+ , _ => $default:expr $(,)?) => {
+        match $node.kind() {
+            $($crate::model::statement::NodeKind::$kind($binding) => $arm,)+
+            #[allow(unreachable_patterns)]
+            _ => $default,
+        }
+    };
+    ($node:expr, $($kind:ident($binding:pat) => $arm:expr),+ $(,)?) => {
+        match $node.kind() {
+            $($crate::model::statement::NodeKind::$kind($binding) => $arm,)+
+        }
+    };
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for NodeKind<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Blank(node) => write!(f, "{}:{}", BLANK_NODE_NAMESPACE, node),
+            Self::Iri(iri) => write!(f, "<{}>", iri),
+            Self::Literal(literal) => write!(f, "{}", literal),
+            Self::Statement(st) => write!(f, "<< {} >>", st),
+        }
+    }
+}
+
+impl NodeKind<'_> {
+    ///
+    /// Return `true` if this is a blank node, else `false`.
+    ///
+    pub fn is_blank(&self) -> bool {
+        matches!(self, Self::Blank(_))
+    }
+
+    ///
+    /// Return `true` if this is a named node, else `false`.
+    ///
+    pub fn is_iri(&self) -> bool {
+        matches!(self, Self::Iri(_))
+    }
+
+    ///
+    /// Return `true` if this is a literal value, else `false`.
+    ///
+    pub fn is_literal(&self) -> bool {
+        matches!(self, Self::Literal(_))
+    }
+
+    ///
+    /// Return `true` if this is a statement, else `false`.
+    ///
+    pub fn is_statement(&self) -> bool {
+        matches!(self, Self::Statement(_))
+    }
+}
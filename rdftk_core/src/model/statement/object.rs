@@ -1,17 +1,16 @@
 use crate::model::literal::LiteralRef;
-use crate::model::statement::{StatementRef, BLANK_NODE_NAMESPACE};
+use crate::model::statement::{NodeKind, StatementRef};
 use crate::model::{Equiv, Provided};
 use rdftk_iri::IRIRef;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::ops::Deref;
-use std::rc::Rc;
+use std::sync::Arc;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-pub trait ObjectNode: Debug + Provided {
+pub trait ObjectNode: Debug + Provided + Send + Sync {
     // --------------------------------------------------------------------------------------------
     // Inner type checks/accessors
     // --------------------------------------------------------------------------------------------
@@ -63,12 +62,29 @@ pub trait ObjectNode: Debug + Provided {
     /// Return a statement reference, if `self.is_statement()`, else `None`.
     ///
     fn as_statement(&self) -> Option<&StatementRef>;
+
+    ///
+    /// Return the kind of this object, with a reference to its value, for exhaustive matching.
+    ///
+    fn kind(&self) -> NodeKind<'_> {
+        if let Some(value) = self.as_blank() {
+            NodeKind::Blank(value)
+        } else if let Some(value) = self.as_iri() {
+            NodeKind::Iri(value)
+        } else if let Some(value) = self.as_literal() {
+            NodeKind::Literal(value)
+        } else if let Some(value) = self.as_statement() {
+            NodeKind::Statement(value)
+        } else {
+            unreachable!()
+        }
+    }
 }
 
 ///
 /// The actual object storage type, reference counted for memory management.
 ///
-pub type ObjectNodeRef = Rc<dyn ObjectNode>;
+pub type ObjectNodeRef = Arc<dyn ObjectNode>;
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
@@ -76,25 +92,7 @@ pub type ObjectNodeRef = Rc<dyn ObjectNode>;
 
 impl PartialEq<dyn ObjectNode> for dyn ObjectNode {
     fn eq(&self, other: &dyn ObjectNode) -> bool {
-        if self.is_blank() && other.is_blank() {
-            let lhs = self.as_blank().unwrap();
-            let rhs = other.as_blank().unwrap();
-            lhs == rhs
-        } else if self.is_iri() && other.is_iri() {
-            let lhs = self.as_iri().unwrap();
-            let rhs = other.as_iri().unwrap();
-            lhs == rhs
-        } else if self.is_statement() && other.is_statement() {
-            let lhs = self.as_statement().unwrap();
-            let rhs = other.as_statement().unwrap();
-            lhs == rhs
-        } else if self.is_literal() && other.is_literal() {
-            let lhs = self.as_literal().unwrap();
-            let rhs = other.as_literal().unwrap();
-            lhs == rhs
-        } else {
-            false
-        }
+        self.kind() == other.kind()
     }
 }
 
@@ -111,17 +109,7 @@ impl Hash for dyn ObjectNode {
 
 impl Display for dyn ObjectNode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some(node) = self.as_blank() {
-            write!(f, "{}:{}", BLANK_NODE_NAMESPACE, node)
-        } else if let Some(iri) = self.as_iri() {
-            write!(f, "<{}>", iri)
-        } else if let Some(literal) = self.as_literal() {
-            write!(f, "{}", literal)
-        } else if let Some(st) = self.as_statement() {
-            write!(f, "<< {} >>", st.deref().to_string())
-        } else {
-            unreachable!()
-        }
+        write!(f, "{}", self.kind())
     }
 }
 
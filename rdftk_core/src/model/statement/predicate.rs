@@ -6,11 +6,11 @@
 
 use crate::model::Provided;
 use std::fmt::Debug;
-use std::rc::Rc;
+use std::sync::Arc;
 
-pub trait PredicateNode: Debug + Provided {}
+pub trait PredicateNode: Debug + Provided + Send + Sync {}
 
-pub type PredicateNodeRef = Rc<dyn PredicateNode>;
+pub type PredicateNodeRef = Arc<dyn PredicateNode>;
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
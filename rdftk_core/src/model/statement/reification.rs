@@ -0,0 +1,173 @@
+use crate::error::Result;
+use crate::model::graph::Graph;
+use crate::model::statement::{
+    ObjectNodeRef, Statement, StatementFactory, StatementFactoryRef, StatementList, StatementRef,
+    SubjectNodeRef,
+};
+use rdftk_iri::IRIRef;
+use rdftk_names::rdf;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Reify a single statement, returning the list of resulting statements.
+///
+pub fn reify_statement(
+    st: &StatementRef,
+    factory: &StatementFactoryRef,
+) -> Result<(SubjectNodeRef, Vec<StatementRef>)> {
+    reify(&**st, &**factory)
+}
+
+///
+/// Reconstruct the statements reified in `graph`, returning each with the node that reifies it.
+///
+/// A node reifies a statement if it has exactly one value for each of `rdf:subject`,
+/// `rdf:predicate`, and `rdf:object`; the `rdf:type rdf:Statement` statement is not required. A
+/// subject or object value that is itself typed as an `rdf:Statement`, and reifies a statement, is
+/// reconstructed as an RDF-star nested statement, reversing `reify_statement`; the nested
+/// statement is also returned on its own.
+///
+pub fn from_reification(
+    graph: &dyn Graph,
+    factory: &StatementFactoryRef,
+) -> Vec<(SubjectNodeRef, StatementRef)> {
+    from_reification_with(graph, &**factory)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn reify<S, F>(st: &S, factory: &F) -> Result<(SubjectNodeRef, StatementList)>
+where
+    S: Statement + ?Sized,
+    F: StatementFactory + ?Sized,
+{
+    let mut statements: Vec<StatementRef> = Default::default();
+    let new_subject = factory.blank_subject();
+    statements.push(factory.statement(
+        new_subject.clone(),
+        rdf::a_type().clone(),
+        factory.named_object(rdf::statement().clone()),
+    )?);
+    if st.subject().is_statement() {
+        let nested = reify(&**st.subject().as_statement().unwrap(), factory)?;
+        statements.extend(nested.1);
+        statements.push(factory.statement(
+            new_subject.clone(),
+            rdf::subject().clone(),
+            factory.subject_as_object(nested.0),
+        )?);
+    } else {
+        statements.push(factory.statement(
+            new_subject.clone(),
+            rdf::subject().clone(),
+            factory.subject_as_object(st.subject().clone()),
+        )?);
+    }
+    statements.push(factory.statement(
+        new_subject.clone(),
+        rdf::predicate().clone(),
+        factory.named_object(st.predicate().clone()),
+    )?);
+    if st.object().is_statement() {
+        let nested = reify(&**st.object().as_statement().unwrap(), factory)?;
+        statements.extend(nested.1);
+        statements.push(factory.statement(
+            new_subject.clone(),
+            rdf::object().clone(),
+            factory.subject_as_object(nested.0),
+        )?);
+    } else {
+        statements.push(factory.statement(
+            new_subject.clone(),
+            rdf::object().clone(),
+            st.object().clone(),
+        )?);
+    }
+    Ok((new_subject, statements))
+}
+
+pub(super) fn from_reification_with<F>(
+    graph: &dyn Graph,
+    factory: &F,
+) -> Vec<(SubjectNodeRef, StatementRef)>
+where
+    F: StatementFactory + ?Sized,
+{
+    let mut nodes: Vec<&SubjectNodeRef> = Default::default();
+    for statement in graph.statements() {
+        if statement.predicate() == rdf::subject() && !nodes.contains(&statement.subject()) {
+            nodes.push(statement.subject());
+        }
+    }
+    nodes
+        .into_iter()
+        .filter_map(|node| {
+            reified(graph, factory, node, &mut Default::default())
+                .map(|statement| (node.clone(), statement))
+        })
+        .collect()
+}
+
+fn reified<F>(
+    graph: &dyn Graph,
+    factory: &F,
+    node: &SubjectNodeRef,
+    enclosing: &mut Vec<SubjectNodeRef>,
+) -> Option<StatementRef>
+where
+    F: StatementFactory + ?Sized,
+{
+    let value = |predicate: &IRIRef| -> Option<ObjectNodeRef> {
+        let objects = graph.objects_for(node, predicate);
+        if objects.len() == 1 {
+            objects.into_iter().next().cloned()
+        } else {
+            None
+        }
+    };
+    let subject = factory.object_as_subject(value(rdf::subject())?)?;
+    let predicate = value(rdf::predicate())?.as_iri()?.clone();
+    let object = value(rdf::object())?;
+
+    enclosing.push(node.clone());
+    let subject = match nested(graph, factory, &subject, enclosing) {
+        Some(statement) => factory.statement_subject(statement),
+        None => subject,
+    };
+    let object = match factory
+        .object_as_subject(object.clone())
+        .and_then(|object| nested(graph, factory, &object, enclosing))
+    {
+        Some(statement) => factory.statement_object(statement),
+        None => object,
+    };
+    let _ = enclosing.pop();
+
+    factory.statement(subject, predicate, object).ok()
+}
+
+fn nested<F>(
+    graph: &dyn Graph,
+    factory: &F,
+    node: &SubjectNodeRef,
+    enclosing: &mut Vec<SubjectNodeRef>,
+) -> Option<StatementRef>
+where
+    F: StatementFactory + ?Sized,
+{
+    if !enclosing.contains(node)
+        && graph
+            .objects_for(node, rdf::a_type())
+            .into_iter()
+            .any(|object| object.as_iri() == Some(rdf::statement()))
+    {
+        reified(graph, factory, node, enclosing)
+    } else {
+        None
+    }
+}
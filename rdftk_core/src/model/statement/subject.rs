@@ -1,16 +1,15 @@
-use crate::model::statement::{StatementRef, BLANK_NODE_NAMESPACE};
+use crate::model::statement::{NodeKind, StatementRef};
 use crate::model::{Equiv, Provided};
 use rdftk_iri::IRIRef;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::ops::Deref;
-use std::rc::Rc;
+use std::sync::Arc;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-pub trait SubjectNode: Debug + Provided {
+pub trait SubjectNode: Debug + Provided + Send + Sync {
     // --------------------------------------------------------------------------------------------
     // Inner type checks/accessors
     // --------------------------------------------------------------------------------------------
@@ -50,12 +49,27 @@ pub trait SubjectNode: Debug + Provided {
     /// Return a statement reference, if `self.is_statement()`, else `None`.
     ///
     fn as_statement(&self) -> Option<&StatementRef>;
+
+    ///
+    /// Return the kind of this subject, with a reference to its value, for exhaustive matching.
+    ///
+    fn kind(&self) -> NodeKind<'_> {
+        if let Some(value) = self.as_blank() {
+            NodeKind::Blank(value)
+        } else if let Some(value) = self.as_iri() {
+            NodeKind::Iri(value)
+        } else if let Some(value) = self.as_statement() {
+            NodeKind::Statement(value)
+        } else {
+            unreachable!()
+        }
+    }
 }
 
 ///
 /// The actual subject storage type, reference counted for memory management.
 ///
-pub type SubjectNodeRef = Rc<dyn SubjectNode>;
+pub type SubjectNodeRef = Arc<dyn SubjectNode>;
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
@@ -63,21 +77,7 @@ pub type SubjectNodeRef = Rc<dyn SubjectNode>;
 
 impl PartialEq<dyn SubjectNode> for dyn SubjectNode {
     fn eq(&self, other: &dyn SubjectNode) -> bool {
-        if self.is_blank() && other.is_blank() {
-            let lhs = self.as_blank().unwrap();
-            let rhs = other.as_blank().unwrap();
-            lhs == rhs
-        } else if self.is_iri() && other.is_iri() {
-            let lhs = self.as_iri().unwrap();
-            let rhs = other.as_iri().unwrap();
-            lhs == rhs
-        } else if self.is_statement() && other.is_statement() {
-            let lhs = self.as_statement().unwrap();
-            let rhs = other.as_statement().unwrap();
-            lhs == rhs
-        } else {
-            false
-        }
+        self.kind() == other.kind()
     }
 }
 
@@ -93,15 +93,7 @@ impl Hash for dyn SubjectNode {
 
 impl Display for dyn SubjectNode {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some(node) = self.as_blank() {
-            write!(f, "{}:{}", BLANK_NODE_NAMESPACE, node)
-        } else if let Some(iri) = self.as_iri() {
-            write!(f, "<{}>", iri)
-        } else if let Some(st) = self.as_statement() {
-            write!(f, "<< {} >>", st.deref().to_string())
-        } else {
-            unreachable!()
-        }
+        write!(f, "{}", self.kind())
     }
 }
 
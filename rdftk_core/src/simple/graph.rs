@@ -4,7 +4,9 @@ Simple, in-memory implementation of the `Graph` and `GraphFactory` traits.
 
 use crate::model::features::{Featured, FEATURE_GRAPH_DUPLICATES, FEATURE_RDF_STAR};
 use crate::model::graph::mapping::PrefixMappingFactoryRef;
-use crate::model::graph::{Graph, GraphFactory, GraphFactoryRef, GraphRef, PrefixMappingRef};
+use crate::model::graph::{
+    Graph, GraphFactory, GraphFactoryRef, GraphRef, PrefixMappingRef, Snapshot, StatementIter,
+};
 use crate::model::literal::LiteralFactoryRef;
 use crate::model::statement::{
     ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
@@ -27,9 +29,12 @@ use std::sync::Arc;
 ///
 /// Simple, in-memory implementation of the `Graph` trait.
 ///
+/// The statements are shared with any snapshots of the graph, and copied when the graph is
+/// modified while a snapshot is held.
+///
 #[derive(Clone, Debug)]
 pub struct SimpleGraph {
-    statements: StatementList,
+    statements: Rc<StatementList>,
     mappings: PrefixMappingRef,
 }
 
@@ -90,6 +95,15 @@ impl GraphFactory for SimpleGraphFactory {
 
 // ------------------------------------------------------------------------------------------------
 
+impl Default for SimpleGraph {
+    fn default() -> Self {
+        Self {
+            statements: Default::default(),
+            mappings: crate::simple::mapping::prefix_mapping_factory().empty(),
+        }
+    }
+}
+
 impl Featured for SimpleGraph {
     fn supports_feature(&self, feature: &IRIRef) -> bool {
         feature == FEATURE_GRAPH_DUPLICATES.deref() || feature == FEATURE_RDF_STAR.deref()
@@ -115,25 +129,12 @@ impl Graph for SimpleGraph {
         self.contains_subject(&subject)
     }
 
-    fn matches(
-        &self,
-        subject: Option<&SubjectNodeRef>,
-        predicate: Option<&IRIRef>,
-        object: Option<&ObjectNodeRef>,
-    ) -> HashSet<&StatementRef> {
-        self.statements
-            .iter()
-            .filter(|st| {
-                let st = st;
-                (subject.is_some() && st.subject() == subject.unwrap())
-                    && (predicate.is_some() && st.predicate() == predicate.unwrap())
-                    && (object.is_some() && st.object() == object.unwrap())
-            })
-            .collect()
+    fn statements(&self) -> StatementIter<'_> {
+        StatementIter::new(self.statements.iter())
     }
 
-    fn statements<'a>(&'a self) -> Box<dyn Iterator<Item = &'a StatementRef> + 'a> {
-        Box::new(self.statements.iter())
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self.statements.clone())
     }
 
     fn subjects(&self) -> HashSet<&SubjectNodeRef> {
@@ -196,11 +197,31 @@ impl Graph for SimpleGraph {
     }
 
     fn statements_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut StatementRef> + 'a> {
-        Box::new(self.statements.iter_mut())
+        Box::new(Rc::make_mut(&mut self.statements).iter_mut())
     }
 
     fn insert(&mut self, statement: StatementRef) {
-        self.statements.push(statement);
+        Rc::make_mut(&mut self.statements).push(statement);
+    }
+
+    fn extend_from(&mut self, statements: &mut dyn Iterator<Item = StatementRef>) -> usize {
+        // hash only the new statements, removing those found in a single scan of the graph.
+        let mut pending: HashSet<StatementRef> = Default::default();
+        let batch: StatementList = statements.filter(|st| pending.insert(st.clone())).collect();
+        for st in self.statements.iter() {
+            if pending.is_empty() {
+                break;
+            }
+            let _ = pending.remove(st);
+        }
+        let list = Rc::make_mut(&mut self.statements);
+        let before = list.len();
+        list.extend(batch.into_iter().filter(|st| pending.contains(st)));
+        list.len() - before
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Rc::make_mut(&mut self.statements).reserve(additional)
     }
 
     fn merge(&mut self, other: &Self) {
@@ -219,16 +240,13 @@ impl Graph for SimpleGraph {
                 (keep, discard)
             },
         );
-        self.statements = StatementList::from_iter(keep.into_iter());
+        self.statements = Rc::new(StatementList::from_iter(keep));
         discard
     }
 
     fn remove(&mut self, statement: &StatementRef) {
-        for (idx, st) in self.statements.iter().enumerate() {
-            if st == statement {
-                let _ = self.statements.remove(idx);
-                break;
-            }
+        if let Some(idx) = self.statements.iter().position(|st| st == statement) {
+            let _ = Rc::make_mut(&mut self.statements).remove(idx);
         }
     }
 
@@ -244,12 +262,49 @@ impl Graph for SimpleGraph {
                 (keep, discard)
             },
         );
-        self.statements = keep;
+        self.statements = Rc::new(keep);
         discard
     }
 
     fn clear(&mut self) {
-        self.statements.clear()
+        self.statements = Default::default()
+    }
+}
+
+impl<'a> IntoIterator for &'a SimpleGraph {
+    type Item = &'a StatementRef;
+    type IntoIter = StatementIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.statements()
+    }
+}
+
+impl IntoIterator for SimpleGraph {
+    type Item = StatementRef;
+    type IntoIter = std::vec::IntoIter<StatementRef>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Rc::try_unwrap(self.statements)
+            .unwrap_or_else(|statements| (*statements).clone())
+            .into_iter()
+    }
+}
+
+impl Extend<StatementRef> for SimpleGraph {
+    fn extend<T: IntoIterator<Item = StatementRef>>(&mut self, iter: T) {
+        for statement in iter {
+            self.insert(statement);
+        }
+    }
+}
+
+impl FromIterator<StatementRef> for SimpleGraph {
+    fn from_iter<T: IntoIterator<Item = StatementRef>>(iter: T) -> Self {
+        Self {
+            statements: Rc::new(StatementList::from_iter(iter)),
+            mappings: crate::simple::mapping::prefix_mapping_factory().empty(),
+        }
     }
 }
 
@@ -8,7 +8,10 @@ use crate::model::features::{
     FEATURE_IDX_SUBJECT, FEATURE_RDF_STAR,
 };
 use crate::model::graph::mapping::PrefixMappingFactoryRef;
-use crate::model::graph::{Graph, GraphFactory, GraphFactoryRef, GraphRef, PrefixMappingRef};
+use crate::model::graph::{
+    statement_matches, Graph, GraphFactory, GraphFactoryRef, GraphRef, PrefixMappingRef, Snapshot,
+    StatementIter,
+};
 use crate::model::literal::LiteralFactoryRef;
 use crate::model::statement::{
     ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
@@ -34,7 +37,7 @@ use std::sync::Arc;
 ///
 #[derive(Clone, Debug)]
 pub struct IndexedSimpleGraph {
-    statements: StatementList,
+    statements: Rc<StatementList>,
     mappings: PrefixMappingRef,
     s_index: HashMap<SubjectNodeRef, StatementList>,
     p_index: HashMap<IRIRef, StatementList>,
@@ -105,6 +108,18 @@ impl GraphFactory for IndexedSimpleGraphFactory {
 
 // ------------------------------------------------------------------------------------------------
 
+impl Default for IndexedSimpleGraph {
+    fn default() -> Self {
+        Self {
+            statements: Default::default(),
+            mappings: crate::simple::mapping::prefix_mapping_factory().empty(),
+            s_index: Default::default(),
+            p_index: Default::default(),
+            o_index: Default::default(),
+        }
+    }
+}
+
 impl Featured for IndexedSimpleGraph {
     fn supports_feature(&self, feature: &IRIRef) -> bool {
         feature == FEATURE_GRAPH_DUPLICATES.deref()
@@ -140,41 +155,54 @@ impl Graph for IndexedSimpleGraph {
         predicate: Option<&IRIRef>,
         object: Option<&ObjectNodeRef>,
     ) -> HashSet<&StatementRef> {
-        let s_sts: HashSet<&StatementRef> = subject
-            .map(|subject| {
-                self.s_index
-                    .get(subject)
-                    .map(|sts| HashSet::from_iter(sts))
-                    .unwrap_or_default()
-            })
-            .unwrap_or_default();
-        let p_sts: HashSet<&StatementRef> = predicate
-            .map(|predicate| {
-                self.p_index
-                    .get(predicate)
-                    .map(|sts| HashSet::from_iter(sts))
-                    .unwrap_or_default()
-            })
-            .unwrap_or_default();
-        let o_sts: HashSet<&StatementRef> = object
-            .map(|object| {
-                self.o_index
-                    .get(object)
-                    .map(|sts| HashSet::from_iter(sts))
-                    .unwrap_or_default()
-            })
-            .unwrap_or_default();
-        s_sts
-            .intersection(&p_sts)
-            .cloned()
-            .collect::<HashSet<&StatementRef>>()
-            .intersection(&o_sts)
+        // each position that is provided narrows the result, one that is not matches anything.
+        let constraints: Vec<HashSet<&StatementRef>> = vec![
+            subject.map(|subject| index_lookup(&self.s_index, subject)),
+            predicate.map(|predicate| index_lookup(&self.p_index, predicate)),
+            object.map(|object| index_lookup(&self.o_index, object)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let mut constraints = constraints.into_iter();
+        match constraints.next() {
+            None => self.statements.iter().collect(),
+            Some(first) => constraints.fold(first, |result, constraint| {
+                result.intersection(&constraint).cloned().collect()
+            }),
+        }
+    }
+
+    fn statements_matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> StatementList {
+        // scan the shortest index list for a provided position, these retain insertion order.
+        let candidates: &[StatementRef] = vec![
+            subject.map(|subject| self.s_index.get(subject)),
+            predicate.map(|predicate| self.p_index.get(predicate)),
+            object.map(|object| self.o_index.get(object)),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|sts| sts.map(|sts| sts.as_slice()).unwrap_or_default())
+        .min_by_key(|sts| sts.len())
+        .unwrap_or(self.statements.as_slice());
+        candidates
+            .iter()
+            .filter(|st| statement_matches(st, subject, predicate, object))
             .cloned()
-            .collect::<HashSet<&StatementRef>>()
+            .collect()
+    }
+
+    fn statements(&self) -> StatementIter<'_> {
+        StatementIter::new(self.statements.iter())
     }
 
-    fn statements<'a>(&'a self) -> Box<dyn Iterator<Item = &'a StatementRef> + 'a> {
-        Box::new(self.statements.iter())
+    fn snapshot(&self) -> Snapshot {
+        Snapshot::new(self.statements.clone())
     }
 
     fn subjects(&self) -> HashSet<&SubjectNodeRef> {
@@ -203,6 +231,26 @@ impl Graph for IndexedSimpleGraph {
             .collect()
     }
 
+    fn distinct_subjects(&self) -> Box<dyn Iterator<Item = (&SubjectNodeRef, usize)> + '_> {
+        Box::new(
+            self.s_index
+                .iter()
+                .map(|(subject, sts)| (subject, sts.len())),
+        )
+    }
+
+    fn distinct_predicates(&self) -> Box<dyn Iterator<Item = (&IRIRef, usize)> + '_> {
+        Box::new(
+            self.p_index
+                .iter()
+                .map(|(predicate, sts)| (predicate, sts.len())),
+        )
+    }
+
+    fn distinct_objects(&self) -> Box<dyn Iterator<Item = (&ObjectNodeRef, usize)> + '_> {
+        Box::new(self.o_index.iter().map(|(object, sts)| (object, sts.len())))
+    }
+
     fn prefix_mappings(&self) -> PrefixMappingRef {
         self.mappings.clone()
     }
@@ -224,7 +272,7 @@ impl Graph for IndexedSimpleGraph {
     }
 
     fn statements_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut StatementRef> + 'a> {
-        Box::new(self.statements.iter_mut())
+        Box::new(Rc::make_mut(&mut self.statements).iter_mut())
     }
 
     fn insert(&mut self, statement: StatementRef) {
@@ -258,7 +306,27 @@ impl Graph for IndexedSimpleGraph {
                 sts.push(statement.clone());
             }
         }
-        self.statements.push(statement);
+        Rc::make_mut(&mut self.statements).push(statement);
+    }
+
+    fn extend_from(&mut self, statements: &mut dyn Iterator<Item = StatementRef>) -> usize {
+        let before = self.statements.len();
+        for statement in statements {
+            // only the statements with the same subject need to be checked.
+            let known = self
+                .s_index
+                .get(statement.subject())
+                .map(|sts| sts.contains(&statement))
+                .unwrap_or_default();
+            if !known {
+                self.insert(statement);
+            }
+        }
+        self.statements.len() - before
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Rc::make_mut(&mut self.statements).reserve(additional)
     }
 
     fn merge(&mut self, other: &Self)
@@ -280,7 +348,7 @@ impl Graph for IndexedSimpleGraph {
                 (keep, discard)
             },
         );
-        self.statements = keep.into_iter().collect::<StatementList>();
+        self.statements = Rc::new(keep.into_iter().collect::<StatementList>());
         for st in &discard {
             self.remove_indices_for(st);
         }
@@ -288,12 +356,9 @@ impl Graph for IndexedSimpleGraph {
     }
 
     fn remove(&mut self, statement: &StatementRef) {
-        for (idx, st) in self.statements.iter().enumerate() {
-            if st == statement {
-                let _ = self.statements.remove(idx);
-                self.remove_indices_for(statement);
-                break;
-            }
+        if let Some(idx) = self.statements.iter().position(|st| st == statement) {
+            let _ = Rc::make_mut(&mut self.statements).remove(idx);
+            self.remove_indices_for(statement);
         }
     }
 
@@ -310,13 +375,55 @@ impl Graph for IndexedSimpleGraph {
     }
 
     fn clear(&mut self) {
-        self.statements.clear();
+        self.statements = Default::default();
         self.s_index.clear();
         self.p_index.clear();
         self.o_index.clear();
     }
 }
 
+impl<'a> IntoIterator for &'a IndexedSimpleGraph {
+    type Item = &'a StatementRef;
+    type IntoIter = StatementIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.statements()
+    }
+}
+
+impl IntoIterator for IndexedSimpleGraph {
+    type Item = StatementRef;
+    type IntoIter = std::vec::IntoIter<StatementRef>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Rc::try_unwrap(self.statements)
+            .unwrap_or_else(|statements| (*statements).clone())
+            .into_iter()
+    }
+}
+
+impl Extend<StatementRef> for IndexedSimpleGraph {
+    fn extend<T: IntoIterator<Item = StatementRef>>(&mut self, iter: T) {
+        for statement in iter {
+            self.insert(statement);
+        }
+    }
+}
+
+impl FromIterator<StatementRef> for IndexedSimpleGraph {
+    fn from_iter<T: IntoIterator<Item = StatementRef>>(iter: T) -> Self {
+        let mut graph = Self {
+            statements: Default::default(),
+            mappings: crate::simple::mapping::prefix_mapping_factory().empty(),
+            s_index: Default::default(),
+            p_index: Default::default(),
+            o_index: Default::default(),
+        };
+        graph.extend(iter);
+        graph
+    }
+}
+
 impl IndexedSimpleGraph {
     fn remove_indices_for(&mut self, statement: &StatementRef) {
         Self::remove_from_index(statement, statement.subject(), &mut self.s_index);
@@ -329,11 +436,15 @@ impl IndexedSimpleGraph {
         key: &T,
         index: &mut HashMap<T, StatementList>,
     ) {
-        let _ = index.get_mut(key).map(|sts| {
-            sts.iter().position(|st| st == statement).map(|idx| {
+        let now_empty = index.get_mut(key).map(|sts| {
+            if let Some(idx) = sts.iter().position(|st| st == statement) {
                 let _ = sts.remove(idx);
-            })
+            }
+            sts.is_empty()
         });
+        if now_empty == Some(true) {
+            let _ = index.remove(key);
+        }
     }
 }
 
@@ -341,6 +452,13 @@ impl IndexedSimpleGraph {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn index_lookup<'a, K: Eq + Hash>(
+    index: &'a HashMap<K, StatementList>,
+    key: &K,
+) -> HashSet<&'a StatementRef> {
+    index.get(key).map(HashSet::from_iter).unwrap_or_default()
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -0,0 +1,310 @@
+/*!
+An opt-in, memory-compact, implementation of the `Graph` and `GraphFactory` traits that interns
+each distinct term.
+
+Each graph created by this factory holds its statements in an `InternedStore`: each distinct IRI,
+blank node, literal, and RDF-star statement is held once in a term dictionary, and each statement
+only as the identifiers of its subject, predicate, and object in three ordered indexes. The graph
+does not hold the `StatementRef`s themselves; these are created from the dictionary as they are
+read, and the graph is a `StoreGraph` over the store, so that `contains`, `matches`, and the
+subject, predicate, and object queries are answered by a single range of one index.
+
+As `Graph` returns references, the statements created to answer a query are held by the graph
+until it is next changed, or until `release` is called. `statements` walks the subject index,
+creating each statement only as the iterator reaches it, and a graph that has been iterated should
+be released afterwards. A graph does not hold duplicate
+statements, and terms are not removed from the dictionary when the last statement using them is
+removed; only `clear` releases them.
+
+The `interned` benchmark in `rdftk_io` reads the same N-Triples document into this graph and the
+default graph, reporting the memory retained by each.
+
+# Example
+
+```rust
+use rdftk_core::simple::interned::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let subject = factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/s").unwrap()));
+let graph = graph_factory().graph();
+let mut graph = graph.borrow_mut();
+for name in &["Alice", "Bob", "Alice"] {
+    graph.insert(
+        factory
+            .statement(
+                subject.clone(),
+                foaf::name().clone(),
+                factory.literal_object(literal_factory().literal(name)),
+            )
+            .unwrap(),
+    );
+}
+
+assert_eq!(graph.len(), 2);
+assert_eq!(graph.objects_for(&subject, foaf::name()).len(), 2);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::features::Featured;
+use crate::model::graph::mapping::PrefixMappingFactoryRef;
+use crate::model::graph::store::interned::InternedStore;
+use crate::model::graph::store::StoreGraph;
+use crate::model::graph::{
+    Graph, GraphFactory, GraphFactoryRef, GraphRef, PrefixMappingRef, Snapshot, StatementIter,
+};
+use crate::model::literal::LiteralFactoryRef;
+use crate::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
+};
+use crate::model::Provided;
+use crate::simple::literal::literal_factory;
+use crate::simple::statement::statement_factory;
+use rdftk_iri::IRIRef;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+use std::rc::Rc;
+use std::sync::Arc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An in-memory implementation of the `Graph` trait whose statements are held as the identifiers
+/// of interned terms; see the module documentation.
+///
+#[derive(Debug)]
+pub struct InternedGraph {
+    graph: StoreGraph<InternedStore>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug)]
+struct InternedGraphFactory {}
+
+lazy_static! {
+    static ref FACTORY: Arc<InternedGraphFactory> = Arc::new(InternedGraphFactory {});
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Retrieve the `GraphFactory` for `InternedGraph` instances.
+///
+pub fn graph_factory() -> GraphFactoryRef {
+    FACTORY.clone()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Provided for InternedGraphFactory {
+    fn provider_id(&self) -> &'static str {
+        super::PROVIDER_ID
+    }
+}
+
+impl GraphFactory for InternedGraphFactory {
+    fn graph(&self) -> GraphRef {
+        self.with_mappings(self.mapping_factory().empty())
+    }
+
+    fn mapping_factory(&self) -> PrefixMappingFactoryRef {
+        crate::simple::mapping::prefix_mapping_factory()
+    }
+
+    fn with_mappings(&self, mappings: PrefixMappingRef) -> GraphRef {
+        let mut graph = InternedGraph::default();
+        graph.set_prefix_mappings(mappings);
+        Rc::new(RefCell::new(graph))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Default for InternedGraph {
+    fn default() -> Self {
+        Self {
+            // opening a graph over an empty in-memory store cannot fail.
+            graph: StoreGraph::open(InternedStore::default()).unwrap(),
+        }
+    }
+}
+
+impl InternedGraph {
+    ///
+    /// Return the store holding the statements of this graph.
+    ///
+    pub fn store(&self) -> &InternedStore {
+        self.graph.store()
+    }
+
+    ///
+    /// Return the number of distinct terms held in the dictionary of this graph.
+    ///
+    pub fn term_count(&self) -> usize {
+        self.store().term_count()
+    }
+
+    ///
+    /// Release the statements held by this graph to answer earlier queries, returning any error
+    /// from the store.
+    ///
+    pub fn release(&mut self) -> Result<()> {
+        self.graph.release()
+    }
+}
+
+impl Featured for InternedGraph {
+    fn supports_feature(&self, feature: &IRIRef) -> bool {
+        self.graph.supports_feature(feature)
+    }
+}
+
+impl Graph for InternedGraph {
+    fn is_empty(&self) -> bool {
+        self.graph.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.graph.len()
+    }
+
+    fn contains_subject(&self, subject: &SubjectNodeRef) -> bool {
+        self.graph.contains_subject(subject)
+    }
+
+    fn contains_individual(&self, subject: &IRIRef) -> bool {
+        self.graph.contains_individual(subject)
+    }
+
+    fn contains(&self, statement: &StatementRef) -> bool {
+        self.graph.contains(statement)
+    }
+
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        self.graph.matches(subject, predicate, object)
+    }
+
+    fn statements_matching(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> StatementList {
+        self.graph.statements_matching(subject, predicate, object)
+    }
+
+    fn statements(&self) -> StatementIter<'_> {
+        self.graph.statements()
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        self.graph.snapshot()
+    }
+
+    fn subjects(&self) -> HashSet<&SubjectNodeRef> {
+        self.graph.subjects()
+    }
+
+    fn predicates(&self) -> HashSet<&IRIRef> {
+        self.graph.predicates()
+    }
+
+    fn predicates_for(&self, subject: &SubjectNodeRef) -> HashSet<&IRIRef> {
+        self.graph.predicates_for(subject)
+    }
+
+    fn objects(&self) -> HashSet<&ObjectNodeRef> {
+        self.graph.objects()
+    }
+
+    fn objects_for(&self, subject: &SubjectNodeRef, predicate: &IRIRef) -> HashSet<&ObjectNodeRef> {
+        self.graph.objects_for(subject, predicate)
+    }
+
+    fn prefix_mappings(&self) -> PrefixMappingRef {
+        self.graph.prefix_mappings()
+    }
+
+    fn set_prefix_mappings(&mut self, mappings: PrefixMappingRef) {
+        self.graph.set_prefix_mappings(mappings)
+    }
+
+    fn factory(&self) -> GraphFactoryRef {
+        graph_factory()
+    }
+
+    fn statement_factory(&self) -> StatementFactoryRef {
+        statement_factory()
+    }
+
+    fn literal_factory(&self) -> LiteralFactoryRef {
+        literal_factory()
+    }
+
+    fn statements_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut StatementRef> + 'a> {
+        self.graph.statements_mut()
+    }
+
+    fn insert(&mut self, statement: StatementRef) {
+        self.graph.insert(statement)
+    }
+
+    fn extend_from(&mut self, statements: &mut dyn Iterator<Item = StatementRef>) -> usize {
+        self.graph.extend_from(statements)
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.graph.merge(&other.graph)
+    }
+
+    fn dedup(&mut self) -> StatementList {
+        self.graph.dedup()
+    }
+
+    fn remove(&mut self, statement: &StatementRef) {
+        self.graph.remove(statement)
+    }
+
+    fn remove_all_for(&mut self, subject: &SubjectNodeRef) -> StatementList {
+        self.graph.remove_all_for(subject)
+    }
+
+    fn clear(&mut self) {
+        self.graph.clear()
+    }
+}
+
+impl Extend<StatementRef> for InternedGraph {
+    fn extend<T: IntoIterator<Item = StatementRef>>(&mut self, iter: T) {
+        let _ = self.extend_from(&mut iter.into_iter());
+    }
+}
+
+impl FromIterator<StatementRef> for InternedGraph {
+    fn from_iter<T: IntoIterator<Item = StatementRef>>(iter: T) -> Self {
+        let mut graph = Self::default();
+        graph.extend(iter);
+        graph
+    }
+}
@@ -7,7 +7,6 @@ use crate::model::literal::{
 };
 use crate::model::Provided;
 use rdftk_iri::IRIRef;
-use std::rc::Rc;
 use std::sync::Arc;
 
 // ------------------------------------------------------------------------------------------------
@@ -35,6 +34,23 @@ pub fn literal_factory() -> LiteralFactoryRef {
     FACTORY.clone()
 }
 
+///
+/// Create a literal from a lexical form that is already escaped, as returned by `lexical_form`,
+/// such as one read back from a persistent store.
+///
+#[cfg(feature = "sled_store")]
+pub(crate) fn escaped_literal(
+    lexical_form: String,
+    data_type: Option<DataType>,
+    language: Option<LanguageTag>,
+) -> LiteralRef {
+    Arc::new(SimpleLiteral {
+        lexical_form,
+        data_type,
+        language,
+    })
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
@@ -73,7 +89,7 @@ impl Featured for SimpleLiteralFactory {
 
 impl LiteralFactory for SimpleLiteralFactory {
     fn literal(&self, v: &str) -> LiteralRef {
-        Rc::new(SimpleLiteral {
+        Arc::new(SimpleLiteral {
             lexical_form: escape_string(v),
             data_type: None,
             language: None,
@@ -81,7 +97,7 @@ impl LiteralFactory for SimpleLiteralFactory {
     }
 
     fn with_language(&self, v: &str, lang: LanguageTag) -> LiteralRef {
-        Rc::new(SimpleLiteral {
+        Arc::new(SimpleLiteral {
             lexical_form: escape_string(v),
             data_type: None,
             language: Some(lang),
@@ -89,7 +105,7 @@ impl LiteralFactory for SimpleLiteralFactory {
     }
 
     fn with_data_type(&self, v: &str, data_type: DataType) -> LiteralRef {
-        Rc::new(SimpleLiteral {
+        Arc::new(SimpleLiteral {
             lexical_form: escape_string(v),
             data_type: Some(data_type),
             language: None,
@@ -2,11 +2,13 @@
 Simple, in-memory implementation of the `PrefixMappings` trait.
 */
 
-use crate::model::graph::mapping::{PrefixMappingFactory, PrefixMappingFactoryRef, DEFAULT_PREFIX};
+use crate::model::graph::mapping::{
+    mapped_namespace, PrefixMappingFactory, PrefixMappingFactoryRef, DEFAULT_PREFIX,
+};
 use crate::model::graph::{PrefixMappingRef, PrefixMappings};
 use crate::model::qname::QName;
 use bimap::BiHashMap;
-use rdftk_iri::{Fragment, IRIRef};
+use rdftk_iri::IRIRef;
 use std::cell::RefCell;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -142,29 +144,11 @@ impl PrefixMappings for SimplePrefixMappings {
     }
 
     fn compress(&self, iri: &IRIRef) -> Option<QName> {
-        let (iri, name) = if iri.has_fragment() {
-            let fragment = iri.fragment();
-            let fragment = fragment.as_ref().unwrap();
-            (
-                iri.with_new_fragment(Fragment::default()),
-                fragment.value().clone(),
-            )
-        } else if iri.path().has_slug() {
-            let mut path = iri.path().clone();
-            let name = path.pop_slug();
-            (iri.with_new_path(path), name.unwrap())
+        let (prefix, _, name) = mapped_namespace(self.mappings(), iri)?;
+        if prefix == DEFAULT_PREFIX {
+            Some(QName::new_unchecked(None, &name))
         } else {
-            return None;
-        };
-        match self.get_prefix(&IRIRef::from(iri)) {
-            None => None,
-            Some(prefix) => {
-                if prefix == DEFAULT_PREFIX {
-                    Some(QName::new(&name).unwrap())
-                } else {
-                    Some(QName::with_prefix(prefix, &name).unwrap())
-                }
-            }
+            Some(QName::new_unchecked(Some(prefix), &name))
         }
     }
 }
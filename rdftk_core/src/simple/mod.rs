@@ -48,6 +48,8 @@ pub use graph::graph_factory;
 
 pub mod indexed;
 
+pub mod interned;
+
 pub mod literal;
 
 pub mod mapping;
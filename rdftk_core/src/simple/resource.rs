@@ -156,6 +156,22 @@ impl Predicate {
         self
     }
 
+    ///
+    /// Add a new property object, a literal value, to this predicate if `value` is not `None`.
+    ///
+    pub fn maybe_literal(&mut self, value: Option<LiteralRef>) -> &mut Self {
+        self.literals(value)
+    }
+
+    ///
+    /// Add a new property object for each of the literal values in `values` to this predicate.
+    ///
+    pub fn literals(&mut self, values: impl IntoIterator<Item = LiteralRef>) -> &mut Self {
+        self.objects
+            .extend(values.into_iter().map(ResourceObject::Literal));
+        self
+    }
+
     // --------------------------------------------------------------------------------------------
 
     ///
@@ -233,6 +249,15 @@ impl Predicate {
         self
     }
 
+    ///
+    /// Add a new resource object for each of the resources in `values` to this predicate.
+    ///
+    pub fn resources(&mut self, values: impl IntoIterator<Item = Resource>) -> &mut Self {
+        self.objects
+            .extend(values.into_iter().map(ResourceObject::Resource));
+        self
+    }
+
     // --------------------------------------------------------------------------------------------
 
     ///
@@ -480,6 +505,28 @@ impl Resource {
         self.insert(predicate, ResourceObject::Literal(value))
     }
 
+    ///
+    /// Add a new property predicate with a literal value to this resource if `value` is not
+    /// `None`.
+    ///
+    pub fn maybe_literal(&mut self, predicate: IRIRef, value: Option<LiteralRef>) -> &mut Self {
+        self.literals(predicate, value)
+    }
+
+    ///
+    /// Add a new property predicate with each of the literal values in `values` to this resource.
+    ///
+    pub fn literals(
+        &mut self,
+        predicate: IRIRef,
+        values: impl IntoIterator<Item = LiteralRef>,
+    ) -> &mut Self {
+        for value in values {
+            let _ = self.insert(predicate.clone(), ResourceObject::Literal(value));
+        }
+        self
+    }
+
     // --------------------------------------------------------------------------------------------
 
     ///
@@ -568,6 +615,20 @@ impl Resource {
         self.insert(predicate, ResourceObject::Resource(resource))
     }
 
+    ///
+    /// Add a new resource predicate with each of the resources in `values` to this resource.
+    ///
+    pub fn resources(
+        &mut self,
+        predicate: IRIRef,
+        values: impl IntoIterator<Item = Resource>,
+    ) -> &mut Self {
+        for value in values {
+            let _ = self.insert(predicate.clone(), ResourceObject::Resource(value));
+        }
+        self
+    }
+
     // --------------------------------------------------------------------------------------------
 
     ///
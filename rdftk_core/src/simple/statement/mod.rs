@@ -14,7 +14,6 @@ use crate::simple::statement::object::Object;
 use crate::simple::statement::subject::Subject;
 use rdftk_iri::IRIRef;
 use std::ops::Deref;
-use std::rc::Rc;
 use std::sync::Arc;
 use unique_id::sequence::SequenceGenerator as IDGenerator;
 use unique_id::Generator;
@@ -77,7 +76,7 @@ impl StatementFactory for SimpleStatementFactory {
     ) -> Result<StatementRef> {
         if self.provider_id() == subject.provider_id() && self.provider_id() == object.provider_id()
         {
-            Ok(Rc::new(SimpleStatement {
+            Ok(Arc::new(SimpleStatement {
                 subject,
                 predicate,
                 object,
@@ -113,25 +112,25 @@ impl StatementFactory for SimpleStatementFactory {
     }
 
     fn blank_subject(&self) -> SubjectNodeRef {
-        Rc::new(SimpleSubjectNode {
+        Arc::new(SimpleSubjectNode {
             inner: Subject::BNode(new_blank_node_id()),
         })
     }
 
     fn blank_subject_named(&self, name: &str) -> Result<SubjectNodeRef> {
-        Ok(Rc::new(SimpleSubjectNode {
+        Ok(Arc::new(SimpleSubjectNode {
             inner: Subject::BNode(name.to_string()),
         }))
     }
 
     fn named_subject(&self, name: IRIRef) -> SubjectNodeRef {
-        Rc::new(SimpleSubjectNode {
+        Arc::new(SimpleSubjectNode {
             inner: Subject::IRI(name),
         })
     }
 
     fn statement_subject(&self, st: StatementRef) -> SubjectNodeRef {
-        Rc::new(SimpleSubjectNode {
+        Arc::new(SimpleSubjectNode {
             inner: Subject::Star(st),
         })
     }
@@ -150,31 +149,31 @@ impl StatementFactory for SimpleStatementFactory {
     }
 
     fn blank_object(&self) -> ObjectNodeRef {
-        Rc::new(SimpleObjectNode {
+        Arc::new(SimpleObjectNode {
             inner: Object::BNode(new_blank_node_id()),
         })
     }
 
     fn blank_object_named(&self, name: &str) -> Result<ObjectNodeRef> {
-        Ok(Rc::new(SimpleObjectNode {
+        Ok(Arc::new(SimpleObjectNode {
             inner: Object::BNode(name.to_string()),
         }))
     }
 
     fn named_object(&self, name: IRIRef) -> ObjectNodeRef {
-        Rc::new(SimpleObjectNode {
+        Arc::new(SimpleObjectNode {
             inner: Object::IRI(name),
         })
     }
 
     fn literal_object(&self, value: LiteralRef) -> ObjectNodeRef {
-        Rc::new(SimpleObjectNode {
+        Arc::new(SimpleObjectNode {
             inner: Object::Literal(value),
         })
     }
 
     fn statement_object(&self, st: StatementRef) -> ObjectNodeRef {
-        Rc::new(SimpleObjectNode {
+        Arc::new(SimpleObjectNode {
             inner: Object::Star(st),
         })
     }
@@ -0,0 +1,284 @@
+/*!
+A thread-safe, in-memory, implementation of the `Graph` and `GraphFactory` traits.
+*/
+
+use crate::model::features::{Featured, FEATURE_GRAPH_DUPLICATES, FEATURE_RDF_STAR};
+use crate::model::graph::mapping::PrefixMappingFactoryRef;
+use crate::model::graph::{
+    Graph, GraphFactory, GraphFactoryRef, GraphRef, PrefixMappingRef, StatementIter,
+};
+use crate::model::literal::LiteralFactoryRef;
+use crate::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
+};
+use crate::model::Provided;
+use crate::simple::literal::literal_factory;
+use crate::simple::mapping::prefix_mapping_factory;
+use crate::simple::statement::statement_factory;
+use rdftk_iri::IRIRef;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A thread-safe, in-memory, implementation of the `Graph` trait; as for `SimpleGraph` the
+/// statements are kept in insertion order and may include duplicates.
+///
+/// The prefix mappings are held as values, a `PrefixMappingRef` is not `Send`, and so
+/// `prefix_mappings` returns a copy; changes to the copy are only kept once passed to
+/// `set_prefix_mappings`.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ThreadedGraph {
+    statements: StatementList,
+    mappings: Vec<(String, IRIRef)>,
+}
+
+///
+/// The reference type for a graph shared across threads.
+///
+pub type ThreadedGraphRef = Arc<RwLock<dyn Graph + Send + Sync>>;
+
+///
+/// The `GraphFactory` for `ThreadedGraph` instances; in addition to the `GraphRef` values used by
+/// readers and writers this creates `ThreadedGraphRef` values that may be shared across threads.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ThreadedGraphFactory {}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Retrieve the `GraphFactory` factory for `threaded::ThreadedGraph` instances.
+///
+pub fn graph_factory() -> GraphFactoryRef {
+    FACTORY.clone()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref FACTORY: Arc<ThreadedGraphFactory> = Arc::new(ThreadedGraphFactory::default());
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Provided for ThreadedGraphFactory {
+    fn provider_id(&self) -> &'static str {
+        super::PROVIDER_ID
+    }
+}
+
+impl GraphFactory for ThreadedGraphFactory {
+    fn graph(&self) -> GraphRef {
+        Rc::new(RefCell::new(ThreadedGraph::default()))
+    }
+
+    fn mapping_factory(&self) -> PrefixMappingFactoryRef {
+        prefix_mapping_factory()
+    }
+
+    fn with_mappings(&self, mappings: PrefixMappingRef) -> GraphRef {
+        let mut graph = ThreadedGraph::default();
+        graph.set_prefix_mappings(mappings);
+        Rc::new(RefCell::new(graph))
+    }
+}
+
+impl ThreadedGraphFactory {
+    ///
+    /// Create a new, empty, graph that may be shared across threads.
+    ///
+    pub fn shared_graph(&self) -> ThreadedGraphRef {
+        ThreadedGraph::default().shared()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ThreadedGraph {
+    ///
+    /// Construct a new graph containing the statements, and prefix mappings, of `graph`. As
+    /// statements are reference counted they are shared, not copied.
+    ///
+    pub fn from_graph(graph: &dyn Graph) -> Self {
+        let mut new_graph = Self {
+            statements: graph.statements().cloned().collect(),
+            mappings: Default::default(),
+        };
+        new_graph.set_prefix_mappings(graph.prefix_mappings());
+        new_graph
+    }
+
+    ///
+    /// Create a new graph, using `factory`, containing the statements, and prefix mappings, of
+    /// this graph.
+    ///
+    pub fn to_graph(&self, factory: &GraphFactoryRef) -> GraphRef {
+        let graph = factory.with_mappings(self.prefix_mappings());
+        let _ = graph
+            .borrow_mut()
+            .extend_from(&mut self.statements.iter().cloned());
+        graph
+    }
+
+    ///
+    /// Consume this graph, returning a reference that may be shared across threads.
+    ///
+    pub fn shared(self) -> ThreadedGraphRef {
+        Arc::new(RwLock::new(self))
+    }
+}
+
+impl Featured for ThreadedGraph {
+    fn supports_feature(&self, feature: &IRIRef) -> bool {
+        feature == FEATURE_GRAPH_DUPLICATES.deref() || feature == FEATURE_RDF_STAR.deref()
+    }
+}
+
+impl Graph for ThreadedGraph {
+    fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    fn contains_subject(&self, subject: &SubjectNodeRef) -> bool {
+        self.statements.iter().any(|st| st.subject() == subject)
+    }
+
+    fn contains_individual(&self, subject: &IRIRef) -> bool {
+        let subject = self.statement_factory().named_subject(subject.clone());
+        self.contains_subject(&subject)
+    }
+
+    fn statements(&self) -> StatementIter<'_> {
+        StatementIter::new(self.statements.iter())
+    }
+
+    fn subjects(&self) -> HashSet<&SubjectNodeRef> {
+        self.statements.iter().map(|st| st.subject()).collect()
+    }
+
+    fn predicates(&self) -> HashSet<&IRIRef> {
+        self.statements.iter().map(|st| st.predicate()).collect()
+    }
+
+    fn predicates_for(&self, subject: &SubjectNodeRef) -> HashSet<&IRIRef> {
+        self.statements
+            .iter()
+            .filter(|st| st.subject() == subject)
+            .map(|st| st.predicate())
+            .collect()
+    }
+
+    fn objects(&self) -> HashSet<&ObjectNodeRef> {
+        self.statements.iter().map(|st| st.object()).collect()
+    }
+
+    fn objects_for(&self, subject: &SubjectNodeRef, predicate: &IRIRef) -> HashSet<&ObjectNodeRef> {
+        self.statements
+            .iter()
+            .filter(|st| st.subject() == subject && st.predicate() == predicate)
+            .map(|st| st.object())
+            .collect()
+    }
+
+    fn prefix_mappings(&self) -> PrefixMappingRef {
+        let mappings = prefix_mapping_factory().empty();
+        {
+            let mut mappings = mappings.borrow_mut();
+            for (prefix, namespace) in &self.mappings {
+                mappings.insert(prefix, namespace.clone());
+            }
+        }
+        mappings
+    }
+
+    fn set_prefix_mappings(&mut self, mappings: PrefixMappingRef) {
+        self.mappings = mappings
+            .borrow()
+            .mappings()
+            .map(|(prefix, namespace)| (prefix.clone(), namespace.clone()))
+            .collect();
+    }
+
+    fn factory(&self) -> GraphFactoryRef {
+        graph_factory()
+    }
+
+    fn statement_factory(&self) -> StatementFactoryRef {
+        statement_factory()
+    }
+
+    fn literal_factory(&self) -> LiteralFactoryRef {
+        literal_factory()
+    }
+
+    fn statements_mut<'a>(&'a mut self) -> Box<dyn Iterator<Item = &'a mut StatementRef> + 'a> {
+        Box::new(self.statements.iter_mut())
+    }
+
+    fn insert(&mut self, statement: StatementRef) {
+        self.statements.push(statement);
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.statements.reserve(additional)
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.statements.extend(other.statements.iter().cloned())
+    }
+
+    fn dedup(&mut self) -> StatementList {
+        let mut seen: HashSet<StatementRef> = Default::default();
+        let mut discard = StatementList::default();
+        self.statements.retain(|st| {
+            if seen.insert(st.clone()) {
+                true
+            } else {
+                discard.push(st.clone());
+                false
+            }
+        });
+        discard
+    }
+
+    fn remove(&mut self, statement: &StatementRef) {
+        if let Some(idx) = self.statements.iter().position(|st| st == statement) {
+            let _ = self.statements.remove(idx);
+        }
+    }
+
+    fn remove_all_for(&mut self, subject: &SubjectNodeRef) -> StatementList {
+        let mut removed = StatementList::default();
+        self.statements.retain(|st| {
+            if st.subject() == subject {
+                removed.push(st.clone());
+                false
+            } else {
+                true
+            }
+        });
+        removed
+    }
+
+    fn clear(&mut self) {
+        self.statements.clear()
+    }
+}
@@ -0,0 +1,78 @@
+/*!
+This module contains `Send + Sync` counterparts of the simple, in-memory, types for graphs that
+must be shared across threads, or held by asynchronous services.
+
+Statements, and their nodes, are `Send + Sync` but the graph and prefix mapping references of
+the abstract model, `GraphRef` and `PrefixMappingRef`, are `Rc`-based and so may not leave the
+thread that created them. A `ThreadedGraph` implements the `Graph` trait, holding its prefix
+mappings as values, and a `ThreadedGraphRef` is an `Arc<RwLock<dyn Graph + Send + Sync>>` which
+may be shared across threads. The `ThreadedGraphFactory`, returned by `graph_factory`, is passed
+to readers in place of the simple factory so that the same reader and writer code may target
+either.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::threaded::ThreadedGraph;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
+use std::thread;
+
+let factory = statement_factory();
+let graph = graph_factory().graph_from(
+    &[factory
+        .statement(
+            factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap())),
+            foaf::name().clone(),
+            factory.literal_object(literal_factory().literal("Alice")),
+        )
+        .unwrap()],
+    None,
+);
+
+let shared = ThreadedGraph::from_graph(&*graph.borrow()).shared();
+
+let reader = shared.clone();
+let count = thread::spawn(move || reader.read().unwrap().len())
+    .join()
+    .unwrap();
+assert_eq!(count, 1);
+
+let graph = ThreadedGraph::from_graph(&*shared.read().unwrap()).to_graph(&graph_factory());
+assert_eq!(graph.borrow().len(), 1);
+```
+
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The identifier for the threaded model provider.
+///
+pub const PROVIDER_ID: &str = concat!(
+    env!("CARGO_CRATE_NAME"),
+    "::",
+    module_path!(),
+    "@",
+    env!("CARGO_PKG_VERSION")
+);
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod graph;
+pub use graph::{graph_factory, ThreadedGraph, ThreadedGraphFactory, ThreadedGraphRef};
+
+pub mod sharded;
+pub use sharded::{ContentionStatistics, ShardStatistics, ShardedGraph};
+
+pub mod statement;
+pub use statement::{ThreadedLiteral, ThreadedNode, ThreadedStatement, ThreadedStatementRef};
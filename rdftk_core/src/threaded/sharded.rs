@@ -0,0 +1,592 @@
+/*!
+A thread-safe, in-memory, graph whose statements are partitioned into shards by subject, each
+with its own lock, so that concurrent writers touching different subjects do not serialize on a
+single `RwLock`.
+
+All statements with the same subject are held in the same shard and so any operation on a
+single subject takes only one lock; operations over the whole graph take each shard's lock in
+turn and so see each shard, but not the graph as a whole, at a single point in time.
+
+The number of times each shard's lock is acquired, and how often and for how long a caller had
+to wait, are counted and returned by `ShardedGraph::statistics`.
+
+# Example
+
+```rust
+use rdftk_core::threaded::{ShardedGraph, ThreadedNode, ThreadedStatement};
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{foaf, rdf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::thread;
+
+let graph = Arc::new(ShardedGraph::new(16));
+
+let writers: Vec<_> = (0..4)
+    .map(|i| {
+        let graph = graph.clone();
+        thread::spawn(move || {
+            let subject = ThreadedNode::IRI(IRIRef::from(
+                IRI::from_str(&format!("http://example.org/person/{}", i)).unwrap(),
+            ));
+            let statement = ThreadedStatement::new(
+                subject,
+                rdf::a_type().clone(),
+                ThreadedNode::IRI(foaf::person().clone()),
+            )
+            .unwrap();
+            let _ = graph.insert(statement);
+        })
+    })
+    .collect();
+for writer in writers {
+    writer.join().unwrap();
+}
+
+assert_eq!(graph.len(), 4);
+assert_eq!(graph.statistics().writes(), 4);
+```
+
+*/
+
+use crate::error::Result;
+use crate::model::graph::{Graph, GraphFactoryRef, GraphRef};
+use crate::threaded::graph::ThreadedGraph;
+use crate::threaded::statement::{ThreadedNode, ThreadedStatement, ThreadedStatementRef};
+use rdftk_iri::IRIRef;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
+use std::time::{Duration, Instant};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The number of shards used by `ShardedGraph::default`.
+///
+pub const DEFAULT_SHARDS: usize = 16;
+
+///
+/// A thread-safe, in-memory, graph partitioned by subject into separately locked shards.
+/// Statements are kept in insertion order within a shard and, unlike `ThreadedGraph`, duplicate
+/// statements are ignored.
+///
+#[derive(Debug)]
+pub struct ShardedGraph {
+    shards: Vec<Shard>,
+    hasher: RandomState,
+    mappings: RwLock<Vec<(String, IRIRef)>>,
+}
+
+///
+/// The lock statistics for a single shard.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ShardStatistics {
+    statements: usize,
+    reads: usize,
+    writes: usize,
+    contended: usize,
+    wait: Duration,
+}
+
+///
+/// The lock statistics for all the shards of a graph.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContentionStatistics {
+    shards: Vec<ShardStatistics>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct Shard {
+    graph: RwLock<ShardStatements>,
+    reads: AtomicUsize,
+    writes: AtomicUsize,
+    contended: AtomicUsize,
+    wait_nanos: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct ShardStatements {
+    statements: Vec<ThreadedStatementRef>,
+    index: HashSet<ThreadedStatementRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for ShardedGraph {
+    fn default() -> Self {
+        Self::new(DEFAULT_SHARDS)
+    }
+}
+
+impl ShardedGraph {
+    ///
+    /// Construct a new, empty, graph with `shards` shards; at least one shard is always created.
+    ///
+    pub fn new(shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1)).map(|_| Shard::default()).collect(),
+            hasher: Default::default(),
+            mappings: Default::default(),
+        }
+    }
+
+    ///
+    /// Construct a new graph, with `shards` shards, containing copies of the statements, and
+    /// prefix mappings, of `graph`.
+    ///
+    pub fn from_graph(graph: &dyn Graph, shards: usize) -> Self {
+        let new_graph = Self::new(shards);
+        new_graph.insert_all(graph.statements().map(ThreadedStatement::from));
+        *new_graph.mappings.write().unwrap() = graph
+            .prefix_mappings()
+            .borrow()
+            .mappings()
+            .map(|(prefix, namespace)| (prefix.clone(), namespace.clone()))
+            .collect();
+        new_graph
+    }
+
+    ///
+    /// Return a `ThreadedGraph` containing the statements, and prefix mappings, of this graph.
+    ///
+    pub fn to_threaded(&self) -> Result<ThreadedGraph> {
+        let graph = self.to_graph(&super::graph_factory())?;
+        let graph = graph.borrow();
+        Ok(ThreadedGraph::from_graph(&*graph))
+    }
+
+    ///
+    /// Create a new graph, using `factory`, containing copies of the statements, and prefix
+    /// mappings, of this graph.
+    ///
+    pub fn to_graph(&self, factory: &GraphFactoryRef) -> Result<GraphRef> {
+        let mappings = factory.mapping_factory().empty();
+        {
+            let mut mappings = mappings.borrow_mut();
+            for (prefix, namespace) in self.prefix_mappings() {
+                mappings.insert(&prefix, namespace);
+            }
+        }
+        let graph = factory.with_mappings(mappings);
+        {
+            let mut graph = graph.borrow_mut();
+            let statements = graph.statement_factory();
+            let literals = graph.literal_factory();
+            for statement in self.statements() {
+                graph.insert(statement.to_statement(&statements, &literals)?);
+            }
+        }
+        Ok(graph)
+    }
+
+    ///
+    /// Return the number of shards in this graph.
+    ///
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    ///
+    /// Returns `true` if there are no statements in this graph, else `false`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.shards.iter().all(|shard| shard.read().is_empty())
+    }
+
+    ///
+    /// Return the number of statements in this graph.
+    ///
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|shard| shard.read().len()).sum()
+    }
+
+    ///
+    /// Returns `true` if this graph contains `statement`, else `false`.
+    ///
+    pub fn contains(&self, statement: &ThreadedStatement) -> bool {
+        self.shard_for(statement.subject())
+            .read()
+            .contains(statement)
+    }
+
+    ///
+    /// Return all the statements in this graph, shard by shard.
+    ///
+    pub fn statements(&self) -> Vec<ThreadedStatementRef> {
+        self.shards
+            .iter()
+            .flat_map(|shard| shard.read().statements().cloned().collect::<Vec<_>>())
+            .collect()
+    }
+
+    ///
+    /// Return all the statements matching the provided subject, predicate, and object; a value
+    /// of `None` matches any value in that position. If `subject` is provided only its shard is
+    /// locked.
+    ///
+    pub fn matches(
+        &self,
+        subject: Option<&ThreadedNode>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ThreadedNode>,
+    ) -> Vec<ThreadedStatementRef> {
+        match subject {
+            Some(subject) => {
+                self.shard_for(subject)
+                    .read()
+                    .matches(Some(subject), predicate, object)
+            }
+            None => self
+                .shards
+                .iter()
+                .flat_map(|shard| shard.read().matches(None, predicate, object))
+                .collect(),
+        }
+    }
+
+    ///
+    /// Return the set of all subjects in this graph.
+    ///
+    pub fn subjects(&self) -> HashSet<ThreadedNode> {
+        self.shards
+            .iter()
+            .flat_map(|shard| {
+                shard
+                    .read()
+                    .subjects()
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    ///
+    /// Return a copy of the prefix mappings of this graph.
+    ///
+    pub fn prefix_mappings(&self) -> Vec<(String, IRIRef)> {
+        self.mappings.read().unwrap().clone()
+    }
+
+    ///
+    /// Add, or replace, the mapping from `prefix` to `namespace`.
+    ///
+    pub fn insert_prefix_mapping(&self, prefix: &str, namespace: IRIRef) {
+        let mut mappings = self.mappings.write().unwrap();
+        mappings.retain(|(existing, _)| existing != prefix);
+        mappings.push((prefix.to_string(), namespace));
+    }
+
+    ///
+    /// Insert `statement` into this graph, returning `false` if it was already present.
+    ///
+    pub fn insert(&self, statement: ThreadedStatement) -> bool {
+        let mut shard = self.shard_for(statement.subject()).write();
+        if shard.contains(&statement) {
+            false
+        } else {
+            shard.insert(statement);
+            true
+        }
+    }
+
+    ///
+    /// Insert all of `statements` into this graph; the statements are grouped by shard so that
+    /// each shard's lock is taken once.
+    ///
+    pub fn insert_all(&self, statements: impl IntoIterator<Item = ThreadedStatement>) {
+        let mut by_shard: HashMap<usize, Vec<ThreadedStatement>> = Default::default();
+        for statement in statements {
+            by_shard
+                .entry(self.shard_index(statement.subject()))
+                .or_default()
+                .push(statement);
+        }
+        for (index, statements) in by_shard {
+            let mut shard = self.shards[index].write();
+            for statement in statements {
+                shard.insert(statement);
+            }
+        }
+    }
+
+    ///
+    /// Remove `statement` from this graph, returning `false` if it was not present.
+    ///
+    pub fn remove(&self, statement: &ThreadedStatement) -> bool {
+        let mut shard = self.shard_for(statement.subject()).write();
+        if shard.contains(statement) {
+            shard.remove(statement);
+            true
+        } else {
+            false
+        }
+    }
+
+    ///
+    /// Remove all statements with the subject `subject`, returning the number removed.
+    ///
+    pub fn remove_subject(&self, subject: &ThreadedNode) -> usize {
+        let mut shard = self.shard_for(subject).write();
+        let removed = shard.matches(Some(subject), None, None);
+        for statement in &removed {
+            shard.remove(statement);
+        }
+        removed.len()
+    }
+
+    ///
+    /// Remove all statements from this graph.
+    ///
+    pub fn clear(&self) {
+        for shard in &self.shards {
+            shard.write().clear();
+        }
+    }
+
+    ///
+    /// Return the lock statistics for each shard of this graph.
+    ///
+    pub fn statistics(&self) -> ContentionStatistics {
+        ContentionStatistics {
+            shards: self.shards.iter().map(Shard::statistics).collect(),
+        }
+    }
+
+    ///
+    /// Reset the lock statistics of all shards to zero.
+    ///
+    pub fn reset_statistics(&self) {
+        for shard in &self.shards {
+            shard.reads.store(0, Ordering::Relaxed);
+            shard.writes.store(0, Ordering::Relaxed);
+            shard.contended.store(0, Ordering::Relaxed);
+            shard.wait_nanos.store(0, Ordering::Relaxed);
+        }
+    }
+
+    fn shard_index(&self, subject: &ThreadedNode) -> usize {
+        (self.hasher.hash_one(subject) % self.shards.len() as u64) as usize
+    }
+
+    fn shard_for(&self, subject: &ThreadedNode) -> &Shard {
+        &self.shards[self.shard_index(subject)]
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ShardStatistics {
+    ///
+    /// Return the number of statements in the shard.
+    ///
+    pub fn statements(&self) -> usize {
+        self.statements
+    }
+
+    ///
+    /// Return the number of times the shard was locked for reading.
+    ///
+    pub fn reads(&self) -> usize {
+        self.reads
+    }
+
+    ///
+    /// Return the number of times the shard was locked for writing.
+    ///
+    pub fn writes(&self) -> usize {
+        self.writes
+    }
+
+    ///
+    /// Return the number of times a caller had to wait for the shard's lock.
+    ///
+    pub fn contended(&self) -> usize {
+        self.contended
+    }
+
+    ///
+    /// Return the total time callers spent waiting for the shard's lock.
+    ///
+    pub fn wait(&self) -> Duration {
+        self.wait
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ContentionStatistics {
+    ///
+    /// Return the statistics for each shard.
+    ///
+    pub fn shards(&self) -> &[ShardStatistics] {
+        &self.shards
+    }
+
+    ///
+    /// Return the number of times any shard was locked for reading.
+    ///
+    pub fn reads(&self) -> usize {
+        self.shards.iter().map(ShardStatistics::reads).sum()
+    }
+
+    ///
+    /// Return the number of times any shard was locked for writing.
+    ///
+    pub fn writes(&self) -> usize {
+        self.shards.iter().map(ShardStatistics::writes).sum()
+    }
+
+    ///
+    /// Return the number of times a caller had to wait for any shard's lock.
+    ///
+    pub fn contended(&self) -> usize {
+        self.shards.iter().map(ShardStatistics::contended).sum()
+    }
+
+    ///
+    /// Return the total time callers spent waiting for any shard's lock.
+    ///
+    pub fn wait(&self) -> Duration {
+        self.shards.iter().map(ShardStatistics::wait).sum()
+    }
+
+    ///
+    /// Return the proportion, from `0.0` to `1.0`, of lock acquisitions that had to wait.
+    ///
+    pub fn contention_ratio(&self) -> f64 {
+        let acquisitions = self.reads() + self.writes();
+        if acquisitions == 0 {
+            0.0
+        } else {
+            self.contended() as f64 / acquisitions as f64
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Shard {
+    fn read(&self) -> RwLockReadGuard<'_, ShardStatements> {
+        let _ = self.reads.fetch_add(1, Ordering::Relaxed);
+        match self.graph.try_read() {
+            Ok(guard) => guard,
+            Err(TryLockError::WouldBlock) => {
+                let started = Instant::now();
+                let guard = self.graph.read().unwrap();
+                self.waited(started);
+                guard
+            }
+            Err(TryLockError::Poisoned(_)) => self.graph.read().unwrap(),
+        }
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, ShardStatements> {
+        let _ = self.writes.fetch_add(1, Ordering::Relaxed);
+        match self.graph.try_write() {
+            Ok(guard) => guard,
+            Err(TryLockError::WouldBlock) => {
+                let started = Instant::now();
+                let guard = self.graph.write().unwrap();
+                self.waited(started);
+                guard
+            }
+            Err(TryLockError::Poisoned(_)) => self.graph.write().unwrap(),
+        }
+    }
+
+    fn waited(&self, started: Instant) {
+        let _ = self.contended.fetch_add(1, Ordering::Relaxed);
+        let _ = self
+            .wait_nanos
+            .fetch_add(started.elapsed().as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    fn statistics(&self) -> ShardStatistics {
+        let statements = self.graph.read().unwrap().len();
+        ShardStatistics {
+            statements,
+            reads: self.reads.load(Ordering::Relaxed),
+            writes: self.writes.load(Ordering::Relaxed),
+            contended: self.contended.load(Ordering::Relaxed),
+            wait: Duration::from_nanos(self.wait_nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ShardStatements {
+    fn is_empty(&self) -> bool {
+        self.statements.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.statements.len()
+    }
+
+    fn contains(&self, statement: &ThreadedStatement) -> bool {
+        self.index.contains(statement)
+    }
+
+    fn statements(&self) -> impl Iterator<Item = &ThreadedStatementRef> {
+        self.statements.iter()
+    }
+
+    fn matches(
+        &self,
+        subject: Option<&ThreadedNode>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ThreadedNode>,
+    ) -> Vec<ThreadedStatementRef> {
+        self.statements
+            .iter()
+            .filter(|statement| {
+                (subject.is_none() || subject == Some(statement.subject()))
+                    && (predicate.is_none() || predicate == Some(statement.predicate()))
+                    && (object.is_none() || object == Some(statement.object()))
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn subjects(&self) -> HashSet<&ThreadedNode> {
+        self.statements
+            .iter()
+            .map(|statement| statement.subject())
+            .collect()
+    }
+
+    fn insert(&mut self, statement: ThreadedStatement) {
+        if !self.index.contains(&statement) {
+            let statement = Arc::new(statement);
+            let _ = self.index.insert(statement.clone());
+            self.statements.push(statement);
+        }
+    }
+
+    fn remove(&mut self, statement: &ThreadedStatement) {
+        if self.index.remove(statement) {
+            self.statements
+                .retain(|existing| existing.as_ref() != statement);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.statements.clear();
+        self.index.clear();
+    }
+}
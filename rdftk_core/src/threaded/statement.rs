@@ -0,0 +1,286 @@
+/*!
+Thread-safe values for statements, their nodes, and literals, with conversions to and from the
+reference types of the abstract model.
+*/
+
+use crate::error::{ErrorKind, Result};
+use crate::model::literal::{DataType, LanguageTag, LiteralFactoryRef, LiteralRef};
+use crate::model::statement::{ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A literal value, with an optional data type or language.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ThreadedLiteral {
+    lexical_form: String,
+    data_type: Option<DataType>,
+    language: Option<LanguageTag>,
+}
+
+///
+/// A node in the subject or object position of a statement.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ThreadedNode {
+    /// A blank node, with its label.
+    Blank(String),
+    /// A named node.
+    #[allow(clippy::upper_case_acronyms)]
+    IRI(IRIRef),
+    /// A literal value, only valid in the object position.
+    Literal(ThreadedLiteral),
+    /// An embedded statement, from RDF-star.
+    Statement(ThreadedStatementRef),
+}
+
+///
+/// A single statement, or triple.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ThreadedStatement {
+    subject: ThreadedNode,
+    predicate: IRIRef,
+    object: ThreadedNode,
+}
+
+///
+/// The reference type for a thread-safe statement.
+///
+pub type ThreadedStatementRef = Arc<ThreadedStatement>;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<&LiteralRef> for ThreadedLiteral {
+    fn from(literal: &LiteralRef) -> Self {
+        Self {
+            lexical_form: literal.lexical_form().clone(),
+            data_type: literal.data_type().cloned(),
+            language: literal.language().cloned(),
+        }
+    }
+}
+
+impl ThreadedLiteral {
+    ///
+    /// Return the lexical form of this literal.
+    ///
+    pub fn lexical_form(&self) -> &String {
+        &self.lexical_form
+    }
+
+    ///
+    /// Return the data type of this literal, if any.
+    ///
+    pub fn data_type(&self) -> Option<&DataType> {
+        self.data_type.as_ref()
+    }
+
+    ///
+    /// Return the language of this literal, if any.
+    ///
+    pub fn language(&self) -> Option<&LanguageTag> {
+        self.language.as_ref()
+    }
+
+    ///
+    /// Create a literal reference, using `factory`, with the same value as this literal.
+    ///
+    pub fn to_literal(&self, factory: &LiteralFactoryRef) -> LiteralRef {
+        match (&self.data_type, &self.language) {
+            (Some(data_type), _) => factory.with_data_type(&self.lexical_form, data_type.clone()),
+            (None, Some(language)) => factory.with_language(&self.lexical_form, language.clone()),
+            (None, None) => factory.literal(&self.lexical_form),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ThreadedNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Blank(name) => write!(f, "_:{}", name),
+            Self::IRI(iri) => write!(f, "<{}>", iri),
+            Self::Literal(literal) => match (&literal.data_type, &literal.language) {
+                (Some(data_type), _) => {
+                    write!(f, "\"{}\"^^<{}>", literal.lexical_form, data_type.as_iri())
+                }
+                (None, Some(language)) => write!(f, "\"{}\"@{}", literal.lexical_form, language),
+                (None, None) => write!(f, "\"{}\"", literal.lexical_form),
+            },
+            Self::Statement(statement) => write!(f, "<< {} >>", statement),
+        }
+    }
+}
+
+impl From<&SubjectNodeRef> for ThreadedNode {
+    fn from(subject: &SubjectNodeRef) -> Self {
+        if let Some(name) = subject.as_blank() {
+            Self::Blank(name.clone())
+        } else if let Some(iri) = subject.as_iri() {
+            Self::IRI(iri.clone())
+        } else {
+            Self::Statement(Arc::new(subject.as_statement().unwrap().into()))
+        }
+    }
+}
+
+impl From<&ObjectNodeRef> for ThreadedNode {
+    fn from(object: &ObjectNodeRef) -> Self {
+        if let Some(name) = object.as_blank() {
+            Self::Blank(name.clone())
+        } else if let Some(iri) = object.as_iri() {
+            Self::IRI(iri.clone())
+        } else if let Some(literal) = object.as_literal() {
+            Self::Literal(literal.into())
+        } else {
+            Self::Statement(Arc::new(object.as_statement().unwrap().into()))
+        }
+    }
+}
+
+impl ThreadedNode {
+    ///
+    /// Returns `true` if this node is a blank node, else `false`.
+    ///
+    pub fn is_blank(&self) -> bool {
+        matches!(self, Self::Blank(_))
+    }
+
+    ///
+    /// Returns `true` if this node is an IRI, else `false`.
+    ///
+    pub fn is_iri(&self) -> bool {
+        matches!(self, Self::IRI(_))
+    }
+
+    ///
+    /// Returns `true` if this node is a literal, else `false`.
+    ///
+    pub fn is_literal(&self) -> bool {
+        matches!(self, Self::Literal(_))
+    }
+
+    ///
+    /// Returns `true` if this node is an embedded statement, else `false`.
+    ///
+    pub fn is_statement(&self) -> bool {
+        matches!(self, Self::Statement(_))
+    }
+
+    ///
+    /// Create a subject node, using `factories`, for this node; literals may not be subjects.
+    ///
+    pub fn to_subject(
+        &self,
+        statements: &StatementFactoryRef,
+        literals: &LiteralFactoryRef,
+    ) -> Result<SubjectNodeRef> {
+        match self {
+            Self::Blank(name) => statements.blank_subject_named(name),
+            Self::IRI(iri) => Ok(statements.named_subject(iri.clone())),
+            Self::Literal(_) => Err(ErrorKind::InvalidState.into()),
+            Self::Statement(statement) => {
+                Ok(statements.statement_subject(statement.to_statement(statements, literals)?))
+            }
+        }
+    }
+
+    ///
+    /// Create an object node, using `factories`, for this node.
+    ///
+    pub fn to_object(
+        &self,
+        statements: &StatementFactoryRef,
+        literals: &LiteralFactoryRef,
+    ) -> Result<ObjectNodeRef> {
+        match self {
+            Self::Blank(name) => statements.blank_object_named(name),
+            Self::IRI(iri) => Ok(statements.named_object(iri.clone())),
+            Self::Literal(literal) => Ok(statements.literal_object(literal.to_literal(literals))),
+            Self::Statement(statement) => {
+                Ok(statements.statement_object(statement.to_statement(statements, literals)?))
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ThreadedStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <{}> {}", self.subject, self.predicate, self.object)
+    }
+}
+
+impl From<&StatementRef> for ThreadedStatement {
+    fn from(statement: &StatementRef) -> Self {
+        Self {
+            subject: statement.subject().into(),
+            predicate: statement.predicate().clone(),
+            object: statement.object().into(),
+        }
+    }
+}
+
+impl ThreadedStatement {
+    ///
+    /// Construct a new statement; returns an error if `subject` is a literal.
+    ///
+    pub fn new(subject: ThreadedNode, predicate: IRIRef, object: ThreadedNode) -> Result<Self> {
+        if subject.is_literal() {
+            Err(ErrorKind::InvalidState.into())
+        } else {
+            Ok(Self {
+                subject,
+                predicate,
+                object,
+            })
+        }
+    }
+
+    ///
+    /// Return the subject of this statement.
+    ///
+    pub fn subject(&self) -> &ThreadedNode {
+        &self.subject
+    }
+
+    ///
+    /// Return the predicate of this statement.
+    ///
+    pub fn predicate(&self) -> &IRIRef {
+        &self.predicate
+    }
+
+    ///
+    /// Return the object of this statement.
+    ///
+    pub fn object(&self) -> &ThreadedNode {
+        &self.object
+    }
+
+    ///
+    /// Create a statement reference, using the provided factories, equal to this statement.
+    ///
+    pub fn to_statement(
+        &self,
+        statements: &StatementFactoryRef,
+        literals: &LiteralFactoryRef,
+    ) -> Result<StatementRef> {
+        statements.statement(
+            self.subject.to_subject(statements, literals)?,
+            self.predicate.clone(),
+            self.object.to_object(statements, literals)?,
+        )
+    }
+}
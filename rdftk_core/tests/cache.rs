@@ -0,0 +1,168 @@
+use rdftk_core::model::graph::cache::{describe, CachingGraph};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::SimpleGraph;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::{iri, subject};
+use rdftk_iri::IRIRef;
+
+fn label() -> IRIRef {
+    iri("http://www.w3.org/2000/01/rdf-schema#label")
+}
+
+fn cached_graph(capacity: usize) -> CachingGraph<SimpleGraph> {
+    let factory = statement_factory();
+    let mut graph = CachingGraph::new(SimpleGraph::default(), capacity);
+    let address = factory.blank_subject_named("address").unwrap();
+    for name in &["a", "b", "c"] {
+        graph.insert(
+            factory
+                .statement(
+                    subject(&format!("http://example.org/{}", name)),
+                    label(),
+                    factory.literal_object(literal_factory().literal(name)),
+                )
+                .unwrap(),
+        );
+    }
+    graph.insert(
+        factory
+            .statement(
+                subject("http://example.org/a"),
+                iri("http://example.org/address"),
+                factory.subject_as_object(address.clone()),
+            )
+            .unwrap(),
+    );
+    graph.insert(
+        factory
+            .statement(
+                address,
+                iri("http://example.org/city"),
+                factory.literal_object(literal_factory().literal("Paris")),
+            )
+            .unwrap(),
+    );
+    graph
+}
+
+#[test]
+fn describe_follows_blank_nodes() {
+    let graph = cached_graph(10);
+    assert_eq!(describe(&graph, &subject("http://example.org/a")).len(), 3);
+    assert_eq!(describe(&graph, &subject("http://example.org/b")).len(), 1);
+    assert!(describe(&graph, &subject("http://example.org/z")).is_empty());
+}
+
+#[test]
+fn describe_is_cached() {
+    let graph = cached_graph(10);
+    let a = subject("http://example.org/a");
+    assert_eq!(graph.describe(&a).len(), 3);
+    assert_eq!(graph.describe(&a).len(), 3);
+    assert_eq!(graph.described_objects(&a, &label()).len(), 1);
+
+    let statistics = graph.statistics();
+    assert_eq!(statistics.misses(), 1);
+    assert_eq!(statistics.hits(), 2);
+    assert_eq!(statistics.entries(), 1);
+}
+
+#[test]
+fn least_recently_used_is_evicted() {
+    let graph = cached_graph(2);
+    let a = subject("http://example.org/a");
+    let b = subject("http://example.org/b");
+    let c = subject("http://example.org/c");
+    let _ = graph.describe(&a);
+    let _ = graph.describe(&b);
+    let _ = graph.describe(&a);
+    let _ = graph.describe(&c);
+    let _ = graph.describe(&a);
+    let _ = graph.describe(&b);
+
+    let statistics = graph.statistics();
+    assert_eq!(statistics.evictions(), 2);
+    assert_eq!(statistics.hits(), 2);
+    assert_eq!(statistics.misses(), 4);
+    assert_eq!(statistics.entries(), 2);
+}
+
+#[test]
+fn insert_invalidates_description() {
+    let mut graph = cached_graph(10);
+    let factory = statement_factory();
+    let a = subject("http://example.org/a");
+    let b = subject("http://example.org/b");
+    let _ = graph.describe(&a);
+    let _ = graph.describe(&b);
+
+    // changing the blank node invalidates the description of `a`, which includes it.
+    graph.insert(
+        factory
+            .statement(
+                factory.blank_subject_named("address").unwrap(),
+                iri("http://example.org/country"),
+                factory.literal_object(literal_factory().literal("France")),
+            )
+            .unwrap(),
+    );
+    assert_eq!(graph.statistics().invalidations(), 1);
+    assert_eq!(graph.describe(&a).len(), 4);
+    assert_eq!(graph.describe(&b).len(), 1);
+    assert_eq!(graph.statistics().hits(), 1);
+}
+
+#[test]
+fn extend_from_invalidates_description() {
+    let mut graph = cached_graph(10);
+    let factory = statement_factory();
+    let a = subject("http://example.org/a");
+    let b = subject("http://example.org/b");
+    let _ = graph.describe(&a);
+    let _ = graph.describe(&b);
+
+    let country = factory
+        .statement(
+            factory.blank_subject_named("address").unwrap(),
+            iri("http://example.org/country"),
+            factory.literal_object(literal_factory().literal("France")),
+        )
+        .unwrap();
+    assert_eq!(
+        graph.extend_from(&mut vec![country.clone(), country].into_iter()),
+        1
+    );
+    assert_eq!(graph.statistics().invalidations(), 1);
+    assert_eq!(graph.describe(&a).len(), 4);
+    assert_eq!(graph.describe(&b).len(), 1);
+    assert_eq!(graph.statistics().hits(), 1);
+}
+
+#[test]
+fn remove_and_clear_invalidate() {
+    let mut graph = cached_graph(10);
+    let a = subject("http://example.org/a");
+    let b = subject("http://example.org/b");
+    let _ = graph.describe(&a);
+    let _ = graph.describe(&b);
+
+    let statement = graph.statements_matching(Some(&b), None, None).remove(0);
+    graph.remove(&statement);
+    assert!(graph.describe(&b).is_empty());
+    assert_eq!(graph.statistics().invalidations(), 1);
+
+    graph.clear();
+    assert_eq!(graph.statistics().entries(), 0);
+    assert!(graph.describe(&a).is_empty());
+}
+
+#[test]
+fn zero_capacity_disables_cache() {
+    let graph = cached_graph(0);
+    let a = subject("http://example.org/a");
+    let _ = graph.describe(&a);
+    let _ = graph.describe(&a);
+    assert_eq!(graph.statistics().hits(), 0);
+    assert_eq!(graph.statistics().misses(), 2);
+}
@@ -0,0 +1,90 @@
+use rdftk_core::model::graph::canonical::{canonical_hash, canonical_labels, canonical_n_triples};
+use rdftk_fixtures::terms::graph_from_triples;
+
+#[test]
+fn canonicalize_unique_hashes() {
+    // Example from RDFC-1.0, section 4.4.3.
+    let graph = graph_from_triples(&[
+        ("http://example.com/#p", "http://example.com/#q", "_:e0"),
+        ("http://example.com/#p", "http://example.com/#r", "_:e1"),
+        ("_:e0", "http://example.com/#s", "http://example.com/#u"),
+        ("_:e1", "http://example.com/#t", "http://example.com/#u"),
+    ]);
+    assert_eq!(
+        canonical_n_triples(&*graph.borrow()).unwrap(),
+        vec![
+            "<http://example.com/#p> <http://example.com/#q> _:c14n0 .",
+            "<http://example.com/#p> <http://example.com/#r> _:c14n1 .",
+            "_:c14n0 <http://example.com/#s> <http://example.com/#u> .",
+            "_:c14n1 <http://example.com/#t> <http://example.com/#u> .",
+        ]
+    );
+}
+
+#[test]
+fn canonicalize_shared_hashes() {
+    let cycle = |a: &'static str, b: &'static str, c: &'static str| {
+        graph_from_triples(&[
+            (a, "http://example.org/vocab#next", b),
+            (a, "http://example.org/vocab#prev", c),
+            (b, "http://example.org/vocab#next", c),
+            (b, "http://example.org/vocab#prev", a),
+            (c, "http://example.org/vocab#next", a),
+            (c, "http://example.org/vocab#prev", b),
+        ])
+    };
+    let lhs = cycle("_:e0", "_:e1", "_:e2");
+    let rhs = cycle("_:x", "_:z", "_:y");
+
+    let labels = canonical_labels(&*lhs.borrow()).unwrap();
+    let mut values: Vec<&String> = labels.values().collect();
+    values.sort();
+    assert_eq!(values, vec!["c14n0", "c14n1", "c14n2"]);
+
+    assert_eq!(
+        canonical_n_triples(&*lhs.borrow()).unwrap(),
+        canonical_n_triples(&*rhs.borrow()).unwrap()
+    );
+}
+
+#[test]
+fn canonical_hash_ignores_labels_and_order() {
+    let lhs = graph_from_triples(&[
+        ("_:a", "http://xmlns.com/foaf/0.1/name", "\"Alice"),
+        ("_:a", "http://xmlns.com/foaf/0.1/knows", "_:b"),
+        ("_:b", "http://xmlns.com/foaf/0.1/name", "\"Bob"),
+    ]);
+    let rhs = graph_from_triples(&[
+        ("_:person2", "http://xmlns.com/foaf/0.1/name", "\"Bob"),
+        ("_:person1", "http://xmlns.com/foaf/0.1/knows", "_:person2"),
+        ("_:person1", "http://xmlns.com/foaf/0.1/name", "\"Alice"),
+        ("_:person1", "http://xmlns.com/foaf/0.1/name", "\"Alice"),
+    ]);
+    let different = graph_from_triples(&[
+        ("_:a", "http://xmlns.com/foaf/0.1/name", "\"Alice"),
+        ("_:b", "http://xmlns.com/foaf/0.1/knows", "_:a"),
+        ("_:b", "http://xmlns.com/foaf/0.1/name", "\"Bob"),
+    ]);
+    assert_eq!(
+        canonical_hash(&*lhs.borrow()).unwrap(),
+        canonical_hash(&*rhs.borrow()).unwrap()
+    );
+    assert_ne!(
+        canonical_hash(&*lhs.borrow()).unwrap(),
+        canonical_hash(&*different.borrow()).unwrap()
+    );
+}
+
+#[test]
+fn canonicalize_graph() {
+    let graph = graph_from_triples(&[
+        ("_:a", "http://xmlns.com/foaf/0.1/knows", "_:b"),
+        ("_:b", "http://xmlns.com/foaf/0.1/name", "\"Bob"),
+    ]);
+    let canonical = graph.borrow().canonicalize().unwrap();
+    let canonical = canonical.borrow();
+    assert_eq!(canonical.len(), 2);
+    assert!(canonical
+        .statements()
+        .all(|st| st.subject().as_blank().unwrap().starts_with("c14n")));
+}
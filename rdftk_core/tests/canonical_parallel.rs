@@ -0,0 +1,123 @@
+#![cfg(feature = "parallel")]
+
+use rdftk_core::model::graph::canonical::{
+    canonical_hash, canonical_hash_parallel, canonical_labels, canonical_labels_parallel,
+    canonical_n_triples, canonical_n_triples_parallel,
+};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_fixtures::terms::graph_from_triples;
+
+fn triple(s: &str, p: &str, o: &str) -> (String, String, String) {
+    (s.to_string(), p.to_string(), o.to_string())
+}
+
+fn assert_same_as_sequential(graph: &GraphRef) {
+    let graph = graph.borrow();
+    assert_eq!(
+        canonical_labels_parallel(&*graph).unwrap(),
+        canonical_labels(&*graph).unwrap()
+    );
+    assert_eq!(
+        canonical_n_triples_parallel(&*graph).unwrap(),
+        canonical_n_triples(&*graph).unwrap()
+    );
+    assert_eq!(
+        canonical_hash_parallel(&*graph).unwrap(),
+        canonical_hash(&*graph).unwrap()
+    );
+}
+
+#[test]
+fn parallel_unique_hashes() {
+    let graph = graph_from_triples(&[
+        triple("http://example.com/#p", "http://example.com/#q", "_:e0"),
+        triple("http://example.com/#p", "http://example.com/#r", "_:e1"),
+        triple("_:e0", "http://example.com/#s", "http://example.com/#u"),
+        triple("_:e1", "http://example.com/#t", "http://example.com/#u"),
+        triple("http://example.com/#p", "http://example.com/#s", "\"ground"),
+        triple("http://example.com/#p", "http://example.com/#s", "\"ground"),
+    ]);
+    assert_same_as_sequential(&graph);
+    assert_eq!(
+        canonical_n_triples_parallel(&*graph.borrow())
+            .unwrap()
+            .len(),
+        5
+    );
+}
+
+#[test]
+fn parallel_shared_hashes_across_components() {
+    // many isomorphic components, each a cycle with a tail, so that the blank nodes of each share
+    // first-degree hashes with those of every other component.
+    let mut triples: Vec<(String, String, String)> = Default::default();
+    for component in 0..40 {
+        let node = |index: usize| format!("_:n{}x{}", component, index);
+        for index in 0..4 {
+            triples.push(triple(
+                &node(index),
+                "http://example.com/#next",
+                &node((index + 1) % 4),
+            ));
+        }
+        triples.push(triple(&node(0), "http://example.com/#tail", &node(4)));
+        if component % 3 == 0 {
+            triples.push(triple(
+                &node(4),
+                "http://example.com/#label",
+                &format!("\"component {}", component / 3),
+            ));
+        }
+        triples.push(triple(
+            &format!("http://example.com/#c{}", component % 7),
+            "http://example.com/#ground",
+            "http://example.com/#value",
+        ));
+    }
+    let graph = graph_from_triples(&triples);
+    assert_same_as_sequential(&graph);
+    assert_eq!(
+        canonical_labels_parallel(&*graph.borrow()).unwrap().len(),
+        200
+    );
+}
+
+#[test]
+fn parallel_ignores_labels_and_order() {
+    let make = |prefix: &str, reverse: bool| {
+        let mut triples = vec![
+            triple(
+                &format!("_:{}a", prefix),
+                "http://example.com/#p",
+                &format!("_:{}b", prefix),
+            ),
+            triple(
+                &format!("_:{}b", prefix),
+                "http://example.com/#p",
+                &format!("_:{}a", prefix),
+            ),
+            triple(
+                &format!("_:{}c", prefix),
+                "http://example.com/#p",
+                &format!("_:{}d", prefix),
+            ),
+            triple(
+                &format!("_:{}d", prefix),
+                "http://example.com/#p",
+                &format!("_:{}c", prefix),
+            ),
+        ];
+        if reverse {
+            triples.reverse();
+        }
+        graph_from_triples(&triples)
+    };
+    let lhs = make("x", false);
+    let rhs = make("other", true);
+    assert_same_as_sequential(&lhs);
+    assert_same_as_sequential(&rhs);
+    assert_eq!(
+        canonical_hash_parallel(&*lhs.borrow()).unwrap(),
+        canonical_hash_parallel(&*rhs.borrow()).unwrap()
+    );
+}
@@ -0,0 +1,153 @@
+use rdftk_core::model::graph::cardinality::{CardinalityConstraints, Enforcement};
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{foaf, owl, rdf};
+use std::str::FromStr;
+
+fn age(person: &str, age: i64) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(person)),
+            foaf::age().clone(),
+            factory.literal_object(literal_factory().long(age)),
+        )
+        .unwrap()
+}
+
+fn mbox(person: &str, mbox: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(person)),
+            foaf::mbox().clone(),
+            factory.named_object(IRI::from_str(mbox).unwrap().into()),
+        )
+        .unwrap()
+}
+
+fn constraints(enforcement: Enforcement) -> CardinalityConstraints {
+    let mut constraints = CardinalityConstraints::new(enforcement);
+    let _ = constraints
+        .functional(foaf::age().clone())
+        .inverse_functional(foaf::mbox().clone());
+    constraints
+}
+
+#[test]
+fn reject_conflicting_values() {
+    let constraints = constraints(Enforcement::Reject);
+    let graph = graph_factory().graph();
+    let mut graph = graph.borrow_mut();
+
+    assert!(constraints
+        .insert(&mut *graph, age("alice", 33))
+        .unwrap()
+        .is_none());
+    // re-inserting the same statement is not a conflict.
+    assert!(constraints
+        .insert(&mut *graph, age("alice", 33))
+        .unwrap()
+        .is_none());
+    assert!(constraints
+        .insert(&mut *graph, age("bob", 33))
+        .unwrap()
+        .is_none());
+    assert!(constraints.insert(&mut *graph, age("alice", 34)).is_err());
+
+    assert!(constraints
+        .insert(&mut *graph, mbox("alice", "mailto:alice@example.org"))
+        .unwrap()
+        .is_none());
+    assert!(constraints
+        .insert(&mut *graph, mbox("alice", "mailto:alicia@example.org"))
+        .unwrap()
+        .is_none());
+    assert!(constraints
+        .insert(&mut *graph, mbox("bob", "mailto:alice@example.org"))
+        .is_err());
+
+    assert!(!graph.contains(&age("alice", 34)));
+    assert!(!graph.contains(&mbox("bob", "mailto:alice@example.org")));
+}
+
+#[test]
+fn replace_conflicting_values() {
+    let constraints = constraints(Enforcement::Replace);
+    let graph = graph_factory().graph();
+    let mut graph = graph.borrow_mut();
+
+    let _ = constraints.insert(&mut *graph, age("alice", 33)).unwrap();
+    let conflict = constraints
+        .insert(&mut *graph, age("alice", 34))
+        .unwrap()
+        .unwrap();
+    assert!(!conflict.is_inverse());
+    assert_eq!(conflict.statement(), &age("alice", 34));
+    assert_eq!(conflict.existing(), &vec![age("alice", 33)]);
+    assert!(graph.contains(&age("alice", 34)));
+    assert!(!graph.contains(&age("alice", 33)));
+
+    let _ = constraints
+        .insert(&mut *graph, mbox("alice", "mailto:alice@example.org"))
+        .unwrap();
+    let conflict = constraints
+        .insert(&mut *graph, mbox("bob", "mailto:alice@example.org"))
+        .unwrap()
+        .unwrap();
+    assert!(conflict.is_inverse());
+    assert_eq!(graph.len(), 2);
+}
+
+#[test]
+fn warn_conflicting_values() {
+    let constraints = constraints(Enforcement::Warn);
+    let graph = graph_factory().graph();
+    let mut graph = graph.borrow_mut();
+
+    let _ = constraints.insert(&mut *graph, age("alice", 33)).unwrap();
+    let conflict = constraints
+        .insert(&mut *graph, age("alice", 34))
+        .unwrap()
+        .unwrap();
+    assert_eq!(conflict.existing().len(), 1);
+    assert_eq!(graph.len(), 2);
+    assert!(constraints
+        .conflicts(&*graph, &age("alice", 35))
+        .map(|conflict| conflict.existing().len() == 2)
+        .unwrap());
+}
+
+#[test]
+fn constraints_from_ontology() {
+    let factory = statement_factory();
+    let declare = |property: &IRIRef, class: &IRIRef| {
+        factory
+            .statement(
+                factory.named_subject(property.clone()),
+                rdf::a_type().clone(),
+                factory.named_object(class.clone()),
+            )
+            .unwrap()
+    };
+    let ontology = graph_factory().graph_from(
+        &[
+            declare(foaf::age(), owl::functional_property()),
+            declare(foaf::mbox(), owl::inverse_functional_property()),
+            declare(foaf::knows(), owl::object_property()),
+        ],
+        None,
+    );
+
+    let constraints =
+        CardinalityConstraints::from_ontology(&*ontology.borrow(), Enforcement::Reject);
+    assert!(constraints.is_functional(foaf::age()));
+    assert!(constraints.is_inverse_functional(foaf::mbox()));
+    assert!(!constraints.is_functional(foaf::knows()));
+    assert!(!constraints.is_inverse_functional(foaf::knows()));
+    assert!(!constraints.is_empty());
+}
@@ -0,0 +1,169 @@
+use rdftk_core::error::ErrorKind;
+use rdftk_core::model::graph::cardinality::{CardinalityConstraints, Enforcement};
+use rdftk_core::model::graph::change_set::{Change, ChangeSet, ChangeValidator};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_names::foaf;
+
+fn age(person: &str, age: i64) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(person)),
+            foaf::age().clone(),
+            factory.literal_object(literal_factory().long(age)),
+        )
+        .unwrap()
+}
+
+fn knows(person: &str, other: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(person)),
+            foaf::knows().clone(),
+            factory.named_object(example_iri(other)),
+        )
+        .unwrap()
+}
+
+#[test]
+fn apply_in_order() {
+    let graph = graph_factory().graph_from(&[age("alice", 33)], None);
+    let mut changes = ChangeSet::default();
+    let _ = changes
+        .add(knows("alice", "bob"))
+        .remove(age("alice", 33))
+        .add(age("alice", 34))
+        .remove(knows("alice", "bob"));
+    assert_eq!(changes.len(), 4);
+    assert_eq!(changes.additions().count(), 2);
+    assert_eq!(changes.removals().count(), 2);
+
+    changes.apply(&mut *graph.borrow_mut());
+    let graph = graph.borrow();
+    assert_eq!(graph.len(), 1);
+    assert!(graph.contains(&age("alice", 34)));
+}
+
+#[test]
+fn preview_leaves_graph_unchanged() {
+    let graph = graph_factory().graph_from(&[age("alice", 33)], None);
+    let mut changes = ChangeSet::default();
+    let _ = changes.add(knows("alice", "bob"));
+    let preview = changes.preview(&*graph.borrow());
+    assert_eq!(preview.borrow().len(), 2);
+    assert_eq!(graph.borrow().len(), 1);
+}
+
+#[test]
+fn inverse_restores_graph() {
+    let graph = graph_factory().graph_from(&[age("alice", 33), knows("alice", "bob")], None);
+    let mut changes = ChangeSet::default();
+    let _ = changes
+        .remove(age("alice", 33))
+        .add(age("alice", 34))
+        .add(knows("bob", "alice"));
+    changes.apply(&mut *graph.borrow_mut());
+    assert_eq!(graph.borrow().len(), 3);
+    changes.inverse().apply(&mut *graph.borrow_mut());
+    let graph = graph.borrow();
+    assert_eq!(graph.len(), 2);
+    assert!(graph.contains(&age("alice", 33)));
+    assert!(graph.contains(&knows("alice", "bob")));
+    assert_eq!(
+        changes.inverse().changes().next(),
+        Some(&Change::Remove(knows("bob", "alice")))
+    );
+}
+
+#[test]
+fn between_versions() {
+    let before = graph_factory().graph_from(&[age("alice", 33), knows("alice", "bob")], None);
+    let after = graph_factory().graph_from(&[age("alice", 34), knows("alice", "bob")], None);
+    let changes = ChangeSet::between(&*before.borrow(), &*after.borrow());
+    assert_eq!(
+        changes.changes().cloned().collect::<Vec<Change>>(),
+        vec![
+            Change::Remove(age("alice", 33)),
+            Change::Add(age("alice", 34))
+        ]
+    );
+    changes.apply(&mut *before.borrow_mut());
+    assert!(ChangeSet::between(&*before.borrow(), &*after.borrow()).is_empty());
+}
+
+#[test]
+fn rejected_changes_are_not_applied() {
+    let graph = graph_factory().graph_from(&[age("alice", 33)], None);
+    let mut constraints = CardinalityConstraints::new(Enforcement::Reject);
+    let _ = constraints.functional(foaf::age().clone());
+
+    let mut changes = ChangeSet::default();
+    let _ = changes.add(knows("alice", "bob")).add(age("alice", 34));
+    let problems = changes.validate(&*graph.borrow(), &[&constraints]);
+    assert_eq!(problems.len(), 1);
+    assert!(problems[0].contains("functional predicate"));
+
+    let result = changes.apply_validated(&mut *graph.borrow_mut(), &[&constraints]);
+    match result.unwrap_err().kind() {
+        ErrorKind::ChangeSetRejected(problems) => assert_eq!(problems.len(), 1),
+        kind => panic!("unexpected error {:?}", kind),
+    }
+    assert_eq!(graph.borrow().len(), 1);
+    assert!(!graph.borrow().contains(&knows("alice", "bob")));
+
+    // replacing the value in the same change set is valid.
+    let _ = changes.remove(age("alice", 33));
+    changes
+        .apply_validated(&mut *graph.borrow_mut(), &[&constraints])
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 2);
+}
+
+#[test]
+fn all_validators_are_run() {
+    let graph = graph_factory().graph();
+    let no_knows = |_: &dyn Graph, _: &dyn Graph, changes: &ChangeSet| {
+        changes
+            .additions()
+            .filter(|statement| statement.predicate() == foaf::knows())
+            .map(|statement| format!("{} is not allowed", statement))
+            .collect::<Vec<String>>()
+    };
+    let max_two = |_: &dyn Graph, after: &dyn Graph, _: &ChangeSet| {
+        if after.len() > 2 {
+            vec!["too many statements".to_string()]
+        } else {
+            Vec::default()
+        }
+    };
+    let validators: [&dyn ChangeValidator; 2] = [&no_knows, &max_two];
+
+    let mut changes = ChangeSet::default();
+    let _ = changes
+        .add(knows("alice", "bob"))
+        .add(knows("bob", "alice"))
+        .add(age("alice", 33));
+    assert_eq!(changes.validate(&*graph.borrow(), &validators).len(), 3);
+    assert!(changes.validate(&*graph.borrow(), &[]).is_empty());
+}
+
+#[test]
+fn rdf_patch() {
+    let mut changes = ChangeSet::default();
+    let _ = changes.remove(age("alice", 33)).add(knows("alice", "bob"));
+    assert_eq!(
+        changes.to_rdf_patch(),
+        "TX .
+D <http://example.org/alice> <http://xmlns.com/foaf/0.1/age> \"33\"^^<http://www.w3.org/2001/XMLSchema#long> .
+A <http://example.org/alice> <http://xmlns.com/foaf/0.1/knows> <http://example.org/bob> .
+TC .
+"
+    );
+    assert_eq!(ChangeSet::default().to_rdf_patch(), "TX .\nTC .\n");
+}
@@ -0,0 +1,185 @@
+use rdftk_core::error::ErrorKind;
+use rdftk_core::model::data_set::change_set::DataSetChangeSet;
+use rdftk_core::model::data_set::{DataSetRef, GraphName, GraphNameRef};
+use rdftk_core::model::graph::change_set::ChangeSet;
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_names::foaf;
+
+fn name(subject: &str, value: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(subject)),
+            foaf::name().clone(),
+            factory.literal_object(literal_factory().literal(value)),
+        )
+        .unwrap()
+}
+
+fn graph_name(name: &str) -> GraphNameRef {
+    GraphName::named_ref(example_iri(name))
+}
+
+fn graph(data_set: &DataSetRef, name: &str) -> GraphRef {
+    data_set
+        .borrow()
+        .graph_named(&graph_name(name))
+        .unwrap()
+        .clone()
+}
+
+fn make_data_set() -> DataSetRef {
+    let data_set = data_set_factory().data_set(Some(graph_factory().graph()));
+    {
+        let mut data_set = data_set.borrow_mut();
+        data_set.insert(
+            graph_name("draft"),
+            graph_factory().graph_from(&[name("alice", "Alice"), name("bob", "Bob")], None),
+        );
+        data_set.insert(graph_name("published"), graph_factory().graph());
+    }
+    data_set
+}
+
+#[test]
+fn move_between_graphs() {
+    let data_set = make_data_set();
+    let mut changes = DataSetChangeSet::default();
+    let _ = changes
+        .move_statements(
+            Some(&graph_name("draft")),
+            Some(&graph_name("published")),
+            &[name("alice", "Alice")],
+        )
+        .add(None, name("carol", "Carol"));
+    assert_eq!(changes.len(), 3);
+    assert_eq!(changes.graphs().count(), 3);
+
+    changes.apply(&mut *data_set.borrow_mut()).unwrap();
+
+    assert!(!graph(&data_set, "draft")
+        .borrow()
+        .contains(&name("alice", "Alice")));
+    assert!(graph(&data_set, "draft")
+        .borrow()
+        .contains(&name("bob", "Bob")));
+    assert!(graph(&data_set, "published")
+        .borrow()
+        .contains(&name("alice", "Alice")));
+    assert!(data_set
+        .borrow()
+        .default_graph()
+        .unwrap()
+        .borrow()
+        .contains(&name("carol", "Carol")));
+
+    // and back again.
+    changes
+        .inverse()
+        .apply(&mut *data_set.borrow_mut())
+        .unwrap();
+    assert_eq!(graph(&data_set, "draft").borrow().len(), 2);
+    assert!(graph(&data_set, "published").borrow().is_empty());
+    assert!(data_set
+        .borrow()
+        .default_graph()
+        .unwrap()
+        .borrow()
+        .is_empty());
+}
+
+#[test]
+fn missing_graphs_are_created() {
+    let data_set = data_set_factory().data_set(None);
+    let mut changes = DataSetChangeSet::default();
+    let _ = changes
+        .add(Some(&graph_name("new")), name("alice", "Alice"))
+        .remove(Some(&graph_name("absent")), name("bob", "Bob"))
+        .add(None, name("carol", "Carol"));
+
+    changes.apply(&mut *data_set.borrow_mut()).unwrap();
+
+    let data_set = data_set.borrow();
+    assert_eq!(data_set.len(), 2);
+    assert!(data_set.has_default_graph());
+    assert!(data_set.has_graph_named(&graph_name("new")));
+    assert!(!data_set.has_graph_named(&graph_name("absent")));
+}
+
+#[test]
+fn rejected_changes_apply_to_no_graph() {
+    let data_set = make_data_set();
+    let mut changes = DataSetChangeSet::default();
+    let _ = changes.move_statements(
+        Some(&graph_name("draft")),
+        Some(&graph_name("published")),
+        &[name("alice", "Alice"), name("bob", "Bob")],
+    );
+
+    let no_bob = |_: &dyn Graph, after: &dyn Graph, _: &ChangeSet| {
+        if after.contains(&name("bob", "Bob")) {
+            vec!["bob is not ready".to_string()]
+        } else {
+            Vec::new()
+        }
+    };
+    let result = changes.apply_validated(&mut *data_set.borrow_mut(), &[&no_bob]);
+    match result.unwrap_err().kind() {
+        ErrorKind::ChangeSetRejected(problems) => {
+            assert_eq!(
+                problems,
+                &vec!["<http://example.org/published>: bob is not ready".to_string()]
+            );
+        }
+        _ => panic!("expected the changes to be rejected"),
+    }
+
+    // the draft graph, which passed validation, was not changed either.
+    assert_eq!(graph(&data_set, "draft").borrow().len(), 2);
+    assert!(graph(&data_set, "published").borrow().is_empty());
+}
+
+#[test]
+fn borrowed_graphs_apply_to_no_graph() {
+    let data_set = make_data_set();
+    let mut changes = DataSetChangeSet::default();
+    let _ = changes.move_statements(
+        Some(&graph_name("draft")),
+        Some(&graph_name("published")),
+        &[name("alice", "Alice")],
+    );
+
+    let published = graph(&data_set, "published");
+    let reader = published.borrow();
+    let result = changes.apply(&mut *data_set.borrow_mut());
+    assert!(matches!(
+        result.unwrap_err().kind(),
+        ErrorKind::GraphInUse(_)
+    ));
+    drop(reader);
+
+    assert_eq!(graph(&data_set, "draft").borrow().len(), 2);
+    assert!(published.borrow().is_empty());
+}
+
+#[test]
+fn data_set_rdf_patch() {
+    let mut changes = DataSetChangeSet::default();
+    let _ = changes
+        .remove(Some(&graph_name("draft")), name("alice", "Alice"))
+        .add(None, name("alice", "Alice"));
+    assert_eq!(
+        changes.to_rdf_patch(),
+        r#"TX .
+D <http://example.org/alice> <http://xmlns.com/foaf/0.1/name> "Alice" <http://example.org/draft> .
+A <http://example.org/alice> <http://xmlns.com/foaf/0.1/name> "Alice" .
+TC .
+"#
+    );
+}
@@ -0,0 +1,88 @@
+use rdftk_core::model::data_set::{GraphName, GraphNameRef};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+
+fn statement(subject: &str, value: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(subject)),
+            example_iri("value"),
+            factory.literal_object(literal_factory().literal(value)),
+        )
+        .unwrap()
+}
+
+fn graph(statements: &[StatementRef]) -> GraphRef {
+    graph_factory().graph_from(statements, None)
+}
+
+#[test]
+fn data_set_graphs() {
+    let data_set = data_set_factory().data_set(Some(graph(&[statement("a", "default")])));
+    let first: GraphNameRef = GraphName::named_ref(example_iri("first"));
+    let second: GraphNameRef = GraphName::named_ref(example_iri("second"));
+    {
+        let mut data_set = data_set.borrow_mut();
+        data_set.insert(first.clone(), graph(&[statement("a", "first")]));
+        data_set.insert(
+            second.clone(),
+            graph(&[statement("a", "second"), statement("a", "default")]),
+        );
+    }
+
+    let data_set = data_set.borrow();
+    assert!(data_set.has_default_graph());
+    assert_eq!(data_set.len(), 3);
+    assert!(data_set.has_graph_named(&first));
+    assert!(!data_set.has_graph_named(&GraphName::named_ref(example_iri("third"))));
+
+    let mut names: Vec<String> = data_set
+        .graph_names()
+        .map(|name| name.to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec![first.to_string(), second.to_string()]);
+
+    let quads = data_set.quads();
+    assert_eq!(quads.len(), 4);
+    assert_eq!(quads[0], (None, statement("a", "default")));
+    assert_eq!(
+        quads
+            .iter()
+            .filter(|(name, _)| name.as_ref() == Some(&second))
+            .count(),
+        2
+    );
+
+    let union = data_set.union_graph();
+    let union = union.borrow();
+    assert_eq!(union.len(), 3);
+    assert!(union.contains(&statement("a", "first")));
+    assert!(union.contains(&statement("a", "second")));
+    assert!(union.contains(&statement("a", "default")));
+}
+
+#[test]
+fn data_set_mutation() {
+    let data_set = data_set_factory().data_set(None);
+    let name = GraphName::named_ref(example_iri("first"));
+    let mut data_set = data_set.borrow_mut();
+    assert!(data_set.is_empty());
+    assert!(data_set.quads().is_empty());
+
+    data_set.insert(name.clone(), graph(&[statement("a", "first")]));
+    data_set.set_default_graph(graph(&[]));
+    assert!(data_set.has_default_graph());
+    assert_eq!(data_set.quads().len(), 1);
+
+    data_set.unset_default_graph();
+    data_set.remove(&name);
+    assert!(!data_set.has_default_graph());
+    assert!(data_set.is_empty());
+}
@@ -0,0 +1,143 @@
+use rdftk_core::model::data_set::GraphName;
+use rdftk_core::model::graph::gc::{collect_data_set_garbage, collect_garbage, collect_orphans};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::IRIRef;
+use rdftk_names::{foaf, rdf};
+
+fn blank(name: &str) -> SubjectNodeRef {
+    statement_factory().blank_subject_named(name).unwrap()
+}
+
+fn blank_object(name: &str) -> ObjectNodeRef {
+    statement_factory().blank_object_named(name).unwrap()
+}
+
+fn named(name: &str) -> SubjectNodeRef {
+    statement_factory().named_subject(example_iri(name))
+}
+
+fn literal(value: &str) -> ObjectNodeRef {
+    statement_factory().literal_object(literal_factory().literal(value))
+}
+
+fn statement(subject: SubjectNodeRef, predicate: &IRIRef, object: ObjectNodeRef) -> StatementRef {
+    statement_factory()
+        .statement(subject, predicate.clone(), object)
+        .unwrap()
+}
+
+// alice knows _:bob, who has an address _:address; _:loop1 and _:loop2 refer only to each other;
+// _:anonymous is never the object of any statement.
+fn make_graph() -> GraphRef {
+    graph_factory().graph_from(
+        &[
+            statement(named("alice"), foaf::knows(), blank_object("bob")),
+            statement(blank("bob"), foaf::name(), literal("Bob")),
+            statement(
+                blank("bob"),
+                &example_iri("address"),
+                blank_object("address"),
+            ),
+            statement(blank("address"), &example_iri("city"), literal("Oxford")),
+            statement(blank("loop1"), &example_iri("next"), blank_object("loop2")),
+            statement(blank("loop2"), &example_iri("next"), blank_object("loop1")),
+            statement(
+                blank("anonymous"),
+                rdf::a_type(),
+                statement_factory().named_object(example_iri("Thing")),
+            ),
+        ],
+        None,
+    )
+}
+
+#[test]
+fn collect_unreachable_blank_nodes() {
+    let graph = make_graph();
+    let collected = collect_garbage(&mut *graph.borrow_mut());
+    assert_eq!(collected.len(), 3);
+    assert_eq!(
+        collected.blank_nodes().iter().collect::<Vec<_>>(),
+        vec!["anonymous", "loop1", "loop2"]
+    );
+    assert_eq!(graph.borrow().len(), 4);
+
+    // nothing left to collect.
+    assert!(collect_garbage(&mut *graph.borrow_mut()).is_empty());
+}
+
+#[test]
+fn collect_orphaned_subtree() {
+    let graph = make_graph();
+    let knows = statement(named("alice"), foaf::knows(), blank_object("bob"));
+    graph.borrow_mut().remove(&knows);
+
+    let collected = collect_orphans(&mut *graph.borrow_mut(), &[knows]);
+    assert_eq!(collected.len(), 3);
+    assert_eq!(
+        collected.blank_nodes().iter().collect::<Vec<_>>(),
+        vec!["address", "bob"]
+    );
+    // the cycle, and the anonymous resource, were not referred to by the removed statement.
+    assert_eq!(graph.borrow().len(), 3);
+}
+
+#[test]
+fn orphans_still_referenced_are_kept() {
+    let graph = make_graph();
+    let also_knows = statement(named("carol"), foaf::knows(), blank_object("bob"));
+    graph.borrow_mut().insert(also_knows);
+    let knows = statement(named("alice"), foaf::knows(), blank_object("bob"));
+    graph.borrow_mut().remove(&knows);
+
+    let collected = collect_orphans(&mut *graph.borrow_mut(), &[knows]);
+    assert!(collected.is_empty());
+    assert_eq!(graph.borrow().len(), 7);
+
+    // a blank node referred to from a remaining, unreferenced, blank node is also kept.
+    let knows = statement(named("carol"), foaf::knows(), blank_object("bob"));
+    graph.borrow_mut().remove(&knows);
+    graph.borrow_mut().insert(statement(
+        blank("anonymous"),
+        foaf::knows(),
+        blank_object("bob"),
+    ));
+    let collected = collect_orphans(&mut *graph.borrow_mut(), &[knows]);
+    assert!(collected.is_empty());
+}
+
+#[test]
+fn collect_data_set_shared_blank_nodes() {
+    let default_graph = graph_factory().graph_from(
+        &[statement(
+            named("alice"),
+            foaf::knows(),
+            blank_object("bob"),
+        )],
+        None,
+    );
+    let named_graph = graph_factory().graph_from(
+        &[
+            statement(blank("bob"), foaf::name(), literal("Bob")),
+            statement(blank("orphan"), foaf::name(), literal("Nobody")),
+        ],
+        None,
+    );
+    let data_set = data_set_factory().data_set(Some(default_graph.clone()));
+    data_set.borrow_mut().insert(
+        GraphName::named_ref(example_iri("people")),
+        named_graph.clone(),
+    );
+
+    let collected = collect_data_set_garbage(&*data_set.borrow());
+    assert_eq!(collected.len(), 1);
+    assert!(collected.blank_nodes().contains("orphan"));
+    assert_eq!(default_graph.borrow().len(), 1);
+    assert_eq!(named_graph.borrow().len(), 1);
+}
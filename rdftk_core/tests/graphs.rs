@@ -1,7 +1,12 @@
 use parameterized::parameterized;
-use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::model::graph::iter::snapshot;
+use rdftk_core::model::graph::projection::SubjectFilter;
+use rdftk_core::model::graph::{Graph, GraphFactoryRef, GraphRef};
+use rdftk_core::model::statement::StatementRef;
 use rdftk_core::simple::graph::graph_factory as simple_graph_factory;
+use rdftk_core::simple::graph::SimpleGraph;
 use rdftk_core::simple::indexed::graph_factory as indexed_graph_factory;
+use rdftk_core::simple::indexed::IndexedSimpleGraph;
 use rdftk_core::simple::PROVIDER_ID;
 use rdftk_iri::{IRIRef, IRI};
 use std::str::FromStr;
@@ -149,3 +154,306 @@ fn graph_contains_subject(graph_factory: GraphFactoryRef) {
         assert!(!graph.contains_subject(&subject));
     }
 }
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_statement_iter(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory);
+    let graph = graph.borrow();
+
+    let statements = graph.statements();
+    assert_eq!(statements.len(), 5);
+    assert_eq!(statements.size_hint(), (5, Some(5)));
+
+    let forward: Vec<&StatementRef> = graph.statements().collect();
+    let mut backward: Vec<&StatementRef> = graph.statements().rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    let mut count = 0;
+    for _ in &*graph {
+        count += 1;
+    }
+    assert_eq!(count, 5);
+}
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_from_iter(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory.clone());
+    let graph = graph.borrow();
+
+    let copy = graph_factory.graph_from_iter(&mut graph.statements().cloned());
+    assert_eq!(copy.borrow().len(), 5);
+
+    // as for `extend_from`, statements already in the graph are skipped.
+    copy.borrow_mut().extend(graph.statements().cloned());
+    assert_eq!(copy.borrow().len(), 5);
+}
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_distinct_nodes(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory);
+    let mut graph = graph.borrow_mut();
+
+    let subject_iri =
+        IRIRef::from(IRI::from_str("http://en.wikipedia.org/wiki/Tony_Benn").unwrap());
+    let subject = graph.statement_factory().named_subject(subject_iri);
+
+    let mut subject_counts: Vec<usize> = graph.distinct_subjects().map(|(_, n)| n).collect();
+    subject_counts.sort_unstable();
+    assert_eq!(subject_counts, vec![2, 3]);
+    assert_eq!(
+        graph
+            .distinct_subjects()
+            .find(|(s, _)| *s == &subject)
+            .map(|(_, n)| n),
+        Some(3)
+    );
+
+    assert_eq!(graph.distinct_predicates().count(), 5);
+    assert!(graph.distinct_predicates().all(|(_, n)| n == 1));
+
+    let mut object_counts: Vec<usize> = graph.distinct_objects().map(|(_, n)| n).collect();
+    object_counts.sort_unstable();
+    assert_eq!(object_counts, vec![1, 1, 1, 2]);
+
+    let title = IRIRef::from(IRI::from_str("http://purl.org/dc/elements/1.1/title").unwrap());
+    let counts = graph.count_by_predicate();
+    assert_eq!(counts.len(), 5);
+    assert_eq!(counts.get(&title), Some(&1));
+
+    let removals: Vec<StatementRef> = graph
+        .statements()
+        .filter(|st| st.subject() == &subject)
+        .cloned()
+        .collect();
+    for statement in &removals {
+        graph.remove(statement);
+    }
+    assert_eq!(graph.distinct_subjects().count(), 1);
+    assert_eq!(graph.distinct_predicates().count(), 2);
+    assert_eq!(graph.distinct_objects().count(), 2);
+    assert!(!graph.contains_subject(&subject));
+}
+
+#[test]
+fn simple_graph_collect() {
+    let graph = tony_benn_graph(simple_graph_factory());
+    let graph = graph.borrow();
+
+    let simple: SimpleGraph = graph.statements().cloned().collect();
+    assert_eq!(simple.len(), 5);
+    assert_eq!((&simple).into_iter().count(), 5);
+    assert_eq!(simple.into_iter().count(), 5);
+
+    let indexed: IndexedSimpleGraph = graph.statements().cloned().collect();
+    assert_eq!(indexed.len(), 5);
+    assert_eq!(indexed.subjects().len(), 2);
+}
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_projection(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory);
+    let graph = graph.borrow();
+    let dc_title = IRIRef::from(IRI::from_str("http://purl.org/dc/elements/1.1/title").unwrap());
+    let dc_description =
+        IRIRef::from(IRI::from_str("http://purl.org/dc/elements/1.1/description").unwrap());
+    let foaf_name = IRIRef::from(IRI::from_str("http://xmlns.com/foaf/0.1/name").unwrap());
+    let foaf_person = IRIRef::from(IRI::from_str("http://xmlns.com/foaf/0.1/Person").unwrap());
+
+    let people: Vec<String> = graph
+        .project(SubjectFilter::OfType(foaf_person.clone()), |resource| {
+            resource.value(&foaf_name).cloned()
+        })
+        .collect();
+    assert_eq!(people, vec!["Tony Benn".to_string()]);
+
+    let pages: Vec<(String, String, Vec<String>)> = graph
+        .project(SubjectFilter::Named, |resource| {
+            let description = resource.resource(&dc_description)?;
+            Some((
+                resource.value(&dc_title)?.clone(),
+                description.value(&foaf_name)?.clone(),
+                description
+                    .types()
+                    .iter()
+                    .map(|iri| iri.to_string())
+                    .collect(),
+            ))
+        })
+        .collect();
+    assert_eq!(
+        pages,
+        vec![(
+            "Tony Benn".to_string(),
+            "Tony Benn".to_string(),
+            vec![foaf_person.to_string()]
+        )]
+    );
+
+    // the mapper may skip subjects.
+    assert_eq!(graph.project(SubjectFilter::All, |_| None::<()>).count(), 0);
+    assert_eq!(
+        graph
+            .project(SubjectFilter::All, |resource| Some(resource.clone()))
+            .count(),
+        2
+    );
+}
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_matches(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory);
+    let mut graph = graph.borrow_mut();
+    let factory = graph.statement_factory();
+    let subject = factory.named_subject(IRIRef::from(
+        IRI::from_str("http://en.wikipedia.org/wiki/Tony_Benn").unwrap(),
+    ));
+    let blank = factory.blank_subject_named("B1").unwrap();
+    let dc_title = IRIRef::from(IRI::from_str("http://purl.org/dc/elements/1.1/title").unwrap());
+    let foaf_name = IRIRef::from(IRI::from_str("http://xmlns.com/foaf/0.1/name").unwrap());
+    let tony_benn = factory.literal_object(graph.literal_factory().literal("Tony Benn"));
+
+    assert_eq!(graph.matches(None, None, None).len(), 5);
+    assert_eq!(graph.matches(Some(&subject), None, None).len(), 3);
+    assert_eq!(graph.matches(None, None, Some(&tony_benn)).len(), 2);
+    assert_eq!(
+        graph
+            .matches(Some(&subject), Some(&foaf_name), Some(&tony_benn))
+            .len(),
+        0
+    );
+
+    let matching = graph.statements_matching(None, None, Some(&tony_benn));
+    assert_eq!(matching.len(), 2);
+    assert_eq!(matching[0].predicate(), &dc_title);
+    assert_eq!(matching[1].predicate(), &foaf_name);
+    assert_eq!(
+        graph
+            .statements_matching(Some(&blank), Some(&foaf_name), None)
+            .len(),
+        1
+    );
+    assert_eq!(graph.statements_matching(None, None, None).len(), 5);
+
+    // unlike `matches`, duplicates are retained.
+    let duplicate = matching[0].clone();
+    graph.insert(duplicate);
+    assert_eq!(graph.matches(None, Some(&dc_title), None).len(), 1);
+    assert_eq!(
+        graph.statements_matching(None, Some(&dc_title), None).len(),
+        2
+    );
+}
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_matches_wildcards(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory);
+    let graph = graph.borrow();
+    let factory = graph.statement_factory();
+    let subject = factory.named_subject(IRIRef::from(
+        IRI::from_str("http://en.wikipedia.org/wiki/Tony_Benn").unwrap(),
+    ));
+    let dc_title = IRIRef::from(IRI::from_str("http://purl.org/dc/elements/1.1/title").unwrap());
+    let tony_benn = factory.literal_object(graph.literal_factory().literal("Tony Benn"));
+
+    // a position that is not provided matches any value, rather than none.
+    assert_eq!(graph.matches(None, Some(&dc_title), None).len(), 1);
+    assert_eq!(
+        graph.matches(Some(&subject), Some(&dc_title), None).len(),
+        1
+    );
+    assert_eq!(
+        graph.matches(None, Some(&dc_title), Some(&tony_benn)).len(),
+        1
+    );
+    assert_eq!(
+        graph.matches(Some(&subject), None, Some(&tony_benn)).len(),
+        1
+    );
+    assert_eq!(
+        graph
+            .matches(Some(&subject), Some(&dc_title), Some(&tony_benn))
+            .len(),
+        1
+    );
+}
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_modify_during_snapshot(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory);
+
+    let mut seen = 0;
+    for statement in snapshot(&graph) {
+        seen += 1;
+        graph.borrow_mut().remove(&statement);
+    }
+    assert_eq!(seen, 5);
+    assert!(graph.borrow().is_empty());
+}
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_snapshot_is_unchanged(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory);
+    let before = graph.borrow().snapshot();
+    assert!(before.shares_storage(&graph.borrow().snapshot()));
+
+    let first = before.iter().next().unwrap().clone();
+    graph.borrow_mut().remove(&first);
+    let after = snapshot(&graph);
+
+    assert!(!before.shares_storage(&after));
+    assert_eq!(before.len(), 5);
+    assert!(before.contains(&first));
+    assert_eq!(after.len(), 4);
+    assert!(!after.contains(&first));
+    assert_eq!(before.into_iter().rev().count(), 5);
+}
+
+#[parameterized(graph_factory = { simple_graph_factory(), indexed_graph_factory()})]
+fn graph_extend_from(graph_factory: GraphFactoryRef) {
+    let graph = tony_benn_graph(graph_factory);
+    let existing: Vec<StatementRef> = graph.borrow().statements().cloned().collect();
+    let factory = graph.borrow().statement_factory();
+    let subject = factory.named_subject(IRIRef::from(
+        IRI::from_str("http://en.wikipedia.org/wiki/Hilary_Benn").unwrap(),
+    ));
+    let name = factory
+        .statement(
+            subject.clone(),
+            IRIRef::from(IRI::from_str("http://xmlns.com/foaf/0.1/name").unwrap()),
+            factory.literal_object(graph.borrow().literal_factory().literal("Hilary Benn")),
+        )
+        .unwrap();
+    let kind = factory
+        .statement(
+            subject,
+            IRIRef::from(IRI::from_str("http://www.w3.org/1999/02/22-rdf-syntax-ns#type").unwrap()),
+            factory.named_object(IRIRef::from(
+                IRI::from_str("http://xmlns.com/foaf/0.1/Person").unwrap(),
+            )),
+        )
+        .unwrap();
+
+    let mut graph = graph.borrow_mut();
+    graph.reserve(4);
+    let mut statements = vec![
+        existing[0].clone(),
+        name.clone(),
+        kind.clone(),
+        name.clone(),
+    ]
+    .into_iter();
+    assert_eq!(graph.extend_from(&mut statements), 2);
+    assert_eq!(graph.len(), 7);
+    assert!(graph.contains(&name));
+    assert!(graph.contains(&kind));
+    assert_eq!(graph.matches(None, None, None).len(), 7);
+    assert_eq!(graph.extend_from(&mut existing.clone().into_iter()), 0);
+    assert_eq!(graph.len(), 7);
+
+    // `Extend` has the same semantics as `extend_from`.
+    let graph: &mut dyn Graph = &mut *graph;
+    graph.extend(vec![existing[1].clone(), name.clone(), kind, name]);
+    assert_eq!(graph.len(), 7);
+    assert_eq!(graph.statements_matching(None, None, None).len(), 7);
+}
@@ -0,0 +1,255 @@
+use rdftk_core::model::graph::store::interned::InternedStore;
+use rdftk_core::model::graph::store::{StatementStore, StoreGraph};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LanguageTag};
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_core::simple::interned;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+fn subject(s: &str) -> SubjectNodeRef {
+    statement_factory().named_subject(example_iri(s))
+}
+
+fn object(s: &str) -> ObjectNodeRef {
+    statement_factory().named_object(example_iri(s))
+}
+
+fn statement(s: SubjectNodeRef, p: &str, o: ObjectNodeRef) -> StatementRef {
+    statement_factory().statement(s, example_iri(p), o).unwrap()
+}
+
+fn statements() -> Vec<StatementRef> {
+    let factory = statement_factory();
+    let literals = literal_factory();
+    let knows = statement(subject("alice"), "knows", object("bob"));
+    vec![
+        knows.clone(),
+        statement(subject("alice"), "knows", object("carol")),
+        statement(subject("bob"), "knows", object("carol")),
+        statement(
+            subject("alice"),
+            "name",
+            factory.literal_object(literals.literal("Alice \"A\"\n")),
+        ),
+        statement(
+            subject("alice"),
+            "name",
+            factory.literal_object(
+                literals.with_language("Alicia", LanguageTag::from_str("es").unwrap()),
+            ),
+        ),
+        statement(
+            subject("alice"),
+            "age",
+            factory.literal_object(literals.with_data_type("34", DataType::Integer)),
+        ),
+        statement(
+            subject("alice"),
+            "age",
+            factory.literal_object(literals.with_data_type(
+                "34",
+                DataType::Other(IRIRef::from(
+                    IRI::from_str("http://www.w3.org/2001/XMLSchema#integer").unwrap(),
+                )),
+            )),
+        ),
+        statement(
+            factory.blank_subject_named("b1").unwrap(),
+            "city",
+            factory.blank_object_named("b2").unwrap(),
+        ),
+        statement(factory.statement_subject(knows), "source", object("survey")),
+    ]
+}
+
+#[test]
+fn interned_insert_and_remove() {
+    let mut store = InternedStore::default();
+    assert!(store.is_empty().unwrap());
+    for st in statements() {
+        assert!(store.insert(&st).unwrap());
+        assert!(!store.insert(&st).unwrap());
+        assert!(store.contains(&st).unwrap());
+    }
+    assert_eq!(store.len().unwrap(), statements().len());
+
+    let mut read: Vec<StatementRef> = store
+        .matching(None, None, None)
+        .unwrap()
+        .map(|st| st.unwrap())
+        .collect();
+    for st in statements() {
+        let index = read.iter().position(|r| r == &st).unwrap();
+        let _ = read.remove(index);
+    }
+    assert!(read.is_empty());
+
+    let st = &statements()[0];
+    assert!(store.remove(st).unwrap());
+    assert!(!store.remove(st).unwrap());
+    assert!(!store.contains(st).unwrap());
+    assert_eq!(store.len().unwrap(), statements().len() - 1);
+    assert_eq!(
+        store
+            .matching(None, None, Some(&object("bob")))
+            .unwrap()
+            .count(),
+        0
+    );
+    assert!(!store
+        .contains(&statement(subject("nobody"), "knows", object("bob")))
+        .unwrap());
+
+    store.clear().unwrap();
+    assert!(store.is_empty().unwrap());
+    assert_eq!(store.term_count(), 0);
+}
+
+#[test]
+fn interned_matching() {
+    let store: InternedStore = statements().into_iter().collect();
+    let count = |s: Option<&SubjectNodeRef>, p: Option<&IRIRef>, o: Option<&ObjectNodeRef>| {
+        store
+            .matching(s, p, o)
+            .unwrap()
+            .map(|st| {
+                let st = st.unwrap();
+                assert!(s.map(|s| st.subject() == s).unwrap_or(true));
+                assert!(p.map(|p| st.predicate() == p).unwrap_or(true));
+                assert!(o.map(|o| st.object() == o).unwrap_or(true));
+            })
+            .count()
+    };
+    let alice = subject("alice");
+    let knows = example_iri("knows");
+    let carol = object("carol");
+    assert_eq!(count(None, None, None), 9);
+    assert_eq!(count(Some(&alice), None, None), 6);
+    assert_eq!(count(Some(&alice), Some(&knows), None), 2);
+    assert_eq!(count(None, Some(&knows), None), 3);
+    assert_eq!(count(None, Some(&knows), Some(&carol)), 2);
+    assert_eq!(count(None, None, Some(&carol)), 2);
+    assert_eq!(count(Some(&alice), None, Some(&carol)), 1);
+    assert_eq!(count(Some(&alice), Some(&knows), Some(&carol)), 1);
+    assert_eq!(count(Some(&subject("bob")), Some(&knows), Some(&carol)), 1);
+    assert_eq!(
+        count(Some(&subject("carol")), Some(&knows), Some(&carol)),
+        0
+    );
+    assert_eq!(count(None, Some(&example_iri("age")), None), 2);
+    assert_eq!(count(Some(&subject("alic")), None, None), 0);
+}
+
+#[test]
+fn interned_terms_are_shared() {
+    let mut store = InternedStore::default();
+    for index in 0..100 {
+        let _ = store
+            .insert(&statement(
+                subject("alice"),
+                "knows",
+                object(&format!("person{}", index % 10)),
+            ))
+            .unwrap();
+    }
+    // alice, knows, and ten people.
+    assert_eq!(store.len().unwrap(), 10);
+    assert_eq!(store.term_count(), 12);
+
+    // fourteen new terms, including the statement used as a subject.
+    store.extend(statements());
+    assert_eq!(store.len().unwrap(), 19);
+    assert_eq!(store.term_count(), 12 + 14);
+}
+
+#[test]
+fn interned_store_graph() {
+    let mut graph = StoreGraph::open(InternedStore::default()).unwrap();
+    for st in statements() {
+        graph.insert(st);
+    }
+    graph
+        .prefix_mappings()
+        .borrow_mut()
+        .insert("ex", example_iri(""));
+    let store = graph.into_store().unwrap();
+    assert_eq!(store.len().unwrap(), statements().len());
+    assert_eq!(
+        store.prefix_mappings().unwrap(),
+        vec![("ex".to_string(), example_iri(""))]
+    );
+
+    let graph = StoreGraph::open(store).unwrap();
+    assert_eq!(graph.len(), statements().len());
+    assert_eq!(
+        graph
+            .objects_for(&subject("alice"), &example_iri("knows"))
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn store_graph_reads_lazily() {
+    let mut store = InternedStore::default();
+    for st in statements() {
+        let _ = store.insert(&st).unwrap();
+    }
+    let mut graph = StoreGraph::open(store).unwrap();
+    assert_eq!(
+        graph
+            .objects_for(&subject("alice"), &example_iri("knows"))
+            .len(),
+        2
+    );
+    assert_eq!(graph.held(), 2);
+
+    // statements are only read, and held, as the iterator is advanced.
+    let mut iter = graph.statements();
+    assert_eq!(iter.len(), statements().len());
+    let _ = iter.next().unwrap();
+    let _ = iter.next().unwrap();
+    drop(iter);
+    assert_eq!(graph.held(), 2 + 2);
+    assert_eq!(graph.try_statements().unwrap().count(), statements().len());
+
+    // once changed the graph is answered from the store again.
+    graph.insert(statement(subject("alice"), "knows", object("dave")));
+    assert_eq!(graph.held(), 0);
+    assert_eq!(
+        graph
+            .objects_for(&subject("alice"), &example_iri("knows"))
+            .len(),
+        3
+    );
+    assert_eq!(graph.held(), 3);
+    graph.release().unwrap();
+    assert_eq!(graph.held(), 0);
+}
+
+#[test]
+fn interned_graph() {
+    let graph = interned::graph_factory().graph_from(&statements(), None);
+    let mut graph = graph.borrow_mut();
+    assert_eq!(graph.len(), statements().len());
+    assert!(graph.contains(&statements()[2]));
+    assert!(graph.contains_individual(&example_iri("bob")));
+    assert_eq!(graph.predicates_for(&subject("alice")).len(), 3);
+    assert_eq!(
+        graph
+            .objects_for(&subject("alice"), &example_iri("knows"))
+            .len(),
+        2
+    );
+    assert_eq!(graph.statements().count(), statements().len());
+
+    graph.remove(&statements()[2]);
+    assert!(!graph.contains_individual(&example_iri("bob")));
+    assert_eq!(graph.len(), statements().len() - 1);
+    graph.clear();
+    assert!(graph.is_empty());
+}
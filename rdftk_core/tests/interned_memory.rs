@@ -0,0 +1,85 @@
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::interned::InternedGraph;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+const SUBJECTS: usize = 500;
+const PREDICATES: usize = 10;
+
+// each statement is created with new nodes, as a parser would, with many repeated terms.
+fn statements() -> impl Iterator<Item = StatementRef> {
+    let factory = statement_factory();
+    (0..SUBJECTS).flat_map(move |subject| {
+        let factory = factory.clone();
+        (0..PREDICATES).map(move |predicate| {
+            factory
+                .statement(
+                    factory.named_subject(example_iri(&format!("s/{}", subject))),
+                    example_iri(&format!("p/{}", predicate)),
+                    factory.literal_object(
+                        literal_factory().literal(&format!("value {}", (subject + predicate) % 50)),
+                    ),
+                )
+                .unwrap()
+        })
+    })
+}
+
+fn retained<T>(build: impl FnOnce() -> T) -> (T, usize) {
+    let before = LIVE_BYTES.load(Ordering::Relaxed);
+    let graph = build();
+    (graph, LIVE_BYTES.load(Ordering::Relaxed) - before)
+}
+
+// a single test, as the allocator counts the allocations of all threads.
+#[test]
+fn interned_graph_retains_less_memory() {
+    let (simple, simple_bytes) = retained(|| graph_factory().graph_from_iter(&mut statements()));
+    let (mut graph, interned_bytes) = retained(|| statements().collect::<InternedGraph>());
+    assert_eq!(simple.borrow().len(), SUBJECTS * PREDICATES);
+    assert_eq!(graph.len(), SUBJECTS * PREDICATES);
+    assert!(
+        interned_bytes * 3 < simple_bytes,
+        "interned graph retained {} bytes, simple graph {} bytes",
+        interned_bytes,
+        simple_bytes
+    );
+    drop(simple);
+
+    // a query holds only the statements it returns, and iteration only the statements it has
+    // reached, until the graph is released.
+    let subject = statement_factory().named_subject(example_iri("s/7"));
+    let before = LIVE_BYTES.load(Ordering::Relaxed);
+    assert_eq!(graph.predicates_for(&subject).len(), PREDICATES);
+    assert!(LIVE_BYTES.load(Ordering::Relaxed) - before < interned_bytes / 10);
+    assert_eq!(graph.statements().take(PREDICATES).count(), PREDICATES);
+    assert!(LIVE_BYTES.load(Ordering::Relaxed) - before < interned_bytes / 10);
+    assert_eq!(graph.statements().count(), SUBJECTS * PREDICATES);
+    assert!(LIVE_BYTES.load(Ordering::Relaxed) - before > interned_bytes / 2);
+    graph.release().unwrap();
+    assert!(LIVE_BYTES.load(Ordering::Relaxed) <= before);
+}
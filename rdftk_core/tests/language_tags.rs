@@ -1,4 +1,5 @@
 use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::simple::literal::literal_factory;
 use std::str::FromStr;
 
 // See: https://schneegans.de/lv/?tags=de
@@ -462,3 +463,68 @@ fn some_equality() {
 
     assert_eq!(Some(lhs), Some(rhs));
 }
+
+#[test]
+fn display_preserves_case() {
+    for value in &[
+        "zh-Hant",
+        "zh-Hant-TW",
+        "sl-IT-rozaj-biske-1994",
+        "en-a-bbb-x-a-ccc",
+        "x-fr-CH",
+        "i-klingon",
+    ] {
+        assert_eq!(LanguageTag::from_str(value).unwrap().to_string(), *value);
+    }
+}
+
+#[test]
+fn spec_canonical_format() {
+    for (value, canonical) in &[
+        ("ZH-hant-tw", "zh-Hant-TW"),
+        (
+            "en-BOONT-B-Ccc-A-bbb-X-Private",
+            "en-boont-a-bbb-b-ccc-x-private",
+        ),
+        ("X-FR-CH", "x-fr-ch"),
+        ("es-419", "es-419"),
+    ] {
+        assert_eq!(
+            LanguageTag::from_str(value)
+                .unwrap()
+                .to_canonical_format()
+                .to_string(),
+            *canonical
+        );
+    }
+}
+
+#[test]
+fn spec_invalid_trailing_text() {
+    assert!(LanguageTag::from_str("i-klingon-x").is_err());
+    assert!(LanguageTag::from_str("en-").is_err());
+    assert!(LanguageTag::from_str("x-").is_err());
+}
+
+#[test]
+fn basic_filtering() {
+    let tag = LanguageTag::from_str("de-Latn-DE").unwrap();
+    assert!(tag.matches("*"));
+    assert!(tag.matches("de"));
+    assert!(tag.matches("DE-latn"));
+    assert!(tag.matches("de-Latn-DE"));
+    assert!(!tag.matches("de-DE"));
+    assert!(!tag.matches("de-La"));
+    assert!(!tag.matches("en"));
+    assert!(!tag.matches(""));
+}
+
+#[test]
+fn literal_language_matches() {
+    let literals = literal_factory();
+    let literal = literals.with_language("漢字", LanguageTag::from_str("zh-Hant").unwrap());
+    assert!(literal.language_matches("zh"));
+    assert!(literal.language_matches("zh-hant"));
+    assert!(!literal.language_matches("zh-Hans"));
+    assert!(!literals.literal("untagged").language_matches("*"));
+}
@@ -0,0 +1,106 @@
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::IRIRef;
+use rdftk_names::foaf;
+
+fn blank(name: &str) -> SubjectNodeRef {
+    statement_factory().blank_subject_named(name).unwrap()
+}
+
+fn blank_object(name: &str) -> ObjectNodeRef {
+    statement_factory().blank_object_named(name).unwrap()
+}
+
+fn statement(subject: SubjectNodeRef, predicate: &IRIRef, object: ObjectNodeRef) -> StatementRef {
+    statement_factory()
+        .statement(subject, predicate.clone(), object)
+        .unwrap()
+}
+
+fn person(name: &str, value: &str) -> Vec<StatementRef> {
+    let factory = statement_factory();
+    vec![
+        statement(
+            factory.named_subject(example_iri("group")),
+            foaf::member(),
+            blank_object(name),
+        ),
+        statement(
+            blank(name),
+            foaf::name(),
+            factory.literal_object(literal_factory().literal(value)),
+        ),
+    ]
+}
+
+#[test]
+fn lean_removes_repeated_loads() {
+    let mut statements = person("b1", "Alice");
+    statements.extend(person("b2", "Alice"));
+    statements.extend(person("b3", "Bob"));
+    let graph = graph_factory().graph_from(&statements, None);
+    assert_eq!(graph.borrow().len(), 6);
+    assert!(!graph.borrow().is_lean());
+
+    let lean = graph.borrow().lean();
+    let lean = lean.borrow();
+    assert_eq!(lean.len(), 4);
+    assert!(lean.is_lean());
+    assert_eq!(
+        lean.statements()
+            .filter(|st| st.predicate() == foaf::name())
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn lean_keeps_distinct_structures() {
+    let knows = foaf::knows().clone();
+    let graph = graph_factory().graph_from(
+        &[
+            statement(blank("a"), &knows, blank_object("b")),
+            statement(blank("b"), &knows, blank_object("a")),
+        ],
+        None,
+    );
+    assert!(graph.borrow().is_lean());
+    assert_eq!(graph.borrow().lean().borrow().len(), 2);
+}
+
+#[test]
+fn lean_folds_chains_into_cycles() {
+    let knows = foaf::knows().clone();
+    let graph = graph_factory().graph_from(
+        &[
+            statement(blank("a"), &knows, blank_object("b")),
+            statement(blank("b"), &knows, blank_object("c")),
+            statement(blank("c"), &knows, blank_object("c")),
+        ],
+        None,
+    );
+    let lean = graph.borrow().lean();
+    let lean = lean.borrow();
+    assert_eq!(lean.len(), 1);
+    let statement = lean.statements().next().unwrap();
+    assert_eq!(statement.subject().as_blank().unwrap(), "c");
+    assert_eq!(statement.object().as_blank().unwrap(), "c");
+}
+
+#[test]
+fn lean_ignores_ground_graphs() {
+    let factory = statement_factory();
+    let graph = graph_factory().graph_from(
+        &[statement(
+            factory.named_subject(example_iri("alice")),
+            foaf::knows(),
+            factory.named_object(example_iri("bob")),
+        )],
+        None,
+    );
+    assert!(graph.borrow().is_lean());
+    assert_eq!(graph.borrow().lean().borrow().len(), 1);
+}
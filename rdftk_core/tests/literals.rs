@@ -1,4 +1,8 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use rdftk_core::model::literal::DataType;
 use rdftk_core::simple::literal::literal_factory;
+use rdftk_names::xsd;
+use std::cmp::Ordering;
 use std::time::Duration;
 
 #[test]
@@ -97,3 +101,512 @@ fn typed_as_duration() {
         "\"PT63542S\"^^<http://www.w3.org/2001/XMLSchema#duration>"
     );
 }
+
+#[test]
+fn typed_values_integers() {
+    let literals = literal_factory();
+    assert_eq!(literals.long(i64::MIN).as_i64().unwrap(), i64::MIN);
+    assert_eq!(literals.unsigned_long(u64::MAX).as_u64().unwrap(), u64::MAX);
+    assert_eq!(literals.byte(-8).as_f64().unwrap(), -8.0);
+    assert_eq!(
+        literals
+            .with_data_type(" +0012 ", DataType::Int)
+            .as_i64()
+            .unwrap(),
+        12
+    );
+    assert_eq!(
+        literals
+            .with_data_type("-42", DataType::Other(xsd::long().clone()))
+            .as_i64()
+            .unwrap(),
+        -42
+    );
+    assert_eq!(
+        literals
+            .with_data_type("42", DataType::Other(xsd::positive_integer().clone()))
+            .as_u64()
+            .unwrap(),
+        42
+    );
+
+    // out of range for the data type
+    assert!(literals
+        .with_data_type("256", DataType::UnsignedByte)
+        .as_i64()
+        .is_err());
+    assert!(literals
+        .with_data_type("0", DataType::Other(xsd::positive_integer().clone()))
+        .as_i64()
+        .is_err());
+    // out of range for the Rust type
+    assert!(literals.unsigned_long(u64::MAX).as_i64().is_err());
+    assert!(literals.int(-1).as_u64().is_err());
+    // invalid lexical forms
+    assert!(literals
+        .with_data_type("1.0", DataType::Int)
+        .as_i64()
+        .is_err());
+    assert!(literals.with_data_type("", DataType::Int).as_i64().is_err());
+    assert!(literals
+        .with_data_type("0x10", DataType::Int)
+        .as_i64()
+        .is_err());
+    // incompatible data types
+    assert!(literals.string("12").as_i64().is_err());
+    assert!(literals.literal("12").as_i64().is_err());
+    assert!(literals.double(12.0).as_i64().is_err());
+}
+
+#[test]
+fn typed_values_floats() {
+    let literals = literal_factory();
+    assert_eq!(literals.double(-2.5e-10).as_f64().unwrap(), -2.5e-10);
+    assert_eq!(literals.float(1.5).as_f64().unwrap(), 1.5);
+    assert_eq!(
+        literals
+            .with_data_type("-INF", DataType::Double)
+            .as_f64()
+            .unwrap(),
+        f64::NEG_INFINITY
+    );
+    assert!(literals
+        .with_data_type("NaN", DataType::Float)
+        .as_f64()
+        .unwrap()
+        .is_nan());
+    assert_eq!(
+        literals
+            .with_data_type(".5e1", DataType::Double)
+            .as_f64()
+            .unwrap(),
+        5.0
+    );
+    assert_eq!(
+        literals
+            .with_data_type("-0.25", DataType::Other(xsd::decimal().clone()))
+            .as_f64()
+            .unwrap(),
+        -0.25
+    );
+
+    assert!(literals
+        .with_data_type("inf", DataType::Double)
+        .as_f64()
+        .is_err());
+    assert!(literals
+        .with_data_type("1e", DataType::Double)
+        .as_f64()
+        .is_err());
+    assert!(literals
+        .with_data_type("1e5", DataType::Other(xsd::decimal().clone()))
+        .as_f64()
+        .is_err());
+    assert!(literals.boolean(true).as_f64().is_err());
+}
+
+#[test]
+fn typed_values_booleans() {
+    let literals = literal_factory();
+    assert!(literals.boolean(true).as_bool().unwrap());
+    assert!(!literals.boolean(false).as_bool().unwrap());
+    assert!(literals
+        .with_data_type("1", DataType::Boolean)
+        .as_bool()
+        .unwrap());
+    assert!(!literals
+        .with_data_type("0", DataType::Boolean)
+        .as_bool()
+        .unwrap());
+    assert!(literals
+        .with_data_type("TRUE", DataType::Boolean)
+        .as_bool()
+        .is_err());
+    assert!(literals.int(1).as_bool().is_err());
+}
+
+#[test]
+fn typed_values_durations() {
+    let literals = literal_factory();
+    assert_eq!(
+        literals
+            .duration(Duration::from_secs(63542))
+            .as_duration()
+            .unwrap(),
+        Duration::from_secs(63542)
+    );
+    assert_eq!(
+        literals
+            .with_data_type("P1DT2H3M4.5S", DataType::Duration)
+            .as_duration()
+            .unwrap(),
+        Duration::from_millis(93_784_500)
+    );
+    assert_eq!(
+        literals
+            .with_data_type("-PT90S", DataType::Duration)
+            .as_chrono_duration()
+            .unwrap(),
+        chrono::Duration::seconds(-90)
+    );
+
+    // years and months have no fixed length
+    assert!(literals
+        .with_data_type("P1M", DataType::Duration)
+        .as_duration()
+        .is_err());
+    // std durations cannot be negative
+    assert!(literals
+        .with_data_type("-PT1S", DataType::Duration)
+        .as_duration()
+        .is_err());
+    for invalid in &[
+        "P", "PT", "P1S", "PT1D", "P1DT", "1D", "P1H2D", "P1.5D", "-",
+    ] {
+        assert!(
+            literals
+                .with_data_type(invalid, DataType::Duration)
+                .as_duration()
+                .is_err(),
+            "{} should be invalid",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn typed_values_date_times() {
+    let literals = literal_factory();
+    let date_time = DataType::DateTime;
+    let value = literals
+        .with_data_type("2021-07-04T12:30:15.25-05:00", date_time.clone())
+        .as_date_time()
+        .unwrap();
+    assert_eq!(value.to_rfc3339(), "2021-07-04T12:30:15.250-05:00");
+    let value = literals
+        .with_data_type("2020-12-31T24:00:00", date_time.clone())
+        .as_date_time()
+        .unwrap();
+    assert_eq!(value.to_rfc3339(), "2021-01-01T00:00:00+00:00");
+
+    for invalid in &[
+        "2021-02-29T00:00:00",
+        "2021-13-01T00:00:00",
+        "2021-07-04",
+        "2021-07-04T25:00:00",
+        "2021-07-04T24:00:01",
+        "21-07-04T00:00:00",
+        "2021-07-04T00:00:00+15:00",
+        "2021-07-04T00:00:00.Z",
+    ] {
+        assert!(
+            literals
+                .with_data_type(invalid, date_time.clone())
+                .as_date_time()
+                .is_err(),
+            "{} should be invalid",
+            invalid
+        );
+    }
+    assert!(literals
+        .string("2021-07-04T00:00:00Z")
+        .as_date_time()
+        .is_err());
+}
+
+#[test]
+fn canonical_forms() {
+    let literals = literal_factory();
+    let decimal = DataType::Other(xsd::decimal().clone());
+    let date_time = DataType::Other(xsd::date_time().clone());
+    for (lexical, data_type, canonical) in &[
+        ("1", DataType::Boolean, "true"),
+        ("+007", DataType::Int, "7"),
+        ("-0", DataType::Long, "0"),
+        (
+            "000123456789012345678901234567890",
+            DataType::Other(xsd::integer().clone()),
+            "123456789012345678901234567890",
+        ),
+        ("+01.500", decimal.clone(), "1.5"),
+        ("2.0", decimal.clone(), "2"),
+        ("-.0", decimal.clone(), "0"),
+        (".25", decimal.clone(), "0.25"),
+        ("100", DataType::Double, "1.0E2"),
+        ("0.0", DataType::Double, "0.0E0"),
+        ("-0", DataType::Double, "-0.0E0"),
+        ("1.5e-10", DataType::Double, "1.5E-10"),
+        ("+INF", DataType::Float, "INF"),
+        ("0.1", DataType::Float, "1.0E-1"),
+        ("PT63542S", DataType::Duration, "PT17H39M2S"),
+        ("P13M", DataType::Duration, "P1Y1M"),
+        ("-P0D", DataType::Duration, "PT0S"),
+        ("PT36H0.50S", DataType::Duration, "P1DT12H0.5S"),
+        (
+            "2021-07-04T09:05:00.500Z",
+            date_time.clone(),
+            "2021-07-04T09:05:00.5Z",
+        ),
+        (
+            "2021-07-04T09:05:00+00:00",
+            date_time.clone(),
+            "2021-07-04T09:05:00Z",
+        ),
+        (
+            "2021-12-31T24:00:00-08:30",
+            date_time.clone(),
+            "2022-01-01T00:00:00-08:30",
+        ),
+    ] {
+        assert_eq!(
+            literals
+                .with_data_type(lexical, data_type.clone())
+                .canonicalize()
+                .unwrap(),
+            *canonical,
+            "canonical form of {}",
+            lexical
+        );
+    }
+    assert_eq!(
+        literals.literal("  untouched ").canonicalize().unwrap(),
+        "  untouched "
+    );
+    assert_eq!(
+        literals.string(" untouched").canonicalize().unwrap(),
+        " untouched"
+    );
+    assert!(literals
+        .with_data_type("maybe", DataType::Boolean)
+        .canonicalize()
+        .is_err());
+}
+
+#[test]
+fn compare_date_times() {
+    let literals = literal_factory();
+    let date_time = |value: &str| literals.with_data_type(value, DataType::DateTime);
+    let compare =
+        |lhs: &str, rhs: &str| date_time(lhs).compare_date_time(&*date_time(rhs)).unwrap();
+
+    assert_eq!(
+        compare("2021-07-04T12:00:00-05:00", "2021-07-04T17:00:00Z"),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        compare("2021-07-04T20:00:00+02:00", "2021-07-04T12:00:00-05:00"),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        compare("2021-12-31T24:00:00Z", "2022-01-01T00:00:00.5Z"),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        compare("2021-07-04T12:00:00", "2021-07-04T11:59:59"),
+        Some(Ordering::Greater)
+    );
+
+    // a value without a timezone is ordered against one with a timezone only if they are more
+    // than 14 hours apart.
+    assert_eq!(compare("2021-07-04T12:00:00", "2021-07-04T12:00:00Z"), None);
+    assert_eq!(compare("2021-07-04T12:00:00Z", "2021-07-05T01:59:59"), None);
+    assert_eq!(
+        compare("2021-07-04T12:00:00Z", "2021-07-05T02:00:01"),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        compare("2021-07-05T02:00:01", "2021-07-04T12:00:00Z"),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(
+        compare("2021-07-03T21:59:59", "2021-07-04T12:00:00Z"),
+        Some(Ordering::Less)
+    );
+
+    let date = |value: &str| literals.with_data_type(value, DataType::Date);
+    assert_eq!(
+        date("2021-07-04+10:00")
+            .compare_date_time(&*date("2021-07-03Z"))
+            .unwrap(),
+        Some(Ordering::Greater)
+    );
+    assert!(date("2021-07-04")
+        .compare_date_time(&*date_time("2021-07-04T00:00:00"))
+        .is_err());
+    assert!(date_time("2021-07-04T00:00:00")
+        .compare_date_time(&*literals.string("2021-07-04T00:00:00"))
+        .is_err());
+    assert!(date_time("2021-07-04T00:00:00Q")
+        .compare_date_time(&*date_time("2021-07-04T00:00:00"))
+        .is_err());
+}
+
+#[test]
+fn date_time_time_zones() {
+    let literals = literal_factory();
+    let date_time = |value: &str| literals.with_data_type(value, DataType::DateTime);
+    assert_eq!(
+        date_time("2021-07-04T12:00:00-05:30").time_zone().unwrap(),
+        Some(-330)
+    );
+    assert_eq!(date_time("2021-07-04T12:00:00").time_zone().unwrap(), None);
+    assert_eq!(
+        literals
+            .with_data_type("2021-07-04Z", DataType::Date)
+            .time_zone()
+            .unwrap(),
+        Some(0)
+    );
+    assert!(literals.int(1).time_zone().is_err());
+
+    for (lexical, canonical) in &[
+        ("2021-07-04T12:00:00-05:30", "2021-07-04T17:30:00Z"),
+        ("2021-12-31T22:30:00.25-01:30", "2022-01-01T00:00:00.25Z"),
+        ("2000-03-01T01:00:00+02:00", "2000-02-29T23:00:00Z"),
+        ("-0001-01-01T00:00:00+14:00", "-0002-12-31T10:00:00Z"),
+        ("2021-12-31T24:00:00+01:00", "2021-12-31T23:00:00Z"),
+        ("2021-07-04T12:00:00", "2021-07-04T12:00:00"),
+    ] {
+        assert_eq!(
+            date_time(lexical).canonicalize_utc().unwrap(),
+            *canonical,
+            "UTC canonical form of {}",
+            lexical
+        );
+    }
+    assert_eq!(
+        literals
+            .with_data_type("2021-07-04-05:00", DataType::Date)
+            .canonicalize_utc()
+            .unwrap(),
+        "2021-07-04-05:00"
+    );
+}
+
+#[test]
+fn typed_values_dates() {
+    let literals = literal_factory();
+    let date = NaiveDate::from_ymd_opt(2021, 7, 4).unwrap();
+    let value = literals.date(date);
+    assert_eq!(value.data_type(), Some(&DataType::Date));
+    assert_eq!(value.lexical_form(), "2021-07-04");
+    assert_eq!(value.as_date().unwrap(), date);
+
+    let value = literals.with_data_type("-0044-03-15-05:00", DataType::Other(xsd::date().clone()));
+    assert_eq!(
+        value.as_date().unwrap(),
+        NaiveDate::from_ymd_opt(-44, 3, 15).unwrap()
+    );
+    assert_eq!(value.canonicalize().unwrap(), "-0044-03-15-05:00");
+
+    for invalid in &[
+        "2021-7-4",
+        "2021-02-29",
+        "2021-07-04T00:00:00",
+        "2021-07-04+5:00",
+        "",
+    ] {
+        assert!(
+            literals
+                .with_data_type(invalid, DataType::Date)
+                .as_date()
+                .is_err(),
+            "{} should be invalid",
+            invalid
+        );
+    }
+    assert!(literals.date_time(Utc::now()).as_date().is_err());
+}
+
+#[test]
+fn typed_values_utc_date_times() {
+    let literals = literal_factory();
+    let date_time =
+        Utc.with_ymd_and_hms(2021, 7, 4, 12, 30, 15).unwrap() + chrono::Duration::milliseconds(250);
+    let value = literals.date_time(date_time);
+    assert_eq!(value.data_type(), Some(&DataType::DateTime));
+    assert_eq!(value.lexical_form(), "2021-07-04T12:30:15.25Z");
+    assert_eq!(value.as_date_time().unwrap().with_timezone(&Utc), date_time);
+
+    // data types given by IRI, as from a parser, are equivalent.
+    let parsed = literals.with_data_type(
+        "2021-07-04T12:30:15.25Z",
+        DataType::from(xsd::date_time().clone()),
+    );
+    assert_eq!(&*parsed, &*value);
+}
+
+#[test]
+fn checked_decimals_and_integers() {
+    let literals = literal_factory();
+    let value = literals.decimal_str("-0012.50").unwrap();
+    assert_eq!(value.data_type(), Some(&DataType::Decimal));
+    assert_eq!(value.canonicalize().unwrap(), "-12.5");
+
+    let value = literals
+        .integer_str("123456789012345678901234567890123456789012")
+        .unwrap();
+    assert_eq!(value.data_type(), Some(&DataType::Integer));
+    assert!(value.as_i64().is_err());
+
+    assert!(literals.decimal_str("1e5").is_err());
+    assert!(literals.integer_str("1.0").is_err());
+    assert!(literals
+        .with_data_type_checked("yes", DataType::Boolean)
+        .is_err());
+    assert!(literals
+        .with_data_type_checked("opaque", DataType::Other(xsd::any_uri().clone()))
+        .is_ok());
+    assert_eq!(DataType::from(xsd::decimal().clone()), DataType::Decimal);
+    assert_eq!(DataType::Integer.as_iri(), xsd::integer());
+}
+
+#[cfg(feature = "decimal_types")]
+#[test]
+fn typed_values_rust_decimals() {
+    use rust_decimal::Decimal;
+    use std::str::FromStr;
+
+    let literals = literal_factory();
+    let value = literals.decimal(Decimal::from_str("-12.500").unwrap());
+    assert_eq!(value.lexical_form(), "-12.5");
+    assert_eq!(
+        value.as_decimal().unwrap(),
+        Decimal::from_str("-12.5").unwrap()
+    );
+    assert_eq!(
+        literals.integer_str("42").unwrap().as_decimal().unwrap(),
+        Decimal::from(42)
+    );
+    assert!(literals
+        .decimal_str("0.123456789012345678901234567890123")
+        .unwrap()
+        .as_decimal()
+        .is_err());
+    assert!(literals.double(1.5).as_decimal().is_err());
+}
+
+#[cfg(feature = "bigint_types")]
+#[test]
+fn typed_values_big_integers() {
+    use num_bigint::BigInt;
+    use std::str::FromStr;
+
+    let literals = literal_factory();
+    let big = BigInt::from_str("-123456789012345678901234567890").unwrap();
+    let value = literals.integer(big.clone());
+    assert_eq!(value.lexical_form(), "-123456789012345678901234567890");
+    assert_eq!(value.as_big_integer().unwrap(), big);
+    assert_eq!(
+        literals
+            .with_data_type("+007", DataType::Other(xsd::positive_integer().clone()))
+            .as_big_integer()
+            .unwrap(),
+        BigInt::from(7)
+    );
+    assert!(literals
+        .decimal_str("1.5")
+        .unwrap()
+        .as_big_integer()
+        .is_err());
+}
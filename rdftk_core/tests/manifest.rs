@@ -0,0 +1,251 @@
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::data_set::manifest::{Manifest, ManifestGenerator, Signer, Verifier};
+use rdftk_core::model::data_set::{DataSetRef, GraphName, GraphNameRef};
+use rdftk_core::model::literal::DataType;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_names::foaf;
+use sha2::{Digest, Sha256};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+struct KeyedHash(&'static str);
+
+impl KeyedHash {
+    fn digest(&self, message: &[u8]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.0.as_bytes());
+        hasher.update(message);
+        hasher.finalize().to_vec()
+    }
+}
+
+impl Signer for KeyedHash {
+    fn algorithm(&self) -> String {
+        "keyed-sha256".to_string()
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        Ok(self.digest(message))
+    }
+}
+
+impl Verifier for KeyedHash {
+    fn verify(&self, algorithm: &str, message: &[u8], signature: &[u8]) -> Result<bool> {
+        Ok(algorithm == "keyed-sha256" && self.digest(message) == signature)
+    }
+}
+
+fn knows(person: &str, other: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(person)),
+            foaf::knows().clone(),
+            factory.named_object(example_iri(other)),
+        )
+        .unwrap()
+}
+
+fn anonymous_friend(person: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(person)),
+            foaf::knows().clone(),
+            factory.blank_object(),
+        )
+        .unwrap()
+}
+
+fn make_data_set() -> (DataSetRef, GraphNameRef, GraphNameRef) {
+    let people = GraphName::named_ref(example_iri("people"));
+    let friends: GraphNameRef = GraphName::blank_named("friends").into();
+    let data_set = data_set_factory().data_set(Some(
+        graph_factory().graph_from(&[knows("nobody", "nobody")], None),
+    ));
+    {
+        let mut data_set = data_set.borrow_mut();
+        data_set.insert(
+            people.clone(),
+            graph_factory().graph_from(&[knows("alice", "bob"), knows("bob", "carol")], None),
+        );
+        data_set.insert(
+            friends.clone(),
+            graph_factory().graph_from(&[anonymous_friend("alice")], None),
+        );
+    }
+    (data_set, people, friends)
+}
+
+#[test]
+fn generate_manifest() {
+    let (data_set, people, friends) = make_data_set();
+    let timestamp = UNIX_EPOCH + Duration::from_millis(1_600_000_000_250);
+    let manifest = ManifestGenerator::default()
+        .with_source(&people, example_iri("people.ttl"))
+        .with_default_source(example_iri("dump.trig"))
+        .with_timestamp(timestamp)
+        .generate(&*data_set.borrow())
+        .unwrap();
+
+    // the default graph is not included.
+    assert_eq!(manifest.entries().len(), 2);
+    let entry = manifest.entry(&people).unwrap();
+    assert_eq!(entry.triples(), 2);
+    assert_eq!(entry.hash().len(), 64);
+    assert_eq!(entry.source(), Some(&example_iri("people.ttl")));
+    assert_eq!(entry.created(), "2020-09-13T12:26:40.25Z");
+    let entry = manifest.entry(&friends).unwrap();
+    assert_eq!(entry.triples(), 1);
+    assert_eq!(entry.source(), Some(&example_iri("dump.trig")));
+    assert!(manifest.signature().is_none());
+
+    // the hash does not depend on blank node labels.
+    let other = data_set_factory().data_set(None);
+    other.borrow_mut().insert(
+        friends.clone(),
+        graph_factory().graph_from(&[anonymous_friend("alice")], None),
+    );
+    let other = ManifestGenerator::default()
+        .generate(&*other.borrow())
+        .unwrap();
+    assert_eq!(other.entry(&friends).unwrap().hash(), entry.hash());
+}
+
+#[test]
+fn manifest_round_trip() {
+    let (data_set, people, _) = make_data_set();
+    let mut manifest = ManifestGenerator::default()
+        .with_source(&people, example_iri("people.ttl"))
+        .generate(&*data_set.borrow())
+        .unwrap();
+    manifest.sign(&KeyedHash("secret")).unwrap();
+
+    let graph = manifest.to_graph(&graph_factory()).unwrap();
+    assert_eq!(
+        graph
+            .borrow()
+            .prefix_mappings()
+            .borrow()
+            .get_namespace("manifest")
+            .unwrap()
+            .to_string(),
+        "http://rust-rdftk.dev/manifest/"
+    );
+    let read = Manifest::from_graph(&*graph.borrow()).unwrap();
+    assert_eq!(read, manifest);
+    assert!(read.verify_signature(&KeyedHash("secret")).unwrap());
+    assert!(read.verify(&*data_set.borrow()).unwrap().is_empty());
+}
+
+#[test]
+fn verify_signature() {
+    let (data_set, people, _) = make_data_set();
+    let generator = ManifestGenerator::default().with_timestamp(SystemTime::now());
+    let mut manifest = generator.generate(&*data_set.borrow()).unwrap();
+    assert!(!manifest.verify_signature(&KeyedHash("secret")).unwrap());
+
+    manifest.sign(&KeyedHash("secret")).unwrap();
+    let signature = manifest.signature().unwrap();
+    assert_eq!(signature.algorithm(), "keyed-sha256");
+    assert_eq!(signature.value().len(), 32);
+    assert!(manifest.verify_signature(&KeyedHash("secret")).unwrap());
+    assert!(!manifest.verify_signature(&KeyedHash("other")).unwrap());
+
+    // a manifest for changed graphs does not match the signature.
+    data_set
+        .borrow()
+        .graph_named(&people)
+        .unwrap()
+        .borrow_mut()
+        .insert(knows("carol", "dave"));
+    let mut changed = generator.generate(&*data_set.borrow()).unwrap();
+    assert!(!changed.verify_signature(&KeyedHash("secret")).unwrap());
+    changed.sign(&KeyedHash("secret")).unwrap();
+    assert_ne!(changed.signature(), manifest.signature());
+}
+
+#[test]
+fn verify_graphs() {
+    let (data_set, people, friends) = make_data_set();
+    let manifest = ManifestGenerator::default()
+        .generate(&*data_set.borrow())
+        .unwrap();
+    assert!(manifest.verify(&*data_set.borrow()).unwrap().is_empty());
+
+    // a changed graph, a missing graph, and an unlisted graph.
+    let extra = GraphName::named_ref(example_iri("extra"));
+    let changed = data_set_factory().data_set(None);
+    {
+        let mut changed = changed.borrow_mut();
+        changed.insert(
+            people.clone(),
+            graph_factory().graph_from(&[knows("alice", "bob"), knows("bob", "dave")], None),
+        );
+        changed.insert(
+            extra.clone(),
+            graph_factory().graph_from(&[knows("alice", "bob")], None),
+        );
+    }
+    assert_eq!(
+        manifest.verify(&*changed.borrow()).unwrap(),
+        vec![people, friends, extra]
+    );
+}
+
+#[test]
+fn reject_invalid_manifests() {
+    let graph = graph_factory().graph();
+    assert!(matches!(
+        Manifest::from_graph(&*graph.borrow()).err().unwrap().kind(),
+        ErrorKind::InvalidManifest(_)
+    ));
+
+    let (data_set, _, _) = make_data_set();
+    let manifest = ManifestGenerator::default()
+        .generate(&*data_set.borrow())
+        .unwrap();
+    let graph = manifest.to_graph(&graph_factory()).unwrap();
+    let counts: Vec<StatementRef> = graph
+        .borrow()
+        .statements()
+        .filter(|st| st.predicate() == rdftk_names::void::triples())
+        .cloned()
+        .collect();
+    {
+        let mut graph = graph.borrow_mut();
+        let factory = graph.statement_factory();
+        for count in counts {
+            graph.remove(&count);
+            graph.insert(
+                factory
+                    .statement(
+                        count.subject().clone(),
+                        count.predicate().clone(),
+                        factory.literal_object(
+                            literal_factory().with_data_type("many", DataType::UnsignedLong),
+                        ),
+                    )
+                    .unwrap(),
+            );
+        }
+    }
+    assert!(Manifest::from_graph(&*graph.borrow()).is_err());
+}
+
+#[test]
+fn created_before_epoch() {
+    let (data_set, people, _) = make_data_set();
+    let manifest = ManifestGenerator::default()
+        .with_timestamp(UNIX_EPOCH - Duration::from_millis(500))
+        .generate(&*data_set.borrow())
+        .unwrap();
+    assert_eq!(
+        manifest.entry(&people).unwrap().created(),
+        "1969-12-31T23:59:59.5Z"
+    );
+}
@@ -0,0 +1,145 @@
+use rdftk_core::model::graph::merge::{ConflictKind, Merger};
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::IRIRef;
+use rdftk_names::{foaf, owl, rdf};
+
+fn statement(subject: &str, predicate: IRIRef, object: IRIRef) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(subject)),
+            predicate,
+            factory.named_object(object),
+        )
+        .unwrap()
+}
+
+fn age(person: &str, age: i64) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(person)),
+            foaf::age().clone(),
+            factory.literal_object(literal_factory().long(age)),
+        )
+        .unwrap()
+}
+
+fn ontology() -> Vec<StatementRef> {
+    vec![
+        statement(
+            "age",
+            rdf::a_type().clone(),
+            owl::functional_property().clone(),
+        ),
+        statement(
+            "Person",
+            owl::disjoint_with().clone(),
+            example_iri("Organization"),
+        ),
+        statement(
+            "employs",
+            owl::property_disjoint_with().clone(),
+            example_iri("sues"),
+        ),
+    ]
+}
+
+#[test]
+fn merge_without_conflicts() {
+    let hr = graph_factory().graph_from(&[age("alice", 33), age("bob", 40)], None);
+    let crm = graph_factory().graph_from(&[age("alice", 33), age("carol", 27)], None);
+
+    let mut merger = Merger::default();
+    let _ = merger.functional(foaf::age().clone());
+    let merged = merger
+        .merge(&[("hr", &*hr.borrow()), ("crm", &*crm.borrow())])
+        .unwrap();
+
+    assert!(merged.is_consistent());
+    assert_eq!(merged.graph().borrow().len(), 3);
+    assert_eq!(
+        merged.sources_of(&age("alice", 33)).unwrap(),
+        &vec!["hr".to_string(), "crm".to_string()]
+    );
+    assert_eq!(
+        merged.sources_of(&age("carol", 27)).unwrap(),
+        &vec!["crm".to_string()]
+    );
+}
+
+#[test]
+fn merge_reports_conflicts_from_ontology() {
+    let ontology = graph_factory().graph_from(&ontology(), None);
+    let merger = Merger::from_ontology(&*ontology.borrow());
+
+    let hr = graph_factory().graph_from(
+        &[
+            statement("acme", rdf::a_type().clone(), example_iri("Organization")),
+            statement("acme", example_iri("employs"), example_iri("alice")),
+            statement("alice", example_iri("age"), example_iri("thirty")),
+        ],
+        None,
+    );
+    let crm = graph_factory().graph_from(
+        &[
+            statement("acme", rdf::a_type().clone(), example_iri("Person")),
+            statement("acme", example_iri("sues"), example_iri("alice")),
+            statement("alice", example_iri("age"), example_iri("forty")),
+        ],
+        None,
+    );
+
+    let merged = merger
+        .merge(&[("hr", &*hr.borrow()), ("crm", &*crm.borrow())])
+        .unwrap();
+    assert_eq!(merged.graph().borrow().len(), 6);
+
+    let kinds: Vec<&ConflictKind> = merged.conflicts().iter().map(|c| c.kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &ConflictKind::Functional(example_iri("age")),
+            &ConflictKind::DisjointClasses(example_iri("Person"), example_iri("Organization")),
+            &ConflictKind::DisjointProperties(example_iri("employs"), example_iri("sues")),
+        ]
+    );
+    for conflict in merged.conflicts() {
+        assert!(conflict.is_between_sources());
+        assert_eq!(conflict.statements().count(), 2);
+    }
+
+    let report = merged.to_string();
+    assert!(report.contains("conflict on functional predicate <http://example.org/age>:"));
+    assert!(report.contains("# from hr"));
+    assert!(report.contains("# from crm"));
+}
+
+#[test]
+fn merge_keeps_blank_nodes_apart() {
+    let factory = statement_factory();
+    let blank = |value: &str| {
+        factory
+            .statement(
+                factory.blank_subject_named("b1").unwrap(),
+                foaf::name().clone(),
+                factory.literal_object(literal_factory().literal(value)),
+            )
+            .unwrap()
+    };
+    let hr = graph_factory().graph_from(&[blank("Alice")], None);
+    let crm = graph_factory().graph_from(&[blank("Bob")], None);
+
+    let mut merger = Merger::default();
+    let _ = merger.functional(foaf::name().clone());
+    let merged = merger
+        .merge(&[("hr", &*hr.borrow()), ("crm", &*crm.borrow())])
+        .unwrap();
+
+    assert_eq!(merged.graph().borrow().len(), 2);
+    assert!(merged.is_consistent());
+}
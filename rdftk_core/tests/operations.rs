@@ -0,0 +1,112 @@
+use rdftk_core::model::graph::operations::{intersection, union};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_names::foaf;
+
+fn named(subject: &str, object: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(subject)),
+            foaf::knows().clone(),
+            factory.named_object(example_iri(object)),
+        )
+        .unwrap()
+}
+
+fn blank(subject: &str, name: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.blank_subject_named(subject).unwrap(),
+            foaf::name().clone(),
+            factory.literal_object(literal_factory().literal(name)),
+        )
+        .unwrap()
+}
+
+fn make_graph(statements: &[StatementRef]) -> GraphRef {
+    graph_factory().graph_from(statements, None)
+}
+
+#[test]
+fn union_of_ground_graphs() {
+    let lhs = make_graph(&[named("alice", "bob"), named("bob", "carol")]);
+    let rhs = make_graph(&[named("bob", "carol"), named("carol", "dave")]);
+    let result = union(&*lhs.borrow(), &*rhs.borrow()).unwrap();
+    let result = result.borrow();
+    assert_eq!(result.len(), 3);
+    assert!(result.contains(&named("alice", "bob")));
+    assert!(result.contains(&named("carol", "dave")));
+}
+
+#[test]
+fn union_renames_colliding_blank_nodes() {
+    let lhs = make_graph(&[blank("p", "Alice"), blank("q", "Bob")]);
+    let rhs = make_graph(&[blank("p", "Carol"), blank("r", "Dave")]);
+    let result = lhs.borrow().union(&*rhs.borrow()).unwrap();
+    let result = result.borrow();
+    assert_eq!(result.len(), 4);
+    assert_eq!(result.subjects().len(), 4);
+
+    // statements from lhs are unchanged, as are those in rhs without a collision.
+    assert!(result.contains(&blank("p", "Alice")));
+    assert!(result.contains(&blank("q", "Bob")));
+    assert!(result.contains(&blank("r", "Dave")));
+    assert!(!result.contains(&blank("p", "Carol")));
+}
+
+#[test]
+fn union_renames_consistently() {
+    let factory = statement_factory();
+    let lhs = make_graph(&[blank("p", "Alice")]);
+    let rhs = make_graph(&[
+        blank("p", "Carol"),
+        factory
+            .statement(
+                factory.named_subject(example_iri("dave")),
+                foaf::knows().clone(),
+                factory.blank_object_named("p").unwrap(),
+            )
+            .unwrap(),
+    ]);
+    let result = union(&*lhs.borrow(), &*rhs.borrow()).unwrap();
+    let result = result.borrow();
+    let carol = result
+        .statements()
+        .find(|st| st.object().as_literal().map(|l| l.lexical_form().as_str()) == Some("Carol"))
+        .unwrap()
+        .subject()
+        .as_blank()
+        .unwrap()
+        .clone();
+    assert_ne!(carol, "p");
+    assert!(result
+        .statements()
+        .any(|st| st.object().as_blank() == Some(&carol)));
+}
+
+#[test]
+fn intersection_and_difference() {
+    let lhs = make_graph(&[
+        named("alice", "bob"),
+        named("bob", "carol"),
+        blank("p", "Eve"),
+    ]);
+    let rhs = make_graph(&[named("bob", "carol"), blank("p", "Eve"), named("x", "y")]);
+
+    let both = intersection(&*lhs.borrow(), &*rhs.borrow());
+    let both = both.borrow();
+    assert_eq!(both.len(), 2);
+    assert!(both.contains(&named("bob", "carol")));
+    assert!(both.contains(&blank("p", "Eve")));
+
+    let only = lhs.borrow().difference(&*rhs.borrow());
+    let only = only.borrow();
+    assert_eq!(only.len(), 1);
+    assert!(only.contains(&named("alice", "bob")));
+}
@@ -0,0 +1,122 @@
+use rdftk_core::model::graph::canonical::canonical_n_triples_ordered;
+use rdftk_core::model::graph::change_set::ChangeSet;
+use rdftk_core::model::graph::order::{sorted_statements, LexicalOrder, PredicateOrder};
+use rdftk_core::model::statement::StatementRef;
+use rdftk_fixtures::terms::{graph_from_triples, iri, statement};
+
+fn objects(statements: &[StatementRef]) -> Vec<String> {
+    statements
+        .iter()
+        .map(|st| st.object().to_string())
+        .collect()
+}
+
+#[test]
+fn lexical_order() {
+    let graph = graph_from_triples(&[
+        ("http://example.com/b", "http://example.com/p", "\"3"),
+        ("http://example.com/a", "http://example.com/q", "\"2"),
+        ("http://example.com/a", "http://example.com/p", "\"1"),
+    ]);
+    let statements = sorted_statements(&*graph.borrow(), &LexicalOrder);
+    assert_eq!(objects(&statements), vec!["\"1\"", "\"2\"", "\"3\""]);
+}
+
+#[test]
+fn lexical_order_uses_canonical_labels() {
+    let triples = |first: &'static str, second: &'static str| {
+        vec![
+            (first, "http://example.com/p", "\"1"),
+            (second, "http://example.com/q", "\"2"),
+            (second, "http://example.com/q", "\"3"),
+        ]
+    };
+    let lhs = graph_from_triples(&triples("_:x", "_:y"));
+    let rhs = graph_from_triples(&triples("_:y", "_:x"));
+    assert_eq!(
+        objects(&sorted_statements(&*lhs.borrow(), &LexicalOrder)),
+        objects(&sorted_statements(&*rhs.borrow(), &LexicalOrder)),
+    );
+}
+
+#[test]
+fn predicate_order() {
+    let graph = graph_from_triples(&[
+        ("http://example.com/a", "http://example.com/z", "\"4"),
+        ("http://example.com/a", "http://example.com/label", "\"2"),
+        ("http://example.com/b", "http://example.com/type", "\"5"),
+        ("http://example.com/a", "http://example.com/b", "\"3"),
+        ("http://example.com/a", "http://example.com/type", "\"1"),
+    ]);
+    let order = PredicateOrder::new(&[
+        iri("http://example.com/type"),
+        iri("http://example.com/label"),
+    ]);
+    let statements = sorted_statements(&*graph.borrow(), &order);
+    assert_eq!(
+        objects(&statements),
+        vec!["\"1\"", "\"2\"", "\"3\"", "\"4\"", "\"5\""]
+    );
+}
+
+#[test]
+fn sort_change_set() {
+    let mut changes = ChangeSet::default();
+    let _ = changes
+        .remove(statement(
+            "http://example.com/b",
+            "http://example.com/p",
+            "\"1",
+        ))
+        .remove(statement(
+            "http://example.com/a",
+            "http://example.com/p",
+            "\"1",
+        ))
+        .add(statement(
+            "http://example.com/d",
+            "http://example.com/p",
+            "\"1",
+        ))
+        .add(statement(
+            "http://example.com/c",
+            "http://example.com/p",
+            "\"1",
+        ))
+        .remove(statement(
+            "http://example.com/b",
+            "http://example.com/p",
+            "\"1",
+        ))
+        .sort(&LexicalOrder);
+
+    assert_eq!(
+        changes.to_rdf_patch(),
+        r#"TX .
+D <http://example.com/a> <http://example.com/p> "1" .
+D <http://example.com/b> <http://example.com/p> "1" .
+A <http://example.com/c> <http://example.com/p> "1" .
+A <http://example.com/d> <http://example.com/p> "1" .
+D <http://example.com/b> <http://example.com/p> "1" .
+TC .
+"#
+    );
+}
+
+#[test]
+fn canonical_n_triples_in_order() {
+    let graph = graph_from_triples(&[
+        ("_:e0", "http://example.com/type", "http://example.com/T"),
+        ("_:e0", "http://example.com/a", "\"x"),
+        ("http://example.com/p", "http://example.com/q", "_:e0"),
+    ]);
+    let order = PredicateOrder::new(&[iri("http://example.com/type")]);
+    assert_eq!(
+        canonical_n_triples_ordered(&*graph.borrow(), &order).unwrap(),
+        vec![
+            "<http://example.com/p> <http://example.com/q> _:c14n0 .",
+            "_:c14n0 <http://example.com/type> <http://example.com/T> .",
+            "_:c14n0 <http://example.com/a> \"x\" .",
+        ]
+    );
+}
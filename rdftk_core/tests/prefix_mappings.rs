@@ -1,7 +1,8 @@
-use rdftk_core::model::graph::mapping::DEFAULT_PREFIX;
+use rdftk_core::model::graph::mapping::{split_iri, DEFAULT_PREFIX};
 use rdftk_core::model::graph::{GraphFactoryRef, PrefixMappingRef};
 use rdftk_core::model::qname::QName;
 use rdftk_core::simple::graph_factory;
+use rdftk_fixtures::terms::iri;
 use rdftk_iri::{IRIRef, IRI};
 use std::str::FromStr;
 
@@ -91,3 +92,109 @@ fn test_mapping_compress() {
         None
     );
 }
+
+#[test]
+fn test_mapping_expand_qname() {
+    let mappings = make_mappings(graph_factory());
+    let mappings = mappings.borrow();
+
+    let bag = Some(iri("http://www.w3.org/1999/02/22-rdf-syntax-ns#Bag"));
+    let knows = Some(iri("http://xmlns.com/foaf/0.1/knows"));
+    assert_eq!(mappings.expand_qname("rdf:Bag"), bag);
+    assert_eq!(mappings.expand_qname("[rdf:Bag]"), bag);
+    assert_eq!(mappings.expand_qname("knows"), knows);
+    assert_eq!(mappings.expand_qname(":knows"), knows);
+
+    assert_eq!(mappings.expand_qname("rdfx:Bag"), None);
+    assert_eq!(mappings.expand_qname("rdf:Bag:More"), None);
+    assert_eq!(mappings.expand_qname("rdf:1Bag"), None);
+    assert_eq!(mappings.expand_qname(""), None);
+}
+
+#[test]
+fn test_mapping_compress_registered_namespace() {
+    let mappings = make_mappings(graph_factory());
+    let mut mappings = mappings.borrow_mut();
+    mappings.insert("ex", iri("http://example.org/"));
+    mappings.insert("item", iri("http://example.org/item/"));
+
+    let compress = |s: &str| mappings.compress(&iri(s)).map(|qname| qname.to_string());
+    assert_eq!(
+        compress("http://example.org/thing"),
+        Some("ex:thing".to_string())
+    );
+    assert_eq!(
+        compress("http://example.org/item/a42"),
+        Some("item:a42".to_string())
+    );
+    assert_eq!(compress("http://example.org/item/42abc"), None);
+    assert_eq!(compress("http://example.org/other/thing"), None);
+
+    let split = |s: &str| {
+        let (namespace, name) = mappings.split(&iri(s));
+        (namespace.to_string(), name)
+    };
+    assert_eq!(
+        split("http://example.org/item/a42"),
+        (
+            "http://example.org/item/".to_string(),
+            Some("a42".to_string())
+        )
+    );
+    assert_eq!(
+        split("http://example.org/other/thing"),
+        (
+            "http://example.org/other/".to_string(),
+            Some("thing".to_string())
+        )
+    );
+}
+
+#[test]
+fn test_split_iri() {
+    let split = |s: &str| {
+        let (namespace, name) = split_iri(&iri(s));
+        (namespace.to_string(), name)
+    };
+    assert_eq!(
+        split("http://www.w3.org/1999/02/22-rdf-syntax-ns#Bag"),
+        (
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#".to_string(),
+            Some("Bag".to_string())
+        )
+    );
+    assert_eq!(
+        split("http://xmlns.com/foaf/0.1/knows"),
+        (
+            "http://xmlns.com/foaf/0.1/".to_string(),
+            Some("knows".to_string())
+        )
+    );
+    assert_eq!(
+        split("http://example.org/terms/has-part.v2"),
+        (
+            "http://example.org/terms/".to_string(),
+            Some("has-part.v2".to_string())
+        )
+    );
+    assert_eq!(
+        split("http://example.org/item/42abc"),
+        ("http://example.org/item/42abc".to_string(), None)
+    );
+    assert_eq!(
+        split("http://example.org/a%20b"),
+        ("http://example.org/a%20b".to_string(), None)
+    );
+    assert_eq!(
+        split("http://example.org"),
+        ("http://example.org".to_string(), None)
+    );
+    assert_eq!(
+        split("urn:isbn:0451450523"),
+        ("urn:isbn:0451450523".to_string(), None)
+    );
+    assert_eq!(
+        split("http://example.org/"),
+        ("http://example.org/".to_string(), None)
+    );
+}
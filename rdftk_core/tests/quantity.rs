@@ -0,0 +1,193 @@
+use rdftk_core::error::ErrorKind;
+use rdftk_core::model::graph::quantity::{Quantity, Unit, UnitRegistry};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::literal::DataType;
+use rdftk_core::model::statement::{StatementRef, SubjectNodeRef};
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::IRIRef;
+use rdftk_names::qudt::{quantity_kind, schema as qudt, unit};
+use rdftk_names::rdf;
+
+fn named(name: &str) -> SubjectNodeRef {
+    statement_factory().named_subject(example_iri(name))
+}
+
+fn number(subject: &str, predicate: &IRIRef, value: &str) -> StatementRef {
+    statement_factory()
+        .statement(
+            named(subject),
+            predicate.clone(),
+            statement_factory()
+                .literal_object(literal_factory().with_data_type(value, DataType::Decimal)),
+        )
+        .unwrap()
+}
+
+fn assert_close(lhs: f64, rhs: f64) {
+    assert!((lhs - rhs).abs() < 1e-9, "{} != {}", lhs, rhs);
+}
+
+#[test]
+fn insert_and_read_quantities() {
+    let graph: GraphRef = graph_factory().graph();
+    let node = Quantity::new(101.3, unit::kilo_pa().clone())
+        .insert(
+            &mut *graph.borrow_mut(),
+            named("tank"),
+            example_iri("pressure"),
+        )
+        .unwrap();
+
+    let graph = graph.borrow();
+    assert_eq!(graph.len(), 4);
+    assert!(node.is_blank());
+    assert!(graph.contains_all(
+        &node,
+        rdf::a_type(),
+        &statement_factory().named_object(qudt::quantity_value().clone())
+    ));
+
+    let values = Quantity::values_for(&*graph, &named("tank"), &example_iri("pressure"));
+    assert_eq!(values, vec![Quantity::new(101.3, unit::kilo_pa().clone())]);
+    assert_eq!(
+        values[0].to_string(),
+        "101.3 <http://qudt.org/vocab/unit/KiloPA>"
+    );
+    assert!(Quantity::values_for(&*graph, &named("tank"), &example_iri("volume")).is_empty());
+}
+
+#[test]
+fn read_alternate_properties() {
+    let factory = statement_factory();
+    let graph = graph_factory().graph_from(
+        &[
+            number("reading", qudt::value(), "21.5"),
+            factory
+                .statement(
+                    named("reading"),
+                    qudt::has_unit().clone(),
+                    factory.named_object(unit::deg_c().clone()),
+                )
+                .unwrap(),
+            number("no_unit", qudt::numeric_value(), "1"),
+        ],
+        None,
+    );
+    let graph = graph.borrow();
+    let reading = Quantity::from_node(&*graph, &named("reading")).unwrap();
+    assert_close(reading.value(), 21.5);
+    assert_eq!(reading.unit(), unit::deg_c());
+    assert!(Quantity::from_node(&*graph, &named("no_unit")).is_none());
+}
+
+#[test]
+fn convert_compatible_units() {
+    let registry = UnitRegistry::default();
+    assert!(!registry.is_empty());
+
+    let converted = registry
+        .convert(&Quantity::new(1.0, unit::mi().clone()), unit::kilo_m())
+        .unwrap();
+    assert_close(converted.value(), 1.609344);
+    assert_eq!(converted.unit(), unit::kilo_m());
+
+    // conversions with an offset.
+    let converted = registry
+        .convert(&Quantity::new(100.0, unit::deg_c().clone()), unit::deg_f())
+        .unwrap();
+    assert_close(converted.value(), 212.0);
+    let converted = registry
+        .convert(&Quantity::new(0.0, unit::deg_c().clone()), unit::k())
+        .unwrap();
+    assert_close(converted.value(), 273.15);
+
+    let converted = registry
+        .convert(
+            &Quantity::new(90.0, unit::kilo_m_per_hr().clone()),
+            unit::m_per_sec(),
+        )
+        .unwrap();
+    assert_close(converted.value(), 25.0);
+}
+
+#[test]
+fn reject_incompatible_units() {
+    let registry = UnitRegistry::default();
+    assert!(registry.is_compatible(unit::bar(), unit::psi()));
+    assert!(!registry.is_compatible(unit::m(), unit::kilo_gm()));
+    assert!(!registry.is_compatible(unit::m(), &example_iri("furlong")));
+    // the same unit is always compatible, even when unknown.
+    assert!(registry.is_compatible(&example_iri("furlong"), &example_iri("furlong")));
+
+    let result = registry.convert(&Quantity::new(1.0, unit::m().clone()), unit::sec());
+    assert!(matches!(
+        result.unwrap_err().kind(),
+        ErrorKind::IncompatibleUnits(_, _)
+    ));
+}
+
+#[test]
+fn units_from_graph() {
+    let factory = statement_factory();
+    let graph = graph_factory().graph_from(
+        &[
+            number("furlong", qudt::conversion_multiplier(), "201.168"),
+            factory
+                .statement(
+                    named("furlong"),
+                    qudt::has_quantity_kind().clone(),
+                    factory.named_object(quantity_kind::length().clone()),
+                )
+                .unwrap(),
+            number(
+                "rankine",
+                qudt::conversion_multiplier(),
+                "0.5555555555555556",
+            ),
+            factory
+                .statement(
+                    named("rankine"),
+                    qudt::has_quantity_kind().clone(),
+                    factory.named_object(quantity_kind::thermodynamic_temperature().clone()),
+                )
+                .unwrap(),
+            number("shifted", qudt::conversion_multiplier(), "1"),
+            number("shifted", qudt::conversion_offset(), "10"),
+        ],
+        None,
+    );
+
+    let mut registry = UnitRegistry::default();
+    let known = registry.len();
+    assert_eq!(registry.add_graph(&*graph.borrow()), 3);
+    assert_eq!(registry.len(), known + 3);
+
+    let furlong = registry.get(&example_iri("furlong")).unwrap();
+    assert_eq!(furlong.quantity_kind(), Some(quantity_kind::length()));
+    assert_close(furlong.multiplier(), 201.168);
+    assert_close(furlong.offset(), 0.0);
+    assert_close(
+        registry.get(&example_iri("shifted")).unwrap().offset(),
+        10.0,
+    );
+
+    let converted = registry
+        .convert(&Quantity::new(5.0, example_iri("furlong")), unit::kilo_m())
+        .unwrap();
+    assert_close(converted.value(), 1.00584);
+
+    // a unit without a quantity kind cannot be converted.
+    assert!(!registry.is_compatible(&example_iri("shifted"), unit::k()));
+
+    let mut registry = UnitRegistry::empty();
+    let _ = registry.insert(Unit::new(
+        example_iri("cubit"),
+        Some(quantity_kind::length().clone()),
+        0.4572,
+        0.0,
+    ));
+    assert!(!registry.is_compatible(&example_iri("cubit"), unit::m()));
+}
@@ -0,0 +1,481 @@
+use rdftk_core::error::Result;
+use rdftk_core::model::graph::reasoner::{
+    OwlRlReasoner, OwlRlRule, RdfsReasoner, RdfsRule, Rule, RuleContext, RuleRef,
+};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::IRIRef;
+use rdftk_names::{owl, rdf, rdfs};
+use std::rc::Rc;
+
+fn named(name: &str) -> SubjectNodeRef {
+    statement_factory().named_subject(example_iri(name))
+}
+
+fn named_object(name: &str) -> ObjectNodeRef {
+    statement_factory().named_object(example_iri(name))
+}
+
+fn statement(subject: &str, predicate: &IRIRef, object: &str) -> StatementRef {
+    statement_factory()
+        .statement(named(subject), predicate.clone(), named_object(object))
+        .unwrap()
+}
+
+fn make_graph() -> GraphRef {
+    graph_factory().graph_from(
+        &[
+            // schema
+            statement("Cat", rdfs::subclass_of(), "Mammal"),
+            statement("Mammal", rdfs::subclass_of(), "Animal"),
+            statement("hasOwner", rdfs::subproperty_of(), "relatedTo"),
+            statement("relatedTo", rdfs::subproperty_of(), "knows"),
+            statement("hasOwner", rdfs::domain(), "Pet"),
+            statement("hasOwner", rdfs::range(), "Person"),
+            statement("name", rdfs::range(), "Name"),
+            // data
+            statement("tom", rdf::a_type(), "Cat"),
+            statement("tom", &example_iri("hasOwner"), "jon"),
+            statement_factory()
+                .statement(
+                    named("tom"),
+                    example_iri("name"),
+                    statement_factory().literal_object(literal_factory().literal("Tom")),
+                )
+                .unwrap(),
+        ],
+        None,
+    )
+}
+
+fn contains(graph: &GraphRef, subject: &str, predicate: &IRIRef, object: &str) -> bool {
+    graph
+        .borrow()
+        .contains_all(&named(subject), predicate, &named_object(object))
+}
+
+#[test]
+fn materialize_rdfs_closure() {
+    let graph = make_graph();
+    let inferred = RdfsReasoner::default()
+        .materialize(&mut *graph.borrow_mut())
+        .unwrap();
+
+    // subclass transitivity
+    assert!(contains(&graph, "Cat", rdfs::subclass_of(), "Animal"));
+    // subproperty transitivity
+    assert!(contains(
+        &graph,
+        "hasOwner",
+        rdfs::subproperty_of(),
+        "knows"
+    ));
+    // class membership through subclasses
+    assert!(contains(&graph, "tom", rdf::a_type(), "Mammal"));
+    assert!(contains(&graph, "tom", rdf::a_type(), "Animal"));
+    // property values through subproperties
+    assert!(contains(&graph, "tom", &example_iri("relatedTo"), "jon"));
+    assert!(contains(&graph, "tom", &example_iri("knows"), "jon"));
+    // domain and range typing
+    assert!(contains(&graph, "tom", rdf::a_type(), "Pet"));
+    assert!(contains(&graph, "jon", rdf::a_type(), "Person"));
+
+    assert_eq!(inferred.len(), 8);
+    assert_eq!(graph.borrow().len(), 18);
+
+    // a literal object is never typed by a range.
+    assert!(!graph
+        .borrow()
+        .statements()
+        .any(|statement| statement.object() == &named_object("Name")
+            && statement.predicate() == rdf::a_type()));
+
+    // the closure is complete.
+    assert!(RdfsReasoner::default()
+        .entailments(&*graph.borrow())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn entailments_do_not_modify_graph() {
+    let graph = make_graph();
+    let entailed = RdfsReasoner::default()
+        .entailments(&*graph.borrow())
+        .unwrap();
+    assert_eq!(entailed.len(), 8);
+    assert_eq!(graph.borrow().len(), 10);
+}
+
+#[test]
+fn selected_rules_only() {
+    let graph = make_graph();
+    let reasoner = RdfsReasoner::with_rules(&[RdfsRule::SubClassMembership]);
+    assert!(reasoner.is_enabled(RdfsRule::SubClassMembership));
+    assert!(!reasoner.is_enabled(RdfsRule::Domain));
+
+    let inferred = reasoner.materialize(&mut *graph.borrow_mut()).unwrap();
+    // without subclass transitivity membership still follows the chain of subclasses.
+    assert_eq!(inferred.len(), 2);
+    assert!(contains(&graph, "tom", rdf::a_type(), "Animal"));
+    assert!(!contains(&graph, "Cat", rdfs::subclass_of(), "Animal"));
+    assert!(!contains(&graph, "tom", rdf::a_type(), "Pet"));
+}
+
+#[test]
+fn subclass_cycles_terminate() {
+    let graph = graph_factory().graph_from(
+        &[
+            statement("A", rdfs::subclass_of(), "B"),
+            statement("B", rdfs::subclass_of(), "A"),
+            statement("x", rdf::a_type(), "A"),
+        ],
+        None,
+    );
+    let inferred = RdfsReasoner::default()
+        .materialize(&mut *graph.borrow_mut())
+        .unwrap();
+    assert!(contains(&graph, "x", rdf::a_type(), "B"));
+    assert!(contains(&graph, "A", rdfs::subclass_of(), "A"));
+    assert!(contains(&graph, "B", rdfs::subclass_of(), "B"));
+    assert_eq!(inferred.len(), 3);
+    assert_eq!(RdfsRule::SubClassTransitivity.to_string(), "rdfs11");
+}
+
+fn make_owl_graph() -> GraphRef {
+    let factory = statement_factory();
+    let list = |name: &str| factory.blank_object_named(name).unwrap();
+    let cell = |name: &str| factory.blank_subject_named(name).unwrap();
+    graph_factory().graph_from(
+        &[
+            // schema
+            statement("hasParent", owl::inverse_of(), "hasChild"),
+            statement_factory()
+                .statement(
+                    named("marriedTo"),
+                    rdf::a_type().clone(),
+                    factory.named_object(owl::symmetric_property().clone()),
+                )
+                .unwrap(),
+            statement_factory()
+                .statement(
+                    named("ancestorOf"),
+                    rdf::a_type().clone(),
+                    factory.named_object(owl::transitive_property().clone()),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    named("hasGrandparent"),
+                    owl::property_chain_axiom().clone(),
+                    list("l1"),
+                )
+                .unwrap(),
+            factory
+                .statement(cell("l1"), rdf::first().clone(), named_object("hasParent"))
+                .unwrap(),
+            factory
+                .statement(cell("l1"), rdf::rest().clone(), list("l2"))
+                .unwrap(),
+            factory
+                .statement(cell("l2"), rdf::first().clone(), named_object("hasParent"))
+                .unwrap(),
+            factory
+                .statement(
+                    cell("l2"),
+                    rdf::rest().clone(),
+                    factory.named_object(rdf::nil().clone()),
+                )
+                .unwrap(),
+            statement("Human", owl::equivalent_class(), "Person"),
+            statement("forename", owl::equivalent_property(), "givenName"),
+            // data
+            statement("ann", &example_iri("hasParent"), "bob"),
+            statement("bob", &example_iri("hasParent"), "cat"),
+            statement("ann", &example_iri("marriedTo"), "dan"),
+            statement("cat", &example_iri("ancestorOf"), "bob"),
+            statement("bob", &example_iri("ancestorOf"), "ann"),
+            statement("ann", rdf::a_type(), "Human"),
+            statement("ann", &example_iri("forename"), "Ann"),
+        ],
+        None,
+    )
+}
+
+#[test]
+fn materialize_owl_rl_closure() {
+    let graph = make_owl_graph();
+    let derivations = OwlRlReasoner::default()
+        .materialize(&mut *graph.borrow_mut())
+        .unwrap();
+    assert!(!derivations.is_empty());
+
+    // inverse properties, in both directions
+    assert!(contains(&graph, "bob", &example_iri("hasChild"), "ann"));
+    assert!(contains(&graph, "cat", &example_iri("hasChild"), "bob"));
+    // symmetric properties
+    assert!(contains(&graph, "dan", &example_iri("marriedTo"), "ann"));
+    // transitive properties
+    assert!(contains(&graph, "cat", &example_iri("ancestorOf"), "ann"));
+    // property chains
+    assert!(contains(
+        &graph,
+        "ann",
+        &example_iri("hasGrandparent"),
+        "cat"
+    ));
+    assert!(!contains(
+        &graph,
+        "bob",
+        &example_iri("hasGrandparent"),
+        "cat"
+    ));
+    // equivalent classes and properties
+    assert!(contains(&graph, "ann", rdf::a_type(), "Person"));
+    assert!(contains(&graph, "ann", &example_iri("givenName"), "Ann"));
+
+    // the closure is complete.
+    assert!(OwlRlReasoner::default()
+        .entailments(&*graph.borrow())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn same_as_equality() {
+    let graph = graph_factory().graph_from(
+        &[
+            statement("ann", owl::same_as(), "anne"),
+            statement("anne", owl::same_as(), "annie"),
+            statement("ann", &example_iri("knows"), "bob"),
+            statement("bob", &example_iri("likes"), "annie"),
+        ],
+        None,
+    );
+    let _ = OwlRlReasoner::default()
+        .materialize(&mut *graph.borrow_mut())
+        .unwrap();
+
+    // symmetry and transitivity
+    assert!(contains(&graph, "anne", owl::same_as(), "ann"));
+    assert!(contains(&graph, "ann", owl::same_as(), "annie"));
+    assert!(contains(&graph, "annie", owl::same_as(), "ann"));
+    // replacement of subjects and objects
+    assert!(contains(&graph, "annie", &example_iri("knows"), "bob"));
+    assert!(contains(&graph, "bob", &example_iri("likes"), "ann"));
+    assert!(contains(&graph, "bob", &example_iri("likes"), "anne"));
+}
+
+#[test]
+fn selected_rule_groups_only() {
+    let graph = make_owl_graph();
+    let reasoner = OwlRlReasoner::with_rules(&[], &[OwlRlRule::InverseOf]);
+    assert!(reasoner.is_enabled(OwlRlRule::InverseOf));
+    assert!(!reasoner.is_enabled(OwlRlRule::TransitiveProperty));
+    assert!(!reasoner.rdfs().is_enabled(RdfsRule::Domain));
+
+    let derivations = reasoner.materialize(&mut *graph.borrow_mut()).unwrap();
+    assert_eq!(derivations.len(), 2);
+    assert!(derivations
+        .iter()
+        .all(|derivation| derivation.rule() == "prp-inv"));
+    assert!(contains(&graph, "bob", &example_iri("hasChild"), "ann"));
+    assert!(!contains(&graph, "dan", &example_iri("marriedTo"), "ann"));
+    assert!(!contains(&graph, "cat", &example_iri("ancestorOf"), "ann"));
+    assert_eq!(OwlRlRule::PropertyChain.to_string(), "prp-spo2");
+}
+
+#[test]
+fn derivation_provenance() {
+    let graph = make_owl_graph();
+    let derivations = OwlRlReasoner::default()
+        .derivations(&*graph.borrow())
+        .unwrap();
+
+    let grandparent = derivations
+        .iter()
+        .find(|derivation| derivation.statement().predicate() == &example_iri("hasGrandparent"))
+        .unwrap();
+    assert_eq!(grandparent.rule(), "prp-spo2");
+    assert_eq!(
+        grandparent.premises(),
+        &vec![
+            grandparent.premises()[0].clone(),
+            statement("ann", &example_iri("hasParent"), "bob"),
+            statement("bob", &example_iri("hasParent"), "cat"),
+        ]
+    );
+    assert_eq!(
+        grandparent.premises()[0].predicate(),
+        owl::property_chain_axiom()
+    );
+
+    let ancestor = derivations
+        .iter()
+        .find(|derivation| {
+            derivation.statement() == &statement("cat", &example_iri("ancestorOf"), "ann")
+        })
+        .unwrap();
+    assert_eq!(ancestor.rule(), "prp-trp");
+    assert!(ancestor.to_string().contains(" <= rule \"prp-trp\""));
+
+    // every premise is either in the graph, or itself derived.
+    let graph = graph.borrow();
+    for derivation in &derivations {
+        assert!(!graph.contains(derivation.statement()));
+        for premise in derivation.premises() {
+            assert!(
+                graph.contains(premise)
+                    || derivations.iter().any(|other| other.statement() == premise)
+            );
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PartOfPropagation;
+
+impl Rule for PartOfPropagation {
+    fn name(&self) -> &str {
+        "part-of"
+    }
+
+    fn apply(&self, statement: &StatementRef, context: &mut RuleContext<'_>) -> Result<()> {
+        if statement.predicate() == &example_iri("locatedIn") {
+            if let Some(place) = context
+                .statement_factory()
+                .object_as_subject(statement.object().clone())
+            {
+                for part_of in context.values(&example_iri("partOf"), &place).to_vec() {
+                    context.derive(
+                        statement.subject().clone(),
+                        statement.predicate(),
+                        part_of.object().clone(),
+                        &[statement, &part_of],
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn make_places_graph() -> GraphRef {
+    graph_factory().graph_from(
+        &[
+            statement("locatedIn", rdfs::range(), "Place"),
+            statement("shop", &example_iri("locatedIn"), "soho"),
+            statement("soho", &example_iri("partOf"), "london"),
+            statement("london", &example_iri("partOf"), "england"),
+        ],
+        None,
+    )
+}
+
+#[test]
+fn custom_rules() {
+    let rule: RuleRef = Rc::new(PartOfPropagation);
+    let reasoner = RdfsReasoner::default().with_rule(rule.clone());
+    assert_eq!(reasoner.custom_rules().len(), 1);
+    assert_ne!(reasoner, RdfsReasoner::default());
+    assert_eq!(reasoner, RdfsReasoner::default().with_rule(rule.clone()));
+
+    let graph = make_places_graph();
+    let derivations = reasoner.materialize(&mut *graph.borrow_mut()).unwrap();
+    assert!(contains(
+        &graph,
+        "shop",
+        &example_iri("locatedIn"),
+        "london"
+    ));
+    assert!(contains(
+        &graph,
+        "shop",
+        &example_iri("locatedIn"),
+        "england"
+    ));
+    // statements derived by the custom rule are available to the built-in rules.
+    assert!(contains(&graph, "england", rdf::a_type(), "Place"));
+
+    let england = derivations
+        .iter()
+        .find(|derivation| {
+            derivation.statement() == &statement("shop", &example_iri("locatedIn"), "england")
+        })
+        .unwrap();
+    assert_eq!(england.rule(), "part-of");
+    assert_eq!(
+        england.premises(),
+        &vec![
+            statement("shop", &example_iri("locatedIn"), "london"),
+            statement("london", &example_iri("partOf"), "england"),
+        ]
+    );
+    let typed = derivations
+        .iter()
+        .find(|derivation| derivation.statement() == &statement("england", rdf::a_type(), "Place"))
+        .unwrap();
+    assert_eq!(typed.rule(), "rdfs3");
+    assert_eq!(
+        &typed.premises()[1],
+        &statement("shop", &example_iri("locatedIn"), "england")
+    );
+
+    let reasoner = OwlRlReasoner::with_rules(&[], &[]).with_rule(rule);
+    let entailed = reasoner
+        .entailments(&*make_places_graph().borrow())
+        .unwrap();
+    assert_eq!(entailed.len(), 2);
+    assert!(RdfsReasoner::with_rules(&[])
+        .entailments(&*make_places_graph().borrow())
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn materialize_added_statements() {
+    let rule: RuleRef = Rc::new(PartOfPropagation);
+    let reasoner = OwlRlReasoner::default().with_rule(rule);
+    let graph = make_places_graph();
+    let _ = reasoner.materialize(&mut *graph.borrow_mut()).unwrap();
+    let before = graph.borrow().len();
+
+    let derivations = reasoner
+        .materialize_added(
+            &mut *graph.borrow_mut(),
+            &[
+                statement("cafe", &example_iri("locatedIn"), "soho"),
+                statement("shop", &example_iri("locatedIn"), "soho"),
+            ],
+        )
+        .unwrap();
+    // the statement already in the graph is not reasoned over again.
+    assert_eq!(derivations.len(), 2);
+    assert_eq!(graph.borrow().len(), before + 3);
+    assert!(contains(
+        &graph,
+        "cafe",
+        &example_iri("locatedIn"),
+        "england"
+    ));
+    assert!(derivations
+        .iter()
+        .all(|derivation| derivation.statement().subject() == &named("cafe")));
+
+    // schema added later applies to the statements already materialized.
+    let derivations = reasoner
+        .materialize_added(
+            &mut *graph.borrow_mut(),
+            &[statement("Place", owl::equivalent_class(), "Location")],
+        )
+        .unwrap();
+    assert!(contains(&graph, "england", rdf::a_type(), "Location"));
+    assert!(derivations
+        .iter()
+        .all(|derivation| derivation.rule() == "cax-eqc"));
+    assert!(reasoner.entailments(&*graph.borrow()).unwrap().is_empty());
+}
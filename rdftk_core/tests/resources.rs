@@ -1,5 +1,6 @@
 use rdftk_core::model::statement::StatementList;
-use rdftk_core::simple::resource::Resource;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::resource::{Predicate, Resource};
 use rdftk_iri::IRIRef;
 use rdftk_iri::IRI;
 use std::str::FromStr;
@@ -36,3 +37,41 @@ fn wikipedia_example_01() {
         println!("{}", st);
     }
 }
+
+#[test]
+fn optional_and_multiple_values() {
+    let literals = literal_factory();
+    let nickname: Option<&str> = None;
+    let resource = Resource::named(
+        IRI::from_str("http://www.w3.org/People/EM/contact#me")
+            .unwrap()
+            .into(),
+    )
+    .maybe_literal(contact("fullName"), Some(literals.literal("Eric Miller")))
+    .maybe_literal(
+        contact("nickname"),
+        nickname.map(|name| literals.literal(name)),
+    )
+    .literals(
+        contact("phone"),
+        ["555-1234", "555-5678"]
+            .iter()
+            .map(|phone| literals.literal(phone)),
+    )
+    .resources(
+        contact("knows"),
+        vec![Resource::named(contact("alice")), Resource::blank()],
+    )
+    .predicate(
+        Predicate::new(contact("personalTitle"))
+            .maybe_literal(Some(literals.literal("Dr.")))
+            .maybe_literal(None)
+            .literals(vec![literals.literal("Prof.")])
+            .resources(Vec::new())
+            .to_owned(),
+    )
+    .to_owned();
+    let sts: StatementList = resource.into();
+    assert_eq!(sts.len(), 7);
+    assert!(!sts.iter().any(|st| st.predicate() == &contact("nickname")));
+}
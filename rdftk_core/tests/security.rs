@@ -0,0 +1,92 @@
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::security::{
+    redact, Redaction, RedactionPolicy, SecurityLabel, SecurityLabels,
+};
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::IRI;
+use std::str::FromStr;
+
+fn person_graph() -> (GraphRef, Vec<StatementRef>) {
+    let factory = statement_factory();
+    let subject = factory.named_subject(IRI::from_str("http://example.org/alice").unwrap().into());
+    let statements: Vec<StatementRef> = vec![
+        ("name", "Alice"),
+        ("phone", "555-1234"),
+        ("salary", "100000"),
+    ]
+    .into_iter()
+    .map(|(predicate, value)| {
+        factory
+            .statement(
+                subject.clone(),
+                IRI::from_str(&format!("http://example.org/{}", predicate))
+                    .unwrap()
+                    .into(),
+                factory.literal_object(literal_factory().literal(value)),
+            )
+            .unwrap()
+    })
+    .collect();
+    (graph_factory().graph_from(&statements, None), statements)
+}
+
+#[test]
+fn label_statements() {
+    let (_, statements) = person_graph();
+    let pii = SecurityLabel::from("pii");
+
+    let mut labels = SecurityLabels::default();
+    labels.add_label(&statements[1], pii.clone());
+    labels.add_label(&statements[1], "contact".into());
+    assert_eq!(labels.len(), 1);
+    assert!(labels.has_label(&statements[1], &pii));
+    assert!(!labels.has_label(&statements[0], &pii));
+
+    labels.remove_label(&statements[1], &pii);
+    labels.remove_label(&statements[1], &"contact".into());
+    assert!(labels.is_empty());
+}
+
+#[test]
+fn redact_labeled_statements() {
+    let (graph, statements) = person_graph();
+
+    let mut labels = SecurityLabels::default();
+    labels.add_label(&statements[1], "pii".into());
+    labels.add_label(&statements[2], "pii".into());
+    labels.add_label(&statements[2], "confidential".into());
+
+    let mut policy = RedactionPolicy::default();
+    let _ = policy
+        .mask("pii".into())
+        .drop("confidential".into())
+        .mask_value("***");
+
+    let (redacted, report) = redact(&graph, &labels, &policy).unwrap();
+    println!("{}", report);
+    assert_eq!(graph.borrow().len(), 3);
+    assert_eq!(redacted.borrow().len(), 2);
+    assert_eq!(report.len(), 2);
+    assert_eq!(report.masked(), 1);
+    assert_eq!(report.dropped(), 1);
+
+    let dropped = report
+        .withheld()
+        .find(|withheld| withheld.action() == Redaction::Drop)
+        .unwrap();
+    assert_eq!(dropped.statement(), &statements[2]);
+    assert_eq!(dropped.labels(), &vec![SecurityLabel::from("confidential")]);
+
+    let redacted = redacted.borrow();
+    assert!(redacted.contains(&statements[0]));
+    assert!(!redacted.contains(&statements[1]));
+    assert!(redacted
+        .statements()
+        .any(|statement| statement.object().to_string() == "\"***\""));
+    assert!(report
+        .to_string()
+        .starts_with("2 statement(s) withheld: 1 dropped, 1 masked."));
+}
@@ -1 +1,82 @@
+use rdftk_core::model::graph::skolem::skolemize;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use std::str::FromStr;
 
+#[test]
+fn skolemize_blank_nodes() {
+    let factory = statement_factory();
+    let graph = graph_factory().graph_from(
+        &[
+            factory
+                .statement(
+                    factory.blank_subject_named("alice").unwrap(),
+                    foaf::knows().clone(),
+                    factory.blank_object_named("bob").unwrap(),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    factory.blank_subject_named("bob").unwrap(),
+                    foaf::name().clone(),
+                    factory.literal_object(literal_factory().literal("Bob")),
+                )
+                .unwrap(),
+        ],
+        None,
+    );
+    let base: IRIRef = IRI::from_str("http://example.org/people").unwrap().into();
+
+    let skolemized = skolemize(&*graph.borrow(), &base).unwrap();
+    let skolemized = skolemized.borrow();
+    assert_eq!(skolemized.len(), 2);
+    assert!(skolemized
+        .statements()
+        .all(|st| !st.subject().is_blank() && !st.object().is_blank()));
+
+    // the same blank node is replaced by the same IRI.
+    let bob = skolemized
+        .statements()
+        .find(|st| st.predicate() == foaf::name())
+        .unwrap()
+        .subject()
+        .as_iri()
+        .unwrap()
+        .clone();
+    assert!(bob
+        .to_string()
+        .starts_with("http://example.org/.well-known/genid/"));
+    assert!(skolemized
+        .statements()
+        .any(|st| st.object().as_iri() == Some(&bob)));
+
+    // the original graph is unchanged.
+    assert!(graph
+        .borrow()
+        .statements()
+        .all(|st| st.subject().is_blank()));
+}
+
+#[test]
+fn skolemize_via_graph() {
+    let factory = statement_factory();
+    let graph = graph_factory().graph_from(
+        &[factory
+            .statement(
+                factory.named_subject(IRI::from_str("http://example.org/alice").unwrap().into()),
+                foaf::knows().clone(),
+                factory.blank_object(),
+            )
+            .unwrap()],
+        None,
+    );
+    let base: IRIRef = IRI::from_str("http://example.org/").unwrap().into();
+    let skolemized = graph.borrow().skolemize(&base).unwrap();
+    assert!(skolemized
+        .borrow()
+        .statements()
+        .all(|st| st.object().is_iri()));
+}
@@ -1,5 +1,7 @@
+use rdftk_core::match_node;
 use rdftk_core::model::literal::DataType;
-use rdftk_core::model::statement::reify_statement;
+use rdftk_core::model::statement::{from_reification, reify_statement, NodeKind, ObjectNodeRef};
+use rdftk_core::simple::graph::graph_factory;
 use rdftk_core::simple::literal::literal_factory;
 use rdftk_core::simple::statement::statement_factory;
 use rdftk_iri::IRI;
@@ -62,6 +64,62 @@ fn reify_nested_statement() {
     assert_eq!(sts.1.len(), 8);
 }
 
+#[test]
+fn reconstruct_reified_statements() {
+    let factory = statement_factory();
+    let alice = factory.named_subject(IRI::from_str("http://example.org/alice").unwrap().into());
+    let st = factory
+        .statement(
+            alice.clone(),
+            rdf::a_type().clone(),
+            factory.named_object(rdfs::class().clone()),
+        )
+        .unwrap();
+    let nested = factory
+        .statement(
+            factory.statement_subject(st.clone()),
+            rdfs::comment().clone(),
+            factory.literal_object(literal_factory().literal("dubious")),
+        )
+        .unwrap();
+
+    let (node, statements) = nested.reify().unwrap();
+    assert_eq!(statements.len(), 8);
+    assert!(statements.iter().all(|statement| !statement.is_nested()));
+
+    let graph = graph_factory().graph_from(&statements, None);
+    let reified = factory.reified_statements(&*graph.borrow());
+    assert_eq!(reified.len(), 2);
+    assert!(reified.contains(&(node, nested)));
+    assert!(reified.iter().any(|(_, statement)| statement == &st));
+}
+
+#[test]
+fn reconstruct_ignores_incomplete_reification() {
+    let factory = statement_factory();
+    let node = factory.blank_subject_named("r1").unwrap();
+    let graph = graph_factory().graph_from(
+        &[
+            factory
+                .statement(
+                    node.clone(),
+                    rdf::subject().clone(),
+                    factory.named_object(rdfs::class().clone()),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    node,
+                    rdf::predicate().clone(),
+                    factory.named_object(rdf::a_type().clone()),
+                )
+                .unwrap(),
+        ],
+        None,
+    );
+    assert!(from_reification(&*graph.borrow(), &factory).is_empty());
+}
+
 #[test]
 fn make_literal_statement() {
     let factory = statement_factory();
@@ -121,3 +179,43 @@ fn make_an_embedded_statement() {
 
     assert_eq!(st.to_string(), "_:a <http://example.org/v/occurenceOf> << <http://example.org/s> <http://example.org/p> <http://example.org/o> >>");
 }
+
+#[test]
+fn match_node_kinds() {
+    let factory = statement_factory();
+    let blank = factory.blank_object_named("b1").unwrap();
+    let named = factory.named_object(rdfs::class().clone());
+    let literal = factory.literal_object(literal_factory().literal("value"));
+    let statement = factory.statement_object(
+        factory
+            .statement(
+                factory.blank_subject_named("01").unwrap(),
+                rdf::a_type().clone(),
+                named.clone(),
+            )
+            .unwrap(),
+    );
+
+    assert_eq!(blank.kind(), NodeKind::Blank(&"b1".to_string()));
+    assert_eq!(named.kind(), NodeKind::Iri(rdfs::class()));
+    assert!(literal.kind().is_literal());
+    assert!(statement.kind().is_statement());
+    assert_eq!(named.kind().to_string(), named.to_string());
+
+    let describe = |node: &ObjectNodeRef| {
+        match_node!(node,
+            Blank(label) => format!("blank {}", label),
+            Iri(iri) => format!("iri {}", iri),
+            Literal(literal) => format!("literal {}", literal.lexical_form()),
+            Statement(_) => "statement".to_string(),
+        )
+    };
+    assert_eq!(describe(&blank), "blank b1");
+    assert_eq!(describe(&literal), "literal value");
+    assert_eq!(describe(&statement), "statement");
+
+    let subject = factory.named_subject(rdfs::class().clone());
+    let is_named = match_node!(subject, Iri(_) => true, _ => false);
+    assert!(is_named);
+    assert!(!match_node!(factory.blank_subject(), Iri(_) => true, _ => false));
+}
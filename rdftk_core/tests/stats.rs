@@ -0,0 +1,237 @@
+use rdftk_core::model::graph::collecting::{CollectingGraph, CollectingGraphFactory};
+use rdftk_core::model::graph::stats::{
+    collect_statements, GraphStatistics, LiteralLengths, NamespaceCounts, PredicateHistogram,
+    StatisticsCollectorRef,
+};
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_core::simple::graph::SimpleGraph;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::IRIRef;
+use rdftk_names::{foaf, rdf, void};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn named(name: &str) -> SubjectNodeRef {
+    statement_factory().named_subject(example_iri(name))
+}
+
+fn literal(value: &str) -> ObjectNodeRef {
+    statement_factory().literal_object(literal_factory().literal(value))
+}
+
+fn class(class: &IRIRef) -> ObjectNodeRef {
+    statement_factory().named_object(class.clone())
+}
+
+fn statement(subject: SubjectNodeRef, predicate: &IRIRef, object: ObjectNodeRef) -> StatementRef {
+    statement_factory()
+        .statement(subject, predicate.clone(), object)
+        .unwrap()
+}
+
+fn make_graph() -> GraphRef {
+    graph_factory().graph_from(
+        &[
+            statement(named("alice"), rdf::a_type(), class(foaf::person())),
+            statement(named("alice"), foaf::name(), literal("Alice")),
+            statement(
+                named("alice"),
+                foaf::knows(),
+                statement_factory().named_object(example_iri("bob")),
+            ),
+            statement(named("bob"), rdf::a_type(), class(foaf::person())),
+            statement(named("bob"), foaf::name(), literal("Bob")),
+            statement(named("acme"), rdf::a_type(), class(foaf::organization())),
+            statement(named("acme"), foaf::name(), literal("Acme")),
+        ],
+        None,
+    )
+}
+
+fn integer(graph: &GraphRef, subject: &SubjectNodeRef, predicate: &IRIRef) -> usize {
+    let graph = graph.borrow();
+    let objects = graph.objects_for(subject, predicate);
+    assert_eq!(objects.len(), 1);
+    objects
+        .into_iter()
+        .next()
+        .unwrap()
+        .as_literal()
+        .unwrap()
+        .lexical_form()
+        .parse()
+        .unwrap()
+}
+
+#[test]
+fn graph_statistics() {
+    let graph = make_graph();
+    let statistics = GraphStatistics::from(&*graph.borrow());
+
+    assert_eq!(statistics.triples(), 7);
+    assert_eq!(statistics.distinct_subjects(), 3);
+    assert_eq!(statistics.distinct_predicates(), 3);
+    assert_eq!(statistics.distinct_objects(), 6);
+    assert_eq!(statistics.distinct_classes(), 2);
+    assert_eq!(statistics.class_count(foaf::person()), 2);
+    assert_eq!(statistics.class_count(foaf::organization()), 1);
+    assert_eq!(statistics.class_count(foaf::group()), 0);
+    assert_eq!(statistics.property_count(foaf::name()), 3);
+    assert_eq!(statistics.property_count(foaf::knows()), 1);
+
+    assert_eq!(
+        statistics.class_usage(),
+        vec![(foaf::person(), 2), (foaf::organization(), 1)]
+    );
+    let properties = statistics.property_usage();
+    assert_eq!(properties.len(), 3);
+    // rdf:type and foaf:name are equally used, and so are ordered by IRI.
+    assert_eq!(properties[0], (rdf::a_type(), 3));
+    assert_eq!(properties[1], (foaf::name(), 3));
+    assert_eq!(properties[2], (foaf::knows(), 1));
+}
+
+#[test]
+fn empty_graph_statistics() {
+    let graph = graph_factory().graph();
+    let statistics = GraphStatistics::from(&*graph.borrow());
+    assert_eq!(statistics, GraphStatistics::default());
+    assert!(statistics.class_usage().is_empty());
+}
+
+#[test]
+fn void_description() {
+    let graph = make_graph();
+    let statistics = GraphStatistics::from(&*graph.borrow());
+    let void = statistics
+        .to_void(&example_iri("dataset"), &graph_factory())
+        .unwrap();
+
+    let dataset = named("dataset");
+    assert!(void
+        .borrow()
+        .contains_all(&dataset, rdf::a_type(), &class(void::dataset())));
+    assert_eq!(integer(&void, &dataset, void::triples()), 7);
+    assert_eq!(integer(&void, &dataset, void::distinct_subjects()), 3);
+    assert_eq!(integer(&void, &dataset, void::properties()), 3);
+    assert_eq!(integer(&void, &dataset, void::distinct_objects()), 6);
+    assert_eq!(integer(&void, &dataset, void::classes()), 2);
+
+    let factory = statement_factory();
+    let partitions: Vec<SubjectNodeRef> = void
+        .borrow()
+        .objects_for(&dataset, void::class_partition())
+        .into_iter()
+        .map(|object| factory.object_as_subject(object.clone()).unwrap())
+        .collect();
+    assert_eq!(partitions.len(), 2);
+    let person = partitions
+        .iter()
+        .find(|partition| {
+            void.borrow()
+                .contains_all(partition, void::class(), &class(foaf::person()))
+        })
+        .unwrap();
+    assert_eq!(integer(&void, person, void::entities()), 2);
+
+    assert_eq!(
+        void.borrow()
+            .objects_for(&dataset, void::property_partition())
+            .len(),
+        3
+    );
+    assert_eq!(
+        void.borrow()
+            .prefix_mappings()
+            .borrow()
+            .get_namespace("void"),
+        Some(void::namespace_iri())
+    );
+}
+
+#[test]
+fn collect_statistics() {
+    let graph = make_graph();
+    let mut predicates = PredicateHistogram::default();
+    let mut namespaces = NamespaceCounts::default();
+    let mut lengths = LiteralLengths::default();
+    let mut subjects: Vec<String> = Default::default();
+    let mut custom = |statement: &StatementRef| subjects.push(statement.subject().to_string());
+    collect_statements(
+        graph.borrow().statements(),
+        &mut [&mut predicates, &mut namespaces, &mut lengths, &mut custom],
+    );
+
+    assert_eq!(predicates.len(), 3);
+    assert_eq!(predicates.count(foaf::name()), 3);
+    assert_eq!(predicates.histogram()[2], (foaf::knows(), 1));
+
+    // 7 subjects and 1 object in example.org, 4 predicates and 3 classes in foaf.
+    assert_eq!(namespaces.len(), 3);
+    assert_eq!(namespaces.count(&example_iri("")), 8);
+    assert_eq!(namespaces.count(foaf::namespace_iri()), 7);
+    assert_eq!(namespaces.count(rdf::namespace_iri()), 3);
+    assert_eq!(namespaces.histogram()[0], (&example_iri(""), 8));
+
+    assert_eq!(lengths.count(), 3);
+    assert_eq!(lengths.min(), Some(3));
+    assert_eq!(lengths.max(), Some(5));
+    assert_eq!(lengths.mean(), Some(4.0));
+    assert_eq!(
+        lengths.distribution(),
+        vec![((0, 0), 0), ((1, 1), 0), ((2, 3), 1), ((4, 7), 2)]
+    );
+
+    assert_eq!(subjects.len(), 7);
+}
+
+#[test]
+fn empty_collectors() {
+    let lengths = LiteralLengths::default();
+    assert_eq!(lengths.mean(), None);
+    assert!(lengths.distribution().is_empty());
+    assert!(PredicateHistogram::default().is_empty());
+    assert!(NamespaceCounts::default().is_empty());
+}
+
+#[test]
+fn collecting_graph() {
+    let predicates = Rc::new(RefCell::new(PredicateHistogram::default()));
+    let mut graph = CollectingGraph::new(
+        SimpleGraph::default(),
+        vec![predicates.clone() as StatisticsCollectorRef],
+    );
+    let statements: Vec<StatementRef> = make_graph().borrow().statements().cloned().collect();
+    for statement in &statements {
+        graph.insert(statement.clone());
+    }
+    graph.remove(&statements[0]);
+
+    assert_eq!(graph.len(), 6);
+    assert_eq!(graph.collectors().len(), 1);
+    assert_eq!(predicates.borrow().count(rdf::a_type()), 3);
+}
+
+#[test]
+fn collecting_graph_factory() {
+    let predicates = Rc::new(RefCell::new(PredicateHistogram::default()));
+    let lengths = Rc::new(RefCell::new(LiteralLengths::default()));
+    let factory = CollectingGraphFactory::<SimpleGraph>::new(vec![
+        predicates.clone() as StatisticsCollectorRef,
+        lengths.clone() as StatisticsCollectorRef,
+    ])
+    .into_ref();
+
+    let statements: Vec<StatementRef> = make_graph().borrow().statements().cloned().collect();
+    let first = factory.graph_from(&statements, None);
+    let second = factory.graph_from_iter(&mut statements.into_iter());
+
+    assert_eq!(first.borrow().len(), 7);
+    assert_eq!(second.borrow().len(), 7);
+    assert_eq!(predicates.borrow().count(foaf::name()), 6);
+    assert_eq!(lengths.borrow().count(), 6);
+}
@@ -0,0 +1,402 @@
+#![cfg(feature = "sled_store")]
+
+use rdftk_core::error::ErrorKind;
+use rdftk_core::model::graph::store::sled::SledStore;
+use rdftk_core::model::graph::store::{StatementStore, StoreGraph};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LanguageTag};
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_iri::{IRIRef, IRI};
+use std::fs::remove_dir_all;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+fn subject(s: &str) -> SubjectNodeRef {
+    statement_factory().named_subject(example_iri(s))
+}
+
+fn object(s: &str) -> ObjectNodeRef {
+    statement_factory().named_object(example_iri(s))
+}
+
+fn statement(s: SubjectNodeRef, p: &str, o: ObjectNodeRef) -> StatementRef {
+    statement_factory().statement(s, example_iri(p), o).unwrap()
+}
+
+fn statements() -> Vec<StatementRef> {
+    let factory = statement_factory();
+    let literals = literal_factory();
+    let knows = statement(subject("alice"), "knows", object("bob"));
+    vec![
+        knows.clone(),
+        statement(subject("alice"), "knows", object("carol")),
+        statement(subject("bob"), "knows", object("carol")),
+        statement(
+            subject("alice"),
+            "name",
+            factory.literal_object(literals.literal("Alice \"A\"\n")),
+        ),
+        statement(
+            subject("alice"),
+            "name",
+            factory.literal_object(
+                literals.with_language("Alicia", LanguageTag::from_str("es").unwrap()),
+            ),
+        ),
+        statement(
+            subject("alice"),
+            "age",
+            factory.literal_object(literals.with_data_type("34", DataType::Integer)),
+        ),
+        statement(
+            subject("alice"),
+            "age",
+            factory.literal_object(literals.with_data_type(
+                "34",
+                DataType::Other(IRIRef::from(
+                    IRI::from_str("http://www.w3.org/2001/XMLSchema#integer").unwrap(),
+                )),
+            )),
+        ),
+        statement(
+            factory.blank_subject_named("b1").unwrap(),
+            "city",
+            factory.blank_object_named("b2").unwrap(),
+        ),
+        statement(factory.statement_subject(knows), "source", object("survey")),
+    ]
+}
+
+fn temporary_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("rdftk-store-{}-{}", name, std::process::id()));
+    let _ = remove_dir_all(&path);
+    path
+}
+
+#[test]
+fn store_insert_and_remove() {
+    let mut store = SledStore::temporary().unwrap();
+    assert!(store.is_empty().unwrap());
+    for st in statements() {
+        assert!(store.insert(&st).unwrap());
+        assert!(!store.insert(&st).unwrap());
+        assert!(store.contains(&st).unwrap());
+    }
+    assert_eq!(store.len().unwrap(), statements().len());
+
+    let mut read: Vec<StatementRef> = store
+        .matching(None, None, None)
+        .unwrap()
+        .map(|st| st.unwrap())
+        .collect();
+    for st in statements() {
+        let index = read.iter().position(|r| r == &st).unwrap();
+        let _ = read.remove(index);
+    }
+    assert!(read.is_empty());
+
+    let st = &statements()[0];
+    assert!(store.remove(st).unwrap());
+    assert!(!store.remove(st).unwrap());
+    assert!(!store.contains(st).unwrap());
+    assert_eq!(store.len().unwrap(), statements().len() - 1);
+    assert_eq!(
+        store
+            .matching(None, None, Some(&object("bob")))
+            .unwrap()
+            .count(),
+        0
+    );
+
+    store.clear().unwrap();
+    assert!(store.is_empty().unwrap());
+}
+
+#[test]
+fn store_matching() {
+    let mut store = SledStore::temporary().unwrap();
+    for st in statements() {
+        let _ = store.insert(&st).unwrap();
+    }
+    let count = |s: Option<&SubjectNodeRef>, p: Option<&IRIRef>, o: Option<&ObjectNodeRef>| {
+        store
+            .matching(s, p, o)
+            .unwrap()
+            .map(|st| {
+                let st = st.unwrap();
+                assert!(s.map(|s| st.subject() == s).unwrap_or(true));
+                assert!(p.map(|p| st.predicate() == p).unwrap_or(true));
+                assert!(o.map(|o| st.object() == o).unwrap_or(true));
+            })
+            .count()
+    };
+    let alice = subject("alice");
+    let knows = example_iri("knows");
+    let carol = object("carol");
+    assert_eq!(count(None, None, None), 9);
+    assert_eq!(count(Some(&alice), None, None), 6);
+    assert_eq!(count(Some(&alice), Some(&knows), None), 2);
+    assert_eq!(count(None, Some(&knows), None), 3);
+    assert_eq!(count(None, Some(&knows), Some(&carol)), 2);
+    assert_eq!(count(None, None, Some(&carol)), 2);
+    assert_eq!(count(Some(&alice), None, Some(&carol)), 1);
+    assert_eq!(count(Some(&alice), Some(&knows), Some(&carol)), 1);
+    assert_eq!(count(Some(&subject("bob")), Some(&knows), Some(&carol)), 1);
+    assert_eq!(
+        count(Some(&subject("carol")), Some(&knows), Some(&carol)),
+        0
+    );
+    assert_eq!(count(None, Some(&example_iri("age")), None), 2);
+    assert_eq!(count(Some(&subject("alic")), None, None), 0);
+}
+
+#[test]
+fn store_survives_restart() {
+    let path = temporary_path("restart");
+    {
+        let mut store = SledStore::open(&path).unwrap();
+        for st in statements() {
+            let _ = store.insert(&st).unwrap();
+        }
+        store
+            .set_prefix_mappings(&[
+                ("ex".to_string(), example_iri("")),
+                (String::new(), example_iri("default/")),
+            ])
+            .unwrap();
+        store.flush().unwrap();
+        assert!(store.size_on_disk().unwrap() > 0);
+    }
+    {
+        let store = SledStore::open(&path).unwrap();
+        assert_eq!(store.len().unwrap(), statements().len());
+        for st in statements() {
+            // blank node labels from a previous process do not identify stored blank nodes.
+            assert_eq!(
+                store.contains(&st).unwrap(),
+                !st.subject().is_blank() && !st.object().is_blank()
+            );
+        }
+        let city: Vec<StatementRef> = store
+            .matching(None, Some(&example_iri("city")), None)
+            .unwrap()
+            .map(|st| st.unwrap())
+            .collect();
+        assert_eq!(city.len(), 1);
+        assert!(store.contains(&city[0]).unwrap());
+        assert_eq!(
+            store.prefix_mappings().unwrap(),
+            vec![
+                (String::new(), example_iri("default/")),
+                ("ex".to_string(), example_iri("")),
+            ]
+        );
+    }
+    remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn store_blank_nodes_after_restart() {
+    let path = temporary_path("blank");
+    let factory = statement_factory();
+    let first = statement(
+        factory.blank_subject_named("b1").unwrap(),
+        "city",
+        object("paris"),
+    );
+    {
+        let mut store = SledStore::open(&path).unwrap();
+        assert!(store.insert(&first).unwrap());
+        // within a process the label provided identifies the node.
+        assert!(store.contains(&first).unwrap());
+        let read: Vec<StatementRef> = store
+            .matching(Some(first.subject()), None, None)
+            .unwrap()
+            .map(|st| st.unwrap())
+            .collect();
+        assert_eq!(read, vec![first.clone()]);
+        store.flush().unwrap();
+    }
+    {
+        let mut store = SledStore::open(&path).unwrap();
+        let stored = store
+            .matching(None, Some(&example_iri("city")), None)
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        // a new process may generate a label already used in the store, it is a new node.
+        let second = statement(
+            factory.blank_subject_named("b1").unwrap(),
+            "city",
+            object("rome"),
+        );
+        assert!(store.insert(&second).unwrap());
+        let fresh = statement(factory.blank_subject(), "city", object("oslo"));
+        assert!(store.insert(&fresh).unwrap());
+        assert_eq!(store.len().unwrap(), 3);
+        assert_eq!(
+            store
+                .matching(Some(second.subject()), None, None)
+                .unwrap()
+                .count(),
+            1
+        );
+
+        // the node read from the store still identifies only its own statements.
+        let read: Vec<StatementRef> = store
+            .matching(Some(stored.subject()), None, None)
+            .unwrap()
+            .map(|st| st.unwrap())
+            .collect();
+        assert_eq!(read, vec![stored.clone()]);
+        assert_eq!(read[0].object(), &object("paris"));
+        let extra = statement(stored.subject().clone(), "country", object("france"));
+        assert!(store.insert(&extra).unwrap());
+        assert_eq!(
+            store
+                .matching(Some(stored.subject()), None, None)
+                .unwrap()
+                .count(),
+            2
+        );
+    }
+    remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn store_graph() {
+    let path = temporary_path("graph");
+    {
+        let mut graph = StoreGraph::open(SledStore::open(&path).unwrap()).unwrap();
+        assert!(graph.is_empty());
+        for st in statements() {
+            graph.insert(st.clone());
+            graph.insert(st);
+        }
+        assert_eq!(graph.len(), statements().len());
+        assert_eq!(graph.store().len().unwrap(), statements().len());
+        assert!(graph.dedup().is_empty());
+
+        let removed = graph.remove_all_for(&subject("bob"));
+        assert_eq!(removed.len(), 1);
+        assert!(!graph.contains_subject(&subject("bob")));
+        assert!(!graph.store().contains(&removed[0]).unwrap());
+
+        graph
+            .prefix_mappings()
+            .borrow_mut()
+            .insert("ex", example_iri(""));
+        graph.flush().unwrap();
+    }
+    {
+        let mut graph = StoreGraph::open(SledStore::open(&path).unwrap()).unwrap();
+        assert_eq!(graph.len(), statements().len() - 1);
+        assert!(graph.contains_individual(&example_iri("alice")));
+        assert_eq!(
+            graph
+                .objects_for(&subject("alice"), &example_iri("knows"))
+                .len(),
+            2
+        );
+        assert_eq!(
+            graph.prefix_mappings().borrow().get_namespace("ex"),
+            Some(&example_iri(""))
+        );
+
+        for st in graph.statements_mut() {
+            if st.predicate() == &example_iri("knows") {
+                *st = statement(st.subject().clone(), "likes", st.object().clone());
+            }
+        }
+        graph.clear();
+        assert!(graph.store().is_empty().unwrap());
+        graph.insert(statement(subject("dave"), "knows", object("erin")));
+        let store = graph.into_store().unwrap();
+        assert_eq!(store.len().unwrap(), 1);
+    }
+    remove_dir_all(&path).unwrap();
+}
+
+#[test]
+fn storage_error_display() {
+    let error: rdftk_core::error::Error = ErrorKind::Storage("disk full".to_string()).into();
+    assert_eq!(
+        error.to_string(),
+        "A persistent store failed to read or write statements: disk full."
+    );
+}
+
+#[test]
+fn store_language_tag_case() {
+    let factory = statement_factory();
+    let literals = literal_factory();
+    let colour = |tag: &str| {
+        statement(
+            subject("alice"),
+            "colour",
+            factory.literal_object(literals.with_language_str("colour", tag).unwrap()),
+        )
+    };
+    let mut store = SledStore::temporary().unwrap();
+    assert!(store.insert(&colour("en-GB")).unwrap());
+    assert!(!store.insert(&colour("en-gb")).unwrap());
+    assert_eq!(store.len().unwrap(), 1);
+    assert!(store.contains(&colour("EN-gb")).unwrap());
+    assert!(store.remove(&colour("en-gb")).unwrap());
+    assert!(store.is_empty().unwrap());
+}
+
+#[test]
+fn store_graph_writes_only_edits() {
+    let mut graph = StoreGraph::open(SledStore::temporary().unwrap()).unwrap();
+    for st in statements() {
+        graph.insert(st);
+    }
+    assert_eq!(graph.predicates_for(&subject("alice")).len(), 3);
+    assert_eq!(graph.subjects().len(), 4);
+    assert_eq!(
+        graph
+            .matches(None, Some(&example_iri("knows")), Some(&object("carol")))
+            .len(),
+        2
+    );
+
+    for st in graph.statements_mut() {
+        if st.predicate() == &example_iri("knows") {
+            *st = statement(st.subject().clone(), "likes", st.object().clone());
+        }
+    }
+    // the graph is answered from the edits, the store is unchanged until they are written.
+    assert_eq!(
+        graph
+            .objects_for(&subject("alice"), &example_iri("likes"))
+            .len(),
+        2
+    );
+    assert!(!graph.contains(&statements()[0]));
+    assert!(graph.store().contains(&statements()[0]).unwrap());
+
+    graph.flush().unwrap();
+    assert_eq!(graph.len(), statements().len());
+    assert!(!graph.store().contains(&statements()[0]).unwrap());
+    assert!(graph.store().contains(&statements()[3]).unwrap());
+    assert_eq!(
+        graph
+            .store()
+            .matching(None, Some(&example_iri("likes")), None)
+            .unwrap()
+            .count(),
+        3
+    );
+    assert_eq!(
+        graph
+            .objects_for(&subject("alice"), &example_iri("likes"))
+            .len(),
+        2
+    );
+}
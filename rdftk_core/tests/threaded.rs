@@ -0,0 +1,328 @@
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_core::threaded::{
+    self, ShardedGraph, ThreadedGraph, ThreadedGraphFactory, ThreadedGraphRef, ThreadedNode,
+    ThreadedStatement, PROVIDER_ID,
+};
+use rdftk_fixtures::terms::example_iri;
+use rdftk_names::{foaf, rdf};
+use std::sync::Arc;
+use std::thread;
+
+fn make_graph() -> GraphRef {
+    let factory = statement_factory();
+    let literals = literal_factory();
+    let alice = factory.named_subject(example_iri("alice"));
+    let address = factory.blank_subject_named("address").unwrap();
+    let graph = graph_factory().graph_from(
+        &[
+            factory
+                .statement(
+                    alice.clone(),
+                    foaf::name().clone(),
+                    factory.literal_object(literals.with_language_str("Alice", "en").unwrap()),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    alice.clone(),
+                    foaf::age().clone(),
+                    factory.literal_object(literals.long(33)),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    alice,
+                    example_iri("address"),
+                    factory.blank_object_named("address").unwrap(),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    address,
+                    rdf::a_type().clone(),
+                    factory.named_object(example_iri("Address")),
+                )
+                .unwrap(),
+        ],
+        None,
+    );
+    graph
+        .borrow()
+        .prefix_mappings()
+        .borrow_mut()
+        .insert("ex", example_iri(""));
+    graph
+}
+
+fn sorted_lines(graph: &GraphRef) -> Vec<String> {
+    let mut lines: Vec<String> = graph
+        .borrow()
+        .statements()
+        .map(|st| st.to_string())
+        .collect();
+    lines.sort();
+    lines
+}
+
+#[test]
+fn threaded_types_are_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<ThreadedGraph>();
+    assert_send_sync::<ThreadedGraphRef>();
+    assert_send_sync::<ThreadedStatement>();
+    assert_send_sync::<ShardedGraph>();
+    assert_send_sync::<StatementRef>();
+}
+
+#[test]
+fn round_trip_through_threaded_graph() {
+    let graph = make_graph();
+    let threaded = ThreadedGraph::from_graph(&*graph.borrow());
+    assert_eq!(threaded.len(), 4);
+    assert_eq!(threaded.subjects().len(), 2);
+    assert_eq!(threaded.factory().provider_id(), PROVIDER_ID);
+    assert_eq!(
+        threaded.prefix_mappings().borrow().get_namespace("ex"),
+        Some(&example_iri(""))
+    );
+
+    let round_tripped = threaded.to_graph(&graph_factory());
+    assert_eq!(sorted_lines(&graph), sorted_lines(&round_tripped));
+    assert_eq!(
+        round_tripped
+            .borrow()
+            .prefix_mappings()
+            .borrow()
+            .get_namespace("ex"),
+        Some(&example_iri(""))
+    );
+}
+
+#[test]
+fn threaded_graph_factory() {
+    let graph = threaded::graph_factory().graph_from(
+        &make_graph()
+            .borrow()
+            .statements()
+            .cloned()
+            .collect::<Vec<_>>(),
+        None,
+    );
+    assert_eq!(graph.borrow().len(), 4);
+    assert_eq!(graph.borrow().factory().provider_id(), PROVIDER_ID);
+
+    let shared = ThreadedGraphFactory::default().shared_graph();
+    let statement = graph.borrow().statements().next().unwrap().clone();
+    shared.write().unwrap().insert(statement.clone());
+    shared.write().unwrap().insert(statement.clone());
+    assert_eq!(shared.read().unwrap().len(), 2);
+    assert_eq!(shared.write().unwrap().dedup().len(), 1);
+    assert!(shared.read().unwrap().contains(&statement));
+
+    shared.write().unwrap().remove(&statement);
+    assert!(shared.read().unwrap().is_empty());
+}
+
+#[test]
+fn prefix_mappings_are_copied() {
+    let graph = ThreadedGraph::from_graph(&*make_graph().borrow());
+    graph
+        .prefix_mappings()
+        .borrow_mut()
+        .insert("foaf", foaf::namespace_iri().clone());
+    assert!(graph
+        .prefix_mappings()
+        .borrow()
+        .get_namespace("foaf")
+        .is_none());
+
+    let mut graph = graph;
+    let mappings = graph.prefix_mappings();
+    mappings
+        .borrow_mut()
+        .insert("foaf", foaf::namespace_iri().clone());
+    graph.set_prefix_mappings(mappings);
+    assert!(graph
+        .prefix_mappings()
+        .borrow()
+        .get_namespace("foaf")
+        .is_some());
+}
+
+#[test]
+fn literal_subjects_are_rejected() {
+    let graph = ShardedGraph::from_graph(&*make_graph().borrow(), 2);
+    let literal = graph
+        .matches(None, Some(foaf::age()), None)
+        .first()
+        .unwrap()
+        .object()
+        .clone();
+    assert!(literal.is_literal());
+    assert!(ThreadedStatement::new(
+        literal,
+        foaf::name().clone(),
+        ThreadedNode::IRI(example_iri("x"))
+    )
+    .is_err());
+}
+
+#[test]
+fn concurrent_readers_and_writers() {
+    let shared: ThreadedGraphRef = ThreadedGraph::from_graph(&*make_graph().borrow()).shared();
+    let alice = statement_factory().named_subject(example_iri("alice"));
+
+    let writers: Vec<_> = (0..4)
+        .map(|n| {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                let factory = statement_factory();
+                for i in 0..25 {
+                    let statement = factory
+                        .statement(
+                            factory.named_subject(example_iri(&format!("person-{}-{}", n, i))),
+                            foaf::knows().clone(),
+                            factory.named_object(example_iri("alice")),
+                        )
+                        .unwrap();
+                    shared.write().unwrap().insert(statement);
+                }
+            })
+        })
+        .collect();
+    let readers: Vec<_> = (0..4)
+        .map(|_| {
+            let shared = shared.clone();
+            let alice = alice.clone();
+            thread::spawn(move || {
+                let graph = shared.read().unwrap();
+                graph
+                    .statements()
+                    .filter(|st| st.subject() == &alice)
+                    .count()
+            })
+        })
+        .collect();
+
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    for reader in readers {
+        assert_eq!(reader.join().unwrap(), 3);
+    }
+
+    let graph = shared.read().unwrap();
+    assert_eq!(graph.len(), 104);
+    assert_eq!(
+        graph
+            .statements()
+            .filter(|st| st.predicate() == foaf::knows())
+            .count(),
+        100
+    );
+}
+
+fn typed(subject: &str, class: &str) -> ThreadedStatement {
+    ThreadedStatement::new(
+        ThreadedNode::IRI(example_iri(subject)),
+        rdf::a_type().clone(),
+        ThreadedNode::IRI(example_iri(class)),
+    )
+    .unwrap()
+}
+
+#[test]
+fn sharded_graph_round_trip() {
+    let graph = make_graph();
+    let sharded = ShardedGraph::from_graph(&*graph.borrow(), 4);
+    assert_eq!(sharded.shard_count(), 4);
+    assert_eq!(sharded.len(), 4);
+    assert_eq!(sharded.subjects().len(), 2);
+    assert_eq!(sharded.prefix_mappings().len(), 1);
+
+    let round_tripped = sharded.to_graph(&graph_factory()).unwrap();
+    assert_eq!(sorted_lines(&round_tripped), sorted_lines(&graph));
+    assert!(round_tripped
+        .borrow()
+        .prefix_mappings()
+        .borrow()
+        .get_namespace("ex")
+        .is_some());
+}
+
+#[test]
+fn sharded_graph_insert_and_remove() {
+    let graph = ShardedGraph::new(0);
+    assert_eq!(graph.shard_count(), 1);
+    assert!(graph.is_empty());
+
+    assert!(graph.insert(typed("alice", "Person")));
+    assert!(!graph.insert(typed("alice", "Person")));
+    assert!(graph.insert(typed("alice", "Employee")));
+    assert!(graph.insert(typed("bob", "Person")));
+    assert_eq!(graph.len(), 3);
+    assert!(graph.contains(&typed("bob", "Person")));
+
+    let alice = ThreadedNode::IRI(example_iri("alice"));
+    assert_eq!(graph.matches(Some(&alice), None, None).len(), 2);
+    assert_eq!(
+        graph
+            .matches(None, None, Some(&ThreadedNode::IRI(example_iri("Person"))))
+            .len(),
+        2
+    );
+
+    assert!(graph.remove(&typed("bob", "Person")));
+    assert!(!graph.remove(&typed("bob", "Person")));
+    assert_eq!(graph.remove_subject(&alice), 2);
+    assert!(graph.is_empty());
+}
+
+#[test]
+fn sharded_graph_concurrent_writers() {
+    let graph = Arc::new(ShardedGraph::default());
+    let writers: Vec<_> = (0..16)
+        .map(|writer| {
+            let graph = graph.clone();
+            thread::spawn(move || {
+                graph
+                    .insert_all((0..100).map(|i| typed(&format!("item/{}/{}", writer, i), "Item")));
+                for i in 0..100 {
+                    let _ = graph.insert(typed(&format!("item/{}/{}", writer, i), "Thing"));
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+    assert_eq!(graph.len(), 16 * 100 * 2);
+    assert_eq!(graph.subjects().len(), 16 * 100);
+
+    let statistics = graph.statistics();
+    assert_eq!(statistics.shards().len(), 16);
+    assert_eq!(
+        statistics
+            .shards()
+            .iter()
+            .map(|shard| shard.statements())
+            .sum::<usize>(),
+        16 * 100 * 2
+    );
+    // every writer inserts every item into its shard individually.
+    assert!(statistics.writes() >= 16 * 100);
+    assert!(statistics.contended() <= statistics.reads() + statistics.writes());
+    assert!((0.0..=1.0).contains(&statistics.contention_ratio()));
+
+    graph.reset_statistics();
+    let statistics = graph.statistics();
+    assert_eq!(statistics.reads() + statistics.writes(), 0);
+    assert_eq!(statistics.contention_ratio(), 0.0);
+
+    graph.clear();
+    assert!(graph.is_empty());
+}
@@ -0,0 +1,145 @@
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::cardinality::{CardinalityConstraints, Enforcement};
+use rdftk_core::model::graph::change_set::ChangeValidator;
+use rdftk_core::model::graph::transaction::{Transaction, Transactional};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_names::foaf;
+
+fn knows(person: &str, other: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(example_iri(person)),
+            foaf::knows().clone(),
+            factory.named_object(example_iri(other)),
+        )
+        .unwrap()
+}
+
+fn bulk_update(graph: &mut dyn Graph, fail: bool) -> Result<()> {
+    let mut transaction = graph.begin();
+    let _ = transaction.insert(knows("alice", "carol"));
+    if fail {
+        return Err(ErrorKind::InvalidState.into());
+    }
+    let _ = transaction.remove(&knows("alice", "bob"));
+    let _ = transaction.commit();
+    Ok(())
+}
+
+#[test]
+fn commit_applies_changes() {
+    let graph = graph_factory().graph_from(&[knows("alice", "bob")], None);
+    let mut graph = graph.borrow_mut();
+
+    let mut transaction = graph.begin();
+    let _ = transaction
+        .insert(knows("alice", "carol"))
+        .remove(&knows("alice", "bob"));
+    assert_eq!(transaction.changes().len(), 2);
+    assert!(transaction.contains(&knows("alice", "carol")));
+    assert!(!transaction.contains(&knows("alice", "bob")));
+    assert!(transaction.graph().contains(&knows("alice", "bob")));
+    assert!(!transaction.graph().contains(&knows("alice", "carol")));
+    let applied = transaction.commit();
+
+    assert_eq!(applied.len(), 2);
+    assert_eq!(graph.len(), 1);
+    assert!(graph.contains(&knows("alice", "carol")));
+}
+
+#[test]
+fn rollback_discards_changes() {
+    let graph = graph_factory().graph_from(&[knows("alice", "bob")], None);
+    let mut graph = graph.borrow_mut();
+
+    let mut transaction = graph.begin();
+    let _ = transaction.insert(knows("alice", "carol"));
+    let discarded = transaction.rollback();
+    assert_eq!(discarded.len(), 1);
+    assert_eq!(graph.len(), 1);
+
+    // dropping an uncommitted transaction is a rollback.
+    assert!(bulk_update(&mut *graph, true).is_err());
+    assert_eq!(graph.len(), 1);
+    assert!(graph.contains(&knows("alice", "bob")));
+
+    assert!(bulk_update(&mut *graph, false).is_ok());
+    assert_eq!(graph.len(), 1);
+    assert!(graph.contains(&knows("alice", "carol")));
+}
+
+#[test]
+fn commit_records_effective_changes() {
+    let graph = graph_factory().graph_from(&[knows("alice", "bob")], None);
+    let mut graph = graph.borrow_mut();
+
+    let mut transaction = Transaction::begin(&mut *graph);
+    let _ = transaction
+        .insert(knows("alice", "bob"))
+        .remove(&knows("bob", "carol"))
+        .insert(knows("bob", "carol"))
+        .remove(&knows("alice", "bob"));
+    assert!(transaction.contains(&knows("bob", "carol")));
+    let applied = transaction.commit();
+
+    assert_eq!(applied.len(), 2);
+    assert_eq!(applied.additions().count(), 1);
+    assert_eq!(applied.removals().count(), 1);
+
+    // the inverse of the applied changes restores the graph.
+    applied.inverse().apply(&mut *graph);
+    assert_eq!(graph.len(), 1);
+    assert!(graph.contains(&knows("alice", "bob")));
+    assert!(!graph.contains(&knows("bob", "carol")));
+}
+
+#[test]
+fn commit_validated() {
+    let graph = graph_factory().graph_from(&[knows("alice", "bob")], None);
+    let mut graph = graph.borrow_mut();
+    let mut constraints = CardinalityConstraints::new(Enforcement::Reject);
+    let _ = constraints.functional(foaf::knows().clone());
+    let validators: [&dyn ChangeValidator; 1] = [&constraints];
+
+    let mut transaction = graph.begin();
+    let _ = transaction.insert(knows("alice", "carol"));
+    let error = transaction.commit_validated(&validators).err().unwrap();
+    assert!(matches!(error.kind(), ErrorKind::ChangeSetRejected(_)));
+    assert_eq!(graph.len(), 1);
+
+    let mut transaction = graph.begin();
+    let _ = transaction
+        .remove(&knows("alice", "bob"))
+        .insert(knows("alice", "carol"));
+    assert!(transaction.commit_validated(&validators).is_ok());
+    assert!(graph.contains(&knows("alice", "carol")));
+}
+
+#[test]
+fn repeated_changes_apply_once() {
+    let graph = graph_factory().graph_from(&[knows("alice", "bob")], None);
+    let mut graph = graph.borrow_mut();
+
+    let mut transaction = graph.begin();
+    for _ in 0..100 {
+        let _ = transaction
+            .insert(knows("bob", "carol"))
+            .remove(&knows("bob", "carol"))
+            .remove(&knows("alice", "bob"))
+            .insert(knows("alice", "bob"));
+    }
+    let _ = transaction.insert(knows("carol", "dave"));
+    assert_eq!(transaction.changes().len(), 401);
+    assert!(!transaction.contains(&knows("bob", "carol")));
+    assert!(transaction.contains(&knows("alice", "bob")));
+    let applied = transaction.commit();
+
+    assert_eq!(applied.len(), 1);
+    assert_eq!(graph.len(), 2);
+    assert!(graph.contains(&knows("carol", "dave")));
+}
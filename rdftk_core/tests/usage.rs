@@ -0,0 +1,101 @@
+use rdftk_core::model::graph::usage::{AccessPattern, UsageTrackingGraph};
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::simple::indexed::IndexedSimpleGraph;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::{iri, subject};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn tracked_graph() -> Rc<RefCell<UsageTrackingGraph<IndexedSimpleGraph>>> {
+    let tracked = Rc::new(RefCell::new(UsageTrackingGraph::new(
+        IndexedSimpleGraph::default(),
+    )));
+    {
+        let factory = statement_factory();
+        let mut graph = tracked.borrow_mut();
+        for (s, p, o) in &[
+            ("http://example.org/a", "http://example.org/name", "A"),
+            ("http://example.org/a", "http://example.org/size", "1"),
+            ("http://example.org/b", "http://example.org/name", "B"),
+        ] {
+            graph.insert(
+                factory
+                    .statement(
+                        subject(s),
+                        iri(p),
+                        factory.literal_object(literal_factory().literal(o)),
+                    )
+                    .unwrap(),
+            );
+        }
+    }
+    tracked
+}
+
+#[test]
+fn access_pattern_display() {
+    assert_eq!(AccessPattern::new(true, true, false).to_string(), "SP?");
+    assert_eq!(AccessPattern::new(false, false, true).to_string(), "??O");
+}
+
+#[test]
+fn insert_is_not_recorded() {
+    let tracked = tracked_graph();
+    let usage = tracked.borrow().usage();
+    assert_eq!(usage.queries(), 0);
+    assert_eq!(usage.scans(), 0);
+    assert_eq!(tracked.borrow().inner().len(), 3);
+}
+
+#[test]
+fn queries_are_recorded() {
+    let tracked = tracked_graph();
+    let graph: GraphRef = tracked.clone();
+    let a = subject("http://example.org/a");
+    let b = subject("http://example.org/b");
+    let name = iri("http://example.org/name");
+
+    {
+        let graph = graph.borrow();
+        assert_eq!(graph.objects_for(&a, &name).len(), 1);
+        assert_eq!(graph.objects_for(&b, &name).len(), 1);
+        assert_eq!(graph.predicates_for(&a).len(), 2);
+        assert_eq!(graph.matches(None, Some(&name), None).len(), 2);
+        assert!(graph.contains_individual(&iri("http://example.org/a")));
+        assert_eq!(graph.statements().count(), 3);
+    }
+
+    let usage = tracked.borrow().usage();
+    assert_eq!(usage.queries(), 5);
+    assert_eq!(usage.scans(), 1);
+    assert_eq!(usage.subject_count(&a), 3);
+    assert_eq!(usage.subject_count(&b), 1);
+    assert_eq!(usage.predicate_count(&name), 3);
+    assert_eq!(
+        usage.pattern_count(AccessPattern::new(true, true, false)),
+        2
+    );
+    assert_eq!(
+        usage.pattern_count(AccessPattern::new(true, false, false)),
+        2
+    );
+    assert_eq!(
+        usage.pattern_count(AccessPattern::new(false, true, false)),
+        1
+    );
+
+    assert_eq!(usage.most_queried_subjects(1), vec![(&a, 3)]);
+    assert_eq!(usage.most_queried_predicates(5), vec![(&name, 3)]);
+}
+
+#[test]
+fn reset_usage() {
+    let tracked = tracked_graph();
+    let a = subject("http://example.org/a");
+    assert!(tracked.borrow().contains_subject(&a));
+
+    let usage = tracked.borrow().reset_usage();
+    assert_eq!(usage.subject_count(&a), 1);
+    assert_eq!(tracked.borrow().usage().queries(), 0);
+}
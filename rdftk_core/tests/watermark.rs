@@ -0,0 +1,108 @@
+use rdftk_core::model::graph::watermark::Watermark;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::example_iri;
+use rdftk_names::foaf;
+
+fn make_graph(people: usize) -> GraphRef {
+    let factory = statement_factory();
+    let graph = graph_factory().graph();
+    for i in 0..people {
+        graph.borrow_mut().insert(
+            factory
+                .statement(
+                    factory.named_subject(example_iri(&format!("people/{}", i))),
+                    foaf::name().clone(),
+                    factory.literal_object(literal_factory().literal(&format!("Person {}", i))),
+                )
+                .unwrap(),
+        );
+    }
+    graph
+}
+
+fn watermark(key: &str) -> Watermark {
+    let mut watermark = Watermark::new(key, example_iri("ns/provenance#mark"));
+    let _ = watermark.rate(4);
+    watermark
+}
+
+#[test]
+fn embed_only_adds_statements() {
+    let original = make_graph(200);
+    let marked = make_graph(200);
+    let added = watermark("key").embed(&mut *marked.borrow_mut()).unwrap();
+    assert!(added > 0 && added < 200);
+    assert_eq!(marked.borrow().len(), 200 + added);
+    for statement in original.borrow().statements() {
+        assert!(marked.borrow().contains(statement));
+    }
+
+    // embedding again is a no-op.
+    assert_eq!(
+        watermark("key").embed(&mut *marked.borrow_mut()).unwrap(),
+        0
+    );
+}
+
+#[test]
+fn detect_in_partial_copy() {
+    let graph = make_graph(200);
+    let _ = watermark("key").embed(&mut *graph.borrow_mut()).unwrap();
+
+    // a copy of the marked statements for half the subjects.
+    let copy = graph_factory().graph();
+    for statement in graph.borrow().statements() {
+        let subject = statement.subject().as_iri().unwrap().to_string();
+        let n: usize = subject.rsplit('/').next().unwrap().parse().unwrap();
+        if n.is_multiple_of(2) {
+            copy.borrow_mut().insert(statement.clone());
+        }
+    }
+
+    let detection = watermark("key").detect(&*copy.borrow());
+    assert!(detection.expected() > 0);
+    assert_eq!(detection.found(), detection.expected());
+    assert!(detection.is_match(0.9));
+
+    let detection = watermark("another key").detect(&*copy.borrow());
+    assert_eq!(detection.found(), 0);
+    assert!(!detection.is_match(0.1));
+}
+
+#[test]
+fn unmarked_graph_is_not_detected() {
+    let graph = make_graph(200);
+    let detection = watermark("key").detect(&*graph.borrow());
+    assert!(detection.expected() > 0);
+    assert_eq!(detection.found(), 0);
+    assert_eq!(detection.score(), 0.0);
+}
+
+#[test]
+fn strip_restores_original() {
+    let graph = make_graph(50);
+    let added = watermark("key").embed(&mut *graph.borrow_mut()).unwrap();
+    let removed = watermark("key").strip(&mut *graph.borrow_mut());
+    assert_eq!(removed.len(), added);
+    assert_eq!(graph.borrow().len(), 50);
+}
+
+#[test]
+fn predicate_in_use_is_rejected() {
+    let graph = make_graph(10);
+    let factory = statement_factory();
+    graph.borrow_mut().insert(
+        factory
+            .statement(
+                factory.named_subject(example_iri("people/0")),
+                example_iri("ns/provenance#mark"),
+                factory.literal_object(literal_factory().literal("not a mark")),
+            )
+            .unwrap(),
+    );
+    assert!(watermark("key").embed(&mut *graph.borrow_mut()).is_err());
+    assert_eq!(graph.borrow().len(), 11);
+}
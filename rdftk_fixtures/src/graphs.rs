@@ -0,0 +1,360 @@
+/*!
+Functions that create each of the fixture graphs, every call returns a new graph.
+
+All subjects and predicates, other than those from common vocabularies such as RDF and FOAF, are
+in the fixture namespace `NAMESPACE`, which is mapped to the prefix `PREFIX` in each graph.
+
+# Example
+
+```rust
+use rdftk_fixtures::graphs::deep_blank_nesting;
+use rdftk_fixtures::DEEP_NESTING_DEPTH;
+
+let graph = deep_blank_nesting();
+let graph = graph.borrow();
+let blank_subjects = graph.subjects().iter().filter(|subject| subject.is_blank()).count();
+assert_eq!(blank_subjects, DEEP_NESTING_DEPTH);
+```
+
+*/
+
+use crate::{
+    fixture_iri, DEEP_NESTING_DEPTH, HUGE_PREDICATE_LENGTH, MEDIUM_PEOPLE, NAMESPACE, PREFIX,
+};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::literal::{DataType, LiteralFactoryRef, LiteralRef};
+use rdftk_core::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, SubjectNodeRef,
+};
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{foaf, rdf, rdfs};
+use std::str::FromStr;
+use std::time::Duration;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Accumulates statements for a fixture and creates the graph.
+///
+struct Builder {
+    statements: StatementFactoryRef,
+    literals: LiteralFactoryRef,
+    list: StatementList,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Two people, one of whom has a blank node address; 10 statements.
+///
+pub fn small() -> GraphRef {
+    let mut b = Builder::default();
+    let alice = b.named("alice");
+    let bob = b.named("bob");
+    let address = b.blank("address");
+
+    b.add(&alice, rdf::a_type(), b.iri(foaf::person()));
+    b.add(&alice, foaf::name(), b.literal("Alice"));
+    b.add(&alice, foaf::nick(), b.language("ally", "en"));
+    b.add(&alice, foaf::age(), b.typed(b.literals.int(42)));
+    b.add(&alice, foaf::homepage(), b.iri(&fixture_iri("alice/home")));
+    b.add(&alice, foaf::knows(), b.object(&bob));
+    b.add(&alice, &fixture_iri("address"), b.object(&address));
+    b.add(&address, &fixture_iri("city"), b.literal("Springfield"));
+    b.add(&bob, rdf::a_type(), b.iri(foaf::person()));
+    b.add(&bob, foaf::name(), b.literal("Bob"));
+    b.build()
+}
+
+///
+/// A social graph of `MEDIUM_PEOPLE` people, each with a type, name, age, mailbox, and two
+/// friends; `6 * MEDIUM_PEOPLE` statements.
+///
+pub fn medium() -> GraphRef {
+    let mut b = Builder::default();
+    let people: Vec<SubjectNodeRef> = (0..MEDIUM_PEOPLE)
+        .map(|i| b.named(&format!("person/{}", i)))
+        .collect();
+    for (i, person) in people.iter().enumerate() {
+        b.add(person, rdf::a_type(), b.iri(foaf::person()));
+        b.add(person, foaf::name(), b.literal(&format!("Person {}", i)));
+        b.add(
+            person,
+            foaf::age(),
+            b.typed(b.literals.int(18 + (i % 60) as i32)),
+        );
+        b.add(
+            person,
+            foaf::mbox(),
+            b.iri(&IRIRef::from(
+                IRI::from_str(&format!("mailto:person{}@example.org", i)).unwrap(),
+            )),
+        );
+        b.add(
+            person,
+            foaf::knows(),
+            b.object(&people[(i + 1) % MEDIUM_PEOPLE]),
+        );
+        b.add(
+            person,
+            foaf::knows(),
+            b.object(&people[(i + 7) % MEDIUM_PEOPLE]),
+        );
+    }
+    b.build()
+}
+
+///
+/// Cycles between named nodes, a self reference, a cycle between blank nodes, a cycle in the
+/// class hierarchy, and an `rdf:List` whose rest refers back to its head; 14 statements.
+///
+pub fn cycles() -> GraphRef {
+    let mut b = Builder::default();
+    let (x, y, z) = (b.named("x"), b.named("y"), b.named("z"));
+    b.add(&x, foaf::knows(), b.object(&y));
+    b.add(&y, foaf::knows(), b.object(&z));
+    b.add(&z, foaf::knows(), b.object(&x));
+
+    let narcissus = b.named("narcissus");
+    b.add(&narcissus, foaf::knows(), b.object(&narcissus));
+
+    let (left, right) = (b.blank("left"), b.blank("right"));
+    b.add(&left, foaf::knows(), b.object(&right));
+    b.add(&right, foaf::knows(), b.object(&left));
+
+    let (chicken, egg) = (b.named("Chicken"), b.named("Egg"));
+    b.add(&chicken, rdfs::subclass_of(), b.object(&egg));
+    b.add(&egg, rdfs::subclass_of(), b.object(&chicken));
+
+    let (head, tail) = (b.blank("head"), b.blank("tail"));
+    b.add(&b.named("ring"), &fixture_iri("members"), b.object(&head));
+    b.add(&head, rdf::first(), b.literal("one"));
+    b.add(&head, rdf::rest(), b.object(&tail));
+    b.add(&tail, rdf::first(), b.literal("two"));
+    b.add(&tail, rdf::rest(), b.object(&head));
+    b.add(&chicken, rdf::a_type(), b.iri(rdfs::class()));
+    b.build()
+}
+
+///
+/// A named root with a chain of `DEEP_NESTING_DEPTH` nested blank nodes, each with its depth;
+/// `2 * DEEP_NESTING_DEPTH + 1` statements.
+///
+pub fn deep_blank_nesting() -> GraphRef {
+    let mut b = Builder::default();
+    let child = fixture_iri("child");
+    let depth = fixture_iri("depth");
+    let mut parent = b.named("root");
+    for i in 1..=DEEP_NESTING_DEPTH {
+        let node = b.blank(&format!("n{}", i));
+        b.add(&parent, &child, b.object(&node));
+        b.add(&node, &depth, b.typed(b.literals.int(i as i32)));
+        parent = node;
+    }
+    b.add(&parent, &fixture_iri("leaf"), b.literal("bottom"));
+    b.build()
+}
+
+///
+/// A literal of every `DataType`, with boundary values for numeric types, as well as plain and
+/// language tagged literals and data types without a `DataType` variant; one subject with 30
+/// statements.
+///
+pub fn all_literal_types() -> GraphRef {
+    let mut b = Builder::default();
+    let subject = b.named("literals");
+    let l = literal_factory();
+    let cases: Vec<(&str, LiteralRef)> = vec![
+        ("plain", l.literal("plain")),
+        ("empty", l.literal("")),
+        ("language", l.with_language_str("colour", "en-GB").unwrap()),
+        ("string", l.string("typed string")),
+        ("qname", l.qname("xsd:string")),
+        ("anyURI", l.uri(&fixture_iri("target"))),
+        ("booleanTrue", l.boolean(true)),
+        ("booleanFalse", l.boolean(false)),
+        ("float", l.float(1.5)),
+        ("floatInfinity", l.with_data_type("INF", DataType::Float)),
+        ("floatNaN", l.with_data_type("NaN", DataType::Float)),
+        ("double", l.double(-2.5e-10)),
+        ("doubleMax", l.double(f64::MAX)),
+        ("longMin", l.long(i64::MIN)),
+        ("longMax", l.long(i64::MAX)),
+        ("intMin", l.int(i32::MIN)),
+        ("short", l.short(i16::MAX)),
+        ("byte", l.byte(i8::MIN)),
+        ("unsignedLong", l.unsigned_long(u64::MAX)),
+        ("unsignedInt", l.unsigned_int(u32::MAX)),
+        ("unsignedShort", l.unsigned_short(u16::MAX)),
+        ("unsignedByte", l.unsigned_byte(u8::MAX)),
+        ("unsignedZero", l.unsigned_byte(0)),
+        ("duration", l.duration(Duration::from_secs(90_061))),
+        (
+            "xmlLiteral",
+            l.with_data_type(
+                "<p xmlns=\"http://www.w3.org/1999/xhtml\">Some <em>XML</em></p>",
+                DataType::XmlLiteral,
+            ),
+        ),
+        ("date", l.with_data_type("2021-07-04", DataType::Date)),
+        (
+            "dateTime",
+            l.with_data_type("2021-07-04T12:00:00Z", DataType::DateTime),
+        ),
+        ("decimal", l.with_data_type("-0.000001", DataType::Decimal)),
+        (
+            "bigInteger",
+            l.with_data_type("123456789012345678901234567890", DataType::Integer),
+        ),
+        (
+            "unknownType",
+            l.with_data_type("opaque", DataType::Other(fixture_iri("datatype/unknown"))),
+        ),
+    ];
+    for (name, literal) in cases {
+        b.add(&subject, &fixture_iri(name), b.typed(literal));
+    }
+    b.build()
+}
+
+///
+/// Literals and IRIs containing characters that are often mishandled: combining characters,
+/// characters outside the basic multilingual plane, joiners, right-to-left text, characters that
+/// must be escaped, and non-ASCII IRIs; 16 statements.
+///
+pub fn unicode_edge_cases() -> GraphRef {
+    let mut b = Builder::default();
+    let subject = b.named("unicode");
+    let text = fixture_iri("text");
+    for value in &[
+        "caf\u{e9}",
+        "cafe\u{301}",
+        "\u{1f980}",
+        "\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}",
+        "say \"hello\" \\ goodbye",
+        "line\nbreak\ttab\rreturn",
+        "\u{feff}byte order mark",
+        "\u{10ffff}",
+        "  leading and trailing space  ",
+    ] {
+        b.add(&subject, &text, b.literal(value));
+    }
+    for (value, language) in &[
+        ("\u{645}\u{631}\u{62d}\u{628}\u{627}", "ar"),
+        ("\u{6f22}\u{5b57}", "zh-Hant-TW"),
+        ("Stra\u{df}e", "de-CH-1901"),
+        ("\u{417}\u{434}\u{440}\u{430}\u{432}\u{43e}", "sr-Cyrl"),
+    ] {
+        b.add(&subject, &text, b.language(value, language));
+    }
+
+    let naive = b.named("unicode/na\u{ef}ve");
+    b.add(
+        &naive,
+        &fixture_iri("\u{540d}\u{524d}"),
+        b.iri(&IRIRef::from(
+            IRI::from_str("http://example.org/\u{30d1}\u{30b9}?q=\u{1f980}#\u{e9}").unwrap(),
+        )),
+    );
+    b.add(
+        &naive,
+        &fixture_iri("encoded"),
+        b.iri(&fixture_iri("path%20with%20spaces/%E2%9C%93")),
+    );
+    b.add(&naive, rdfs::label(), b.literal("na\u{ef}ve"));
+    b.build()
+}
+
+///
+/// Predicates with very long IRIs, each of at least `HUGE_PREDICATE_LENGTH` characters: a long
+/// local name, many path segments, and a long query and fragment; 3 statements.
+///
+pub fn huge_predicates() -> GraphRef {
+    let mut b = Builder::default();
+    let subject = b.named("huge");
+    let long_name = "p".repeat(HUGE_PREDICATE_LENGTH);
+    let many_segments = "segment/".repeat(HUGE_PREDICATE_LENGTH / 8);
+    let long_query = format!(
+        "query?{}#{}",
+        "key=value&".repeat(HUGE_PREDICATE_LENGTH / 20),
+        "f".repeat(HUGE_PREDICATE_LENGTH / 2)
+    );
+    for (local_name, value) in &[
+        (long_name, "long local name"),
+        (many_segments, "many path segments"),
+        (long_query, "long query and fragment"),
+    ] {
+        b.add(&subject, &fixture_iri(local_name), b.literal(value));
+    }
+    b.build()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            statements: statement_factory(),
+            literals: literal_factory(),
+            list: Default::default(),
+        }
+    }
+}
+
+impl Builder {
+    fn named(&self, local_name: &str) -> SubjectNodeRef {
+        self.statements.named_subject(fixture_iri(local_name))
+    }
+
+    fn blank(&self, name: &str) -> SubjectNodeRef {
+        self.statements.blank_subject_named(name).unwrap()
+    }
+
+    fn object(&self, subject: &SubjectNodeRef) -> ObjectNodeRef {
+        self.statements.subject_as_object(subject.clone())
+    }
+
+    fn iri(&self, iri: &IRIRef) -> ObjectNodeRef {
+        self.statements.named_object(iri.clone())
+    }
+
+    fn literal(&self, value: &str) -> ObjectNodeRef {
+        self.typed(self.literals.literal(value))
+    }
+
+    fn language(&self, value: &str, language: &str) -> ObjectNodeRef {
+        self.typed(self.literals.with_language_str(value, language).unwrap())
+    }
+
+    fn typed(&self, literal: LiteralRef) -> ObjectNodeRef {
+        self.statements.literal_object(literal)
+    }
+
+    fn add(&mut self, subject: &SubjectNodeRef, predicate: &IRIRef, object: ObjectNodeRef) {
+        self.list.push(
+            self.statements
+                .statement(subject.clone(), predicate.clone(), object)
+                .unwrap(),
+        );
+    }
+
+    fn build(self) -> GraphRef {
+        let factory = graph_factory();
+        let mappings = factory.mapping_factory().common();
+        {
+            let mut mappings = mappings.borrow_mut();
+            mappings.insert(PREFIX, IRIRef::from(IRI::from_str(NAMESPACE).unwrap()));
+            mappings.insert(foaf::default_prefix(), foaf::namespace_iri().clone());
+        }
+        factory.graph_from(&self.list, Some(mappings))
+    }
+}
@@ -0,0 +1,203 @@
+/*!
+This crate provides a shared corpus of example graphs for testing the RDFtk crates, and any crate
+that uses them, so that all readers, writers, and models are tested against the same data.
+
+Each fixture is identified by a [`Fixture`](enum.Fixture.html) value, and may be loaded as a new
+`GraphRef` with [`Fixture::graph`](enum.Fixture.html#method.graph), or with the corresponding
+function in the [`graphs`](graphs/index.html) module. Every call creates a new graph so tests may
+freely modify the result. The fixtures are built directly using the `rdftk_core` simple model, not
+parsed from files, so they do not depend on any reader being correct.
+
+The [`terms`](terms/index.html) module provides the small helpers, for IRIs, nodes, statements,
+and graphs written as string triples, shared by the tests of the RDFtk crates.
+
+| Fixture              | Content                                                                |
+|----------------------|------------------------------------------------------------------------|
+| `Small`              | Two people, with a blank node address, in a handful of statements.     |
+| `Medium`             | A social graph of `MEDIUM_PEOPLE` people with names, ages, and friends. |
+| `Cycles`             | Cycles between named nodes, blank nodes, classes, and through a list.  |
+| `DeepBlankNesting`   | A chain of `DEEP_NESTING_DEPTH` nested blank nodes.                    |
+| `AllLiteralTypes`    | A literal of every data type, including boundary values.               |
+| `UnicodeEdgeCases`   | Combining characters, astral plane, RTL, escapes, and Unicode IRIs.    |
+| `HugePredicates`     | Predicates with very long IRIs, at least `HUGE_PREDICATE_LENGTH`.      |
+
+All fixtures other than `Small` and `Medium` are *pathological*, they exercise the edges of what
+a reader, writer, or algorithm may be expected to handle.
+
+# Example
+
+```rust
+use rdftk_fixtures::Fixture;
+
+for fixture in Fixture::ALL.iter() {
+    let graph = fixture.graph();
+    assert!(!graph.borrow().is_empty(), "fixture {} is empty", fixture.name());
+}
+
+let small = Fixture::Small.graph();
+assert_eq!(small.borrow().len(), 10);
+```
+
+*/
+
+#![warn(
+    // ---------- Stylistic
+    future_incompatible,
+    nonstandard_style,
+    rust_2018_idioms,
+    trivial_casts,
+    trivial_numeric_casts,
+    // ---------- Public
+    missing_debug_implementations,
+    missing_docs,
+    unreachable_pub,
+    // ---------- Unsafe
+    unsafe_code,
+    // ---------- Unused
+    unused_extern_crates,
+    unused_import_braces,
+    unused_qualifications,
+    unused_results,
+)]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_iri::{IRIRef, IRI};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The namespace of all subjects and predicates defined by the fixtures.
+///
+pub const NAMESPACE: &str = "http://example.org/fixtures/";
+
+///
+/// The prefix mapped to `NAMESPACE` in every fixture graph.
+///
+pub const PREFIX: &str = "ex";
+
+///
+/// The number of people in the `Medium` fixture.
+///
+pub const MEDIUM_PEOPLE: usize = 250;
+
+///
+/// The number of nested blank nodes in the `DeepBlankNesting` fixture.
+///
+pub const DEEP_NESTING_DEPTH: usize = 128;
+
+///
+/// The minimum length, in characters, of the predicate IRIs in the `HugePredicates` fixture.
+///
+pub const HUGE_PREDICATE_LENGTH: usize = 8192;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Identifies one of the graphs in the fixture corpus.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Fixture {
+    /// Two people, with a blank node address, see `graphs::small`.
+    Small,
+    /// A social graph of `MEDIUM_PEOPLE` people, see `graphs::medium`.
+    Medium,
+    /// Cycles of every kind, see `graphs::cycles`.
+    Cycles,
+    /// A chain of `DEEP_NESTING_DEPTH` blank nodes, see `graphs::deep_blank_nesting`.
+    DeepBlankNesting,
+    /// A literal of every data type, see `graphs::all_literal_types`.
+    AllLiteralTypes,
+    /// Unicode edge cases in literals and IRIs, see `graphs::unicode_edge_cases`.
+    UnicodeEdgeCases,
+    /// Very long predicate IRIs, see `graphs::huge_predicates`.
+    HugePredicates,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the IRI for `local_name` in the fixture namespace, `NAMESPACE`.
+///
+pub fn fixture_iri(local_name: &str) -> IRIRef {
+    IRIRef::from(IRI::from_str(&format!("{}{}", NAMESPACE, local_name)).unwrap())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Fixture {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Fixture {
+    ///
+    /// All fixtures, in the order they are declared.
+    ///
+    pub const ALL: [Fixture; 7] = [
+        Self::Small,
+        Self::Medium,
+        Self::Cycles,
+        Self::DeepBlankNesting,
+        Self::AllLiteralTypes,
+        Self::UnicodeEdgeCases,
+        Self::HugePredicates,
+    ];
+
+    ///
+    /// The name of this fixture, the same as the name of the function in `graphs` that creates
+    /// it.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Cycles => "cycles",
+            Self::DeepBlankNesting => "deep_blank_nesting",
+            Self::AllLiteralTypes => "all_literal_types",
+            Self::UnicodeEdgeCases => "unicode_edge_cases",
+            Self::HugePredicates => "huge_predicates",
+        }
+    }
+
+    ///
+    /// Returns `true` if this fixture is one of the pathological cases rather than a typical
+    /// graph.
+    ///
+    pub fn is_pathological(&self) -> bool {
+        !matches!(self, Self::Small | Self::Medium)
+    }
+
+    ///
+    /// Create a new graph containing this fixture.
+    ///
+    pub fn graph(&self) -> GraphRef {
+        match self {
+            Self::Small => graphs::small(),
+            Self::Medium => graphs::medium(),
+            Self::Cycles => graphs::cycles(),
+            Self::DeepBlankNesting => graphs::deep_blank_nesting(),
+            Self::AllLiteralTypes => graphs::all_literal_types(),
+            Self::UnicodeEdgeCases => graphs::unicode_edge_cases(),
+            Self::HugePredicates => graphs::huge_predicates(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod graphs;
+
+pub mod terms;
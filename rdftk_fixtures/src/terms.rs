@@ -0,0 +1,114 @@
+/*!
+Small helpers for creating the IRIs, nodes, statements, and graphs used by tests, so that each
+test file does not need its own copy.
+
+Nodes and statements may be written in a terse string form: a value starting with `_:` is a
+blank node with that label, an object starting with `"` is a plain literal with the rest of the
+value, and anything else is an absolute IRI.
+
+# Example
+
+```rust
+use rdftk_fixtures::terms::{example_iri, graph_from_triples, iri};
+
+let graph = graph_from_triples(&[
+    ("http://example.org/alice", "http://example.org/knows", "_:b0"),
+    ("_:b0", "http://example.org/name", "\"Bob"),
+]);
+let graph = graph.borrow();
+assert_eq!(graph.len(), 2);
+assert!(graph.contains_individual(&example_iri("alice")));
+assert_eq!(example_iri("knows"), iri("http://example.org/knows"));
+```
+
+*/
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The namespace used by `example_iri`.
+///
+pub const EXAMPLE_NAMESPACE: &str = "http://example.org/";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Parse `value` as an absolute IRI, panicking if it is not valid.
+///
+pub fn iri(value: &str) -> IRIRef {
+    IRIRef::from(IRI::from_str(value).unwrap())
+}
+
+///
+/// Return the IRI for `local_name` in `namespace`.
+///
+pub fn iri_in(namespace: &str, local_name: &str) -> IRIRef {
+    iri(&format!("{}{}", namespace, local_name))
+}
+
+///
+/// Return the IRI for `local_name` in `EXAMPLE_NAMESPACE`.
+///
+pub fn example_iri(local_name: &str) -> IRIRef {
+    iri_in(EXAMPLE_NAMESPACE, local_name)
+}
+
+///
+/// Create a subject node from `value`, either a blank node `_:label` or an absolute IRI.
+///
+pub fn subject(value: &str) -> SubjectNodeRef {
+    let factory = statement_factory();
+    match value.strip_prefix("_:") {
+        Some(label) => factory.blank_subject_named(label).unwrap(),
+        None => factory.named_subject(iri(value)),
+    }
+}
+
+///
+/// Create an object node from `value`, either a blank node `_:label`, a plain literal `"value`,
+/// or an absolute IRI.
+///
+pub fn object(value: &str) -> ObjectNodeRef {
+    let factory = statement_factory();
+    if let Some(label) = value.strip_prefix("_:") {
+        factory.blank_object_named(label).unwrap()
+    } else if let Some(literal) = value.strip_prefix('"') {
+        factory.literal_object(literal_factory().literal(literal))
+    } else {
+        factory.named_object(iri(value))
+    }
+}
+
+///
+/// Create a statement from the terse string forms of its subject, predicate, and object.
+///
+pub fn statement(subject_value: &str, predicate: &str, object_value: &str) -> StatementRef {
+    statement_factory()
+        .statement(subject(subject_value), iri(predicate), object(object_value))
+        .unwrap()
+}
+
+///
+/// Create a new graph from `triples`, each in the terse string form accepted by `statement`.
+///
+pub fn graph_from_triples<S: AsRef<str>>(triples: &[(S, S, S)]) -> GraphRef {
+    graph_factory().graph_from(
+        &triples
+            .iter()
+            .map(|(s, p, o)| statement(s.as_ref(), p.as_ref(), o.as_ref()))
+            .collect::<Vec<StatementRef>>(),
+        None,
+    )
+}
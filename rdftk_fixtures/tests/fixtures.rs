@@ -0,0 +1,98 @@
+use rdftk_fixtures::{
+    fixture_iri, graphs, Fixture, DEEP_NESTING_DEPTH, HUGE_PREDICATE_LENGTH, MEDIUM_PEOPLE,
+    NAMESPACE, PREFIX,
+};
+
+#[test]
+fn test_all_fixtures_load() {
+    for fixture in Fixture::ALL.iter() {
+        let graph = fixture.graph();
+        assert!(!graph.borrow().is_empty(), "fixture {} is empty", fixture);
+    }
+}
+
+#[test]
+fn test_statement_counts() {
+    let expected = [
+        (Fixture::Small, 10),
+        (Fixture::Medium, 6 * MEDIUM_PEOPLE),
+        (Fixture::Cycles, 14),
+        (Fixture::DeepBlankNesting, 2 * DEEP_NESTING_DEPTH + 1),
+        (Fixture::AllLiteralTypes, 30),
+        (Fixture::UnicodeEdgeCases, 16),
+        (Fixture::HugePredicates, 3),
+    ];
+    for (fixture, count) in expected.iter() {
+        assert_eq!(
+            fixture.graph().borrow().len(),
+            *count,
+            "fixture {}",
+            fixture
+        );
+    }
+}
+
+#[test]
+fn test_names_and_kinds() {
+    let names: Vec<&str> = Fixture::ALL.iter().map(|fixture| fixture.name()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "small",
+            "medium",
+            "cycles",
+            "deep_blank_nesting",
+            "all_literal_types",
+            "unicode_edge_cases",
+            "huge_predicates"
+        ]
+    );
+    assert!(!Fixture::Small.is_pathological());
+    assert!(!Fixture::Medium.is_pathological());
+    assert!(Fixture::Cycles.is_pathological());
+    assert!(Fixture::HugePredicates.is_pathological());
+    assert_eq!(Fixture::DeepBlankNesting.to_string(), "deep_blank_nesting");
+}
+
+#[test]
+fn test_each_call_is_a_new_graph() {
+    let first = graphs::small();
+    let second = graphs::small();
+    let statements: Vec<_> = first.borrow().statements().cloned().collect();
+    for statement in statements {
+        first.borrow_mut().remove(&statement);
+    }
+    assert!(first.borrow().is_empty());
+    assert_eq!(second.borrow().len(), 10);
+}
+
+#[test]
+fn test_prefix_mappings() {
+    let graph = graphs::small();
+    let graph = graph.borrow();
+    let mappings = graph.prefix_mappings();
+    let mappings = mappings.borrow();
+    assert_eq!(
+        mappings.get_namespace(PREFIX).map(|iri| iri.to_string()),
+        Some(NAMESPACE.to_string())
+    );
+    assert!(mappings.get_namespace("foaf").is_some());
+    assert!(mappings.get_namespace("rdf").is_some());
+}
+
+#[test]
+fn test_huge_predicates_are_huge() {
+    let graph = graphs::huge_predicates();
+    let graph = graph.borrow();
+    for predicate in graph.predicates() {
+        assert!(predicate.to_string().len() >= HUGE_PREDICATE_LENGTH);
+    }
+}
+
+#[test]
+fn test_fixture_iri() {
+    assert_eq!(
+        fixture_iri("alice").to_string(),
+        "http://example.org/fixtures/alice"
+    );
+}
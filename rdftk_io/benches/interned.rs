@@ -0,0 +1,115 @@
+/*!
+Compare reading an N-Triples document into the default, reference counted, graph with reading it
+into the interned graph of `simple::interned`; reporting the time taken, the number of heap
+allocations made, and the memory retained.
+
+Run with `cargo bench -p rdftk_io --bench interned`.
+*/
+
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::interned;
+use rdftk_io::nt::reader::NTriplesReader;
+use rdftk_io::GraphReader;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        let _ = LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+const SUBJECTS: usize = 2_000;
+const PREDICATES: usize = 10;
+const RUNS: usize = 5;
+
+fn document() -> String {
+    let mut document = String::new();
+    for subject in 0..SUBJECTS {
+        for predicate in 0..PREDICATES {
+            document.push_str(&format!(
+                "<http://example.org/s/{}> <http://example.org/p/{}> \"value {}\" .\n",
+                subject,
+                predicate,
+                (subject + predicate) % 50
+            ));
+        }
+        document.push_str(&format!(
+            "<http://example.org/s/{}> <http://example.org/p/next> <http://example.org/s/{}> .\n",
+            subject,
+            (subject + 1) % SUBJECTS
+        ));
+    }
+    document
+}
+
+fn read(document: &str, factory: GraphFactoryRef) -> GraphRef {
+    NTriplesReader::default()
+        .read(&mut document.as_bytes(), factory)
+        .unwrap()
+}
+
+fn measure<T>(name: &str, document: &str, load: impl Fn(&str) -> (T, usize)) {
+    let mut best = Duration::MAX;
+    let mut allocations = 0;
+    let mut retained = 0;
+    for _ in 0..RUNS {
+        let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+        let bytes_before = LIVE_BYTES.load(Ordering::Relaxed);
+        let start = Instant::now();
+        let (loaded, len) = load(document);
+        best = best.min(start.elapsed());
+        allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+        retained = LIVE_BYTES.load(Ordering::Relaxed) - bytes_before;
+        assert_eq!(len, SUBJECTS * (PREDICATES + 1));
+        drop(loaded);
+    }
+    println!(
+        "{:<8} {:>10.2?} {:>12} {:>10.1} {:>10} KiB",
+        name,
+        best,
+        allocations,
+        allocations as f64 / (SUBJECTS * (PREDICATES + 1)) as f64,
+        retained / 1024
+    );
+}
+
+fn main() {
+    let document = document();
+    println!(
+        "reading {} statements, best of {} runs",
+        SUBJECTS * (PREDICATES + 1),
+        RUNS
+    );
+    println!(
+        "{:<8} {:>10} {:>12} {:>10} {:>14}",
+        "graph", "time", "allocations", "per stmt", "retained"
+    );
+    let graph_len = |graph: GraphRef| {
+        let len = graph.borrow().len();
+        (graph, len)
+    };
+    measure("simple", &document, |document| {
+        graph_len(read(document, graph_factory()))
+    });
+    measure("interned", &document, |document| {
+        graph_len(read(document, interned::graph_factory()))
+    });
+}
@@ -1,6 +1,6 @@
 #!/usr/bin/env bash
 
-for grammar in "nq" "nt" "turtle"
+for grammar in "nq" "nt" "trig" "turtle"
 do
   echo cat "src/$grammar/$grammar-in.pest" "src/common/common.pest" ">" "src/$grammar/$grammar.pest"
   cat "src/$grammar/$grammar-in.pest" "src/common/common.pest" > "src/$grammar/$grammar.pest"
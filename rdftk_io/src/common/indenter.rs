@@ -46,11 +46,11 @@ impl Display for Indenter {
 }
 
 impl Indenter {
-    #[allow(dead_code)]
     pub(crate) fn with_width(width: usize) -> Self {
         Self { width, depth: 0 }
     }
 
+    #[allow(dead_code)]
     pub(crate) fn depth(&self) -> usize {
         self.depth
     }
@@ -35,7 +35,19 @@ More detailed description, with
 
 pub(crate) mod indenter;
 
+#[cfg(any(feature = "nq", feature = "nt", feature = "trig", feature = "turtle"))]
 #[macro_use]
 pub(crate) mod parser_error;
 
+#[cfg(any(
+    feature = "json-ld",
+    feature = "loader",
+    feature = "nq",
+    feature = "nt",
+    feature = "resolver",
+    feature = "sparql-results",
+    feature = "trig",
+    feature = "turtle",
+    feature = "xml"
+))]
 pub(crate) mod parser;
@@ -1,38 +1,170 @@
 /*!
-One-line description.
-
-More detailed description, with
-
-# Example
+Functions shared by the parsers for the N-Triples and Turtle families of representations, these
+handle the escape sequences allowed in IRIs, strings, and local names as well as the resolution of
+relative IRIs against a base.
 
 */
 
-// use ...
+use regex::Regex;
 
 // ------------------------------------------------------------------------------------------------
-// Public Types
+// Public Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Public Macros
-// ------------------------------------------------------------------------------------------------
+///
+/// Replace any numeric escape sequences (`\uXXXX` or `\UXXXXXXXX`) in the IRI.
+///
+pub(crate) fn unescape_iri(iri: &str) -> String {
+    UNICODE_ESC
+        .replace_all(iri, |caps: &regex::Captures<'_>| {
+            unescape_uchar(&caps[0]).to_string()
+        })
+        .to_string()
+}
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
+///
+/// Replace any numeric or character escape sequences in the string.
+///
+pub(crate) fn unescape_string(value: &str) -> String {
+    STRING_ESC
+        .replace_all(value, |caps: &regex::Captures<'_>| {
+            let escape = &caps[0];
+            match escape {
+                "\\t" => '\t',
+                "\\b" => '\u{0008}',
+                "\\n" => '\n',
+                "\\r" => '\r',
+                "\\f" => '\u{000C}',
+                "\\\"" => '"',
+                "\\'" => '\'',
+                "\\\\" => '\\',
+                _ => unescape_uchar(escape),
+            }
+            .to_string()
+        })
+        .to_string()
+}
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+///
+/// Replace any reserved character escape sequences in the local part of a prefixed name.
+///
+pub(crate) fn unescape_local_name(local: &str) -> String {
+    LOCAL_ESC.replace_all(local, "$1").to_string()
+}
 
-// ------------------------------------------------------------------------------------------------
-// Implementations
-// ------------------------------------------------------------------------------------------------
+///
+/// Resolve the relative reference `relative` against `base` following the merge and remove dot
+/// segment rules of RFC 3986, section 5.2.
+///
+pub(crate) fn resolve(base: &str, relative: &str) -> String {
+    let without_fragment = base.split('#').next().unwrap();
+    let without_query = without_fragment.split('?').next().unwrap();
+    let (scheme, rest) = without_query.split_at(without_query.find(':').unwrap() + 1);
+    let (authority, path) = if let Some(rest) = rest.strip_prefix("//") {
+        let end = rest.find('/').unwrap_or(rest.len());
+        (format!("//{}", &rest[..end]), &rest[end..])
+    } else {
+        (String::new(), rest)
+    };
+    if relative.is_empty() {
+        without_fragment.to_string()
+    } else if relative.starts_with('#') {
+        format!("{}{}", without_fragment, relative)
+    } else if relative.starts_with("//") {
+        format!("{}{}", scheme, relative)
+    } else if relative.starts_with('?') {
+        format!("{}{}", without_query, relative)
+    } else if relative.starts_with('/') {
+        format!("{}{}{}", scheme, authority, remove_dot_segments(relative))
+    } else {
+        let merged = match path.rfind('/') {
+            None if !authority.is_empty() => format!("/{}", relative),
+            None => relative.to_string(),
+            Some(index) => format!("{}{}", &path[..=index], relative),
+        };
+        format!("{}{}{}", scheme, authority, remove_dot_segments(&merged))
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+lazy_static! {
+    static ref UNICODE_ESC: Regex =
+        Regex::new(r"(\\U[[:xdigit:]]{8})|(\\u[[:xdigit:]]{4})").unwrap();
+    static ref STRING_ESC: Regex =
+        Regex::new(r#"(\\U[[:xdigit:]]{8})|(\\u[[:xdigit:]]{4})|(\\[tbnrf"'\\])"#).unwrap();
+    static ref LOCAL_ESC: Regex = Regex::new(r"\\(.)").unwrap();
+}
+
+fn unescape_uchar(uchar: &str) -> char {
+    use std::char;
+    let uchar = &uchar[2..];
+    let uchar_u32 = u32::from_str_radix(uchar, 16).unwrap();
+    char::from_u32(uchar_u32).unwrap_or(char::REPLACEMENT_CHARACTER)
+}
+
+fn remove_dot_segments(path: &str) -> String {
+    let (path, suffix) = match path.find(['?', '#']) {
+        None => (path, ""),
+        Some(index) => path.split_at(index),
+    };
+    let mut output: Vec<&str> = Default::default();
+    let segments: Vec<&str> = path.split('/').collect();
+    for (index, segment) in segments.iter().enumerate() {
+        let last = index == segments.len() - 1;
+        match *segment {
+            "." => {
+                if last {
+                    output.push("");
+                }
+            }
+            ".." => {
+                if output.len() > 1 {
+                    let _ = output.pop();
+                }
+                if last {
+                    output.push("");
+                }
+            }
+            _ => output.push(segment),
+        }
+    }
+    format!("{}{}", output.join("/"), suffix)
+}
+
 // ------------------------------------------------------------------------------------------------
-// Modules
+// Unit Tests
 // ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_relative_iris() {
+        let base = "http://a/b/c/d;p?q";
+        assert_eq!(resolve(base, "g"), "http://a/b/c/g");
+        assert_eq!(resolve(base, "./g"), "http://a/b/c/g");
+        assert_eq!(resolve(base, "g/"), "http://a/b/c/g/");
+        assert_eq!(resolve(base, "/g"), "http://a/g");
+        assert_eq!(resolve(base, "//g"), "http://g");
+        assert_eq!(resolve(base, "?y"), "http://a/b/c/d;p?y");
+        assert_eq!(resolve(base, "#s"), "http://a/b/c/d;p?q#s");
+        assert_eq!(resolve(base, ""), "http://a/b/c/d;p?q");
+        assert_eq!(resolve(base, ".."), "http://a/b/");
+        assert_eq!(resolve(base, "../g"), "http://a/b/g");
+        assert_eq!(resolve(base, "../../g"), "http://a/g");
+    }
+
+    #[test]
+    fn unescape_values() {
+        assert_eq!(
+            unescape_iri(r"http://example.org/\u00E9"),
+            "http://example.org/é"
+        );
+        assert_eq!(unescape_string(r#"a\tb\"c\U0001F600"#), "a\tb\"c😀");
+        assert_eq!(unescape_local_name(r"a\-b\.c"), "a-b.c");
+    }
+}
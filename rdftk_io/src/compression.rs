@@ -0,0 +1,337 @@
+/*!
+Provides compression-aware input and output for the readers and writers in this crate, so that
+large exports may be streamed directly to compressed files such as `graph.ttl.gz` without an
+intermediate file, and compressed dumps such as `dump.nt.bz2` read without first decompressing
+them.
+
+The compression to use is usually determined from the extension of the path, `.gz` for gzip
+(requires the `gzip` feature), `.xz` for xz (requires the `xz` feature), `.bz2` for bzip2 (requires
+the `bzip2` feature), and `.zst` for Zstandard (requires the `zstd` feature); any other extension
+results in uncompressed output. When reading, the first bytes of the file are examined first, as
+each of these formats starts with a distinct signature, and the extension is only used if the
+signature is not recognized.
+
+# Examples
+
+```rust,no_run
+use rdftk_io::compression::Compression;
+use rdftk_io::turtle::writer::TurtleWriter;
+use rdftk_io::{write_graph_to_path, write_graph_to_path_with};
+# use rdftk_core::model::graph::GraphRef;
+# fn make_graph() -> GraphRef { rdftk_core::simple::graph::graph_factory().graph() }
+
+let writer = TurtleWriter::default();
+let graph = make_graph();
+
+// compression determined by the path extension.
+write_graph_to_path(&writer, &graph, "export.ttl.gz").unwrap();
+
+// compression specified explicitly.
+write_graph_to_path_with(&writer, &graph, "export.ttl", Compression::None).unwrap();
+```
+
+```rust,no_run
+use rdftk_core::simple::graph_factory;
+use rdftk_io::nt::reader::NTriplesReader;
+use rdftk_io::read_graph_from_path;
+
+// compression determined by the file's signature, or extension.
+let graph = read_graph_from_path(&NTriplesReader::default(), "dump.nt.gz", graph_factory())
+    .unwrap();
+```
+
+*/
+
+use rdftk_core::error::{Error, ErrorKind, Result};
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The compression applied to output written to, or input read from, a file.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Compression {
+    /// No compression, the output is written as-is.
+    None,
+    /// [gzip](https://www.rfc-editor.org/rfc/rfc1952) compression, requires the `gzip` feature.
+    Gzip,
+    /// [xz](https://tukaani.org/xz/format.html) compression, requires the `xz` feature.
+    Xz,
+    /// [bzip2](https://sourceware.org/bzip2/) compression, requires the `bzip2` feature.
+    Bzip2,
+    /// [Zstandard](https://www.rfc-editor.org/rfc/rfc8878) compression, requires the `zstd`
+    /// feature.
+    Zstd,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+pub(crate) enum CompressedWriter {
+    Plain(BufWriter<File>),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::write::GzEncoder<BufWriter<File>>),
+    #[cfg(feature = "xz")]
+    Xz(xz2::write::XzEncoder<BufWriter<File>>),
+    #[cfg(feature = "bzip2")]
+    Bzip2(bzip2::write::BzEncoder<BufWriter<File>>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::write::Encoder<'static, BufWriter<File>>),
+}
+
+pub(crate) enum CompressedReader {
+    Plain(BufReader<File>),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::bufread::MultiGzDecoder<BufReader<File>>),
+    #[cfg(feature = "xz")]
+    Xz(xz2::bufread::XzDecoder<BufReader<File>>),
+    #[cfg(feature = "bzip2")]
+    Bzip2(bzip2::bufread::MultiBzDecoder<BufReader<File>>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::read::Decoder<'static, BufReader<File>>),
+}
+
+#[cfg(feature = "xz")]
+const XZ_PRESET: u32 = 6;
+
+#[cfg(feature = "zstd")]
+const ZSTD_LEVEL: i32 = 3;
+
+const SIGNATURES: &[(&[u8], Compression)] = &[
+    (&[0x1F, 0x8B], Compression::Gzip),
+    (&[0xFD, b'7', b'z', b'X', b'Z', 0x00], Compression::Xz),
+    (b"BZh", Compression::Bzip2),
+    (&[0x28, 0xB5, 0x2F, 0xFD], Compression::Zstd),
+];
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Compression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::None => "none",
+                Self::Gzip => "gzip",
+                Self::Xz => "xz",
+                Self::Bzip2 => "bzip2",
+                Self::Zstd => "zstd",
+            }
+        )
+    }
+}
+
+impl Compression {
+    ///
+    /// Determine the compression to use from the extension of `path`; `.gz` for gzip, `.xz` for
+    /// xz, `.bz2` for bzip2, `.zst` for Zstandard, and no compression otherwise.
+    ///
+    pub fn from_path(path: &Path) -> Self {
+        match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase())
+            .as_deref()
+        {
+            Some("gz") => Self::Gzip,
+            Some("xz") => Self::Xz,
+            Some("bz2") => Self::Bzip2,
+            Some("zst") => Self::Zstd,
+            _ => Self::None,
+        }
+    }
+
+    ///
+    /// Determine the compression of data from its first bytes, `bytes`, which must include at least
+    /// the first six bytes of the data to recognize all formats; returns `None` if no signature is
+    /// recognized.
+    ///
+    pub fn from_signature(bytes: &[u8]) -> Option<Self> {
+        SIGNATURES
+            .iter()
+            .find(|(signature, _)| bytes.starts_with(signature))
+            .map(|(_, compression)| *compression)
+    }
+
+    ///
+    /// Return the file extension, without the leading `'.'`, conventionally appended to files
+    /// using this compression.
+    ///
+    pub fn file_extension(&self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Gzip => Some("gz"),
+            Self::Xz => Some("xz"),
+            Self::Bzip2 => Some("bz2"),
+            Self::Zstd => Some("zst"),
+        }
+    }
+
+    ///
+    /// Returns `true` if this compression is supported by the enabled features of this crate.
+    ///
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::None => true,
+            Self::Gzip => cfg!(feature = "gzip"),
+            Self::Xz => cfg!(feature = "xz"),
+            Self::Bzip2 => cfg!(feature = "bzip2"),
+            Self::Zstd => cfg!(feature = "zstd"),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Write for CompressedWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(w) => w.write(buf),
+            #[cfg(feature = "gzip")]
+            Self::Gzip(w) => w.write(buf),
+            #[cfg(feature = "xz")]
+            Self::Xz(w) => w.write(buf),
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2(w) => w.write(buf),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(w) => w.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Plain(w) => w.flush(),
+            #[cfg(feature = "gzip")]
+            Self::Gzip(w) => w.flush(),
+            #[cfg(feature = "xz")]
+            Self::Xz(w) => w.flush(),
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2(w) => w.flush(),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(w) => w.flush(),
+        }
+    }
+}
+
+impl CompressedWriter {
+    ///
+    /// Create the file at `path`, compressing everything written to it with `compression`.
+    ///
+    pub(crate) fn create(path: &Path, compression: Compression) -> Result<Self> {
+        if !compression.is_supported() {
+            return Err(ErrorKind::ReadWrite(compression.to_string()).into());
+        }
+        let file = BufWriter::new(File::create(path).map_err(|e| io_error(e, compression))?);
+        Ok(match compression {
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => Self::Gzip(flate2::write::GzEncoder::new(
+                file,
+                flate2::Compression::default(),
+            )),
+            #[cfg(feature = "xz")]
+            Compression::Xz => Self::Xz(xz2::write::XzEncoder::new(file, XZ_PRESET)),
+            #[cfg(feature = "bzip2")]
+            Compression::Bzip2 => Self::Bzip2(bzip2::write::BzEncoder::new(
+                file,
+                bzip2::Compression::default(),
+            )),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Self::Zstd(
+                zstd::stream::write::Encoder::new(file, ZSTD_LEVEL)
+                    .map_err(|e| io_error(e, compression))?,
+            ),
+            _ => Self::Plain(file),
+        })
+    }
+
+    ///
+    /// Write any remaining compressed data, including trailers, and flush the underlying file.
+    ///
+    pub(crate) fn finish(self) -> Result<()> {
+        let mut file = match self {
+            Self::Plain(w) => w,
+            #[cfg(feature = "gzip")]
+            Self::Gzip(w) => w.finish().map_err(|e| io_error(e, Compression::Gzip))?,
+            #[cfg(feature = "xz")]
+            Self::Xz(w) => w.finish().map_err(|e| io_error(e, Compression::Xz))?,
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2(w) => w.finish().map_err(|e| io_error(e, Compression::Bzip2))?,
+            #[cfg(feature = "zstd")]
+            Self::Zstd(w) => w.finish().map_err(|e| io_error(e, Compression::Zstd))?,
+        };
+        file.flush().map_err(|e| io_error(e, Compression::None))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Read for CompressedReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(r) => r.read(buf),
+            #[cfg(feature = "gzip")]
+            Self::Gzip(r) => r.read(buf),
+            #[cfg(feature = "xz")]
+            Self::Xz(r) => r.read(buf),
+            #[cfg(feature = "bzip2")]
+            Self::Bzip2(r) => r.read(buf),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(r) => r.read(buf),
+        }
+    }
+}
+
+impl CompressedReader {
+    ///
+    /// Open the file at `path`, decompressing everything read from it with `compression`, or if
+    /// `None` with the compression determined by the file's signature or extension.
+    ///
+    pub(crate) fn open(path: &Path, compression: Option<Compression>) -> Result<Self> {
+        let mut file =
+            BufReader::new(File::open(path).map_err(|e| io_error(e, Compression::None))?);
+        let compression = match compression {
+            Some(compression) => compression,
+            None => {
+                let bytes = file
+                    .fill_buf()
+                    .map_err(|e| io_error(e, Compression::None))?;
+                Compression::from_signature(bytes).unwrap_or_else(|| Compression::from_path(path))
+            }
+        };
+        if !compression.is_supported() {
+            return Err(ErrorKind::ReadWrite(compression.to_string()).into());
+        }
+        Ok(match compression {
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => Self::Gzip(flate2::bufread::MultiGzDecoder::new(file)),
+            #[cfg(feature = "xz")]
+            Compression::Xz => Self::Xz(xz2::bufread::XzDecoder::new_multi_decoder(file)),
+            #[cfg(feature = "bzip2")]
+            Compression::Bzip2 => Self::Bzip2(bzip2::bufread::MultiBzDecoder::new(file)),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Self::Zstd(
+                zstd::stream::read::Decoder::with_buffer(file)
+                    .map_err(|e| io_error(e, compression))?,
+            ),
+            _ => Self::Plain(file),
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn io_error(e: std::io::Error, compression: Compression) -> Error {
+    Error::with_chain(e, ErrorKind::ReadWrite(compression.to_string()))
+}
@@ -0,0 +1,485 @@
+/*!
+Provides a reloadable configuration for long-running services, and a `ConfigHandle` shared by the
+components that use it.
+
+A configuration is read from a JSON document with the following members, all of which are
+optional.
+
+| Member      | Content                                                                        |
+|-------------|--------------------------------------------------------------------------------|
+| `profile`   | The name of the active prefix profile                                          |
+| `profiles`  | An object mapping profile names to objects mapping prefixes to namespace IRIs  |
+| `catalog`   | An object mapping IRI prefixes to replacement locations                        |
+| `languages` | An array of language tags required for language-tagged literals                |
+| `rules`     | An object mapping validation rule names to `true` or `false`                   |
+
+A `ConfigHandle` holds the current configuration; components such as the `TurtleWriter` and
+`XmlWriter` retrieve the current value each time they are used so that a replaced configuration
+applies without recreating them. `ConfigHandle::watch` starts a background thread that polls a
+configuration file and replaces the configuration whenever the file changes; a file that cannot
+be read is logged and the previous configuration retained.
+
+# Example
+
+```rust
+use rdftk_io::config::{Config, ConfigHandle};
+use rdftk_io::turtle::writer::TurtleWriter;
+
+let config = Config::from_json_str(r#"{
+  "profile": "publishing",
+  "profiles": {
+    "publishing": { "ex": "http://example.org/vocab#" }
+  },
+  "languages": ["en", "fr"],
+  "rules": { "required-languages": true }
+}"#).unwrap();
+
+let handle = ConfigHandle::new(config);
+let mut writer = TurtleWriter::default();
+let _ = writer.config(handle.clone());
+
+assert_eq!(handle.current().prefixes().count(), 1);
+assert_eq!(handle.generation(), 0);
+```
+
+*/
+
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::mapping::{PrefixMappingFactoryRef, PrefixMappingRef};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::model::statement::SubjectNodeRef;
+use rdftk_iri::{IRIRef, IRI};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::{Arc, RwLock};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The name of the rule, enabled by default, that checks literals against the required
+/// languages.
+///
+pub const RULE_REQUIRED_LANGUAGES: &str = "required-languages";
+
+///
+/// A single configuration value, see the module documentation for the file format.
+///
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    profile: Option<String>,
+    profiles: BTreeMap<String, BTreeMap<String, IRIRef>>,
+    catalog: BTreeMap<String, String>,
+    languages: Vec<LanguageTag>,
+    rules: BTreeMap<String, bool>,
+}
+
+///
+/// A shared, replaceable, configuration. Cloning a handle shares the underlying configuration.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ConfigHandle {
+    current: Arc<RwLock<Arc<Config>>>,
+    generation: Arc<AtomicUsize>,
+}
+
+///
+/// A background thread polling a configuration file, the thread stops when this value is
+/// dropped.
+///
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    path: PathBuf,
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+///
+/// A subject and predicate with language-tagged values but no value in a required language.
+///
+#[derive(Clone, Debug)]
+pub struct MissingLanguage {
+    /// The subject of the statements.
+    pub subject: SubjectNodeRef,
+    /// The predicate of the statements.
+    pub predicate: IRIRef,
+    /// The required language that has no value.
+    pub language: LanguageTag,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const NAME: &str = "config";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Config {
+    ///
+    /// Parse a configuration from a JSON string.
+    ///
+    pub fn from_json_str(s: &str) -> Result<Self> {
+        let value: Value = serde_json::from_str(s)
+            .map_err(|e| Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string())))?;
+        Self::from_json(&value)
+    }
+
+    ///
+    /// Read a configuration from the JSON content of `r`.
+    ///
+    pub fn read(r: &mut impl Read) -> Result<Self> {
+        let value: Value = serde_json::from_reader(r)
+            .map_err(|e| Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string())))?;
+        Self::from_json(&value)
+    }
+
+    ///
+    /// Read a configuration from the JSON file at `path`.
+    ///
+    pub fn read_file(path: &Path) -> Result<Self> {
+        let mut file = File::open(path)
+            .map_err(|e| Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string())))?;
+        Self::read(&mut file)
+    }
+
+    ///
+    /// Parse a configuration from a JSON value.
+    ///
+    pub fn from_json(value: &Value) -> Result<Self> {
+        let object = as_object(value, "configuration")?;
+        let mut config = Self::default();
+        for (key, value) in object {
+            match key.as_str() {
+                "profile" => {
+                    config.profile = Some(as_str(value, key)?.to_string());
+                }
+                "profiles" => {
+                    for (name, mappings) in as_object(value, key)? {
+                        let mut profile: BTreeMap<String, IRIRef> = Default::default();
+                        for (prefix, namespace) in as_object(mappings, name)? {
+                            let namespace = as_str(namespace, prefix)?;
+                            let namespace = IRI::from_str(namespace).map_err(|_| {
+                                config_error(&format!("invalid namespace IRI {:?}", namespace))
+                            })?;
+                            let _ = profile.insert(prefix.clone(), IRIRef::from(namespace));
+                        }
+                        let _ = config.profiles.insert(name.clone(), profile);
+                    }
+                }
+                "catalog" => {
+                    for (from, to) in as_object(value, key)? {
+                        let _ = config
+                            .catalog
+                            .insert(from.clone(), as_str(to, from)?.to_string());
+                    }
+                }
+                "languages" => {
+                    for language in as_array(value, key)? {
+                        let language = as_str(language, key)?;
+                        config
+                            .languages
+                            .push(LanguageTag::from_str(language).map_err(|_| {
+                                config_error(&format!("invalid language tag {:?}", language))
+                            })?);
+                    }
+                }
+                "rules" => {
+                    for (rule, enabled) in as_object(value, key)? {
+                        let enabled = enabled.as_bool().ok_or_else(|| {
+                            config_error(&format!("rule {:?} must be true or false", rule))
+                        })?;
+                        let _ = config.rules.insert(rule.clone(), enabled);
+                    }
+                }
+                _ => warn!("ignoring unknown configuration member {:?}", key),
+            }
+        }
+        if let Some(profile) = &config.profile {
+            if !config.profiles.contains_key(profile) {
+                return Err(config_error(&format!("unknown profile {:?}", profile)));
+            }
+        }
+        Ok(config)
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Return the name of the active prefix profile, if any.
+    ///
+    pub fn profile(&self) -> Option<&String> {
+        self.profile.as_ref()
+    }
+
+    ///
+    /// Return the names of all prefix profiles.
+    ///
+    pub fn profiles(&self) -> impl Iterator<Item = &String> {
+        self.profiles.keys()
+    }
+
+    ///
+    /// Return the prefix mappings of the active profile; if no profile is named and only one is
+    /// defined then that profile is active.
+    ///
+    pub fn prefixes(&self) -> impl Iterator<Item = (&String, &IRIRef)> {
+        let profile = match &self.profile {
+            Some(name) => self.profiles.get(name),
+            None if self.profiles.len() == 1 => self.profiles.values().next(),
+            None => None,
+        };
+        profile.into_iter().flat_map(|mappings| mappings.iter())
+    }
+
+    ///
+    /// Return a new set of prefix mappings containing `mappings`, and those mappings from the
+    /// active profile whose prefix and namespace are not already present.
+    ///
+    pub fn merge_prefixes(
+        &self,
+        mappings: &PrefixMappingRef,
+        factory: &PrefixMappingFactoryRef,
+    ) -> PrefixMappingRef {
+        let merged = factory.empty();
+        {
+            let mappings = mappings.borrow();
+            let mut merged = merged.borrow_mut();
+            if let Some(default) = mappings.get_default_namespace() {
+                merged.set_default_namespace(default.clone());
+            }
+            for (prefix, namespace) in mappings.mappings() {
+                merged.insert(prefix, namespace.clone());
+            }
+            for (prefix, namespace) in self.prefixes() {
+                if merged.get_namespace(prefix).is_none() && merged.get_prefix(namespace).is_none()
+                {
+                    merged.insert(prefix, namespace.clone());
+                }
+            }
+        }
+        merged
+    }
+
+    ///
+    /// Return the location for `iri` from the catalog, replacing the longest matching IRI
+    /// prefix with its mapped location.
+    ///
+    pub fn catalog_lookup(&self, iri: &IRIRef) -> Option<String> {
+        let iri = iri.to_string();
+        self.catalog
+            .iter()
+            .filter(|(from, _)| iri.starts_with(from.as_str()))
+            .max_by_key(|(from, _)| from.len())
+            .map(|(from, to)| format!("{}{}", to, &iri[from.len()..]))
+    }
+
+    ///
+    /// Return the languages required for language-tagged literals.
+    ///
+    pub fn required_languages(&self) -> impl Iterator<Item = &LanguageTag> {
+        self.languages.iter()
+    }
+
+    ///
+    /// Returns `true` unless the named rule has been disabled.
+    ///
+    pub fn rule_enabled(&self, rule: &str) -> bool {
+        self.rules.get(rule).copied().unwrap_or(true)
+    }
+
+    ///
+    /// Return each subject and predicate in `graph` with language-tagged literal values but no
+    /// value in one of the required languages. Nothing is returned if the
+    /// `RULE_REQUIRED_LANGUAGES` rule is disabled.
+    ///
+    pub fn missing_languages(&self, graph: &GraphRef) -> Vec<MissingLanguage> {
+        let mut results: Vec<MissingLanguage> = Default::default();
+        if self.languages.is_empty() || !self.rule_enabled(RULE_REQUIRED_LANGUAGES) {
+            return results;
+        }
+        let graph = graph.borrow();
+        let mut subjects: Vec<&SubjectNodeRef> = graph.subjects().into_iter().collect();
+        subjects.sort_by_key(|subject| subject.to_string());
+        for subject in subjects {
+            let mut predicates: Vec<&IRIRef> = graph.predicates_for(subject).into_iter().collect();
+            predicates.sort();
+            for predicate in predicates {
+                let found: BTreeSet<String> = graph
+                    .objects_for(subject, predicate)
+                    .into_iter()
+                    .filter_map(|object| object.as_literal())
+                    .filter_map(|literal| literal.language())
+                    .map(|language| language.to_string().to_lowercase())
+                    .collect();
+                if found.is_empty() {
+                    continue;
+                }
+                for language in &self.languages {
+                    if !found.contains(&language.to_string().to_lowercase()) {
+                        results.push(MissingLanguage {
+                            subject: subject.clone(),
+                            predicate: predicate.clone(),
+                            language: language.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        results
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<Config> for ConfigHandle {
+    fn from(config: Config) -> Self {
+        Self::new(config)
+    }
+}
+
+impl ConfigHandle {
+    ///
+    /// Create a new handle with the initial configuration.
+    ///
+    pub fn new(config: Config) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(config))),
+            generation: Default::default(),
+        }
+    }
+
+    ///
+    /// Return the current configuration; the returned value is not affected by later changes.
+    ///
+    pub fn current(&self) -> Arc<Config> {
+        self.current.read().unwrap().clone()
+    }
+
+    ///
+    /// Return the number of times the configuration has been replaced.
+    ///
+    pub fn generation(&self) -> usize {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    ///
+    /// Replace the configuration for all users of this handle.
+    ///
+    pub fn replace(&self, config: Config) {
+        *self.current.write().unwrap() = Arc::new(config);
+        let _ = self.generation.fetch_add(1, Ordering::SeqCst);
+    }
+
+    ///
+    /// Replace the configuration with the content of the file at `path`, the current
+    /// configuration is retained if the file cannot be read.
+    ///
+    pub fn reload(&self, path: &Path) -> Result<()> {
+        self.replace(Config::read_file(path)?);
+        Ok(())
+    }
+
+    ///
+    /// Load the configuration from the file at `path`, and start a thread that reloads it each
+    /// time the file is found to have changed, checking every `interval`.
+    ///
+    pub fn watch(&self, path: &Path, interval: Duration) -> Result<ConfigWatcher> {
+        self.reload(path)?;
+        let handle = self.clone();
+        let watched = path.to_path_buf();
+        let mut last_seen = file_stamp(path);
+        let (stop, stopped) = channel::<()>();
+        let thread = std::thread::spawn(move || {
+            // the channel is only disconnected when the watcher is dropped.
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                let stamp = file_stamp(&watched);
+                if stamp.is_some() && stamp != last_seen {
+                    last_seen = stamp;
+                    match handle.reload(&watched) {
+                        Ok(_) => info!("reloaded configuration from {:?}", watched),
+                        Err(e) => error!(
+                            "retaining configuration, could not reload {:?}: {}",
+                            watched, e
+                        ),
+                    }
+                }
+            }
+        });
+        Ok(ConfigWatcher {
+            path: path.to_path_buf(),
+            stop: Some(stop),
+            thread: Some(thread),
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        let _ = self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl ConfigWatcher {
+    ///
+    /// Return the path of the file being watched.
+    ///
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    ///
+    /// Stop watching the file, this is equivalent to dropping the watcher.
+    ///
+    pub fn stop(self) {}
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn config_error(message: &str) -> Error {
+    error!("configuration: {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
+
+fn as_object<'a>(value: &'a Value, name: &str) -> Result<&'a Map<String, Value>> {
+    value
+        .as_object()
+        .ok_or_else(|| config_error(&format!("{:?} must be an object", name)))
+}
+
+fn as_array<'a>(value: &'a Value, name: &str) -> Result<&'a Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| config_error(&format!("{:?} must be an array", name)))
+}
+
+fn as_str<'a>(value: &'a Value, name: &str) -> Result<&'a str> {
+    value
+        .as_str()
+        .ok_or_else(|| config_error(&format!("{:?} must be a string", name)))
+}
+
+fn file_stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    std::fs::metadata(path)
+        .ok()
+        .and_then(|metadata| metadata.modified().ok().map(|time| (time, metadata.len())))
+}
@@ -0,0 +1,443 @@
+/*!
+Provides `Violation`, a description of input that a strict reader rejects as it does not conform
+to the specification of its representation, and the `check` function that finds them.
+
+By default the readers accept some input that their parsers can make sense of but which a
+specification forbids, or which cannot be represented in all of the RDF serializations; a strict
+reader instead checks its input before parsing, and if any violations are found the read fails
+with a `NonConformant` error listing all of them. Each violation is also logged as an error, and a
+strict reader never skips a statement or repairs its input. The checks for each representation
+are:
+
+* **N-Triples, N-Quads, Turtle, and TriG**; numeric escapes (`\uXXXX` and `\UXXXXXXXX`) in IRIs
+  and strings must denote a Unicode character, not a surrogate code point, which includes the
+  surrogate pairs used by JSON, or a value beyond `U+10FFFF`. Language tags must be well-formed
+  according to BCP 47. Literals must not contain, either directly or as an escape, a control
+  character that is not also an XML character, as the literal could not then be written as RDF/XML.
+* **RDF/XML**; character references must denote XML characters, and the values of `xml:lang`
+  must be well-formed language tags.
+* **RDF/JSON and JSON-LD**; the values of `lang` and `@language` respectively must be well-formed
+  language tags, and strings must not contain escaped control characters that are not XML
+  characters.
+
+Each violation carries the line and column, both starting at 1, at which it was found, the text of
+that line, a message, and a reference to the section of the specification it violates.
+
+# Example
+
+```rust
+use rdftk_io::conformance::check;
+use rdftk_io::format::Format;
+
+let content = r#"<http://example.org/a> <http://example.org/b> "\uD83D\uDE00" .
+<http://example.org/a> <http://example.org/c> "one"@en-abcdefghi .
+"#;
+
+let violations = check(Format::NTriples, content);
+assert_eq!(violations.len(), 3);
+assert_eq!(violations[0].column(), 48);
+assert_eq!(violations[0].reference(), "RDF 1.1 Turtle §6.4 (Escape Sequences)");
+assert_eq!(violations[2].line(), 2);
+```
+
+*/
+
+use crate::format::Format;
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::literal::LanguageTag;
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A description of input that does not conform to the specification of its representation, with
+/// its position in the input.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    line: usize,
+    column: usize,
+    text: String,
+    message: String,
+    reference: &'static str,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const ESCAPES: &str = "RDF 1.1 Turtle §6.4 (Escape Sequences)";
+
+const LANGUAGE_TAGS: &str = "RDF 1.1 Concepts §3.3 (Literals), BCP 47 §2.2.9";
+
+const CHARACTERS: &str = "XML 1.0 §2.2 (Characters)";
+
+const CHARACTER_REFERENCES: &str = "XML 1.0 §4.1 (Character and Entity References)";
+
+#[derive(Debug)]
+struct Source<'a> {
+    content: &'a str,
+    line_starts: Vec<usize>,
+    violations: Vec<Violation>,
+}
+
+lazy_static! {
+    static ref CHAR_REF: Regex = Regex::new(r"&#(?:x([[:xdigit:]]+)|([0-9]+));").unwrap();
+    static ref XML_LANG: Regex = Regex::new(r#"xml:lang\s*=\s*(?:"([^"]*)"|'([^']*)')"#).unwrap();
+    static ref JSON_LANG: Regex = Regex::new(r#""(@?lang(?:uage)?)"\s*:\s*"([^"]*)""#).unwrap();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Check that `content` conforms to the specification of the representation `format`, returning
+/// a violation for each deviation found, in the order they appear.
+///
+pub fn check(format: Format, content: &str) -> Vec<Violation> {
+    let mut source = Source::new(content);
+    match format {
+        Format::NTriples | Format::NQuads | Format::Turtle | Format::TriG => {
+            source.check_turtle_family()
+        }
+        Format::Xml => source.check_xml(),
+        Format::Json => source.check_json("lang"),
+        Format::JsonLd => source.check_json("@language"),
+        Format::Dot => {}
+    }
+    source.violations
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: {} ({}); in `{}`",
+            self.line, self.column, self.message, self.reference, self.text
+        )
+    }
+}
+
+impl Violation {
+    ///
+    /// Return the line, starting at 1, of the violation.
+    ///
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    ///
+    /// Return the column, starting at 1, at which the violation was found.
+    ///
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    ///
+    /// Return the text of the line containing the violation.
+    ///
+    pub fn text(&self) -> &String {
+        &self.text
+    }
+
+    ///
+    /// Return a description of the violation.
+    ///
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+
+    ///
+    /// Return a reference to the section of the specification that the input violates.
+    ///
+    pub fn reference(&self) -> &'static str {
+        self.reference
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Source<'a> {
+    fn new(content: &'a str) -> Self {
+        Self {
+            content,
+            line_starts: std::iter::once(0)
+                .chain(content.match_indices('\n').map(|(index, _)| index + 1))
+                .collect(),
+            violations: Default::default(),
+        }
+    }
+
+    fn violation(&mut self, offset: usize, message: String, reference: &'static str) {
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1,
+        };
+        let start = self.line_starts[line];
+        let end = self.content[start..]
+            .find('\n')
+            .map(|end| start + end)
+            .unwrap_or(self.content.len());
+        self.violations.push(Violation {
+            line: line + 1,
+            column: self.content[start..offset].chars().count() + 1,
+            text: self.content[start..end].trim_end_matches('\r').to_string(),
+            message,
+            reference,
+        })
+    }
+
+    fn check_turtle_family(&mut self) {
+        let bytes = self.content.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            match bytes[index] {
+                b'#' => {
+                    index = self.content[index..]
+                        .find('\n')
+                        .map(|end| index + end)
+                        .unwrap_or(bytes.len())
+                }
+                b'\\' => index += 2,
+                b'<' if bytes.get(index + 1) == Some(&b'<') => index += 2,
+                b'<' => match self.content[index..].find('>') {
+                    Some(end) => {
+                        self.check_escapes(index + 1, index + end, false);
+                        index += end + 1;
+                    }
+                    None => break,
+                },
+                quote @ (b'"' | b'\'') => {
+                    let long = bytes.get(index + 1) == Some(&quote)
+                        && bytes.get(index + 2) == Some(&quote);
+                    let delimiter = if long { 3 } else { 1 };
+                    let start = index + delimiter;
+                    match string_end(bytes, start, quote, long) {
+                        Some(end) => {
+                            self.check_escapes(start, end, true);
+                            index = end + delimiter;
+                            if bytes.get(index) == Some(&b'@') {
+                                let tag_end = self.content[index + 1..]
+                                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+                                    .map(|end| index + 1 + end)
+                                    .unwrap_or(bytes.len());
+                                self.check_language_tag(index + 1, tag_end);
+                                index = tag_end;
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                _ => index += 1,
+            }
+        }
+    }
+
+    fn check_xml(&mut self) {
+        let content = self.content;
+        for captures in CHAR_REF.captures_iter(content) {
+            let reference = captures.get(0).unwrap();
+            let value = match (captures.get(1), captures.get(2)) {
+                (Some(hex), _) => u32::from_str_radix(hex.as_str(), 16).ok(),
+                (None, Some(decimal)) => decimal.as_str().parse::<u32>().ok(),
+                (None, None) => None,
+            };
+            if !value
+                .and_then(char::from_u32)
+                .map(is_xml_char)
+                .unwrap_or(false)
+            {
+                self.violation(
+                    reference.start(),
+                    format!(
+                        "the character reference {} does not denote an XML character",
+                        reference.as_str()
+                    ),
+                    CHARACTER_REFERENCES,
+                );
+            }
+        }
+        for captures in XML_LANG.captures_iter(content) {
+            let tag = captures.get(1).or_else(|| captures.get(2)).unwrap();
+            if !tag.as_str().is_empty() {
+                self.check_language_tag(tag.start(), tag.end());
+            }
+        }
+    }
+
+    fn check_json(&mut self, language_key: &str) {
+        let content = self.content;
+        let bytes = content.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b'"' {
+                match string_end(bytes, index + 1, b'"', false) {
+                    Some(end) => {
+                        self.check_json_escapes(index + 1, end);
+                        index = end + 1;
+                    }
+                    None => break,
+                }
+            } else {
+                index += 1;
+            }
+        }
+        for captures in JSON_LANG.captures_iter(content) {
+            if &captures[1] == language_key {
+                let tag = captures.get(2).unwrap();
+                self.check_language_tag(tag.start(), tag.end());
+            }
+        }
+    }
+
+    fn check_escapes(&mut self, start: usize, end: usize, literal: bool) {
+        let content = self.content;
+        let mut chars = content[start..end].char_indices();
+        while let Some((offset, c)) = chars.next() {
+            let offset = start + offset;
+            if c == '\\' {
+                let length = match chars.next() {
+                    Some((_, 'u')) => 4,
+                    Some((_, 'U')) => 8,
+                    Some((_, 'b')) if literal => {
+                        self.control_character(offset, '\u{0008}');
+                        continue;
+                    }
+                    Some((_, 'f')) if literal => {
+                        self.control_character(offset, '\u{000C}');
+                        continue;
+                    }
+                    _ => continue,
+                };
+                let escape = match content.get(offset..offset + 2 + length) {
+                    Some(escape) => escape,
+                    None => continue,
+                };
+                let value = match u32::from_str_radix(&escape[2..], 16) {
+                    Ok(value) => value,
+                    Err(_) => continue,
+                };
+                for _ in 0..length {
+                    let _ = chars.next();
+                }
+                match char::from_u32(value) {
+                    None => self.violation(
+                        offset,
+                        format!("the escape {} does not denote a Unicode character", escape),
+                        ESCAPES,
+                    ),
+                    Some(c) if literal && !is_xml_char(c) => self.control_character(offset, c),
+                    Some(_) => {}
+                }
+            } else if literal && !is_xml_char(c) {
+                self.control_character(offset, c);
+            }
+        }
+    }
+
+    fn check_json_escapes(&mut self, start: usize, end: usize) {
+        let content = self.content;
+        let mut index = start;
+        while index < end {
+            if content.as_bytes()[index] == b'\\' {
+                if content.as_bytes().get(index + 1) == Some(&b'u') {
+                    let value = content
+                        .get(index + 2..index + 6)
+                        .and_then(|hex| u32::from_str_radix(hex, 16).ok());
+                    // surrogates are either a pair, denoting a single character, or rejected by
+                    // the JSON parser.
+                    if let Some(c) = value.and_then(char::from_u32) {
+                        if !is_xml_char(c) {
+                            self.control_character(index, c);
+                        }
+                    }
+                }
+                index += 2;
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    fn check_language_tag(&mut self, start: usize, end: usize) {
+        let tag = &self.content[start..end];
+        if LanguageTag::from_str(tag).is_err() {
+            self.violation(
+                start,
+                format!("the language tag {:?} is not well-formed", tag),
+                LANGUAGE_TAGS,
+            );
+        }
+    }
+
+    fn control_character(&mut self, offset: usize, c: char) {
+        self.violation(
+            offset,
+            format!(
+                "the literal contains U+{:04X}, which is not an XML character",
+                c as u32
+            ),
+            CHARACTERS,
+        );
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Check `content` for the representation `format`, logging each violation and returning a
+/// `NonConformant` error listing them if there are any.
+///
+pub(crate) fn conform(format: Format, content: &str) -> Result<()> {
+    let violations = check(format, content);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        for violation in &violations {
+            error!("non-conformant {} input, {}", format, violation);
+        }
+        Err(ErrorKind::NonConformant(
+            format.name().to_string(),
+            violations.iter().map(|v| v.to_string()).collect(),
+        )
+        .into())
+    }
+}
+
+fn string_end(bytes: &[u8], start: usize, quote: u8, long: bool) -> Option<usize> {
+    let mut index = start;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b if b == quote
+                && (!long
+                    || (bytes.get(index + 1) == Some(&quote)
+                        && bytes.get(index + 2) == Some(&quote))) =>
+            {
+                return Some(index)
+            }
+            b'\n' | b'\r' if !long => return None,
+            _ => index += 1,
+        }
+    }
+    None
+}
+
+fn is_xml_char(c: char) -> bool {
+    matches!(c,
+        '\t' | '\n' | '\r'
+        | '\u{20}'..='\u{D7FF}'
+        | '\u{E000}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{10FFFF}')
+}
@@ -0,0 +1,364 @@
+/*!
+Provides the `DereferenceChecker`, a batch check that the IRIs published in a dataset may be
+dereferenced, and that each returns a representation in the RDF formats requested through content
+negotiation. This is intended as a quality check before publishing a linked dataset; every subject
+IRI in the dataset's namespace should resolve, with `HEAD` or `GET`, to a successful response of
+the requested media type.
+
+IRIs that differ only in their fragment identify the same document, which is checked once. Each
+document is requested once per media type, with `HEAD`; if the server does not support `HEAD` the
+request is repeated with `GET`.
+
+Requests are made through an implementation of the `Fetcher` trait, such as the `HttpTransport` of
+the `http` module (the `http` feature), which is expected to follow redirects, such as the
+`303 See Other` responses common for linked data, and return the final response. Requests are made
+one at a time, in IRI order, so that the client may apply any rate limiting or politeness delay
+required by the publishing server.
+
+# Example
+
+```rust
+use rdftk_core::error::Result;
+use rdftk_io::dereference::{DereferenceChecker, Fetcher, Method, Response};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+# use rdftk_core::model::graph::GraphRef;
+# fn make_graph() -> GraphRef { rdftk_core::simple::graph::graph_factory().graph() }
+
+struct Offline;
+
+impl Fetcher for Offline {
+    fn fetch(&self, _: Method, _: &IRIRef, _: &str) -> Result<Response> {
+        Ok(Response::new(404, None))
+    }
+}
+
+let checker = DereferenceChecker::new(Offline);
+let namespace = IRIRef::from(IRI::from_str("http://example.org/").unwrap());
+let report = checker.check(&make_graph(), &namespace);
+assert!(report.is_ok());
+```
+
+*/
+
+use rdftk_core::error::Result;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_iri::IRIRef;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The media types requested by default; Turtle, RDF/XML, JSON-LD, and N-Triples.
+///
+pub const DEFAULT_MEDIA_TYPES: &[&str] = &[
+    "text/turtle",
+    "application/rdf+xml",
+    "application/ld+json",
+    "application/n-triples",
+];
+
+///
+/// The HTTP method used to dereference an IRI.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    /// Request only the response headers.
+    Head,
+    /// Request the representation.
+    Get,
+}
+
+///
+/// The parts of an HTTP response used by the checker.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    status: u16,
+    content_type: Option<String>,
+}
+
+///
+/// Performs HTTP requests on behalf of the checker.
+///
+pub trait Fetcher {
+    ///
+    /// Request `iri` using `method`, with `accept` as the value of the `Accept` header. Redirects
+    /// are expected to be followed, and the final response returned. An error is returned if no
+    /// response was received.
+    ///
+    fn fetch(&self, method: Method, iri: &IRIRef, accept: &str) -> Result<Response>;
+}
+
+///
+/// A problem found when dereferencing an IRI.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    /// No response was received, with the reason given by the fetcher.
+    Unreachable(String),
+    /// The response status, after redirects, was not successful.
+    Status(u16),
+    /// A successful response was not of the requested media type.
+    NotNegotiated {
+        /// The media type requested.
+        requested: String,
+        /// The media type of the response, if any.
+        received: Option<String>,
+    },
+}
+
+///
+/// The result of checking a set of IRIs.
+///
+#[derive(Clone, Debug, Default)]
+pub struct DereferenceReport {
+    checked: usize,
+    problems: Vec<(IRIRef, Problem)>,
+}
+
+///
+/// Checks that IRIs may be dereferenced, with content negotiation, using a `Fetcher`.
+///
+#[derive(Debug)]
+pub struct DereferenceChecker<F: Fetcher> {
+    fetcher: F,
+    media_types: Vec<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Response {
+    ///
+    /// Create a new response with the given status code and the value of the `Content-Type`
+    /// header, if any.
+    ///
+    pub fn new(status: u16, content_type: Option<&str>) -> Self {
+        Self {
+            status,
+            content_type: content_type.map(|s| s.to_string()),
+        }
+    }
+
+    ///
+    /// Return the status code of this response.
+    ///
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    ///
+    /// Return the value of the `Content-Type` header of this response, if any.
+    ///
+    pub fn content_type(&self) -> Option<&String> {
+        self.content_type.as_ref()
+    }
+
+    ///
+    /// Returns `true` if the status code of this response is in the `2xx` range.
+    ///
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    ///
+    /// Returns `true` if the media type of this response, ignoring any parameters and case, is
+    /// `media_type`.
+    ///
+    pub fn is_media_type(&self, media_type: &str) -> bool {
+        match &self.content_type {
+            None => false,
+            Some(content_type) => content_type
+                .split(';')
+                .next()
+                .map(|s| s.trim().eq_ignore_ascii_case(media_type))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Unreachable(reason) => write!(f, "unreachable: {}", reason),
+            Problem::Status(status) => write!(f, "returned status {}", status),
+            Problem::NotNegotiated {
+                requested,
+                received: None,
+            } => write!(f, "requested {}, received no content type", requested),
+            Problem::NotNegotiated {
+                requested,
+                received: Some(received),
+            } => write!(f, "requested {}, received {}", requested, received),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for DereferenceReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "checked {} IRIs, {} problems",
+            self.checked,
+            self.problems.len()
+        )?;
+        for (iri, problem) in &self.problems {
+            writeln!(f, "<{}> {}", iri, problem)?;
+        }
+        Ok(())
+    }
+}
+
+impl DereferenceReport {
+    ///
+    /// Return the number of documents checked.
+    ///
+    pub fn checked(&self) -> usize {
+        self.checked
+    }
+
+    ///
+    /// Return all problems found, with the IRI requested.
+    ///
+    pub fn problems(&self) -> impl Iterator<Item = &(IRIRef, Problem)> {
+        self.problems.iter()
+    }
+
+    ///
+    /// Return the problems found for `iri`, which is compared without any fragment.
+    ///
+    pub fn problems_for(&self, iri: &IRIRef) -> impl Iterator<Item = &Problem> {
+        let iri = document(iri);
+        self.problems
+            .iter()
+            .filter(move |(problem_iri, _)| *problem_iri == iri)
+            .map(|(_, problem)| problem)
+    }
+
+    ///
+    /// Returns `true` if every IRI checked was dereferenced successfully.
+    ///
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F: Fetcher> DereferenceChecker<F> {
+    ///
+    /// Create a new checker, using `fetcher`, that requests each of the `DEFAULT_MEDIA_TYPES`.
+    ///
+    pub fn new(fetcher: F) -> Self {
+        Self {
+            fetcher,
+            media_types: DEFAULT_MEDIA_TYPES.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    ///
+    /// Replace the media types requested for each IRI.
+    ///
+    pub fn with_media_types(self, media_types: &[&str]) -> Self {
+        Self {
+            media_types: media_types.iter().map(|s| s.to_string()).collect(),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the media types requested for each IRI.
+    ///
+    pub fn media_types(&self) -> &Vec<String> {
+        &self.media_types
+    }
+
+    ///
+    /// Check all the subject IRIs in `graph` that start with `namespace`.
+    ///
+    pub fn check(&self, graph: &GraphRef, namespace: &IRIRef) -> DereferenceReport {
+        let namespace = namespace.to_string();
+        let graph = graph.borrow();
+        let iris: Vec<IRIRef> = graph
+            .subjects()
+            .into_iter()
+            .filter_map(|subject| subject.as_iri())
+            .filter(|iri| iri.to_string().starts_with(&namespace))
+            .cloned()
+            .collect();
+        self.check_iris(iris)
+    }
+
+    ///
+    /// Check each of the IRIs in `iris`.
+    ///
+    pub fn check_iris(&self, iris: impl IntoIterator<Item = IRIRef>) -> DereferenceReport {
+        let mut documents: Vec<IRIRef> = iris.into_iter().map(|iri| document(&iri)).collect();
+        documents.sort_by_cached_key(|iri| iri.to_string());
+        documents.dedup();
+
+        let mut report = DereferenceReport {
+            checked: documents.len(),
+            problems: Default::default(),
+        };
+        for iri in documents {
+            for media_type in &self.media_types {
+                match self.negotiate(&iri, media_type) {
+                    Ok(None) => {}
+                    Ok(Some(problem @ Problem::NotNegotiated { .. })) => {
+                        report.problems.push((iri.clone(), problem));
+                    }
+                    Ok(Some(problem)) => {
+                        // the document is missing, there is no point in requesting other types.
+                        report.problems.push((iri.clone(), problem));
+                        break;
+                    }
+                    Err(e) => {
+                        report
+                            .problems
+                            .push((iri.clone(), Problem::Unreachable(e.to_string())));
+                        break;
+                    }
+                }
+            }
+        }
+        report
+    }
+
+    fn negotiate(&self, iri: &IRIRef, media_type: &str) -> Result<Option<Problem>> {
+        let mut response = self.fetcher.fetch(Method::Head, iri, media_type)?;
+        if response.status() == 405 || response.status() == 501 {
+            response = self.fetcher.fetch(Method::Get, iri, media_type)?;
+        }
+        Ok(if !response.is_success() {
+            Some(Problem::Status(response.status()))
+        } else if !response.is_media_type(media_type) {
+            Some(Problem::NotNegotiated {
+                requested: media_type.to_string(),
+                received: response.content_type().cloned(),
+            })
+        } else {
+            None
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn document(iri: &IRIRef) -> IRIRef {
+    if iri.has_fragment() {
+        IRIRef::from(iri.without_fragment())
+    } else {
+        iri.clone()
+    }
+}
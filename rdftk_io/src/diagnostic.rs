@@ -0,0 +1,99 @@
+/*!
+Provides `Diagnostic`, a description of an invalid statement skipped by a reader in lenient mode.
+Rather than aborting on the first malformed statement, a lenient reader continues with the next,
+returning the statements it could read along with a diagnostic for each one it could not. Each
+diagnostic carries the line and column, both starting at 1, at which the error was found, the
+text of the offending line, and a message describing the error.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::nt::reader::NTriplesReader;
+
+let content = r#"<http://example.org/a> <http://example.org/b> "one" .
+<http://example.org/a> <http://example.org/b> .
+<http://example.org/a> <http://example.org/b> "three" .
+"#;
+
+let (graph, diagnostics) = NTriplesReader::default()
+    .read_with_diagnostics(&mut content.as_bytes(), graph_factory())
+    .unwrap();
+assert_eq!(graph.borrow().len(), 2);
+assert_eq!(diagnostics.len(), 1);
+assert_eq!(diagnostics[0].line(), 2);
+```
+
+*/
+
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A description of an invalid statement, with its position in the input.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    line: usize,
+    column: usize,
+    text: String,
+    message: String,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}: {}; in `{}`",
+            self.line, self.column, self.message, self.text
+        )
+    }
+}
+
+impl Diagnostic {
+    pub(crate) fn new(line: usize, column: usize, text: &str, message: &str) -> Self {
+        Self {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    ///
+    /// Return the line, starting at 1, of the invalid statement.
+    ///
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    ///
+    /// Return the column, starting at 1, at which the error was found; where the error is not
+    /// in the syntax of the statement this is the start of the statement.
+    ///
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    ///
+    /// Return the text of the line containing the invalid statement.
+    ///
+    pub fn text(&self) -> &String {
+        &self.text
+    }
+
+    ///
+    /// Return a description of the error.
+    ///
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
@@ -0,0 +1,466 @@
+/*!
+Provides `Format`, a registry of the built-in representations, so that an application may choose
+a reader or writer from an HTTP `Content-Type` or a file extension rather than naming the module
+of each representation. A format may be looked up by any of its media types, ignoring case and
+any parameters such as `charset`, or by any of its file extensions; the functions `reader_for`,
+`writer_for`, `reader_for_extension`, and `writer_for_extension` only return formats that are
+enabled by this crate's features and that support reading, or writing, respectively.
+
+Each format reads and writes using the default options of its reader and writer; where options
+are required the writer should be constructed directly from its module. A base IRI, against which
+relative IRIs are resolved, may be provided with `read_graph_with_base` and
+`read_data_set_with_base`. N-Quads and TriG are
+read as data sets, all other formats as graphs. Any format that writes a graph, other than TriG,
+may be used with `write_graph`, and N-Quads and TriG with `write_data_set`. Notation3 is not
+included as it has no reader or writer.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::format::{reader_for, writer_for_extension, Format};
+
+let format = reader_for("text/turtle; charset=utf-8").unwrap();
+assert_eq!(format, Format::Turtle);
+
+let content = "<http://example.org/a> <http://example.org/b> <http://example.org/c> .";
+let graph = format.read_graph(&mut content.as_bytes(), graph_factory()).unwrap();
+
+let format = writer_for_extension("nt").unwrap();
+let mut output: Vec<u8> = Default::default();
+format.write_graph(&mut output, &graph).unwrap();
+assert_eq!(
+    String::from_utf8(output).unwrap(),
+    "<http://example.org/a> <http://example.org/b> <http://example.org/c> .\n"
+);
+```
+
+*/
+
+use crate::compression::Compression;
+#[allow(unused_imports)] // << unused if no representations are enabled.
+use crate::{DataSetReader, DataSetWriter, GraphReader, GraphWriter};
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::data_set::{DataSetFactoryRef, DataSetRef};
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_iri::IRIRef;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+use std::path::Path;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The built-in representations.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// RDF 1.1 N-Triples, see the `nt` module.
+    NTriples,
+    /// RDF 1.1 N-Quads, see the `nq` module.
+    NQuads,
+    /// RDF 1.1 Turtle, see the `turtle` module.
+    Turtle,
+    /// RDF 1.1 TriG, see the `trig` module.
+    TriG,
+    /// RDF 1.1 XML Syntax, see the `xml` module.
+    Xml,
+    /// RDF 1.1 JSON Alternate Serialization, see the `json` module.
+    Json,
+    /// JSON-LD 1.1, see the `json_ld` module.
+    JsonLd,
+    /// GraphViz dot files, see the `dot` module.
+    Dot,
+}
+
+///
+/// All the built-in representations, whether or not they are enabled.
+///
+pub const ALL_FORMATS: &[Format] = &[
+    Format::NTriples,
+    Format::NQuads,
+    Format::Turtle,
+    Format::TriG,
+    Format::Xml,
+    Format::Json,
+    Format::JsonLd,
+    Format::Dot,
+];
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the enabled format, able to read a graph or a data set, for `media_type`.
+///
+pub fn reader_for(media_type: &str) -> Option<Format> {
+    Format::from_media_type(media_type).filter(|format| format.is_readable())
+}
+
+///
+/// Return the enabled format, able to read a graph or a data set, for the file extension `ext`.
+///
+pub fn reader_for_extension(ext: &str) -> Option<Format> {
+    Format::from_extension(ext).filter(|format| format.is_readable())
+}
+
+///
+/// Return the enabled format, able to write a graph or a data set, for `media_type`.
+///
+pub fn writer_for(media_type: &str) -> Option<Format> {
+    Format::from_media_type(media_type).filter(|format| format.is_writable())
+}
+
+///
+/// Return the enabled format, able to write a graph or a data set, for the file extension `ext`.
+///
+pub fn writer_for_extension(ext: &str) -> Option<Format> {
+    Format::from_extension(ext).filter(|format| format.is_writable())
+}
+
+///
+/// Return a value for the HTTP `Accept` header listing the media types of all the enabled formats
+/// able to read a graph.
+///
+pub fn graph_accept() -> String {
+    ALL_FORMATS
+        .iter()
+        .filter(|format| format.reads_graph())
+        .map(|format| format.mime_type())
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Format {
+    ///
+    /// Return the format with `media_type` as one of its media types; parameters, and the case of
+    /// the type, are ignored.
+    ///
+    pub fn from_media_type(media_type: &str) -> Option<Self> {
+        let media_type = media_type.split(';').next().unwrap_or_default().trim();
+        ALL_FORMATS.iter().copied().find(|format| {
+            format
+                .media_types()
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(media_type))
+        })
+    }
+
+    ///
+    /// Return the format with `ext`, with or without a leading `'.'`, as one of its file
+    /// extensions; case is ignored.
+    ///
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        let ext = ext.strip_prefix('.').unwrap_or(ext);
+        ALL_FORMATS.iter().copied().find(|format| {
+            format
+                .file_extensions()
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(ext))
+        })
+    }
+
+    ///
+    /// Return the format of the file at `path` from its extension; any compression extension,
+    /// such as `.gz`, is skipped so that `dump.nt.gz` is N-Triples.
+    ///
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let path = path.as_ref();
+        let path = if Compression::from_path(path) == Compression::None {
+            path.to_path_buf()
+        } else {
+            path.with_extension("")
+        };
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(Self::from_extension)
+    }
+
+    ///
+    /// Return the display name of this format.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NTriples => "N-Triples",
+            Self::NQuads => "N-Quads",
+            Self::Turtle => "Turtle",
+            Self::TriG => "TriG",
+            Self::Xml => "XML",
+            Self::Json => "JSON",
+            Self::JsonLd => "JSON-LD",
+            Self::Dot => "GraphViz",
+        }
+    }
+
+    ///
+    /// Return the media type used when writing this format.
+    ///
+    pub fn mime_type(&self) -> &'static str {
+        self.media_types()[0]
+    }
+
+    ///
+    /// Return all the media types recognized for this format, the first is the one used when
+    /// writing.
+    ///
+    pub fn media_types(&self) -> &'static [&'static str] {
+        match self {
+            Self::NTriples => &["application/n-triples"],
+            Self::NQuads => &["application/n-quads", "text/x-nquads"],
+            Self::Turtle => &["text/turtle", "application/x-turtle"],
+            Self::TriG => &["application/trig", "application/x-trig"],
+            Self::Xml => &["application/rdf+xml"],
+            Self::Json => &["application/rdf+json"],
+            Self::JsonLd => &["application/ld+json"],
+            Self::Dot => &["text/vnd.graphviz"],
+        }
+    }
+
+    ///
+    /// Return the common file extension, without the leading `'.'`, for this format.
+    ///
+    pub fn file_extension(&self) -> &'static str {
+        self.file_extensions()[0]
+    }
+
+    ///
+    /// Return all the file extensions recognized for this format, the first is the common one.
+    ///
+    pub fn file_extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::NTriples => &["nt"],
+            Self::NQuads => &["nq"],
+            Self::Turtle => &["ttl", "turtle"],
+            Self::TriG => &["trig"],
+            Self::Xml => &["rdf", "owl"],
+            Self::Json => &["json", "rj"],
+            Self::JsonLd => &["jsonld"],
+            Self::Dot => &["dot", "gv"],
+        }
+    }
+
+    ///
+    /// Returns `true` if the feature providing this format is enabled.
+    ///
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Self::NTriples => cfg!(feature = "nt"),
+            Self::NQuads => cfg!(feature = "nq"),
+            Self::Turtle => cfg!(feature = "turtle"),
+            Self::TriG => cfg!(feature = "trig"),
+            Self::Xml => cfg!(feature = "xml"),
+            Self::Json => cfg!(feature = "json"),
+            Self::JsonLd => cfg!(feature = "json-ld"),
+            Self::Dot => cfg!(feature = "dot"),
+        }
+    }
+
+    ///
+    /// Returns `true` if this format is enabled and may be read with `read_graph`.
+    ///
+    pub fn reads_graph(&self) -> bool {
+        self.is_enabled()
+            && matches!(
+                self,
+                Self::NTriples | Self::Turtle | Self::Xml | Self::Json | Self::JsonLd
+            )
+    }
+
+    ///
+    /// Returns `true` if this format is enabled and may be read with `read_data_set`.
+    ///
+    pub fn reads_data_set(&self) -> bool {
+        self.is_enabled() && matches!(self, Self::NQuads | Self::TriG)
+    }
+
+    ///
+    /// Returns `true` if this format is enabled and may be written with `write_graph`.
+    ///
+    pub fn writes_graph(&self) -> bool {
+        self.is_enabled() && !matches!(self, Self::TriG)
+    }
+
+    ///
+    /// Returns `true` if this format is enabled and may be written with `write_data_set`.
+    ///
+    pub fn writes_data_set(&self) -> bool {
+        self.is_enabled() && matches!(self, Self::NQuads | Self::TriG)
+    }
+
+    ///
+    /// Returns `true` if this format is enabled and may be read as a graph or a data set.
+    ///
+    pub fn is_readable(&self) -> bool {
+        self.reads_graph() || self.reads_data_set()
+    }
+
+    ///
+    /// Returns `true` if this format is enabled and may be written as a graph or a data set.
+    ///
+    pub fn is_writable(&self) -> bool {
+        self.writes_graph() || self.writes_data_set()
+    }
+
+    ///
+    /// Read a graph in this format, using the default reader, from `r`.
+    ///
+    pub fn read_graph(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<GraphRef> {
+        self.read_graph_with(r, factory, None)
+    }
+
+    ///
+    /// Read a graph in this format, using the default reader, from `r`, resolving relative IRIs
+    /// against `base`. The base is ignored by N-Triples and JSON, which only allow absolute IRIs.
+    ///
+    pub fn read_graph_with_base(
+        &self,
+        r: &mut impl Read,
+        factory: GraphFactoryRef,
+        base: &IRIRef,
+    ) -> Result<GraphRef> {
+        self.read_graph_with(r, factory, Some(base))
+    }
+
+    ///
+    /// Read a data set in this format, using the default reader, from `r`.
+    ///
+    pub fn read_data_set(
+        &self,
+        r: &mut impl Read,
+        factory: DataSetFactoryRef,
+    ) -> Result<DataSetRef> {
+        self.read_data_set_with(r, factory, None)
+    }
+
+    ///
+    /// Read a data set in this format, using the default reader, from `r`, resolving relative IRIs
+    /// against `base`. The base is ignored by N-Quads, which only allows absolute IRIs.
+    ///
+    pub fn read_data_set_with_base(
+        &self,
+        r: &mut impl Read,
+        factory: DataSetFactoryRef,
+        base: &IRIRef,
+    ) -> Result<DataSetRef> {
+        self.read_data_set_with(r, factory, Some(base))
+    }
+
+    ///
+    /// Write `graph` in this format, using the default writer, to `w`.
+    ///
+    pub fn write_graph(&self, w: &mut impl Write, graph: &GraphRef) -> Result<()> {
+        match self {
+            #[cfg(feature = "nt")]
+            Self::NTriples => crate::nt::writer::NTripleWriter::default().write(w, graph),
+            #[cfg(feature = "nq")]
+            Self::NQuads => crate::nq::writer::NQuadGraphWriter::default().write(w, graph),
+            #[cfg(feature = "turtle")]
+            Self::Turtle => crate::turtle::writer::TurtleWriter::default().write(w, graph),
+            #[cfg(feature = "xml")]
+            Self::Xml => crate::xml::writer::XmlWriter::default().write(w, graph),
+            #[cfg(feature = "json")]
+            Self::Json => crate::json::writer::JsonWriter::default().write(w, graph),
+            #[cfg(feature = "json-ld")]
+            Self::JsonLd => crate::json_ld::writer::JsonLdWriter::default().write(w, graph),
+            #[cfg(feature = "dot")]
+            Self::Dot => crate::dot::writer::DotWriter::default().write(w, graph),
+            _ => {
+                let _ = (w, graph);
+                Err(self.not_supported("write a graph"))
+            }
+        }
+    }
+
+    ///
+    /// Write `data_set` in this format, using the default writer, to `w`.
+    ///
+    pub fn write_data_set(&self, w: &mut impl Write, data_set: &DataSetRef) -> Result<()> {
+        match self {
+            #[cfg(feature = "nq")]
+            Self::NQuads => crate::nq::writer::NQuadDataSetWriter::default().write(w, data_set),
+            #[cfg(feature = "trig")]
+            Self::TriG => crate::trig::writer::TriGWriter::default().write(w, data_set),
+            _ => {
+                let _ = (w, data_set);
+                Err(self.not_supported("write a data set"))
+            }
+        }
+    }
+
+    fn read_graph_with(
+        &self,
+        r: &mut impl Read,
+        factory: GraphFactoryRef,
+        base: Option<&IRIRef>,
+    ) -> Result<GraphRef> {
+        let _ = (&factory, base);
+        match self {
+            #[cfg(feature = "nt")]
+            Self::NTriples => crate::nt::reader::NTriplesReader::default().read(r, factory),
+            #[cfg(feature = "turtle")]
+            Self::Turtle => base
+                .cloned()
+                .map(crate::turtle::reader::TurtleReader::with_base)
+                .unwrap_or_default()
+                .read(r, factory),
+            #[cfg(feature = "xml")]
+            Self::Xml => base
+                .cloned()
+                .map(crate::xml::reader::XmlReader::with_base)
+                .unwrap_or_default()
+                .read(r, factory),
+            #[cfg(feature = "json")]
+            Self::Json => crate::json::reader::JsonReader::default().read(r, factory),
+            #[cfg(feature = "json-ld")]
+            Self::JsonLd => base
+                .cloned()
+                .map(crate::json_ld::reader::JsonLdReader::with_base)
+                .unwrap_or_default()
+                .read(r, factory),
+            _ => {
+                let _ = r;
+                Err(self.not_supported("read a graph"))
+            }
+        }
+    }
+
+    fn read_data_set_with(
+        &self,
+        r: &mut impl Read,
+        factory: DataSetFactoryRef,
+        base: Option<&IRIRef>,
+    ) -> Result<DataSetRef> {
+        let _ = (&factory, base);
+        match self {
+            #[cfg(feature = "nq")]
+            Self::NQuads => crate::nq::reader::NQuadDataSetReader::default().read(r, factory),
+            #[cfg(feature = "trig")]
+            Self::TriG => base
+                .cloned()
+                .map(crate::trig::reader::TriGReader::with_base)
+                .unwrap_or_default()
+                .read(r, factory),
+            _ => {
+                let _ = r;
+                Err(self.not_supported("read a data set"))
+            }
+        }
+    }
+
+    fn not_supported(&self, operation: &str) -> rdftk_core::error::Error {
+        ErrorKind::OperationNotSupported(operation.to_string(), self.name().to_string()).into()
+    }
+}
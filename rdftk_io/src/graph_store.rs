@@ -0,0 +1,366 @@
+/*!
+Provides `GraphStoreClient`, a client for the
+[SPARQL 1.1 Graph Store HTTP Protocol](https://www.w3.org/TR/sparql11-http-rdf-update/), which
+retrieves, replaces, adds to, and deletes the graphs of a remote graph store.
+
+Graphs are identified indirectly, by the `graph` parameter of the query string of a request to the
+graph store endpoint, or as the store's default graph by the `default` parameter. A `GET` request
+accepts any representation enabled by this crate's features that may be read as a graph, and the
+response is read using the reader, from the `format` module, for its `Content-Type`. The bodies of
+`PUT` and `POST` requests are written in the client's format, Turtle by default, using the writer
+from the `format` module.
+
+As with the `sparql_client` module requests are made through an implementation of the `Transport`
+trait, such as the `HttpTransport` of the `http` module (the `http` feature), and responses use the
+`Response` type of the `sparql_client` module. A graph that the store reports as not found, with the
+status `404`, results in an `UnknownGraph` error. Closures of the form
+`Fn(&IRIRef, &Request) -> Result<Response>` implement `Transport`.
+
+# Example
+
+```rust
+use rdftk_core::error::Result;
+use rdftk_io::graph_store::{GraphStoreClient, Method, Request};
+use rdftk_io::sparql_client::Response;
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+let transport = |_: &IRIRef, request: &Request| -> Result<Response> {
+    assert_eq!(request.method(), Method::Get);
+    assert_eq!(
+        request.query_string(),
+        "graph=http%3A%2F%2Fexample.org%2Fpeople"
+    );
+    Ok(Response::new(
+        200,
+        Some("text/turtle"),
+        b"<http://example.org/alice> <http://xmlns.com/foaf/0.1/name> \"Alice\" .".to_vec(),
+    ))
+};
+
+let endpoint = IRIRef::from(IRI::from_str("http://example.org/store").unwrap());
+let client = GraphStoreClient::new(endpoint, transport);
+let people = IRIRef::from(IRI::from_str("http://example.org/people").unwrap());
+let graph = client.get(Some(&people)).unwrap();
+assert_eq!(graph.borrow().len(), 1);
+```
+
+*/
+
+use crate::format::{graph_accept, reader_for, Format};
+use crate::sparql_client::{percent_encode, Response};
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_iri::IRIRef;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The display name of this client.
+///
+pub const NAME: &str = "SPARQL Graph Store Protocol";
+
+///
+/// The HTTP method of a request to the graph store.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Method {
+    /// Retrieve a graph.
+    Get,
+    /// Replace a graph, creating it if necessary.
+    Put,
+    /// Add statements to a graph, creating it if necessary.
+    Post,
+    /// Remove a graph.
+    Delete,
+}
+
+///
+/// A request to be sent to a graph store.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    method: Method,
+    graph: Option<IRIRef>,
+    accept: Option<String>,
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+///
+/// Performs HTTP requests on behalf of the client.
+///
+pub trait Transport {
+    ///
+    /// Send `request` to the graph store at `endpoint`, appending the query string of the request
+    /// to the endpoint IRI. Redirects are expected to be followed, and the final response returned.
+    /// An error is returned if no response was received.
+    ///
+    fn send(&self, endpoint: &IRIRef, request: &Request) -> Result<Response>;
+}
+
+///
+/// A client for a single graph store, using a `Transport`.
+///
+#[derive(Debug)]
+pub struct GraphStoreClient<T: Transport> {
+    endpoint: IRIRef,
+    transport: T,
+    factory: GraphFactoryRef,
+    format: Format,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Method {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Get => "GET",
+                Self::Put => "PUT",
+                Self::Post => "POST",
+                Self::Delete => "DELETE",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Request {
+    ///
+    /// Create a new request, without a body, for `graph`, or for the default graph if `None`.
+    ///
+    pub fn new(method: Method, graph: Option<&IRIRef>) -> Self {
+        Self {
+            method,
+            graph: graph.cloned(),
+            accept: None,
+            content_type: None,
+            body: Default::default(),
+        }
+    }
+
+    ///
+    /// Set the value of the `Accept` header for this request.
+    ///
+    pub fn with_accept(self, accept: &str) -> Self {
+        Self {
+            accept: Some(accept.to_string()),
+            ..self
+        }
+    }
+
+    ///
+    /// Set the body of this request, and the media type of the body as the value of the
+    /// `Content-Type` header.
+    ///
+    pub fn with_body(self, content_type: &str, body: Vec<u8>) -> Self {
+        Self {
+            content_type: Some(content_type.to_string()),
+            body,
+            ..self
+        }
+    }
+
+    ///
+    /// Return the HTTP method of this request.
+    ///
+    pub fn method(&self) -> Method {
+        self.method
+    }
+
+    ///
+    /// Return the IRI of the graph, or `None` for the default graph.
+    ///
+    pub fn graph(&self) -> Option<&IRIRef> {
+        self.graph.as_ref()
+    }
+
+    ///
+    /// Return the value of the `Accept` header for this request, if any.
+    ///
+    pub fn accept(&self) -> Option<&String> {
+        self.accept.as_ref()
+    }
+
+    ///
+    /// Return the value of the `Content-Type` header for this request, if it has a body.
+    ///
+    pub fn content_type(&self) -> Option<&String> {
+        self.content_type.as_ref()
+    }
+
+    ///
+    /// Return the body of this request, which is empty for `GET` and `DELETE` requests.
+    ///
+    pub fn body(&self) -> &Vec<u8> {
+        &self.body
+    }
+
+    ///
+    /// Return the query string identifying the graph; either `graph=` and the encoded IRI of the
+    /// graph, or `default` for the default graph.
+    ///
+    pub fn query_string(&self) -> String {
+        match &self.graph {
+            Some(graph) => format!("graph={}", percent_encode(&graph.to_string())),
+            None => "default".to_string(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F> Transport for F
+where
+    F: Fn(&IRIRef, &Request) -> Result<Response>,
+{
+    fn send(&self, endpoint: &IRIRef, request: &Request) -> Result<Response> {
+        self(endpoint, request)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T: Transport> GraphStoreClient<T> {
+    ///
+    /// Create a new client for the graph store at `endpoint`; retrieved graphs are created with
+    /// the simple graph factory.
+    ///
+    pub fn new(endpoint: IRIRef, transport: T) -> Self {
+        Self::with_factory(endpoint, transport, graph_factory())
+    }
+
+    ///
+    /// Create a new client for the graph store at `endpoint`; retrieved graphs are created with
+    /// `factory`.
+    ///
+    pub fn with_factory(endpoint: IRIRef, transport: T, factory: GraphFactoryRef) -> Self {
+        Self {
+            endpoint,
+            transport,
+            factory,
+            format: Format::Turtle,
+        }
+    }
+
+    ///
+    /// Set the format used to write the bodies of `PUT` and `POST` requests, returning an error
+    /// if the format is not enabled or may not be written as a graph.
+    ///
+    pub fn with_format(self, format: Format) -> Result<Self> {
+        if format.writes_graph() {
+            Ok(Self { format, ..self })
+        } else {
+            Err(
+                ErrorKind::OperationNotSupported("write graph".to_string(), format.to_string())
+                    .into(),
+            )
+        }
+    }
+
+    ///
+    /// Return the IRI of the graph store endpoint.
+    ///
+    pub fn endpoint(&self) -> &IRIRef {
+        &self.endpoint
+    }
+
+    ///
+    /// Return the transport used to send requests.
+    ///
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    ///
+    /// Return the format used to write the bodies of `PUT` and `POST` requests.
+    ///
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    ///
+    /// Retrieve `graph`, or the default graph if `None`.
+    ///
+    pub fn get(&self, graph: Option<&IRIRef>) -> Result<GraphRef> {
+        let response = self.send(Request::new(Method::Get, graph).with_accept(&graph_accept()))?;
+        match response.content_type().and_then(|s| reader_for(s)) {
+            Some(format) if format.reads_graph() => {
+                format.read_graph(&mut response.body().as_slice(), self.factory.clone())
+            }
+            _ => Err(read_error(&format!(
+                "unsupported response content type {:?}",
+                response.content_type()
+            ))),
+        }
+    }
+
+    ///
+    /// Replace the content of `graph`, or the default graph if `None`, with `content`.
+    ///
+    pub fn put(&self, graph: Option<&IRIRef>, content: &GraphRef) -> Result<()> {
+        self.send_graph(Method::Put, graph, content)
+    }
+
+    ///
+    /// Add the statements in `content` to `graph`, or the default graph if `None`.
+    ///
+    pub fn post(&self, graph: Option<&IRIRef>, content: &GraphRef) -> Result<()> {
+        self.send_graph(Method::Post, graph, content)
+    }
+
+    ///
+    /// Remove `graph`, or all the statements in the default graph if `None`.
+    ///
+    pub fn delete(&self, graph: Option<&IRIRef>) -> Result<()> {
+        let _ = self.send(Request::new(Method::Delete, graph))?;
+        Ok(())
+    }
+
+    fn send_graph(&self, method: Method, graph: Option<&IRIRef>, content: &GraphRef) -> Result<()> {
+        let mut body: Vec<u8> = Default::default();
+        self.format.write_graph(&mut body, content)?;
+        let request = Request::new(method, graph).with_body(self.format.mime_type(), body);
+        let _ = self.send(request)?;
+        Ok(())
+    }
+
+    fn send(&self, request: Request) -> Result<Response> {
+        let response = self.transport.send(&self.endpoint, &request)?;
+        if response.is_success() {
+            Ok(response)
+        } else if response.status() == 404 {
+            Err(ErrorKind::UnknownGraph(match request.graph() {
+                Some(graph) => graph.to_string(),
+                None => "default".to_string(),
+            })
+            .into())
+        } else {
+            Err(read_error(&format!(
+                "{} of graph {} returned status {}",
+                request.method(),
+                request.query_string(),
+                response.status()
+            )))
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn read_error(message: &str) -> rdftk_core::error::Error {
+    error!("GraphStoreClient {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
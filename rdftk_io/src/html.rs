@@ -0,0 +1,163 @@
+/*!
+Provides the `HtmlEmbeddingWriter`, an implementation of the `GraphWriter` trait that wraps the
+output of another writer in an HTML `<script>` element, so that static site generators can embed
+structured data directly in a page. JSON-LD is embedded in a `<script type="application/ld+json">`
+block, as recognized by search engines, and Turtle in a `<script type="text/turtle">` data block;
+any other writer may be embedded with the MIME type of its output.
+
+The content of a `<script>` element is not decoded by an HTML parser, so entity escaping would
+change the embedded data. Instead the sequences `</` and `<!--`, which could end the element
+early, are rewritten using the escapes of the embedded format; as `<\/` and `\u003C!--` in
+JSON, and as `<\u002F` and `<\u0021--` in Turtle and N-Triples, where these sequences may only
+occur within strings and IRIs. Attribute values, the MIME type and any `id`, are HTML escaped.
+
+# Example
+
+```rust
+use rdftk_io::html::HtmlEmbeddingWriter;
+use rdftk_io::json_ld::writer::{JsonLdOptions, JsonLdWriter};
+use rdftk_io::write_graph_to_string;
+# use rdftk_core::model::graph::GraphRef;
+# fn make_graph() -> GraphRef { rdftk_core::simple::graph::graph_factory().graph() }
+
+let writer = HtmlEmbeddingWriter::json_ld(JsonLdWriter::new(JsonLdOptions::compacted()));
+let result = write_graph_to_string(&writer, &make_graph()).unwrap();
+assert!(result.starts_with(r#"<script type="application/ld+json">"#));
+assert!(result.ends_with("</script>\n"));
+```
+
+*/
+
+use crate::GraphWriter;
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::GraphRef;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The display name of this serialization format.
+///
+pub const NAME: &str = "HTML";
+
+///
+/// The common file extension for this serialization format.
+///
+pub const FILE_EXTENSION: &str = "html";
+
+///
+/// The MIME type used for this serialization format.
+///
+pub const MIME_TYPE: &str = "text/html";
+
+///
+/// A writer that wraps the output of the wrapped writer in an HTML `<script>` element.
+///
+#[derive(Debug)]
+pub struct HtmlEmbeddingWriter<W: GraphWriter> {
+    writer: W,
+    script_type: String,
+    id: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<W: GraphWriter> GraphWriter for HtmlEmbeddingWriter<W> {
+    fn write(&self, w: &mut impl Write, graph: &GraphRef) -> Result<()> {
+        let mut buffer: Vec<u8> = Default::default();
+        self.writer.write(&mut buffer, graph)?;
+        let content = String::from_utf8(buffer)
+            .map_err(|e| Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string())))?;
+        let content = if is_json(&self.script_type) {
+            content.replace("</", "<\\/").replace("<!--", "\\u003C!--")
+        } else {
+            content
+                .replace("</", "<\\u002F")
+                .replace("<!--", "<\\u0021--")
+        };
+
+        write!(
+            w,
+            "<script type=\"{}\"",
+            escape_attribute(&self.script_type)
+        )
+        .map_err(io_error)?;
+        if let Some(id) = &self.id {
+            write!(w, " id=\"{}\"", escape_attribute(id)).map_err(io_error)?;
+        }
+        writeln!(w, ">").map_err(io_error)?;
+        w.write_all(content.trim().as_bytes()).map_err(io_error)?;
+        writeln!(w, "\n</script>").map_err(io_error)
+    }
+}
+
+impl<W: GraphWriter> HtmlEmbeddingWriter<W> {
+    ///
+    /// Create a new writer that embeds the output of `writer`, which is of the MIME type
+    /// `script_type`.
+    ///
+    pub fn new(writer: W, script_type: &str) -> Self {
+        Self {
+            writer,
+            script_type: script_type.to_string(),
+            id: None,
+        }
+    }
+
+    ///
+    /// Create a new writer that embeds the JSON-LD output of `writer`.
+    ///
+    pub fn json_ld(writer: W) -> Self {
+        Self::new(writer, "application/ld+json")
+    }
+
+    ///
+    /// Create a new writer that embeds the Turtle output of `writer` as a data block.
+    ///
+    pub fn turtle(writer: W) -> Self {
+        Self::new(writer, "text/turtle")
+    }
+
+    ///
+    /// Set the `id` attribute of the `<script>` element, so that it may be found by scripts on the
+    /// page.
+    ///
+    pub fn with_id(self, id: &str) -> Self {
+        Self {
+            id: Some(id.to_string()),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the MIME type of the embedded content.
+    ///
+    pub fn script_type(&self) -> &String {
+        &self.script_type
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_json(script_type: &str) -> bool {
+    let script_type = script_type.to_lowercase();
+    script_type.ends_with("/json") || script_type.ends_with("+json")
+}
+
+fn escape_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string()))
+}
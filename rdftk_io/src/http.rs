@@ -0,0 +1,300 @@
+/*!
+Provides `HttpTransport`, an HTTP client using [ureq](https://crates.io/crates/ureq), which
+implements the `Transport` traits of the `loader`, `sparql_client`, and `graph_store` modules and
+the `Fetcher` trait of the `dereference` module, so that these may be used without providing an
+HTTP client of your own. The transport for each module is available when that module's feature is
+enabled along with the `http` feature.
+
+As each trait expects, requests sent for the `loader` do not follow redirects, leaving the loader to
+follow them itself, while requests for the other modules follow up to `max_redirects` redirects
+and return the final response. Every request is limited to `timeout`, or for the loader to the time
+remaining for the IRI being loaded, and the body of a response for the loader is read no further
+than one byte beyond the limit of the request, so that a larger body is reported by the loader
+without being read in full. A response with an error status, `4xx` or `5xx`, is returned as a
+response rather than an error so that each client may report it; an error is only returned if no
+response was received.
+
+# Example
+
+```rust,no_run
+use rdftk_io::dereference::DereferenceChecker;
+use rdftk_io::http::HttpTransport;
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+use std::time::Duration;
+
+let transport = HttpTransport::default().with_timeout(Duration::from_secs(10));
+let checker = DereferenceChecker::new(transport);
+let foaf = IRIRef::from(IRI::from_str("http://xmlns.com/foaf/0.1/").unwrap());
+let report = checker.check_iris(vec![foaf]);
+assert!(report.is_ok());
+```
+
+*/
+
+use crate::dereference::{self, Fetcher};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_iri::IRIRef;
+use std::io::Read;
+use std::time::Duration;
+use ureq::{Agent, AgentBuilder};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The default limit on the time taken by each request.
+///
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+///
+/// The default limit on the number of redirects followed, for those clients that expect the
+/// transport to follow redirects.
+///
+pub const DEFAULT_MAX_REDIRECTS: u32 = 10;
+
+///
+/// The value of the `User-Agent` header sent by default.
+///
+pub const DEFAULT_USER_AGENT: &str = concat!("rdftk_io/", env!("CARGO_PKG_VERSION"));
+
+///
+/// An HTTP client implementing the transport traits of this crate; see the module documentation.
+///
+#[derive(Clone, Debug)]
+pub struct HttpTransport {
+    following: Agent,
+    direct: Agent,
+    timeout: Duration,
+    max_redirects: u32,
+    user_agent: String,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        Self::with_agents(DEFAULT_TIMEOUT, DEFAULT_MAX_REDIRECTS, DEFAULT_USER_AGENT)
+    }
+}
+
+impl HttpTransport {
+    ///
+    /// Set the limit on the time taken by each request.
+    ///
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    ///
+    /// Set the limit on the number of redirects followed.
+    ///
+    pub fn with_max_redirects(self, max_redirects: u32) -> Self {
+        Self::with_agents(self.timeout, max_redirects, &self.user_agent)
+    }
+
+    ///
+    /// Set the value of the `User-Agent` header sent with each request.
+    ///
+    pub fn with_user_agent(self, user_agent: &str) -> Self {
+        Self::with_agents(self.timeout, self.max_redirects, user_agent)
+    }
+
+    ///
+    /// Return the limit on the time taken by each request.
+    ///
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    ///
+    /// Return the limit on the number of redirects followed.
+    ///
+    pub fn max_redirects(&self) -> u32 {
+        self.max_redirects
+    }
+
+    ///
+    /// Return the value of the `User-Agent` header sent with each request.
+    ///
+    pub fn user_agent(&self) -> &String {
+        &self.user_agent
+    }
+
+    fn with_agents(timeout: Duration, max_redirects: u32, user_agent: &str) -> Self {
+        let agent = |redirects| {
+            AgentBuilder::new()
+                .redirects(redirects)
+                .user_agent(user_agent)
+                .build()
+        };
+        Self {
+            following: agent(max_redirects),
+            direct: agent(0),
+            timeout,
+            max_redirects,
+            user_agent: user_agent.to_string(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Fetcher for HttpTransport {
+    fn fetch(
+        &self,
+        method: dereference::Method,
+        iri: &IRIRef,
+        accept: &str,
+    ) -> Result<dereference::Response> {
+        let url = iri.to_string();
+        let method = match method {
+            dereference::Method::Head => "HEAD",
+            dereference::Method::Get => "GET",
+        };
+        let response = send(
+            self.following
+                .request(method, &url)
+                .timeout(self.timeout)
+                .set("Accept", accept),
+            None,
+            &url,
+        )?;
+        Ok(dereference::Response::new(
+            response.status(),
+            response.header("Content-Type"),
+        ))
+    }
+}
+
+#[cfg(feature = "sparql-client")]
+impl crate::sparql_client::Transport for HttpTransport {
+    fn send(
+        &self,
+        endpoint: &IRIRef,
+        request: &crate::sparql_client::Request,
+    ) -> Result<crate::sparql_client::Response> {
+        let url = endpoint.to_string();
+        let response = send(
+            self.following
+                .post(&url)
+                .timeout(self.timeout)
+                .set("Accept", request.accept())
+                .set("Content-Type", request.content_type()),
+            Some(request.query().as_bytes()),
+            &url,
+        )?;
+        let (status, content_type, body) = read_response(response, u64::MAX, &url)?;
+        Ok(crate::sparql_client::Response::new(
+            status,
+            content_type.as_deref(),
+            body,
+        ))
+    }
+}
+
+#[cfg(feature = "graph-store")]
+impl crate::graph_store::Transport for HttpTransport {
+    fn send(
+        &self,
+        endpoint: &IRIRef,
+        request: &crate::graph_store::Request,
+    ) -> Result<crate::sparql_client::Response> {
+        let endpoint = endpoint.to_string();
+        let separator = if endpoint.contains('?') { '&' } else { '?' };
+        let url = format!("{}{}{}", endpoint, separator, request.query_string());
+        let mut http_request = self
+            .following
+            .request(&request.method().to_string(), &url)
+            .timeout(self.timeout);
+        if let Some(accept) = request.accept() {
+            http_request = http_request.set("Accept", accept);
+        }
+        let body = match request.content_type() {
+            Some(content_type) => {
+                http_request = http_request.set("Content-Type", content_type);
+                Some(request.body().as_slice())
+            }
+            None => None,
+        };
+        let response = send(http_request, body, &url)?;
+        let (status, content_type, body) = read_response(response, u64::MAX, &url)?;
+        Ok(crate::sparql_client::Response::new(
+            status,
+            content_type.as_deref(),
+            body,
+        ))
+    }
+}
+
+#[cfg(feature = "loader")]
+impl crate::loader::Transport for HttpTransport {
+    fn get(&self, request: &crate::loader::Request) -> Result<crate::loader::Response> {
+        let url = request.iri().to_string();
+        let response = send(
+            self.direct
+                .get(&url)
+                .timeout(request.timeout())
+                .set("Accept", request.accept()),
+            None,
+            &url,
+        )?;
+        let location = response.header("Location").map(str::to_string);
+        let (status, content_type, body) =
+            read_response(response, request.max_size() as u64 + 1, &url)?;
+        let response = crate::loader::Response::new(status, content_type.as_deref(), body);
+        Ok(match location {
+            Some(location) => response.with_location(&location),
+            None => response,
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Send `request`, with `body` if any, returning a response with an error status as a response.
+///
+fn send(request: ureq::Request, body: Option<&[u8]>, url: &str) -> Result<ureq::Response> {
+    let result = match body {
+        Some(body) => request.send_bytes(body),
+        None => request.call(),
+    };
+    match result {
+        Ok(response) => Ok(response),
+        Err(ureq::Error::Status(_, response)) => Ok(response),
+        Err(ureq::Error::Transport(e)) => Err(Error::with_chain(
+            e,
+            ErrorKind::Msg(format!("no response was received from {}", url)),
+        )),
+    }
+}
+
+///
+/// Return the status, `Content-Type`, and at most `limit` bytes of the body of `response`.
+///
+fn read_response(
+    response: ureq::Response,
+    limit: u64,
+    url: &str,
+) -> Result<(u16, Option<String>, Vec<u8>)> {
+    let status = response.status();
+    let content_type = response.header("Content-Type").map(str::to_string);
+    let mut body = Vec::new();
+    let _ = response
+        .into_reader()
+        .take(limit)
+        .read_to_end(&mut body)
+        .map_err(|e| {
+            Error::with_chain(
+                e,
+                ErrorKind::Msg(format!("the response from {} could not be read", url)),
+            )
+        })?;
+    Ok((status, content_type, body))
+}
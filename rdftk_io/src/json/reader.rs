@@ -5,6 +5,8 @@ Provides the `JsonReader` implementation of the `GraphReader` trait.
 
 */
 
+use crate::conformance::conform;
+use crate::format::Format;
 use crate::json::syntax::{
     BNODE_PREFIX, OBJ_KEY_DATATYPE, OBJ_KEY_LANG, OBJ_KEY_TYPE, OBJ_KEY_VALUE, OBJ_TYPE_BNODE,
     OBJ_TYPE_LITERAL, OBJ_TYPE_URI,
@@ -26,9 +28,13 @@ use std::str::FromStr;
 
 ///
 /// An implementation of the GraphReader trait to read resources in the JSON representation.
+/// If `strict` is set the document is first checked for conformance, see the `conformance`
+/// module, and any violation aborts the read.
 ///
 #[derive(Clone, Debug)]
-pub struct JsonReader {}
+pub struct JsonReader {
+    strict: bool,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
@@ -44,13 +50,38 @@ pub struct JsonReader {}
 
 impl Default for JsonReader {
     fn default() -> Self {
-        Self {}
+        Self { strict: false }
+    }
+}
+
+impl JsonReader {
+    ///
+    /// If `strict` is `true`, the document is checked for conformance before it is parsed, and
+    /// any violation aborts the read.
+    ///
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict }
+    }
+
+    ///
+    /// Returns `true` if the document is checked for conformance before it is parsed.
+    ///
+    pub fn is_strict(&self) -> bool {
+        self.strict
     }
 }
 
 impl GraphReader for JsonReader {
     fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<GraphRef> {
-        let value: Value = serde_json::from_reader(r).map_err(|e| {
+        let value: Value = if self.strict {
+            let mut content: String = String::new();
+            let _ = r.read_to_string(&mut content).map_err(io_error)?;
+            conform(Format::Json, &content)?;
+            serde_json::from_str(&content)
+        } else {
+            serde_json::from_reader(r)
+        }
+        .map_err(|e| {
             rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
         })?;
         parse_graph(value, factory)
@@ -221,6 +252,10 @@ fn parse_uri_object(
     }
 }
 
+fn io_error(e: std::io::Error) -> rdftk_core::error::Error {
+    rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -0,0 +1,427 @@
+/*!
+Provides the active context used when interpreting a JSON-LD document, as described in
+[Context Processing Algorithms](https://www.w3.org/TR/json-ld11-api/#context-processing-algorithms).
+
+Only local, inline, contexts are supported; a reference to a remote context or an `@import` is
+an error.
+
+*/
+
+use crate::common::parser::resolve;
+use crate::json_ld::read_error;
+use crate::json_ld::syntax::{
+    BNODE_PREFIX, GEN_DELIMS, KW_BASE, KW_CONTAINER, KW_CONTEXT, KW_ID, KW_IMPORT, KW_LANGUAGE,
+    KW_PREFIX, KW_REVERSE, KW_TYPE, KW_VOCAB,
+};
+use rdftk_core::error::Result;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The active context; the base IRI, default vocabulary and language, and term definitions.
+///
+#[derive(Clone, Debug, Default)]
+pub(super) struct Context {
+    original_base: Option<String>,
+    base: Option<String>,
+    vocab: Option<String>,
+    language: Option<String>,
+    terms: HashMap<String, TermDefinition>,
+}
+
+///
+/// A single term definition, a term mapped to `null` has no IRI and is ignored.
+///
+#[derive(Clone, Debug, Default)]
+pub(super) struct TermDefinition {
+    iri: Option<String>,
+    reverse: bool,
+    prefix: bool,
+    type_mapping: Option<String>,
+    language: Option<Option<String>>,
+    container: Vec<String>,
+    context: Option<Value>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Context {
+    pub(super) fn with_base(base: Option<String>) -> Self {
+        Self {
+            original_base: base.clone(),
+            base,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Return a new active context that is the result of applying `local` to this one.
+    ///
+    pub(super) fn process(&self, local: &Value) -> Result<Self> {
+        let mut result = self.clone();
+        let contexts = match local {
+            Value::Array(contexts) => contexts.iter().collect(),
+            context => vec![context],
+        };
+        for context in contexts {
+            match context {
+                Value::Null => result = Self::with_base(self.original_base.clone()),
+                Value::String(iri) => {
+                    return Err(read_error(&format!(
+                        "remote contexts are not supported, found {:?}",
+                        iri
+                    )))
+                }
+                Value::Object(definitions) => result.process_definitions(definitions)?,
+                _ => return Err(read_error("a local context must be an object")),
+            }
+        }
+        Ok(result)
+    }
+
+    ///
+    /// Expand a term, compact IRI, or relative IRI, returning `None` if the value does not
+    /// expand to a keyword, an absolute IRI, or a blank node identifier.
+    ///
+    pub(super) fn expand_iri(
+        &self,
+        value: &str,
+        document_relative: bool,
+        vocab: bool,
+    ) -> Option<String> {
+        if value.starts_with('@') {
+            return Some(value.to_string());
+        }
+        if vocab {
+            if let Some(definition) = self.terms.get(value) {
+                return definition.iri.clone();
+            }
+        }
+        if let Some(index) = value.find(':') {
+            let (prefix, suffix) = (&value[..index], &value[index + 1..]);
+            if value.starts_with(BNODE_PREFIX) || suffix.starts_with("//") {
+                return Some(value.to_string());
+            }
+            if let Some(TermDefinition { iri: Some(iri), .. }) = self.terms.get(prefix) {
+                return Some(format!("{}{}", iri, suffix));
+            }
+            return Some(value.to_string());
+        }
+        if vocab {
+            if let Some(vocab) = &self.vocab {
+                return Some(format!("{}{}", vocab, value));
+            }
+        }
+        if document_relative {
+            if let Some(base) = &self.base {
+                return Some(resolve(base, value));
+            }
+        }
+        None
+    }
+
+    pub(super) fn term(&self, term: &str) -> Option<&TermDefinition> {
+        self.terms.get(term)
+    }
+
+    pub(super) fn language(&self) -> Option<&String> {
+        self.language.as_ref()
+    }
+
+    ///
+    /// Return the prefix and namespace pairs for all terms that may be used as prefixes, and the
+    /// default vocabulary with an empty prefix.
+    ///
+    pub(super) fn prefixes(&self) -> Vec<(String, String)> {
+        let mut prefixes: Vec<(String, String)> = self
+            .terms
+            .iter()
+            .filter(|(_, definition)| definition.prefix)
+            .filter_map(|(term, definition)| {
+                definition
+                    .iri
+                    .as_ref()
+                    .map(|iri| (term.clone(), iri.clone()))
+            })
+            .collect();
+        if let Some(vocab) = &self.vocab {
+            prefixes.push((String::new(), vocab.clone()));
+        }
+        prefixes
+    }
+
+    fn process_definitions(&mut self, definitions: &Map<String, Value>) -> Result<()> {
+        if definitions.contains_key(KW_IMPORT) {
+            return Err(read_error("@import is not supported"));
+        }
+        match definitions.get(KW_BASE) {
+            None => {}
+            Some(Value::Null) => self.base = None,
+            Some(Value::String(base)) => {
+                self.base = Some(match &self.base {
+                    Some(current) if !base.contains(':') => resolve(current, base),
+                    _ => base.clone(),
+                })
+            }
+            Some(_) => return Err(read_error("@base must be a string or null")),
+        }
+        match definitions.get(KW_VOCAB) {
+            None => {}
+            Some(Value::Null) => self.vocab = None,
+            Some(Value::String(vocab)) => match self.expand_iri(vocab, true, true) {
+                Some(vocab) => self.vocab = Some(vocab),
+                None => return Err(read_error(&format!("invalid @vocab {:?}", vocab))),
+            },
+            Some(_) => return Err(read_error("@vocab must be a string or null")),
+        }
+        match definitions.get(KW_LANGUAGE) {
+            None => {}
+            Some(Value::Null) => self.language = None,
+            Some(Value::String(language)) => self.language = Some(language.clone()),
+            Some(_) => return Err(read_error("@language must be a string or null")),
+        }
+        let mut defined: HashMap<String, bool> = Default::default();
+        for term in definitions.keys().filter(|term| !term.starts_with('@')) {
+            self.create_term_definition(definitions, term, &mut defined)?;
+        }
+        Ok(())
+    }
+
+    fn create_term_definition(
+        &mut self,
+        definitions: &Map<String, Value>,
+        term: &str,
+        defined: &mut HashMap<String, bool>,
+    ) -> Result<()> {
+        match defined.get(term) {
+            Some(true) => return Ok(()),
+            Some(false) => {
+                return Err(read_error(&format!(
+                    "cyclic IRI mapping for term {:?}",
+                    term
+                )))
+            }
+            None => {}
+        }
+        let _ = defined.insert(term.to_string(), false);
+        let _ = self.terms.remove(term);
+
+        let definition = match definitions.get(term).unwrap() {
+            Value::Null => TermDefinition::default(),
+            Value::String(id) => {
+                let iri = self.expand_dependent(definitions, id, defined)?;
+                TermDefinition {
+                    prefix: !term.contains(':')
+                        && iri.as_ref().map(|iri| iri.ends_with(GEN_DELIMS)) == Some(true),
+                    iri,
+                    ..Default::default()
+                }
+            }
+            Value::Object(value) => {
+                self.expanded_term_definition(definitions, term, value, defined)?
+            }
+            _ => {
+                return Err(read_error(&format!(
+                    "invalid term definition for {:?}",
+                    term
+                )))
+            }
+        };
+        let _ = self.terms.insert(term.to_string(), definition);
+        let _ = defined.insert(term.to_string(), true);
+        Ok(())
+    }
+
+    fn expanded_term_definition(
+        &mut self,
+        definitions: &Map<String, Value>,
+        term: &str,
+        value: &Map<String, Value>,
+        defined: &mut HashMap<String, bool>,
+    ) -> Result<TermDefinition> {
+        let mut definition = TermDefinition::default();
+
+        if let Some(reverse) = value.get(KW_REVERSE) {
+            match reverse {
+                Value::String(reverse) => {
+                    definition.iri = self.expand_dependent(definitions, reverse, defined)?;
+                    definition.reverse = true;
+                }
+                _ => return Err(read_error("@reverse must be a string")),
+            }
+        } else {
+            match value.get(KW_ID) {
+                Some(Value::Null) => {}
+                Some(Value::String(id)) if id != term => {
+                    definition.iri = self.expand_dependent(definitions, id, defined)?;
+                }
+                Some(Value::String(_)) | None => {
+                    definition.iri = if let Some(index) = term.find(':') {
+                        let prefix = &term[..index];
+                        if definitions.contains_key(prefix) {
+                            self.create_term_definition(definitions, prefix, defined)?;
+                        }
+                        self.expand_iri(term, false, false)
+                    } else {
+                        match &self.vocab {
+                            Some(vocab) => Some(format!("{}{}", vocab, term)),
+                            None => {
+                                return Err(read_error(&format!(
+                                    "no IRI mapping for term {:?}",
+                                    term
+                                )))
+                            }
+                        }
+                    };
+                }
+                Some(_) => return Err(read_error("@id must be a string or null")),
+            }
+        }
+
+        match value.get(KW_TYPE) {
+            None => {}
+            Some(Value::String(type_mapping)) => {
+                definition.type_mapping = if type_mapping.starts_with('@') {
+                    Some(type_mapping.clone())
+                } else {
+                    self.expand_dependent(definitions, type_mapping, defined)?
+                };
+            }
+            Some(_) => return Err(read_error("@type must be a string")),
+        }
+
+        match value.get(KW_CONTAINER) {
+            None | Some(Value::Null) => {}
+            Some(Value::String(container)) => definition.container.push(container.clone()),
+            Some(Value::Array(containers)) => {
+                for container in containers {
+                    match container {
+                        Value::String(container) => definition.container.push(container.clone()),
+                        _ => return Err(read_error("@container values must be strings")),
+                    }
+                }
+            }
+            Some(_) => return Err(read_error("@container must be a string or array")),
+        }
+
+        match value.get(KW_LANGUAGE) {
+            None => {}
+            Some(Value::Null) => definition.language = Some(None),
+            Some(Value::String(language)) => definition.language = Some(Some(language.clone())),
+            Some(_) => return Err(read_error("@language must be a string or null")),
+        }
+
+        match value.get(KW_PREFIX) {
+            None => {}
+            Some(Value::Bool(prefix)) => definition.prefix = *prefix,
+            Some(_) => return Err(read_error("@prefix must be a boolean")),
+        }
+
+        definition.context = value.get(KW_CONTEXT).cloned();
+
+        Ok(definition)
+    }
+
+    ///
+    /// Expand `value` as a vocabulary IRI, first defining any term in the local context that it
+    /// depends upon.
+    ///
+    fn expand_dependent(
+        &mut self,
+        definitions: &Map<String, Value>,
+        value: &str,
+        defined: &mut HashMap<String, bool>,
+    ) -> Result<Option<String>> {
+        if definitions.contains_key(value) && !value.starts_with('@') {
+            self.create_term_definition(definitions, value, defined)?;
+        } else if let Some(index) = value.find(':') {
+            let prefix = &value[..index];
+            if definitions.contains_key(prefix) {
+                self.create_term_definition(definitions, prefix, defined)?;
+            }
+        }
+        Ok(self.expand_iri(value, false, true))
+    }
+}
+
+impl TermDefinition {
+    pub(super) fn is_reverse(&self) -> bool {
+        self.reverse
+    }
+
+    pub(super) fn type_mapping(&self) -> Option<&String> {
+        self.type_mapping.as_ref()
+    }
+
+    pub(super) fn language(&self) -> Option<&Option<String>> {
+        self.language.as_ref()
+    }
+
+    pub(super) fn has_container(&self, container: &str) -> bool {
+        self.container.iter().any(|c| c == container)
+    }
+
+    pub(super) fn context(&self) -> Option<&Value> {
+        self.context.as_ref()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Unit Tests
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn expand_terms_and_compact_iris() {
+        let context = Context::with_base(Some("http://example.org/doc/".to_string()))
+            .process(&json!({
+                "@vocab": "http://schema.org/",
+                "foaf": "http://xmlns.com/foaf/0.1/",
+                "knows": "foaf:knows",
+                "nothing": null
+            }))
+            .unwrap();
+
+        assert_eq!(
+            context.expand_iri("knows", false, true),
+            Some("http://xmlns.com/foaf/0.1/knows".to_string())
+        );
+        assert_eq!(
+            context.expand_iri("foaf:name", false, true),
+            Some("http://xmlns.com/foaf/0.1/name".to_string())
+        );
+        assert_eq!(
+            context.expand_iri("name", false, true),
+            Some("http://schema.org/name".to_string())
+        );
+        assert_eq!(
+            context.expand_iri("alice", true, false),
+            Some("http://example.org/doc/alice".to_string())
+        );
+        assert_eq!(context.expand_iri("nothing", false, true), None);
+        assert_eq!(
+            context.expand_iri("_:b0", true, false),
+            Some("_:b0".to_string())
+        );
+        assert_eq!(context.prefixes().len(), 2);
+    }
+
+    #[test]
+    fn reject_cyclic_and_remote_contexts() {
+        let context = Context::default();
+        assert!(context.process(&json!({ "a": "b:x", "b": "a:y" })).is_err());
+        assert!(context
+            .process(&json!("http://example.org/context.jsonld"))
+            .is_err());
+    }
+}
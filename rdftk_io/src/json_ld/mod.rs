@@ -2,6 +2,8 @@
 Provides support for the W3C's
 [JSON-LD 1.1](https://www.w3.org/TR/json-ld/), _A JSON-based Serialization for Linked Data_, format.
 
+Both reading and writing are supported, see the `reader` and `writer` modules for details.
+
 */
 
 /// The display name of this serialization format.
@@ -13,6 +15,9 @@ pub const FILE_EXTENSION: &str = "jsonld";
 /// The MIME type used for this serialization format.
 pub const MIME_TYPE: &str = "application/ld+json";
 
+/// An IRI that defines the language.
+pub const FORMAT_IRI: &str = "http://www.w3.org/ns/formats/JSON-LD";
+
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
@@ -33,12 +38,26 @@ pub const MIME_TYPE: &str = "application/ld+json";
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn read_error(message: &str) -> rdftk_core::error::Error {
+    use rdftk_core::error::ErrorKind;
+    error!("{}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
+
+fn write_error(message: &str) -> rdftk_core::error::Error {
+    use rdftk_core::error::ErrorKind;
+    error!("{}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
 
-#[doc(hidden)]
+mod syntax;
+
+mod context;
+
 pub mod reader;
 
-#[doc(hidden)]
 pub mod writer;
@@ -1,34 +1,621 @@
 /*!
-One-line description.
+Provides the `JsonLdReader` implementation of the `GraphReader` trait.
 
-More detailed description, with
+Documents in either the expanded or compacted form are supported, the `@context` of a document
+may be provided inline as an object or an array of objects. Statements in any `@graph` within
+the document are added to the resulting graph, as are any `@included` nodes.
 
 # Example
 
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::json_ld::reader::JsonLdReader;
+use rdftk_io::GraphReader;
+
+let mut content = r#"{
+  "@context": {
+    "@vocab": "http://schema.org/",
+    "knows": { "@type": "@id" }
+  },
+  "@id": "http://example.org/alice",
+  "@type": "Person",
+  "name": "Alice",
+  "knows": "http://example.org/bob"
+}"#.as_bytes();
+let reader = JsonLdReader::default();
+let graph = reader.read(&mut content, graph_factory()).unwrap();
+assert_eq!(graph.borrow().len(), 3);
+```
+
 */
 
-// use ...
+use crate::conformance::conform;
+use crate::format::Format;
+use crate::json_ld::context::{Context, TermDefinition};
+use crate::json_ld::read_error;
+use crate::json_ld::syntax::{
+    BNODE_PREFIX, KW_CONTEXT, KW_GRAPH, KW_ID, KW_INCLUDED, KW_JSON, KW_LANGUAGE, KW_LIST, KW_NEST,
+    KW_NONE, KW_REVERSE, KW_SET, KW_TYPE, KW_VALUE, KW_VOCAB,
+};
+use crate::GraphReader;
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralFactoryRef, LiteralRef};
+use rdftk_core::model::statement::{ObjectNodeRef, StatementFactoryRef, SubjectNodeRef};
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, xsd};
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::io::Read;
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// An implementation of the GraphReader trait to read resources in the JSON-LD representation.
+/// Relative IRIs are resolved against the base IRI, either one provided to `with_base` or one
+/// declared in a context; relative IRIs that cannot be resolved are ignored. If `strict` is set
+/// the document is first checked for conformance, see the `conformance` module, and any violation
+/// aborts the read.
+///
+#[derive(Clone, Debug, Default)]
+pub struct JsonLdReader {
+    base: Option<IRIRef>,
+    strict: bool,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+#[derive(Debug)]
+struct ReaderState {
+    graph: GraphRef,
+    statements: StatementFactoryRef,
+    literals: LiteralFactoryRef,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl JsonLdReader {
+    ///
+    /// Create a new reader that will resolve relative IRIs against the provided base IRI.
+    ///
+    pub fn with_base(base: IRIRef) -> Self {
+        Self {
+            base: Some(base),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// If `strict` is `true`, the document is checked for conformance before it is parsed, and
+    /// any violation aborts the read.
+    ///
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    ///
+    /// Returns `true` if the document is checked for conformance before it is parsed.
+    ///
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+}
+
+impl GraphReader for JsonLdReader {
+    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<GraphRef> {
+        let value: Value = if self.strict {
+            let mut content: String = String::new();
+            let _ = r.read_to_string(&mut content).map_err(|e| {
+                rdftk_core::error::Error::with_chain(
+                    e,
+                    ErrorKind::ReadWrite(super::NAME.to_string()),
+                )
+            })?;
+            conform(Format::JsonLd, &content)?;
+            serde_json::from_str(&content)
+        } else {
+            serde_json::from_reader(r)
+        }
+        .map_err(|e| {
+            rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
+        })?;
+        let context = Context::with_base(self.base.as_ref().map(|base| base.to_string()));
+        parse_document(&value, &context, factory)
+    }
+}
+
+impl ReaderState {
+    fn new(factory: GraphFactoryRef) -> Self {
+        let graph = factory.graph();
+        let (statements, literals) = {
+            let graph = graph.borrow();
+            (graph.statement_factory(), graph.literal_factory())
+        };
+        Self {
+            graph,
+            statements,
+            literals,
+        }
+    }
+
+    fn insert(
+        &self,
+        subject: SubjectNodeRef,
+        predicate: IRIRef,
+        object: ObjectNodeRef,
+    ) -> Result<()> {
+        let statement = self.statements.statement(subject, predicate, object)?;
+        self.graph.borrow_mut().insert(statement);
+        Ok(())
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn parse_document(value: &Value, context: &Context, factory: GraphFactoryRef) -> Result<GraphRef> {
+    let state = ReaderState::new(factory);
+    match value {
+        Value::Array(nodes) => {
+            for node in nodes {
+                top_level_node(node, context, &state)?;
+            }
+        }
+        Value::Object(_) => top_level_node(value, context, &state)?,
+        _ => return Err(read_error("a document must be an object or an array")),
+    }
+    Ok(state.graph)
+}
+
+fn top_level_node(value: &Value, context: &Context, state: &ReaderState) -> Result<()> {
+    if let Value::Object(node) = value {
+        let context = local_context(node, context)?;
+        {
+            let mappings = state.graph.borrow().prefix_mappings();
+            let mut mappings = mappings.borrow_mut();
+            for (prefix, namespace) in context.prefixes() {
+                mappings.insert(&prefix, IRIRef::new(IRI::from_str(&namespace)?));
+            }
+        }
+        let keywords = keyword_entries(node, &context);
+        let graph_only = node.keys().all(|key| {
+            key == KW_CONTEXT || context.expand_iri(key, false, true).as_deref() == Some(KW_GRAPH)
+        });
+        if let (Some(nodes), true) = (keywords.get(KW_GRAPH), graph_only) {
+            // the top-level object only wraps a graph, it does not describe a node itself.
+            for node in as_array(nodes) {
+                let _ = node_object(node, &context, state)?;
+            }
+        } else {
+            let _ = node_object(value, &context, state)?;
+        }
+        Ok(())
+    } else {
+        Err(read_error("top-level values must be node objects"))
+    }
+}
+
+fn node_object(value: &Value, context: &Context, state: &ReaderState) -> Result<SubjectNodeRef> {
+    if let Value::Object(node) = value {
+        let context = local_context(node, context)?;
+        let keywords = keyword_entries(node, &context);
+        let subject = match keywords.get(KW_ID) {
+            Some(Value::String(id)) => context
+                .expand_iri(id, true, false)
+                .map(|id| subject_node(&id, state))
+                .transpose()?
+                .flatten(),
+            Some(_) => return Err(read_error("@id must be a string")),
+            None => None,
+        };
+        let subject = subject.unwrap_or_else(|| state.statements.blank_subject());
+        node_properties(&subject, node, &context, state)?;
+        Ok(subject)
+    } else {
+        Err(read_error("expecting a node object"))
+    }
+}
+
+fn node_properties(
+    subject: &SubjectNodeRef,
+    node: &Map<String, Value>,
+    context: &Context,
+    state: &ReaderState,
+) -> Result<()> {
+    for (key, value) in node.iter().filter(|(key, _)| *key != KW_CONTEXT) {
+        let property = match context.expand_iri(key, false, true) {
+            Some(property) => property,
+            None => continue,
+        };
+        match property.as_str() {
+            KW_TYPE => {
+                for type_value in as_array(value) {
+                    match type_value {
+                        Value::String(type_value) => {
+                            if let Some(object) = context
+                                .expand_iri(type_value, true, true)
+                                .map(|iri| iri_object(&iri, state))
+                                .transpose()?
+                                .flatten()
+                            {
+                                state.insert(subject.clone(), rdf::a_type().clone(), object)?;
+                            }
+                        }
+                        _ => return Err(read_error("@type values must be strings")),
+                    }
+                }
+            }
+            KW_GRAPH | KW_INCLUDED => {
+                for node in as_array(value) {
+                    let _ = node_object(node, context, state)?;
+                }
+            }
+            KW_REVERSE => {
+                if let Value::Object(reverse) = value {
+                    for (key, value) in reverse {
+                        if let Some(property) = property_iri(context, key)? {
+                            for object in property_values(value, context.term(key), context, state)?
+                            {
+                                reverse_insert(subject, &property, object, state)?;
+                            }
+                        }
+                    }
+                } else {
+                    return Err(read_error("@reverse must be an object"));
+                }
+            }
+            KW_NEST => {
+                for nested in as_array(value) {
+                    if let Value::Object(nested) = nested {
+                        node_properties(subject, nested, context, state)?;
+                    } else {
+                        return Err(read_error("@nest values must be objects"));
+                    }
+                }
+            }
+            _ => {
+                if let Some(property) = property_iri(context, key)? {
+                    let definition = context.term(key);
+                    let reverse = definition.map(|d| d.is_reverse()).unwrap_or_default();
+                    for object in property_values(value, definition, context, state)? {
+                        if reverse {
+                            reverse_insert(subject, &property, object, state)?;
+                        } else {
+                            state.insert(subject.clone(), property.clone(), object)?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn reverse_insert(
+    subject: &SubjectNodeRef,
+    property: &IRIRef,
+    object: ObjectNodeRef,
+    state: &ReaderState,
+) -> Result<()> {
+    match state.statements.object_as_subject(object) {
+        Some(reverse_subject) => state.insert(
+            reverse_subject,
+            property.clone(),
+            state.statements.subject_as_object(subject.clone()),
+        ),
+        None => Err(read_error("reverse property values must be node objects")),
+    }
+}
+
+fn property_values(
+    value: &Value,
+    definition: Option<&TermDefinition>,
+    context: &Context,
+    state: &ReaderState,
+) -> Result<Vec<ObjectNodeRef>> {
+    let scoped_context;
+    let context = match definition.and_then(|d| d.context()) {
+        Some(scoped) => {
+            scoped_context = context.process(scoped)?;
+            &scoped_context
+        }
+        None => context,
+    };
+    let has_container = |container| {
+        definition
+            .map(|d| d.has_container(container))
+            .unwrap_or_default()
+    };
+
+    if has_container(KW_LIST) && !is_list_object(value, context) {
+        let members = value_objects(value, definition, context, state)?;
+        Ok(vec![list_object(members, state)?])
+    } else if has_container(KW_LANGUAGE) && value.is_object() {
+        let mut objects: Vec<ObjectNodeRef> = Default::default();
+        for (language, strings) in value.as_object().unwrap() {
+            for string in as_array(strings) {
+                match string {
+                    Value::Null => {}
+                    Value::String(string) => {
+                        let literal = if language == KW_NONE {
+                            state.literals.literal(string)
+                        } else {
+                            state
+                                .literals
+                                .with_language(string, LanguageTag::from_str(language)?)
+                        };
+                        objects.push(state.statements.literal_object(literal));
+                    }
+                    _ => return Err(read_error("language map values must be strings")),
+                }
+            }
+        }
+        Ok(objects)
+    } else {
+        value_objects(value, definition, context, state)
+    }
+}
+
+fn value_objects(
+    value: &Value,
+    definition: Option<&TermDefinition>,
+    context: &Context,
+    state: &ReaderState,
+) -> Result<Vec<ObjectNodeRef>> {
+    let type_mapping = definition.and_then(|d| d.type_mapping());
+    Ok(match value {
+        Value::Null => Default::default(),
+        Value::Array(values) => {
+            let mut objects: Vec<ObjectNodeRef> = Default::default();
+            for value in values {
+                objects.extend(value_objects(value, definition, context, state)?);
+            }
+            objects
+        }
+        Value::String(string) => {
+            let object = match type_mapping.map(String::as_str) {
+                Some(KW_ID) => context
+                    .expand_iri(string, true, false)
+                    .map(|iri| iri_object(&iri, state))
+                    .transpose()?
+                    .flatten(),
+                Some(KW_VOCAB) => context
+                    .expand_iri(string, true, true)
+                    .map(|iri| iri_object(&iri, state))
+                    .transpose()?
+                    .flatten(),
+                Some(data_type) if !data_type.starts_with('@') => Some(
+                    state
+                        .statements
+                        .literal_object(typed_literal(string, data_type, state)?),
+                ),
+                _ => {
+                    let language = match definition.and_then(|d| d.language()) {
+                        Some(language) => language.as_ref(),
+                        None => context.language(),
+                    };
+                    let literal = match language {
+                        Some(language) => state
+                            .literals
+                            .with_language(string, LanguageTag::from_str(language)?),
+                        None => state.literals.literal(string),
+                    };
+                    Some(state.statements.literal_object(literal))
+                }
+            };
+            object.into_iter().collect()
+        }
+        Value::Number(number) => {
+            let literal = match type_mapping {
+                Some(data_type) if !data_type.starts_with('@') => {
+                    typed_literal(&number.to_string(), data_type, state)?
+                }
+                _ => number_literal(number, state)?,
+            };
+            vec![state.statements.literal_object(literal)]
+        }
+        Value::Bool(boolean) => {
+            let literal = match type_mapping {
+                Some(data_type) if !data_type.starts_with('@') => {
+                    typed_literal(&boolean.to_string(), data_type, state)?
+                }
+                _ => state.literals.boolean(*boolean),
+            };
+            vec![state.statements.literal_object(literal)]
+        }
+        Value::Object(object) => {
+            let keywords = keyword_entries(object, context);
+            if let Some(value) = keywords.get(KW_VALUE) {
+                value_object(value, &keywords, context, state)?
+                    .into_iter()
+                    .collect()
+            } else if let Some(members) = keywords.get(KW_LIST) {
+                let members = value_objects(members, definition, context, state)?;
+                vec![list_object(members, state)?]
+            } else if let Some(members) = keywords.get(KW_SET) {
+                value_objects(members, definition, context, state)?
+            } else if object.len() == 1 && keywords.contains_key(KW_ID) {
+                // a node reference, @id is document relative regardless of the type mapping.
+                match keywords[KW_ID] {
+                    Value::String(id) => context
+                        .expand_iri(id, true, false)
+                        .map(|iri| iri_object(&iri, state))
+                        .transpose()?
+                        .flatten()
+                        .into_iter()
+                        .collect(),
+                    _ => return Err(read_error("@id must be a string")),
+                }
+            } else {
+                let subject = node_object(value, context, state)?;
+                vec![state.statements.subject_as_object(subject)]
+            }
+        }
+    })
+}
+
+fn value_object(
+    value: &Value,
+    keywords: &HashMap<&str, &Value>,
+    context: &Context,
+    state: &ReaderState,
+) -> Result<Option<ObjectNodeRef>> {
+    let data_type = match keywords.get(KW_TYPE) {
+        None => None,
+        Some(Value::String(data_type)) if data_type == KW_JSON => Some(rdf::json().to_string()),
+        Some(Value::String(data_type)) => context.expand_iri(data_type, true, true),
+        Some(_) => return Err(read_error("a value object @type must be a string")),
+    };
+    let language = match keywords.get(KW_LANGUAGE) {
+        None => None,
+        Some(Value::String(language)) => Some(LanguageTag::from_str(language)?),
+        Some(_) => return Err(read_error("a value object @language must be a string")),
+    };
+    let literal = match (value, data_type, language) {
+        (Value::Null, _, _) => return Ok(None),
+        (value, Some(data_type), None) if data_type == rdf::json().to_string() => {
+            typed_literal(&value.to_string(), &data_type, state)?
+        }
+        (Value::String(string), None, None) => state.literals.literal(string),
+        (Value::String(string), None, Some(language)) => {
+            state.literals.with_language(string, language)
+        }
+        (Value::String(string), Some(data_type), None) => typed_literal(string, &data_type, state)?,
+        (Value::Number(number), None, None) => number_literal(number, state)?,
+        (Value::Number(number), Some(data_type), None) => {
+            typed_literal(&number.to_string(), &data_type, state)?
+        }
+        (Value::Bool(boolean), None, None) => state.literals.boolean(*boolean),
+        (Value::Bool(boolean), Some(data_type), None) => {
+            typed_literal(&boolean.to_string(), &data_type, state)?
+        }
+        _ => return Err(read_error("invalid value object")),
+    };
+    Ok(Some(state.statements.literal_object(literal)))
+}
+
+fn list_object(members: Vec<ObjectNodeRef>, state: &ReaderState) -> Result<ObjectNodeRef> {
+    let mut list = state.statements.named_object(rdf::nil().clone());
+    for member in members.into_iter().rev() {
+        let node = state.statements.blank_subject();
+        state.insert(node.clone(), rdf::first().clone(), member)?;
+        state.insert(node.clone(), rdf::rest().clone(), list)?;
+        list = state.statements.subject_as_object(node);
+    }
+    Ok(list)
+}
+
+fn number_literal(number: &Number, state: &ReaderState) -> Result<LiteralRef> {
+    if number.is_f64() {
+        let value = number.as_f64().unwrap();
+        if value.fract() == 0.0 && value.abs() < 1e21 {
+            typed_literal(&format!("{:.0}", value), &xsd::integer().to_string(), state)
+        } else {
+            // the canonical form of xsd:double always has a fractional part in the mantissa.
+            let mut lexical_form = format!("{:E}", value);
+            if !lexical_form.contains('.') {
+                lexical_form = lexical_form.replace('E', ".0E");
+            }
+            typed_literal(&lexical_form, &xsd::double().to_string(), state)
+        }
+    } else {
+        typed_literal(&number.to_string(), &xsd::integer().to_string(), state)
+    }
+}
+
+fn typed_literal(lexical_form: &str, data_type: &str, state: &ReaderState) -> Result<LiteralRef> {
+    let data_type = IRIRef::new(IRI::from_str(data_type)?);
+    Ok(state
+        .literals
+        .with_data_type(lexical_form, DataType::from(data_type)))
+}
+
+fn property_iri(context: &Context, key: &str) -> Result<Option<IRIRef>> {
+    match context.expand_iri(key, false, true) {
+        Some(property) if is_absolute(&property) => {
+            Ok(Some(IRIRef::new(IRI::from_str(&property)?)))
+        }
+        _ => Ok(None),
+    }
+}
+
+fn subject_node(id: &str, state: &ReaderState) -> Result<Option<SubjectNodeRef>> {
+    if let Some(label) = id.strip_prefix(BNODE_PREFIX) {
+        Ok(Some(state.statements.blank_subject_named(label)?))
+    } else if is_absolute(id) {
+        Ok(Some(
+            state
+                .statements
+                .named_subject(IRIRef::new(IRI::from_str(id)?)),
+        ))
+    } else {
+        Ok(None)
+    }
+}
+
+fn iri_object(iri: &str, state: &ReaderState) -> Result<Option<ObjectNodeRef>> {
+    Ok(subject_node(iri, state)?.map(|subject| state.statements.subject_as_object(subject)))
+}
+
+fn is_absolute(iri: &str) -> bool {
+    !iri.starts_with('@') && !iri.starts_with(BNODE_PREFIX) && iri.contains(':')
+}
+
+fn is_list_object(value: &Value, context: &Context) -> bool {
+    match value {
+        Value::Object(object) => keyword_entries(object, context).contains_key(KW_LIST),
+        _ => false,
+    }
+}
+
+fn local_context(node: &Map<String, Value>, context: &Context) -> Result<Context> {
+    match node.get(KW_CONTEXT) {
+        Some(local) => context.process(local),
+        None => Ok(context.clone()),
+    }
+}
+
+///
+/// Return the entries of the object whose keys are keywords, or aliases of keywords.
+///
+fn keyword_entries<'a>(
+    object: &'a Map<String, Value>,
+    context: &Context,
+) -> HashMap<&'static str, &'a Value> {
+    const KEYWORDS: &[&str] = &[
+        KW_GRAPH,
+        KW_ID,
+        KW_LANGUAGE,
+        KW_LIST,
+        KW_SET,
+        KW_TYPE,
+        KW_VALUE,
+        KW_REVERSE,
+    ];
+    object
+        .iter()
+        .filter_map(|(key, value)| {
+            context.expand_iri(key, false, true).and_then(|expanded| {
+                KEYWORDS
+                    .iter()
+                    .find(|keyword| **keyword == expanded)
+                    .map(|keyword| (*keyword, value))
+            })
+        })
+        .collect()
+}
+
+fn as_array(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(values) => values.iter().collect(),
+        value => vec![value],
+    }
+}
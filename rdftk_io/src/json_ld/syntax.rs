@@ -0,0 +1,30 @@
+pub(super) const KW_ALWAYS: &str = "@always";
+pub(super) const KW_BASE: &str = "@base";
+pub(super) const KW_CONTAINER: &str = "@container";
+pub(super) const KW_CONTEXT: &str = "@context";
+pub(super) const KW_EMBED: &str = "@embed";
+pub(super) const KW_EXPLICIT: &str = "@explicit";
+pub(super) const KW_GRAPH: &str = "@graph";
+pub(super) const KW_ID: &str = "@id";
+pub(super) const KW_IMPORT: &str = "@import";
+pub(super) const KW_INCLUDED: &str = "@included";
+pub(super) const KW_JSON: &str = "@json";
+pub(super) const KW_LANGUAGE: &str = "@language";
+pub(super) const KW_LIST: &str = "@list";
+pub(super) const KW_NEST: &str = "@nest";
+pub(super) const KW_NEVER: &str = "@never";
+pub(super) const KW_NONE: &str = "@none";
+pub(super) const KW_ONCE: &str = "@once";
+pub(super) const KW_PREFIX: &str = "@prefix";
+pub(super) const KW_REVERSE: &str = "@reverse";
+pub(super) const KW_SET: &str = "@set";
+pub(super) const KW_TYPE: &str = "@type";
+pub(super) const KW_VALUE: &str = "@value";
+pub(super) const KW_VOCAB: &str = "@vocab";
+
+pub(super) const BNODE_PREFIX: &str = "_:";
+
+///
+/// Characters which, when they end an IRI, allow a simple term definition to be used as a prefix.
+///
+pub(super) const GEN_DELIMS: &[char] = &[':', '/', '?', '#', '[', ']', '@'];
@@ -1,34 +1,764 @@
 /*!
-One-line description.
+Provides the `JsonLdWriter` implementation of the `GraphWriter` trait.
 
-More detailed description, with
+A graph may be written in the expanded form, or in the compacted form where IRIs are compacted
+using a set of prefix mappings, by default the graph's own. The generated `@context` only
+contains these prefixes, and the default namespace as `@vocab`.
+
+A frame document may also be provided to select the nodes that become top-level objects, and to
+shape how the nodes they reference are embedded. Frames support matching on `@id`, `@type`
+(including the `{}` wildcard), and the presence, or with `[]` the absence, of properties; the
+`@embed` (`@always`, `@once`, `@never`) and `@explicit` flags are also supported. Any `@context`
+in the frame is used to expand the frame itself and its prefixes are added to those used to
+compact the output. Without a frame every node is written as a top-level object, except blank
+nodes referenced exactly once which are embedded where they are referenced.
 
 # Example
 
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::json_ld::writer::{JsonLdOptions, JsonLdWriter};
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::{write_graph_to_string, GraphReader};
+use serde_json::json;
+
+let mut content = r#"@prefix schema: <http://schema.org/> .
+<http://example.org/alice> a schema:Person ;
+    schema:name "Alice" ;
+    schema:address [ schema:streetAddress "1 Main St" ] .
+"#.as_bytes();
+let graph = TurtleReader::default().read(&mut content, graph_factory()).unwrap();
+
+let mut options = JsonLdOptions::compacted();
+let _ = options.framed(json!({
+    "@context": { "schema": "http://schema.org/" },
+    "@type": "schema:Person"
+}));
+let writer = JsonLdWriter::new(options);
+let result = write_graph_to_string(&writer, &graph).unwrap();
+assert!(result.contains(r#""@type":"schema:Person""#));
+assert!(result.contains(r#""schema:address":{"schema:streetAddress":"1 Main St"}"#));
+```
+
 */
 
-// use ...
+use crate::json_ld::context::Context;
+use crate::json_ld::syntax::{
+    BNODE_PREFIX, KW_ALWAYS, KW_CONTEXT, KW_EMBED, KW_EXPLICIT, KW_GRAPH, KW_ID, KW_LANGUAGE,
+    KW_NEVER, KW_ONCE, KW_TYPE, KW_VALUE, KW_VOCAB,
+};
+use crate::json_ld::{write_error, NAME};
+use crate::GraphWriter;
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::mapping::PrefixMappingRef;
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::statement::ObjectNodeRef;
+use rdftk_names::{rdf, xsd};
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// The document form to generate.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JsonLdForm {
+    /// All IRIs are absolute and all values are explicit arrays of objects.
+    Expanded,
+    /// IRIs are compacted using prefix mappings and single values are not wrapped in arrays.
+    Compacted,
+}
+
+///
+/// Options that control how the JSON-LD writer will render a graph.
+///
+#[derive(Clone, Debug)]
+pub struct JsonLdOptions {
+    /// Determines the form of the generated document. Default is `Compacted`.
+    pub form: JsonLdForm,
+    /// The prefix mappings used to compact IRIs, if `None` the graph's own mappings are used.
+    /// Default is `None`.
+    pub mappings: Option<PrefixMappingRef>,
+    /// A frame document used to select and shape the top-level objects. Default is `None`.
+    pub frame: Option<Value>,
+    /// Should the output be pretty-printed. Default is `false`.
+    pub pretty: bool,
+}
+
+///
+/// A Writer to output JSON-LD.
+///
+#[derive(Debug, Default)]
+pub struct JsonLdWriter {
+    options: JsonLdOptions,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum NodeValue {
+    Reference(String),
+    Literal {
+        value: String,
+        language: Option<String>,
+        data_type: Option<String>,
+    },
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    types: Vec<String>,
+    properties: BTreeMap<String, Vec<NodeValue>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Embed {
+    Always,
+    Once,
+    Never,
+}
+
+#[derive(Clone, Debug)]
+struct Frame {
+    ids: Option<Vec<String>>,
+    types: Option<Vec<String>>,
+    properties: HashMap<String, Option<Frame>>,
+    absent: Vec<String>,
+    embed: Embed,
+    explicit: bool,
+}
+
+struct Embedder<'a> {
+    nodes: &'a BTreeMap<String, Node>,
+    references: HashMap<&'a String, Vec<&'a String>>,
+    top_level: HashSet<String>,
+    inline: HashSet<String>,
+    embedded: HashSet<String>,
+}
+
+struct Compactor {
+    vocab: Option<String>,
+    prefixes: Vec<(String, String)>,
+}
+
 // ------------------------------------------------------------------------------------------------
-// Public Functions
+// Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl Default for JsonLdOptions {
+    fn default() -> Self {
+        Self {
+            form: JsonLdForm::Compacted,
+            mappings: None,
+            frame: None,
+            pretty: false,
+        }
+    }
+}
+
+impl JsonLdOptions {
+    /// Create an option instance with `JsonLdForm::Expanded`.
+    pub fn expanded() -> Self {
+        Self {
+            form: JsonLdForm::Expanded,
+            ..Default::default()
+        }
+    }
+
+    /// Create an option instance with `JsonLdForm::Compacted`.
+    pub fn compacted() -> Self {
+        Self {
+            form: JsonLdForm::Compacted,
+            ..Default::default()
+        }
+    }
+
+    /// Create an option instance with `JsonLdForm::Compacted` using the provided mappings.
+    pub fn compacted_with(mappings: PrefixMappingRef) -> Self {
+        Self {
+            form: JsonLdForm::Compacted,
+            mappings: Some(mappings),
+            ..Default::default()
+        }
+    }
+
+    /// Set the frame document used to shape the output.
+    pub fn framed(&mut self, frame: Value) -> &mut Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// Set the option to emit pretty-printed JSON.
+    pub fn pretty(&mut self) -> &mut Self {
+        self.pretty = true;
+        self
+    }
+
+    /// Set the option to emit plain, non-indented, JSON.
+    pub fn plain(&mut self) -> &mut Self {
+        self.pretty = false;
+        self
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
-// Implementations
+
+impl GraphWriter for JsonLdWriter {
+    fn write(&self, w: &mut impl Write, graph: &GraphRef) -> Result<()> {
+        let graph = graph.borrow();
+        let nodes = node_map(&*graph)?;
+
+        let mut prefixes: BTreeMap<String, String> = Default::default();
+        let mappings = match &self.options.mappings {
+            Some(mappings) => mappings.clone(),
+            None => graph.prefix_mappings(),
+        };
+        for (prefix, namespace) in mappings.borrow().mappings() {
+            let _ = prefixes.insert(prefix.clone(), namespace.to_string());
+        }
+
+        let expanded = match &self.options.frame {
+            None => Embedder::new(&nodes).unframed(),
+            Some(frame) => {
+                let (frame, context) = Frame::parse(frame)?;
+                prefixes.extend(context.prefixes());
+                Embedder::new(&nodes).framed(&frame)
+            }
+        };
+
+        let document = match self.options.form {
+            JsonLdForm::Expanded => Value::Array(expanded),
+            JsonLdForm::Compacted => Compactor::new(prefixes).document(expanded),
+        };
+
+        if self.options.pretty {
+            serde_json::to_writer_pretty(w, &document).map_err(json_error)?;
+        } else {
+            serde_json::to_writer(w, &document).map_err(json_error)?;
+        }
+        Ok(())
+    }
+}
+
+impl JsonLdWriter {
+    ///
+    /// Create a new writer with the provided options, this is used to override the default
+    /// options that are used when calling `Default::default`.
+    ///
+    pub fn new(options: JsonLdOptions) -> Self {
+        Self { options }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 
+impl Frame {
+    ///
+    /// Parse a frame document, returning the frame and the context used to expand it.
+    ///
+    fn parse(frame: &Value) -> Result<(Self, Context)> {
+        let frame = match frame {
+            Value::Object(frame) => frame,
+            Value::Array(frames) if frames.len() == 1 && frames[0].is_object() => {
+                frames[0].as_object().unwrap()
+            }
+            _ => return Err(write_error("a frame must be a single object")),
+        };
+        let context = match frame.get(KW_CONTEXT) {
+            Some(context) => Context::default().process(context)?,
+            None => Context::default(),
+        };
+        let frame = Self::from_object(frame, &context, Embed::Once)?;
+        Ok((frame, context))
+    }
+
+    fn from_object(object: &Map<String, Value>, context: &Context, embed: Embed) -> Result<Self> {
+        let embed = match object.get(KW_EMBED) {
+            None => embed,
+            Some(Value::String(value)) if value == KW_ALWAYS => Embed::Always,
+            Some(Value::String(value)) if value == KW_ONCE => Embed::Once,
+            Some(Value::String(value)) if value == KW_NEVER => Embed::Never,
+            Some(Value::Bool(true)) => Embed::Once,
+            Some(Value::Bool(false)) => Embed::Never,
+            Some(value) => return Err(write_error(&format!("invalid @embed value {}", value))),
+        };
+        let explicit = match object.get(KW_EXPLICIT) {
+            None => false,
+            Some(Value::Bool(explicit)) => *explicit,
+            Some(_) => return Err(write_error("@explicit must be a boolean")),
+        };
+        let ids = match object.get(KW_ID) {
+            None => None,
+            Some(value) => Some(frame_strings(value, KW_ID, |id| {
+                context.expand_iri(id, true, false)
+            })?),
+        };
+        let types = match object.get(KW_TYPE) {
+            None => None,
+            Some(Value::Object(wildcard)) if wildcard.is_empty() => Some(Vec::new()),
+            Some(value) => Some(frame_strings(value, KW_TYPE, |value| {
+                context.expand_iri(value, true, true)
+            })?),
+        };
+
+        let mut properties: HashMap<String, Option<Frame>> = Default::default();
+        let mut absent: Vec<String> = Default::default();
+        for (key, value) in object.iter().filter(|(key, _)| !key.starts_with('@')) {
+            let property = match context.expand_iri(key, false, true) {
+                Some(property) => property,
+                None => return Err(write_error(&format!("no IRI mapping for term {:?}", key))),
+            };
+            let sub_frame = match value {
+                Value::Array(values) if values.is_empty() => {
+                    absent.push(property);
+                    continue;
+                }
+                Value::Array(values) => values.first().and_then(Value::as_object),
+                Value::Object(value) => Some(value),
+                _ => None,
+            };
+            let sub_frame = match sub_frame {
+                Some(sub_frame) => Some(Self::from_object(sub_frame, context, embed)?),
+                None => None,
+            };
+            let _ = properties.insert(property, sub_frame);
+        }
+
+        Ok(Self {
+            ids,
+            types,
+            properties,
+            absent,
+            embed,
+            explicit,
+        })
+    }
+
+    fn matches(&self, id: &str, node: &Node) -> bool {
+        let id_matches = match &self.ids {
+            None => true,
+            Some(ids) => ids.iter().any(|frame_id| frame_id == id),
+        };
+        let types_match = match &self.types {
+            None => true,
+            Some(types) if types.is_empty() => !node.types.is_empty(),
+            Some(types) => node.types.iter().any(|node_type| types.contains(node_type)),
+        };
+        id_matches
+            && types_match
+            && self
+                .properties
+                .keys()
+                .all(|property| node.properties.contains_key(property))
+            && !self
+                .absent
+                .iter()
+                .any(|property| node.properties.contains_key(property))
+    }
+
+    ///
+    /// The frame used for values of `property`, either an explicit sub-frame or a default frame
+    /// inheriting the `@embed` flag.
+    ///
+    fn child(&self, property: &str) -> Self {
+        match self.properties.get(property) {
+            Some(Some(frame)) => frame.clone(),
+            _ => Self {
+                ids: None,
+                types: None,
+                properties: Default::default(),
+                absent: Default::default(),
+                embed: self.embed,
+                explicit: false,
+            },
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
-// Private Functions
+
+impl<'a> Embedder<'a> {
+    fn new(nodes: &'a BTreeMap<String, Node>) -> Self {
+        let mut references: HashMap<&String, Vec<&String>> = Default::default();
+        for (id, node) in nodes {
+            for value in node.properties.values().flatten() {
+                if let NodeValue::Reference(reference) = value {
+                    references.entry(reference).or_default().push(id);
+                }
+            }
+        }
+        Self {
+            nodes,
+            references,
+            top_level: Default::default(),
+            inline: Default::default(),
+            embedded: Default::default(),
+        }
+    }
+
+    ///
+    /// Return all nodes as top-level objects, except blank nodes referenced exactly once by
+    /// another node which are embedded instead.
+    ///
+    fn unframed(mut self) -> Vec<Value> {
+        self.inline = self
+            .references
+            .iter()
+            .filter(|(reference, referrers)| {
+                reference.starts_with(BNODE_PREFIX)
+                    && referrers.len() == 1
+                    && referrers[0] != **reference
+            })
+            .map(|(reference, _)| reference.to_string())
+            .collect();
+
+        let mut results: Vec<Value> = Default::default();
+        for (id, node) in self.nodes {
+            if !self.inline.contains(id) {
+                results.push(self.node_object(id, node, None, &mut Vec::new()));
+            }
+        }
+        // blank nodes that only reference each other in a cycle.
+        for (id, node) in self.nodes {
+            if self.inline.contains(id) && !self.embedded.contains(id) {
+                let _ = self.embedded.insert(id.clone());
+                results.push(self.node_object(id, node, None, &mut Vec::new()));
+            }
+        }
+        results
+    }
+
+    ///
+    /// Return all nodes matching the frame as top-level objects.
+    ///
+    fn framed(mut self, frame: &Frame) -> Vec<Value> {
+        let mut results: Vec<Value> = Default::default();
+        for (id, node) in self.nodes {
+            if frame.matches(id, node) {
+                let _ = self.top_level.insert(id.clone());
+            }
+        }
+        for (id, node) in self.nodes {
+            if self.top_level.contains(id) {
+                let _ = self.embedded.insert(id.clone());
+                results.push(self.node_object(id, node, Some(frame), &mut Vec::new()));
+            }
+        }
+        results
+    }
+
+    fn node_object(
+        &mut self,
+        id: &str,
+        node: &Node,
+        frame: Option<&Frame>,
+        path: &mut Vec<String>,
+    ) -> Value {
+        let mut object = Map::new();
+        // blank node identifiers are only needed if the node is referenced from elsewhere.
+        let references = self.references.get(&id.to_string()).map_or(0, Vec::len);
+        let embedded = !path.is_empty();
+        if !id.starts_with(BNODE_PREFIX)
+            || self.top_level.contains(id)
+            || references != if embedded { 1 } else { 0 }
+        {
+            let _ = object.insert(KW_ID.to_string(), Value::String(id.to_string()));
+        }
+        if !node.types.is_empty() {
+            let _ = object.insert(
+                KW_TYPE.to_string(),
+                Value::Array(node.types.iter().cloned().map(Value::String).collect()),
+            );
+        }
+        path.push(id.to_string());
+        for (property, values) in &node.properties {
+            if let Some(frame) = frame {
+                if frame.explicit && !frame.properties.contains_key(property) {
+                    continue;
+                }
+            }
+            let child = frame.map(|frame| frame.child(property));
+            let values = values
+                .iter()
+                .map(|value| self.value_object(value, child.as_ref(), path))
+                .collect();
+            let _ = object.insert(property.clone(), Value::Array(values));
+        }
+        let _ = path.pop();
+        Value::Object(object)
+    }
+
+    fn value_object(
+        &mut self,
+        value: &NodeValue,
+        frame: Option<&Frame>,
+        path: &mut Vec<String>,
+    ) -> Value {
+        let mut object = Map::new();
+        match value {
+            NodeValue::Literal {
+                value,
+                language,
+                data_type,
+            } => {
+                let _ = object.insert(KW_VALUE.to_string(), Value::String(value.clone()));
+                if let Some(language) = language {
+                    let _ = object.insert(KW_LANGUAGE.to_string(), Value::String(language.clone()));
+                }
+                if let Some(data_type) = data_type {
+                    let _ = object.insert(KW_TYPE.to_string(), Value::String(data_type.clone()));
+                }
+            }
+            NodeValue::Reference(id) => {
+                if let Some(node) = self.nodes.get(id) {
+                    let embed = !path.contains(id)
+                        && match frame {
+                            Some(frame) => match frame.embed {
+                                Embed::Always => true,
+                                Embed::Once => !self.embedded.contains(id),
+                                Embed::Never => false,
+                            },
+                            None => self.inline.contains(id),
+                        };
+                    if embed {
+                        let _ = self.embedded.insert(id.clone());
+                        return self.node_object(id, node, frame, path);
+                    }
+                }
+                let _ = object.insert(KW_ID.to_string(), Value::String(id.clone()));
+            }
+        }
+        Value::Object(object)
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 
+impl Compactor {
+    fn new(mut prefixes: BTreeMap<String, String>) -> Self {
+        let vocab = prefixes.remove("");
+        let mut prefixes: Vec<(String, String)> = prefixes.into_iter().collect();
+        // prefer the most specific namespace when more than one matches.
+        prefixes.sort_by(|lhs, rhs| rhs.1.len().cmp(&lhs.1.len()).then(lhs.0.cmp(&rhs.0)));
+        Self { vocab, prefixes }
+    }
+
+    fn document(&self, nodes: Vec<Value>) -> Value {
+        let mut nodes: Vec<Value> = nodes
+            .into_iter()
+            .map(|node| self.compact_value(node))
+            .collect();
+        let single = nodes.len() == 1;
+        let mut document = match nodes.pop() {
+            Some(Value::Object(node)) if single => node,
+            last => {
+                nodes.extend(last);
+                let mut document = Map::new();
+                let _ = document.insert(KW_GRAPH.to_string(), Value::Array(nodes));
+                document
+            }
+        };
+
+        let mut context = Map::new();
+        for (prefix, namespace) in &self.prefixes {
+            let _ = context.insert(prefix.clone(), Value::String(namespace.clone()));
+        }
+        if let Some(vocab) = &self.vocab {
+            let _ = context.insert(KW_VOCAB.to_string(), Value::String(vocab.clone()));
+        }
+        if !context.is_empty() {
+            let _ = document.insert(KW_CONTEXT.to_string(), Value::Object(context));
+        }
+        Value::Object(document)
+    }
+
+    fn compact_value(&self, value: Value) -> Value {
+        match value {
+            Value::Object(object) => self.compact_object(object),
+            Value::Array(values) => {
+                let mut values: Vec<Value> = values
+                    .into_iter()
+                    .map(|value| self.compact_value(value))
+                    .collect();
+                if values.len() == 1 {
+                    values.remove(0)
+                } else {
+                    Value::Array(values)
+                }
+            }
+            value => value,
+        }
+    }
+
+    fn compact_object(&self, object: Map<String, Value>) -> Value {
+        if object.len() == 1 && object.contains_key(KW_VALUE) {
+            return object.into_iter().next().unwrap().1;
+        }
+        let mut compacted = Map::new();
+        for (key, value) in object {
+            let (key, value) = match (key.as_str(), value) {
+                (KW_ID, Value::String(id)) => (key, Value::String(self.compact_iri(&id, false))),
+                (KW_TYPE, value) => (key, self.compact_types(value)),
+                (_, value) if key.starts_with('@') => (key, value),
+                (_, value) => (self.compact_iri(&key, true), self.compact_value(value)),
+            };
+            let _ = compacted.insert(key, value);
+        }
+        Value::Object(compacted)
+    }
+
+    fn compact_types(&self, value: Value) -> Value {
+        match value {
+            Value::String(value) => Value::String(self.compact_iri(&value, true)),
+            Value::Array(values) => self.compact_value(Value::Array(
+                values
+                    .into_iter()
+                    .map(|value| self.compact_types(value))
+                    .collect(),
+            )),
+            value => value,
+        }
+    }
+
+    ///
+    /// Compact an IRI to a term relative to `@vocab`, if `vocab` is true, or to a compact IRI
+    /// using the most specific prefix; otherwise the IRI is returned unchanged.
+    ///
+    fn compact_iri(&self, iri: &str, vocab: bool) -> String {
+        if vocab {
+            if let Some(term) = self
+                .vocab
+                .as_ref()
+                .and_then(|namespace| iri.strip_prefix(namespace.as_str()))
+            {
+                if !term.is_empty()
+                    && !term.contains(':')
+                    && !term.starts_with('@')
+                    && !self.prefixes.iter().any(|(prefix, _)| prefix == term)
+                {
+                    return term.to_string();
+                }
+            }
+        }
+        for (prefix, namespace) in &self.prefixes {
+            if let Some(suffix) = iri.strip_prefix(namespace.as_str()) {
+                if !suffix.starts_with("//") {
+                    return format!("{}:{}", prefix, suffix);
+                }
+            }
+        }
+        iri.to_string()
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
-// Modules
+// Private Functions
 // ------------------------------------------------------------------------------------------------
+
+///
+/// Collect the statements of the graph into node objects keyed by the node identifier.
+///
+fn node_map(graph: &dyn Graph) -> Result<BTreeMap<String, Node>> {
+    let mut nodes: BTreeMap<String, Node> = Default::default();
+    for statement in graph.statements() {
+        let subject = statement.subject();
+        let id = if let Some(iri) = subject.as_iri() {
+            iri.to_string()
+        } else if let Some(name) = subject.as_blank() {
+            format!("{}{}", BNODE_PREFIX, name)
+        } else {
+            return Err(ErrorKind::RdfStarNotSupported(NAME.to_string()).into());
+        };
+        let node = nodes.entry(id).or_default();
+        let value = node_value(statement.object())?;
+        match value {
+            NodeValue::Reference(id) if statement.predicate() == rdf::a_type() => {
+                node.types.push(id)
+            }
+            value => node
+                .properties
+                .entry(statement.predicate().to_string())
+                .or_default()
+                .push(value),
+        }
+    }
+    for node in nodes.values_mut() {
+        node.types.sort();
+        node.types.dedup();
+        for values in node.properties.values_mut() {
+            values.sort();
+            values.dedup();
+        }
+    }
+    Ok(nodes)
+}
+
+fn node_value(object: &ObjectNodeRef) -> Result<NodeValue> {
+    if let Some(iri) = object.as_iri() {
+        Ok(NodeValue::Reference(iri.to_string()))
+    } else if let Some(name) = object.as_blank() {
+        Ok(NodeValue::Reference(format!("{}{}", BNODE_PREFIX, name)))
+    } else if let Some(literal) = object.as_literal() {
+        let language = literal.language().map(|language| language.to_string());
+        let data_type = match (&language, literal.data_type()) {
+            (None, Some(data_type)) if data_type.as_iri() != xsd::string() => {
+                Some(data_type.as_iri().to_string())
+            }
+            _ => None,
+        };
+        Ok(NodeValue::Literal {
+            value: literal.lexical_form().clone(),
+            language,
+            data_type,
+        })
+    } else {
+        Err(ErrorKind::RdfStarNotSupported(NAME.to_string()).into())
+    }
+}
+
+fn frame_strings(
+    value: &Value,
+    keyword: &str,
+    expand: impl Fn(&str) -> Option<String>,
+) -> Result<Vec<String>> {
+    let values = match value {
+        Value::String(value) => vec![value],
+        Value::Array(values) => {
+            let strings: Vec<&String> = values
+                .iter()
+                .filter_map(|value| match value {
+                    Value::String(value) => Some(value),
+                    _ => None,
+                })
+                .collect();
+            if strings.len() != values.len() {
+                return Err(write_error(&format!(
+                    "{} in a frame must be a string or array of strings",
+                    keyword
+                )));
+            }
+            strings
+        }
+        _ => {
+            return Err(write_error(&format!(
+                "{} in a frame must be a string or array of strings",
+                keyword
+            )))
+        }
+    };
+    Ok(values
+        .into_iter()
+        .map(|value| expand(value).unwrap_or_else(|| value.clone()))
+        .collect())
+}
+
+fn json_error(e: serde_json::Error) -> Error {
+    error!("Error writing JSON-LD: {:?}", e);
+    Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string()))
+}
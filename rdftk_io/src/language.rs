@@ -0,0 +1,230 @@
+/*!
+Provides the `LanguageFilteringWriter`, an implementation of the `GraphWriter` trait that
+restricts the language-tagged literals written by another writer to a set of languages, so that
+per-locale subsets of a multilingual graph may be published directly.
+
+A `LanguageFilter` holds a list of language ranges, matched against language tags using the
+*basic filtering* scheme of [RFC-4647](https://www.rfc-editor.org/rfc/rfc4647.html#section-3.3.1);
+a range matches a tag if it is equal to the tag, or to a prefix of the tag followed by `-`,
+ignoring case, and the range `*` matches any tag. So the range `en` matches `en`, `en-GB`, and
+`en-US`. Statements whose object is a literal tagged with a language that matches none of the
+ranges are not written.
+
+String literals without a language tag are written by default, this may be disabled with
+`keep_untagged`. Alternatively, `drop_untagged_duplicates` will omit only those untagged string
+literals with the same subject, predicate, and lexical form as a tagged literal that is written.
+Literals with any other data type, such as numbers and dates, are always written.
+
+# Example
+
+```rust
+use rdftk_io::language::{LanguageFilter, LanguageFilteringWriter};
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::write_graph_to_string;
+# use rdftk_core::model::graph::GraphRef;
+# fn make_graph() -> GraphRef { rdftk_core::simple::graph::graph_factory().graph() }
+
+let mut filter = LanguageFilter::new(&["en", "fr"]);
+let _ = filter.drop_untagged_duplicates(true);
+
+let writer = LanguageFilteringWriter::new(NTripleWriter::default(), filter);
+let result = write_graph_to_string(&writer, &make_graph());
+assert!(result.is_ok());
+```
+
+*/
+
+use crate::GraphWriter;
+use rdftk_core::error::Result;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralRef};
+use rdftk_core::model::statement::{StatementList, StatementRef, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use std::collections::HashSet;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The set of languages, and the treatment of untagged literals, used to filter statements.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LanguageFilter {
+    ranges: Vec<String>,
+    keep_untagged: bool,
+    drop_untagged_duplicates: bool,
+}
+
+///
+/// A writer that removes literals in other languages and writes the remaining graph using the
+/// wrapped writer.
+///
+#[derive(Debug)]
+pub struct LanguageFilteringWriter<W: GraphWriter> {
+    writer: W,
+    filter: LanguageFilter,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl LanguageFilter {
+    ///
+    /// Create a new filter that keeps literals tagged with a language matching any of `ranges`,
+    /// and all untagged literals.
+    ///
+    pub fn new(ranges: &[&str]) -> Self {
+        Self {
+            ranges: ranges
+                .iter()
+                .map(|range| range.trim().to_lowercase())
+                .filter(|range| !range.is_empty())
+                .collect(),
+            keep_untagged: true,
+            drop_untagged_duplicates: false,
+        }
+    }
+
+    ///
+    /// Add a language range to the filter.
+    ///
+    pub fn add_range(&mut self, range: &str) -> &mut Self {
+        let range = range.trim().to_lowercase();
+        if !range.is_empty() && !self.ranges.contains(&range) {
+            self.ranges.push(range);
+        }
+        self
+    }
+
+    ///
+    /// Set whether string literals without a language tag are kept, the default is `true`.
+    ///
+    pub fn keep_untagged(&mut self, keep_untagged: bool) -> &mut Self {
+        self.keep_untagged = keep_untagged;
+        self
+    }
+
+    ///
+    /// Set whether untagged string literals are dropped when a kept, tagged, literal has the same
+    /// subject, predicate, and lexical form. The default is `false`.
+    ///
+    pub fn drop_untagged_duplicates(&mut self, drop_untagged_duplicates: bool) -> &mut Self {
+        self.drop_untagged_duplicates = drop_untagged_duplicates;
+        self
+    }
+
+    ///
+    /// The language ranges, in lower case, matched by this filter.
+    ///
+    pub fn ranges(&self) -> impl Iterator<Item = &String> {
+        self.ranges.iter()
+    }
+
+    ///
+    /// Returns `true` if `language` matches any of the ranges of this filter.
+    ///
+    pub fn matches(&self, language: &LanguageTag) -> bool {
+        self.ranges.iter().any(|range| language.matches(range))
+    }
+
+    ///
+    /// Return the statements of `graph` that are kept by this filter, in graph order.
+    ///
+    pub fn statements(&self, graph: &GraphRef) -> StatementList {
+        let graph = graph.borrow();
+        let tagged: HashSet<(&SubjectNodeRef, &IRIRef, &String)> = if self.drop_untagged_duplicates
+        {
+            graph
+                .statements()
+                .filter_map(|statement| {
+                    literal(statement)
+                        .filter(|literal| {
+                            literal
+                                .language()
+                                .map(|language| self.matches(language))
+                                .unwrap_or_default()
+                        })
+                        .map(|literal| {
+                            (
+                                statement.subject(),
+                                statement.predicate(),
+                                literal.lexical_form(),
+                            )
+                        })
+                })
+                .collect()
+        } else {
+            Default::default()
+        };
+        graph
+            .statements()
+            .filter(|statement| match literal(statement) {
+                None => true,
+                Some(literal) => match literal.language() {
+                    Some(language) => self.matches(language),
+                    None if is_string(literal) => {
+                        self.keep_untagged
+                            && !tagged.contains(&(
+                                statement.subject(),
+                                statement.predicate(),
+                                literal.lexical_form(),
+                            ))
+                    }
+                    None => true,
+                },
+            })
+            .cloned()
+            .collect()
+    }
+
+    ///
+    /// Return a new graph, with the same prefix mappings, containing only those statements of
+    /// `graph` that are kept by this filter.
+    ///
+    pub fn filter(&self, graph: &GraphRef) -> GraphRef {
+        let statements = self.statements(graph);
+        let graph = graph.borrow();
+        graph
+            .factory()
+            .graph_from(&statements, Some(graph.prefix_mappings()))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<W: GraphWriter> GraphWriter for LanguageFilteringWriter<W> {
+    fn write(&self, w: &mut impl Write, graph: &GraphRef) -> Result<()> {
+        self.writer.write(w, &self.filter.filter(graph))
+    }
+}
+
+impl<W: GraphWriter> LanguageFilteringWriter<W> {
+    ///
+    /// Create a new writer that applies `filter` to the graph before writing with `writer`.
+    ///
+    pub fn new(writer: W, filter: LanguageFilter) -> Self {
+        Self { writer, filter }
+    }
+
+    ///
+    /// Return the filter applied by this writer.
+    ///
+    pub fn filter(&self) -> &LanguageFilter {
+        &self.filter
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn literal(statement: &StatementRef) -> Option<&LiteralRef> {
+    statement.object().as_literal()
+}
+
+fn is_string(literal: &LiteralRef) -> bool {
+    matches!(literal.data_type(), None | Some(DataType::String))
+}
@@ -5,24 +5,43 @@ This crate provides traits for reading and writing `Statement`s and `Graph`s as
 The following are some well-known formats (see [Wikipedia](https://en.wikipedia.org/wiki/Resource_Description_Framework#Serialization_formats)
 for a description of different serializations), support is indicated in the final column with
 an **R** for read support and **W** for write support. One additional module, `dot` allows for the
-creation of [GraphViz](https://graphviz.gitlab.io/) dot files for a visualization of a graph's structure.
+creation of [GraphViz](https://graphviz.gitlab.io/) dot files for a visualization of a graph's structure. The
+`redact` module provides a writer that withholds statements labeled as sensitive before
+delegating to any of these writers, the `language` module a writer that restricts literals to a
+set of languages, the `html` module a writer that embeds the output of another in an HTML
+`<script>` element, the `dereference` module a check that the IRIs of a published dataset may be
+dereferenced, the `http` module an HTTP client for this check and the clients below, the `results`
+module readers and writers for the SPARQL query results formats,
+the `sparql_client` module a client for remote SPARQL query endpoints, the `graph_store` module
+a client for remote graph stores, the `loader` module a linked data loader dereferencing IRIs, the `conformance` module the checks made by readers in strict
+mode, the `resolver` module nested JSON objects for resources shaped by a field definition, and
+the `config` module a reloadable configuration of prefix
+profiles and validation settings for long-running services. The `read_graph_from_path` and `read_data_set_from_path` functions will
+decompress their input, as described in the `compression` module, according to the signature
+or extension of the file they read. The `write_graph_to_path` and `write_data_set_to_path`
+functions will compress their output, as described in the `compression` module, according to the
+extension of the file they create; the `output` module describes how these functions replace a file
+atomically, and how to keep partial output. The N-Triples and N-Quads writers also implement
+`StatementWriter`, to write statements as they are produced rather than from a complete graph.
 
 
 | Module    | Name                                                                                                | MIME Type                   | R/W     |
 |-----------|---------------------------------------------------------------------------------------------------- |-----------------------------|---------|
 | `nt`      | [RDF 1.1 N-Triples](https://www.w3.org/TR/n-triples/); A line-based syntax for an RDF graph         | `application/n-triples`     | **R+W** |
-| `nq`      | [RDF 1.1 N-Quads](https://www.w3.org/TR/n-quads/); A line-based syntax for RDF datasets             | `application/n-quads`       | **W**   |
-| `turtle`  | [RDF 1.1 Turtle](https://www.w3.org/TR/turtle/); Terse RDF Triple Language                          | `text/turtle`               | **W**   |
-| `trig`    | [RDF 1.1 TriG](https://www.w3.org/TR/trig/); RDF Dataset Language                                   | `application/trig`          |         |
-| `xml`     | [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/)                                     | `application/rdf+xml`       | **W**   |
+| `nq`      | [RDF 1.1 N-Quads](https://www.w3.org/TR/n-quads/); A line-based syntax for RDF datasets             | `application/n-quads`       | **R+W** |
+| `turtle`  | [RDF 1.1 Turtle](https://www.w3.org/TR/turtle/); Terse RDF Triple Language                          | `text/turtle`               | **R+W** |
+| `trig`    | [RDF 1.1 TriG](https://www.w3.org/TR/trig/); RDF Dataset Language                                   | `application/trig`          | **R+W** |
+| `xml`     | [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/)                                     | `application/rdf+xml`       | **R+W** |
 | `json`    | [RDF 1.1 JSON Alternate Serialization](https://www.w3.org/TR/rdf-json/)                             | `application/rdf+json`      | **W**   |
 | `n3`      | [Notation3 (N3): A readable RDF syntax](https://www.w3.org/TeamSubmission/n3/)                      | `text/rdf+n3`               | **W**   |
 | TBD       | [Binary RDF Representation for Publication and Exchange (HDT)](https://www.w3.org/Submission/HDT/)  | N/A                         |         |
 | TBD       | [RDF Binary using Apache Thrift](https://afs.github.io/rdf-thrift/)                                 | `application/x-binary-rdf`  |         |
-| TBD       | [JSON-LD 1.1](https://www.w3.org/TR/json-ld/); A JSON-based Serialization for Linked Data           | `application/ld+json`       |         |
+| `json_ld` | [JSON-LD 1.1](https://www.w3.org/TR/json-ld/); A JSON-based Serialization for Linked Data           | `application/ld+json`       | **R+W** |
 | TBD       | [RDFa Core 1.1 - Third Edition](https://www.w3.org/TR/rdfa-core/)                                   | `text/html`                 |         |
 
-Each module will also provide public constants `NAME`, `FILE_EXTENSION`, and `MIME_TYPE`.
+Each module will also provide public constants `NAME`, `FILE_EXTENSION`, and `MIME_TYPE`. The
+`format` module provides a registry of these representations, so that a reader or writer may be
+chosen by media type or file extension.
 
 # Example
 
@@ -69,14 +88,18 @@ extern crate lazy_static;
 #[macro_use]
 extern crate log;
 
-#[cfg(any(feature = "nt", feature = "turtle"))]
+#[cfg(any(feature = "nq", feature = "nt", feature = "trig", feature = "turtle"))]
 #[macro_use]
 extern crate pest_derive;
 
+use crate::compression::{CompressedReader, Compression};
+use crate::output::OutputOptions;
 use rdftk_core::error::Result;
-use rdftk_core::model::data_set::DataSetRef;
-use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::model::data_set::{DataSetFactoryRef, DataSetRef};
+use rdftk_core::model::graph::{Graph, GraphFactoryRef, GraphRef};
+use rdftk_core::model::statement::StatementRef;
 use std::io::{Read, Write};
+use std::path::Path;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -89,6 +112,30 @@ use std::io::{Read, Write};
 pub trait GraphReader {
     /// Read a graph from the read implementation `r`.
     fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<GraphRef>;
+
+    /// Read statements, and prefix mappings, from the read implementation `r` into the existing
+    /// `graph`; this allows reading into graphs, such as a locked `ThreadedGraphRef`, that are not
+    /// held in a `GraphRef`.
+    fn read_into(&self, r: &mut impl Read, graph: &mut dyn Graph) -> Result<()> {
+        let read = self.read(r, graph.factory())?;
+        let read = read.borrow();
+        let mappings = graph.prefix_mappings();
+        for (prefix, namespace) in read.prefix_mappings().borrow().mappings() {
+            mappings.borrow_mut().insert(prefix, namespace.clone());
+        }
+        graph.set_prefix_mappings(mappings);
+        let _ = graph.extend_from(&mut read.statements().cloned());
+        Ok(())
+    }
+}
+
+///
+/// Read an entire `DataSet`, including any named graphs, from the provided implementation of
+/// [`Read`](https://doc.rust-lang.org/std/io/trait.Read.html).
+///
+pub trait DataSetReader {
+    /// Read a data set from the read implementation `r`.
+    fn read(&self, r: &mut impl Read, factory: DataSetFactoryRef) -> Result<DataSetRef>;
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -113,6 +160,30 @@ pub trait DataSetWriter {
     fn write(&self, w: &mut impl Write, data_set: &DataSetRef) -> Result<()>;
 }
 
+///
+/// Write [`Statement`](../rdftk_core/statement/struct.Statement.html)s one at a time, as they are
+/// produced, so that a large export need never hold a complete graph in memory. A writer is
+/// called once with `begin`, then with `write_statement` for each statement, and finally with
+/// `end`. As statements are not collected, any statement order configured for the writer's
+/// `GraphWriter` form is not applied.
+///
+pub trait StatementWriter {
+    /// Write anything that must precede the first statement; by default nothing.
+    fn begin(&mut self, w: &mut impl Write) -> Result<()> {
+        let _ = w;
+        Ok(())
+    }
+
+    /// Write the formatted `statement` using the write implementation `w`.
+    fn write_statement(&mut self, w: &mut impl Write, statement: &StatementRef) -> Result<()>;
+
+    /// Write anything that must follow the last statement; by default nothing.
+    fn end(&mut self, w: &mut impl Write) -> Result<()> {
+        let _ = w;
+        Ok(())
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -139,6 +210,155 @@ pub fn write_data_set_to_string(w: &impl DataSetWriter, data_set: &DataSetRef) -
     Ok(String::from_utf8(buffer.into_inner()).unwrap())
 }
 
+///
+/// A convenience function that will write each of `statements`, as they are produced by the
+/// iterator, using the `StatementWriter`; `begin` and `end` are called before the first and after
+/// the last statement.
+///
+pub fn write_statements(
+    writer: &mut impl StatementWriter,
+    w: &mut impl Write,
+    statements: impl IntoIterator<Item = StatementRef>,
+) -> Result<()> {
+    writer.begin(w)?;
+    for statement in statements {
+        writer.write_statement(w, &statement)?;
+    }
+    writer.end(w)
+}
+
+///
+/// A convenience function that will read a `Graph` using the `GraphReader` from the file at `path`,
+/// decompressed according to the file's signature or extension.
+///
+pub fn read_graph_from_path(
+    r: &impl GraphReader,
+    path: impl AsRef<Path>,
+    factory: GraphFactoryRef,
+) -> Result<GraphRef> {
+    let mut file = CompressedReader::open(path.as_ref(), None)?;
+    r.read(&mut file, factory)
+}
+
+///
+/// A convenience function that will read a `Graph` using the `GraphReader` from the file at `path`,
+/// streamed through the given `compression`.
+///
+pub fn read_graph_from_path_with(
+    r: &impl GraphReader,
+    path: impl AsRef<Path>,
+    factory: GraphFactoryRef,
+    compression: Compression,
+) -> Result<GraphRef> {
+    let mut file = CompressedReader::open(path.as_ref(), Some(compression))?;
+    r.read(&mut file, factory)
+}
+
+///
+/// A convenience function that will read a `DataSet` using the `DataSetReader` from the file at
+/// `path`, decompressed according to the file's signature or extension.
+///
+pub fn read_data_set_from_path(
+    r: &impl DataSetReader,
+    path: impl AsRef<Path>,
+    factory: DataSetFactoryRef,
+) -> Result<DataSetRef> {
+    let mut file = CompressedReader::open(path.as_ref(), None)?;
+    r.read(&mut file, factory)
+}
+
+///
+/// A convenience function that will read a `DataSet` using the `DataSetReader` from the file at
+/// `path`, streamed through the given `compression`.
+///
+pub fn read_data_set_from_path_with(
+    r: &impl DataSetReader,
+    path: impl AsRef<Path>,
+    factory: DataSetFactoryRef,
+    compression: Compression,
+) -> Result<DataSetRef> {
+    let mut file = CompressedReader::open(path.as_ref(), Some(compression))?;
+    r.read(&mut file, factory)
+}
+
+///
+/// A convenience function that will write the output of the `GraphWriter` for the given `Graph`
+/// instance to the file at `path`, compressed according to the file's extension.
+///
+pub fn write_graph_to_path(
+    w: &impl GraphWriter,
+    graph: &GraphRef,
+    path: impl AsRef<Path>,
+) -> Result<()> {
+    write_graph_to_path_with_options(w, graph, path, &OutputOptions::default())
+}
+
+///
+/// A convenience function that will write the output of the `GraphWriter` for the given `Graph`
+/// instance to the file at `path`, streamed through the given `compression`.
+///
+pub fn write_graph_to_path_with(
+    w: &impl GraphWriter,
+    graph: &GraphRef,
+    path: impl AsRef<Path>,
+    compression: Compression,
+) -> Result<()> {
+    let options = OutputOptions::default().with_compression(compression);
+    write_graph_to_path_with_options(w, graph, path, &options)
+}
+
+///
+/// A convenience function that will write the output of the `GraphWriter` for the given `Graph`
+/// instance to the file at `path`, as described by `options`.
+///
+pub fn write_graph_to_path_with_options(
+    w: &impl GraphWriter,
+    graph: &GraphRef,
+    path: impl AsRef<Path>,
+    options: &OutputOptions,
+) -> Result<()> {
+    output::write_to_path(path.as_ref(), options, |file| w.write(file, graph))
+}
+
+///
+/// A convenience function that will write the output of the `DataSetWriter` for the given
+/// `DataSet` instance to the file at `path`, compressed according to the file's extension.
+///
+pub fn write_data_set_to_path(
+    w: &impl DataSetWriter,
+    data_set: &DataSetRef,
+    path: impl AsRef<Path>,
+) -> Result<()> {
+    write_data_set_to_path_with_options(w, data_set, path, &OutputOptions::default())
+}
+
+///
+/// A convenience function that will write the output of the `DataSetWriter` for the given
+/// `DataSet` instance to the file at `path`, streamed through the given `compression`.
+///
+pub fn write_data_set_to_path_with(
+    w: &impl DataSetWriter,
+    data_set: &DataSetRef,
+    path: impl AsRef<Path>,
+    compression: Compression,
+) -> Result<()> {
+    let options = OutputOptions::default().with_compression(compression);
+    write_data_set_to_path_with_options(w, data_set, path, &options)
+}
+
+///
+/// A convenience function that will write the output of the `DataSetWriter` for the given
+/// `DataSet` instance to the file at `path`, as described by `options`.
+///
+pub fn write_data_set_to_path_with_options(
+    w: &impl DataSetWriter,
+    data_set: &DataSetRef,
+    path: impl AsRef<Path>,
+    options: &OutputOptions,
+) -> Result<()> {
+    output::write_to_path(path.as_ref(), options, |file| w.write(file, data_set))
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -146,12 +366,41 @@ pub fn write_data_set_to_string(w: &impl DataSetWriter, data_set: &DataSetRef) -
 #[macro_use]
 mod common;
 
+pub mod compression;
+
+#[cfg(feature = "config")]
+pub mod config;
+
+pub mod conformance;
+
+pub mod dereference;
+
+pub mod diagnostic;
+
 #[cfg(feature = "dot")]
 pub mod dot;
 
+pub mod format;
+
+#[cfg(feature = "graph-store")]
+pub mod graph_store;
+
 #[cfg(feature = "json")]
 pub mod json;
 
+#[cfg(feature = "json-ld")]
+pub mod json_ld;
+
+pub mod html;
+
+#[cfg(feature = "http")]
+pub mod http;
+
+pub mod language;
+
+#[cfg(feature = "loader")]
+pub mod loader;
+
 #[cfg(feature = "n3")]
 #[doc(hidden)]
 pub mod n3;
@@ -162,13 +411,29 @@ pub mod nq;
 #[cfg(feature = "nt")]
 pub mod nt;
 
+pub mod output;
+
+pub mod redact;
+
+#[cfg(feature = "resolver")]
+pub mod resolver;
+
+#[cfg(feature = "sparql-results")]
+pub mod results;
+
+#[cfg(feature = "sparql-client")]
+pub mod sparql_client;
+
+pub mod stability;
+
 #[cfg(feature = "trig")]
-#[doc(hidden)]
 pub mod trig;
 
 #[cfg(feature = "turtle")]
-#[doc(hidden)]
 pub mod turtle;
 
+#[cfg(feature = "watch")]
+pub mod watch;
+
 #[cfg(feature = "xml")]
 pub mod xml;
@@ -0,0 +1,417 @@
+/*!
+Provides `Loader`, which dereferences an IRI as linked data, returning the graph it describes.
+
+The document identified by the IRI, without any fragment, is requested with `GET` and an `Accept`
+header listing the media types of every enabled format that can read a graph. Redirects, such as
+the `303 See Other` responses common for linked data, are followed by the loader itself up to a
+configurable limit, resolving relative `Location` headers. The final response is read using the
+reader, from the `format` module, for its `Content-Type`, and any relative IRIs in the document are
+resolved against the IRI of the final location.
+
+The size of each response body, and the total time taken by all the requests for an IRI, are
+limited; by default to `DEFAULT_MAX_SIZE` bytes and `DEFAULT_TIMEOUT`. Each request carries the
+remaining limits so that a transport may stop reading early, and the loader also checks each
+response, failing with a `LimitExceeded` error once a limit is passed.
+
+As with the `sparql_client` module requests are made through an implementation of the `Transport`
+trait, such as the `HttpTransport` of the `http` module (the `http` feature), which is expected
+*not* to follow redirects. Closures of the form `Fn(&Request) -> Result<Response>` implement
+`Transport`.
+
+# Example
+
+```rust
+use rdftk_core::error::Result;
+use rdftk_io::loader::{Loader, Request, Response};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+let transport = |request: &Request| -> Result<Response> {
+    Ok(match request.iri().to_string().as_str() {
+        "http://example.org/id/alice" => {
+            Response::new(303, None, Vec::new()).with_location("/doc/alice")
+        }
+        "http://example.org/doc/alice" => Response::new(
+            200,
+            Some("text/turtle; charset=utf-8"),
+            b"<../id/alice> <http://xmlns.com/foaf/0.1/name> \"Alice\" .".to_vec(),
+        ),
+        _ => Response::new(404, None, Vec::new()),
+    })
+};
+
+let loader = Loader::new(transport);
+let alice = IRIRef::from(IRI::from_str("http://example.org/id/alice#me").unwrap());
+let graph = loader.load(&alice).unwrap();
+assert_eq!(graph.borrow().len(), 1);
+```
+
+*/
+
+use crate::common::parser::resolve;
+use crate::format::{graph_accept, reader_for};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The display name of this loader.
+///
+pub const NAME: &str = "Linked Data Loader";
+
+///
+/// The default limit on the size of a response body, in bytes; 16 MiB.
+///
+pub const DEFAULT_MAX_SIZE: usize = 16 * 1024 * 1024;
+
+///
+/// The default limit on the total time taken to dereference an IRI.
+///
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+///
+/// The default limit on the number of redirects followed.
+///
+pub const DEFAULT_MAX_REDIRECTS: usize = 10;
+
+///
+/// A `GET` request to be sent for a document.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    iri: IRIRef,
+    accept: String,
+    timeout: Duration,
+    max_size: usize,
+}
+
+///
+/// The parts of an HTTP response used by the loader.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    status: u16,
+    content_type: Option<String>,
+    location: Option<String>,
+    body: Vec<u8>,
+}
+
+///
+/// Performs HTTP requests on behalf of the loader.
+///
+pub trait Transport {
+    ///
+    /// Send `request`, returning the response without following any redirect. An error is
+    /// returned if no response was received.
+    ///
+    fn get(&self, request: &Request) -> Result<Response>;
+}
+
+///
+/// Dereferences IRIs using a `Transport`.
+///
+#[derive(Debug)]
+pub struct Loader<T: Transport> {
+    transport: T,
+    factory: GraphFactoryRef,
+    max_size: usize,
+    timeout: Duration,
+    max_redirects: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Request {
+    ///
+    /// Return the IRI of the document requested.
+    ///
+    pub fn iri(&self) -> &IRIRef {
+        &self.iri
+    }
+
+    ///
+    /// Return the value of the `Accept` header for this request.
+    ///
+    pub fn accept(&self) -> &String {
+        &self.accept
+    }
+
+    ///
+    /// Return the time remaining for this request.
+    ///
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    ///
+    /// Return the largest response body, in bytes, that will be accepted.
+    ///
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Response {
+    ///
+    /// Create a new response with the given status code, the value of the `Content-Type` header,
+    /// if any, and the body.
+    ///
+    pub fn new(status: u16, content_type: Option<&str>, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            content_type: content_type.map(str::to_string),
+            location: None,
+            body,
+        }
+    }
+
+    ///
+    /// Set the value of the `Location` header of this response.
+    ///
+    pub fn with_location(self, location: &str) -> Self {
+        Self {
+            location: Some(location.to_string()),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the status code of this response.
+    ///
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    ///
+    /// Return the value of the `Content-Type` header, if any.
+    ///
+    pub fn content_type(&self) -> Option<&String> {
+        self.content_type.as_ref()
+    }
+
+    ///
+    /// Return the value of the `Location` header, if any.
+    ///
+    pub fn location(&self) -> Option<&String> {
+        self.location.as_ref()
+    }
+
+    ///
+    /// Return the body of this response.
+    ///
+    pub fn body(&self) -> &Vec<u8> {
+        &self.body
+    }
+
+    ///
+    /// Returns `true` if the status code is in the `2xx` range.
+    ///
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    ///
+    /// Returns `true` if the status code is one of the redirects `301`, `302`, `303`, `307`, or
+    /// `308`.
+    ///
+    pub fn is_redirect(&self) -> bool {
+        matches!(self.status, 301 | 302 | 303 | 307 | 308)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F> Transport for F
+where
+    F: Fn(&Request) -> Result<Response>,
+{
+    fn get(&self, request: &Request) -> Result<Response> {
+        self(request)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T: Transport> Loader<T> {
+    ///
+    /// Create a new loader with the default limits; graphs are created with the simple graph
+    /// factory.
+    ///
+    pub fn new(transport: T) -> Self {
+        Self::with_factory(transport, graph_factory())
+    }
+
+    ///
+    /// Create a new loader with the default limits; graphs are created with `factory`.
+    ///
+    pub fn with_factory(transport: T, factory: GraphFactoryRef) -> Self {
+        Self {
+            transport,
+            factory,
+            max_size: DEFAULT_MAX_SIZE,
+            timeout: DEFAULT_TIMEOUT,
+            max_redirects: DEFAULT_MAX_REDIRECTS,
+        }
+    }
+
+    ///
+    /// Set the limit on the size of a response body, in bytes.
+    ///
+    pub fn with_max_size(self, max_size: usize) -> Self {
+        Self { max_size, ..self }
+    }
+
+    ///
+    /// Set the limit on the total time taken to dereference an IRI.
+    ///
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    ///
+    /// Set the limit on the number of redirects followed.
+    ///
+    pub fn with_max_redirects(self, max_redirects: usize) -> Self {
+        Self {
+            max_redirects,
+            ..self
+        }
+    }
+
+    ///
+    /// Return the transport used to send requests.
+    ///
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    ///
+    /// Return the limit on the size of a response body, in bytes.
+    ///
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    ///
+    /// Return the limit on the total time taken to dereference an IRI.
+    ///
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    ///
+    /// Return the limit on the number of redirects followed.
+    ///
+    pub fn max_redirects(&self) -> usize {
+        self.max_redirects
+    }
+
+    ///
+    /// Dereference `iri`, returning the graph read from the final response.
+    ///
+    pub fn load(&self, iri: &IRIRef) -> Result<GraphRef> {
+        let started = Instant::now();
+        let accept = graph_accept();
+        let mut location = iri.to_string();
+        if let Some(end) = location.find('#') {
+            location.truncate(end);
+        }
+        let mut redirects = 0;
+        loop {
+            let timeout = match self.timeout.checked_sub(started.elapsed()) {
+                Some(timeout) if !timeout.is_zero() => timeout,
+                _ => return Err(self.time_exceeded()),
+            };
+            let request = Request {
+                iri: parse_iri(&location)?,
+                accept: accept.clone(),
+                timeout,
+                max_size: self.max_size,
+            };
+            let response = self.transport.get(&request)?;
+            if started.elapsed() > self.timeout {
+                return Err(self.time_exceeded());
+            }
+            if response.body.len() > self.max_size {
+                return Err(ErrorKind::LimitExceeded(
+                    "size".to_string(),
+                    format!("{} bytes", self.max_size),
+                )
+                .into());
+            }
+
+            if response.is_redirect() {
+                let target = match response.location() {
+                    Some(target) => match IRI::from_str(target) {
+                        Ok(target) if target.is_absolute() => target.to_string(),
+                        _ => resolve(&location, target),
+                    },
+                    None => {
+                        return Err(load_error(&format!(
+                            "redirect from <{}> has no location",
+                            location
+                        )))
+                    }
+                };
+                if redirects == self.max_redirects {
+                    return Err(ErrorKind::LimitExceeded(
+                        "redirect".to_string(),
+                        self.max_redirects.to_string(),
+                    )
+                    .into());
+                }
+                redirects += 1;
+                location = target;
+            } else if response.is_success() {
+                return match response.content_type().and_then(|s| reader_for(s)) {
+                    Some(format) if format.reads_graph() => format.read_graph_with_base(
+                        &mut response.body().as_slice(),
+                        self.factory.clone(),
+                        &request.iri,
+                    ),
+                    _ => Err(load_error(&format!(
+                        "unsupported content type {:?} from <{}>",
+                        response.content_type(),
+                        location
+                    ))),
+                };
+            } else {
+                return Err(load_error(&format!(
+                    "<{}> returned status {}",
+                    location,
+                    response.status()
+                )));
+            }
+        }
+    }
+
+    fn time_exceeded(&self) -> Error {
+        ErrorKind::LimitExceeded("time".to_string(), format!("{:?}", self.timeout)).into()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn parse_iri(iri: &str) -> Result<IRIRef> {
+    IRI::from_str(iri)
+        .map(IRIRef::from)
+        .map_err(|_| load_error(&format!("invalid location {:?}", iri)))
+}
+
+fn load_error(message: &str) -> Error {
+    error!("Loader {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
@@ -1,5 +1,5 @@
 /*!
-Provides for reading and writing a `Graph` instance in the
+Provides for reading and writing a `DataSet` instance in the
 W3C [RDF 1.1 N-Quads](https://www.w3.org/TR/n-quads/), _a line-based syntax for RDF datasets_,
 format.
 */
@@ -26,7 +26,6 @@ pub const FORMAT_IRI: &str = "http://www.w3.org/ns/formats/N-Quads";
 
 mod parser;
 
-#[doc(hidden)]
 pub mod reader;
 
 pub mod writer;
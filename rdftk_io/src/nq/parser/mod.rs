@@ -1,15 +1,25 @@
 /*!
-One-line description.
-
-More detailed description, with
-
-# Example
+A parser for the W3C [RDF 1.1 N-Quads](https://www.w3.org/TR/n-quads/) format, producing a
+`DataSet` with a default graph and any named graphs referenced by quads.
 
 */
 
 #![allow(clippy::upper_case_acronyms)] // << generated by pest.
 
+use crate::common::parser::{unescape_iri, unescape_string};
+use crate::common::parser_error::ParserErrorFactory;
+use pest::iterators::Pair;
 use pest::Parser;
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::data_set::{DataSetFactoryRef, DataSetRef, GraphName, GraphNameRef};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralFactoryRef, LiteralRef};
+use rdftk_core::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef,
+};
+use rdftk_iri::{IRIRef, IRI};
+use std::rc::Rc;
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -23,20 +33,16 @@ struct NQuadParser;
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
+const ERROR: ParserErrorFactory = ParserErrorFactory { repr: super::NAME };
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)]
-pub(super) fn parse_text(input: &str) {
-    let result = NQuadParser::parse(Rule::nquadsDoc, input);
-    match result {
-        Ok(parsed) => println!("{:#?}", parsed),
-        Err(err) => {
-            println!("{}", err);
-            panic!("test failed");
-        }
-    }
+pub(super) fn parse_data_set(input: &str, factory: DataSetFactoryRef) -> Result<DataSetRef> {
+    let mut parsed = NQuadParser::parse(Rule::nquadsDoc, input).map_err(|e| ERROR.parser(e))?;
+    let top_node = parsed.next().unwrap();
+    nquads_doc(top_node, factory)
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -47,6 +53,275 @@ pub(super) fn parse_text(input: &str) {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn nquads_doc(input_pair: Pair<'_, Rule>, factory: DataSetFactoryRef) -> Result<DataSetRef> {
+    trace!("nquads_doc({:?})", &input_pair.as_rule());
+
+    let data_set = factory.data_set(None);
+
+    if input_pair.as_rule() == Rule::nquadsDoc {
+        let graph_factory = data_set.borrow().graph_factory();
+        let (statements, literals) = {
+            let graph = graph_factory.graph();
+            let graph = graph.borrow();
+            (graph.statement_factory(), graph.literal_factory())
+        };
+        for inner_pair in input_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::quad => {
+                    let (st, graph_name) = quad(inner_pair, &statements, &literals)?;
+                    let mut data_set = data_set.borrow_mut();
+                    let graph: GraphRef = match &graph_name {
+                        None => {
+                            if !data_set.has_default_graph() {
+                                data_set.set_default_graph(graph_factory.graph());
+                            }
+                            data_set.default_graph().unwrap().clone()
+                        }
+                        Some(graph_name) => {
+                            if !data_set.has_graph_named(graph_name) {
+                                data_set.insert(graph_name.clone(), graph_factory.graph());
+                            }
+                            data_set.graph_named(graph_name).unwrap().clone()
+                        }
+                    };
+                    graph.borrow_mut().insert(st);
+                }
+                Rule::EOI => {
+                    trace!("Done.")
+                }
+                _ => {
+                    unexpected!("nquads_doc", inner_pair)
+                }
+            }
+        }
+    } else {
+        unexpected!("nquads_doc", input_pair);
+    }
+
+    Ok(data_set)
+}
+
+fn quad(
+    input_pair: Pair<'_, Rule>,
+    statements: &StatementFactoryRef,
+    literals: &LiteralFactoryRef,
+) -> Result<(StatementRef, Option<GraphNameRef>)> {
+    trace!("quad({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::quad {
+        let mut inner_pairs = input_pair.into_inner();
+        let subject = subject(inner_pairs.next().unwrap(), statements)?;
+        let predicate = predicate(inner_pairs.next().unwrap())?;
+        let object = object(inner_pairs.next().unwrap(), statements, literals)?;
+        let graph_name = match inner_pairs.next() {
+            None => None,
+            Some(inner_pair) => Some(graph_label(inner_pair)?),
+        };
+        Ok((
+            statements.statement(subject, predicate, object)?,
+            graph_name,
+        ))
+    } else {
+        unexpected!("quad", input_pair);
+    }
+}
+
+fn graph_label(input_pair: Pair<'_, Rule>) -> Result<GraphNameRef> {
+    trace!("graph_label({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::graphLabel {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::IRIREF => Ok(GraphName::named_ref(iri_ref(inner_pair)?)),
+            Rule::BlankNode => {
+                let node = inner_pair.as_str().to_string();
+                // strip the leading '_:'
+                let node = &node[2..];
+                Ok(Rc::from(GraphName::blank_named(node)))
+            }
+            _ => {
+                unexpected!("graph_label", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("graph_label", input_pair);
+    }
+}
+
+fn subject(input_pair: Pair<'_, Rule>, factory: &StatementFactoryRef) -> Result<SubjectNodeRef> {
+    trace!("subject({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::subject {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::IRIREF => Ok(factory.named_subject(iri_ref(inner_pair)?)),
+            Rule::BlankNode => {
+                let node = inner_pair.as_str().to_string();
+                // strip the leading '_:'
+                let node = &node[2..];
+                factory.blank_subject_named(node)
+            }
+            _ => {
+                unexpected!("subject", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("subject", input_pair);
+    }
+}
+
+fn predicate(input_pair: Pair<'_, Rule>) -> Result<IRIRef> {
+    trace!("predicate({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::predicate {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        if inner_pair.as_rule() == Rule::IRIREF {
+            Ok(iri_ref(inner_pair)?)
+        } else {
+            unexpected!("subject", inner_pair);
+        }
+    } else {
+        unexpected!("subject", input_pair);
+    }
+}
+
+fn object(
+    input_pair: Pair<'_, Rule>,
+    factory: &StatementFactoryRef,
+    literals: &LiteralFactoryRef,
+) -> Result<ObjectNodeRef> {
+    trace!("object({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::object {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::IRIREF => Ok(factory.named_object(iri_ref(inner_pair)?)),
+            Rule::BlankNode => {
+                let node = inner_pair.as_str().to_string();
+                // strip the leading '_:'
+                let node = &node[2..];
+                Ok(factory.blank_object_named(node)?)
+            }
+            Rule::literal => {
+                let literal = literal(inner_pair, literals)?;
+                Ok(factory.literal_object(literal))
+            }
+            _ => {
+                unexpected!("object", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("object", input_pair);
+    }
+}
+
+fn literal(input_pair: Pair<'_, Rule>, literals: &LiteralFactoryRef) -> Result<LiteralRef> {
+    trace!("literal({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::literal {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        rdf_literal(inner_pair, literals)
+    } else {
+        unexpected!("literal", input_pair);
+    }
+}
+
+fn rdf_literal(input_pair: Pair<'_, Rule>, literals: &LiteralFactoryRef) -> Result<LiteralRef> {
+    trace!("literal({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::rdfLiteral {
+        let mut inner_pair = input_pair.into_inner();
+        let lexical_form = string(inner_pair.next().unwrap())?;
+
+        if let Some(other) = inner_pair.next() {
+            match other.as_rule() {
+                Rule::iri => {
+                    let data_type = DataType::Other(iri(other)?);
+                    Ok(literals.with_data_type(&lexical_form, data_type))
+                }
+                Rule::LANGTAG => {
+                    let lang_tag = lang_tag(other)?;
+                    Ok(literals.with_language(&lexical_form, lang_tag))
+                }
+                _ => {
+                    unexpected!("literal", other);
+                }
+            }
+        } else {
+            Ok(literals.literal(&lexical_form))
+        }
+    } else {
+        unexpected!("literal", input_pair);
+    }
+}
+
+fn string(input_pair: Pair<'_, Rule>) -> Result<String> {
+    trace!("string({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::String {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::STRING_LITERAL_QUOTE => {
+                let inner_pair = inner_pair.into_inner().next().unwrap();
+                if inner_pair.as_rule() == Rule::QUOTE_INNER {
+                    Ok(unescape_string(inner_pair.as_str()))
+                } else {
+                    unexpected!("string", inner_pair);
+                }
+            }
+            _ => {
+                unexpected!("string", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("string", input_pair);
+    }
+}
+
+fn iri(input_pair: Pair<'_, Rule>) -> Result<IRIRef> {
+    trace!("iri({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::iri {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        if inner_pair.as_rule() == Rule::IRIREF {
+            iri_ref(inner_pair)
+        } else {
+            unexpected!("iri", inner_pair);
+        }
+    } else {
+        unexpected!("iri", input_pair);
+    }
+}
+
+fn iri_ref(input_pair: Pair<'_, Rule>) -> Result<IRIRef> {
+    trace!("iri_ref({:?})", &input_pair.as_rule());
+    if input_pair.as_rule() == Rule::IRIREF {
+        let iri = input_pair.as_str().to_string();
+        // strip the '<' and '>' characters.
+        let iri_str = unescape_iri(&iri[1..iri.len() - 1]);
+        let iri = IRIRef::new(IRI::from_str(&iri_str)?);
+        if !iri.is_relative_reference() {
+            Ok(iri)
+        } else {
+            Err(ErrorKind::AbsoluteIriExpected(iri_str).into())
+        }
+    } else {
+        unexpected!("iri_ref", input_pair);
+    }
+}
+
+fn lang_tag(input_pair: Pair<'_, Rule>) -> Result<LanguageTag> {
+    trace!("lang_tag({:?})", &input_pair.as_rule());
+    if input_pair.as_rule() == Rule::LANGTAG {
+        let tag = input_pair.as_str().to_string();
+        // strip the leading '@'
+        let tag = &tag[1..];
+        Ok(LanguageTag::from_str(tag)?)
+    } else {
+        unexpected!("lang_tag", input_pair);
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -54,26 +329,58 @@ pub(super) fn parse_text(input: &str) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rdftk_core::simple::data_set::data_set_factory;
+
+    fn parse_text(input: &str) -> DataSetRef {
+        match parse_data_set(input, data_set_factory()) {
+            Ok(data_set) => data_set,
+            Err(e) => {
+                println!("{:?}", e);
+                panic!("test failed");
+            }
+        }
+    }
 
     #[test]
     fn parse_simple_1() {
-        parse_text(
+        let data_set = parse_text(
             r###"
 <http://one.example/subject1> <http://one.example/predicate1> <http://one.example/object1> <http://example.org/graph3> . # comments here
 # or on a line by themselves
 _:subject1 <http://an.example/predicate1> "object1" <http://example.org/graph1> .
 _:subject2 <http://an.example/predicate2> "object2" <http://example.org/graph5> .
 "###,
-        )
+        );
+        let data_set = data_set.borrow();
+        assert!(!data_set.has_default_graph());
+        assert_eq!(data_set.len(), 3);
     }
 
     #[test]
     fn parse_simple_2() {
-        parse_text(
+        let data_set = parse_text(
             r###"
 _:alice <http://xmlns.com/foaf/0.1/knows> _:bob <http://example.org/graphs/john> .
 _:bob <http://xmlns.com/foaf/0.1/knows> _:alice <http://example.org/graphs/james> .
 "###,
-        )
+        );
+        let data_set = data_set.borrow();
+        assert_eq!(data_set.len(), 2);
+    }
+
+    #[test]
+    fn parse_default_and_blank_graphs() {
+        let data_set = parse_text(
+            r###"
+<http://one.example/subject1> <http://one.example/predicate1> <http://one.example/object1> .
+<http://one.example/subject1> <http://one.example/predicate2> <http://one.example/object2> .
+<http://one.example/subject1> <http://one.example/predicate1> "object1"@en _:g1 .
+"###,
+        );
+        let data_set = data_set.borrow();
+        assert_eq!(data_set.len(), 2);
+        assert_eq!(data_set.default_graph().unwrap().borrow().len(), 2);
+        let blank_name: GraphNameRef = Rc::from(GraphName::blank_named("g1"));
+        assert_eq!(data_set.graph_named(&blank_name).unwrap().borrow().len(), 1);
     }
 }
@@ -1,34 +1,81 @@
 /*!
-One-line description.
-
-More detailed description, with
+Provides the `NQuadDataSetReader` implementation of the `DataSetReader` trait.
 
 # Example
 
+```rust
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_io::nq::reader::NQuadDataSetReader;
+use rdftk_io::DataSetReader;
+use std::fs::File;
+use std::path::PathBuf;
+
+let file_path = PathBuf::from("tests/w3c/nq/nq-syntax-uri-01.nq");
+let mut file = File::open(file_path).unwrap();
+let reader = NQuadDataSetReader::default();
+assert!(reader.read(&mut file, data_set_factory()).is_ok());
+```
+
 */
 
-// use ...
+use crate::conformance::conform;
+use crate::format::Format;
+use crate::nq::parser;
+use crate::DataSetReader;
+use rdftk_core::error::Result;
+use rdftk_core::model::data_set::{DataSetFactoryRef, DataSetRef};
+use std::io::Read;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
-
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+///
+/// An implementation of the DataSetReader trait to read resources in the N-Quads representation.
+/// Quads without a graph label are added to the data set's default graph.
+///
+#[derive(Clone, Debug, Default)]
+pub struct NQuadDataSetReader {
+    strict: bool,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl NQuadDataSetReader {
+    ///
+    /// If `strict` is `true`, the input is checked for conformance to the N-Quads specification,
+    /// see the `conformance` module, and any violation aborts the read.
+    ///
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict }
+    }
+
+    ///
+    /// Returns `true` if the input is checked for conformance before it is parsed.
+    ///
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+}
+
+impl DataSetReader for NQuadDataSetReader {
+    fn read(&self, r: &mut impl Read, factory: DataSetFactoryRef) -> Result<DataSetRef> {
+        let mut content: String = String::new();
+        let _ = r.read_to_string(&mut content).map_err(io_error)?;
+        if self.strict {
+            conform(Format::NQuads, &content)?;
+        }
+        parser::parse_data_set(&content, factory)
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn io_error(e: std::io::Error) -> rdftk_core::error::Error {
+    use rdftk_core::error::ErrorKind;
+    rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
+}
@@ -1,6 +1,6 @@
 /*!
 Provides the `NQuadDataSetWriter` implementation of the `DataSetWriter` trait and the
-`NQuadGraphWriter` implementation of the `GraphWriter` trait.
+`NQuadGraphWriter` implementation of the `GraphWriter` and `StatementWriter` traits.
 
 # Example
 
@@ -20,10 +20,13 @@ let result = write_data_set_to_string(&writer, &make_data_set());
 
 */
 
-use crate::{DataSetWriter, GraphWriter};
+use crate::{DataSetWriter, GraphWriter, StatementWriter};
 use rdftk_core::error::Result;
 use rdftk_core::model::data_set::{DataSetRef, GraphNameRef};
+use rdftk_core::model::graph::order::{lexical_order, sorted_statements, StatementOrderRef};
 use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
+use rdftk_iri::IRIRef;
 use std::io::Write;
 
 // ------------------------------------------------------------------------------------------------
@@ -38,12 +41,13 @@ use std::io::Write;
 pub struct NQuadDataSetWriter {}
 
 ///
-/// This struct implements the `DataSetWriter` trait and will write out a serialized form of the
-/// entire data set.
+/// This struct implements the `GraphWriter` trait and will write out a serialized form of a
+/// single graph, using the optional name as the graph label of each quad.
 ///
 #[derive(Debug)]
 pub struct NQuadGraphWriter {
     name: Option<GraphNameRef>,
+    order: Option<StatementOrderRef>,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -75,22 +79,30 @@ impl DataSetWriter for NQuadDataSetWriter {
 
 impl Default for NQuadGraphWriter {
     fn default() -> Self {
-        Self { name: None }
+        Self {
+            name: None,
+            order: None,
+        }
     }
 }
 
 impl GraphWriter for NQuadGraphWriter {
     fn write(&self, w: &mut impl Write, graph: &GraphRef) -> Result<()> {
         let graph = graph.borrow();
-        for subject in graph.subjects() {
-            for predicate in graph.predicates_for(subject) {
-                for object in graph.objects_for(subject, predicate) {
-                    if let Some(graph_name) = &self.name {
-                        writeln!(w, "{} <{}> {} {} .", subject, predicate, object, graph_name)
-                            .map_err(io_error)?;
-                    } else {
-                        writeln!(w, "{} <{}> {} .", subject, predicate, object)
-                            .map_err(io_error)?;
+        if let Some(order) = &self.order {
+            for statement in sorted_statements(&*graph, &**order) {
+                self.write_quad(
+                    w,
+                    statement.subject(),
+                    statement.predicate(),
+                    statement.object(),
+                )?;
+            }
+        } else {
+            for subject in graph.subjects() {
+                for predicate in graph.predicates_for(subject) {
+                    for object in graph.objects_for(subject, predicate) {
+                        self.write_quad(w, subject, predicate, object)?;
                     }
                 }
             }
@@ -99,10 +111,60 @@ impl GraphWriter for NQuadGraphWriter {
     }
 }
 
+impl StatementWriter for NQuadGraphWriter {
+    fn write_statement(&mut self, w: &mut impl Write, statement: &StatementRef) -> Result<()> {
+        self.write_quad(
+            w,
+            statement.subject(),
+            statement.predicate(),
+            statement.object(),
+        )
+    }
+}
+
 impl NQuadGraphWriter {
     /// Construct a new quad writer with the provided graph name.
     pub fn named(name: GraphNameRef) -> Self {
-        Self { name: Some(name) }
+        Self {
+            name: Some(name),
+            order: None,
+        }
+    }
+
+    ///
+    /// Write statements sorted by subject, predicate, and object, rather than in the graph's
+    /// iteration order, so that output is reproducible; see `LexicalOrder`.
+    ///
+    pub fn sorted(self, sorted: bool) -> Self {
+        Self {
+            order: if sorted { Some(lexical_order()) } else { None },
+            ..self
+        }
+    }
+
+    ///
+    /// Write statements sorted by `order`.
+    ///
+    pub fn ordered(self, order: StatementOrderRef) -> Self {
+        Self {
+            order: Some(order),
+            ..self
+        }
+    }
+
+    fn write_quad(
+        &self,
+        w: &mut impl Write,
+        subject: &SubjectNodeRef,
+        predicate: &IRIRef,
+        object: &ObjectNodeRef,
+    ) -> Result<()> {
+        if let Some(graph_name) = &self.name {
+            writeln!(w, "{} <{}> {} {} .", subject, predicate, object, graph_name)
+        } else {
+            writeln!(w, "{} <{}> {} .", subject, predicate, object)
+        }
+        .map_err(io_error)
     }
 }
 
@@ -9,7 +9,10 @@ More detailed description, with
 
 #![allow(clippy::upper_case_acronyms)] // << generated by pest.
 
+use crate::common::parser::{unescape_iri, unescape_string};
 use crate::common::parser_error::ParserErrorFactory;
+use crate::diagnostic::Diagnostic;
+use pest::error::LineColLocation;
 use pest::iterators::Pair;
 use pest::Parser;
 use rdftk_core::error::{ErrorKind, Result};
@@ -19,7 +22,6 @@ use rdftk_core::model::statement::{
     ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef,
 };
 use rdftk_iri::{IRIRef, IRI};
-use regex::Regex;
 use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
@@ -46,6 +48,41 @@ pub(super) fn parse_graph(input: &str, factory: GraphFactoryRef) -> Result<Graph
     ntriples_doc(top_node, factory)
 }
 
+pub(super) fn parse_statement(
+    input: &str,
+    statements: &StatementFactoryRef,
+    literals: &LiteralFactoryRef,
+) -> Result<Option<StatementRef>> {
+    let mut parsed =
+        NTripleParser::parse(Rule::ntriplesLine, input).map_err(|e| ERROR.parser(e))?;
+    let top_node = parsed.next().unwrap();
+    ntriples_line(top_node, statements, literals)
+}
+
+pub(super) fn parse_statement_or_diagnostic(
+    input: &str,
+    line: usize,
+    statements: &StatementFactoryRef,
+    literals: &LiteralFactoryRef,
+) -> std::result::Result<Option<StatementRef>, Diagnostic> {
+    match NTripleParser::parse(Rule::ntriplesLine, input) {
+        Ok(mut parsed) => {
+            let top_node = parsed.next().unwrap();
+            ntriples_line(top_node, statements, literals).map_err(|e| {
+                let column = input.len() - input.trim_start().len() + 1;
+                Diagnostic::new(line, column, input, &e.to_string())
+            })
+        }
+        Err(e) => {
+            let column = match e.line_col {
+                LineColLocation::Pos((_, column)) => column,
+                LineColLocation::Span((_, column), _) => column,
+            };
+            Err(Diagnostic::new(line, column, input, &e.variant.message()))
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -86,6 +123,36 @@ fn ntriples_doc(input_pair: Pair<'_, Rule>, factory: GraphFactoryRef) -> Result<
     Ok(graph)
 }
 
+fn ntriples_line(
+    input_pair: Pair<'_, Rule>,
+    statements: &StatementFactoryRef,
+    literals: &LiteralFactoryRef,
+) -> Result<Option<StatementRef>> {
+    trace!("ntriples_line({:?})", &input_pair.as_rule());
+
+    let mut result = None;
+
+    if input_pair.as_rule() == Rule::ntriplesLine {
+        for inner_pair in input_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::triple => {
+                    result = Some(triple(inner_pair, statements, literals)?);
+                }
+                Rule::EOI => {
+                    trace!("Done.")
+                }
+                _ => {
+                    unexpected!("ntriples_line", inner_pair)
+                }
+            }
+        }
+    } else {
+        unexpected!("ntriples_line", input_pair);
+    }
+
+    Ok(result)
+}
+
 fn triple(
     input_pair: Pair<'_, Rule>,
     statements: &StatementFactoryRef,
@@ -95,7 +162,7 @@ fn triple(
 
     if input_pair.as_rule() == Rule::triple {
         let mut inner_pairs = input_pair.into_inner();
-        let subject = subject(inner_pairs.next().unwrap(), statements)?;
+        let subject = subject(inner_pairs.next().unwrap(), statements, literals)?;
         let predicate = predicate(inner_pairs.next().unwrap())?;
         let object = object(inner_pairs.next().unwrap(), statements, literals)?;
         statements.statement(subject, predicate, object)
@@ -104,7 +171,29 @@ fn triple(
     }
 }
 
-fn subject(input_pair: Pair<'_, Rule>, factory: &StatementFactoryRef) -> Result<SubjectNodeRef> {
+fn quoted_triple(
+    input_pair: Pair<'_, Rule>,
+    statements: &StatementFactoryRef,
+    literals: &LiteralFactoryRef,
+) -> Result<StatementRef> {
+    trace!("quoted_triple({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::quotedTriple {
+        let mut inner_pairs = input_pair.into_inner();
+        let subject = subject(inner_pairs.next().unwrap(), statements, literals)?;
+        let predicate = predicate(inner_pairs.next().unwrap())?;
+        let object = object(inner_pairs.next().unwrap(), statements, literals)?;
+        statements.statement(subject, predicate, object)
+    } else {
+        unexpected!("quoted_triple", input_pair);
+    }
+}
+
+fn subject(
+    input_pair: Pair<'_, Rule>,
+    factory: &StatementFactoryRef,
+    literals: &LiteralFactoryRef,
+) -> Result<SubjectNodeRef> {
     trace!("subject({:?})", &input_pair.as_rule());
 
     if input_pair.as_rule() == Rule::subject {
@@ -117,6 +206,9 @@ fn subject(input_pair: Pair<'_, Rule>, factory: &StatementFactoryRef) -> Result<
                 let node = &node[2..];
                 factory.blank_subject_named(node)
             }
+            Rule::quotedTriple => {
+                Ok(factory.statement_subject(quoted_triple(inner_pair, factory, literals)?))
+            }
             _ => {
                 unexpected!("subject", inner_pair)
             }
@@ -162,6 +254,9 @@ fn object(
                 let literal = literal(inner_pair, literals)?;
                 Ok(factory.literal_object(literal))
             }
+            Rule::quotedTriple => {
+                Ok(factory.statement_object(quoted_triple(inner_pair, factory, literals)?))
+            }
             _ => {
                 unexpected!("object", inner_pair)
             }
@@ -220,7 +315,7 @@ fn string(input_pair: Pair<'_, Rule>) -> Result<String> {
             Rule::STRING_LITERAL_QUOTE => {
                 let inner_pair = inner_pair.into_inner().next().unwrap();
                 if inner_pair.as_rule() == Rule::QUOTE_INNER {
-                    Ok(inner_pair.as_str().to_string())
+                    Ok(unescape_string(inner_pair.as_str()))
                 } else {
                     unexpected!("string", inner_pair);
                 }
@@ -278,40 +373,6 @@ fn lang_tag(input_pair: Pair<'_, Rule>) -> Result<LanguageTag> {
     }
 }
 
-// ------------------------------------------------------------------------------------------------
-
-lazy_static! {
-    static ref UNICODE_ESC: Regex =
-        Regex::new(r"(\\U[[:xdigit:]]{8})|(\\u[[:xdigit:]]{4})").unwrap();
-}
-
-fn unescape_iri(iri: &str) -> String {
-    let (new_iri, end) =
-        UNICODE_ESC
-            .captures_iter(iri)
-            .fold((String::new(), 0), |(so_far, start), cap| {
-                let cap = cap.get(0).unwrap();
-                (
-                    format!(
-                        "{}{}{}",
-                        so_far,
-                        &iri[start..cap.start()],
-                        unescape_uchar(cap.as_str())
-                    ),
-                    cap.end(),
-                )
-            });
-
-    format!("{}{}", new_iri, &iri[end..])
-}
-
-fn unescape_uchar(uchar: &str) -> char {
-    use std::char;
-    let uchar = &uchar[2..];
-    let uchar_u32 = u32::from_str_radix(uchar, 16).unwrap();
-    char::from_u32(uchar_u32).unwrap()
-}
-
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -16,13 +16,54 @@ let reader = NTriplesReader::default();
 assert!(reader.read(&mut file, graph_factory()).is_ok());
 ```
 
+For very large inputs the reader can also produce statements one line at a time, without
+constructing a graph.
+
+```rust
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_io::nt::reader::NTriplesReader;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+let file_path = PathBuf::from("tests/w3c/nt/literal.nt");
+let file = BufReader::new(File::open(file_path).unwrap());
+let reader = NTriplesReader::default();
+for statement in reader.read_statements(file, statement_factory(), literal_factory()) {
+    println!("{}", statement.unwrap());
+}
+```
+
+A malformed statement usually aborts the read with an error; a lenient reader instead skips each
+invalid statement, see the `diagnostic` module, and `read_with_diagnostics` returns the
+statements read along with a diagnostic for each statement skipped.
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::nt::reader::NTriplesReader;
+use rdftk_io::GraphReader;
+
+let content = "<http://example.org/a> <http://example.org/b> <http://example.org/c .";
+let reader = NTriplesReader::default();
+assert!(reader.read(&mut content.as_bytes(), graph_factory()).is_err());
+
+let reader = NTriplesReader::default().lenient(true);
+assert!(reader.read(&mut content.as_bytes(), graph_factory()).is_ok());
+```
+
 */
 
+use crate::conformance::conform;
+use crate::diagnostic::Diagnostic;
+use crate::format::Format;
 use crate::nt::parser;
 use crate::GraphReader;
 use rdftk_core::error::Result;
 use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
-use std::io::Read;
+use rdftk_core::model::literal::LiteralFactoryRef;
+use rdftk_core::model::statement::{StatementFactoryRef, StatementRef};
+use std::io::{BufRead, Read};
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -31,8 +72,11 @@ use std::io::Read;
 ///
 /// An implementation of the GraphReader trait to read resources in the NTriples representation.
 ///
-#[derive(Clone, Debug)]
-pub struct NTriplesReader {}
+#[derive(Clone, Debug, Default)]
+pub struct NTriplesReader {
+    lenient: bool,
+    strict: bool,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
@@ -46,17 +90,88 @@ pub struct NTriplesReader {}
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-impl Default for NTriplesReader {
-    fn default() -> Self {
-        Self {}
+impl GraphReader for NTriplesReader {
+    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<GraphRef> {
+        let mut content: String = String::new();
+        let _ = r.read_to_string(&mut content).map_err(io_error)?;
+        if self.strict {
+            conform(Format::NTriples, &content)?;
+            parser::parse_graph(&content, factory)
+        } else if self.lenient {
+            let (graph, diagnostics) = read_lenient(&content, factory);
+            for diagnostic in diagnostics {
+                warn!("skipped invalid statement, {}", diagnostic);
+            }
+            Ok(graph)
+        } else {
+            parser::parse_graph(&content, factory)
+        }
     }
 }
 
-impl GraphReader for NTriplesReader {
-    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<GraphRef> {
+impl NTriplesReader {
+    ///
+    /// If `lenient` is `true`, invalid statements are skipped, and logged, rather than aborting the
+    /// read with an error.
+    ///
+    pub fn lenient(self, lenient: bool) -> Self {
+        Self { lenient, ..self }
+    }
+
+    ///
+    /// Returns `true` if invalid statements are skipped rather than aborting the read.
+    ///
+    pub fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    ///
+    /// If `strict` is `true`, the input is checked for conformance to the N-Triples specification,
+    /// see the `conformance` module, and any violation aborts the read; a strict reader is never
+    /// lenient.
+    ///
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    ///
+    /// Returns `true` if the input is checked for conformance before it is parsed.
+    ///
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    ///
+    /// Read a graph from `r`, skipping any invalid statement whether or not this reader is
+    /// lenient, and return the graph along with a diagnostic for each statement skipped. Each
+    /// statement must be on a single line, as required by N-Triples. An error is only returned if
+    /// `r` cannot be read.
+    ///
+    pub fn read_with_diagnostics(
+        &self,
+        r: &mut impl Read,
+        factory: GraphFactoryRef,
+    ) -> Result<(GraphRef, Vec<Diagnostic>)> {
         let mut content: String = String::new();
         let _ = r.read_to_string(&mut content).map_err(io_error)?;
-        parser::parse_graph(&content, factory)
+        Ok(read_lenient(&content, factory))
+    }
+
+    ///
+    /// Read statements from `r` one line at a time, rather than reading the entire input before
+    /// constructing a graph. Blank lines and comments are skipped, and an error for any line does
+    /// not stop the iterator from continuing with the next line.
+    ///
+    pub fn read_statements<R: BufRead>(
+        &self,
+        r: R,
+        statements: StatementFactoryRef,
+        literals: LiteralFactoryRef,
+    ) -> impl Iterator<Item = Result<StatementRef>> {
+        r.lines().filter_map(move |line| match line {
+            Ok(line) => parser::parse_statement(&line, &statements, &literals).transpose(),
+            Err(e) => Some(Err(io_error(e))),
+        })
     }
 }
 
@@ -64,6 +179,24 @@ impl GraphReader for NTriplesReader {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+fn read_lenient(content: &str, factory: GraphFactoryRef) -> (GraphRef, Vec<Diagnostic>) {
+    let graph = factory.graph();
+    let mut diagnostics: Vec<Diagnostic> = Default::default();
+    {
+        let mut graph = graph.borrow_mut();
+        let statements = graph.statement_factory();
+        let literals = graph.literal_factory();
+        for (i, line) in content.lines().enumerate() {
+            match parser::parse_statement_or_diagnostic(line, i + 1, &statements, &literals) {
+                Ok(Some(statement)) => graph.insert(statement),
+                Ok(None) => {}
+                Err(diagnostic) => diagnostics.push(diagnostic),
+            }
+        }
+    }
+    (graph, diagnostics)
+}
+
 fn io_error(e: std::io::Error) -> rdftk_core::error::Error {
     use rdftk_core::error::ErrorKind;
     rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
@@ -1,5 +1,5 @@
 /*!
-Provides the `NTripleWriter` implementation of the `GraphWriter` trait.
+Provides the `NTripleWriter` implementation of the `GraphWriter` and `StatementWriter` traits.
 
 # Example
 
@@ -7,6 +7,7 @@ Provides the `NTripleWriter` implementation of the `GraphWriter` trait.
 use rdftk_io::nt::writer::NTripleWriter;
 use rdftk_io::write_graph_to_string;
 # use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::StatementRef;
 # fn make_graph() -> GraphRef { rdftk_core::simple::graph::graph_factory().graph() }
 
 let writer = NTripleWriter::default();
@@ -14,12 +15,28 @@ let writer = NTripleWriter::default();
 let result = write_graph_to_string(&writer, &make_graph());
 ```
 
+Statements may also be written as they are produced, without first collecting them into a graph.
+
+```rust
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::write_statements;
+# use rdftk_core::model::statement::StatementRef;
+# fn export() -> impl Iterator<Item = StatementRef> { std::iter::empty() }
+
+let mut writer = NTripleWriter::default();
+let mut output = std::io::stdout();
+
+write_statements(&mut writer, &mut output, export()).unwrap();
+```
+
 */
 
 use crate::nq::writer::NQuadGraphWriter;
-use crate::GraphWriter;
+use crate::{GraphWriter, StatementWriter};
 use rdftk_core::error::Result;
+use rdftk_core::model::graph::order::{lexical_order, StatementOrderRef};
 use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::StatementRef;
 use std::borrow::Borrow;
 use std::io::Write;
 
@@ -32,7 +49,9 @@ use std::io::Write;
 /// entire graph.
 ///
 #[derive(Debug)]
-pub struct NTripleWriter {}
+pub struct NTripleWriter {
+    order: Option<StatementOrderRef>,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
@@ -40,14 +59,42 @@ pub struct NTripleWriter {}
 
 impl Default for NTripleWriter {
     fn default() -> Self {
-        Self {}
+        Self { order: None }
     }
 }
 
 impl GraphWriter for NTripleWriter {
     fn write(&self, w: &mut impl Write, graph: &GraphRef) -> Result<()> {
         let graph = graph.borrow();
-        let inner_writer = NQuadGraphWriter::default();
+        let inner_writer = match &self.order {
+            Some(order) => NQuadGraphWriter::default().ordered(order.clone()),
+            None => NQuadGraphWriter::default(),
+        };
         inner_writer.write(w, graph)
     }
 }
+
+impl StatementWriter for NTripleWriter {
+    fn write_statement(&mut self, w: &mut impl Write, statement: &StatementRef) -> Result<()> {
+        NQuadGraphWriter::default().write_statement(w, statement)
+    }
+}
+
+impl NTripleWriter {
+    ///
+    /// Write statements sorted by subject, predicate, and object, rather than in the graph's
+    /// iteration order, so that output is reproducible; see `LexicalOrder`.
+    ///
+    pub fn sorted(self, sorted: bool) -> Self {
+        Self {
+            order: if sorted { Some(lexical_order()) } else { None },
+        }
+    }
+
+    ///
+    /// Write statements sorted by `order`.
+    ///
+    pub fn ordered(self, order: StatementOrderRef) -> Self {
+        Self { order: Some(order) }
+    }
+}
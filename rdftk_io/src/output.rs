@@ -0,0 +1,157 @@
+/*!
+Provides the options used by the functions that write to a file, such as `write_graph_to_path`.
+Output is first written to a temporary file, in the same directory as the file being created, that
+is renamed over the file only once the writer, and any compression, has finished successfully.
+As a rename within a directory is atomic, a process watching the file sees either the previous
+complete output or the new complete output, never a truncated file.
+
+If writing fails the temporary file is removed, leaving any previous file unchanged. For debugging
+a failed export the partial output may instead be kept, with `OutputOptions::keep_partial`, in a
+file named by `partial_path`; that is the name of the file with the extension `.partial` appended.
+
+# Example
+
+```rust,no_run
+use rdftk_io::output::{partial_path, OutputOptions};
+use rdftk_io::turtle::writer::TurtleWriter;
+use rdftk_io::write_graph_to_path_with_options;
+# use rdftk_core::model::graph::GraphRef;
+# fn make_graph() -> GraphRef { rdftk_core::simple::graph::graph_factory().graph() }
+
+let options = OutputOptions::default().keep_partial(true);
+let result = write_graph_to_path_with_options(
+    &TurtleWriter::default(),
+    &make_graph(),
+    "export.ttl.gz",
+    &options,
+);
+if result.is_err() {
+    eprintln!("partial output in {:?}", partial_path("export.ttl.gz"));
+}
+```
+
+*/
+
+use crate::compression::{CompressedWriter, Compression};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The extension appended to the name of a file to name the file holding partial output.
+///
+pub const PARTIAL_EXTENSION: &str = "partial";
+
+///
+/// Options for writing to a file.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    compression: Option<Compression>,
+    keep_partial: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the path of the file that holds partial output, if kept, when writing to `path` fails.
+///
+pub fn partial_path(path: impl AsRef<Path>) -> PathBuf {
+    let mut name: OsString = path.as_ref().as_os_str().to_os_string();
+    name.push(".");
+    name.push(PARTIAL_EXTENSION);
+    PathBuf::from(name)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl OutputOptions {
+    ///
+    /// Compress output with `compression`, rather than according to the extension of the file.
+    ///
+    pub fn with_compression(self, compression: Compression) -> Self {
+        Self {
+            compression: Some(compression),
+            ..self
+        }
+    }
+
+    ///
+    /// If `keep_partial` is `true`, keep the partial output of a failed write in the file named by
+    /// `partial_path`, rather than removing it.
+    ///
+    pub fn keep_partial(self, keep_partial: bool) -> Self {
+        Self {
+            keep_partial,
+            ..self
+        }
+    }
+
+    ///
+    /// Return the compression to use, if set, rather than the compression determined by the
+    /// extension of the file.
+    ///
+    pub fn compression(&self) -> Option<Compression> {
+        self.compression
+    }
+
+    ///
+    /// Returns `true` if the partial output of a failed write is kept.
+    ///
+    pub fn is_keeping_partial(&self) -> bool {
+        self.keep_partial
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Write to a temporary file using `write_fn`, then rename it to `path` if successful.
+///
+pub(crate) fn write_to_path(
+    path: &Path,
+    options: &OutputOptions,
+    write_fn: impl FnOnce(&mut CompressedWriter) -> Result<()>,
+) -> Result<()> {
+    let compression = options
+        .compression
+        .unwrap_or_else(|| Compression::from_path(path));
+    let temp_path = temp_path(path);
+    let mut file = CompressedWriter::create(&temp_path, compression)?;
+    let result = write_fn(&mut file).and_then(|_| file.finish());
+    match result {
+        Ok(_) => std::fs::rename(&temp_path, path).map_err(|e| {
+            let _ = std::fs::remove_file(&temp_path);
+            io_error(e)
+        }),
+        Err(e) => {
+            if options.keep_partial {
+                let _ = std::fs::rename(&temp_path, partial_path(path));
+            } else {
+                let _ = std::fs::remove_file(&temp_path);
+            }
+            Err(e)
+        }
+    }
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(format!(".{}.tmp", std::process::id()));
+    path.with_file_name(name)
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::with_chain(e, ErrorKind::ReadWrite("file".to_string()))
+}
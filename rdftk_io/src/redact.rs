@@ -0,0 +1,85 @@
+/*!
+Provides the `RedactingWriter`, an implementation of the `GraphWriter` trait that applies a
+`RedactionPolicy` to labeled statements before delegating to another writer.
+
+The audit report from the most recent write is retained by the writer and may be retrieved with
+`report`.
+
+# Example
+
+```rust
+use rdftk_core::model::security::{RedactionPolicy, SecurityLabels};
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::redact::RedactingWriter;
+use rdftk_io::write_graph_to_string;
+# use rdftk_core::model::graph::GraphRef;
+# fn make_graph() -> GraphRef { rdftk_core::simple::graph::graph_factory().graph() }
+
+let labels = SecurityLabels::default();
+let mut policy = RedactionPolicy::default();
+let _ = policy.drop("confidential".into());
+
+let writer = RedactingWriter::new(NTripleWriter::default(), labels, policy);
+let result = write_graph_to_string(&writer, &make_graph());
+assert!(result.is_ok());
+assert!(writer.report().is_empty());
+```
+
+*/
+
+use crate::GraphWriter;
+use rdftk_core::error::Result;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::security::{redact, RedactionPolicy, RedactionReport, SecurityLabels};
+use std::cell::RefCell;
+use std::io::Write;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A writer that redacts labeled statements and writes the remaining graph using the wrapped
+/// writer.
+///
+#[derive(Debug)]
+pub struct RedactingWriter<W: GraphWriter> {
+    writer: W,
+    labels: SecurityLabels,
+    policy: RedactionPolicy,
+    report: RefCell<RedactionReport>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl<W: GraphWriter> GraphWriter for RedactingWriter<W> {
+    fn write(&self, w: &mut impl Write, graph: &GraphRef) -> Result<()> {
+        let (redacted, report) = redact(graph, &self.labels, &self.policy)?;
+        let _ = self.report.replace(report);
+        self.writer.write(w, &redacted)
+    }
+}
+
+impl<W: GraphWriter> RedactingWriter<W> {
+    ///
+    /// Create a new writer that applies `policy` to the statements labeled in `labels` before
+    /// writing with `writer`.
+    ///
+    pub fn new(writer: W, labels: SecurityLabels, policy: RedactionPolicy) -> Self {
+        Self {
+            writer,
+            labels,
+            policy,
+            report: Default::default(),
+        }
+    }
+
+    ///
+    /// Return the audit report from the most recent write.
+    ///
+    pub fn report(&self) -> RedactionReport {
+        self.report.borrow().clone()
+    }
+}
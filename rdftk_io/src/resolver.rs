@@ -0,0 +1,588 @@
+/*!
+Provides `Resolver`, which serves nested JSON objects describing a resource, and the resources it
+is linked to, directly from a graph according to a `Shape`; the basis of a GraphQL-like read API
+over RDF without hand-written resolvers.
+
+A shape names the fields of the JSON object for a resource, and for each field the predicate whose
+values it holds, whether the predicate is followed from the resource as subject or, if inverse,
+as object, whether the field holds one value or a list, and optionally the shape of the linked
+resources. A linked resource with a shape is resolved as a nested object, otherwise it is
+represented by its identifier; the IRI, or `_:` and the label of a blank node. Nesting is limited
+to a configurable depth, and a resource that is already being resolved further up is also only
+represented by its identifier, so cyclic data always results in a finite response.
+
+Literals are represented as JSON booleans and numbers for the XML Schema boolean and numeric
+types, where the lexical form is valid, and as strings otherwise. A single-valued field holds the
+least of its values, ordered by identifier or lexical form, or `null` if there are none; a list
+field holds all of its values in the same order.
+
+Shapes may be constructed in code, or from a JSON definition in which each key is a field name and
+each value either the predicate of the field, or an object with the keys `path`, `many`, and
+`shape`. A path is an IRI, or a prefixed name using the prefixes declared in the `@prefixes`
+object of the definition, and a path starting with `^` is inverse. The value `@id` names the field
+holding the identifier of the resource itself.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::resolver::{Resolver, Shape};
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_iri::{IRIRef, IRI};
+use serde_json::json;
+use std::str::FromStr;
+
+let mut content = r#"@prefix schema: <http://schema.org/> .
+<http://example.org/alice> schema:name "Alice" ; schema:knows <http://example.org/bob> .
+<http://example.org/bob> schema:name "Bob" ; schema:age 42 .
+"#.as_bytes();
+let graph = TurtleReader::default().read(&mut content, graph_factory()).unwrap();
+
+let shape = Shape::from_json(&json!({
+    "@prefixes": { "schema": "http://schema.org/" },
+    "id": "@id",
+    "name": "schema:name",
+    "friends": { "path": "schema:knows", "many": true, "shape": {
+        "name": "schema:name",
+        "age": "schema:age",
+        "knownBy": { "path": "^schema:knows", "many": true }
+    } }
+})).unwrap();
+
+let resolver = Resolver::new(graph);
+let alice = IRIRef::from(IRI::from_str("http://example.org/alice").unwrap());
+assert_eq!(
+    resolver.resolve_iri(&alice, &shape).unwrap(),
+    Some(json!({
+        "id": "http://example.org/alice",
+        "name": "Alice",
+        "friends": [{ "name": "Bob", "age": 42, "knownBy": ["http://example.org/alice"] }]
+    }))
+);
+```
+
+*/
+
+use crate::common::parser::unescape_string;
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::literal::{DataType, LiteralRef};
+use rdftk_core::model::statement::{ObjectNodeRef, SubjectNodeRef};
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::rdf;
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The display name of this resolver.
+///
+pub const NAME: &str = "Resolver";
+
+///
+/// The default limit on the depth of nested objects.
+///
+pub const DEFAULT_MAX_DEPTH: usize = 3;
+
+///
+/// A field of a `Shape`, holding the values of a predicate.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    predicate: IRIRef,
+    inverse: bool,
+    many: bool,
+    shape: Option<Shape>,
+}
+
+///
+/// The definition of the JSON object for a resource; the named fields, and the name of the field
+/// holding the resource's identifier, if any.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Shape {
+    id: Option<String>,
+    fields: Vec<(String, Field)>,
+}
+
+///
+/// Resolves resources of a graph as nested JSON objects.
+///
+#[derive(Debug)]
+pub struct Resolver {
+    graph: GraphRef,
+    max_depth: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const KEY_ID: &str = "@id";
+
+const KEY_PREFIXES: &str = "@prefixes";
+
+const KEY_PATH: &str = "path";
+
+const KEY_MANY: &str = "many";
+
+const KEY_SHAPE: &str = "shape";
+
+const BNODE_PREFIX: &str = "_:";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Field {
+    ///
+    /// Create a single-valued field holding the objects of statements with `predicate` whose
+    /// subject is the resource.
+    ///
+    pub fn new(predicate: IRIRef) -> Self {
+        Self {
+            predicate,
+            inverse: false,
+            many: false,
+            shape: None,
+        }
+    }
+
+    ///
+    /// Create a single-valued field holding the subjects of statements with `predicate` whose
+    /// object is the resource.
+    ///
+    pub fn inverse(predicate: IRIRef) -> Self {
+        Self {
+            inverse: true,
+            ..Self::new(predicate)
+        }
+    }
+
+    ///
+    /// Make this field hold a list of all its values.
+    ///
+    pub fn many(self) -> Self {
+        Self { many: true, ..self }
+    }
+
+    ///
+    /// Set the shape used to resolve the linked resources of this field as nested objects.
+    ///
+    pub fn with_shape(self, shape: Shape) -> Self {
+        Self {
+            shape: Some(shape),
+            ..self
+        }
+    }
+
+    ///
+    /// Return the predicate of this field.
+    ///
+    pub fn predicate(&self) -> &IRIRef {
+        &self.predicate
+    }
+
+    ///
+    /// Returns `true` if this field follows its predicate from object to subject.
+    ///
+    pub fn is_inverse(&self) -> bool {
+        self.inverse
+    }
+
+    ///
+    /// Returns `true` if this field holds a list of values.
+    ///
+    pub fn is_many(&self) -> bool {
+        self.many
+    }
+
+    ///
+    /// Return the shape of linked resources, if they are resolved as nested objects.
+    ///
+    pub fn shape(&self) -> Option<&Shape> {
+        self.shape.as_ref()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Shape {
+    ///
+    /// Parse a shape from its JSON definition, described in the module documentation.
+    ///
+    pub fn from_json(definition: &Value) -> Result<Self> {
+        let mut prefixes: HashMap<String, String> = Default::default();
+        if let Some(declared) = definition.get(KEY_PREFIXES) {
+            for (prefix, namespace) in as_object(declared, KEY_PREFIXES)? {
+                let namespace = as_str(namespace, prefix)?;
+                let _ = prefixes.insert(prefix.clone(), namespace.to_string());
+            }
+        }
+        Self::from_object(as_object(definition, "shape")?, &prefixes)
+    }
+
+    fn from_object(
+        object: &Map<String, Value>,
+        prefixes: &HashMap<String, String>,
+    ) -> Result<Self> {
+        let mut shape = Self::default();
+        for (name, value) in object.iter().filter(|(name, _)| *name != KEY_PREFIXES) {
+            let field = match value {
+                Value::String(path) if path == KEY_ID => {
+                    shape = shape.with_id(name);
+                    continue;
+                }
+                Value::String(path) => field_for(path, prefixes)?,
+                Value::Object(definition) => {
+                    let path = match definition.get(KEY_PATH) {
+                        Some(path) => as_str(path, KEY_PATH)?,
+                        None => {
+                            return Err(resolver_error(&format!("field {:?} has no path", name)))
+                        }
+                    };
+                    let mut field = field_for(path, prefixes)?;
+                    match definition.get(KEY_MANY) {
+                        None | Some(Value::Bool(false)) => {}
+                        Some(Value::Bool(true)) => field = field.many(),
+                        Some(_) => return Err(resolver_error("many must be a boolean")),
+                    }
+                    if let Some(nested) = definition.get(KEY_SHAPE) {
+                        field = field.with_shape(Self::from_object(
+                            as_object(nested, KEY_SHAPE)?,
+                            prefixes,
+                        )?);
+                    }
+                    field
+                }
+                _ => {
+                    return Err(resolver_error(&format!(
+                        "field {:?} must be a path or an object",
+                        name
+                    )))
+                }
+            };
+            shape = shape.with_field(name, field);
+        }
+        Ok(shape)
+    }
+
+    ///
+    /// Name the field holding the identifier of the resource.
+    ///
+    pub fn with_id(self, name: &str) -> Self {
+        Self {
+            id: Some(name.to_string()),
+            ..self
+        }
+    }
+
+    ///
+    /// Add, or replace, the field `name`.
+    ///
+    pub fn with_field(mut self, name: &str, field: Field) -> Self {
+        self.fields.retain(|(existing, _)| existing != name);
+        self.fields.push((name.to_string(), field));
+        self
+    }
+
+    ///
+    /// Return the name of the field holding the identifier of the resource, if any.
+    ///
+    pub fn id(&self) -> Option<&String> {
+        self.id.as_ref()
+    }
+
+    ///
+    /// Return the field `name`, if present.
+    ///
+    pub fn field(&self, name: &str) -> Option<&Field> {
+        self.fields
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, field)| field)
+    }
+
+    ///
+    /// Return an iterator over the names and fields of this shape, in the order they were added.
+    ///
+    pub fn fields(&self) -> impl Iterator<Item = (&String, &Field)> {
+        self.fields.iter().map(|(name, field)| (name, field))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Resolver {
+    ///
+    /// Create a new resolver over `graph`, nesting objects to `DEFAULT_MAX_DEPTH`.
+    ///
+    pub fn new(graph: GraphRef) -> Self {
+        Self {
+            graph,
+            max_depth: DEFAULT_MAX_DEPTH,
+        }
+    }
+
+    ///
+    /// Set the limit on the depth of nested objects; beyond this depth linked resources are
+    /// represented by their identifier.
+    ///
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    ///
+    /// Return the limit on the depth of nested objects.
+    ///
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    ///
+    /// Resolve `resource` as an object according to `shape`.
+    ///
+    pub fn resolve(&self, resource: &SubjectNodeRef, shape: &Shape) -> Result<Value> {
+        let graph = self.graph.borrow();
+        self.resolve_node(&*graph, resource, shape, &mut Vec::new())
+    }
+
+    ///
+    /// Resolve the resource `iri` as an object according to `shape`, or return `None` if the
+    /// graph contains no statements about it.
+    ///
+    pub fn resolve_iri(&self, iri: &IRIRef, shape: &Shape) -> Result<Option<Value>> {
+        let graph = self.graph.borrow();
+        let resource = graph.statement_factory().named_subject(iri.clone());
+        if graph.contains_subject(&resource) {
+            Ok(Some(self.resolve_node(
+                &*graph,
+                &resource,
+                shape,
+                &mut Vec::new(),
+            )?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    ///
+    /// Resolve every instance of `class`, with an `rdf:type` statement, as an object according
+    /// to `shape`, ordered by identifier.
+    ///
+    pub fn resolve_instances(&self, class: &IRIRef, shape: &Shape) -> Result<Vec<Value>> {
+        let graph = self.graph.borrow();
+        let class = graph.statement_factory().named_object(class.clone());
+        let mut instances: Vec<(String, &SubjectNodeRef)> = graph
+            .matches(None, Some(rdf::a_type()), Some(&class))
+            .into_iter()
+            .map(|statement| Ok((subject_id(statement.subject())?, statement.subject())))
+            .collect::<Result<_>>()?;
+        instances.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+        instances
+            .into_iter()
+            .map(|(_, resource)| self.resolve_node(&*graph, resource, shape, &mut Vec::new()))
+            .collect()
+    }
+
+    fn resolve_node(
+        &self,
+        graph: &dyn Graph,
+        resource: &SubjectNodeRef,
+        shape: &Shape,
+        path: &mut Vec<String>,
+    ) -> Result<Value> {
+        let id = subject_id(resource)?;
+        let mut object = Map::new();
+        if let Some(name) = &shape.id {
+            let _ = object.insert(name.clone(), Value::String(id.clone()));
+        }
+        path.push(id);
+        for (name, field) in &shape.fields {
+            let mut values = self.field_values(graph, resource, field, path)?;
+            let value = if field.many {
+                Value::Array(values.into_iter().map(|(_, value)| value).collect())
+            } else if values.is_empty() {
+                Value::Null
+            } else {
+                values.remove(0).1
+            };
+            let _ = object.insert(name.clone(), value);
+        }
+        let _ = path.pop();
+        Ok(Value::Object(object))
+    }
+
+    ///
+    /// Return the values of `field` for `resource`, with the identifier or lexical form they are
+    /// ordered by.
+    ///
+    fn field_values(
+        &self,
+        graph: &dyn Graph,
+        resource: &SubjectNodeRef,
+        field: &Field,
+        path: &mut Vec<String>,
+    ) -> Result<Vec<(String, Value)>> {
+        let mut values: Vec<(String, Value)> = Default::default();
+        if field.inverse {
+            let object = graph
+                .statement_factory()
+                .subject_as_object(resource.clone());
+            for statement in graph.matches(None, Some(&field.predicate), Some(&object)) {
+                let subject = statement.subject();
+                let id = subject_id(subject)?;
+                let value = self.linked(graph, &id, subject, field, path)?;
+                values.push((id, value));
+            }
+        } else {
+            for object in graph.objects_for(resource, &field.predicate) {
+                values.push(self.object_value(graph, object, field, path)?);
+            }
+        }
+        values.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+        Ok(values)
+    }
+
+    fn object_value(
+        &self,
+        graph: &dyn Graph,
+        object: &ObjectNodeRef,
+        field: &Field,
+        path: &mut Vec<String>,
+    ) -> Result<(String, Value)> {
+        if let Some(literal) = object.as_literal() {
+            Ok((literal.lexical_form().clone(), literal_json(literal)))
+        } else {
+            match graph.statement_factory().object_as_subject(object.clone()) {
+                Some(subject) if !subject.is_statement() => {
+                    let id = subject_id(&subject)?;
+                    let value = self.linked(graph, &id, &subject, field, path)?;
+                    Ok((id, value))
+                }
+                _ => Err(ErrorKind::RdfStarNotSupported(NAME.to_string()).into()),
+            }
+        }
+    }
+
+    ///
+    /// Resolve a linked resource as a nested object if the field has a shape, the depth limit has
+    /// not been reached, and the resource is not already being resolved; otherwise return its
+    /// identifier.
+    ///
+    fn linked(
+        &self,
+        graph: &dyn Graph,
+        id: &str,
+        resource: &SubjectNodeRef,
+        field: &Field,
+        path: &mut Vec<String>,
+    ) -> Result<Value> {
+        match &field.shape {
+            Some(shape) if path.len() <= self.max_depth && !path.iter().any(|on| on == id) => {
+                self.resolve_node(graph, resource, shape, path)
+            }
+            _ => Ok(Value::String(id.to_string())),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn resolver_error(message: &str) -> Error {
+    error!("resolver: {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
+
+fn as_object<'a>(value: &'a Value, name: &str) -> Result<&'a Map<String, Value>> {
+    value
+        .as_object()
+        .ok_or_else(|| resolver_error(&format!("{} must be an object", name)))
+}
+
+fn as_str<'a>(value: &'a Value, name: &str) -> Result<&'a str> {
+    value
+        .as_str()
+        .ok_or_else(|| resolver_error(&format!("{} must be a string", name)))
+}
+
+///
+/// Create a field from a path; an IRI or prefixed name, made inverse by a leading `^`.
+///
+fn field_for(path: &str, prefixes: &HashMap<String, String>) -> Result<Field> {
+    let (inverse, path) = match path.strip_prefix('^') {
+        Some(path) => (true, path),
+        None => (false, path),
+    };
+    let expanded = match path.split_once(':') {
+        Some((prefix, local)) if prefixes.contains_key(prefix) => {
+            format!("{}{}", prefixes[prefix], local)
+        }
+        _ => path.to_string(),
+    };
+    let predicate = IRI::from_str(&expanded)
+        .map(IRIRef::from)
+        .map_err(|_| resolver_error(&format!("invalid path {:?}", path)))?;
+    Ok(if inverse {
+        Field::inverse(predicate)
+    } else {
+        Field::new(predicate)
+    })
+}
+
+fn subject_id(subject: &SubjectNodeRef) -> Result<String> {
+    if let Some(iri) = subject.as_iri() {
+        Ok(iri.to_string())
+    } else if let Some(name) = subject.as_blank() {
+        Ok(format!("{}{}", BNODE_PREFIX, name))
+    } else {
+        Err(ErrorKind::RdfStarNotSupported(NAME.to_string()).into())
+    }
+}
+
+///
+/// Return the JSON value of `literal`; a boolean or number for the XML Schema boolean and numeric
+/// types if the lexical form is valid, otherwise a string.
+///
+fn literal_json(literal: &LiteralRef) -> Value {
+    let value = unescape_string(literal.lexical_form());
+    let lexical = value.trim();
+    // readers may use `DataType::Other` for the IRIs of the XML Schema types.
+    let data_type = literal
+        .data_type()
+        .map(|data_type| DataType::from(data_type.as_iri().clone()));
+    let json = match data_type {
+        Some(DataType::Boolean) => match lexical {
+            "true" | "1" => Some(Value::Bool(true)),
+            "false" | "0" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        Some(
+            DataType::Long
+            | DataType::Int
+            | DataType::Short
+            | DataType::Byte
+            | DataType::Integer
+            | DataType::UnsignedLong
+            | DataType::UnsignedInt
+            | DataType::UnsignedShort
+            | DataType::UnsignedByte,
+        ) => match i64::from_str(lexical) {
+            Ok(number) => Some(Value::Number(number.into())),
+            Err(_) => u64::from_str(lexical).ok().map(|n| Value::Number(n.into())),
+        },
+        Some(DataType::Decimal | DataType::Double | DataType::Float) => f64::from_str(lexical)
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number),
+        _ => None,
+    };
+    json.unwrap_or(Value::String(value))
+}
@@ -0,0 +1,204 @@
+/*!
+Provides the `CsvResultsReader` and `CsvResultsWriter` implementations of the `ResultsReader` and
+`ResultsWriter` traits for the
+[SPARQL 1.1 Query Results CSV Format](https://www.w3.org/TR/sparql11-results-csv-tsv/).
+
+The first row lists the variable names, and each following row a solution, with an empty field
+for each unbound variable. IRIs are written without enclosing `<` and `>`, blank nodes as `_:`
+and their label, and literals as their lexical form only; as CSV does not distinguish between
+IRIs and literals, the reader returns blank nodes for values starting with `_:` and simple
+literals for all other values. Fields are quoted when they contain a quote, comma, or line break,
+and rows end with CRLF.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::results::csv::CsvResultsReader;
+use rdftk_io::results::ResultsReader;
+
+let content = "name,friend\r\n\"Smith, Alice\",_:b0\r\nBob,\r\n";
+let results = CsvResultsReader::default()
+    .read(&mut content.as_bytes(), graph_factory())
+    .unwrap();
+let result_set = results.as_solutions().unwrap();
+assert_eq!(result_set.len(), 2);
+assert_eq!(
+    result_set.column("name")[0].unwrap().as_literal().unwrap().lexical_form(),
+    "Smith, Alice"
+);
+assert!(result_set.column("friend")[1].is_none());
+```
+
+*/
+
+use crate::results::{
+    literal_value, QueryResults, ResultSet, ResultsReader, ResultsWriter, Solution, TermFactory,
+};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::GraphFactoryRef;
+use rdftk_core::model::statement::ObjectNodeRef;
+use std::io::{Read, Write};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "SPARQL Results CSV";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "csv";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "text/csv";
+
+///
+/// This struct implements the `ResultsReader` trait for the CSV results format.
+///
+#[derive(Debug, Default)]
+pub struct CsvResultsReader {}
+
+///
+/// This struct implements the `ResultsWriter` trait for the CSV results format.
+///
+#[derive(Debug, Default)]
+pub struct CsvResultsWriter {}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ResultsReader for CsvResultsReader {
+    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<QueryResults> {
+        let mut content = String::new();
+        let _ = r.read_to_string(&mut content).map_err(io_error)?;
+        let mut records = records(&content)?.into_iter();
+        let variables = records
+            .next()
+            .ok_or_else(|| read_error("missing the header row of variable names"))?;
+        let terms = TermFactory::new(&factory);
+        let mut result_set = ResultSet::new(variables);
+        for record in records {
+            if record.len() != result_set.variables().len() {
+                return Err(read_error(&format!(
+                    "row has {} fields, expected {}",
+                    record.len(),
+                    result_set.variables().len()
+                )));
+            }
+            let mut solution = Solution::default();
+            for (variable, field) in result_set.variables().iter().zip(record) {
+                if let Some(label) = field.strip_prefix("_:") {
+                    solution.bind(variable, terms.blank(label)?);
+                } else if !field.is_empty() {
+                    solution.bind(variable, terms.literal(&field, None, None)?);
+                }
+            }
+            result_set.push(solution);
+        }
+        Ok(QueryResults::Solutions(result_set))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ResultsWriter for CsvResultsWriter {
+    fn write(&self, w: &mut impl Write, results: &QueryResults) -> Result<()> {
+        let result_set = match results {
+            QueryResults::Solutions(result_set) => result_set,
+            QueryResults::Boolean(_) => {
+                return Err(ErrorKind::OperationNotSupported(
+                    "write boolean results".to_string(),
+                    NAME.to_string(),
+                )
+                .into())
+            }
+        };
+        let header: Vec<String> = result_set
+            .variables()
+            .iter()
+            .map(|variable| field(variable))
+            .collect();
+        write!(w, "{}\r\n", header.join(",")).map_err(io_error)?;
+        for solution in result_set.iter() {
+            let row: Vec<String> = result_set
+                .variables()
+                .iter()
+                .map(|variable| match solution.get(variable) {
+                    Some(value) => term(value).map(|value| field(&value)),
+                    None => Ok(String::new()),
+                })
+                .collect::<Result<Vec<String>>>()?;
+            write!(w, "{}\r\n", row.join(",")).map_err(io_error)?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn term(value: &ObjectNodeRef) -> Result<String> {
+    if let Some(iri) = value.as_iri() {
+        Ok(iri.to_string())
+    } else if let Some(label) = value.as_blank() {
+        Ok(format!("_:{}", label))
+    } else if let Some(literal) = value.as_literal() {
+        Ok(literal_value(literal))
+    } else {
+        Err(ErrorKind::RdfStarNotSupported(NAME.to_string()).into())
+    }
+}
+
+fn field(value: &str) -> String {
+    if value.contains(['"', ',', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn records(content: &str) -> Result<Vec<Vec<String>>> {
+    let mut records: Vec<Vec<String>> = Default::default();
+    let mut record: Vec<String> = Default::default();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                let _ = chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, c) => field.push(c),
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => record.push(std::mem::take(&mut field)),
+            (false, '\r') if chars.peek() == Some(&'\n') => {}
+            (false, '\n') => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (false, c) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(read_error("unterminated quoted field"));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    Ok(records)
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string()))
+}
+
+fn read_error(message: &str) -> Error {
+    error!("CsvResultsReader {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
@@ -0,0 +1,210 @@
+/*!
+Provides the `JsonResultsReader` and `JsonResultsWriter` implementations of the `ResultsReader`
+and `ResultsWriter` traits for the
+[SPARQL 1.1 Query Results JSON Format](https://www.w3.org/TR/sparql11-results-json/). The reader
+also accepts the `typed-literal` term type of earlier drafts of the format.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::results::json::JsonResultsReader;
+use rdftk_io::results::ResultsReader;
+
+let content = r#"{
+  "head": { "vars": [ "s" ] },
+  "results": { "bindings": [ { "s": { "type": "uri", "value": "http://example.org/s" } } ] }
+}"#;
+let results = JsonResultsReader::default()
+    .read(&mut content.as_bytes(), graph_factory())
+    .unwrap();
+assert_eq!(results.as_solutions().unwrap().len(), 1);
+```
+
+*/
+
+use crate::results::{
+    literal_value, QueryResults, ResultSet, ResultsReader, ResultsWriter, Solution, TermFactory,
+};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::GraphFactoryRef;
+use rdftk_core::model::statement::ObjectNodeRef;
+use serde_json::{Map, Value};
+use std::io::{Read, Write};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "SPARQL Results JSON";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "srj";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/sparql-results+json";
+
+///
+/// This struct implements the `ResultsReader` trait for the JSON results format.
+///
+#[derive(Debug, Default)]
+pub struct JsonResultsReader {}
+
+///
+/// This struct implements the `ResultsWriter` trait for the JSON results format.
+///
+#[derive(Debug, Default)]
+pub struct JsonResultsWriter {
+    pretty: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ResultsReader for JsonResultsReader {
+    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<QueryResults> {
+        let value: Value = serde_json::from_reader(r).map_err(json_error)?;
+        if let Some(boolean) = value.get("boolean") {
+            return boolean
+                .as_bool()
+                .map(QueryResults::Boolean)
+                .ok_or_else(|| read_error("boolean result is not a boolean"));
+        }
+        let terms = TermFactory::new(&factory);
+        let mut variables: Vec<String> = Default::default();
+        if let Some(vars) = value.get("head").and_then(|head| head.get("vars")) {
+            for variable in array(vars, "vars")? {
+                variables.push(
+                    variable
+                        .as_str()
+                        .ok_or_else(|| read_error("variable name is not a string"))?
+                        .to_string(),
+                );
+            }
+        }
+        let mut result_set = ResultSet::new(variables);
+        let bindings = value
+            .get("results")
+            .and_then(|results| results.get("bindings"))
+            .ok_or_else(|| read_error("results have neither bindings nor a boolean"))?;
+        for binding in array(bindings, "bindings")? {
+            let binding = binding
+                .as_object()
+                .ok_or_else(|| read_error("binding is not an object"))?;
+            let mut solution = Solution::default();
+            for (variable, term) in binding {
+                let field = |name: &str| term.get(name).and_then(|value| value.as_str());
+                let value = field("value").ok_or_else(|| read_error("term has no value"))?;
+                let value = match field("type") {
+                    Some("uri") => terms.iri(value)?,
+                    Some("bnode") => terms.blank(value)?,
+                    Some("literal") | Some("typed-literal") => {
+                        terms.literal(value, field("datatype"), field("xml:lang"))?
+                    }
+                    kind => return Err(read_error(&format!("unknown term type {:?}", kind))),
+                };
+                solution.bind(variable, value);
+            }
+            result_set.push(solution);
+        }
+        Ok(QueryResults::Solutions(result_set))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ResultsWriter for JsonResultsWriter {
+    fn write(&self, w: &mut impl Write, results: &QueryResults) -> Result<()> {
+        let mut document = Map::new();
+        match results {
+            QueryResults::Boolean(boolean) => {
+                let _ = document.insert("head".to_string(), Value::Object(Map::new()));
+                let _ = document.insert("boolean".to_string(), Value::Bool(*boolean));
+            }
+            QueryResults::Solutions(result_set) => {
+                let mut head = Map::new();
+                let _ = head.insert(
+                    "vars".to_string(),
+                    Value::Array(
+                        result_set
+                            .variables()
+                            .iter()
+                            .map(|variable| Value::String(variable.clone()))
+                            .collect(),
+                    ),
+                );
+                let _ = document.insert("head".to_string(), Value::Object(head));
+                let mut bindings = Vec::new();
+                for solution in result_set.iter() {
+                    let mut binding = Map::new();
+                    for (variable, value) in solution.values() {
+                        let _ = binding.insert(variable.clone(), term(value)?);
+                    }
+                    bindings.push(Value::Object(binding));
+                }
+                let mut results = Map::new();
+                let _ = results.insert("bindings".to_string(), Value::Array(bindings));
+                let _ = document.insert("results".to_string(), Value::Object(results));
+            }
+        }
+        if self.pretty {
+            serde_json::to_writer_pretty(w, &Value::Object(document)).map_err(json_error)
+        } else {
+            serde_json::to_writer(w, &Value::Object(document)).map_err(json_error)
+        }
+    }
+}
+
+impl JsonResultsWriter {
+    /// Construct a writer that will output a pretty-printed form.
+    pub fn pretty() -> Self {
+        Self { pretty: true }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn term(value: &ObjectNodeRef) -> Result<Value> {
+    let mut term = Map::new();
+    let mut insert = |key: &str, value: String| {
+        let _ = term.insert(key.to_string(), Value::String(value));
+    };
+    if let Some(iri) = value.as_iri() {
+        insert("type", "uri".to_string());
+        insert("value", iri.to_string());
+    } else if let Some(label) = value.as_blank() {
+        insert("type", "bnode".to_string());
+        insert("value", label.clone());
+    } else if let Some(literal) = value.as_literal() {
+        insert("type", "literal".to_string());
+        insert("value", literal_value(literal));
+        if let Some(language) = literal.language() {
+            insert("xml:lang", language.to_string());
+        } else if let Some(data_type) = literal.data_type() {
+            insert("datatype", data_type.as_iri().to_string());
+        }
+    } else {
+        return Err(ErrorKind::RdfStarNotSupported(NAME.to_string()).into());
+    }
+    Ok(Value::Object(term))
+}
+
+fn array<'a>(value: &'a Value, name: &str) -> Result<&'a Vec<Value>> {
+    value
+        .as_array()
+        .ok_or_else(|| read_error(&format!("{} is not an array", name)))
+}
+
+fn json_error(e: serde_json::Error) -> Error {
+    error!("Error reading or writing SPARQL JSON results: {:?}", e);
+    Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string()))
+}
+
+fn read_error(message: &str) -> Error {
+    error!("JsonResultsReader {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
@@ -0,0 +1,329 @@
+/*!
+Provides for reading and writing the results of `SELECT` and `ASK` queries in the SPARQL 1.1 Query
+Results formats:
+
+* [JSON](https://www.w3.org/TR/sparql11-results-json/), the `json` module.
+* [XML](https://www.w3.org/TR/rdf-sparql-XMLres/), the `xml` module.
+* [CSV and TSV](https://www.w3.org/TR/sparql11-results-csv-tsv/), the `csv` and `tsv` modules.
+
+The results of a `SELECT` query are a `ResultSet`, the projected variables in order and a sequence
+of solutions, each binding variables to object nodes; a variable that is unbound in a solution has
+no value. The result of an `ASK` query is a boolean. Readers create terms using the statement and
+literal factories of a graph from the graph factory they are given, and blank node labels are only
+meaningful within a single result set.
+
+The JSON, XML, and TSV formats preserve the kind, data type, and language of each term; CSV does
+not, and is intended for use with tools such as spreadsheets. Neither CSV nor TSV may represent the
+result of an `ASK` query, and their writers return an error for a boolean result.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_io::results::json::{JsonResultsReader, JsonResultsWriter};
+use rdftk_io::results::tsv::TsvResultsWriter;
+use rdftk_io::results::{QueryResults, ResultSet, ResultsReader, ResultsWriter, Solution};
+
+let mut solution = Solution::default();
+solution.bind("name", statement_factory().literal_object(literal_factory().literal("Alice")));
+let mut result_set = ResultSet::new(vec!["name".to_string(), "age".to_string()]);
+result_set.push(solution);
+let results = QueryResults::from(result_set);
+
+let mut tsv: Vec<u8> = Default::default();
+TsvResultsWriter::default().write(&mut tsv, &results).unwrap();
+assert_eq!(String::from_utf8(tsv).unwrap(), "?name\t?age\n\"Alice\"\t\n");
+
+let mut json: Vec<u8> = Default::default();
+JsonResultsWriter::default().write(&mut json, &results).unwrap();
+let read = JsonResultsReader::default()
+    .read(&mut json.as_slice(), graph_factory())
+    .unwrap();
+let result_set = read.as_solutions().unwrap();
+assert_eq!(result_set.variables(), &["name", "age"]);
+assert!(!result_set.iter().next().unwrap().is_bound("age"));
+```
+
+*/
+
+use crate::common::parser::unescape_string;
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::GraphFactoryRef;
+use rdftk_core::model::literal::{DataType, LiteralFactoryRef, LiteralRef};
+use rdftk_core::model::statement::{ObjectNodeRef, StatementFactoryRef};
+use rdftk_iri::{IRIRef, IRI};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Read query results from the provided implementation of
+/// [`Read`](https://doc.rust-lang.org/std/io/trait.Read.html).
+///
+pub trait ResultsReader {
+    /// Read query results from `r`, creating terms with the factories of a graph from `factory`.
+    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<QueryResults>;
+}
+
+///
+/// Write query results using the provided implementation of
+/// [`Write`](https://doc.rust-lang.org/std/io/trait.Write.html).
+///
+pub trait ResultsWriter {
+    /// Write `results` using the write implementation `w`.
+    fn write(&self, w: &mut impl Write, results: &QueryResults) -> Result<()>;
+}
+
+///
+/// The results of a query.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum QueryResults {
+    /// The solutions of a `SELECT` query.
+    Solutions(ResultSet),
+    /// The result of an `ASK` query.
+    Boolean(bool),
+}
+
+///
+/// The solutions of a `SELECT` query, in order, and the variables projected into each of them.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResultSet {
+    variables: Vec<String>,
+    solutions: Vec<Solution>,
+}
+
+///
+/// A single solution of a `SELECT` query, the values bound to each variable.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Solution {
+    values: BTreeMap<String, ObjectNodeRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub(crate) struct TermFactory {
+    statements: StatementFactoryRef,
+    literals: LiteralFactoryRef,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<ResultSet> for QueryResults {
+    fn from(result_set: ResultSet) -> Self {
+        Self::Solutions(result_set)
+    }
+}
+
+impl From<bool> for QueryResults {
+    fn from(boolean: bool) -> Self {
+        Self::Boolean(boolean)
+    }
+}
+
+impl QueryResults {
+    ///
+    /// Return the solutions, if these are the results of a `SELECT` query.
+    ///
+    pub fn as_solutions(&self) -> Option<&ResultSet> {
+        match self {
+            Self::Solutions(result_set) => Some(result_set),
+            Self::Boolean(_) => None,
+        }
+    }
+
+    ///
+    /// Return the result, if this is the result of an `ASK` query.
+    ///
+    pub fn as_boolean(&self) -> Option<bool> {
+        match self {
+            Self::Solutions(_) => None,
+            Self::Boolean(boolean) => Some(*boolean),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ResultSet {
+    ///
+    /// Create a new, empty, result set projecting `variables`.
+    ///
+    pub fn new(variables: Vec<String>) -> Self {
+        Self {
+            variables,
+            solutions: Default::default(),
+        }
+    }
+
+    ///
+    /// Return the projected variables, in order.
+    ///
+    pub fn variables(&self) -> &Vec<String> {
+        &self.variables
+    }
+
+    ///
+    /// Add `solution` after any existing solutions.
+    ///
+    pub fn push(&mut self, solution: Solution) {
+        self.solutions.push(solution)
+    }
+
+    ///
+    /// Return an iterator over the solutions, in order.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &Solution> {
+        self.solutions.iter()
+    }
+
+    ///
+    /// Return the values of `variable` in each solution, in order.
+    ///
+    pub fn column(&self, variable: &str) -> Vec<Option<&ObjectNodeRef>> {
+        self.solutions
+            .iter()
+            .map(|solution| solution.get(variable))
+            .collect()
+    }
+
+    ///
+    /// Return the number of solutions.
+    ///
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    ///
+    /// Returns `true` if there are no solutions.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Solution {
+    ///
+    /// Bind `variable` to `value`, replacing any existing value.
+    ///
+    pub fn bind(&mut self, variable: &str, value: ObjectNodeRef) {
+        let _ = self.values.insert(variable.to_string(), value);
+    }
+
+    ///
+    /// Return the value bound to `variable`, if any.
+    ///
+    pub fn get(&self, variable: &str) -> Option<&ObjectNodeRef> {
+        self.values.get(variable)
+    }
+
+    ///
+    /// Returns `true` if a value is bound to `variable`.
+    ///
+    pub fn is_bound(&self, variable: &str) -> bool {
+        self.values.contains_key(variable)
+    }
+
+    ///
+    /// Return an iterator over the variables bound in this solution, and their values.
+    ///
+    pub fn values(&self) -> impl Iterator<Item = (&String, &ObjectNodeRef)> {
+        self.values.iter()
+    }
+
+    ///
+    /// Return the number of variables bound in this solution.
+    ///
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    ///
+    /// Returns `true` if no variables are bound in this solution.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TermFactory {
+    pub(crate) fn new(factory: &GraphFactoryRef) -> Self {
+        let graph = factory.graph();
+        let graph = graph.borrow();
+        Self {
+            statements: graph.statement_factory(),
+            literals: graph.literal_factory(),
+        }
+    }
+
+    pub(crate) fn iri(&self, value: &str) -> Result<ObjectNodeRef> {
+        Ok(self.statements.named_object(iri(value)?))
+    }
+
+    pub(crate) fn blank(&self, label: &str) -> Result<ObjectNodeRef> {
+        self.statements.blank_object_named(label)
+    }
+
+    pub(crate) fn literal(
+        &self,
+        value: &str,
+        data_type: Option<&str>,
+        language: Option<&str>,
+    ) -> Result<ObjectNodeRef> {
+        let literal = match (data_type, language) {
+            (_, Some(language)) => self.literals.with_language_str(value, language)?,
+            (Some(data_type), None) => self
+                .literals
+                .with_data_type(value, DataType::from(iri(data_type)?)),
+            (None, None) => self.literals.literal(value),
+        };
+        Ok(self.statements.literal_object(literal))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the value of `literal`; literal factories store the lexical form with any quotes, line
+/// breaks, and backslashes escaped, as they appear in N-Triples, where these formats do not.
+///
+pub(crate) fn literal_value(literal: &LiteralRef) -> String {
+    unescape_string(literal.lexical_form())
+}
+
+fn iri(value: &str) -> Result<IRIRef> {
+    IRI::from_str(value)
+        .map(IRIRef::from)
+        .map_err(|_| ErrorKind::Msg(format!("invalid IRI {:?} in query results", value)).into())
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod csv;
+
+pub mod json;
+
+pub mod tsv;
+
+pub mod xml;
@@ -0,0 +1,233 @@
+/*!
+Provides the `TsvResultsReader` and `TsvResultsWriter` implementations of the `ResultsReader` and
+`ResultsWriter` traits for the
+[SPARQL 1.1 Query Results TSV Format](https://www.w3.org/TR/sparql11-results-csv-tsv/).
+
+The first row lists the variables, each with a leading `?`, and each following row a solution,
+with an empty field for each unbound variable. Terms are written in the syntax of Turtle, IRIs in
+`<` and `>`, blank nodes as `_:` and their label, and literals quoted with their language or data
+type; the reader also accepts the abbreviated forms of integers, decimals, doubles, and booleans.
+Rows end with LF.
+
+# Example
+
+```rust
+use rdftk_core::model::literal::DataType;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::results::tsv::TsvResultsReader;
+use rdftk_io::results::ResultsReader;
+
+let content = "?s\t?age\n<http://example.org/alice>\t34\n";
+let results = TsvResultsReader::default()
+    .read(&mut content.as_bytes(), graph_factory())
+    .unwrap();
+let age = results.as_solutions().unwrap().column("age")[0].unwrap().clone();
+assert_eq!(age.as_literal().unwrap().data_type(), Some(&DataType::Integer));
+```
+
+*/
+
+use crate::common::parser::{unescape_iri, unescape_string};
+use crate::results::{
+    QueryResults, ResultSet, ResultsReader, ResultsWriter, Solution, TermFactory,
+};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::GraphFactoryRef;
+use rdftk_core::model::literal::DataType;
+use rdftk_core::model::statement::ObjectNodeRef;
+use regex::Regex;
+use std::io::{Read, Write};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "SPARQL Results TSV";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "tsv";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "text/tab-separated-values";
+
+///
+/// This struct implements the `ResultsReader` trait for the TSV results format.
+///
+#[derive(Debug, Default)]
+pub struct TsvResultsReader {}
+
+///
+/// This struct implements the `ResultsWriter` trait for the TSV results format.
+///
+#[derive(Debug, Default)]
+pub struct TsvResultsWriter {}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+lazy_static! {
+    static ref INTEGER: Regex = Regex::new(r"^[+-]?[0-9]+$").unwrap();
+    static ref DECIMAL: Regex = Regex::new(r"^[+-]?[0-9]*\.[0-9]+$").unwrap();
+    static ref DOUBLE: Regex =
+        Regex::new(r"^[+-]?([0-9]+\.[0-9]*|\.[0-9]+|[0-9]+)[eE][+-]?[0-9]+$").unwrap();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ResultsReader for TsvResultsReader {
+    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<QueryResults> {
+        let mut content = String::new();
+        let _ = r.read_to_string(&mut content).map_err(io_error)?;
+        let mut lines = content.lines();
+        let variables = lines
+            .next()
+            .ok_or_else(|| read_error("missing the header row of variables"))?
+            .split('\t')
+            .filter(|variable| !variable.is_empty())
+            .map(|variable| match variable.strip_prefix(&['?', '$'][..]) {
+                Some(name) => Ok(name.to_string()),
+                None => Err(read_error(&format!("invalid variable {:?}", variable))),
+            })
+            .collect::<Result<Vec<String>>>()?;
+        let terms = TermFactory::new(&factory);
+        let mut result_set = ResultSet::new(variables);
+        for line in lines.filter(|line| !line.is_empty()) {
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != result_set.variables().len() {
+                return Err(read_error(&format!(
+                    "row has {} fields, expected {}",
+                    fields.len(),
+                    result_set.variables().len()
+                )));
+            }
+            let mut solution = Solution::default();
+            for (variable, field) in result_set.variables().iter().zip(fields) {
+                let field = field.trim();
+                if !field.is_empty() {
+                    solution.bind(variable, parse_term(field, &terms)?);
+                }
+            }
+            result_set.push(solution);
+        }
+        Ok(QueryResults::Solutions(result_set))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ResultsWriter for TsvResultsWriter {
+    fn write(&self, w: &mut impl Write, results: &QueryResults) -> Result<()> {
+        let result_set = match results {
+            QueryResults::Solutions(result_set) => result_set,
+            QueryResults::Boolean(_) => {
+                return Err(ErrorKind::OperationNotSupported(
+                    "write boolean results".to_string(),
+                    NAME.to_string(),
+                )
+                .into())
+            }
+        };
+        let header: Vec<String> = result_set
+            .variables()
+            .iter()
+            .map(|variable| format!("?{}", variable))
+            .collect();
+        writeln!(w, "{}", header.join("\t")).map_err(io_error)?;
+        for solution in result_set.iter() {
+            let row: Vec<String> = result_set
+                .variables()
+                .iter()
+                .map(|variable| match solution.get(variable) {
+                    Some(value) => term(value),
+                    None => Ok(String::new()),
+                })
+                .collect::<Result<Vec<String>>>()?;
+            writeln!(w, "{}", row.join("\t")).map_err(io_error)?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn term(value: &ObjectNodeRef) -> Result<String> {
+    if let Some(iri) = value.as_iri() {
+        Ok(format!("<{}>", iri))
+    } else if let Some(label) = value.as_blank() {
+        Ok(format!("_:{}", label))
+    } else if let Some(literal) = value.as_literal() {
+        let mut term = format!("\"{}\"", literal.lexical_form());
+        if let Some(language) = literal.language() {
+            term.push_str(&format!("@{}", language));
+        } else if let Some(data_type) = literal.data_type() {
+            term.push_str(&format!("^^<{}>", data_type.as_iri()));
+        }
+        Ok(term)
+    } else {
+        Err(ErrorKind::RdfStarNotSupported(NAME.to_string()).into())
+    }
+}
+
+fn parse_term(field: &str, terms: &TermFactory) -> Result<ObjectNodeRef> {
+    if let Some(iri) = field.strip_prefix('<').and_then(|s| s.strip_suffix('>')) {
+        terms.iri(&unescape_iri(iri))
+    } else if let Some(label) = field.strip_prefix("_:") {
+        terms.blank(label)
+    } else if let Some(quote) = field.chars().next().filter(|c| *c == '"' || *c == '\'') {
+        let end = closing_quote(field, quote)
+            .ok_or_else(|| read_error(&format!("unterminated literal {:?}", field)))?;
+        let value = unescape_string(&field[1..end]);
+        let suffix = &field[end + 1..];
+        if let Some(language) = suffix.strip_prefix('@') {
+            terms.literal(&value, None, Some(language))
+        } else if let Some(data_type) = suffix.strip_prefix("^^<").and_then(|s| s.strip_suffix('>'))
+        {
+            terms.literal(&value, Some(&unescape_iri(data_type)), None)
+        } else if suffix.is_empty() {
+            terms.literal(&value, None, None)
+        } else {
+            Err(read_error(&format!("invalid literal {:?}", field)))
+        }
+    } else {
+        let data_type = if field == "true" || field == "false" {
+            DataType::Boolean
+        } else if INTEGER.is_match(field) {
+            DataType::Integer
+        } else if DECIMAL.is_match(field) {
+            DataType::Decimal
+        } else if DOUBLE.is_match(field) {
+            DataType::Double
+        } else {
+            return Err(read_error(&format!("invalid term {:?}", field)));
+        };
+        terms.literal(field, Some(&data_type.as_iri().to_string()), None)
+    }
+}
+
+fn closing_quote(field: &str, quote: char) -> Option<usize> {
+    let mut escaped = false;
+    for (index, c) in field.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            c if c == quote => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string()))
+}
+
+fn read_error(message: &str) -> Error {
+    error!("TsvResultsReader {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
@@ -0,0 +1,291 @@
+/*!
+Provides the `XmlResultsReader` and `XmlResultsWriter` implementations of the `ResultsReader` and
+`ResultsWriter` traits for the
+[SPARQL Query Results XML Format](https://www.w3.org/TR/rdf-sparql-XMLres/). The reader
+identifies elements by their local names, and ignores any `link` elements.
+
+# Example
+
+```rust
+use rdftk_io::results::xml::XmlResultsWriter;
+use rdftk_io::results::{QueryResults, ResultsWriter};
+
+let mut output: Vec<u8> = Default::default();
+XmlResultsWriter::default()
+    .write(&mut output, &QueryResults::Boolean(true))
+    .unwrap();
+assert!(String::from_utf8(output).unwrap().contains("<boolean>true</boolean>"));
+```
+
+*/
+
+use crate::results::{
+    literal_value, QueryResults, ResultSet, ResultsReader, ResultsWriter, Solution, TermFactory,
+};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::GraphFactoryRef;
+use rdftk_core::model::statement::ObjectNodeRef;
+use std::io::{Read, Write};
+use xml::common::XmlVersion;
+use xml::reader::XmlEvent;
+use xml::writer::XmlEvent as WriterEvent;
+use xml::{EmitterConfig, EventReader, EventWriter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "SPARQL Results XML";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "srx";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/sparql-results+xml";
+
+/// The namespace of the elements of this serialization format.
+pub const NAMESPACE: &str = "http://www.w3.org/2005/sparql-results#";
+
+///
+/// This struct implements the `ResultsReader` trait for the XML results format.
+///
+#[derive(Debug, Default)]
+pub struct XmlResultsReader {}
+
+///
+/// This struct implements the `ResultsWriter` trait for the XML results format.
+///
+#[derive(Debug, Default)]
+pub struct XmlResultsWriter {
+    pretty: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Term {
+    kind: String,
+    data_type: Option<String>,
+    language: Option<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ResultsReader for XmlResultsReader {
+    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<QueryResults> {
+        let terms = TermFactory::new(&factory);
+        let mut variables: Vec<String> = Default::default();
+        let mut solutions: Vec<Solution> = Default::default();
+        let mut boolean: Option<bool> = None;
+        let mut solution: Option<Solution> = None;
+        let mut variable: Option<String> = None;
+        let mut term: Option<Term> = None;
+        let mut text = String::new();
+        for event in EventReader::new(r) {
+            match event {
+                Ok(XmlEvent::StartElement {
+                    name, attributes, ..
+                }) => {
+                    let attribute = |local_name: &str, prefix: Option<&str>| {
+                        attributes
+                            .iter()
+                            .find(|attribute| {
+                                attribute.name.local_name == local_name
+                                    && attribute.name.prefix.as_deref() == prefix
+                            })
+                            .map(|attribute| attribute.value.clone())
+                    };
+                    text.clear();
+                    match name.local_name.as_str() {
+                        "variable" => {
+                            variables.push(attribute("name", None).ok_or_else(|| {
+                                read_error("variable element has no name attribute")
+                            })?)
+                        }
+                        "result" => solution = Some(Solution::default()),
+                        "binding" => variable = attribute("name", None),
+                        kind @ ("uri" | "bnode" | "literal") => {
+                            term = Some(Term {
+                                kind: kind.to_string(),
+                                data_type: attribute("datatype", None),
+                                language: attribute("lang", Some("xml")),
+                            })
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(XmlEvent::EndElement { name }) => match name.local_name.as_str() {
+                    "result" => {
+                        if let Some(solution) = solution.take() {
+                            solutions.push(solution);
+                        }
+                    }
+                    "binding" => variable = None,
+                    "uri" | "bnode" | "literal" => {
+                        let value = match term.take() {
+                            Some(Term { kind, .. }) if kind == "uri" => terms.iri(&text)?,
+                            Some(Term { kind, .. }) if kind == "bnode" => terms.blank(&text)?,
+                            Some(Term {
+                                data_type,
+                                language,
+                                ..
+                            }) => {
+                                terms.literal(&text, data_type.as_deref(), language.as_deref())?
+                            }
+                            None => return Err(read_error("unexpected end of term")),
+                        };
+                        match (&mut solution, &variable) {
+                            (Some(solution), Some(variable)) => solution.bind(variable, value),
+                            _ => return Err(read_error("term is not within a binding")),
+                        }
+                    }
+                    "boolean" => match text.trim() {
+                        "true" => boolean = Some(true),
+                        "false" => boolean = Some(false),
+                        _ => return Err(read_error("boolean result is not a boolean")),
+                    },
+                    _ => {}
+                },
+                Ok(XmlEvent::Characters(value))
+                | Ok(XmlEvent::CData(value))
+                | Ok(XmlEvent::Whitespace(value)) => text.push_str(&value),
+                Ok(_) => {}
+                Err(e) => return Err(Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string()))),
+            }
+        }
+        Ok(match boolean {
+            Some(boolean) => QueryResults::Boolean(boolean),
+            None => {
+                let mut result_set = ResultSet::new(variables);
+                solutions
+                    .into_iter()
+                    .for_each(|solution| result_set.push(solution));
+                QueryResults::Solutions(result_set)
+            }
+        })
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ResultsWriter for XmlResultsWriter {
+    fn write(&self, w: &mut impl Write, results: &QueryResults) -> Result<()> {
+        let config = EmitterConfig::new()
+            .perform_indent(self.pretty)
+            .normalize_empty_elements(true);
+        let mut writer = config.create_writer(w);
+        writer
+            .write(WriterEvent::StartDocument {
+                version: XmlVersion::Version10,
+                encoding: Some("utf-8"),
+                standalone: None,
+            })
+            .map_err(xml_error)?;
+        writer
+            .write(WriterEvent::start_element("sparql").default_ns(NAMESPACE))
+            .map_err(xml_error)?;
+        writer
+            .write(WriterEvent::start_element("head"))
+            .map_err(xml_error)?;
+        if let QueryResults::Solutions(result_set) = results {
+            for variable in result_set.variables() {
+                writer
+                    .write(WriterEvent::start_element("variable").attr("name", variable))
+                    .map_err(xml_error)?;
+                end_element(&mut writer)?;
+            }
+        }
+        end_element(&mut writer)?;
+        match results {
+            QueryResults::Boolean(boolean) => {
+                writer
+                    .write(WriterEvent::start_element("boolean"))
+                    .map_err(xml_error)?;
+                writer
+                    .write(WriterEvent::characters(&boolean.to_string()))
+                    .map_err(xml_error)?;
+                end_element(&mut writer)?;
+            }
+            QueryResults::Solutions(result_set) => {
+                writer
+                    .write(WriterEvent::start_element("results"))
+                    .map_err(xml_error)?;
+                for solution in result_set.iter() {
+                    writer
+                        .write(WriterEvent::start_element("result"))
+                        .map_err(xml_error)?;
+                    for (variable, value) in solution.values() {
+                        writer
+                            .write(WriterEvent::start_element("binding").attr("name", variable))
+                            .map_err(xml_error)?;
+                        write_term(&mut writer, value)?;
+                        end_element(&mut writer)?;
+                    }
+                    end_element(&mut writer)?;
+                }
+                end_element(&mut writer)?;
+            }
+        }
+        end_element(&mut writer)
+    }
+}
+
+impl XmlResultsWriter {
+    /// Construct a writer that will output an indented form.
+    pub fn pretty() -> Self {
+        Self { pretty: true }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn write_term<W: Write>(writer: &mut EventWriter<W>, value: &ObjectNodeRef) -> Result<()> {
+    let (element, text) = if let Some(iri) = value.as_iri() {
+        (WriterEvent::start_element("uri"), iri.to_string())
+    } else if let Some(label) = value.as_blank() {
+        (WriterEvent::start_element("bnode"), label.clone())
+    } else if let Some(literal) = value.as_literal() {
+        let element = WriterEvent::start_element("literal");
+        let language = literal.language().map(|language| language.to_string());
+        let data_type = literal
+            .data_type()
+            .map(|data_type| data_type.as_iri().to_string());
+        let element = match (&language, &data_type) {
+            (Some(language), _) => element.attr("xml:lang", language),
+            (None, Some(data_type)) => element.attr("datatype", data_type),
+            (None, None) => element,
+        };
+        writer.write(element).map_err(xml_error)?;
+        writer
+            .write(WriterEvent::characters(&literal_value(literal)))
+            .map_err(xml_error)?;
+        return end_element(writer);
+    } else {
+        return Err(ErrorKind::RdfStarNotSupported(NAME.to_string()).into());
+    };
+    writer.write(element).map_err(xml_error)?;
+    writer
+        .write(WriterEvent::characters(&text))
+        .map_err(xml_error)?;
+    end_element(writer)
+}
+
+fn end_element<W: Write>(writer: &mut EventWriter<W>) -> Result<()> {
+    writer.write(WriterEvent::end_element()).map_err(xml_error)
+}
+
+fn xml_error(e: xml::writer::Error) -> Error {
+    Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string()))
+}
+
+fn read_error(message: &str) -> Error {
+    error!("XmlResultsReader {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
@@ -0,0 +1,392 @@
+/*!
+Provides `SparqlClient`, a client for the query operation of the
+[SPARQL 1.1 Protocol](https://www.w3.org/TR/sparql11-protocol/), which sends `SELECT`, `ASK`,
+`CONSTRUCT`, and `DESCRIBE` queries to a remote endpoint. The results of `SELECT` and `ASK` queries
+are read from either the
+[SPARQL 1.1 Query Results JSON Format](https://www.w3.org/TR/sparql11-results-json/) or the
+[SPARQL Query Results XML Format](https://www.w3.org/TR/rdf-sparql-XMLres/), chosen by the
+`Content-Type` of the response, using the readers of the `results` module. The results of
+`CONSTRUCT` and `DESCRIBE` queries are read into a graph using the reader, from the `format`
+module, for the `Content-Type` of the response; the request accepts any representation enabled by
+this crate's features that may be read as a graph.
+
+The terms in the results of a `SELECT` query are created by the statement and literal factories of
+a graph from the client's graph factory.
+
+As with the `dereference` module requests are made through an implementation of the `Transport`
+trait, such as the `HttpTransport` of the `http` module (the `http` feature). Each `Request`
+provides the query as the body of a `POST` with the media type `application/sparql-query`, the value
+of the `Accept` header, and the query as a form-encoded string for transports that use `GET` or a
+form `POST`. Closures of the form `Fn(&IRIRef, &Request) -> Result<Response>` implement `Transport`.
+
+# Example
+
+```rust
+use rdftk_core::error::Result;
+use rdftk_io::sparql_client::{Request, Response, SparqlClient};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+let transport = |_: &IRIRef, _: &Request| -> Result<Response> {
+    Ok(Response::new(
+        200,
+        Some("application/sparql-results+json"),
+        br#"{ "head": {}, "boolean": true }"#.to_vec(),
+    ))
+};
+
+let endpoint = IRIRef::from(IRI::from_str("http://example.org/sparql").unwrap());
+let client = SparqlClient::new(endpoint, transport);
+assert!(client.ask("ASK { ?s ?p ?o }").unwrap());
+```
+
+*/
+
+use crate::format::{graph_accept, reader_for};
+use crate::results::json::{JsonResultsReader, MIME_TYPE as JSON_MIME_TYPE};
+use crate::results::xml::{XmlResultsReader, MIME_TYPE as XML_MIME_TYPE};
+use crate::results::{QueryResults, ResultSet, ResultsReader};
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_iri::IRIRef;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The display name of this client.
+///
+pub const NAME: &str = "SPARQL Protocol";
+
+///
+/// The media type of a query sent directly as the body of a `POST` request.
+///
+pub const QUERY_MIME_TYPE: &str = "application/sparql-query";
+
+///
+/// A query request to be sent to an endpoint.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    query: String,
+    accept: String,
+}
+
+///
+/// The parts of an HTTP response used by the client.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    status: u16,
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+///
+/// Performs HTTP requests on behalf of the client.
+///
+pub trait Transport {
+    ///
+    /// Send `request` to `endpoint`. Redirects are expected to be followed, and the final response
+    /// returned. An error is returned if no response was received.
+    ///
+    fn send(&self, endpoint: &IRIRef, request: &Request) -> Result<Response>;
+}
+
+///
+/// The results of a query sent to an endpoint.
+///
+#[derive(Clone, Debug)]
+pub enum EndpointResults {
+    /// The solutions of a `SELECT` query.
+    Solutions(ResultSet),
+    /// The result of an `ASK` query.
+    Boolean(bool),
+    /// The graph returned by a `CONSTRUCT` or `DESCRIBE` query.
+    Graph(GraphRef),
+}
+
+///
+/// A client for a single SPARQL query endpoint, using a `Transport`.
+///
+#[derive(Debug)]
+pub struct SparqlClient<T: Transport> {
+    endpoint: IRIRef,
+    transport: T,
+    factory: GraphFactoryRef,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Request {
+    ///
+    /// Create a new request for `query`, with `accept` as the value of the `Accept` header.
+    ///
+    pub fn new(query: &str, accept: &str) -> Self {
+        Self {
+            query: query.to_string(),
+            accept: accept.to_string(),
+        }
+    }
+
+    ///
+    /// Return the text of the query.
+    ///
+    pub fn query(&self) -> &String {
+        &self.query
+    }
+
+    ///
+    /// Return the value of the `Accept` header for this request.
+    ///
+    pub fn accept(&self) -> &String {
+        &self.accept
+    }
+
+    ///
+    /// Return the value of the `Content-Type` header when the query is sent as the body of a
+    /// `POST` request.
+    ///
+    pub fn content_type(&self) -> &'static str {
+        QUERY_MIME_TYPE
+    }
+
+    ///
+    /// Return the query as `application/x-www-form-urlencoded` parameters, for use as the query
+    /// string of a `GET` request or the body of a form `POST` request.
+    ///
+    pub fn form_encoded(&self) -> String {
+        format!("query={}", percent_encode(&self.query))
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Response {
+    ///
+    /// Create a new response with the given status code, the value of the `Content-Type` header,
+    /// if any, and the response body.
+    ///
+    pub fn new(status: u16, content_type: Option<&str>, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            content_type: content_type.map(|s| s.to_string()),
+            body,
+        }
+    }
+
+    ///
+    /// Return the status code of this response.
+    ///
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    ///
+    /// Return the value of the `Content-Type` header of this response, if any.
+    ///
+    pub fn content_type(&self) -> Option<&String> {
+        self.content_type.as_ref()
+    }
+
+    ///
+    /// Return the body of this response.
+    ///
+    pub fn body(&self) -> &Vec<u8> {
+        &self.body
+    }
+
+    ///
+    /// Returns `true` if the status code of this response is in the `2xx` range.
+    ///
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    ///
+    /// Returns `true` if the media type of this response, ignoring any parameters and case, is
+    /// `media_type`.
+    ///
+    pub fn is_media_type(&self, media_type: &str) -> bool {
+        match &self.content_type {
+            None => false,
+            Some(content_type) => content_type
+                .split(';')
+                .next()
+                .map(|s| s.trim().eq_ignore_ascii_case(media_type))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F> Transport for F
+where
+    F: Fn(&IRIRef, &Request) -> Result<Response>,
+{
+    fn send(&self, endpoint: &IRIRef, request: &Request) -> Result<Response> {
+        self(endpoint, request)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl From<QueryResults> for EndpointResults {
+    fn from(results: QueryResults) -> Self {
+        match results {
+            QueryResults::Solutions(result_set) => Self::Solutions(result_set),
+            QueryResults::Boolean(boolean) => Self::Boolean(boolean),
+        }
+    }
+}
+
+impl Display for EndpointResults {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Solutions(result_set) => write!(f, "{} solutions", result_set.len()),
+            Self::Boolean(boolean) => write!(f, "{}", boolean),
+            Self::Graph(graph) => write!(f, "{} statements", graph.borrow().len()),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<T: Transport> SparqlClient<T> {
+    ///
+    /// Create a new client for `endpoint`; terms and graphs in the results are created with the
+    /// simple graph factory.
+    ///
+    pub fn new(endpoint: IRIRef, transport: T) -> Self {
+        Self::with_factory(endpoint, transport, graph_factory())
+    }
+
+    ///
+    /// Create a new client for `endpoint`; terms and graphs in the results are created with
+    /// `factory`.
+    ///
+    pub fn with_factory(endpoint: IRIRef, transport: T, factory: GraphFactoryRef) -> Self {
+        Self {
+            endpoint,
+            transport,
+            factory,
+        }
+    }
+
+    ///
+    /// Return the IRI of the endpoint.
+    ///
+    pub fn endpoint(&self) -> &IRIRef {
+        &self.endpoint
+    }
+
+    ///
+    /// Return the transport used to send requests.
+    ///
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    ///
+    /// Send a `SELECT` query, returning its solutions.
+    ///
+    pub fn select(&self, query: &str) -> Result<ResultSet> {
+        match self.query(query, &results_accept())? {
+            EndpointResults::Solutions(result_set) => Ok(result_set),
+            _ => Err(read_error("expected the solutions of a SELECT query")),
+        }
+    }
+
+    ///
+    /// Send an `ASK` query, returning its result.
+    ///
+    pub fn ask(&self, query: &str) -> Result<bool> {
+        match self.query(query, &results_accept())? {
+            EndpointResults::Boolean(boolean) => Ok(boolean),
+            _ => Err(read_error("expected the result of an ASK query")),
+        }
+    }
+
+    ///
+    /// Send a `CONSTRUCT` or `DESCRIBE` query, returning the resulting graph.
+    ///
+    pub fn construct(&self, query: &str) -> Result<GraphRef> {
+        match self.query(query, &graph_accept())? {
+            EndpointResults::Graph(graph) => Ok(graph),
+            _ => Err(read_error("expected the graph of a CONSTRUCT query")),
+        }
+    }
+
+    ///
+    /// Send a query of any form, accepting both results formats and any representation that may
+    /// be read as a graph; the results are read according to the `Content-Type` of the response.
+    ///
+    pub fn query_any(&self, query: &str) -> Result<EndpointResults> {
+        self.query(query, &format!("{}, {}", results_accept(), graph_accept()))
+    }
+
+    fn query(&self, query: &str, accept: &str) -> Result<EndpointResults> {
+        let response = self
+            .transport
+            .send(&self.endpoint, &Request::new(query, accept))?;
+        if !response.is_success() {
+            return Err(read_error(&format!(
+                "endpoint {} returned status {}",
+                self.endpoint,
+                response.status()
+            )));
+        }
+        let mut body = response.body().as_slice();
+        if response.is_media_type(JSON_MIME_TYPE) {
+            Ok(JsonResultsReader::default()
+                .read(&mut body, self.factory.clone())?
+                .into())
+        } else if response.is_media_type(XML_MIME_TYPE) {
+            Ok(XmlResultsReader::default()
+                .read(&mut body, self.factory.clone())?
+                .into())
+        } else {
+            match response.content_type().and_then(|s| reader_for(s)) {
+                Some(format) if format.reads_graph() => Ok(EndpointResults::Graph(
+                    format.read_graph(&mut body, self.factory.clone())?,
+                )),
+                _ => Err(read_error(&format!(
+                    "unsupported response content type {:?}",
+                    response.content_type()
+                ))),
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn results_accept() -> String {
+    format!("{}, {};q=0.9", JSON_MIME_TYPE, XML_MIME_TYPE)
+}
+
+pub(crate) fn percent_encode(value: &str) -> String {
+    let mut encoded = String::new();
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn read_error(message: &str) -> rdftk_core::error::Error {
+    error!("SparqlClient {}", message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
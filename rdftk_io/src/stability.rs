@@ -0,0 +1,365 @@
+/*!
+Compare the same data as read from two representations, or two exports, and report the
+differences in its identifiers; for example to check that a migration from RDF/XML to Turtle is
+lossless. Three kinds of difference are reported:
+
+* **IRIs** used as a subject, predicate, or object in one graph but not in the other.
+* **blank node structure**; the statements with a blank node subject or object are compared in
+  their canonical form (see `rdftk_core::model::graph::canonical`), so that differences in blank
+  node labels alone are not reported.
+* **literal drift**; a literal with the same subject, predicate, and lexical form, but a different
+  data type or language, in each graph. A literal with no data type is the same as one with the
+  data type `xsd:string`.
+
+The `Display` implementation of `StabilityReport` lists each difference, the left-hand graph is
+shown as `<` and the right-hand graph as `>`.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::format::Format;
+use rdftk_io::stability::compare;
+
+let turtle = r#"@prefix ex: <http://example.org/> .
+ex:a ex:size "12" ; ex:owner [ ex:name "Alice" ] ."#;
+let xml = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/">
+  <rdf:Description rdf:about="http://example.org/a">
+    <ex:size rdf:datatype="http://www.w3.org/2001/XMLSchema#int">12</ex:size>
+    <ex:owner><rdf:Description><ex:name>Alice</ex:name></rdf:Description></ex:owner>
+  </rdf:Description>
+</rdf:RDF>"#;
+
+let left = Format::Turtle.read_graph(&mut turtle.as_bytes(), graph_factory()).unwrap();
+let right = Format::Xml.read_graph(&mut xml.as_bytes(), graph_factory()).unwrap();
+
+let report = compare(&*left.borrow(), &*right.borrow()).unwrap();
+assert!(!report.is_stable());
+assert!(report.left_only_iris().is_empty());
+assert!(report.left_only_blank_statements().is_empty());
+assert_eq!(report.literal_drift().len(), 1);
+```
+
+*/
+
+use crate::compression::CompressedReader;
+use crate::format::Format;
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::canonical::canonical_n_triples;
+use rdftk_core::model::graph::{Graph, GraphFactoryRef};
+use rdftk_core::model::literal::{DataType, LiteralRef};
+use rdftk_core::model::statement::{StatementList, SubjectNodeRef};
+use rdftk_iri::IRIRef;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A literal with a different data type, or language, in each graph.
+///
+#[derive(Clone, Debug)]
+pub struct LiteralDrift {
+    subject: SubjectNodeRef,
+    predicate: IRIRef,
+    lexical_form: String,
+    left: String,
+    right: String,
+}
+
+///
+/// The differences in identifiers between two graphs.
+///
+#[derive(Clone, Debug, Default)]
+pub struct StabilityReport {
+    left_only_iris: BTreeSet<IRIRef>,
+    right_only_iris: BTreeSet<IRIRef>,
+    left_only_blank_statements: Vec<String>,
+    right_only_blank_statements: Vec<String>,
+    literal_drift: Vec<LiteralDrift>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+type LiteralKey = (SubjectNodeRef, IRIRef, String);
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Compare the identifiers in the `left` and `right` graphs. This will fail if either graph
+/// contains statements, from RDF-star, used as the subject or object of a statement with a blank
+/// node, as these cannot be canonicalized.
+///
+pub fn compare(left: &dyn Graph, right: &dyn Graph) -> Result<StabilityReport> {
+    let left_iris = iris(left);
+    let right_iris = iris(right);
+    let left_blank = blank_statements(left)?;
+    let right_blank = blank_statements(right)?;
+    Ok(StabilityReport {
+        left_only_iris: left_iris.difference(&right_iris).cloned().collect(),
+        right_only_iris: right_iris.difference(&left_iris).cloned().collect(),
+        left_only_blank_statements: left_blank.difference(&right_blank).cloned().collect(),
+        right_only_blank_statements: right_blank.difference(&left_blank).cloned().collect(),
+        literal_drift: literal_drift(left, right),
+    })
+}
+
+///
+/// Read the files at `left` and `right`, each in the format given by its extension and
+/// decompressed as by `read_graph_from_path`, and compare their identifiers.
+///
+pub fn compare_paths(
+    left: impl AsRef<Path>,
+    right: impl AsRef<Path>,
+    factory: GraphFactoryRef,
+) -> Result<StabilityReport> {
+    let left = read_path(left.as_ref(), factory.clone())?;
+    let right = read_path(right.as_ref(), factory)?;
+    let report = compare(&*left.borrow(), &*right.borrow());
+    report
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for LiteralDrift {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} <{}> {:?}: {} became {}",
+            self.subject, self.predicate, self.lexical_form, self.left, self.right
+        )
+    }
+}
+
+impl LiteralDrift {
+    ///
+    /// Return the subject of the statements with the literal.
+    ///
+    pub fn subject(&self) -> &SubjectNodeRef {
+        &self.subject
+    }
+
+    ///
+    /// Return the predicate of the statements with the literal.
+    ///
+    pub fn predicate(&self) -> &IRIRef {
+        &self.predicate
+    }
+
+    ///
+    /// Return the lexical form of the literal.
+    ///
+    pub fn lexical_form(&self) -> &String {
+        &self.lexical_form
+    }
+
+    ///
+    /// Return the data type IRI, or the language in the form `@tag`, of the literal in the
+    /// left-hand graph.
+    ///
+    pub fn left(&self) -> &String {
+        &self.left
+    }
+
+    ///
+    /// Return the data type IRI, or the language in the form `@tag`, of the literal in the
+    /// right-hand graph.
+    ///
+    pub fn right(&self) -> &String {
+        &self.right
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for StabilityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_stable() {
+            return writeln!(f, "No differences");
+        }
+        if !self.left_only_iris.is_empty() || !self.right_only_iris.is_empty() {
+            writeln!(f, "IRIs:")?;
+            for iri in &self.left_only_iris {
+                writeln!(f, "  < <{}>", iri)?;
+            }
+            for iri in &self.right_only_iris {
+                writeln!(f, "  > <{}>", iri)?;
+            }
+        }
+        if !self.left_only_blank_statements.is_empty()
+            || !self.right_only_blank_statements.is_empty()
+        {
+            writeln!(f, "Blank node statements:")?;
+            for statement in &self.left_only_blank_statements {
+                writeln!(f, "  < {}", statement)?;
+            }
+            for statement in &self.right_only_blank_statements {
+                writeln!(f, "  > {}", statement)?;
+            }
+        }
+        if !self.literal_drift.is_empty() {
+            writeln!(f, "Literal drift:")?;
+            for drift in &self.literal_drift {
+                writeln!(f, "  {}", drift)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl StabilityReport {
+    ///
+    /// Returns `true` if no differences were found.
+    ///
+    pub fn is_stable(&self) -> bool {
+        self.left_only_iris.is_empty()
+            && self.right_only_iris.is_empty()
+            && self.left_only_blank_statements.is_empty()
+            && self.right_only_blank_statements.is_empty()
+            && self.literal_drift.is_empty()
+    }
+
+    ///
+    /// Return the IRIs used in the left-hand graph but not in the right-hand graph.
+    ///
+    pub fn left_only_iris(&self) -> &BTreeSet<IRIRef> {
+        &self.left_only_iris
+    }
+
+    ///
+    /// Return the IRIs used in the right-hand graph but not in the left-hand graph.
+    ///
+    pub fn right_only_iris(&self) -> &BTreeSet<IRIRef> {
+        &self.right_only_iris
+    }
+
+    ///
+    /// Return the canonical N-Triples form of the statements with blank nodes found only in the
+    /// left-hand graph.
+    ///
+    pub fn left_only_blank_statements(&self) -> &Vec<String> {
+        &self.left_only_blank_statements
+    }
+
+    ///
+    /// Return the canonical N-Triples form of the statements with blank nodes found only in the
+    /// right-hand graph.
+    ///
+    pub fn right_only_blank_statements(&self) -> &Vec<String> {
+        &self.right_only_blank_statements
+    }
+
+    ///
+    /// Return the literals with a different data type, or language, in each graph.
+    ///
+    pub fn literal_drift(&self) -> &Vec<LiteralDrift> {
+        &self.literal_drift
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn read_path(path: &Path, factory: GraphFactoryRef) -> Result<rdftk_core::model::graph::GraphRef> {
+    match Format::from_path(path) {
+        Some(format) => {
+            let mut file = CompressedReader::open(path, None)?;
+            format.read_graph(&mut file, factory)
+        }
+        None => Err(ErrorKind::Msg(format!(
+            "No format is known for the file {:?}",
+            path.display()
+        ))
+        .into()),
+    }
+}
+
+fn iris(graph: &dyn Graph) -> BTreeSet<IRIRef> {
+    graph
+        .statements()
+        .flat_map(|statement| {
+            vec![
+                statement.subject().as_iri().cloned(),
+                Some(statement.predicate().clone()),
+                statement.object().as_iri().cloned(),
+            ]
+        })
+        .flatten()
+        .collect()
+}
+
+fn blank_statements(graph: &dyn Graph) -> Result<HashSet<String>> {
+    let statements: StatementList = graph
+        .statements()
+        .filter(|statement| statement.subject().is_blank() || statement.object().is_blank())
+        .cloned()
+        .collect();
+    let blank_graph = graph.factory().graph_from(&statements, None);
+    let canonical = canonical_n_triples(&*blank_graph.borrow())?;
+    Ok(canonical.into_iter().collect())
+}
+
+fn literal_drift(left: &dyn Graph, right: &dyn Graph) -> Vec<LiteralDrift> {
+    let left_literals = literals(left);
+    let right_literals = literals(right);
+    let mut drift: Vec<LiteralDrift> = Default::default();
+    for (key, left_types) in &left_literals {
+        if let Some(right_types) = right_literals.get(key) {
+            let left_only: Vec<&String> = left_types.difference(right_types).collect();
+            let right_only: Vec<&String> = right_types.difference(left_types).collect();
+            for (left_type, right_type) in left_only.iter().zip(right_only.iter()) {
+                let (subject, predicate, lexical_form) = key.clone();
+                drift.push(LiteralDrift {
+                    subject,
+                    predicate,
+                    lexical_form,
+                    left: left_type.to_string(),
+                    right: right_type.to_string(),
+                });
+            }
+        }
+    }
+    drift.sort_by_key(|drift| drift.to_string());
+    drift
+}
+
+fn literals(graph: &dyn Graph) -> HashMap<LiteralKey, BTreeSet<String>> {
+    let mut literals: HashMap<LiteralKey, BTreeSet<String>> = Default::default();
+    for statement in graph.statements() {
+        if statement.subject().is_blank() {
+            // drift under blank nodes is reported as a difference in structure.
+            continue;
+        }
+        if let Some(literal) = statement.object().as_literal() {
+            let _ = literals
+                .entry((
+                    statement.subject().clone(),
+                    statement.predicate().clone(),
+                    literal.lexical_form().clone(),
+                ))
+                .or_default()
+                .insert(literal_type(literal));
+        }
+    }
+    literals
+}
+
+fn literal_type(literal: &LiteralRef) -> String {
+    match (literal.language(), literal.data_type()) {
+        (Some(language), _) => format!("@{}", language),
+        (None, Some(data_type)) => data_type.as_iri().to_string(),
+        (None, None) => DataType::String.as_iri().to_string(),
+    }
+}
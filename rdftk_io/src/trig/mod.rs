@@ -1,8 +1,30 @@
 /*!
-Provides for reading and writing a `Graph` instance in the
+Provides for reading and writing a `DataSet` instance in the
 W3C [RDF 1.1 TriG](https://www.w3.org/TR/trig/), _RDF Dataset Language_ format.
 */
 
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+/// The display name of this serialization format.
+pub const NAME: &str = "TriG";
+
+/// The common file extension for this serialization format.
+pub const FILE_EXTENSION: &str = "trig";
+
+/// The MIME type used for this serialization format.
+pub const MIME_TYPE: &str = "application/trig";
+
+/// An IRI that defines the language.
+pub const FORMAT_IRI: &str = "http://www.w3.org/ns/formats/TriG";
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+mod parser;
+
 pub mod reader;
 
 pub mod writer;
@@ -0,0 +1,713 @@
+/*!
+A parser for the W3C [RDF 1.1 TriG](https://www.w3.org/TR/trig/) format, producing a `DataSet`
+with a default graph and any named graphs declared in the document.
+
+All graphs created by the parser share a single prefix mapping, populated from the prefix
+directives in the document. Relative IRIs are resolved against the current base, if any, which
+may be provided by the caller and replaced by base directives in the document.
+
+*/
+
+#![allow(clippy::upper_case_acronyms)] // << generated by pest.
+
+use crate::common::parser::{resolve, unescape_iri, unescape_local_name, unescape_string};
+use crate::common::parser_error::ParserErrorFactory;
+use pest::iterators::Pair;
+use pest::Parser;
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::data_set::{DataSetFactoryRef, DataSetRef, GraphName, GraphNameRef};
+use rdftk_core::model::graph::mapping::PrefixMappingRef;
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralFactoryRef, LiteralRef};
+use rdftk_core::model::statement::{ObjectNodeRef, StatementFactoryRef, SubjectNodeRef};
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, xsd};
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Parser)]
+#[grammar = "trig/trig.pest"]
+struct TriGParser;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const ERROR: ParserErrorFactory = ParserErrorFactory { repr: super::NAME };
+
+#[derive(Debug)]
+struct ParserState {
+    data_set: DataSetRef,
+    graph_factory: GraphFactoryRef,
+    statements: StatementFactoryRef,
+    literals: LiteralFactoryRef,
+    mappings: PrefixMappingRef,
+    prefixes: HashMap<String, IRIRef>,
+    base: Option<IRIRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn parse_data_set(
+    input: &str,
+    factory: DataSetFactoryRef,
+    base: Option<IRIRef>,
+) -> Result<DataSetRef> {
+    let mut parsed = TriGParser::parse(Rule::trigDoc, input).map_err(|e| ERROR.parser(e))?;
+    let top_node = parsed.next().unwrap();
+    trig_doc(top_node, factory, base)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ParserState {
+    fn new(factory: DataSetFactoryRef, base: Option<IRIRef>) -> Self {
+        let data_set = factory.data_set(None);
+        let graph_factory = data_set.borrow().graph_factory();
+        let (statements, literals) = {
+            let graph = graph_factory.graph();
+            let graph = graph.borrow();
+            (graph.statement_factory(), graph.literal_factory())
+        };
+        let mappings = graph_factory.mapping_factory().empty();
+        Self {
+            data_set,
+            graph_factory,
+            statements,
+            literals,
+            mappings,
+            prefixes: Default::default(),
+            base,
+        }
+    }
+
+    fn default_graph(&self) -> GraphRef {
+        let mut data_set = self.data_set.borrow_mut();
+        if !data_set.has_default_graph() {
+            data_set.set_default_graph(self.graph_factory.with_mappings(self.mappings.clone()));
+        }
+        data_set.default_graph().unwrap().clone()
+    }
+
+    fn named_graph(&self, name: &GraphNameRef) -> GraphRef {
+        let mut data_set = self.data_set.borrow_mut();
+        if !data_set.has_graph_named(name) {
+            data_set.insert(
+                name.clone(),
+                self.graph_factory.with_mappings(self.mappings.clone()),
+            );
+        }
+        data_set.graph_named(name).unwrap().clone()
+    }
+
+    fn insert(
+        &self,
+        graph: &GraphRef,
+        subject: SubjectNodeRef,
+        predicate: IRIRef,
+        object: ObjectNodeRef,
+    ) -> Result<()> {
+        let statement = self.statements.statement(subject, predicate, object)?;
+        graph.borrow_mut().insert(statement);
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn trig_doc(
+    input_pair: Pair<'_, Rule>,
+    factory: DataSetFactoryRef,
+    base: Option<IRIRef>,
+) -> Result<DataSetRef> {
+    trace!("trig_doc({:?})", &input_pair.as_rule());
+
+    let mut state = ParserState::new(factory, base);
+
+    if input_pair.as_rule() == Rule::trigDoc {
+        for inner_pair in input_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::directive => directive(inner_pair, &mut state)?,
+                Rule::block => block(inner_pair, &mut state)?,
+                Rule::EOI => {
+                    trace!("Done.")
+                }
+                _ => {
+                    unexpected!("trig_doc", inner_pair)
+                }
+            }
+        }
+    } else {
+        unexpected!("trig_doc", input_pair);
+    }
+
+    Ok(state.data_set)
+}
+
+fn directive(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<()> {
+    trace!("directive({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::directive {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::prefixID | Rule::sparqlPrefix => {
+                let mut inner_pairs = inner_pair.into_inner();
+                let prefix = inner_pairs.next().unwrap().as_str();
+                // strip the trailing ':'
+                let prefix = &prefix[..prefix.len() - 1];
+                let namespace = iri_ref(inner_pairs.next().unwrap(), state)?;
+                state
+                    .mappings
+                    .borrow_mut()
+                    .insert(prefix, namespace.clone());
+                let _ = state.prefixes.insert(prefix.to_string(), namespace);
+            }
+            Rule::base | Rule::sparqlBase => {
+                let base = iri_ref(inner_pair.into_inner().next().unwrap(), state)?;
+                state.base = Some(base);
+            }
+            _ => {
+                unexpected!("directive", inner_pair)
+            }
+        }
+        Ok(())
+    } else {
+        unexpected!("directive", input_pair);
+    }
+}
+
+fn block(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<()> {
+    trace!("block({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::block {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::namedGraph => {
+                let mut inner_pairs = inner_pair.into_inner();
+                let name = graph_name(inner_pairs.next().unwrap(), state)?;
+                let graph = state.named_graph(&name);
+                wrapped_graph(inner_pairs.next().unwrap(), state, &graph)
+            }
+            Rule::triplesOrGraph => {
+                let mut inner_pairs = inner_pair.into_inner();
+                let label_or_subject = inner_pairs.next().unwrap();
+                let rest = inner_pairs.next().unwrap();
+                if rest.as_rule() == Rule::wrappedGraph {
+                    let name = graph_name(label_or_subject, state)?;
+                    let graph = state.named_graph(&name);
+                    wrapped_graph(rest, state, &graph)
+                } else {
+                    let graph = state.default_graph();
+                    let subject = label_or_subject_node(label_or_subject, state)?;
+                    predicate_object_list(rest, state, &graph, &subject)
+                }
+            }
+            Rule::wrappedGraph => {
+                let graph = state.default_graph();
+                wrapped_graph(inner_pair, state, &graph)
+            }
+            Rule::triples2 => {
+                let graph = state.default_graph();
+                triples(inner_pair, state, &graph)
+            }
+            _ => {
+                unexpected!("block", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("block", input_pair);
+    }
+}
+
+fn graph_name(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<GraphNameRef> {
+    trace!("graph_name({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::labelOrSubject {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(GraphName::named_ref(iri(inner_pair, state)?)),
+            Rule::BlankNode => match blank_node_label(&inner_pair) {
+                Some(label) => Ok(Rc::from(GraphName::blank_named(label))),
+                None => Ok(GraphName::blank_ref()),
+            },
+            _ => {
+                unexpected!("graph_name", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("graph_name", input_pair);
+    }
+}
+
+fn label_or_subject_node(
+    input_pair: Pair<'_, Rule>,
+    state: &ParserState,
+) -> Result<SubjectNodeRef> {
+    trace!("label_or_subject_node({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::labelOrSubject {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(state.statements.named_subject(iri(inner_pair, state)?)),
+            Rule::BlankNode => blank_subject(&inner_pair, state),
+            _ => {
+                unexpected!("label_or_subject_node", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("label_or_subject_node", input_pair);
+    }
+}
+
+fn wrapped_graph(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &GraphRef,
+) -> Result<()> {
+    trace!("wrapped_graph({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::wrappedGraph {
+        if let Some(triples_block) = input_pair.into_inner().next() {
+            for inner_pair in triples_block.into_inner() {
+                triples(inner_pair, state, graph)?;
+            }
+        }
+        Ok(())
+    } else {
+        unexpected!("wrapped_graph", input_pair);
+    }
+}
+
+fn triples(input_pair: Pair<'_, Rule>, state: &mut ParserState, graph: &GraphRef) -> Result<()> {
+    trace!("triples({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::triples || input_pair.as_rule() == Rule::triples2 {
+        let mut inner_pairs = input_pair.into_inner();
+        let first = inner_pairs.next().unwrap();
+        let subject = match first.as_rule() {
+            Rule::subject => subject(first, state, graph)?,
+            Rule::blankNodePropertyList => {
+                let object = blank_node_property_list(first, state, graph)?;
+                state.statements.object_as_subject(object).unwrap()
+            }
+            Rule::collection => {
+                let object = collection(first, state, graph)?;
+                state.statements.object_as_subject(object).unwrap()
+            }
+            _ => {
+                unexpected!("triples", first)
+            }
+        };
+        if let Some(inner_pair) = inner_pairs.next() {
+            predicate_object_list(inner_pair, state, graph, &subject)?;
+        }
+        Ok(())
+    } else {
+        unexpected!("triples", input_pair);
+    }
+}
+
+fn predicate_object_list(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &GraphRef,
+    subject: &SubjectNodeRef,
+) -> Result<()> {
+    trace!("predicate_object_list({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::predicateObjectList {
+        for verb_object_list in input_pair.into_inner() {
+            let mut inner_pairs = verb_object_list.into_inner();
+            let predicate = verb(inner_pairs.next().unwrap(), state)?;
+            for inner_pair in inner_pairs.next().unwrap().into_inner() {
+                let object = object(inner_pair, state, graph)?;
+                state.insert(graph, subject.clone(), predicate.clone(), object)?;
+            }
+        }
+        Ok(())
+    } else {
+        unexpected!("predicate_object_list", input_pair);
+    }
+}
+
+fn verb(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("verb({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::verb {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::predicate => iri(inner_pair.into_inner().next().unwrap(), state),
+            Rule::rdfType => Ok(rdf::a_type().clone()),
+            _ => {
+                unexpected!("verb", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("verb", input_pair);
+    }
+}
+
+fn subject(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &GraphRef,
+) -> Result<SubjectNodeRef> {
+    trace!("subject({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::subject {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(state.statements.named_subject(iri(inner_pair, state)?)),
+            Rule::BlankNode => blank_subject(&inner_pair, state),
+            Rule::collection => {
+                let object = collection(inner_pair, state, graph)?;
+                Ok(state.statements.object_as_subject(object).unwrap())
+            }
+            _ => {
+                unexpected!("subject", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("subject", input_pair);
+    }
+}
+
+fn object(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &GraphRef,
+) -> Result<ObjectNodeRef> {
+    trace!("object({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::object {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(state.statements.named_object(iri(inner_pair, state)?)),
+            Rule::BlankNode => Ok(state
+                .statements
+                .subject_as_object(blank_subject(&inner_pair, state)?)),
+            Rule::collection => collection(inner_pair, state, graph),
+            Rule::blankNodePropertyList => blank_node_property_list(inner_pair, state, graph),
+            Rule::literal => Ok(state.statements.literal_object(literal(inner_pair, state)?)),
+            _ => {
+                unexpected!("object", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("object", input_pair);
+    }
+}
+
+fn blank_node_property_list(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &GraphRef,
+) -> Result<ObjectNodeRef> {
+    trace!("blank_node_property_list({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::blankNodePropertyList {
+        let subject = state.statements.blank_subject();
+        predicate_object_list(
+            input_pair.into_inner().next().unwrap(),
+            state,
+            graph,
+            &subject,
+        )?;
+        Ok(state.statements.subject_as_object(subject))
+    } else {
+        unexpected!("blank_node_property_list", input_pair);
+    }
+}
+
+fn collection(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    graph: &GraphRef,
+) -> Result<ObjectNodeRef> {
+    trace!("collection({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::collection {
+        let members = input_pair
+            .into_inner()
+            .map(|inner_pair| object(inner_pair, state, graph))
+            .collect::<Result<Vec<ObjectNodeRef>>>()?;
+        let mut list = state.statements.named_object(rdf::nil().clone());
+        for member in members.into_iter().rev() {
+            let node = state.statements.blank_subject();
+            state.insert(graph, node.clone(), rdf::first().clone(), member)?;
+            state.insert(graph, node.clone(), rdf::rest().clone(), list)?;
+            list = state.statements.subject_as_object(node);
+        }
+        Ok(list)
+    } else {
+        unexpected!("collection", input_pair);
+    }
+}
+
+fn blank_subject(input_pair: &Pair<'_, Rule>, state: &ParserState) -> Result<SubjectNodeRef> {
+    match blank_node_label(input_pair) {
+        Some(label) => state.statements.blank_subject_named(label),
+        None => Ok(state.statements.blank_subject()),
+    }
+}
+
+fn blank_node_label<'a>(input_pair: &Pair<'a, Rule>) -> Option<&'a str> {
+    trace!("blank_node_label({:?})", &input_pair.as_rule());
+
+    let inner_pair = input_pair.clone().into_inner().next().unwrap();
+    if inner_pair.as_rule() == Rule::BLANK_NODE_LABEL {
+        // strip the leading '_:'
+        Some(&inner_pair.as_str()[2..])
+    } else {
+        None
+    }
+}
+
+fn literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<LiteralRef> {
+    trace!("literal({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::literal {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::rdfLiteral => rdf_literal(inner_pair, state),
+            Rule::NumericLiteral => {
+                let lexical_form = inner_pair.as_str();
+                let data_type = match inner_pair.into_inner().last().unwrap().as_rule() {
+                    Rule::INTEGER => xsd::integer(),
+                    Rule::DECIMAL => xsd::decimal(),
+                    _ => xsd::double(),
+                };
+                Ok(state
+                    .literals
+                    .with_data_type(lexical_form, DataType::Other(data_type.clone())))
+            }
+            Rule::BooleanLiteral => Ok(state.literals.boolean(inner_pair.as_str() == "true")),
+            _ => {
+                unexpected!("literal", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("literal", input_pair);
+    }
+}
+
+fn rdf_literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<LiteralRef> {
+    trace!("rdf_literal({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::rdfLiteral {
+        let mut inner_pair = input_pair.into_inner();
+        let lexical_form = string(inner_pair.next().unwrap())?;
+
+        if let Some(other) = inner_pair.next() {
+            match other.as_rule() {
+                Rule::iri => {
+                    let data_type = DataType::Other(iri(other, state)?);
+                    Ok(state.literals.with_data_type(&lexical_form, data_type))
+                }
+                Rule::LANGTAG => {
+                    // strip the leading '@'
+                    let lang_tag = LanguageTag::from_str(&other.as_str()[1..])?;
+                    Ok(state.literals.with_language(&lexical_form, lang_tag))
+                }
+                _ => {
+                    unexpected!("rdf_literal", other);
+                }
+            }
+        } else {
+            Ok(state.literals.literal(&lexical_form))
+        }
+    } else {
+        unexpected!("rdf_literal", input_pair);
+    }
+}
+
+fn string(input_pair: Pair<'_, Rule>) -> Result<String> {
+    trace!("string({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::String {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::STRING_LITERAL_QUOTE
+            | Rule::STRING_LITERAL_SINGLE_QUOTE
+            | Rule::STRING_LITERAL_LONG_QUOTE
+            | Rule::STRING_LITERAL_LONG_SINGLE_QUOTE => {
+                let inner_pair = inner_pair.into_inner().next().unwrap();
+                Ok(unescape_string(inner_pair.as_str()))
+            }
+            _ => {
+                unexpected!("string", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("string", input_pair);
+    }
+}
+
+fn iri(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("iri({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::iri {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::IRIREF => iri_ref(inner_pair, state),
+            Rule::prefixedName => prefixed_name(inner_pair, state),
+            _ => {
+                unexpected!("iri", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("iri", input_pair);
+    }
+}
+
+fn iri_ref(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("iri_ref({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::IRIREF {
+        let iri = input_pair.as_str();
+        // strip the '<' and '>' characters.
+        let iri_str = unescape_iri(&iri[1..iri.len() - 1]);
+        let iri = IRI::from_str(&iri_str)?;
+        if iri.has_scheme() {
+            Ok(IRIRef::new(iri))
+        } else {
+            match &state.base {
+                None => Err(ErrorKind::AbsoluteIriExpected(iri_str).into()),
+                Some(base) => Ok(IRIRef::new(IRI::from_str(&resolve(
+                    &base.to_string(),
+                    &iri_str,
+                ))?)),
+            }
+        }
+    } else {
+        unexpected!("iri_ref", input_pair);
+    }
+}
+
+fn prefixed_name(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("prefixed_name({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::prefixedName {
+        let qname = input_pair.as_str();
+        let (prefix, local) = qname.split_at(qname.find(':').unwrap());
+        // prefix mappings may not map two prefixes to the same namespace, so resolve from the
+        // prefixes declared in the document.
+        match state.prefixes.get(prefix) {
+            None => Err(ErrorKind::InvalidQName(qname.to_string()).into()),
+            Some(namespace) => Ok(IRIRef::new(IRI::from_str(&format!(
+                "{}{}",
+                namespace,
+                unescape_local_name(&local[1..])
+            ))?)),
+        }
+    } else {
+        unexpected!("prefixed_name", input_pair);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rdftk_core::simple::data_set::data_set_factory;
+
+    fn parse_text(input: &str) -> DataSetRef {
+        match parse_data_set(input, data_set_factory(), None) {
+            Ok(data_set) => data_set,
+            Err(e) => {
+                println!("{:?}", e);
+                panic!("test failed");
+            }
+        }
+    }
+
+    #[test]
+    fn parse_default_graph_only() {
+        let data_set = parse_text(
+            r###"@prefix ex: <http://example.org/> .
+ex:subject ex:predicate "object" ; a ex:Thing .
+"###,
+        );
+        let data_set = data_set.borrow();
+        assert_eq!(data_set.len(), 1);
+        assert_eq!(data_set.default_graph().unwrap().borrow().len(), 2);
+    }
+
+    #[test]
+    fn parse_spec_example_2() {
+        let data_set = parse_text(
+            r###"# This document contains a default graph and two named graphs.
+
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix dc: <http://purl.org/dc/terms/> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+
+# default graph
+    {
+      <http://example.org/bob> dc:publisher "Bob" .
+      <http://example.org/alice> dc:publisher "Alice" .
+    }
+
+<http://example.org/bob>
+    {
+       _:a foaf:name "Bob" .
+       _:a foaf:mbox <mailto:bob@oldcorp.example.org> .
+       _:a foaf:knows _:b .
+    }
+
+<http://example.org/alice>
+    {
+       _:b foaf:name "Alice" .
+       _:b foaf:mbox <mailto:alice@work.example.org>
+    }
+"###,
+        );
+        let data_set = data_set.borrow();
+        assert_eq!(data_set.default_graph().unwrap().borrow().len(), 2);
+        assert_eq!(data_set.len(), 3);
+        let bob = GraphName::named_ref(IRIRef::from(
+            IRI::from_str("http://example.org/bob").unwrap(),
+        ));
+        assert_eq!(data_set.graph_named(&bob).unwrap().borrow().len(), 3);
+    }
+
+    #[test]
+    fn parse_graph_keyword_and_collections() {
+        let data_set = parse_text(
+            r###"PREFIX : <http://example.org/ns#>
+BASE <http://example.org/base/>
+
+GRAPH <graph1> {
+    :s :p ( 1 2.5 "three"@en ) ;
+       :q [ :r true ] .
+}
+"###,
+        );
+        let data_set = data_set.borrow();
+        assert!(!data_set.has_default_graph());
+        let name = GraphName::named_ref(IRIRef::from(
+            IRI::from_str("http://example.org/base/graph1").unwrap(),
+        ));
+        // 2 from :s, 6 for the list, 1 for the nested blank node.
+        assert_eq!(data_set.graph_named(&name).unwrap().borrow().len(), 9);
+    }
+
+    #[test]
+    fn parse_undefined_prefix() {
+        assert!(parse_data_set("ex:s ex:p ex:o .", data_set_factory(), None).is_err());
+    }
+}
@@ -1,34 +1,115 @@
 /*!
-One-line description.
-
-More detailed description, with
+Provides the `TriGReader` implementation of the `DataSetReader` trait.
 
 # Example
 
+```rust
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_io::trig::reader::TriGReader;
+use rdftk_io::DataSetReader;
+
+let mut content = r#"@prefix ex: <http://example.org/> .
+ex:alice ex:knows ex:bob .
+ex:graph { ex:bob ex:knows ex:alice . }
+"#.as_bytes();
+let reader = TriGReader::default();
+let data_set = reader.read(&mut content, data_set_factory()).unwrap();
+assert_eq!(data_set.borrow().len(), 2);
+```
+
+A document using relative IRIs, without a base directive, may be read with a base IRI provided
+by the caller, such as the location it was retrieved from.
+
+```rust
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_io::trig::reader::TriGReader;
+use rdftk_io::DataSetReader;
+use std::str::FromStr;
+
+let mut content = "<alice> <knows> <bob> .".as_bytes();
+let base = IRIRef::from(IRI::from_str("http://example.org/people/").unwrap());
+let reader = TriGReader::with_base(base);
+let data_set = reader.read(&mut content, data_set_factory()).unwrap();
+assert!(data_set.borrow().has_default_graph());
+```
+
 */
 
-// use ...
+use crate::conformance::conform;
+use crate::format::Format;
+use crate::trig::parser;
+use crate::DataSetReader;
+use rdftk_core::error::Result;
+use rdftk_core::model::data_set::{DataSetFactoryRef, DataSetRef};
+use rdftk_iri::IRIRef;
+use std::io::Read;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
-
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+///
+/// An implementation of the DataSetReader trait to read resources in the TriG representation.
+/// Triples outside of a graph block are added to the data set's default graph. Relative IRIs are
+/// resolved against the base IRI, either one provided to `with_base` or one declared in the
+/// document; without a base relative IRIs are an error. If `strict` is set the document is first
+/// checked for conformance to the TriG specification, see the `conformance` module, and any
+/// violation aborts the read.
+///
+#[derive(Clone, Debug, Default)]
+pub struct TriGReader {
+    base: Option<IRIRef>,
+    strict: bool,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl TriGReader {
+    ///
+    /// Create a new reader that will resolve relative IRIs against the provided base IRI.
+    ///
+    pub fn with_base(base: IRIRef) -> Self {
+        Self {
+            base: Some(base),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// If `strict` is `true`, the document is checked for conformance before it is parsed, and
+    /// any violation aborts the read.
+    ///
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    ///
+    /// Returns `true` if the document is checked for conformance before it is parsed.
+    ///
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+}
+
+impl DataSetReader for TriGReader {
+    fn read(&self, r: &mut impl Read, factory: DataSetFactoryRef) -> Result<DataSetRef> {
+        let mut content: String = String::new();
+        let _ = r.read_to_string(&mut content).map_err(io_error)?;
+        if self.strict {
+            conform(Format::TriG, &content)?;
+        }
+        parser::parse_data_set(&content, factory, self.base.clone())
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn io_error(e: std::io::Error) -> rdftk_core::error::Error {
+    use rdftk_core::error::ErrorKind;
+    rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
+}
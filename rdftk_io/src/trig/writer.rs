@@ -1,34 +1,129 @@
 /*!
-One-line description.
+Provides the `TriGWriter` implementation of the `DataSetWriter` trait.
 
-More detailed description, with
+The writer uses the same options and prefix handling as the Turtle writer; the prefix mappings of
+all graphs in the data set are merged, with the first mapping for any prefix or namespace taking
+precedence. Statements in the default graph are written first, outside of any graph block,
+followed by each named graph in order of its name.
 
 # Example
 
+```rust
+use rdftk_io::trig::writer::TriGWriter;
+use rdftk_io::turtle::writer::TurtleOptions;
+use rdftk_io::write_data_set_to_string;
+# use rdftk_core::model::data_set::DataSetRef;
+# use rdftk_core::simple::data_set::data_set_factory;
+# fn make_data_set() -> DataSetRef { data_set_factory().data_set(None) }
+
+let writer = TriGWriter::new(TurtleOptions::default());
+
+let result = write_data_set_to_string(&writer, &make_data_set());
+```
+
 */
 
-// use ...
+use crate::turtle::writer::{io_error, TurtleOptions, TurtleWriter};
+use crate::DataSetWriter;
+use rdftk_core::error::Result;
+use rdftk_core::model::data_set::{DataSet, DataSetRef, GraphNameRef};
+use rdftk_core::model::graph::mapping::PrefixMappingRef;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_iri::IRIRef;
+use std::io::Write;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Private Types
-// ------------------------------------------------------------------------------------------------
-
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+///
+/// This struct implements the `DataSetWriter` trait and will write out a serialized form of the
+/// entire data set.
+///
+#[derive(Debug, Default)]
+pub struct TriGWriter {
+    inner: TurtleWriter,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl DataSetWriter for TriGWriter {
+    fn write(&self, w: &mut impl Write, data_set: &DataSetRef) -> Result<()> {
+        let data_set = data_set.borrow();
+        let mappings = merged_mappings(&data_set);
+        self.inner.write_header(w, &mappings)?;
+
+        if let Some(graph) = data_set.default_graph() {
+            let graph = graph.borrow();
+            self.inner
+                .write_statements(w, &graph, &mappings, self.inner.indenter())?;
+        }
+
+        let mut graphs: Vec<(&GraphNameRef, &GraphRef)> = data_set.graphs().collect();
+        graphs.sort_by_key(|(name, _)| name.to_string());
+        for (name, graph) in graphs {
+            match name.as_iri() {
+                Some(iri) => self.inner.write_iri(w, iri, &mappings).map_err(io_error)?,
+                None => write!(w, "{} ", name).map_err(io_error)?,
+            }
+            writeln!(w, "{{").map_err(io_error)?;
+            let graph = graph.borrow();
+            self.inner
+                .write_statements(w, &graph, &mappings, self.inner.indenter().indent())?;
+            writeln!(w, "}}").map_err(io_error)?;
+            writeln!(w).map_err(io_error)?;
+        }
+        Ok(())
+    }
+}
+
+impl TriGWriter {
+    ///
+    /// Create a new writer with the provided options, this is used to override the default
+    /// options that are used when calling `Default::default`.
+    ///
+    pub fn new(options: TurtleOptions) -> Self {
+        Self {
+            inner: TurtleWriter::new(options),
+        }
+    }
+
+    ///
+    /// Create a new writer with the provided base IRI and options, IRIs that start with the base
+    /// IRI will be written as relative IRIs.
+    ///
+    pub fn with_base(base: IRIRef, options: TurtleOptions) -> Self {
+        Self {
+            inner: TurtleWriter::with_base(base, options),
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn merged_mappings(data_set: &std::cell::Ref<'_, dyn DataSet>) -> PrefixMappingRef {
+    let merged = data_set.graph_factory().mapping_factory().empty();
+    {
+        let mut merged = merged.borrow_mut();
+        for graph in data_set
+            .default_graph()
+            .into_iter()
+            .chain(data_set.graphs().map(|(_, graph)| graph))
+        {
+            let graph = graph.borrow();
+            let mappings = graph.prefix_mappings();
+            let mappings = mappings.borrow();
+            for (prefix, namespace) in mappings.mappings() {
+                if merged.get_namespace(prefix).is_none() && merged.get_prefix(namespace).is_none()
+                {
+                    merged.insert(prefix, namespace.clone());
+                }
+            }
+        }
+    }
+    merged
+}
@@ -17,13 +17,17 @@ pub const FILE_EXTENSION: &str = "ttl";
 /// The MIME type used for this serialization format.
 pub const MIME_TYPE: &str = "text/turtle";
 
+/// An IRI that defines the language.
+pub const FORMAT_IRI: &str = "http://www.w3.org/ns/formats/Turtle";
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
 
 mod parser;
 
-#[doc(hidden)]
 pub mod reader;
 
+pub mod repair;
+
 pub mod writer;
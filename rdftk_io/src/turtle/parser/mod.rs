@@ -1,20 +1,35 @@
 /*!
-One-line description.
+A parser for the W3C [RDF 1.1 Turtle](https://www.w3.org/TR/turtle/) format, including the
+embedded triples of [RDF-star](https://w3c.github.io/rdf-star/cg-spec/).
 
-More detailed description, with
+The graph's prefix mappings are populated from the prefix directives in the document. Relative
+IRIs are resolved against the current base, which may be provided by the caller and replaced by
+base directives in the document.
 
-# Example
+Blank node labels are scoped to the document, so by default each label is replaced by a newly
+generated blank node. Alternatively the labels may be preserved as-is, in which case the nodes
+generated for anonymous blank nodes, collections, and property lists will never collide with a
+label used anywhere in the document.
 
 */
 
 #![allow(clippy::upper_case_acronyms)] // << generated by pest.
 
+use crate::common::parser::{resolve, unescape_iri, unescape_local_name, unescape_string};
 use crate::common::parser_error::ParserErrorFactory;
 use pest::iterators::Pair;
 use pest::Parser;
-use rdftk_core::error::Error;
-use rdftk_core::model::graph::GraphRef;
-use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::mapping::PrefixMappingRef;
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralFactoryRef, LiteralRef};
+use rdftk_core::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef,
+};
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, xsd};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -28,58 +43,551 @@ struct TurtleParser;
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)]
 const ERROR: ParserErrorFactory = ParserErrorFactory { repr: super::NAME };
 
+#[derive(Debug)]
+struct ParserState {
+    graph: GraphRef,
+    statements: StatementFactoryRef,
+    literals: LiteralFactoryRef,
+    mappings: PrefixMappingRef,
+    prefixes: HashMap<String, IRIRef>,
+    base: Option<IRIRef>,
+    preserve_labels: bool,
+    labels: HashMap<String, SubjectNodeRef>,
+    reserved: HashSet<String>,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)]
-pub(super) fn parse_text(input: &str) -> Result<GraphRef, Error> {
+pub(super) fn parse_graph(
+    input: &str,
+    factory: GraphFactoryRef,
+    base: Option<IRIRef>,
+    preserve_labels: bool,
+) -> Result<GraphRef> {
     let mut parsed =
         TurtleParser::parse(Rule::turtleStarDoc, input).map_err(|e| ERROR.parser(e))?;
     let top_node = parsed.next().unwrap();
-    turtle_star_doc(top_node)
+    turtle_star_doc(top_node, factory, base, preserve_labels)
 }
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl ParserState {
+    fn new(factory: GraphFactoryRef, base: Option<IRIRef>, preserve_labels: bool) -> Self {
+        let mappings = factory.mapping_factory().empty();
+        let graph = factory.with_mappings(mappings.clone());
+        let (statements, literals) = {
+            let graph = graph.borrow();
+            (graph.statement_factory(), graph.literal_factory())
+        };
+        Self {
+            graph,
+            statements,
+            literals,
+            mappings,
+            prefixes: Default::default(),
+            base,
+            preserve_labels,
+            labels: Default::default(),
+            reserved: Default::default(),
+        }
+    }
+
+    fn insert(
+        &self,
+        subject: SubjectNodeRef,
+        predicate: IRIRef,
+        object: ObjectNodeRef,
+    ) -> Result<()> {
+        let statement = self.statements.statement(subject, predicate, object)?;
+        self.graph.borrow_mut().insert(statement);
+        Ok(())
+    }
+
+    ///
+    /// Return the blank node for `label`, either the preserved label itself or the node
+    /// generated the first time the label was seen in this document.
+    ///
+    fn labelled(&mut self, label: &str) -> Result<SubjectNodeRef> {
+        if self.preserve_labels {
+            self.statements.blank_subject_named(label)
+        } else if let Some(node) = self.labels.get(label) {
+            Ok(node.clone())
+        } else {
+            let node = self.anonymous();
+            let _ = self.labels.insert(label.to_string(), node.clone());
+            Ok(node)
+        }
+    }
+
+    ///
+    /// Return a newly generated blank node, skipping any whose name collides with a label
+    /// reserved by the document.
+    ///
+    fn anonymous(&self) -> SubjectNodeRef {
+        loop {
+            let node = self.statements.blank_subject();
+            if !self.reserved.contains(node.as_blank().unwrap()) {
+                return node;
+            }
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)]
-fn turtle_star_doc(input_pair: Pair<'_, Rule>) -> Result<GraphRef, Error> {
-    let graph: GraphRef = graph_factory().graph();
-
+fn turtle_star_doc(
+    input_pair: Pair<'_, Rule>,
+    factory: GraphFactoryRef,
+    base: Option<IRIRef>,
+    preserve_labels: bool,
+) -> Result<GraphRef> {
     trace!("turtle_star_doc({:?})", &input_pair.as_rule());
 
-    match input_pair.as_rule() {
-        Rule::turtleStarDoc => {}
-        _ => unexpected!("parse_idl", input_pair),
+    let mut state = ParserState::new(factory, base, preserve_labels);
+    if preserve_labels {
+        state.reserved = input_pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .filter(|pair| pair.as_rule() == Rule::BLANK_NODE_LABEL)
+            .map(|pair| pair.as_str()[2..].to_string())
+            .collect();
+    }
+
+    if input_pair.as_rule() == Rule::turtleStarDoc {
+        for inner_pair in input_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::statement => statement(inner_pair, &mut state)?,
+                Rule::EOI => {
+                    trace!("Done.")
+                }
+                _ => {
+                    unexpected!("turtle_star_doc", inner_pair)
+                }
+            }
+        }
+    } else {
+        unexpected!("turtle_star_doc", input_pair);
     }
 
-    Ok(graph)
+    // graphs may copy the mappings passed to the factory, so set the populated mappings again.
+    state
+        .graph
+        .borrow_mut()
+        .set_prefix_mappings(state.mappings.clone());
+    Ok(state.graph)
 }
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn statement(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<()> {
+    trace!("statement({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::statement {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::directive => directive(inner_pair, state),
+            Rule::triples => triples(inner_pair, state),
+            _ => {
+                unexpected!("statement", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("statement", input_pair);
+    }
+}
+
+fn directive(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<()> {
+    trace!("directive({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::directive {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::prefixID | Rule::sparqlPrefix => {
+                let mut inner_pairs = inner_pair.into_inner();
+                let prefix = inner_pairs.next().unwrap().as_str();
+                // strip the trailing ':'
+                let prefix = &prefix[..prefix.len() - 1];
+                let namespace = iri_ref(inner_pairs.next().unwrap(), state)?;
+                state
+                    .mappings
+                    .borrow_mut()
+                    .insert(prefix, namespace.clone());
+                let _ = state.prefixes.insert(prefix.to_string(), namespace);
+            }
+            Rule::base | Rule::sparqlBase => {
+                let base = iri_ref(inner_pair.into_inner().next().unwrap(), state)?;
+                state.base = Some(base);
+            }
+            _ => {
+                unexpected!("directive", inner_pair)
+            }
+        }
+        Ok(())
+    } else {
+        unexpected!("directive", input_pair);
+    }
+}
+
+fn triples(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<()> {
+    trace!("triples({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::triples {
+        let mut inner_pairs = input_pair.into_inner();
+        let first = inner_pairs.next().unwrap();
+        let subject = match first.as_rule() {
+            Rule::subject => subject(first, state)?,
+            Rule::blankNodePropertyList => {
+                let object = blank_node_property_list(first, state)?;
+                state.statements.object_as_subject(object).unwrap()
+            }
+            _ => {
+                unexpected!("triples", first)
+            }
+        };
+        if let Some(inner_pair) = inner_pairs.next() {
+            predicate_object_list(inner_pair, state, &subject)?;
+        }
+        Ok(())
+    } else {
+        unexpected!("triples", input_pair);
+    }
+}
+
+fn predicate_object_list(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+    subject: &SubjectNodeRef,
+) -> Result<()> {
+    trace!("predicate_object_list({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::predicateObjectList {
+        for verb_object_list in input_pair.into_inner() {
+            let mut inner_pairs = verb_object_list.into_inner();
+            let predicate = verb(inner_pairs.next().unwrap(), state)?;
+            for inner_pair in inner_pairs.next().unwrap().into_inner() {
+                let object = object(inner_pair, state)?;
+                state.insert(subject.clone(), predicate.clone(), object)?;
+            }
+        }
+        Ok(())
+    } else {
+        unexpected!("predicate_object_list", input_pair);
+    }
+}
+
+fn verb(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("verb({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::verb {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::predicate => predicate(inner_pair, state),
+            Rule::rdfType => Ok(rdf::a_type().clone()),
+            _ => {
+                unexpected!("verb", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("verb", input_pair);
+    }
+}
+
+fn predicate(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("predicate({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::predicate {
+        iri(input_pair.into_inner().next().unwrap(), state)
+    } else {
+        unexpected!("predicate", input_pair);
+    }
+}
+
+fn subject(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<SubjectNodeRef> {
+    trace!("subject({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::subject || input_pair.as_rule() == Rule::subjectX {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(state.statements.named_subject(iri(inner_pair, state)?)),
+            Rule::BlankNode => blank_subject(&inner_pair, state),
+            Rule::collection => {
+                let object = collection(inner_pair, state)?;
+                Ok(state.statements.object_as_subject(object).unwrap())
+            }
+            Rule::tripleX => {
+                let statement = triple_x(inner_pair, state)?;
+                Ok(state.statements.statement_subject(statement))
+            }
+            _ => {
+                unexpected!("subject", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("subject", input_pair);
+    }
+}
+
+fn object(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<ObjectNodeRef> {
+    trace!("object({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::object || input_pair.as_rule() == Rule::objectX {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::iri => Ok(state.statements.named_object(iri(inner_pair, state)?)),
+            Rule::BlankNode => {
+                let subject = blank_subject(&inner_pair, state)?;
+                Ok(state.statements.subject_as_object(subject))
+            }
+            Rule::collection => collection(inner_pair, state),
+            Rule::blankNodePropertyList => blank_node_property_list(inner_pair, state),
+            Rule::literal => Ok(state.statements.literal_object(literal(inner_pair, state)?)),
+            Rule::tripleX => {
+                let statement = triple_x(inner_pair, state)?;
+                Ok(state.statements.statement_object(statement))
+            }
+            _ => {
+                unexpected!("object", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("object", input_pair);
+    }
+}
+
+fn triple_x(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<StatementRef> {
+    trace!("triple_x({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::tripleX {
+        let mut inner_pairs = input_pair.into_inner();
+        let subject = subject(inner_pairs.next().unwrap(), state)?;
+        let predicate = predicate(inner_pairs.next().unwrap(), state)?;
+        let object = object(inner_pairs.next().unwrap(), state)?;
+        state.statements.statement(subject, predicate, object)
+    } else {
+        unexpected!("triple_x", input_pair);
+    }
+}
+
+fn blank_node_property_list(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+) -> Result<ObjectNodeRef> {
+    trace!("blank_node_property_list({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::blankNodePropertyList {
+        let subject = state.anonymous();
+        predicate_object_list(input_pair.into_inner().next().unwrap(), state, &subject)?;
+        Ok(state.statements.subject_as_object(subject))
+    } else {
+        unexpected!("blank_node_property_list", input_pair);
+    }
+}
+
+fn collection(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<ObjectNodeRef> {
+    trace!("collection({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::collection {
+        let members = input_pair
+            .into_inner()
+            .map(|inner_pair| object(inner_pair, state))
+            .collect::<Result<Vec<ObjectNodeRef>>>()?;
+        let mut list = state.statements.named_object(rdf::nil().clone());
+        for member in members.into_iter().rev() {
+            let node = state.anonymous();
+            state.insert(node.clone(), rdf::first().clone(), member)?;
+            state.insert(node.clone(), rdf::rest().clone(), list)?;
+            list = state.statements.subject_as_object(node);
+        }
+        Ok(list)
+    } else {
+        unexpected!("collection", input_pair);
+    }
+}
+
+fn blank_subject(input_pair: &Pair<'_, Rule>, state: &mut ParserState) -> Result<SubjectNodeRef> {
+    trace!("blank_subject({:?})", &input_pair.as_rule());
+
+    let inner_pair = input_pair.clone().into_inner().next().unwrap();
+    if inner_pair.as_rule() == Rule::BLANK_NODE_LABEL {
+        // strip the leading '_:'
+        state.labelled(&inner_pair.as_str()[2..])
+    } else {
+        Ok(state.anonymous())
+    }
+}
+
+fn literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<LiteralRef> {
+    trace!("literal({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::literal {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::rdfLiteral => rdf_literal(inner_pair, state),
+            Rule::NumericLiteral => {
+                let lexical_form = inner_pair.as_str();
+                let data_type = match inner_pair.into_inner().last().unwrap().as_rule() {
+                    Rule::INTEGER => xsd::integer(),
+                    Rule::DECIMAL => xsd::decimal(),
+                    _ => xsd::double(),
+                };
+                Ok(state
+                    .literals
+                    .with_data_type(lexical_form, DataType::Other(data_type.clone())))
+            }
+            Rule::BooleanLiteral => Ok(state.literals.boolean(inner_pair.as_str() == "true")),
+            _ => {
+                unexpected!("literal", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("literal", input_pair);
+    }
+}
+
+fn rdf_literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<LiteralRef> {
+    trace!("rdf_literal({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::rdfLiteral {
+        let mut inner_pair = input_pair.into_inner();
+        let lexical_form = string(inner_pair.next().unwrap())?;
+
+        if let Some(other) = inner_pair.next() {
+            match other.as_rule() {
+                Rule::iri => {
+                    let data_type = DataType::Other(iri(other, state)?);
+                    Ok(state.literals.with_data_type(&lexical_form, data_type))
+                }
+                Rule::LANGTAG => {
+                    // strip the leading '@'
+                    let lang_tag = LanguageTag::from_str(&other.as_str()[1..])?;
+                    Ok(state.literals.with_language(&lexical_form, lang_tag))
+                }
+                _ => {
+                    unexpected!("rdf_literal", other);
+                }
+            }
+        } else {
+            Ok(state.literals.literal(&lexical_form))
+        }
+    } else {
+        unexpected!("rdf_literal", input_pair);
+    }
+}
+
+fn string(input_pair: Pair<'_, Rule>) -> Result<String> {
+    trace!("string({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::String {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::STRING_LITERAL_QUOTE
+            | Rule::STRING_LITERAL_SINGLE_QUOTE
+            | Rule::STRING_LITERAL_LONG_QUOTE
+            | Rule::STRING_LITERAL_LONG_SINGLE_QUOTE => {
+                let inner_pair = inner_pair.into_inner().next().unwrap();
+                Ok(unescape_string(inner_pair.as_str()))
+            }
+            _ => {
+                unexpected!("string", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("string", input_pair);
+    }
+}
+
+fn iri(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("iri({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::iri {
+        let inner_pair = input_pair.into_inner().next().unwrap();
+        match inner_pair.as_rule() {
+            Rule::IRIREF => iri_ref(inner_pair, state),
+            Rule::prefixedName => prefixed_name(inner_pair, state),
+            _ => {
+                unexpected!("iri", inner_pair)
+            }
+        }
+    } else {
+        unexpected!("iri", input_pair);
+    }
+}
+
+fn iri_ref(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("iri_ref({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::IRIREF {
+        let iri = input_pair.as_str();
+        // strip the '<' and '>' characters.
+        let iri_str = unescape_iri(&iri[1..iri.len() - 1]);
+        let iri = IRI::from_str(&iri_str)?;
+        if iri.has_scheme() {
+            Ok(IRIRef::new(iri))
+        } else {
+            match &state.base {
+                None => Err(ErrorKind::AbsoluteIriExpected(iri_str).into()),
+                Some(base) => Ok(IRIRef::new(IRI::from_str(&resolve(
+                    &base.to_string(),
+                    &iri_str,
+                ))?)),
+            }
+        }
+    } else {
+        unexpected!("iri_ref", input_pair);
+    }
+}
+
+fn prefixed_name(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("prefixed_name({:?})", &input_pair.as_rule());
+
+    if input_pair.as_rule() == Rule::prefixedName {
+        let qname = input_pair.as_str();
+        let (prefix, local) = qname.split_at(qname.find(':').unwrap());
+        // prefix mappings may not map two prefixes to the same namespace, so resolve from the
+        // prefixes declared in the document.
+        match state.prefixes.get(prefix) {
+            None => Err(ErrorKind::InvalidQName(qname.to_string()).into()),
+            Some(namespace) => Ok(IRIRef::new(IRI::from_str(&format!(
+                "{}{}",
+                namespace,
+                unescape_local_name(&local[1..])
+            ))?)),
+        }
+    } else {
+        unexpected!("prefixed_name", input_pair);
+    }
+}
 
 // ------------------------------------------------------------------------------------------------
-// Unit Tests
+// Modules
 // ------------------------------------------------------------------------------------------------
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rdftk_core::simple::graph_factory;
+
+    fn parse_text(input: &str) -> GraphRef {
+        match parse_graph(input, graph_factory(), None, false) {
+            Ok(graph) => graph,
+            Err(e) => {
+                println!("{:?}", e);
+                panic!("test failed");
+            }
+        }
+    }
 
     #[test]
     fn parse_simple() {
-        let result: Result<GraphRef, Error> = parse_text(
+        let graph = parse_text(
             r###"@base <http://example.org/> .
 @prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
 @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
@@ -96,6 +604,39 @@ mod tests {
     a foaf:Person ;
     foaf:name "Spiderman", "Человек-паук"@ru ."###,
         );
-        assert!(result.is_ok());
+        assert_eq!(graph.borrow().len(), 7);
+    }
+
+    #[test]
+    fn parse_collections_and_property_lists() {
+        let graph = parse_text(
+            r###"PREFIX : <http://example.org/ns#>
+
+:s :p ( 1 2.5 -3e2 "four"@en ) ;
+   :q [ :r true ; :t false ] .
+[ :u :v ] .
+( :a :b ) :w :x .
+"###,
+        );
+        // 2 from :s, 8 for the first list, 2 for the nested blank node, 1 for the bare blank
+        // node, 4 for the second list and 1 for its statement.
+        assert_eq!(graph.borrow().len(), 18);
+    }
+
+    #[test]
+    fn parse_embedded_triples() {
+        let graph = parse_text(
+            r###"@prefix : <http://example.org/ns#> .
+<< :alice :knows :bob >> :certainty 0.9 .
+"###,
+        );
+        let graph = graph.borrow();
+        assert_eq!(graph.len(), 1);
+        assert!(graph.statements().next().unwrap().subject().is_statement());
+    }
+
+    #[test]
+    fn parse_undefined_prefix() {
+        assert!(parse_graph("ex:s ex:p ex:o .", graph_factory(), None, false).is_err());
     }
 }
@@ -1,32 +1,161 @@
 /*!
-One-line description.
-
-More detailed description, with
+Provides the `TurtleReader` implementation of the `GraphReader` trait.
 
 # Example
 
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+
+let mut content = r#"@prefix ex: <http://example.org/> .
+ex:alice a ex:Person ;
+    ex:knows [ ex:name "Bob" ] ;
+    ex:likes ( ex:cats ex:dogs ) ;
+    ex:age 42 .
+"#.as_bytes();
+let reader = TurtleReader::default();
+let graph = reader.read(&mut content, graph_factory()).unwrap();
+assert_eq!(graph.borrow().len(), 9);
+```
+
 */
 
-// ------------------------------------------------------------------------------------------------
-// Public Types
-// ------------------------------------------------------------------------------------------------
+use crate::conformance::conform;
+use crate::format::Format;
+use crate::turtle::parser;
+use crate::turtle::repair::{repair, Repair};
+use crate::GraphReader;
+use rdftk_core::error::Result;
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_iri::IRIRef;
+use std::io::Read;
 
 // ------------------------------------------------------------------------------------------------
-// Private Types
+// Public Types
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+///
+/// An implementation of the GraphReader trait to read resources in the Turtle representation.
+/// Relative IRIs are resolved against the base IRI, either one provided to `with_base` or one
+/// declared in the document; without a base relative IRIs are an error.
+///
+/// Blank node labels are replaced by generated names unless `preserve_blank_node_labels` is
+/// set, in which case the labels in the document are kept so that a graph written back out
+/// with the same labels differs minimally from its source.
+///
+/// If `repair` is set common faults in the document, such as a missing final `.`, are repaired
+/// before it is parsed and each repair is logged; see the `repair` module. If `strict` is set
+/// the document is instead checked for conformance to the Turtle specification, see the
+/// `conformance` module, and any violation aborts the read.
+///
+#[derive(Clone, Debug, Default)]
+pub struct TurtleReader {
+    base: Option<IRIRef>,
+    preserve_labels: bool,
+    repair: bool,
+    strict: bool,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+impl TurtleReader {
+    ///
+    /// Create a new reader that will resolve relative IRIs against the provided base IRI.
+    ///
+    pub fn with_base(base: IRIRef) -> Self {
+        Self {
+            base: Some(base),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Keep the blank node labels used in the document, rather than generating new names. Names
+    /// generated for unlabelled blank nodes will not collide with any label in the document.
+    ///
+    pub fn preserve_blank_node_labels(self, preserve: bool) -> Self {
+        Self {
+            preserve_labels: preserve,
+            ..self
+        }
+    }
+
+    ///
+    /// If `repair` is `true`, common faults in the document are repaired, and logged, before it is
+    /// parsed.
+    ///
+    pub fn repair(self, repair: bool) -> Self {
+        Self { repair, ..self }
+    }
+
+    ///
+    /// Returns `true` if common faults in the document are repaired before it is parsed.
+    ///
+    pub fn is_repairing(&self) -> bool {
+        self.repair
+    }
+
+    ///
+    /// If `strict` is `true`, the document is checked for conformance before it is parsed, and
+    /// any violation aborts the read; a strict reader never repairs the document.
+    ///
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    ///
+    /// Returns `true` if the document is checked for conformance before it is parsed.
+    ///
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    ///
+    /// Read a graph from `r`, repairing common faults in the document whether or not this reader
+    /// repairs, and return the graph along with the repairs made. An error is returned if the
+    /// repaired document cannot be parsed.
+    ///
+    pub fn read_with_repairs(
+        &self,
+        r: &mut impl Read,
+        factory: GraphFactoryRef,
+    ) -> Result<(GraphRef, Vec<Repair>)> {
+        let mut content: String = String::new();
+        let _ = r.read_to_string(&mut content).map_err(io_error)?;
+        let (content, repairs) = repair(&content);
+        let graph =
+            parser::parse_graph(&content, factory, self.base.clone(), self.preserve_labels)?;
+        Ok((graph, repairs))
+    }
+}
+
+impl GraphReader for TurtleReader {
+    fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<GraphRef> {
+        let mut content: String = String::new();
+        let _ = r.read_to_string(&mut content).map_err(io_error)?;
+        if self.strict {
+            conform(Format::Turtle, &content)?;
+            parser::parse_graph(&content, factory, self.base.clone(), self.preserve_labels)
+        } else if self.repair {
+            let (content, repairs) = repair(&content);
+            for repair in repairs {
+                warn!("repaired Turtle document, {}", repair);
+            }
+            parser::parse_graph(&content, factory, self.base.clone(), self.preserve_labels)
+        } else {
+            parser::parse_graph(&content, factory, self.base.clone(), self.preserve_labels)
+        }
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn io_error(e: std::io::Error) -> rdftk_core::error::Error {
+    use rdftk_core::error::ErrorKind;
+    rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
+}
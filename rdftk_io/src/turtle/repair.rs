@@ -0,0 +1,411 @@
+/*!
+Best-effort repair of common faults in Turtle documents, applied to the text before it is parsed;
+see `TurtleReader::repair` and `TurtleReader::read_with_repairs`. The following faults are
+repaired:
+
+* a byte order mark at the start of the document is removed,
+* a `"` or `'` inside a single-line literal that is not followed by anything that may follow a
+  literal, such as `.`, `;`, or a language tag, is escaped so long as a later quote on the same
+  line can close the literal,
+* a prefix that is used but not declared is declared, if it is the common prefix of one of the
+  vocabularies in `rdftk_names`,
+* a missing `.` after the final statement is added.
+
+Each repair is returned with its kind and its line and column, both starting at 1, in the
+original text.
+
+# Example
+
+```rust
+use rdftk_io::turtle::repair::{repair, RepairKind};
+
+let (repaired, repairs) = repair("<http://example.org/a> rdfs:label \"a \"quoted\" name\"");
+assert_eq!(
+    repaired,
+    "@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n\
+     <http://example.org/a> rdfs:label \"a \\\"quoted\\\" name\"\n.\n"
+);
+assert_eq!(repairs.len(), 4);
+assert_eq!(
+    repairs[0].kind(),
+    &RepairKind::UndeclaredPrefix(
+        "rdfs".to_string(),
+        "http://www.w3.org/2000/01/rdf-schema#".to_string()
+    )
+);
+assert_eq!(repairs[1].kind(), &RepairKind::UnescapedQuote);
+assert_eq!(repairs[3].to_string(), "1:52: added missing final `.`");
+```
+
+*/
+
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The kind of fault repaired.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RepairKind {
+    /// A byte order mark was removed from the start of the document.
+    ByteOrderMark,
+    /// A quote inside a literal was escaped.
+    UnescapedQuote,
+    /// An undeclared prefix, the first value, was declared with the namespace, the second value.
+    UndeclaredPrefix(String, String),
+    /// A `.` was added after the final statement.
+    MissingFinalDot,
+}
+
+///
+/// A single repair made to a document, with its position in the original text.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Repair {
+    kind: RepairKind,
+    line: usize,
+    column: usize,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct Words {
+    word: String,
+    start: (usize, usize),
+    previous: String,
+    declared: HashSet<String>,
+    used: Vec<(String, usize, usize)>,
+    // within a SPARQL style directive, which is not followed by a `.`.
+    in_directive: bool,
+    after_directive: bool,
+}
+
+#[derive(Debug)]
+struct Cursor {
+    chars: Vec<char>,
+    index: usize,
+    line: usize,
+    column: usize,
+    output: String,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Repair the faults described in the module documentation in `content`, returning the repaired
+/// text and a list of the repairs made, in the order of their position in `content`.
+///
+pub fn repair(content: &str) -> (String, Vec<Repair>) {
+    let mut repairs: Vec<Repair> = Default::default();
+    let content = match content.strip_prefix('\u{FEFF}') {
+        Some(content) => {
+            repairs.push(Repair::new(RepairKind::ByteOrderMark, 1, 1));
+            content
+        }
+        None => content,
+    };
+
+    let mut cursor = Cursor::new(content);
+    let mut words = Words::default();
+    let mut last_significant: Option<(char, usize, usize)> = None;
+
+    while let Some(c) = cursor.peek(0) {
+        if c.is_whitespace() || "#<\"'^()[],;{}".contains(c) {
+            words.finish();
+        }
+        match c {
+            '#' => {
+                while !matches!(cursor.peek(0), None | Some('\n')) {
+                    let _ = cursor.bump();
+                }
+            }
+            '<' => {
+                while let Some(c) = cursor.bump() {
+                    if c == '>' || cursor.peek(0) == Some('\n') {
+                        break;
+                    }
+                }
+                last_significant = Some(('>', cursor.line, cursor.column - 1));
+                words.after_directive = words.in_directive;
+                words.in_directive = false;
+            }
+            '"' | '\'' => {
+                words.end_directive();
+                if cursor.peek(1) == Some(c) && cursor.peek(2) == Some(c) {
+                    cursor.long_string(c);
+                } else {
+                    cursor.short_string(c, &mut repairs);
+                }
+                last_significant = Some((c, cursor.line, cursor.column - 1));
+            }
+            c if c.is_whitespace() => {
+                let _ = cursor.bump();
+            }
+            c => {
+                if "^()[],;{}".contains(c) {
+                    words.end_directive();
+                } else {
+                    if words.word.is_empty() {
+                        words.start = (cursor.line, cursor.column);
+                    }
+                    words.word.push(c);
+                }
+                last_significant = Some((c, cursor.line, cursor.column));
+                let _ = cursor.bump();
+            }
+        }
+    }
+    words.finish();
+
+    let mut header = String::new();
+    for (prefix, line, column) in words.used {
+        if !words.declared.contains(&prefix) {
+            if let Some(namespace) = known_namespace(&prefix) {
+                header.push_str(&format!("@prefix {}: <{}> .\n", prefix, namespace));
+                repairs.push(Repair::new(
+                    RepairKind::UndeclaredPrefix(prefix, namespace),
+                    line,
+                    column,
+                ));
+            }
+        }
+    }
+
+    let mut output = cursor.output;
+    if let Some((c, line, column)) = last_significant {
+        if c != '.' && !words.after_directive {
+            if !output.ends_with('\n') {
+                output.push('\n');
+            }
+            output.push_str(".\n");
+            repairs.push(Repair::new(RepairKind::MissingFinalDot, line, column + 1));
+        }
+    }
+
+    repairs.sort_by_key(|repair| (repair.line, repair.column));
+    (format!("{}{}", header, output), repairs)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for RepairKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ByteOrderMark => write!(f, "removed byte order mark"),
+            Self::UnescapedQuote => write!(f, "escaped quote inside literal"),
+            Self::UndeclaredPrefix(prefix, namespace) => {
+                write!(f, "declared prefix `{}:` as <{}>", prefix, namespace)
+            }
+            Self::MissingFinalDot => write!(f, "added missing final `.`"),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Repair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl Repair {
+    fn new(kind: RepairKind, line: usize, column: usize) -> Self {
+        Self { kind, line, column }
+    }
+
+    ///
+    /// Return the kind of fault repaired.
+    ///
+    pub fn kind(&self) -> &RepairKind {
+        &self.kind
+    }
+
+    ///
+    /// Return the line, starting at 1, of the fault in the original text.
+    ///
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    ///
+    /// Return the column, starting at 1, of the fault in the original text.
+    ///
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Words {
+    fn finish(&mut self) {
+        if self.word.is_empty() {
+            return;
+        }
+        let lower = self.word.to_lowercase();
+        let prefix_name = self.previous == "@prefix" || self.previous == "prefix";
+        if prefix_name {
+            if let Some(prefix) = self.word.strip_suffix(':') {
+                let _ = self.declared.insert(prefix.to_string());
+            }
+        } else if let Some(prefix) = used_prefix(&self.word) {
+            if !self.used.iter().any(|(used, _, _)| used == prefix) {
+                self.used
+                    .push((prefix.to_string(), self.start.0, self.start.1));
+            }
+        }
+        if lower == "prefix" || lower == "base" {
+            self.in_directive = true;
+            self.after_directive = false;
+        } else if !(prefix_name && self.in_directive) {
+            self.end_directive();
+        }
+        self.previous = lower;
+        self.word.clear();
+    }
+
+    fn end_directive(&mut self) {
+        self.in_directive = false;
+        self.after_directive = false;
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Cursor {
+    fn new(content: &str) -> Self {
+        Self {
+            chars: content.chars().collect(),
+            index: 0,
+            line: 1,
+            column: 1,
+            output: String::with_capacity(content.len()),
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.index + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.index += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        self.output.push(c);
+        Some(c)
+    }
+
+    fn long_string(&mut self, quote: char) {
+        for _ in 0..3 {
+            let _ = self.bump();
+        }
+        while let Some(c) = self.bump() {
+            if c == '\\' {
+                let _ = self.bump();
+            } else if c == quote && self.peek(0) == Some(quote) && self.peek(1) == Some(quote) {
+                let _ = self.bump();
+                let _ = self.bump();
+                break;
+            }
+        }
+    }
+
+    fn short_string(&mut self, quote: char, repairs: &mut Vec<Repair>) {
+        let _ = self.bump();
+        loop {
+            match self.peek(0) {
+                None | Some('\n') | Some('\r') => break,
+                Some('\\') => {
+                    let _ = self.bump();
+                    if !matches!(self.peek(0), None | Some('\n') | Some('\r')) {
+                        let _ = self.bump();
+                    }
+                }
+                Some(c) if c == quote => {
+                    if self.closes(self.index, quote) || !self.closed_later(quote) {
+                        let _ = self.bump();
+                        break;
+                    }
+                    repairs.push(Repair::new(
+                        RepairKind::UnescapedQuote,
+                        self.line,
+                        self.column,
+                    ));
+                    self.output.push('\\');
+                    let _ = self.bump();
+                }
+                Some(_) => {
+                    let _ = self.bump();
+                }
+            }
+        }
+    }
+
+    fn closes(&self, index: usize, quote: char) -> bool {
+        match self.chars[index + 1..]
+            .iter()
+            .find(|c| **c != ' ' && **c != '\t')
+        {
+            None | Some('\n') | Some('\r') => true,
+            Some(c) => *c == quote || ".;,)]}^@#".contains(*c),
+        }
+    }
+
+    fn closed_later(&self, quote: char) -> bool {
+        let mut index = self.index + 1;
+        while let Some(c) = self.chars.get(index) {
+            match c {
+                '\n' | '\r' => return false,
+                '\\' => index += 1,
+                c if *c == quote && self.closes(index, quote) => return true,
+                _ => {}
+            }
+            index += 1;
+        }
+        false
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn used_prefix(word: &str) -> Option<&str> {
+    if word.starts_with('@') || word.starts_with("_:") {
+        return None;
+    }
+    let (prefix, _) = word.split_once(':')?;
+    let mut chars = prefix.chars();
+    match chars.next() {
+        None => Some(prefix),
+        Some(c) if c.is_alphabetic() => {
+            if chars.all(|c| c.is_alphanumeric() || "-_.".contains(c)) {
+                Some(prefix)
+            } else {
+                None
+            }
+        }
+        Some(_) => None,
+    }
+}
+
+fn known_namespace(prefix: &str) -> Option<String> {
+    rdftk_names::vocabulary(prefix).map(|vocabulary| vocabulary.namespace().to_string())
+}
@@ -25,29 +25,96 @@ let result = write_graph_to_string(&writer, &make_graph());
 */
 
 use crate::common::indenter::Indenter;
+#[cfg(feature = "config")]
+use crate::config::ConfigHandle;
 use crate::GraphWriter;
 use rdftk_core::model::graph::mapping::PrefixMappingRef;
+use rdftk_core::model::graph::order::{
+    lexical_order, sorted_statements, StatementOrder, StatementOrderRef,
+};
 use rdftk_core::model::graph::{Graph, GraphRef};
 use rdftk_core::model::literal::LiteralRef;
-use rdftk_core::model::statement::SubjectNodeRef;
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef, SubjectNodeRef};
 use rdftk_iri::IRIRef;
+use rdftk_names::rdf;
 use std::cell::Ref;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Options controlling the Turtle output; the fields may be set directly or by the builder
+/// methods, which enable the more compact forms not written by default.
+///
 #[derive(Debug)]
 pub struct TurtleOptions {
+    /// Write a blank node that is the object of only one statement inline, as `[ ... ]`, rather
+    /// than with its label.
     pub nest_blank_nodes: bool,
+    /// Write the SPARQL-style `BASE` and `PREFIX` directives rather than `@base` and `@prefix`.
     pub use_sparql_style: bool,
+    /// Write every blank node with its label, rather than nesting, so that a graph read with
+    /// preserved labels can be written back out with minimal differences.
+    pub preserve_blank_node_labels: bool,
+    /// Write the keyword `a` in place of the predicate `rdf:type`.
+    pub use_type_keyword: bool,
+    /// Write well-formed `rdf:first`/`rdf:rest` lists of blank nodes using the collection syntax
+    /// `( ... )`; this does not apply when blank node labels are preserved.
+    pub use_list_syntax: bool,
+    /// Write prefixes, subjects, predicates, and objects in a stable order, sorted by their
+    /// string form, rather than the graph's iteration order, so that output is reproducible.
+    pub sorted: bool,
+    /// The order of statements used when `sorted` is set, `LexicalOrder` if not specified;
+    /// subjects are written in the order of their first statement, and so on for predicates.
+    pub statement_order: Option<StatementOrderRef>,
+    /// The number of spaces for each level of indentation.
+    pub indent_width: usize,
+    /// If set, the objects of a predicate are written on the same line while the line is no
+    /// longer than this, rather than each on a new line.
+    pub max_line_length: Option<usize>,
+    /// Align the second and later objects of a predicate, when written on a new line, with the
+    /// first, rather than indenting them one level from the predicate.
+    pub align_objects: bool,
 }
 
 #[derive(Debug)]
 pub struct TurtleWriter {
     base: Option<String>,
     options: TurtleOptions,
+    #[cfg(feature = "config")]
+    config: Option<ConfigHandle>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Tracks the column, in characters, of the output so that lines may be wrapped.
+///
+struct LineWriter<'a, W: Write> {
+    inner: &'a mut W,
+    column: usize,
+}
+
+struct Context<'a> {
+    graph: &'a dyn Graph,
+    mappings: &'a PrefixMappingRef,
+    labels: HashMap<String, String>,
+    order: Option<Order>,
+}
+
+///
+/// The subjects, predicates, and objects of a graph in the order of a `StatementOrder`.
+///
+#[derive(Default)]
+struct Order {
+    subjects: Vec<SubjectNodeRef>,
+    predicates: HashMap<SubjectNodeRef, Vec<IRIRef>>,
+    objects: HashMap<(SubjectNodeRef, IRIRef), Vec<ObjectNodeRef>>,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -59,6 +126,67 @@ impl Default for TurtleOptions {
         Self {
             nest_blank_nodes: true,
             use_sparql_style: false,
+            preserve_blank_node_labels: false,
+            use_type_keyword: false,
+            use_list_syntax: false,
+            sorted: false,
+            statement_order: None,
+            indent_width: 2,
+            max_line_length: None,
+            align_objects: false,
+        }
+    }
+}
+
+impl TurtleOptions {
+    ///
+    /// Write the keyword `a` in place of the predicate `rdf:type`.
+    ///
+    pub fn use_type_keyword(self, use_type_keyword: bool) -> Self {
+        Self {
+            use_type_keyword,
+            ..self
+        }
+    }
+
+    ///
+    /// Write well-formed lists of blank nodes using the collection syntax `( ... )`.
+    ///
+    pub fn use_list_syntax(self, use_list_syntax: bool) -> Self {
+        Self {
+            use_list_syntax,
+            ..self
+        }
+    }
+
+    ///
+    /// Indent each level by `indent_width` spaces.
+    ///
+    pub fn indent_width(self, indent_width: usize) -> Self {
+        Self {
+            indent_width,
+            ..self
+        }
+    }
+
+    ///
+    /// Write the objects of a predicate on the same line while the line is no longer than
+    /// `max_line_length`.
+    ///
+    pub fn max_line_length(self, max_line_length: usize) -> Self {
+        Self {
+            max_line_length: Some(max_line_length),
+            ..self
+        }
+    }
+
+    ///
+    /// Align the second and later objects of a predicate with the first.
+    ///
+    pub fn align_objects(self, align_objects: bool) -> Self {
+        Self {
+            align_objects,
+            ..self
         }
     }
 }
@@ -70,6 +198,8 @@ impl Default for TurtleWriter {
         Self {
             base: None,
             options: Default::default(),
+            #[cfg(feature = "config")]
+            config: None,
         }
     }
 }
@@ -77,7 +207,71 @@ impl Default for TurtleWriter {
 impl GraphWriter for TurtleWriter {
     fn write(&self, w: &mut impl Write, graph: &GraphRef) -> rdftk_core::error::Result<()> {
         let graph = graph.borrow();
+        let mappings = graph.prefix_mappings();
+        #[cfg(feature = "config")]
+        let mappings = match &self.config {
+            Some(config) => config
+                .current()
+                .merge_prefixes(&mappings, &graph.factory().mapping_factory()),
+            None => mappings,
+        };
+        self.write_header(w, &mappings)?;
+        self.write_statements(w, &graph, &mappings, self.indenter())
+    }
+}
 
+impl TurtleWriter {
+    ///
+    /// Create a new writer with the provided options, this is used to override the default
+    /// options that are used when calling `Default::default`.
+    ///
+    pub fn new(options: TurtleOptions) -> Self {
+        Self {
+            base: None,
+            options,
+            #[cfg(feature = "config")]
+            config: None,
+        }
+    }
+
+    ///
+    /// Create a new writer with the provided options and a base IRI; the base is written before
+    /// the statements and any IRI that starts with it is written relative to it.
+    ///
+    pub fn with_base(base: IRIRef, options: TurtleOptions) -> Self {
+        Self {
+            base: Some(base.to_string()),
+            options,
+            #[cfg(feature = "config")]
+            config: None,
+        }
+    }
+
+    ///
+    /// Use the prefix mappings of the active profile in `config`, in addition to those of the
+    /// graph, each time a graph is written.
+    ///
+    #[cfg(feature = "config")]
+    pub fn config(&mut self, config: ConfigHandle) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+
+    ///
+    /// Return a new indenter, at depth zero, with the configured indent width.
+    ///
+    pub(crate) fn indenter(&self) -> Indenter {
+        Indenter::with_width(self.options.indent_width)
+    }
+
+    ///
+    /// Write out the base IRI, if any, followed by all of the prefix mappings.
+    ///
+    pub(crate) fn write_header(
+        &self,
+        w: &mut impl Write,
+        mappings: &PrefixMappingRef,
+    ) -> rdftk_core::error::Result<()> {
         //
         // Write out the graph base IRI
         //
@@ -92,9 +286,12 @@ impl GraphWriter for TurtleWriter {
         //
         // Write all prefix mappings
         //
-        let mappings = graph.prefix_mappings();
         let mappings = mappings.borrow();
-        for (prefix, namespace) in mappings.mappings() {
+        let mut mappings: Vec<(&String, &IRIRef)> = mappings.mappings().collect();
+        if self.options.sorted {
+            mappings.sort_by(|lhs, rhs| lhs.0.cmp(rhs.0));
+        }
+        for (prefix, namespace) in mappings {
             if self.options.use_sparql_style {
                 writeln!(w, "PREFIX {}: <{}>", prefix, namespace).map_err(io_error)?;
             } else {
@@ -102,17 +299,46 @@ impl GraphWriter for TurtleWriter {
             }
         }
         writeln!(w).map_err(io_error)?;
+        Ok(())
+    }
+
+    ///
+    /// Write out all the statements in `graph`, compressing IRIs using `mappings`, with each
+    /// top-level subject at the indentation level of `indenter`.
+    ///
+    pub(crate) fn write_statements(
+        &self,
+        w: &mut impl Write,
+        graph: &Ref<'_, dyn Graph>,
+        mappings: &PrefixMappingRef,
+        indenter: Indenter,
+    ) -> rdftk_core::error::Result<()> {
+        let context = Context {
+            graph: &**graph,
+            mappings,
+            labels: blank_node_labels(graph),
+            order: if self.options.sorted {
+                let order = match &self.options.statement_order {
+                    Some(order) => order.clone(),
+                    None => lexical_order(),
+                };
+                Some(Order::new(&**graph, &*order))
+            } else {
+                None
+            },
+        };
+        let w = &mut LineWriter::new(w);
         //
         // Write statements, start with those where subject is an IRI
         //
         let mut blanks_to_write: Vec<&SubjectNodeRef> = Default::default();
         let mut blanks_written: Vec<SubjectNodeRef> = Default::default();
-        for subject in graph.subjects() {
+        for subject in context.subjects() {
             if subject.is_blank() {
                 blanks_to_write.push(subject);
             } else {
                 let mut inner_written = self
-                    .write_sub_graph(w, subject, &graph, Indenter::default())
+                    .write_sub_graph(w, subject, &context, indenter.clone(), false)
                     .map_err(io_error)?;
                 blanks_written.append(&mut inner_written);
             }
@@ -122,96 +348,129 @@ impl GraphWriter for TurtleWriter {
         // Write statements where subject is a blank node
         //
         blanks_to_write.retain(|subject| !blanks_written.contains(subject));
+        if self.options.use_list_syntax && !self.options.preserve_blank_node_labels {
+            // list nodes are written in place by the statement that refers to them.
+            let factory = graph.statement_factory();
+            blanks_to_write.retain(|subject| {
+                list_items(&**graph, &factory.subject_as_object((*subject).clone())).is_none()
+            });
+        }
         for subject in blanks_to_write {
             let _ = self
-                .write_sub_graph(w, subject, &graph, Indenter::default())
+                .write_sub_graph(w, subject, &context, indenter.clone(), false)
                 .map_err(io_error)?;
         }
         Ok(())
     }
-}
 
-impl TurtleWriter {
-    ///
-    /// Create a new writer with the provided options, this is used to override the default
-    /// options that are used when calling `Default::default`.
-    ///
-    pub fn new(options: TurtleOptions) -> Self {
-        Self {
-            base: None,
-            options,
-        }
-    }
-    pub fn with_base(base: IRIRef, options: TurtleOptions) -> Self {
-        Self {
-            base: Some(base.to_string()),
-            options,
-        }
-    }
-
-    fn write_sub_graph(
+    fn write_sub_graph<W: Write>(
         &self,
-        w: &mut impl Write,
+        w: &mut LineWriter<'_, W>,
         subject: &SubjectNodeRef,
-        in_graph: &Ref<'_, dyn Graph>,
+        context: &Context<'_>,
         indenter: Indenter,
+        nested: bool,
     ) -> std::io::Result<Vec<SubjectNodeRef>> {
+        let in_graph = context.graph;
+        let mappings = context.mappings;
+        let nest_blank_nodes =
+            self.options.nest_blank_nodes && !self.options.preserve_blank_node_labels;
         write!(w, "{}", indenter)?;
         let mut indenter = indenter;
         let mut blanks_written: Vec<SubjectNodeRef> = Default::default();
-        let mappings = in_graph.prefix_mappings();
-        if subject.is_blank() && indenter.depth() == 0 {
-            write!(w, "_:{} ", subject.as_blank().unwrap())?;
+        if subject.is_blank() && !nested {
+            write!(w, "_:{} ", context.label(subject.as_blank().unwrap()))?;
         } else if subject.is_iri() {
-            self.write_iri(w, subject.as_iri().unwrap(), &mappings)?;
+            self.write_iri(w, subject.as_iri().unwrap(), mappings)?;
+        } else if let Some(statement) = subject.as_statement() {
+            self.write_quoted_triple(w, statement, context)?;
         }
-        let predicates = in_graph.predicates_for(subject);
+        let predicates = context.predicates_for(subject);
         indenter = indenter.indent();
         let mut p_iter = predicates.iter().peekable();
         while let Some(predicate) = p_iter.next() {
-            self.write_iri(w, predicate, &mappings)?;
-            let objects = in_graph.objects_for(subject, predicate);
+            if self.options.use_type_keyword && *predicate == rdf::a_type() {
+                write!(w, "a ")?;
+            } else {
+                self.write_iri(w, predicate, mappings)?;
+            }
+            let objects = context.objects_for(subject, predicate);
             if objects.len() > 1 {
                 indenter = indenter.indent();
             }
+            let continuation = if self.options.align_objects {
+                " ".repeat(w.column())
+            } else {
+                indenter.to_string()
+            };
             let mut o_iter = objects.iter().peekable();
+            let mut first = true;
             while let Some(object) = o_iter.next() {
-                if object.is_blank() && self.options.nest_blank_nodes {
+                let list =
+                    if self.options.use_list_syntax && !self.options.preserve_blank_node_labels {
+                        list_items(in_graph, object)
+                    } else {
+                        None
+                    };
+                let mut rendered: Vec<u8> = Default::default();
+                if let Some((items, mut nodes)) = list {
+                    write!(rendered, "( ")?;
+                    for item in items {
+                        self.write_object(&mut rendered, item, context)?;
+                    }
+                    write!(rendered, ") ")?;
+                    blanks_written.append(&mut nodes);
+                } else if object.is_blank() && !nest_blank_nodes {
+                    write!(rendered, "_:{}", context.label(object.as_blank().unwrap()))?;
+                } else if !object.is_blank() {
+                    self.write_object(&mut rendered, object, context)?;
+                }
+                if !first {
+                    // allow for the space and separator following the object.
+                    let fits = match self.options.max_line_length {
+                        Some(max) if !rendered.is_empty() => {
+                            w.column() + 1 + width(trim_end(&rendered)) + 2 <= max
+                        }
+                        _ => false,
+                    };
+                    if fits {
+                        write!(w, " ")?;
+                    } else {
+                        write!(w, "\n{}", continuation)?;
+                    }
+                }
+                first = false;
+                if !rendered.is_empty() {
+                    w.write_all(&rendered)?;
+                } else {
                     write!(w, "[\n{}", indenter.one())?;
                     let inner_subject: SubjectNodeRef = in_graph
                         .statement_factory()
                         .object_as_subject(
-                            <&std::rc::Rc<dyn rdftk_core::model::statement::ObjectNode>>::clone(
+                            <&std::sync::Arc<dyn rdftk_core::model::statement::ObjectNode>>::clone(
                                 object,
                             )
                             .clone(),
                         )
                         .unwrap();
                     let mut inner_written =
-                        self.write_sub_graph(w, &inner_subject, in_graph, indenter.clone())?;
+                        self.write_sub_graph(w, &inner_subject, context, indenter.clone(), true)?;
                     blanks_written.push(inner_subject);
                     blanks_written.append(&mut inner_written);
                     write!(w, "{}]", indenter)?;
-                } else if object.is_blank() && !self.options.nest_blank_nodes {
-                    write!(w, "_:{}", object.as_blank().unwrap())?;
-                } else if object.is_iri() {
-                    self.write_iri(w, object.as_iri().unwrap(), &mappings)?;
-                } else {
-                    self.write_literal(w, object.as_literal().unwrap(), &mappings)?;
                 }
                 if o_iter.peek().is_some() {
-                    writeln!(w, ",")?;
+                    write!(w, ",")?;
                 }
             }
-            if p_iter.peek().is_some() {
-                write!(w, ";\n{}", indenter)?;
-            }
             if objects.len() > 1 {
                 indenter = indenter.outdent();
             }
+            if p_iter.peek().is_some() {
+                write!(w, ";\n{}", indenter)?;
+            }
         }
-        indenter = indenter.outdent();
-        if indenter.depth() == 0 {
+        if !nested {
             writeln!(w, ".")?;
         } else {
             writeln!(w)?;
@@ -219,7 +478,48 @@ impl TurtleWriter {
         Ok(blanks_written)
     }
 
-    fn write_iri<W: Write>(
+    fn write_object<W: Write>(
+        &self,
+        w: &mut W,
+        object: &ObjectNodeRef,
+        context: &Context<'_>,
+    ) -> std::io::Result<()> {
+        if let Some(name) = object.as_blank() {
+            write!(w, "_:{} ", context.label(name))
+        } else if let Some(iri) = object.as_iri() {
+            self.write_iri(w, iri, context.mappings)
+        } else if let Some(literal) = object.as_literal() {
+            self.write_literal(w, literal, context.mappings)
+        } else {
+            self.write_quoted_triple(w, object.as_statement().unwrap(), context)
+        }
+    }
+
+    ///
+    /// Write an RDF-star quoted triple, blank nodes within a quoted triple are always written
+    /// with their labels as they may not be nested.
+    ///
+    fn write_quoted_triple<W: Write>(
+        &self,
+        w: &mut W,
+        statement: &StatementRef,
+        context: &Context<'_>,
+    ) -> std::io::Result<()> {
+        write!(w, "<< ")?;
+        let subject = statement.subject();
+        if let Some(name) = subject.as_blank() {
+            write!(w, "_:{} ", context.label(name))?;
+        } else if let Some(iri) = subject.as_iri() {
+            self.write_iri(w, iri, context.mappings)?;
+        } else {
+            self.write_quoted_triple(w, subject.as_statement().unwrap(), context)?;
+        }
+        self.write_iri(w, statement.predicate(), context.mappings)?;
+        self.write_object(w, statement.object(), context)?;
+        write!(w, ">> ")
+    }
+
+    pub(crate) fn write_iri<W: Write>(
         &self,
         w: &mut W,
         iri: &IRIRef,
@@ -234,7 +534,12 @@ impl TurtleWriter {
         write!(
             w,
             "{} ",
-            match mappings.borrow().compress(&iri) {
+            // a local name may not end with a '.' in Turtle, it would end the statement.
+            match mappings
+                .borrow()
+                .compress(iri)
+                .filter(|qname| !qname.name().ends_with('.'))
+            {
                 None => format!("<{}>", iri),
                 Some(qname) => qname.to_string(),
             }
@@ -252,10 +557,206 @@ impl TurtleWriter {
     }
 }
 
+// ------------------------------------------------------------------------------------------------
+
+impl<'a, W: Write> LineWriter<'a, W> {
+    fn new(inner: &'a mut W) -> Self {
+        Self { inner, column: 0 }
+    }
+
+    fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl<W: Write> Write for LineWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        let buf = &buf[..written];
+        match buf.iter().rposition(|b| *b == b'\n') {
+            Some(index) => self.column = width(&buf[index + 1..]),
+            None => self.column += width(buf),
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Context<'_> {
+    fn label<'b>(&'b self, name: &'b str) -> &'b str {
+        self.labels.get(name).map(|s| s.as_str()).unwrap_or(name)
+    }
+
+    fn subjects(&self) -> Vec<&SubjectNodeRef> {
+        match &self.order {
+            Some(order) => order.subjects.iter().collect(),
+            None => self.graph.subjects().into_iter().collect(),
+        }
+    }
+
+    fn predicates_for(&self, subject: &SubjectNodeRef) -> Vec<&IRIRef> {
+        match &self.order {
+            Some(order) => order
+                .predicates
+                .get(subject)
+                .map(|predicates| predicates.iter().collect())
+                .unwrap_or_default(),
+            None => self.graph.predicates_for(subject).into_iter().collect(),
+        }
+    }
+
+    fn objects_for(&self, subject: &SubjectNodeRef, predicate: &IRIRef) -> Vec<&ObjectNodeRef> {
+        match &self.order {
+            Some(order) => order
+                .objects
+                .get(&(subject.clone(), predicate.clone()))
+                .map(|objects| objects.iter().collect())
+                .unwrap_or_default(),
+            None => self
+                .graph
+                .objects_for(subject, predicate)
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Order {
+    fn new(graph: &dyn Graph, order: &dyn StatementOrder) -> Self {
+        let mut result = Self::default();
+        let Order {
+            subjects,
+            predicates,
+            objects,
+        } = &mut result;
+        for statement in sorted_statements(graph, order) {
+            let subject = statement.subject();
+            let predicate = statement.predicate();
+            let predicates = predicates.entry(subject.clone()).or_insert_with(|| {
+                subjects.push(subject.clone());
+                Default::default()
+            });
+            let objects = objects
+                .entry((subject.clone(), predicate.clone()))
+                .or_insert_with(|| {
+                    predicates.push(predicate.clone());
+                    Default::default()
+                });
+            if !objects.contains(statement.object()) {
+                objects.push(statement.object().clone());
+            }
+        }
+        result
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
+///
+/// Blank node names that are not valid Turtle labels are mapped to new labels, each checked for
+/// collisions against the names already used in the graph.
+///
+fn blank_node_labels(graph: &Ref<'_, dyn Graph>) -> HashMap<String, String> {
+    let mut names: Vec<&String> = graph
+        .subjects()
+        .into_iter()
+        .filter_map(|subject| subject.as_blank())
+        .chain(
+            graph
+                .objects()
+                .into_iter()
+                .filter_map(|object| object.as_blank()),
+        )
+        .collect();
+    names.sort();
+    names.dedup();
+    let mut used: HashSet<String> = names.iter().map(|name| name.to_string()).collect();
+    let mut labels: HashMap<String, String> = Default::default();
+    let mut next = 0;
+    for name in names.into_iter().filter(|name| !is_blank_node_label(name)) {
+        let label = loop {
+            let label = format!("b{}", next);
+            next += 1;
+            if !used.contains(&label) {
+                break label;
+            }
+        };
+        let _ = used.insert(label.clone());
+        let _ = labels.insert(name.clone(), label);
+    }
+    labels
+}
+
+///
+/// If `object` is the head of a well-formed list, return its items and the blank nodes that make
+/// up the list. Each node of a well-formed list is a blank node, used as the object of only one
+/// statement, with exactly one `rdf:first` and one `rdf:rest` value and no other predicates; the
+/// last `rdf:rest` value is `rdf:nil`.
+///
+fn list_items<'a>(
+    graph: &'a dyn Graph,
+    object: &ObjectNodeRef,
+) -> Option<(Vec<&'a ObjectNodeRef>, Vec<SubjectNodeRef>)> {
+    let factory = graph.statement_factory();
+    let mut items: Vec<&ObjectNodeRef> = Default::default();
+    let mut nodes: Vec<SubjectNodeRef> = Default::default();
+    let mut object = object.clone();
+    while object.as_iri() != Some(rdf::nil()) {
+        if !object.is_blank() || graph.statements_matching(None, None, Some(&object)).len() != 1 {
+            return None;
+        }
+        let node = factory.object_as_subject(object).unwrap();
+        if nodes.contains(&node) || graph.predicates_for(&node).len() != 2 {
+            return None;
+        }
+        let first = graph.objects_for(&node, rdf::first());
+        let rest = graph.objects_for(&node, rdf::rest());
+        if first.len() != 1 || rest.len() != 1 {
+            return None;
+        }
+        items.push(first.into_iter().next().unwrap());
+        object = rest.into_iter().next().unwrap().clone();
+        nodes.push(node);
+    }
+    if nodes.is_empty() {
+        None
+    } else {
+        Some((items, nodes))
+    }
+}
+
+///
+/// The number of characters in the UTF-8 encoded `bytes`.
+///
+fn width(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|b| (**b & 0xC0) != 0x80).count()
+}
+
+fn trim_end(bytes: &[u8]) -> &[u8] {
+    match bytes.iter().rposition(|b| *b != b' ') {
+        Some(index) => &bytes[..=index],
+        None => &bytes[..0],
+    }
+}
+
+fn is_blank_node_label(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphanumeric() || c == '_' => {}
+        _ => return false,
+    }
+    !name.ends_with('.') && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.')
+}
+
 pub fn io_error(e: std::io::Error) -> rdftk_core::error::Error {
     use rdftk_core::error::ErrorKind;
     rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
@@ -0,0 +1,428 @@
+/*!
+Provides `Watcher`, which re-reads a graph file whenever its content changes, reporting any syntax
+errors with their line and column, running a set of configured checks over the graph read, and
+writing the graph, formatted, to an output file; a fast feedback loop for authors editing Turtle
+or N-Triples files by hand. The `rdftk watch` command, in the `rdftk_cli` crate, is built on this
+module.
+
+The format of the watched file, and of the output file, is determined from the file extension, as
+described in the `format` module. An N-Triples file is read leniently, so that every invalid
+statement is reported, while for other formats the first syntax error is reported. The output is
+only written when the file was read without error, so that a partial graph never replaces the
+last good output; if `WatchOptions::canonical` is set, blank nodes are relabelled using RDFC-1.0
+canonicalization before the graph is written, so the output only changes when the graph does.
+
+A check is any function from a graph to a list of problems, for example one that runs a SHACL
+validation and returns the message of each violation.
+
+Rather than relying on platform file notifications the file is polled, at `WatchOptions::interval`,
+and it is only read again when its content has changed.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::Graph;
+use rdftk_io::watch::{WatchOptions, Watcher};
+# let path = std::env::temp_dir().join(format!("rdftk-watch-doc-{}.ttl", std::process::id()));
+
+std::fs::write(
+    &path,
+    "@prefix ex: <http://example.org/> .\nex:a ex:b ex:c .\nex:a ex:b .\n",
+)
+.unwrap();
+
+let mut watcher = Watcher::new(&path, WatchOptions::default()).with_check(|graph: &dyn Graph| {
+    if graph.is_empty() {
+        vec!["the graph is empty".to_string()]
+    } else {
+        Vec::new()
+    }
+});
+
+let report = watcher.poll().unwrap().unwrap();
+assert_eq!(report.diagnostics().len(), 1);
+assert_eq!(report.diagnostics()[0].line(), 3);
+// the file has not changed, so it is not read again.
+assert!(watcher.poll().unwrap().is_none());
+# std::fs::remove_file(&path).unwrap();
+```
+
+*/
+
+use crate::diagnostic::Diagnostic;
+use crate::format::Format;
+use crate::output::{write_to_path, OutputOptions};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::simple::graph::graph_factory;
+use regex::Regex;
+use std::fmt::{Debug, Display, Formatter};
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::Duration;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The default interval at which a watched file is polled for changes.
+///
+pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+///
+/// A check run over the graph read from a watched file, returning a description of each problem
+/// found.
+///
+pub type Check = Box<dyn Fn(&dyn Graph) -> Vec<String>>;
+
+///
+/// Options controlling a `Watcher`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchOptions {
+    interval: Duration,
+    output: Option<PathBuf>,
+    canonical: bool,
+}
+
+///
+/// Watches a single graph file; see the module documentation.
+///
+pub struct Watcher {
+    path: PathBuf,
+    options: WatchOptions,
+    checks: Vec<Check>,
+    last: Option<String>,
+}
+
+///
+/// The result of reading, and checking, a watched file.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Report {
+    path: PathBuf,
+    statements: usize,
+    diagnostics: Vec<Diagnostic>,
+    problems: Vec<String>,
+    written: Option<PathBuf>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for WatchOptions {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_INTERVAL,
+            output: None,
+            canonical: false,
+        }
+    }
+}
+
+impl WatchOptions {
+    ///
+    /// Poll the watched file at `interval`.
+    ///
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    ///
+    /// Write the graph, in the format of its extension, to `output` each time the watched file
+    /// is read without error.
+    ///
+    pub fn with_output(mut self, output: impl AsRef<Path>) -> Self {
+        self.output = Some(output.as_ref().to_path_buf());
+        self
+    }
+
+    ///
+    /// Canonicalize blank node labels before the graph is written.
+    ///
+    pub fn canonical(mut self) -> Self {
+        self.canonical = true;
+        self
+    }
+
+    ///
+    /// Return the interval at which the watched file is polled.
+    ///
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    ///
+    /// Return the file the graph is written to, if any.
+    ///
+    pub fn output(&self) -> Option<&PathBuf> {
+        self.output.as_ref()
+    }
+
+    ///
+    /// Returns `true` if blank node labels are canonicalized before the graph is written.
+    ///
+    pub fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Debug for Watcher {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Watcher")
+            .field("path", &self.path)
+            .field("options", &self.options)
+            .field("checks", &self.checks.len())
+            .finish()
+    }
+}
+
+impl Watcher {
+    ///
+    /// Create a watcher for the graph file at `path`.
+    ///
+    pub fn new(path: impl AsRef<Path>, options: WatchOptions) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            options,
+            checks: Default::default(),
+            last: None,
+        }
+    }
+
+    ///
+    /// Add `check`, which is run over the graph each time the watched file is read without error.
+    ///
+    pub fn with_check(mut self, check: impl Fn(&dyn Graph) -> Vec<String> + 'static) -> Self {
+        self.checks.push(Box::new(check));
+        self
+    }
+
+    ///
+    /// Return the path of the watched file.
+    ///
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    ///
+    /// Return the options for this watcher.
+    ///
+    pub fn options(&self) -> &WatchOptions {
+        &self.options
+    }
+
+    ///
+    /// Read, and check, the watched file if its content has changed since it was last read,
+    /// returning `None` if it has not. An error is returned if the file cannot be read, or if
+    /// the output cannot be written; the latter only once for each change to the file.
+    ///
+    pub fn poll(&mut self) -> Result<Option<Report>> {
+        let content = read_to_string(&self.path).map_err(|e| self.io_error(e))?;
+        if self.last.as_ref() == Some(&content) {
+            return Ok(None);
+        }
+        let report = self.check(&content);
+        self.last = Some(content);
+        report.map(Some)
+    }
+
+    ///
+    /// Poll the watched file, at the configured interval, calling `on_report` with each report,
+    /// or error, until it returns `false`.
+    ///
+    pub fn run(&mut self, mut on_report: impl FnMut(Result<Report>) -> bool) {
+        loop {
+            let keep_going = match self.poll() {
+                Ok(None) => true,
+                Ok(Some(report)) => on_report(Ok(report)),
+                Err(e) => on_report(Err(e)),
+            };
+            if !keep_going {
+                break;
+            }
+            sleep(self.options.interval);
+        }
+    }
+
+    fn check(&self, content: &str) -> Result<Report> {
+        let format = Format::from_path(&self.path)
+            .filter(|format| format.reads_graph())
+            .ok_or_else(|| self.unsupported(&self.path))?;
+        let (graph, diagnostics) = read(format, content)?;
+        let mut report = Report {
+            path: self.path.clone(),
+            statements: graph.borrow().len(),
+            diagnostics,
+            problems: Default::default(),
+            written: None,
+        };
+        if report.diagnostics.is_empty() {
+            report.problems = self
+                .checks
+                .iter()
+                .flat_map(|check| check(&*graph.borrow()))
+                .collect();
+            if let Some(output) = &self.options.output {
+                self.write(&graph, output)?;
+                report.written = Some(output.clone());
+            }
+        }
+        Ok(report)
+    }
+
+    fn write(&self, graph: &GraphRef, output: &Path) -> Result<()> {
+        let format = Format::from_path(output)
+            .filter(|format| format.writes_graph())
+            .ok_or_else(|| self.unsupported(output))?;
+        let graph = if self.options.canonical {
+            graph.borrow().canonicalize()?
+        } else {
+            graph.clone()
+        };
+        write_to_path(output, &OutputOptions::default(), |file| {
+            format.write_graph(file, &graph)
+        })
+    }
+
+    fn unsupported(&self, path: &Path) -> Error {
+        ErrorKind::Msg(format!(
+            "the format of {} cannot be determined, or is not supported",
+            path.display()
+        ))
+        .into()
+    }
+
+    fn io_error(&self, e: std::io::Error) -> Error {
+        Error::with_chain(
+            e,
+            ErrorKind::Msg(format!("cannot read {}", self.path.display())),
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let path = self.path.display();
+        for diagnostic in &self.diagnostics {
+            writeln!(f, "{}:{}", path, diagnostic)?;
+        }
+        for problem in &self.problems {
+            writeln!(f, "{}: {}", path, problem)?;
+        }
+        write!(
+            f,
+            "{}: {} statements, {} errors, {} problems",
+            path,
+            self.statements,
+            self.diagnostics.len(),
+            self.problems.len()
+        )?;
+        if let Some(written) = &self.written {
+            write!(f, ", written to {}", written.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl Report {
+    ///
+    /// Return the path of the file read.
+    ///
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    ///
+    /// Return the number of statements read.
+    ///
+    pub fn statements(&self) -> usize {
+        self.statements
+    }
+
+    ///
+    /// Return the syntax errors found while reading the file.
+    ///
+    pub fn diagnostics(&self) -> &Vec<Diagnostic> {
+        &self.diagnostics
+    }
+
+    ///
+    /// Return the problems found by the configured checks; the checks are only run when the file
+    /// was read without error.
+    ///
+    pub fn problems(&self) -> &Vec<String> {
+        &self.problems
+    }
+
+    ///
+    /// Return the output file written, if any.
+    ///
+    pub fn written(&self) -> Option<&PathBuf> {
+        self.written.as_ref()
+    }
+
+    ///
+    /// Returns `true` if there were no syntax errors and no problems, else `false`.
+    ///
+    pub fn is_clean(&self) -> bool {
+        self.diagnostics.is_empty() && self.problems.is_empty()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn read(format: Format, content: &str) -> Result<(GraphRef, Vec<Diagnostic>)> {
+    #[cfg(feature = "nt")]
+    if format == Format::NTriples {
+        return crate::nt::reader::NTriplesReader::default()
+            .read_with_diagnostics(&mut content.as_bytes(), graph_factory());
+    }
+    match format.read_graph(&mut content.as_bytes(), graph_factory()) {
+        Ok(graph) => Ok((graph, Default::default())),
+        Err(e) => match syntax_error(&e, content) {
+            Some(diagnostic) => Ok((graph_factory().graph(), vec![diagnostic])),
+            None => Err(e),
+        },
+    }
+}
+
+///
+/// Find the position of a syntax error, reported by a parser as `--> line:column`, in the
+/// chain of `e`.
+///
+fn syntax_error(e: &Error, content: &str) -> Option<Diagnostic> {
+    lazy_static! {
+        static ref POSITION: Regex = Regex::new(r"-->\s*(\d+):(\d+)").unwrap();
+    }
+    e.iter().find_map(|cause| {
+        let cause = cause.to_string();
+        let position = POSITION.captures(&cause)?;
+        let line: usize = position[1].parse().ok()?;
+        let column: usize = position[2].parse().ok()?;
+        let message = cause
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("= "))
+            .next()
+            .unwrap_or("syntax error");
+        Some(Diagnostic::new(
+            line,
+            column,
+            content
+                .lines()
+                .nth(line.saturating_sub(1))
+                .unwrap_or_default(),
+            message,
+        ))
+    })
+}
@@ -1,5 +1,5 @@
 /*!
-Provides for writing out in the [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/)
+Provides for reading and writing out in the [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/)
 format.
 */
 
@@ -22,7 +22,6 @@ pub const MIME_TYPE: &str = "application/rdf+xml";
 
 mod syntax;
 
-#[doc(hidden)]
 pub mod reader;
 
 pub mod writer;
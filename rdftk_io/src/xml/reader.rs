@@ -1,598 +1,662 @@
 /*!
 Provides the `XmlReader` implementation of the `GraphReader` trait.
 
-# Example
+The reader supports the [RDF 1.1 XML Syntax](https://www.w3.org/TR/rdf-syntax-grammar/) grammar,
+including typed node elements, property attributes, `rdf:about`, `rdf:ID`, `rdf:nodeID`,
+`rdf:resource`, `rdf:datatype`, nested node elements, `rdf:li` container membership, and the
+`Resource`, `Literal`, and `Collection` values of `rdf:parseType`. The in-scope `xml:lang` is applied
+to plain literals, and `xml:base` to relative IRIs. The namespaces declared on the document
+element are added to the graph's prefix mappings.
 
-*/
+# Example
 
-// use ...
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::xml::reader::XmlReader;
+use rdftk_io::GraphReader;
+
+let mut content = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:foaf="http://xmlns.com/foaf/0.1/"
+         xml:lang="en">
+  <foaf:Person rdf:about="http://example.org/alice" foaf:name="Alice">
+    <foaf:knows>
+      <foaf:Person foaf:name="Bob"/>
+    </foaf:knows>
+  </foaf:Person>
+</rdf:RDF>"#.as_bytes();
+let reader = XmlReader::default();
+let graph = reader.read(&mut content, graph_factory()).unwrap();
+assert_eq!(graph.borrow().len(), 5);
+```
 
-// ------------------------------------------------------------------------------------------------
-// Public Types
-// ------------------------------------------------------------------------------------------------
+*/
 
+use crate::common::parser::resolve;
+use crate::conformance::conform;
+use crate::format::Format;
 use crate::xml::syntax::{
-    ATTRIBUTE_ABOUT, ATTRIBUTE_ID, ATTRIBUTE_NODE_ID, ATTRIBUTE_PARSE_TYPE, ATTRIBUTE_RESOURCE,
-    ATTRIBUTE_XML_BASE, ATTRIBUTE_XML_LANG, ELEMENT_DESCRIPTION, ELEMENT_RDF,
-    PARSE_TYPE_COLLECTION, PARSE_TYPE_LITERAL, PARSE_TYPE_RESOURCE, XML_NAMESPACE,
+    ATTRIBUTE_ABOUT, ATTRIBUTE_DATATYPE, ATTRIBUTE_ID, ATTRIBUTE_NODE_ID, ATTRIBUTE_PARSE_TYPE,
+    ATTRIBUTE_RESOURCE, ATTRIBUTE_XML_BASE, ATTRIBUTE_XML_LANG, ELEMENT_DESCRIPTION, ELEMENT_LI,
+    ELEMENT_RDF, PARSE_TYPE_COLLECTION, PARSE_TYPE_RESOURCE, XML_NAMESPACE,
 };
 use crate::GraphReader;
-use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::error::Result;
 use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
-use rdftk_core::model::literal::{DataType, LanguageTag};
-use rdftk_core::model::statement::SubjectNodeRef;
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralFactoryRef};
+use rdftk_core::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef,
+};
 use rdftk_iri::{IRIRef, IRI};
 use rdftk_names::rdf;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::io::Read;
 use std::str::FromStr;
 use xml::attribute::OwnedAttribute;
 use xml::name::OwnedName;
+use xml::namespace::{Namespace, NS_NO_PREFIX, NS_XMLNS_PREFIX, NS_XML_PREFIX};
 use xml::reader::XmlEvent;
-use xml::{EventReader, EventWriter};
+use xml::writer::XmlEvent as WriterEvent;
+use xml::{EmitterConfig, EventReader, EventWriter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
 
 ///
 /// An implementation of the GraphReader trait to read resources in the XML representation.
+/// Relative IRIs are resolved against the base IRI, either one provided to `with_base` or one
+/// declared with `xml:base`; without a base relative IRIs are an error. If `strict` is set the
+/// document is first checked for conformance, see the `conformance` module, and any violation
+/// aborts the read.
 ///
-#[derive(Clone, Debug)]
-pub struct XmlReader {}
+#[derive(Clone, Debug, Default)]
+pub struct XmlReader {
+    base: Option<IRIRef>,
+    strict: bool,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Private Types
 // ------------------------------------------------------------------------------------------------
 
 #[derive(Clone, Debug)]
-struct ExpectedName {
-    local_name: String,
-    namespace: String,
-}
-
-#[derive(Clone, Debug)]
-enum SubjectType {
-    BlankNamed(String),
-    Resource(IRIRef),
-    RelativeResource(String),
+struct Element {
+    name: OwnedName,
+    attributes: Vec<OwnedAttribute>,
+    namespace: Namespace,
+    content: Vec<Content>,
 }
 
 #[derive(Clone, Debug)]
-enum ParseType {
-    XmlLiteral,
-    Resource,
-    Collection,
+enum Content {
+    Element(Element),
+    Text(String),
 }
 
-#[derive(Clone, Debug)]
-struct Attributes<'a> {
-    subject_type: Option<SubjectType>,
-    parse_type: Option<ParseType>,
-    uri_base: Option<IRIRef>,
-    data_type: Option<IRIRef>,
+#[derive(Clone, Debug, Default)]
+struct Scope {
+    base: Option<String>,
     language: Option<LanguageTag>,
-    resource: Option<IRIRef>,
-    inner: Vec<&'a OwnedAttribute>,
 }
 
-// ------------------------------------------------------------------------------------------------
-// Public Functions
-// ------------------------------------------------------------------------------------------------
+#[derive(Debug)]
+struct ReaderState {
+    graph: GraphRef,
+    statements: StatementFactoryRef,
+    literals: LiteralFactoryRef,
+}
 
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
-impl Default for XmlReader {
-    fn default() -> Self {
-        Self {}
+impl XmlReader {
+    ///
+    /// Create a new reader that will resolve relative IRIs against the provided base IRI.
+    ///
+    pub fn with_base(base: IRIRef) -> Self {
+        Self {
+            base: Some(base),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// If `strict` is `true`, the document is checked for conformance before it is parsed, and
+    /// any violation aborts the read.
+    ///
+    pub fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    ///
+    /// Returns `true` if the document is checked for conformance before it is parsed.
+    ///
+    pub fn is_strict(&self) -> bool {
+        self.strict
     }
 }
 
 impl GraphReader for XmlReader {
     fn read(&self, r: &mut impl Read, factory: GraphFactoryRef) -> Result<GraphRef> {
-        let mut event_reader = xml::EventReader::new(r);
-        parse_document(&mut event_reader, factory)
+        let root = if self.strict {
+            let mut content: String = String::new();
+            let _ = r.read_to_string(&mut content).map_err(io_error)?;
+            conform(Format::Xml, &content)?;
+            read_document(&mut content.as_bytes())?
+        } else {
+            read_document(r)?
+        };
+        let graph = factory.graph();
+        let statements = graph.borrow().statement_factory();
+        let literals = graph.borrow().literal_factory();
+        let state = ReaderState {
+            graph,
+            statements,
+            literals,
+        };
+        state.prefix_mappings(&root)?;
+        let scope = Scope {
+            base: self.base.as_ref().map(|base| base.to_string()),
+            language: None,
+        };
+        state.document(&root, &scope)?;
+        Ok(state.graph)
     }
 }
 
 // ------------------------------------------------------------------------------------------------
 
-impl ExpectedName {
-    pub(crate) fn new(local_name: &str, namespace: &str) -> Self {
-        Self {
-            local_name: local_name.to_string(),
-            namespace: namespace.to_string(),
-        }
+impl Element {
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.content.iter().filter_map(|content| match content {
+            Content::Element(element) => Some(element),
+            Content::Text(_) => None,
+        })
     }
 
-    pub(crate) fn matches(&self, other: &OwnedName) -> bool {
-        self.local_name == other.local_name && Some(self.namespace.clone()) == other.namespace
+    fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(|content| match content {
+                Content::Element(_) => None,
+                Content::Text(text) => Some(text.as_str()),
+            })
+            .collect()
+    }
+
+    fn is_rdf(&self, local_name: &str) -> bool {
+        is_rdf_name(&self.name, local_name)
+    }
+
+    fn rdf_attribute(&self, local_name: &str) -> Option<&String> {
+        self.attributes
+            .iter()
+            .find(|attribute| is_rdf_name(&attribute.name, local_name))
+            .map(|attribute| &attribute.value)
+    }
+
+    fn xml_attribute(&self, local_name: &str) -> Option<&String> {
+        self.attributes
+            .iter()
+            .find(|attribute| {
+                attribute.name.namespace.as_deref() == Some(XML_NAMESPACE)
+                    && attribute.name.local_name == local_name
+            })
+            .map(|attribute| &attribute.value)
+    }
+
+    fn property_attributes(&self) -> impl Iterator<Item = &OwnedAttribute> {
+        // SPEC: §2.5 Property Attributes
+        self.attributes
+            .iter()
+            .filter(|attribute| match attribute.name.namespace.as_deref() {
+                None | Some(XML_NAMESPACE) => false,
+                Some(_) => !SYNTAX_ATTRIBUTES
+                    .iter()
+                    .any(|local_name| is_rdf_name(&attribute.name, local_name)),
+            })
     }
 }
 
-// ------------------------------------------------------------------------------------------------
-// Private Functions
 // ------------------------------------------------------------------------------------------------
 
-macro_rules! trace_event {
-    ($fn_name:expr => $event:expr) => {
-        trace!("XmlReader::{} event: {:?}", $fn_name, $event);
-    };
-    ($fn_name:expr => ignore $event:expr) => {
-        trace!("XmlReader::{} ignoring event: {:?}", $fn_name, &$event);
-    };
-}
+impl Scope {
+    fn enter(&self, element: &Element) -> Result<Self> {
+        let mut scope = self.clone();
+        if let Some(base) = element.xml_attribute(ATTRIBUTE_XML_BASE) {
+            // SPEC: §2.14 Abbreviating URIs: rdf:ID and xml:base
+            let base = match &self.base {
+                Some(current) if !is_absolute(base) => resolve(current, base),
+                _ => base.to_string(),
+            };
+            scope.base = Some(base.split('#').next().unwrap().to_string());
+        }
+        if let Some(language) = element.xml_attribute(ATTRIBUTE_XML_LANG) {
+            // SPEC: §2.7 Languages: xml:lang
+            scope.language = if language.is_empty() {
+                None
+            } else {
+                Some(LanguageTag::from_str(language)?)
+            };
+        }
+        Ok(scope)
+    }
 
-macro_rules! error_event {
-    (parse => $fn_name:expr, $error:expr) => {
-        let inner: rdftk_core::error::Error =
-            ErrorKind::Msg(format!("error parsing XML: {:?}", $error,)).into();
-        error_event!($fn_name, inner);
-    };
-    (unexpected => $fn_name:expr, $event:expr) => {
-        let inner: rdftk_core::error::Error =
-            ErrorKind::Msg(format!("unexpected XML Event: {:?}", $event,)).into();
-        error_event!(inner);
-    };
-    (state => $fn_name:expr, $msg:expr) => {
-        let inner: rdftk_core::error::Error =
-            ErrorKind::Msg(format!("invalid state: {}", $msg,)).into();
-        error_event!($fn_name, inner);
-    };
-    (unsupported => $fn_name:expr, $feature:expr) => {
-        let inner: rdftk_core::error::Error =
-            ErrorKind::Msg(format!("unsupported feature/capability: {}", $feature)).into();
-        error_event!($fn_name, inner);
-    };
-    ($fn_name:expr, $inner:expr) => {
-        error!("XmlReader::{} {}", $fn_name, $inner);
-        return Err(rdftk_core::error::Error::with_chain(
-            $inner,
-            ErrorKind::ReadWrite(super::NAME.to_string()),
-        ));
-    };
+    fn resolve(&self, value: &str) -> Result<IRIRef> {
+        let value = if is_absolute(value) {
+            value.to_string()
+        } else if let Some(base) = &self.base {
+            resolve(base, value)
+        } else {
+            return Err(read_error(&format!(
+                "relative IRI '{}' found, and no base IRI in scope",
+                value
+            )));
+        };
+        Ok(IRIRef::new(IRI::from_str(&value)?))
+    }
 }
 
-fn parse_document<R: Read>(
-    event_reader: &mut EventReader<&mut R>,
-    factory: GraphFactoryRef,
-) -> Result<GraphRef> {
-    let mut graph = factory.graph();
-    let rdf_element = ExpectedName::new(ELEMENT_RDF, rdf::namespace_str());
-
-    loop {
-        let event = event_reader.next();
-        match &event {
-            Ok(XmlEvent::EndDocument) => {
-                trace_event!("parse_document" => event);
-                return Ok(graph);
-            }
-            Ok(XmlEvent::StartElement {
-                name,
-                namespace: _,
-                attributes,
-            }) => {
-                trace_event!("parse_document" => event);
-                let attributes = parse_attributes(attributes)?;
-                if rdf_element.matches(&name) {
-                    let _ = parse_subject_element(
-                        event_reader,
-                        &attributes.uri_base,
-                        None,
-                        &mut graph,
-                    )?;
-                } else {
-                    error_event!(state => "parse_document", "expecting rdf:RDF");
-                }
-            }
-            Ok(_) => {
-                trace_event!("parse_document" => ignore event);
-            }
-            Err(e) => {
-                error_event!(parse => "parse_document", e);
+// ------------------------------------------------------------------------------------------------
+
+impl ReaderState {
+    fn prefix_mappings(&self, root: &Element) -> Result<()> {
+        let mappings = self.graph.borrow().prefix_mappings();
+        let mut mappings = mappings.borrow_mut();
+        for (prefix, namespace) in root.namespace.0.iter() {
+            if prefix != NS_XML_PREFIX && prefix != NS_XMLNS_PREFIX && !namespace.is_empty() {
+                mappings.insert(prefix, IRIRef::new(IRI::from_str(namespace)?));
             }
         }
+        Ok(())
     }
-}
 
-fn parse_subject_element<R: Read>(
-    event_reader: &mut EventReader<&mut R>,
-    xml_base: &Option<IRIRef>,
-    subject: Option<&SubjectNodeRef>,
-    graph: &mut GraphRef,
-) -> Result<Option<SubjectNodeRef>> {
-    let description_element = ExpectedName::new(ELEMENT_DESCRIPTION, rdf::namespace_str());
-    loop {
-        let event = event_reader.next();
-        let mut subject: Option<SubjectNodeRef> = None;
-        match &event {
-            Ok(XmlEvent::StartElement {
-                name,
-                namespace: _,
-                attributes,
-            }) => {
-                trace_event!("parse_subject_element" => event);
-                let attributes = parse_attributes(attributes)?;
-                let subject_node = match &attributes.subject_type {
-                    None => {
-                        // SPEC: §2.1 Introduction
-                        graph.borrow().statement_factory().blank_subject()
-                    }
-                    Some(SubjectType::Resource(subject)) => {
-                        // SPEC: §2.2 Node Elements and Property Elements
-                        graph
-                            .borrow()
-                            .statement_factory()
-                            .named_subject(subject.clone())
-                    }
-                    Some(SubjectType::RelativeResource(subject)) => {
-                        // SPEC: 2.14 Abbreviating URIs: rdf:ID and xml:base
-                        let uri = format!("{}{}", xml_base.as_ref().unwrap(), subject);
-                        graph
-                            .borrow()
-                            .statement_factory()
-                            .named_subject(value_to_iri(&uri)?)
-                    }
-                    Some(SubjectType::BlankNamed(subject)) => {
-                        // SPEC: §2.10 Identifying Blank Nodes: rdf:nodeID
-                        graph
-                            .borrow()
-                            .statement_factory()
-                            .blank_subject_named(subject)
-                            .unwrap()
-                    }
-                };
-                if !description_element.matches(&name) {
-                    // SPEC: §2.13 Typed Node Elements
-                    let statement_factory = graph.borrow().statement_factory();
-                    let mut graph = graph.borrow_mut();
-                    graph.insert(
-                        statement_factory
-                            .statement(
-                                subject_node.clone(),
-                                rdf::a_type().clone(),
-                                statement_factory.named_object(name_to_iri(&name)?),
-                            )
-                            .unwrap(),
-                    );
-                }
-                parse_predicate_attributes(&attributes.inner, &subject_node, graph)?;
-                parse_predicate_element(
-                    event_reader,
-                    if attributes.uri_base.is_some() {
-                        &attributes.uri_base
-                    } else {
-                        xml_base
-                    },
-                    &subject_node,
-                    graph,
-                )?;
-                subject = Some(subject_node);
-            }
-            Ok(XmlEvent::EndElement { .. }) => {
-                trace_event!("parse_subject_element" => event);
-                return Ok(subject);
-            }
-            Ok(_) => {
-                trace_event!("parse_subject_element" => ignore event);
-            }
-            Err(e) => {
-                error_event!(parse => "parse_subject_element", e);
+    fn document(&self, root: &Element, scope: &Scope) -> Result<()> {
+        let scope = scope.enter(root)?;
+        if root.is_rdf(ELEMENT_RDF) {
+            for element in root.elements() {
+                let _ = self.node_element(element, &scope)?;
             }
+        } else {
+            // SPEC: §2.15 ... the rdf:RDF element may be omitted if there is only one node element.
+            let _ = self.node_element(root, &scope)?;
         }
+        Ok(())
     }
-}
 
-#[inline]
-fn name_to_iri(name: &OwnedName) -> Result<IRIRef> {
-    Ok(IRIRef::new(IRI::from_str(&format!(
-        "{}{}",
-        name.namespace.as_ref().unwrap(),
-        name.local_name
-    ))?))
-}
+    fn node_element(&self, element: &Element, scope: &Scope) -> Result<SubjectNodeRef> {
+        trace!("XmlReader::node_element {}", element.name);
+        let scope = scope.enter(element)?;
+        let subject = if let Some(about) = element.rdf_attribute(ATTRIBUTE_ABOUT) {
+            // SPEC: §2.2 Node Elements and Property Elements
+            self.statements.named_subject(scope.resolve(about)?)
+        } else if let Some(id) = element.rdf_attribute(ATTRIBUTE_ID) {
+            // SPEC: §2.14 Abbreviating URIs: rdf:ID and xml:base
+            self.statements
+                .named_subject(scope.resolve(&format!("#{}", id))?)
+        } else if let Some(node_id) = element.rdf_attribute(ATTRIBUTE_NODE_ID) {
+            // SPEC: §2.10 Identifying Blank Nodes: rdf:nodeID
+            self.statements.blank_subject_named(node_id)?
+        } else {
+            self.statements.blank_subject()
+        };
+
+        if !element.is_rdf(ELEMENT_DESCRIPTION) {
+            // SPEC: §2.13 Typed Node Elements
+            self.insert(
+                &subject,
+                rdf::a_type().clone(),
+                self.statements.named_object(name_to_iri(&element.name)?),
+            )?;
+        }
 
-#[inline]
-fn value_to_iri(name: &str) -> Result<IRIRef> {
-    Ok(IRIRef::new(IRI::from_str(name)?))
-}
+        self.property_attributes(element, &subject, &scope)?;
 
-fn parse_predicate_attributes(
-    attributes: &[&OwnedAttribute],
-    subject: &SubjectNodeRef,
-    graph: &mut GraphRef,
-) -> Result<()> {
-    // SPEC: §2.5 Property Attributes
-    // SPEC: §2.12 Omitting Nodes: Property Attributes on an empty Property Element
-    for attribute in attributes {
-        trace!(
-            "XmlReader::parse_predicate_attributes attribute: {:?}",
-            attribute
-        );
-        let statement_factory = graph.borrow().statement_factory();
-        let literal_factory = graph.borrow().literal_factory();
-        let mut graph = graph.borrow_mut();
-        graph.insert(
-            statement_factory
-                .statement(
-                    subject.clone(),
-                    name_to_iri(&attribute.name)?,
-                    statement_factory.literal_object(literal_factory.literal(&attribute.value)),
-                )
-                .unwrap(),
-        );
+        let mut member = 0;
+        for property in element.elements() {
+            self.property_element(property, &subject, &scope, &mut member)?;
+        }
+        Ok(subject)
     }
-    Ok(())
-}
 
-fn parse_predicate_element<R: Read>(
-    event_reader: &mut EventReader<&mut R>,
-    xml_base: &Option<IRIRef>,
-    subject: &SubjectNodeRef,
-    graph: &mut GraphRef,
-) -> Result<()> {
-    loop {
-        let event = event_reader.next();
-        let mut no_child_elements = false;
-        match &event {
-            Ok(XmlEvent::StartElement {
-                name,
-                namespace: _,
-                attributes,
-            }) => {
-                trace_event!("parse_predicate_element" => event);
-                if no_child_elements {
-                    error_event!(state => "parse_predicate_element", "child elements not allowed here");
-                }
-                let attributes = parse_attributes(attributes)?;
-                if let Some(resource) = attributes.resource {
-                    // SPEC: §2.4 Empty Property Elements
-                    let statement_factory = graph.borrow().statement_factory();
-                    let mut graph = graph.borrow_mut();
-                    graph.insert(
-                        statement_factory
-                            .statement(
-                                subject.clone(),
-                                name_to_iri(&name)?,
-                                statement_factory.named_object(resource),
-                            )
-                            .unwrap(),
-                    );
-                    no_child_elements = true;
-                } else {
-                    let statement_factory = graph.borrow().statement_factory();
-                    let literal_factory = graph.borrow().literal_factory();
-                    match attributes.parse_type {
-                        None => {
-                            if let Some(content) =
-                                parse_object_element(event_reader, xml_base, graph)?
-                            {
-                                let literal = if let Some(data_type) = attributes.data_type {
-                                    // SPEC: §2.9 Typed Literals: rdf:datatype
-                                    literal_factory
-                                        .with_data_type(&content, DataType::from(data_type))
-                                } else if let Some(language) = attributes.language {
-                                    // SPEC: §2.7 Languages: xml:lang
-                                    literal_factory.with_language(&content, language)
-                                } else {
-                                    literal_factory.literal(&content)
-                                };
-                                graph.borrow_mut().insert(
-                                    statement_factory
-                                        .statement(
-                                            subject.clone(),
-                                            name_to_iri(&name)?,
-                                            statement_factory.literal_object(literal),
-                                        )
-                                        .unwrap(),
-                                );
-                            }
-                        }
-                        Some(ParseType::XmlLiteral) => {
-                            // SPEC: §2.8 XML Literals: rdf:parseType="Literal"
-                            let content = parse_xml_literal_element(event_reader)?
-                                .replace('<', "&lt;")
-                                .replace('>', "&gt;");
-                            graph.borrow_mut().insert(
-                                statement_factory
-                                    .statement(
-                                        subject.clone(),
-                                        name_to_iri(&name)?,
-                                        statement_factory.literal_object(
-                                            literal_factory
-                                                .with_data_type(&content, DataType::XmlLiteral),
-                                        ),
-                                    )
-                                    .unwrap(),
-                            );
-                        }
-                        Some(ParseType::Resource) => {
-                            // SPEC: §2.11 Omitting Blank Nodes: rdf:parseType="Resource"
-                            let subject_node = statement_factory.blank_subject();
-                            //parse_predicate_attributes(&attributes.inner, &subject_node, graph)?;
-                            parse_subject_element(
-                                event_reader,
-                                if attributes.uri_base.is_some() {
-                                    &attributes.uri_base
-                                } else {
-                                    xml_base
-                                },
-                                Some(&subject_node),
-                                graph,
-                            )?;
-                        }
-                        Some(ParseType::Collection) => {
-                            // SPEC: §2.16 Collections: rdf:parseType="Collection"
-                            todo!()
-                        }
-                    }
+    fn property_attributes(
+        &self,
+        element: &Element,
+        subject: &SubjectNodeRef,
+        scope: &Scope,
+    ) -> Result<()> {
+        for attribute in element.property_attributes() {
+            let object = if is_rdf_name(&attribute.name, TYPE_ATTRIBUTE) {
+                self.statements
+                    .named_object(scope.resolve(&attribute.value)?)
+            } else {
+                self.statements.literal_object(match &scope.language {
+                    None => self.literals.literal(&attribute.value),
+                    Some(language) => self
+                        .literals
+                        .with_language(&attribute.value, language.clone()),
+                })
+            };
+            self.insert(subject, name_to_iri(&attribute.name)?, object)?;
+        }
+        Ok(())
+    }
+
+    fn property_element(
+        &self,
+        element: &Element,
+        subject: &SubjectNodeRef,
+        scope: &Scope,
+        member: &mut usize,
+    ) -> Result<()> {
+        trace!("XmlReader::property_element {}", element.name);
+        let scope = scope.enter(element)?;
+        let predicate = if element.is_rdf(ELEMENT_LI) {
+            // SPEC: §2.15 Container Membership Property Elements: rdf:li and rdf:_n
+            *member += 1;
+            rdf::member(*member)
+        } else {
+            name_to_iri(&element.name)?
+        };
+
+        let object = match element
+            .rdf_attribute(ATTRIBUTE_PARSE_TYPE)
+            .map(String::as_str)
+        {
+            Some(PARSE_TYPE_RESOURCE) => {
+                // SPEC: §2.11 Omitting Blank Nodes: rdf:parseType="Resource"
+                let node = self.statements.blank_subject();
+                let mut node_member = 0;
+                for property in element.elements() {
+                    self.property_element(property, &node, &scope, &mut node_member)?;
                 }
+                self.statements.subject_as_object(node)
             }
-            Ok(XmlEvent::EndElement { .. }) => {
-                trace_event!("parse_predicate_element" => event);
-                return Ok(());
+            Some(PARSE_TYPE_COLLECTION) => {
+                // SPEC: §2.16 Collections: rdf:parseType="Collection"
+                let members = element
+                    .elements()
+                    .map(|node| self.node_element(node, &scope))
+                    .collect::<Result<Vec<SubjectNodeRef>>>()?;
+                self.collection(members)?
             }
-            Ok(_) => {
-                trace_event!("parse_predicate_element" => ignore event);
-            }
-            Err(e) => {
-                error_event!(parse => "parse_predicate_element", e);
+            Some(_) => {
+                // SPEC: §2.8 XML Literals: rdf:parseType="Literal", other values are treated
+                // the same way.
+                let content = write_xml_literal(&element.content)?;
+                self.statements
+                    .literal_object(self.literals.with_data_type(&content, DataType::XmlLiteral))
             }
+            None => self.property_value(element, &scope)?,
+        };
+
+        let statement = self
+            .statements
+            .statement(subject.clone(), predicate, object)?;
+        self.graph.borrow_mut().insert(statement.clone());
+
+        if let Some(id) = element.rdf_attribute(ATTRIBUTE_ID) {
+            // SPEC: §2.17 Reifying Statements: rdf:ID
+            self.reify(&statement, scope.resolve(&format!("#{}", id))?)?;
+        }
+        Ok(())
+    }
+
+    fn property_value(&self, element: &Element, scope: &Scope) -> Result<ObjectNodeRef> {
+        let nodes: Vec<&Element> = element.elements().collect();
+        if nodes.len() > 1 {
+            return Err(read_error(&format!(
+                "property element {} contains more than one node element",
+                element.name
+            )));
+        } else if let Some(node) = nodes.first() {
+            // SPEC: §2.3 Multiple Property Elements, nested node elements
+            return Ok(self
+                .statements
+                .subject_as_object(self.node_element(node, scope)?));
+        }
+
+        let has_properties = element.property_attributes().next().is_some();
+        let node = if let Some(resource) = element.rdf_attribute(ATTRIBUTE_RESOURCE) {
+            // SPEC: §2.4 Empty Property Elements
+            Some(self.statements.named_subject(scope.resolve(resource)?))
+        } else if let Some(node_id) = element.rdf_attribute(ATTRIBUTE_NODE_ID) {
+            // SPEC: §2.10 Identifying Blank Nodes: rdf:nodeID
+            Some(self.statements.blank_subject_named(node_id)?)
+        } else if has_properties {
+            // SPEC: §2.12 Omitting Nodes: Property Attributes on an empty Property Element
+            Some(self.statements.blank_subject())
+        } else {
+            None
+        };
+
+        if let Some(node) = node {
+            self.property_attributes(element, &node, scope)?;
+            Ok(self.statements.subject_as_object(node))
+        } else {
+            let content = element.text();
+            let literal = if let Some(data_type) = element.rdf_attribute(ATTRIBUTE_DATATYPE) {
+                // SPEC: §2.9 Typed Literals: rdf:datatype
+                self.literals
+                    .with_data_type(&content, DataType::from(scope.resolve(data_type)?))
+            } else if let Some(language) = &scope.language {
+                // SPEC: §2.7 Languages: xml:lang
+                self.literals.with_language(&content, language.clone())
+            } else {
+                self.literals.literal(&content)
+            };
+            Ok(self.statements.literal_object(literal))
+        }
+    }
+
+    fn collection(&self, members: Vec<SubjectNodeRef>) -> Result<ObjectNodeRef> {
+        let mut rest = self.statements.named_object(rdf::nil().clone());
+        for member in members.into_iter().rev() {
+            let cell = self.statements.blank_subject();
+            self.insert(
+                &cell,
+                rdf::first().clone(),
+                self.statements.subject_as_object(member),
+            )?;
+            self.insert(&cell, rdf::rest().clone(), rest)?;
+            rest = self.statements.subject_as_object(cell);
         }
+        Ok(rest)
+    }
+
+    fn reify(&self, statement: &StatementRef, id: IRIRef) -> Result<()> {
+        let subject = self.statements.named_subject(id);
+        self.insert(
+            &subject,
+            rdf::a_type().clone(),
+            self.statements.named_object(rdf::statement().clone()),
+        )?;
+        self.insert(
+            &subject,
+            rdf::subject().clone(),
+            self.statements
+                .subject_as_object(statement.subject().clone()),
+        )?;
+        self.insert(
+            &subject,
+            rdf::predicate().clone(),
+            self.statements.named_object(statement.predicate().clone()),
+        )?;
+        self.insert(&subject, rdf::object().clone(), statement.object().clone())
+    }
+
+    fn insert(
+        &self,
+        subject: &SubjectNodeRef,
+        predicate: IRIRef,
+        object: ObjectNodeRef,
+    ) -> Result<()> {
+        let statement = self
+            .statements
+            .statement(subject.clone(), predicate, object)?;
+        self.graph.borrow_mut().insert(statement);
+        Ok(())
     }
 }
 
-fn parse_object_element<R: Read>(
-    event_reader: &mut EventReader<&mut R>,
-    xml_base: &Option<IRIRef>,
-    graph: &mut GraphRef,
-) -> Result<Option<String>> {
-    let mut content = String::new();
-    loop {
-        let event = event_reader.next();
-        let mut has_elements = false;
-        let mut has_characters = false;
-        match &event {
+// ------------------------------------------------------------------------------------------------
+// Private Values
+// ------------------------------------------------------------------------------------------------
+
+const TYPE_ATTRIBUTE: &str = "type";
+
+const SYNTAX_ATTRIBUTES: &[&str] = &[
+    ATTRIBUTE_ABOUT,
+    ATTRIBUTE_DATATYPE,
+    ATTRIBUTE_ID,
+    ATTRIBUTE_NODE_ID,
+    ATTRIBUTE_PARSE_TYPE,
+    ATTRIBUTE_RESOURCE,
+    "aboutEach",
+    "aboutEachPrefix",
+    "bagID",
+];
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn read_document(r: &mut impl Read) -> Result<Element> {
+    let mut stack: Vec<Element> = Default::default();
+    for event in EventReader::new(r) {
+        match event {
             Ok(XmlEvent::StartElement {
                 name,
+                attributes,
                 namespace,
+            }) => stack.push(Element {
+                name,
                 attributes,
-            }) => {
-                trace_event!("parse_content_element" => event);
-                if has_characters {
-                    error_event!(state => "parse_object_element", &format!("found XML content, parseType != Literal ({:?})", name));
-                }
-                has_elements = true;
-                let attributes = parse_attributes(attributes)?;
-                let subject_node = graph.borrow().statement_factory().blank_subject();
-                parse_subject_element(
-                    event_reader,
-                    if attributes.uri_base.is_some() {
-                        &attributes.uri_base
-                    } else {
-                        xml_base
-                    },
-                    Some(&subject_node),
-                    graph,
-                )?;
-            }
-            Ok(XmlEvent::EndElement { name }) => {
-                trace_event!("parse_content_element" => event);
-                return Ok(Some(content));
-            }
-            Ok(XmlEvent::CData(value)) => {
-                trace_event!("parse_content_element" => event);
-                if has_elements {
-                    error_event!(state => "parse_object_element", &format!("found character content after element(s)"));
+                namespace,
+                content: Default::default(),
+            }),
+            Ok(XmlEvent::EndElement { .. }) => {
+                let element = stack.pop().unwrap();
+                match stack.last_mut() {
+                    None => return Ok(element),
+                    Some(parent) => parent.content.push(Content::Element(element)),
                 }
-                has_characters = true;
-                content.push_str(&value);
             }
-            Ok(XmlEvent::Characters(value)) => {
-                trace_event!("parse_content_element" => event);
-                if has_elements {
-                    error_event!(state => "parse_object_element", "found character content after element(s)");
+            Ok(XmlEvent::Characters(text))
+            | Ok(XmlEvent::CData(text))
+            | Ok(XmlEvent::Whitespace(text)) => {
+                if let Some(parent) = stack.last_mut() {
+                    parent.content.push(Content::Text(text));
                 }
-                has_characters = true;
-                content.push_str(&value);
             }
-            Ok(_) => {
-                trace_event!("parse_object_element" => ignore event);
+            Ok(event) => {
+                trace!("XmlReader::read_document ignoring event: {:?}", event);
             }
             Err(e) => {
-                error_event!(parse => "parse_object_element", e);
+                return Err(read_error(&format!("error parsing XML: {}", e)));
             }
         }
     }
+    Err(read_error("document has no root element"))
 }
 
-fn parse_xml_literal_element<R: Read>(event_reader: &mut EventReader<&mut R>) -> Result<String> {
-    let mut content: Vec<u8> = Vec::new();
-    let mut writer_config = xml::EmitterConfig::new();
-    writer_config.write_document_declaration = false;
-    writer_config.normalize_empty_elements = true;
-    let mut writer = EventWriter::new_with_config(&mut content, writer_config);
-    let mut opened: u32 = 0;
-    loop {
-        let event = event_reader.next();
-        match &event {
-            Ok(XmlEvent::StartElement { .. }) => {
-                trace_event!("parse_content_element" => event);
-                if let Some(event) = event.unwrap().as_writer_event() {
-                    writer.write(event).unwrap()
-                }
-                opened += 1;
-            }
-            Ok(XmlEvent::EndElement { .. }) => {
-                trace_event!("parse_content_element" => event);
-                if let Some(event) = event.unwrap().as_writer_event() {
-                    writer.write(event).unwrap()
-                }
-                opened -= 1;
-                if opened == 0 {
-                    return Ok(String::from_utf8(content).unwrap());
-                }
-            }
-            Ok(event) => {
-                // We only leave trim_whitespace=false so that these events are also written out.
-                if let Some(event) = event.as_writer_event() {
-                    writer.write(event).unwrap()
-                }
-            }
-            Err(e) => {
-                error_event!(parse => "parse_content_element", e);
+fn write_xml_literal(content: &[Content]) -> Result<String> {
+    let mut buffer: Vec<u8> = Default::default();
+    let mut writer = EventWriter::new_with_config(
+        &mut buffer,
+        EmitterConfig {
+            write_document_declaration: false,
+            normalize_empty_elements: false,
+            ..Default::default()
+        },
+    );
+    write_xml_content(&mut writer, content)
+        .map_err(|e| read_error(&format!("error writing XML literal: {}", e)))?;
+    Ok(String::from_utf8(buffer).unwrap())
+}
+
+fn write_xml_content(
+    writer: &mut EventWriter<&mut Vec<u8>>,
+    content: &[Content],
+) -> xml::writer::Result<()> {
+    for content in content {
+        match content {
+            Content::Text(text) => writer.write(WriterEvent::Characters(text))?,
+            Content::Element(element) => {
+                // Only declare the namespaces used by the element and its attributes.
+                let used: BTreeMap<String, String> = std::iter::once(&element.name)
+                    .chain(element.attributes.iter().map(|attribute| &attribute.name))
+                    .filter_map(|name| {
+                        let prefix = name.prefix.as_deref().unwrap_or(NS_NO_PREFIX);
+                        element
+                            .namespace
+                            .get(prefix)
+                            .map(|namespace| (prefix.to_string(), namespace.to_string()))
+                    })
+                    .filter(|(prefix, _)| prefix != NS_XML_PREFIX)
+                    .collect();
+                let attributes: Vec<xml::attribute::Attribute<'_>> = element
+                    .attributes
+                    .iter()
+                    .map(OwnedAttribute::borrow)
+                    .collect();
+                writer.write(WriterEvent::StartElement {
+                    name: element.name.borrow(),
+                    attributes: Cow::Owned(attributes),
+                    namespace: Cow::Owned(Namespace(used)),
+                })?;
+                write_xml_content(writer, &element.content)?;
+                writer.write(WriterEvent::EndElement {
+                    name: Some(element.name.borrow()),
+                })?;
             }
         }
     }
+    Ok(())
 }
 
-fn parse_attributes(attributes: &[OwnedAttribute]) -> Result<Attributes<'_>> {
-    let mut response = Attributes {
-        subject_type: None,
-        parse_type: None,
-        uri_base: None,
-        data_type: None,
-        language: None,
-        resource: None,
-        inner: Default::default(),
-    };
-
-    for attribute in attributes {
-        if attribute.name.namespace == Some(XML_NAMESPACE.to_string()) {
-            if attribute.name.local_name == ATTRIBUTE_XML_BASE {
-                response.uri_base = Some(value_to_iri(&attribute.value)?);
-            } else if attribute.name.local_name == ATTRIBUTE_XML_LANG {
-                response.language = Some(LanguageTag::from_str(&attribute.value)?);
-            }
-        } else if attribute.name.namespace == Some(rdf::namespace_str().to_string()) {
-            if attribute.name.local_name == ATTRIBUTE_ABOUT {
-                response.subject_type =
-                    Some(SubjectType::Resource(value_to_iri(&attribute.value)?));
-            } else if attribute.name.local_name == ATTRIBUTE_NODE_ID {
-                response.subject_type = Some(SubjectType::BlankNamed(attribute.value.to_string()));
-            } else if attribute.name.local_name == ATTRIBUTE_ID {
-                response.subject_type =
-                    Some(SubjectType::RelativeResource(attribute.value.to_string()));
-            } else if attribute.name.local_name == ATTRIBUTE_RESOURCE {
-                response.resource = Some(value_to_iri(&attribute.value)?);
-            } else if attribute.name.local_name == ATTRIBUTE_PARSE_TYPE {
-                if attribute.value == PARSE_TYPE_LITERAL {
-                    response.parse_type = Some(ParseType::XmlLiteral);
-                } else if attribute.value == PARSE_TYPE_RESOURCE {
-                    response.parse_type = Some(ParseType::Resource);
-                } else if attribute.value == PARSE_TYPE_COLLECTION {
-                    response.parse_type = Some(ParseType::Collection);
-                } else {
-                    panic!();
-                }
-            }
-        } else {
-            response.inner.push(attribute);
-        }
+#[inline]
+fn is_rdf_name(name: &OwnedName, local_name: &str) -> bool {
+    name.local_name == local_name && name.namespace.as_deref() == Some(rdf::namespace_str())
+}
+
+#[inline]
+fn name_to_iri(name: &OwnedName) -> Result<IRIRef> {
+    match &name.namespace {
+        None => Err(read_error(&format!(
+            "element or attribute {} has no namespace",
+            name
+        ))),
+        Some(namespace) => Ok(IRIRef::new(IRI::from_str(&format!(
+            "{}{}",
+            namespace, name.local_name
+        ))?)),
     }
+}
 
-    trace!("parse_attributes -> {:?}", response);
+fn is_absolute(value: &str) -> bool {
+    match value.find(':') {
+        None | Some(0) => false,
+        Some(index) => {
+            let scheme = &value[..index];
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
+        }
+    }
+}
 
-    Ok(response)
+fn read_error(message: &str) -> rdftk_core::error::Error {
+    use rdftk_core::error::ErrorKind;
+    error!("XmlReader {}", message);
+    ErrorKind::ReadWrite(super::NAME.to_string()).into()
 }
 
-// ------------------------------------------------------------------------------------------------
-// Modules
-// ------------------------------------------------------------------------------------------------
+fn io_error(e: std::io::Error) -> rdftk_core::error::Error {
+    use rdftk_core::error::ErrorKind;
+    rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
+}
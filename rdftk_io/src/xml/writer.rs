@@ -4,7 +4,12 @@ format.
 
 This writer has a number of options, it can be written in a plain, streaming, form or alternatively
 pretty-printed  for readability. It is also possible to pick one of the type styles described
-in the specification, "flat" or "striped".
+in the specification, "flat" or "striped", and to use typed node elements where the element name
+for a subject is taken from its `rdf:type` rather than using `rdf:Description`.
+
+All namespaces used in the document are declared on the `rdf:RDF` element, the graph's prefix
+mappings are used to name these namespaces where available, followed by a set of common
+prefixes, otherwise prefixes are generated.
 
 # Example
 
@@ -13,7 +18,7 @@ use rdftk_io::xml::writer::{XmlOptions, XmlWriter};
 use rdftk_io::write_graph_to_string;
 # let graph = rdftk_core::simple::graph::graph_factory().graph();
 
-let options: XmlOptions = XmlOptions::flat().pretty().clone();
+let options: XmlOptions = XmlOptions::striped().typed_nodes().pretty().clone();
 
 let writer = XmlWriter::new(options);
 
@@ -26,15 +31,16 @@ use super::syntax::{
     ATTRIBUTE_ABOUT, ATTRIBUTE_DATATYPE, ATTRIBUTE_NODE_ID, ATTRIBUTE_RESOURCE, DEFAULT_ENCODING,
     ELEMENT_DESCRIPTION, ELEMENT_RDF,
 };
+#[cfg(feature = "config")]
+use crate::config::ConfigHandle;
 use crate::xml::syntax::ATTRIBUTE_XML_LANG_PREFIXED;
 use crate::GraphWriter;
 use rdftk_core::error::{ErrorKind, Result};
-use rdftk_core::model::graph::{Graph, GraphRef};
-use rdftk_core::model::statement::SubjectNodeRef;
+use rdftk_core::model::graph::{Graph, GraphRef, PrefixMappings};
+use rdftk_core::model::statement::{ObjectNodeRef, SubjectNodeRef};
 use rdftk_iri::IRIRef;
 use rdftk_names::{dc, foaf, geo, owl, rdf, rdfs, xsd};
-use std::cell::Ref;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::Write;
 use xml::common::XmlVersion;
 use xml::writer::{EventWriter, XmlEvent};
@@ -66,6 +72,8 @@ pub struct XmlOptions {
     pub pretty: bool,
     /// The encoding to specify in the XML declaration. Default is "utf-8".
     pub encoding: String,
+    /// Should the `rdf:type` of a subject be used as its element name. Default is `false`.
+    pub typed_nodes: bool,
 }
 
 ///
@@ -75,6 +83,21 @@ pub struct XmlOptions {
 pub struct XmlWriter {
     mappings: HashMap<String, String>,
     options: XmlOptions,
+    #[cfg(feature = "config")]
+    config: Option<ConfigHandle>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+struct WriterState<'a> {
+    graph: &'a dyn Graph,
+    typed_nodes: bool,
+    striped: bool,
+    namespaces: BTreeMap<String, String>,
+    references: HashMap<String, usize>,
+    written: HashSet<String>,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -96,6 +119,7 @@ impl Default for XmlOptions {
             style: XmlStyle::Flat,
             pretty: false,
             encoding: String::from(DEFAULT_ENCODING),
+            typed_nodes: false,
         }
     }
 }
@@ -105,8 +129,7 @@ impl XmlOptions {
     pub fn flat() -> Self {
         Self {
             style: XmlStyle::Flat,
-            pretty: false,
-            encoding: String::from(DEFAULT_ENCODING),
+            ..Default::default()
         }
     }
 
@@ -114,8 +137,7 @@ impl XmlOptions {
     pub fn striped() -> Self {
         Self {
             style: XmlStyle::Striped,
-            pretty: false,
-            encoding: String::from(DEFAULT_ENCODING),
+            ..Default::default()
         }
     }
 
@@ -136,6 +158,18 @@ impl XmlOptions {
         self.encoding = encoding.to_string();
         self
     }
+
+    /// Set the option to use a subject's `rdf:type` as its element name.
+    pub fn typed_nodes(&mut self) -> &mut Self {
+        self.typed_nodes = true;
+        self
+    }
+
+    /// Set the option to always use `rdf:Description` as the element name for a subject.
+    pub fn untyped_nodes(&mut self) -> &mut Self {
+        self.typed_nodes = false;
+        self
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -145,6 +179,8 @@ impl Default for XmlWriter {
         Self {
             mappings: Self::default_mappings(),
             options: Default::default(),
+            #[cfg(feature = "config")]
+            config: None,
         }
     }
 }
@@ -166,21 +202,54 @@ impl GraphWriter for XmlWriter {
             })
             .map_err(xml_error)?;
 
+        let mut state = WriterState {
+            graph: &*graph,
+            typed_nodes: self.options.typed_nodes,
+            striped: self.options.style == XmlStyle::Striped,
+            namespaces: self.namespaces(&*graph)?,
+            references: Default::default(),
+            written: Default::default(),
+        };
+
         let container_name = format!("{}:{}", rdf::default_prefix(), ELEMENT_RDF);
-        writer
-            .write(
-                XmlEvent::start_element(container_name.as_str())
-                    .ns(rdf::default_prefix(), rdf::namespace_str()),
-            )
-            .map_err(xml_error)?;
+        let mut container = XmlEvent::start_element(container_name.as_str());
+        for (namespace, prefix) in &state.namespaces {
+            container = if prefix.is_empty() {
+                container.default_ns(namespace.as_str())
+            } else {
+                container.ns(prefix.as_str(), namespace.as_str())
+            };
+        }
+        writer.write(container).map_err(xml_error)?;
+
+        let mut subjects: Vec<&SubjectNodeRef> = graph.subjects().into_iter().collect();
+        subjects.sort_by_key(|subject| subject.to_string());
 
-        if self.options.style == XmlStyle::Flat {
-            for subject in graph.subjects() {
-                self.write_subject(&mut writer, &graph, subject, true)?;
+        if state.striped {
+            for statement in graph.statements() {
+                if let Some(blank) = statement.object().as_blank() {
+                    *state.references.entry(blank.clone()).or_default() += 1;
+                }
+            }
+            // IRI subjects first, nesting blank nodes referenced only once, then any blank node
+            // that could not be nested.
+            for subject in subjects.iter().filter(|subject| subject.is_iri()) {
+                state.write_subject(&mut writer, subject, false)?;
+            }
+            for nestable in &[false, true] {
+                for subject in &subjects {
+                    if let Some(blank) = subject.as_blank() {
+                        if !state.written.contains(blank)
+                            && (*nestable || state.references.get(blank) != Some(&1))
+                        {
+                            state.write_subject(&mut writer, subject, false)?;
+                        }
+                    }
+                }
             }
         } else {
-            for subject in graph.subjects().iter().filter(|s| s.is_iri()) {
-                self.write_subject(&mut writer, &graph, subject, false)?;
+            for subject in &subjects {
+                state.write_subject(&mut writer, subject, false)?;
             }
         }
 
@@ -198,9 +267,21 @@ impl XmlWriter {
         Self {
             mappings: Self::default_mappings(),
             options,
+            #[cfg(feature = "config")]
+            config: None,
         }
     }
 
+    ///
+    /// Prefer the prefixes of the active profile in `config` over the writer's own defaults
+    /// for namespaces not mapped by the graph, each time a graph is written.
+    ///
+    #[cfg(feature = "config")]
+    pub fn config(&mut self, config: ConfigHandle) -> &mut Self {
+        self.config = Some(config);
+        self
+    }
+
     fn default_mappings() -> HashMap<String, String> {
         let mappings: HashMap<String, String> = [
             (
@@ -238,104 +319,226 @@ impl XmlWriter {
         mappings
     }
 
+    ///
+    /// Returns a map from namespace to prefix for all namespaces used as element names, the `rdf`
+    /// namespace always uses the `rdf` prefix as it is required for the syntax.
+    ///
+    fn namespaces(&self, graph: &dyn Graph) -> Result<BTreeMap<String, String>> {
+        let graph_mappings = graph.prefix_mappings();
+        let graph_mappings = graph_mappings.borrow();
+        let mut used: Vec<String> = Default::default();
+        for statement in graph.statements() {
+            used.push(split_uri(&*graph_mappings, statement.predicate())?.0);
+            if self.options.typed_nodes && statement.predicate() == rdf::a_type() {
+                if let Some(iri) = statement.object().as_iri() {
+                    if let Ok((namespace, _)) = split_uri(&*graph_mappings, iri) {
+                        used.push(namespace);
+                    }
+                }
+            }
+        }
+        used.sort();
+        used.dedup();
+
+        #[cfg(feature = "config")]
+        let config = self.config.as_ref().map(|config| config.current());
+        #[cfg(feature = "config")]
+        let profile_prefix = |namespace: &String| {
+            config.as_ref().and_then(|config| {
+                config
+                    .prefixes()
+                    .find(|(_, iri)| iri.to_string() == *namespace)
+                    .map(|(prefix, _)| prefix.clone())
+            })
+        };
+        #[cfg(not(feature = "config"))]
+        let profile_prefix = |_: &String| None;
+        let mut namespaces: BTreeMap<String, String> = Default::default();
+        let mut prefixes: HashSet<String> = Default::default();
+        let _ = namespaces.insert(
+            rdf::namespace_str().to_string(),
+            rdf::default_prefix().to_string(),
+        );
+        let _ = prefixes.insert(rdf::default_prefix().to_string());
+
+        let mut generated = 0;
+        for namespace in used {
+            if namespaces.contains_key(&namespace) {
+                continue;
+            }
+            let preferred = graph_mappings
+                .mappings()
+                .find(|(_, iri)| iri.to_string() == namespace)
+                .map(|(prefix, _)| prefix.clone())
+                .or_else(|| profile_prefix(&namespace))
+                .or_else(|| self.mappings.get(&namespace).cloned())
+                .filter(|prefix| !prefixes.contains(prefix));
+            let prefix = match preferred {
+                Some(prefix) => prefix,
+                None => loop {
+                    let prefix = format!("ns{}", generated);
+                    generated += 1;
+                    if !prefixes.contains(&prefix) {
+                        break prefix;
+                    }
+                },
+            };
+            let _ = prefixes.insert(prefix.clone());
+            let _ = namespaces.insert(namespace, prefix);
+        }
+        Ok(namespaces)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> WriterState<'a> {
     fn write_subject<W: Write>(
-        &self,
+        &mut self,
         writer: &mut EventWriter<W>,
-        graph: &Ref<'_, dyn Graph>,
         subject: &SubjectNodeRef,
-        flat: bool,
+        nested: bool,
     ) -> Result<()> {
+        let node_type = if self.typed_nodes {
+            self.node_type(subject)
+        } else {
+            None
+        };
+        let element_name = match &node_type {
+            Some(node_type) => self.qname(node_type)?,
+            None => RDF_DESCRIPTION.to_string(),
+        };
+
+        let element = XmlEvent::start_element(element_name.as_str());
         if let Some(blank) = subject.as_blank() {
-            if flat {
-                writer
-                    .write(
-                        XmlEvent::start_element(RDF_DESCRIPTION.as_str())
-                            .attr(RDF_NODE_ID.as_str(), blank),
-                    )
-                    .map_err(xml_error)?;
+            let _ = self.written.insert(blank.clone());
+            if nested || (self.striped && !self.references.contains_key(blank)) {
+                writer.write(element).map_err(xml_error)?;
             } else {
                 writer
-                    .write(XmlEvent::start_element(RDF_DESCRIPTION.as_str()))
+                    .write(element.attr(RDF_NODE_ID.as_str(), blank))
                     .map_err(xml_error)?;
             }
         } else if let Some(subject) = subject.as_iri() {
             writer
-                .write(
-                    XmlEvent::start_element(RDF_DESCRIPTION.as_str())
-                        .attr(RDF_ABOUT.as_str(), &subject.to_string()),
-                )
+                .write(element.attr(RDF_ABOUT.as_str(), &subject.to_string()))
                 .map_err(xml_error)?;
         } else {
             return Err(ErrorKind::Msg("RDF* not supported by XML writer".to_string()).into());
         }
 
-        for predicate in graph.predicates_for(subject) {
-            let (ns, mut name) = split_uri(predicate);
-
-            for object in graph.objects_for(subject, predicate) {
-                let mut element = if let Some(prefix) = self.mappings.get(&ns) {
-                    name = format!("{}:{}", prefix, name);
-                    XmlEvent::start_element(name.as_str()).ns(prefix, &ns)
-                } else {
-                    XmlEvent::start_element(name.as_str()).default_ns(&ns)
-                };
-
-                if let Some(iri) = object.as_iri() {
-                    let iri = iri.to_string();
-                    element = element.attr(RDF_RESOURCE.as_str(), &iri);
-                    writer.write(element).map_err(xml_error)?;
-                } else if let Some(blank) = object.as_blank() {
-                    if flat {
-                        element = element.attr(RDF_NODE_ID.as_str(), blank);
-                        writer.write(element).map_err(xml_error)?;
-                    } else {
-                        writer.write(element).map_err(xml_error)?;
-                        self.write_subject(
-                            writer,
-                            graph,
-                            &graph
-                                .statement_factory()
-                                .blank_subject_named(blank)
-                                .unwrap(),
-                            flat,
-                        )?;
-                    }
-                } else if let Some(literal) = object.as_literal() {
-                    let language = literal
-                        .language()
-                        .map(|l| l.to_string())
-                        .unwrap_or_default();
-                    if !language.is_empty() {
-                        element = element.attr(ATTRIBUTE_XML_LANG_PREFIXED, &language)
-                    }
-                    if let Some(data_type) = literal.data_type() {
-                        let dt_iri = data_type.as_iri().to_string();
-                        writer
-                            .write(element.attr(RDF_DATATYPE.as_str(), &dt_iri))
-                            .map_err(xml_error)?
-                    } else {
-                        writer.write(element).map_err(xml_error)?;
-                    }
-                    writer
-                        .write(XmlEvent::Characters(literal.lexical_form()))
-                        .map_err(xml_error)?;
-                } else {
-                    return Err(
-                        ErrorKind::Msg("RDF* not supported by XML writer".to_string()).into(),
-                    );
-                }
-                writer
-                    .write(XmlEvent::end_element().name(name.as_str()))
-                    .map_err(xml_error)?;
+        let mut predicates: Vec<&IRIRef> = self.graph.predicates_for(subject).into_iter().collect();
+        predicates.sort_by_key(|predicate| predicate.to_string());
+        for predicate in predicates {
+            let name = self.qname(predicate)?;
+            let mut objects: Vec<&ObjectNodeRef> = self
+                .graph
+                .objects_for(subject, predicate)
+                .into_iter()
+                .filter(|object| {
+                    !(predicate == rdf::a_type()
+                        && node_type.is_some()
+                        && object.as_iri() == node_type.as_ref())
+                })
+                .collect();
+            objects.sort_by_key(|object| object.to_string());
+            for object in objects {
+                self.write_object(writer, &name, object)?;
             }
         }
 
         writer
-            .write(XmlEvent::end_element().name(RDF_DESCRIPTION.as_str()))
+            .write(XmlEvent::end_element().name(element_name.as_str()))
             .map_err(xml_error)?;
 
         Ok(())
     }
+
+    fn write_object<W: Write>(
+        &mut self,
+        writer: &mut EventWriter<W>,
+        name: &str,
+        object: &ObjectNodeRef,
+    ) -> Result<()> {
+        let mut element = XmlEvent::start_element(name);
+        if let Some(iri) = object.as_iri() {
+            let iri = iri.to_string();
+            element = element.attr(RDF_RESOURCE.as_str(), &iri);
+            writer.write(element).map_err(xml_error)?;
+        } else if let Some(blank) = object.as_blank() {
+            let nested = self.striped
+                && self.references.get(blank) == Some(&1)
+                && !self.written.contains(blank);
+            if nested {
+                writer.write(element).map_err(xml_error)?;
+                let subject = self.graph.statement_factory().blank_subject_named(blank)?;
+                self.write_subject(writer, &subject, true)?;
+            } else {
+                element = element.attr(RDF_NODE_ID.as_str(), blank);
+                writer.write(element).map_err(xml_error)?;
+            }
+        } else if let Some(literal) = object.as_literal() {
+            let language = literal
+                .language()
+                .map(|l| l.to_string())
+                .unwrap_or_default();
+            if !language.is_empty() {
+                element = element.attr(ATTRIBUTE_XML_LANG_PREFIXED, &language)
+            }
+            if let Some(data_type) = literal.data_type() {
+                let dt_iri = data_type.as_iri().to_string();
+                writer
+                    .write(element.attr(RDF_DATATYPE.as_str(), &dt_iri))
+                    .map_err(xml_error)?
+            } else {
+                writer.write(element).map_err(xml_error)?;
+            }
+            writer
+                .write(XmlEvent::Characters(literal.lexical_form()))
+                .map_err(xml_error)?;
+        } else {
+            return Err(ErrorKind::Msg("RDF* not supported by XML writer".to_string()).into());
+        }
+        writer
+            .write(XmlEvent::end_element().name(name))
+            .map_err(xml_error)?;
+        Ok(())
+    }
+
+    ///
+    /// Return the first, in lexical order, type of the subject that can be written as an element
+    /// name.
+    ///
+    fn node_type(&self, subject: &SubjectNodeRef) -> Option<IRIRef> {
+        let mappings = self.graph.prefix_mappings();
+        let mappings = mappings.borrow();
+        let mut types: Vec<&IRIRef> = self
+            .graph
+            .objects_for(subject, rdf::a_type())
+            .into_iter()
+            .filter_map(|object| object.as_iri())
+            .filter(|iri| {
+                split_uri(&*mappings, iri)
+                    .map(|(namespace, _)| self.namespaces.contains_key(&namespace))
+                    .unwrap_or_default()
+            })
+            .collect();
+        types.sort_by_key(|iri| iri.to_string());
+        types.first().map(|iri| (*iri).clone())
+    }
+
+    fn qname(&self, iri: &IRIRef) -> Result<String> {
+        let (namespace, name) = split_uri(&*self.graph.prefix_mappings().borrow(), iri)?;
+        match self.namespaces.get(&namespace) {
+            Some(prefix) if prefix.is_empty() => Ok(name),
+            Some(prefix) => Ok(format!("{}:{}", prefix, name)),
+            None => Err(ErrorKind::Msg(format!(
+                "no namespace declared for IRI {} in XML writer",
+                iri
+            ))
+            .into()),
+        }
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -347,15 +550,17 @@ fn xml_error(e: xml::writer::Error) -> rdftk_core::error::Error {
     rdftk_core::error::Error::with_chain(e, ErrorKind::ReadWrite(super::NAME.to_string()))
 }
 
-fn split_uri(iri: &IRIRef) -> (String, String) {
-    let iri = iri.to_string();
-    let index = iri
-        .chars()
-        .rev()
-        .enumerate()
-        .find_map(|(i, c)| if c == '#' || c == '/' { Some(i) } else { None })
-        .unwrap();
-    assert_ne!(index, iri.len());
-    let index = iri.len() - index;
-    (iri[..index].to_string(), iri[index..].to_string())
+///
+/// Split an IRI into a namespace and a local name that is a valid XML element name, after a
+/// namespace mapped by the graph, or after the last `/` or `#` of the IRI.
+///
+fn split_uri(mappings: &dyn PrefixMappings, iri: &IRIRef) -> Result<(String, String)> {
+    match mappings.split(iri) {
+        (namespace, Some(name)) => Ok((namespace.to_string(), name)),
+        _ => Err(ErrorKind::Msg(format!(
+            "IRI {} cannot be written as an XML element name",
+            iri
+        ))
+        .into()),
+    }
 }
@@ -0,0 +1,145 @@
+#![cfg(all(feature = "config", feature = "turtle"))]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::iri;
+use rdftk_io::config::{Config, ConfigHandle, RULE_REQUIRED_LANGUAGES};
+use rdftk_io::turtle::writer::TurtleWriter;
+use rdftk_io::write_graph_to_string;
+use std::time::{Duration, Instant};
+
+const PROFILES: &str = r#"{
+  "profile": "publishing",
+  "profiles": {
+    "publishing": { "ex": "http://example.org/vocab#" },
+    "internal": { "int": "http://example.org/vocab#" }
+  },
+  "catalog": {
+    "http://example.org/": "file:///srv/vocab/",
+    "http://example.org/vocab#": "file:///srv/vocab/current.ttl#"
+  },
+  "languages": ["en", "fr"],
+  "rules": { "unused-prefix": false }
+}"#;
+
+fn labeled_graph() -> GraphRef {
+    let factory = statement_factory();
+    let literals = literal_factory();
+    let subject = factory.named_subject(iri("http://example.org/things/one"));
+    graph_factory().graph_from(
+        &[
+            factory
+                .statement(
+                    subject.clone(),
+                    iri("http://example.org/vocab#name"),
+                    factory.literal_object(literals.with_language_str("One", "en").unwrap()),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    subject,
+                    iri("http://example.org/vocab#code"),
+                    factory.literal_object(literals.literal("001")),
+                )
+                .unwrap(),
+        ],
+        None,
+    )
+}
+
+#[test]
+fn parse_config() {
+    let config = Config::from_json_str(PROFILES).unwrap();
+    assert_eq!(config.profile(), Some(&"publishing".to_string()));
+    assert_eq!(config.profiles().count(), 2);
+    assert_eq!(
+        config
+            .prefixes()
+            .map(|(p, _)| p.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["ex"]
+    );
+    assert_eq!(
+        config.catalog_lookup(&iri("http://example.org/vocab#name")),
+        Some("file:///srv/vocab/current.ttl#name".to_string())
+    );
+    assert_eq!(
+        config.catalog_lookup(&iri("http://example.org/other")),
+        Some("file:///srv/vocab/other".to_string())
+    );
+    assert_eq!(config.catalog_lookup(&iri("http://example.com/")), None);
+    assert!(!config.rule_enabled("unused-prefix"));
+    assert!(config.rule_enabled(RULE_REQUIRED_LANGUAGES));
+
+    assert!(Config::from_json_str(r#"{ "profile": "missing" }"#).is_err());
+    assert!(Config::from_json_str(r#"{ "languages": ["not a tag"] }"#).is_err());
+}
+
+#[test]
+fn missing_languages() {
+    let config = Config::from_json_str(PROFILES).unwrap();
+    let missing = config.missing_languages(&labeled_graph());
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].predicate, iri("http://example.org/vocab#name"));
+    assert_eq!(missing[0].language.to_string(), "fr");
+
+    let config = Config::from_json_str(
+        r#"{ "languages": ["fr"], "rules": { "required-languages": false } }"#,
+    )
+    .unwrap();
+    assert!(config.missing_languages(&labeled_graph()).is_empty());
+}
+
+#[test]
+fn writer_uses_replaced_config() {
+    let handle = ConfigHandle::default();
+    let mut writer = TurtleWriter::default();
+    let _ = writer.config(handle.clone());
+
+    let graph = labeled_graph();
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    assert!(!output.contains("@prefix ex:"));
+
+    handle.replace(Config::from_json_str(PROFILES).unwrap());
+    assert_eq!(handle.generation(), 1);
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    println!("{}", output);
+    assert!(output.contains("@prefix ex: <http://example.org/vocab#> ."));
+    assert!(output.contains("ex:name"));
+
+    // the graph itself is unchanged.
+    assert!(graph.borrow().prefix_mappings().borrow().is_empty());
+}
+
+#[test]
+fn watch_reloads_file() {
+    let path = std::env::temp_dir().join(format!("rdftk-config-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{ "languages": ["en"] }"#).unwrap();
+
+    let handle = ConfigHandle::default();
+    let watcher = handle.watch(&path, Duration::from_millis(10)).unwrap();
+    assert_eq!(watcher.path(), path.as_path());
+    assert_eq!(handle.current().required_languages().count(), 1);
+    let generation = handle.generation();
+
+    // an invalid file is ignored, and the previous configuration retained.
+    std::fs::write(&path, r#"{ "languages": "en" }"#).unwrap();
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(handle.current().required_languages().count(), 1);
+
+    std::fs::write(&path, r#"{ "languages": ["en", "fr", "de"] }"#).unwrap();
+    let start = Instant::now();
+    while handle.current().required_languages().count() != 3 {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "configuration not reloaded"
+        );
+        std::thread::sleep(Duration::from_millis(10));
+    }
+    assert!(handle.generation() > generation);
+
+    watcher.stop();
+    std::fs::remove_file(&path).unwrap();
+}
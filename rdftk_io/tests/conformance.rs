@@ -0,0 +1,265 @@
+use rdftk_core::error::ErrorKind;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::conformance::check;
+use rdftk_io::format::Format;
+use rdftk_io::json::reader::JsonReader;
+use rdftk_io::json_ld::reader::JsonLdReader;
+use rdftk_io::nq::reader::NQuadDataSetReader;
+use rdftk_io::nt::reader::NTriplesReader;
+use rdftk_io::trig::reader::TriGReader;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::xml::reader::XmlReader;
+use rdftk_io::{DataSetReader, GraphReader};
+
+const NT_VALID: &str = r#"<http://example.org/s> <http://example.org/p> "café"@fr-CA .
+<http://example.org/s> <http://example.org/p> "tab\there" .
+"#;
+
+const NT_INVALID: &str = r#"<http://example.org/s> <http://example.org/p> "\uD800" .
+<http://example.org/s> <http://example.org/p> "bell\u0007" .
+<http://example.org/s> <http://example.org/p> "bad tag"@en-toolongsubtag .
+"#;
+
+fn assert_non_conformant(error: rdftk_core::error::Error, expected: usize) {
+    match error.kind() {
+        ErrorKind::NonConformant(_, violations) => assert_eq!(violations.len(), expected),
+        kind => panic!("expected NonConformant, not {:?}", kind),
+    }
+}
+
+#[test]
+fn check_n_triples() {
+    assert!(check(Format::NTriples, NT_VALID).is_empty());
+
+    let violations = check(Format::NTriples, NT_INVALID);
+    assert_eq!(violations.len(), 3);
+    let positions: Vec<(usize, usize)> = violations
+        .iter()
+        .map(|violation| (violation.line(), violation.column()))
+        .collect();
+    assert_eq!(positions, vec![(1, 48), (2, 52), (3, 57)]);
+    assert_eq!(
+        violations[0].reference(),
+        "RDF 1.1 Turtle §6.4 (Escape Sequences)"
+    );
+    assert_eq!(violations[1].reference(), "XML 1.0 §2.2 (Characters)");
+    assert_eq!(
+        violations[2].reference(),
+        "RDF 1.1 Concepts §3.3 (Literals), BCP 47 §2.2.9"
+    );
+    assert_eq!(
+        violations[2].text(),
+        r#"<http://example.org/s> <http://example.org/p> "bad tag"@en-toolongsubtag ."#
+    );
+    assert!(violations[2].to_string().starts_with("3:57: "));
+}
+
+#[test]
+fn check_turtle_ignores_comments_and_iris() {
+    let content = r#"@prefix ex: <http://example.org/> .
+# a comment mentioning "\uD800" and @en-toolongsubtag
+ex:s ex:p """long
+string with a "quote" and é""" ;
+    ex:q "short"@en-GB .
+"#;
+    assert!(check(Format::Turtle, content).is_empty());
+    assert_eq!(
+        check(Format::Turtle, &content.replace("@en-GB", "@en-GB-")).len(),
+        1
+    );
+}
+
+#[test]
+fn check_xml() {
+    let content = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/">
+  <rdf:Description rdf:about="http://example.org/s">
+    <ex:p xml:lang="en-US">caf&#xE9;</ex:p>
+  </rdf:Description>
+</rdf:RDF>
+"#;
+    assert!(check(Format::Xml, content).is_empty());
+
+    let invalid = content.replace("&#xE9;", "&#x1;").replace("en-US", "en_US");
+    let violations = check(Format::Xml, &invalid);
+    assert_eq!(violations.len(), 2);
+    assert!(violations.iter().all(|violation| violation.line() == 5));
+}
+
+#[test]
+fn check_json() {
+    let content = r#"{
+  "http://example.org/s": {
+    "http://example.org/p": [
+      { "type": "literal", "value": "caf\u00e9", "lang": "fr" }
+    ]
+  }
+}"#;
+    assert!(check(Format::Json, content).is_empty());
+    assert_eq!(
+        check(Format::Json, &content.replace("\"fr\"", "\"f\"")).len(),
+        1
+    );
+    assert_eq!(
+        check(Format::Json, &content.replace("\\u00e9", "\\u0001")).len(),
+        1
+    );
+
+    let json_ld = r#"{ "@id": "http://example.org/s",
+  "http://example.org/p": { "@value": "one", "@language": "en-" } }"#;
+    assert_eq!(check(Format::JsonLd, json_ld).len(), 1);
+    assert_eq!(
+        check(Format::JsonLd, &json_ld.replace("en-", "en")).len(),
+        0
+    );
+}
+
+#[test]
+fn strict_n_triples() {
+    let reader = NTriplesReader::default().strict(true).lenient(true);
+    assert!(reader.is_strict());
+    assert!(reader
+        .read(&mut NT_VALID.as_bytes(), graph_factory())
+        .is_ok());
+    assert_non_conformant(
+        reader
+            .read(&mut NT_INVALID.as_bytes(), graph_factory())
+            .err()
+            .unwrap(),
+        3,
+    );
+
+    // a strict reader does not skip statements it cannot parse, even if lenient.
+    let content = format!("{}not a statement\n", NT_VALID);
+    assert!(reader
+        .read(&mut content.as_bytes(), graph_factory())
+        .is_err());
+    assert!(NTriplesReader::default()
+        .lenient(true)
+        .read(&mut content.as_bytes(), graph_factory())
+        .is_ok());
+}
+
+#[test]
+fn strict_n_quads() {
+    let content = r#"<http://example.org/s> <http://example.org/p> "bell\u0007" <http://example.org/g> .
+"#;
+    assert!(NQuadDataSetReader::default()
+        .read(&mut content.as_bytes(), data_set_factory())
+        .is_ok());
+    let reader = NQuadDataSetReader::default().strict(true);
+    assert!(reader.is_strict());
+    assert_non_conformant(
+        reader
+            .read(&mut content.as_bytes(), data_set_factory())
+            .err()
+            .unwrap(),
+        1,
+    );
+}
+
+#[test]
+fn strict_turtle() {
+    let content = r#"@prefix ex: <http://example.org/> .
+ex:s ex:p "bell\u0007" .
+"#;
+    let reader = TurtleReader::default();
+    assert!(!reader.is_strict());
+    assert!(reader
+        .read(&mut content.as_bytes(), graph_factory())
+        .is_ok());
+    let reader = reader.strict(true);
+    assert_non_conformant(
+        reader
+            .read(&mut content.as_bytes(), graph_factory())
+            .err()
+            .unwrap(),
+        1,
+    );
+
+    // a strict reader does not repair its input.
+    let undeclared = "<http://example.org/s> dc:title \"one\" .\n";
+    let reader = reader.repair(true);
+    assert!(reader
+        .read(&mut undeclared.as_bytes(), graph_factory())
+        .is_err());
+    let reader = reader.strict(false);
+    assert!(reader
+        .read(&mut undeclared.as_bytes(), graph_factory())
+        .is_ok());
+}
+
+#[test]
+fn strict_trig() {
+    let content = r#"@prefix ex: <http://example.org/> .
+ex:g { ex:s ex:p "\U00110000" . }
+"#;
+    let reader = TriGReader::default().strict(true);
+    assert_non_conformant(
+        reader
+            .read(&mut content.as_bytes(), data_set_factory())
+            .err()
+            .unwrap(),
+        1,
+    );
+}
+
+#[test]
+fn strict_xml() {
+    let content = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/">
+  <rdf:Description rdf:about="http://example.org/s">
+    <ex:p xml:lang="en-toolongsubtag">one</ex:p>
+  </rdf:Description>
+</rdf:RDF>
+"#;
+    let reader = XmlReader::default().strict(true);
+    assert!(reader.is_strict());
+    assert_non_conformant(
+        reader
+            .read(&mut content.as_bytes(), graph_factory())
+            .err()
+            .unwrap(),
+        1,
+    );
+    let valid = content.replace("en-toolongsubtag", "en");
+    let graph = reader.read(&mut valid.as_bytes(), graph_factory()).unwrap();
+    assert_eq!(graph.borrow().len(), 1);
+}
+
+#[test]
+fn strict_json() {
+    let content = r#"{
+  "http://example.org/s": {
+    "http://example.org/p": [
+      { "type": "literal", "value": "one", "lang": "en-toolongsubtag" }
+    ]
+  }
+}"#;
+    let reader = JsonReader::default().strict(true);
+    assert!(reader.is_strict());
+    assert_non_conformant(
+        reader
+            .read(&mut content.as_bytes(), graph_factory())
+            .err()
+            .unwrap(),
+        1,
+    );
+    let valid = content.replace("en-toolongsubtag", "en");
+    let graph = reader.read(&mut valid.as_bytes(), graph_factory()).unwrap();
+    assert_eq!(graph.borrow().len(), 1);
+
+    let json_ld = r#"{ "@id": "http://example.org/s",
+  "http://example.org/p": { "@value": "one", "@language": "en-toolongsubtag" } }"#;
+    assert_non_conformant(
+        JsonLdReader::default()
+            .strict(true)
+            .read(&mut json_ld.as_bytes(), graph_factory())
+            .err()
+            .unwrap(),
+        1,
+    );
+}
@@ -0,0 +1,123 @@
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::iri;
+use rdftk_io::dereference::{DereferenceChecker, Fetcher, Method, Problem, Response};
+use rdftk_iri::IRIRef;
+use std::cell::RefCell;
+
+struct MockFetcher {
+    requests: RefCell<Vec<(Method, String, String)>>,
+}
+
+impl Fetcher for MockFetcher {
+    fn fetch(&self, method: Method, iri: &IRIRef, accept: &str) -> Result<Response> {
+        self.requests
+            .borrow_mut()
+            .push((method, iri.to_string(), accept.to_string()));
+        match iri.to_string().as_str() {
+            "http://example.org/ok" => Ok(Response::new(200, Some(accept))),
+            "http://example.org/vocab" => Ok(Response::new(200, Some(accept))),
+            "http://example.org/html" => Ok(Response::new(200, Some("text/html; charset=utf-8"))),
+            "http://example.org/no-head" => match method {
+                Method::Head => Ok(Response::new(405, None)),
+                Method::Get => Ok(Response::new(200, Some(&format!("{};q=1", accept)))),
+            },
+            "http://example.org/gone" => Ok(Response::new(410, None)),
+            _ => Err(ErrorKind::ReadWrite("connection refused".to_string()).into()),
+        }
+    }
+}
+
+fn checker() -> DereferenceChecker<MockFetcher> {
+    DereferenceChecker::new(MockFetcher {
+        requests: Default::default(),
+    })
+    .with_media_types(&["text/turtle", "application/rdf+xml"])
+}
+
+#[test]
+fn check_dereferenceable_iris() {
+    let checker = checker();
+    let report = checker.check_iris(vec![
+        iri("http://example.org/ok"),
+        iri("http://example.org/vocab#A"),
+        iri("http://example.org/vocab#B"),
+        iri("http://example.org/no-head"),
+    ]);
+    println!("{}", report);
+
+    assert!(report.is_ok());
+    assert_eq!(report.checked(), 3);
+}
+
+#[test]
+fn check_problem_iris() {
+    let checker = checker();
+    let report = checker.check_iris(vec![
+        iri("http://example.org/html"),
+        iri("http://example.org/gone"),
+        iri("http://example.org/down"),
+    ]);
+    println!("{}", report);
+
+    assert!(!report.is_ok());
+    assert_eq!(report.checked(), 3);
+    assert_eq!(report.problems().count(), 4);
+
+    let html: Vec<&Problem> = report
+        .problems_for(&iri("http://example.org/html"))
+        .collect();
+    assert_eq!(
+        html,
+        vec![
+            &Problem::NotNegotiated {
+                requested: "text/turtle".to_string(),
+                received: Some("text/html; charset=utf-8".to_string()),
+            },
+            &Problem::NotNegotiated {
+                requested: "application/rdf+xml".to_string(),
+                received: Some("text/html; charset=utf-8".to_string()),
+            },
+        ]
+    );
+    assert_eq!(
+        report
+            .problems_for(&iri("http://example.org/gone"))
+            .collect::<Vec<&Problem>>(),
+        vec![&Problem::Status(410)]
+    );
+    assert!(matches!(
+        report.problems_for(&iri("http://example.org/down")).next(),
+        Some(Problem::Unreachable(_))
+    ));
+}
+
+#[test]
+fn check_graph_namespace() {
+    let factory = statement_factory();
+    let graph = graph_factory().graph_from(
+        &[
+            factory
+                .statement(
+                    factory.named_subject(iri("http://example.org/ok")),
+                    iri("http://example.org/vocab#p"),
+                    factory.named_object(iri("http://other.example.com/down")),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    factory.named_subject(iri("http://other.example.com/down")),
+                    iri("http://example.org/vocab#p"),
+                    factory.named_object(iri("http://example.org/ok")),
+                )
+                .unwrap(),
+        ],
+        None,
+    );
+    let checker = checker();
+    let report = checker.check(&graph, &iri("http://example.org/"));
+
+    assert!(report.is_ok());
+    assert_eq!(report.checked(), 1);
+}
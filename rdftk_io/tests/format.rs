@@ -0,0 +1,148 @@
+#![cfg(all(
+    feature = "json",
+    feature = "json-ld",
+    feature = "nq",
+    feature = "nt",
+    feature = "trig",
+    feature = "turtle",
+    feature = "xml"
+))]
+
+use rdftk_core::error::ErrorKind;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::format::{
+    reader_for, reader_for_extension, writer_for, writer_for_extension, Format, ALL_FORMATS,
+};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+mod common;
+
+#[test]
+fn lookup_by_media_type() {
+    assert_eq!(Format::from_media_type("text/turtle"), Some(Format::Turtle));
+    assert_eq!(
+        Format::from_media_type(" Application/N-Triples ; charset=utf-8"),
+        Some(Format::NTriples)
+    );
+    assert_eq!(
+        Format::from_media_type("application/x-trig"),
+        Some(Format::TriG)
+    );
+    assert_eq!(Format::from_media_type("text/html"), None);
+
+    assert_eq!(reader_for("application/ld+json"), Some(Format::JsonLd));
+    assert_eq!(writer_for("application/n-quads"), Some(Format::NQuads));
+    assert_eq!(reader_for("text/vnd.graphviz"), None);
+}
+
+#[test]
+fn lookup_by_extension() {
+    assert_eq!(Format::from_extension("ttl"), Some(Format::Turtle));
+    assert_eq!(Format::from_extension(".TTL"), Some(Format::Turtle));
+    assert_eq!(Format::from_extension("owl"), Some(Format::Xml));
+    assert_eq!(Format::from_extension("txt"), None);
+    assert_eq!(Format::from_path("dump.nt.gz"), Some(Format::NTriples));
+    assert_eq!(
+        Format::from_path("data/people.jsonld"),
+        Some(Format::JsonLd)
+    );
+    assert_eq!(Format::from_path("README"), None);
+
+    assert_eq!(reader_for_extension("nq"), Some(Format::NQuads));
+    assert_eq!(writer_for_extension("rdf"), Some(Format::Xml));
+}
+
+#[test]
+fn module_constants() {
+    assert_eq!(Format::NTriples.name(), rdftk_io::nt::NAME);
+    assert_eq!(Format::NTriples.mime_type(), rdftk_io::nt::MIME_TYPE);
+    assert_eq!(
+        Format::NTriples.file_extension(),
+        rdftk_io::nt::FILE_EXTENSION
+    );
+    assert_eq!(Format::NQuads.mime_type(), rdftk_io::nq::MIME_TYPE);
+    assert_eq!(
+        Format::NQuads.file_extension(),
+        rdftk_io::nq::FILE_EXTENSION
+    );
+    assert_eq!(Format::Turtle.mime_type(), rdftk_io::turtle::MIME_TYPE);
+    assert_eq!(
+        Format::Turtle.file_extension(),
+        rdftk_io::turtle::FILE_EXTENSION
+    );
+    assert_eq!(Format::TriG.mime_type(), rdftk_io::trig::MIME_TYPE);
+    assert_eq!(
+        Format::TriG.file_extension(),
+        rdftk_io::trig::FILE_EXTENSION
+    );
+    assert_eq!(Format::Xml.mime_type(), rdftk_io::xml::MIME_TYPE);
+    assert_eq!(Format::Xml.file_extension(), rdftk_io::xml::FILE_EXTENSION);
+    assert_eq!(Format::Json.mime_type(), rdftk_io::json::MIME_TYPE);
+    assert_eq!(
+        Format::Json.file_extension(),
+        rdftk_io::json::FILE_EXTENSION
+    );
+    assert_eq!(Format::JsonLd.mime_type(), rdftk_io::json_ld::MIME_TYPE);
+    assert_eq!(
+        Format::JsonLd.file_extension(),
+        rdftk_io::json_ld::FILE_EXTENSION
+    );
+    assert_eq!(ALL_FORMATS.len(), 8);
+}
+
+#[test]
+fn read_and_write_through_formats() {
+    let graph = common::tony_benn_graph();
+    for format in ALL_FORMATS.iter().filter(|format| format.writes_graph()) {
+        let mut output: Vec<u8> = Default::default();
+        format.write_graph(&mut output, &graph).unwrap();
+        assert!(!output.is_empty(), "no output for {}", format);
+    }
+
+    let mut output: Vec<u8> = Default::default();
+    Format::NTriples.write_graph(&mut output, &graph).unwrap();
+    let read = Format::NTriples
+        .read_graph(&mut output.as_slice(), graph_factory())
+        .unwrap();
+    assert_eq!(read.borrow().len(), graph.borrow().len());
+
+    let data_set = Format::NQuads
+        .read_data_set(&mut output.as_slice(), data_set_factory())
+        .unwrap();
+    assert_eq!(data_set.borrow().default_graph().unwrap().borrow().len(), 5);
+
+    let error = Format::TriG.write_graph(&mut output, &graph).unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ErrorKind::OperationNotSupported(_, representation) if representation == "TriG"
+    ));
+    assert!(Format::Turtle
+        .read_data_set(&mut output.as_slice(), data_set_factory())
+        .is_err());
+}
+
+#[test]
+fn read_with_base() {
+    let base = IRIRef::from(IRI::from_str("http://example.org/base/").unwrap());
+    let content = "<a> <b> <c> .";
+
+    assert!(Format::Turtle
+        .read_graph(&mut content.as_bytes(), graph_factory())
+        .is_err());
+    let graph = Format::Turtle
+        .read_graph_with_base(&mut content.as_bytes(), graph_factory(), &base)
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 1);
+
+    let data_set = Format::TriG
+        .read_data_set_with_base(&mut content.as_bytes(), data_set_factory(), &base)
+        .unwrap();
+    assert_eq!(data_set.borrow().default_graph().unwrap().borrow().len(), 1);
+
+    // N-Triples only allows absolute IRIs, the base is not used.
+    assert!(Format::NTriples
+        .read_graph_with_base(&mut content.as_bytes(), graph_factory(), &base)
+        .is_err());
+}
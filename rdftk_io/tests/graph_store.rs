@@ -0,0 +1,171 @@
+#![cfg(feature = "graph-store")]
+
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_fixtures::terms::iri;
+use rdftk_io::format::{Format, ALL_FORMATS};
+use rdftk_io::graph_store::{GraphStoreClient, Method, Request, Transport};
+use rdftk_io::sparql_client::Response;
+use rdftk_iri::IRIRef;
+use std::cell::RefCell;
+
+const TURTLE: &str = r#"@prefix ex: <http://example.org/> .
+ex:alice ex:knows ex:bob .
+ex:bob ex:knows ex:carol .
+"#;
+
+struct MockTransport {
+    requests: RefCell<Vec<Request>>,
+    response: Response,
+}
+
+impl Transport for MockTransport {
+    fn send(&self, _: &IRIRef, request: &Request) -> Result<Response> {
+        self.requests.borrow_mut().push(request.clone());
+        Ok(self.response.clone())
+    }
+}
+
+fn client(status: u16, content_type: Option<&str>, body: &str) -> GraphStoreClient<MockTransport> {
+    GraphStoreClient::new(
+        iri("http://example.org/store"),
+        MockTransport {
+            requests: Default::default(),
+            response: Response::new(status, content_type, body.as_bytes().to_vec()),
+        },
+    )
+}
+
+fn client_requests(client: &GraphStoreClient<MockTransport>) -> Vec<Request> {
+    client.transport().requests.borrow().clone()
+}
+
+#[test]
+fn get_graph() {
+    let client = client(200, Some("text/turtle; charset=utf-8"), TURTLE);
+    let people = iri("http://example.org/people?version=2");
+    let graph = client.get(Some(&people)).unwrap();
+    assert_eq!(graph.borrow().len(), 2);
+
+    let requests = client_requests(&client);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method(), Method::Get);
+    assert_eq!(requests[0].graph(), Some(&people));
+    assert_eq!(
+        requests[0].query_string(),
+        "graph=http%3A%2F%2Fexample.org%2Fpeople%3Fversion%3D2"
+    );
+    for format in ALL_FORMATS.iter().filter(|format| format.reads_graph()) {
+        assert!(requests[0].accept().unwrap().contains(format.mime_type()));
+    }
+    assert!(requests[0].content_type().is_none());
+    assert!(requests[0].body().is_empty());
+}
+
+#[test]
+fn get_default_graph() {
+    let client = client(200, Some("application/n-triples"), "");
+    assert!(client.get(None).unwrap().borrow().is_empty());
+    assert_eq!(client_requests(&client)[0].query_string(), "default");
+}
+
+#[test]
+fn get_unsupported_content_type() {
+    let html = client(200, Some("text/html"), "<html></html>");
+    assert!(html.get(None).is_err());
+    let missing = client(200, None, TURTLE);
+    assert!(missing.get(None).is_err());
+}
+
+#[test]
+fn put_graph() {
+    let source = client(200, Some("text/turtle"), TURTLE);
+    let graph = source.get(None).unwrap();
+
+    let client = client(201, None, "");
+    let people = iri("http://example.org/people");
+    client.put(Some(&people), &graph).unwrap();
+
+    let requests = client_requests(&client);
+    assert_eq!(requests[0].method(), Method::Put);
+    assert_eq!(requests[0].content_type().unwrap(), "text/turtle");
+    let read = Format::Turtle
+        .read_graph(&mut requests[0].body().as_slice(), graph_factory())
+        .unwrap();
+    assert_eq!(read.borrow().len(), 2);
+}
+
+#[test]
+fn post_graph_with_format() {
+    let source = client(200, Some("text/turtle"), TURTLE);
+    let graph = source.get(None).unwrap();
+
+    let client = client(204, None, "").with_format(Format::NTriples).unwrap();
+    assert_eq!(client.format(), Format::NTriples);
+    client.post(None, &graph).unwrap();
+
+    let requests = client_requests(&client);
+    assert_eq!(requests[0].method(), Method::Post);
+    assert_eq!(requests[0].query_string(), "default");
+    assert_eq!(requests[0].content_type().unwrap(), "application/n-triples");
+    let body = String::from_utf8(requests[0].body().clone()).unwrap();
+    assert_eq!(body.lines().count(), 2);
+}
+
+#[test]
+fn reject_unwritable_format() {
+    let result = client(200, None, "").with_format(Format::TriG);
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::OperationNotSupported(_, _)
+    ));
+}
+
+#[test]
+fn delete_graph() {
+    let client = client(204, None, "");
+    let people = iri("http://example.org/people");
+    client.delete(Some(&people)).unwrap();
+    let requests = client_requests(&client);
+    assert_eq!(requests[0].method(), Method::Delete);
+    assert!(requests[0].accept().is_none());
+}
+
+#[test]
+fn unknown_graph() {
+    let client = client(404, Some("text/plain"), "Not Found");
+    let people = iri("http://example.org/people");
+    let result = client.get(Some(&people));
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::UnknownGraph(name) if name == "http://example.org/people"
+    ));
+    assert!(matches!(
+        client.delete(None).err().unwrap().kind(),
+        ErrorKind::UnknownGraph(name) if name == "default"
+    ));
+}
+
+#[test]
+fn unsuccessful_response() {
+    let client = client(500, Some("text/plain"), "Internal Server Error");
+    assert!(matches!(
+        client.delete(None).err().unwrap().kind(),
+        ErrorKind::ReadWrite(_)
+    ));
+}
+
+#[test]
+fn closure_transport() {
+    let transport = |endpoint: &IRIRef, request: &Request| -> Result<Response> {
+        assert_eq!(endpoint.to_string(), "http://example.org/store");
+        assert_eq!(request.method(), Method::Get);
+        Ok(Response::new(
+            200,
+            Some("text/turtle"),
+            TURTLE.as_bytes().to_vec(),
+        ))
+    };
+    let client = GraphStoreClient::new(iri("http://example.org/store"), transport);
+    assert_eq!(client.get(None).unwrap().borrow().len(), 2);
+}
@@ -0,0 +1,200 @@
+#![cfg(all(
+    feature = "http",
+    feature = "loader",
+    feature = "graph-store",
+    feature = "turtle"
+))]
+
+use rdftk_core::error::ErrorKind;
+use rdftk_fixtures::terms::{graph_from_triples, iri, subject};
+use rdftk_io::dereference::{DereferenceChecker, Problem};
+use rdftk_io::graph_store::GraphStoreClient;
+use rdftk_io::http::HttpTransport;
+use rdftk_io::loader::Loader;
+use rdftk_io::sparql_client::SparqlClient;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread::spawn;
+use tiny_http::{Header, Method, Response, Server};
+
+const ALICE: &[u8] = b"<../id/alice> <http://xmlns.com/foaf/0.1/name> \"Alice\" .";
+
+///
+/// The method, URL, `Accept` header, `Content-Type` header, and body of a request received.
+///
+type Received = (String, String, Option<String>, Option<String>, Vec<u8>);
+
+///
+/// Start a server on a free local port, serving linked data under `/id` and `/doc`, a SPARQL
+/// endpoint at `/sparql`, and an in-memory graph store at `/store`; returning the base URL of the
+/// server and the requests it receives.
+///
+fn serve() -> (String, Arc<Mutex<Vec<Received>>>) {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let base = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let received: Arc<Mutex<Vec<Received>>> = Default::default();
+    let log = received.clone();
+    let _ = spawn(move || {
+        let mut store: HashMap<String, (String, Vec<u8>)> = Default::default();
+        for mut request in server.incoming_requests() {
+            let header = |name: &str| {
+                request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.as_str().as_str().eq_ignore_ascii_case(name))
+                    .map(|header| header.value.to_string())
+            };
+            let accept = header("Accept");
+            let content_type = header("Content-Type");
+            let mut body = Vec::new();
+            let _ = request.as_reader().read_to_end(&mut body).unwrap();
+            let method = request.method().clone();
+            let url = request.url().to_string();
+            log.lock().unwrap().push((
+                method.to_string(),
+                url.clone(),
+                accept,
+                content_type.clone(),
+                body.clone(),
+            ));
+
+            let response = match (&method, url.as_str()) {
+                (_, "/id/alice") => Response::from_data(Vec::new())
+                    .with_status_code(303)
+                    .with_header(Header::from_bytes("Location", "/doc/alice").unwrap()),
+                (_, "/doc/alice") => Response::from_data(ALICE).with_header(
+                    Header::from_bytes("Content-Type", "text/turtle; charset=utf-8").unwrap(),
+                ),
+                (Method::Post, "/sparql") => {
+                    Response::from_data(&br#"{ "head": {}, "boolean": true }"#[..]).with_header(
+                        Header::from_bytes("Content-Type", "application/sparql-results+json")
+                            .unwrap(),
+                    )
+                }
+                (_, url) if url.starts_with("/store?") => match method {
+                    Method::Get => match store.get(url) {
+                        Some((content_type, body)) => Response::from_data(body.clone())
+                            .with_header(
+                                Header::from_bytes("Content-Type", &content_type[..]).unwrap(),
+                            ),
+                        None => Response::from_data(Vec::new()).with_status_code(404),
+                    },
+                    Method::Put => {
+                        let _ = store.insert(url.to_string(), (content_type.unwrap(), body));
+                        Response::from_data(Vec::new()).with_status_code(201)
+                    }
+                    Method::Delete => match store.remove(url) {
+                        Some(_) => Response::from_data(Vec::new()).with_status_code(204),
+                        None => Response::from_data(Vec::new()).with_status_code(404),
+                    },
+                    _ => Response::from_data(Vec::new()).with_status_code(405),
+                },
+                _ => Response::from_data(Vec::new()).with_status_code(404),
+            };
+            request.respond(response).unwrap();
+        }
+    });
+    (base, received)
+}
+
+#[test]
+fn http_dereference_checker() {
+    let (base, _) = serve();
+    let checker =
+        DereferenceChecker::new(HttpTransport::default()).with_media_types(&["text/turtle"]);
+    let alice = iri(&format!("{}/id/alice", base));
+    let bob = iri(&format!("{}/id/bob", base));
+    let report = checker.check_iris(vec![alice.clone(), bob.clone()]);
+    assert_eq!(report.checked(), 2);
+    assert!(report.problems_for(&alice).next().is_none());
+    assert_eq!(
+        report.problems_for(&bob).collect::<Vec<&Problem>>(),
+        vec![&Problem::Status(404)]
+    );
+}
+
+#[test]
+fn http_sparql_client_posts_query() {
+    let (base, received) = serve();
+    let client = SparqlClient::new(iri(&format!("{}/sparql", base)), HttpTransport::default());
+    assert!(client.ask("ASK { ?s ?p ?o }").unwrap());
+
+    let received = received.lock().unwrap();
+    let (method, _, accept, content_type, body) = &received[0];
+    assert_eq!(method, "POST");
+    assert!(accept
+        .as_ref()
+        .unwrap()
+        .contains("application/sparql-results+json"));
+    assert_eq!(content_type.as_deref(), Some("application/sparql-query"));
+    assert_eq!(body, b"ASK { ?s ?p ?o }");
+}
+
+#[test]
+fn http_graph_store_round_trip() {
+    let (base, _) = serve();
+    let client = GraphStoreClient::new(iri(&format!("{}/store", base)), HttpTransport::default());
+    let people = iri("http://example.org/people");
+    assert!(matches!(
+        client.get(Some(&people)).unwrap_err().kind(),
+        ErrorKind::UnknownGraph(_)
+    ));
+
+    let graph = graph_from_triples(&[(
+        "http://example.org/alice",
+        "http://xmlns.com/foaf/0.1/name",
+        "\"Alice",
+    )]);
+    client.put(Some(&people), &graph).unwrap();
+    let stored = client.get(Some(&people)).unwrap();
+    assert_eq!(stored.borrow().len(), 1);
+
+    client.delete(Some(&people)).unwrap();
+    assert!(client.get(Some(&people)).is_err());
+}
+
+#[test]
+fn http_loader_follows_redirects() {
+    let (base, received) = serve();
+    let loader = Loader::new(HttpTransport::default());
+    let graph = loader.load(&iri(&format!("{}/id/alice#me", base))).unwrap();
+    let graph = graph.borrow();
+    assert_eq!(graph.len(), 1);
+    assert!(graph.contains_subject(&subject(&format!("{}/id/alice", base))));
+
+    // the loader, not the transport, followed the redirect.
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[0].1, "/id/alice");
+    assert_eq!(received[1].1, "/doc/alice");
+    assert!(received[1].2.as_ref().unwrap().contains("text/turtle"));
+}
+
+#[test]
+fn http_loader_limits_size() {
+    let (base, _) = serve();
+    let loader = Loader::new(HttpTransport::default()).with_max_size(10);
+    let result = loader.load(&iri(&format!("{}/doc/alice", base)));
+    assert!(matches!(
+        result.unwrap_err().kind(),
+        ErrorKind::LimitExceeded(_, _)
+    ));
+}
+
+#[test]
+fn http_unreachable() {
+    // a port that was free, and so will refuse the connection.
+    let port = {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.local_addr().unwrap().port()
+    };
+    let unreachable = iri(&format!("http://127.0.0.1:{}/id/alice", port));
+    let report =
+        DereferenceChecker::new(HttpTransport::default()).check_iris(vec![unreachable.clone()]);
+    assert!(matches!(
+        report.problems_for(&unreachable).next(),
+        Some(Problem::Unreachable(_))
+    ));
+    let loader = Loader::new(HttpTransport::default());
+    assert!(loader.load(&unreachable).is_err());
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "loader")]
+
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_fixtures::terms::{iri, subject};
+use rdftk_io::format::{Format, ALL_FORMATS};
+use rdftk_io::loader::{
+    Loader, Request, Response, Transport, DEFAULT_MAX_REDIRECTS, DEFAULT_MAX_SIZE, DEFAULT_TIMEOUT,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::thread::sleep;
+use std::time::Duration;
+
+const TURTLE: &str = r#"@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+<#me> foaf:name "Alice" ; foaf:knows <../bob#me> .
+"#;
+
+#[derive(Default)]
+struct MockTransport {
+    responses: HashMap<String, Response>,
+    requests: RefCell<Vec<Request>>,
+}
+
+impl MockTransport {
+    fn with(mut self, iri: &str, response: Response) -> Self {
+        let _ = self.responses.insert(iri.to_string(), response);
+        self
+    }
+}
+
+impl Transport for MockTransport {
+    fn get(&self, request: &Request) -> Result<Response> {
+        self.requests.borrow_mut().push(request.clone());
+        Ok(self
+            .responses
+            .get(&request.iri().to_string())
+            .cloned()
+            .unwrap_or_else(|| Response::new(404, Some("text/plain"), b"Not Found".to_vec())))
+    }
+}
+
+fn people() -> MockTransport {
+    MockTransport::default()
+        .with(
+            "http://example.org/id/alice",
+            Response::new(303, None, Vec::new()).with_location("../people/alice"),
+        )
+        .with(
+            "http://example.org/people/alice",
+            Response::new(
+                200,
+                Some("text/turtle; charset=utf-8"),
+                TURTLE.as_bytes().to_vec(),
+            ),
+        )
+}
+
+#[test]
+fn load_with_redirect() {
+    let loader = Loader::new(people());
+    assert_eq!(loader.max_size(), DEFAULT_MAX_SIZE);
+    assert_eq!(loader.timeout(), DEFAULT_TIMEOUT);
+    assert_eq!(loader.max_redirects(), DEFAULT_MAX_REDIRECTS);
+
+    let graph = loader.load(&iri("http://example.org/id/alice#me")).unwrap();
+    let graph = graph.borrow();
+    assert_eq!(graph.len(), 2);
+    // relative IRIs are resolved against the final location.
+    assert!(graph.contains_subject(&subject("http://example.org/people/alice#me")));
+    let knows = iri("http://xmlns.com/foaf/0.1/knows");
+    let known: Vec<String> = graph
+        .objects_for(&subject("http://example.org/people/alice#me"), &knows)
+        .into_iter()
+        .map(|object| object.as_iri().unwrap().to_string())
+        .collect();
+    assert_eq!(known, vec!["http://example.org/bob#me"]);
+
+    let requests = loader.transport().requests.borrow();
+    let iris: Vec<String> = requests
+        .iter()
+        .map(|request| request.iri().to_string())
+        .collect();
+    assert_eq!(
+        iris,
+        vec![
+            "http://example.org/id/alice",
+            "http://example.org/people/alice"
+        ]
+    );
+    for format in ALL_FORMATS.iter().filter(|format| format.reads_graph()) {
+        assert!(requests[0].accept().contains(format.mime_type()));
+    }
+    assert_eq!(requests[0].max_size(), DEFAULT_MAX_SIZE);
+    assert!(requests[1].timeout() <= DEFAULT_TIMEOUT);
+}
+
+#[test]
+fn load_negotiated_format() {
+    let transport = MockTransport::default().with(
+        "http://example.org/alice",
+        Response::new(
+            200,
+            Some(Format::NTriples.mime_type()),
+            b"<http://example.org/alice> <http://xmlns.com/foaf/0.1/name> \"Alice\" .\n".to_vec(),
+        ),
+    );
+    let graph = Loader::new(transport)
+        .load(&iri("http://example.org/alice"))
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 1);
+}
+
+#[test]
+fn too_many_redirects() {
+    let transport = MockTransport::default()
+        .with(
+            "http://example.org/a",
+            Response::new(302, None, Vec::new()).with_location("http://example.org/b"),
+        )
+        .with(
+            "http://example.org/b",
+            Response::new(301, None, Vec::new()).with_location("/a"),
+        );
+    let loader = Loader::new(transport).with_max_redirects(3);
+    let result = loader.load(&iri("http://example.org/a"));
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::LimitExceeded(name, limit) if name == "redirect" && limit == "3"
+    ));
+    assert_eq!(loader.transport().requests.borrow().len(), 4);
+}
+
+#[test]
+fn response_too_large() {
+    let loader = Loader::new(people()).with_max_size(16);
+    let result = loader.load(&iri("http://example.org/id/alice"));
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::LimitExceeded(name, _) if name == "size"
+    ));
+    assert_eq!(loader.transport().requests.borrow()[1].max_size(), 16);
+}
+
+#[test]
+fn response_too_slow() {
+    let transport = |request: &Request| -> Result<Response> {
+        sleep(Duration::from_millis(20));
+        Ok(Response::new(303, None, Vec::new()).with_location(&format!("{}/next", request.iri())))
+    };
+    let loader = Loader::new(transport).with_timeout(Duration::from_millis(50));
+    let result = loader.load(&iri("http://example.org/slow"));
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::LimitExceeded(name, _) if name == "time"
+    ));
+}
+
+#[test]
+fn unsuccessful_response() {
+    let loader = Loader::new(people());
+    assert!(matches!(
+        loader
+            .load(&iri("http://example.org/id/bob"))
+            .err()
+            .unwrap()
+            .kind(),
+        ErrorKind::ReadWrite(_)
+    ));
+
+    let transport = MockTransport::default()
+        .with(
+            "http://example.org/page",
+            Response::new(200, Some("text/html"), b"<html></html>".to_vec()),
+        )
+        .with(
+            "http://example.org/lost",
+            Response::new(303, None, Vec::new()),
+        );
+    let loader = Loader::new(transport);
+    assert!(loader.load(&iri("http://example.org/page")).is_err());
+    assert!(loader.load(&iri("http://example.org/lost")).is_err());
+}
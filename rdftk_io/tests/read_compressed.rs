@@ -0,0 +1,146 @@
+#![cfg(feature = "nt")]
+
+use rdftk_core::simple::graph_factory;
+use rdftk_io::compression::Compression;
+use rdftk_io::nt::reader::NTriplesReader;
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::{
+    read_graph_from_path, read_graph_from_path_with, write_graph_to_path, write_graph_to_string,
+};
+use std::path::{Path, PathBuf};
+
+mod common;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rdftk_io-read-{}-{}", std::process::id(), name))
+}
+
+fn sorted_lines(s: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = s.lines().collect();
+    lines.sort_unstable();
+    lines
+}
+
+fn assert_reads_back(path: &Path) {
+    let expected =
+        write_graph_to_string(&NTripleWriter::default(), &common::tony_benn_graph()).unwrap();
+    let graph = read_graph_from_path(&NTriplesReader::default(), path, graph_factory()).unwrap();
+    std::fs::remove_file(path).unwrap();
+    let read = write_graph_to_string(&NTripleWriter::default(), &graph).unwrap();
+    assert_eq!(sorted_lines(&read), sorted_lines(&expected));
+}
+
+#[test]
+fn compression_from_signature() {
+    assert_eq!(
+        Compression::from_signature(&[0x1f, 0x8b, 0x08, 0x00]),
+        Some(Compression::Gzip)
+    );
+    assert_eq!(
+        Compression::from_signature(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]),
+        Some(Compression::Xz)
+    );
+    assert_eq!(
+        Compression::from_signature(b"BZh91AY&SY"),
+        Some(Compression::Bzip2)
+    );
+    assert_eq!(
+        Compression::from_signature(&[0x28, 0xb5, 0x2f, 0xfd]),
+        Some(Compression::Zstd)
+    );
+    assert_eq!(Compression::from_signature(b"<http://example.org/>"), None);
+    assert_eq!(Compression::from_signature(&[]), None);
+}
+
+#[test]
+fn compression_from_new_extensions() {
+    assert_eq!(
+        Compression::from_path(Path::new("dump.ttl.bz2")),
+        Compression::Bzip2
+    );
+    assert_eq!(
+        Compression::from_path(Path::new("dump.nt.zst")),
+        Compression::Zstd
+    );
+    assert_eq!(Compression::Zstd.file_extension(), Some("zst"));
+}
+
+#[test]
+fn read_uncompressed_from_path() {
+    let path = temp_path("plain.nt");
+    write_graph_to_path(&NTripleWriter::default(), &common::tony_benn_graph(), &path).unwrap();
+    assert_reads_back(&path);
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn read_gzip_from_path() {
+    let path = temp_path("graph.nt.gz");
+    write_graph_to_path(&NTripleWriter::default(), &common::tony_benn_graph(), &path).unwrap();
+    assert_reads_back(&path);
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn read_gzip_by_signature() {
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+
+    // the extension does not indicate compression, the signature does.
+    let path = temp_path("unlabeled.nt");
+    let content =
+        write_graph_to_string(&NTripleWriter::default(), &common::tony_benn_graph()).unwrap();
+    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(content.as_bytes()).unwrap();
+    std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+    assert_reads_back(&path);
+}
+
+#[test]
+#[cfg(feature = "bzip2")]
+fn read_bzip2_from_path() {
+    let path = temp_path("graph.nt.bz2");
+    write_graph_to_path(&NTripleWriter::default(), &common::tony_benn_graph(), &path).unwrap();
+    assert_reads_back(&path);
+}
+
+#[test]
+#[cfg(feature = "zstd")]
+fn read_zstd_from_path() {
+    let path = temp_path("graph.nt.zst");
+    write_graph_to_path(&NTripleWriter::default(), &common::tony_benn_graph(), &path).unwrap();
+    assert_reads_back(&path);
+}
+
+#[test]
+#[cfg(not(feature = "xz"))]
+fn read_unsupported_compression() {
+    use std::io::Write;
+    use xz2::write::XzEncoder;
+
+    let path = temp_path("unsupported.nt");
+    let mut encoder = XzEncoder::new(Vec::new(), 6);
+    encoder
+        .write_all(b"<http://example.org/a> <http://example.org/b> <http://example.org/c> .\n")
+        .unwrap();
+    std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+    let result = read_graph_from_path(&NTriplesReader::default(), &path, graph_factory());
+    std::fs::remove_file(&path).unwrap();
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_with_explicit_compression() {
+    let path = temp_path("explicit.nt");
+    write_graph_to_path(&NTripleWriter::default(), &common::tony_benn_graph(), &path).unwrap();
+    let graph = read_graph_from_path_with(
+        &NTriplesReader::default(),
+        &path,
+        graph_factory(),
+        Compression::None,
+    )
+    .unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(!graph.borrow().is_empty());
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "json-ld")]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::json_ld::reader::JsonLdReader;
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::{write_graph_to_string, GraphReader};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+fn read(reader: JsonLdReader, content: &str) -> GraphRef {
+    reader
+        .read(&mut content.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn to_ntriples(graph: &GraphRef) -> String {
+    let output = write_graph_to_string(&NTripleWriter::default(), graph).unwrap();
+    println!("# format: n-triples\n{}", output);
+    output
+}
+
+#[test]
+fn read_expanded_form() {
+    let graph = read(
+        JsonLdReader::default(),
+        r#"[
+  {
+    "@id": "http://example.org/alice",
+    "@type": ["http://xmlns.com/foaf/0.1/Person"],
+    "http://xmlns.com/foaf/0.1/name": [{ "@value": "Alice", "@language": "en" }],
+    "http://xmlns.com/foaf/0.1/age": [{ "@value": "42", "@type": "http://www.w3.org/2001/XMLSchema#integer" }],
+    "http://xmlns.com/foaf/0.1/knows": [{ "@id": "_:bob" }]
+  },
+  {
+    "@id": "_:bob",
+    "http://xmlns.com/foaf/0.1/name": [{ "@value": "Bob" }]
+  }
+]"#,
+    );
+    assert_eq!(graph.borrow().len(), 5);
+
+    let output = to_ntriples(&graph);
+    assert!(output.contains(
+        "<http://example.org/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://xmlns.com/foaf/0.1/Person> ."
+    ));
+    assert!(output
+        .contains("<http://example.org/alice> <http://xmlns.com/foaf/0.1/name> \"Alice\"@en ."));
+    assert!(output.contains("<http://xmlns.com/foaf/0.1/knows> _:bob ."));
+    assert!(output.contains("_:bob <http://xmlns.com/foaf/0.1/name> \"Bob\" ."));
+}
+
+#[test]
+fn read_compacted_form() {
+    let graph = read(
+        JsonLdReader::default(),
+        r#"{
+  "@context": {
+    "@vocab": "http://schema.org/",
+    "foaf": "http://xmlns.com/foaf/0.1/",
+    "xsd": "http://www.w3.org/2001/XMLSchema#",
+    "id": "@id",
+    "type": "@type",
+    "knows": { "@id": "foaf:knows", "@type": "@id" },
+    "born": { "@id": "birthDate", "@type": "xsd:date" },
+    "label": { "@container": "@language" },
+    "tags": { "@container": "@list" },
+    "parentOf": { "@reverse": "parent" }
+  },
+  "id": "http://example.org/alice",
+  "type": "Person",
+  "name": "Alice",
+  "age": 42,
+  "height": 1.75,
+  "member": true,
+  "born": "1990-01-01",
+  "knows": ["http://example.org/bob", "http://example.org/carol"],
+  "label": { "en": "Alice", "fr": ["Alice", "Alicia"] },
+  "tags": ["a", "b"],
+  "parentOf": { "id": "http://example.org/dave" },
+  "address": { "streetAddress": "1 Main St" },
+  "ignored": null
+}"#,
+    );
+
+    let output = to_ntriples(&graph);
+    assert!(output.contains(
+        "<http://example.org/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://schema.org/Person> ."
+    ));
+    assert!(output.contains("<http://example.org/alice> <http://schema.org/name> \"Alice\" ."));
+    assert!(output
+        .contains("<http://schema.org/age> \"42\"^^<http://www.w3.org/2001/XMLSchema#integer> ."));
+    assert!(output.contains(
+        "<http://schema.org/height> \"1.75E0\"^^<http://www.w3.org/2001/XMLSchema#double> ."
+    ));
+    assert!(output.contains(
+        "<http://schema.org/member> \"true\"^^<http://www.w3.org/2001/XMLSchema#boolean> ."
+    ));
+    assert!(output.contains(
+        "<http://schema.org/birthDate> \"1990-01-01\"^^<http://www.w3.org/2001/XMLSchema#date> ."
+    ));
+    assert!(output.contains(
+        "<http://example.org/alice> <http://xmlns.com/foaf/0.1/knows> <http://example.org/carol> ."
+    ));
+    assert!(output.contains("<http://schema.org/label> \"Alicia\"@fr ."));
+    assert!(output.contains(
+        "<http://example.org/dave> <http://schema.org/parent> <http://example.org/alice> ."
+    ));
+    assert!(output.contains("<http://schema.org/streetAddress> \"1 Main St\" ."));
+
+    // 14 direct values, 4 statements for the list, and 1 for the nested address node.
+    assert_eq!(graph.borrow().len(), 19);
+
+    let graph = graph.borrow();
+    let mappings = graph.prefix_mappings();
+    let mappings = mappings.borrow();
+    assert_eq!(
+        mappings.get_namespace("foaf").unwrap().to_string(),
+        "http://xmlns.com/foaf/0.1/"
+    );
+    assert_eq!(
+        mappings.get_namespace("").unwrap().to_string(),
+        "http://schema.org/"
+    );
+}
+
+#[test]
+fn read_top_level_graph() {
+    let graph = read(
+        JsonLdReader::with_base(IRIRef::from(
+            IRI::from_str("http://example.org/people/").unwrap(),
+        )),
+        r#"{
+  "@context": [
+    { "@vocab": "http://xmlns.com/foaf/0.1/" },
+    { "friend": { "@id": "knows", "@type": "@id" } }
+  ],
+  "@graph": [
+    { "@id": "alice", "name": "Alice", "friend": "bob" },
+    { "@id": "bob", "name": "Bob", "@context": { "@language": "en" } }
+  ]
+}"#,
+    );
+    let output = to_ntriples(&graph);
+    assert_eq!(graph.borrow().len(), 3);
+    assert!(output.contains(
+        "<http://example.org/people/alice> <http://xmlns.com/foaf/0.1/knows> <http://example.org/people/bob> ."
+    ));
+    assert!(output
+        .contains("<http://example.org/people/bob> <http://xmlns.com/foaf/0.1/name> \"Bob\"@en ."));
+}
+
+#[test]
+fn read_invalid_json_ld() {
+    let reader = JsonLdReader::default();
+    for content in &[
+        r#"{ "@context": "http://schema.org/", "name": "Alice" }"#,
+        r#"{ "@context": { "a": "b:x", "b": "a:y" }, "a": 1 }"#,
+        r#""just a string""#,
+        r#"{ "@id": "http://example.org/alice", "#,
+    ] {
+        assert!(reader
+            .read(&mut content.as_bytes(), graph_factory())
+            .is_err());
+    }
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "nq")]
+
+use rdftk_core::model::data_set::{DataSetRef, GraphName};
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_io::nq::reader::NQuadDataSetReader;
+use rdftk_io::nq::writer::NQuadDataSetWriter;
+use rdftk_io::{write_data_set_to_string, DataSetReader};
+use rdftk_iri::IRI;
+use std::str::FromStr;
+
+mod common;
+
+#[test]
+fn read_written_nquads() {
+    let graph_name = GraphName::named_ref(
+        IRI::from_str("http://en.wikipedia.org/wiki/Tony_Benn")
+            .unwrap()
+            .into(),
+    );
+    let data_set = data_set_factory().data_set(Some(common::tony_benn_graph()));
+    {
+        let mut data_set = data_set.borrow_mut();
+        data_set.insert(graph_name.clone(), common::tony_benn_graph());
+    }
+
+    let writer = NQuadDataSetWriter::default();
+    let output = write_data_set_to_string(&writer, &(data_set as DataSetRef)).unwrap();
+
+    let reader = NQuadDataSetReader::default();
+    let result = reader.read(&mut output.as_bytes(), data_set_factory());
+    assert!(result.is_ok());
+    let data_set = result.unwrap();
+    let data_set = data_set.borrow();
+
+    assert_eq!(data_set.len(), 2);
+    assert_eq!(data_set.default_graph().unwrap().borrow().len(), 5);
+    assert_eq!(data_set.graph_named(&graph_name).unwrap().borrow().len(), 5);
+}
@@ -1 +1,153 @@
+#![cfg(feature = "nt")]
 
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_io::nt::reader::NTriplesReader;
+use rdftk_io::GraphReader;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+#[test]
+fn read_statements_streaming() {
+    let nt = r###"
+<http://one.example/subject1> <http://one.example/predicate1> <http://one.example/object1> . # comments here
+# or on a line by themselves
+
+_:subject1 <http://an.example/predicate1> "object1" .
+_:subject2 <http://an.example/predicate2> "object2"@en .
+"###
+    .as_bytes();
+
+    let reader = NTriplesReader::default();
+    let statements: Vec<StatementRef> = reader
+        .read_statements(nt, statement_factory(), literal_factory())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(statements.len(), 3);
+    assert_eq!(
+        statements[0].predicate().to_string(),
+        "http://one.example/predicate1"
+    );
+    assert!(statements[1].subject().is_blank());
+    assert!(statements[2].object().is_literal());
+}
+
+#[test]
+fn read_statements_continues_after_error() {
+    let nt = r###"<http://one.example/subject1> <http://one.example/predicate1> <http://one.example/object1> .
+<http://one.example/subject1> "not a predicate" <http://one.example/object1> .
+<http://one.example/subject2> <http://one.example/predicate2> <http://one.example/object2> .
+"###
+    .as_bytes();
+
+    let reader = NTriplesReader::default();
+    let results: Vec<_> = reader
+        .read_statements(nt, statement_factory(), literal_factory())
+        .collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn read_statements_matches_graph_reader() {
+    let file_path = PathBuf::from("tests/w3c/nt/literal_with_UTF8_boundaries.nt");
+    let reader = NTriplesReader::default();
+
+    let mut file = File::open(&file_path).unwrap();
+    let graph = reader
+        .read(&mut file, rdftk_core::simple::graph_factory())
+        .unwrap();
+
+    let file = BufReader::new(File::open(&file_path).unwrap());
+    let statements: Vec<StatementRef> = reader
+        .read_statements(file, statement_factory(), literal_factory())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(statements.len(), graph.borrow().len());
+}
+
+#[test]
+fn read_quoted_triples() {
+    let nt = r###"
+<< <http://example.org/alice> <http://xmlns.com/foaf/0.1/knows> _:bob >> <http://example.org/certainty> "0.9" .
+_:claim <http://example.org/says> << << <http://example.org/a> <http://example.org/b> "c"@en >> <http://example.org/d> <http://example.org/e> >> .
+"###
+    .as_bytes();
+
+    let reader = NTriplesReader::default();
+    let statements: Vec<StatementRef> = reader
+        .read_statements(nt, statement_factory(), literal_factory())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(statements.len(), 2);
+
+    let quoted = statements[0].subject().as_statement().unwrap();
+    assert_eq!(
+        quoted.predicate().to_string(),
+        "http://xmlns.com/foaf/0.1/knows"
+    );
+    assert!(quoted.object().is_blank());
+
+    let quoted = statements[1].object().as_statement().unwrap();
+    let nested = quoted.subject().as_statement().unwrap();
+    assert!(nested.object().is_literal());
+    assert_eq!(
+        statements[1].to_string(),
+        r#"_:claim <http://example.org/says> << << <http://example.org/a> <http://example.org/b> "c"@en >> <http://example.org/d> <http://example.org/e> >>"#
+    );
+}
+
+#[test]
+fn read_with_diagnostics() {
+    let nt = r###"<http://example.org/s> <http://example.org/p> "one" .
+<http://example.org/s> <http://example.org/p> .
+# a comment
+
+<http://example.org/s> <http://example.org/p> "two" .
+  <http://example.org/s> <http://example.org/p> <http://example.org/o
+<http://example.org/s> <http://example.org/p> "three"^^<http://example.org/type> .
+"###;
+
+    let reader = NTriplesReader::default();
+    assert!(!reader.is_lenient());
+    assert!(reader
+        .read(&mut nt.as_bytes(), rdftk_core::simple::graph_factory())
+        .is_err());
+
+    let (graph, diagnostics) = reader
+        .read_with_diagnostics(&mut nt.as_bytes(), rdftk_core::simple::graph_factory())
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 3);
+    assert_eq!(diagnostics.len(), 2);
+
+    assert_eq!(diagnostics[0].line(), 2);
+    assert_eq!(diagnostics[0].column(), 47);
+    assert_eq!(
+        diagnostics[0].text(),
+        "<http://example.org/s> <http://example.org/p> ."
+    );
+    assert!(!diagnostics[0].message().is_empty());
+    assert!(diagnostics[0].to_string().starts_with("2:47: "));
+
+    assert_eq!(diagnostics[1].line(), 6);
+    assert!(diagnostics[1].column() > 47);
+}
+
+#[test]
+fn read_lenient() {
+    let nt = r###"<http://example.org/s> <http://example.org/p> "one" .
+not a statement
+<http://example.org/s> <http://example.org/p> "two" .
+"###;
+
+    let reader = NTriplesReader::default().lenient(true);
+    assert!(reader.is_lenient());
+    let graph = reader
+        .read(&mut nt.as_bytes(), rdftk_core::simple::graph_factory())
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 2);
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "turtle")]
+
+use rdftk_core::threaded::{ThreadedGraphFactory, ThreadedGraphRef};
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_names::foaf;
+use std::thread;
+
+const TURTLE: &str = r##"@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+@prefix ex: <http://example.org/> .
+
+ex:alice foaf:name "Alice" ;
+    foaf:knows ex:bob .
+ex:bob foaf:name "Bob" .
+"##;
+
+#[test]
+fn read_into_shared_graph_from_another_thread() {
+    let shared: ThreadedGraphRef = ThreadedGraphFactory::default().shared_graph();
+
+    let writer = shared.clone();
+    thread::spawn(move || {
+        let mut graph = writer.write().unwrap();
+        TurtleReader::default()
+            .read_into(&mut TURTLE.as_bytes(), &mut *graph)
+            .unwrap();
+    })
+    .join()
+    .unwrap();
+
+    let graph = shared.read().unwrap();
+    assert_eq!(graph.len(), 3);
+    assert_eq!(
+        graph
+            .statements()
+            .filter(|st| st.predicate() == foaf::name())
+            .count(),
+        2
+    );
+    assert!(graph
+        .prefix_mappings()
+        .borrow()
+        .get_namespace("ex")
+        .is_some());
+}
+
+#[test]
+fn read_into_shared_graph_from_many_threads() {
+    let shared: ThreadedGraphRef = ThreadedGraphFactory::default().shared_graph();
+
+    let readers: Vec<_> = (0..4)
+        .map(|i| {
+            let shared = shared.clone();
+            thread::spawn(move || {
+                let source = TURTLE
+                    .replace("ex:alice", &format!("ex:alice-{}", i))
+                    .replace("ex:bob", &format!("ex:bob-{}", i));
+                TurtleReader::default()
+                    .read_into(&mut source.as_bytes(), &mut *shared.write().unwrap())
+                    .unwrap();
+            })
+        })
+        .collect();
+    for reader in readers {
+        reader.join().unwrap();
+    }
+
+    assert_eq!(shared.read().unwrap().len(), 4 * 3);
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "trig")]
+
+use rdftk_core::model::data_set::{DataSetRef, GraphName};
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_io::trig::reader::TriGReader;
+use rdftk_io::trig::writer::TriGWriter;
+use rdftk_io::turtle::writer::TurtleOptions;
+use rdftk_io::{write_data_set_to_string, DataSetReader};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+mod common;
+
+#[test]
+fn read_spec_example() {
+    let reader = TriGReader::default();
+    let result = reader.read(
+        &mut r###"@prefix ex: <http://www.example.org/vocabulary#> .
+@prefix : <http://www.example.org/exampleDocument#> .
+
+:G1 { :Monica a ex:Person ;
+              ex:name "Monica Murphy" ;
+              ex:homepage <http://www.monicamurphy.org> ;
+              ex:email <mailto:monica@monicamurphy.org> ;
+              ex:hasSkill ex:Management ,
+                          ex:Programming . }
+"###
+        .as_bytes(),
+        data_set_factory(),
+    );
+    assert!(result.is_ok());
+    let data_set = result.unwrap();
+    let data_set = data_set.borrow();
+
+    assert!(!data_set.has_default_graph());
+    let graph_name = GraphName::named_ref(
+        IRI::from_str("http://www.example.org/exampleDocument#G1")
+            .unwrap()
+            .into(),
+    );
+    let graph = data_set.graph_named(&graph_name).unwrap().borrow();
+    assert_eq!(graph.len(), 6);
+    assert_eq!(
+        graph
+            .prefix_mappings()
+            .borrow()
+            .get_namespace("ex")
+            .unwrap()
+            .to_string(),
+        "http://www.example.org/vocabulary#"
+    );
+}
+
+#[test]
+fn read_invalid_trig() {
+    let reader = TriGReader::default();
+    let result = reader.read(
+        &mut "<http://example.org/g> { <http://example.org/s> <http://example.org/p> }".as_bytes(),
+        data_set_factory(),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn read_relative_iris_with_base() {
+    let content = "<alice> <knows> <bob> .\n<people> { <bob> <knows> <#carol> . }";
+    assert!(TriGReader::default()
+        .read(&mut content.as_bytes(), data_set_factory())
+        .is_err());
+
+    let base = IRIRef::from(IRI::from_str("http://example.org/people/").unwrap());
+    let data_set = TriGReader::with_base(base)
+        .read(&mut content.as_bytes(), data_set_factory())
+        .unwrap();
+    let data_set = data_set.borrow();
+    let default_graph = data_set.default_graph().unwrap().borrow();
+    let statement = default_graph.statements().next().unwrap();
+    assert_eq!(
+        statement.subject().to_string(),
+        "<http://example.org/people/alice>"
+    );
+    let graph_name = GraphName::named_ref(IRIRef::from(
+        IRI::from_str("http://example.org/people/people").unwrap(),
+    ));
+    let named = data_set.graph_named(&graph_name).unwrap().borrow();
+    let statement = named.statements().next().unwrap();
+    assert_eq!(
+        statement.object().to_string(),
+        "<http://example.org/people/#carol>"
+    );
+}
+
+#[test]
+fn read_written_trig() {
+    let graph_name = GraphName::named_ref(
+        IRI::from_str("http://en.wikipedia.org/wiki/Tony_Benn")
+            .unwrap()
+            .into(),
+    );
+    let data_set = data_set_factory().data_set(Some(common::tony_benn_graph()));
+    {
+        let mut data_set = data_set.borrow_mut();
+        data_set.insert(graph_name.clone(), common::tony_benn_graph());
+    }
+
+    let writer = TriGWriter::with_base(
+        IRIRef::from(IRI::from_str("http://en.wikipedia.org/wiki/").unwrap()),
+        TurtleOptions::default(),
+    );
+    let output = write_data_set_to_string(&writer, &(data_set as DataSetRef)).unwrap();
+    println!("# format: trig\n{}", output);
+
+    let reader = TriGReader::default();
+    let result = reader.read(&mut output.as_bytes(), data_set_factory());
+    assert!(result.is_ok());
+    let data_set = result.unwrap();
+    let data_set = data_set.borrow();
+
+    assert_eq!(data_set.len(), 2);
+    assert_eq!(data_set.default_graph().unwrap().borrow().len(), 5);
+    assert_eq!(data_set.graph_named(&graph_name).unwrap().borrow().len(), 5);
+}
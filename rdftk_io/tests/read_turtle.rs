@@ -0,0 +1,228 @@
+#![cfg(feature = "turtle")]
+
+use rdftk_core::model::graph::collecting::CollectingGraphFactory;
+use rdftk_core::model::graph::stats::{
+    LiteralLengths, NamespaceCounts, PredicateHistogram, StatisticsCollectorRef,
+};
+use rdftk_core::simple::graph::SimpleGraph;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::turtle::repair::{repair, RepairKind};
+use rdftk_io::turtle::writer::{TurtleOptions, TurtleWriter};
+use rdftk_io::{write_graph_to_string, GraphReader};
+use rdftk_iri::{IRIRef, IRI};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::str::FromStr;
+
+mod common;
+
+#[test]
+fn read_relative_iris_with_base() {
+    let content = r###"@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+<Tony_Benn> a foaf:Person ; foaf:name "Tony Benn" .
+"###;
+
+    let reader = TurtleReader::default();
+    assert!(reader
+        .read(&mut content.as_bytes(), graph_factory())
+        .is_err());
+
+    let reader = TurtleReader::with_base(IRIRef::from(
+        IRI::from_str("http://en.wikipedia.org/wiki/").unwrap(),
+    ));
+    let graph = reader
+        .read(&mut content.as_bytes(), graph_factory())
+        .unwrap();
+    let graph = graph.borrow();
+
+    assert_eq!(graph.len(), 2);
+    assert!(graph.contains_individual(&IRIRef::from(
+        IRI::from_str("http://en.wikipedia.org/wiki/Tony_Benn").unwrap()
+    )));
+    assert_eq!(
+        graph
+            .prefix_mappings()
+            .borrow()
+            .get_namespace("foaf")
+            .unwrap()
+            .to_string(),
+        "http://xmlns.com/foaf/0.1/"
+    );
+}
+
+#[test]
+fn read_written_turtle() {
+    let graph = common::tony_benn_graph();
+
+    let writer = TurtleWriter::with_base(
+        IRIRef::from(IRI::from_str("http://en.wikipedia.org/wiki/").unwrap()),
+        TurtleOptions::default(),
+    );
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    let reader = TurtleReader::default();
+    let result = reader.read(&mut output.as_bytes(), graph_factory());
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().borrow().len(), 5);
+}
+
+#[test]
+fn read_preserving_blank_node_labels() {
+    let content = r###"@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+_:alice foaf:knows _:bob , [ foaf:name "Carol" ] .
+_:bob foaf:name "Bob" .
+"###;
+    let blank_names = |graph: &rdftk_core::model::graph::GraphRef| {
+        let graph = graph.borrow();
+        let mut names: Vec<String> = graph
+            .subjects()
+            .into_iter()
+            .filter_map(|subject| subject.as_blank().cloned())
+            .collect();
+        names.sort();
+        names
+    };
+
+    let graph = TurtleReader::default()
+        .read(&mut content.as_bytes(), graph_factory())
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 4);
+    let names = blank_names(&graph);
+    assert_eq!(names.len(), 3);
+    assert!(!names.contains(&"alice".to_string()));
+
+    let reader = TurtleReader::default().preserve_blank_node_labels(true);
+    let graph = reader
+        .read(&mut content.as_bytes(), graph_factory())
+        .unwrap();
+    let names = blank_names(&graph);
+    assert_eq!(names.len(), 3);
+    assert!(names.contains(&"alice".to_string()));
+    assert!(names.contains(&"bob".to_string()));
+
+    let options = TurtleOptions {
+        preserve_blank_node_labels: true,
+        ..Default::default()
+    };
+    let output = write_graph_to_string(&TurtleWriter::new(options), &graph).unwrap();
+    println!("# format: turtle\n{}", output);
+    assert!(output.contains("_:alice foaf:knows "));
+    assert!(output.contains("_:bob foaf:name \"Bob\""));
+    assert!(!output.contains('['));
+
+    let graph = reader
+        .read(&mut output.as_bytes(), graph_factory())
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 4);
+    assert_eq!(blank_names(&graph), names);
+}
+
+#[test]
+fn read_with_repairs() {
+    let content = format!(
+        "{}{}",
+        '\u{FEFF}',
+        r#"<http://example.org/memo> a foaf:Document ;
+    dc:title "The \"final\" memo" ;
+    dc:description "Say "hello" to everyone"@en ;
+    ex:note "not a known prefix" ;
+    foaf:maker <http://example.org/alice>"#
+    );
+
+    let reader = TurtleReader::default();
+    assert!(reader
+        .read(&mut content.as_bytes(), graph_factory())
+        .is_err());
+
+    let (graph, repairs) = reader
+        .read_with_repairs(
+            &mut content.replace("ex:note", "dc:subject").as_bytes(),
+            graph_factory(),
+        )
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 5);
+    let kinds: Vec<&RepairKind> = repairs.iter().map(|repair| repair.kind()).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            &RepairKind::ByteOrderMark,
+            &RepairKind::UndeclaredPrefix(
+                "foaf".to_string(),
+                "http://xmlns.com/foaf/0.1/".to_string()
+            ),
+            &RepairKind::UndeclaredPrefix(
+                "dc".to_string(),
+                "http://purl.org/dc/elements/1.1/".to_string()
+            ),
+            &RepairKind::UnescapedQuote,
+            &RepairKind::UnescapedQuote,
+            &RepairKind::MissingFinalDot,
+        ]
+    );
+    assert_eq!((repairs[3].line(), repairs[3].column()), (3, 25));
+    assert_eq!((repairs[5].line(), repairs[5].column()), (5, 42));
+
+    // the prefix `ex` is not known, and so cannot be declared.
+    let reader = reader.repair(true);
+    assert!(reader
+        .read(&mut content.as_bytes(), graph_factory())
+        .is_err());
+    assert!(reader
+        .read(
+            &mut content.replace("ex:note", "dc:subject").as_bytes(),
+            graph_factory()
+        )
+        .is_ok());
+}
+
+#[test]
+fn repair_leaves_valid_documents_unchanged() {
+    let content = r#"PREFIX ex: <http://example.org/>
+BASE <http://example.org/base/>
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+ex:a rdfs:label """a "long" label""" , 'it\'s' ; # a "comment
+    ex:value 1.5 ; ex:items ( "a" "b" ) ; ex:ref _:b1 .
+_:b1 rdfs:comment "x"^^<http://www.w3.org/2001/XMLSchema#string> .
+"#;
+    let (repaired, repairs) = repair(content);
+    assert!(repairs.is_empty(), "{:?}", repairs);
+    assert_eq!(repaired, content);
+
+    let content = "PREFIX ex: <http://example.org/>";
+    let (repaired, repairs) = repair(content);
+    assert!(repairs.is_empty());
+    assert_eq!(repaired, content);
+}
+
+#[test]
+fn read_with_statistics_collectors() {
+    let content = r#"@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+@prefix ex: <http://example.org/> .
+ex:alice a foaf:Person ; foaf:name "Alice" ; foaf:knows ex:bob .
+ex:bob a foaf:Person ; foaf:name "Bob" .
+"#;
+    let predicates = Rc::new(RefCell::new(PredicateHistogram::default()));
+    let namespaces = Rc::new(RefCell::new(NamespaceCounts::default()));
+    let lengths = Rc::new(RefCell::new(LiteralLengths::default()));
+    let factory = CollectingGraphFactory::<SimpleGraph>::new(vec![
+        predicates.clone() as StatisticsCollectorRef,
+        namespaces.clone() as StatisticsCollectorRef,
+        lengths.clone() as StatisticsCollectorRef,
+    ])
+    .into_ref();
+
+    let graph = TurtleReader::default()
+        .read(&mut content.as_bytes(), factory)
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 5);
+
+    let name = IRIRef::from(IRI::from_str("http://xmlns.com/foaf/0.1/name").unwrap());
+    assert_eq!(predicates.borrow().count(&name), 2);
+    assert_eq!(predicates.borrow().len(), 3);
+    let example = IRIRef::from(IRI::from_str("http://example.org/").unwrap());
+    assert_eq!(namespaces.borrow().count(&example), 6);
+    assert_eq!(lengths.borrow().count(), 2);
+    assert_eq!(lengths.borrow().max(), Some(5));
+}
@@ -1,6 +1,10 @@
+use rdftk_core::model::graph::GraphRef;
 use rdftk_core::simple::graph_factory;
+use rdftk_io::nt::writer::NTripleWriter;
 use rdftk_io::xml::reader::XmlReader;
-use rdftk_io::GraphReader;
+use rdftk_io::{write_graph_to_string, GraphReader};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
 
 // https://www.w3.org/RDF/Validator/rdfval
 
@@ -27,7 +31,6 @@ fn read_example_00() {
 }
 
 #[test]
-#[ignore]
 fn read_example_01() {
     let mut xml = r##"<?xml version="1.0"?>
 <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
@@ -59,3 +62,150 @@ fn read_example_01() {
     }
     assert_eq!(graph.borrow().len(), 6);
 }
+
+fn read_to_ntriples(reader: XmlReader, mut xml: &[u8]) -> (GraphRef, String) {
+    logging::try_init();
+    let graph = reader.read(&mut xml, graph_factory()).unwrap();
+    let nt = write_graph_to_string(&NTripleWriter::default(), &graph).unwrap();
+    println!("{}", nt);
+    (graph, nt)
+}
+
+#[test]
+fn read_typed_nodes_and_languages() {
+    let (graph, nt) = read_to_ntriples(
+        XmlReader::default(),
+        br##"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#"
+         xmlns:rdfs="http://www.w3.org/2000/01/rdf-schema#"
+         xml:lang="en">
+  <owl:Class rdf:about="http://example.org/Person">
+    <rdfs:label>Person</rdfs:label>
+    <rdfs:label xml:lang="fr">Personne</rdfs:label>
+    <rdfs:comment xml:lang="">No language.</rdfs:comment>
+    <rdfs:subClassOf>
+      <owl:Restriction>
+        <owl:onProperty rdf:resource="http://example.org/name"/>
+        <owl:minCardinality rdf:datatype="http://www.w3.org/2001/XMLSchema#nonNegativeInteger">1</owl:minCardinality>
+      </owl:Restriction>
+    </rdfs:subClassOf>
+  </owl:Class>
+</rdf:RDF>"##,
+    );
+    assert_eq!(graph.borrow().len(), 8);
+    assert!(nt.contains(
+        "<http://example.org/Person> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> ."
+    ));
+    assert!(nt.contains("\"Person\"@en ."));
+    assert!(nt.contains("\"Personne\"@fr ."));
+    assert!(nt.contains("\"No language.\" ."));
+    assert!(nt.contains(
+        "<http://www.w3.org/2002/07/owl#minCardinality> \"1\"^^<http://www.w3.org/2001/XMLSchema#nonNegativeInteger> ."
+    ));
+    let mappings = graph.borrow().prefix_mappings();
+    assert!(mappings.borrow().get_namespace("owl").is_some());
+}
+
+#[test]
+fn read_collection() {
+    let (graph, nt) = read_to_ntriples(
+        XmlReader::default(),
+        br##"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/stuff/1.0/">
+  <rdf:Description rdf:about="http://example.org/basket">
+    <ex:hasFruit rdf:parseType="Collection">
+      <rdf:Description rdf:about="http://example.org/banana"/>
+      <rdf:Description rdf:about="http://example.org/apple"/>
+      <rdf:Description rdf:about="http://example.org/pear"/>
+    </ex:hasFruit>
+    <ex:hasNothing rdf:parseType="Collection"/>
+  </rdf:Description>
+</rdf:RDF>"##,
+    );
+    assert_eq!(graph.borrow().len(), 8);
+    assert!(nt.contains(
+        "<http://www.w3.org/1999/02/22-rdf-syntax-ns#first> <http://example.org/banana> ."
+    ));
+    assert!(nt.contains(
+        "<http://example.org/basket> <http://example.org/stuff/1.0/hasNothing> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil> ."
+    ));
+    assert_eq!(
+        nt.matches("rdf-syntax-ns#rest> <http://www.w3.org/1999/02/22-rdf-syntax-ns#nil>")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn read_blank_nodes_and_containers() {
+    let (graph, nt) = read_to_ntriples(
+        XmlReader::default(),
+        br##"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:dc="http://purl.org/dc/elements/1.1/"
+         xmlns:ex="http://example.org/stuff/1.0/">
+  <rdf:Description rdf:about="http://www.w3.org/TR/rdf-syntax-grammar" dc:title="RDF1.1 XML Syntax">
+    <ex:editor rdf:nodeID="abc"/>
+    <ex:publisher rdf:parseType="Resource">
+      <ex:fullName>W3C</ex:fullName>
+    </ex:publisher>
+    <ex:contact ex:email="nobody@example.org"/>
+  </rdf:Description>
+  <rdf:Description rdf:nodeID="abc" ex:fullName="Dave Beckett"/>
+  <rdf:Bag rdf:about="http://example.org/favourite-fruit">
+    <rdf:li rdf:resource="http://example.org/banana"/>
+    <rdf:li rdf:resource="http://example.org/apple"/>
+  </rdf:Bag>
+</rdf:RDF>"##,
+    );
+    assert_eq!(graph.borrow().len(), 10);
+    assert!(nt.contains("_:abc <http://example.org/stuff/1.0/fullName> \"Dave Beckett\" ."));
+    assert!(nt.contains(
+        "<http://www.w3.org/TR/rdf-syntax-grammar> <http://example.org/stuff/1.0/editor> _:abc ."
+    ));
+    assert!(nt.contains("<http://example.org/stuff/1.0/email> \"nobody@example.org\" ."));
+    assert!(nt.contains(
+        "<http://example.org/favourite-fruit> <http://www.w3.org/1999/02/22-rdf-syntax-ns#_2> <http://example.org/apple> ."
+    ));
+}
+
+#[test]
+fn read_base_and_reification() {
+    let (graph, nt) = read_to_ntriples(
+        XmlReader::with_base(IRIRef::from(
+            IRI::from_str("http://example.org/unused/").unwrap(),
+        )),
+        br##"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/stuff/1.0/"
+         xml:base="http://example.org/here/">
+  <rdf:Description rdf:ID="snack">
+    <ex:prop rdf:resource="fruit/apple" rdf:ID="triple1"/>
+    <ex:note rdf:parseType="Literal"><b xmlns="http://www.w3.org/1999/xhtml">crisp</b> &amp; sweet</ex:note>
+  </rdf:Description>
+</rdf:RDF>"##,
+    );
+    assert_eq!(graph.borrow().len(), 6);
+    assert!(nt.contains(
+        "<http://example.org/here/#snack> <http://example.org/stuff/1.0/prop> <http://example.org/here/fruit/apple> ."
+    ));
+    assert!(nt.contains(
+        "<http://example.org/here/#triple1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#subject> <http://example.org/here/#snack> ."
+    ));
+    assert!(nt.contains(
+        "\"<b xmlns=\\\"http://www.w3.org/1999/xhtml\\\">crisp</b> &amp; sweet\"^^<http://www.w3.org/1999/02/22-rdf-syntax-ns#XMLLiteral> ."
+    ));
+}
+
+#[test]
+fn read_relative_without_base() {
+    let mut xml = br##"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about="relative"/>
+</rdf:RDF>"##
+        .as_ref();
+    assert!(XmlReader::default()
+        .read(&mut xml, graph_factory())
+        .is_err());
+}
@@ -0,0 +1,198 @@
+#![cfg(feature = "resolver")]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_fixtures::terms::iri;
+use rdftk_io::resolver::{Field, Resolver, Shape, DEFAULT_MAX_DEPTH};
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use serde_json::{json, Value};
+
+const TURTLE: &str = r#"@prefix ex: <http://example.org/> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+ex:alice a ex:Person ;
+    ex:name "Alice" ;
+    ex:age 34 ;
+    ex:member true ;
+    ex:height "1.72"^^xsd:decimal ;
+    ex:motto "Say \"hi\"\n" ;
+    ex:address [ ex:city "Springfield" ] ;
+    ex:knows ex:bob, ex:carol .
+ex:bob a ex:Person ;
+    ex:name "Bob" ;
+    ex:age "unknown"^^xsd:integer ;
+    ex:knows ex:alice .
+ex:carol a ex:Person ;
+    ex:name "Carol" ;
+    ex:knows ex:dave .
+ex:dave ex:name "Dave" ;
+    ex:knows ex:erin .
+ex:erin ex:name "Erin" .
+"#;
+
+fn graph() -> GraphRef {
+    TurtleReader::default()
+        .read(&mut TURTLE.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn resolve(resolver: &Resolver, name: &str, shape: &Shape) -> Value {
+    resolver
+        .resolve_iri(&iri(&format!("http://example.org/{}", name)), shape)
+        .unwrap()
+        .unwrap()
+}
+
+fn friends_shape() -> Shape {
+    Shape::from_json(&json!({
+        "@prefixes": { "ex": "http://example.org/" },
+        "name": "ex:name",
+        "friends": { "path": "ex:knows", "many": true, "shape": {
+            "name": "ex:name",
+            "friends": { "path": "ex:knows", "many": true, "shape": {
+                "name": "ex:name",
+                "friends": { "path": "ex:knows", "many": true, "shape": {
+                    "name": "ex:name",
+                    "friends": { "path": "ex:knows", "many": true, "shape": {
+                        "name": "ex:name"
+                    } }
+                } }
+            } }
+        } }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn resolve_literals() {
+    let shape = Shape::from_json(&json!({
+        "@prefixes": { "ex": "http://example.org/" },
+        "id": "@id",
+        "name": "ex:name",
+        "age": "ex:age",
+        "member": "ex:member",
+        "height": "ex:height",
+        "motto": "ex:motto",
+        "email": "ex:email",
+        "phones": { "path": "ex:phone", "many": true }
+    }))
+    .unwrap();
+    let resolver = Resolver::new(graph());
+    assert_eq!(
+        resolve(&resolver, "alice", &shape),
+        json!({
+            "id": "http://example.org/alice",
+            "name": "Alice",
+            "age": 34,
+            "member": true,
+            "height": 1.72,
+            "motto": "Say \"hi\"\n",
+            "email": null,
+            "phones": []
+        })
+    );
+    assert_eq!(resolve(&resolver, "bob", &shape)["age"], json!("unknown"));
+}
+
+#[test]
+fn resolve_nested_and_inverse() {
+    let shape = Shape::from_json(&json!({
+        "@prefixes": { "ex": "http://example.org/" },
+        "address": { "path": "ex:address", "shape": { "city": "ex:city" } },
+        "knownBy": { "path": "^ex:knows", "many": true, "shape": { "name": "ex:name" } },
+        "firstFriend": "ex:knows"
+    }))
+    .unwrap();
+    let resolver = Resolver::new(graph());
+    assert_eq!(
+        resolve(&resolver, "alice", &shape),
+        json!({
+            "address": { "city": "Springfield" },
+            "knownBy": [{ "name": "Bob" }],
+            "firstFriend": "http://example.org/bob"
+        })
+    );
+}
+
+#[test]
+fn resolve_to_max_depth() {
+    let resolver = Resolver::new(graph());
+    assert_eq!(resolver.max_depth(), DEFAULT_MAX_DEPTH);
+    let alice = resolve(&resolver, "alice", &friends_shape());
+    // bob knows alice, who is already being resolved.
+    assert_eq!(
+        alice["friends"][0],
+        json!({ "name": "Bob", "friends": ["http://example.org/alice"] })
+    );
+    // carol (1) knows dave (2), who knows erin (3).
+    assert_eq!(
+        alice["friends"][1]["friends"][0]["friends"][0],
+        json!({ "name": "Erin", "friends": [] })
+    );
+
+    let resolver = resolver.with_max_depth(1);
+    let alice = resolve(&resolver, "alice", &friends_shape());
+    assert_eq!(
+        alice["friends"][1],
+        json!({ "name": "Carol", "friends": ["http://example.org/dave"] })
+    );
+
+    let resolver = Resolver::new(graph()).with_max_depth(0);
+    assert_eq!(
+        resolve(&resolver, "alice", &friends_shape())["friends"],
+        json!(["http://example.org/bob", "http://example.org/carol"])
+    );
+}
+
+#[test]
+fn resolve_instances() {
+    let shape = Shape::default()
+        .with_id("id")
+        .with_field("name", Field::new(iri("http://example.org/name")));
+    let resolver = Resolver::new(graph());
+    let people = resolver
+        .resolve_instances(&iri("http://example.org/Person"), &shape)
+        .unwrap();
+    let names: Vec<&Value> = people.iter().map(|person| &person["name"]).collect();
+    assert_eq!(names, vec!["Alice", "Bob", "Carol"]);
+    assert!(resolver
+        .resolve_iri(&iri("http://example.org/nobody"), &shape)
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn build_shape() {
+    let knows = iri("http://example.org/knows");
+    let shape = Shape::default()
+        .with_field("friend", Field::new(knows.clone()))
+        .with_field(
+            "friend",
+            Field::inverse(knows.clone())
+                .many()
+                .with_shape(Shape::default().with_id("id")),
+        );
+    assert_eq!(shape.fields().count(), 1);
+    let field = shape.field("friend").unwrap();
+    assert_eq!(field.predicate(), &knows);
+    assert!(field.is_inverse());
+    assert!(field.is_many());
+    assert_eq!(field.shape().unwrap().id().unwrap(), "id");
+    assert_eq!(
+        Shape::from_json(&json!({
+            "friend": { "path": "^http://example.org/knows", "many": true, "shape": { "id": "@id" } }
+        }))
+        .unwrap(),
+        shape
+    );
+}
+
+#[test]
+fn reject_invalid_shapes() {
+    assert!(Shape::from_json(&json!("ex:name")).is_err());
+    assert!(Shape::from_json(&json!({ "name": 42 })).is_err());
+    assert!(Shape::from_json(&json!({ "name": { "many": true } })).is_err());
+    assert!(Shape::from_json(&json!({ "name": { "path": "ex:name", "many": "yes" } })).is_err());
+    assert!(Shape::from_json(&json!({ "@prefixes": [] })).is_err());
+    assert!(Shape::from_json(&json!({ "name": "not an iri" })).is_err());
+}
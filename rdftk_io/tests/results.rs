@@ -0,0 +1,212 @@
+use rdftk_core::error::ErrorKind;
+use rdftk_core::model::literal::DataType;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_io::results::csv::{CsvResultsReader, CsvResultsWriter};
+use rdftk_io::results::json::{JsonResultsReader, JsonResultsWriter};
+use rdftk_io::results::tsv::{TsvResultsReader, TsvResultsWriter};
+use rdftk_io::results::xml::{XmlResultsReader, XmlResultsWriter};
+use rdftk_io::results::{QueryResults, ResultSet, ResultsReader, ResultsWriter, Solution};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+fn result_set() -> ResultSet {
+    let statements = statement_factory();
+    let literals = literal_factory();
+    let mut result_set = ResultSet::new(vec![
+        "person".to_string(),
+        "name".to_string(),
+        "age".to_string(),
+        "friend".to_string(),
+    ]);
+
+    let mut alice = Solution::default();
+    alice.bind(
+        "person",
+        statements.named_object(IRIRef::from(
+            IRI::from_str("http://example.org/alice").unwrap(),
+        )),
+    );
+    alice.bind(
+        "name",
+        statements.literal_object(literals.with_language_str("Alice", "en").unwrap()),
+    );
+    alice.bind("age", statements.literal_object(literals.long(34)));
+    alice.bind("friend", statements.blank_object_named("b0").unwrap());
+    result_set.push(alice);
+
+    let mut bob = Solution::default();
+    bob.bind(
+        "name",
+        statements.literal_object(literals.literal("Bob \"the\", builder\n\tjr.")),
+    );
+    result_set.push(bob);
+    result_set
+}
+
+fn round_trip(reader: &impl ResultsReader, writer: &impl ResultsWriter, results: &QueryResults) {
+    let mut output: Vec<u8> = Default::default();
+    writer.write(&mut output, results).unwrap();
+    let read = reader
+        .read(&mut output.as_slice(), graph_factory())
+        .unwrap();
+    assert_eq!(&read, results);
+}
+
+#[test]
+fn round_trip_json() {
+    round_trip(
+        &JsonResultsReader::default(),
+        &JsonResultsWriter::default(),
+        &result_set().into(),
+    );
+    round_trip(
+        &JsonResultsReader::default(),
+        &JsonResultsWriter::pretty(),
+        &QueryResults::Boolean(true),
+    );
+}
+
+#[test]
+fn round_trip_xml() {
+    round_trip(
+        &XmlResultsReader::default(),
+        &XmlResultsWriter::default(),
+        &result_set().into(),
+    );
+    round_trip(
+        &XmlResultsReader::default(),
+        &XmlResultsWriter::pretty(),
+        &QueryResults::Boolean(false),
+    );
+}
+
+#[test]
+fn round_trip_tsv() {
+    round_trip(
+        &TsvResultsReader::default(),
+        &TsvResultsWriter::default(),
+        &result_set().into(),
+    );
+}
+
+#[test]
+fn write_tsv() {
+    let mut output: Vec<u8> = Default::default();
+    TsvResultsWriter::default()
+        .write(&mut output, &result_set().into())
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "?person\t?name\t?age\t?friend\n\
+         <http://example.org/alice>\t\"Alice\"@en\t\"34\"^^<http://www.w3.org/2001/XMLSchema#long>\t_:b0\n\
+         \t\"Bob \\\"the\\\", builder\\n\\tjr.\"\t\t\n"
+    );
+}
+
+#[test]
+fn write_csv() {
+    let mut output: Vec<u8> = Default::default();
+    CsvResultsWriter::default()
+        .write(&mut output, &result_set().into())
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "person,name,age,friend\r\n\
+         http://example.org/alice,Alice,34,_:b0\r\n\
+         ,\"Bob \"\"the\"\", builder\n\tjr.\",,\r\n"
+    );
+}
+
+#[test]
+fn read_csv() {
+    let mut output: Vec<u8> = Default::default();
+    CsvResultsWriter::default()
+        .write(&mut output, &result_set().into())
+        .unwrap();
+    let results = CsvResultsReader::default()
+        .read(&mut output.as_slice(), graph_factory())
+        .unwrap();
+    let read = results.as_solutions().unwrap();
+    assert_eq!(read.len(), 2);
+    assert_eq!(read.variables(), &["person", "name", "age", "friend"]);
+
+    let alice = read.iter().next().unwrap();
+    assert_eq!(alice.len(), 4);
+    assert_eq!(alice.get("friend").unwrap().as_blank().unwrap(), "b0");
+    let person = alice.get("person").unwrap().as_literal().unwrap().clone();
+    assert_eq!(person.lexical_form(), "http://example.org/alice");
+    assert!(person.data_type().is_none());
+
+    let bob = read.iter().nth(1).unwrap();
+    assert_eq!(bob.len(), 1);
+    assert_eq!(bob.get("name"), result_set().column("name")[1]);
+}
+
+#[test]
+fn read_tsv_abbreviations() {
+    let content = "?a\t?b\t?c\t?d\t?e\n1\t-2.5\t1.0e3\ttrue\t'single'\n";
+    let results = TsvResultsReader::default()
+        .read(&mut content.as_bytes(), graph_factory())
+        .unwrap();
+    let result_set = results.as_solutions().unwrap();
+    let data_type = |variable: &str| {
+        result_set.column(variable)[0]
+            .unwrap()
+            .as_literal()
+            .unwrap()
+            .data_type()
+            .cloned()
+    };
+    assert_eq!(data_type("a"), Some(DataType::Integer));
+    assert_eq!(data_type("b"), Some(DataType::Decimal));
+    assert_eq!(data_type("c"), Some(DataType::Double));
+    assert_eq!(data_type("d"), Some(DataType::Boolean));
+    assert_eq!(data_type("e"), None);
+}
+
+#[test]
+fn reject_invalid_results() {
+    assert!(TsvResultsReader::default()
+        .read(&mut "name\n".as_bytes(), graph_factory())
+        .is_err());
+    assert!(TsvResultsReader::default()
+        .read(
+            &mut "?a\t?b\n<http://example.org/>\n".as_bytes(),
+            graph_factory()
+        )
+        .is_err());
+    assert!(TsvResultsReader::default()
+        .read(&mut "?a\n\"unterminated\n".as_bytes(), graph_factory())
+        .is_err());
+    assert!(CsvResultsReader::default()
+        .read(&mut "a,b\r\n1\r\n".as_bytes(), graph_factory())
+        .is_err());
+    assert!(CsvResultsReader::default()
+        .read(&mut "a\r\n\"open\r\n".as_bytes(), graph_factory())
+        .is_err());
+    assert!(XmlResultsReader::default()
+        .read(
+            &mut "<sparql><results><result><uri>x</uri></result></results></sparql>".as_bytes(),
+            graph_factory()
+        )
+        .is_err());
+}
+
+#[test]
+fn booleans_not_supported() {
+    let results = QueryResults::Boolean(true);
+    let mut output: Vec<u8> = Default::default();
+    let csv = CsvResultsWriter::default().write(&mut output, &results);
+    assert!(matches!(
+        csv.err().unwrap().kind(),
+        ErrorKind::OperationNotSupported(_, _)
+    ));
+    let tsv = TsvResultsWriter::default().write(&mut output, &results);
+    assert!(matches!(
+        tsv.err().unwrap().kind(),
+        ErrorKind::OperationNotSupported(_, _)
+    ));
+    assert!(output.is_empty());
+}
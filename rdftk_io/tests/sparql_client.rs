@@ -0,0 +1,244 @@
+#![cfg(feature = "sparql-client")]
+
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::literal::DataType;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::results::json::JsonResultsReader;
+use rdftk_io::results::xml::XmlResultsReader;
+use rdftk_io::results::{QueryResults, ResultSet, ResultsReader};
+use rdftk_io::sparql_client::{EndpointResults, Request, Response, SparqlClient, Transport};
+use rdftk_iri::{IRIRef, IRI};
+use std::cell::RefCell;
+use std::str::FromStr;
+
+const JSON_RESULTS: &str = r#"{
+  "head": { "vars": [ "person", "name", "age", "friend" ] },
+  "results": {
+    "bindings": [
+      {
+        "person": { "type": "uri", "value": "http://example.org/alice" },
+        "name": { "type": "literal", "value": "Alice", "xml:lang": "en" },
+        "age": {
+          "type": "literal",
+          "value": "34",
+          "datatype": "http://www.w3.org/2001/XMLSchema#integer"
+        },
+        "friend": { "type": "bnode", "value": "b0" }
+      },
+      {
+        "person": { "type": "uri", "value": "http://example.org/bob" },
+        "name": { "type": "literal", "value": "Bob" }
+      }
+    ]
+  }
+}"#;
+
+const XML_RESULTS: &str = r#"<?xml version="1.0"?>
+<sparql xmlns="http://www.w3.org/2005/sparql-results#">
+  <head>
+    <variable name="person"/>
+    <variable name="name"/>
+    <variable name="age"/>
+    <variable name="friend"/>
+  </head>
+  <results>
+    <result>
+      <binding name="person"><uri>http://example.org/alice</uri></binding>
+      <binding name="name"><literal xml:lang="en">Alice</literal></binding>
+      <binding name="age">
+        <literal datatype="http://www.w3.org/2001/XMLSchema#integer">34</literal>
+      </binding>
+      <binding name="friend"><bnode>b0</bnode></binding>
+    </result>
+    <result>
+      <binding name="person"><uri>http://example.org/bob</uri></binding>
+      <binding name="name"><literal>Bob</literal></binding>
+    </result>
+  </results>
+</sparql>"#;
+
+const TURTLE: &str = r#"@prefix ex: <http://example.org/> .
+ex:alice ex:knows ex:bob .
+ex:bob ex:knows ex:carol .
+"#;
+
+struct MockTransport {
+    requests: RefCell<Vec<Request>>,
+    response: Response,
+}
+
+impl Transport for MockTransport {
+    fn send(&self, _: &IRIRef, request: &Request) -> Result<Response> {
+        self.requests.borrow_mut().push(request.clone());
+        Ok(self.response.clone())
+    }
+}
+
+fn client(status: u16, content_type: &str, body: &str) -> SparqlClient<MockTransport> {
+    SparqlClient::new(
+        IRIRef::from(IRI::from_str("http://example.org/sparql").unwrap()),
+        MockTransport {
+            requests: Default::default(),
+            response: Response::new(status, Some(content_type), body.as_bytes().to_vec()),
+        },
+    )
+}
+
+fn assert_solutions(solutions: &ResultSet) {
+    assert_eq!(solutions.variables(), &["person", "name", "age", "friend"]);
+    assert_eq!(solutions.len(), 2);
+
+    let alice = solutions.iter().next().unwrap();
+    assert_eq!(alice.len(), 4);
+    assert_eq!(
+        alice.get("person").unwrap().as_iri().unwrap().to_string(),
+        "http://example.org/alice"
+    );
+    let name = alice.get("name").unwrap().as_literal().unwrap().clone();
+    assert_eq!(name.lexical_form(), "Alice");
+    assert_eq!(name.language().unwrap().to_string(), "en");
+    let age = alice.get("age").unwrap().as_literal().unwrap().clone();
+    assert_eq!(age.data_type(), Some(&DataType::Integer));
+    assert_eq!(age.as_i64().unwrap(), 34);
+    assert_eq!(alice.get("friend").unwrap().as_blank().unwrap(), "b0");
+
+    let bob = solutions.iter().nth(1).unwrap();
+    assert!(bob.is_bound("name"));
+    assert!(!bob.is_bound("age"));
+    assert!(bob
+        .get("name")
+        .unwrap()
+        .as_literal()
+        .unwrap()
+        .language()
+        .is_none());
+}
+
+#[test]
+fn read_json_solutions() {
+    let results = JsonResultsReader::default()
+        .read(&mut JSON_RESULTS.as_bytes(), graph_factory())
+        .unwrap();
+    assert_solutions(results.as_solutions().unwrap());
+}
+
+#[test]
+fn read_xml_solutions() {
+    let results = XmlResultsReader::default()
+        .read(&mut XML_RESULTS.as_bytes(), graph_factory())
+        .unwrap();
+    assert_solutions(results.as_solutions().unwrap());
+}
+
+#[test]
+fn read_booleans() {
+    let json = JsonResultsReader::default().read(
+        &mut r#"{"head":{},"boolean":false}"#.as_bytes(),
+        graph_factory(),
+    );
+    assert!(matches!(json.unwrap(), QueryResults::Boolean(false)));
+
+    let xml = r#"<sparql xmlns="http://www.w3.org/2005/sparql-results#">
+      <head/><boolean>true</boolean></sparql>"#;
+    let xml = XmlResultsReader::default().read(&mut xml.as_bytes(), graph_factory());
+    assert!(matches!(xml.unwrap(), QueryResults::Boolean(true)));
+}
+
+#[test]
+fn reject_invalid_results() {
+    let results =
+        r#"{"head":{"vars":["x"]},"results":{"bindings":[{"x":{"type":"thing","value":"1"}}]}}"#;
+    assert!(JsonResultsReader::default()
+        .read(&mut results.as_bytes(), graph_factory())
+        .is_err());
+    assert!(JsonResultsReader::default()
+        .read(&mut r#"{"head":{}}"#.as_bytes(), graph_factory())
+        .is_err());
+    assert!(XmlResultsReader::default()
+        .read(&mut "<sparql>".as_bytes(), graph_factory())
+        .is_err());
+}
+
+#[test]
+fn select_query() {
+    let client = client(
+        200,
+        "application/sparql-results+json; charset=utf-8",
+        JSON_RESULTS,
+    );
+    let solutions = client.select("SELECT * WHERE { ?s ?p ?o }").unwrap();
+    assert_eq!(solutions.len(), 2);
+
+    let requests = client_requests(&client);
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].query(), "SELECT * WHERE { ?s ?p ?o }");
+    assert_eq!(requests[0].content_type(), "application/sparql-query");
+    assert!(requests[0]
+        .accept()
+        .starts_with("application/sparql-results+json"));
+    assert_eq!(
+        requests[0].form_encoded(),
+        "query=SELECT%20%2A%20WHERE%20%7B%20%3Fs%20%3Fp%20%3Fo%20%7D"
+    );
+}
+
+#[test]
+fn ask_query() {
+    let client = client(
+        200,
+        "application/sparql-results+xml",
+        r#"<sparql xmlns="http://www.w3.org/2005/sparql-results#"><boolean>true</boolean></sparql>"#,
+    );
+    assert!(client.ask("ASK { ?s ?p ?o }").unwrap());
+    assert!(client.select("ASK { ?s ?p ?o }").is_err());
+}
+
+#[test]
+fn construct_query() {
+    let turtle = client(200, "text/turtle", TURTLE);
+    let graph = turtle
+        .construct("CONSTRUCT { ?s ?p ?o } WHERE { ?s ?p ?o }")
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 2);
+    assert!(client_requests(&turtle)[0].accept().contains("text/turtle"));
+
+    let html = client(200, "text/html", "<html></html>");
+    assert!(html
+        .construct("DESCRIBE <http://example.org/alice>")
+        .is_err());
+}
+
+#[test]
+fn unsuccessful_response() {
+    let client = client(400, "text/plain", "Parse error");
+    let result = client.select("SELECT");
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::ReadWrite(_)
+    ));
+}
+
+#[test]
+fn closure_transport() {
+    let transport = |endpoint: &IRIRef, request: &Request| -> Result<Response> {
+        assert_eq!(endpoint.to_string(), "http://example.org/sparql");
+        assert!(request.accept().contains("application/sparql-results+json"));
+        Ok(Response::new(
+            200,
+            Some("application/sparql-results+json"),
+            JSON_RESULTS.as_bytes().to_vec(),
+        ))
+    };
+    let client = SparqlClient::new(
+        IRIRef::from(IRI::from_str("http://example.org/sparql").unwrap()),
+        transport,
+    );
+    match client.query_any("SELECT * WHERE { ?s ?p ?o }").unwrap() {
+        EndpointResults::Solutions(solutions) => assert_solutions(&solutions),
+        _ => panic!("expected solutions"),
+    }
+}
+
+fn client_requests(client: &SparqlClient<MockTransport>) -> Vec<Request> {
+    client.transport().requests.borrow().clone()
+}
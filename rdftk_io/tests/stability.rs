@@ -0,0 +1,140 @@
+#![cfg(all(feature = "nt", feature = "turtle"))]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::format::Format;
+use rdftk_io::stability::{compare, compare_paths};
+
+fn turtle(content: &str) -> GraphRef {
+    Format::Turtle
+        .read_graph(&mut content.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn n_triples(content: &str) -> GraphRef {
+    Format::NTriples
+        .read_graph(&mut content.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+#[test]
+fn same_data_is_stable() {
+    let left = turtle(
+        r#"@prefix ex: <http://example.org/> .
+ex:a ex:name "A" ; ex:owner [ ex:name "Alice" ] ."#,
+    );
+    let right = n_triples(
+        r#"<http://example.org/a> <http://example.org/owner> _:someone .
+_:someone <http://example.org/name> "Alice"^^<http://www.w3.org/2001/XMLSchema#string> .
+<http://example.org/a> <http://example.org/name> "A" .
+"#,
+    );
+    let report = compare(&*left.borrow(), &*right.borrow()).unwrap();
+    assert!(report.is_stable());
+    assert_eq!(report.to_string(), "No differences\n");
+}
+
+#[test]
+fn missing_iris() {
+    let left = turtle(
+        r#"@prefix ex: <http://example.org/> .
+ex:a ex:knows ex:b ; ex:knows ex:c ."#,
+    );
+    let right = n_triples(
+        r#"<http://example.org/a> <http://example.org/knows> <http://example.org/b> .
+<http://example.org/a> <http://example.org/likes> <http://example.org/d> .
+"#,
+    );
+    let report = compare(&*left.borrow(), &*right.borrow()).unwrap();
+    assert!(!report.is_stable());
+    let left_only: Vec<String> = report
+        .left_only_iris()
+        .iter()
+        .map(|iri| iri.to_string())
+        .collect();
+    let right_only: Vec<String> = report
+        .right_only_iris()
+        .iter()
+        .map(|iri| iri.to_string())
+        .collect();
+    assert_eq!(left_only, vec!["http://example.org/c"]);
+    assert_eq!(
+        right_only,
+        vec!["http://example.org/d", "http://example.org/likes"]
+    );
+    assert_eq!(
+        report.to_string(),
+        "IRIs:\n  < <http://example.org/c>\n  > <http://example.org/d>\n  > <http://example.org/likes>\n"
+    );
+}
+
+#[test]
+fn blank_node_structure() {
+    let left = turtle(
+        r#"@prefix ex: <http://example.org/> .
+ex:a ex:owner [ ex:name "Alice" ; ex:age 42 ] ."#,
+    );
+    let right = n_triples(
+        r#"<http://example.org/a> <http://example.org/owner> _:b0 .
+_:b0 <http://example.org/name> "Alice" .
+"#,
+    );
+    let report = compare(&*left.borrow(), &*right.borrow()).unwrap();
+    assert_eq!(report.left_only_iris().len(), 1);
+    assert!(report.literal_drift().is_empty());
+    assert_eq!(
+        report.left_only_blank_statements(),
+        &vec![
+            "_:c14n0 <http://example.org/age> \"42\"^^<http://www.w3.org/2001/XMLSchema#integer> ."
+                .to_string()
+        ]
+    );
+    assert!(report.right_only_blank_statements().is_empty());
+}
+
+#[test]
+fn literal_drift() {
+    let left = turtle(
+        r#"@prefix ex: <http://example.org/> .
+ex:a ex:size 12 ; ex:label "chat"@fr ; ex:name "A" ."#,
+    );
+    let right = n_triples(
+        r#"<http://example.org/a> <http://example.org/size> "12"^^<http://www.w3.org/2001/XMLSchema#int> .
+<http://example.org/a> <http://example.org/label> "chat"@en .
+<http://example.org/a> <http://example.org/name> "A" .
+"#,
+    );
+    let report = compare(&*left.borrow(), &*right.borrow()).unwrap();
+    assert!(report.left_only_iris().is_empty());
+    let drift = report.literal_drift();
+    assert_eq!(drift.len(), 2);
+    assert_eq!(drift[0].predicate().to_string(), "http://example.org/label");
+    assert_eq!(drift[0].left(), "@fr");
+    assert_eq!(drift[0].right(), "@en");
+    assert_eq!(drift[1].lexical_form(), "12");
+    assert_eq!(drift[1].left(), "http://www.w3.org/2001/XMLSchema#integer");
+    assert_eq!(drift[1].right(), "http://www.w3.org/2001/XMLSchema#int");
+}
+
+#[test]
+fn compare_files() {
+    let dir = std::env::temp_dir();
+    let left = dir.join(format!("rdftk_io-stability-{}.ttl", std::process::id()));
+    let right = dir.join(format!("rdftk_io-stability-{}.nt", std::process::id()));
+    std::fs::write(
+        &left,
+        "<http://example.org/a> <http://example.org/b> <http://example.org/c> .",
+    )
+    .unwrap();
+    std::fs::write(
+        &right,
+        "<http://example.org/a> <http://example.org/b> <http://example.org/c> .\n",
+    )
+    .unwrap();
+    let report = compare_paths(&left, &right, graph_factory());
+    std::fs::remove_file(&left).unwrap();
+    std::fs::remove_file(&right).unwrap();
+    assert!(report.unwrap().is_stable());
+
+    assert!(compare_paths("unknown.format", "unknown.format", graph_factory()).is_err());
+}
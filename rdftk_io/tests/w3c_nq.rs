@@ -0,0 +1,186 @@
+#![cfg(feature = "nq")]
+
+use rdftk_core::error::eprint_trace;
+use rdftk_core::model::data_set::DataSetRef;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_io::nq::reader::NQuadDataSetReader;
+use rdftk_io::DataSetReader;
+use std::fs::File;
+use std::path::PathBuf;
+
+macro_rules! positive_test {
+    ($name:ident, $comment:expr, $file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            match read_test_file($file) {
+                Ok(_) => {}
+                Err(e) => {
+                    eprint_trace(&e);
+                    panic!("{}", e);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! negative_test {
+    ($name:ident, $comment:expr, $file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            assert!(read_test_file($file).is_err());
+        }
+    };
+}
+
+fn read_test_file(file_name: &str) -> Result<DataSetRef, rdftk_core::error::Error> {
+    let file_path = PathBuf::from(format!("tests/w3c/nq/{}.nq", file_name));
+    let mut file = File::open(file_path).unwrap();
+    let reader = NQuadDataSetReader::default();
+    reader.read(&mut file, data_set_factory())
+}
+
+positive_test!(nq_syntax_uri_01, "Graph IRI", "nq-syntax-uri-01");
+
+positive_test!(
+    nq_syntax_uri_02,
+    "Graph IRI with bnode subject",
+    "nq-syntax-uri-02"
+);
+
+positive_test!(
+    nq_syntax_uri_03,
+    "Graph IRI with bnode object",
+    "nq-syntax-uri-03"
+);
+
+positive_test!(
+    nq_syntax_uri_04,
+    "Graph IRI with simple literal",
+    "nq-syntax-uri-04"
+);
+
+positive_test!(
+    nq_syntax_uri_05,
+    "Graph IRI with language tagged literal",
+    "nq-syntax-uri-05"
+);
+
+positive_test!(
+    nq_syntax_uri_06,
+    "Graph IRI with datatyped literal",
+    "nq-syntax-uri-06"
+);
+
+positive_test!(nq_syntax_bnode_01, "Graph bnode", "nq-syntax-bnode-01");
+
+positive_test!(
+    nq_syntax_bnode_02,
+    "Graph bnode with bnode subject",
+    "nq-syntax-bnode-02"
+);
+
+positive_test!(
+    nq_syntax_bnode_03,
+    "Graph bnode with bnode object",
+    "nq-syntax-bnode-03"
+);
+
+positive_test!(
+    nq_syntax_bnode_04,
+    "Graph bnode with simple literal",
+    "nq-syntax-bnode-04"
+);
+
+positive_test!(
+    nq_syntax_bnode_05,
+    "Graph bnode with language tagged literal",
+    "nq-syntax-bnode-05"
+);
+
+positive_test!(
+    nq_syntax_bnode_06,
+    "Graph bnode with datatyped literal",
+    "nq-syntax-bnode-06"
+);
+
+negative_test!(
+    nq_syntax_bad_literal_01,
+    "Graph name may not be a simple literal (negative test)",
+    "nq-syntax-bad-literal-01"
+);
+
+negative_test!(
+    nq_syntax_bad_literal_02,
+    "Graph name may not be a language tagged literal (negative test)",
+    "nq-syntax-bad-literal-02"
+);
+
+negative_test!(
+    nq_syntax_bad_literal_03,
+    "Graph name may not be a datatyped literal (negative test)",
+    "nq-syntax-bad-literal-03"
+);
+
+negative_test!(
+    nq_syntax_bad_uri_01,
+    "Graph name IRI must be absolute (negative test)",
+    "nq-syntax-bad-uri-01"
+);
+
+negative_test!(
+    nq_syntax_bad_quint_01,
+    "N-Quads does not have a fifth element (negative test)",
+    "nq-syntax-bad-quint-01"
+);
+
+positive_test!(nt_syntax_file_01, "Empty file", "nt-syntax-file-01");
+
+positive_test!(nt_syntax_file_02, "Only comment", "nt-syntax-file-02");
+
+positive_test!(
+    nt_syntax_file_03,
+    "One comment, one empty line",
+    "nt-syntax-file-03"
+);
+
+positive_test!(nt_syntax_uri_01, "Only IRIs", "nt-syntax-uri-01");
+
+positive_test!(
+    nt_syntax_string_02,
+    "langString literal",
+    "nt-syntax-string-02"
+);
+
+positive_test!(nt_syntax_bnode_01, "bnode subject", "nt-syntax-bnode-01");
+
+positive_test!(
+    nt_syntax_datatypes_01,
+    "xsd:byte literal",
+    "nt-syntax-datatypes-01"
+);
+
+negative_test!(
+    nt_syntax_bad_uri_01,
+    "Bad IRI : space (negative test)",
+    "nt-syntax-bad-uri-01"
+);
+
+negative_test!(
+    nt_syntax_bad_prefix_01,
+    "N-Triples does not have prefix declarations (negative test)",
+    "nt-syntax-bad-prefix-01"
+);
+
+negative_test!(
+    nt_syntax_bad_struct_01,
+    "Turtle-style object list is not allowed (negative test)",
+    "nt-syntax-bad-struct-01"
+);
+
+negative_test!(
+    nt_syntax_bad_string_01,
+    "mismatching string literal open/close (negative test)",
+    "nt-syntax-bad-string-01"
+);
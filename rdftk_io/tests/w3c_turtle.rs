@@ -0,0 +1,1913 @@
+#![cfg(feature = "turtle")]
+
+use rdftk_core::error::eprint_trace;
+use rdftk_core::model::graph::canonical::canonical_n_triples;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::nt::reader::NTriplesReader;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_iri::{IRIRef, IRI};
+use std::fs::File;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+macro_rules! positive_test {
+    ($name:ident, $comment:expr, $file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            match read_test_file($file) {
+                Ok(_) => {}
+                Err(e) => {
+                    eprint_trace(&e);
+                    panic!("{}", e);
+                }
+            }
+        }
+    };
+}
+
+macro_rules! negative_test {
+    ($name:ident, $comment:expr, $file:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            assert!(read_test_file($file).is_err());
+        }
+    };
+}
+
+macro_rules! eval_test {
+    ($name:ident, $comment:expr, $file:expr, $result:expr) => {
+        #[test]
+        fn $name() {
+            println!($comment);
+            match read_test_file($file) {
+                Ok(graph) => {
+                    let expected = read_result_file($result);
+                    assert_eq!(
+                        canonical_n_triples(&*graph.borrow()).unwrap(),
+                        canonical_n_triples(&*expected.borrow()).unwrap()
+                    );
+                }
+                Err(e) => {
+                    eprint_trace(&e);
+                    panic!("{}", e);
+                }
+            }
+        }
+    };
+}
+
+const TEST_BASE: &str = "http://www.w3.org/2013/TurtleTests/";
+
+fn read_test_file(file_name: &str) -> Result<GraphRef, rdftk_core::error::Error> {
+    let file_path = PathBuf::from(format!("tests/w3c/turtle/{}.ttl", file_name));
+    let mut file = File::open(file_path).unwrap();
+    let base = IRI::from_str(&format!("{}{}.ttl", TEST_BASE, file_name)).unwrap();
+    let reader = TurtleReader::with_base(IRIRef::from(base));
+    reader.read(&mut file, graph_factory())
+}
+
+fn read_result_file(file_name: &str) -> GraphRef {
+    let file_path = PathBuf::from(format!("tests/w3c/turtle/{}.nt", file_name));
+    let mut file = File::open(file_path).unwrap();
+    let reader = NTriplesReader::default();
+    reader.read(&mut file, graph_factory()).unwrap()
+}
+
+eval_test!(turtle_iri_subject, "IRI subject", "IRI_subject", "IRI_spo");
+
+eval_test!(
+    turtle_iri_with_four_digit_numeric_escape,
+    "IRI with four digit numeric escape (\\u)",
+    "IRI_with_four_digit_numeric_escape",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_iri_with_eight_digit_numeric_escape,
+    "IRI with eight digit numeric escape (\\U)",
+    "IRI_with_eight_digit_numeric_escape",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_iri_with_all_punctuation,
+    "IRI with all punctuation",
+    "IRI_with_all_punctuation",
+    "IRI_with_all_punctuation"
+);
+
+eval_test!(
+    turtle_bareword_a_predicate,
+    "bareword a predicate",
+    "bareword_a_predicate",
+    "bareword_a_predicate"
+);
+
+eval_test!(
+    turtle_old_style_prefix,
+    "old-style prefix",
+    "old_style_prefix",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_sparql_style_prefix,
+    "SPARQL-style prefix",
+    "SPARQL_style_prefix",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_prefixed_iri_predicate,
+    "prefixed IRI predicate",
+    "prefixed_IRI_predicate",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_prefixed_iri_object,
+    "prefixed IRI object",
+    "prefixed_IRI_object",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_prefix_only_iri,
+    "prefix-only IRI (p:)",
+    "prefix_only_IRI",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_prefix_with_pn_chars_base_character_boundaries,
+    "prefix with PN CHARS BASE character boundaries (prefix: AZazÀÖØöø...:)",
+    "prefix_with_PN_CHARS_BASE_character_boundaries",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_prefix_with_non_leading_extras,
+    "prefix with_non_leading_extras (_:a·̀ͯ‿.⁀)",
+    "prefix_with_non_leading_extras",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_localname_with_assigned_nfc_bmp_pn_chars_base_character_boundaries,
+    "localName with assigned, NFC-normalized, basic-multilingual-plane PN CHARS BASE character boundaries (p:AZazÀÖØöø...)",
+    "localName_with_assigned_nfc_bmp_PN_CHARS_BASE_character_boundaries",
+    "localName_with_assigned_nfc_bmp_PN_CHARS_BASE_character_boundaries"
+);
+
+eval_test!(
+    turtle_localname_with_assigned_nfc_pn_chars_base_character_boundaries,
+    "localName with assigned, NFC-normalized PN CHARS BASE character boundaries (p:AZazÀÖØöø...)",
+    "localName_with_assigned_nfc_PN_CHARS_BASE_character_boundaries",
+    "localName_with_assigned_nfc_PN_CHARS_BASE_character_boundaries"
+);
+
+eval_test!(
+    turtle_localname_with_nfc_pn_chars_base_character_boundaries,
+    "localName with nfc-normalize PN CHARS BASE character boundaries (p:AZazÀÖØöø...)",
+    "localName_with_nfc_PN_CHARS_BASE_character_boundaries",
+    "localName_with_nfc_PN_CHARS_BASE_character_boundaries"
+);
+
+eval_test!(
+    turtle_default_namespace_iri,
+    "default namespace IRI (:ln)",
+    "default_namespace_IRI",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_prefix_reassigned_and_used,
+    "prefix reassigned and used",
+    "prefix_reassigned_and_used",
+    "prefix_reassigned_and_used"
+);
+
+eval_test!(
+    turtle_reserved_escaped_localname,
+    "reserved-escaped local name",
+    "reserved_escaped_localName",
+    "reserved_escaped_localName"
+);
+
+eval_test!(
+    turtle_percent_escaped_localname,
+    "percent-escaped local name",
+    "percent_escaped_localName",
+    "percent_escaped_localName"
+);
+
+eval_test!(
+    turtle_hyphen_minus_in_localname,
+    "HYPHEN-MINUS in local name",
+    "HYPHEN_MINUS_in_localName",
+    "HYPHEN_MINUS_in_localName"
+);
+
+eval_test!(
+    turtle_underscore_in_localname,
+    "underscore in local name",
+    "underscore_in_localName",
+    "underscore_in_localName"
+);
+
+eval_test!(
+    turtle_localname_with_colon,
+    "localname with COLON",
+    "localname_with_COLON",
+    "localname_with_COLON"
+);
+
+eval_test!(
+    turtle_localname_with_leading_underscore,
+    "localName with leading underscore (p:_)",
+    "localName_with_leading_underscore",
+    "localName_with_leading_underscore"
+);
+
+eval_test!(
+    turtle_localname_with_leading_digit,
+    "localName with leading digit (p:_)",
+    "localName_with_leading_digit",
+    "localName_with_leading_digit"
+);
+
+eval_test!(
+    turtle_localname_with_non_leading_extras,
+    "localName with_non_leading_extras (_:a·̀ͯ‿.⁀)",
+    "localName_with_non_leading_extras",
+    "localName_with_non_leading_extras"
+);
+
+eval_test!(
+    turtle_old_style_base,
+    "old-style base",
+    "old_style_base",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_sparql_style_base,
+    "SPARQL-style base",
+    "SPARQL_style_base",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_labeled_blank_node_subject,
+    "labeled blank node subject",
+    "labeled_blank_node_subject",
+    "labeled_blank_node_subject"
+);
+
+eval_test!(
+    turtle_labeled_blank_node_object,
+    "labeled blank node object",
+    "labeled_blank_node_object",
+    "labeled_blank_node_object"
+);
+
+eval_test!(
+    turtle_labeled_blank_node_with_pn_chars_base_character_boundaries,
+    "labeled blank node with PN_CHARS_BASE character boundaries (_:AZazÀÖØöø...)",
+    "labeled_blank_node_with_PN_CHARS_BASE_character_boundaries",
+    "labeled_blank_node_object"
+);
+
+eval_test!(
+    turtle_labeled_blank_node_with_leading_underscore,
+    "labeled blank node with_leading_underscore (_:_)",
+    "labeled_blank_node_with_leading_underscore",
+    "labeled_blank_node_object"
+);
+
+eval_test!(
+    turtle_labeled_blank_node_with_leading_digit,
+    "labeled blank node with_leading_digit (_:0)",
+    "labeled_blank_node_with_leading_digit",
+    "labeled_blank_node_object"
+);
+
+eval_test!(
+    turtle_labeled_blank_node_with_non_leading_extras,
+    "labeled blank node with_non_leading_extras (_:a·̀ͯ‿.⁀)",
+    "labeled_blank_node_with_non_leading_extras",
+    "labeled_blank_node_object"
+);
+
+eval_test!(
+    turtle_anonymous_blank_node_subject,
+    "anonymous blank node subject",
+    "anonymous_blank_node_subject",
+    "labeled_blank_node_subject"
+);
+
+eval_test!(
+    turtle_anonymous_blank_node_object,
+    "anonymous blank node object",
+    "anonymous_blank_node_object",
+    "labeled_blank_node_object"
+);
+
+eval_test!(
+    turtle_sole_blanknodepropertylist,
+    "sole blankNodePropertyList [ <p> <o> ] .",
+    "sole_blankNodePropertyList",
+    "labeled_blank_node_subject"
+);
+
+eval_test!(
+    turtle_blanknodepropertylist_as_subject,
+    "blankNodePropertyList as subject [ … ] <p> <o> .",
+    "blankNodePropertyList_as_subject",
+    "blankNodePropertyList_as_subject"
+);
+
+eval_test!(
+    turtle_blanknodepropertylist_as_object,
+    "blankNodePropertyList as object <s> <p> [ … ] .",
+    "blankNodePropertyList_as_object",
+    "blankNodePropertyList_as_object"
+);
+
+eval_test!(
+    turtle_blanknodepropertylist_with_multiple_triples,
+    "blankNodePropertyList with multiple triples [ <s> <p> ; <s2> <p2> ]",
+    "blankNodePropertyList_with_multiple_triples",
+    "blankNodePropertyList_with_multiple_triples"
+);
+
+eval_test!(
+    turtle_nested_blanknodepropertylists,
+    "nested blankNodePropertyLists [ <p1> [ <p2> <o2> ] ; <p3> <o3> ]",
+    "nested_blankNodePropertyLists",
+    "nested_blankNodePropertyLists"
+);
+
+eval_test!(
+    turtle_blanknodepropertylist_containing_collection,
+    "blankNodePropertyList containing collection [ <p1> ( … ) ]",
+    "blankNodePropertyList_containing_collection",
+    "blankNodePropertyList_containing_collection"
+);
+
+eval_test!(
+    turtle_collection_subject,
+    "collection subject",
+    "collection_subject",
+    "collection_subject"
+);
+
+eval_test!(
+    turtle_collection_object,
+    "collection object",
+    "collection_object",
+    "collection_object"
+);
+
+eval_test!(
+    turtle_empty_collection,
+    "empty collection ()",
+    "empty_collection",
+    "empty_collection"
+);
+
+eval_test!(
+    turtle_nested_collection,
+    "nested collection (())",
+    "nested_collection",
+    "nested_collection"
+);
+
+eval_test!(
+    turtle_first,
+    "first, not last, non-empty nested collection",
+    "first",
+    "first"
+);
+
+eval_test!(
+    turtle_last,
+    "last, not first, non-empty nested collection",
+    "last",
+    "last"
+);
+
+eval_test!(turtle_literal1, "LITERAL1 'x'", "LITERAL1", "LITERAL1");
+
+eval_test!(
+    turtle_literal1_ascii_boundaries,
+    "LITERAL1_ascii_boundaries '\\x00\\x09\\x0b\\x0c\\x0e\\x26\\x28...'",
+    "LITERAL1_ascii_boundaries",
+    "LITERAL1_ascii_boundaries"
+);
+
+eval_test!(
+    turtle_literal1_with_utf8_boundaries,
+    "LITERAL1_with_UTF8_boundaries '\\x80\\x7ff\\x800\\xfff...'",
+    "LITERAL1_with_UTF8_boundaries",
+    "LITERAL_with_UTF8_boundaries"
+);
+
+eval_test!(
+    turtle_literal1_all_controls,
+    "LITERAL1_all_controls '\\x00\\x01\\x02\\x03\\x04...'",
+    "LITERAL1_all_controls",
+    "LITERAL1_all_controls"
+);
+
+eval_test!(
+    turtle_literal1_all_punctuation,
+    "LITERAL1_all_punctuation '!'#$%&()...'",
+    "LITERAL1_all_punctuation",
+    "LITERAL1_all_punctuation"
+);
+
+eval_test!(
+    turtle_literal_long1,
+    "LITERAL_LONG1 '''x'''",
+    "LITERAL_LONG1",
+    "LITERAL1"
+);
+
+eval_test!(
+    turtle_literal_long1_ascii_boundaries,
+    "LITERAL_LONG1_ascii_boundaries '\\x00\\x26\\x28...'",
+    "LITERAL_LONG1_ascii_boundaries",
+    "LITERAL_LONG1_ascii_boundaries"
+);
+
+eval_test!(
+    turtle_literal_long1_with_utf8_boundaries,
+    "LITERAL_LONG1_with_UTF8_boundaries '\\x80\\x7ff\\x800\\xfff...'",
+    "LITERAL_LONG1_with_UTF8_boundaries",
+    "LITERAL_with_UTF8_boundaries"
+);
+
+eval_test!(
+    turtle_literal_long1_with_1_squote,
+    "LITERAL_LONG1 with 1 squote '''a'b'''",
+    "LITERAL_LONG1_with_1_squote",
+    "LITERAL_LONG1_with_1_squote"
+);
+
+eval_test!(
+    turtle_literal_long1_with_2_squotes,
+    "LITERAL_LONG1 with 2 squotes '''a''b'''",
+    "LITERAL_LONG1_with_2_squotes",
+    "LITERAL_LONG1_with_2_squotes"
+);
+
+eval_test!(turtle_literal2, "LITERAL2 'x'", "LITERAL2", "LITERAL1");
+
+eval_test!(
+    turtle_literal2_ascii_boundaries,
+    "LITERAL2_ascii_boundaries '\\x00\\x09\\x0b\\x0c\\x0e\\x21\\x23...'",
+    "LITERAL2_ascii_boundaries",
+    "LITERAL2_ascii_boundaries"
+);
+
+eval_test!(
+    turtle_literal2_with_utf8_boundaries,
+    "LITERAL2_with_UTF8_boundaries '\\x80\\x7ff\\x800\\xfff...'",
+    "LITERAL2_with_UTF8_boundaries",
+    "LITERAL_with_UTF8_boundaries"
+);
+
+eval_test!(
+    turtle_literal_long2,
+    "LITERAL_LONG2 '''x'''",
+    "LITERAL_LONG2",
+    "LITERAL1"
+);
+
+eval_test!(
+    turtle_literal_long2_ascii_boundaries,
+    "LITERAL_LONG2_ascii_boundaries '\\x00\\x21\\x23...'",
+    "LITERAL_LONG2_ascii_boundaries",
+    "LITERAL_LONG2_ascii_boundaries"
+);
+
+eval_test!(
+    turtle_literal_long2_with_utf8_boundaries,
+    "LITERAL_LONG2_with_UTF8_boundaries '\\x80\\x7ff\\x800\\xfff...'",
+    "LITERAL_LONG2_with_UTF8_boundaries",
+    "LITERAL_with_UTF8_boundaries"
+);
+
+eval_test!(
+    turtle_literal_long2_with_1_squote,
+    "LITERAL_LONG2 with 1 squote '''a'b'''",
+    "LITERAL_LONG2_with_1_squote",
+    "LITERAL_LONG2_with_1_squote"
+);
+
+eval_test!(
+    turtle_literal_long2_with_2_squotes,
+    "LITERAL_LONG2 with 2 squotes '''a''b'''",
+    "LITERAL_LONG2_with_2_squotes",
+    "LITERAL_LONG2_with_2_squotes"
+);
+
+eval_test!(
+    turtle_literal_with_character_tabulation,
+    "literal with CHARACTER TABULATION",
+    "literal_with_CHARACTER_TABULATION",
+    "literal_with_CHARACTER_TABULATION"
+);
+
+eval_test!(
+    turtle_literal_with_backspace,
+    "literal with BACKSPACE",
+    "literal_with_BACKSPACE",
+    "literal_with_BACKSPACE"
+);
+
+eval_test!(
+    turtle_literal_with_line_feed,
+    "literal with LINE FEED",
+    "literal_with_LINE_FEED",
+    "literal_with_LINE_FEED"
+);
+
+eval_test!(
+    turtle_literal_with_carriage_return,
+    "literal with CARRIAGE RETURN",
+    "literal_with_CARRIAGE_RETURN",
+    "literal_with_CARRIAGE_RETURN"
+);
+
+eval_test!(
+    turtle_literal_with_form_feed,
+    "literal with FORM FEED",
+    "literal_with_FORM_FEED",
+    "literal_with_FORM_FEED"
+);
+
+eval_test!(
+    turtle_literal_with_reverse_solidus,
+    "literal with REVERSE SOLIDUS",
+    "literal_with_REVERSE_SOLIDUS",
+    "literal_with_REVERSE_SOLIDUS"
+);
+
+eval_test!(
+    turtle_literal_with_escaped_character_tabulation,
+    "literal with escaped CHARACTER TABULATION",
+    "literal_with_escaped_CHARACTER_TABULATION",
+    "literal_with_CHARACTER_TABULATION"
+);
+
+eval_test!(
+    turtle_literal_with_escaped_backspace,
+    "literal with escaped BACKSPACE",
+    "literal_with_escaped_BACKSPACE",
+    "literal_with_BACKSPACE"
+);
+
+eval_test!(
+    turtle_literal_with_escaped_line_feed,
+    "literal with escaped LINE FEED",
+    "literal_with_escaped_LINE_FEED",
+    "literal_with_LINE_FEED"
+);
+
+eval_test!(
+    turtle_literal_with_escaped_carriage_return,
+    "literal with escaped CARRIAGE RETURN",
+    "literal_with_escaped_CARRIAGE_RETURN",
+    "literal_with_CARRIAGE_RETURN"
+);
+
+eval_test!(
+    turtle_literal_with_escaped_form_feed,
+    "literal with escaped FORM FEED",
+    "literal_with_escaped_FORM_FEED",
+    "literal_with_FORM_FEED"
+);
+
+eval_test!(
+    turtle_literal_with_numeric_escape4,
+    "literal with numeric escape4 \\u",
+    "literal_with_numeric_escape4",
+    "literal_with_numeric_escape4"
+);
+
+eval_test!(
+    turtle_literal_with_numeric_escape8,
+    "literal with numeric escape8 \\U",
+    "literal_with_numeric_escape8",
+    "literal_with_numeric_escape4"
+);
+
+eval_test!(
+    turtle_iriref_datatype,
+    "IRIREF datatype ''^^<t>",
+    "IRIREF_datatype",
+    "IRIREF_datatype"
+);
+
+eval_test!(
+    turtle_prefixed_name_datatype,
+    "prefixed name datatype ''^^p:t",
+    "prefixed_name_datatype",
+    "IRIREF_datatype"
+);
+
+eval_test!(
+    turtle_bareword_integer,
+    "bareword integer",
+    "bareword_integer",
+    "IRIREF_datatype"
+);
+
+eval_test!(
+    turtle_bareword_decimal,
+    "bareword decimal",
+    "bareword_decimal",
+    "bareword_decimal"
+);
+
+eval_test!(
+    turtle_bareword_double,
+    "bareword double",
+    "bareword_double",
+    "bareword_double"
+);
+
+eval_test!(
+    turtle_double_lower_case_e,
+    "double lower case e",
+    "double_lower_case_e",
+    "double_lower_case_e"
+);
+
+eval_test!(
+    turtle_negative_numeric,
+    "negative numeric",
+    "negative_numeric",
+    "negative_numeric"
+);
+
+eval_test!(
+    turtle_positive_numeric,
+    "positive numeric",
+    "positive_numeric",
+    "positive_numeric"
+);
+
+eval_test!(
+    turtle_numeric_with_leading_0,
+    "numeric with leading 0",
+    "numeric_with_leading_0",
+    "numeric_with_leading_0"
+);
+
+eval_test!(
+    turtle_literal_true,
+    "literal true",
+    "literal_true",
+    "literal_true"
+);
+
+eval_test!(
+    turtle_literal_false,
+    "literal false",
+    "literal_false",
+    "literal_false"
+);
+
+eval_test!(
+    turtle_langtagged_non_long,
+    "langtagged non-LONG 'x'@en",
+    "langtagged_non_LONG",
+    "langtagged_non_LONG"
+);
+
+eval_test!(
+    turtle_langtagged_long,
+    "langtagged LONG '''x'''@en",
+    "langtagged_LONG",
+    "langtagged_non_LONG"
+);
+
+eval_test!(
+    turtle_lantag_with_subtag,
+    "lantag with subtag 'x'@en-us",
+    "lantag_with_subtag",
+    "lantag_with_subtag"
+);
+
+eval_test!(
+    turtle_objectlist_with_two_objects,
+    "objectList with two objects … <o1>,<o2>",
+    "objectList_with_two_objects",
+    "objectList_with_two_objects"
+);
+
+eval_test!(
+    turtle_predicateobjectlist_with_two_objectlists,
+    "predicateObjectList with two objectLists … <o1>,<o2>",
+    "predicateObjectList_with_two_objectLists",
+    "predicateObjectList_with_two_objectLists"
+);
+
+eval_test!(
+    turtle_repeated_semis_at_end,
+    "repeated semis at end <s> <p> <o> ;; <p2> <o2> .",
+    "repeated_semis_at_end",
+    "predicateObjectList_with_two_objectLists"
+);
+
+eval_test!(
+    turtle_repeated_semis_not_at_end,
+    "repeated semis not at end <s> <p> <o> ;;.",
+    "repeated_semis_not_at_end",
+    "repeated_semis_not_at_end"
+);
+
+positive_test!(turtle_syntax_file_01, "Empty file", "turtle-syntax-file-01");
+
+positive_test!(
+    turtle_syntax_file_02,
+    "Only comment",
+    "turtle-syntax-file-02"
+);
+
+positive_test!(
+    turtle_syntax_file_03,
+    "One comment, one empty line",
+    "turtle-syntax-file-03"
+);
+
+positive_test!(turtle_syntax_uri_01, "Only IRIs", "turtle-syntax-uri-01");
+
+positive_test!(
+    turtle_syntax_uri_02,
+    "IRIs with Unicode escape",
+    "turtle-syntax-uri-02"
+);
+
+positive_test!(
+    turtle_syntax_uri_03,
+    "IRIs with long Unicode escape",
+    "turtle-syntax-uri-03"
+);
+
+positive_test!(turtle_syntax_uri_04, "Legal IRIs", "turtle-syntax-uri-04");
+
+positive_test!(turtle_syntax_base_01, "@base", "turtle-syntax-base-01");
+
+positive_test!(turtle_syntax_base_02, "BASE", "turtle-syntax-base-02");
+
+positive_test!(
+    turtle_syntax_base_03,
+    "@base with relative IRIs",
+    "turtle-syntax-base-03"
+);
+
+positive_test!(
+    turtle_syntax_base_04,
+    "base with relative IRIs",
+    "turtle-syntax-base-04"
+);
+
+positive_test!(
+    turtle_syntax_prefix_01,
+    "@prefix",
+    "turtle-syntax-prefix-01"
+);
+
+positive_test!(turtle_syntax_prefix_02, "PreFIX", "turtle-syntax-prefix-02");
+
+positive_test!(
+    turtle_syntax_prefix_03,
+    "Empty PREFIX",
+    "turtle-syntax-prefix-03"
+);
+
+positive_test!(
+    turtle_syntax_prefix_04,
+    "Empty @prefix with % escape",
+    "turtle-syntax-prefix-04"
+);
+
+positive_test!(
+    turtle_syntax_prefix_05,
+    "@prefix with no suffix",
+    "turtle-syntax-prefix-05"
+);
+
+positive_test!(
+    turtle_syntax_prefix_06,
+    "colon is a legal pname character",
+    "turtle-syntax-prefix-06"
+);
+
+positive_test!(
+    turtle_syntax_prefix_07,
+    "dash is a legal pname character",
+    "turtle-syntax-prefix-07"
+);
+
+positive_test!(
+    turtle_syntax_prefix_08,
+    "underscore is a legal pname character",
+    "turtle-syntax-prefix-08"
+);
+
+positive_test!(
+    turtle_syntax_prefix_09,
+    "percents in pnames",
+    "turtle-syntax-prefix-09"
+);
+
+positive_test!(
+    turtle_syntax_string_01,
+    "string literal",
+    "turtle-syntax-string-01"
+);
+
+positive_test!(
+    turtle_syntax_string_02,
+    "langString literal",
+    "turtle-syntax-string-02"
+);
+
+positive_test!(
+    turtle_syntax_string_03,
+    "langString literal with region",
+    "turtle-syntax-string-03"
+);
+
+positive_test!(
+    turtle_syntax_string_04,
+    "squote string literal",
+    "turtle-syntax-string-04"
+);
+
+positive_test!(
+    turtle_syntax_string_05,
+    "squote langString literal",
+    "turtle-syntax-string-05"
+);
+
+positive_test!(
+    turtle_syntax_string_06,
+    "squote langString literal with region",
+    "turtle-syntax-string-06"
+);
+
+positive_test!(
+    turtle_syntax_string_07,
+    "long string literal with embedded single- and double-quotes",
+    "turtle-syntax-string-07"
+);
+
+positive_test!(
+    turtle_syntax_string_08,
+    "long string literal with embedded newline",
+    "turtle-syntax-string-08"
+);
+
+positive_test!(
+    turtle_syntax_string_09,
+    "squote long string literal with embedded single- and double-quotes",
+    "turtle-syntax-string-09"
+);
+
+positive_test!(
+    turtle_syntax_string_10,
+    "long langString literal with embedded newline",
+    "turtle-syntax-string-10"
+);
+
+positive_test!(
+    turtle_syntax_string_11,
+    "squote long langString literal with embedded newline",
+    "turtle-syntax-string-11"
+);
+
+positive_test!(
+    turtle_syntax_str_esc_01,
+    "string literal with escaped newline",
+    "turtle-syntax-str-esc-01"
+);
+
+positive_test!(
+    turtle_syntax_str_esc_02,
+    "string literal with Unicode escape",
+    "turtle-syntax-str-esc-02"
+);
+
+positive_test!(
+    turtle_syntax_str_esc_03,
+    "string literal with long Unicode escape",
+    "turtle-syntax-str-esc-03"
+);
+
+positive_test!(
+    turtle_syntax_pname_esc_01,
+    "pname with back-slash escapes",
+    "turtle-syntax-pname-esc-01"
+);
+
+positive_test!(
+    turtle_syntax_pname_esc_02,
+    "pname with back-slash escapes (2)",
+    "turtle-syntax-pname-esc-02"
+);
+
+positive_test!(
+    turtle_syntax_pname_esc_03,
+    "pname with back-slash escapes (3)",
+    "turtle-syntax-pname-esc-03"
+);
+
+positive_test!(
+    turtle_syntax_bnode_01,
+    "bnode subject",
+    "turtle-syntax-bnode-01"
+);
+
+positive_test!(
+    turtle_syntax_bnode_02,
+    "bnode object",
+    "turtle-syntax-bnode-02"
+);
+
+positive_test!(
+    turtle_syntax_bnode_03,
+    "bnode property list object",
+    "turtle-syntax-bnode-03"
+);
+
+positive_test!(
+    turtle_syntax_bnode_04,
+    "bnode property list object (2)",
+    "turtle-syntax-bnode-04"
+);
+
+positive_test!(
+    turtle_syntax_bnode_05,
+    "bnode property list subject",
+    "turtle-syntax-bnode-05"
+);
+
+positive_test!(
+    turtle_syntax_bnode_06,
+    "labeled bnode subject",
+    "turtle-syntax-bnode-06"
+);
+
+positive_test!(
+    turtle_syntax_bnode_07,
+    "labeled bnode subject and object",
+    "turtle-syntax-bnode-07"
+);
+
+positive_test!(
+    turtle_syntax_bnode_08,
+    "bare bnode property list",
+    "turtle-syntax-bnode-08"
+);
+
+positive_test!(
+    turtle_syntax_bnode_09,
+    "bnode property list",
+    "turtle-syntax-bnode-09"
+);
+
+positive_test!(
+    turtle_syntax_bnode_10,
+    "mixed bnode property list and triple",
+    "turtle-syntax-bnode-10"
+);
+
+positive_test!(
+    turtle_syntax_number_01,
+    "integer literal",
+    "turtle-syntax-number-01"
+);
+
+positive_test!(
+    turtle_syntax_number_02,
+    "negative integer literal",
+    "turtle-syntax-number-02"
+);
+
+positive_test!(
+    turtle_syntax_number_03,
+    "positive integer literal",
+    "turtle-syntax-number-03"
+);
+
+positive_test!(
+    turtle_syntax_number_04,
+    "decimal literal",
+    "turtle-syntax-number-04"
+);
+
+positive_test!(
+    turtle_syntax_number_05,
+    "decimal literal (no leading digits)",
+    "turtle-syntax-number-05"
+);
+
+positive_test!(
+    turtle_syntax_number_06,
+    "negative decimal literal",
+    "turtle-syntax-number-06"
+);
+
+positive_test!(
+    turtle_syntax_number_07,
+    "positive decimal literal",
+    "turtle-syntax-number-07"
+);
+
+positive_test!(
+    turtle_syntax_number_08,
+    "integer literal with decimal lexical confusion",
+    "turtle-syntax-number-08"
+);
+
+positive_test!(
+    turtle_syntax_number_09,
+    "double literal",
+    "turtle-syntax-number-09"
+);
+
+positive_test!(
+    turtle_syntax_number_10,
+    "negative double literal",
+    "turtle-syntax-number-10"
+);
+
+positive_test!(
+    turtle_syntax_number_11,
+    "double literal no fraction",
+    "turtle-syntax-number-11"
+);
+
+positive_test!(
+    turtle_syntax_datatypes_01,
+    "xsd:byte literal",
+    "turtle-syntax-datatypes-01"
+);
+
+positive_test!(
+    turtle_syntax_datatypes_02,
+    "integer as xsd:string",
+    "turtle-syntax-datatypes-02"
+);
+
+positive_test!(
+    turtle_syntax_kw_01,
+    "boolean literal (true)",
+    "turtle-syntax-kw-01"
+);
+
+positive_test!(
+    turtle_syntax_kw_02,
+    "boolean literal (false)",
+    "turtle-syntax-kw-02"
+);
+
+positive_test!(turtle_syntax_kw_03, "'a' as keyword", "turtle-syntax-kw-03");
+
+positive_test!(
+    turtle_syntax_struct_01,
+    "object list",
+    "turtle-syntax-struct-01"
+);
+
+positive_test!(
+    turtle_syntax_struct_02,
+    "predicate list with object list",
+    "turtle-syntax-struct-02"
+);
+
+positive_test!(
+    turtle_syntax_struct_03,
+    "predicate list with object list and dangling ';'",
+    "turtle-syntax-struct-03"
+);
+
+positive_test!(
+    turtle_syntax_struct_04,
+    "predicate list with multiple ;;",
+    "turtle-syntax-struct-04"
+);
+
+positive_test!(
+    turtle_syntax_struct_05,
+    "predicate list with multiple ;;",
+    "turtle-syntax-struct-05"
+);
+
+positive_test!(
+    turtle_syntax_lists_01,
+    "empty list",
+    "turtle-syntax-lists-01"
+);
+
+positive_test!(
+    turtle_syntax_lists_02,
+    "mixed list",
+    "turtle-syntax-lists-02"
+);
+
+positive_test!(
+    turtle_syntax_lists_03,
+    "isomorphic list as subject and object",
+    "turtle-syntax-lists-03"
+);
+
+positive_test!(
+    turtle_syntax_lists_04,
+    "lists of lists",
+    "turtle-syntax-lists-04"
+);
+
+positive_test!(
+    turtle_syntax_lists_05,
+    "mixed lists with embedded lists",
+    "turtle-syntax-lists-05"
+);
+
+negative_test!(
+    turtle_syntax_bad_uri_01,
+    "Bad IRI : space (negative test)",
+    "turtle-syntax-bad-uri-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_uri_02,
+    "Bad IRI : bad escape (negative test)",
+    "turtle-syntax-bad-uri-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_uri_03,
+    "Bad IRI : bad long escape (negative test)",
+    "turtle-syntax-bad-uri-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_uri_04,
+    "Bad IRI : character escapes not allowed (negative test)",
+    "turtle-syntax-bad-uri-04"
+);
+
+negative_test!(
+    turtle_syntax_bad_uri_05,
+    "Bad IRI : character escapes not allowed (2) (negative test)",
+    "turtle-syntax-bad-uri-05"
+);
+
+negative_test!(
+    turtle_syntax_bad_prefix_01,
+    "No prefix (negative test)",
+    "turtle-syntax-bad-prefix-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_prefix_02,
+    "No prefix (2) (negative test)",
+    "turtle-syntax-bad-prefix-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_prefix_03,
+    "@prefix without URI (negative test)",
+    "turtle-syntax-bad-prefix-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_prefix_04,
+    "@prefix without prefix name (negative test)",
+    "turtle-syntax-bad-prefix-04"
+);
+
+negative_test!(
+    turtle_syntax_bad_prefix_05,
+    "@prefix without ':' (negative test)",
+    "turtle-syntax-bad-prefix-05"
+);
+
+negative_test!(
+    turtle_syntax_bad_base_01,
+    "@base without URI (negative test)",
+    "turtle-syntax-bad-base-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_base_02,
+    "@base in wrong case (negative test)",
+    "turtle-syntax-bad-base-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_base_03,
+    "BASE without URI (negative test)",
+    "turtle-syntax-bad-base-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_01,
+    "Turtle is not TriG (negative test)",
+    "turtle-syntax-bad-struct-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_02,
+    "Turtle is not N3 (negative test)",
+    "turtle-syntax-bad-struct-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_03,
+    "Turtle is not NQuads (negative test)",
+    "turtle-syntax-bad-struct-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_04,
+    "Turtle does not allow literals-as-subjects (negative test)",
+    "turtle-syntax-bad-struct-04"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_05,
+    "Turtle does not allow literals-as-predicates (negative test)",
+    "turtle-syntax-bad-struct-05"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_06,
+    "Turtle does not allow bnodes-as-predicates (negative test)",
+    "turtle-syntax-bad-struct-06"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_07,
+    "Turtle does not allow labeled bnodes-as-predicates (negative test)",
+    "turtle-syntax-bad-struct-07"
+);
+
+negative_test!(
+    turtle_syntax_bad_kw_01,
+    "'A' is not a keyword (negative test)",
+    "turtle-syntax-bad-kw-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_kw_02,
+    "'a' cannot be used as subject (negative test)",
+    "turtle-syntax-bad-kw-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_kw_03,
+    "'a' cannot be used as object (negative test)",
+    "turtle-syntax-bad-kw-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_kw_04,
+    "'true' cannot be used as subject (negative test)",
+    "turtle-syntax-bad-kw-04"
+);
+
+negative_test!(
+    turtle_syntax_bad_kw_05,
+    "'true' cannot be used as object (negative test)",
+    "turtle-syntax-bad-kw-05"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_01,
+    "{{}} fomulae not in Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_02,
+    "= is not Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_03,
+    "N3 paths not in Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_04,
+    "N3 paths not in Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-04"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_05,
+    "N3 is...of not in Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-05"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_06,
+    "N3 paths not in Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-06"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_07,
+    "@keywords is not Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-07"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_08,
+    "@keywords is not Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-08"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_09,
+    "=> is not Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-09"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_10,
+    "<= is not Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-10"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_11,
+    "@forSome is not Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-11"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_12,
+    "@forAll is not Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-12"
+);
+
+negative_test!(
+    turtle_syntax_bad_n3_extras_13,
+    "@keywords is not Turtle (negative test)",
+    "turtle-syntax-bad-n3-extras-13"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_08,
+    "missing '.' (negative test)",
+    "turtle-syntax-bad-struct-08"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_09,
+    "extra '.' (negative test)",
+    "turtle-syntax-bad-struct-09"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_10,
+    "extra '.' (negative test)",
+    "turtle-syntax-bad-struct-10"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_11,
+    "trailing ';' no '.' (negative test)",
+    "turtle-syntax-bad-struct-11"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_12,
+    "subject, predicate, no object (negative test)",
+    "turtle-syntax-bad-struct-12"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_13,
+    "subject, predicate, no object (negative test)",
+    "turtle-syntax-bad-struct-13"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_14,
+    "literal as subject (negative test)",
+    "turtle-syntax-bad-struct-14"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_15,
+    "literal as predicate (negative test)",
+    "turtle-syntax-bad-struct-15"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_16,
+    "bnode as predicate (negative test)",
+    "turtle-syntax-bad-struct-16"
+);
+
+negative_test!(
+    turtle_syntax_bad_struct_17,
+    "labeled bnode as predicate (negative test)",
+    "turtle-syntax-bad-struct-17"
+);
+
+negative_test!(
+    turtle_syntax_bad_lang_01,
+    "langString with bad lang (negative test)",
+    "turtle-syntax-bad-lang-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_esc_01,
+    "Bad string escape (negative test)",
+    "turtle-syntax-bad-esc-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_esc_02,
+    "Bad string escape (negative test)",
+    "turtle-syntax-bad-esc-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_esc_03,
+    "Bad string escape (negative test)",
+    "turtle-syntax-bad-esc-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_esc_04,
+    "Bad string escape (negative test)",
+    "turtle-syntax-bad-esc-04"
+);
+
+negative_test!(
+    turtle_syntax_bad_pname_01,
+    "'~' must be escaped in pname (negative test)",
+    "turtle-syntax-bad-pname-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_pname_02,
+    "Bad %-sequence in pname (negative test)",
+    "turtle-syntax-bad-pname-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_pname_03,
+    "Bad unicode escape in pname (negative test)",
+    "turtle-syntax-bad-pname-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_string_01,
+    "mismatching string literal open/close (negative test)",
+    "turtle-syntax-bad-string-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_string_02,
+    "mismatching string literal open/close (negative test)",
+    "turtle-syntax-bad-string-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_string_03,
+    "mismatching string literal long/short (negative test)",
+    "turtle-syntax-bad-string-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_string_04,
+    "mismatching long string literal open/close (negative test)",
+    "turtle-syntax-bad-string-04"
+);
+
+negative_test!(
+    turtle_syntax_bad_string_05,
+    "Long literal with missing end (negative test)",
+    "turtle-syntax-bad-string-05"
+);
+
+negative_test!(
+    turtle_syntax_bad_string_06,
+    "Long literal with extra quote (negative test)",
+    "turtle-syntax-bad-string-06"
+);
+
+negative_test!(
+    turtle_syntax_bad_string_07,
+    "Long literal with extra squote (negative test)",
+    "turtle-syntax-bad-string-07"
+);
+
+negative_test!(
+    turtle_syntax_bad_num_01,
+    "Bad number format (negative test)",
+    "turtle-syntax-bad-num-01"
+);
+
+negative_test!(
+    turtle_syntax_bad_num_02,
+    "Bad number format (negative test)",
+    "turtle-syntax-bad-num-02"
+);
+
+negative_test!(
+    turtle_syntax_bad_num_03,
+    "Bad number format (negative test)",
+    "turtle-syntax-bad-num-03"
+);
+
+negative_test!(
+    turtle_syntax_bad_num_04,
+    "Bad number format (negative test)",
+    "turtle-syntax-bad-num-04"
+);
+
+negative_test!(
+    turtle_syntax_bad_num_05,
+    "Bad number format (negative test)",
+    "turtle-syntax-bad-num-05"
+);
+
+eval_test!(
+    turtle_eval_struct_01,
+    "triple with IRIs",
+    "turtle-eval-struct-01",
+    "turtle-eval-struct-01"
+);
+
+eval_test!(
+    turtle_eval_struct_02,
+    "triple with IRIs and embedded whitespace",
+    "turtle-eval-struct-02",
+    "turtle-eval-struct-02"
+);
+
+eval_test!(
+    turtle_subm_01,
+    "Blank subject",
+    "turtle-subm-01",
+    "turtle-subm-01"
+);
+
+eval_test!(
+    turtle_subm_02,
+    "@prefix and qnames",
+    "turtle-subm-02",
+    "turtle-subm-02"
+);
+
+eval_test!(
+    turtle_subm_03,
+    ", operator",
+    "turtle-subm-03",
+    "turtle-subm-03"
+);
+
+eval_test!(
+    turtle_subm_04,
+    "; operator",
+    "turtle-subm-04",
+    "turtle-subm-04"
+);
+
+eval_test!(
+    turtle_subm_05,
+    "empty [] as subject and object",
+    "turtle-subm-05",
+    "turtle-subm-05"
+);
+
+eval_test!(
+    turtle_subm_06,
+    "non-empty [] as subject and object",
+    "turtle-subm-06",
+    "turtle-subm-06"
+);
+
+eval_test!(
+    turtle_subm_07,
+    "'a' as predicate",
+    "turtle-subm-07",
+    "turtle-subm-07"
+);
+
+eval_test!(
+    turtle_subm_08,
+    "simple collection",
+    "turtle-subm-08",
+    "turtle-subm-08"
+);
+
+eval_test!(
+    turtle_subm_09,
+    "empty collection",
+    "turtle-subm-09",
+    "turtle-subm-09"
+);
+
+eval_test!(
+    turtle_subm_10,
+    "integer datatyped literal",
+    "turtle-subm-10",
+    "turtle-subm-10"
+);
+
+eval_test!(
+    turtle_subm_11,
+    "decimal integer canonicalization",
+    "turtle-subm-11",
+    "turtle-subm-11"
+);
+
+eval_test!(
+    turtle_subm_12,
+    "- and _ in names and qnames",
+    "turtle-subm-12",
+    "turtle-subm-12"
+);
+
+eval_test!(
+    turtle_subm_13,
+    "tests for rdf:_<numbers> and other qnames starting with _",
+    "turtle-subm-13",
+    "turtle-subm-13"
+);
+
+eval_test!(
+    turtle_subm_14,
+    "bare : allowed",
+    "turtle-subm-14",
+    "turtle-subm-14"
+);
+
+eval_test!(
+    turtle_subm_15,
+    "simple long literal",
+    "turtle-subm-15",
+    "turtle-subm-15"
+);
+
+eval_test!(
+    turtle_subm_16,
+    "long literals with escapes",
+    "turtle-subm-16",
+    "turtle-subm-16"
+);
+
+eval_test!(
+    turtle_subm_17,
+    "floating point number",
+    "turtle-subm-17",
+    "turtle-subm-17"
+);
+
+eval_test!(
+    turtle_subm_18,
+    "empty literals, normal and long variant",
+    "turtle-subm-18",
+    "turtle-subm-18"
+);
+
+eval_test!(
+    turtle_subm_19,
+    "positive integer, decimal and doubles",
+    "turtle-subm-19",
+    "turtle-subm-19"
+);
+
+eval_test!(
+    turtle_subm_20,
+    "negative integer, decimal and doubles",
+    "turtle-subm-20",
+    "turtle-subm-20"
+);
+
+eval_test!(
+    turtle_subm_21,
+    "long literal ending in double quote",
+    "turtle-subm-21",
+    "turtle-subm-21"
+);
+
+eval_test!(
+    turtle_subm_22,
+    "boolean literals",
+    "turtle-subm-22",
+    "turtle-subm-22"
+);
+
+eval_test!(
+    turtle_subm_23,
+    "comments",
+    "turtle-subm-23",
+    "turtle-subm-23"
+);
+
+eval_test!(
+    turtle_subm_24,
+    "no final mewline",
+    "turtle-subm-24",
+    "turtle-subm-24"
+);
+
+eval_test!(
+    turtle_subm_25,
+    "repeating a @prefix changes pname definition",
+    "turtle-subm-25",
+    "turtle-subm-25"
+);
+
+eval_test!(
+    turtle_subm_26,
+    "Variations on decimal canonicalization",
+    "turtle-subm-26",
+    "turtle-subm-26"
+);
+
+eval_test!(
+    turtle_subm_27,
+    "Repeating @base changes base for relative IRI lookup",
+    "turtle-subm-27",
+    "turtle-subm-27"
+);
+
+negative_test!(
+    turtle_eval_bad_01,
+    "Bad IRI : good escape, bad charcater (negative evaluation test)",
+    "turtle-eval-bad-01"
+);
+
+negative_test!(
+    turtle_eval_bad_02,
+    "Bad IRI : hex 3C is < (negative evaluation test)",
+    "turtle-eval-bad-02"
+);
+
+negative_test!(
+    turtle_eval_bad_03,
+    "Bad IRI : hex 3E is  (negative evaluation test)",
+    "turtle-eval-bad-03"
+);
+
+negative_test!(
+    turtle_eval_bad_04,
+    "Bad IRI : {{abc}} (negative evaluation test)",
+    "turtle-eval-bad-04"
+);
+
+eval_test!(
+    turtle_comment_following_localname,
+    "comment following localName",
+    "comment_following_localName",
+    "IRI_spo"
+);
+
+eval_test!(
+    turtle_number_sign_following_localname,
+    "number sign following localName",
+    "number_sign_following_localName",
+    "number_sign_following_localName"
+);
+
+eval_test!(
+    turtle_comment_following_pname_ns,
+    "comment following PNAME_NS",
+    "comment_following_PNAME_NS",
+    "comment_following_PNAME_NS"
+);
+
+eval_test!(
+    turtle_number_sign_following_pname_ns,
+    "number sign following PNAME_NS",
+    "number_sign_following_PNAME_NS",
+    "number_sign_following_PNAME_NS"
+);
+
+eval_test!(
+    turtle_literal_long2_with_reverse_solidus,
+    "REVERSE SOLIDUS at end of LITERAL_LONG2",
+    "LITERAL_LONG2_with_REVERSE_SOLIDUS",
+    "LITERAL_LONG2_with_REVERSE_SOLIDUS"
+);
+
+negative_test!(
+    turtle_syntax_bad_literal2_with_langtag_and_datatype,
+    "Bad number format (negative test)",
+    "turtle-syntax-bad-LITERAL2_with_langtag_and_datatype"
+);
+
+eval_test!(
+    turtle_two_literal_long2s,
+    "two LITERAL_LONG2s testing quote delimiter overrun",
+    "two_LITERAL_LONG2s",
+    "two_LITERAL_LONG2s"
+);
+
+eval_test!(
+    turtle_langtagged_long_with_subtag,
+    "langtagged LONG with subtag '''Cheers'''@en-UK",
+    "langtagged_LONG_with_subtag",
+    "langtagged_LONG_with_subtag"
+);
+
+negative_test!(
+    turtle_syntax_bad_blank_label_dot_end,
+    "Blank node label must not end in dot",
+    "turtle-syntax-bad-blank-label-dot-end"
+);
+
+negative_test!(
+    turtle_syntax_bad_number_dot_in_anon,
+    "Dot delimeter may not appear in anonymous nodes",
+    "turtle-syntax-bad-number-dot-in-anon"
+);
+
+negative_test!(
+    turtle_syntax_bad_ln_dash_start,
+    "Local name must not begin with dash",
+    "turtle-syntax-bad-ln-dash-start"
+);
+
+negative_test!(
+    turtle_syntax_bad_ln_escape,
+    "Bad hex escape in local name",
+    "turtle-syntax-bad-ln-escape"
+);
+
+negative_test!(
+    turtle_syntax_bad_ln_escape_start,
+    "Bad hex escape at start of local name",
+    "turtle-syntax-bad-ln-escape-start"
+);
+
+negative_test!(
+    turtle_syntax_bad_ns_dot_end,
+    "Prefix must not end in dot",
+    "turtle-syntax-bad-ns-dot-end"
+);
+
+negative_test!(
+    turtle_syntax_bad_ns_dot_start,
+    "Prefix must not start with dot",
+    "turtle-syntax-bad-ns-dot-start"
+);
+
+negative_test!(
+    turtle_syntax_bad_missing_ns_dot_end,
+    "Prefix must not end in dot (error in triple, not prefix directive like turtle-syntax-bad-ns-dot-end)",
+    "turtle-syntax-bad-missing-ns-dot-end"
+);
+
+negative_test!(
+    turtle_syntax_bad_missing_ns_dot_start,
+    "Prefix must not start with dot (error in triple, not prefix directive like turtle-syntax-bad-ns-dot-end)",
+    "turtle-syntax-bad-missing-ns-dot-start"
+);
+
+positive_test!(
+    turtle_syntax_ln_dots,
+    "Dots in pname local names",
+    "turtle-syntax-ln-dots"
+);
+
+positive_test!(
+    turtle_syntax_ln_colons,
+    "Colons in pname local names",
+    "turtle-syntax-ln-colons"
+);
+
+positive_test!(
+    turtle_syntax_ns_dots,
+    "Dots in namespace names",
+    "turtle-syntax-ns-dots"
+);
+
+positive_test!(
+    turtle_syntax_blank_label,
+    "Characters allowed in blank node labels",
+    "turtle-syntax-blank-label"
+);
@@ -0,0 +1,151 @@
+#![cfg(all(feature = "watch", feature = "nt", feature = "turtle"))]
+
+use rdftk_core::model::graph::Graph;
+use rdftk_io::watch::{WatchOptions, Watcher};
+use std::fs::{read_to_string, remove_dir_all, write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn temporary_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("rdftk-watch-{}-{}", name, std::process::id()));
+    let _ = remove_dir_all(&path);
+    std::fs::create_dir_all(&path).unwrap();
+    path
+}
+
+const VALID: &str = r#"@prefix ex: <http://example.org/> .
+ex:a ex:b ex:c ;
+    ex:d [ ex:e "one" ] .
+"#;
+
+#[test]
+fn watch_reports_syntax_errors() {
+    let dir = temporary_dir("errors");
+    let path = dir.join("graph.ttl");
+    let output = dir.join("graph.nt");
+    write(
+        &path,
+        "@prefix ex: <http://example.org/> .\nex:a ex:b ex:c .\nex:a ex:b .\n",
+    )
+    .unwrap();
+
+    let mut watcher = Watcher::new(&path, WatchOptions::default().with_output(&output));
+    let report = watcher.poll().unwrap().unwrap();
+    assert!(!report.is_clean());
+    assert_eq!(report.diagnostics().len(), 1);
+    assert_eq!(report.diagnostics()[0].line(), 3);
+    assert_eq!(report.diagnostics()[0].column(), 11);
+    assert_eq!(report.diagnostics()[0].text(), "ex:a ex:b .");
+    // a graph with errors is never written.
+    assert!(report.written().is_none());
+    assert!(!output.exists());
+    assert!(report
+        .to_string()
+        .contains("graph.ttl:3:11: expected object"));
+
+    assert!(watcher.poll().unwrap().is_none());
+
+    write(&path, VALID).unwrap();
+    let report = watcher.poll().unwrap().unwrap();
+    assert!(report.is_clean());
+    assert_eq!(report.statements(), 3);
+    assert_eq!(report.written(), Some(&output));
+    assert_eq!(read_to_string(&output).unwrap().lines().count(), 3);
+    remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch_reports_every_invalid_ntriples_statement() {
+    let dir = temporary_dir("nt");
+    let path = dir.join("graph.nt");
+    write(
+        &path,
+        r#"<http://example.org/a> <http://example.org/b> "one" .
+<http://example.org/a> <http://example.org/b> .
+<http://example.org/a> <http://example.org/b> "three" .
+<http://example.org/a> "four" .
+"#,
+    )
+    .unwrap();
+
+    let mut watcher = Watcher::new(&path, WatchOptions::default());
+    let report = watcher.poll().unwrap().unwrap();
+    assert_eq!(report.statements(), 2);
+    assert_eq!(
+        report
+            .diagnostics()
+            .iter()
+            .map(|diagnostic| diagnostic.line())
+            .collect::<Vec<usize>>(),
+        vec![2, 4]
+    );
+    remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch_runs_checks_and_writes_canonical_output() {
+    let dir = temporary_dir("checks");
+    let path = dir.join("graph.ttl");
+    let output = dir.join("formatted.nt");
+    write(&path, VALID).unwrap();
+
+    let mut watcher = Watcher::new(
+        &path,
+        WatchOptions::default().with_output(&output).canonical(),
+    )
+    .with_check(|graph: &dyn Graph| {
+        graph
+            .subjects()
+            .into_iter()
+            .filter(|subject| subject.is_blank())
+            .map(|subject| format!("{} is a blank node", subject))
+            .collect()
+    });
+    let report = watcher.poll().unwrap().unwrap();
+    assert_eq!(report.problems().len(), 1);
+    assert!(!report.is_clean());
+    let written = read_to_string(&output).unwrap();
+    assert!(written.contains("_:c14n0"));
+
+    // rewriting the same graph, with a different label, produces the same output.
+    write(
+        &path,
+        VALID.replace("[ ex:e \"one\" ]", "_:x .\n_:x ex:e \"one\""),
+    )
+    .unwrap();
+    let report = watcher.poll().unwrap().unwrap();
+    assert_eq!(report.statements(), 3);
+    let mut before: Vec<&str> = written.lines().collect();
+    let after = read_to_string(&output).unwrap();
+    let mut after: Vec<&str> = after.lines().collect();
+    before.sort_unstable();
+    after.sort_unstable();
+    assert_eq!(before, after);
+    remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch_run_until_stopped() {
+    let dir = temporary_dir("run");
+    let path = dir.join("graph.ttl");
+    write(&path, VALID).unwrap();
+
+    let mut watcher = Watcher::new(
+        &path,
+        WatchOptions::default().with_interval(Duration::from_millis(1)),
+    );
+    let mut reports = 0;
+    watcher.run(|report| {
+        assert!(report.unwrap().is_clean());
+        reports += 1;
+        false
+    });
+    assert_eq!(reports, 1);
+
+    let mut watcher = Watcher::new(dir.join("missing.ttl"), WatchOptions::default());
+    assert!(watcher.poll().is_err());
+    let mut watcher = Watcher::new(dir.join("graph.unknown"), WatchOptions::default());
+    write(dir.join("graph.unknown"), VALID).unwrap();
+    assert!(watcher.poll().is_err());
+    remove_dir_all(&dir).unwrap();
+}
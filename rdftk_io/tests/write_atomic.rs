@@ -0,0 +1,104 @@
+#![cfg(feature = "nt")]
+
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::output::{partial_path, OutputOptions};
+use rdftk_io::{
+    write_graph_to_path, write_graph_to_path_with_options, write_graph_to_string, GraphWriter,
+};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+mod common;
+
+///
+/// Writes a partial line and then fails, as a writer would on an invalid graph.
+///
+struct FailingWriter;
+
+impl GraphWriter for FailingWriter {
+    fn write(&self, w: &mut impl Write, _: &GraphRef) -> Result<()> {
+        w.write_all(b"<http://example.org/partial> ")
+            .map_err(|_| ErrorKind::ReadWrite("test".to_string()))?;
+        Err(ErrorKind::Msg("failed".to_string()).into())
+    }
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rdftk_io-atomic-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn sorted_lines(s: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = s.lines().collect();
+    lines.sort_unstable();
+    lines
+}
+
+fn dir_entries(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn partial_path_appends_extension() {
+    assert_eq!(
+        partial_path("out/graph.nt.gz"),
+        PathBuf::from("out/graph.nt.gz.partial")
+    );
+}
+
+#[test]
+fn write_leaves_no_temporary_file() {
+    let dir = temp_dir("success");
+    let path = dir.join("graph.nt");
+    let graph = common::tony_benn_graph();
+
+    write_graph_to_path(&NTripleWriter::default(), &graph, &path).unwrap();
+
+    assert_eq!(dir_entries(&dir), vec!["graph.nt".to_string()]);
+    let written = std::fs::read_to_string(&path).unwrap();
+    let expected = write_graph_to_string(&NTripleWriter::default(), &graph).unwrap();
+    assert_eq!(sorted_lines(&written), sorted_lines(&expected));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn failed_write_keeps_previous_file() {
+    let dir = temp_dir("failure");
+    let path = dir.join("graph.nt");
+    let graph = common::tony_benn_graph();
+    std::fs::write(&path, "previous\n").unwrap();
+
+    assert!(write_graph_to_path(&FailingWriter, &graph, &path).is_err());
+
+    assert_eq!(dir_entries(&dir), vec!["graph.nt".to_string()]);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "previous\n");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn failed_write_keeps_partial_output() {
+    let dir = temp_dir("partial");
+    let path = dir.join("graph.nt");
+    let graph = common::tony_benn_graph();
+    let options = OutputOptions::default().keep_partial(true);
+    assert!(options.is_keeping_partial());
+
+    assert!(write_graph_to_path_with_options(&FailingWriter, &graph, &path, &options).is_err());
+
+    assert!(!path.exists());
+    assert_eq!(dir_entries(&dir), vec!["graph.nt.partial".to_string()]);
+    assert_eq!(
+        std::fs::read_to_string(partial_path(&path)).unwrap(),
+        "<http://example.org/partial> "
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
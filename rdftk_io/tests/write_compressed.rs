@@ -0,0 +1,119 @@
+#![cfg(feature = "nt")]
+
+use rdftk_io::compression::Compression;
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::{write_graph_to_path, write_graph_to_path_with, write_graph_to_string};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+mod common;
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rdftk_io-{}-{}", std::process::id(), name))
+}
+
+fn sorted_lines(s: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = s.lines().collect();
+    lines.sort_unstable();
+    lines
+}
+
+fn read_bytes(path: &Path) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let _ = File::open(path).unwrap().read_to_end(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn compression_from_path() {
+    assert_eq!(
+        Compression::from_path(Path::new("graph.ttl.gz")),
+        Compression::Gzip
+    );
+    assert_eq!(
+        Compression::from_path(Path::new("graph.nt.XZ")),
+        Compression::Xz
+    );
+    assert_eq!(
+        Compression::from_path(Path::new("graph.ttl")),
+        Compression::None
+    );
+    assert_eq!(
+        Compression::from_path(Path::new("graph")),
+        Compression::None
+    );
+    assert_eq!(Compression::Gzip.file_extension(), Some("gz"));
+    assert_eq!(Compression::None.file_extension(), None);
+}
+
+#[test]
+fn write_uncompressed_to_path() {
+    let graph = common::tony_benn_graph();
+    let writer = NTripleWriter::default();
+    let expected = write_graph_to_string(&writer, &graph).unwrap();
+
+    let path = temp_path("plain.nt");
+    write_graph_to_path(&writer, &graph, &path).unwrap();
+    let written = String::from_utf8(read_bytes(&path)).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sorted_lines(&written), sorted_lines(&expected));
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn write_gzip_to_path() {
+    use flate2::read::GzDecoder;
+
+    let graph = common::tony_benn_graph();
+    let writer = NTripleWriter::default();
+    let expected = write_graph_to_string(&writer, &graph).unwrap();
+
+    let path = temp_path("graph.nt.gz");
+    write_graph_to_path(&writer, &graph, &path).unwrap();
+    let bytes = read_bytes(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(&bytes[..2], &[0x1f, 0x8b]);
+
+    let mut written = String::new();
+    let _ = GzDecoder::new(bytes.as_slice())
+        .read_to_string(&mut written)
+        .unwrap();
+    assert_eq!(sorted_lines(&written), sorted_lines(&expected));
+}
+
+#[test]
+#[cfg(feature = "xz")]
+fn write_xz_to_path() {
+    use xz2::read::XzDecoder;
+
+    let graph = common::tony_benn_graph();
+    let writer = NTripleWriter::default();
+    let expected = write_graph_to_string(&writer, &graph).unwrap();
+
+    // the explicit compression overrides the file extension.
+    let path = temp_path("graph.nt");
+    write_graph_to_path_with(&writer, &graph, &path, Compression::Xz).unwrap();
+    let bytes = read_bytes(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let mut written = String::new();
+    let _ = XzDecoder::new(bytes.as_slice())
+        .read_to_string(&mut written)
+        .unwrap();
+    assert_eq!(sorted_lines(&written), sorted_lines(&expected));
+}
+
+#[test]
+#[cfg(not(feature = "xz"))]
+fn write_unsupported_compression() {
+    let graph = common::tony_benn_graph();
+    let path = temp_path("unsupported.nt.xz");
+    assert!(!Compression::Xz.is_supported());
+    assert!(
+        write_graph_to_path_with(&NTripleWriter::default(), &graph, &path, Compression::Xz)
+            .is_err()
+    );
+    assert!(!path.exists());
+}
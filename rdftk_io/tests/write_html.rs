@@ -0,0 +1,90 @@
+#![cfg(all(feature = "json-ld", feature = "turtle"))]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_io::html::HtmlEmbeddingWriter;
+use rdftk_io::json_ld::reader::JsonLdReader;
+use rdftk_io::json_ld::writer::{JsonLdOptions, JsonLdWriter};
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::turtle::writer::TurtleWriter;
+use rdftk_io::{write_graph_to_string, GraphReader};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
+
+mod common;
+
+fn script_graph() -> GraphRef {
+    let factory = statement_factory();
+    graph_factory().graph_from(
+        &[factory
+            .statement(
+                factory.named_subject(IRIRef::from(
+                    IRI::from_str("http://example.org/page").unwrap(),
+                )),
+                IRIRef::from(IRI::from_str("http://purl.org/dc/elements/1.1/title").unwrap()),
+                factory.literal_object(
+                    literal_factory().literal("Escaping </script> and <!-- comments -->"),
+                ),
+            )
+            .unwrap()],
+        None,
+    )
+}
+
+fn content(output: &str) -> &str {
+    let start = output.find(">\n").unwrap() + 2;
+    let end = output.rfind("\n</script>").unwrap();
+    &output[start..end]
+}
+
+#[test]
+fn write_json_ld_script() {
+    let graph = common::tony_benn_graph();
+    let writer =
+        HtmlEmbeddingWriter::json_ld(JsonLdWriter::new(JsonLdOptions::compacted())).with_id("data");
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    println!("{}", output);
+
+    assert!(output.starts_with("<script type=\"application/ld+json\" id=\"data\">\n"));
+    assert!(output.ends_with("\n</script>\n"));
+    let _: serde_json::Value = serde_json::from_str(content(&output)).unwrap();
+}
+
+#[test]
+fn write_escaped_json_ld_script() {
+    let graph = script_graph();
+    let writer = HtmlEmbeddingWriter::json_ld(JsonLdWriter::new(JsonLdOptions::compacted()));
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    println!("{}", output);
+
+    assert_eq!(output.matches("</script").count(), 1);
+    assert!(!output.contains("<!--"));
+    let read_back = JsonLdReader::default()
+        .read(&mut content(&output).as_bytes(), graph_factory())
+        .unwrap();
+    assert_eq!(
+        read_back.borrow().statements().next().unwrap().object(),
+        graph.borrow().statements().next().unwrap().object()
+    );
+}
+
+#[test]
+fn write_escaped_turtle_script() {
+    let graph = script_graph();
+    let writer = HtmlEmbeddingWriter::turtle(TurtleWriter::default()).with_id("a\"b");
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    println!("{}", output);
+
+    assert!(output.starts_with("<script type=\"text/turtle\" id=\"a&quot;b\">\n"));
+    assert_eq!(output.matches("</script").count(), 1);
+    assert!(!output.contains("<!--"));
+    let read_back = TurtleReader::default()
+        .read(&mut content(&output).as_bytes(), graph_factory())
+        .unwrap();
+    assert_eq!(
+        read_back.borrow().statements().next().unwrap().object(),
+        graph.borrow().statements().next().unwrap().object()
+    );
+}
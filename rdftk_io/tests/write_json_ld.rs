@@ -1 +1,122 @@
+#![cfg(feature = "json-ld")]
 
+use rdftk_core::simple::graph_factory;
+use rdftk_io::json_ld::reader::JsonLdReader;
+use rdftk_io::json_ld::writer::{JsonLdOptions, JsonLdWriter};
+use rdftk_io::{write_graph_to_string, GraphReader};
+use rdftk_iri::{IRIRef, IRI};
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+mod common;
+
+fn write_json_ld(options: JsonLdOptions) -> Value {
+    let graph = common::tony_benn_graph();
+    let framed = options.frame.is_some();
+    let output = write_graph_to_string(&JsonLdWriter::new(options), &graph).unwrap();
+    println!("# format: JSON-LD\n{}", output);
+
+    if !framed {
+        // every unframed form must read back into the same number of statements.
+        let read_back = JsonLdReader::default()
+            .read(&mut output.as_bytes(), graph_factory())
+            .unwrap();
+        assert_eq!(read_back.borrow().len(), graph.borrow().len());
+    }
+
+    serde_json::from_str(&output).unwrap()
+}
+
+#[test]
+fn write_json_ld_expanded() {
+    let output = write_json_ld(JsonLdOptions::expanded());
+    assert_eq!(
+        output,
+        json!([{
+            "@id": "http://en.wikipedia.org/wiki/Tony_Benn",
+            "http://purl.org/dc/elements/1.1/description": [{
+                "@type": ["http://xmlns.com/foaf/0.1/Person"],
+                "http://xmlns.com/foaf/0.1/name": [{ "@value": "Tony Benn" }]
+            }],
+            "http://purl.org/dc/elements/1.1/publisher": [{ "@value": "Wikipedia" }],
+            "http://purl.org/dc/elements/1.1/title": [{ "@value": "Tony Benn" }]
+        }])
+    );
+}
+
+#[test]
+fn write_json_ld_compacted() {
+    let output = write_json_ld(JsonLdOptions::default());
+    assert_eq!(
+        output["@context"]["dc"],
+        json!("http://purl.org/dc/elements/1.1/")
+    );
+    assert_eq!(output["dc:title"], json!("Tony Benn"));
+    assert_eq!(output["dc:description"]["@type"], json!("foaf:Person"));
+    assert_eq!(output["dc:description"]["foaf:name"], json!("Tony Benn"));
+
+    let mappings = graph_factory().mapping_factory().empty();
+    mappings.borrow_mut().set_default_namespace(IRIRef::from(
+        IRI::from_str("http://purl.org/dc/elements/1.1/").unwrap(),
+    ));
+    let output = write_json_ld(JsonLdOptions::compacted_with(mappings));
+    assert_eq!(
+        output["@context"],
+        json!({ "@vocab": "http://purl.org/dc/elements/1.1/" })
+    );
+    assert_eq!(output["publisher"], json!("Wikipedia"));
+    assert_eq!(
+        output["description"]["@type"],
+        json!("http://xmlns.com/foaf/0.1/Person")
+    );
+}
+
+#[test]
+fn write_json_ld_framed() {
+    let mut options = JsonLdOptions::compacted();
+    let _ = options.framed(json!({
+        "@context": { "foaf": "http://xmlns.com/foaf/0.1/" },
+        "@type": "foaf:Person"
+    }));
+    let output = write_json_ld(options);
+    assert_eq!(output["@id"], json!("_:B1"));
+    assert_eq!(output["foaf:name"], json!("Tony Benn"));
+    assert!(output.get("dc:title").is_none());
+
+    let mut options = JsonLdOptions::compacted();
+    let _ = options.framed(json!({
+        "@context": { "dc": "http://purl.org/dc/elements/1.1/" },
+        "dc:title": {},
+        "dc:description": { "@embed": "@never" },
+        "@explicit": true
+    }));
+    let output = write_json_ld(options);
+    assert_eq!(
+        output,
+        json!({
+            "@context": {
+                "dc": "http://purl.org/dc/elements/1.1/",
+                "foaf": "http://xmlns.com/foaf/0.1/",
+                "rdf": "http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+            },
+            "@id": "http://en.wikipedia.org/wiki/Tony_Benn",
+            "dc:title": "Tony Benn",
+            "dc:description": { "@id": "_:B1" }
+        })
+    );
+}
+
+#[test]
+fn write_json_ld_invalid_frame() {
+    for frame in [
+        json!("frame"),
+        json!({ "@embed": "@sometimes" }),
+        json!({ "@type": 1 }),
+    ] {
+        let mut options = JsonLdOptions::default();
+        let _ = options.framed(frame);
+        assert!(
+            write_graph_to_string(&JsonLdWriter::new(options), &common::tony_benn_graph()).is_err()
+        );
+    }
+}
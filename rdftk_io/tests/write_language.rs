@@ -0,0 +1,110 @@
+#![cfg(feature = "nt")]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_io::language::{LanguageFilter, LanguageFilteringWriter};
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::write_graph_to_string;
+use rdftk_iri::IRI;
+use rdftk_names::{dc, rdf, rdfs};
+use std::str::FromStr;
+
+fn multilingual_graph() -> GraphRef {
+    let factory = statement_factory();
+    let literals = literal_factory();
+    let subject = factory.named_subject(IRI::from_str("http://example.org/cat").unwrap().into());
+    let label = |text: &str, language: Option<&str>| {
+        factory
+            .statement(
+                subject.clone(),
+                rdfs::label().clone(),
+                factory.literal_object(match language {
+                    None => literals.literal(text),
+                    Some(language) => {
+                        literals.with_language(text, LanguageTag::from_str(language).unwrap())
+                    }
+                }),
+            )
+            .unwrap()
+    };
+    let statements = vec![
+        factory
+            .statement(
+                subject.clone(),
+                rdf::a_type().clone(),
+                factory.named_object(rdfs::class().clone()),
+            )
+            .unwrap(),
+        label("Cat", None),
+        label("Cat", Some("en")),
+        label("Cat", Some("en-GB")),
+        label("Chat", Some("fr")),
+        label("Katze", Some("de")),
+        label("Gato", None),
+        factory
+            .statement(
+                subject.clone(),
+                dc::elements::description().clone(),
+                factory.literal_object(literals.long(4)),
+            )
+            .unwrap(),
+    ];
+    graph_factory().graph_from(&statements, None)
+}
+
+fn write(filter: LanguageFilter) -> String {
+    let writer = LanguageFilteringWriter::new(NTripleWriter::default(), filter);
+    let output = write_graph_to_string(&writer, &multilingual_graph()).unwrap();
+    println!("{}", output);
+    output
+}
+
+#[test]
+fn filter_matches_ranges() {
+    let filter = LanguageFilter::new(&["EN", "fr-CA"]);
+    assert!(filter.matches(&LanguageTag::from_str("en").unwrap()));
+    assert!(filter.matches(&LanguageTag::from_str("en-GB").unwrap()));
+    assert!(!filter.matches(&LanguageTag::from_str("eng").unwrap()));
+    assert!(!filter.matches(&LanguageTag::from_str("fr").unwrap()));
+    assert!(filter.matches(&LanguageTag::from_str("fr-CA").unwrap()));
+    assert!(LanguageFilter::new(&["*"]).matches(&LanguageTag::from_str("de").unwrap()));
+}
+
+#[test]
+fn write_selected_languages() {
+    let output = write(LanguageFilter::new(&["en"]));
+    assert_eq!(output.lines().count(), 6);
+    assert!(output.contains("\"Cat\"@en-GB"));
+    assert!(!output.contains("Chat"));
+    assert!(!output.contains("Katze"));
+    assert!(output.contains("\"Gato\" ."));
+}
+
+#[test]
+fn write_without_untagged() {
+    let mut filter = LanguageFilter::new(&["fr"]);
+    let _ = filter.keep_untagged(false);
+    let output = write(filter);
+    assert_eq!(output.lines().count(), 3);
+    assert!(output.contains("\"Chat\"@fr"));
+    assert!(output.contains("\"4\"^^"));
+}
+
+#[test]
+fn write_without_untagged_duplicates() {
+    let mut filter = LanguageFilter::new(&["en"]);
+    let _ = filter.drop_untagged_duplicates(true);
+    let output = write(filter);
+    assert_eq!(output.lines().count(), 5);
+    assert!(!output.contains("\"Cat\" ."));
+    assert!(output.contains("\"Gato\" ."));
+
+    // the untagged duplicate is only dropped if its tagged equivalent is written.
+    let mut filter = LanguageFilter::new(&["de"]);
+    let _ = filter.drop_untagged_duplicates(true);
+    let output = write(filter);
+    assert!(output.contains("\"Cat\" ."));
+}
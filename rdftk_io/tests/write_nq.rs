@@ -2,8 +2,8 @@
 
 use rdftk_core::model::data_set::{DataSetRef, GraphName};
 use rdftk_core::simple::data_set::data_set_factory;
-use rdftk_io::nq::writer::NQuadDataSetWriter;
-use rdftk_io::write_data_set_to_string;
+use rdftk_io::nq::writer::{NQuadDataSetWriter, NQuadGraphWriter};
+use rdftk_io::{write_data_set_to_string, StatementWriter};
 use rdftk_iri::IRI;
 use std::str::FromStr;
 
@@ -38,3 +38,25 @@ fn write_to_nquads() {
     assert!(output.contains("_:B1 <http://xmlns.com/foaf/0.1/name> \"Tony Benn\" <http://en.wikipedia.org/wiki/Tony_Benn> .\n"));
     assert!(output.contains("_:B1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://xmlns.com/foaf/0.1/Person> <http://en.wikipedia.org/wiki/Tony_Benn> .\n"));
 }
+
+#[test]
+fn write_nquads_from_statements() {
+    let graph = common::tony_benn_graph();
+    let name = GraphName::named_ref(IRI::from_str("http://example.org/export").unwrap().into());
+
+    let mut writer = NQuadGraphWriter::named(name);
+    let mut buffer: Vec<u8> = Default::default();
+    writer.begin(&mut buffer).unwrap();
+    for statement in graph.borrow().statements() {
+        writer.write_statement(&mut buffer, statement).unwrap();
+    }
+    writer.end(&mut buffer).unwrap();
+    let output = String::from_utf8(buffer).unwrap();
+    println!("# format: N-Quads\n{}", output);
+
+    assert_eq!(output.lines().count(), 5);
+    assert!(output.contains("<http://en.wikipedia.org/wiki/Tony_Benn> <http://purl.org/dc/elements/1.1/title> \"Tony Benn\" <http://example.org/export> .\n"));
+    assert!(output
+        .lines()
+        .all(|line| line.ends_with(" <http://example.org/export> .")));
+}
@@ -1,7 +1,8 @@
 #![cfg(feature = "nt")]
 
+use rdftk_core::model::statement::StatementRef;
 use rdftk_io::nt::writer::NTripleWriter;
-use rdftk_io::write_graph_to_string;
+use rdftk_io::{write_graph_to_string, write_statements};
 
 mod common;
 
@@ -22,3 +23,43 @@ fn write_to_ntriples() {
     assert!(output.contains("_:B1 <http://xmlns.com/foaf/0.1/name> \"Tony Benn\" .\n"));
     assert!(output.contains("_:B1 <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://xmlns.com/foaf/0.1/Person> .\n"));
 }
+
+#[test]
+fn write_to_ntriples_sorted() {
+    let writer = NTripleWriter::default().sorted(true);
+
+    let output = write_graph_to_string(&writer, &common::tony_benn_graph()).unwrap();
+    println!("# format: N-Triples\n{}", output);
+
+    let lines: Vec<&str> = output.lines().collect();
+    let mut sorted_lines = lines.clone();
+    sorted_lines.sort_unstable();
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines, sorted_lines);
+
+    for _ in 0..5 {
+        assert_eq!(
+            write_graph_to_string(&writer, &common::tony_benn_graph()).unwrap(),
+            output
+        );
+    }
+}
+
+#[test]
+fn write_ntriples_from_statements() {
+    let graph = common::tony_benn_graph();
+    let statements: Vec<StatementRef> = graph.borrow().statements().cloned().collect();
+
+    let mut writer = NTripleWriter::default();
+    let mut buffer: Vec<u8> = Default::default();
+    write_statements(&mut writer, &mut buffer, statements).unwrap();
+    let streamed = String::from_utf8(buffer).unwrap();
+    println!("# format: N-Triples\n{}", streamed);
+
+    let mut streamed: Vec<&str> = streamed.lines().collect();
+    streamed.sort_unstable();
+    let written = write_graph_to_string(&NTripleWriter::default(), &graph).unwrap();
+    let mut written: Vec<&str> = written.lines().collect();
+    written.sort_unstable();
+    assert_eq!(streamed, written);
+}
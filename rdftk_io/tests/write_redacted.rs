@@ -0,0 +1,33 @@
+#![cfg(feature = "nt")]
+
+use rdftk_core::model::security::{RedactionPolicy, SecurityLabels};
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::redact::RedactingWriter;
+use rdftk_io::write_graph_to_string;
+
+mod common;
+
+#[test]
+fn write_redacted_graph() {
+    let graph = common::tony_benn_graph();
+    let mut labels = SecurityLabels::default();
+    for statement in graph.borrow().statements() {
+        if statement.predicate().to_string().ends_with("/publisher") {
+            labels.add_label(statement, "internal".into());
+        }
+    }
+
+    let mut policy = RedactionPolicy::default();
+    let _ = policy.drop("internal".into());
+    let writer = RedactingWriter::new(NTripleWriter::default(), labels, policy);
+
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    println!("# format: N-Triples (redacted)\n{}", output);
+    assert_eq!(output.lines().count(), 4);
+    assert!(!output.contains("Wikipedia"));
+
+    let report = writer.report();
+    println!("{}", report);
+    assert_eq!(report.dropped(), 1);
+    assert!(report.to_string().contains("dropped [internal]: <http://en.wikipedia.org/wiki/Tony_Benn> <http://purl.org/dc/elements/1.1/publisher> \"Wikipedia\""));
+}
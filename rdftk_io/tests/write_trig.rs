@@ -0,0 +1,36 @@
+#![cfg(feature = "trig")]
+
+use rdftk_core::model::data_set::{DataSetRef, GraphName};
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_io::trig::writer::TriGWriter;
+use rdftk_io::write_data_set_to_string;
+use rdftk_iri::IRI;
+use std::str::FromStr;
+
+mod common;
+
+#[test]
+fn write_to_trig() {
+    let data_set = data_set_factory().data_set(None);
+    {
+        let mut data_set = data_set.borrow_mut();
+        data_set.insert(
+            GraphName::named_ref(IRI::from_str("http://example.org/graph").unwrap().into()),
+            common::tony_benn_graph(),
+        );
+    }
+
+    let writer = TriGWriter::default();
+
+    let result = write_data_set_to_string(&writer, &(data_set as DataSetRef));
+    assert!(result.is_ok());
+    let output = result.unwrap();
+    println!("# format: trig\n{}", output);
+
+    assert!(output.contains("@prefix dc: <http://purl.org/dc/elements/1.1/> .\n"));
+    assert!(output.contains("<http://example.org/graph> {\n"));
+    assert!(output.contains("  <http://en.wikipedia.org/wiki/Tony_Benn> dc:"));
+    assert!(output.contains("dc:description [\n"));
+    assert!(output.contains("      foaf:name \"Tony Benn\""));
+    assert!(output.ends_with("}\n\n"));
+}
@@ -1,9 +1,16 @@
 #![cfg(feature = "turtle")]
 
+use rdftk_core::model::graph::order::PredicateOrder;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_io::turtle::reader::TurtleReader;
 use rdftk_io::turtle::writer::{TurtleOptions, TurtleWriter};
-use rdftk_io::write_graph_to_string;
+use rdftk_io::{write_graph_to_string, GraphReader};
 use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::dc::elements as dc;
+use rdftk_names::rdf;
 use std::str::FromStr;
+use std::sync::Arc;
 
 mod common;
 
@@ -70,3 +77,290 @@ fn write_to_turtle_with_options() {
     assert!(output.contains("dc:description _:B1"));
     assert!(output.contains("\n_:B1"));
 }
+
+#[test]
+fn write_to_turtle_relabelling_invalid_blank_nodes() {
+    let factory = statement_factory();
+    let knows = IRIRef::from(IRI::from_str("http://xmlns.com/foaf/0.1/knows").unwrap());
+    let graph = graph_factory().graph_from(
+        &[
+            factory
+                .statement(
+                    factory.blank_subject_named("b0").unwrap(),
+                    knows.clone(),
+                    factory.blank_object_named("not a label").unwrap(),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    factory.blank_subject_named("not a label").unwrap(),
+                    knows,
+                    factory.blank_object_named("b0").unwrap(),
+                )
+                .unwrap(),
+        ],
+        None,
+    );
+
+    let options = TurtleOptions {
+        preserve_blank_node_labels: true,
+        ..Default::default()
+    };
+    let output = write_graph_to_string(&TurtleWriter::new(options), &graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(!output.contains("not a label"));
+    assert!(output.contains("_:b0 <http://xmlns.com/foaf/0.1/knows> _:b1"));
+    assert!(output.contains("_:b1 <http://xmlns.com/foaf/0.1/knows> _:b0"));
+}
+
+#[test]
+fn write_to_turtle_with_quoted_triples() {
+    let factory = statement_factory();
+    let iri =
+        |name: &str| IRIRef::from(IRI::from_str(&format!("http://example.org/{}", name)).unwrap());
+    let quoted = factory
+        .statement(
+            factory.named_subject(iri("alice")),
+            iri("knows"),
+            factory.blank_object_named("bob").unwrap(),
+        )
+        .unwrap();
+    let graph = graph_factory().graph_from(
+        &[
+            factory
+                .statement(
+                    factory.statement_subject(quoted.clone()),
+                    iri("source"),
+                    factory.named_object(iri("hr")),
+                )
+                .unwrap(),
+            factory
+                .statement(
+                    factory.named_subject(iri("crm")),
+                    iri("asserts"),
+                    factory.statement_object(quoted),
+                )
+                .unwrap(),
+        ],
+        None,
+    );
+
+    let output = write_graph_to_string(&TurtleWriter::default(), &graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains(
+        "<< <http://example.org/alice> <http://example.org/knows> _:bob >> <http://example.org/source> <http://example.org/hr> ."
+    ));
+    assert!(output.contains(
+        "<http://example.org/crm> <http://example.org/asserts> << <http://example.org/alice> <http://example.org/knows> _:bob >> ."
+    ));
+
+    let graph = TurtleReader::default()
+        .read(&mut output.as_bytes(), graph_factory())
+        .unwrap();
+    let graph = graph.borrow();
+    assert_eq!(graph.len(), 2);
+    assert!(graph.statements().any(|st| st.subject().is_statement()));
+    assert!(graph.statements().any(|st| st.object().is_statement()));
+}
+
+#[test]
+fn write_to_turtle_with_lists() {
+    let graph = TurtleReader::default()
+        .read(
+            &mut r#"@prefix ex: <http://example.org/> .
+ex:book a ex:Book ;
+    ex:authors ( ex:alice ex:bob "Carol" ) ;
+    ex:chapters ( [ ex:title "One" ] ) .
+"#
+            .as_bytes(),
+            graph_factory(),
+        )
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 12);
+
+    let output = write_graph_to_string(&TurtleWriter::default(), &graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains("#type> ex:Book"));
+    assert!(output.contains("#first>"));
+    assert!(!output.contains("( "));
+
+    let options = TurtleOptions::default()
+        .use_type_keyword(true)
+        .use_list_syntax(true);
+    let output = write_graph_to_string(&TurtleWriter::new(options), &graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains("@prefix ex: <http://example.org/> .\n"));
+    assert!(output.contains("a ex:Book"));
+    assert!(output.contains("ex:authors ( ex:alice ex:bob \"Carol\" )"));
+    assert!(!output.contains("#first"));
+    assert!(!output.contains("#rest"));
+
+    let read_back = TurtleReader::default()
+        .read(&mut output.as_bytes(), graph_factory())
+        .unwrap();
+    assert_eq!(read_back.borrow().len(), 12);
+}
+
+#[test]
+fn write_to_turtle_compressing_iris() {
+    let graph = TurtleReader::default()
+        .read(
+            &mut r#"@prefix ex: <http://example.org/> .
+@prefix item: <http://example.org/item/> .
+ex:shelf ex:holds item:a42, <http://example.org/item/42abc>, <http://example.org/item/v1.> .
+"#
+            .as_bytes(),
+            graph_factory(),
+        )
+        .unwrap();
+
+    let output = write_graph_to_string(&TurtleWriter::default(), &graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(output.contains("ex:shelf ex:holds"));
+    assert!(output.contains("item:a42"));
+    assert!(output.contains("<http://example.org/item/42abc>"));
+    assert!(output.contains("<http://example.org/item/v1.>"));
+
+    let read_back = TurtleReader::default()
+        .read(&mut output.as_bytes(), graph_factory())
+        .unwrap();
+    assert_eq!(read_back.borrow().len(), 3);
+}
+
+#[test]
+fn write_to_turtle_with_malformed_lists() {
+    let graph = TurtleReader::default()
+        .read(
+            &mut r#"@prefix ex: <http://example.org/> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+ex:a ex:list _:l1 .
+ex:b ex:list _:l1 .
+_:l1 rdf:first ex:one ; rdf:rest rdf:nil .
+"#
+            .as_bytes(),
+            graph_factory(),
+        )
+        .unwrap();
+
+    let output = write_graph_to_string(&TurtleWriter::default(), &graph).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    assert!(!output.contains("( "));
+    assert!(output.contains("rdf:first"));
+}
+
+#[test]
+fn write_to_turtle_sorted() {
+    let writer = TurtleWriter::new(TurtleOptions {
+        sorted: true,
+        ..Default::default()
+    });
+
+    let output = write_graph_to_string(&writer, &common::tony_benn_graph()).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    let dc = output.find("@prefix dc:").unwrap();
+    let foaf = output.find("@prefix foaf:").unwrap();
+    let rdf = output.find("@prefix rdf:").unwrap();
+    assert!(dc < foaf && foaf < rdf);
+
+    let description = output.find("dc:description").unwrap();
+    let publisher = output.find("dc:publisher").unwrap();
+    let title = output.find("dc:title").unwrap();
+    assert!(description < publisher && publisher < title);
+
+    for _ in 0..5 {
+        assert_eq!(
+            write_graph_to_string(&writer, &common::tony_benn_graph()).unwrap(),
+            output
+        );
+    }
+}
+
+#[test]
+fn write_to_turtle_with_statement_order() {
+    let writer = TurtleWriter::new(TurtleOptions {
+        sorted: true,
+        statement_order: Some(Arc::new(PredicateOrder::new(&[
+            rdf::a_type().clone(),
+            dc::title().clone(),
+        ]))),
+        ..Default::default()
+    });
+
+    let output = write_graph_to_string(&writer, &common::tony_benn_graph()).unwrap();
+    println!("# format: turtle\n{}", output);
+
+    let title = output.find("dc:title").unwrap();
+    let description = output.find("dc:description").unwrap();
+    let publisher = output.find("dc:publisher").unwrap();
+    assert!(title < description && description < publisher);
+
+    let person = output.find("rdf:type foaf:Person").unwrap();
+    let name = output.find("foaf:name").unwrap();
+    assert!(person < name);
+}
+
+fn tagged_graph() -> rdftk_core::model::graph::GraphRef {
+    TurtleReader::default()
+        .read(
+            &mut r#"@prefix ex: <http://example.org/ns#> .
+ex:book ex:title "Turtle" ;
+    ex:tag "one", "two", "three", "four" .
+"#
+            .as_bytes(),
+            graph_factory(),
+        )
+        .unwrap()
+}
+
+#[test]
+fn write_to_turtle_with_layout_options() {
+    let graph = tagged_graph();
+    let write = |options: TurtleOptions| {
+        let output = write_graph_to_string(
+            &TurtleWriter::new(TurtleOptions {
+                sorted: true,
+                ..options
+            }),
+            &graph,
+        )
+        .unwrap();
+        println!("# format: turtle\n{}", output);
+        output
+    };
+
+    let output = write(Default::default());
+    assert!(output.contains("ex:book ex:tag \"four\" ,\n    \"one\" ,\n    \"three\" ,\n    \"two\" ;\n  ex:title \"Turtle\" ."));
+
+    let output = write(TurtleOptions {
+        indent_width: 4,
+        ..Default::default()
+    });
+    assert!(output.contains("ex:book ex:tag \"four\" ,\n        \"one\" ,\n        \"three\" ,\n        \"two\" ;\n    ex:title \"Turtle\" ."));
+
+    let output = write(TurtleOptions {
+        max_line_length: Some(80),
+        ..Default::default()
+    });
+    assert!(
+        output.contains("ex:book ex:tag \"four\" , \"one\" , \"three\" , \"two\" ;\n  ex:title")
+    );
+
+    let output = write(TurtleOptions {
+        max_line_length: Some(32),
+        align_objects: true,
+        ..Default::default()
+    });
+    assert!(output.contains(
+        "ex:book ex:tag \"four\" , \"one\" ,\n               \"three\" , \"two\" ;\n  ex:title"
+    ));
+    for line in output.lines().filter(|line| !line.starts_with('@')) {
+        assert!(line.len() <= 32);
+    }
+}
@@ -1,7 +1,11 @@
 #![cfg(feature = "xml")]
 
-use rdftk_io::write_graph_to_string;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::xml::reader::XmlReader;
 use rdftk_io::xml::writer::{XmlOptions, XmlStyle, XmlWriter};
+use rdftk_io::{write_graph_to_string, GraphReader};
+use rdftk_iri::{IRIRef, IRI};
+use std::str::FromStr;
 
 mod common;
 
@@ -13,6 +17,7 @@ fn write_to_flat_xml() {
         style: XmlStyle::Flat,
         pretty: true,
         encoding: "utf-8".to_string(),
+        typed_nodes: false,
     };
     let writer = XmlWriter::new(options);
 
@@ -30,6 +35,7 @@ fn write_to_striped_xml() {
         style: XmlStyle::Striped,
         pretty: true,
         encoding: "utf-8".to_string(),
+        typed_nodes: false,
     };
     let writer = XmlWriter::new(options);
 
@@ -38,3 +44,63 @@ fn write_to_striped_xml() {
     let output = result.unwrap();
     println!("# format: XML\n{}", output);
 }
+
+#[test]
+fn write_to_typed_striped_xml() {
+    let graph = common::tony_benn_graph();
+    {
+        let mappings = graph.borrow().prefix_mappings();
+        let mut mappings = mappings.borrow_mut();
+        mappings.remove("dc");
+        mappings.insert(
+            "dce",
+            IRIRef::from(IRI::from_str("http://purl.org/dc/elements/1.1/").unwrap()),
+        );
+    }
+
+    let writer = XmlWriter::new(XmlOptions::striped().typed_nodes().pretty().clone());
+
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    println!("# format: XML\n{}", output);
+    assert!(output.contains("xmlns:dce=\"http://purl.org/dc/elements/1.1/\""));
+    assert!(output.contains("<dce:title>Tony Benn</dce:title>"));
+    assert!(output.contains("<foaf:Person>"));
+    assert!(!output.contains("rdf:nodeID"));
+    assert!(!output.contains("rdf:type"));
+
+    let read = XmlReader::default()
+        .read(&mut output.as_bytes(), graph_factory())
+        .unwrap();
+    assert_eq!(read.borrow().len(), graph.borrow().len());
+}
+
+#[test]
+fn write_shared_blank_nodes_striped_xml() {
+    let mut xml = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/vocab#">
+  <rdf:Description rdf:about="http://example.org/a">
+    <ex:knows rdf:nodeID="shared"/>
+  </rdf:Description>
+  <rdf:Description rdf:about="http://example.org/b">
+    <ex:knows rdf:nodeID="shared"/>
+  </rdf:Description>
+  <rdf:Description rdf:nodeID="shared" ex:name="Shared"/>
+  <rdf:Description rdf:nodeID="x"><ex:next rdf:nodeID="y"/></rdf:Description>
+  <rdf:Description rdf:nodeID="y"><ex:next rdf:nodeID="x"/></rdf:Description>
+</rdf:RDF>"#
+        .as_bytes();
+    let graph = XmlReader::default()
+        .read(&mut xml, graph_factory())
+        .unwrap();
+
+    let writer = XmlWriter::new(XmlOptions::striped().plain().clone());
+    let output = write_graph_to_string(&writer, &graph).unwrap();
+    println!("# format: XML\n{}", output);
+    assert_eq!(output.matches("ex:knows rdf:nodeID=\"shared\"").count(), 2);
+
+    let read = XmlReader::default()
+        .read(&mut output.as_bytes(), graph_factory())
+        .unwrap();
+    assert_eq!(read.borrow().len(), graph.borrow().len());
+}
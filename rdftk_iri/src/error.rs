@@ -81,6 +81,11 @@ error_chain! {
             description("An error occurred parsing the IRI fragment.")
             display("An error occurred parsing the IRI fragment: {:?}.", s)
         }
+        #[doc = "The IRI scheme is not a registered scheme."]
+        UnregisteredScheme(s: String) {
+            description("The IRI scheme is not a registered scheme.")
+            display("The IRI scheme {:?} is not a registered scheme.", s)
+        }
         #[doc = "An error occurred normalizing an IRI component."]
         Normalization(c: Component) {
             description("An error occurred normalizing an IRI component.")
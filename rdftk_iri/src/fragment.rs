@@ -1,7 +1,7 @@
 #![allow(clippy::module_name_repetitions)]
 
-use crate::error::{Error as IriError, ErrorKind};
-use crate::pct_encoding::{fragment_map, pct_encode};
+use crate::error::{Error as IriError, ErrorKind, Result as IriResult};
+use crate::pct_encoding::{fragment_map, pct_encode, pct_normalize};
 use crate::{parse, ValidateStr};
 use crate::{Normalize, PercentEncoding};
 use std::fmt::{Display, Formatter};
@@ -64,7 +64,12 @@ impl ValidateStr for Fragment {
     }
 }
 
-impl Normalize for Fragment {}
+impl Normalize for Fragment {
+    // SPEC: RFC-3986 §6.2.2
+    fn normalize(self) -> IriResult<Self> {
+        Ok(Self(pct_normalize(&self.0)))
+    }
+}
 
 impl PercentEncoding for Fragment {
     fn encode(&self, for_uri: bool) -> Self
@@ -124,6 +124,22 @@ pub struct IRI {
 #[allow(clippy::upper_case_acronyms)]
 pub type IRIRef = Arc<IRI>;
 
+///
+/// The strictness of the checks made by [`IRI::validate`](struct.IRI.html#method.validate) and
+/// [`IRI::parse_with`](struct.IRI.html#method.parse_with). Every level includes the checks of the
+/// levels before it.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationLevel {
+    /// Only the syntax of the IRI is checked, any well-formed scheme is allowed; this is the
+    /// level applied by `IRI::from_str`.
+    #[default]
+    Syntax,
+    /// The scheme, if present, must also be one of the registered schemes known to
+    /// [`Scheme`](scheme/struct.Scheme.html).
+    RegisteredScheme,
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -251,6 +267,12 @@ impl Normalize for IRI {
     }
 }
 
+impl Normalize for IRIRef {
+    fn normalize(self) -> IriResult<Self> {
+        Ok(Arc::new(IRI::clone(&self).normalize()?))
+    }
+}
+
 impl PercentEncoding for IRI {
     fn encode(&self, for_uri: bool) -> Self
     where
@@ -383,6 +405,25 @@ impl IRI {
         Ok(iri)
     }
 
+    ///
+    /// Parse a new `IRI` from the string `s`, checking it at the validation `level`; parsing
+    /// with `ValidationLevel::Syntax` is the same as using `IRI::from_str`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use rdftk_iri::{ValidationLevel, IRI};
+    ///
+    /// assert!(IRI::parse_with("my-app://example.org/", ValidationLevel::Syntax).is_ok());
+    /// assert!(IRI::parse_with("my-app://example.org/", ValidationLevel::RegisteredScheme).is_err());
+    /// ```
+    ///
+    pub fn parse_with(s: &str, level: ValidationLevel) -> IriResult<Self> {
+        let iri = parse_iri(s)?;
+        iri.validate(level)?;
+        Ok(iri)
+    }
+
     // --------------------------------------------------------------------------------------------
 
     ///
@@ -451,6 +492,23 @@ impl IRI {
 
     // --------------------------------------------------------------------------------------------
 
+    ///
+    /// Check this `IRI` at the validation `level`, returning the first failed check as an error.
+    /// The syntax of an `IRI` is checked when it is constructed and so always succeeds.
+    ///
+    pub fn validate(&self, level: ValidationLevel) -> IriResult<()> {
+        if level >= ValidationLevel::RegisteredScheme {
+            if let Some(scheme) = &self.scheme {
+                if !scheme.is_registered() {
+                    return Err(ErrorKind::UnregisteredScheme(scheme.value().clone()).into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // --------------------------------------------------------------------------------------------
+
     ///
     /// Resolves the `IRI` value `relative` using `self` as the base.
     ///
@@ -259,6 +259,11 @@ use std::str::FromStr;
 /// 1. Normalization will ensure the correct case of certain components.
 /// 1. Normalization will removing any unnecessary "." and ".." segments from the path component of
 ///    a hierarchical URI.
+/// 1. Normalization will make the hexadecimal digits of percent-encoded octets upper case, and
+///    decode any octets that represent unreserved characters.
+///
+/// The trait is also implemented for [`IRIRef`](type.IRIRef.html), returning a new reference to the
+/// normalized value.
 ///
 pub trait Normalize {
     /// Return a normalized version of `self`. The default for normalization is to do nothing and
@@ -333,4 +338,4 @@ pub use fragment::Fragment;
 #[allow(clippy::module_inception)]
 #[doc(hidden)]
 pub mod iri;
-pub use iri::{IRIRef, IRI};
+pub use iri::{IRIRef, ValidationLevel, IRI};
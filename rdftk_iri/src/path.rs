@@ -17,7 +17,7 @@ TBD
 #![allow(clippy::module_name_repetitions)]
 
 use crate::error::{Component, Error as IriError, ErrorKind, Result as IriResult};
-use crate::pct_encoding::{path_map, pct_encode};
+use crate::pct_encoding::{path_map, pct_encode, pct_normalize};
 use crate::{parse, ValidateStr};
 use crate::{Normalize, PercentEncoding};
 use std::fmt::{Display, Formatter};
@@ -103,9 +103,9 @@ impl ValidateStr for Path {
 }
 
 impl Normalize for Path {
-    // SPEC: RFC-3986 §5.4
+    // SPEC: RFC-3986 §5.4, §6.2.2
     fn normalize(self) -> IriResult<Self> {
-        let mut segments = self.hierarchical_segments();
+        let mut segments = Self(pct_normalize(&self.0)).hierarchical_segments();
         let mut index: usize = 0;
         while index < segments.len() {
             let segment = segments.get(index).unwrap();
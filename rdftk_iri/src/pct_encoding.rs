@@ -88,6 +88,33 @@ pub(crate) fn pct_encode(s: &str, replacements: BTreeMap<char, String>, for_uri:
         .collect()
 }
 
+///
+/// Normalize the percent-encoded octets in `s` (RFC-3986 §6.2.2.1 and §6.2.2.2); hexadecimal
+/// digits are made upper case and any octet corresponding to an unreserved character is decoded.
+///
+pub(crate) fn pct_normalize(s: &str) -> String {
+    let mut normalized = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            let hex: String = chars.clone().take(2).collect();
+            if hex.len() == 2 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                let octet = u8::from_str_radix(&hex, 16).unwrap();
+                let _ = chars.nth(1);
+                let decoded = octet as char;
+                if octet.is_ascii_alphanumeric() || "-._~".contains(decoded) {
+                    normalized.push(decoded);
+                } else {
+                    normalized.push_str(&format!("%{:02X}", octet));
+                }
+                continue;
+            }
+        }
+        normalized.push(c);
+    }
+    normalized
+}
+
 // ------------------------------------------------------------------------------------------------
 // Implementations
 // ------------------------------------------------------------------------------------------------
@@ -12,7 +12,7 @@ TBD
 #![allow(clippy::module_name_repetitions)]
 
 use crate::error::{Component, Error as IriError, ErrorKind, Result as IriResult};
-use crate::pct_encoding::{pct_encode, query_map};
+use crate::pct_encoding::{pct_encode, pct_normalize, query_map};
 use crate::{parse, ValidateStr};
 use crate::{Normalize, PercentEncoding};
 use std::fmt::{Display, Formatter};
@@ -80,8 +80,9 @@ impl ValidateStr for Query {
 }
 
 impl Normalize for Query {
+    // SPEC: RFC-3986 §6.2.2
     fn normalize(self) -> IriResult<Self> {
-        unimplemented!()
+        Ok(Self(pct_normalize(&self.0)))
     }
 }
 
@@ -44,6 +44,97 @@ pub struct Scheme(String);
 // Implementations
 // ------------------------------------------------------------------------------------------------
 
+// The value of each of the known schemes below, sorted for search.
+const REGISTERED_SCHEMES: &[&str] = &[
+    "aaa",
+    "aaas",
+    "about",
+    "acap",
+    "acct",
+    "blob",
+    "cap",
+    "cid",
+    "coap",
+    "coaps",
+    "crid",
+    "data",
+    "dav",
+    "dict",
+    "dns",
+    "example",
+    "file",
+    "ftp",
+    "geo",
+    "go",
+    "gopher",
+    "http",
+    "https",
+    "iax",
+    "icap",
+    "im",
+    "imap",
+    "info",
+    "ipp",
+    "ipps",
+    "iris",
+    "iris.beep",
+    "iris.lws",
+    "iris.xpc",
+    "iris.xpcs",
+    "ldap",
+    "mailto",
+    "mid",
+    "msrp",
+    "msrps",
+    "mtqp",
+    "mupdate",
+    "news",
+    "nfs",
+    "ni",
+    "nih",
+    "nntp",
+    "opaquelocktoken",
+    "pkcs11",
+    "pop",
+    "pres",
+    "reload",
+    "rtsp",
+    "service",
+    "session",
+    "shttp",
+    "sieve",
+    "sip",
+    "sips",
+    "sms",
+    "snmp",
+    "soap.beep",
+    "soap.beeps",
+    "stun",
+    "stuns",
+    "tag",
+    "tel",
+    "telnet",
+    "tftp",
+    "thismessage",
+    "tip",
+    "tn3270",
+    "turn",
+    "turns",
+    "tv",
+    "urn",
+    "vemmi",
+    "vnc",
+    "ws",
+    "wss",
+    "xcon",
+    "xcon-userid",
+    "xmlrpc.beep",
+    "xmlrpc.beeps",
+    "xmpp",
+    "z39.50r",
+    "z39.50s",
+];
+
 impl PartialEq for Scheme {
     fn eq(&self, other: &Self) -> bool {
         self.0.to_lowercase() == other.0.to_lowercase()
@@ -530,4 +621,11 @@ impl Scheme {
     pub fn value(&self) -> &String {
         &self.0
     }
+
+    /// Return `true` if this is one of the known schemes above, ignoring case, else `false`.
+    pub fn is_registered(&self) -> bool {
+        REGISTERED_SCHEMES
+            .binary_search(&self.0.to_ascii_lowercase().as_str())
+            .is_ok()
+    }
 }
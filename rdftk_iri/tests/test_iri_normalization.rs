@@ -61,3 +61,34 @@ fn test_path() {
     normalize_and_compare("http://example.com/.", "http://example.com:80/");
     normalize_and_compare("http://example.com/..", "http://example.com:80/");
 }
+
+#[test]
+fn test_percent_encoding() {
+    normalize_and_compare("http://example.com/a%2fb", "http://example.com:80/a%2Fb");
+    normalize_and_compare("http://example.com/%7Euser", "http://example.com:80/~user");
+    normalize_and_compare("http://example.com/%41%62c", "http://example.com:80/Abc");
+    normalize_and_compare("http://example.com/a/%2E%2E/b", "http://example.com:80/b");
+}
+
+#[test]
+fn test_query_and_fragment() {
+    normalize_and_compare(
+        "http://example.com/?q=a%2fb%2D",
+        "http://example.com:80/?q=a%2Fb-",
+    );
+    normalize_and_compare(
+        "http://example.com/#s%5fone",
+        "http://example.com:80/#s_one",
+    );
+}
+
+#[test]
+fn test_normalize_iri_ref() {
+    use rdftk_iri::{IRIRef, Normalize, IRI};
+    use std::str::FromStr;
+
+    let iri = IRIRef::from(IRI::from_str("HTTP://EXAMPLE.com/a/./b").unwrap());
+    let normalized: IRIRef = iri.clone().normalize().unwrap();
+    assert_eq!(normalized.to_string(), "http://example.com:80/a/b");
+    assert_eq!(iri.to_string(), "HTTP://EXAMPLE.com/a/./b");
+}
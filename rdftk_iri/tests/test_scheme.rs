@@ -237,3 +237,25 @@ proptest! {
 fn test_unicode_gibberish() {
     assert!(Scheme::from_str("º").is_err());
 }
+
+#[test]
+fn test_registered_schemes() {
+    assert!(Scheme::http().is_registered());
+    assert!(Scheme::from_str("HTTPS").unwrap().is_registered());
+    assert!(Scheme::xcon_user_id().is_registered());
+    assert!(!Scheme::from_str("my-app").unwrap().is_registered());
+}
+
+#[test]
+fn test_validation_levels() {
+    use rdftk_iri::{ValidationLevel, IRI};
+
+    let iri = IRI::from_str("my-app://example.org/").unwrap();
+    assert!(iri.validate(ValidationLevel::Syntax).is_ok());
+    assert!(iri.validate(ValidationLevel::RegisteredScheme).is_err());
+
+    assert!(IRI::parse_with("http://example.org/", ValidationLevel::RegisteredScheme).is_ok());
+    assert!(IRI::parse_with("/relative/path", ValidationLevel::RegisteredScheme).is_ok());
+    assert!(IRI::parse_with("my-app:thing", ValidationLevel::RegisteredScheme).is_err());
+    assert!(IRI::parse_with("http://exa mple.org/", ValidationLevel::Syntax).is_err());
+}
@@ -0,0 +1,57 @@
+/*!
+Functions that create IRIs for the W3C [Data Catalog Vocabulary](https://www.w3.org/TR/vocab-dcat-3/)
+(DCAT) namespace.
+*/
+
+namespace! {
+    "dcat",
+    "http://www.w3.org/ns/dcat#",
+    {
+        catalog, "Catalog",
+        catalog_record, "CatalogRecord",
+        data_service, "DataService",
+        dataset, "Dataset",
+        dataset_series, "DatasetSeries",
+        distribution, "Distribution",
+        relationship, "Relationship",
+        resource, "Resource",
+        role, "Role",
+
+        access_service, "accessService",
+        access_url, "accessURL",
+        bbox, "bbox",
+        byte_size, "byteSize",
+        centroid, "centroid",
+        compress_format, "compressFormat",
+        contact_point, "contactPoint",
+        download_url, "downloadURL",
+        end_date, "endDate",
+        endpoint_description, "endpointDescription",
+        endpoint_url, "endpointURL",
+        first, "first",
+        had_role, "hadRole",
+        has_catalog, "catalog",
+        has_current_version, "hasCurrentVersion",
+        has_dataset, "dataset",
+        has_distribution, "distribution",
+        has_version, "hasVersion",
+        in_series, "inSeries",
+        keyword, "keyword",
+        landing_page, "landingPage",
+        last, "last",
+        media_type, "mediaType",
+        package_format, "packageFormat",
+        prev, "prev",
+        previous_version, "previousVersion",
+        qualified_relation, "qualifiedRelation",
+        record, "record",
+        serves_dataset, "servesDataset",
+        service, "service",
+        spatial_resolution_in_meters, "spatialResolutionInMeters",
+        start_date, "startDate",
+        temporal_resolution, "temporalResolution",
+        theme, "theme",
+        theme_taxonomy, "themeTaxonomy",
+        version, "version"
+    }
+}
@@ -79,6 +79,7 @@ extern crate paste;
 /// 1. An identifier for the vocabulary struct.
 /// 1. A constant, `PREFIX` that contains the string passed in the `$prefix` parameter.
 /// 1. A constant, `NAMESPACE` that contains the string passed in the `namespace` parameter.
+/// 1. A function, `local_names`, that returns each `$name` passed in the list of members.
 /// 1. For each pair of `$fn_name`, `$name` (assuming `foo` and `"Foo"`):
 ///    1. create a function `fn foo() -> IRI` that returns the name as a full IRI. This concatenates
 ///       `NAMESPACE` and `$name`.
@@ -125,6 +126,8 @@ macro_rules! namespace {
 
         const NAMESPACE: &str = $namespace;
 
+        const LOCAL_NAMES: &[&str] = &[$($name),*];
+
         lazy_static! {
             static ref NS_IRI: IRIRef = IRIRef::new(NAMESPACE.parse().unwrap());
             static ref NS_CACHE: HashMap<String, (IRIRef, String)> = make_cache();
@@ -154,6 +157,10 @@ macro_rules! namespace {
         #[doc = "Returns the IRI for this namespace."]
         pub fn namespace_iri() -> &'static IRIRef { &NS_IRI }
 
+        #[inline]
+        #[doc = "Returns the local name of each member of this namespace."]
+        pub fn local_names() -> &'static [&'static str] { LOCAL_NAMES }
+
         $(
             nsname!($fn_name, $name);
         )*
@@ -189,22 +196,128 @@ macro_rules! nsname {
     };
 }
 
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A description of one of the vocabularies provided by this crate; its default prefix, its
+/// namespace, and the local names of its members.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Vocabulary {
+    prefix: &'static str,
+    namespace: &'static rdftk_iri::IRIRef,
+    local_names: &'static [&'static str],
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return a description of each of the vocabularies provided by this crate.
+///
+pub fn vocabularies() -> Vec<Vocabulary> {
+    macro_rules! vocabulary {
+        ($($module:ident)::+) => {
+            Vocabulary {
+                prefix: $($module)::+::default_prefix(),
+                namespace: $($module)::+::namespace_iri(),
+                local_names: $($module)::+::local_names(),
+            }
+        };
+    }
+    vec![
+        vocabulary!(dc::dcam),
+        vocabulary!(dc::dcmi_type),
+        vocabulary!(dc::elements),
+        vocabulary!(dc::terms),
+        vocabulary!(dcat),
+        vocabulary!(foaf),
+        vocabulary!(geo),
+        vocabulary!(owl),
+        vocabulary!(prov),
+        vocabulary!(qudt::quantity_kind),
+        vocabulary!(qudt::schema),
+        vocabulary!(qudt::unit),
+        vocabulary!(rdf),
+        vocabulary!(rdfs),
+        vocabulary!(schema),
+        vocabulary!(shacl),
+        vocabulary!(skos),
+        vocabulary!(void),
+        vocabulary!(xsd),
+    ]
+}
+
+///
+/// Return the description of the vocabulary provided by this crate with the default prefix
+/// `prefix`, if any.
+///
+pub fn vocabulary(prefix: &str) -> Option<Vocabulary> {
+    vocabularies()
+        .into_iter()
+        .find(|vocabulary| vocabulary.prefix == prefix)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Vocabulary {
+    ///
+    /// Return the commonly used prefix for this vocabulary.
+    ///
+    pub fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    ///
+    /// Return the IRI for this vocabulary's namespace.
+    ///
+    pub fn namespace(&self) -> &'static rdftk_iri::IRIRef {
+        self.namespace
+    }
+
+    ///
+    /// Return the local name of each member of this vocabulary.
+    ///
+    pub fn local_names(&self) -> &'static [&'static str] {
+        self.local_names
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
 
 pub mod dc;
 
+pub mod dcat;
+
 pub mod foaf;
 
 pub mod geo;
 
 pub mod owl;
 
+pub mod prov;
+
+pub mod qudt;
+
 pub mod rdf;
 
 pub mod rdfs;
 
+pub mod schema;
+
+pub mod shacl;
+
+pub mod skos;
+
+pub mod void;
+
 pub mod xsd;
 
 // ------------------------------------------------------------------------------------------------
@@ -234,5 +347,18 @@ mod tests {
 
         assert_eq!(bar().to_string(), "heep://schema/com/p#Bar");
         assert_eq!(bar_qname(), "p:Bar");
+
+        assert_eq!(local_names(), &["Foo", "Bar"]);
+    }
+
+    #[test]
+    fn test_vocabularies() {
+        let foaf = vocabulary("foaf").unwrap();
+        assert_eq!(foaf.namespace(), foaf::namespace_iri());
+        assert!(foaf.local_names().contains(&"Person"));
+        assert!(vocabulary("unknown").is_none());
+        assert!(vocabularies()
+            .iter()
+            .all(|vocabulary| !vocabulary.local_names().is_empty()));
     }
 }
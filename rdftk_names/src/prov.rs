@@ -0,0 +1,96 @@
+/*!
+Functions that create IRIs for the W3C [PROV Ontology](https://www.w3.org/TR/prov-o/) (PROV-O)
+namespace; this includes the starting point, expanded, and qualified terms.
+*/
+
+namespace! {
+    "prov",
+    "http://www.w3.org/ns/prov#",
+    {
+        activity, "Activity",
+        agent, "Agent",
+        entity, "Entity",
+
+        acted_on_behalf_of, "actedOnBehalfOf",
+        ended_at_time, "endedAtTime",
+        started_at_time, "startedAtTime",
+        used, "used",
+        was_associated_with, "wasAssociatedWith",
+        was_attributed_to, "wasAttributedTo",
+        was_derived_from, "wasDerivedFrom",
+        was_generated_by, "wasGeneratedBy",
+        was_informed_by, "wasInformedBy",
+
+        bundle, "Bundle",
+        collection, "Collection",
+        empty_collection, "EmptyCollection",
+        location, "Location",
+        organization, "Organization",
+        person, "Person",
+        software_agent, "SoftwareAgent",
+
+        alternate_of, "alternateOf",
+        at_location, "atLocation",
+        generated, "generated",
+        generated_at_time, "generatedAtTime",
+        had_member, "hadMember",
+        had_primary_source, "hadPrimarySource",
+        influenced, "influenced",
+        invalidated, "invalidated",
+        invalidated_at_time, "invalidatedAtTime",
+        specialization_of, "specializationOf",
+        value, "value",
+        was_ended_by, "wasEndedBy",
+        was_influenced_by, "wasInfluencedBy",
+        was_invalidated_by, "wasInvalidatedBy",
+        was_quoted_from, "wasQuotedFrom",
+        was_revision_of, "wasRevisionOf",
+        was_started_by, "wasStartedBy",
+
+        activity_influence, "ActivityInfluence",
+        agent_influence, "AgentInfluence",
+        association, "Association",
+        attribution, "Attribution",
+        communication, "Communication",
+        delegation, "Delegation",
+        derivation, "Derivation",
+        end, "End",
+        entity_influence, "EntityInfluence",
+        generation, "Generation",
+        influence, "Influence",
+        instantaneous_event, "InstantaneousEvent",
+        invalidation, "Invalidation",
+        plan, "Plan",
+        primary_source, "PrimarySource",
+        quotation, "Quotation",
+        revision, "Revision",
+        role, "Role",
+        start, "Start",
+        usage, "Usage",
+
+        influence_activity, "activity",
+        influence_agent, "agent",
+        at_time, "atTime",
+        influence_entity, "entity",
+        had_activity, "hadActivity",
+        had_generation, "hadGeneration",
+        had_plan, "hadPlan",
+        had_role, "hadRole",
+        had_usage, "hadUsage",
+        influencer, "influencer",
+        qualified_association, "qualifiedAssociation",
+        qualified_attribution, "qualifiedAttribution",
+        qualified_communication, "qualifiedCommunication",
+        qualified_delegation, "qualifiedDelegation",
+        qualified_derivation, "qualifiedDerivation",
+        qualified_end, "qualifiedEnd",
+        qualified_generation, "qualifiedGeneration",
+        qualified_influence, "qualifiedInfluence",
+        qualified_invalidation, "qualifiedInvalidation",
+        qualified_primary_source, "qualifiedPrimarySource",
+        qualified_quotation, "qualifiedQuotation",
+        qualified_revision, "qualifiedRevision",
+        qualified_start, "qualifiedStart",
+        qualified_usage, "qualifiedUsage"
+    }
+}
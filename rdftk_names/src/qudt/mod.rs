@@ -0,0 +1,14 @@
+/*!
+Namespaces for the [Quantities, Units, Dimensions and Types](https://qudt.org/) (QUDT) ontology
+and its vocabularies of units and quantity kinds.
+*/
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod quantity_kind;
+
+pub mod schema;
+
+pub mod unit;
@@ -0,0 +1,26 @@
+/*!
+Functions that create IRIs for commonly used members of the
+[QUDT](https://qudt.org/vocab/quantitykind/) quantity kinds vocabulary.
+*/
+
+namespace! {
+    "quantitykind",
+    "http://qudt.org/vocab/quantitykind/",
+    {
+        area, "Area",
+        dimensionless, "Dimensionless",
+        electric_current, "ElectricCurrent",
+        energy, "Energy",
+        force, "Force",
+        frequency, "Frequency",
+        length, "Length",
+        mass, "Mass",
+        power, "Power",
+        pressure, "Pressure",
+        thermodynamic_temperature, "ThermodynamicTemperature",
+        time, "Time",
+        velocity, "Velocity",
+        voltage, "Voltage",
+        volume, "Volume"
+    }
+}
@@ -0,0 +1,31 @@
+/*!
+Functions that create IRIs for the [QUDT](https://qudt.org/schema/qudt/) schema namespace, the
+classes and properties used to describe quantities, their values, and their units. As in the
+`shacl` module, where a class and property share a name the class keeps the plain function name;
+`value_unit` returns the `qudt:unit` property and `quantity_has_value` the `qudt:quantityValue`
+property.
+*/
+
+namespace! {
+    "qudt",
+    "http://qudt.org/schema/qudt/",
+    {
+        quantity, "Quantity",
+        quantity_kind, "QuantityKind",
+        quantity_value, "QuantityValue",
+        unit, "Unit",
+
+        applicable_unit, "applicableUnit",
+        conversion_multiplier, "conversionMultiplier",
+        conversion_offset, "conversionOffset",
+        has_quantity_kind, "hasQuantityKind",
+        has_unit, "hasUnit",
+        numeric_value, "numericValue",
+        quantity_has_value, "quantityValue",
+        standard_uncertainty, "standardUncertainty",
+        symbol, "symbol",
+        ucum_code, "ucumCode",
+        value_unit, "unit",
+        value, "value"
+    }
+}
@@ -0,0 +1,66 @@
+/*!
+Functions that create IRIs for commonly used members of the [QUDT](https://qudt.org/vocab/unit/)
+units vocabulary. Note that the unit `IN` (inch) is returned by the function `inch` as `in` is a
+reserved word.
+*/
+
+namespace! {
+    "unit",
+    "http://qudt.org/vocab/unit/",
+    {
+        m, "M",
+        kilo_m, "KiloM",
+        centi_m, "CentiM",
+        milli_m, "MilliM",
+        micro_m, "MicroM",
+        inch, "IN",
+        ft, "FT",
+        yd, "YD",
+        mi, "MI",
+
+        kilo_gm, "KiloGM",
+        gm, "GM",
+        milli_gm, "MilliGM",
+        tonne, "TONNE",
+        lb, "LB",
+
+        sec, "SEC",
+        min, "MIN",
+        hr, "HR",
+        day, "DAY",
+
+        k, "K",
+        deg_c, "DEG_C",
+        deg_f, "DEG_F",
+
+        pa, "PA",
+        kilo_pa, "KiloPA",
+        mega_pa, "MegaPA",
+        bar, "BAR",
+        psi, "PSI",
+
+        m2, "M2",
+        m3, "M3",
+        l, "L",
+        milli_l, "MilliL",
+
+        j, "J",
+        kilo_j, "KiloJ",
+        kilo_w_hr, "KiloW-HR",
+        w, "W",
+        kilo_w, "KiloW",
+        mega_w, "MegaW",
+        n, "N",
+        kilo_n, "KiloN",
+
+        m_per_sec, "M-PER-SEC",
+        kilo_m_per_hr, "KiloM-PER-HR",
+        mi_per_hr, "MI-PER-HR",
+
+        hz, "HZ",
+        a, "A",
+        v, "V",
+        unitless, "UNITLESS",
+        percent, "PERCENT"
+    }
+}
@@ -0,0 +1,127 @@
+/*!
+Functions that create IRIs for the commonly used types and properties of the
+[Schema.org](https://schema.org/) vocabulary. The namespace used is `https://schema.org/`, data
+using the older `http://schema.org/` form will need to be mapped.
+*/
+
+namespace! {
+    "schema",
+    "https://schema.org/",
+    {
+        action, "Action",
+        aggregate_rating, "AggregateRating",
+        article, "Article",
+        book, "Book",
+        brand, "Brand",
+        contact_point, "ContactPoint",
+        country, "Country",
+        creative_work, "CreativeWork",
+        data_catalog, "DataCatalog",
+        data_download, "DataDownload",
+        dataset, "Dataset",
+        event, "Event",
+        geo_coordinates, "GeoCoordinates",
+        image_object, "ImageObject",
+        intangible, "Intangible",
+        local_business, "LocalBusiness",
+        media_object, "MediaObject",
+        offer, "Offer",
+        organization, "Organization",
+        person, "Person",
+        place, "Place",
+        postal_address, "PostalAddress",
+        product, "Product",
+        rating, "Rating",
+        review, "Review",
+        software_application, "SoftwareApplication",
+        thing, "Thing",
+        web_page, "WebPage",
+        web_site, "WebSite",
+
+        boolean_type, "Boolean",
+        date_type, "Date",
+        date_time_type, "DateTime",
+        integer_type, "Integer",
+        number_type, "Number",
+        text_type, "Text",
+        time_type, "Time",
+        url_type, "URL",
+
+        about, "about",
+        additional_type, "additionalType",
+        address, "address",
+        address_country, "addressCountry",
+        address_locality, "addressLocality",
+        address_region, "addressRegion",
+        has_aggregate_rating, "aggregateRating",
+        alternate_name, "alternateName",
+        author, "author",
+        birth_date, "birthDate",
+        has_brand, "brand",
+        citation, "citation",
+        has_contact_point, "contactPoint",
+        content_url, "contentUrl",
+        contributor, "contributor",
+        creator, "creator",
+        date_created, "dateCreated",
+        date_modified, "dateModified",
+        date_published, "datePublished",
+        description, "description",
+        distribution, "distribution",
+        duration, "duration",
+        email, "email",
+        encoding_format, "encodingFormat",
+        end_date, "endDate",
+        family_name, "familyName",
+        founder, "founder",
+        founding_date, "foundingDate",
+        funder, "funder",
+        geo, "geo",
+        given_name, "givenName",
+        has_part, "hasPart",
+        headline, "headline",
+        identifier, "identifier",
+        image, "image",
+        in_language, "inLanguage",
+        is_part_of, "isPartOf",
+        job_title, "jobTitle",
+        keywords, "keywords",
+        knows, "knows",
+        latitude, "latitude",
+        license, "license",
+        location, "location",
+        logo, "logo",
+        longitude, "longitude",
+        main_entity, "mainEntity",
+        main_entity_of_page, "mainEntityOfPage",
+        member, "member",
+        member_of, "memberOf",
+        name, "name",
+        offers, "offers",
+        organizer, "organizer",
+        parent_organization, "parentOrganization",
+        position, "position",
+        postal_code, "postalCode",
+        potential_action, "potentialAction",
+        price, "price",
+        price_currency, "priceCurrency",
+        publisher, "publisher",
+        rating_value, "ratingValue",
+        has_review, "review",
+        review_rating, "reviewRating",
+        same_as, "sameAs",
+        sku, "sku",
+        spatial_coverage, "spatialCoverage",
+        start_date, "startDate",
+        street_address, "streetAddress",
+        sub_organization, "subOrganization",
+        target, "target",
+        telephone, "telephone",
+        temporal_coverage, "temporalCoverage",
+        text, "text",
+        url, "url",
+        variable_measured, "variableMeasured",
+        version, "version",
+        works_for, "worksFor"
+    }
+}
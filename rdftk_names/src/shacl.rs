@@ -0,0 +1,83 @@
+/*!
+Functions that create IRIs for the W3C [Shapes Constraint Language](https://www.w3.org/TR/shacl/)
+(SHACL) namespace.
+*/
+
+namespace! {
+    "sh",
+    "http://www.w3.org/ns/shacl#",
+    {
+        shape, "Shape",
+        node_shape, "NodeShape",
+        property_shape, "PropertyShape",
+        severity, "Severity",
+        info, "Info",
+        warning, "Warning",
+        violation, "Violation",
+        validation_report, "ValidationReport",
+        validation_result, "ValidationResult",
+        iri, "IRI",
+        blank_node, "BlankNode",
+        literal, "Literal",
+        blank_node_or_iri, "BlankNodeOrIRI",
+        blank_node_or_literal, "BlankNodeOrLiteral",
+        iri_or_literal, "IRIOrLiteral",
+
+        target_class, "targetClass",
+        target_node, "targetNode",
+        target_objects_of, "targetObjectsOf",
+        target_subjects_of, "targetSubjectsOf",
+
+        path, "path",
+        name, "name",
+        description, "description",
+        message, "message",
+        shape_severity, "severity",
+        deactivated, "deactivated",
+        order, "order",
+        group, "group",
+
+        class, "class",
+        datatype, "datatype",
+        node_kind, "nodeKind",
+        min_count, "minCount",
+        max_count, "maxCount",
+        min_exclusive, "minExclusive",
+        min_inclusive, "minInclusive",
+        max_exclusive, "maxExclusive",
+        max_inclusive, "maxInclusive",
+        min_length, "minLength",
+        max_length, "maxLength",
+        pattern, "pattern",
+        flags, "flags",
+        language_in, "languageIn",
+        unique_lang, "uniqueLang",
+        equals, "equals",
+        disjoint, "disjoint",
+        less_than, "lessThan",
+        less_than_or_equals, "lessThanOrEquals",
+        not, "not",
+        and, "and",
+        or, "or",
+        xone, "xone",
+        node, "node",
+        property, "property",
+        qualified_value_shape, "qualifiedValueShape",
+        qualified_min_count, "qualifiedMinCount",
+        qualified_max_count, "qualifiedMaxCount",
+        closed, "closed",
+        ignored_properties, "ignoredProperties",
+        has_value, "hasValue",
+        in_values, "in",
+
+        conforms, "conforms",
+        result, "result",
+        focus_node, "focusNode",
+        result_path, "resultPath",
+        value, "value",
+        source_constraint_component, "sourceConstraintComponent",
+        source_shape, "sourceShape",
+        result_severity, "resultSeverity",
+        result_message, "resultMessage"
+    }
+}
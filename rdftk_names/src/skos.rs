@@ -0,0 +1,44 @@
+/*!
+Functions that create IRIs for the W3C [Simple Knowledge Organization System](https://www.w3.org/TR/skos-reference/)
+(SKOS) namespace.
+*/
+
+namespace! {
+    "skos",
+    "http://www.w3.org/2004/02/skos/core#",
+    {
+        collection, "Collection",
+        concept, "Concept",
+        concept_scheme, "ConceptScheme",
+        ordered_collection, "OrderedCollection",
+
+        alt_label, "altLabel",
+        broad_match, "broadMatch",
+        broader, "broader",
+        broader_transitive, "broaderTransitive",
+        change_note, "changeNote",
+        close_match, "closeMatch",
+        definition, "definition",
+        editorial_note, "editorialNote",
+        exact_match, "exactMatch",
+        example, "example",
+        has_top_concept, "hasTopConcept",
+        hidden_label, "hiddenLabel",
+        history_note, "historyNote",
+        in_scheme, "inScheme",
+        mapping_relation, "mappingRelation",
+        member, "member",
+        member_list, "memberList",
+        narrow_match, "narrowMatch",
+        narrower, "narrower",
+        narrower_transitive, "narrowerTransitive",
+        notation, "notation",
+        note, "note",
+        pref_label, "prefLabel",
+        related, "related",
+        related_match, "relatedMatch",
+        scope_note, "scopeNote",
+        semantic_relation, "semanticRelation",
+        top_concept_of, "topConceptOf"
+    }
+}
@@ -0,0 +1,43 @@
+/*!
+Functions that create IRIs for the W3C [Vocabulary of Interlinked Datasets](https://www.w3.org/TR/void/)
+(VoID) namespace.
+*/
+
+namespace! {
+    "void",
+    "http://rdfs.org/ns/void#",
+    {
+        dataset, "Dataset",
+        dataset_description, "DatasetDescription",
+        linkset, "Linkset",
+        technical_feature, "TechnicalFeature",
+
+        class, "class",
+        class_partition, "classPartition",
+        classes, "classes",
+        data_dump, "dataDump",
+        distinct_objects, "distinctObjects",
+        distinct_subjects, "distinctSubjects",
+        documents, "documents",
+        entities, "entities",
+        example_resource, "exampleResource",
+        feature, "feature",
+        in_dataset, "inDataset",
+        link_predicate, "linkPredicate",
+        objects_target, "objectsTarget",
+        open_search_description, "openSearchDescription",
+        properties, "properties",
+        property, "property",
+        property_partition, "propertyPartition",
+        root_resource, "rootResource",
+        sparql_endpoint, "sparqlEndpoint",
+        subjects_target, "subjectsTarget",
+        subset, "subset",
+        target, "target",
+        triples, "triples",
+        uri_lookup_endpoint, "uriLookupEndpoint",
+        uri_regex_pattern, "uriRegexPattern",
+        uri_space, "uriSpace",
+        vocabulary, "vocabulary"
+    }
+}
@@ -8,6 +8,7 @@ namespace! {
     {
         any_uri, "anyURI",
         base64_binary, "base64Binary",
+        date, "date",
         date_time, "dateTime",
         date_time_stamp, "dateTimeStamp",
         day_time_duration, "dayTimeDuration",
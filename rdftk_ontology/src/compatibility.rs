@@ -0,0 +1,869 @@
+/*!
+Compare two versions of an ontology, axiom by axiom, and classify each change as backward
+compatible or breaking, so that a new version of a vocabulary can be checked before it is
+published.
+
+The following axioms are compared:
+
+* **declarations**; classes, typed as `rdfs:Class` or `owl:Class`, and properties, typed as
+  `rdf:Property` or one of the OWL property types. Removing a term is breaking, adding one is not.
+* **hierarchy**; `rdfs:subClassOf` and `rdfs:subPropertyOf` between named terms. Removing a super
+  class or super property is breaking, as data loses the types it could previously be inferred to
+  have.
+* **domains and ranges**; `rdfs:domain` and `rdfs:range`. A change is breaking if it narrows the
+  constraint, that is, if the new version adds a class that is not already a (transitive) super
+  class of one of the classes in the old version.
+* **cardinality**; `owl:Restriction`s with `owl:minCardinality`, `owl:maxCardinality`, or
+  `owl:cardinality`, and their qualified forms, that are super classes of a named class. Raising a
+  minimum or lowering a maximum is breaking.
+* **characteristics**; making a property `owl:FunctionalProperty` or
+  `owl:InverseFunctionalProperty` is breaking.
+* **deprecation**; terms newly marked `owl:deprecated`, or typed as `owl:DeprecatedClass` or
+  `owl:DeprecatedProperty`, are reported as compatible changes.
+
+The `Display` implementation of `CompatibilityReport` is a migration report, listing the breaking
+changes, with the action required of data publishers, before the compatible ones.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::IRI;
+use rdftk_names::{owl, rdf};
+use rdftk_ontology::compatibility::{compare, ChangeKind};
+use std::str::FromStr;
+
+let factory = statement_factory();
+let class = |name: &str| {
+    factory.statement(
+        factory.named_subject(IRI::from_str(name).unwrap().into()),
+        rdf::a_type().clone(),
+        factory.named_object(owl::class().clone()),
+    ).unwrap()
+};
+let old = graph_factory().graph_from(
+    &[class("http://example.org/Person"), class("http://example.org/Agent")],
+    None,
+);
+let new = graph_factory().graph_from(&[class("http://example.org/Person")], None);
+
+let report = compare(&*old.borrow(), &*new.borrow());
+assert!(!report.is_compatible());
+assert_eq!(report.breaking().count(), 1);
+assert_eq!(report.changes()[0].kind(), &ChangeKind::ClassRemoved);
+assert_eq!(report.changes()[0].subject().to_string(), "http://example.org/Agent");
+```
+
+*/
+
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::{ObjectNodeRef, StatementRef};
+use rdftk_iri::IRIRef;
+use rdftk_names::{owl, rdf, rdfs};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Whether a change is safe for existing consumers of the ontology.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Compatibility {
+    /// Existing data and queries remain valid with the new version.
+    Compatible,
+    /// Existing data or queries may be invalid, or mean something different, with the new
+    /// version.
+    Breaking,
+}
+
+///
+/// The number of values a class allows for a property, from `owl:Restriction`s; the default is
+/// unconstrained, `0..*`.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cardinality {
+    min: u64,
+    max: Option<u64>,
+}
+
+///
+/// The kind of change made to a single term.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    /// A new class was declared.
+    ClassAdded,
+    /// A class was removed.
+    ClassRemoved,
+    /// A new property was declared.
+    PropertyAdded,
+    /// A property was removed.
+    PropertyRemoved,
+    /// The term is now deprecated.
+    Deprecated,
+    /// The class has a new named super class.
+    SuperClassAdded(IRIRef),
+    /// The class is no longer a sub class of the named class.
+    SuperClassRemoved(IRIRef),
+    /// The property has a new named super property.
+    SuperPropertyAdded(IRIRef),
+    /// The property is no longer a sub property of the named property.
+    SuperPropertyRemoved(IRIRef),
+    /// The domain of the property now excludes some resources it previously allowed.
+    DomainNarrowed {
+        /// The domain classes of the old version.
+        from: Vec<IRIRef>,
+        /// The domain classes of the new version.
+        to: Vec<IRIRef>,
+    },
+    /// The domain of the property now allows more resources.
+    DomainWidened {
+        /// The domain classes of the old version.
+        from: Vec<IRIRef>,
+        /// The domain classes of the new version.
+        to: Vec<IRIRef>,
+    },
+    /// The range of the property now excludes some values it previously allowed.
+    RangeNarrowed {
+        /// The range classes of the old version.
+        from: Vec<IRIRef>,
+        /// The range classes of the new version.
+        to: Vec<IRIRef>,
+    },
+    /// The range of the property now allows more values.
+    RangeWidened {
+        /// The range classes of the old version.
+        from: Vec<IRIRef>,
+        /// The range classes of the new version.
+        to: Vec<IRIRef>,
+    },
+    /// The class allows fewer values of the property.
+    CardinalityTightened {
+        /// The restricted property.
+        property: IRIRef,
+        /// The cardinality in the old version.
+        from: Cardinality,
+        /// The cardinality in the new version.
+        to: Cardinality,
+    },
+    /// The class allows more values of the property.
+    CardinalityRelaxed {
+        /// The restricted property.
+        property: IRIRef,
+        /// The cardinality in the old version.
+        from: Cardinality,
+        /// The cardinality in the new version.
+        to: Cardinality,
+    },
+    /// The property now has the characteristic, such as `owl:FunctionalProperty`.
+    CharacteristicAdded(IRIRef),
+    /// The property no longer has the characteristic.
+    CharacteristicRemoved(IRIRef),
+}
+
+///
+/// A single change to a term between the two versions.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Change {
+    subject: IRIRef,
+    kind: ChangeKind,
+}
+
+///
+/// All the changes between two versions of an ontology.
+///
+#[derive(Clone, Debug, Default)]
+pub struct CompatibilityReport {
+    changes: Vec<Change>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+type Relations = BTreeMap<IRIRef, BTreeSet<IRIRef>>;
+
+///
+/// The axioms of a single version of the ontology.
+///
+#[derive(Debug, Default)]
+struct Axioms {
+    classes: BTreeSet<IRIRef>,
+    properties: BTreeSet<IRIRef>,
+    deprecated: HashSet<IRIRef>,
+    super_classes: Relations,
+    super_properties: Relations,
+    domains: Relations,
+    ranges: Relations,
+    characteristics: Relations,
+    cardinalities: BTreeMap<(IRIRef, IRIRef), Cardinality>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Compare the `old` and `new` versions of an ontology, returning every change between them.
+///
+pub fn compare(old: &dyn Graph, new: &dyn Graph) -> CompatibilityReport {
+    let old = Axioms::read(old);
+    let new = Axioms::read(new);
+    let mut changes: Vec<Change> = Default::default();
+
+    diff_terms(
+        &old.classes,
+        &new.classes,
+        ChangeKind::ClassAdded,
+        ChangeKind::ClassRemoved,
+        &mut changes,
+    );
+    diff_terms(
+        &old.properties,
+        &new.properties,
+        ChangeKind::PropertyAdded,
+        ChangeKind::PropertyRemoved,
+        &mut changes,
+    );
+    for term in new.classes.iter().chain(new.properties.iter()) {
+        if new.deprecated.contains(term) && !old.deprecated.contains(term) {
+            changes.push(Change::new(term, ChangeKind::Deprecated));
+        }
+    }
+
+    let classes: BTreeSet<IRIRef> = old.classes.intersection(&new.classes).cloned().collect();
+    let properties: BTreeSet<IRIRef> = old
+        .properties
+        .intersection(&new.properties)
+        .cloned()
+        .collect();
+    diff_relations(
+        &classes,
+        &old.super_classes,
+        &new.super_classes,
+        ChangeKind::SuperClassAdded,
+        ChangeKind::SuperClassRemoved,
+        &mut changes,
+    );
+    diff_relations(
+        &properties,
+        &old.super_properties,
+        &new.super_properties,
+        ChangeKind::SuperPropertyAdded,
+        ChangeKind::SuperPropertyRemoved,
+        &mut changes,
+    );
+    diff_relations(
+        &properties,
+        &old.characteristics,
+        &new.characteristics,
+        ChangeKind::CharacteristicAdded,
+        ChangeKind::CharacteristicRemoved,
+        &mut changes,
+    );
+
+    for property in &properties {
+        let from = old.domains.get(property).cloned().unwrap_or_default();
+        let to = new.domains.get(property).cloned().unwrap_or_default();
+        if from != to {
+            let narrowed = new.narrows(&from, &to);
+            let (from, to) = (from.into_iter().collect(), to.into_iter().collect());
+            changes.push(Change::new(
+                property,
+                if narrowed {
+                    ChangeKind::DomainNarrowed { from, to }
+                } else {
+                    ChangeKind::DomainWidened { from, to }
+                },
+            ));
+        }
+        let from = old.ranges.get(property).cloned().unwrap_or_default();
+        let to = new.ranges.get(property).cloned().unwrap_or_default();
+        if from != to {
+            let narrowed = new.narrows(&from, &to);
+            let (from, to) = (from.into_iter().collect(), to.into_iter().collect());
+            changes.push(Change::new(
+                property,
+                if narrowed {
+                    ChangeKind::RangeNarrowed { from, to }
+                } else {
+                    ChangeKind::RangeWidened { from, to }
+                },
+            ));
+        }
+    }
+
+    let restricted: BTreeSet<&(IRIRef, IRIRef)> = old
+        .cardinalities
+        .keys()
+        .chain(new.cardinalities.keys())
+        .filter(|(class, _)| classes.contains(class))
+        .collect();
+    for key in restricted {
+        let from = old.cardinalities.get(key).copied().unwrap_or_default();
+        let to = new.cardinalities.get(key).copied().unwrap_or_default();
+        if from != to {
+            let (class, property) = key;
+            let property = property.clone();
+            changes.push(Change::new(
+                class,
+                if to.is_tighter_than(&from) {
+                    ChangeKind::CardinalityTightened { property, from, to }
+                } else {
+                    ChangeKind::CardinalityRelaxed { property, from, to }
+                },
+            ));
+        }
+    }
+
+    changes.sort_by(|lhs, rhs| {
+        rhs.compatibility()
+            .cmp(&lhs.compatibility())
+            .then_with(|| lhs.subject.cmp(&rhs.subject))
+    });
+    CompatibilityReport { changes }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Compatibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Compatible => "compatible",
+                Self::Breaking => "breaking",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Cardinality {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.max {
+            None => write!(f, "{}..*", self.min),
+            Some(max) => write!(f, "{}..{}", self.min, max),
+        }
+    }
+}
+
+impl Cardinality {
+    ///
+    /// Construct a new cardinality, `max` of `None` is unbounded.
+    ///
+    pub fn new(min: u64, max: Option<u64>) -> Self {
+        Self { min, max }
+    }
+
+    ///
+    /// The minimum number of values.
+    ///
+    pub fn min(&self) -> u64 {
+        self.min
+    }
+
+    ///
+    /// The maximum number of values, if bounded.
+    ///
+    pub fn max(&self) -> Option<u64> {
+        self.max
+    }
+
+    ///
+    /// Returns `true` if this cardinality disallows any count of values that `other` allows.
+    ///
+    pub fn is_tighter_than(&self, other: &Self) -> bool {
+        self.min > other.min
+            || match (self.max, other.max) {
+                (Some(max), Some(other)) => max < other,
+                (Some(_), None) => true,
+                (None, _) => false,
+            }
+    }
+
+    fn restrict(&mut self, min: Option<u64>, max: Option<u64>) {
+        if let Some(min) = min {
+            self.min = self.min.max(min);
+        }
+        if let Some(max) = max {
+            self.max = Some(self.max.map_or(max, |current| current.min(max)));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ChangeKind {
+    ///
+    /// Whether this kind of change is safe for existing consumers.
+    ///
+    pub fn compatibility(&self) -> Compatibility {
+        match self {
+            Self::ClassRemoved
+            | Self::PropertyRemoved
+            | Self::SuperClassRemoved(_)
+            | Self::SuperPropertyRemoved(_)
+            | Self::DomainNarrowed { .. }
+            | Self::RangeNarrowed { .. }
+            | Self::CardinalityTightened { .. }
+            | Self::CharacteristicAdded(_) => Compatibility::Breaking,
+            _ => Compatibility::Compatible,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let subject = &self.subject;
+        match &self.kind {
+            ChangeKind::ClassAdded => write!(f, "class <{}> added", subject),
+            ChangeKind::ClassRemoved => write!(f, "class <{}> removed", subject),
+            ChangeKind::PropertyAdded => write!(f, "property <{}> added", subject),
+            ChangeKind::PropertyRemoved => write!(f, "property <{}> removed", subject),
+            ChangeKind::Deprecated => write!(f, "<{}> deprecated", subject),
+            ChangeKind::SuperClassAdded(class) => {
+                write!(f, "class <{}> is now a sub class of <{}>", subject, class)
+            }
+            ChangeKind::SuperClassRemoved(class) => {
+                write!(
+                    f,
+                    "class <{}> is no longer a sub class of <{}>",
+                    subject, class
+                )
+            }
+            ChangeKind::SuperPropertyAdded(property) => write!(
+                f,
+                "property <{}> is now a sub property of <{}>",
+                subject, property
+            ),
+            ChangeKind::SuperPropertyRemoved(property) => write!(
+                f,
+                "property <{}> is no longer a sub property of <{}>",
+                subject, property
+            ),
+            ChangeKind::DomainNarrowed { from, to } => write!(
+                f,
+                "domain of <{}> narrowed from {} to {}",
+                subject,
+                join(from),
+                join(to)
+            ),
+            ChangeKind::DomainWidened { from, to } => write!(
+                f,
+                "domain of <{}> widened from {} to {}",
+                subject,
+                join(from),
+                join(to)
+            ),
+            ChangeKind::RangeNarrowed { from, to } => write!(
+                f,
+                "range of <{}> narrowed from {} to {}",
+                subject,
+                join(from),
+                join(to)
+            ),
+            ChangeKind::RangeWidened { from, to } => write!(
+                f,
+                "range of <{}> widened from {} to {}",
+                subject,
+                join(from),
+                join(to)
+            ),
+            ChangeKind::CardinalityTightened { property, from, to } => write!(
+                f,
+                "cardinality of <{}> on class <{}> tightened from {} to {}",
+                property, subject, from, to
+            ),
+            ChangeKind::CardinalityRelaxed { property, from, to } => write!(
+                f,
+                "cardinality of <{}> on class <{}> relaxed from {} to {}",
+                property, subject, from, to
+            ),
+            ChangeKind::CharacteristicAdded(characteristic) => {
+                write!(f, "property <{}> is now <{}>", subject, characteristic)
+            }
+            ChangeKind::CharacteristicRemoved(characteristic) => {
+                write!(
+                    f,
+                    "property <{}> is no longer <{}>",
+                    subject, characteristic
+                )
+            }
+        }
+    }
+}
+
+impl Change {
+    fn new(subject: &IRIRef, kind: ChangeKind) -> Self {
+        Self {
+            subject: subject.clone(),
+            kind,
+        }
+    }
+
+    ///
+    /// The term that changed; for cardinality changes this is the restricted class.
+    ///
+    pub fn subject(&self) -> &IRIRef {
+        &self.subject
+    }
+
+    ///
+    /// What changed.
+    ///
+    pub fn kind(&self) -> &ChangeKind {
+        &self.kind
+    }
+
+    ///
+    /// Whether this change is safe for existing consumers.
+    ///
+    pub fn compatibility(&self) -> Compatibility {
+        self.kind.compatibility()
+    }
+
+    ///
+    /// The action required of data publishers for a breaking change, `None` for compatible
+    /// changes.
+    ///
+    pub fn migration(&self) -> Option<String> {
+        let subject = &self.subject;
+        match &self.kind {
+            ChangeKind::ClassRemoved => Some(format!(
+                "replace, or remove, any use of <{}> as a type",
+                subject
+            )),
+            ChangeKind::PropertyRemoved => Some(format!(
+                "replace, or remove, any statement with the predicate <{}>",
+                subject
+            )),
+            ChangeKind::SuperClassRemoved(class) => Some(format!(
+                "add an explicit type <{}> to instances of <{}> that require it",
+                class, subject
+            )),
+            ChangeKind::SuperPropertyRemoved(property) => Some(format!(
+                "add explicit <{}> statements where <{}> was relied upon to imply them",
+                property, subject
+            )),
+            ChangeKind::DomainNarrowed { to, .. } => Some(format!(
+                "ensure every subject of <{}> is an instance of {}",
+                subject,
+                join(to)
+            )),
+            ChangeKind::RangeNarrowed { to, .. } => Some(format!(
+                "ensure every value of <{}> is an instance of {}",
+                subject,
+                join(to)
+            )),
+            ChangeKind::CardinalityTightened { property, to, .. } => Some(format!(
+                "ensure every instance of <{}> has {} value(s) of <{}>",
+                subject, to, property
+            )),
+            ChangeKind::CharacteristicAdded(characteristic) => Some(format!(
+                "ensure statements with the predicate <{}> satisfy <{}>",
+                subject, characteristic
+            )),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for CompatibilityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes");
+        }
+        writeln!(
+            f,
+            "{}, {} breaking and {} compatible change(s)",
+            if self.is_compatible() {
+                "Compatible"
+            } else {
+                "Breaking"
+            },
+            self.breaking().count(),
+            self.compatible().count()
+        )?;
+        if !self.is_compatible() {
+            writeln!(f, "Breaking changes:")?;
+            for change in self.breaking() {
+                writeln!(f, "  {}", change)?;
+                if let Some(migration) = change.migration() {
+                    writeln!(f, "    migration: {}", migration)?;
+                }
+            }
+        }
+        if self.compatible().next().is_some() {
+            writeln!(f, "Compatible changes:")?;
+            for change in self.compatible() {
+                writeln!(f, "  {}", change)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CompatibilityReport {
+    ///
+    /// Returns `true` if there are no breaking changes.
+    ///
+    pub fn is_compatible(&self) -> bool {
+        self.breaking().next().is_none()
+    }
+
+    ///
+    /// Return all the changes, breaking changes first.
+    ///
+    pub fn changes(&self) -> &Vec<Change> {
+        &self.changes
+    }
+
+    ///
+    /// Return only the breaking changes.
+    ///
+    pub fn breaking(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| change.compatibility() == Compatibility::Breaking)
+    }
+
+    ///
+    /// Return only the compatible changes.
+    ///
+    pub fn compatible(&self) -> impl Iterator<Item = &Change> {
+        self.changes
+            .iter()
+            .filter(|change| change.compatibility() == Compatibility::Compatible)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Axioms {
+    fn read(graph: &dyn Graph) -> Self {
+        let mut named: HashMap<&IRIRef, Vec<&StatementRef>> = Default::default();
+        let mut blank: HashMap<&String, Vec<&StatementRef>> = Default::default();
+        for statement in graph.statements() {
+            if let Some(subject) = statement.subject().as_iri() {
+                named.entry(subject).or_default().push(statement);
+            } else if let Some(subject) = statement.subject().as_blank() {
+                blank.entry(subject).or_default().push(statement);
+            }
+        }
+
+        let class_types = [rdfs::class(), owl::class(), owl::deprecated_class()];
+        let property_types = [
+            rdf::property(),
+            owl::object_property(),
+            owl::datatype_property(),
+            owl::annotation_property(),
+            owl::functional_property(),
+            owl::inverse_functional_property(),
+            owl::deprecated_property(),
+        ];
+        let characteristics = [
+            owl::functional_property(),
+            owl::inverse_functional_property(),
+        ];
+
+        let mut axioms = Self::default();
+        for (subject, statements) in &named {
+            let subject = *subject;
+            for statement in statements {
+                let predicate = statement.predicate();
+                let object = statement.object();
+                if predicate == rdf::a_type() {
+                    if let Some(class) = object.as_iri() {
+                        if class_types.contains(&class) {
+                            let _ = axioms.classes.insert(subject.clone());
+                        }
+                        if property_types.contains(&class) {
+                            let _ = axioms.properties.insert(subject.clone());
+                        }
+                        if characteristics.contains(&class) {
+                            insert(&mut axioms.characteristics, subject, class);
+                        }
+                        if class == owl::deprecated_class() || class == owl::deprecated_property() {
+                            let _ = axioms.deprecated.insert(subject.clone());
+                        }
+                    }
+                } else if predicate == owl::deprecated() {
+                    if is_true(object) {
+                        let _ = axioms.deprecated.insert(subject.clone());
+                    }
+                } else if predicate == rdfs::subclass_of() {
+                    if let Some(class) = object.as_iri() {
+                        insert(&mut axioms.super_classes, subject, class);
+                    } else if let Some(restriction) = object.as_blank() {
+                        axioms.read_restriction(subject, blank.get(restriction));
+                    }
+                } else if predicate == rdfs::subproperty_of() {
+                    if let Some(property) = object.as_iri() {
+                        insert(&mut axioms.super_properties, subject, property);
+                    }
+                } else if predicate == rdfs::domain() {
+                    if let Some(class) = object.as_iri() {
+                        insert(&mut axioms.domains, subject, class);
+                    }
+                } else if predicate == rdfs::range() {
+                    if let Some(class) = object.as_iri() {
+                        insert(&mut axioms.ranges, subject, class);
+                    }
+                }
+            }
+        }
+        axioms
+    }
+
+    fn read_restriction(&mut self, class: &IRIRef, statements: Option<&Vec<&StatementRef>>) {
+        let statements = match statements {
+            Some(statements) => statements,
+            None => return,
+        };
+        let mut property = None;
+        let (mut min, mut max) = (None, None);
+        for statement in statements {
+            let predicate = statement.predicate();
+            let object = statement.object();
+            if predicate == owl::on_property() {
+                property = object.as_iri();
+            } else if predicate == owl::min_cardinality()
+                || predicate == owl::min_qualified_cardinality()
+            {
+                min = count(object);
+            } else if predicate == owl::max_cardinality()
+                || predicate == owl::max_qualified_cardinality()
+            {
+                max = count(object);
+            } else if predicate == owl::cardinality() || predicate == owl::qualified_cardinality() {
+                min = count(object);
+                max = min;
+            }
+        }
+        if let Some(property) = property {
+            if min.is_some() || max.is_some() {
+                self.cardinalities
+                    .entry((class.clone(), property.clone()))
+                    .or_default()
+                    .restrict(min, max);
+            }
+        }
+    }
+
+    ///
+    /// Returns `true` if the classes `to` allow fewer resources than the classes `from`, that is
+    /// if some class in `to` is not already implied by a class in `from`.
+    ///
+    fn narrows(&self, from: &BTreeSet<IRIRef>, to: &BTreeSet<IRIRef>) -> bool {
+        let implied: HashSet<&IRIRef> = from
+            .iter()
+            .flat_map(|class| self.super_classes_of(class))
+            .collect();
+        to.iter().any(|class| {
+            !implied.contains(class) && class != owl::thing() && class != rdfs::resource()
+        })
+    }
+
+    ///
+    /// Return `class` and all of its transitive super classes.
+    ///
+    fn super_classes_of<'a>(&'a self, class: &'a IRIRef) -> HashSet<&'a IRIRef> {
+        let mut classes: HashSet<&IRIRef> = Default::default();
+        let mut stack = vec![class];
+        while let Some(class) = stack.pop() {
+            if classes.insert(class) {
+                stack.extend(self.super_classes.get(class).into_iter().flatten());
+            }
+        }
+        classes
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn insert(relations: &mut Relations, subject: &IRIRef, object: &IRIRef) {
+    let _ = relations
+        .entry(subject.clone())
+        .or_default()
+        .insert(object.clone());
+}
+
+fn is_true(object: &ObjectNodeRef) -> bool {
+    object
+        .as_literal()
+        .map(|literal| literal.lexical_form().trim() == "true")
+        .unwrap_or_default()
+}
+
+fn count(object: &ObjectNodeRef) -> Option<u64> {
+    object
+        .as_literal()
+        .and_then(|literal| literal.lexical_form().trim().parse().ok())
+}
+
+fn join(classes: &[IRIRef]) -> String {
+    if classes.is_empty() {
+        "(any)".to_string()
+    } else {
+        classes
+            .iter()
+            .map(|class| format!("<{}>", class))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+fn diff_terms(
+    old: &BTreeSet<IRIRef>,
+    new: &BTreeSet<IRIRef>,
+    added: ChangeKind,
+    removed: ChangeKind,
+    changes: &mut Vec<Change>,
+) {
+    for term in new.difference(old) {
+        changes.push(Change::new(term, added.clone()));
+    }
+    for term in old.difference(new) {
+        changes.push(Change::new(term, removed.clone()));
+    }
+}
+
+///
+/// Compare the relations of those subjects that are declared in both versions.
+///
+fn diff_relations(
+    subjects: &BTreeSet<IRIRef>,
+    old: &Relations,
+    new: &Relations,
+    added: impl Fn(IRIRef) -> ChangeKind,
+    removed: impl Fn(IRIRef) -> ChangeKind,
+    changes: &mut Vec<Change>,
+) {
+    let empty: BTreeSet<IRIRef> = Default::default();
+    for subject in subjects {
+        let old = old.get(subject).unwrap_or(&empty);
+        let new = new.get(subject).unwrap_or(&empty);
+        for object in new.difference(old) {
+            changes.push(Change::new(subject, added(object.clone())));
+        }
+        for object in old.difference(new) {
+            changes.push(Change::new(subject, removed(object.clone())));
+        }
+    }
+}
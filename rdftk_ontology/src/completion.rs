@@ -0,0 +1,398 @@
+/*!
+A prefix-search index over the terms of loaded vocabularies, designed to back the autocompletion
+of terms when authoring Turtle in an editor or IDE.
+
+An index may be populated from the vocabularies provided by `rdftk_names`, and from any ontology
+graphs loaded by an application. Terms from a graph are classes, properties, or datatypes declared
+with `rdf:type`, or resources with an `rdfs:label` or `rdfs:comment`; their labels and comments,
+including the SKOS `skos:prefLabel` and `skos:definition`, are kept with each term for display.
+As `rdftk_names` provides only the names of each term, its terms are classified by convention, a
+local name starting with an upper case letter being a class and with a lower case letter a
+property, other than the terms of XML Schema which are datatypes. Adding a graph that describes
+a term already in the index adds to, and corrects, what is known of the term.
+
+Each term is found by a case-insensitive prefix of its qualified name, its local name, any of its
+labels, or any word in its local name or labels. Results are ranked with matches on the name
+before matches on labels, exact matches before prefixes, and shorter names first.
+
+# Example
+
+```rust
+use rdftk_names::foaf;
+use rdftk_ontology::completion::{CompletionIndex, TermKind};
+
+let index = CompletionIndex::with_vocabularies();
+
+let completions = index.complete("foaf:nam", 5);
+assert_eq!(completions[0].iri(), foaf::name());
+
+let classes = index.complete_kind("foaf:Pers", TermKind::Class, 5);
+assert_eq!(classes[0].qname(), Some(&"foaf:Person".to_string()));
+```
+
+*/
+
+use crate::labels::{local_name, words};
+use rdftk_core::model::graph::Graph;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{owl, rdf, rdfs, skos, xsd, Vocabulary};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The kind of a term, used to restrict completions to those valid in a position; such as only
+/// classes as the object of `rdf:type`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TermKind {
+    /// An `rdfs:Class` or `owl:Class`.
+    Class,
+    /// An `rdf:Property`, or any of the OWL property types.
+    Property,
+    /// An `rdfs:Datatype`.
+    Datatype,
+    /// A resource that is none of the above, such as an individual.
+    Other,
+}
+
+///
+/// A term in a `CompletionIndex`.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Term {
+    iri: IRIRef,
+    qname: Option<String>,
+    local_name: String,
+    kind: TermKind,
+    labels: Vec<String>,
+    comments: Vec<String>,
+}
+
+///
+/// A prefix-search index over terms; see the module documentation.
+///
+#[derive(Clone, Debug, Default)]
+pub struct CompletionIndex {
+    terms: Vec<Term>,
+    by_iri: HashMap<IRIRef, usize>,
+    keys: BTreeMap<String, BTreeSet<(usize, MatchRank)>>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum MatchRank {
+    Name,
+    Label,
+    Word,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Term {
+    ///
+    /// Return the IRI of this term.
+    ///
+    pub fn iri(&self) -> &IRIRef {
+        &self.iri
+    }
+
+    ///
+    /// Return the qualified name of this term, if it has a known prefix.
+    ///
+    pub fn qname(&self) -> Option<&String> {
+        self.qname.as_ref()
+    }
+
+    ///
+    /// Return the local name of this term, or the complete IRI if it has no local name.
+    ///
+    pub fn local_name(&self) -> &String {
+        &self.local_name
+    }
+
+    ///
+    /// Return the kind of this term.
+    ///
+    pub fn kind(&self) -> TermKind {
+        self.kind
+    }
+
+    ///
+    /// Return the labels of this term, if any.
+    ///
+    pub fn labels(&self) -> &Vec<String> {
+        &self.labels
+    }
+
+    ///
+    /// Return the comments, or definitions, of this term, if any.
+    ///
+    pub fn comments(&self) -> &Vec<String> {
+        &self.comments
+    }
+
+    fn new(iri: IRIRef, qname: Option<String>, kind: TermKind) -> Self {
+        let local_name = local_name(&iri).unwrap_or_else(|| iri.to_string());
+        Self {
+            iri,
+            qname,
+            local_name,
+            kind,
+            labels: Default::default(),
+            comments: Default::default(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl CompletionIndex {
+    ///
+    /// Construct a new index populated with the terms of every vocabulary provided by
+    /// `rdftk_names`.
+    ///
+    pub fn with_vocabularies() -> Self {
+        let mut index = Self::default();
+        for vocabulary in rdftk_names::vocabularies() {
+            let _ = index.add_vocabulary(&vocabulary);
+        }
+        index
+    }
+
+    ///
+    /// Add the terms of `vocabulary`, classifying each by the case of its local name.
+    ///
+    pub fn add_vocabulary(&mut self, vocabulary: &Vocabulary) -> &mut Self {
+        let namespace = vocabulary.namespace();
+        for name in vocabulary.local_names() {
+            let iri = match IRI::from_str(&format!("{}{}", namespace, name)) {
+                Ok(iri) => IRIRef::from(iri),
+                Err(_) => continue,
+            };
+            let kind = if namespace == xsd::namespace_iri() {
+                TermKind::Datatype
+            } else if name.starts_with(char::is_uppercase) {
+                TermKind::Class
+            } else {
+                TermKind::Property
+            };
+            let term = Term::new(iri, Some(format!("{}:{}", vocabulary.prefix(), name)), kind);
+            self.merge(term, false);
+        }
+        self
+    }
+
+    ///
+    /// Add the terms described in `graph`, using the graph's prefix mappings for qualified names.
+    ///
+    pub fn add_graph(&mut self, graph: &dyn Graph) -> &mut Self {
+        let mut described: BTreeMap<IRIRef, Term> = Default::default();
+        let mappings = graph.prefix_mappings();
+        let mappings = mappings.borrow();
+        for statement in graph.statements() {
+            let subject = match statement.subject().as_iri() {
+                Some(subject) => subject,
+                None => continue,
+            };
+            let predicate = statement.predicate();
+            let object = statement.object();
+            let kind = if predicate == rdf::a_type() {
+                object.as_iri().and_then(kind_of_type)
+            } else if predicate == rdfs::subclass_of() {
+                Some(TermKind::Class)
+            } else if predicate == rdfs::subproperty_of()
+                || predicate == rdfs::domain()
+                || predicate == rdfs::range()
+            {
+                Some(TermKind::Property)
+            } else {
+                None
+            };
+            let text = object
+                .as_literal()
+                .map(|literal| literal.lexical_form().clone());
+            let is_label = predicate == rdfs::label() || predicate == skos::pref_label();
+            let is_comment = predicate == rdfs::comment() || predicate == skos::definition();
+            if kind.is_none() && !(text.is_some() && (is_label || is_comment)) {
+                continue;
+            }
+
+            let term = described.entry(subject.clone()).or_insert_with(|| {
+                let qname = mappings.compress(subject).map(|qname| qname.to_string());
+                Term::new(subject.clone(), qname, TermKind::Other)
+            });
+            if let Some(kind) = kind {
+                term.kind = kind;
+            }
+            match text {
+                Some(text) if is_label => term.labels.push(text),
+                Some(text) if is_comment => term.comments.push(text),
+                _ => {}
+            }
+        }
+        for (_, term) in described {
+            self.merge(term, true);
+        }
+        self
+    }
+
+    ///
+    /// Returns `true` if the index contains no terms.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    ///
+    /// Return the number of terms in the index.
+    ///
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    ///
+    /// Return the term with the IRI `iri`, if it is in the index.
+    ///
+    pub fn get(&self, iri: &IRIRef) -> Option<&Term> {
+        self.by_iri.get(iri).map(|index| &self.terms[*index])
+    }
+
+    ///
+    /// Return, at most, `limit` terms matching the prefix `text`, best match first.
+    ///
+    pub fn complete(&self, text: &str, limit: usize) -> Vec<&Term> {
+        self.search(text, None, limit)
+    }
+
+    ///
+    /// Return, at most, `limit` terms of the kind `kind` matching the prefix `text`, best match
+    /// first.
+    ///
+    pub fn complete_kind(&self, text: &str, kind: TermKind, limit: usize) -> Vec<&Term> {
+        self.search(text, Some(kind), limit)
+    }
+
+    fn search(&self, text: &str, kind: Option<TermKind>, limit: usize) -> Vec<&Term> {
+        let text = text.to_lowercase();
+        let mut best: HashMap<usize, (MatchRank, bool)> = Default::default();
+        for (key, matches) in self
+            .keys
+            .range(text.clone()..)
+            .take_while(|(key, _)| key.starts_with(&text))
+        {
+            let exact = *key == text;
+            for (term, rank) in matches {
+                if kind.is_none() || kind == Some(self.terms[*term].kind) {
+                    let score = (*rank, !exact);
+                    let current = best.entry(*term).or_insert(score);
+                    if score < *current {
+                        *current = score;
+                    }
+                }
+            }
+        }
+        let mut results: Vec<(&Term, (MatchRank, bool))> = best
+            .into_iter()
+            .map(|(term, score)| (&self.terms[term], score))
+            .collect();
+        results.sort_by(|(lhs, lhs_score), (rhs, rhs_score)| {
+            lhs_score
+                .cmp(rhs_score)
+                .then_with(|| lhs.local_name.len().cmp(&rhs.local_name.len()))
+                .then_with(|| lhs.iri.cmp(&rhs.iri))
+        });
+        results
+            .into_iter()
+            .take(limit)
+            .map(|(term, _)| term)
+            .collect()
+    }
+
+    fn merge(&mut self, term: Term, authoritative: bool) {
+        let index = match self.by_iri.get(&term.iri) {
+            Some(index) => {
+                let existing = &mut self.terms[*index];
+                if existing.qname.is_none() {
+                    existing.qname = term.qname;
+                }
+                if authoritative && term.kind != TermKind::Other {
+                    existing.kind = term.kind;
+                }
+                for label in term.labels {
+                    if !existing.labels.contains(&label) {
+                        existing.labels.push(label);
+                    }
+                }
+                for comment in term.comments {
+                    if !existing.comments.contains(&comment) {
+                        existing.comments.push(comment);
+                    }
+                }
+                *index
+            }
+            None => {
+                let index = self.terms.len();
+                let _ = self.by_iri.insert(term.iri.clone(), index);
+                self.terms.push(term);
+                index
+            }
+        };
+
+        let term = &self.terms[index];
+        let mut keys: Vec<(String, MatchRank)> = vec![(term.local_name.clone(), MatchRank::Name)];
+        if let Some(qname) = &term.qname {
+            keys.push((qname.clone(), MatchRank::Name));
+        }
+        for label in &term.labels {
+            keys.push((label.clone(), MatchRank::Label));
+            keys.extend(
+                label
+                    .split_whitespace()
+                    .map(|word| (word.to_string(), MatchRank::Word)),
+            );
+        }
+        keys.extend(
+            words(&term.local_name)
+                .into_iter()
+                .map(|word| (word, MatchRank::Word)),
+        );
+        for (key, rank) in keys {
+            let _ = self
+                .keys
+                .entry(key.to_lowercase())
+                .or_default()
+                .insert((index, rank));
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn kind_of_type(class: &IRIRef) -> Option<TermKind> {
+    if class == rdfs::class() || class == owl::class() {
+        Some(TermKind::Class)
+    } else if class == rdf::property()
+        || class == rdfs::property()
+        || class == owl::object_property()
+        || class == owl::datatype_property()
+        || class == owl::annotation_property()
+        || class == owl::functional_property()
+    {
+        Some(TermKind::Property)
+    } else if class == rdfs::data_type() {
+        Some(TermKind::Datatype)
+    } else {
+        None
+    }
+}
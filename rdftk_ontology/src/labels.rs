@@ -0,0 +1,347 @@
+/*!
+Text utilities for generating vocabularies programmatically, converting between the local names of
+terms and their human-readable labels, and an enrichment pass, `LabelGenerator`, that adds an
+`rdfs:label` to each term that lacks one.
+
+Local names are split into words at case changes, digits, and punctuation, so that `hasPart`,
+`has_part`, and `has-part` all have the words `has` and `part`; a run of capitals is kept together
+as an acronym, so that `HTTPRequest` has the words `HTTP` and `Request`. Labels keep the case of
+the first letter of the local name, so that the class `PostalAddress` is labeled "Postal address"
+and the property `hasPart` "has part", and keep acronyms as written.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, rdfs};
+use rdftk_ontology::labels::{plural, to_camel_case, to_label, to_slug, LabelGenerator};
+use std::str::FromStr;
+
+assert_eq!(to_label("PostalAddress"), "Postal address");
+assert_eq!(to_camel_case("has part"), "hasPart");
+assert_eq!(to_slug("HTTPRequest"), "http-request");
+assert_eq!(plural("postal address"), "postal addresses");
+
+let factory = statement_factory();
+let graph = graph_factory().graph_from(
+    &[factory
+        .statement(
+            factory.named_subject(IRIRef::from(
+                IRI::from_str("http://example.org/ns#PostalAddress").unwrap(),
+            )),
+            rdf::a_type().clone(),
+            factory.named_object(rdfs::class().clone()),
+        )
+        .unwrap()],
+    None,
+);
+
+let added = LabelGenerator::default().enrich(&graph);
+assert_eq!(added, 1);
+assert_eq!(graph.borrow().len(), 2);
+```
+
+*/
+
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::model::statement::StatementList;
+use rdftk_iri::IRIRef;
+use rdftk_names::rdfs;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Generates an `rdfs:label` for each named subject of a graph that does not already have one,
+/// from the local name of its IRI.
+///
+#[derive(Clone, Debug, Default)]
+pub struct LabelGenerator {
+    language: Option<LanguageTag>,
+    namespace: Option<IRIRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Split `name` into words, at changes from lower to upper case, between letters and digits, and
+/// at any character that is not alphanumeric. A run of upper case letters is kept together as an
+/// acronym, other than a final letter that starts a capitalized word.
+///
+pub fn words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words: Vec<String> = Default::default();
+    let mut current = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if let Some(previous) = current.chars().last() {
+            let next = chars.get(i + 1);
+            let boundary = (previous.is_lowercase() && c.is_uppercase())
+                || (previous.is_alphabetic() != c.is_alphabetic())
+                || (previous.is_uppercase()
+                    && c.is_uppercase()
+                    && next.map(|n| n.is_lowercase()).unwrap_or_default());
+            if boundary {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(*c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+///
+/// Convert `name` to camel case, as used for the local names of properties; `has part` becomes
+/// `hasPart`.
+///
+pub fn to_camel_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            if i == 0 {
+                word.to_lowercase()
+            } else {
+                capitalize(word)
+            }
+        })
+        .collect()
+}
+
+///
+/// Convert `name` to Pascal case, as used for the local names of classes; `postal address`
+/// becomes `PostalAddress`.
+///
+pub fn to_pascal_case(name: &str) -> String {
+    words(name).iter().map(|word| capitalize(word)).collect()
+}
+
+///
+/// Convert `name` to a slug, lower case words separated by hyphens, as used in paths and file
+/// names; `HTTPRequest` becomes `http-request`.
+///
+pub fn to_slug(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+///
+/// Convert the local name `name` to a label, words separated by spaces; the first letter keeps its
+/// case, acronyms are unchanged, and all other letters are lower case. `PostalAddress` becomes
+/// "Postal address".
+///
+pub fn to_label(name: &str) -> String {
+    words(name)
+        .iter()
+        .enumerate()
+        .map(|(i, word)| {
+            if is_acronym(word) {
+                word.clone()
+            } else if i == 0 && word.starts_with(char::is_uppercase) {
+                capitalize(word)
+            } else {
+                word.to_lowercase()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+///
+/// Return the English plural of `phrase`, by pluralizing its last word. This uses the common
+/// spelling rules, and a small number of irregular nouns, and is intended for generated text such
+/// as the labels of collections rather than as a complete treatment of English.
+///
+pub fn plural(phrase: &str) -> String {
+    let split = phrase
+        .rfind(|c: char| !c.is_alphanumeric())
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let (head, word) = phrase.split_at(split);
+    if word.is_empty() {
+        return phrase.to_string();
+    }
+    let lower = word.to_lowercase();
+    let plural = if let Some((_, irregular)) = IRREGULAR_PLURALS
+        .iter()
+        .find(|(singular, _)| lower == *singular)
+    {
+        let mut chars = irregular.chars();
+        match (word.starts_with(char::is_uppercase), chars.next()) {
+            (true, Some(first)) => format!("{}{}", first.to_uppercase(), chars.as_str()),
+            _ => irregular.to_string(),
+        }
+    } else if is_acronym(word) {
+        format!("{}s", word)
+    } else if lower.ends_with('y')
+        && !lower.ends_with("ay")
+        && !lower.ends_with("ey")
+        && !lower.ends_with("oy")
+        && !lower.ends_with("uy")
+    {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if lower.ends_with('s')
+        || lower.ends_with('x')
+        || lower.ends_with('z')
+        || lower.ends_with("ch")
+        || lower.ends_with("sh")
+    {
+        format!("{}es", word)
+    } else {
+        format!("{}s", word)
+    };
+    format!("{}{}", head, plural)
+}
+
+///
+/// Return the local name of `iri`, its fragment if it has one, else the last segment of its path.
+///
+pub fn local_name(iri: &IRIRef) -> Option<String> {
+    match iri.fragment() {
+        Some(fragment) if !fragment.is_empty() => Some(fragment.value().clone()),
+        _ => iri
+            .path()
+            .value()
+            .rsplit('/')
+            .next()
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_string()),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl LabelGenerator {
+    ///
+    /// Set the language of the generated labels.
+    ///
+    pub fn language(&mut self, language: LanguageTag) -> &mut Self {
+        self.language = Some(language);
+        self
+    }
+
+    ///
+    /// Only generate labels for subjects whose IRI starts with `namespace`, so that terms from
+    /// other vocabularies are left unchanged.
+    ///
+    pub fn namespace(&mut self, namespace: IRIRef) -> &mut Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    ///
+    /// Return the label generated for `iri`, if it has a local name.
+    ///
+    pub fn label_for(&self, iri: &IRIRef) -> Option<String> {
+        local_name(iri)
+            .map(|name| to_label(&name))
+            .filter(|label| !label.is_empty())
+    }
+
+    ///
+    /// Return an `rdfs:label` statement for each named subject in `graph`, within the namespace
+    /// if one is set, that has no `rdfs:label` in any language.
+    ///
+    pub fn missing_labels(&self, graph: &dyn Graph) -> StatementList {
+        let namespace = self
+            .namespace
+            .as_ref()
+            .map(|namespace| namespace.to_string());
+        let statements = graph.statement_factory();
+        let literals = graph.literal_factory();
+        let mut subjects: Vec<_> = graph
+            .subjects()
+            .into_iter()
+            .filter(|subject| match (subject.as_iri(), &namespace) {
+                (None, _) => false,
+                (Some(_), None) => true,
+                (Some(iri), Some(namespace)) => iri.to_string().starts_with(namespace),
+            })
+            .filter(|subject| graph.objects_for(subject, rdfs::label()).is_empty())
+            .cloned()
+            .collect();
+        subjects.sort_by_cached_key(|subject| subject.to_string());
+        subjects
+            .into_iter()
+            .filter_map(|subject| {
+                let label = self.label_for(subject.as_iri().unwrap())?;
+                let literal = match &self.language {
+                    None => literals.literal(&label),
+                    Some(language) => literals.with_language(&label, language.clone()),
+                };
+                statements
+                    .statement(
+                        subject,
+                        rdfs::label().clone(),
+                        statements.literal_object(literal),
+                    )
+                    .ok()
+            })
+            .collect()
+    }
+
+    ///
+    /// Add the statements returned by `missing_labels` to `graph`, returning the number added.
+    ///
+    pub fn enrich(&self, graph: &GraphRef) -> usize {
+        let labels = self.missing_labels(&*graph.borrow());
+        let mut graph = graph.borrow_mut();
+        for statement in &labels {
+            graph.insert(statement.clone());
+        }
+        labels.len()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const IRREGULAR_PLURALS: &[(&str, &str)] = &[
+    ("child", "children"),
+    ("datum", "data"),
+    ("foot", "feet"),
+    ("man", "men"),
+    ("mouse", "mice"),
+    ("person", "people"),
+    ("woman", "women"),
+];
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn is_acronym(word: &str) -> bool {
+    word.chars().filter(|c| c.is_alphabetic()).count() > 1 && !word.chars().any(char::is_lowercase)
+}
+
+fn capitalize(word: &str) -> String {
+    if is_acronym(word) {
+        return word.to_string();
+    }
+    let mut chars = word.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => format!("{}{}", first.to_uppercase(), chars.as_str().to_lowercase()),
+    }
+}
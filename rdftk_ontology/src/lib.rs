@@ -127,3 +127,16 @@ pub mod macros;
 pub mod rdfs;
 
 pub mod owl;
+
+pub mod compatibility;
+
+pub mod completion;
+
+pub mod labels;
+
+pub mod resolution;
+
+pub mod shacl;
+
+#[cfg(feature = "publish")]
+pub mod publish;
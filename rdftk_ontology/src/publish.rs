@@ -0,0 +1,913 @@
+/*!
+Generate a deployable bundle that publishes a vocabulary at its namespace IRI.
+
+Given a graph describing a vocabulary the `Publisher` produces an HTML index page, an HTML page for
+each term in the namespace, a serialization of the complete graph in each requested
+representation, and an Apache `.htaccess` file. The `.htaccess` file performs content negotiation
+so that the namespace IRI, and for slash namespaces each term IRI, is redirected (`303 See Other`)
+to the representation requested by the client's `Accept` header, or to the HTML documentation by
+default.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_ontology::publish::Publisher;
+use std::str::FromStr;
+
+let mut content = r#"@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix ex: <http://example.org/vocab/> .
+ex:Person a rdfs:Class ;
+    rdfs:label "Person"@en ;
+    rdfs:comment "A human being."@en .
+"#.as_bytes();
+let graph = TurtleReader::default().read(&mut content, graph_factory()).unwrap();
+
+let publisher = Publisher::new(IRIRef::from(IRI::from_str("http://example.org/vocab/").unwrap()));
+let bundle = publisher.publish(&graph).unwrap();
+
+assert_eq!(bundle.base_path(), "/vocab/");
+assert!(bundle.file("index.html").is_some());
+assert!(bundle.file("index.ttl").is_some());
+assert!(bundle.file("Person.html").is_some());
+assert!(bundle.file(".htaccess").is_some());
+```
+
+The resulting bundle can then be written to the directory served for the namespace path using
+`Bundle::write_to`.
+
+*/
+
+use rdftk_core::error::Result;
+use rdftk_core::model::graph::mapping::PrefixMappingRef;
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::model::statement::{ObjectNodeRef, SubjectNodeRef};
+use rdftk_io::json_ld::writer::JsonLdWriter;
+use rdftk_io::nt::writer::NTripleWriter;
+use rdftk_io::turtle::writer::TurtleWriter;
+use rdftk_io::write_graph_to_string;
+use rdftk_io::xml::writer::XmlWriter;
+use rdftk_iri::IRIRef;
+use rdftk_names::{dc::terms as dcterms, owl, rdf, rdfs};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::Path;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The file name, without extension, used for the vocabulary index page and serializations.
+///
+pub const INDEX_FILE_NAME: &str = "index";
+
+///
+/// The file name of the generated Apache content negotiation configuration.
+///
+pub const HTACCESS_FILE_NAME: &str = ".htaccess";
+
+///
+/// The file extension used for all generated HTML pages.
+///
+pub const HTML_FILE_EXTENSION: &str = "html";
+
+///
+/// The MIME type used for all generated HTML pages.
+///
+pub const HTML_MIME_TYPE: &str = "text/html";
+
+///
+/// The RDF representations that a vocabulary may be published in.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Serialization {
+    Turtle,
+    JsonLd,
+    RdfXml,
+    NTriples,
+}
+
+///
+/// Generates a `Bundle` from a vocabulary graph, see the module documentation for details.
+///
+#[derive(Clone, Debug)]
+pub struct Publisher {
+    namespace: IRIRef,
+    title: Option<String>,
+    language: Option<LanguageTag>,
+    serializations: Vec<Serialization>,
+}
+
+///
+/// A single generated file, the path is relative to the directory served at the bundle's base
+/// path.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BundleFile {
+    path: String,
+    media_type: String,
+    content: String,
+}
+
+///
+/// A single entry in the content negotiation redirect map; a request for `from`, relative to the
+/// bundle's base path, that accepts `media_type` is redirected to the file `to`. The HTML
+/// redirect for each path is the default and applies regardless of the requested media type.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Redirect {
+    from: String,
+    media_type: String,
+    to: String,
+}
+
+///
+/// The complete set of files, and the redirect map, generated for a vocabulary.
+///
+#[derive(Clone, Debug)]
+pub struct Bundle {
+    base_path: String,
+    files: Vec<BundleFile>,
+    redirects: Vec<Redirect>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum TermKind {
+    Class,
+    Property,
+    Datatype,
+    Individual,
+    Other,
+}
+
+#[derive(Debug)]
+struct Term {
+    iri: IRIRef,
+    subject: SubjectNodeRef,
+    kind: TermKind,
+    label: Option<String>,
+    description: Option<String>,
+}
+
+struct Context<'a> {
+    namespace: String,
+    title: String,
+    language: Option<&'a LanguageTag>,
+    mappings: PrefixMappingRef,
+    terms: BTreeMap<String, Term>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Serialization {
+    ///
+    /// Return all supported serializations.
+    ///
+    pub fn all() -> Vec<Self> {
+        vec![Self::Turtle, Self::JsonLd, Self::RdfXml, Self::NTriples]
+    }
+
+    ///
+    /// Return a display name for this serialization.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Turtle => rdftk_io::turtle::NAME,
+            Self::JsonLd => rdftk_io::json_ld::NAME,
+            Self::RdfXml => "RDF/XML",
+            Self::NTriples => rdftk_io::nt::NAME,
+        }
+    }
+
+    ///
+    /// Return the file extension used when writing this serialization.
+    ///
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Turtle => rdftk_io::turtle::FILE_EXTENSION,
+            Self::JsonLd => rdftk_io::json_ld::FILE_EXTENSION,
+            Self::RdfXml => rdftk_io::xml::FILE_EXTENSION,
+            Self::NTriples => rdftk_io::nt::FILE_EXTENSION,
+        }
+    }
+
+    ///
+    /// Return the MIME type used to negotiate this serialization.
+    ///
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            Self::Turtle => rdftk_io::turtle::MIME_TYPE,
+            Self::JsonLd => rdftk_io::json_ld::MIME_TYPE,
+            Self::RdfXml => rdftk_io::xml::MIME_TYPE,
+            Self::NTriples => rdftk_io::nt::MIME_TYPE,
+        }
+    }
+
+    fn write(&self, graph: &GraphRef) -> Result<String> {
+        match self {
+            Self::Turtle => write_graph_to_string(&TurtleWriter::default(), graph),
+            Self::JsonLd => write_graph_to_string(&JsonLdWriter::default(), graph),
+            Self::RdfXml => write_graph_to_string(&XmlWriter::default(), graph),
+            Self::NTriples => write_graph_to_string(&NTripleWriter::default(), graph),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Publisher {
+    ///
+    /// Create a new publisher for the vocabulary identified by `namespace`; only subjects whose
+    /// IRI is a direct child of this namespace are documented as terms.
+    ///
+    pub fn new(namespace: IRIRef) -> Self {
+        Self {
+            namespace,
+            title: None,
+            language: None,
+            serializations: Serialization::all(),
+        }
+    }
+
+    ///
+    /// Set the title of the vocabulary, overriding any `dcterms:title` or `rdfs:label` of the
+    /// vocabulary itself.
+    ///
+    pub fn title(&mut self, title: &str) -> &mut Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    ///
+    /// Set the language used to select labels and descriptions and declared on the HTML pages.
+    ///
+    pub fn language(&mut self, language: LanguageTag) -> &mut Self {
+        self.language = Some(language);
+        self
+    }
+
+    ///
+    /// Set the RDF representations included in the bundle, the default is all of them.
+    ///
+    pub fn serializations(&mut self, serializations: &[Serialization]) -> &mut Self {
+        self.serializations = serializations.to_vec();
+        self
+    }
+
+    ///
+    /// Generate the complete bundle for the provided vocabulary graph.
+    ///
+    pub fn publish(&self, graph: &GraphRef) -> Result<Bundle> {
+        let (base_path, root) = split_namespace_path(&self.namespace);
+        let is_slash_namespace = root.is_empty();
+
+        let mut files: Vec<BundleFile> = Default::default();
+        let mut redirects: Vec<Redirect> = Default::default();
+
+        for serialization in &self.serializations {
+            files.push(BundleFile {
+                path: file_name(INDEX_FILE_NAME, serialization.file_extension()),
+                media_type: serialization.media_type().to_string(),
+                content: serialization.write(graph)?,
+            });
+        }
+
+        {
+            let graph = graph.borrow();
+            let context = self.make_context(&*graph);
+
+            files.push(BundleFile {
+                path: file_name(INDEX_FILE_NAME, HTML_FILE_EXTENSION),
+                media_type: HTML_MIME_TYPE.to_string(),
+                content: self.index_page(&*graph, &context),
+            });
+            self.add_redirects(&mut redirects, &root, INDEX_FILE_NAME);
+
+            let referenced_by = referenced_by(&*graph, &context);
+            for (local_name, term) in &context.terms {
+                files.push(BundleFile {
+                    path: file_name(local_name, HTML_FILE_EXTENSION),
+                    media_type: HTML_MIME_TYPE.to_string(),
+                    content: self.term_page(&*graph, &context, term, referenced_by.get(local_name)),
+                });
+                if is_slash_namespace {
+                    self.add_redirects(&mut redirects, &regex_escape(local_name), local_name);
+                }
+            }
+        }
+
+        files.push(BundleFile {
+            path: HTACCESS_FILE_NAME.to_string(),
+            media_type: "text/plain".to_string(),
+            content: self.htaccess(&base_path, &redirects),
+        });
+
+        Ok(Bundle {
+            base_path,
+            files,
+            redirects,
+        })
+    }
+
+    fn make_context<'a>(&'a self, graph: &dyn Graph) -> Context<'a> {
+        let namespace = self.namespace.to_string();
+        let language = self.language.as_ref();
+
+        let mut terms: BTreeMap<String, Term> = Default::default();
+        let mut vocabulary: Option<&SubjectNodeRef> = None;
+        let vocabulary_iri = namespace.trim_end_matches(['#', '/']);
+        for subject in graph.subjects() {
+            if let Some(iri) = subject.as_iri() {
+                let iri_str = iri.to_string();
+                if iri_str == namespace || iri_str == vocabulary_iri {
+                    vocabulary = Some(subject);
+                } else if let Some(local_name) = iri_str.strip_prefix(&namespace) {
+                    if !local_name.contains(['/', '#']) {
+                        let _ = terms.insert(
+                            local_name.to_string(),
+                            Term {
+                                iri: iri.clone(),
+                                subject: subject.clone(),
+                                kind: term_kind(graph, subject),
+                                label: preferred_text(
+                                    graph,
+                                    subject,
+                                    &[rdfs::label(), dcterms::title()],
+                                    language,
+                                ),
+                                description: preferred_text(
+                                    graph,
+                                    subject,
+                                    &[rdfs::comment(), dcterms::description()],
+                                    language,
+                                ),
+                            },
+                        );
+                    }
+                }
+            }
+        }
+
+        let title = match (&self.title, vocabulary) {
+            (Some(title), _) => Some(title.clone()),
+            (None, Some(vocabulary)) => preferred_text(
+                graph,
+                vocabulary,
+                &[dcterms::title(), rdfs::label()],
+                language,
+            ),
+            (None, None) => None,
+        };
+
+        Context {
+            title: title.unwrap_or_else(|| namespace.clone()),
+            namespace,
+            language,
+            mappings: graph.prefix_mappings(),
+            terms,
+        }
+    }
+
+    fn add_redirects(&self, redirects: &mut Vec<Redirect>, from: &str, file: &str) {
+        for serialization in &self.serializations {
+            redirects.push(Redirect {
+                from: from.to_string(),
+                media_type: serialization.media_type().to_string(),
+                to: file_name(INDEX_FILE_NAME, serialization.file_extension()),
+            });
+        }
+        redirects.push(Redirect {
+            from: from.to_string(),
+            media_type: HTML_MIME_TYPE.to_string(),
+            to: file_name(file, HTML_FILE_EXTENSION),
+        });
+    }
+
+    fn index_page(&self, graph: &dyn Graph, context: &Context<'_>) -> String {
+        let mut body = String::new();
+        let _ = writeln!(body, "<h1>{}</h1>", escape(&context.title));
+        let _ = writeln!(body, "<p><code>{}</code></p>", escape(&context.namespace));
+        let vocabulary_iri = context.namespace.trim_end_matches(['#', '/']);
+        if let Some(description) = graph
+            .subjects()
+            .into_iter()
+            .filter(|subject| match subject.as_iri() {
+                Some(iri) => {
+                    let iri = iri.to_string();
+                    iri == context.namespace || iri == vocabulary_iri
+                }
+                None => false,
+            })
+            .find_map(|subject| {
+                preferred_text(
+                    graph,
+                    subject,
+                    &[dcterms::description(), rdfs::comment()],
+                    context.language,
+                )
+            })
+        {
+            let _ = writeln!(body, "<p>{}</p>", escape(&description));
+        }
+        if !self.serializations.is_empty() {
+            let links: Vec<String> = self
+                .serializations
+                .iter()
+                .map(|serialization| {
+                    format!(
+                        "<a href=\"{}\" type=\"{}\">{}</a>",
+                        file_name(INDEX_FILE_NAME, serialization.file_extension()),
+                        serialization.media_type(),
+                        serialization.name()
+                    )
+                })
+                .collect();
+            let _ = writeln!(body, "<p>Download: {}</p>", links.join(" | "));
+        }
+
+        for kind in &[
+            TermKind::Class,
+            TermKind::Property,
+            TermKind::Datatype,
+            TermKind::Individual,
+            TermKind::Other,
+        ] {
+            let terms: Vec<(&String, &Term)> = context
+                .terms
+                .iter()
+                .filter(|(_, term)| term.kind == *kind)
+                .collect();
+            if !terms.is_empty() {
+                let _ = writeln!(body, "<h2 id=\"{}\">{}</h2>", kind.anchor(), kind.plural());
+                let _ = writeln!(
+                    body,
+                    "<table>\n<tr><th>Term</th><th>Label</th><th>Description</th></tr>"
+                );
+                for (local_name, term) in terms {
+                    let _ = writeln!(
+                        body,
+                        "<tr><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td></tr>",
+                        escape(&file_name(local_name, HTML_FILE_EXTENSION)),
+                        escape(local_name),
+                        escape(term.label.as_deref().unwrap_or_default()),
+                        escape(term.description.as_deref().unwrap_or_default()),
+                    );
+                }
+                let _ = writeln!(body, "</table>");
+            }
+        }
+
+        html_page(&context.title, context.language, &body)
+    }
+
+    fn term_page(
+        &self,
+        graph: &dyn Graph,
+        context: &Context<'_>,
+        term: &Term,
+        referenced_by: Option<&Vec<(String, String)>>,
+    ) -> String {
+        let iri = term.iri.to_string();
+        let local_name = &iri[context.namespace.len()..];
+        let heading = term.label.as_deref().unwrap_or(local_name);
+
+        let mut body = String::new();
+        let _ = writeln!(
+            body,
+            "<p><a href=\"{}\">{}</a></p>",
+            file_name(INDEX_FILE_NAME, HTML_FILE_EXTENSION),
+            escape(&context.title)
+        );
+        let _ = writeln!(body, "<h1>{}</h1>", escape(heading));
+        let _ = writeln!(
+            body,
+            "<p><code>{}</code> ({})</p>",
+            escape(&iri),
+            term.kind.singular()
+        );
+        if let Some(description) = &term.description {
+            let _ = writeln!(body, "<p>{}</p>", escape(description));
+        }
+
+        let mut predicates: Vec<&IRIRef> =
+            graph.predicates_for(&term.subject).into_iter().collect();
+        predicates.sort_by_key(|predicate| predicate.to_string());
+        let _ = writeln!(
+            body,
+            "<h2>Statements</h2>\n<table>\n<tr><th>Predicate</th><th>Value</th></tr>"
+        );
+        for predicate in predicates {
+            let mut objects: Vec<String> = graph
+                .objects_for(&term.subject, predicate)
+                .into_iter()
+                .map(|object| html_object(object, context))
+                .collect();
+            objects.sort();
+            let _ = writeln!(
+                body,
+                "<tr><td>{}</td><td>{}</td></tr>",
+                html_iri(predicate, context),
+                objects.join("<br>")
+            );
+        }
+        let _ = writeln!(body, "</table>");
+
+        if let Some(referenced_by) = referenced_by {
+            let _ = writeln!(
+                body,
+                "<h2>Referenced By</h2>\n<table>\n<tr><th>Subject</th><th>Predicate</th></tr>"
+            );
+            for (subject, predicate) in referenced_by {
+                let _ = writeln!(body, "<tr><td>{}</td><td>{}</td></tr>", subject, predicate);
+            }
+            let _ = writeln!(body, "</table>");
+        }
+
+        html_page(
+            &format!("{} - {}", heading, context.title),
+            context.language,
+            &body,
+        )
+    }
+
+    fn htaccess(&self, base_path: &str, redirects: &[Redirect]) -> String {
+        let mut content = String::new();
+        let _ = writeln!(
+            content,
+            "# Content negotiation for the vocabulary <{}>.",
+            self.namespace
+        );
+        let _ = writeln!(
+            content,
+            "AddType {} .{}",
+            HTML_MIME_TYPE, HTML_FILE_EXTENSION
+        );
+        for serialization in &self.serializations {
+            let _ = writeln!(
+                content,
+                "AddType {} .{}",
+                serialization.media_type(),
+                serialization.file_extension()
+            );
+        }
+        let _ = writeln!(content, "Options -MultiViews");
+        let _ = writeln!(
+            content,
+            "DirectoryIndex {}",
+            file_name(INDEX_FILE_NAME, HTML_FILE_EXTENSION)
+        );
+        let _ = writeln!(content, "RewriteEngine On");
+        let _ = writeln!(content, "RewriteBase {}\n", base_path);
+        for redirect in redirects {
+            if redirect.media_type == HTML_MIME_TYPE {
+                let _ = writeln!(
+                    content,
+                    "RewriteRule ^{}$ {} [R=303,L]\n",
+                    redirect.from, redirect.to
+                );
+            } else {
+                let _ = writeln!(
+                    content,
+                    "RewriteCond %{{HTTP_ACCEPT}} {}\nRewriteRule ^{}$ {} [R=303,L]",
+                    regex_escape(&redirect.media_type),
+                    redirect.from,
+                    redirect.to
+                );
+            }
+        }
+        content
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl BundleFile {
+    ///
+    /// The path of this file, relative to the bundle's base path.
+    ///
+    pub fn path(&self) -> &String {
+        &self.path
+    }
+
+    ///
+    /// The MIME type of the content of this file.
+    ///
+    pub fn media_type(&self) -> &String {
+        &self.media_type
+    }
+
+    ///
+    /// The content of this file.
+    ///
+    pub fn content(&self) -> &String {
+        &self.content
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Redirect {
+    ///
+    /// The request path, relative to the bundle's base path, as a regular expression.
+    ///
+    pub fn from(&self) -> &String {
+        &self.from
+    }
+
+    ///
+    /// The media type that must be accepted by the client for this redirect to apply.
+    ///
+    pub fn media_type(&self) -> &String {
+        &self.media_type
+    }
+
+    ///
+    /// The bundle file that the request is redirected to.
+    ///
+    pub fn to(&self) -> &String {
+        &self.to
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Bundle {
+    ///
+    /// The absolute path, taken from the namespace IRI, at which the bundle must be served.
+    ///
+    pub fn base_path(&self) -> &String {
+        &self.base_path
+    }
+
+    ///
+    /// Return an iterator over all generated files.
+    ///
+    pub fn files(&self) -> impl Iterator<Item = &BundleFile> {
+        self.files.iter()
+    }
+
+    ///
+    /// Return the generated file with the provided path, if one exists.
+    ///
+    pub fn file(&self, path: &str) -> Option<&BundleFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
+
+    ///
+    /// Return an iterator over the content negotiation redirect map, the redirects for each path
+    /// are in the order they must be tested.
+    ///
+    pub fn redirects(&self) -> impl Iterator<Item = &Redirect> {
+        self.redirects.iter()
+    }
+
+    ///
+    /// Write all generated files into the provided directory, creating it if necessary.
+    ///
+    pub fn write_to(&self, directory: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(directory)?;
+        for file in &self.files {
+            std::fs::write(directory.join(&file.path), &file.content)?;
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl TermKind {
+    fn singular(&self) -> &'static str {
+        match self {
+            Self::Class => "Class",
+            Self::Property => "Property",
+            Self::Datatype => "Datatype",
+            Self::Individual => "Individual",
+            Self::Other => "Term",
+        }
+    }
+
+    fn plural(&self) -> &'static str {
+        match self {
+            Self::Class => "Classes",
+            Self::Property => "Properties",
+            Self::Datatype => "Datatypes",
+            Self::Individual => "Individuals",
+            Self::Other => "Other Terms",
+        }
+    }
+
+    fn anchor(&self) -> &'static str {
+        match self {
+            Self::Class => "classes",
+            Self::Property => "properties",
+            Self::Datatype => "datatypes",
+            Self::Individual => "individuals",
+            Self::Other => "other",
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Split the namespace IRI's path into the directory the bundle is served from and the name of
+/// the vocabulary document within it; for slash namespaces the document name is empty.
+///
+fn split_namespace_path(namespace: &IRIRef) -> (String, String) {
+    let path = namespace.path().value();
+    match path.rfind('/') {
+        Some(index) => (path[..=index].to_string(), regex_escape(&path[index + 1..])),
+        None => ("/".to_string(), regex_escape(path)),
+    }
+}
+
+fn file_name(name: &str, extension: &str) -> String {
+    format!("{}.{}", name, extension)
+}
+
+fn term_kind(graph: &dyn Graph, subject: &SubjectNodeRef) -> TermKind {
+    let types = graph.objects_for(subject, rdf::a_type());
+    types
+        .iter()
+        .filter_map(|object| object.as_iri())
+        .map(|iri| {
+            if iri == rdfs::class() || iri == owl::class() {
+                TermKind::Class
+            } else if iri == rdf::property()
+                || iri == owl::object_property()
+                || iri == owl::datatype_property()
+                || iri == owl::annotation_property()
+            {
+                TermKind::Property
+            } else if iri == rdfs::data_type() {
+                TermKind::Datatype
+            } else {
+                TermKind::Individual
+            }
+        })
+        .min()
+        .unwrap_or(TermKind::Other)
+}
+
+///
+/// Return the text of the first predicate with a literal value, preferring a value in the
+/// requested language, then one with no language, then any other.
+///
+fn preferred_text(
+    graph: &dyn Graph,
+    subject: &SubjectNodeRef,
+    predicates: &[&IRIRef],
+    language: Option<&LanguageTag>,
+) -> Option<String> {
+    predicates.iter().find_map(|predicate| {
+        let mut literals: Vec<(u8, &String)> = graph
+            .objects_for(subject, predicate)
+            .into_iter()
+            .filter_map(|object| object.as_literal())
+            .map(|literal| {
+                let rank = match (literal.language(), language) {
+                    (Some(actual), Some(preferred)) if actual == preferred => 0,
+                    (None, _) => 1,
+                    _ => 2,
+                };
+                (rank, literal.lexical_form())
+            })
+            .collect();
+        literals.sort();
+        literals.first().map(|(_, text)| text.to_string())
+    })
+}
+
+///
+/// For each term, the (subject, predicate) pairs of statements with the term as object.
+///
+fn referenced_by(
+    graph: &dyn Graph,
+    context: &Context<'_>,
+) -> HashMap<String, Vec<(String, String)>> {
+    let mut references: HashMap<String, Vec<(String, String)>> = Default::default();
+    for statement in graph.statements() {
+        if let Some(iri) = statement.object().as_iri() {
+            if let Some(local_name) = local_term(iri, context) {
+                references.entry(local_name).or_default().push((
+                    match statement.subject().as_iri() {
+                        Some(subject) => html_iri(subject, context),
+                        None => {
+                            format!("<code>{}</code>", escape(&statement.subject().to_string()))
+                        }
+                    },
+                    html_iri(statement.predicate(), context),
+                ));
+            }
+        }
+    }
+    for pairs in references.values_mut() {
+        pairs.sort();
+        pairs.dedup();
+    }
+    references
+}
+
+fn local_term(iri: &IRIRef, context: &Context<'_>) -> Option<String> {
+    iri.to_string()
+        .strip_prefix(&context.namespace)
+        .filter(|local_name| context.terms.contains_key(*local_name))
+        .map(str::to_string)
+}
+
+fn html_iri(iri: &IRIRef, context: &Context<'_>) -> String {
+    let text = match context.mappings.borrow().compress(iri) {
+        Some(qname) => qname.to_string(),
+        None => iri.to_string(),
+    };
+    let href = match local_term(iri, context) {
+        Some(local_name) => file_name(&local_name, HTML_FILE_EXTENSION),
+        None => iri.to_string(),
+    };
+    format!("<a href=\"{}\">{}</a>", escape(&href), escape(&text))
+}
+
+fn html_object(object: &ObjectNodeRef, context: &Context<'_>) -> String {
+    if let Some(iri) = object.as_iri() {
+        html_iri(iri, context)
+    } else if let Some(literal) = object.as_literal() {
+        let mut html = escape(literal.lexical_form());
+        if let Some(language) = literal.language() {
+            let _ = write!(html, " <small>@{}</small>", escape(&language.to_string()));
+        } else if let Some(data_type) = literal.data_type() {
+            let _ = write!(
+                html,
+                " <small>^^{}</small>",
+                html_iri(data_type.as_iri(), context)
+            );
+        }
+        html
+    } else {
+        format!("<code>{}</code>", escape(&object.to_string()))
+    }
+}
+
+fn html_page(title: &str, language: Option<&LanguageTag>, body: &str) -> String {
+    let mut page = String::new();
+    let _ = writeln!(page, "<!DOCTYPE html>");
+    match language {
+        Some(language) => {
+            let _ = writeln!(page, "<html lang=\"{}\">", escape(&language.to_string()));
+        }
+        None => {
+            let _ = writeln!(page, "<html>");
+        }
+    }
+    let _ = writeln!(page, "<head>");
+    let _ = writeln!(page, "<meta charset=\"utf-8\">");
+    let _ = writeln!(page, "<title>{}</title>", escape(title));
+    let _ = writeln!(
+        page,
+        "<style>body {{ font-family: sans-serif; margin: 2em; }} table {{ border-collapse: collapse; }} th, td {{ border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; vertical-align: top; }}</style>"
+    );
+    let _ = writeln!(page, "</head>");
+    let _ = writeln!(page, "<body>");
+    page.push_str(body);
+    let _ = writeln!(page, "</body>");
+    let _ = writeln!(page, "</html>");
+    page
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn regex_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
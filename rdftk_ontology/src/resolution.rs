@@ -0,0 +1,551 @@
+/*!
+Detect resources in two graphs that are likely to describe the same entity, producing scored
+`owl:sameAs` candidates for human review.
+
+An `EntityMatcher` is configured with a set of `PropertyRule`s, each naming a property, a weight,
+and how values of the property are compared:
+
+* **labels** are compared approximately, so that "Tony Benn" and "Benn, Tony" score highly;
+* **identifiers**, such as ISBNs or e-mail addresses, identify a resource so that a matching value
+  on its own is enough to score a candidate as `1.0`;
+* **functional** properties, which have a single value per resource, are compared exactly.
+
+For each pair of named resources, one from each graph, the score is the weighted mean similarity
+of all the rules for which both resources have values. Only pairs sharing at least one (normalized)
+value, or label word, are compared and only pairs scoring at or above the matcher's threshold are
+returned. Each `SameAsCandidate` records the evidence for its score and implements `ToStatements`
+so that accepted candidates may be added to a graph.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::IRI;
+use rdftk_names::foaf;
+use rdftk_ontology::resolution::EntityMatcher;
+use rdftk_ontology::ToStatements;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let person = |iri: &str, name: &str| {
+    let statement = factory.statement(
+        factory.named_subject(IRI::from_str(iri).unwrap().into()),
+        foaf::name().clone(),
+        factory.literal_object(literal_factory().literal(name)),
+    ).unwrap();
+    graph_factory().graph_from(&[statement], None)
+};
+let left = person("http://example.org/people/benn", "Tony Benn");
+let right = person("http://example.com/id/42", "Benn, Tony");
+
+let mut matcher = EntityMatcher::new(0.8);
+let _ = matcher.label(foaf::name().clone(), 1.0);
+
+let candidates = matcher.candidates(&left, &right);
+assert_eq!(candidates.len(), 1);
+assert_eq!(candidates[0].right().to_string(), "http://example.com/id/42");
+
+let statements = candidates[0].to_statements(&factory);
+assert_eq!(statements.len(), 1);
+```
+
+*/
+
+use crate::ToStatements;
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, SubjectNodeRef,
+};
+use rdftk_iri::IRIRef;
+use rdftk_names::{owl, rdfs};
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The threshold used by the default matcher.
+///
+pub const DEFAULT_THRESHOLD: f64 = 0.8;
+
+///
+/// Determines how the values of a property are compared.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    /// Values must be identical, IRIs are compared as strings and literals by lexical form.
+    Exact,
+    /// Values are identical once case, punctuation, and white space are ignored.
+    Normalized,
+    /// Values are scored between `0.0` and `1.0` on the character bigrams they share once
+    /// normalized, ignoring word order.
+    Similar,
+}
+
+///
+/// A property to compare, its contribution to the overall score, and how its values are compared.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyRule {
+    predicate: IRIRef,
+    weight: f64,
+    comparison: Comparison,
+    identifying: bool,
+}
+
+///
+/// Scores resources across two graphs using a set of property rules.
+///
+#[derive(Clone, Debug)]
+pub struct EntityMatcher {
+    rules: Vec<PropertyRule>,
+    threshold: f64,
+}
+
+///
+/// The similarity of the values of one property for a candidate pair.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Evidence {
+    predicate: IRIRef,
+    similarity: f64,
+}
+
+///
+/// A pair of resources, one from each graph, that may be `owl:sameAs` each other.
+///
+#[derive(Clone, Debug)]
+pub struct SameAsCandidate {
+    left: IRIRef,
+    right: IRIRef,
+    score: f64,
+    evidence: Vec<Evidence>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+type Values = Vec<Vec<String>>;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl PropertyRule {
+    ///
+    /// Create a new rule, the weight is clamped to be non-negative.
+    ///
+    pub fn new(predicate: IRIRef, weight: f64, comparison: Comparison) -> Self {
+        Self {
+            predicate,
+            weight: weight.max(0.0),
+            comparison,
+            identifying: false,
+        }
+    }
+
+    ///
+    /// Create a rule for a label property, such as `rdfs:label`, whose values are compared
+    /// approximately.
+    ///
+    pub fn label(predicate: IRIRef, weight: f64) -> Self {
+        Self::new(predicate, weight, Comparison::Similar)
+    }
+
+    ///
+    /// Create a rule for an identifying (inverse functional) property; any matching value makes
+    /// the pair a candidate with a score of `1.0`.
+    ///
+    pub fn identifier(predicate: IRIRef) -> Self {
+        Self {
+            identifying: true,
+            ..Self::new(predicate, 1.0, Comparison::Normalized)
+        }
+    }
+
+    ///
+    /// Create a rule for a functional property whose values are compared exactly.
+    ///
+    pub fn functional(predicate: IRIRef, weight: f64) -> Self {
+        Self::new(predicate, weight, Comparison::Exact)
+    }
+
+    ///
+    /// The property compared by this rule.
+    ///
+    pub fn predicate(&self) -> &IRIRef {
+        &self.predicate
+    }
+
+    ///
+    /// The contribution of this rule to the overall score.
+    ///
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    ///
+    /// How values of the property are compared.
+    ///
+    pub fn comparison(&self) -> Comparison {
+        self.comparison
+    }
+
+    ///
+    /// Returns `true` if a matching value for this property identifies the resource, else `false`.
+    ///
+    pub fn is_identifying(&self) -> bool {
+        self.identifying
+    }
+
+    fn similarity(&self, lhs: &[String], rhs: &[String]) -> f64 {
+        lhs.iter()
+            .flat_map(|l| rhs.iter().map(move |r| self.compare(l, r)))
+            .fold(0.0, f64::max)
+    }
+
+    fn compare(&self, lhs: &str, rhs: &str) -> f64 {
+        match self.comparison {
+            Comparison::Exact => bool_score(lhs == rhs),
+            Comparison::Normalized => bool_score(normalize(lhs) == normalize(rhs)),
+            Comparison::Similar => dice_coefficient(lhs, rhs),
+        }
+    }
+
+    fn blocking_keys(&self, value: &str) -> Vec<String> {
+        match self.comparison {
+            Comparison::Exact => vec![value.to_string()],
+            Comparison::Normalized => vec![normalize(value)],
+            Comparison::Similar => normalize(value)
+                .split(' ')
+                .filter(|word| !word.is_empty())
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Default for EntityMatcher {
+    fn default() -> Self {
+        let mut matcher = Self::new(DEFAULT_THRESHOLD);
+        let _ = matcher.label(rdfs::label().clone(), 1.0);
+        matcher
+    }
+}
+
+impl EntityMatcher {
+    ///
+    /// Create a new matcher, with no rules, that returns candidates scoring at or above
+    /// `threshold`.
+    ///
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            rules: Default::default(),
+            threshold,
+        }
+    }
+
+    ///
+    /// Add a rule to this matcher.
+    ///
+    pub fn rule(&mut self, rule: PropertyRule) -> &mut Self {
+        self.rules.push(rule);
+        self
+    }
+
+    ///
+    /// Add a label rule, see `PropertyRule::label`.
+    ///
+    pub fn label(&mut self, predicate: IRIRef, weight: f64) -> &mut Self {
+        self.rule(PropertyRule::label(predicate, weight))
+    }
+
+    ///
+    /// Add an identifier rule, see `PropertyRule::identifier`.
+    ///
+    pub fn identifier(&mut self, predicate: IRIRef) -> &mut Self {
+        self.rule(PropertyRule::identifier(predicate))
+    }
+
+    ///
+    /// Add a functional property rule, see `PropertyRule::functional`.
+    ///
+    pub fn functional(&mut self, predicate: IRIRef, weight: f64) -> &mut Self {
+        self.rule(PropertyRule::functional(predicate, weight))
+    }
+
+    ///
+    /// Set the minimum score for a pair to be returned as a candidate.
+    ///
+    pub fn threshold(&mut self, threshold: f64) -> &mut Self {
+        self.threshold = threshold;
+        self
+    }
+
+    ///
+    /// Return an iterator over the rules in this matcher.
+    ///
+    pub fn rules(&self) -> impl Iterator<Item = &PropertyRule> {
+        self.rules.iter()
+    }
+
+    ///
+    /// Score all pairs of named resources from `left` and `right` that share at least one value,
+    /// returning those at or above the threshold ordered by descending score. Resources with the
+    /// same IRI in both graphs are not returned.
+    ///
+    pub fn candidates(&self, left: &GraphRef, right: &GraphRef) -> Vec<SameAsCandidate> {
+        let left = self.values(&*left.borrow());
+        let right = self.values(&*right.borrow());
+
+        let mut index: HashMap<(usize, String), BTreeSet<&IRIRef>> = Default::default();
+        for (resource, values) in &right {
+            for key in self.keys(values) {
+                let _ = index.entry(key).or_default().insert(resource);
+            }
+        }
+
+        let mut candidates: Vec<SameAsCandidate> = Default::default();
+        for (left_resource, left_values) in &left {
+            let mut compared: HashSet<&IRIRef> = Default::default();
+            for key in self.keys(left_values) {
+                for right_resource in index.get(&key).into_iter().flatten() {
+                    if *right_resource != left_resource && compared.insert(right_resource) {
+                        if let Some(candidate) = self.score(
+                            left_resource,
+                            left_values,
+                            right_resource,
+                            &right[*right_resource],
+                        ) {
+                            candidates.push(candidate);
+                        }
+                    }
+                }
+            }
+        }
+        candidates.sort_by(|lhs, rhs| {
+            rhs.score
+                .partial_cmp(&lhs.score)
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| lhs.left.to_string().cmp(&rhs.left.to_string()))
+                .then_with(|| lhs.right.to_string().cmp(&rhs.right.to_string()))
+        });
+        candidates
+    }
+
+    fn values(&self, graph: &dyn Graph) -> HashMap<IRIRef, Values> {
+        graph
+            .subjects()
+            .into_iter()
+            .filter_map(|subject| subject.as_iri().map(|iri| (iri, subject)))
+            .map(|(iri, subject)| {
+                let values: Values = self
+                    .rules
+                    .iter()
+                    .map(|rule| property_values(graph, subject, &rule.predicate))
+                    .collect();
+                (iri.clone(), values)
+            })
+            .filter(|(_, values)| values.iter().any(|values| !values.is_empty()))
+            .collect()
+    }
+
+    fn keys<'a>(&'a self, values: &'a Values) -> impl Iterator<Item = (usize, String)> + 'a {
+        self.rules
+            .iter()
+            .zip(values.iter())
+            .enumerate()
+            .flat_map(|(i, (rule, values))| {
+                values
+                    .iter()
+                    .flat_map(move |value| rule.blocking_keys(value))
+                    .map(move |key| (i, key))
+            })
+    }
+
+    fn score(
+        &self,
+        left: &IRIRef,
+        left_values: &Values,
+        right: &IRIRef,
+        right_values: &Values,
+    ) -> Option<SameAsCandidate> {
+        let mut evidence: Vec<Evidence> = Default::default();
+        let mut total = 0.0;
+        let mut weights = 0.0;
+        let mut identified = false;
+        for ((rule, lhs), rhs) in self.rules.iter().zip(left_values).zip(right_values) {
+            if lhs.is_empty() || rhs.is_empty() {
+                continue;
+            }
+            let similarity = rule.similarity(lhs, rhs);
+            identified = identified || (rule.identifying && similarity >= 1.0);
+            total += rule.weight * similarity;
+            weights += rule.weight;
+            evidence.push(Evidence {
+                predicate: rule.predicate.clone(),
+                similarity,
+            });
+        }
+        let score = if identified {
+            1.0
+        } else if weights > 0.0 {
+            total / weights
+        } else {
+            0.0
+        };
+        if !evidence.is_empty() && score >= self.threshold {
+            Some(SameAsCandidate {
+                left: left.clone(),
+                right: right.clone(),
+                score,
+                evidence,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Evidence {
+    ///
+    /// The property compared.
+    ///
+    pub fn predicate(&self) -> &IRIRef {
+        &self.predicate
+    }
+
+    ///
+    /// The similarity, between `0.0` and `1.0`, of the best matching pair of values.
+    ///
+    pub fn similarity(&self) -> f64 {
+        self.similarity
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ToStatements for SameAsCandidate {
+    fn to_statements(&self, factory: &StatementFactoryRef) -> StatementList {
+        vec![factory
+            .statement(
+                factory.named_subject(self.left.clone()),
+                owl::same_as().clone(),
+                factory.named_object(self.right.clone()),
+            )
+            .unwrap()]
+    }
+}
+
+impl SameAsCandidate {
+    ///
+    /// The resource from the left-hand graph.
+    ///
+    pub fn left(&self) -> &IRIRef {
+        &self.left
+    }
+
+    ///
+    /// The resource from the right-hand graph.
+    ///
+    pub fn right(&self) -> &IRIRef {
+        &self.right
+    }
+
+    ///
+    /// The overall score, between `0.0` and `1.0`, for this pair.
+    ///
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+
+    ///
+    /// Return an iterator over the evidence for each property compared.
+    ///
+    pub fn evidence(&self) -> impl Iterator<Item = &Evidence> {
+        self.evidence.iter()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn property_values(graph: &dyn Graph, subject: &SubjectNodeRef, predicate: &IRIRef) -> Vec<String> {
+    graph
+        .objects_for(subject, predicate)
+        .into_iter()
+        .filter_map(object_value)
+        .collect()
+}
+
+fn object_value(object: &ObjectNodeRef) -> Option<String> {
+    if let Some(iri) = object.as_iri() {
+        Some(iri.to_string())
+    } else {
+        object
+            .as_literal()
+            .map(|literal| literal.lexical_form().clone())
+    }
+}
+
+fn normalize(value: &str) -> String {
+    value
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn bigrams(value: &str) -> Vec<(char, char)> {
+    let mut words: Vec<&str> = value.split(' ').collect();
+    words.sort_unstable();
+    let chars: Vec<char> = words.join(" ").chars().collect();
+    let mut bigrams: Vec<(char, char)> = chars.windows(2).map(|pair| (pair[0], pair[1])).collect();
+    bigrams.sort_unstable();
+    bigrams
+}
+
+fn dice_coefficient(lhs: &str, rhs: &str) -> f64 {
+    let lhs = normalize(lhs);
+    let rhs = normalize(rhs);
+    if lhs == rhs {
+        return bool_score(!lhs.is_empty());
+    }
+    let lhs = bigrams(&lhs);
+    let rhs = bigrams(&rhs);
+    if lhs.is_empty() || rhs.is_empty() {
+        return 0.0;
+    }
+    let (mut i, mut j, mut shared) = (0, 0, 0);
+    while i < lhs.len() && j < rhs.len() {
+        match lhs[i].cmp(&rhs[j]) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                shared += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    (2 * shared) as f64 / (lhs.len() + rhs.len()) as f64
+}
+
+fn bool_score(value: bool) -> f64 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,1274 @@
+/*!
+A validator for a subset of the [Shapes Constraint Language](https://www.w3.org/TR/shacl/) (SHACL)
+Core, which checks a data graph against the shapes described in a shapes graph and produces a
+`ValidationReport`.
+
+The following are supported:
+
+* **targets**; `sh:targetClass`, `sh:targetNode`, `sh:targetSubjectsOf`, `sh:targetObjectsOf`, and
+  implicit class targets for shapes that are also an `rdfs:Class`.
+* **shapes**; node shapes, and property shapes, linked with `sh:property`, with a predicate as
+  their `sh:path`, along with `sh:deactivated`, `sh:severity`, and `sh:message`.
+* **constraints**; `sh:class`, `sh:datatype`, `sh:nodeKind`, `sh:minCount`, `sh:maxCount`,
+  `sh:minInclusive`, `sh:maxInclusive`, `sh:minExclusive`, `sh:maxExclusive`, `sh:minLength`,
+  `sh:maxLength`, `sh:languageIn`, `sh:hasValue`, `sh:in`, `sh:node`, and `sh:closed` with
+  `sh:ignoredProperties`.
+
+Other constraint components, and property shapes with complex paths, are ignored. Numeric range
+constraints compare values numerically, and a value that is not a number is a violation.
+
+The `ShapesValidator` type uses the same validation to check a `ChangeSet` before it is applied to
+a graph, rejecting changes that would introduce new violations.
+
+The `IncrementalValidator` type keeps the results for a data graph up to date as change sets are
+applied to it, re-validating only the focus nodes that a change may affect. These are the subjects
+and objects of the changed statements, and, for shapes with `sh:class` or `sh:node` constraints
+that read the statements of value nodes, the nodes that refer to a changed subject. A change to
+`rdfs:subClassOf`, which may change the targets and classes of any node, re-validates the whole
+graph.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{foaf, rdf, shacl};
+use rdftk_ontology::shacl::{validate, ConstraintComponent};
+use std::str::FromStr;
+
+let factory = statement_factory();
+let literals = literal_factory();
+let alice = IRIRef::from(IRI::from_str("http://example.org/alice").unwrap());
+let shape = IRIRef::from(IRI::from_str("http://example.org/shapes/Person").unwrap());
+
+let shapes = graph_factory().graph();
+{
+    let mut shapes = shapes.borrow_mut();
+    let property = factory.blank_subject_named("name").unwrap();
+    shapes.insert(factory.statement(
+        factory.named_subject(shape.clone()),
+        shacl::target_class().clone(),
+        factory.named_object(foaf::person().clone()),
+    ).unwrap());
+    shapes.insert(factory.statement(
+        factory.named_subject(shape.clone()),
+        shacl::property().clone(),
+        factory.blank_object_named("name").unwrap(),
+    ).unwrap());
+    shapes.insert(factory.statement(
+        property.clone(),
+        shacl::path().clone(),
+        factory.named_object(foaf::name().clone()),
+    ).unwrap());
+    shapes.insert(factory.statement(
+        property,
+        shacl::min_count().clone(),
+        factory.literal_object(literals.long(1)),
+    ).unwrap());
+}
+
+let data = graph_factory().graph_from(
+    &[factory.statement(
+        factory.named_subject(alice),
+        rdf::a_type().clone(),
+        factory.named_object(foaf::person().clone()),
+    ).unwrap()],
+    None,
+);
+
+let report = validate(&*shapes.borrow(), &*data.borrow());
+assert!(!report.conforms());
+assert_eq!(report.results().len(), 1);
+assert_eq!(report.results()[0].component(), ConstraintComponent::MinCount);
+```
+
+*/
+
+use rdftk_core::model::graph::change_set::{ChangeSet, ChangeValidator};
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_core::model::statement::{ObjectNodeRef, StatementFactoryRef, SubjectNodeRef};
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, rdfs, shacl, xsd};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The severity of a validation result, from `sh:severity`.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Severity {
+    /// An informational result, `sh:Info`.
+    Info,
+    /// A warning, `sh:Warning`.
+    Warning,
+    /// A violation, `sh:Violation`, the default.
+    Violation,
+}
+
+///
+/// The constraint component that produced a validation result.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConstraintComponent {
+    /// `sh:ClassConstraintComponent`
+    Class,
+    /// `sh:DatatypeConstraintComponent`
+    Datatype,
+    /// `sh:NodeKindConstraintComponent`
+    NodeKind,
+    /// `sh:MinCountConstraintComponent`
+    MinCount,
+    /// `sh:MaxCountConstraintComponent`
+    MaxCount,
+    /// `sh:MinInclusiveConstraintComponent`
+    MinInclusive,
+    /// `sh:MaxInclusiveConstraintComponent`
+    MaxInclusive,
+    /// `sh:MinExclusiveConstraintComponent`
+    MinExclusive,
+    /// `sh:MaxExclusiveConstraintComponent`
+    MaxExclusive,
+    /// `sh:MinLengthConstraintComponent`
+    MinLength,
+    /// `sh:MaxLengthConstraintComponent`
+    MaxLength,
+    /// `sh:LanguageInConstraintComponent`
+    LanguageIn,
+    /// `sh:HasValueConstraintComponent`
+    HasValue,
+    /// `sh:InConstraintComponent`
+    In,
+    /// `sh:NodeConstraintComponent`
+    Node,
+    /// `sh:ClosedConstraintComponent`
+    Closed,
+}
+
+///
+/// A single result, describing a failure of a focus node to conform to a shape.
+///
+#[derive(Clone, Debug)]
+pub struct ValidationResult {
+    focus_node: ObjectNodeRef,
+    path: Option<IRIRef>,
+    value: Option<ObjectNodeRef>,
+    source_shape: SubjectNodeRef,
+    component: ConstraintComponent,
+    severity: Severity,
+    message: String,
+}
+
+///
+/// The results of validating a data graph against a shapes graph.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    results: Vec<ValidationResult>,
+}
+
+///
+/// A `ChangeValidator` that reports each `sh:Violation` result that a change set would introduce,
+/// violations already present in the graph before the changes do not prevent them from being
+/// applied.
+///
+#[derive(Clone, Debug)]
+pub struct ShapesValidator {
+    shapes: GraphRef,
+}
+
+///
+/// Holds the results of validating a data graph, and re-validates only the focus nodes affected
+/// by each change set applied to the graph. Changes to the shapes graph are not tracked, use
+/// `revalidate` after changing the shapes.
+///
+#[derive(Clone, Debug)]
+pub struct IncrementalValidator {
+    shapes: GraphRef,
+    results: BTreeMap<(String, String), Vec<ValidationResult>>,
+}
+
+///
+/// The difference re-validation made to the results of an `IncrementalValidator`.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ValidationDelta {
+    added: Vec<ValidationResult>,
+    resolved: Vec<ValidationResult>,
+    validated: usize,
+    full: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+struct Validator<'a> {
+    shapes: &'a dyn Graph,
+    data: &'a dyn Graph,
+    factory: StatementFactoryRef,
+}
+
+const MAX_NODE_DEPTH: usize = 32;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Validate the `data` graph against all the shapes, with targets, in the `shapes` graph.
+///
+pub fn validate(shapes: &dyn Graph, data: &dyn Graph) -> ValidationReport {
+    let validator = Validator::new(shapes, data);
+    let mut results = Vec::default();
+    for shape in validator.targeted_shapes() {
+        for focus in validator.focus_nodes(&shape) {
+            validator.validate_shape(&shape, &focus, 0, &mut results);
+        }
+    }
+    ValidationReport { results }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Info => "Info",
+                Self::Warning => "Warning",
+                Self::Violation => "Violation",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ConstraintComponent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", shacl::default_prefix(), self.local_name())
+    }
+}
+
+impl ConstraintComponent {
+    ///
+    /// Return the local name of this component within the SHACL namespace.
+    ///
+    pub fn local_name(&self) -> &'static str {
+        match self {
+            Self::Class => "ClassConstraintComponent",
+            Self::Datatype => "DatatypeConstraintComponent",
+            Self::NodeKind => "NodeKindConstraintComponent",
+            Self::MinCount => "MinCountConstraintComponent",
+            Self::MaxCount => "MaxCountConstraintComponent",
+            Self::MinInclusive => "MinInclusiveConstraintComponent",
+            Self::MaxInclusive => "MaxInclusiveConstraintComponent",
+            Self::MinExclusive => "MinExclusiveConstraintComponent",
+            Self::MaxExclusive => "MaxExclusiveConstraintComponent",
+            Self::MinLength => "MinLengthConstraintComponent",
+            Self::MaxLength => "MaxLengthConstraintComponent",
+            Self::LanguageIn => "LanguageInConstraintComponent",
+            Self::HasValue => "HasValueConstraintComponent",
+            Self::In => "InConstraintComponent",
+            Self::Node => "NodeConstraintComponent",
+            Self::Closed => "ClosedConstraintComponent",
+        }
+    }
+
+    ///
+    /// Return the IRI of this component.
+    ///
+    pub fn to_iri(&self) -> IRIRef {
+        IRIRef::from(
+            IRI::from_str(&format!("{}{}", shacl::namespace_str(), self.local_name())).unwrap(),
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ValidationResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} focus: {}",
+            self.severity, self.component, self.focus_node
+        )?;
+        if let Some(path) = &self.path {
+            write!(f, " path: <{}>", path)?;
+        }
+        if let Some(value) = &self.value {
+            write!(f, " value: {}", value)?;
+        }
+        write!(f, " - {}", self.message)
+    }
+}
+
+impl ValidationResult {
+    ///
+    /// The focus node that failed to conform.
+    ///
+    pub fn focus_node(&self) -> &ObjectNodeRef {
+        &self.focus_node
+    }
+
+    ///
+    /// The path of the property shape, if the result was produced by a property shape.
+    ///
+    pub fn path(&self) -> Option<&IRIRef> {
+        self.path.as_ref()
+    }
+
+    ///
+    /// The value node that failed the constraint, if any.
+    ///
+    pub fn value(&self) -> Option<&ObjectNodeRef> {
+        self.value.as_ref()
+    }
+
+    ///
+    /// The shape containing the failed constraint.
+    ///
+    pub fn source_shape(&self) -> &SubjectNodeRef {
+        &self.source_shape
+    }
+
+    ///
+    /// The constraint component that failed.
+    ///
+    pub fn component(&self) -> ConstraintComponent {
+        self.component
+    }
+
+    ///
+    /// The severity of this result.
+    ///
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    ///
+    /// A message describing this result, either the `sh:message` of the shape or a default.
+    ///
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ValidationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.conforms() {
+            writeln!(f, "Conforms")
+        } else {
+            writeln!(f, "Does not conform, {} result(s)", self.results.len())?;
+            for result in &self.results {
+                writeln!(f, "  {}", result)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl ValidationReport {
+    ///
+    /// Returns `true` if validation produced no results, of any severity.
+    ///
+    pub fn conforms(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    ///
+    /// Return all the results of validation.
+    ///
+    pub fn results(&self) -> &Vec<ValidationResult> {
+        &self.results
+    }
+
+    ///
+    /// Return only those results with the severity `sh:Violation`.
+    ///
+    pub fn violations(&self) -> impl Iterator<Item = &ValidationResult> {
+        self.results
+            .iter()
+            .filter(|result| result.severity == Severity::Violation)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ChangeValidator for ShapesValidator {
+    fn validate(&self, before: &dyn Graph, after: &dyn Graph, _: &ChangeSet) -> Vec<String> {
+        let shapes = self.shapes.borrow();
+        let existing: HashSet<String> = validate(&*shapes, before)
+            .violations()
+            .map(|result| result.to_string())
+            .collect();
+        validate(&*shapes, after)
+            .violations()
+            .map(|result| result.to_string())
+            .filter(|result| !existing.contains(result))
+            .collect()
+    }
+}
+
+impl ShapesValidator {
+    ///
+    /// Construct a new validator for the shapes in the `shapes` graph.
+    ///
+    pub fn new(shapes: GraphRef) -> Self {
+        Self { shapes }
+    }
+
+    ///
+    /// Return the graph containing the shapes used by this validator.
+    ///
+    pub fn shapes(&self) -> &GraphRef {
+        &self.shapes
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl IncrementalValidator {
+    ///
+    /// Construct a new validator for the shapes in the `shapes` graph, validating all of `data`.
+    ///
+    pub fn new(shapes: GraphRef, data: &dyn Graph) -> Self {
+        let mut validator = Self {
+            shapes,
+            results: Default::default(),
+        };
+        let _ = validator.revalidate(data);
+        validator
+    }
+
+    ///
+    /// Return the graph containing the shapes used by this validator.
+    ///
+    pub fn shapes(&self) -> &GraphRef {
+        &self.shapes
+    }
+
+    ///
+    /// Return the current results as a report, ordered by shape and focus node.
+    ///
+    pub fn report(&self) -> ValidationReport {
+        ValidationReport {
+            results: self.results.values().flatten().cloned().collect(),
+        }
+    }
+
+    ///
+    /// Returns `true` if the data graph currently has no results, of any severity.
+    ///
+    pub fn conforms(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    ///
+    /// Re-validate the focus nodes affected by `changes`, which have already been applied to
+    /// `data`.
+    ///
+    pub fn update(&mut self, data: &dyn Graph, changes: &ChangeSet) -> ValidationDelta {
+        if changes
+            .changes()
+            .any(|change| change.statement().predicate() == rdfs::subclass_of())
+        {
+            return self.revalidate(data);
+        }
+        let shapes = self.shapes.clone();
+        let shapes = shapes.borrow();
+        let validator = Validator::new(&*shapes, data);
+
+        let mut changed: HashSet<ObjectNodeRef> = Default::default();
+        let mut subjects: HashSet<ObjectNodeRef> = Default::default();
+        for change in changes.changes() {
+            let statement = change.statement();
+            if let Some(subject) = validator.subject_to_object(statement.subject()) {
+                let _ = changed.insert(subject.clone());
+                let _ = subjects.insert(subject);
+            }
+            let _ = changed.insert(statement.object().clone());
+        }
+        let mut referencing: Option<HashSet<ObjectNodeRef>> = None;
+
+        let mut delta = ValidationDelta::default();
+        for shape in validator.targeted_shapes() {
+            let mut candidates: Vec<ObjectNodeRef> = if validator.is_deep(&shape) {
+                referencing
+                    .get_or_insert_with(|| validator.referencing(&subjects))
+                    .union(&changed)
+                    .cloned()
+                    .collect()
+            } else {
+                changed.iter().cloned().collect()
+            };
+            candidates.sort_by_key(|focus| focus.to_string());
+            for focus in candidates {
+                let key = (shape.to_string(), focus.to_string());
+                let before = self.results.remove(&key).unwrap_or_default();
+                let mut after = Vec::default();
+                if validator.is_focus_node(&shape, &focus) {
+                    validator.validate_shape(&shape, &focus, 0, &mut after);
+                    delta.validated += 1;
+                }
+                delta.compare(before, &after);
+                if !after.is_empty() {
+                    let _ = self.results.insert(key, after);
+                }
+            }
+        }
+        delta
+    }
+
+    ///
+    /// Re-validate all of `data`, as is required after changing the shapes graph.
+    ///
+    pub fn revalidate(&mut self, data: &dyn Graph) -> ValidationDelta {
+        let shapes = self.shapes.clone();
+        let shapes = shapes.borrow();
+        let validator = Validator::new(&*shapes, data);
+        let mut delta = ValidationDelta {
+            full: true,
+            ..Default::default()
+        };
+        let mut results: BTreeMap<(String, String), Vec<ValidationResult>> = Default::default();
+        for shape in validator.targeted_shapes() {
+            for focus in validator.focus_nodes(&shape) {
+                let mut after = Vec::default();
+                validator.validate_shape(&shape, &focus, 0, &mut after);
+                delta.validated += 1;
+                if !after.is_empty() {
+                    let _ = results.insert((shape.to_string(), focus.to_string()), after);
+                }
+            }
+        }
+        let before = std::mem::replace(&mut self.results, results);
+        delta.compare(
+            before.into_values().flatten().collect(),
+            &self.results.values().flatten().cloned().collect::<Vec<_>>(),
+        );
+        delta
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ValidationDelta {
+    ///
+    /// Return the results that were not present before re-validation.
+    ///
+    pub fn added(&self) -> &Vec<ValidationResult> {
+        &self.added
+    }
+
+    ///
+    /// Return the results that were present before re-validation, but no longer are.
+    ///
+    pub fn resolved(&self) -> &Vec<ValidationResult> {
+        &self.resolved
+    }
+
+    ///
+    /// Returns `true` if re-validation made no difference to the results.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.resolved.is_empty()
+    }
+
+    ///
+    /// Return the number of focus nodes validated, counted once for each shape targeting them.
+    ///
+    pub fn validated(&self) -> usize {
+        self.validated
+    }
+
+    ///
+    /// Returns `true` if the whole data graph was re-validated.
+    ///
+    pub fn is_full(&self) -> bool {
+        self.full
+    }
+
+    fn compare(&mut self, before: Vec<ValidationResult>, after: &[ValidationResult]) {
+        let before_strings: HashSet<String> = before.iter().map(|r| r.to_string()).collect();
+        let after_strings: HashSet<String> = after.iter().map(|r| r.to_string()).collect();
+        self.added.extend(
+            after
+                .iter()
+                .filter(|result| !before_strings.contains(&result.to_string()))
+                .cloned(),
+        );
+        self.resolved.extend(
+            before
+                .into_iter()
+                .filter(|result| !after_strings.contains(&result.to_string())),
+        );
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Validator<'a> {
+    fn new(shapes: &'a dyn Graph, data: &'a dyn Graph) -> Self {
+        Self {
+            shapes,
+            data,
+            factory: data.statement_factory(),
+        }
+    }
+}
+
+impl Validator<'_> {
+    fn targeted_shapes(&self) -> Vec<SubjectNodeRef> {
+        let targets = [
+            shacl::target_class(),
+            shacl::target_node(),
+            shacl::target_subjects_of(),
+            shacl::target_objects_of(),
+        ];
+        let mut shapes: Vec<SubjectNodeRef> = Default::default();
+        for statement in self.shapes.statements() {
+            let is_target = targets.contains(&statement.predicate())
+                || (statement.predicate() == rdf::a_type()
+                    && statement.object().as_iri() == Some(rdfs::class())
+                    && self.is_shape(statement.subject()));
+            if is_target && !shapes.contains(statement.subject()) {
+                shapes.push(statement.subject().clone());
+            }
+        }
+        shapes.retain(|shape| !self.is_deactivated(shape));
+        shapes.sort_by_key(|shape| shape.to_string());
+        shapes
+    }
+
+    fn is_shape(&self, subject: &SubjectNodeRef) -> bool {
+        self.shape_values(subject, rdf::a_type())
+            .iter()
+            .any(|class| {
+                class.as_iri() == Some(shacl::node_shape())
+                    || class.as_iri() == Some(shacl::property_shape())
+            })
+    }
+
+    fn is_deactivated(&self, shape: &SubjectNodeRef) -> bool {
+        self.shape_value(shape, shacl::deactivated())
+            .and_then(|value| value.as_literal().map(|l| l.lexical_form() == "true"))
+            .unwrap_or(false)
+    }
+
+    fn focus_nodes(&self, shape: &SubjectNodeRef) -> Vec<ObjectNodeRef> {
+        let mut nodes: Vec<ObjectNodeRef> = Default::default();
+        let mut add = |node: ObjectNodeRef| {
+            if !nodes.contains(&node) {
+                nodes.push(node);
+            }
+        };
+        for class in self.target_classes(shape).iter() {
+            for statement in self.data.statements() {
+                if statement.predicate() == rdf::a_type() {
+                    if let Some(node_class) = statement.object().as_iri() {
+                        if self.is_subclass_of(node_class, class) {
+                            if let Some(node) = self.subject_to_object(statement.subject()) {
+                                add(node);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        for node in self.shape_values(shape, shacl::target_node()) {
+            add(node);
+        }
+        for predicate in self.shape_values(shape, shacl::target_subjects_of()) {
+            if let Some(predicate) = predicate.as_iri() {
+                for statement in self.data.statements_matching(None, Some(predicate), None) {
+                    if let Some(node) = self.subject_to_object(statement.subject()) {
+                        add(node);
+                    }
+                }
+            }
+        }
+        for predicate in self.shape_values(shape, shacl::target_objects_of()) {
+            if let Some(predicate) = predicate.as_iri() {
+                for statement in self.data.statements_matching(None, Some(predicate), None) {
+                    add(statement.object().clone());
+                }
+            }
+        }
+        nodes
+    }
+
+    fn target_classes(&self, shape: &SubjectNodeRef) -> Vec<IRIRef> {
+        let mut classes: Vec<IRIRef> = self
+            .shape_values(shape, shacl::target_class())
+            .iter()
+            .filter_map(|class| class.as_iri().cloned())
+            .collect();
+        if let Some(iri) = shape.as_iri() {
+            if self
+                .shape_values(shape, rdf::a_type())
+                .iter()
+                .any(|class| class.as_iri() == Some(rdfs::class()))
+            {
+                classes.push(iri.clone());
+            }
+        }
+        classes
+    }
+
+    ///
+    /// The same test as `focus_nodes`, for a single node.
+    ///
+    fn is_focus_node(&self, shape: &SubjectNodeRef, node: &ObjectNodeRef) -> bool {
+        if self
+            .shape_values(shape, shacl::target_node())
+            .contains(node)
+        {
+            return true;
+        }
+        let classes = self.target_classes(shape);
+        if !classes.is_empty()
+            && self
+                .data_values(node, rdf::a_type())
+                .iter()
+                .filter_map(|node_class| node_class.as_iri())
+                .any(|node_class| {
+                    classes
+                        .iter()
+                        .any(|class| self.is_subclass_of(node_class, class))
+                })
+        {
+            return true;
+        }
+        if let Some(subject) = self.object_to_subject(node) {
+            if self
+                .shape_values(shape, shacl::target_subjects_of())
+                .iter()
+                .filter_map(|predicate| predicate.as_iri())
+                .any(|predicate| {
+                    !self
+                        .data
+                        .statements_matching(Some(&subject), Some(predicate), None)
+                        .is_empty()
+                })
+            {
+                return true;
+            }
+        }
+        self.shape_values(shape, shacl::target_objects_of())
+            .iter()
+            .filter_map(|predicate| predicate.as_iri())
+            .any(|predicate| {
+                !self
+                    .data
+                    .statements_matching(None, Some(predicate), Some(node))
+                    .is_empty()
+            })
+    }
+
+    ///
+    /// Returns `true` if validating the shape reads the statements of value nodes, and not only
+    /// those of the focus node.
+    ///
+    fn is_deep(&self, shape: &SubjectNodeRef) -> bool {
+        let is_deep = |shape: &SubjectNodeRef| {
+            self.shape_value(shape, shacl::class()).is_some()
+                || self.shape_value(shape, shacl::node()).is_some()
+        };
+        is_deep(shape)
+            || self
+                .shape_values(shape, shacl::property())
+                .iter()
+                .filter_map(|property| self.object_to_subject(property))
+                .any(|property| is_deep(&property))
+    }
+
+    ///
+    /// Return the nodes, and those that refer to them through up to `MAX_NODE_DEPTH` statements.
+    ///
+    fn referencing(&self, nodes: &HashSet<ObjectNodeRef>) -> HashSet<ObjectNodeRef> {
+        let mut found = nodes.clone();
+        let mut frontier: Vec<ObjectNodeRef> = nodes.iter().cloned().collect();
+        for _ in 0..MAX_NODE_DEPTH {
+            let mut next: Vec<ObjectNodeRef> = Default::default();
+            for node in &frontier {
+                for statement in self.data.statements_matching(None, None, Some(node)) {
+                    if let Some(subject) = self.subject_to_object(statement.subject()) {
+                        if found.insert(subject.clone()) {
+                            next.push(subject);
+                        }
+                    }
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+        found
+    }
+
+    fn validate_shape(
+        &self,
+        shape: &SubjectNodeRef,
+        focus: &ObjectNodeRef,
+        depth: usize,
+        results: &mut Vec<ValidationResult>,
+    ) {
+        if self.shape_value(shape, shacl::path()).is_some() {
+            self.validate_property(shape, focus, depth, results);
+        } else {
+            self.validate_values(
+                shape,
+                focus,
+                None,
+                std::slice::from_ref(focus),
+                depth,
+                results,
+            );
+            if self.is_closed(shape) {
+                self.validate_closed(shape, focus, results);
+            }
+            for property in self.shape_values(shape, shacl::property()) {
+                if let Some(property) = self.object_to_subject(&property) {
+                    if !self.is_deactivated(&property) {
+                        self.validate_property(&property, focus, depth, results);
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_property(
+        &self,
+        shape: &SubjectNodeRef,
+        focus: &ObjectNodeRef,
+        depth: usize,
+        results: &mut Vec<ValidationResult>,
+    ) {
+        let path = match self
+            .shape_value(shape, shacl::path())
+            .and_then(|path| path.as_iri().cloned())
+        {
+            Some(path) => path,
+            None => return,
+        };
+        let values = self.data_values(focus, &path);
+        let mut result = |component, message: String| {
+            results.push(self.result(shape, focus, Some(&path), None, component, message))
+        };
+        if let Some(min) = self.shape_count(shape, shacl::min_count()) {
+            if values.len() < min {
+                result(
+                    ConstraintComponent::MinCount,
+                    format!("Expected at least {} value(s), found {}", min, values.len()),
+                );
+            }
+        }
+        if let Some(max) = self.shape_count(shape, shacl::max_count()) {
+            if values.len() > max {
+                result(
+                    ConstraintComponent::MaxCount,
+                    format!("Expected at most {} value(s), found {}", max, values.len()),
+                );
+            }
+        }
+        self.validate_values(shape, focus, Some(&path), &values, depth, results);
+    }
+
+    fn validate_values(
+        &self,
+        shape: &SubjectNodeRef,
+        focus: &ObjectNodeRef,
+        path: Option<&IRIRef>,
+        values: &[ObjectNodeRef],
+        depth: usize,
+        results: &mut Vec<ValidationResult>,
+    ) {
+        let mut result = |value: &ObjectNodeRef, component, message: String| {
+            results.push(self.result(shape, focus, path, Some(value), component, message))
+        };
+        for class in self.shape_values(shape, shacl::class()) {
+            if let Some(class) = class.as_iri() {
+                for value in values
+                    .iter()
+                    .filter(|value| !self.is_instance_of(value, class))
+                {
+                    result(
+                        value,
+                        ConstraintComponent::Class,
+                        format!("Value is not an instance of <{}>", class),
+                    );
+                }
+            }
+        }
+        if let Some(data_type) = self.shape_value(shape, shacl::datatype()) {
+            if let Some(data_type) = data_type.as_iri() {
+                for value in values
+                    .iter()
+                    .filter(|value| literal_data_type(value).as_ref() != Some(data_type))
+                {
+                    result(
+                        value,
+                        ConstraintComponent::Datatype,
+                        format!("Value does not have the data type <{}>", data_type),
+                    );
+                }
+            }
+        }
+        if let Some(kind) = self.shape_value(shape, shacl::node_kind()) {
+            if let Some(kind) = kind.as_iri() {
+                for value in values.iter().filter(|value| !is_node_kind(value, kind)) {
+                    result(
+                        value,
+                        ConstraintComponent::NodeKind,
+                        format!("Value is not of the node kind <{}>", kind),
+                    );
+                }
+            }
+        }
+        let ranges = [
+            (shacl::min_inclusive(), ConstraintComponent::MinInclusive),
+            (shacl::max_inclusive(), ConstraintComponent::MaxInclusive),
+            (shacl::min_exclusive(), ConstraintComponent::MinExclusive),
+            (shacl::max_exclusive(), ConstraintComponent::MaxExclusive),
+        ];
+        for (predicate, component) in ranges.iter() {
+            if let Some(limit) = self.shape_value(shape, predicate).and_then(|v| number(&v)) {
+                for value in values {
+                    let valid = number(value).is_some_and(|value| match component {
+                        ConstraintComponent::MinInclusive => value >= limit,
+                        ConstraintComponent::MaxInclusive => value <= limit,
+                        ConstraintComponent::MinExclusive => value > limit,
+                        _ => value < limit,
+                    });
+                    if !valid {
+                        result(
+                            value,
+                            *component,
+                            format!("Value is outside the range set by {}", component),
+                        );
+                    }
+                }
+            }
+        }
+        if let Some(min) = self.shape_count(shape, shacl::min_length()) {
+            for value in values
+                .iter()
+                .filter(|value| string_length(value).is_none_or(|len| len < min))
+            {
+                result(
+                    value,
+                    ConstraintComponent::MinLength,
+                    format!("Value is shorter than {} character(s)", min),
+                );
+            }
+        }
+        if let Some(max) = self.shape_count(shape, shacl::max_length()) {
+            for value in values
+                .iter()
+                .filter(|value| string_length(value).is_none_or(|len| len > max))
+            {
+                result(
+                    value,
+                    ConstraintComponent::MaxLength,
+                    format!("Value is longer than {} character(s)", max),
+                );
+            }
+        }
+        if let Some(languages) = self.shape_list(shape, shacl::language_in()) {
+            let languages: Vec<String> = languages
+                .iter()
+                .filter_map(|language| language.as_literal())
+                .map(|language| language.lexical_form().to_lowercase())
+                .collect();
+            for value in values.iter().filter(|value| {
+                !value
+                    .as_literal()
+                    .and_then(|literal| literal.language())
+                    .is_some_and(|language| language_matches(&language.to_string(), &languages))
+            }) {
+                result(
+                    value,
+                    ConstraintComponent::LanguageIn,
+                    format!("Value does not have one of the languages {:?}", languages),
+                );
+            }
+        }
+        if let Some(allowed) = self.shape_list(shape, shacl::in_values()) {
+            for value in values.iter().filter(|value| !allowed.contains(value)) {
+                result(
+                    value,
+                    ConstraintComponent::In,
+                    "Value is not one of the allowed values".to_string(),
+                );
+            }
+        }
+        for node_shape in self.shape_values(shape, shacl::node()) {
+            if let Some(node_shape) = self.object_to_subject(&node_shape) {
+                if depth < MAX_NODE_DEPTH {
+                    for value in values {
+                        let mut nested = Vec::default();
+                        self.validate_shape(&node_shape, value, depth + 1, &mut nested);
+                        if !nested.is_empty() {
+                            result(
+                                value,
+                                ConstraintComponent::Node,
+                                format!("Value does not conform to the shape {}", node_shape),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(expected) = self.shape_value(shape, shacl::has_value()) {
+            if !values.contains(&expected) {
+                results.push(self.result(
+                    shape,
+                    focus,
+                    path,
+                    None,
+                    ConstraintComponent::HasValue,
+                    format!("Expected the value {}", expected),
+                ));
+            }
+        }
+    }
+
+    fn is_closed(&self, shape: &SubjectNodeRef) -> bool {
+        self.shape_value(shape, shacl::closed())
+            .and_then(|value| value.as_literal().map(|l| l.lexical_form() == "true"))
+            .unwrap_or(false)
+    }
+
+    fn validate_closed(
+        &self,
+        shape: &SubjectNodeRef,
+        focus: &ObjectNodeRef,
+        results: &mut Vec<ValidationResult>,
+    ) {
+        let mut allowed: HashSet<IRIRef> = self
+            .shape_list(shape, shacl::ignored_properties())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|property| property.as_iri().cloned())
+            .collect();
+        for property in self.shape_values(shape, shacl::property()) {
+            if let Some(property) = self.object_to_subject(&property) {
+                if let Some(path) = self
+                    .shape_value(&property, shacl::path())
+                    .and_then(|path| path.as_iri().cloned())
+                {
+                    let _ = allowed.insert(path);
+                }
+            }
+        }
+        if let Some(subject) = self.object_to_subject(focus) {
+            for statement in self.data.statements_matching(Some(&subject), None, None) {
+                if !allowed.contains(statement.predicate()) {
+                    results.push(self.result(
+                        shape,
+                        focus,
+                        Some(statement.predicate()),
+                        Some(statement.object()),
+                        ConstraintComponent::Closed,
+                        "Property is not allowed by the closed shape".to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn result(
+        &self,
+        shape: &SubjectNodeRef,
+        focus: &ObjectNodeRef,
+        path: Option<&IRIRef>,
+        value: Option<&ObjectNodeRef>,
+        component: ConstraintComponent,
+        default_message: String,
+    ) -> ValidationResult {
+        let severity = match self
+            .shape_value(shape, shacl::shape_severity())
+            .and_then(|severity| severity.as_iri().cloned())
+        {
+            Some(severity) if &severity == shacl::info() => Severity::Info,
+            Some(severity) if &severity == shacl::warning() => Severity::Warning,
+            _ => Severity::Violation,
+        };
+        let message = self
+            .shape_value(shape, shacl::message())
+            .and_then(|message| message.as_literal().map(|l| l.lexical_form().clone()))
+            .unwrap_or(default_message);
+        ValidationResult {
+            focus_node: focus.clone(),
+            path: path.cloned(),
+            value: value.cloned(),
+            source_shape: shape.clone(),
+            component,
+            severity,
+            message,
+        }
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    fn shape_values(&self, shape: &SubjectNodeRef, predicate: &IRIRef) -> Vec<ObjectNodeRef> {
+        self.shapes
+            .statements_matching(Some(shape), Some(predicate), None)
+            .iter()
+            .map(|statement| statement.object().clone())
+            .collect()
+    }
+
+    fn shape_value(&self, shape: &SubjectNodeRef, predicate: &IRIRef) -> Option<ObjectNodeRef> {
+        self.shape_values(shape, predicate).into_iter().next()
+    }
+
+    fn shape_count(&self, shape: &SubjectNodeRef, predicate: &IRIRef) -> Option<usize> {
+        self.shape_value(shape, predicate).and_then(|value| {
+            value
+                .as_literal()
+                .and_then(|l| l.lexical_form().parse().ok())
+        })
+    }
+
+    fn shape_list(&self, shape: &SubjectNodeRef, predicate: &IRIRef) -> Option<Vec<ObjectNodeRef>> {
+        let mut list = self.shape_value(shape, predicate)?;
+        let mut members: Vec<ObjectNodeRef> = Default::default();
+        while list.as_iri() != Some(rdf::nil()) && members.len() < self.shapes.len() {
+            let node = self.object_to_subject(&list)?;
+            members.push(self.shape_value(&node, rdf::first())?);
+            list = self.shape_value(&node, rdf::rest())?;
+        }
+        Some(members)
+    }
+
+    fn data_values(&self, focus: &ObjectNodeRef, predicate: &IRIRef) -> Vec<ObjectNodeRef> {
+        match self.object_to_subject(focus) {
+            Some(subject) => self
+                .data
+                .statements_matching(Some(&subject), Some(predicate), None)
+                .iter()
+                .map(|statement| statement.object().clone())
+                .collect(),
+            None => Default::default(),
+        }
+    }
+
+    fn is_instance_of(&self, value: &ObjectNodeRef, class: &IRIRef) -> bool {
+        self.data_values(value, rdf::a_type())
+            .iter()
+            .filter_map(|node_class| node_class.as_iri())
+            .any(|node_class| self.is_subclass_of(node_class, class))
+    }
+
+    fn is_subclass_of(&self, class: &IRIRef, super_class: &IRIRef) -> bool {
+        let mut visited: HashSet<IRIRef> = Default::default();
+        let mut queue = vec![class.clone()];
+        while let Some(class) = queue.pop() {
+            if &class == super_class {
+                return true;
+            }
+            if visited.insert(class.clone()) {
+                let class = self.factory.named_object(class);
+                queue.extend(
+                    self.data_values(&class, rdfs::subclass_of())
+                        .iter()
+                        .filter_map(|parent| parent.as_iri().cloned()),
+                );
+            }
+        }
+        false
+    }
+
+    fn subject_to_object(&self, subject: &SubjectNodeRef) -> Option<ObjectNodeRef> {
+        if let Some(iri) = subject.as_iri() {
+            Some(self.factory.named_object(iri.clone()))
+        } else if let Some(name) = subject.as_blank() {
+            self.factory.blank_object_named(name).ok()
+        } else {
+            None
+        }
+    }
+
+    fn object_to_subject(&self, object: &ObjectNodeRef) -> Option<SubjectNodeRef> {
+        if let Some(iri) = object.as_iri() {
+            Some(self.factory.named_subject(iri.clone()))
+        } else if let Some(name) = object.as_blank() {
+            self.factory.blank_subject_named(name).ok()
+        } else {
+            None
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn literal_data_type(value: &ObjectNodeRef) -> Option<IRIRef> {
+    value
+        .as_literal()
+        .map(|literal| match (literal.data_type(), literal.language()) {
+            (Some(data_type), _) => data_type.as_iri().clone(),
+            (None, Some(_)) => rdf::lang_string().clone(),
+            (None, None) => xsd::string().clone(),
+        })
+}
+
+fn is_node_kind(value: &ObjectNodeRef, kind: &IRIRef) -> bool {
+    let (blank, iri, literal) = (value.is_blank(), value.is_iri(), value.is_literal());
+    if kind == shacl::iri() {
+        iri
+    } else if kind == shacl::blank_node() {
+        blank
+    } else if kind == shacl::literal() {
+        literal
+    } else if kind == shacl::blank_node_or_iri() {
+        blank || iri
+    } else if kind == shacl::blank_node_or_literal() {
+        blank || literal
+    } else if kind == shacl::iri_or_literal() {
+        iri || literal
+    } else {
+        false
+    }
+}
+
+fn number(value: &ObjectNodeRef) -> Option<f64> {
+    value
+        .as_literal()
+        .and_then(|literal| literal.lexical_form().trim().parse().ok())
+}
+
+fn string_length(value: &ObjectNodeRef) -> Option<usize> {
+    if let Some(literal) = value.as_literal() {
+        Some(literal.lexical_form().chars().count())
+    } else {
+        value.as_iri().map(|iri| iri.to_string().chars().count())
+    }
+}
+
+fn language_matches(language: &str, ranges: &[String]) -> bool {
+    let language = language.to_lowercase();
+    ranges.iter().any(|range| {
+        range == "*"
+            || language == *range
+            || (language.starts_with(range.as_str()) && language[range.len()..].starts_with('-'))
+    })
+}
@@ -0,0 +1,207 @@
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::owl;
+use rdftk_ontology::compatibility::{
+    compare, Cardinality, ChangeKind, Compatibility, CompatibilityReport,
+};
+use std::str::FromStr;
+
+const PREFIXES: &str = r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix ex: <http://example.org/> .
+"#;
+
+const BASE: &str = r#"
+ex:Agent a owl:Class .
+ex:Person a owl:Class ; rdfs:subClassOf ex:Agent ;
+    rdfs:subClassOf [ a owl:Restriction ; owl:onProperty ex:name ; owl:maxCardinality 2 ] .
+ex:Employee a owl:Class ; rdfs:subClassOf ex:Person .
+ex:name a owl:DatatypeProperty ; rdfs:domain ex:Person .
+ex:employer a owl:ObjectProperty ; rdfs:domain ex:Employee ; rdfs:range ex:Agent .
+"#;
+
+fn turtle(body: &str) -> GraphRef {
+    TurtleReader::default()
+        .read(
+            &mut format!("{}{}", PREFIXES, body).as_bytes(),
+            graph_factory(),
+        )
+        .unwrap()
+}
+
+fn run(old: &str, new: &str) -> CompatibilityReport {
+    compare(&*turtle(old).borrow(), &*turtle(new).borrow())
+}
+
+fn ex(name: &str) -> IRIRef {
+    IRIRef::from(IRI::from_str(&format!("http://example.org/{}", name)).unwrap())
+}
+
+fn kinds(report: &CompatibilityReport) -> Vec<(String, ChangeKind)> {
+    report
+        .changes()
+        .iter()
+        .map(|change| (change.subject().to_string(), change.kind().clone()))
+        .collect()
+}
+
+#[test]
+fn test_no_changes() {
+    let report = run(BASE, BASE);
+    assert!(report.is_compatible());
+    assert!(report.changes().is_empty());
+    assert_eq!(report.to_string(), "No changes\n");
+}
+
+#[test]
+fn test_removed_and_added_terms() {
+    let new = BASE.replace(
+        "ex:name a owl:DatatypeProperty",
+        "ex:fullName a owl:DatatypeProperty",
+    ) + "ex:Robot a owl:Class ; rdfs:subClassOf ex:Agent .\n";
+    let report = run(BASE, &new);
+    assert!(!report.is_compatible());
+    assert_eq!(
+        kinds(&report),
+        vec![
+            (ex("name").to_string(), ChangeKind::PropertyRemoved),
+            (ex("Robot").to_string(), ChangeKind::ClassAdded),
+            (ex("fullName").to_string(), ChangeKind::PropertyAdded),
+        ]
+    );
+    assert_eq!(report.changes()[0].compatibility(), Compatibility::Breaking);
+    assert!(report.changes()[0].migration().is_some());
+    assert!(report.changes()[1].migration().is_none());
+}
+
+#[test]
+fn test_domain_and_range() {
+    // narrowing the range from ex:Agent to ex:Person is breaking, widening the domain from
+    // ex:Employee to its super class ex:Person is not.
+    let new = BASE.replace(
+        "rdfs:domain ex:Employee ; rdfs:range ex:Agent",
+        "rdfs:domain ex:Person ; rdfs:range ex:Person",
+    );
+    let report = run(BASE, &new);
+    assert_eq!(
+        kinds(&report),
+        vec![
+            (
+                ex("employer").to_string(),
+                ChangeKind::RangeNarrowed {
+                    from: vec![ex("Agent")],
+                    to: vec![ex("Person")]
+                }
+            ),
+            (
+                ex("employer").to_string(),
+                ChangeKind::DomainWidened {
+                    from: vec![ex("Employee")],
+                    to: vec![ex("Person")]
+                }
+            ),
+        ]
+    );
+
+    // adding a domain class implied by an existing one is compatible, adding any other is not.
+    let report = run(
+        BASE,
+        &format!("{}ex:employer rdfs:domain ex:Agent .\n", BASE),
+    );
+    assert!(report.is_compatible(), "{}", report);
+    let report = run(
+        BASE,
+        &BASE.replace(
+            "rdfs:domain ex:Employee ; ",
+            "rdfs:domain ex:Employee, ex:Robot ; ",
+        ),
+    );
+    assert!(!report.is_compatible());
+}
+
+#[test]
+fn test_cardinality() {
+    let tightened = BASE.replace("owl:maxCardinality 2", "owl:cardinality 1");
+    let report = run(BASE, &tightened);
+    assert_eq!(
+        kinds(&report),
+        vec![(
+            ex("Person").to_string(),
+            ChangeKind::CardinalityTightened {
+                property: ex("name"),
+                from: Cardinality::new(0, Some(2)),
+                to: Cardinality::new(1, Some(1)),
+            }
+        )]
+    );
+    assert_eq!(
+        report.changes()[0].to_string(),
+        "cardinality of <http://example.org/name> on class <http://example.org/Person> tightened from 0..2 to 1..1"
+    );
+
+    let relaxed = BASE.replace("owl:maxCardinality 2", "owl:maxCardinality 3");
+    assert!(run(BASE, &relaxed).is_compatible());
+    let report = run(&tightened, BASE);
+    assert_eq!(report.changes().len(), 1);
+    assert!(report.is_compatible());
+}
+
+#[test]
+fn test_hierarchy_characteristics_and_deprecation() {
+    let new = BASE
+        .replace(
+            "ex:Employee a owl:Class ; rdfs:subClassOf ex:Person .",
+            "ex:Employee a owl:Class ; rdfs:subClassOf ex:Agent ; owl:deprecated true .",
+        )
+        .replace(
+            "ex:employer a owl:ObjectProperty",
+            "ex:employer a owl:ObjectProperty, owl:FunctionalProperty",
+        );
+    let report = run(BASE, &new);
+    assert_eq!(
+        kinds(&report),
+        vec![
+            (
+                ex("Employee").to_string(),
+                ChangeKind::SuperClassRemoved(ex("Person"))
+            ),
+            (
+                ex("employer").to_string(),
+                ChangeKind::CharacteristicAdded(owl::functional_property().clone())
+            ),
+            (ex("Employee").to_string(), ChangeKind::Deprecated),
+            (
+                ex("Employee").to_string(),
+                ChangeKind::SuperClassAdded(ex("Agent"))
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_migration_report() {
+    let new = BASE.replace(
+        "ex:Agent a owl:Class .",
+        "ex:Agent a owl:Class .\nex:Organization a owl:Class .",
+    );
+    let new = new.replace(
+        "ex:Employee a owl:Class ; rdfs:subClassOf ex:Person .\n",
+        "",
+    );
+    let report = run(BASE, &new);
+    assert_eq!(
+        report.to_string(),
+        "Breaking, 1 breaking and 1 compatible change(s)
+Breaking changes:
+  class <http://example.org/Employee> removed
+    migration: replace, or remove, any use of <http://example.org/Employee> as a type
+Compatible changes:
+  class <http://example.org/Organization> added
+"
+    );
+}
@@ -0,0 +1,140 @@
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_fixtures::terms::iri_in;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_names::{foaf, rdf, xsd};
+use rdftk_ontology::completion::{CompletionIndex, TermKind};
+
+const NS: &str = "http://example.org/ns#";
+
+const ONTOLOGY: &str = r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+@prefix ex: <http://example.org/ns#> .
+
+ex:PostalAddress a owl:Class ;
+    rdfs:label "Postal address" ;
+    rdfs:comment "The mailing address of a resource." .
+ex:postcode a owl:DatatypeProperty ;
+    rdfs:domain ex:PostalAddress ;
+    skos:prefLabel "Zip code" .
+ex:Parcel rdfs:subClassOf ex:Package .
+ex:London rdfs:label "London" .
+ex:unlabeled ex:value "ignored" .
+foaf:Person rdfs:comment "A person." .
+"#;
+
+fn ontology() -> GraphRef {
+    TurtleReader::default()
+        .read(&mut ONTOLOGY.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn index() -> CompletionIndex {
+    let mut index = CompletionIndex::with_vocabularies();
+    let _ = index.add_graph(&*ontology().borrow());
+    index
+}
+
+#[test]
+fn complete_vocabulary_terms() {
+    let index = CompletionIndex::with_vocabularies();
+    assert!(!index.is_empty());
+
+    let completions = index.complete("foaf:na", 10);
+    assert_eq!(completions[0].iri(), foaf::name());
+    assert!(completions
+        .iter()
+        .all(|term| term.qname().unwrap().starts_with("foaf:na")));
+
+    // matching is case-insensitive, and exact matches rank first.
+    let completions = index.complete("FOAF:PERSON", 10);
+    assert_eq!(completions[0].iri(), foaf::person());
+
+    let term = index.get(rdf::a_type()).unwrap();
+    assert_eq!(term.kind(), TermKind::Property);
+    assert_eq!(term.local_name(), "type");
+    assert_eq!(index.get(xsd::string()).unwrap().kind(), TermKind::Datatype);
+    assert_eq!(index.get(foaf::person()).unwrap().kind(), TermKind::Class);
+}
+
+#[test]
+fn complete_by_kind() {
+    let index = CompletionIndex::with_vocabularies();
+    let classes = index.complete_kind("foaf:", TermKind::Class, 1000);
+    assert!(!classes.is_empty());
+    assert!(classes.iter().all(|term| term.kind() == TermKind::Class));
+    assert!(classes.iter().any(|term| term.iri() == foaf::person()));
+    assert!(!classes.iter().any(|term| term.iri() == foaf::name()));
+
+    assert_eq!(index.complete("foaf:", 3).len(), 3);
+    assert!(index.complete("nothing-starts-with-this", 10).is_empty());
+}
+
+#[test]
+fn complete_graph_terms() {
+    let index = index();
+
+    let address = index.get(&iri_in(NS, "PostalAddress")).unwrap();
+    assert_eq!(address.kind(), TermKind::Class);
+    assert_eq!(address.qname(), Some(&"ex:PostalAddress".to_string()));
+    assert_eq!(address.labels(), &vec!["Postal address".to_string()]);
+    assert_eq!(
+        address.comments(),
+        &vec!["The mailing address of a resource.".to_string()]
+    );
+
+    assert_eq!(
+        index.get(&iri_in(NS, "postcode")).unwrap().kind(),
+        TermKind::Property
+    );
+    assert_eq!(
+        index.get(&iri_in(NS, "Parcel")).unwrap().kind(),
+        TermKind::Class
+    );
+    assert_eq!(
+        index.get(&iri_in(NS, "London")).unwrap().kind(),
+        TermKind::Other
+    );
+    assert!(index.get(&iri_in(NS, "unlabeled")).is_none());
+
+    // vocabulary terms described in a graph are enriched, not duplicated.
+    let person = index.get(foaf::person()).unwrap();
+    assert_eq!(person.kind(), TermKind::Class);
+    assert_eq!(person.comments(), &vec!["A person.".to_string()]);
+    assert_eq!(
+        index
+            .complete("foaf:Person", 10)
+            .iter()
+            .filter(|term| term.iri() == foaf::person())
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn complete_by_label_and_word() {
+    let mut index = CompletionIndex::default();
+    let _ = index.add_graph(&*ontology().borrow());
+
+    // by a word in the local name.
+    let completions = index.complete_kind("addr", TermKind::Class, 10);
+    assert!(completions
+        .iter()
+        .any(|term| term.iri() == &iri_in(NS, "PostalAddress")));
+
+    // by a label, and a word in a label.
+    assert_eq!(
+        index.complete("zip code", 10)[0].iri(),
+        &iri_in(NS, "postcode")
+    );
+    assert_eq!(index.complete("code", 10)[0].iri(), &iri_in(NS, "postcode"));
+
+    // equally good matches rank shorter names first.
+    let completions = index.complete("post", 10);
+    assert_eq!(completions[0].iri(), &iri_in(NS, "postcode"));
+    assert_eq!(completions[1].iri(), &iri_in(NS, "PostalAddress"));
+}
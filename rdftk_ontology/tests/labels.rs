@@ -0,0 +1,119 @@
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::iri;
+use rdftk_iri::IRIRef;
+use rdftk_names::{rdf, rdfs};
+use rdftk_ontology::labels::{
+    local_name, plural, to_camel_case, to_label, to_pascal_case, to_slug, words, LabelGenerator,
+};
+use std::str::FromStr;
+
+fn typed(subject: &str, class: &IRIRef) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(iri(subject)),
+            rdf::a_type().clone(),
+            factory.named_object(class.clone()),
+        )
+        .unwrap()
+}
+
+#[test]
+fn split_words() {
+    assert_eq!(words("hasPart"), vec!["has", "Part"]);
+    assert_eq!(words("has_part"), vec!["has", "part"]);
+    assert_eq!(words("has-part"), vec!["has", "part"]);
+    assert_eq!(words("HTTPRequest"), vec!["HTTP", "Request"]);
+    assert_eq!(words("isbn13Code"), vec!["isbn", "13", "Code"]);
+    assert_eq!(words("  postal  address "), vec!["postal", "address"]);
+    assert!(words("--").is_empty());
+}
+
+#[test]
+fn convert_case() {
+    assert_eq!(to_camel_case("has part"), "hasPart");
+    assert_eq!(to_camel_case("Postal_Address"), "postalAddress");
+    assert_eq!(to_pascal_case("postal address"), "PostalAddress");
+    assert_eq!(to_pascal_case("http request"), "HttpRequest");
+    assert_eq!(to_pascal_case("HTTP request"), "HTTPRequest");
+    assert_eq!(to_slug("PostalAddress"), "postal-address");
+    assert_eq!(to_slug("HTTPRequest"), "http-request");
+    assert_eq!(to_label("PostalAddress"), "Postal address");
+    assert_eq!(to_label("hasPart"), "has part");
+    assert_eq!(to_label("sentViaHTTP"), "sent via HTTP");
+}
+
+#[test]
+fn pluralize() {
+    assert_eq!(plural("book"), "books");
+    assert_eq!(plural("postal address"), "postal addresses");
+    assert_eq!(plural("category"), "categories");
+    assert_eq!(plural("survey"), "surveys");
+    assert_eq!(plural("box"), "boxes");
+    assert_eq!(plural("branch"), "branches");
+    assert_eq!(plural("Person"), "People");
+    assert_eq!(plural("contact person"), "contact people");
+    assert_eq!(plural("URI"), "URIs");
+    assert_eq!(plural(""), "");
+}
+
+#[test]
+fn local_names() {
+    assert_eq!(
+        local_name(&iri("http://example.org/ns#PostalAddress")),
+        Some("PostalAddress".to_string())
+    );
+    assert_eq!(
+        local_name(&iri("http://example.org/terms/hasPart")),
+        Some("hasPart".to_string())
+    );
+    assert_eq!(local_name(&iri("http://example.org/terms/")), None);
+}
+
+#[test]
+fn generate_missing_labels() {
+    let factory = statement_factory();
+    let graph = graph_factory().graph_from(
+        &[
+            typed("http://example.org/ns#PostalAddress", rdfs::class()),
+            typed("http://example.org/ns#hasPart", rdf::property()),
+            typed("http://example.org/ns#Book", rdfs::class()),
+            factory
+                .statement(
+                    factory.named_subject(iri("http://example.org/ns#Book")),
+                    rdfs::label().clone(),
+                    factory.literal_object(literal_factory().literal("Book")),
+                )
+                .unwrap(),
+            typed("http://example.com/other#ExternalTerm", rdfs::class()),
+        ],
+        None,
+    );
+
+    let mut generator = LabelGenerator::default();
+    let _ = generator
+        .namespace(iri("http://example.org/ns#"))
+        .language(LanguageTag::from_str("en").unwrap());
+
+    let labels = generator.missing_labels(&*graph.borrow());
+    assert_eq!(labels.len(), 2);
+    assert_eq!(
+        labels[0].subject().as_iri().unwrap(),
+        &iri("http://example.org/ns#PostalAddress")
+    );
+    let label = labels[0].object().as_literal().unwrap();
+    assert_eq!(label.lexical_form(), "Postal address");
+    assert_eq!(label.language().as_ref().unwrap().to_string(), "en");
+    assert_eq!(
+        labels[1].object().as_literal().unwrap().lexical_form(),
+        "has part"
+    );
+
+    assert_eq!(generator.enrich(&graph), 2);
+    assert_eq!(graph.borrow().len(), 7);
+    assert!(generator.missing_labels(&*graph.borrow()).is_empty());
+}
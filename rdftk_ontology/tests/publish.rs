@@ -0,0 +1,115 @@
+#![cfg(feature = "publish")]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_ontology::publish::{Publisher, Serialization};
+use std::str::FromStr;
+
+const VOCABULARY: &str = r#"@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix dcterms: <http://purl.org/dc/terms/> .
+@prefix ex: <http://example.org/vocab/> .
+
+<http://example.org/vocab> dcterms:title "Example Vocabulary"@en ;
+    dcterms:description "A vocabulary for <testing>."@en .
+
+ex:Person a rdfs:Class ;
+    rdfs:label "Person"@en, "Personne"@fr ;
+    rdfs:comment "A human being."@en .
+
+ex:knows a rdf:Property ;
+    rdfs:label "knows"@en ;
+    rdfs:domain ex:Person ;
+    rdfs:range ex:Person .
+"#;
+
+fn vocabulary() -> GraphRef {
+    TurtleReader::default()
+        .read(&mut VOCABULARY.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn namespace(iri: &str) -> IRIRef {
+    IRIRef::from(IRI::from_str(iri).unwrap())
+}
+
+#[test]
+fn publish_slash_namespace() {
+    let bundle = Publisher::new(namespace("http://example.org/vocab/"))
+        .publish(&vocabulary())
+        .unwrap();
+
+    assert_eq!(bundle.base_path(), "/vocab/");
+    let mut paths: Vec<&String> = bundle.files().map(|file| file.path()).collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![
+            ".htaccess",
+            "Person.html",
+            "index.html",
+            "index.jsonld",
+            "index.nt",
+            "index.rdf",
+            "index.ttl",
+            "knows.html"
+        ]
+    );
+
+    let index = bundle.file("index.html").unwrap().content();
+    println!("{}", index);
+    assert!(index.contains("<title>Example Vocabulary</title>"));
+    assert!(index.contains("A vocabulary for &lt;testing&gt;."));
+    assert!(index.contains("<h2 id=\"classes\">Classes</h2>"));
+    assert!(index.contains("<a href=\"knows.html\">knows</a>"));
+    assert!(index.contains("<a href=\"index.ttl\" type=\"text/turtle\">Turtle</a>"));
+
+    let person = bundle.file("Person.html").unwrap().content();
+    println!("{}", person);
+    assert!(person.contains("<h1>Person</h1>"));
+    assert!(person.contains("A human being."));
+    assert!(person.contains("<h2>Referenced By</h2>"));
+    assert!(person.contains("<a href=\"knows.html\">ex:knows</a>"));
+
+    let htaccess = bundle.file(".htaccess").unwrap().content();
+    println!("{}", htaccess);
+    assert!(htaccess.contains("RewriteBase /vocab/"));
+    assert!(htaccess.contains(
+        "RewriteCond %{HTTP_ACCEPT} application/rdf\\+xml\nRewriteRule ^Person$ index.rdf [R=303,L]"
+    ));
+    assert!(htaccess.contains("RewriteRule ^$ index.html [R=303,L]"));
+    assert!(htaccess.contains("RewriteRule ^knows$ knows.html [R=303,L]"));
+
+    // four serializations and the HTML page for the namespace and each of the two terms.
+    assert_eq!(bundle.redirects().count(), 15);
+}
+
+#[test]
+fn publish_with_options() {
+    let mut publisher = Publisher::new(namespace("http://example.org/vocab/"));
+    let _ = publisher
+        .title("Overridden")
+        .language(FromStr::from_str("fr").unwrap())
+        .serializations(&[Serialization::Turtle]);
+    let bundle = publisher.publish(&vocabulary()).unwrap();
+    assert!(bundle.file("index.rdf").is_none());
+    let person = bundle.file("Person.html").unwrap().content();
+    assert!(person.contains("<html lang=\"fr\">"));
+    assert!(person.contains("<h1>Personne</h1>"));
+    assert!(person.contains("<title>Personne - Overridden</title>"));
+}
+
+#[test]
+fn publish_hash_namespace() {
+    let bundle = Publisher::new(namespace("http://example.org/ns#"))
+        .publish(&graph_factory().graph())
+        .unwrap();
+    assert_eq!(bundle.base_path(), "/");
+    let htaccess = bundle.file(".htaccess").unwrap().content();
+    assert!(htaccess.contains("RewriteRule ^ns$ index.html [R=303,L]"));
+    assert!(htaccess
+        .contains("RewriteCond %{HTTP_ACCEPT} text/turtle\nRewriteRule ^ns$ index.ttl [R=303,L]"));
+}
@@ -0,0 +1,117 @@
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::iri;
+use rdftk_iri::IRIRef;
+use rdftk_names::{dc::terms as dcterms, foaf, owl};
+use rdftk_ontology::resolution::{EntityMatcher, PropertyRule};
+use rdftk_ontology::ToStatements;
+
+fn literal(subject: &str, predicate: &IRIRef, value: &str) -> StatementRef {
+    let factory = statement_factory();
+    factory
+        .statement(
+            factory.named_subject(iri(subject)),
+            predicate.clone(),
+            factory.literal_object(literal_factory().literal(value)),
+        )
+        .unwrap()
+}
+
+fn left_graph() -> GraphRef {
+    graph_factory().graph_from(
+        &[
+            literal("http://example.org/benn", foaf::name(), "Tony Benn"),
+            literal("http://example.org/benn", foaf::mbox(), "tony@example.org"),
+            literal("http://example.org/benn", dcterms::identifier(), "TB-1925"),
+            literal("http://example.org/foot", foaf::name(), "Michael Foot"),
+            literal("http://example.org/foot", dcterms::identifier(), "MF-1913"),
+        ],
+        None,
+    )
+}
+
+fn right_graph() -> GraphRef {
+    graph_factory().graph_from(
+        &[
+            literal("http://example.com/p/1", foaf::name(), "BENN, Tony"),
+            literal("http://example.com/p/1", dcterms::identifier(), "TB-1925"),
+            literal("http://example.com/p/2", foaf::name(), "Mike Foot"),
+            literal("http://example.com/p/2", dcterms::identifier(), "MF-0000"),
+            literal("http://example.com/p/3", foaf::name(), "Tony Blair"),
+            literal("http://example.com/p/4", foaf::mbox(), "TONY@example.org"),
+        ],
+        None,
+    )
+}
+
+#[test]
+fn label_and_functional_scores() {
+    let mut matcher = EntityMatcher::new(0.0);
+    let _ = matcher
+        .label(foaf::name().clone(), 2.0)
+        .functional(dcterms::identifier().clone(), 1.0);
+
+    let candidates = matcher.candidates(&left_graph(), &right_graph());
+    let pairs: Vec<(String, String)> = candidates
+        .iter()
+        .map(|c| (c.left().to_string(), c.right().to_string()))
+        .collect();
+    assert_eq!(
+        pairs[0],
+        (
+            "http://example.org/benn".to_string(),
+            "http://example.com/p/1".to_string()
+        )
+    );
+    assert_eq!(candidates[0].score(), 1.0);
+    assert_eq!(candidates[0].evidence().count(), 2);
+
+    // a similar name, but the identifier mismatch pulls the score down.
+    let foot = candidates
+        .iter()
+        .find(|c| c.right().to_string() == "http://example.com/p/2")
+        .unwrap();
+    assert!(foot.score() > 0.4 && foot.score() < 0.5);
+    assert!(foot
+        .evidence()
+        .any(|e| e.predicate() == dcterms::identifier() && e.similarity() == 0.0));
+
+    let mut strict = matcher.clone();
+    let _ = strict.threshold(0.9);
+    assert_eq!(strict.candidates(&left_graph(), &right_graph()).len(), 1);
+}
+
+#[test]
+fn identifier_short_circuits() {
+    let mut matcher = EntityMatcher::new(0.9);
+    let _ = matcher
+        .rule(PropertyRule::identifier(foaf::mbox().clone()))
+        .label(foaf::name().clone(), 1.0);
+
+    let candidates = matcher.candidates(&left_graph(), &right_graph());
+    assert!(candidates
+        .iter()
+        .any(|c| c.right().to_string() == "http://example.com/p/4" && c.score() == 1.0));
+
+    let factory = statement_factory();
+    let statements = candidates[0].to_statements(&factory);
+    assert_eq!(statements.len(), 1);
+    assert_eq!(statements[0].predicate(), owl::same_as());
+}
+
+#[test]
+fn same_resource_is_not_a_candidate() {
+    let matcher = EntityMatcher::default();
+    let graph = graph_factory().graph_from(
+        &[literal(
+            "http://example.org/benn",
+            rdftk_names::rdfs::label(),
+            "Tony Benn",
+        )],
+        None,
+    );
+    assert!(matcher.candidates(&graph, &graph).is_empty());
+}
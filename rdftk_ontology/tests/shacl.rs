@@ -0,0 +1,292 @@
+use rdftk_core::model::graph::change_set::ChangeSet;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_ontology::shacl::{
+    validate, ConstraintComponent, IncrementalValidator, Severity, ShapesValidator,
+    ValidationReport,
+};
+
+const PREFIXES: &str = r#"@prefix sh: <http://www.w3.org/ns/shacl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix ex: <http://example.org/> .
+"#;
+
+fn turtle(body: &str) -> GraphRef {
+    TurtleReader::default()
+        .read(
+            &mut format!("{}{}", PREFIXES, body).as_bytes(),
+            graph_factory(),
+        )
+        .unwrap()
+}
+
+fn run(shapes: &str, data: &str) -> ValidationReport {
+    validate(&*turtle(shapes).borrow(), &*turtle(data).borrow())
+}
+
+fn components(report: &ValidationReport) -> Vec<ConstraintComponent> {
+    let mut components: Vec<ConstraintComponent> =
+        report.results().iter().map(|r| r.component()).collect();
+    components.sort_by_key(|c| c.local_name());
+    components
+}
+
+#[test]
+fn test_targets() {
+    let shapes = r#"
+ex:ClassShape sh:targetClass ex:Animal ; sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+ex:NodeShape sh:targetNode ex:rex ; sh:property [ sh:path ex:owner ; sh:minCount 1 ] .
+ex:SubjectsShape sh:targetSubjectsOf ex:owner ; sh:nodeKind sh:IRI .
+ex:ObjectsShape sh:targetObjectsOf ex:owner ; sh:class ex:Person .
+"#;
+    let data = r#"
+ex:Dog rdfs:subClassOf ex:Animal .
+ex:rex a ex:Dog .
+_:tom a ex:Animal ; ex:name "Tom" ; ex:owner ex:jane .
+"#;
+    let report = run(shapes, data);
+    assert!(!report.conforms());
+    assert_eq!(
+        components(&report),
+        vec![
+            ConstraintComponent::Class,
+            ConstraintComponent::MinCount,
+            ConstraintComponent::MinCount,
+            ConstraintComponent::NodeKind,
+        ]
+    );
+}
+
+#[test]
+fn test_implicit_class_target() {
+    let shapes = r#"ex:Person a rdfs:Class, sh:NodeShape ;
+    sh:property [ sh:path ex:name ; sh:datatype xsd:string ] ."#;
+    let report = run(shapes, r#"ex:jane a ex:Person ; ex:name 42 ."#);
+    assert_eq!(components(&report), vec![ConstraintComponent::Datatype]);
+    assert!(run(shapes, r#"ex:jane a ex:Person ; ex:name "Jane" ."#).conforms());
+}
+
+#[test]
+fn test_value_constraints() {
+    let shapes = r#"ex:S sh:targetNode ex:x ;
+    sh:property [ sh:path ex:size ; sh:in ( "S" "M" "L" ) ] ;
+    sh:property [ sh:path ex:label ; sh:languageIn ( "en" "fr" ) ] ;
+    sh:property [ sh:path ex:kind ; sh:hasValue ex:Shirt ] ;
+    sh:property [ sh:path ex:code ; sh:minLength 2 ; sh:maxLength 4 ] .
+"#;
+    assert!(run(
+        shapes,
+        r#"ex:x ex:size "M" ; ex:label "shirt"@en ; ex:kind ex:Shirt ; ex:code "AB" ."#
+    )
+    .conforms());
+    let report = run(
+        shapes,
+        r#"ex:x ex:size "XL" ; ex:label "Hemd"@de ; ex:kind ex:Hat ; ex:code "ABCDE" ."#,
+    );
+    assert_eq!(
+        components(&report),
+        vec![
+            ConstraintComponent::HasValue,
+            ConstraintComponent::In,
+            ConstraintComponent::LanguageIn,
+            ConstraintComponent::MaxLength,
+        ]
+    );
+}
+
+#[test]
+fn test_closed_and_node() {
+    let shapes = r#"
+ex:AddressShape sh:closed true ; sh:ignoredProperties ( ex:note ) ;
+    sh:property [ sh:path ex:city ; sh:minCount 1 ] .
+ex:PersonShape sh:targetNode ex:jane ;
+    sh:property [ sh:path ex:address ; sh:node ex:AddressShape ] .
+"#;
+    assert!(run(
+        shapes,
+        r#"ex:jane ex:address [ ex:city "Paris" ; ex:note "home" ] ."#
+    )
+    .conforms());
+    let report = run(shapes, r#"ex:jane ex:address [ ex:street "Rue" ] ."#);
+    assert_eq!(components(&report), vec![ConstraintComponent::Node]);
+}
+
+#[test]
+fn test_severity_message_and_deactivated() {
+    let shapes = r#"
+ex:Warn sh:targetNode ex:x ;
+    sh:property [ sh:path ex:name ; sh:minCount 1 ; sh:severity sh:Warning ; sh:message "needs a name" ] .
+ex:Off sh:targetNode ex:x ; sh:deactivated true ;
+    sh:property [ sh:path ex:age ; sh:minCount 1 ] .
+"#;
+    let report = run(shapes, r#"ex:x ex:other 1 ."#);
+    assert!(!report.conforms());
+    assert_eq!(report.results().len(), 1);
+    assert_eq!(report.violations().count(), 0);
+    let result = &report.results()[0];
+    assert_eq!(result.severity(), Severity::Warning);
+    assert_eq!(result.component(), ConstraintComponent::MinCount);
+    assert_eq!(result.message(), "needs a name");
+}
+
+#[test]
+fn test_numeric_ranges() {
+    let shapes = r#"ex:S sh:targetNode ex:x ;
+    sh:property [ sh:path ex:n ; sh:minExclusive 0 ; sh:maxInclusive 10 ] ."#;
+    assert!(run(shapes, r#"ex:x ex:n 10 ."#).conforms());
+    let report = run(shapes, r#"ex:x ex:n 0, 11, "ten" ."#);
+    assert_eq!(
+        components(&report),
+        vec![
+            ConstraintComponent::MaxInclusive,
+            ConstraintComponent::MaxInclusive,
+            ConstraintComponent::MinExclusive,
+            ConstraintComponent::MinExclusive,
+        ]
+    );
+}
+
+#[test]
+fn test_shapes_change_validator() {
+    let shapes = r#"ex:S sh:targetNode ex:x, ex:y ;
+    sh:property [ sh:path ex:n ; sh:maxCount 1 ] ."#;
+    let validator = ShapesValidator::new(turtle(shapes));
+    // ex:y already violates the shape, which does not block unrelated changes.
+    let data = turtle(r#"ex:x ex:n 1 . ex:y ex:n 1, 2 ."#);
+    let update = turtle(r#"ex:x ex:n 2 ."#);
+    let statement = update.borrow().statements().next().unwrap().clone();
+
+    let mut changes = ChangeSet::default();
+    let _ = changes.add(statement.clone());
+    let result = changes.apply_validated(&mut *data.borrow_mut(), &[&validator]);
+    assert!(result.is_err());
+    assert!(!data.borrow().contains(&statement));
+
+    let existing = data
+        .borrow()
+        .statements()
+        .find(|st| st.subject().to_string() == "<http://example.org/x>")
+        .unwrap()
+        .clone();
+    let _ = changes.remove(existing);
+    changes
+        .apply_validated(&mut *data.borrow_mut(), &[&validator])
+        .unwrap();
+    assert!(data.borrow().contains(&statement));
+}
+
+fn result_strings(report: &ValidationReport) -> Vec<String> {
+    let mut results: Vec<String> = report.results().iter().map(|r| r.to_string()).collect();
+    results.sort();
+    results
+}
+
+fn change(data: &GraphRef, add: &str, remove: &str) -> ChangeSet {
+    let mut changes = ChangeSet::default();
+    for statement in turtle(add).borrow().statements() {
+        let _ = changes.add(statement.clone());
+    }
+    for statement in turtle(remove).borrow().statements() {
+        let _ = changes.remove(statement.clone());
+    }
+    changes.apply(&mut *data.borrow_mut());
+    changes
+}
+
+#[test]
+fn test_incremental_validation() {
+    let shapes = turtle(
+        r#"
+ex:PersonShape sh:targetClass ex:Person ;
+    sh:property [ sh:path ex:name ; sh:minCount 1 ; sh:maxCount 1 ] .
+ex:OwnerShape sh:targetObjectsOf ex:owner ; sh:class ex:Person .
+"#,
+    );
+    let data = turtle(
+        r#"
+ex:jane a ex:Person ; ex:name "Jane" .
+ex:john a ex:Person .
+ex:rex ex:owner ex:jane .
+ex:tom ex:owner ex:bob .
+ex:a a ex:Person ; ex:name "A" .
+ex:b a ex:Person ; ex:name "B" .
+ex:c a ex:Person ; ex:name "C" .
+"#,
+    );
+    let mut validator = IncrementalValidator::new(shapes.clone(), &*data.borrow());
+    assert_eq!(validator.report().results().len(), 2);
+
+    // naming john resolves a result without re-validating the other people.
+    let changes = change(&data, r#"ex:john ex:name "John" ."#, "");
+    let delta = validator.update(&*data.borrow(), &changes);
+    assert!(!delta.is_full());
+    assert_eq!(delta.validated(), 1);
+    assert_eq!(delta.added().len(), 0);
+    assert_eq!(delta.resolved().len(), 1);
+    assert_eq!(
+        delta.resolved()[0].component(),
+        ConstraintComponent::MinCount
+    );
+
+    // typing bob as a person resolves the owner's class constraint, and makes bob a focus node.
+    let changes = change(&data, r#"ex:bob a ex:Person ."#, "");
+    let delta = validator.update(&*data.borrow(), &changes);
+    assert_eq!(delta.resolved().len(), 1);
+    assert_eq!(delta.resolved()[0].component(), ConstraintComponent::Class);
+    assert_eq!(delta.added().len(), 1);
+    assert_eq!(delta.added()[0].component(), ConstraintComponent::MinCount);
+
+    // removing jane's type leaves her as the object of ex:owner, but not a person.
+    let changes = change(
+        &data,
+        r#"ex:jane ex:name "J" ."#,
+        r#"ex:jane a ex:Person ."#,
+    );
+    let delta = validator.update(&*data.borrow(), &changes);
+    assert_eq!(delta.added().len(), 1);
+    assert_eq!(delta.added()[0].component(), ConstraintComponent::Class);
+    assert!(delta.resolved().is_empty());
+
+    assert_eq!(
+        result_strings(&validator.report()),
+        result_strings(&validate(&*shapes.borrow(), &*data.borrow()))
+    );
+}
+
+#[test]
+fn test_incremental_validation_depends_on_values() {
+    let shapes = turtle(
+        r#"
+ex:AddressShape sh:property [ sh:path ex:city ; sh:minCount 1 ] .
+ex:PersonShape sh:targetNode ex:jane ;
+    sh:property [ sh:path ex:address ; sh:node ex:AddressShape ] .
+"#,
+    );
+    let data = turtle(r#"ex:jane ex:address ex:home . ex:home ex:city "Paris" ."#);
+    let mut validator = IncrementalValidator::new(shapes.clone(), &*data.borrow());
+    assert!(validator.conforms());
+
+    // the change is to the address, which is not itself a focus node.
+    let changes = change(&data, "", r#"ex:home ex:city "Paris" ."#);
+    let delta = validator.update(&*data.borrow(), &changes);
+    assert_eq!(delta.added().len(), 1);
+    assert_eq!(delta.added()[0].component(), ConstraintComponent::Node);
+    assert!(!validator.conforms());
+
+    // a change to the class hierarchy re-validates the whole graph.
+    let changes = change(&data, r#"ex:Home rdfs:subClassOf ex:Place ."#, "");
+    let delta = validator.update(&*data.borrow(), &changes);
+    assert!(delta.is_full());
+    assert!(delta.is_empty());
+
+    let changes = change(&data, r#"ex:home ex:city "Lyon" ."#, "");
+    let _ = validator.update(&*data.borrow(), &changes);
+    assert!(validator.conforms());
+    assert_eq!(
+        result_strings(&validator.report()),
+        result_strings(&validate(&*shapes.borrow(), &*data.borrow()))
+    );
+}
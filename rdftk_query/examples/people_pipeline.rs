@@ -0,0 +1,104 @@
+/*!
+An end-to-end pipeline across the RDFtk crates:
+
+1. parse a Turtle document containing both data and a small ontology,
+1. validate the data against the functional properties declared in the ontology,
+1. query the validated graph with SPARQL, and
+1. serialize the graph as compacted JSON-LD.
+
+Run with `cargo run --example people_pipeline -p rdftk_query`.
+
+*/
+
+use rdftk_core::model::graph::cardinality::{CardinalityConstraints, Enforcement};
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::json_ld::writer::{JsonLdOptions, JsonLdWriter};
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::{write_graph_to_string, GraphReader};
+use rdftk_query::sparql::Query;
+use std::error::Error;
+use std::str::FromStr;
+
+const PEOPLE: &str = r#"@prefix ex: <http://example.org/> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+
+foaf:age a owl:FunctionalProperty .
+foaf:mbox a owl:InverseFunctionalProperty .
+
+ex:alice a foaf:Person ;
+    foaf:name "Alice" ;
+    foaf:age 34 ;
+    foaf:mbox <mailto:alice@example.org> ;
+    foaf:knows ex:bob .
+
+ex:bob a foaf:Person ;
+    foaf:name "Bob" ;
+    foaf:age 27 .
+"#;
+
+const OVER_30: &str = r#"PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+SELECT ?name ?age WHERE { ?person a foaf:Person ; foaf:name ?name ; foaf:age ?age FILTER(?age > 30) }
+ORDER BY ?name"#;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    //
+    // 1. Parse
+    //
+    let source = TurtleReader::default().read(&mut PEOPLE.as_bytes(), graph_factory())?;
+    println!("# parsed {} statements", source.borrow().len());
+
+    //
+    // 2. Validate
+    //
+    let validated = validate(&source)?;
+    println!("# validated {} statements", validated.borrow().len());
+
+    //
+    // 3. Query
+    //
+    let results = Query::from_str(OVER_30)?.execute(&validated)?;
+    for solution in results.as_solutions().unwrap() {
+        println!(
+            "# {} is {}",
+            solution
+                .get("name")
+                .unwrap()
+                .as_literal()
+                .unwrap()
+                .lexical_form(),
+            solution
+                .get("age")
+                .unwrap()
+                .as_literal()
+                .unwrap()
+                .lexical_form()
+        );
+    }
+
+    //
+    // 4. Serialize
+    //
+    let writer = JsonLdWriter::new(JsonLdOptions::compacted());
+    println!("{}", write_graph_to_string(&writer, &validated)?);
+
+    Ok(())
+}
+
+///
+/// Copy the statements in `source` into a new graph, rejecting any that would add a second value
+/// for a functional, or inverse-functional, property declared in `source` itself.
+///
+fn validate(source: &GraphRef) -> Result<GraphRef, Box<dyn Error>> {
+    let source = source.borrow();
+    let constraints = CardinalityConstraints::from_ontology(&*source, Enforcement::Reject);
+    let validated = graph_factory().with_mappings(source.prefix_mappings());
+    {
+        let mut validated = validated.borrow_mut();
+        for statement in source.statements() {
+            let _ = constraints.insert(&mut *validated, statement.clone())?;
+        }
+    }
+    Ok(validated)
+}
@@ -0,0 +1,144 @@
+/*!
+Forward-chaining inference with rules whose actions are all `ADD`; the rules are applied by the
+inference engine of `rdftk_core::model::graph::inference`, until no new statements are produced,
+or until an `InferenceBudget` is exhausted.
+
+Each rule is applied incrementally, matching its conditions only when a statement matching one of
+them becomes known, and each inferred statement is recorded with an `Explanation`, the name of the
+rule that produced it and the statements matched by the rule's conditions. The rules of a set may
+also be applied along with the built-in RDFS and OWL 2 RL rules, by adding those returned by
+`RuleSet::inference_rules` to a reasoner in `rdftk_core::model::graph::reasoner`.
+
+When the budget is exhausted inference stops, the statements inferred so far remain in the graph
+and the returned `Inference` is marked as incomplete.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_query::rules::inference::{BudgetLimit, InferenceBudget};
+use rdftk_query::rules::RuleSet;
+use std::str::FromStr;
+
+let graph = TurtleReader::default()
+    .read(
+        &mut r#"@prefix ex: <http://example.org/> .
+ex:wheel ex:partOf ex:axle . ex:axle ex:partOf ex:chassis . ex:chassis ex:partOf ex:car ."#
+            .as_bytes(),
+        graph_factory(),
+    )
+    .unwrap();
+
+let rules = RuleSet::from_str(
+    r#"PREFIX ex: <http://example.org/>
+       RULE "transitive part"
+         IF ?a ex:partOf ?b AND ?b ex:partOf ?c
+         THEN ADD ?a ex:partOf ?c"#,
+)
+.unwrap();
+
+let inference = rules
+    .infer(&mut *graph.borrow_mut(), &InferenceBudget::default().with_max_statements(2))
+    .unwrap();
+assert!(!inference.is_complete());
+assert_eq!(inference.exhausted(), Some(BudgetLimit::Statements));
+assert_eq!(inference.len(), 2);
+
+let explanation = &inference.explanations()[0];
+assert_eq!(explanation.rule(), "transitive part");
+assert_eq!(explanation.premises().len(), 2);
+```
+
+*/
+
+use crate::rules::{Action, Rule, RuleSet};
+use crate::sparql::eval::{rule_solutions, unify};
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::graph::inference::{materialize, RuleContext, RuleRef};
+pub use rdftk_core::model::graph::inference::{
+    BudgetLimit, Explanation, Inference, InferenceBudget,
+};
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::statement::{StatementList, StatementRef};
+use std::rc::Rc;
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl RuleSet {
+    ///
+    /// Apply the rules in this set to `graph`, repeatedly, until no new statements are inferred
+    /// or `budget` is exhausted. All statements inferred are added to `graph`. This will fail if
+    /// any rule has an action other than `ADD`, as inference must only add statements.
+    ///
+    pub fn infer(&self, graph: &mut dyn Graph, budget: &InferenceBudget) -> Result<Inference> {
+        materialize(graph, &self.inference_rules()?, budget)
+    }
+
+    ///
+    /// Return the rules in this set as rules of the inference engine, so that they may be applied
+    /// along with other rules. This will fail if any rule has an action other than `ADD`.
+    ///
+    pub fn inference_rules(&self) -> Result<Vec<RuleRef>> {
+        if let Some(rule) = self.rules.iter().find(|rule| {
+            rule.actions
+                .iter()
+                .any(|action| !matches!(action, Action::Add(_)))
+        }) {
+            return Err(ErrorKind::Msg(format!(
+                "rule {:?}: only ADD actions may be used for inference",
+                rule.name
+            ))
+            .into());
+        }
+        let mut rules: Vec<RuleRef> = Default::default();
+        for rule in &self.rules {
+            rules.push(Rc::new(rule.clone()));
+        }
+        Ok(rules)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl rdftk_core::model::graph::inference::Rule for Rule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn apply(&self, statement: &StatementRef, context: &mut RuleContext<'_>) -> Result<()> {
+        let statements = context.statement_factory().clone();
+        let mut problems: Vec<String> = Default::default();
+        for condition in &self.conditions {
+            let seed = match unify(condition, statement, &statements) {
+                Some(seed) => seed,
+                None => continue,
+            };
+            for solution in rule_solutions(&self.conditions, &self.filters, context, seed) {
+                let premises: StatementList = self
+                    .conditions
+                    .iter()
+                    .filter_map(|condition| {
+                        self.instantiate(condition, &solution, &statements, &mut Default::default())
+                    })
+                    .collect();
+                for action in &self.actions {
+                    if let Action::Add(pattern) = action {
+                        if let Some(statement) =
+                            self.instantiate(pattern, &solution, &statements, &mut problems)
+                        {
+                            context.derive_statement(statement, premises.clone());
+                        }
+                    }
+                }
+            }
+        }
+        for problem in problems {
+            context.problem(problem);
+        }
+        Ok(())
+    }
+}
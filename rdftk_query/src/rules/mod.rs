@@ -0,0 +1,395 @@
+/*!
+Declarative cleanup rules, executed over any implementation of the `Graph` trait, so that
+common data transformations may be kept in configuration files rather than code.
+
+A rule set starts with the same `BASE` and `PREFIX` declarations as a SPARQL query, followed by
+any number of rules of the form `RULE "name" IF conditions THEN actions`, where `RULE "name"` may
+be omitted. Conditions, separated by `AND`, are either a single triple pattern or a SPARQL
+expression; together they are evaluated as a basic graph pattern with a filter. Actions, also separated by `AND`, are applied once for each
+solution:
+
+* `RETYPE ?var AS iri` replaces the literal bound to `?var`, in each statement matched by a
+  condition, with one that has the same lexical form and the new data type. A lexical form that
+  is not valid for the new data type is reported as a problem and left unchanged.
+* `ADD subject predicate object` adds a statement.
+* `DELETE subject predicate object` removes a statement.
+
+Each rule is evaluated against the graph as left by the rules before it, and its changes are
+collected into a `ChangeSet` so that they may be previewed, validated, or written as an RDF Patch.
+
+# Example
+
+```rust
+use rdftk_core::model::literal::DataType;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::dc::elements as dc;
+use rdftk_query::rules::RuleSet;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let graph = graph_factory().graph_from(
+    &[factory
+        .statement(
+            factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/report").unwrap())),
+            dc::date().clone(),
+            factory.literal_object(literal_factory().literal("2021-07-04")),
+        )
+        .unwrap()],
+    None,
+);
+
+let rules = RuleSet::from_str(
+    r#"PREFIX dc: <http://purl.org/dc/elements/1.1/>
+       PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+       RULE "typed dates"
+         IF ?s dc:date ?d AND datatype(?d) = xsd:string
+         THEN RETYPE ?d AS xsd:date"#,
+)
+.unwrap();
+
+let outcomes = rules.apply(&mut *graph.borrow_mut()).unwrap();
+assert_eq!(outcomes[0].changes().len(), 2);
+assert!(outcomes[0].problems().is_empty());
+
+let graph = graph.borrow();
+let date = graph.statements().next().unwrap().object().as_literal().unwrap().clone();
+assert_eq!(date.data_type(), Some(&DataType::Date));
+```
+
+*/
+
+use crate::sparql::algebra::{BinaryOperator, Variable};
+use crate::sparql::algebra::{Expression, Pattern, Projection, QueryForm, Term, TriplePattern};
+use crate::sparql::results::Solution;
+use crate::sparql::Query;
+use rdftk_core::error::{Error, Result};
+use rdftk_core::model::graph::change_set::ChangeSet;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LiteralFactoryRef};
+use rdftk_core::model::statement::{ObjectNodeRef, StatementFactoryRef, StatementRef};
+use rdftk_iri::IRIRef;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The name of the rule language, used in errors.
+///
+pub const NAME: &str = "Rules";
+
+///
+/// An ordered list of rules, parsed from text.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+}
+
+///
+/// A single named rule; a set of conditions to match and the actions to apply to each match.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rule {
+    name: String,
+    conditions: Vec<TriplePattern>,
+    filters: Vec<Expression>,
+    actions: Vec<Action>,
+}
+
+///
+/// An action applied to each solution of a rule's conditions.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum Action {
+    /// Replace the literal bound to the variable with one of the given data type.
+    Retype(Variable, IRIRef),
+    /// Add a statement, variables in the pattern are replaced by their bound values.
+    Add(TriplePattern),
+    /// Remove a statement, variables in the pattern are replaced by their bound values.
+    Delete(TriplePattern),
+}
+
+///
+/// The result of evaluating a single rule; the changes it makes and any problems found.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuleOutcome {
+    name: String,
+    changes: ChangeSet,
+    problems: Vec<String>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FromStr for RuleSet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self {
+            rules: parser::parse_rules(s)?,
+        })
+    }
+}
+
+impl From<Vec<Rule>> for RuleSet {
+    fn from(rules: Vec<Rule>) -> Self {
+        Self { rules }
+    }
+}
+
+impl RuleSet {
+    ///
+    /// Return the rules in this set, in the order they are applied.
+    ///
+    pub fn rules(&self) -> impl Iterator<Item = &Rule> {
+        self.rules.iter()
+    }
+
+    ///
+    /// Evaluate, and apply, each rule in turn to `graph`; returning the outcome of each.
+    ///
+    pub fn apply(&self, graph: &mut dyn Graph) -> Result<Vec<RuleOutcome>> {
+        let mut outcomes: Vec<RuleOutcome> = Default::default();
+        for rule in &self.rules {
+            let outcome = rule.evaluate(graph)?;
+            outcome.changes.apply(graph);
+            outcomes.push(outcome);
+        }
+        Ok(outcomes)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Rule {
+    ///
+    /// Construct a new rule from its conditions and actions.
+    ///
+    pub fn new(
+        name: &str,
+        conditions: Vec<TriplePattern>,
+        filters: Vec<Expression>,
+        actions: Vec<Action>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            conditions,
+            filters,
+            actions,
+        }
+    }
+
+    ///
+    /// Return the name of this rule.
+    ///
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    ///
+    /// Return the triple patterns this rule matches.
+    ///
+    pub fn conditions(&self) -> &[TriplePattern] {
+        &self.conditions
+    }
+
+    ///
+    /// Return the expressions each match must satisfy.
+    ///
+    pub fn filters(&self) -> &[Expression] {
+        &self.filters
+    }
+
+    ///
+    /// Return the actions applied to each match.
+    ///
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    ///
+    /// Return the query used to match this rule's conditions.
+    ///
+    pub fn query(&self) -> Query {
+        let pattern = Pattern::Bgp(self.conditions.clone());
+        let pattern = match self.filters.iter().cloned().reduce(|lhs, rhs| {
+            Expression::Binary(BinaryOperator::And, Box::new(lhs), Box::new(rhs))
+        }) {
+            None => pattern,
+            Some(expression) => Pattern::Filter(expression, Box::new(pattern)),
+        };
+        Query::new(
+            QueryForm::Select {
+                modifier: None,
+                projection: Projection::All,
+            },
+            pattern,
+        )
+    }
+
+    ///
+    /// Evaluate this rule against `graph`, returning the changes it would make; `graph` itself
+    /// is unchanged.
+    ///
+    pub fn evaluate(&self, graph: &dyn Graph) -> Result<RuleOutcome> {
+        let statements = graph.statement_factory();
+        let literals = graph.literal_factory();
+        let mut outcome = RuleOutcome {
+            name: self.name.clone(),
+            changes: Default::default(),
+            problems: Default::default(),
+        };
+        let results = self.query().evaluate(graph)?;
+        for solution in results.as_solutions().unwrap().iter() {
+            for action in &self.actions {
+                match action {
+                    Action::Retype(variable, data_type) => self.retype(
+                        variable,
+                        data_type,
+                        solution,
+                        (&statements, &literals),
+                        &mut outcome,
+                    ),
+                    Action::Add(pattern) => {
+                        if let Some(statement) =
+                            self.instantiate(pattern, solution, &statements, &mut outcome.problems)
+                        {
+                            let _ = outcome.changes.add(statement);
+                        }
+                    }
+                    Action::Delete(pattern) => {
+                        if let Some(statement) =
+                            self.instantiate(pattern, solution, &statements, &mut outcome.problems)
+                        {
+                            let _ = outcome.changes.remove(statement);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(outcome)
+    }
+
+    fn retype(
+        &self,
+        variable: &str,
+        data_type: &IRIRef,
+        solution: &Solution,
+        (statements, literals): (&StatementFactoryRef, &LiteralFactoryRef),
+        outcome: &mut RuleOutcome,
+    ) {
+        let value = match solution.get(variable).and_then(|value| value.as_literal()) {
+            None => {
+                outcome.problems.push(format!(
+                    "rule {:?}: ?{} is not bound to a literal",
+                    self.name, variable
+                ));
+                return;
+            }
+            Some(value) => value,
+        };
+        let retyped = match literals
+            .with_data_type_checked(value.lexical_form(), DataType::from(data_type.clone()))
+        {
+            Err(_) => {
+                outcome.problems.push(format!(
+                    "rule {:?}: {} is not a valid <{}>",
+                    self.name, value, data_type
+                ));
+                return;
+            }
+            Ok(retyped) => statements.literal_object(retyped),
+        };
+        for condition in self
+            .conditions
+            .iter()
+            .filter(|condition| condition.object().binding_name().as_deref() == Some(variable))
+        {
+            if let Some(statement) =
+                self.instantiate(condition, solution, statements, &mut outcome.problems)
+            {
+                let replacement = statements
+                    .statement(
+                        statement.subject().clone(),
+                        statement.predicate().clone(),
+                        retyped.clone(),
+                    )
+                    .unwrap();
+                let _ = outcome.changes.remove(statement).add(replacement);
+            }
+        }
+    }
+
+    fn instantiate(
+        &self,
+        pattern: &TriplePattern,
+        solution: &Solution,
+        statements: &StatementFactoryRef,
+        problems: &mut Vec<String>,
+    ) -> Option<StatementRef> {
+        let value = |term: &Term| -> ObjectNodeRef {
+            match term {
+                Term::Iri(iri) => statements.named_object(iri.clone()),
+                Term::Literal(literal) => statements.literal_object(literal.clone()),
+                // the parser ensures that all variables in an action are bound by the conditions.
+                term => solution.get(&term.binding_name().unwrap()).unwrap().clone(),
+            }
+        };
+        let statement = match (
+            statements.object_as_subject(value(pattern.subject())),
+            value(pattern.predicate()).as_iri(),
+        ) {
+            (Some(subject), Some(predicate)) => statements
+                .statement(subject, predicate.clone(), value(pattern.object()))
+                .ok(),
+            _ => None,
+        };
+        if statement.is_none() {
+            problems.push(format!(
+                "rule {:?}: {} is not a valid statement",
+                self.name, pattern
+            ));
+        }
+        statement
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl RuleOutcome {
+    ///
+    /// Return the name of the rule evaluated.
+    ///
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    ///
+    /// Return the changes made, or to be made, by the rule.
+    ///
+    pub fn changes(&self) -> &ChangeSet {
+        &self.changes
+    }
+
+    ///
+    /// Return any problems found, these are values the rule matched but could not change.
+    ///
+    pub fn problems(&self) -> &[String] {
+        &self.problems
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod inference;
+
+mod parser;
@@ -0,0 +1,173 @@
+/*!
+A parser for cleanup rules, the grammar in `rules.pest` extends the SPARQL grammar and so this
+parser uses the SPARQL parser for prologues, terms, and expressions.
+
+*/
+
+#![allow(clippy::upper_case_acronyms)] // << generated by pest.
+
+use crate::rules::{Action, Rule, NAME};
+use crate::sparql::algebra::{Expression, TriplePattern};
+use crate::sparql::parser::{
+    expression, iri, prologue, unescape_string, var_or_term, variable, verb, ParserState,
+    Rule as Production, SparqlParser,
+};
+use pest::iterators::Pair;
+use pest::Parser;
+use rdftk_core::error::{Error, ErrorKind, Result};
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn parse_rules(input: &str) -> Result<Vec<Rule>> {
+    let mut parsed = SparqlParser::parse(Production::ruleSetUnit, input)
+        .map_err(|e| Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string())))?;
+    let top_node = parsed.next().unwrap();
+    rule_set_unit(top_node)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn unexpected(fn_name: &str, pair: &Pair<'_, Production>) -> Error {
+    error!(
+        "{} parser: unexpected {:?} in {}",
+        NAME,
+        pair.as_rule(),
+        fn_name
+    );
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
+
+fn invalid(rule: &str, message: &str) -> Error {
+    error!("{} parser: rule {:?} {}", NAME, rule, message);
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
+
+fn rule_set_unit(input_pair: Pair<'_, Production>) -> Result<Vec<Rule>> {
+    trace!("rule_set_unit({:?})", &input_pair.as_rule());
+
+    let mut state = ParserState::new();
+    let mut rules: Vec<Rule> = Default::default();
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Production::prologue => prologue(inner_pair, &mut state)?,
+            Production::ruleDecl => {
+                let default_name = format!("rule {}", rules.len() + 1);
+                rules.push(rule_decl(inner_pair, &default_name, &mut state)?)
+            }
+            Production::EOI => {
+                trace!("Done.")
+            }
+            _ => return Err(unexpected("rule_set_unit", &inner_pair)),
+        }
+    }
+    Ok(rules)
+}
+
+fn rule_decl(
+    input_pair: Pair<'_, Production>,
+    default_name: &str,
+    state: &mut ParserState,
+) -> Result<Rule> {
+    trace!("rule_decl({:?})", &input_pair.as_rule());
+
+    let mut name = default_name.to_string();
+    let mut conditions: Vec<TriplePattern> = Default::default();
+    let mut filters: Vec<Expression> = Default::default();
+    let mut actions: Vec<Action> = Default::default();
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Production::String => {
+                let string_pair = inner_pair.into_inner().next().unwrap();
+                name = unescape_string(string_pair.into_inner().next().unwrap().as_str());
+            }
+            Production::ruleCondition => {
+                let inner_pair = inner_pair.into_inner().next().unwrap();
+                match inner_pair.as_rule() {
+                    Production::triplePattern => {
+                        conditions.push(triple_pattern(inner_pair, state)?)
+                    }
+                    _ => filters.push(expression(inner_pair, state)?),
+                }
+            }
+            Production::ruleAction => actions.push(rule_action(inner_pair, state)?),
+            _ => return Err(unexpected("rule_decl", &inner_pair)),
+        }
+    }
+
+    let bound: Vec<String> = conditions
+        .iter()
+        .flat_map(|condition| condition.terms())
+        .filter_map(|term| term.binding_name())
+        .collect();
+    for action in &actions {
+        match action {
+            Action::Retype(variable, _) => {
+                if !conditions
+                    .iter()
+                    .any(|condition| condition.object().binding_name().as_ref() == Some(variable))
+                {
+                    return Err(invalid(
+                        &name,
+                        &format!(
+                            "retypes ?{} which is not the object of a condition",
+                            variable
+                        ),
+                    ));
+                }
+            }
+            Action::Add(pattern) | Action::Delete(pattern) => {
+                if let Some(unbound) = pattern
+                    .terms()
+                    .filter_map(|term| term.binding_name())
+                    .find(|name| !bound.contains(name))
+                {
+                    return Err(invalid(
+                        &name,
+                        &format!("uses {} which is not bound by a condition", unbound),
+                    ));
+                }
+            }
+        }
+    }
+    Ok(Rule::new(&name, conditions, filters, actions))
+}
+
+fn rule_action(input_pair: Pair<'_, Production>, state: &mut ParserState) -> Result<Action> {
+    trace!("rule_action({:?})", &input_pair.as_rule());
+
+    let inner_pair = input_pair.into_inner().next().unwrap();
+    let rule = inner_pair.as_rule();
+    let mut inner_pairs = inner_pair.into_inner();
+    match rule {
+        Production::retypeAction => {
+            let name = variable(inner_pairs.next().unwrap());
+            let data_type = iri(inner_pairs.next().unwrap(), state)?;
+            Ok(Action::Retype(name, data_type))
+        }
+        Production::addAction => Ok(Action::Add(triple_pattern(
+            inner_pairs.next().unwrap(),
+            state,
+        )?)),
+        _ => Ok(Action::Delete(triple_pattern(
+            inner_pairs.next().unwrap(),
+            state,
+        )?)),
+    }
+}
+
+fn triple_pattern(
+    input_pair: Pair<'_, Production>,
+    state: &mut ParserState,
+) -> Result<TriplePattern> {
+    trace!("triple_pattern({:?})", &input_pair.as_rule());
+
+    let mut inner_pairs = input_pair.into_inner();
+    let subject = var_or_term(inner_pairs.next().unwrap(), state)?;
+    let predicate = verb(inner_pairs.next().unwrap(), state)?;
+    let object = var_or_term(inner_pairs.next().unwrap(), state)?;
+    Ok(TriplePattern::new(subject, predicate, object))
+}
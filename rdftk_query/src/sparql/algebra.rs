@@ -0,0 +1,436 @@
+/*!
+The algebra produced by parsing a query, following section 18 of the SPARQL 1.1 specification.
+
+A query's `WHERE` clause is translated into a tree of `Pattern` values; basic graph patterns
+hold `TriplePattern`s, and `FILTER` constraints hold `Expression`s. Blank nodes in a pattern
+behave as variables that are never projected.
+
+# Example
+
+```rust
+use rdftk_query::sparql::algebra::{Pattern, Projection, QueryForm};
+use rdftk_query::sparql::Query;
+use std::str::FromStr;
+
+let query = Query::from_str(
+    "SELECT ?name WHERE { ?person <http://xmlns.com/foaf/0.1/name> ?name OPTIONAL { ?person <http://xmlns.com/foaf/0.1/age> ?age } }",
+)
+.unwrap();
+
+assert!(matches!(
+    query.form(),
+    QueryForm::Select { projection: Projection::Variables(_), .. }
+));
+assert!(matches!(query.pattern(), Pattern::LeftJoin(_, _, None)));
+```
+
+*/
+
+use rdftk_core::model::literal::LiteralRef;
+use rdftk_iri::IRIRef;
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The name of a query variable, without the leading `?` or `$`.
+///
+pub type Variable = String;
+
+///
+/// A term in a triple pattern, or a constant in an expression.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum Term {
+    /// A query variable.
+    Variable(Variable),
+    /// A blank node, which acts as a variable that is not projected.
+    BlankNode(String),
+    /// An IRI constant.
+    Iri(IRIRef),
+    /// A literal constant.
+    Literal(LiteralRef),
+}
+
+///
+/// A single triple pattern within a basic graph pattern.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct TriplePattern {
+    subject: Term,
+    predicate: Term,
+    object: Term,
+}
+
+///
+/// A graph pattern, the algebraic form of a query's `WHERE` clause.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pattern {
+    /// A basic graph pattern, the conjunction of a set of triple patterns.
+    Bgp(Vec<TriplePattern>),
+    /// The compatible combinations of the solutions of both patterns.
+    Join(Box<Pattern>, Box<Pattern>),
+    /// The solutions of the left pattern, extended by those of the right pattern where they are
+    /// compatible and satisfy the optional expression; the result of `OPTIONAL`.
+    LeftJoin(Box<Pattern>, Box<Pattern>, Option<Expression>),
+    /// The solutions of either pattern; the result of `UNION`.
+    Union(Box<Pattern>, Box<Pattern>),
+    /// The solutions of the pattern for which the expression is true; the result of `FILTER`.
+    Filter(Expression, Box<Pattern>),
+    /// The solutions of the pattern matched against the named graph, or each of the named graphs
+    /// if the term is a variable, which is bound to the graph's name; the result of `GRAPH`.
+    Graph(Term, Box<Pattern>),
+}
+
+///
+/// The unary operators available in expressions.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnaryOperator {
+    /// Logical not, `!`.
+    Not,
+    /// Numeric identity, `+`.
+    Plus,
+    /// Numeric negation, `-`.
+    Minus,
+}
+
+///
+/// The binary operators available in expressions.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryOperator {
+    /// Logical or, `||`.
+    Or,
+    /// Logical and, `&&`.
+    And,
+    /// Equality, `=`.
+    Equal,
+    /// Inequality, `!=`.
+    NotEqual,
+    /// Less than, `<`.
+    Less,
+    /// Less than or equal, `<=`.
+    LessOrEqual,
+    /// Greater than, `>`.
+    Greater,
+    /// Greater than or equal, `>=`.
+    GreaterOrEqual,
+    /// Numeric addition, `+`.
+    Add,
+    /// Numeric subtraction, `-`.
+    Subtract,
+    /// Numeric multiplication, `*`.
+    Multiply,
+    /// Numeric division, `/`.
+    Divide,
+}
+
+///
+/// The built-in functions available in expressions.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Function {
+    /// `BOUND(?var)`
+    Bound,
+    /// `isIRI(term)`, also `isURI(term)`
+    IsIri,
+    /// `isBLANK(term)`
+    IsBlank,
+    /// `isLITERAL(term)`
+    IsLiteral,
+    /// `STR(term)`
+    Str,
+    /// `LANG(literal)`
+    Lang,
+    /// `DATATYPE(literal)`
+    Datatype,
+    /// `LANGMATCHES(tag, range)`
+    LangMatches,
+    /// `REGEX(text, pattern [, flags])`
+    Regex,
+    /// `sameTerm(term, term)`
+    SameTerm,
+    /// `CONTAINS(string, string)`
+    Contains,
+    /// `STRSTARTS(string, string)`
+    StrStarts,
+    /// `STRENDS(string, string)`
+    StrEnds,
+    /// `STRLEN(string)`
+    StrLen,
+    /// `UCASE(string)`
+    UCase,
+    /// `LCASE(string)`
+    LCase,
+}
+
+///
+/// An expression, as used by `FILTER` and `ORDER BY`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression {
+    /// A variable or constant.
+    Term(Term),
+    /// A unary operator applied to an expression.
+    Unary(UnaryOperator, Box<Expression>),
+    /// A binary operator applied to two expressions.
+    Binary(BinaryOperator, Box<Expression>, Box<Expression>),
+    /// A built-in function applied to its arguments.
+    Function(Function, Vec<Expression>),
+}
+
+///
+/// A single `ORDER BY` condition.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderCondition {
+    expression: Expression,
+    descending: bool,
+}
+
+///
+/// Whether a `SELECT` query removes duplicate solutions.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SelectModifier {
+    /// `DISTINCT`, all duplicate solutions are removed.
+    Distinct,
+    /// `REDUCED`, duplicate solutions may be removed.
+    Reduced,
+}
+
+///
+/// The variables projected by a `SELECT` query.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum Projection {
+    /// `SELECT *`, all the variables used in the pattern.
+    All,
+    /// The listed variables, in order.
+    Variables(Vec<Variable>),
+}
+
+///
+/// The form of a query, which determines the form of its results.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum QueryForm {
+    /// A `SELECT` query, returning a sequence of solutions.
+    Select {
+        /// Any `DISTINCT` or `REDUCED` modifier.
+        modifier: Option<SelectModifier>,
+        /// The variables to return in each solution.
+        projection: Projection,
+    },
+    /// An `ASK` query, returning `true` if the pattern has any solution.
+    Ask,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Term {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Variable(name) => write!(f, "?{}", name),
+            Self::BlankNode(name) => write!(f, "_:{}", name),
+            Self::Iri(iri) => write!(f, "<{}>", iri),
+            Self::Literal(literal) => write!(f, "{}", literal),
+        }
+    }
+}
+
+impl Term {
+    ///
+    /// Returns `true` if this term is a variable, or a blank node, that is bound during matching.
+    ///
+    pub fn is_variable(&self) -> bool {
+        matches!(self, Self::Variable(_) | Self::BlankNode(_))
+    }
+
+    ///
+    /// Return the name this term is bound to in a solution, if it is a variable or a blank node.
+    /// Blank node names start with `_:` so that they are distinct from variable names.
+    ///
+    pub fn binding_name(&self) -> Option<String> {
+        match self {
+            Self::Variable(name) => Some(name.clone()),
+            Self::BlankNode(name) => Some(format!("_:{}", name)),
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for TriplePattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.subject, self.predicate, self.object)
+    }
+}
+
+impl TriplePattern {
+    ///
+    /// Construct a new triple pattern.
+    ///
+    pub fn new(subject: Term, predicate: Term, object: Term) -> Self {
+        Self {
+            subject,
+            predicate,
+            object,
+        }
+    }
+
+    ///
+    /// Return the subject term of this pattern.
+    ///
+    pub fn subject(&self) -> &Term {
+        &self.subject
+    }
+
+    ///
+    /// Return the predicate term of this pattern.
+    ///
+    pub fn predicate(&self) -> &Term {
+        &self.predicate
+    }
+
+    ///
+    /// Return the object term of this pattern.
+    ///
+    pub fn object(&self) -> &Term {
+        &self.object
+    }
+
+    ///
+    /// Return an iterator over the terms of this pattern, in subject, predicate, object order.
+    ///
+    pub fn terms(&self) -> impl Iterator<Item = &Term> {
+        vec![&self.subject, &self.predicate, &self.object].into_iter()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Default for Pattern {
+    fn default() -> Self {
+        Self::Bgp(Default::default())
+    }
+}
+
+impl Pattern {
+    ///
+    /// Returns `true` if this is an empty basic graph pattern, the identity for `Join`.
+    ///
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Bgp(triples) if triples.is_empty())
+    }
+
+    ///
+    /// Return the variables used in this pattern, in order of first appearance. Blank nodes
+    /// are not included.
+    ///
+    pub fn variables(&self) -> Vec<Variable> {
+        let mut variables = Vec::default();
+        self.collect_variables(&mut variables);
+        variables
+    }
+
+    fn collect_variables(&self, variables: &mut Vec<Variable>) {
+        match self {
+            Self::Bgp(triples) => {
+                for term in triples.iter().flat_map(|triple| triple.terms()) {
+                    if let Term::Variable(name) = term {
+                        if !variables.contains(name) {
+                            variables.push(name.clone());
+                        }
+                    }
+                }
+            }
+            Self::Join(lhs, rhs) | Self::LeftJoin(lhs, rhs, _) | Self::Union(lhs, rhs) => {
+                lhs.collect_variables(variables);
+                rhs.collect_variables(variables);
+            }
+            Self::Filter(_, pattern) => pattern.collect_variables(variables),
+            Self::Graph(name, pattern) => {
+                if let Term::Variable(name) = name {
+                    if !variables.contains(name) {
+                        variables.push(name.clone());
+                    }
+                }
+                pattern.collect_variables(variables);
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Function {
+    ///
+    /// Return the number of arguments this function accepts, as an inclusive range.
+    ///
+    pub fn arity(&self) -> (usize, usize) {
+        match self {
+            Self::Bound
+            | Self::IsIri
+            | Self::IsBlank
+            | Self::IsLiteral
+            | Self::Str
+            | Self::Lang
+            | Self::Datatype
+            | Self::StrLen
+            | Self::UCase
+            | Self::LCase => (1, 1),
+            Self::LangMatches
+            | Self::SameTerm
+            | Self::Contains
+            | Self::StrStarts
+            | Self::StrEnds => (2, 2),
+            Self::Regex => (2, 3),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl OrderCondition {
+    ///
+    /// Construct a new ascending order condition.
+    ///
+    pub fn ascending(expression: Expression) -> Self {
+        Self {
+            expression,
+            descending: false,
+        }
+    }
+
+    ///
+    /// Construct a new descending order condition.
+    ///
+    pub fn descending(expression: Expression) -> Self {
+        Self {
+            expression,
+            descending: true,
+        }
+    }
+
+    ///
+    /// Return the expression whose value is ordered.
+    ///
+    pub fn expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    ///
+    /// Returns `true` if solutions are ordered by descending value.
+    ///
+    pub fn is_descending(&self) -> bool {
+        self.descending
+    }
+}
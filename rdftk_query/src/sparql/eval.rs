@@ -0,0 +1,862 @@
+/*!
+Evaluation of the query algebra over any implementation of the `Graph` trait, or over the graphs
+of a `DataSet`.
+
+Basic graph patterns are matched one triple pattern at a time, substituting the values already
+bound into each pattern before calling `Graph::matches`. Expressions are evaluated following
+section 17 of the SPARQL 1.1 specification; an expression that raises an error, such as a
+comparison of incompatible values, is treated as `false` by `FILTER`. Dates and date times are
+compared on the timeline, so that values in different timezones compare correctly; a comparison
+the XML Schema order leaves indeterminate, between a value with a timezone and one without, is an
+error.
+
+A `GRAPH` pattern is matched against the named graphs of a data set; when a query is evaluated
+against a single graph there are no named graphs, and so a `GRAPH` pattern has no solutions.
+
+*/
+
+use crate::sparql::algebra::{
+    BinaryOperator, Expression, Function, OrderCondition, Pattern, Projection, QueryForm,
+    SelectModifier, Term, TriplePattern, UnaryOperator,
+};
+use crate::sparql::results::{QueryResults, Solution, Solutions};
+use crate::sparql::Query;
+use rdftk_core::error::Result;
+use rdftk_core::model::data_set::{DataSet, GraphNameRef};
+use rdftk_core::model::graph::inference::RuleContext;
+use rdftk_core::model::graph::Graph;
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralRef};
+use rdftk_core::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementRef, SubjectNodeRef,
+};
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_iri::IRIRef;
+use rdftk_names::{rdf, xsd};
+use regex::Regex;
+use std::cell::Ref;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+pub(crate) trait Matches {
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef>;
+}
+
+struct Evaluator<'a, G: ?Sized> {
+    graph: &'a G,
+    named: &'a [(ObjectNodeRef, &'a dyn Graph)],
+    statements: StatementFactoryRef,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Iri(IRIRef),
+    Blank(String),
+    Boolean(bool),
+    Numeric {
+        value: f64,
+        lexical: String,
+        data_type: IRIRef,
+    },
+    String(String, Option<LanguageTag>),
+    Typed(String, IRIRef),
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn evaluate(query: &Query, graph: &dyn Graph) -> Result<QueryResults> {
+    evaluate_with_named(query, graph, &[])
+}
+
+pub(super) fn evaluate_data_set(
+    query: &Query,
+    data_set: &dyn DataSet,
+    default_graph_as_union: bool,
+) -> Result<QueryResults> {
+    let default_graph = if default_graph_as_union {
+        data_set.union_graph()
+    } else {
+        match data_set.default_graph() {
+            Some(graph) => graph.clone(),
+            None => data_set.graph_factory().graph(),
+        }
+    };
+    let default_graph = default_graph.borrow();
+    let borrowed: Vec<(&GraphNameRef, Ref<'_, dyn Graph>)> = data_set
+        .graphs()
+        .map(|(name, graph)| (name, graph.borrow()))
+        .collect();
+    let statements = default_graph.statement_factory();
+    let named: Vec<(ObjectNodeRef, &dyn Graph)> = borrowed
+        .iter()
+        .map(|(name, graph)| {
+            let name = match name.as_iri() {
+                Some(iri) => statements.named_object(iri.clone()),
+                None => statements
+                    .blank_object_named(name.as_blank().unwrap())
+                    .unwrap_or_else(|_| statements.blank_object()),
+            };
+            (name, &**graph)
+        })
+        .collect();
+    evaluate_with_named(query, &*default_graph, &named)
+}
+
+///
+/// Return the solutions of `conditions`, extending `seed`, that satisfy every filter; used to
+/// match the conditions of an inference rule against the statements known to the engine.
+///
+pub(crate) fn rule_solutions(
+    conditions: &[TriplePattern],
+    filters: &[Expression],
+    source: &RuleContext<'_>,
+    seed: Solution,
+) -> Vec<Solution> {
+    let evaluator = Evaluator {
+        graph: source,
+        named: &[],
+        statements: source.statement_factory().clone(),
+    };
+    conditions
+        .iter()
+        .fold(vec![seed], |solutions, triple| {
+            solutions
+                .iter()
+                .flat_map(|solution| evaluator.triple(triple, solution))
+                .collect()
+        })
+        .into_iter()
+        .filter(|solution| {
+            filters
+                .iter()
+                .all(|expression| evaluator.filter(expression, solution))
+        })
+        .collect()
+}
+
+///
+/// Return the solution binding the variables of `condition` to the values of `statement`, or
+/// `None` if the statement does not match the condition.
+///
+pub(crate) fn unify(
+    condition: &TriplePattern,
+    statement: &StatementRef,
+    statements: &StatementFactoryRef,
+) -> Option<Solution> {
+    let evaluator = Evaluator {
+        graph: statement,
+        named: &[],
+        statements: statements.clone(),
+    };
+    evaluator
+        .triple(condition, &Solution::default())
+        .into_iter()
+        .next()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Matches for dyn Graph + '_ {
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        Graph::matches(self, subject, predicate, object)
+    }
+}
+
+impl Matches for RuleContext<'_> {
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        RuleContext::matches(self, subject, predicate, object)
+    }
+}
+
+impl Matches for StatementRef {
+    fn matches(
+        &self,
+        subject: Option<&SubjectNodeRef>,
+        predicate: Option<&IRIRef>,
+        object: Option<&ObjectNodeRef>,
+    ) -> HashSet<&StatementRef> {
+        let mut matched: HashSet<&StatementRef> = Default::default();
+        if subject
+            .map(|subject| subject == self.subject())
+            .unwrap_or(true)
+            && predicate
+                .map(|predicate| predicate == self.predicate())
+                .unwrap_or(true)
+            && object.map(|object| object == self.object()).unwrap_or(true)
+        {
+            let _ = matched.insert(self);
+        }
+        matched
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a, G: Matches + ?Sized> Evaluator<'a, G> {
+    fn pattern(&self, pattern: &Pattern) -> Vec<Solution> {
+        match pattern {
+            Pattern::Bgp(triples) => {
+                triples
+                    .iter()
+                    .fold(vec![Solution::default()], |solutions, triple| {
+                        solutions
+                            .iter()
+                            .flat_map(|solution| self.triple(triple, solution))
+                            .collect()
+                    })
+            }
+            Pattern::Join(lhs, rhs) => {
+                let rhs = self.pattern(rhs);
+                self.pattern(lhs)
+                    .iter()
+                    .flat_map(|lhs| rhs.iter().filter_map(move |rhs| lhs.merge(rhs)))
+                    .collect()
+            }
+            Pattern::LeftJoin(lhs, rhs, expression) => {
+                let rhs = self.pattern(rhs);
+                let mut solutions: Vec<Solution> = Default::default();
+                for lhs in self.pattern(lhs) {
+                    let extended: Vec<Solution> = rhs
+                        .iter()
+                        .filter_map(|rhs| lhs.merge(rhs))
+                        .filter(|merged| match expression {
+                            None => true,
+                            Some(expression) => self.filter(expression, merged),
+                        })
+                        .collect();
+                    if extended.is_empty() {
+                        solutions.push(lhs);
+                    } else {
+                        solutions.extend(extended);
+                    }
+                }
+                solutions
+            }
+            Pattern::Union(lhs, rhs) => {
+                let mut solutions = self.pattern(lhs);
+                solutions.extend(self.pattern(rhs));
+                solutions
+            }
+            Pattern::Filter(expression, pattern) => self
+                .pattern(pattern)
+                .into_iter()
+                .filter(|solution| self.filter(expression, solution))
+                .collect(),
+            Pattern::Graph(name, pattern) => {
+                let mut solutions: Vec<Solution> = Default::default();
+                for (graph_name, graph) in self.named {
+                    let matched = match name {
+                        Term::Iri(iri) => graph_name.as_iri() == Some(iri),
+                        Term::Literal(_) => false,
+                        _ => true,
+                    };
+                    if matched {
+                        let evaluator: Evaluator<'_, dyn Graph> = Evaluator {
+                            graph: *graph,
+                            named: self.named,
+                            statements: self.statements.clone(),
+                        };
+                        solutions.extend(evaluator.pattern(pattern).into_iter().filter_map(
+                            |mut solution| {
+                                if bind(&mut solution, name, graph_name.clone()) {
+                                    Some(solution)
+                                } else {
+                                    None
+                                }
+                            },
+                        ));
+                    }
+                }
+                solutions
+            }
+        }
+    }
+
+    fn triple(&self, triple: &TriplePattern, solution: &Solution) -> Vec<Solution> {
+        let subject: Option<SubjectNodeRef> = match triple.subject() {
+            Term::Iri(iri) => Some(self.statements.named_subject(iri.clone())),
+            Term::Literal(_) => return Default::default(),
+            term => match self.bound(term, solution) {
+                None => None,
+                Some(value) => match self.statements.object_as_subject(value.clone()) {
+                    None => return Default::default(),
+                    subject => subject,
+                },
+            },
+        };
+        let predicate: Option<IRIRef> = match triple.predicate() {
+            Term::Iri(iri) => Some(iri.clone()),
+            Term::Literal(_) => return Default::default(),
+            term => match self.bound(term, solution) {
+                None => None,
+                Some(value) => match value.as_iri() {
+                    None => return Default::default(),
+                    iri => iri.cloned(),
+                },
+            },
+        };
+        // literal constants are compared by value below, rather than by the graph, so that the
+        // different representations of a data type match each other.
+        let object: Option<ObjectNodeRef> = match triple.object() {
+            Term::Iri(iri) => Some(self.statements.named_object(iri.clone())),
+            Term::Literal(_) => None,
+            term => self.bound(term, solution).cloned(),
+        };
+
+        self.graph
+            .matches(subject.as_ref(), predicate.as_ref(), object.as_ref())
+            .into_iter()
+            .filter(|statement| match triple.object() {
+                Term::Literal(literal) => statement
+                    .object()
+                    .as_literal()
+                    .map(|object| same_literal(object, literal))
+                    .unwrap_or_default(),
+                _ => true,
+            })
+            .filter_map(|statement| {
+                let mut solution = solution.clone();
+                let bound = bind(
+                    &mut solution,
+                    triple.subject(),
+                    self.statements
+                        .subject_as_object(statement.subject().clone()),
+                ) && bind(
+                    &mut solution,
+                    triple.predicate(),
+                    self.statements.named_object(statement.predicate().clone()),
+                ) && bind(&mut solution, triple.object(), statement.object().clone());
+                if bound {
+                    Some(solution)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn bound<'b>(&self, term: &Term, solution: &'b Solution) -> Option<&'b ObjectNodeRef> {
+        term.binding_name().and_then(|name| solution.get(&name))
+    }
+
+    fn order(&self, solutions: Vec<Solution>, conditions: &[OrderCondition]) -> Vec<Solution> {
+        if conditions.is_empty() {
+            return solutions;
+        }
+        let mut keyed: Vec<(Vec<Option<Value>>, Solution)> = solutions
+            .into_iter()
+            .map(|solution| {
+                (
+                    conditions
+                        .iter()
+                        .map(|condition| self.expression(condition.expression(), &solution))
+                        .collect(),
+                    solution,
+                )
+            })
+            .collect();
+        keyed.sort_by(|(lhs, _), (rhs, _)| {
+            conditions
+                .iter()
+                .zip(lhs.iter().zip(rhs.iter()))
+                .map(|(condition, (lhs, rhs))| {
+                    let ordering = order_values(lhs, rhs);
+                    if condition.is_descending() {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                })
+                .find(|ordering| *ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        });
+        keyed.into_iter().map(|(_, solution)| solution).collect()
+    }
+
+    fn filter(&self, expression: &Expression, solution: &Solution) -> bool {
+        self.expression(expression, solution)
+            .as_ref()
+            .and_then(effective_boolean_value)
+            .unwrap_or_default()
+    }
+
+    fn expression(&self, expression: &Expression, solution: &Solution) -> Option<Value> {
+        match expression {
+            Expression::Term(Term::Iri(iri)) => Some(Value::Iri(iri.clone())),
+            Expression::Term(Term::Literal(literal)) => Some(Value::from_literal(literal)),
+            Expression::Term(term) => self.bound(term, solution).and_then(Value::from_node),
+            Expression::Unary(operator, operand) => {
+                let operand = self.expression(operand, solution)?;
+                match (operator, operand) {
+                    (UnaryOperator::Not, operand) => {
+                        Some(Value::Boolean(!effective_boolean_value(&operand)?))
+                    }
+                    (UnaryOperator::Plus, operand @ Value::Numeric { .. }) => Some(operand),
+                    (
+                        UnaryOperator::Minus,
+                        Value::Numeric {
+                            value, data_type, ..
+                        },
+                    ) => Some(Value::numeric(-value, data_type)),
+                    _ => None,
+                }
+            }
+            Expression::Binary(BinaryOperator::Or, lhs, rhs) => {
+                let lhs = self.expression(lhs, solution);
+                let rhs = self.expression(rhs, solution);
+                match (
+                    lhs.as_ref().and_then(effective_boolean_value),
+                    rhs.as_ref().and_then(effective_boolean_value),
+                ) {
+                    (Some(true), _) | (_, Some(true)) => Some(Value::Boolean(true)),
+                    (Some(false), Some(false)) => Some(Value::Boolean(false)),
+                    _ => None,
+                }
+            }
+            Expression::Binary(BinaryOperator::And, lhs, rhs) => {
+                let lhs = self.expression(lhs, solution);
+                let rhs = self.expression(rhs, solution);
+                match (
+                    lhs.as_ref().and_then(effective_boolean_value),
+                    rhs.as_ref().and_then(effective_boolean_value),
+                ) {
+                    (Some(false), _) | (_, Some(false)) => Some(Value::Boolean(false)),
+                    (Some(true), Some(true)) => Some(Value::Boolean(true)),
+                    _ => None,
+                }
+            }
+            Expression::Binary(operator, lhs, rhs) => {
+                let lhs = self.expression(lhs, solution)?;
+                let rhs = self.expression(rhs, solution)?;
+                match operator {
+                    BinaryOperator::Equal => equal_values(&lhs, &rhs).map(Value::Boolean),
+                    BinaryOperator::NotEqual => {
+                        equal_values(&lhs, &rhs).map(|eq| Value::Boolean(!eq))
+                    }
+                    BinaryOperator::Less => {
+                        compare_values(&lhs, &rhs).map(|o| Value::Boolean(o == Ordering::Less))
+                    }
+                    BinaryOperator::LessOrEqual => {
+                        compare_values(&lhs, &rhs).map(|o| Value::Boolean(o != Ordering::Greater))
+                    }
+                    BinaryOperator::Greater => {
+                        compare_values(&lhs, &rhs).map(|o| Value::Boolean(o == Ordering::Greater))
+                    }
+                    BinaryOperator::GreaterOrEqual => {
+                        compare_values(&lhs, &rhs).map(|o| Value::Boolean(o != Ordering::Less))
+                    }
+                    _ => arithmetic(*operator, &lhs, &rhs),
+                }
+            }
+            Expression::Function(function, arguments) => {
+                self.function(*function, arguments, solution)
+            }
+        }
+    }
+
+    fn function(
+        &self,
+        function: Function,
+        arguments: &[Expression],
+        solution: &Solution,
+    ) -> Option<Value> {
+        if function == Function::Bound {
+            return match &arguments[0] {
+                Expression::Term(term) => {
+                    Some(Value::Boolean(self.bound(term, solution).is_some()))
+                }
+                _ => None,
+            };
+        }
+        let arguments: Vec<Value> = arguments
+            .iter()
+            .map(|argument| self.expression(argument, solution))
+            .collect::<Option<Vec<Value>>>()?;
+        match (function, arguments.as_slice()) {
+            (Function::IsIri, [value]) => Some(Value::Boolean(matches!(value, Value::Iri(_)))),
+            (Function::IsBlank, [value]) => Some(Value::Boolean(matches!(value, Value::Blank(_)))),
+            (Function::IsLiteral, [value]) => Some(Value::Boolean(value.is_literal())),
+            (Function::Str, [Value::Blank(_)]) => None,
+            (Function::Str, [value]) => Some(Value::String(value.lexical_form(), None)),
+            (Function::Lang, [Value::String(_, language)]) => Some(Value::String(
+                language
+                    .as_ref()
+                    .map(|language| language.to_string())
+                    .unwrap_or_default(),
+                None,
+            )),
+            (Function::Lang, [value]) if value.is_literal() => {
+                Some(Value::String(String::new(), None))
+            }
+            (Function::Datatype, [value]) => value.data_type().map(Value::Iri),
+            (Function::LangMatches, [Value::String(tag, _), Value::String(range, _)]) => {
+                Some(Value::Boolean(lang_matches(tag, range)))
+            }
+            (Function::Regex, [Value::String(text, _), Value::String(pattern, _), flags @ ..]) => {
+                let flags = match flags {
+                    [] => String::new(),
+                    [Value::String(flags, None)] => flags.clone(),
+                    _ => return None,
+                };
+                let pattern = if flags.is_empty() {
+                    pattern.clone()
+                } else {
+                    format!("(?{}){}", flags, pattern)
+                };
+                Regex::new(&pattern)
+                    .ok()
+                    .map(|regex| Value::Boolean(regex.is_match(text)))
+            }
+            (Function::SameTerm, [lhs, rhs]) => Some(Value::Boolean(lhs == rhs)),
+            (Function::Contains, [Value::String(lhs, _), Value::String(rhs, _)]) => {
+                Some(Value::Boolean(lhs.contains(rhs.as_str())))
+            }
+            (Function::StrStarts, [Value::String(lhs, _), Value::String(rhs, _)]) => {
+                Some(Value::Boolean(lhs.starts_with(rhs.as_str())))
+            }
+            (Function::StrEnds, [Value::String(lhs, _), Value::String(rhs, _)]) => {
+                Some(Value::Boolean(lhs.ends_with(rhs.as_str())))
+            }
+            (Function::StrLen, [Value::String(value, _)]) => Some(Value::numeric(
+                value.chars().count() as f64,
+                xsd::integer().clone(),
+            )),
+            (Function::UCase, [Value::String(value, language)]) => {
+                Some(Value::String(value.to_uppercase(), language.clone()))
+            }
+            (Function::LCase, [Value::String(value, language)]) => {
+                Some(Value::String(value.to_lowercase(), language.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Value {
+    fn from_node(node: &ObjectNodeRef) -> Option<Self> {
+        if let Some(iri) = node.as_iri() {
+            Some(Self::Iri(iri.clone()))
+        } else if let Some(name) = node.as_blank() {
+            Some(Self::Blank(name.clone()))
+        } else {
+            node.as_literal().map(Self::from_literal)
+        }
+    }
+
+    fn from_literal(literal: &LiteralRef) -> Self {
+        let lexical = literal.lexical_form();
+        match literal.data_type().map(|data_type| data_type.as_iri()) {
+            None => Self::String(lexical.clone(), literal.language().cloned()),
+            Some(data_type) if data_type == xsd::string() => Self::String(lexical.clone(), None),
+            Some(data_type) if data_type == xsd::boolean() => match lexical.trim() {
+                "true" | "1" => Self::Boolean(true),
+                "false" | "0" => Self::Boolean(false),
+                _ => Self::Typed(lexical.clone(), data_type.clone()),
+            },
+            Some(data_type) if is_numeric(data_type) => match lexical.trim().parse::<f64>() {
+                Ok(value) => Self::Numeric {
+                    value,
+                    lexical: lexical.clone(),
+                    data_type: data_type.clone(),
+                },
+                Err(_) => Self::Typed(lexical.clone(), data_type.clone()),
+            },
+            Some(data_type) => Self::Typed(lexical.clone(), data_type.clone()),
+        }
+    }
+
+    fn numeric(value: f64, data_type: IRIRef) -> Self {
+        let lexical = if is_integer(&data_type) {
+            format!("{}", value as i64)
+        } else {
+            format!("{}", value)
+        };
+        Self::Numeric {
+            value,
+            lexical,
+            data_type,
+        }
+    }
+
+    fn is_literal(&self) -> bool {
+        !matches!(self, Self::Iri(_) | Self::Blank(_))
+    }
+
+    fn lexical_form(&self) -> String {
+        match self {
+            Self::Iri(iri) => iri.to_string(),
+            Self::Blank(name) => name.clone(),
+            Self::Boolean(value) => value.to_string(),
+            Self::Numeric { lexical, .. } => lexical.clone(),
+            Self::String(value, _) | Self::Typed(value, _) => value.clone(),
+        }
+    }
+
+    fn data_type(&self) -> Option<IRIRef> {
+        match self {
+            Self::Iri(_) | Self::Blank(_) => None,
+            Self::Boolean(_) => Some(xsd::boolean().clone()),
+            Self::Numeric { data_type, .. } | Self::Typed(_, data_type) => Some(data_type.clone()),
+            Self::String(_, None) => Some(xsd::string().clone()),
+            Self::String(_, Some(_)) => Some(rdf::lang_string().clone()),
+        }
+    }
+
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Blank(_) => 1,
+            Self::Iri(_) => 2,
+            _ => 3,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn evaluate_with_named(
+    query: &Query,
+    graph: &dyn Graph,
+    named: &[(ObjectNodeRef, &dyn Graph)],
+) -> Result<QueryResults> {
+    let evaluator = Evaluator {
+        graph,
+        named,
+        statements: graph.statement_factory(),
+    };
+    let solutions = evaluator.pattern(query.pattern());
+    match query.form() {
+        QueryForm::Ask => Ok(QueryResults::Boolean(!solutions.is_empty())),
+        QueryForm::Select {
+            modifier,
+            projection,
+        } => {
+            let solutions = evaluator.order(solutions, query.order());
+            let variables = match projection {
+                Projection::All => query.pattern().variables(),
+                Projection::Variables(variables) => variables.clone(),
+            };
+            let mut rows: Vec<Solution> = solutions
+                .iter()
+                .map(|solution| solution.project(&variables))
+                .collect();
+            match modifier {
+                Some(SelectModifier::Distinct) => {
+                    let mut seen: HashSet<Vec<Option<ObjectNodeRef>>> = Default::default();
+                    rows.retain(|row| {
+                        seen.insert(
+                            variables
+                                .iter()
+                                .map(|variable| row.get(variable).cloned())
+                                .collect(),
+                        )
+                    });
+                }
+                Some(SelectModifier::Reduced) => rows.dedup(),
+                None => {}
+            }
+            let rows: Vec<Solution> = rows
+                .into_iter()
+                .skip(query.offset().unwrap_or(0))
+                .take(query.limit().unwrap_or(usize::MAX))
+                .collect();
+            Ok(Solutions::new(variables, rows).into())
+        }
+    }
+}
+
+fn bind(solution: &mut Solution, term: &Term, value: ObjectNodeRef) -> bool {
+    match term.binding_name() {
+        None => true,
+        Some(name) => solution.bind(name, value),
+    }
+}
+
+fn same_literal(lhs: &LiteralRef, rhs: &LiteralRef) -> bool {
+    let data_type = |literal: &LiteralRef| {
+        literal
+            .data_type()
+            .map(|data_type| data_type.as_iri().clone())
+            .filter(|data_type| data_type != xsd::string())
+    };
+    lhs.lexical_form() == rhs.lexical_form()
+        && lhs.language() == rhs.language()
+        && data_type(lhs) == data_type(rhs)
+}
+
+fn is_numeric(data_type: &IRIRef) -> bool {
+    data_type == xsd::decimal()
+        || data_type == xsd::float()
+        || data_type == xsd::double()
+        || is_integer(data_type)
+}
+
+fn is_integer(data_type: &IRIRef) -> bool {
+    [
+        xsd::integer(),
+        xsd::long(),
+        xsd::int(),
+        xsd::short(),
+        xsd::byte(),
+        xsd::unsigned_long(),
+        xsd::unsigned_int(),
+        xsd::unsigned_short(),
+        xsd::unsigned_byte(),
+        xsd::non_negative_integer(),
+        xsd::non_positive_integer(),
+        xsd::positive_integer(),
+    ]
+    .contains(&data_type)
+}
+
+fn is_date_time(data_type: &IRIRef) -> bool {
+    data_type == xsd::date_time() || data_type == xsd::date_time_stamp() || data_type == xsd::date()
+}
+
+///
+/// Returns `None` if either value is not valid, if one is a date and the other a date time, or if
+/// their order is indeterminate.
+///
+fn compare_date_times(lhs: &Value, rhs: &Value) -> Option<Ordering> {
+    let literal = |value: &Value| match value {
+        Value::Typed(lexical, data_type) => {
+            Some(literal_factory().with_data_type(lexical, DataType::from(data_type.clone())))
+        }
+        _ => None,
+    };
+    literal(lhs)?
+        .compare_date_time(&*literal(rhs)?)
+        .ok()
+        .flatten()
+}
+
+fn effective_boolean_value(value: &Value) -> Option<bool> {
+    match value {
+        Value::Boolean(value) => Some(*value),
+        Value::Numeric { value, .. } => Some(*value != 0.0 && !value.is_nan()),
+        Value::String(value, None) => Some(!value.is_empty()),
+        _ => None,
+    }
+}
+
+fn equal_values(lhs: &Value, rhs: &Value) -> Option<bool> {
+    match (lhs, rhs) {
+        (Value::Numeric { value: lhs, .. }, Value::Numeric { value: rhs, .. }) => Some(lhs == rhs),
+        (Value::Typed(_, lhs_type), Value::Typed(_, rhs_type))
+            if is_date_time(lhs_type) && is_date_time(rhs_type) =>
+        {
+            compare_date_times(lhs, rhs).map(|ordering| ordering == Ordering::Equal)
+        }
+        (Value::Typed(lhs, lhs_type), Value::Typed(rhs, rhs_type)) if lhs_type == rhs_type => {
+            Some(lhs == rhs)
+        }
+        (Value::Typed(_, _), Value::Typed(_, _)) => None,
+        (lhs, rhs) => Some(lhs == rhs),
+    }
+}
+
+fn compare_values(lhs: &Value, rhs: &Value) -> Option<Ordering> {
+    match (lhs, rhs) {
+        (Value::Numeric { value: lhs, .. }, Value::Numeric { value: rhs, .. }) => {
+            lhs.partial_cmp(rhs)
+        }
+        (Value::String(lhs, None), Value::String(rhs, None)) => Some(lhs.cmp(rhs)),
+        (Value::Boolean(lhs), Value::Boolean(rhs)) => Some(lhs.cmp(rhs)),
+        (Value::Typed(_, lhs_type), Value::Typed(_, rhs_type))
+            if is_date_time(lhs_type) && is_date_time(rhs_type) =>
+        {
+            compare_date_times(lhs, rhs)
+        }
+        (Value::Typed(lhs, lhs_type), Value::Typed(rhs, rhs_type)) if lhs_type == rhs_type => {
+            Some(lhs.cmp(rhs))
+        }
+        _ => None,
+    }
+}
+
+fn order_values(lhs: &Option<Value>, rhs: &Option<Value>) -> Ordering {
+    match (lhs, rhs) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(lhs), Some(rhs)) => lhs.rank().cmp(&rhs.rank()).then_with(|| {
+            compare_values(lhs, rhs).unwrap_or_else(|| lhs.lexical_form().cmp(&rhs.lexical_form()))
+        }),
+    }
+}
+
+fn arithmetic(operator: BinaryOperator, lhs: &Value, rhs: &Value) -> Option<Value> {
+    match (lhs, rhs) {
+        (
+            Value::Numeric {
+                value: lhs,
+                data_type: lhs_type,
+                ..
+            },
+            Value::Numeric {
+                value: rhs,
+                data_type: rhs_type,
+                ..
+            },
+        ) => {
+            let data_type = if [lhs_type, rhs_type]
+                .iter()
+                .any(|t| *t == xsd::double() || *t == xsd::float())
+            {
+                xsd::double()
+            } else if operator == BinaryOperator::Divide
+                || lhs_type == xsd::decimal()
+                || rhs_type == xsd::decimal()
+            {
+                xsd::decimal()
+            } else {
+                xsd::integer()
+            };
+            let value = match operator {
+                BinaryOperator::Add => lhs + rhs,
+                BinaryOperator::Subtract => lhs - rhs,
+                BinaryOperator::Multiply => lhs * rhs,
+                BinaryOperator::Divide if *rhs == 0.0 && data_type != xsd::double() => return None,
+                BinaryOperator::Divide => lhs / rhs,
+                _ => return None,
+            };
+            Some(Value::numeric(value, data_type.clone()))
+        }
+        _ => None,
+    }
+}
+
+fn lang_matches(tag: &str, range: &str) -> bool {
+    if range == "*" {
+        !tag.is_empty()
+    } else {
+        let tag = tag.to_lowercase();
+        let range = range.to_lowercase();
+        tag == range || tag.starts_with(&format!("{}-", range))
+    }
+}
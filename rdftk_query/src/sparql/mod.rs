@@ -0,0 +1,249 @@
+/*!
+Support for the `SELECT` and `ASK` forms of the
+[SPARQL 1.1 Query Language](https://www.w3.org/TR/sparql11-query/) over in-memory graphs and
+data sets.
+
+A query is parsed into its algebraic form, a `Query`, which may then be evaluated against any
+implementation of the `Graph` trait, or of the `DataSet` trait. The supported subset of the
+language includes the `BASE` and `PREFIX` declarations, basic graph patterns, `OPTIONAL`, `UNION`,
+`GRAPH`, `FILTER`, the `DISTINCT` and `REDUCED` modifiers, and the `ORDER BY`, `LIMIT`, and
+`OFFSET` solution modifiers.
+
+When evaluated against a data set, patterns outside of `GRAPH` are matched against its default
+graph, and `GRAPH` patterns against its named graphs. A data set may instead be queried with its
+default graph taken to be the union of all its graphs, as many stores do; in either case `GRAPH`
+patterns match only the named graphs.
+
+The `update` module adds the SPARQL 1.1 Update language, to change a graph or data set using the
+same patterns.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::foaf;
+use rdftk_query::sparql::Query;
+use std::str::FromStr;
+
+let factory = statement_factory();
+let graph = graph_factory().graph_from(
+    &[factory
+        .statement(
+            factory.named_subject(IRIRef::from(IRI::from_str("http://example.org/alice").unwrap())),
+            foaf::name().clone(),
+            factory.literal_object(literal_factory().literal("Alice")),
+        )
+        .unwrap()],
+    None,
+);
+
+let query = Query::from_str(
+    r#"PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+       SELECT ?name WHERE { ?person foaf:name ?name FILTER(STRSTARTS(?name, "A")) }"#,
+)
+.unwrap();
+
+let results = query.execute(&graph).unwrap();
+let solutions = results.as_solutions().unwrap();
+assert_eq!(solutions.len(), 1);
+assert_eq!(
+    solutions.column("name")[0].unwrap().as_literal().unwrap().lexical_form(),
+    "Alice"
+);
+
+let query = Query::from_str("ASK { ?person <http://xmlns.com/foaf/0.1/age> ?age }").unwrap();
+assert_eq!(query.execute(&graph).unwrap().as_boolean(), Some(false));
+```
+
+*/
+
+use crate::sparql::algebra::{OrderCondition, Pattern, QueryForm};
+use crate::sparql::results::QueryResults;
+use rdftk_core::error::{Error, Result};
+use rdftk_core::model::data_set::{DataSet, DataSetRef};
+use rdftk_core::model::graph::{Graph, GraphRef};
+use rdftk_iri::IRIRef;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The name of the query language, used in errors.
+///
+pub const NAME: &str = "SPARQL";
+
+///
+/// A parsed query, consisting of its form, the graph pattern to match, and the modifiers to
+/// apply to the resulting solutions.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query {
+    base: Option<IRIRef>,
+    prefixes: Vec<(String, IRIRef)>,
+    form: QueryForm,
+    pattern: Pattern,
+    order: Vec<OrderCondition>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FromStr for Query {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parser::parse_query(s)
+    }
+}
+
+impl Query {
+    ///
+    /// Construct a new query from its form and graph pattern.
+    ///
+    pub fn new(form: QueryForm, pattern: Pattern) -> Self {
+        Self {
+            base: None,
+            prefixes: Default::default(),
+            form,
+            pattern,
+            order: Default::default(),
+            limit: None,
+            offset: None,
+        }
+    }
+
+    ///
+    /// Return the base IRI declared in the query prologue, if any.
+    ///
+    pub fn base(&self) -> Option<&IRIRef> {
+        self.base.as_ref()
+    }
+
+    ///
+    /// Return the prefixes declared in the query prologue, in order.
+    ///
+    pub fn prefixes(&self) -> impl Iterator<Item = (&String, &IRIRef)> {
+        self.prefixes
+            .iter()
+            .map(|(prefix, namespace)| (prefix, namespace))
+    }
+
+    ///
+    /// Return the form of this query.
+    ///
+    pub fn form(&self) -> &QueryForm {
+        &self.form
+    }
+
+    ///
+    /// Return the graph pattern matched by this query.
+    ///
+    pub fn pattern(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    ///
+    /// Return the `ORDER BY` conditions, in order of precedence.
+    ///
+    pub fn order(&self) -> &[OrderCondition] {
+        &self.order
+    }
+
+    ///
+    /// Add an `ORDER BY` condition, with lower precedence than any existing condition.
+    ///
+    pub fn order_by(&mut self, condition: OrderCondition) -> &mut Self {
+        self.order.push(condition);
+        self
+    }
+
+    ///
+    /// Return the maximum number of solutions to return, if limited.
+    ///
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    ///
+    /// Set the maximum number of solutions to return.
+    ///
+    pub fn set_limit(&mut self, limit: Option<usize>) -> &mut Self {
+        self.limit = limit;
+        self
+    }
+
+    ///
+    /// Return the number of solutions to skip, if any.
+    ///
+    pub fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    ///
+    /// Set the number of solutions to skip.
+    ///
+    pub fn set_offset(&mut self, offset: Option<usize>) -> &mut Self {
+        self.offset = offset;
+        self
+    }
+
+    ///
+    /// Evaluate this query against `graph`.
+    ///
+    pub fn evaluate(&self, graph: &dyn Graph) -> Result<QueryResults> {
+        eval::evaluate(self, graph)
+    }
+
+    ///
+    /// Evaluate this query against the graph referenced by `graph`.
+    ///
+    pub fn execute(&self, graph: &GraphRef) -> Result<QueryResults> {
+        self.evaluate(&*graph.borrow())
+    }
+
+    ///
+    /// Evaluate this query against `data_set`. If `default_graph_as_union` is `true` the default
+    /// graph is the union of all the graphs in the data set, else it is the data set's default
+    /// graph, or an empty graph if it has none.
+    ///
+    pub fn evaluate_data_set(
+        &self,
+        data_set: &dyn DataSet,
+        default_graph_as_union: bool,
+    ) -> Result<QueryResults> {
+        eval::evaluate_data_set(self, data_set, default_graph_as_union)
+    }
+
+    ///
+    /// Evaluate this query against the data set referenced by `data_set`, see `evaluate_data_set`.
+    ///
+    pub fn execute_data_set(
+        &self,
+        data_set: &DataSetRef,
+        default_graph_as_union: bool,
+    ) -> Result<QueryResults> {
+        self.evaluate_data_set(&*data_set.borrow(), default_graph_as_union)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod algebra;
+
+pub(crate) mod eval;
+
+pub(crate) mod parser;
+
+pub mod results;
+
+pub mod update;
@@ -0,0 +1,893 @@
+/*!
+A parser for the subset of the [SPARQL 1.1 Query Language](https://www.w3.org/TR/sparql11-query/)
+grammar described in `sparql.pest`, producing a `Query` in its algebraic form, and of the
+[SPARQL 1.1 Update](https://www.w3.org/TR/sparql11-update/) grammar described in `update.pest`,
+producing an `Update`.
+
+Relative IRIs are resolved against the base declared in the query prologue; prefixed names are
+expanded using the prefixes declared in the prologue.
+
+*/
+
+#![allow(clippy::upper_case_acronyms)] // << generated by pest.
+
+use crate::sparql::algebra::{
+    BinaryOperator, Expression, Function, OrderCondition, Pattern, Projection, QueryForm,
+    SelectModifier, Term, TriplePattern, UnaryOperator, Variable,
+};
+use crate::sparql::update::{GraphTarget, QuadPattern, Update, UpdateOperation};
+use crate::sparql::{Query, NAME};
+use pest::iterators::Pair;
+use pest::Parser;
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralFactoryRef, LiteralRef};
+use rdftk_core::simple::literal::literal_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, xsd};
+use regex::Regex;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Parser)]
+#[grammar = "sparql/sparql.pest"]
+#[grammar = "sparql/update.pest"]
+#[grammar = "rules/rules.pest"]
+pub(crate) struct SparqlParser;
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Debug)]
+pub(crate) struct ParserState {
+    literals: LiteralFactoryRef,
+    prefixes: Vec<(String, IRIRef)>,
+    base: Option<IRIRef>,
+    anonymous: usize,
+}
+
+lazy_static! {
+    static ref STRING_ESC: Regex =
+        Regex::new(r#"(\\U[[:xdigit:]]{8})|(\\u[[:xdigit:]]{4})|(\\[tbnrf"'\\])"#).unwrap();
+    static ref LOCAL_ESC: Regex = Regex::new(r"\\(.)").unwrap();
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(super) fn parse_query(input: &str) -> Result<Query> {
+    let mut parsed = SparqlParser::parse(Rule::queryUnit, input)
+        .map_err(|e| Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string())))?;
+    let top_node = parsed.next().unwrap();
+    query_unit(top_node)
+}
+
+pub(super) fn parse_update(input: &str) -> Result<Update> {
+    let mut parsed = SparqlParser::parse(Rule::updateUnit, input)
+        .map_err(|e| Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string())))?;
+    let top_node = parsed.next().unwrap();
+    update_unit(top_node)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ParserState {
+    pub(crate) fn new() -> Self {
+        Self {
+            literals: literal_factory(),
+            prefixes: Default::default(),
+            base: None,
+            anonymous: 0,
+        }
+    }
+
+    fn prefix(&self, prefix: &str) -> Option<&IRIRef> {
+        self.prefixes
+            .iter()
+            .rev()
+            .find(|(p, _)| p == prefix)
+            .map(|(_, namespace)| namespace)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn unexpected(fn_name: &str, pair: &Pair<'_, Rule>) -> Error {
+    error!(
+        "{} parser: unexpected {:?} in {}",
+        NAME,
+        pair.as_rule(),
+        fn_name
+    );
+    ErrorKind::ReadWrite(NAME.to_string()).into()
+}
+
+fn query_unit(input_pair: Pair<'_, Rule>) -> Result<Query> {
+    trace!("query_unit({:?})", &input_pair.as_rule());
+
+    let mut state = ParserState::new();
+    let mut query = None;
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::prologue => prologue(inner_pair, &mut state)?,
+            Rule::selectQuery => query = Some(select_query(inner_pair, &mut state)?),
+            Rule::askQuery => query = Some(ask_query(inner_pair, &mut state)?),
+            Rule::EOI => {
+                trace!("Done.")
+            }
+            _ => return Err(unexpected("query_unit", &inner_pair)),
+        }
+    }
+    let mut query = query.unwrap();
+    query.base = state.base;
+    query.prefixes = state.prefixes;
+    Ok(query)
+}
+
+fn update_unit(input_pair: Pair<'_, Rule>) -> Result<Update> {
+    trace!("update_unit({:?})", &input_pair.as_rule());
+
+    let mut state = ParserState::new();
+    let mut operations: Vec<UpdateOperation> = Default::default();
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::prologue => prologue(inner_pair, &mut state)?,
+            Rule::update1 => operations.push(update_operation(inner_pair, &mut state)?),
+            Rule::EOI => {
+                trace!("Done.")
+            }
+            _ => return Err(unexpected("update_unit", &inner_pair)),
+        }
+    }
+    let mut update = Update::new(operations);
+    update.set_prologue(state.base, state.prefixes);
+    Ok(update)
+}
+
+fn update_operation(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+) -> Result<UpdateOperation> {
+    trace!("update_operation({:?})", &input_pair.as_rule());
+
+    let inner_pair = input_pair.into_inner().next().unwrap();
+    match inner_pair.as_rule() {
+        Rule::load => {
+            let mut silent = false;
+            let mut source = None;
+            let mut into = None;
+            for inner_pair in inner_pair.into_inner() {
+                match inner_pair.as_rule() {
+                    Rule::silent => silent = true,
+                    Rule::iri => source = Some(iri(inner_pair, state)?),
+                    Rule::graphRef => into = Some(graph_ref(inner_pair, state)?),
+                    _ => return Err(unexpected("update_operation", &inner_pair)),
+                }
+            }
+            Ok(UpdateOperation::Load {
+                silent,
+                source: source.unwrap(),
+                into,
+            })
+        }
+        Rule::clear => {
+            let mut silent = false;
+            let mut target = GraphTarget::Default;
+            for inner_pair in inner_pair.into_inner() {
+                match inner_pair.as_rule() {
+                    Rule::silent => silent = true,
+                    Rule::graphRefAll => {
+                        let inner_pair = inner_pair.into_inner().next().unwrap();
+                        target = match inner_pair.as_rule() {
+                            Rule::graphRef => GraphTarget::Named(graph_ref(inner_pair, state)?),
+                            Rule::defaultGraph => GraphTarget::Default,
+                            Rule::namedGraphs => GraphTarget::AllNamed,
+                            Rule::allGraphs => GraphTarget::All,
+                            _ => return Err(unexpected("update_operation", &inner_pair)),
+                        };
+                    }
+                    _ => return Err(unexpected("update_operation", &inner_pair)),
+                }
+            }
+            Ok(UpdateOperation::Clear { silent, target })
+        }
+        Rule::insertData => {
+            let quads = quad_pattern(inner_pair.into_inner().next().unwrap(), state)?;
+            ground_quads(&quads, true)?;
+            Ok(UpdateOperation::InsertData(quads))
+        }
+        Rule::deleteData => {
+            let quads = quad_pattern(inner_pair.into_inner().next().unwrap(), state)?;
+            ground_quads(&quads, false)?;
+            Ok(UpdateOperation::DeleteData(quads))
+        }
+        Rule::deleteWhere => {
+            let quads = quad_pattern(inner_pair.into_inner().next().unwrap(), state)?;
+            no_blank_nodes(&quads)?;
+            let pattern = quads_as_pattern(&quads);
+            Ok(UpdateOperation::DeleteInsert {
+                with: None,
+                delete: quads,
+                insert: Default::default(),
+                pattern,
+            })
+        }
+        Rule::modify => {
+            let mut with = None;
+            let mut delete = Vec::default();
+            let mut insert = Vec::default();
+            let mut pattern = Pattern::default();
+            for inner_pair in inner_pair.into_inner() {
+                match inner_pair.as_rule() {
+                    Rule::iri => with = Some(iri(inner_pair, state)?),
+                    Rule::deleteClause => {
+                        delete = quad_pattern(inner_pair.into_inner().next().unwrap(), state)?;
+                        no_blank_nodes(&delete)?;
+                    }
+                    Rule::insertClause => {
+                        insert = quad_pattern(inner_pair.into_inner().next().unwrap(), state)?;
+                    }
+                    Rule::groupGraphPattern => pattern = group_graph_pattern(inner_pair, state)?,
+                    _ => return Err(unexpected("update_operation", &inner_pair)),
+                }
+            }
+            Ok(UpdateOperation::DeleteInsert {
+                with,
+                delete,
+                insert,
+                pattern,
+            })
+        }
+        _ => Err(unexpected("update_operation", &inner_pair)),
+    }
+}
+
+fn graph_ref(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("graph_ref({:?})", &input_pair.as_rule());
+
+    iri(input_pair.into_inner().next().unwrap(), state)
+}
+
+fn quad_pattern(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Vec<QuadPattern>> {
+    trace!("quad_pattern({:?})", &input_pair.as_rule());
+
+    let mut quads: Vec<QuadPattern> = Default::default();
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::triplesBlock => quads.extend(
+                triples_block(inner_pair, state)?
+                    .into_iter()
+                    .map(|triple| QuadPattern::new(None, triple)),
+            ),
+            Rule::quadsNotTriples => {
+                let mut inner_pairs = inner_pair.into_inner();
+                let graph = var_or_iri(inner_pairs.next().unwrap(), state)?;
+                if let Some(inner_pair) = inner_pairs.next() {
+                    quads.extend(
+                        triples_block(inner_pair, state)?
+                            .into_iter()
+                            .map(|triple| QuadPattern::new(Some(graph.clone()), triple)),
+                    );
+                }
+            }
+            _ => return Err(unexpected("quad_pattern", &inner_pair)),
+        }
+    }
+    Ok(quads)
+}
+
+fn quads_as_pattern(quads: &[QuadPattern]) -> Pattern {
+    let mut result = Pattern::default();
+    let mut default: Vec<TriplePattern> = Default::default();
+    for quad in quads {
+        match quad.graph() {
+            None => default.push(quad.triple().clone()),
+            Some(graph) => {
+                result = join(
+                    result,
+                    Pattern::Graph(
+                        graph.clone(),
+                        Box::new(Pattern::Bgp(vec![quad.triple().clone()])),
+                    ),
+                )
+            }
+        }
+    }
+    join(Pattern::Bgp(default), result)
+}
+
+fn ground_quads(quads: &[QuadPattern], allow_blank_nodes: bool) -> Result<()> {
+    if let Some(term) = quads
+        .iter()
+        .flat_map(|quad| quad.terms())
+        .find(|term| matches!(term, Term::Variable(_)))
+    {
+        error!("{} parser: variable {} in a DATA block", NAME, term);
+        return Err(ErrorKind::ReadWrite(NAME.to_string()).into());
+    }
+    if allow_blank_nodes {
+        Ok(())
+    } else {
+        no_blank_nodes(quads)
+    }
+}
+
+fn no_blank_nodes(quads: &[QuadPattern]) -> Result<()> {
+    if let Some(term) = quads
+        .iter()
+        .flat_map(|quad| quad.terms())
+        .find(|term| matches!(term, Term::BlankNode(_)))
+    {
+        error!("{} parser: blank node {} in a DELETE template", NAME, term);
+        return Err(ErrorKind::ReadWrite(NAME.to_string()).into());
+    }
+    Ok(())
+}
+
+pub(crate) fn prologue(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<()> {
+    trace!("prologue({:?})", &input_pair.as_rule());
+
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::baseDecl => {
+                let iri = iri_ref(inner_pair.into_inner().next().unwrap(), state)?;
+                state.base = Some(iri);
+            }
+            Rule::prefixDecl => {
+                let mut inner_pairs = inner_pair.into_inner();
+                let prefix = inner_pairs.next().unwrap().as_str();
+                let namespace = iri_ref(inner_pairs.next().unwrap(), state)?;
+                state
+                    .prefixes
+                    .push((prefix[..prefix.len() - 1].to_string(), namespace));
+            }
+            _ => return Err(unexpected("prologue", &inner_pair)),
+        }
+    }
+    Ok(())
+}
+
+fn select_query(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Query> {
+    trace!("select_query({:?})", &input_pair.as_rule());
+
+    let mut inner_pairs = input_pair.into_inner();
+
+    let mut modifier = None;
+    let mut variables: Vec<Variable> = Default::default();
+    let mut all = false;
+    for inner_pair in inner_pairs.next().unwrap().into_inner() {
+        match inner_pair.as_rule() {
+            Rule::selectModifier => {
+                modifier = Some(if inner_pair.as_str().eq_ignore_ascii_case("DISTINCT") {
+                    SelectModifier::Distinct
+                } else {
+                    SelectModifier::Reduced
+                })
+            }
+            Rule::selectAll => all = true,
+            Rule::Var => variables.push(variable(inner_pair)),
+            _ => return Err(unexpected("select_query", &inner_pair)),
+        }
+    }
+    let projection = if all {
+        Projection::All
+    } else {
+        Projection::Variables(variables)
+    };
+
+    let pattern = where_clause(inner_pairs.next().unwrap(), state)?;
+    let mut query = Query::new(
+        QueryForm::Select {
+            modifier,
+            projection,
+        },
+        pattern,
+    );
+    solution_modifier(inner_pairs.next().unwrap(), &mut query, state)?;
+    Ok(query)
+}
+
+fn ask_query(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Query> {
+    trace!("ask_query({:?})", &input_pair.as_rule());
+
+    let pattern = where_clause(input_pair.into_inner().next().unwrap(), state)?;
+    Ok(Query::new(QueryForm::Ask, pattern))
+}
+
+fn where_clause(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Pattern> {
+    trace!("where_clause({:?})", &input_pair.as_rule());
+
+    group_graph_pattern(input_pair.into_inner().next().unwrap(), state)
+}
+
+fn solution_modifier(
+    input_pair: Pair<'_, Rule>,
+    query: &mut Query,
+    state: &mut ParserState,
+) -> Result<()> {
+    trace!("solution_modifier({:?})", &input_pair.as_rule());
+
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::orderClause => {
+                for condition in inner_pair.into_inner() {
+                    query.order.push(order_condition(condition, state)?);
+                }
+            }
+            Rule::limitOffsetClauses => {
+                for clause in inner_pair.into_inner() {
+                    let rule = clause.as_rule();
+                    let value = clause.into_inner().next().unwrap().as_str();
+                    let value = usize::from_str(value).map_err(|e| {
+                        Error::with_chain(e, ErrorKind::ReadWrite(NAME.to_string()))
+                    })?;
+                    if rule == Rule::limitClause {
+                        query.limit = Some(value);
+                    } else {
+                        query.offset = Some(value);
+                    }
+                }
+            }
+            _ => return Err(unexpected("solution_modifier", &inner_pair)),
+        }
+    }
+    Ok(())
+}
+
+fn order_condition(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<OrderCondition> {
+    trace!("order_condition({:?})", &input_pair.as_rule());
+
+    let mut descending = false;
+    let mut expression = None;
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::orderDirection => {
+                descending = inner_pair.as_str().eq_ignore_ascii_case("DESC");
+            }
+            Rule::brackettedExpression => {
+                expression = Some(bracketted_expression(inner_pair, state)?);
+            }
+            Rule::Var => expression = Some(Expression::Term(Term::Variable(variable(inner_pair)))),
+            _ => return Err(unexpected("order_condition", &inner_pair)),
+        }
+    }
+    let expression = expression.unwrap();
+    Ok(if descending {
+        OrderCondition::descending(expression)
+    } else {
+        OrderCondition::ascending(expression)
+    })
+}
+
+fn group_graph_pattern(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Pattern> {
+    trace!("group_graph_pattern({:?})", &input_pair.as_rule());
+
+    let mut result = Pattern::default();
+    let mut filters: Vec<Expression> = Default::default();
+    for inner_pair in input_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::triplesBlock => {
+                result = join(result, Pattern::Bgp(triples_block(inner_pair, state)?));
+            }
+            Rule::graphPatternNotTriples => {
+                let inner_pair = inner_pair.into_inner().next().unwrap();
+                match inner_pair.as_rule() {
+                    Rule::optionalGraphPattern => {
+                        let optional =
+                            group_graph_pattern(inner_pair.into_inner().next().unwrap(), state)?;
+                        result = match optional {
+                            Pattern::Filter(expression, optional) => {
+                                Pattern::LeftJoin(Box::new(result), optional, Some(expression))
+                            }
+                            optional => {
+                                Pattern::LeftJoin(Box::new(result), Box::new(optional), None)
+                            }
+                        };
+                    }
+                    Rule::groupOrUnionGraphPattern => {
+                        let mut union: Option<Pattern> = None;
+                        for group in inner_pair.into_inner() {
+                            let group = group_graph_pattern(group, state)?;
+                            union = Some(match union {
+                                None => group,
+                                Some(lhs) => Pattern::Union(Box::new(lhs), Box::new(group)),
+                            });
+                        }
+                        result = join(result, union.unwrap());
+                    }
+                    Rule::graphGraphPattern => {
+                        let mut inner_pairs = inner_pair.into_inner();
+                        let name = var_or_iri(inner_pairs.next().unwrap(), state)?;
+                        let group = group_graph_pattern(inner_pairs.next().unwrap(), state)?;
+                        result = join(result, Pattern::Graph(name, Box::new(group)));
+                    }
+                    Rule::filter => {
+                        let constraint = inner_pair.into_inner().next().unwrap();
+                        filters.push(match constraint.as_rule() {
+                            Rule::brackettedExpression => bracketted_expression(constraint, state)?,
+                            _ => built_in_call(constraint, state)?,
+                        });
+                    }
+                    _ => return Err(unexpected("group_graph_pattern", &inner_pair)),
+                }
+            }
+            _ => return Err(unexpected("group_graph_pattern", &inner_pair)),
+        }
+    }
+    Ok(
+        match filters.into_iter().reduce(|lhs, rhs| {
+            Expression::Binary(BinaryOperator::And, Box::new(lhs), Box::new(rhs))
+        }) {
+            None => result,
+            Some(expression) => Pattern::Filter(expression, Box::new(result)),
+        },
+    )
+}
+
+fn join(lhs: Pattern, rhs: Pattern) -> Pattern {
+    match (lhs, rhs) {
+        (lhs, rhs) if lhs.is_empty() => rhs,
+        (lhs, rhs) if rhs.is_empty() => lhs,
+        (Pattern::Bgp(mut lhs), Pattern::Bgp(rhs)) => {
+            lhs.extend(rhs);
+            Pattern::Bgp(lhs)
+        }
+        (lhs, rhs) => Pattern::Join(Box::new(lhs), Box::new(rhs)),
+    }
+}
+
+fn triples_block(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+) -> Result<Vec<TriplePattern>> {
+    trace!("triples_block({:?})", &input_pair.as_rule());
+
+    let mut triples: Vec<TriplePattern> = Default::default();
+    for inner_pair in input_pair.into_inner() {
+        let mut inner_pairs = inner_pair.into_inner();
+        let subject = var_or_term(inner_pairs.next().unwrap(), state)?;
+        let mut property_list = inner_pairs.next().unwrap().into_inner();
+        while let Some(verb_pair) = property_list.next() {
+            let predicate = verb(verb_pair, state)?;
+            for object in property_list.next().unwrap().into_inner() {
+                triples.push(TriplePattern::new(
+                    subject.clone(),
+                    predicate.clone(),
+                    var_or_term(object, state)?,
+                ));
+            }
+        }
+    }
+    Ok(triples)
+}
+
+pub(crate) fn verb(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Term> {
+    trace!("verb({:?})", &input_pair.as_rule());
+
+    let inner_pair = input_pair.into_inner().next().unwrap();
+    match inner_pair.as_rule() {
+        Rule::rdfType => Ok(Term::Iri(rdf::a_type().clone())),
+        Rule::varOrIri => var_or_iri(inner_pair, state),
+        _ => Err(unexpected("verb", &inner_pair)),
+    }
+}
+
+fn var_or_iri(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Term> {
+    trace!("var_or_iri({:?})", &input_pair.as_rule());
+
+    let inner_pair = input_pair.into_inner().next().unwrap();
+    match inner_pair.as_rule() {
+        Rule::Var => Ok(Term::Variable(variable(inner_pair))),
+        _ => Ok(Term::Iri(iri(inner_pair, state)?)),
+    }
+}
+
+pub(crate) fn var_or_term(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Term> {
+    trace!("var_or_term({:?})", &input_pair.as_rule());
+
+    let inner_pair = input_pair.into_inner().next().unwrap();
+    match inner_pair.as_rule() {
+        Rule::Var => Ok(Term::Variable(variable(inner_pair))),
+        Rule::graphTerm => graph_term(inner_pair.into_inner().next().unwrap(), state),
+        _ => Err(unexpected("var_or_term", &inner_pair)),
+    }
+}
+
+fn graph_term(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Term> {
+    trace!("graph_term({:?})", &input_pair.as_rule());
+
+    match input_pair.as_rule() {
+        Rule::iri => Ok(Term::Iri(iri(input_pair, state)?)),
+        Rule::rdfLiteral => Ok(Term::Literal(rdf_literal(input_pair, state)?)),
+        Rule::numericLiteral => Ok(Term::Literal(numeric_literal(input_pair, state)?)),
+        Rule::booleanLiteral => Ok(Term::Literal(boolean_literal(input_pair, state))),
+        Rule::blankNode => {
+            let inner_pair = input_pair.into_inner().next().unwrap();
+            match inner_pair.as_rule() {
+                Rule::BLANK_NODE_LABEL => Ok(Term::BlankNode(inner_pair.as_str()[2..].to_string())),
+                _ => {
+                    // a leading '.' is not allowed in a label, so these names cannot clash.
+                    state.anonymous += 1;
+                    Ok(Term::BlankNode(format!(".anon{}", state.anonymous)))
+                }
+            }
+        }
+        _ => Err(unexpected("graph_term", &input_pair)),
+    }
+}
+
+pub(crate) fn variable(input_pair: Pair<'_, Rule>) -> Variable {
+    // strip the leading '?' or '$'.
+    input_pair.as_str()[1..].to_string()
+}
+
+pub(crate) fn expression(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+) -> Result<Expression> {
+    trace!("expression({:?})", &input_pair.as_rule());
+
+    match input_pair.as_rule() {
+        Rule::expression | Rule::conditionalAndExpression => {
+            let operator = if input_pair.as_rule() == Rule::expression {
+                BinaryOperator::Or
+            } else {
+                BinaryOperator::And
+            };
+            let mut operands = input_pair.into_inner();
+            let mut result = expression(operands.next().unwrap(), state)?;
+            for operand in operands {
+                result = Expression::Binary(
+                    operator,
+                    Box::new(result),
+                    Box::new(expression(operand, state)?),
+                );
+            }
+            Ok(result)
+        }
+        Rule::relationalExpression | Rule::additiveExpression | Rule::multiplicativeExpression => {
+            let mut inner_pairs = input_pair.into_inner();
+            let mut result = expression(inner_pairs.next().unwrap(), state)?;
+            while let Some(operator) = inner_pairs.next() {
+                let operator = match operator.as_str() {
+                    "=" => BinaryOperator::Equal,
+                    "!=" => BinaryOperator::NotEqual,
+                    "<" => BinaryOperator::Less,
+                    "<=" => BinaryOperator::LessOrEqual,
+                    ">" => BinaryOperator::Greater,
+                    ">=" => BinaryOperator::GreaterOrEqual,
+                    "+" => BinaryOperator::Add,
+                    "-" => BinaryOperator::Subtract,
+                    "*" => BinaryOperator::Multiply,
+                    "/" => BinaryOperator::Divide,
+                    _ => return Err(unexpected("expression", &operator)),
+                };
+                let rhs = expression(inner_pairs.next().unwrap(), state)?;
+                result = Expression::Binary(operator, Box::new(result), Box::new(rhs));
+            }
+            Ok(result)
+        }
+        Rule::unaryExpression => {
+            let mut inner_pairs = input_pair.into_inner();
+            let first = inner_pairs.next().unwrap();
+            if first.as_rule() == Rule::unaryOperator {
+                let operator = match first.as_str() {
+                    "!" => UnaryOperator::Not,
+                    "+" => UnaryOperator::Plus,
+                    _ => UnaryOperator::Minus,
+                };
+                let operand = expression(inner_pairs.next().unwrap(), state)?;
+                Ok(Expression::Unary(operator, Box::new(operand)))
+            } else {
+                expression(first, state)
+            }
+        }
+        Rule::primaryExpression => {
+            let inner_pair = input_pair.into_inner().next().unwrap();
+            match inner_pair.as_rule() {
+                Rule::brackettedExpression => bracketted_expression(inner_pair, state),
+                Rule::builtInCall => built_in_call(inner_pair, state),
+                Rule::Var => Ok(Expression::Term(Term::Variable(variable(inner_pair)))),
+                _ => Ok(Expression::Term(graph_term(inner_pair, state)?)),
+            }
+        }
+        _ => Err(unexpected("expression", &input_pair)),
+    }
+}
+
+fn bracketted_expression(
+    input_pair: Pair<'_, Rule>,
+    state: &mut ParserState,
+) -> Result<Expression> {
+    trace!("bracketted_expression({:?})", &input_pair.as_rule());
+
+    expression(input_pair.into_inner().next().unwrap(), state)
+}
+
+fn built_in_call(input_pair: Pair<'_, Rule>, state: &mut ParserState) -> Result<Expression> {
+    trace!("built_in_call({:?})", &input_pair.as_rule());
+
+    let mut inner_pairs = input_pair.into_inner();
+    let name = inner_pairs.next().unwrap();
+    let function = match name.as_str().to_uppercase().as_str() {
+        "BOUND" => Function::Bound,
+        "ISIRI" | "ISURI" => Function::IsIri,
+        "ISBLANK" => Function::IsBlank,
+        "ISLITERAL" => Function::IsLiteral,
+        "STR" => Function::Str,
+        "LANG" => Function::Lang,
+        "DATATYPE" => Function::Datatype,
+        "LANGMATCHES" => Function::LangMatches,
+        "REGEX" => Function::Regex,
+        "SAMETERM" => Function::SameTerm,
+        "CONTAINS" => Function::Contains,
+        "STRSTARTS" => Function::StrStarts,
+        "STRENDS" => Function::StrEnds,
+        "STRLEN" => Function::StrLen,
+        "UCASE" => Function::UCase,
+        "LCASE" => Function::LCase,
+        _ => return Err(unexpected("built_in_call", &name)),
+    };
+    let arguments = inner_pairs
+        .map(|argument| expression(argument, state))
+        .collect::<Result<Vec<Expression>>>()?;
+    let (min, max) = function.arity();
+    if arguments.len() < min || arguments.len() > max {
+        error!(
+            "{} parser: function {:?} called with {} arguments",
+            NAME,
+            function,
+            arguments.len()
+        );
+        return Err(ErrorKind::ReadWrite(NAME.to_string()).into());
+    }
+    if function == Function::Bound && !matches!(arguments[0], Expression::Term(Term::Variable(_))) {
+        error!("{} parser: BOUND requires a variable argument", NAME);
+        return Err(ErrorKind::ReadWrite(NAME.to_string()).into());
+    }
+    Ok(Expression::Function(function, arguments))
+}
+
+pub(crate) fn iri(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("iri({:?})", &input_pair.as_rule());
+
+    let inner_pair = input_pair.into_inner().next().unwrap();
+    match inner_pair.as_rule() {
+        Rule::IRIREF => iri_ref(inner_pair, state),
+        Rule::prefixedName => prefixed_name(inner_pair.into_inner().next().unwrap(), state),
+        _ => Err(unexpected("iri", &inner_pair)),
+    }
+}
+
+fn iri_ref(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("iri_ref({:?})", &input_pair.as_rule());
+
+    let iri = input_pair.as_str();
+    // strip the '<' and '>' characters.
+    let iri_str = unescape_string(&iri[1..iri.len() - 1]);
+    let iri = IRI::from_str(&iri_str)?;
+    if iri.has_scheme() {
+        Ok(IRIRef::new(iri))
+    } else {
+        match &state.base {
+            None => Err(ErrorKind::AbsoluteIriExpected(iri_str).into()),
+            Some(base) => Ok(IRIRef::new(IRI::from_str(&resolve(
+                &base.to_string(),
+                &iri_str,
+            ))?)),
+        }
+    }
+}
+
+fn prefixed_name(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<IRIRef> {
+    trace!("prefixed_name({:?})", &input_pair.as_rule());
+
+    let qname = input_pair.as_str();
+    let (prefix, local) = qname.split_at(qname.find(':').unwrap());
+    match state.prefix(prefix) {
+        None => Err(ErrorKind::InvalidQName(qname.to_string()).into()),
+        Some(namespace) => Ok(IRIRef::new(IRI::from_str(&format!(
+            "{}{}",
+            namespace,
+            LOCAL_ESC.replace_all(&local[1..], "$1")
+        ))?)),
+    }
+}
+
+fn rdf_literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<LiteralRef> {
+    trace!("rdf_literal({:?})", &input_pair.as_rule());
+
+    let mut inner_pairs = input_pair.into_inner();
+    let string_pair = inner_pairs.next().unwrap().into_inner().next().unwrap();
+    let lexical_form = unescape_string(string_pair.into_inner().next().unwrap().as_str());
+
+    match inner_pairs.next() {
+        None => Ok(state.literals.literal(&lexical_form)),
+        Some(other) => match other.as_rule() {
+            Rule::iri => {
+                let data_type = DataType::Other(iri(other, state)?);
+                Ok(state.literals.with_data_type(&lexical_form, data_type))
+            }
+            Rule::LANGTAG => {
+                // strip the leading '@'
+                let lang_tag = LanguageTag::from_str(&other.as_str()[1..])?;
+                Ok(state.literals.with_language(&lexical_form, lang_tag))
+            }
+            _ => Err(unexpected("rdf_literal", &other)),
+        },
+    }
+}
+
+fn numeric_literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> Result<LiteralRef> {
+    trace!("numeric_literal({:?})", &input_pair.as_rule());
+
+    let lexical_form = input_pair.as_str();
+    let data_type = match input_pair.into_inner().next().unwrap().as_rule() {
+        Rule::INTEGER => xsd::integer(),
+        Rule::DECIMAL => xsd::decimal(),
+        _ => xsd::double(),
+    };
+    Ok(state
+        .literals
+        .with_data_type(lexical_form, DataType::Other(data_type.clone())))
+}
+
+fn boolean_literal(input_pair: Pair<'_, Rule>, state: &ParserState) -> LiteralRef {
+    trace!("boolean_literal({:?})", &input_pair.as_rule());
+
+    state.literals.boolean(input_pair.as_str() == "true")
+}
+
+pub(crate) fn unescape_string(value: &str) -> String {
+    STRING_ESC
+        .replace_all(value, |caps: &regex::Captures<'_>| {
+            let escape = &caps[0];
+            match escape {
+                "\\t" => '\t',
+                "\\b" => '\u{0008}',
+                "\\n" => '\n',
+                "\\r" => '\r',
+                "\\f" => '\u{000C}',
+                "\\\"" => '"',
+                "\\'" => '\'',
+                "\\\\" => '\\',
+                _ => u32::from_str_radix(&escape[2..], 16)
+                    .ok()
+                    .and_then(std::char::from_u32)
+                    .unwrap_or(std::char::REPLACEMENT_CHARACTER),
+            }
+            .to_string()
+        })
+        .to_string()
+}
+
+fn resolve(base: &str, relative: &str) -> String {
+    let without_fragment = base.split('#').next().unwrap();
+    if relative.starts_with('#') {
+        format!("{}{}", without_fragment, relative)
+    } else if let Some(relative) = relative.strip_prefix('/') {
+        let scheme_end = without_fragment.find(':').map(|i| i + 1).unwrap_or(0);
+        let (scheme, rest) = without_fragment.split_at(scheme_end);
+        let authority = match rest.strip_prefix("//") {
+            Some(rest) => format!("//{}", &rest[..rest.find('/').unwrap_or(rest.len())]),
+            None => String::new(),
+        };
+        format!("{}{}/{}", scheme, authority, relative)
+    } else {
+        let without_query = without_fragment.split('?').next().unwrap();
+        match without_query.rfind('/') {
+            Some(index) if index > without_query.find("//").map(|i| i + 1).unwrap_or(0) => {
+                format!("{}{}", &without_query[..=index], relative)
+            }
+            _ => format!("{}/{}", without_query, relative),
+        }
+    }
+}
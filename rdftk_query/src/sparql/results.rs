@@ -0,0 +1,244 @@
+/*!
+The results of evaluating a query; a sequence of solutions for `SELECT`, or a boolean for `ASK`.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_query::sparql::results::QueryResults;
+use rdftk_query::sparql::Query;
+use std::str::FromStr;
+
+let graph = graph_factory().graph();
+let query = Query::from_str("SELECT ?s WHERE { ?s ?p ?o }").unwrap();
+
+match query.execute(&graph).unwrap() {
+    QueryResults::Solutions(solutions) => {
+        assert_eq!(solutions.variables(), &["s".to_string()]);
+        assert!(solutions.is_empty());
+    }
+    QueryResults::Boolean(_) => unreachable!(),
+}
+```
+
+*/
+
+use crate::sparql::algebra::Variable;
+use rdftk_core::model::statement::ObjectNodeRef;
+use std::collections::HashMap;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The results of evaluating a query.
+///
+#[derive(Clone, Debug)]
+pub enum QueryResults {
+    /// The solutions to a `SELECT` query.
+    Solutions(Solutions),
+    /// The answer to an `ASK` query.
+    Boolean(bool),
+}
+
+///
+/// The ordered sequence of solutions to a `SELECT` query, and the variables projected into
+/// each of them.
+///
+#[derive(Clone, Debug, Default)]
+pub struct Solutions {
+    variables: Vec<Variable>,
+    rows: Vec<Solution>,
+}
+
+///
+/// A single solution, mapping variables to the graph nodes they are bound to. A projected
+/// variable that was not bound, for example by an `OPTIONAL` pattern, has no value.
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Solution {
+    values: HashMap<Variable, ObjectNodeRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl From<Solutions> for QueryResults {
+    fn from(solutions: Solutions) -> Self {
+        Self::Solutions(solutions)
+    }
+}
+
+impl From<bool> for QueryResults {
+    fn from(answer: bool) -> Self {
+        Self::Boolean(answer)
+    }
+}
+
+impl QueryResults {
+    ///
+    /// Return the solutions, if these are the results of a `SELECT` query.
+    ///
+    pub fn as_solutions(&self) -> Option<&Solutions> {
+        match self {
+            Self::Solutions(solutions) => Some(solutions),
+            Self::Boolean(_) => None,
+        }
+    }
+
+    ///
+    /// Return the answer, if these are the results of an `ASK` query.
+    ///
+    pub fn as_boolean(&self) -> Option<bool> {
+        match self {
+            Self::Solutions(_) => None,
+            Self::Boolean(answer) => Some(*answer),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl IntoIterator for Solutions {
+    type Item = Solution;
+    type IntoIter = std::vec::IntoIter<Solution>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Solutions {
+    type Item = &'a Solution;
+    type IntoIter = std::slice::Iter<'a, Solution>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.iter()
+    }
+}
+
+impl Solutions {
+    pub(crate) fn new(variables: Vec<Variable>, rows: Vec<Solution>) -> Self {
+        Self { variables, rows }
+    }
+
+    ///
+    /// Return the projected variables, in the order given by the query.
+    ///
+    pub fn variables(&self) -> &[Variable] {
+        &self.variables
+    }
+
+    ///
+    /// Returns `true` if there are no solutions.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    ///
+    /// Return the number of solutions.
+    ///
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    ///
+    /// Return an iterator over the solutions, in order.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &Solution> {
+        self.rows.iter()
+    }
+
+    ///
+    /// Return the values of `variable` in each solution, in order.
+    ///
+    pub fn column(&self, variable: &str) -> Vec<Option<&ObjectNodeRef>> {
+        self.rows.iter().map(|row| row.get(variable)).collect()
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Solution {
+    ///
+    /// Return the value bound to `variable`, if any.
+    ///
+    pub fn get(&self, variable: &str) -> Option<&ObjectNodeRef> {
+        self.values.get(variable)
+    }
+
+    ///
+    /// Returns `true` if `variable` is bound in this solution.
+    ///
+    pub fn is_bound(&self, variable: &str) -> bool {
+        self.values.contains_key(variable)
+    }
+
+    ///
+    /// Returns `true` if no variables are bound in this solution.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    ///
+    /// Return the number of variables bound in this solution.
+    ///
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    ///
+    /// Return an iterator over the bound variables and their values.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = (&Variable, &ObjectNodeRef)> {
+        self.values.iter()
+    }
+
+    ///
+    /// Bind `variable` to `value`; returns `false` if the variable is already bound to a
+    /// different value.
+    ///
+    pub(crate) fn bind(&mut self, variable: String, value: ObjectNodeRef) -> bool {
+        match self.values.get(&variable) {
+            Some(existing) => existing == &value,
+            None => {
+                let _ = self.values.insert(variable, value);
+                true
+            }
+        }
+    }
+
+    ///
+    /// Return the combination of this solution and `other`, if they are compatible; that is, if
+    /// every variable bound in both has the same value.
+    ///
+    pub(crate) fn merge(&self, other: &Solution) -> Option<Solution> {
+        let mut merged = self.clone();
+        for (variable, value) in &other.values {
+            if !merged.bind(variable.clone(), value.clone()) {
+                return None;
+            }
+        }
+        Some(merged)
+    }
+
+    ///
+    /// Return a solution with only the bindings for `variables`.
+    ///
+    pub(crate) fn project(&self, variables: &[Variable]) -> Solution {
+        Self {
+            values: variables
+                .iter()
+                .filter_map(|variable| {
+                    self.values
+                        .get(variable)
+                        .map(|value| (variable.clone(), value.clone()))
+                })
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,623 @@
+/*!
+Support for the [SPARQL 1.1 Update](https://www.w3.org/TR/sparql11-update/) language, applied to
+a single graph or to the graphs of a data set.
+
+An update request is parsed into a sequence of `UpdateOperation`s, sharing the `BASE` and
+`PREFIX` declarations of a query. The supported operations are `INSERT DATA`, `DELETE DATA`,
+`DELETE WHERE`, `DELETE`/`INSERT` with an optional `WITH` graph and a `WHERE` clause that is any
+graph pattern a query supports, `LOAD`, and `CLEAR`; the `USING` clause is not supported.
+
+Operations are evaluated in order, each against the graphs as left by the operations before it,
+and the changes made by each are returned as a `DataSetChangeSet` so that they may be logged,
+inverted, or written as an RDF Patch. The operations are evaluated against a staged copy of the
+graph, or data set, and their changes collected into a single change set that is applied only once
+every operation has succeeded; if any operation fails the graph, or data set, is left unchanged.
+An operation that
+refers to a named graph fails when applied to a single graph, except that `CLEAR NAMED` has
+nothing to clear; applied to a data set, a named graph is created when statements are inserted
+into it. `LOAD` reads a document using a `GraphLoader` provided by the application, as this crate
+does not itself retrieve or parse documents. The `SILENT` form of `LOAD` and `CLEAR` ignores any
+failure.
+
+# Example
+
+```rust
+use rdftk_core::model::graph::Graph;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_query::sparql::update::Update;
+use std::str::FromStr;
+
+let graph = graph_factory().graph();
+let update = Update::from_str(
+    r#"PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+       INSERT DATA { <http://example.org/alice> foaf:name "Alice" ; foaf:nick "Al" } ;
+       DELETE { ?person foaf:nick ?nick } INSERT { ?person foaf:givenName ?nick }
+       WHERE { ?person foaf:nick ?nick }"#,
+)
+.unwrap();
+assert_eq!(update.operations().len(), 2);
+
+let changes = update.apply(&mut *graph.borrow_mut(), None).unwrap();
+assert_eq!(changes[0].len(), 2);
+assert_eq!(changes[1].len(), 2);
+
+let graph = graph.borrow();
+assert_eq!(graph.len(), 2);
+assert!(graph
+    .statements()
+    .any(|statement| statement.predicate().to_string() == "http://xmlns.com/foaf/0.1/givenName"));
+```
+
+*/
+
+use crate::sparql::algebra::{Pattern, Projection, QueryForm, Term, TriplePattern};
+use crate::sparql::results::{QueryResults, Solution};
+use crate::sparql::{Query, NAME};
+use rdftk_core::error::{Error, ErrorKind, Result};
+use rdftk_core::model::data_set::change_set::DataSetChangeSet;
+use rdftk_core::model::data_set::{DataSet, DataSetRef, GraphName, GraphNameRef};
+use rdftk_core::model::graph::{Graph, GraphFactoryRef, GraphRef};
+use rdftk_core::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef,
+};
+use rdftk_iri::IRIRef;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// A parsed update request, a sequence of operations to apply in order.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Update {
+    base: Option<IRIRef>,
+    prefixes: Vec<(String, IRIRef)>,
+    operations: Vec<UpdateOperation>,
+}
+
+///
+/// A triple pattern within a named graph, or within the default graph if it has no graph term.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuadPattern {
+    graph: Option<Term>,
+    triple: TriplePattern,
+}
+
+///
+/// The graphs affected by a `CLEAR` operation.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphTarget {
+    /// `DEFAULT`, the default graph.
+    Default,
+    /// `GRAPH iri`, the named graph.
+    Named(IRIRef),
+    /// `NAMED`, all the named graphs.
+    AllNamed,
+    /// `ALL`, the default graph and all the named graphs.
+    All,
+}
+
+///
+/// A single operation within an update request.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum UpdateOperation {
+    /// `INSERT DATA`, add the statements, which contain no variables.
+    InsertData(Vec<QuadPattern>),
+    /// `DELETE DATA`, remove the statements, which contain no variables or blank nodes.
+    DeleteData(Vec<QuadPattern>),
+    /// `DELETE`/`INSERT`, for each solution of the pattern remove the statements of the delete
+    /// templates and add those of the insert templates. `DELETE WHERE` is parsed into this form,
+    /// with the same quads as both the pattern and the delete templates.
+    DeleteInsert {
+        /// The graph used in place of the default graph, from `WITH`.
+        with: Option<IRIRef>,
+        /// The templates of the statements to remove.
+        delete: Vec<QuadPattern>,
+        /// The templates of the statements to add.
+        insert: Vec<QuadPattern>,
+        /// The pattern whose solutions instantiate the templates.
+        pattern: Pattern,
+    },
+    /// `LOAD`, add the statements of the document `source` to the default graph, or to the
+    /// graph `into`.
+    Load {
+        /// Ignore any failure to load the document.
+        silent: bool,
+        /// The IRI of the document to load.
+        source: IRIRef,
+        /// The named graph to load into, if any.
+        into: Option<IRIRef>,
+    },
+    /// `CLEAR`, remove all the statements in the target graphs.
+    Clear {
+        /// Ignore a target graph that does not exist.
+        silent: bool,
+        /// The graphs to clear.
+        target: GraphTarget,
+    },
+}
+
+///
+/// Loads the document identified by an IRI, for the `LOAD` operation, into a new graph created
+/// by `factory`. This is implemented for any closure with the same signature as `load`.
+///
+pub trait GraphLoader {
+    ///
+    /// Return a new graph containing the statements of the document `source`.
+    ///
+    fn load(&self, source: &IRIRef, factory: &GraphFactoryRef) -> Result<GraphRef>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+trait Store {
+    fn evaluate(&self, query: &Query) -> Result<QueryResults>;
+    fn statements(&self, name: Option<&GraphNameRef>) -> Option<StatementList>;
+    fn named_graphs(&self) -> Vec<GraphNameRef>;
+    fn graph_factory(&self) -> GraphFactoryRef;
+    fn statement_factory(&self) -> StatementFactoryRef;
+    fn apply(&mut self, changes: &DataSetChangeSet) -> Result<()>;
+}
+
+struct GraphStore<'a>(&'a mut dyn Graph);
+
+struct DataSetStore<'a>(&'a mut dyn DataSet);
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl FromStr for Update {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        super::parser::parse_update(s)
+    }
+}
+
+impl Update {
+    ///
+    /// Construct a new update request from its operations.
+    ///
+    pub fn new(operations: Vec<UpdateOperation>) -> Self {
+        Self {
+            base: None,
+            prefixes: Default::default(),
+            operations,
+        }
+    }
+
+    pub(crate) fn set_prologue(&mut self, base: Option<IRIRef>, prefixes: Vec<(String, IRIRef)>) {
+        self.base = base;
+        self.prefixes = prefixes;
+    }
+
+    ///
+    /// Return the base IRI declared in the request, if any.
+    ///
+    pub fn base(&self) -> Option<&IRIRef> {
+        self.base.as_ref()
+    }
+
+    ///
+    /// Return the prefixes declared in the request, in order.
+    ///
+    pub fn prefixes(&self) -> impl Iterator<Item = (&String, &IRIRef)> {
+        self.prefixes
+            .iter()
+            .map(|(prefix, namespace)| (prefix, namespace))
+    }
+
+    ///
+    /// Return the operations of this request, in order.
+    ///
+    pub fn operations(&self) -> &[UpdateOperation] {
+        &self.operations
+    }
+
+    ///
+    /// Apply all operations to `graph`, returning the changes made by each. If any operation
+    /// fails `graph` is unchanged. The `loader` is required only by `LOAD` operations.
+    ///
+    pub fn apply(
+        &self,
+        graph: &mut dyn Graph,
+        loader: Option<&dyn GraphLoader>,
+    ) -> Result<Vec<DataSetChangeSet>> {
+        let staged = copy_graph(graph);
+        let results = self.stage(&mut GraphStore(&mut *staged.borrow_mut()), loader)?;
+        GraphStore(graph).apply(&combine(&results))?;
+        Ok(results)
+    }
+
+    ///
+    /// Apply all operations to `data_set`, returning the changes made by each. If any operation
+    /// fails, or any graph to be changed is borrowed elsewhere, `data_set` is unchanged. The
+    /// `loader` is required only by `LOAD` operations.
+    ///
+    pub fn apply_data_set(
+        &self,
+        data_set: &mut dyn DataSet,
+        loader: Option<&dyn GraphLoader>,
+    ) -> Result<Vec<DataSetChangeSet>> {
+        let staged = copy_data_set(data_set);
+        let results = self.stage(&mut DataSetStore(&mut *staged.borrow_mut()), loader)?;
+        DataSetStore(data_set).apply(&combine(&results))?;
+        Ok(results)
+    }
+
+    ///
+    /// Apply each operation to the staged copy `store`, so that each sees the changes of those
+    /// before it, returning the changes made by each.
+    ///
+    fn stage(
+        &self,
+        store: &mut dyn Store,
+        loader: Option<&dyn GraphLoader>,
+    ) -> Result<Vec<DataSetChangeSet>> {
+        let mut results: Vec<DataSetChangeSet> = Default::default();
+        for operation in &self.operations {
+            let changes = operation.changes(store, loader)?;
+            store.apply(&changes)?;
+            results.push(changes);
+        }
+        Ok(results)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for QuadPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.graph {
+            None => write!(f, "{}", self.triple),
+            Some(graph) => write!(f, "GRAPH {} {{ {} }}", graph, self.triple),
+        }
+    }
+}
+
+impl QuadPattern {
+    ///
+    /// Construct a new quad pattern; `graph` is `None` for the default graph.
+    ///
+    pub fn new(graph: Option<Term>, triple: TriplePattern) -> Self {
+        Self { graph, triple }
+    }
+
+    ///
+    /// Return the graph term of this pattern, or `None` for the default graph.
+    ///
+    pub fn graph(&self) -> Option<&Term> {
+        self.graph.as_ref()
+    }
+
+    ///
+    /// Return the triple pattern.
+    ///
+    pub fn triple(&self) -> &TriplePattern {
+        &self.triple
+    }
+
+    ///
+    /// Return an iterator over the terms of this pattern, the graph term first if present.
+    ///
+    pub fn terms(&self) -> impl Iterator<Item = &Term> {
+        self.graph.iter().chain(self.triple.terms())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl UpdateOperation {
+    fn changes(
+        &self,
+        store: &dyn Store,
+        loader: Option<&dyn GraphLoader>,
+    ) -> Result<DataSetChangeSet> {
+        let mut changes = DataSetChangeSet::default();
+        match self {
+            Self::InsertData(quads) => {
+                let mut templates = Templates::new(store.statement_factory(), None);
+                for (graph, statement) in templates.instantiate(quads, &Solution::default()) {
+                    let _ = changes.add(graph.as_ref(), statement);
+                }
+            }
+            Self::DeleteData(quads) => {
+                let mut templates = Templates::new(store.statement_factory(), None);
+                for (graph, statement) in templates.instantiate(quads, &Solution::default()) {
+                    let _ = changes.remove(graph.as_ref(), statement);
+                }
+            }
+            Self::DeleteInsert {
+                with,
+                delete,
+                insert,
+                pattern,
+            } => {
+                let pattern = match with {
+                    None => pattern.clone(),
+                    Some(with) => {
+                        Pattern::Graph(Term::Iri(with.clone()), Box::new(pattern.clone()))
+                    }
+                };
+                let query = Query::new(
+                    QueryForm::Select {
+                        modifier: None,
+                        projection: Projection::All,
+                    },
+                    pattern,
+                );
+                let results = store.evaluate(&query)?;
+                let solutions = results.as_solutions().unwrap();
+                let mut templates = Templates::new(store.statement_factory(), with.as_ref());
+                // all statements are deleted before any are inserted.
+                for solution in solutions.iter() {
+                    for (graph, statement) in templates.instantiate(delete, solution) {
+                        let _ = changes.remove(graph.as_ref(), statement);
+                    }
+                }
+                for solution in solutions.iter() {
+                    templates.fresh_blank_nodes();
+                    for (graph, statement) in templates.instantiate(insert, solution) {
+                        let _ = changes.add(graph.as_ref(), statement);
+                    }
+                }
+            }
+            Self::Load {
+                silent,
+                source,
+                into,
+            } => {
+                let loaded = match loader {
+                    None => Err(ErrorKind::OperationNotSupported(
+                        "LOAD".to_string(),
+                        NAME.to_string(),
+                    )
+                    .into()),
+                    Some(loader) => loader.load(source, &store.graph_factory()),
+                };
+                match loaded {
+                    Ok(graph) => {
+                        let into = into.as_ref().map(|into| GraphName::named_ref(into.clone()));
+                        for statement in graph.borrow().statements() {
+                            let _ = changes.add(into.as_ref(), statement.clone());
+                        }
+                    }
+                    Err(e) if !silent => return Err(e),
+                    Err(_) => {}
+                }
+            }
+            Self::Clear { silent, target } => {
+                let names: Vec<Option<GraphNameRef>> = match target {
+                    GraphTarget::Default => vec![None],
+                    GraphTarget::Named(name) => {
+                        let name = GraphName::named_ref(name.clone());
+                        if store.statements(Some(&name)).is_none() {
+                            if *silent {
+                                return Ok(changes);
+                            }
+                            return Err(ErrorKind::UnknownGraph(name.to_string()).into());
+                        }
+                        vec![Some(name)]
+                    }
+                    GraphTarget::AllNamed => store.named_graphs().into_iter().map(Some).collect(),
+                    GraphTarget::All => std::iter::once(None)
+                        .chain(store.named_graphs().into_iter().map(Some))
+                        .collect(),
+                };
+                for name in names {
+                    for statement in store.statements(name.as_ref()).unwrap_or_default() {
+                        let _ = changes.remove(name.as_ref(), statement);
+                    }
+                }
+            }
+        }
+        Ok(changes)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<F> GraphLoader for F
+where
+    F: Fn(&IRIRef, &GraphFactoryRef) -> Result<GraphRef>,
+{
+    fn load(&self, source: &IRIRef, factory: &GraphFactoryRef) -> Result<GraphRef> {
+        self(source, factory)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Store for GraphStore<'_> {
+    fn evaluate(&self, query: &Query) -> Result<QueryResults> {
+        query.evaluate(self.0)
+    }
+
+    fn statements(&self, name: Option<&GraphNameRef>) -> Option<StatementList> {
+        match name {
+            None => Some(self.0.statements().cloned().collect()),
+            Some(_) => None,
+        }
+    }
+
+    fn named_graphs(&self) -> Vec<GraphNameRef> {
+        Default::default()
+    }
+
+    fn graph_factory(&self) -> GraphFactoryRef {
+        self.0.factory()
+    }
+
+    fn statement_factory(&self) -> StatementFactoryRef {
+        self.0.statement_factory()
+    }
+
+    fn apply(&mut self, changes: &DataSetChangeSet) -> Result<()> {
+        if let Some((Some(name), _)) = changes
+            .graphs()
+            .find(|(name, changes)| name.is_some() && !changes.is_empty())
+        {
+            return Err(ErrorKind::UnknownGraph(name.to_string()).into());
+        }
+        for (_, changes) in changes.graphs() {
+            changes.apply(self.0);
+        }
+        Ok(())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Store for DataSetStore<'_> {
+    fn evaluate(&self, query: &Query) -> Result<QueryResults> {
+        query.evaluate_data_set(self.0, false)
+    }
+
+    fn statements(&self, name: Option<&GraphNameRef>) -> Option<StatementList> {
+        let graph = match name {
+            None => self.0.default_graph(),
+            Some(name) => self.0.graph_named(name),
+        };
+        graph.map(|graph| graph.borrow().statements().cloned().collect())
+    }
+
+    fn named_graphs(&self) -> Vec<GraphNameRef> {
+        self.0.graph_names().cloned().collect()
+    }
+
+    fn graph_factory(&self) -> GraphFactoryRef {
+        self.0.graph_factory()
+    }
+
+    fn statement_factory(&self) -> StatementFactoryRef {
+        match self.0.default_graph() {
+            Some(graph) => graph.borrow().statement_factory(),
+            None => self.0.graph_factory().graph().borrow().statement_factory(),
+        }
+    }
+
+    fn apply(&mut self, changes: &DataSetChangeSet) -> Result<()> {
+        changes.apply(self.0)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+struct Templates<'a> {
+    statements: StatementFactoryRef,
+    with: Option<&'a IRIRef>,
+    blank_nodes: HashMap<String, ObjectNodeRef>,
+}
+
+impl<'a> Templates<'a> {
+    fn new(statements: StatementFactoryRef, with: Option<&'a IRIRef>) -> Self {
+        Self {
+            statements,
+            with,
+            blank_nodes: Default::default(),
+        }
+    }
+
+    fn fresh_blank_nodes(&mut self) {
+        self.blank_nodes.clear();
+    }
+
+    // templates that leave a variable unbound, or that would form an invalid statement, such as
+    // one with a literal subject, are skipped as required by section 3.1.3 of SPARQL 1.1 Update.
+    fn instantiate(
+        &mut self,
+        quads: &[QuadPattern],
+        solution: &Solution,
+    ) -> Vec<(Option<GraphNameRef>, StatementRef)> {
+        quads
+            .iter()
+            .filter_map(|quad| {
+                let graph = match (quad.graph(), self.with) {
+                    (None, None) => None,
+                    (None, Some(with)) => Some(GraphName::named_ref(with.clone())),
+                    (Some(term), _) => Some(GraphName::named_ref(
+                        self.value(term, solution)?.as_iri()?.clone(),
+                    )),
+                };
+                let triple = quad.triple();
+                let subject = self.value(triple.subject(), solution)?;
+                let predicate = self.value(triple.predicate(), solution)?;
+                let object = self.value(triple.object(), solution)?;
+                let statement = self
+                    .statements
+                    .statement(
+                        self.statements.object_as_subject(subject)?,
+                        predicate.as_iri()?.clone(),
+                        object,
+                    )
+                    .ok()?;
+                Some((graph, statement))
+            })
+            .collect()
+    }
+
+    fn value(&mut self, term: &Term, solution: &Solution) -> Option<ObjectNodeRef> {
+        match term {
+            Term::Iri(iri) => Some(self.statements.named_object(iri.clone())),
+            Term::Literal(literal) => Some(self.statements.literal_object(literal.clone())),
+            Term::Variable(name) => solution.get(name).cloned(),
+            Term::BlankNode(name) => {
+                let statements = &self.statements;
+                Some(
+                    self.blank_nodes
+                        .entry(name.clone())
+                        .or_insert_with(|| statements.blank_object())
+                        .clone(),
+                )
+            }
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn copy_graph(graph: &dyn Graph) -> GraphRef {
+    graph
+        .factory()
+        .graph_from_iter(&mut graph.statements().cloned())
+}
+
+fn copy_data_set(data_set: &dyn DataSet) -> DataSetRef {
+    let copy = data_set.factory().data_set(
+        data_set
+            .default_graph()
+            .map(|graph| copy_graph(&*graph.borrow())),
+    );
+    {
+        let mut copy = copy.borrow_mut();
+        for (name, graph) in data_set.graphs() {
+            copy.insert(name.clone(), copy_graph(&*graph.borrow()));
+        }
+    }
+    copy
+}
+
+///
+/// Combine the changes of each operation, in order, into a single change set.
+///
+fn combine(results: &[DataSetChangeSet]) -> DataSetChangeSet {
+    let mut combined = DataSetChangeSet::default();
+    for changes in results {
+        for (name, changes) in changes.graphs() {
+            let _ = combined.graph(name).extend(changes);
+        }
+    }
+    combined
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "sparql")]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_query::rules::inference::{BudgetLimit, InferenceBudget};
+use rdftk_query::rules::RuleSet;
+use std::str::FromStr;
+use std::time::Duration;
+
+const ORGANIZATION: &str = r#"@prefix ex: <http://example.org/> .
+
+ex:alice ex:reportsTo ex:bob .
+ex:bob ex:reportsTo ex:carol .
+ex:carol ex:reportsTo ex:dave .
+ex:dave a ex:Director .
+"#;
+
+const PREFIXES: &str = r#"PREFIX ex: <http://example.org/>
+"#;
+
+const TRANSITIVE: &str = r#"RULE "transitive"
+  IF ?a ex:reportsTo ?b AND ?b ex:reportsTo ?c
+  THEN ADD ?a ex:reportsTo ?c
+RULE "managed"
+  IF ?a ex:reportsTo ?b AND ?b a ex:Director
+  THEN ADD ?a a ex:Managed"#;
+
+fn organization() -> GraphRef {
+    TurtleReader::default()
+        .read(&mut ORGANIZATION.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn rules(rules: &str) -> RuleSet {
+    RuleSet::from_str(&format!("{}{}", PREFIXES, rules)).unwrap()
+}
+
+#[test]
+fn infer_to_fixpoint() {
+    let graph = organization();
+    let inference = rules(TRANSITIVE)
+        .infer(&mut *graph.borrow_mut(), &InferenceBudget::default())
+        .unwrap();
+    assert!(inference.is_complete());
+    assert_eq!(inference.exhausted(), None);
+    assert!(inference.problems().is_empty());
+    // alice, bob, and carol each report to every manager above them; and all three are managed.
+    assert_eq!(inference.len(), 6);
+    assert_eq!(graph.borrow().len(), 10);
+
+    // a second run infers nothing new.
+    let again = rules(TRANSITIVE)
+        .infer(&mut *graph.borrow_mut(), &InferenceBudget::default())
+        .unwrap();
+    assert!(again.is_empty());
+    assert_eq!(again.rounds(), 1);
+}
+
+#[test]
+fn explain_inferred_statements() {
+    let graph = organization();
+    let inference = rules(TRANSITIVE)
+        .infer(&mut *graph.borrow_mut(), &InferenceBudget::default())
+        .unwrap();
+    let graph = graph.borrow();
+
+    for statement in graph.statements() {
+        let asserted = ORGANIZATION.contains(&format!(
+            "ex:{} ",
+            statement
+                .subject()
+                .as_iri()
+                .unwrap()
+                .to_string()
+                .trim_start_matches("http://example.org/")
+        ));
+        if let Some(explanation) = inference.explain(statement) {
+            assert_eq!(explanation.statement(), statement);
+            assert_eq!(explanation.premises().len(), 2);
+            for premise in explanation.premises() {
+                assert!(graph.contains(premise));
+            }
+        } else {
+            assert!(asserted);
+        }
+    }
+
+    let managed: Vec<_> = inference
+        .explanations()
+        .iter()
+        .filter(|explanation| explanation.rule() == "managed")
+        .collect();
+    assert_eq!(managed.len(), 3);
+
+    // alice reports to dave only by inference, so the premise is itself explained.
+    let alice = managed
+        .iter()
+        .find(|explanation| {
+            explanation
+                .statement()
+                .subject()
+                .to_string()
+                .contains("alice")
+        })
+        .unwrap();
+    assert!(inference.explain(&alice.premises()[0]).is_some());
+    assert!(alice.to_string().contains("<= rule \"managed\""));
+}
+
+#[test]
+fn infer_within_statement_budget() {
+    let graph = organization();
+    let inference = rules(TRANSITIVE)
+        .infer(
+            &mut *graph.borrow_mut(),
+            &InferenceBudget::default().with_max_statements(2),
+        )
+        .unwrap();
+    assert!(!inference.is_complete());
+    assert_eq!(inference.exhausted(), Some(BudgetLimit::Statements));
+    assert_eq!(inference.len(), 2);
+    assert_eq!(graph.borrow().len(), 6);
+    for statement in inference.statements() {
+        assert!(graph.borrow().contains(statement));
+    }
+}
+
+#[test]
+fn infer_within_duration_budget() {
+    let graph = organization();
+    let inference = rules(TRANSITIVE)
+        .infer(
+            &mut *graph.borrow_mut(),
+            &InferenceBudget::default().with_max_duration(Duration::from_secs(0)),
+        )
+        .unwrap();
+    assert_eq!(inference.exhausted(), Some(BudgetLimit::Duration));
+    assert!(inference.is_empty());
+    assert_eq!(graph.borrow().len(), 4);
+}
+
+#[test]
+fn infer_rejects_non_additive_rules() {
+    let graph = organization();
+    let result = rules(r#"RULE "demote" IF ?a a ex:Director THEN DELETE ?a a ex:Director"#)
+        .infer(&mut *graph.borrow_mut(), &InferenceBudget::default());
+    assert!(result.is_err());
+    assert_eq!(graph.borrow().len(), 4);
+}
+
+#[test]
+fn infer_reports_problems() {
+    let graph = organization();
+    let inference = rules(r#"IF ?a ex:reportsTo ?b THEN ADD "boss" ex:of ?a"#)
+        .infer(&mut *graph.borrow_mut(), &InferenceBudget::default())
+        .unwrap();
+    assert!(inference.is_complete());
+    assert!(inference.is_empty());
+    assert_eq!(inference.problems().len(), 1);
+}
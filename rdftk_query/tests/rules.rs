@@ -0,0 +1,169 @@
+#![cfg(feature = "sparql")]
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::GraphReader;
+use rdftk_names::xsd;
+use rdftk_query::rules::{Action, RuleSet};
+use rdftk_query::sparql::Query;
+use std::str::FromStr;
+
+const RECORDS: &str = r#"@prefix dc: <http://purl.org/dc/elements/1.1/> .
+@prefix ex: <http://example.org/> .
+
+ex:report dc:date "2021-07-04" ;
+    dc:title "Report" ;
+    ex:status "draft" .
+
+ex:memo dc:date "July 4th" ;
+    ex:status "final" .
+
+ex:note dc:date "2021-07-05"^^<http://www.w3.org/2001/XMLSchema#date> .
+"#;
+
+const PREFIXES: &str = r#"PREFIX dc: <http://purl.org/dc/elements/1.1/>
+PREFIX ex: <http://example.org/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+"#;
+
+fn records() -> GraphRef {
+    TurtleReader::default()
+        .read(&mut RECORDS.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn rules(rules: &str) -> RuleSet {
+    RuleSet::from_str(&format!("{}{}", PREFIXES, rules)).unwrap()
+}
+
+fn ask(graph: &GraphRef, pattern: &str) -> bool {
+    Query::from_str(&format!("{}ASK {{ {} }}", PREFIXES, pattern))
+        .unwrap()
+        .execute(graph)
+        .unwrap()
+        .as_boolean()
+        .unwrap()
+}
+
+#[test]
+fn parse_rule_set() {
+    let rule_set = rules(
+        r#"RULE "typed dates"
+             IF ?s dc:date ?d AND datatype(?d) = xsd:string
+             THEN RETYPE ?d AS xsd:date .
+           # statuses are replaced by a type.
+           IF ?s ex:status "draft" THEN DELETE ?s ex:status "draft" AND ADD ?s a ex:Draft"#,
+    );
+    let parsed: Vec<_> = rule_set.rules().collect();
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(parsed[0].name(), "typed dates");
+    assert_eq!(parsed[0].conditions().len(), 1);
+    assert_eq!(parsed[0].filters().len(), 1);
+    assert!(matches!(&parsed[0].actions()[0], Action::Retype(d, _) if d == "d"));
+    assert_eq!(parsed[1].name(), "rule 2");
+    assert_eq!(parsed[1].filters().len(), 0);
+    assert!(matches!(parsed[1].actions()[0], Action::Delete(_)));
+    assert!(matches!(parsed[1].actions()[1], Action::Add(_)));
+}
+
+#[test]
+fn parse_errors() {
+    for invalid in [
+        "IF ?s dc:date ?d",
+        "IF ?s dc:date ?d THEN RETYPE ?s AS xsd:date",
+        "IF ?s dc:date ?d THEN ADD ?s dc:title ?title",
+        "IF ?s unknown:date ?d THEN DELETE ?s dc:date ?d",
+        "IF ?s dc:date ?d THEN REPLACE ?d",
+    ] {
+        assert!(
+            RuleSet::from_str(&format!("{}{}", PREFIXES, invalid)).is_err(),
+            "{} should be invalid",
+            invalid
+        );
+    }
+    assert!(RuleSet::from_str(PREFIXES)
+        .unwrap()
+        .rules()
+        .next()
+        .is_none());
+}
+
+#[test]
+fn retype_literals() {
+    let graph = records();
+    let outcomes = rules(
+        r#"RULE "typed dates"
+             IF ?s dc:date ?d AND datatype(?d) = xsd:string
+             THEN RETYPE ?d AS xsd:date"#,
+    )
+    .apply(&mut *graph.borrow_mut())
+    .unwrap();
+
+    assert_eq!(outcomes.len(), 1);
+    assert_eq!(outcomes[0].name(), "typed dates");
+    // one removal and one addition for the valid date.
+    assert_eq!(outcomes[0].changes().len(), 2);
+    assert_eq!(outcomes[0].problems().len(), 1);
+    assert!(outcomes[0].problems()[0].contains("July 4th"));
+
+    assert!(ask(&graph, r#"ex:report dc:date "2021-07-04"^^xsd:date"#));
+    assert!(!ask(&graph, r#"ex:report dc:date "2021-07-04""#));
+    assert!(ask(&graph, r#"ex:memo dc:date "July 4th""#));
+    let graph = graph.borrow();
+    assert_eq!(graph.len(), 6);
+    assert_eq!(
+        graph
+            .statements()
+            .filter(|st| st
+                .object()
+                .as_literal()
+                .and_then(|l| l
+                    .data_type()
+                    .map(|data_type| data_type.as_iri() == xsd::date()))
+                .unwrap_or_default())
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn add_and_delete_statements() {
+    let graph = records();
+    let rule_set = rules(
+        r#"RULE "drafts"
+             IF ?s ex:status ?status AND ?status = "draft"
+             THEN DELETE ?s ex:status ?status AND ADD ?s a ex:Draft
+           RULE "final drafts"
+             IF ?s a ex:Draft AND ?s dc:title ?title
+             THEN ADD ?s ex:label ?title"#,
+    );
+    let outcomes = rule_set.apply(&mut *graph.borrow_mut()).unwrap();
+
+    assert_eq!(outcomes.len(), 2);
+    assert_eq!(outcomes[0].changes().len(), 2);
+    // the second rule sees the changes made by the first.
+    assert_eq!(outcomes[1].changes().len(), 1);
+    assert!(outcomes.iter().all(|outcome| outcome.problems().is_empty()));
+
+    assert!(ask(&graph, "ex:report a ex:Draft"));
+    assert!(ask(&graph, r#"ex:report ex:label "Report""#));
+    assert!(!ask(&graph, r#"ex:report ex:status "draft""#));
+    assert!(ask(&graph, r#"ex:memo ex:status "final""#));
+}
+
+#[test]
+fn evaluate_without_applying() {
+    let graph = records();
+    let rule_set = rules("IF ?s ex:status ?status THEN DELETE ?s ex:status ?status");
+    let outcome = rule_set
+        .rules()
+        .next()
+        .unwrap()
+        .evaluate(&*graph.borrow())
+        .unwrap();
+
+    assert_eq!(outcome.changes().removals().count(), 2);
+    assert!(outcome.changes().to_rdf_patch().starts_with("TX .\nD "));
+    assert_eq!(graph.borrow().len(), 6);
+}
@@ -0,0 +1,404 @@
+#![cfg(feature = "sparql")]
+
+use rdftk_core::model::data_set::DataSetRef;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_io::trig::reader::TriGReader;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::{DataSetReader, GraphReader};
+use rdftk_query::sparql::algebra::{Pattern, Projection, QueryForm, SelectModifier};
+use rdftk_query::sparql::results::Solutions;
+use rdftk_query::sparql::Query;
+use std::str::FromStr;
+
+const PEOPLE: &str = r#"@prefix ex: <http://example.org/> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+
+ex:alice a foaf:Person ;
+    foaf:name "Alice" , "Alicia"@es ;
+    foaf:age 34 ;
+    foaf:knows ex:bob , ex:carol .
+
+ex:bob a foaf:Person ;
+    foaf:name "Bob" ;
+    foaf:age 27 ;
+    foaf:mbox <mailto:bob@example.org> .
+
+ex:carol a foaf:Person ;
+    foaf:name "Carol" ;
+    foaf:age 41 ;
+    foaf:knows [ foaf:name "Dave" ] .
+"#;
+
+const PREFIXES: &str = r#"PREFIX ex: <http://example.org/>
+PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+"#;
+
+fn people() -> GraphRef {
+    TurtleReader::default()
+        .read(&mut PEOPLE.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn select(query: &str) -> Solutions {
+    let query = Query::from_str(&format!("{}{}", PREFIXES, query)).unwrap();
+    query
+        .execute(&people())
+        .unwrap()
+        .as_solutions()
+        .unwrap()
+        .clone()
+}
+
+fn strings(solutions: &Solutions, variable: &str) -> Vec<String> {
+    solutions
+        .column(variable)
+        .into_iter()
+        .map(|value| match value {
+            None => "-".to_string(),
+            Some(value) => match (value.as_literal(), value.as_iri()) {
+                (Some(literal), _) => literal.lexical_form().clone(),
+                (_, Some(iri)) => iri.to_string(),
+                _ => value.to_string(),
+            },
+        })
+        .collect()
+}
+
+#[test]
+fn parse_algebra() {
+    let query = Query::from_str(&format!(
+        "{}{}",
+        PREFIXES,
+        r#"SELECT DISTINCT ?name WHERE {
+             ?person a foaf:Person ; foaf:name ?name .
+             OPTIONAL { ?person foaf:mbox ?mbox FILTER(isIRI(?mbox)) }
+             { ?person foaf:age 34 } UNION { ?person foaf:age 27 }
+             FILTER (LANG(?name) = "")
+           }
+           ORDER BY DESC(?name) LIMIT 5 OFFSET 1"#
+    ))
+    .unwrap();
+
+    assert_eq!(query.prefixes().count(), 2);
+    assert_eq!(
+        query.form(),
+        &QueryForm::Select {
+            modifier: Some(SelectModifier::Distinct),
+            projection: Projection::Variables(vec!["name".to_string()]),
+        }
+    );
+    assert_eq!(query.order().len(), 1);
+    assert!(query.order()[0].is_descending());
+    assert_eq!(query.limit(), Some(5));
+    assert_eq!(query.offset(), Some(1));
+
+    match query.pattern() {
+        Pattern::Filter(_, inner) => match inner.as_ref() {
+            Pattern::Join(lhs, rhs) => {
+                assert!(matches!(lhs.as_ref(), Pattern::LeftJoin(_, _, Some(_))));
+                assert!(matches!(rhs.as_ref(), Pattern::Union(_, _)));
+            }
+            other => panic!("expected a join, not {:?}", other),
+        },
+        other => panic!("expected a filter, not {:?}", other),
+    }
+    assert_eq!(
+        query.pattern().variables(),
+        vec!["person".to_string(), "name".to_string(), "mbox".to_string()]
+    );
+}
+
+#[test]
+fn parse_errors() {
+    assert!(Query::from_str("SELECT ?s WHERE { ?s ?p }").is_err());
+    assert!(Query::from_str("SELECT ?s WHERE { ?s foaf:name ?o }").is_err());
+    assert!(Query::from_str("SELECT ?s WHERE { ?s <relative> ?o }").is_err());
+    assert!(Query::from_str("SELECT ?s WHERE { ?s ?p ?o FILTER(BOUND(1)) }").is_err());
+    assert!(Query::from_str("BASE <http://example.org/> ASK { ?s <relative> ?o }").is_ok());
+}
+
+#[test]
+fn select_basic_graph_pattern() {
+    let solutions = select(
+        r#"SELECT ?name ?age WHERE { ?person a foaf:Person ; foaf:name ?name ; foaf:age ?age .
+             FILTER(LANG(?name) = "") } ORDER BY ?age"#,
+    );
+    assert_eq!(
+        solutions.variables(),
+        &["name".to_string(), "age".to_string()]
+    );
+    assert_eq!(strings(&solutions, "name"), vec!["Bob", "Alice", "Carol"]);
+    assert_eq!(strings(&solutions, "age"), vec!["27", "34", "41"]);
+
+    // literal constants, and blank nodes as variables.
+    let solutions = select(
+        r#"SELECT ?person WHERE { ?person foaf:age 41 ; foaf:knows _:b . _:b foaf:name "Dave" }"#,
+    );
+    assert_eq!(
+        strings(&solutions, "person"),
+        vec!["http://example.org/carol"]
+    );
+
+    let solutions = select(r#"SELECT * WHERE { ?person foaf:knows [] }"#);
+    assert_eq!(solutions.variables(), &["person".to_string()]);
+    assert_eq!(solutions.len(), 3);
+}
+
+#[test]
+fn select_optional_and_union() {
+    let solutions = select(
+        r#"SELECT ?name ?mbox WHERE { ?person foaf:name ?name OPTIONAL { ?person foaf:mbox ?mbox }
+             FILTER(!isBLANK(?person) && LANG(?name) = "") } ORDER BY ?name"#,
+    );
+    assert_eq!(strings(&solutions, "name"), vec!["Alice", "Bob", "Carol"]);
+    assert_eq!(
+        strings(&solutions, "mbox"),
+        vec!["-", "mailto:bob@example.org", "-"]
+    );
+
+    let solutions = select(
+        r#"SELECT ?name WHERE { ?person foaf:name ?name OPTIONAL { ?person foaf:mbox ?mbox }
+             FILTER(!BOUND(?mbox) && isIRI(?person) && LANGMATCHES(LANG(?name), "*")) }"#,
+    );
+    assert_eq!(strings(&solutions, "name"), vec!["Alicia"]);
+
+    let solutions = select(
+        r#"SELECT ?person WHERE { { ?person foaf:age 27 } UNION { ?person foaf:mbox ?m } UNION { ex:alice foaf:knows ?person } }
+             ORDER BY ?person"#,
+    );
+    assert_eq!(
+        strings(&solutions, "person"),
+        vec![
+            "http://example.org/bob",
+            "http://example.org/bob",
+            "http://example.org/bob",
+            "http://example.org/carol"
+        ]
+    );
+}
+
+#[test]
+fn select_modifiers() {
+    let solutions = select(
+        r#"SELECT DISTINCT ?person WHERE { { ?person foaf:age 27 } UNION { ?person foaf:mbox ?m } }"#,
+    );
+    assert_eq!(solutions.len(), 1);
+
+    let solutions = select(
+        r#"SELECT ?name WHERE { ?person foaf:name ?name ; foaf:age ?age FILTER(LANG(?name) = "") }
+             ORDER BY DESC(?age) LIMIT 2"#,
+    );
+    assert_eq!(strings(&solutions, "name"), vec!["Carol", "Alice"]);
+
+    let solutions =
+        select(r#"SELECT ?age WHERE { ?person foaf:age ?age } ORDER BY ?age OFFSET 1 LIMIT 1"#);
+    assert_eq!(strings(&solutions, "age"), vec!["34"]);
+}
+
+#[test]
+fn select_expressions() {
+    let solutions = select(
+        r#"SELECT ?name WHERE { ?person foaf:name ?name ; foaf:age ?age
+             FILTER(?age * 2 > 60 && ?age - 1 != 40) } ORDER BY ?name"#,
+    );
+    assert_eq!(strings(&solutions, "name"), vec!["Alice", "Alicia"]);
+
+    let solutions = select(
+        r#"SELECT ?name WHERE { ?person foaf:name ?name
+             FILTER(REGEX(?name, "^a", "i") && STRLEN(?name) = 5 && CONTAINS(UCASE(?name), "LI")) }"#,
+    );
+    assert_eq!(strings(&solutions, "name"), vec!["Alice"]);
+
+    let solutions = select(
+        r#"SELECT ?name WHERE { ?person foaf:name ?name
+             FILTER(DATATYPE(?name) = <http://www.w3.org/1999/02/22-rdf-syntax-ns#langString>) }"#,
+    );
+    assert_eq!(strings(&solutions, "name"), vec!["Alicia"]);
+
+    let solutions = select(
+        r#"SELECT ?person WHERE { ?person foaf:mbox ?mbox FILTER(STRSTARTS(STR(?mbox), "mailto:")) }"#,
+    );
+    assert_eq!(
+        strings(&solutions, "person"),
+        vec!["http://example.org/bob"]
+    );
+}
+
+#[test]
+fn ask() {
+    let graph = people();
+    let ask = |query: &str| {
+        Query::from_str(&format!("{}{}", PREFIXES, query))
+            .unwrap()
+            .execute(&graph)
+            .unwrap()
+            .as_boolean()
+            .unwrap()
+    };
+    assert!(ask("ASK { ex:alice foaf:knows ex:bob }"));
+    assert!(!ask("ASK { ex:bob foaf:knows ex:alice }"));
+    assert!(ask("ASK WHERE { ?p foaf:age ?age FILTER(?age >= 41) }"));
+    assert!(!ask("ASK { ?p foaf:age ?age FILTER(?age > 41) }"));
+    assert!(ask(r#"ASK { ?p foaf:name "Alicia"@es }"#));
+    assert!(!ask(r#"ASK { ?p foaf:name "Alicia" }"#));
+}
+
+const EVENTS: &str = r#"@prefix ex: <http://example.org/> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+ex:launch ex:at "2021-07-04T12:00:00-05:00"^^xsd:dateTime .
+ex:landing ex:at "2021-07-04T15:30:00Z"^^xsd:dateTime .
+ex:debrief ex:at "2021-07-04T20:00:00+02:00"^^xsd:dateTime .
+ex:party ex:at "2021-07-05T12:00:00"^^xsd:dateTime .
+"#;
+
+#[test]
+fn select_date_times() {
+    let graph = TurtleReader::default()
+        .read(&mut EVENTS.as_bytes(), graph_factory())
+        .unwrap();
+    let select = |query: &str| {
+        Query::from_str(&format!(
+            "PREFIX ex: <http://example.org/>\nPREFIX xsd: <http://www.w3.org/2001/XMLSchema#>\n{}",
+            query
+        ))
+        .unwrap()
+        .execute(&graph)
+        .unwrap()
+        .as_solutions()
+        .unwrap()
+        .clone()
+    };
+
+    // in UTC the launch is at 17:00 and the debrief at 18:00.
+    let solutions = select(
+        r#"SELECT ?event WHERE { ?event ex:at ?at
+             FILTER(?at < "2021-07-04T17:30:00Z"^^xsd:dateTime) } ORDER BY ?event"#,
+    );
+    assert_eq!(
+        strings(&solutions, "event"),
+        vec!["http://example.org/landing", "http://example.org/launch"]
+    );
+
+    let solutions = select(
+        r#"SELECT ?event WHERE { ?event ex:at ?at
+             FILTER(?at = "2021-07-04T17:00:00Z"^^xsd:dateTime) }"#,
+    );
+    assert_eq!(
+        strings(&solutions, "event"),
+        vec!["http://example.org/launch"]
+    );
+
+    // the party has no timezone, and so is only ordered against instants more than 14 hours
+    // away.
+    let solutions = select(
+        r#"SELECT ?event WHERE { ?event ex:at ?at
+             FILTER(?at > "2021-07-04T23:00:00Z"^^xsd:dateTime) }"#,
+    );
+    assert!(solutions.is_empty());
+    let solutions = select(
+        r#"SELECT ?event WHERE { ?event ex:at ?at
+             FILTER(?at > "2021-07-04T08:00:00Z"^^xsd:dateTime) } ORDER BY ?event"#,
+    );
+    assert_eq!(solutions.len(), 4);
+}
+
+const LIBRARY: &str = r#"@prefix ex: <http://example.org/> .
+@prefix dc: <http://purl.org/dc/elements/1.1/> .
+
+ex:catalog dc:title "Catalog" .
+
+ex:fiction {
+    ex:dune dc:title "Dune" ;
+        dc:creator "Frank Herbert" .
+}
+
+ex:poetry {
+    ex:odyssey dc:title "The Odyssey" .
+}
+"#;
+
+fn library() -> DataSetRef {
+    TriGReader::default()
+        .read(&mut LIBRARY.as_bytes(), data_set_factory())
+        .unwrap()
+}
+
+#[test]
+fn select_graph_patterns() {
+    let data_set = library();
+    let select = |query: &str, union: bool| {
+        Query::from_str(&format!(
+            "PREFIX ex: <http://example.org/>\nPREFIX dc: <http://purl.org/dc/elements/1.1/>\n{}",
+            query
+        ))
+        .unwrap()
+        .execute_data_set(&data_set, union)
+        .unwrap()
+        .as_solutions()
+        .unwrap()
+        .clone()
+    };
+
+    let query = Query::from_str("SELECT * WHERE { GRAPH ?g { ?s ?p ?o } }").unwrap();
+    assert!(matches!(query.pattern(), Pattern::Graph(_, _)));
+    assert_eq!(
+        query.pattern().variables(),
+        vec![
+            "g".to_string(),
+            "s".to_string(),
+            "p".to_string(),
+            "o".to_string()
+        ]
+    );
+
+    // the default graph alone.
+    let solutions = select("SELECT ?title WHERE { ?s dc:title ?title }", false);
+    assert_eq!(strings(&solutions, "title"), vec!["Catalog"]);
+
+    // the default graph as the union of all graphs.
+    let solutions = select(
+        "SELECT ?title WHERE { ?s dc:title ?title } ORDER BY ?title",
+        true,
+    );
+    assert_eq!(
+        strings(&solutions, "title"),
+        vec!["Catalog", "Dune", "The Odyssey"]
+    );
+
+    // a named graph.
+    let solutions = select(
+        "SELECT ?title WHERE { GRAPH ex:poetry { ?s dc:title ?title } }",
+        false,
+    );
+    assert_eq!(strings(&solutions, "title"), vec!["The Odyssey"]);
+
+    // each named graph, binding its name.
+    let solutions = select(
+        "SELECT ?g ?title WHERE { GRAPH ?g { ?s dc:title ?title } } ORDER BY ?title",
+        true,
+    );
+    assert_eq!(
+        strings(&solutions, "g"),
+        vec!["http://example.org/fiction", "http://example.org/poetry"]
+    );
+    assert_eq!(strings(&solutions, "title"), vec!["Dune", "The Odyssey"]);
+
+    // joined with the default graph.
+    let solutions = select(
+        r#"SELECT ?g WHERE { ?s dc:creator "Frank Herbert" GRAPH ?g { ?s dc:title ?title } }"#,
+        true,
+    );
+    assert_eq!(strings(&solutions, "g"), vec!["http://example.org/fiction"]);
+
+    // no named graphs when querying a single graph.
+    assert!(Query::from_str("ASK { GRAPH ?g { ?s ?p ?o } }")
+        .unwrap()
+        .execute(&people())
+        .unwrap()
+        .as_boolean()
+        .map(|b| !b)
+        .unwrap());
+}
@@ -0,0 +1,366 @@
+#![cfg(feature = "sparql")]
+
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::data_set::{DataSetRef, GraphName};
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph::graph_factory;
+use rdftk_fixtures::terms::iri;
+use rdftk_io::trig::reader::TriGReader;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::{DataSetReader, GraphReader};
+use rdftk_iri::IRIRef;
+use rdftk_query::sparql::update::{GraphTarget, Update, UpdateOperation};
+use std::str::FromStr;
+
+const PEOPLE: &str = r#"@prefix ex: <http://example.org/> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+
+ex:alice foaf:name "Alice" ;
+    foaf:age 34 ;
+    foaf:knows ex:bob .
+
+ex:bob foaf:name "Bob" ;
+    foaf:age 27 .
+"#;
+
+const LIBRARY: &str = r#"@prefix ex: <http://example.org/> .
+@prefix dc: <http://purl.org/dc/elements/1.1/> .
+
+ex:catalog dc:title "Catalog" .
+
+ex:books {
+    ex:book1 dc:title "Dune" ; dc:creator "Herbert" .
+    ex:book2 dc:title "Emma" ; dc:creator "Austen" .
+}
+
+ex:films {
+    ex:film1 dc:title "Alien" .
+}
+"#;
+
+const PREFIXES: &str = r#"PREFIX ex: <http://example.org/>
+PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+PREFIX dc: <http://purl.org/dc/elements/1.1/>
+"#;
+
+fn people() -> GraphRef {
+    TurtleReader::default()
+        .read(&mut PEOPLE.as_bytes(), graph_factory())
+        .unwrap()
+}
+
+fn library() -> DataSetRef {
+    TriGReader::default()
+        .read(&mut LIBRARY.as_bytes(), data_set_factory())
+        .unwrap()
+}
+
+fn update(request: &str) -> Update {
+    Update::from_str(&format!("{}{}", PREFIXES, request)).unwrap()
+}
+
+fn contains(graph: &GraphRef, subject: &str, predicate: &str, object: &str) -> bool {
+    graph.borrow().statements().any(|statement| {
+        statement.subject().to_string() == format!("<{}>", subject)
+            && statement.predicate().to_string() == predicate
+            && statement.object().to_string() == object
+    })
+}
+
+fn named(data_set: &DataSetRef, name: &str) -> Option<GraphRef> {
+    data_set
+        .borrow()
+        .graph_named(&GraphName::named_ref(iri(name)))
+        .cloned()
+}
+
+#[test]
+fn parse_operations() {
+    let request = update(
+        r#"INSERT DATA { ex:carol foaf:name "Carol" } ;
+           DELETE DATA { GRAPH ex:g { ex:bob foaf:age 27 } } ;
+           DELETE WHERE { ?person foaf:age ?age } ;
+           WITH ex:g DELETE { ?s ?p ?o } INSERT { ?s ?p "x" } WHERE { ?s ?p ?o } ;
+           LOAD SILENT <http://example.org/data.ttl> INTO GRAPH ex:data ;
+           CLEAR NAMED ;"#,
+    );
+    let operations = request.operations();
+    assert_eq!(operations.len(), 6);
+    assert_eq!(request.prefixes().count(), 3);
+
+    assert!(matches!(&operations[0], UpdateOperation::InsertData(quads) if quads.len() == 1));
+    assert!(
+        matches!(&operations[1], UpdateOperation::DeleteData(quads) if quads[0].graph().is_some())
+    );
+    assert!(matches!(
+        &operations[2],
+        UpdateOperation::DeleteInsert { with: None, delete, insert, .. }
+            if delete.len() == 1 && insert.is_empty()
+    ));
+    assert!(matches!(
+        &operations[3],
+        UpdateOperation::DeleteInsert { with: Some(with), .. }
+            if with.to_string() == "http://example.org/g"
+    ));
+    assert!(matches!(
+        &operations[4],
+        UpdateOperation::Load {
+            silent: true,
+            into: Some(_),
+            ..
+        }
+    ));
+    assert_eq!(
+        operations[5],
+        UpdateOperation::Clear {
+            silent: false,
+            target: GraphTarget::AllNamed
+        }
+    );
+}
+
+#[test]
+fn reject_invalid_data() {
+    assert!(Update::from_str("INSERT DATA { ?s <http://example.org/p> 1 }").is_err());
+    assert!(Update::from_str("DELETE DATA { _:b <http://example.org/p> 1 }").is_err());
+    assert!(Update::from_str("DELETE WHERE { _:b <http://example.org/p> ?o }").is_err());
+    assert!(Update::from_str("DELETE { _:b ?p ?o } WHERE { ?s ?p ?o }").is_err());
+    assert!(Update::from_str("INSERT DATA { _:b <http://example.org/p> 1 }").is_ok());
+    assert!(Update::from_str("").unwrap().operations().is_empty());
+}
+
+#[test]
+fn insert_and_delete_data() {
+    let graph = people();
+    let changes = update(
+        r#"INSERT DATA { ex:carol foaf:name "Carol" ; foaf:knows ex:alice } ;
+           DELETE DATA { ex:bob foaf:age 27 . ex:bob foaf:age 99 }"#,
+    )
+    .apply(&mut *graph.borrow_mut(), None)
+    .unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].len(), 2);
+
+    assert_eq!(graph.borrow().len(), 6);
+    assert!(contains(
+        &graph,
+        "http://example.org/carol",
+        "http://xmlns.com/foaf/0.1/name",
+        "\"Carol\""
+    ));
+    assert!(!graph
+        .borrow()
+        .statements()
+        .any(
+            |statement| statement.subject().to_string() == "<http://example.org/bob>"
+                && statement.predicate().to_string() == "http://xmlns.com/foaf/0.1/age"
+        ));
+}
+
+#[test]
+fn delete_where() {
+    let graph = people();
+    let _ = update("DELETE WHERE { ?person foaf:age ?age }")
+        .apply(&mut *graph.borrow_mut(), None)
+        .unwrap();
+    assert_eq!(graph.borrow().len(), 3);
+}
+
+#[test]
+fn delete_insert_where() {
+    let graph = people();
+    let changes = update(
+        r#"DELETE { ?person foaf:age ?age }
+           INSERT { ?person foaf:account _:account . _:account foaf:accountName ?name }
+           WHERE { ?person foaf:name ?name ; foaf:age ?age FILTER(?age > 30) }"#,
+    )
+    .apply(&mut *graph.borrow_mut(), None)
+    .unwrap();
+    assert_eq!(changes[0].len(), 3);
+
+    let graph = graph.borrow();
+    assert_eq!(graph.len(), 6);
+    let account = graph
+        .statements()
+        .find(|statement| statement.predicate().to_string() == "http://xmlns.com/foaf/0.1/account")
+        .unwrap();
+    assert_eq!(account.subject().to_string(), "<http://example.org/alice>");
+    assert!(account.object().is_blank());
+}
+
+#[test]
+fn named_graph_in_single_graph() {
+    let graph = people();
+    let result = update(r#"INSERT DATA { GRAPH ex:g { ex:carol foaf:name "Carol" } }"#)
+        .apply(&mut *graph.borrow_mut(), None);
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::UnknownGraph(_)
+    ));
+    assert_eq!(graph.borrow().len(), 5);
+}
+
+#[test]
+fn failed_operation_leaves_graph_unchanged() {
+    let graph = people();
+    let result = update(
+        r#"INSERT DATA { ex:carol foaf:name "Carol" } ;
+           CLEAR GRAPH ex:missing"#,
+    )
+    .apply(&mut *graph.borrow_mut(), None);
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::UnknownGraph(_)
+    ));
+    assert_eq!(graph.borrow().len(), 5);
+    assert!(!contains(
+        &graph,
+        "http://example.org/carol",
+        "http://xmlns.com/foaf/0.1/name",
+        "\"Carol\""
+    ));
+
+    let data_set = library();
+    let result = update(
+        r#"DELETE DATA { GRAPH ex:books { ex:book1 dc:title "Dune" } } ;
+           INSERT DATA { GRAPH ex:music { ex:album1 dc:title "Abbey Road" } } ;
+           CLEAR GRAPH ex:missing"#,
+    )
+    .apply_data_set(&mut *data_set.borrow_mut(), None);
+    assert!(result.is_err());
+    assert_eq!(
+        named(&data_set, "http://example.org/books")
+            .unwrap()
+            .borrow()
+            .len(),
+        4
+    );
+    assert!(named(&data_set, "http://example.org/music").is_none());
+}
+
+#[test]
+fn update_data_set() {
+    let data_set = library();
+    let _ = update(
+        r#"INSERT DATA { GRAPH ex:music { ex:album1 dc:title "Abbey Road" } } ;
+           DELETE { GRAPH ?g { ?work dc:creator ?creator } }
+           INSERT { GRAPH ex:authors { ?work dc:creator ?creator } }
+           WHERE { GRAPH ?g { ?work dc:creator ?creator } }"#,
+    )
+    .apply_data_set(&mut *data_set.borrow_mut(), None)
+    .unwrap();
+
+    let music = named(&data_set, "http://example.org/music").unwrap();
+    assert_eq!(music.borrow().len(), 1);
+    let authors = named(&data_set, "http://example.org/authors").unwrap();
+    assert_eq!(authors.borrow().len(), 2);
+    let books = named(&data_set, "http://example.org/books").unwrap();
+    assert_eq!(books.borrow().len(), 2);
+    assert!(contains(
+        &authors,
+        "http://example.org/book1",
+        "http://purl.org/dc/elements/1.1/creator",
+        "\"Herbert\""
+    ));
+}
+
+#[test]
+fn update_with_graph() {
+    let data_set = library();
+    let _ = update(
+        r#"WITH ex:books
+           DELETE { ?book dc:title ?title } INSERT { ?book dc:title "Untitled" }
+           WHERE { ?book dc:title ?title }"#,
+    )
+    .apply_data_set(&mut *data_set.borrow_mut(), None)
+    .unwrap();
+
+    let books = named(&data_set, "http://example.org/books").unwrap();
+    assert_eq!(books.borrow().len(), 4);
+    assert!(contains(
+        &books,
+        "http://example.org/book2",
+        "http://purl.org/dc/elements/1.1/title",
+        "\"Untitled\""
+    ));
+    let films = named(&data_set, "http://example.org/films").unwrap();
+    assert!(contains(
+        &films,
+        "http://example.org/film1",
+        "http://purl.org/dc/elements/1.1/title",
+        "\"Alien\""
+    ));
+}
+
+#[test]
+fn clear_graphs() {
+    let data_set = library();
+    let _ = update("CLEAR GRAPH ex:films")
+        .apply_data_set(&mut *data_set.borrow_mut(), None)
+        .unwrap();
+    assert!(named(&data_set, "http://example.org/films")
+        .unwrap()
+        .borrow()
+        .is_empty());
+    assert_eq!(
+        named(&data_set, "http://example.org/books")
+            .unwrap()
+            .borrow()
+            .len(),
+        4
+    );
+
+    let result = update("CLEAR GRAPH ex:music").apply_data_set(&mut *data_set.borrow_mut(), None);
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::UnknownGraph(_)
+    ));
+    assert!(update("CLEAR SILENT GRAPH ex:music")
+        .apply_data_set(&mut *data_set.borrow_mut(), None)
+        .is_ok());
+
+    let _ = update("CLEAR ALL")
+        .apply_data_set(&mut *data_set.borrow_mut(), None)
+        .unwrap();
+    let data_set = data_set.borrow();
+    assert!(data_set.default_graph().unwrap().borrow().is_empty());
+    assert!(data_set
+        .graphs()
+        .all(|(_, graph)| graph.borrow().is_empty()));
+}
+
+#[test]
+fn load_documents() {
+    let loader = |source: &IRIRef, factory: &GraphFactoryRef| -> Result<GraphRef> {
+        assert_eq!(source.to_string(), "http://example.org/people.ttl");
+        TurtleReader::default()
+            .read(&mut PEOPLE.as_bytes(), factory.clone())
+            .map_err(|_| ErrorKind::ReadWrite("turtle".to_string()).into())
+    };
+
+    let data_set = library();
+    let request = update("LOAD <http://example.org/people.ttl> INTO GRAPH ex:people");
+    let changes = request
+        .apply_data_set(&mut *data_set.borrow_mut(), Some(&loader))
+        .unwrap();
+    assert_eq!(changes[0].len(), 5);
+    assert_eq!(
+        named(&data_set, "http://example.org/people")
+            .unwrap()
+            .borrow()
+            .len(),
+        5
+    );
+
+    let graph = people();
+    let result =
+        update("LOAD <http://example.org/people.ttl>").apply(&mut *graph.borrow_mut(), None);
+    assert!(matches!(
+        result.err().unwrap().kind(),
+        ErrorKind::OperationNotSupported(_, _)
+    ));
+    assert!(update("LOAD SILENT <http://example.org/people.ttl>")
+        .apply(&mut *graph.borrow_mut(), None)
+        .is_ok());
+}
@@ -0,0 +1,87 @@
+/*!
+Build a small SKOS concept scheme and publish it:
+
+1. as a Turtle serialization of the scheme itself,
+1. as a SHACL shapes graph describing the conventions data using the scheme should follow, and
+1. as Markdown documentation.
+
+Run with `cargo run --example publish_scheme -p rdftk_skos`.
+
+*/
+
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::turtle::writer::TurtleWriter;
+use rdftk_io::write_graph_to_string;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::document::make_document;
+use rdftk_skos::model::{to_rdf_graph, Labeled, Propertied, Scheme};
+use rdftk_skos::shapes::{to_shapes_graph, ShapeOptions};
+use somedoc::write::markdown::MarkdownFlavor;
+use somedoc::write::write_document_to_string;
+use std::error::Error;
+use std::str::FromStr;
+
+const NAMESPACE: &str = "http://example.org/colors/";
+
+fn iri(name: &str) -> IRIRef {
+    IRIRef::from(IRI::from_str(&format!("{}{}", NAMESPACE, name)).unwrap())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let scheme = make_scheme();
+    let factory = graph_factory();
+    let namespace = IRIRef::from(IRI::from_str(NAMESPACE)?);
+
+    //
+    // 1. The scheme as Turtle
+    //
+    let graph = to_rdf_graph(&scheme, Some(namespace.clone()), &factory);
+    println!(
+        "{}",
+        write_graph_to_string(&TurtleWriter::default(), &graph)?
+    );
+
+    //
+    // 2. The scheme's conventions as SHACL shapes
+    //
+    let options = ShapeOptions::default()
+        .label_language(LanguageTag::from_str("en")?)
+        .notation_pattern("^[A-Z]{3}$");
+    let shapes = to_shapes_graph(&scheme, &options, &factory);
+    println!(
+        "{}",
+        write_graph_to_string(&TurtleWriter::default(), &shapes)?
+    );
+
+    //
+    // 3. The scheme as documentation
+    //
+    let document = make_document(&scheme, Some(LanguageTag::from_str("en")?), Some(namespace))?;
+    println!(
+        "{}",
+        write_document_to_string(&document, MarkdownFlavor::CommonMark.into())?
+    );
+
+    Ok(())
+}
+
+fn make_scheme() -> Scheme {
+    let mut scheme = Scheme::new_with_label(&iri("scheme"), "Colors", "en");
+    let _ = scheme.define("A small scheme of colors.", "en");
+
+    let colors = scheme.new_top_concept(&iri("color"));
+    let mut colors = colors.borrow_mut();
+    colors.add_preferred_label("Color", "en");
+    colors.add_preferred_label("Couleur", "fr");
+    let _ = colors.notation("COL");
+
+    for (name, label, notation) in &[("red", "Red", "RED"), ("green", "Green", "GRN")] {
+        let color = colors.sub_concept(&iri(name));
+        let mut color = color.borrow_mut();
+        color.add_preferred_label(label, "en");
+        let _ = color.notation(notation);
+    }
+
+    scheme
+}
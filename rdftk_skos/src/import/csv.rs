@@ -0,0 +1,238 @@
+/*!
+Provides the `CsvImporter` implementation of the `SchemeImporter` trait.
+
+The first row of the file is a header naming the column for each value, one concept per
+following row. The `id` column is required, the remaining columns are optional and take the
+names of the corresponding SKOS properties: `prefLabel`, `altLabel`, `hiddenLabel`, `notation`,
+`definition`, `scopeNote`, `historyNote`, `editorialNote`, `example`, `note`, `broader`,
+`narrower`, and `related`. Label and note columns may have a language suffix, as in
+`prefLabel@fr`, and any column may hold more than one value separated by the value separator
+(default `|`). Relationship columns refer to other rows by identifier or by preferred label.
+Values are quoted as described in [RFC 4180](https://tools.ietf.org/html/rfc4180).
+
+# Example
+
+```rust
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::import::csv::CsvImporter;
+use rdftk_skos::import::{ImportOptions, SchemeImporter};
+use std::str::FromStr;
+
+let mut content = "id;prefLabel;prefLabel@fr;related\ncat;Cat;Chat;dog\ndog;Dog;Chien;\n".as_bytes();
+
+let options = ImportOptions::new(IRIRef::from(
+    IRI::from_str("http://example.org/pets").unwrap(),
+));
+let mut importer = CsvImporter::default();
+let _ = importer.delimiter(';');
+let scheme = importer.import(&mut content, &options).unwrap();
+assert_eq!(scheme.top_concepts().count(), 2);
+```
+
+*/
+
+use crate::import::{build_scheme, import_error, language_or, ImportOptions, SchemeImporter, Term};
+use crate::model::concept::ConceptRelation;
+use crate::model::properties::LabelKind;
+use crate::model::{LiteralProperty, Scheme};
+use crate::ns;
+use rdftk_core::error::Result;
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_iri::IRIRef;
+use std::io::Read;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the `SchemeImporter` trait for delimited text files with a header row.
+///
+#[derive(Clone, Debug)]
+pub struct CsvImporter {
+    delimiter: char,
+    value_separator: char,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug)]
+enum Column {
+    Id,
+    Label(LabelKind, Option<LanguageTag>),
+    Note(IRIRef, Option<LanguageTag>),
+    Notation,
+    Relation(ConceptRelation),
+    Ignored,
+}
+
+const NAME: &str = "CSV";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for CsvImporter {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            value_separator: '|',
+        }
+    }
+}
+
+impl SchemeImporter for CsvImporter {
+    fn import(&self, r: &mut impl Read, options: &ImportOptions) -> Result<Scheme> {
+        let mut content = String::new();
+        let _ = r
+            .read_to_string(&mut content)
+            .map_err(|e| import_error(NAME, &format!("error reading content: {}", e)))?;
+        let mut rows = self.parse(&content)?.into_iter();
+
+        let columns: Vec<Column> = match rows.next() {
+            None => return Err(import_error(NAME, "content has no header row")),
+            Some(header) => header
+                .iter()
+                .map(|name| column(name, &options.language))
+                .collect(),
+        };
+        if !columns.iter().any(|c| matches!(c, Column::Id)) {
+            return Err(import_error(NAME, "header row has no id column"));
+        }
+
+        let mut terms: Vec<Term> = Default::default();
+        for (line, row) in rows.enumerate() {
+            if row.iter().all(|value| value.trim().is_empty()) {
+                continue;
+            }
+            let id = columns
+                .iter()
+                .zip(row.iter())
+                .find(|(column, _)| matches!(column, Column::Id))
+                .map(|(_, value)| value.trim())
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| {
+                    import_error(NAME, &format!("row {} has no identifier", line + 2))
+                })?;
+            let mut term = Term::new(id);
+            for (column, cell) in columns.iter().zip(row.iter()) {
+                for value in cell.split(self.value_separator) {
+                    match column {
+                        Column::Label(kind, language) => {
+                            term.add_label(kind.clone(), value, language)
+                        }
+                        Column::Note(predicate, language) => {
+                            term.add_note(predicate, value, language)
+                        }
+                        Column::Notation => {
+                            if !value.trim().is_empty() {
+                                term.properties
+                                    .push(LiteralProperty::notation(value.trim()));
+                            }
+                        }
+                        Column::Relation(relation) => term.add_relation(relation.clone(), value),
+                        Column::Id | Column::Ignored => {}
+                    }
+                }
+            }
+            terms.push(term);
+        }
+        build_scheme(terms, None, options, NAME)
+    }
+}
+
+impl CsvImporter {
+    ///
+    /// Set the character separating columns, default is `,`.
+    ///
+    pub fn delimiter(&mut self, delimiter: char) -> &mut Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    ///
+    /// Set the character separating multiple values in a single column, default is `|`.
+    ///
+    pub fn value_separator(&mut self, value_separator: char) -> &mut Self {
+        self.value_separator = value_separator;
+        self
+    }
+
+    fn parse(&self, content: &str) -> Result<Vec<Vec<String>>> {
+        let mut rows: Vec<Vec<String>> = Default::default();
+        let mut row: Vec<String> = Default::default();
+        let mut value = String::new();
+        let mut quoted = false;
+        let mut chars = content.chars().peekable();
+        while let Some(c) = chars.next() {
+            if quoted {
+                if c == '"' {
+                    if chars.peek() == Some(&'"') {
+                        let _ = chars.next();
+                        value.push('"');
+                    } else {
+                        quoted = false;
+                    }
+                } else {
+                    value.push(c);
+                }
+            } else if c == '"' && value.trim().is_empty() {
+                value.clear();
+                quoted = true;
+            } else if c == self.delimiter {
+                row.push(std::mem::take(&mut value));
+            } else if c == '\n' || c == '\r' {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    let _ = chars.next();
+                }
+                row.push(std::mem::take(&mut value));
+                rows.push(std::mem::take(&mut row));
+            } else {
+                value.push(c);
+            }
+        }
+        if quoted {
+            return Err(import_error(NAME, "unterminated quoted value"));
+        }
+        if !value.is_empty() || !row.is_empty() {
+            row.push(value);
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn column(name: &str, default_language: &Option<LanguageTag>) -> Column {
+    let name = name.trim();
+    let (name, language) = match name.find('@') {
+        None => (name, None),
+        Some(index) => (&name[..index], Some(&name[index + 1..])),
+    };
+    let language = language_or(language, default_language);
+    match name {
+        "id" => Column::Id,
+        "prefLabel" => Column::Label(LabelKind::Preferred, language),
+        "altLabel" => Column::Label(LabelKind::Alternative, language),
+        "hiddenLabel" => Column::Label(LabelKind::Hidden, language),
+        "notation" => Column::Notation,
+        "definition" => Column::Note(ns::definition().clone(), language),
+        "scopeNote" => Column::Note(ns::scope_note().clone(), language),
+        "historyNote" => Column::Note(ns::history_note().clone(), language),
+        "editorialNote" => Column::Note(ns::editorial_note().clone(), language),
+        "example" => Column::Note(ns::example().clone(), language),
+        "note" => Column::Note(ns::note().clone(), language),
+        "broader" => Column::Relation(ConceptRelation::Broader),
+        "narrower" => Column::Relation(ConceptRelation::Narrower),
+        "related" => Column::Relation(ConceptRelation::Related),
+        other => {
+            warn!("{} import: ignoring unknown column {:?}", NAME, other);
+            Column::Ignored
+        }
+    }
+}
@@ -0,0 +1,219 @@
+/*!
+Provides the `MarcImporter` implementation of the `SchemeImporter` trait.
+
+The importer reads a subset of [MARC 21 Authority](https://www.loc.gov/marc/authority/) records
+serialized as [MARCXML](https://www.loc.gov/standards/marcxml/), either a single `record` or a
+`collection` of records. Each record becomes a concept, and fields are mapped as follows.
+
+| Field   | Mapping                                                                           |
+|---------|-----------------------------------------------------------------------------------|
+| `001`   | The concept identifier, and `skos:notation`                                       |
+| `1XX`   | `skos:prefLabel`, subdivisions (`$v`, `$x`, `$y`, `$z`) are joined with `--`      |
+| `4XX`   | `skos:altLabel`                                                                   |
+| `5XX`   | `skos:broader` if `$w` starts with `g`, `skos:narrower` if `h`, else `skos:related` |
+| `670`   | `dcterms:source`                                                                  |
+| `677`   | `skos:definition`                                                                 |
+| `680`   | `skos:scopeNote`                                                                  |
+| `688`   | `skos:historyNote`                                                                |
+
+Records refer to each other in `5XX` fields by heading, so the heading must match the `1XX` field
+of the target record.
+
+# Example
+
+```rust
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::import::marc::MarcImporter;
+use rdftk_skos::import::{ImportOptions, SchemeImporter};
+use std::str::FromStr;
+
+let mut content = r#"<collection xmlns="http://www.loc.gov/MARC21/slim">
+  <record>
+    <controlfield tag="001">sh001</controlfield>
+    <datafield tag="150" ind1=" " ind2=" "><subfield code="a">Animals</subfield></datafield>
+  </record>
+  <record>
+    <controlfield tag="001">sh002</controlfield>
+    <datafield tag="150" ind1=" " ind2=" "><subfield code="a">Cats</subfield></datafield>
+    <datafield tag="550" ind1=" " ind2=" ">
+      <subfield code="w">g</subfield><subfield code="a">Animals</subfield>
+    </datafield>
+  </record>
+</collection>"#.as_bytes();
+
+let options = ImportOptions::new(IRIRef::from(
+    IRI::from_str("http://example.org/authorities").unwrap(),
+));
+let scheme = MarcImporter::default().import(&mut content, &options).unwrap();
+assert_eq!(scheme.top_concepts().count(), 1);
+```
+
+*/
+
+use crate::import::{
+    build_scheme, import_error, read_xml, ImportOptions, SchemeImporter, Term, XmlElement,
+};
+use crate::model::concept::ConceptRelation;
+use crate::model::properties::LabelKind;
+use crate::model::{LiteralProperty, Scheme};
+use crate::ns;
+use rdftk_core::error::Result;
+use std::io::Read;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the `SchemeImporter` trait for MARCXML authority records.
+///
+#[derive(Clone, Debug, Default)]
+pub struct MarcImporter {}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+const NAME: &str = "MARC";
+
+const SUBDIVISIONS: &str = "vxyz";
+
+const CONTROL_SUBFIELDS: &str = "0123456789iw";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl SchemeImporter for MarcImporter {
+    fn import(&self, r: &mut impl Read, options: &ImportOptions) -> Result<Scheme> {
+        let root = read_xml(r, NAME)?;
+        let records: Vec<&XmlElement> = match root.name.as_str() {
+            "record" => vec![&root],
+            "collection" => root.children("record").collect(),
+            other => {
+                return Err(import_error(
+                    NAME,
+                    &format!("expected a collection or record element, not {:?}", other),
+                ))
+            }
+        };
+
+        let terms = records
+            .into_iter()
+            .filter(|record| match record.attribute("type") {
+                None | Some("Authority") => true,
+                Some(other) => {
+                    warn!("{} import: skipping record of type {:?}", NAME, other);
+                    false
+                }
+            })
+            .map(|record| read_record(record, options))
+            .collect::<Result<Vec<Term>>>()?;
+        build_scheme(terms, None, options, NAME)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn read_record(record: &XmlElement, options: &ImportOptions) -> Result<Term> {
+    let id = record
+        .children("controlfield")
+        .find(|field| field.attribute("tag") == Some("001"))
+        .map(|field| field.text.trim())
+        .filter(|id| !id.is_empty())
+        .ok_or_else(|| import_error(NAME, "record has no 001 control number"))?;
+    let mut term = Term::new(id);
+    term.properties.push(LiteralProperty::notation(id));
+
+    let language = &options.language;
+    for field in record.children("datafield") {
+        let tag = field.attribute("tag").unwrap_or_default();
+        match tag.as_bytes() {
+            [b'1', _, _] => {
+                if let Some(heading) = heading(field) {
+                    term.add_label(LabelKind::Preferred, &heading, language);
+                }
+            }
+            [b'4', _, _] => {
+                if let Some(heading) = heading(field) {
+                    term.add_label(LabelKind::Alternative, &heading, language);
+                }
+            }
+            [b'5', _, _] => {
+                if let Some(heading) = heading(field) {
+                    let relation = match subfield(field, 'w').and_then(|w| w.chars().next()) {
+                        Some('g') => ConceptRelation::Broader,
+                        Some('h') => ConceptRelation::Narrower,
+                        _ => ConceptRelation::Related,
+                    };
+                    term.add_relation(relation, &heading);
+                }
+            }
+            b"670" => {
+                if let Some(source) = subfield(field, 'a') {
+                    term.properties.push(LiteralProperty::source(source));
+                }
+            }
+            b"677" => term.add_note(ns::definition(), &note_text(field), language),
+            b"680" => term.add_note(ns::scope_note(), &note_text(field), language),
+            b"688" => term.add_note(ns::history_note(), &note_text(field), language),
+            _ => {}
+        }
+    }
+
+    if !term
+        .labels
+        .iter()
+        .any(|label| label.kind() == &LabelKind::Preferred)
+    {
+        warn!("{} import: record {:?} has no 1XX heading", NAME, id);
+    }
+    Ok(term)
+}
+
+fn subfields(field: &XmlElement) -> impl Iterator<Item = (char, &str)> {
+    field.children("subfield").filter_map(|subfield| {
+        subfield
+            .attribute("code")
+            .and_then(|code| code.chars().next())
+            .map(|code| (code, subfield.text.trim()))
+    })
+}
+
+fn subfield(field: &XmlElement, code: char) -> Option<&str> {
+    subfields(field).find(|(c, _)| *c == code).map(|(_, v)| v)
+}
+
+fn heading(field: &XmlElement) -> Option<String> {
+    let mut heading = String::new();
+    for (code, value) in subfields(field) {
+        if value.is_empty() || CONTROL_SUBFIELDS.contains(code) {
+            continue;
+        }
+        if !heading.is_empty() {
+            heading.push_str(if SUBDIVISIONS.contains(code) {
+                "--"
+            } else {
+                " "
+            });
+        }
+        heading.push_str(value);
+    }
+    if heading.is_empty() {
+        None
+    } else {
+        Some(heading)
+    }
+}
+
+fn note_text(field: &XmlElement) -> String {
+    subfields(field)
+        .filter(|(code, value)| {
+            !value.is_empty() && (*code == 'i' || !CONTROL_SUBFIELDS.contains(*code))
+        })
+        .map(|(_, value)| value)
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
@@ -0,0 +1,477 @@
+/*!
+Importers that create a `Scheme` from legacy vocabulary exchange formats.
+
+Each importer implements the `SchemeImporter` trait, reading the source format into a common
+intermediate form of terms, labels, notes, and relationships which is then assembled into a
+`Scheme`. Concept IRIs are formed by appending the (percent-encoded) term identifier to the
+namespace in the `ImportOptions`; relationships may refer to terms either by identifier or by
+preferred label. Concepts with no broader concept become the top concepts of the scheme.
+
+| Module  | Format                                                                    |
+|---------|---------------------------------------------------------------------------|
+| `zthes` | [Zthes](http://zthes.z3950.org/) XML thesaurus records                    |
+| `marc`  | [MARC 21 Authority](https://www.loc.gov/marc/authority/) records in MARCXML |
+| `csv`   | A simple tabular layout, one concept per row                              |
+
+# Example
+
+```rust
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::import::csv::CsvImporter;
+use rdftk_skos::import::{ImportOptions, SchemeImporter};
+use rdftk_skos::model::Labeled;
+use std::str::FromStr;
+
+let mut content = "id,prefLabel,broader\nanimals,Animals,\ncats,Cats,animals\n".as_bytes();
+
+let options = ImportOptions::new(IRIRef::from(
+    IRI::from_str("http://example.org/thesaurus").unwrap(),
+));
+let scheme = CsvImporter::default().import(&mut content, &options).unwrap();
+
+assert_eq!(scheme.top_concepts().count(), 1);
+assert_eq!(scheme.concepts_flattened().len(), 2);
+```
+
+*/
+
+use crate::model::concept::ConceptRelation;
+use crate::model::properties::LabelKind;
+use crate::model::{Concept, Label, Labeled, LiteralProperty, Propertied, Scheme};
+use rdftk_core::error::{ErrorKind, Result};
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_iri::{IRIRef, IRI};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::rc::Rc;
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Options common to all importers.
+///
+#[derive(Clone, Debug)]
+pub struct ImportOptions {
+    /// The IRI of the created scheme.
+    pub scheme: IRIRef,
+    /// The namespace that term identifiers are appended to when creating concept IRIs. Default
+    /// is the scheme IRI followed by `/`.
+    pub namespace: String,
+    /// The language used for labels and notes where the source does not specify one. Default is
+    /// none.
+    pub language: Option<LanguageTag>,
+    /// A label for the scheme, used in preference to any title in the source.
+    pub label: Option<String>,
+}
+
+///
+/// Implemented by all importers to read a `Scheme` from a legacy format.
+///
+pub trait SchemeImporter {
+    ///
+    /// Read a single scheme from the reader.
+    ///
+    fn import(&self, r: &mut impl Read, options: &ImportOptions) -> Result<Scheme>;
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The intermediate form produced by each importer.
+///
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Term {
+    pub(crate) id: String,
+    pub(crate) labels: Vec<Label>,
+    pub(crate) properties: Vec<LiteralProperty>,
+    pub(crate) relations: Vec<(ConceptRelation, String)>,
+}
+
+///
+/// A minimal XML element tree used by the XML based importers, names are local names only.
+///
+#[derive(Clone, Debug, Default)]
+pub(crate) struct XmlElement {
+    pub(crate) name: String,
+    pub(crate) attributes: Vec<(String, String)>,
+    pub(crate) children: Vec<XmlElement>,
+    pub(crate) text: String,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl ImportOptions {
+    ///
+    /// Create options for a scheme with the given IRI, concepts will be created in the namespace
+    /// formed by the scheme IRI followed by `/`.
+    ///
+    pub fn new(scheme: IRIRef) -> Self {
+        let scheme_str = scheme.to_string();
+        let namespace = if scheme_str.ends_with('/') || scheme_str.ends_with('#') {
+            scheme_str
+        } else {
+            format!("{}/", scheme_str)
+        };
+        Self {
+            scheme,
+            namespace,
+            language: None,
+            label: None,
+        }
+    }
+
+    ///
+    /// Set the namespace used to create concept IRIs.
+    ///
+    pub fn namespace(&mut self, namespace: &str) -> &mut Self {
+        self.namespace = namespace.to_string();
+        self
+    }
+
+    ///
+    /// Set the default language for labels and notes.
+    ///
+    pub fn language(&mut self, language: LanguageTag) -> &mut Self {
+        self.language = Some(language);
+        self
+    }
+
+    ///
+    /// Set the label for the scheme.
+    ///
+    pub fn label(&mut self, label: &str) -> &mut Self {
+        self.label = Some(label.to_string());
+        self
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Term {
+    pub(crate) fn new(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn add_label(
+        &mut self,
+        kind: LabelKind,
+        text: &str,
+        language: &Option<LanguageTag>,
+    ) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        let language = language.as_ref().map(|l| l.to_string()).unwrap_or_default();
+        let label = match kind {
+            LabelKind::Preferred => Label::preferred(text, &language),
+            LabelKind::Alternative => Label::alternative(text, &language),
+            LabelKind::Hidden => Label::hidden(text, &language),
+            LabelKind::Other(kind) => Label::other(kind, text, &language),
+        };
+        if !self.labels.contains(&label) {
+            self.labels.push(label);
+        }
+    }
+
+    pub(crate) fn add_note(
+        &mut self,
+        predicate: &IRIRef,
+        text: &str,
+        language: &Option<LanguageTag>,
+    ) {
+        let text = text.trim();
+        if !text.is_empty() {
+            self.properties.push(match language {
+                None => LiteralProperty::new(predicate.clone(), text),
+                Some(language) => {
+                    LiteralProperty::with_language(predicate.clone(), text, language.clone())
+                }
+            });
+        }
+    }
+
+    pub(crate) fn add_relation(&mut self, relation: ConceptRelation, target: &str) {
+        let target = target.trim();
+        if !target.is_empty() {
+            self.relations.push((relation, target.to_string()));
+        }
+    }
+
+    fn preferred_labels(&self) -> impl Iterator<Item = &String> {
+        self.labels
+            .iter()
+            .filter(|label| label.kind() == &LabelKind::Preferred)
+            .map(|label| label.text())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl XmlElement {
+    pub(crate) fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    pub(crate) fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    pub(crate) fn child_text(&self, name: &str) -> Option<&str> {
+        self.child(name)
+            .map(|child| child.text.trim())
+            .filter(|text| !text.is_empty())
+    }
+
+    pub(crate) fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(attribute, _)| attribute == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+pub(crate) fn import_error(format: &str, message: &str) -> rdftk_core::error::Error {
+    error!("{} import: {}", format, message);
+    ErrorKind::ReadWrite(format.to_string()).into()
+}
+
+///
+/// Parse a language, returning the default language if it is empty or not valid.
+///
+pub(crate) fn language_or(
+    value: Option<&str>,
+    default: &Option<LanguageTag>,
+) -> Option<LanguageTag> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        None => default.clone(),
+        Some(value) => match LanguageTag::from_str(value) {
+            Ok(language) => Some(language),
+            Err(_) => {
+                warn!("ignoring invalid language tag {:?}", value);
+                default.clone()
+            }
+        },
+    }
+}
+
+pub(crate) fn read_xml(r: &mut impl Read, format: &str) -> Result<XmlElement> {
+    use xml::reader::XmlEvent;
+    let mut stack: Vec<XmlElement> = Default::default();
+    for event in xml::EventReader::new(r) {
+        match event {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => stack.push(XmlElement {
+                name: name.local_name,
+                attributes: attributes
+                    .into_iter()
+                    .map(|attribute| (attribute.name.local_name, attribute.value))
+                    .collect(),
+                ..Default::default()
+            }),
+            Ok(XmlEvent::EndElement { .. }) => {
+                let element = stack.pop().unwrap();
+                match stack.last_mut() {
+                    None => return Ok(element),
+                    Some(parent) => parent.children.push(element),
+                }
+            }
+            Ok(XmlEvent::Characters(text)) | Ok(XmlEvent::CData(text)) => {
+                if let Some(parent) = stack.last_mut() {
+                    parent.text.push_str(&text);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => return Err(import_error(format, &format!("error parsing XML: {}", e))),
+        }
+    }
+    Err(import_error(format, "document has no root element"))
+}
+
+///
+/// Assemble the scheme from the imported terms, relationships may refer to terms by identifier
+/// or by preferred label.
+///
+pub(crate) fn build_scheme(
+    terms: Vec<Term>,
+    title: Option<&str>,
+    options: &ImportOptions,
+    format: &str,
+) -> Result<Scheme> {
+    let mut scheme = Scheme::new(&options.scheme);
+    if let Some(title) = options.label.as_deref().or(title) {
+        let language = options
+            .language
+            .as_ref()
+            .map(|l| l.to_string())
+            .unwrap_or_default();
+        scheme.add_preferred_label(title, &language);
+    }
+
+    let mut index: HashMap<String, usize> = Default::default();
+    for (i, term) in terms.iter().enumerate() {
+        if index.insert(term.id.clone(), i).is_some() {
+            return Err(import_error(
+                format,
+                &format!("duplicate term identifier {:?}", term.id),
+            ));
+        }
+    }
+    let mut by_label: HashMap<String, usize> = Default::default();
+    for (i, term) in terms.iter().enumerate() {
+        for label in term.preferred_labels() {
+            let _ = by_label.entry(label.to_lowercase()).or_insert(i);
+        }
+    }
+    let resolve = |target: &str| {
+        index
+            .get(target)
+            .or_else(|| by_label.get(&target.to_lowercase()))
+            .copied()
+    };
+
+    let mut concepts: Vec<Rc<RefCell<Concept>>> = Vec::with_capacity(terms.len());
+    for term in &terms {
+        let uri = format!("{}{}", options.namespace, encode_identifier(&term.id));
+        let uri = IRIRef::from(
+            IRI::from_str(&uri)
+                .map_err(|_| import_error(format, &format!("invalid concept IRI {:?}", uri)))?,
+        );
+        let mut concept = Concept::new(&uri);
+        for label in &term.labels {
+            concept.add_label(label.clone());
+        }
+        for property in &term.properties {
+            concept.add_property(property.clone());
+        }
+        concepts.push(Rc::new(RefCell::new(concept)));
+    }
+
+    // collect the hierarchy as parent -> (relation, child), in input order without duplicates.
+    let mut children: Vec<Vec<(ConceptRelation, usize)>> = vec![Default::default(); terms.len()];
+    let mut has_parent: Vec<bool> = vec![false; terms.len()];
+    let mut seen: HashSet<(usize, usize)> = Default::default();
+    let mut related: HashSet<(usize, usize)> = Default::default();
+    for (i, term) in terms.iter().enumerate() {
+        for (relation, target) in &term.relations {
+            let j = match resolve(target) {
+                Some(j) if j != i => j,
+                Some(_) => continue,
+                None => {
+                    warn!(
+                        "{} import: term {:?} refers to unknown term {:?}",
+                        format, term.id, target
+                    );
+                    continue;
+                }
+            };
+            if relation.is_narrower() || relation.is_broader() {
+                let (parent, child, relation) = if relation.is_narrower() {
+                    (i, j, relation.clone())
+                } else {
+                    (j, i, relation.inverse())
+                };
+                if seen.insert((parent, child)) {
+                    children[parent].push((relation, child));
+                    has_parent[child] = true;
+                }
+            } else if related.insert((i, j)) {
+                concepts[i].borrow_mut().add_related(concepts[j].clone());
+            }
+        }
+    }
+
+    check_acyclic(&terms, &children, format)?;
+
+    for (parent, children) in children.into_iter().enumerate() {
+        for (relation, child) in children {
+            concepts[parent]
+                .borrow_mut()
+                .add_related_concept(relation, concepts[child].clone());
+        }
+    }
+    for (i, concept) in concepts.into_iter().enumerate() {
+        if !has_parent[i] {
+            scheme.add_top_concept(concept);
+        }
+    }
+    Ok(scheme)
+}
+
+fn check_acyclic(
+    terms: &[Term],
+    children: &[Vec<(ConceptRelation, usize)>],
+    format: &str,
+) -> Result<()> {
+    // 0 = unvisited, 1 = in progress, 2 = done
+    let mut state = vec![0u8; children.len()];
+    for start in 0..children.len() {
+        if state[start] != 0 {
+            continue;
+        }
+        let mut stack: Vec<(usize, usize)> = vec![(start, 0)];
+        state[start] = 1;
+        while let Some((node, next)) = stack.pop() {
+            if let Some((_, child)) = children[node].get(next) {
+                stack.push((node, next + 1));
+                match state[*child] {
+                    0 => {
+                        state[*child] = 1;
+                        stack.push((*child, 0));
+                    }
+                    1 => {
+                        return Err(import_error(
+                            format,
+                            &format!(
+                                "broader/narrower cycle between terms {:?} and {:?}",
+                                terms[node].id, terms[*child].id
+                            ),
+                        ))
+                    }
+                    _ => {}
+                }
+            } else {
+                state[node] = 2;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn encode_identifier(id: &str) -> String {
+    let mut encoded = String::with_capacity(id.len());
+    for byte in id.trim().bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+// ------------------------------------------------------------------------------------------------
+// Modules
+// ------------------------------------------------------------------------------------------------
+
+pub mod csv;
+
+pub mod marc;
+
+pub mod zthes;
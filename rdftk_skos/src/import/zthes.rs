@@ -0,0 +1,202 @@
+/*!
+Provides the `ZthesImporter` implementation of the `SchemeImporter` trait.
+
+Each preferred (`PT`) term in a [Zthes](http://zthes.z3950.org/) document becomes a concept with
+the term name as its preferred label; non-preferred (`ND`) terms become alternative labels of the
+term(s) they `USE`. Term notes are mapped to SKOS documentation properties by their `label`
+attribute, and the `BT`, `NT`, and `RT` relations (including the generic, partitive, and
+instantial variants) to the corresponding concept relations. The `thesName` of the thesaurus is
+used as the scheme label.
+
+# Example
+
+```rust
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::import::zthes::ZthesImporter;
+use rdftk_skos::import::{ImportOptions, SchemeImporter};
+use std::str::FromStr;
+
+let mut content = r#"<Zthes>
+  <term>
+    <termId>1</termId><termName>Animals</termName><termType>PT</termType>
+    <relation><relationType>NT</relationType><termId>2</termId></relation>
+  </term>
+  <term>
+    <termId>2</termId><termName>Cats</termName><termType>PT</termType>
+  </term>
+</Zthes>"#.as_bytes();
+
+let options = ImportOptions::new(IRIRef::from(
+    IRI::from_str("http://example.org/animals").unwrap(),
+));
+let scheme = ZthesImporter::default().import(&mut content, &options).unwrap();
+assert_eq!(scheme.concepts_flattened().len(), 2);
+```
+
+*/
+
+use crate::import::{
+    build_scheme, import_error, language_or, read_xml, ImportOptions, SchemeImporter, Term,
+    XmlElement,
+};
+use crate::model::concept::ConceptRelation;
+use crate::model::properties::LabelKind;
+use crate::model::{LiteralProperty, Scheme};
+use crate::ns;
+use rdftk_core::error::Result;
+use rdftk_core::model::literal::LanguageTag;
+use std::io::Read;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// An implementation of the `SchemeImporter` trait for Zthes XML documents.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ZthesImporter {}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Clone, Debug)]
+struct NonPreferred {
+    name: String,
+    language: Option<LanguageTag>,
+    use_for: Vec<String>,
+}
+
+const NAME: &str = "Zthes";
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl SchemeImporter for ZthesImporter {
+    fn import(&self, r: &mut impl Read, options: &ImportOptions) -> Result<Scheme> {
+        let root = read_xml(r, NAME)?;
+        if root.name != "Zthes" {
+            return Err(import_error(
+                NAME,
+                &format!("expected a Zthes document element, not {:?}", root.name),
+            ));
+        }
+
+        let mut terms: Vec<Term> = Default::default();
+        let mut non_preferred: Vec<NonPreferred> = Default::default();
+        for element in root.children("term") {
+            let name = element
+                .child_text("termName")
+                .ok_or_else(|| import_error(NAME, "term has no termName"))?;
+            let language = language_or(element.child_text("termLanguage"), &options.language);
+            match element.child_text("termType").unwrap_or("PT") {
+                "ND" => non_preferred.push(NonPreferred {
+                    name: name.to_string(),
+                    language,
+                    use_for: element
+                        .children("relation")
+                        .filter(|relation| relation.child_text("relationType") == Some("USE"))
+                        .filter_map(relation_target)
+                        .collect(),
+                }),
+                _ => terms.push(read_term(element, name, &language)),
+            }
+        }
+
+        for term in non_preferred {
+            for target in &term.use_for {
+                match terms
+                    .iter_mut()
+                    .find(|t| &t.id == target || t.labels.iter().any(|l| l.text() == target))
+                {
+                    Some(preferred) => {
+                        preferred.add_label(LabelKind::Alternative, &term.name, &term.language)
+                    }
+                    None => warn!(
+                        "{} import: non-preferred term {:?} uses unknown term {:?}",
+                        NAME, term.name, target
+                    ),
+                }
+            }
+        }
+
+        let title = root
+            .child("thes")
+            .and_then(|thes| thes.child_text("thesName"));
+        build_scheme(terms, title, options, NAME)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn read_term(element: &XmlElement, name: &str, language: &Option<LanguageTag>) -> Term {
+    let id = element.child_text("termId").unwrap_or(name);
+    let mut term = Term::new(id);
+    term.add_label(LabelKind::Preferred, name, language);
+
+    for note in element.children("termNote") {
+        let predicate = match note.attribute("label").unwrap_or_default() {
+            "Scope" | "SN" => ns::scope_note(),
+            "Definition" | "DEF" => ns::definition(),
+            "History" | "HN" => ns::history_note(),
+            "Editorial" | "EN" => ns::editorial_note(),
+            "Example" => ns::example(),
+            _ => ns::note(),
+        };
+        let language = language_or(note.attribute("language"), language);
+        term.add_note(predicate, &note.text, &language);
+    }
+    if let Some(created) = element.child_text("termCreatedDate") {
+        term.properties.push(LiteralProperty::created(created));
+    }
+    if let Some(modified) = element.child_text("termModifiedDate") {
+        term.properties.push(LiteralProperty::modified(modified));
+    }
+
+    for relation in element.children("relation") {
+        let relation_type = relation.child_text("relationType").unwrap_or_default();
+        let target = match relation_target(relation) {
+            Some(target) => target,
+            None => {
+                warn!("{} import: relation in term {:?} has no target", NAME, id);
+                continue;
+            }
+        };
+        let relation = match relation_type {
+            "BT" | "BTG" => ConceptRelation::Broader,
+            "NT" | "NTG" => ConceptRelation::Narrower,
+            "BTP" => ConceptRelation::BroaderPartitive,
+            "NTP" => ConceptRelation::NarrowerPartitive,
+            "BTI" => ConceptRelation::BroaderInstantial,
+            "NTI" => ConceptRelation::NarrowerInstantial,
+            "RT" => ConceptRelation::Related,
+            "UF" => {
+                if let Some(name) = relation.child_text("termName") {
+                    term.add_label(LabelKind::Alternative, name, language);
+                }
+                continue;
+            }
+            "USE" => continue,
+            other => {
+                warn!(
+                    "{} import: ignoring unknown relation type {:?} in term {:?}",
+                    NAME, other, id
+                );
+                continue;
+            }
+        };
+        term.add_relation(relation, &target);
+    }
+    term
+}
+
+fn relation_target(relation: &XmlElement) -> Option<String> {
+    relation
+        .child_text("termId")
+        .or_else(|| relation.child_text("termName"))
+        .map(str::to_string)
+}
@@ -33,6 +33,9 @@ TBD
 #[macro_use]
 extern crate lazy_static;
 
+#[macro_use]
+extern crate log;
+
 #[macro_use]
 extern crate rdftk_names;
 
@@ -43,6 +46,11 @@ extern crate rdftk_names;
 #[cfg(feature = "documentation")]
 pub mod document;
 
+#[cfg(feature = "import")]
+pub mod import;
+
 pub mod model;
 
 pub mod ns;
+
+pub mod shapes;
@@ -11,8 +11,8 @@ TBD
 */
 
 use crate::model::properties::final_preferred_label;
-use crate::model::ToStatement;
 use crate::model::{Concept, Label, Labeled, LiteralProperty, Propertied, Resource, ToStatements};
+use crate::model::{RdfOptions, ToStatement};
 use crate::ns;
 use rdftk_core::model::literal::{LanguageTag, LiteralFactoryRef};
 use rdftk_core::model::statement::{
@@ -122,9 +122,10 @@ impl Labeled for Collection {
 }
 
 impl ToStatements for Collection {
-    fn to_statements(
+    fn to_statements_with_options(
         &self,
         in_scheme: Option<&ObjectNodeRef>,
+        options: &RdfOptions,
         statements: &StatementFactoryRef,
         literals: &LiteralFactoryRef,
     ) -> StatementList {
@@ -162,9 +163,18 @@ impl ToStatements for Collection {
         if self.has_members() {
             if self.ordered {
                 let mut list_node = make_list_node(&mut statement_list, None, statements);
+                statement_list.push(
+                    statements
+                        .statement(
+                            subject.clone(),
+                            ns::member_list().clone(),
+                            statements.subject_as_object(list_node.clone()),
+                        )
+                        .unwrap(),
+                );
                 for (i, member) in self.members.iter().enumerate() {
                     add_to_list_node(&mut statement_list, &list_node, &member.uri(), statements);
-                    if i < self.members.len() {
+                    if i + 1 < self.members.len() {
                         list_node =
                             make_list_node(&mut statement_list, Some(list_node), statements);
                     }
@@ -186,7 +196,7 @@ impl ToStatements for Collection {
         }
 
         for label in self.labels() {
-            statement_list.push(label.to_statement(&subject, statements, literals));
+            statement_list.extend(label.to_statements(&subject, options, statements, literals));
         }
         for property in self.properties() {
             statement_list.push(property.to_statement(&subject, statements, literals));
@@ -214,10 +224,24 @@ impl Collection {
         collection
     }
 
+    ///
+    /// A copy of this collection, with its labels and properties but without any members.
+    ///
+    pub(crate) fn detached(&self) -> Self {
+        Self {
+            uri: self.uri.clone(),
+            ordered: self.ordered,
+            members: Default::default(),
+            preferred_label: self.preferred_label.clone(),
+            labels: self.labels.clone(),
+            properties: self.properties.clone(),
+        }
+    }
+
     // --------------------------------------------------------------------------------------------
 
     #[inline]
-    fn add_member_collection(&mut self, collection: Rc<RefCell<Collection>>) {
+    pub(crate) fn add_member_collection(&mut self, collection: Rc<RefCell<Collection>>) {
         self.members.push(Member::Collection(collection));
     }
 
@@ -335,15 +359,6 @@ fn make_list_end(
     last: &SubjectNodeRef,
     factory: &StatementFactoryRef,
 ) {
-    statements.push(
-        factory
-            .statement(
-                last.clone(),
-                rdf::a_type().clone(),
-                factory.named_object(rdf::list().clone()),
-            )
-            .unwrap(),
-    );
     statements.push(
         factory
             .statement(
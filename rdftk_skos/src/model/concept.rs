@@ -12,7 +12,7 @@ TBD
 
 use crate::model::properties::final_preferred_label;
 use crate::model::{Label, Labeled, LiteralProperty, Propertied, Resource, ToStatements};
-use crate::model::{ToStatement, ToUri};
+use crate::model::{RdfOptions, ToStatement, ToUri};
 use crate::ns;
 use rdftk_core::model::literal::{LanguageTag, LiteralFactoryRef};
 use rdftk_core::model::statement::{ObjectNodeRef, StatementFactoryRef, StatementList};
@@ -146,9 +146,10 @@ impl Labeled for Concept {
 }
 
 impl ToStatements for Concept {
-    fn to_statements(
+    fn to_statements_with_options(
         &self,
         in_scheme: Option<&ObjectNodeRef>,
+        options: &RdfOptions,
         statements: &StatementFactoryRef,
         literals: &LiteralFactoryRef,
     ) -> StatementList {
@@ -193,7 +194,7 @@ impl ToStatements for Concept {
             );
         }
         for label in self.labels() {
-            statement_list.push(label.to_statement(&subject, statements, literals));
+            statement_list.extend(label.to_statements(&subject, options, statements, literals));
         }
         for property in self.properties() {
             statement_list.push(property.to_statement(&subject, statements, literals));
@@ -220,6 +221,20 @@ impl Concept {
         concept
     }
 
+    ///
+    /// A copy of this concept, with its labels and properties but without any relations.
+    ///
+    pub(crate) fn detached(&self) -> Self {
+        Self {
+            uri: self.uri.clone(),
+            concepts: Default::default(),
+            external_relations: Default::default(),
+            preferred_label: self.preferred_label.clone(),
+            labels: self.labels.clone(),
+            properties: self.properties.clone(),
+        }
+    }
+
     // --------------------------------------------------------------------------------------------
 
     pub fn add_related_concept(
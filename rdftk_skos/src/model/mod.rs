@@ -1,17 +1,59 @@
 /*!
 A simple model for constructing SKOS thesauri. This is not a complete API in
 that it's extensibility with OWL is limited.
+
+A `Scheme` may be written to a graph with `to_rdf_graph`, and the schemes in an existing graph may
+be read back with `from_rdf_graph`. Labels are written as plain literals unless SKOS-XL labels are
+enabled in the `RdfOptions` passed to `to_rdf_graph_with_options`.
+
+# Example
+
+```rust
+use rdftk_core::simple::graph_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::model::{from_rdf_graph, to_rdf_graph, Scheme};
+use std::str::FromStr;
+
+let mut scheme = Scheme::new_with_label(
+    &IRIRef::from(IRI::from_str("http://example.org/colors").unwrap()),
+    "Colors",
+    "en",
+);
+let red = scheme.new_top_concept_with_label(
+    &IRIRef::from(IRI::from_str("http://example.org/colors/red").unwrap()),
+    "Red",
+    "en",
+);
+let _ = red.borrow_mut().sub_concept_with_label(
+    &IRIRef::from(IRI::from_str("http://example.org/colors/crimson").unwrap()),
+    "Crimson",
+    "en",
+);
+
+let graph = to_rdf_graph(&scheme, None, &graph_factory());
+let schemes = from_rdf_graph(&graph);
+
+assert_eq!(schemes.len(), 1);
+assert_eq!(schemes[0].top_concepts().count(), 1);
+assert_eq!(schemes[0].concepts_flattened().len(), 2);
+```
 */
 
+use crate::model::concept::ConceptRelation;
+use crate::model::properties::LabelKind;
 use crate::ns;
 use rdftk_core::model::graph::mapping::PrefixMappingRef;
-use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
-use rdftk_core::model::literal::{LanguageTag, LiteralFactoryRef};
+use rdftk_core::model::graph::{Graph, GraphFactoryRef, GraphRef};
+use rdftk_core::model::literal::{LanguageTag, LiteralFactoryRef, LiteralRef};
 use rdftk_core::model::statement::{
     ObjectNodeRef, StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
 };
 use rdftk_iri::IRIRef;
-use rdftk_names::{dc, owl};
+use rdftk_names::{dc, owl, rdf};
+use std::cell::RefCell;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
 
 // ------------------------------------------------------------------------------------------------
 // Public Types
@@ -85,6 +127,16 @@ pub trait ToStatements {
         in_scheme: Option<&ObjectNodeRef>,
         statements: &StatementFactoryRef,
         literals: &LiteralFactoryRef,
+    ) -> StatementList {
+        self.to_statements_with_options(in_scheme, &Default::default(), statements, literals)
+    }
+
+    fn to_statements_with_options(
+        &self,
+        in_scheme: Option<&ObjectNodeRef>,
+        options: &RdfOptions,
+        statements: &StatementFactoryRef,
+        literals: &LiteralFactoryRef,
     ) -> StatementList;
 }
 
@@ -101,6 +153,28 @@ pub trait ToUri {
     fn to_uri(&self) -> IRIRef;
 }
 
+///
+/// Options controlling the statements generated when writing a scheme to a graph.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RdfOptions {
+    xl_labels: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The statements of a graph, indexed by subject, used to read schemes.
+///
+struct GraphIndex<'a> {
+    named: HashMap<&'a IRIRef, Vec<&'a StatementRef>>,
+    blank: HashMap<&'a String, Vec<&'a StatementRef>>,
+}
+
+type Edges = HashMap<IRIRef, Vec<IRIRef>>;
+
 // ------------------------------------------------------------------------------------------------
 // Public Functions
 // ------------------------------------------------------------------------------------------------
@@ -109,13 +183,26 @@ pub fn to_rdf_graph(
     scheme: &Scheme,
     default_namespace: Option<IRIRef>,
     factory: &GraphFactoryRef,
+) -> GraphRef {
+    to_rdf_graph_with_options(scheme, default_namespace, factory, &Default::default())
+}
+
+///
+/// Write `scheme` to a new graph, as `to_rdf_graph`, with `options` controlling the form of
+/// the generated statements.
+///
+pub fn to_rdf_graph_with_options(
+    scheme: &Scheme,
+    default_namespace: Option<IRIRef>,
+    factory: &GraphFactoryRef,
+    options: &RdfOptions,
 ) -> GraphRef {
     let ns_mappings = standard_mappings(factory);
     if let Some(default_namespace) = default_namespace {
         let mut ns_mappings = ns_mappings.borrow_mut();
         let _ = ns_mappings.set_default_namespace(default_namespace);
     }
-    to_rdf_graph_with_mappings(scheme, ns_mappings, factory)
+    write_rdf_graph(scheme, ns_mappings, factory, options)
 }
 
 pub fn to_rdf_graph_with_mappings(
@@ -123,35 +210,29 @@ pub fn to_rdf_graph_with_mappings(
     ns_mappings: PrefixMappingRef,
     factory: &GraphFactoryRef,
 ) -> GraphRef {
-    let graph = factory.graph();
-    {
-        let mut graph = graph.borrow_mut();
-        let _ = graph.set_prefix_mappings(ns_mappings);
-
-        for statement in
-            scheme.to_statements(None, &graph.statement_factory(), &graph.literal_factory())
-        {
-            graph.insert(statement.into());
-        }
-    }
-    graph
+    write_rdf_graph(scheme, ns_mappings, factory, &Default::default())
 }
 
-pub fn from_rdf_graph<'a>(_graph: &GraphRef) -> Vec<Scheme> {
-    // let schemes = Default::default();
-    // let graph = graph.borrow();
-    // let scheme_subjects: Vec<&SubjectNodeRef> = graph
-    //     .statements()
-    //     .filter_map(|st| {
-    //         if st.predicate() == rdf::a_type() && object_eq_iri(st.object(), ns::concept_scheme()) {
-    //             Some(st.subject())
-    //         } else {
-    //             None
-    //         }
-    //     })
-    //     .collect();
-    //for subject in scheme_subjects {}
-    todo!()
+///
+/// Read every concept scheme, each subject with the type `skos:ConceptScheme`, from `graph`.
+///
+/// Concepts and collections belong to a scheme if they are linked to it by `skos:inScheme`,
+/// `skos:topConceptOf`, or `skos:hasTopConcept`. Hierarchical relations, stated in either
+/// direction, are attached to the broader concept, and any concept without a broader concept in
+/// the scheme becomes a top concept. Relations to resources outside the scheme are kept as
+/// external relations, and any relation that would introduce a cycle into the concept hierarchy,
+/// or into the nesting of collections, is dropped with a warning. Ordered collection members are
+/// read from the `skos:memberList` list. SKOS-XL labels are read with their IRI, literal form, and
+/// literal properties.
+///
+pub fn from_rdf_graph(graph: &GraphRef) -> Vec<Scheme> {
+    let graph = graph.borrow();
+    let index = GraphIndex::new(&*graph);
+    index
+        .typed(ns::concept_scheme())
+        .iter()
+        .map(|uri| index.scheme(uri))
+        .collect()
 }
 
 pub fn standard_mappings(factory: &GraphFactoryRef) -> PrefixMappingRef {
@@ -178,7 +259,29 @@ pub fn standard_mappings(factory: &GraphFactoryRef) -> PrefixMappingRef {
 // Private Functions
 // ------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)]
+fn write_rdf_graph(
+    scheme: &Scheme,
+    ns_mappings: PrefixMappingRef,
+    factory: &GraphFactoryRef,
+    options: &RdfOptions,
+) -> GraphRef {
+    let graph = factory.graph();
+    {
+        let mut graph = graph.borrow_mut();
+        let _ = graph.set_prefix_mappings(ns_mappings);
+
+        for statement in scheme.to_statements_with_options(
+            None,
+            options,
+            &graph.statement_factory(),
+            &graph.literal_factory(),
+        ) {
+            graph.insert(statement);
+        }
+    }
+    graph
+}
+
 fn object_eq_iri(object: &ObjectNodeRef, iri: &IRIRef) -> bool {
     if let Some(lhs) = object.as_iri() {
         lhs == iri
@@ -187,6 +290,412 @@ fn object_eq_iri(object: &ObjectNodeRef, iri: &IRIRef) -> bool {
     }
 }
 
+///
+/// Returns `true` if `to` can be reached from `from` by following `edges`.
+///
+fn reaches(edges: &Edges, from: &IRIRef, to: &IRIRef) -> bool {
+    let mut visited: HashSet<&IRIRef> = Default::default();
+    let mut stack = vec![from];
+    while let Some(node) = stack.pop() {
+        if node == to {
+            return true;
+        }
+        if visited.insert(node) {
+            stack.extend(edges.get(node).into_iter().flatten());
+        }
+    }
+    false
+}
+
+fn xl_label_kind(predicate: &IRIRef) -> Option<LabelKind> {
+    [
+        LabelKind::Preferred,
+        LabelKind::Alternative,
+        LabelKind::Hidden,
+    ]
+    .iter()
+    .find(|kind| kind.to_xl_uri().as_ref() == Some(predicate))
+    .cloned()
+}
+
+fn literal_property(predicate: &IRIRef, literal: &LiteralRef) -> LiteralProperty {
+    let text = literal.lexical_form();
+    if let Some(data_type) = literal.data_type() {
+        LiteralProperty::with_data_type(predicate.clone(), text, data_type.clone())
+    } else if let Some(language) = literal.language() {
+        LiteralProperty::with_language(predicate.clone(), text, language.clone())
+    } else {
+        LiteralProperty::new(predicate.clone(), text)
+    }
+}
+
+fn hierarchical_relation(predicate: &IRIRef) -> Option<ConceptRelation> {
+    [
+        ConceptRelation::Narrower,
+        ConceptRelation::NarrowerPartitive,
+        ConceptRelation::NarrowerInstantial,
+        ConceptRelation::Broader,
+        ConceptRelation::BroaderPartitive,
+        ConceptRelation::BroaderInstantial,
+    ]
+    .iter()
+    .find(|relation| &relation.to_uri() == predicate)
+    .cloned()
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl RdfOptions {
+    ///
+    /// Set whether labels are written as SKOS-XL label resources, linked by `skosxl:prefLabel`,
+    /// `skosxl:altLabel`, and `skosxl:hiddenLabel`, rather than as plain literals. The default is
+    /// `false`, in which case the IRI and properties of SKOS-XL labels are not written.
+    ///
+    pub fn xl_labels(&mut self, xl_labels: bool) -> &mut Self {
+        self.xl_labels = xl_labels;
+        self
+    }
+
+    ///
+    /// Returns `true` if labels are written as SKOS-XL label resources.
+    ///
+    pub fn has_xl_labels(&self) -> bool {
+        self.xl_labels
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> GraphIndex<'a> {
+    fn new(graph: &'a dyn Graph) -> Self {
+        let mut index = Self {
+            named: Default::default(),
+            blank: Default::default(),
+        };
+        for statement in graph.statements() {
+            let subject = statement.subject();
+            if let Some(uri) = subject.as_iri() {
+                index.named.entry(uri).or_default().push(statement);
+            } else if let Some(label) = subject.as_blank() {
+                index.blank.entry(label).or_default().push(statement);
+            }
+        }
+        index
+    }
+
+    fn statements(&self, uri: &IRIRef) -> impl Iterator<Item = &'a StatementRef> + '_ {
+        self.named.get(uri).into_iter().flatten().copied()
+    }
+
+    fn objects(&self, uri: &IRIRef, predicate: &IRIRef) -> Vec<IRIRef> {
+        self.statements(uri)
+            .filter(|st| st.predicate() == predicate)
+            .filter_map(|st| st.object().as_iri().cloned())
+            .collect()
+    }
+
+    fn object(&self, uri: &IRIRef, predicate: &IRIRef) -> Option<&'a ObjectNodeRef> {
+        self.statements(uri)
+            .find(|st| st.predicate() == predicate)
+            .map(|st| st.object())
+    }
+
+    fn subjects(&self, predicate: &IRIRef, object: &IRIRef) -> BTreeSet<IRIRef> {
+        self.named
+            .iter()
+            .filter(|(_, statements)| {
+                statements
+                    .iter()
+                    .any(|st| st.predicate() == predicate && object_eq_iri(st.object(), object))
+            })
+            .map(|(uri, _)| (*uri).clone())
+            .collect()
+    }
+
+    fn typed(&self, class: &IRIRef) -> BTreeSet<IRIRef> {
+        self.subjects(rdf::a_type(), class)
+    }
+
+    fn has_type(&self, uri: &IRIRef, class: &IRIRef) -> bool {
+        self.statements(uri)
+            .any(|st| st.predicate() == rdf::a_type() && object_eq_iri(st.object(), class))
+    }
+
+    fn scheme(&self, uri: &IRIRef) -> Scheme {
+        let mut scheme = Scheme::new(uri);
+        self.read_literals(uri, &mut scheme);
+
+        let top_concepts: BTreeSet<IRIRef> = self
+            .objects(uri, ns::has_top_concept())
+            .into_iter()
+            .chain(self.subjects(ns::top_concept_of(), uri))
+            .collect();
+        let mut concepts: BTreeMap<IRIRef, Rc<RefCell<Concept>>> = Default::default();
+        let mut collections: BTreeMap<IRIRef, Rc<RefCell<Collection>>> = Default::default();
+        for member in self
+            .subjects(ns::in_scheme(), uri)
+            .into_iter()
+            .chain(top_concepts.iter().cloned())
+        {
+            let ordered = self.has_type(&member, ns::ordered_collection())
+                || self.object(&member, ns::member_list()).is_some();
+            if ordered || self.has_type(&member, ns::collection()) {
+                let mut collection = Collection::new(&member, ordered);
+                self.read_literals(&member, &mut collection);
+                let _ = collections.insert(member, Rc::new(RefCell::new(collection)));
+            } else if let Entry::Vacant(entry) = concepts.entry(member) {
+                let mut concept = Concept::new(entry.key());
+                self.read_literals(entry.key(), &mut concept);
+                let _ = entry.insert(Rc::new(RefCell::new(concept)));
+            }
+        }
+
+        let narrower = self.read_relations(&concepts);
+        for concept in concepts
+            .iter()
+            .filter(|(uri, _)| top_concepts.contains(*uri) || !narrower.contains(*uri))
+            .map(|(_, concept)| concept)
+        {
+            scheme.add_top_concept(concept.clone());
+        }
+
+        let members = self.read_members(&concepts, &collections);
+        for collection in collections
+            .iter()
+            .filter(|(uri, _)| !members.contains(*uri))
+            .map(|(_, collection)| collection)
+        {
+            scheme.add_top_collection(collection.clone());
+        }
+
+        scheme
+    }
+
+    fn node_statements(&self, node: &ObjectNodeRef) -> impl Iterator<Item = &'a StatementRef> + '_ {
+        if let Some(label) = node.as_blank() {
+            self.blank.get(label)
+        } else if let Some(uri) = node.as_iri() {
+            self.named.get(uri)
+        } else {
+            None
+        }
+        .into_iter()
+        .flatten()
+        .copied()
+    }
+
+    fn read_literals(&self, uri: &IRIRef, resource: &mut impl Resource) {
+        for statement in self.statements(uri) {
+            let predicate = statement.predicate();
+            if let Some(kind) = xl_label_kind(predicate) {
+                if let Some(label) = self.xl_label(kind, statement.object()) {
+                    resource.add_label(label);
+                }
+            } else if let Some(literal) = statement.object().as_literal() {
+                let text = literal.lexical_form();
+                let language = literal
+                    .language()
+                    .map(|language| language.to_string())
+                    .unwrap_or_default();
+                if predicate == ns::pref_label() {
+                    resource.add_label(Label::preferred(text, &language));
+                } else if predicate == ns::alt_label() {
+                    resource.add_label(Label::alternative(text, &language));
+                } else if predicate == ns::hidden_label() {
+                    resource.add_label(Label::hidden(text, &language));
+                } else {
+                    resource.add_property(literal_property(predicate, literal));
+                }
+            }
+        }
+    }
+
+    ///
+    /// Read the SKOS-XL label resource `node`, returning `None` if it has no literal form.
+    ///
+    fn xl_label(&self, kind: LabelKind, node: &ObjectNodeRef) -> Option<Label> {
+        let literal_form = self
+            .node_statements(node)
+            .filter(|st| st.predicate() == ns::xl::literal_form())
+            .find_map(|st| st.object().as_literal());
+        let literal_form = match literal_form {
+            Some(literal_form) => literal_form,
+            None => {
+                warn!("ignoring SKOS-XL label {}, it has no literal form", node);
+                return None;
+            }
+        };
+        let text = literal_form.lexical_form();
+        let language = literal_form
+            .language()
+            .map(|language| language.to_string())
+            .unwrap_or_default();
+        let mut label = match node.as_iri() {
+            Some(uri) => Label::xl(uri, kind, text, &language),
+            None => Label::new(kind, text, &language),
+        };
+        for statement in self
+            .node_statements(node)
+            .filter(|st| st.predicate() != ns::xl::literal_form())
+        {
+            if let Some(literal) = statement.object().as_literal() {
+                label.add_property(literal_property(statement.predicate(), literal));
+            }
+        }
+        Some(label)
+    }
+
+    ///
+    /// Add relations between concepts, returning the set of concepts that are narrower than
+    /// some other concept in the scheme.
+    ///
+    fn read_relations(&self, concepts: &BTreeMap<IRIRef, Rc<RefCell<Concept>>>) -> HashSet<IRIRef> {
+        let mut hierarchy: Vec<(IRIRef, ConceptRelation, IRIRef)> = Default::default();
+        for (uri, concept) in concepts {
+            for statement in self.statements(uri) {
+                let predicate = statement.predicate();
+                let object = match statement.object().as_iri() {
+                    Some(object) => object,
+                    None => continue,
+                };
+                if predicate == ns::in_scheme()
+                    || predicate == ns::top_concept_of()
+                    || xl_label_kind(predicate).is_some()
+                    || (predicate == rdf::a_type() && object == ns::concept())
+                {
+                    continue;
+                }
+                match (hierarchical_relation(predicate), concepts.get(object)) {
+                    (_, Some(_)) if object == uri => {
+                        warn!(
+                            "ignoring relation {} from concept {} to itself",
+                            predicate, uri
+                        );
+                    }
+                    (Some(relation), Some(_)) if relation.is_narrower() => {
+                        hierarchy.push((uri.clone(), relation, object.clone()));
+                    }
+                    (Some(relation), Some(_)) => {
+                        hierarchy.push((object.clone(), relation.inverse(), uri.clone()));
+                    }
+                    (None, Some(related)) if predicate == ns::related() => {
+                        concept.borrow_mut().add_related(related.clone());
+                    }
+                    _ => {
+                        concept
+                            .borrow_mut()
+                            .add_external_relation(predicate.clone(), object.clone());
+                    }
+                }
+            }
+        }
+
+        let mut edges: Edges = Default::default();
+        let mut narrower: HashSet<IRIRef> = Default::default();
+        for (parent, relation, child) in hierarchy {
+            if edges
+                .get(&parent)
+                .is_some_and(|children| children.contains(&child))
+            {
+                continue;
+            }
+            if reaches(&edges, &child, &parent) {
+                warn!(
+                    "ignoring {} relation from {} to {}, it would create a cycle",
+                    relation.to_uri(),
+                    parent,
+                    child
+                );
+                continue;
+            }
+            concepts[&parent]
+                .borrow_mut()
+                .add_related_concept(relation, concepts[&child].clone());
+            edges.entry(parent).or_default().push(child.clone());
+            let _ = narrower.insert(child);
+        }
+        narrower
+    }
+
+    ///
+    /// Add members to each collection, returning the set of collections that are members of some
+    /// other collection in the scheme.
+    ///
+    fn read_members(
+        &self,
+        concepts: &BTreeMap<IRIRef, Rc<RefCell<Concept>>>,
+        collections: &BTreeMap<IRIRef, Rc<RefCell<Collection>>>,
+    ) -> HashSet<IRIRef> {
+        let mut edges: Edges = Default::default();
+        let mut nested: HashSet<IRIRef> = Default::default();
+        for (uri, collection) in collections {
+            let ordered = collection.borrow().is_ordered();
+            let members = if ordered {
+                self.object(uri, ns::member_list())
+                    .map(|head| self.list(head))
+                    .unwrap_or_default()
+            } else {
+                self.objects(uri, ns::member())
+                    .into_iter()
+                    .collect::<BTreeSet<IRIRef>>()
+                    .into_iter()
+                    .collect()
+            };
+            for member in members {
+                if let Some(concept) = concepts.get(&member) {
+                    collection.borrow_mut().add_member_concept(concept.clone());
+                } else if let Some(member_collection) = collections.get(&member) {
+                    if reaches(&edges, &member, uri) {
+                        warn!(
+                            "ignoring member {} of collection {}, it would create a cycle",
+                            member, uri
+                        );
+                    } else {
+                        collection
+                            .borrow_mut()
+                            .add_member_collection(member_collection.clone());
+                        edges.entry(uri.clone()).or_default().push(member.clone());
+                        let _ = nested.insert(member);
+                    }
+                } else {
+                    warn!(
+                        "ignoring member {} of collection {}, it is not in the scheme",
+                        member, uri
+                    );
+                }
+            }
+        }
+        nested
+    }
+
+    ///
+    /// Return the IRI members of the `rdf:List` starting at the node `head`.
+    ///
+    fn list(&self, head: &'a ObjectNodeRef) -> Vec<IRIRef> {
+        let mut members: Vec<IRIRef> = Default::default();
+        let mut visited: HashSet<&ObjectNodeRef> = Default::default();
+        let mut next = Some(head);
+        while let Some(node) = next.take() {
+            if !visited.insert(node) {
+                warn!("ignoring cycle in list starting at {}", head);
+                break;
+            }
+            for statement in self.node_statements(node) {
+                if statement.predicate() == rdf::first() {
+                    if let Some(member) = statement.object().as_iri() {
+                        members.push(member.clone());
+                    }
+                } else if statement.predicate() == rdf::rest() {
+                    next = Some(statement.object());
+                }
+            }
+        }
+        members
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 // Modules
 // ------------------------------------------------------------------------------------------------
@@ -202,3 +711,6 @@ pub use collection::Collection;
 
 pub mod properties;
 pub use properties::{Label, LiteralProperty};
+
+pub mod validation;
+pub use validation::{validate, Severity, Violation, ViolationKind};
@@ -10,12 +10,14 @@ TBD
 
 */
 
-use crate::model::{Labeled, ToStatement, ToUri};
+use crate::model::{Labeled, Propertied, RdfOptions, ToStatement, ToUri};
 use crate::ns;
 use rdftk_core::model::literal::{DataType, LanguageTag, LiteralFactoryRef, LiteralRef};
-use rdftk_core::model::statement::{StatementFactoryRef, StatementRef, SubjectNodeRef};
+use rdftk_core::model::statement::{
+    StatementFactoryRef, StatementList, StatementRef, SubjectNodeRef,
+};
 use rdftk_iri::IRIRef;
-use rdftk_names::dc;
+use rdftk_names::{dc, rdf};
 use std::str::FromStr;
 
 // ------------------------------------------------------------------------------------------------
@@ -30,11 +32,17 @@ pub enum LabelKind {
     Other(IRIRef),
 }
 
+///
+/// A label, either a plain literal or, if it has its own IRI or properties, a SKOS-XL label
+/// resource with the label text as its `skosxl:literalForm`.
+///
 #[derive(Clone, Debug, PartialEq)]
 pub struct Label {
     kind: LabelKind,
     text: String,
     language: Option<LanguageTag>,
+    uri: Option<IRIRef>,
+    properties: Vec<LiteralProperty>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -84,6 +92,21 @@ impl ToUri for LabelKind {
     }
 }
 
+impl LabelKind {
+    ///
+    /// The SKOS-XL predicate for this kind of label, if one is defined.
+    ///
+    pub fn to_xl_uri(&self) -> Option<IRIRef> {
+        match self {
+            Self::Preferred => Some(ns::xl::pref_label()),
+            Self::Alternative => Some(ns::xl::alt_label()),
+            Self::Hidden => Some(ns::xl::hidden_label()),
+            Self::Other(_) => None,
+        }
+        .cloned()
+    }
+}
+
 // ------------------------------------------------------------------------------------------------
 
 impl ToStatement for Label {
@@ -97,16 +120,22 @@ impl ToStatement for Label {
             .statement(
                 subject.clone(),
                 self.kind.to_uri(),
-                statements.literal_object(if let Some(language) = &self.language {
-                    literals.with_language(&self.text, language.clone())
-                } else {
-                    literals.literal(&self.text)
-                }),
+                statements.literal_object(self.make_literal(literals)),
             )
             .unwrap()
     }
 }
 
+impl Propertied for Label {
+    fn add_property(&mut self, property: LiteralProperty) {
+        self.properties.push(property);
+    }
+
+    fn properties(&self) -> &Vec<LiteralProperty> {
+        &self.properties
+    }
+}
+
 impl Label {
     pub fn preferred(text: &str, language: &str) -> Self {
         Self::new(LabelKind::Preferred, text, language)
@@ -124,7 +153,16 @@ impl Label {
         Self::new(LabelKind::Other(kind), text, language)
     }
 
-    fn new(kind: LabelKind, text: &str, language: &str) -> Self {
+    ///
+    /// Create a SKOS-XL label, identified by `uri`, with `text` as its literal form.
+    ///
+    pub fn xl(uri: &IRIRef, kind: LabelKind, text: &str, language: &str) -> Self {
+        let mut label = Self::new(kind, text, language);
+        label.uri = Some(uri.clone());
+        label
+    }
+
+    pub(crate) fn new(kind: LabelKind, text: &str, language: &str) -> Self {
         Self {
             kind,
             text: text.to_string(),
@@ -133,6 +171,8 @@ impl Label {
             } else {
                 Some(LanguageTag::from_str(language).unwrap())
             },
+            uri: None,
+            properties: Default::default(),
         }
     }
 
@@ -149,6 +189,81 @@ impl Label {
     pub fn language(&self) -> Option<&LanguageTag> {
         self.language.as_ref()
     }
+
+    ///
+    /// The IRI of this label, if it is a SKOS-XL label resource.
+    ///
+    pub fn uri(&self) -> Option<&IRIRef> {
+        self.uri.as_ref()
+    }
+
+    ///
+    /// Returns `true` if this label has its own IRI or properties, and so is only fully
+    /// represented by a SKOS-XL label resource.
+    ///
+    pub fn is_xl(&self) -> bool {
+        self.uri.is_some() || !self.properties.is_empty()
+    }
+
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Return the statements for this label on `subject`. If `options` enables SKOS-XL labels, and
+    /// the kind of label has a SKOS-XL predicate, this is the reified form: the subject refers to
+    /// a `skosxl:Label`, identified by the label's IRI or a blank node, with the text as its
+    /// `skosxl:literalForm` and any label properties. Otherwise this is the plain literal form.
+    ///
+    pub(crate) fn to_statements(
+        &self,
+        subject: &SubjectNodeRef,
+        options: &RdfOptions,
+        statements: &StatementFactoryRef,
+        literals: &LiteralFactoryRef,
+    ) -> StatementList {
+        let predicate = match self.kind.to_xl_uri() {
+            Some(predicate) if options.has_xl_labels() => predicate,
+            _ => return vec![self.to_statement(subject, statements, literals)],
+        };
+        let label = match &self.uri {
+            Some(uri) => statements.named_subject(uri.clone()),
+            None => statements.blank_subject(),
+        };
+        let mut statement_list: StatementList = vec![
+            statements
+                .statement(
+                    subject.clone(),
+                    predicate,
+                    statements.subject_as_object(label.clone()),
+                )
+                .unwrap(),
+            statements
+                .statement(
+                    label.clone(),
+                    rdf::a_type().clone(),
+                    statements.named_object(ns::xl::label().clone()),
+                )
+                .unwrap(),
+            statements
+                .statement(
+                    label.clone(),
+                    ns::xl::literal_form().clone(),
+                    statements.literal_object(self.make_literal(literals)),
+                )
+                .unwrap(),
+        ];
+        for property in &self.properties {
+            statement_list.push(property.to_statement(&label, statements, literals));
+        }
+        statement_list
+    }
+
+    fn make_literal(&self, literals: &LiteralFactoryRef) -> LiteralRef {
+        if let Some(language) = &self.language {
+            literals.with_language(&self.text, language.clone())
+        } else {
+            literals.literal(&self.text)
+        }
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
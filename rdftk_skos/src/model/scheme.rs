@@ -10,17 +10,21 @@ TBD
 
 */
 
+use crate::model::collection::Member;
+use crate::model::concept::ConceptRelation;
 use crate::model::properties::final_preferred_label;
-use crate::model::ToStatement;
 use crate::model::{
     Collection, Concept, Label, Labeled, LiteralProperty, Propertied, Resource, ToStatements,
 };
+use crate::model::{RdfOptions, ToStatement};
 use crate::ns;
 use rdftk_core::model::literal::{LanguageTag, LiteralFactoryRef};
 use rdftk_core::model::statement::{ObjectNodeRef, StatementFactoryRef, StatementList};
 use rdftk_iri::IRIRef;
 use rdftk_names::rdf;
 use std::cell::RefCell;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 // ------------------------------------------------------------------------------------------------
@@ -83,9 +87,10 @@ impl Labeled for Scheme {
 }
 
 impl ToStatements for Scheme {
-    fn to_statements(
+    fn to_statements_with_options(
         &self,
         _: Option<&ObjectNodeRef>,
+        options: &RdfOptions,
         statements: &StatementFactoryRef,
         literals: &LiteralFactoryRef,
     ) -> StatementList {
@@ -110,7 +115,7 @@ impl ToStatements for Scheme {
             statement_list.extend(
                 member
                     .borrow()
-                    .to_statements(Some(&in_scheme), statements, literals)
+                    .to_statements_with_options(Some(&in_scheme), options, statements, literals)
                     .drain(..),
             );
             if top_concepts.contains(&member.borrow().uri().to_string()) {
@@ -138,7 +143,7 @@ impl ToStatements for Scheme {
             statement_list.extend(
                 member
                     .borrow()
-                    .to_statements(Some(&in_scheme), statements, literals)
+                    .to_statements_with_options(Some(&in_scheme), options, statements, literals)
                     .drain(..),
             );
             statement_list.push(
@@ -152,7 +157,7 @@ impl ToStatements for Scheme {
             );
         }
         for label in self.labels() {
-            statement_list.push(label.to_statement(&subject, statements, literals));
+            statement_list.extend(label.to_statements(&subject, options, statements, literals));
         }
         for property in self.properties() {
             statement_list.push(property.to_statement(&subject, statements, literals));
@@ -199,7 +204,7 @@ impl Scheme {
     }
 
     #[inline]
-    fn add_top_concept(&mut self, concept: Rc<RefCell<Concept>>) {
+    pub(crate) fn add_top_concept(&mut self, concept: Rc<RefCell<Concept>>) {
         self.concepts.push(concept);
     }
 
@@ -246,7 +251,7 @@ impl Scheme {
     }
 
     #[inline]
-    fn add_top_collection(&mut self, collection: Rc<RefCell<Collection>>) {
+    pub(crate) fn add_top_collection(&mut self, collection: Rc<RefCell<Collection>>) {
         self.collections.push(collection);
     }
 
@@ -269,4 +274,132 @@ impl Scheme {
             .flatten()
             .collect()
     }
+
+    // --------------------------------------------------------------------------------------------
+
+    ///
+    /// Create a new, self-contained, scheme containing copies of the concept `root_concept` and
+    /// all of its narrower descendants, with `root_concept` as its only top concept. The new
+    /// scheme has the same IRI, labels, and properties as this one, and contains those
+    /// collections that have members in the extracted concepts, with only those members.
+    /// Relations between the extracted concepts are retained and relations to any other concept
+    /// are dropped.
+    ///
+    /// If `include_mappings` is `true` the external relations of the extracted concepts are
+    /// retained, and each relation between an extracted concept and a concept left in this
+    /// scheme is replaced by the corresponding SKOS mapping relation, `skos:broadMatch`,
+    /// `skos:narrowMatch`, or `skos:relatedMatch`, to the concept's IRI.
+    ///
+    /// Returns `None` if `root_concept` is not a concept in this scheme.
+    ///
+    pub fn extract_subtree(&self, root_concept: &IRIRef, include_mappings: bool) -> Option<Self> {
+        let all_concepts = self.concepts_flattened();
+        let root = all_concepts
+            .iter()
+            .find(|concept| concept.borrow().uri() == root_concept)?;
+
+        let mut subtree = root.borrow().concepts_flattened();
+        subtree.push(root.clone());
+        let mut copies: HashMap<IRIRef, Rc<RefCell<Concept>>> = Default::default();
+        subtree.retain(|concept| {
+            let concept = concept.borrow();
+            match copies.entry(concept.uri().clone()) {
+                Entry::Occupied(_) => false,
+                Entry::Vacant(entry) => {
+                    let _ = entry.insert(Rc::new(RefCell::new(concept.detached())));
+                    true
+                }
+            }
+        });
+
+        for concept in &subtree {
+            let concept = concept.borrow();
+            let mut copy = copies[concept.uri()].borrow_mut();
+            for (relation, related) in concept.concepts() {
+                let related = related.borrow();
+                if let Some(related) = copies.get(related.uri()) {
+                    copy.add_related_concept(relation.clone(), related.clone());
+                } else if include_mappings {
+                    add_mapping(&mut copy, relation, related.uri());
+                }
+            }
+            if include_mappings {
+                for (relation, related) in concept.external_relations() {
+                    copy.add_external_relation(relation.clone(), related.clone());
+                }
+            }
+        }
+
+        if include_mappings {
+            for concept in &all_concepts {
+                let concept = concept.borrow();
+                if copies.contains_key(concept.uri()) {
+                    continue;
+                }
+                for (relation, related) in concept.concepts() {
+                    if let Some(copy) = copies.get(related.borrow().uri()) {
+                        add_mapping(&mut copy.borrow_mut(), &relation.inverse(), concept.uri());
+                    }
+                }
+            }
+        }
+
+        let mut scheme = Self::new(&self.uri);
+        scheme.preferred_label = self.preferred_label.clone();
+        scheme.labels = self.labels.clone();
+        scheme.properties = self.properties.clone();
+        scheme.add_top_concept(copies[root_concept].clone());
+        for collection in &self.collections {
+            if let Some(collection) = extract_collection(&collection.borrow(), &copies) {
+                scheme.add_top_collection(collection);
+            }
+        }
+        Some(scheme)
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn add_mapping(concept: &mut Concept, relation: &ConceptRelation, related: &IRIRef) {
+    let mapping = if relation.is_narrower() {
+        ns::narrow_match()
+    } else if relation.is_broader() {
+        ns::broad_match()
+    } else {
+        ns::related_match()
+    };
+    if !concept
+        .external_relations()
+        .any(|(predicate, object)| predicate == mapping && object == related)
+    {
+        concept.add_external_relation(mapping.clone(), related.clone());
+    }
+}
+
+fn extract_collection(
+    collection: &Collection,
+    concepts: &HashMap<IRIRef, Rc<RefCell<Concept>>>,
+) -> Option<Rc<RefCell<Collection>>> {
+    let mut copy = collection.detached();
+    for member in collection.members() {
+        match member {
+            Member::Concept(concept) => {
+                if let Some(concept) = concepts.get(concept.borrow().uri()) {
+                    copy.add_member_concept(concept.clone());
+                }
+            }
+            Member::Collection(member) => {
+                if let Some(member) = extract_collection(&member.borrow(), concepts) {
+                    copy.add_member_collection(member);
+                }
+            }
+        }
+    }
+    if copy.has_members() {
+        Some(Rc::new(RefCell::new(copy)))
+    } else {
+        None
+    }
 }
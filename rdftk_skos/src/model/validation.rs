@@ -0,0 +1,494 @@
+/*!
+Check a scheme against the [integrity conditions](https://www.w3.org/TR/skos-reference/#L1170)
+of the SKOS reference, along with a small number of common quality issues, and return a structured
+diagnostic for each problem found.
+
+The following are reported with the severity `Error`, as they make the resulting graph
+inconsistent with the SKOS data model:
+
+* **S9**, a resource that is both a concept scheme and a concept;
+* **S13**, the same text used, in the same language, for more than one of a resource's
+  `skos:prefLabel`, `skos:altLabel`, and `skos:hiddenLabel`;
+* **S14**, more than one `skos:prefLabel` in the same language;
+* **S27**, two concepts that are `skos:related` as well as connected by the transitive closure of
+  `skos:broader`; and
+* **S37**, a resource that is both a collection and a concept.
+
+In addition, a cycle in the `skos:broader` hierarchy is a `Warning`, and a concept without a
+`skos:prefLabel` is reported as `Info`.
+
+# Example
+
+```rust
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::model::{validate, Labeled, Scheme, Severity, ViolationKind};
+use std::str::FromStr;
+
+let iri = |s: &str| IRIRef::from(IRI::from_str(&format!("http://example.org/{}", s)).unwrap());
+
+let mut scheme = Scheme::new_with_label(&iri("colors"), "Colors", "en");
+let red = scheme.new_top_concept_with_label(&iri("red"), "Red", "en");
+red.borrow_mut().add_alternative_label("Red", "en");
+
+let violations = validate(&scheme);
+assert_eq!(violations.len(), 1);
+assert_eq!(violations[0].severity(), Severity::Error);
+assert!(matches!(violations[0].kind(), ViolationKind::OverlappingLabels { .. }));
+assert_eq!(violations[0].subject(), &iri("red"));
+```
+
+*/
+
+use crate::model::properties::LabelKind;
+use crate::model::{Collection, Concept, Label, Labeled, Resource, Scheme, ToUri};
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_iri::IRIRef;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// How serious a violation is.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// A possible quality issue, the scheme is still consistent.
+    Info,
+    /// A likely modeling mistake, the scheme is still consistent.
+    Warning,
+    /// The scheme breaks one of the SKOS integrity conditions.
+    Error,
+}
+
+///
+/// The kind of problem found, with any details that do not identify a resource.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum ViolationKind {
+    /// S9; the subject is used both as a concept scheme and as a concept.
+    SchemeIsConcept,
+    /// S37; the subject is used both as a collection and as a concept.
+    CollectionIsConcept,
+    /// S13; the subject has the same label text, in the same language, as two kinds of label.
+    OverlappingLabels {
+        /// The label text.
+        text: String,
+        /// The label language, if any.
+        language: Option<LanguageTag>,
+        /// The kinds of label sharing the text.
+        kinds: (LabelKind, LabelKind),
+    },
+    /// S14; the subject has more than one preferred label in the same language.
+    MultiplePreferredLabels {
+        /// The language shared by the labels, if any.
+        language: Option<LanguageTag>,
+        /// The text of each preferred label in that language.
+        texts: Vec<String>,
+    },
+    /// S27; the subject is related to the concepts in `related`, which are also broader or
+    /// narrower than it.
+    RelatedToBroader,
+    /// The subject is broader than itself, by way of the concepts in `related`.
+    BroaderCycle,
+    /// The subject, a concept, has no preferred label.
+    MissingPreferredLabel,
+}
+
+///
+/// A single problem found by `validate`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    severity: Severity,
+    kind: ViolationKind,
+    subject: IRIRef,
+    related: Vec<IRIRef>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+#[derive(Default)]
+struct ConceptIndex {
+    concepts: BTreeMap<IRIRef, Vec<Rc<RefCell<Concept>>>>,
+    broader: BTreeMap<IRIRef, BTreeSet<IRIRef>>,
+    related: BTreeSet<(IRIRef, IRIRef)>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Check `scheme`, and every concept and collection reachable from it, returning the violations
+/// found; an empty list means the scheme is valid. Violations are ordered by kind of check, and
+/// then by subject IRI.
+///
+pub fn validate(scheme: &Scheme) -> Vec<Violation> {
+    let mut violations: Vec<Violation> = Default::default();
+    let index = ConceptIndex::new(scheme);
+    let collections = scheme.collections_flattened();
+
+    if index.concepts.contains_key(scheme.uri()) {
+        violations.push(Violation::new(
+            ViolationKind::SchemeIsConcept,
+            scheme.uri(),
+            Default::default(),
+        ));
+    }
+    let mut seen: BTreeSet<IRIRef> = Default::default();
+    for collection in &collections {
+        let collection = collection.borrow();
+        if index.concepts.contains_key(collection.uri()) && seen.insert(collection.uri().clone()) {
+            violations.push(Violation::new(
+                ViolationKind::CollectionIsConcept,
+                collection.uri(),
+                Default::default(),
+            ));
+        }
+    }
+
+    check_labels(scheme.uri(), scheme.labels().iter(), &mut violations);
+    for (uri, concepts) in &index.concepts {
+        let labels: Vec<_> = concepts
+            .iter()
+            .flat_map(|concept| concept.borrow().labels().clone())
+            .collect();
+        check_labels(uri, labels.iter(), &mut violations);
+        if !labels
+            .iter()
+            .any(|label| label.kind() == &LabelKind::Preferred)
+        {
+            violations.push(Violation::new(
+                ViolationKind::MissingPreferredLabel,
+                uri,
+                Default::default(),
+            ));
+        }
+    }
+    for collection in &collections {
+        let collection: &Collection = &collection.borrow();
+        check_labels(
+            collection.uri(),
+            collection.labels().iter(),
+            &mut violations,
+        );
+    }
+
+    for (lhs, rhs) in &index.related {
+        if index.broader_closure(lhs).contains(rhs) || index.broader_closure(rhs).contains(lhs) {
+            violations.push(Violation::new(
+                ViolationKind::RelatedToBroader,
+                lhs,
+                vec![rhs.clone()],
+            ));
+        }
+    }
+
+    for uri in index.concepts.keys() {
+        if index.broader_closure(uri).contains(uri) {
+            let via: Vec<IRIRef> = index
+                .broader
+                .get(uri)
+                .map(|broader| {
+                    broader
+                        .iter()
+                        .filter(|broader| {
+                            *broader == uri || index.broader_closure(broader).contains(uri)
+                        })
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default();
+            violations.push(Violation::new(ViolationKind::BroaderCycle, uri, via));
+        }
+    }
+
+    violations.sort_by(|lhs, rhs| {
+        lhs.kind
+            .order()
+            .cmp(&rhs.kind.order())
+            .then_with(|| lhs.subject.cmp(&rhs.subject))
+    });
+    violations
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Info => "info",
+                Self::Warning => "warning",
+                Self::Error => "error",
+            }
+        )
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ViolationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SchemeIsConcept => write!(f, "resource is both a concept scheme and a concept"),
+            Self::CollectionIsConcept => write!(f, "resource is both a collection and a concept"),
+            Self::OverlappingLabels {
+                text,
+                language,
+                kinds,
+            } => write!(
+                f,
+                "label {:?}{} is used as both {} and {}",
+                text,
+                language_suffix(language),
+                kinds.0.to_uri(),
+                kinds.1.to_uri()
+            ),
+            Self::MultiplePreferredLabels { language, texts } => write!(
+                f,
+                "more than one preferred label{}; {}",
+                language_suffix(language),
+                texts
+                    .iter()
+                    .map(|text| format!("{:?}", text))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::RelatedToBroader => {
+                write!(f, "concept is related to a broader or narrower concept")
+            }
+            Self::BroaderCycle => write!(f, "concept is broader than itself"),
+            Self::MissingPreferredLabel => write!(f, "concept has no preferred label"),
+        }
+    }
+}
+
+impl ViolationKind {
+    ///
+    /// Return the severity of violations of this kind.
+    ///
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::BroaderCycle => Severity::Warning,
+            Self::MissingPreferredLabel => Severity::Info,
+            _ => Severity::Error,
+        }
+    }
+
+    fn order(&self) -> usize {
+        match self {
+            Self::SchemeIsConcept => 0,
+            Self::CollectionIsConcept => 1,
+            Self::OverlappingLabels { .. } => 2,
+            Self::MultiplePreferredLabels { .. } => 3,
+            Self::RelatedToBroader => 4,
+            Self::BroaderCycle => 5,
+            Self::MissingPreferredLabel => 6,
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: <{}> {}", self.severity, self.subject, self.kind)?;
+        if !self.related.is_empty() {
+            write!(
+                f,
+                " ({})",
+                self.related
+                    .iter()
+                    .map(|iri| format!("<{}>", iri))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Violation {
+    fn new(kind: ViolationKind, subject: &IRIRef, related: Vec<IRIRef>) -> Self {
+        Self {
+            severity: kind.severity(),
+            kind,
+            subject: subject.clone(),
+            related,
+        }
+    }
+
+    ///
+    /// Return the severity of this violation.
+    ///
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    ///
+    /// Return the kind of problem found.
+    ///
+    pub fn kind(&self) -> &ViolationKind {
+        &self.kind
+    }
+
+    ///
+    /// Return the IRI of the scheme, concept, or collection with the problem.
+    ///
+    pub fn subject(&self) -> &IRIRef {
+        &self.subject
+    }
+
+    ///
+    /// Return the IRIs of any other resources involved in the problem.
+    ///
+    pub fn related(&self) -> &Vec<IRIRef> {
+        &self.related
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ConceptIndex {
+    fn new(scheme: &Scheme) -> Self {
+        let mut index = Self::default();
+        let mut pending: Vec<Rc<RefCell<Concept>>> = scheme.top_concepts().cloned().collect();
+        for collection in scheme.collections_flattened() {
+            pending.extend(
+                collection
+                    .borrow()
+                    .members()
+                    .filter_map(|member| member.as_concept())
+                    .cloned(),
+            );
+        }
+        while let Some(concept) = pending.pop() {
+            let uri = concept.borrow().uri().clone();
+            let known = index.concepts.entry(uri.clone()).or_default();
+            if known.iter().any(|other| Rc::ptr_eq(other, &concept)) {
+                continue;
+            }
+            known.push(concept.clone());
+            for (relation, other) in concept.borrow().concepts() {
+                let other_uri = other.borrow().uri().clone();
+                if relation.is_narrower() {
+                    let _ = index
+                        .broader
+                        .entry(other_uri.clone())
+                        .or_default()
+                        .insert(uri.clone());
+                } else if relation.is_broader() {
+                    let _ = index
+                        .broader
+                        .entry(uri.clone())
+                        .or_default()
+                        .insert(other_uri.clone());
+                } else if uri < other_uri {
+                    let _ = index.related.insert((uri.clone(), other_uri.clone()));
+                } else {
+                    let _ = index.related.insert((other_uri.clone(), uri.clone()));
+                }
+                pending.push(other.clone());
+            }
+        }
+        index
+    }
+
+    fn broader_closure(&self, uri: &IRIRef) -> BTreeSet<IRIRef> {
+        let mut closure: BTreeSet<IRIRef> = Default::default();
+        let mut pending: Vec<&IRIRef> = vec![uri];
+        while let Some(next) = pending.pop() {
+            if let Some(broader) = self.broader.get(next) {
+                for broader in broader {
+                    if closure.insert(broader.clone()) {
+                        pending.push(broader);
+                    }
+                }
+            }
+        }
+        closure
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn check_labels<'a>(
+    subject: &IRIRef,
+    labels: impl Iterator<Item = &'a Label>,
+    violations: &mut Vec<Violation>,
+) {
+    let mut by_text: BTreeMap<(String, Option<String>), (LabelKind, Option<LanguageTag>)> =
+        Default::default();
+    let mut preferred: BTreeMap<Option<String>, (Option<LanguageTag>, Vec<String>)> =
+        Default::default();
+    for label in labels {
+        let language = label.language().map(|language| language.to_string());
+        if is_lexical(label.kind()) {
+            match by_text.get(&(label.text().clone(), language.clone())) {
+                Some((kind, language)) if kind != label.kind() => {
+                    violations.push(Violation::new(
+                        ViolationKind::OverlappingLabels {
+                            text: label.text().clone(),
+                            language: language.clone(),
+                            kinds: (kind.clone(), label.kind().clone()),
+                        },
+                        subject,
+                        Default::default(),
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    let _ = by_text.insert(
+                        (label.text().clone(), language.clone()),
+                        (label.kind().clone(), label.language().cloned()),
+                    );
+                }
+            }
+        }
+        if label.kind() == &LabelKind::Preferred {
+            let (_, texts) = preferred
+                .entry(language)
+                .or_insert_with(|| (label.language().cloned(), Default::default()));
+            if !texts.contains(label.text()) {
+                texts.push(label.text().clone());
+            }
+        }
+    }
+    for (_, (language, texts)) in preferred {
+        if texts.len() > 1 {
+            violations.push(Violation::new(
+                ViolationKind::MultiplePreferredLabels { language, texts },
+                subject,
+                Default::default(),
+            ));
+        }
+    }
+}
+
+fn is_lexical(kind: &LabelKind) -> bool {
+    matches!(
+        kind,
+        LabelKind::Preferred | LabelKind::Alternative | LabelKind::Hidden
+    )
+}
+
+fn language_suffix(language: &Option<LanguageTag>) -> String {
+    match language {
+        None => String::new(),
+        Some(language) => format!("@{}", language),
+    }
+}
@@ -0,0 +1,371 @@
+/*!
+Generate [SHACL](https://www.w3.org/TR/shacl/) shapes that describe the conventions of a scheme,
+so that downstream data referencing the vocabulary can be validated automatically.
+
+The generated shapes graph contains a single node shape for concepts, with property shapes for
+each of the conventions enabled in `ShapeOptions`:
+
+1. every concept must have exactly one `skos:prefLabel` in each of the required languages,
+1. every concept must have a `skos:notation` matching a regular expression, and
+1. every concept must have a `skos:inScheme` pointing at the scheme itself.
+
+# Example
+
+```rust
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::simple::graph_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::model::Scheme;
+use rdftk_skos::shapes::{to_shapes_graph, ShapeOptions};
+use std::str::FromStr;
+
+let scheme = Scheme::new(&IRIRef::from(IRI::from_str("http://example.org/scheme").unwrap()));
+
+let options = ShapeOptions::default()
+    .label_language(LanguageTag::from_str("en").unwrap())
+    .notation_pattern("^[0-9]+$");
+
+let shapes = to_shapes_graph(&scheme, &options, &graph_factory());
+assert!(!shapes.borrow().is_empty());
+```
+
+*/
+
+use crate::model::{standard_mappings, Resource, Scheme};
+use crate::ns;
+use rdftk_core::model::graph::{GraphFactoryRef, GraphRef};
+use rdftk_core::model::literal::{DataType, LanguageTag, LiteralFactoryRef, LiteralRef};
+use rdftk_core::model::statement::{
+    ObjectNodeRef, StatementFactoryRef, StatementList, SubjectNodeRef,
+};
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, shacl, xsd};
+use std::str::FromStr;
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Determines which nodes the generated concept shape will be applied to.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ShapeTarget {
+    /// Target all instances of `skos:Concept` using `sh:targetClass`.
+    #[default]
+    AllConcepts,
+    /// Target each concept in the scheme individually using `sh:targetNode`.
+    SchemeConcepts,
+}
+
+///
+/// Options that control which conventions are described by the generated shapes.
+///
+#[derive(Clone, Debug)]
+pub struct ShapeOptions {
+    shape_namespace: Option<IRIRef>,
+    target: ShapeTarget,
+    label_languages: Vec<LanguageTag>,
+    notation_pattern: Option<String>,
+    require_in_scheme: bool,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Construct a new shapes graph describing the conventions of `scheme` as selected by `options`.
+/// The graph's prefix mappings include the standard SKOS mappings as well as the SHACL namespace.
+///
+pub fn to_shapes_graph(
+    scheme: &Scheme,
+    options: &ShapeOptions,
+    factory: &GraphFactoryRef,
+) -> GraphRef {
+    let ns_mappings = standard_mappings(factory);
+    {
+        let mut ns_mappings = ns_mappings.borrow_mut();
+        ns_mappings.insert(shacl::default_prefix(), shacl::namespace_iri().clone());
+        ns_mappings.insert(xsd::default_prefix(), xsd::namespace_iri().clone());
+    }
+    let graph = factory.graph();
+    {
+        let mut graph = graph.borrow_mut();
+        graph.set_prefix_mappings(ns_mappings);
+
+        for statement in to_shape_statements(
+            scheme,
+            options,
+            &graph.statement_factory(),
+            &graph.literal_factory(),
+        ) {
+            graph.insert(statement);
+        }
+    }
+    graph
+}
+
+///
+/// Return the list of statements that make up the shapes describing the conventions of `scheme`.
+///
+pub fn to_shape_statements(
+    scheme: &Scheme,
+    options: &ShapeOptions,
+    statements: &StatementFactoryRef,
+    literals: &LiteralFactoryRef,
+) -> StatementList {
+    let mut statement_list: StatementList = Default::default();
+
+    let shape = match &options.shape_namespace {
+        None => statements.blank_subject(),
+        Some(namespace) => statements.named_subject(shape_iri(namespace, "ConceptShape")),
+    };
+    statement_list.push(
+        statements
+            .statement(
+                shape.clone(),
+                rdf::a_type().clone(),
+                statements.named_object(shacl::node_shape().clone()),
+            )
+            .unwrap(),
+    );
+
+    match options.target {
+        ShapeTarget::AllConcepts => {
+            statement_list.push(
+                statements
+                    .statement(
+                        shape.clone(),
+                        shacl::target_class().clone(),
+                        statements.named_object(ns::concept().clone()),
+                    )
+                    .unwrap(),
+            );
+        }
+        ShapeTarget::SchemeConcepts => {
+            let mut concepts: Vec<IRIRef> = scheme
+                .concepts_flattened()
+                .iter()
+                .map(|concept| concept.borrow().uri().clone())
+                .collect();
+            concepts.sort_by_key(|uri| uri.to_string());
+            concepts.dedup();
+            for concept in concepts {
+                statement_list.push(
+                    statements
+                        .statement(
+                            shape.clone(),
+                            shacl::target_node().clone(),
+                            statements.named_object(concept),
+                        )
+                        .unwrap(),
+                );
+            }
+        }
+    }
+
+    if !options.label_languages.is_empty() {
+        let property =
+            add_property_shape(&mut statement_list, &shape, ns::pref_label(), statements);
+        statement_list.push(
+            statements
+                .statement(
+                    property,
+                    shacl::unique_lang().clone(),
+                    statements.literal_object(literals.boolean(true)),
+                )
+                .unwrap(),
+        );
+        for language in &options.label_languages {
+            let property =
+                add_property_shape(&mut statement_list, &shape, ns::pref_label(), statements);
+            let value_shape = statements.blank_subject();
+            statement_list.push(
+                statements
+                    .statement(
+                        property.clone(),
+                        shacl::qualified_value_shape().clone(),
+                        statements.subject_as_object(value_shape.clone()),
+                    )
+                    .unwrap(),
+            );
+            let language_list = make_list(
+                &mut statement_list,
+                &[statements.literal_object(literals.string(&language.to_string()))],
+                statements,
+            );
+            statement_list.push(
+                statements
+                    .statement(value_shape, shacl::language_in().clone(), language_list)
+                    .unwrap(),
+            );
+            statement_list.push(
+                statements
+                    .statement(
+                        property,
+                        shacl::qualified_min_count().clone(),
+                        statements.literal_object(integer(literals, 1)),
+                    )
+                    .unwrap(),
+            );
+        }
+    }
+
+    if let Some(pattern) = &options.notation_pattern {
+        let property = add_property_shape(&mut statement_list, &shape, ns::notation(), statements);
+        statement_list.push(
+            statements
+                .statement(
+                    property.clone(),
+                    shacl::min_count().clone(),
+                    statements.literal_object(integer(literals, 1)),
+                )
+                .unwrap(),
+        );
+        statement_list.push(
+            statements
+                .statement(
+                    property,
+                    shacl::pattern().clone(),
+                    statements.literal_object(literals.string(pattern)),
+                )
+                .unwrap(),
+        );
+    }
+
+    if options.require_in_scheme {
+        let property = add_property_shape(&mut statement_list, &shape, ns::in_scheme(), statements);
+        statement_list.push(
+            statements
+                .statement(
+                    property.clone(),
+                    shacl::min_count().clone(),
+                    statements.literal_object(integer(literals, 1)),
+                )
+                .unwrap(),
+        );
+        statement_list.push(
+            statements
+                .statement(
+                    property,
+                    shacl::has_value().clone(),
+                    statements.named_object(scheme.uri().clone()),
+                )
+                .unwrap(),
+        );
+    }
+
+    statement_list
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Default for ShapeOptions {
+    fn default() -> Self {
+        Self {
+            shape_namespace: None,
+            target: Default::default(),
+            label_languages: Default::default(),
+            notation_pattern: None,
+            require_in_scheme: true,
+        }
+    }
+}
+
+impl ShapeOptions {
+    /// Name shapes within this namespace; by default shapes are blank nodes.
+    pub fn shape_namespace(mut self, namespace: IRIRef) -> Self {
+        self.shape_namespace = Some(namespace);
+        self
+    }
+
+    /// Set the nodes the concept shape will be applied to, the default is `AllConcepts`.
+    pub fn target(mut self, target: ShapeTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Require every concept to have a preferred label in this language.
+    pub fn label_language(mut self, language: LanguageTag) -> Self {
+        self.label_languages.push(language);
+        self
+    }
+
+    /// Require every concept to have a notation matching this regular expression.
+    pub fn notation_pattern(mut self, pattern: &str) -> Self {
+        self.notation_pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Determine whether every concept must be declared as in the scheme, the default is `true`.
+    pub fn require_in_scheme(mut self, require: bool) -> Self {
+        self.require_in_scheme = require;
+        self
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn shape_iri(namespace: &IRIRef, name: &str) -> IRIRef {
+    IRIRef::from(IRI::from_str(&format!("{}{}", namespace, name)).unwrap())
+}
+
+fn integer(literals: &LiteralFactoryRef, value: u32) -> LiteralRef {
+    literals.with_data_type(&value.to_string(), DataType::Integer)
+}
+
+fn add_property_shape(
+    statements: &mut StatementList,
+    shape: &SubjectNodeRef,
+    path: &IRIRef,
+    factory: &StatementFactoryRef,
+) -> SubjectNodeRef {
+    let property = factory.blank_subject();
+    statements.push(
+        factory
+            .statement(
+                shape.clone(),
+                shacl::property().clone(),
+                factory.subject_as_object(property.clone()),
+            )
+            .unwrap(),
+    );
+    statements.push(
+        factory
+            .statement(
+                property.clone(),
+                shacl::path().clone(),
+                factory.named_object(path.clone()),
+            )
+            .unwrap(),
+    );
+    property
+}
+
+fn make_list(
+    statements: &mut StatementList,
+    members: &[ObjectNodeRef],
+    factory: &StatementFactoryRef,
+) -> ObjectNodeRef {
+    let mut list = factory.named_object(rdf::nil().clone());
+    for member in members.iter().rev() {
+        let node = factory.blank_subject();
+        statements.push(
+            factory
+                .statement(node.clone(), rdf::first().clone(), member.clone())
+                .unwrap(),
+        );
+        statements.push(
+            factory
+                .statement(node.clone(), rdf::rest().clone(), list)
+                .unwrap(),
+        );
+        list = factory.subject_as_object(node);
+    }
+    list
+}
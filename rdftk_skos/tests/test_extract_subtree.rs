@@ -0,0 +1,206 @@
+use rdftk_fixtures::terms::iri_in;
+use rdftk_iri::IRI;
+use rdftk_names::owl;
+use rdftk_skos::model::collection::Member;
+use rdftk_skos::model::concept::ConceptRelation;
+use rdftk_skos::model::{Labeled, Propertied, Resource, Scheme};
+use rdftk_skos::ns;
+use std::str::FromStr;
+
+const COLORS: &str = "http://example.org/colors/";
+
+fn make_scheme() -> Scheme {
+    let mut scheme = Scheme::new_with_label(&iri_in(COLORS, "scheme"), "Colors", "en");
+    scheme.define("Some colors.", "en");
+    let color = scheme.new_top_concept_with_label(&iri_in(COLORS, "color"), "Color", "en");
+    let red = color
+        .borrow_mut()
+        .sub_concept_with_label(&iri_in(COLORS, "red"), "Red", "en");
+    let crimson =
+        red.borrow_mut()
+            .sub_concept_with_label(&iri_in(COLORS, "crimson"), "Crimson", "en");
+    crimson.borrow_mut().notation("R.1");
+    let scarlet =
+        red.borrow_mut()
+            .sub_concept_with_label(&iri_in(COLORS, "scarlet"), "Scarlet", "en");
+    scarlet.borrow_mut().add_related(crimson.clone());
+    let blue = color
+        .borrow_mut()
+        .sub_concept_with_label(&iri_in(COLORS, "blue"), "Blue", "en");
+    blue.borrow_mut().add_related(red.clone());
+    crimson.borrow_mut().add_related(blue.clone());
+    red.borrow_mut().add_external_relation(
+        owl::same_as().clone(),
+        IRI::from_str("http://dbpedia.org/resource/Red")
+            .unwrap()
+            .into(),
+    );
+
+    let warm = scheme.new_top_collection_with_label(&iri_in(COLORS, "warm"), true, "Warm", "en");
+    warm.borrow_mut().add_member_concept(blue.clone());
+    warm.borrow_mut().add_member_concept(scarlet);
+    warm.borrow_mut().add_member_concept(crimson);
+    let cool = scheme.new_top_collection(&iri_in(COLORS, "cool"), false);
+    cool.borrow_mut().add_member_concept(blue);
+    scheme
+}
+
+fn uris(concepts: &[std::rc::Rc<std::cell::RefCell<rdftk_skos::model::Concept>>]) -> Vec<String> {
+    let mut uris: Vec<String> = concepts
+        .iter()
+        .map(|concept| concept.borrow().uri().to_string())
+        .collect();
+    uris.sort();
+    uris
+}
+
+fn externals(scheme: &Scheme, name: &str) -> Vec<(String, String)> {
+    let concept = scheme
+        .concepts_flattened()
+        .into_iter()
+        .find(|concept| concept.borrow().uri() == &iri_in(COLORS, name))
+        .unwrap();
+    let concept = concept.borrow();
+    concept
+        .external_relations()
+        .map(|(predicate, object)| (predicate.to_string(), object.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_extract_subtree() {
+    let scheme = make_scheme();
+    let extracted = scheme
+        .extract_subtree(&iri_in(COLORS, "red"), false)
+        .unwrap();
+
+    assert_eq!(extracted.uri(), scheme.uri());
+    assert_eq!(extracted.labels(), scheme.labels());
+    assert_eq!(extracted.properties(), scheme.properties());
+
+    let top: Vec<_> = extracted.top_concepts().cloned().collect();
+    assert_eq!(uris(&top), vec![iri_in(COLORS, "red").to_string()]);
+    assert_eq!(
+        uris(&extracted.concepts_flattened()),
+        vec![
+            iri_in(COLORS, "crimson").to_string(),
+            iri_in(COLORS, "red").to_string(),
+            iri_in(COLORS, "scarlet").to_string()
+        ]
+    );
+
+    let red = top[0].borrow();
+    assert_eq!(
+        red.labels(),
+        &vec![rdftk_skos::model::Label::preferred("Red", "en")]
+    );
+    assert!(!red.has_external_relations());
+    let scarlet = red
+        .concepts()
+        .find(|(_, concept)| concept.borrow().uri() == &iri_in(COLORS, "scarlet"))
+        .map(|(_, concept)| concept.clone())
+        .unwrap();
+    let related: Vec<_> = scarlet
+        .borrow()
+        .concepts()
+        .map(|(relation, concept)| (relation.clone(), concept.borrow().uri().to_string()))
+        .collect();
+    assert_eq!(
+        related,
+        vec![(
+            ConceptRelation::Related,
+            iri_in(COLORS, "crimson").to_string()
+        )]
+    );
+    assert!(externals(&extracted, "crimson").is_empty());
+}
+
+#[test]
+fn test_extract_subtree_is_a_copy() {
+    let scheme = make_scheme();
+    let extracted = scheme
+        .extract_subtree(&iri_in(COLORS, "red"), false)
+        .unwrap();
+
+    extracted
+        .top_concepts()
+        .next()
+        .unwrap()
+        .borrow_mut()
+        .add_preferred_label("Rouge", "fr");
+    let red = scheme
+        .concepts_flattened()
+        .into_iter()
+        .find(|concept| concept.borrow().uri() == &iri_in(COLORS, "red"))
+        .unwrap();
+    assert_eq!(red.borrow().labels().len(), 1);
+}
+
+#[test]
+fn test_extract_subtree_with_mappings() {
+    let scheme = make_scheme();
+    let extracted = scheme
+        .extract_subtree(&iri_in(COLORS, "red"), true)
+        .unwrap();
+
+    assert_eq!(
+        externals(&extracted, "red"),
+        vec![
+            (
+                owl::same_as().to_string(),
+                "http://dbpedia.org/resource/Red".to_string()
+            ),
+            (
+                ns::related_match().to_string(),
+                iri_in(COLORS, "blue").to_string()
+            ),
+            (
+                ns::broad_match().to_string(),
+                iri_in(COLORS, "color").to_string()
+            ),
+        ]
+    );
+    assert_eq!(
+        externals(&extracted, "crimson"),
+        vec![(
+            ns::related_match().to_string(),
+            iri_in(COLORS, "blue").to_string()
+        )]
+    );
+    assert!(externals(&extracted, "scarlet").is_empty());
+}
+
+#[test]
+fn test_extract_subtree_collections() {
+    let scheme = make_scheme();
+    let extracted = scheme
+        .extract_subtree(&iri_in(COLORS, "red"), false)
+        .unwrap();
+
+    let collections: Vec<_> = extracted.top_collections().collect();
+    assert_eq!(collections.len(), 1);
+    let warm = collections[0].borrow();
+    assert_eq!(warm.uri(), &iri_in(COLORS, "warm"));
+    assert!(warm.is_ordered());
+    let members: Vec<String> = warm
+        .members()
+        .map(|member| match member {
+            Member::Concept(concept) => concept.borrow().uri().to_string(),
+            Member::Collection(collection) => collection.borrow().uri().to_string(),
+        })
+        .collect();
+    assert_eq!(
+        members,
+        vec![
+            iri_in(COLORS, "scarlet").to_string(),
+            iri_in(COLORS, "crimson").to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_extract_unknown_concept() {
+    assert!(make_scheme()
+        .extract_subtree(&iri_in(COLORS, "green"), true)
+        .is_none());
+}
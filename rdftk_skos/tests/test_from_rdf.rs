@@ -0,0 +1,252 @@
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_core::simple::statement::statement_factory;
+use rdftk_fixtures::terms::iri_in;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{owl, rdf};
+use rdftk_skos::model::collection::Member;
+use rdftk_skos::model::concept::ConceptRelation;
+use rdftk_skos::model::{
+    from_rdf_graph, to_rdf_graph, Concept, Labeled, LiteralProperty, Propertied, Resource, Scheme,
+};
+use rdftk_skos::ns;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::str::FromStr;
+
+const COLORS: &str = "http://example.org/colors/";
+
+fn make_scheme() -> Scheme {
+    let mut scheme = Scheme::new_with_label(&iri_in(COLORS, "scheme"), "Colors", "en");
+    scheme.define("Some colors.", "en");
+    let red = scheme.new_top_concept_with_label(&iri_in(COLORS, "red"), "Red", "en");
+    red.borrow_mut().add_preferred_label("Rouge", "fr");
+    red.borrow_mut().add_hidden_label("Redd", "en");
+    let crimson =
+        red.borrow_mut()
+            .sub_concept_with_label(&iri_in(COLORS, "crimson"), "Crimson", "en");
+    crimson.borrow_mut().notation("R.1");
+    let blue = scheme.new_top_concept_with_label(&iri_in(COLORS, "blue"), "Blue", "en");
+    blue.borrow_mut().add_related(crimson.clone());
+    blue.borrow_mut().add_external_relation(
+        owl::same_as().clone(),
+        IRI::from_str("http://dbpedia.org/resource/Blue")
+            .unwrap()
+            .into(),
+    );
+    let _ = blue
+        .borrow_mut()
+        .part_with_label(&iri_in(COLORS, "navy"), "Navy", "en");
+
+    let warm = scheme.new_top_collection_with_label(&iri_in(COLORS, "warm"), true, "Warm", "en");
+    warm.borrow_mut().add_member_concept(red.clone());
+    warm.borrow_mut().add_member_concept(crimson);
+    let cool = warm
+        .borrow_mut()
+        .sub_collection(&iri_in(COLORS, "cool"), false);
+    cool.borrow_mut().add_member_concept(blue);
+    scheme
+}
+
+///
+/// A graph with the scheme "scheme" and the named statements.
+///
+fn scheme_graph(statements: &[(&str, &IRIRef, &str)]) -> GraphRef {
+    let factory = statement_factory();
+    let mut statements: Vec<StatementRef> = statements
+        .iter()
+        .map(|(subject, predicate, object)| {
+            factory
+                .statement(
+                    factory.named_subject(iri_in(COLORS, subject)),
+                    (*predicate).clone(),
+                    factory.named_object(iri_in(COLORS, object)),
+                )
+                .unwrap()
+        })
+        .collect();
+    statements.push(
+        factory
+            .statement(
+                factory.named_subject(iri_in(COLORS, "scheme")),
+                rdf::a_type().clone(),
+                factory.named_object(ns::concept_scheme().clone()),
+            )
+            .unwrap(),
+    );
+    graph_factory().graph_from(&statements, None)
+}
+
+fn uris<'a>(concepts: impl Iterator<Item = &'a Rc<RefCell<Concept>>>) -> Vec<String> {
+    concepts
+        .map(|concept| concept.borrow().uri().to_string())
+        .collect()
+}
+
+#[test]
+fn test_round_trip() {
+    let original = make_scheme();
+    let graph = to_rdf_graph(&original, None, &graph_factory());
+
+    let schemes = from_rdf_graph(&graph);
+    assert_eq!(schemes.len(), 1);
+    let scheme = &schemes[0];
+    assert_eq!(scheme.uri(), &iri_in(COLORS, "scheme"));
+    assert_eq!(scheme.labels(), original.labels());
+    assert_eq!(scheme.properties(), original.properties());
+    assert_eq!(
+        uris(scheme.top_concepts()),
+        vec![
+            iri_in(COLORS, "blue").to_string(),
+            iri_in(COLORS, "red").to_string()
+        ]
+    );
+    assert_eq!(scheme.concepts_flattened().len(), 4);
+
+    let red = scheme
+        .top_concepts()
+        .find(|concept| concept.borrow().uri() == &iri_in(COLORS, "red"))
+        .unwrap();
+    assert_eq!(red.borrow().labels().len(), 3);
+    let (relation, crimson) = red.borrow().concepts().next().unwrap().clone();
+    assert_eq!(relation, ConceptRelation::Narrower);
+    assert_eq!(crimson.borrow().uri(), &iri_in(COLORS, "crimson"));
+    assert_eq!(
+        crimson.borrow().properties(),
+        &vec![LiteralProperty::notation("R.1")]
+    );
+
+    let blue = scheme
+        .top_concepts()
+        .find(|concept| concept.borrow().uri() == &iri_in(COLORS, "blue"))
+        .unwrap();
+    let blue = blue.borrow();
+    let relations: Vec<(ConceptRelation, String)> = blue
+        .concepts()
+        .map(|(relation, concept)| (relation.clone(), concept.borrow().uri().to_string()))
+        .collect();
+    assert_eq!(
+        relations,
+        vec![
+            (
+                ConceptRelation::Related,
+                iri_in(COLORS, "crimson").to_string()
+            ),
+            (
+                ConceptRelation::NarrowerPartitive,
+                iri_in(COLORS, "navy").to_string()
+            ),
+        ]
+    );
+    assert_eq!(blue.external_relations().count(), 1);
+
+    let collections: Vec<_> = scheme.top_collections().collect();
+    assert_eq!(collections.len(), 1);
+    let warm = collections[0].borrow();
+    assert!(warm.is_ordered());
+    let members: Vec<String> = warm
+        .members()
+        .map(|member| match member {
+            Member::Concept(concept) => concept.borrow().uri().to_string(),
+            Member::Collection(collection) => collection.borrow().uri().to_string(),
+        })
+        .collect();
+    assert_eq!(
+        members,
+        vec![
+            iri_in(COLORS, "red").to_string(),
+            iri_in(COLORS, "crimson").to_string(),
+            iri_in(COLORS, "cool").to_string()
+        ]
+    );
+    assert_eq!(scheme.collections_flattened().len(), 2);
+
+    let again = to_rdf_graph(scheme, None, &graph_factory());
+    assert_eq!(again.borrow().len(), graph.borrow().len());
+}
+
+#[test]
+fn test_hierarchy_from_broader() {
+    let graph = scheme_graph(&[
+        ("color", ns::in_scheme(), "scheme"),
+        ("red", ns::in_scheme(), "scheme"),
+        ("red", ns::broader(), "color"),
+        ("crimson", ns::in_scheme(), "scheme"),
+        ("crimson", ns::broader(), "red"),
+        ("color", ns::narrower(), "red"),
+    ]);
+    let schemes = from_rdf_graph(&graph);
+    assert_eq!(schemes.len(), 1);
+    let scheme = &schemes[0];
+    assert_eq!(
+        uris(scheme.top_concepts()),
+        vec![iri_in(COLORS, "color").to_string()]
+    );
+    let color = scheme.top_concepts().next().unwrap().borrow();
+    assert_eq!(color.concepts().count(), 1);
+    assert_eq!(
+        uris(color.concepts_flattened().iter()),
+        vec![
+            iri_in(COLORS, "crimson").to_string(),
+            iri_in(COLORS, "red").to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_hierarchy_cycle_is_broken() {
+    let graph = scheme_graph(&[
+        ("a", ns::in_scheme(), "scheme"),
+        ("b", ns::in_scheme(), "scheme"),
+        ("a", ns::narrower(), "b"),
+        ("b", ns::narrower(), "a"),
+        ("a", ns::related(), "a"),
+    ]);
+
+    let schemes = from_rdf_graph(&graph);
+    let scheme = &schemes[0];
+    assert_eq!(
+        uris(scheme.top_concepts()),
+        vec![iri_in(COLORS, "a").to_string()]
+    );
+    assert_eq!(scheme.concepts_flattened().len(), 2);
+}
+
+#[test]
+fn test_multiple_schemes() {
+    let mut first = make_scheme();
+    first.add_alternative_label("Colours", "en-GB");
+    let second = Scheme::new_with_label(&iri_in(COLORS, "empty"), "Empty", "en");
+
+    let graph = to_rdf_graph(&first, None, &graph_factory());
+    {
+        let mut graph = graph.borrow_mut();
+        for statement in to_rdf_graph(&second, None, &graph_factory())
+            .borrow()
+            .statements()
+        {
+            graph.insert(statement.clone());
+        }
+    }
+
+    let schemes = from_rdf_graph(&graph);
+    let names: Vec<String> = schemes
+        .iter()
+        .map(|scheme| scheme.uri().to_string())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            iri_in(COLORS, "empty").to_string(),
+            iri_in(COLORS, "scheme").to_string()
+        ]
+    );
+    assert!(!schemes[0].has_top_concepts());
+    assert_eq!(schemes[1].labels().len(), 2);
+}
+
+#[test]
+fn test_no_schemes() {
+    assert!(from_rdf_graph(&graph_factory().graph()).is_empty());
+}
@@ -0,0 +1,206 @@
+#![cfg(feature = "import")]
+
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_skos::import::csv::CsvImporter;
+use rdftk_skos::import::marc::MarcImporter;
+use rdftk_skos::import::zthes::ZthesImporter;
+use rdftk_skos::import::{ImportOptions, SchemeImporter};
+use rdftk_skos::model::concept::ConceptRelation;
+use rdftk_skos::model::properties::LabelKind;
+use rdftk_skos::model::{Concept, Labeled, Propertied, Resource, Scheme};
+use rdftk_skos::ns;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::str::FromStr;
+
+fn options() -> ImportOptions {
+    ImportOptions::new(IRIRef::from(
+        IRI::from_str("http://example.org/animals").unwrap(),
+    ))
+}
+
+fn find(scheme: &Scheme, id: &str) -> Rc<RefCell<Concept>> {
+    let uri = format!("http://example.org/animals/{}", id);
+    scheme
+        .concepts_flattened()
+        .into_iter()
+        .find(|c| c.borrow().uri().to_string() == uri)
+        .unwrap()
+}
+
+fn labels(concept: &Rc<RefCell<Concept>>, kind: LabelKind) -> Vec<String> {
+    concept
+        .borrow()
+        .labels()
+        .iter()
+        .filter(|l| l.kind() == &kind)
+        .map(|l| l.text().clone())
+        .collect()
+}
+
+fn narrower(concept: &Rc<RefCell<Concept>>) -> Vec<String> {
+    concept
+        .borrow()
+        .concepts()
+        .filter(|(relation, _)| relation.is_narrower())
+        .map(|(_, c)| c.borrow().uri().to_string())
+        .collect()
+}
+
+#[test]
+fn import_zthes() {
+    let mut content = r#"<?xml version="1.0"?>
+<Zthes>
+  <thes><thesName>Animals</thesName></thes>
+  <term>
+    <termId>1</termId>
+    <termName>Animals</termName>
+    <termType>PT</termType>
+    <termLanguage>en</termLanguage>
+    <termNote label="Scope">All living beasts.</termNote>
+    <relation><relationType>NT</relationType><termId>2</termId></relation>
+  </term>
+  <term>
+    <termId>2</termId>
+    <termName>Cats</termName>
+    <termType>PT</termType>
+    <termLanguage>en</termLanguage>
+    <relation><relationType>BT</relationType><termId>1</termId></relation>
+    <relation><relationType>RT</relationType><termName>Dogs</termName></relation>
+  </term>
+  <term>
+    <termId>3</termId>
+    <termName>Dogs</termName>
+    <termType>PT</termType>
+    <relation><relationType>BT</relationType><termId>1</termId></relation>
+  </term>
+  <term>
+    <termId>4</termId>
+    <termName>Felines</termName>
+    <termType>ND</termType>
+    <relation><relationType>USE</relationType><termId>2</termId></relation>
+  </term>
+</Zthes>"#
+        .as_bytes();
+
+    let scheme = ZthesImporter::default()
+        .import(&mut content, &options())
+        .unwrap();
+    assert_eq!(scheme.get_preferred_label_for(&None), "Animals");
+    assert_eq!(scheme.top_concepts().count(), 1);
+    assert_eq!(scheme.concepts_flattened().len(), 3);
+
+    let animals = find(&scheme, "1");
+    assert!(animals.borrow().has_property(ns::scope_note()));
+    // the BT on "Cats" and the NT on "Animals" are the same relationship.
+    assert_eq!(
+        narrower(&animals),
+        vec![
+            "http://example.org/animals/2".to_string(),
+            "http://example.org/animals/3".to_string()
+        ]
+    );
+
+    let cats = find(&scheme, "2");
+    assert_eq!(labels(&cats, LabelKind::Alternative), vec!["Felines"]);
+    assert!(cats
+        .borrow()
+        .concepts()
+        .any(|(relation, c)| relation == &ConceptRelation::Related
+            && c.borrow().uri().to_string() == "http://example.org/animals/3"));
+}
+
+#[test]
+fn import_marc() {
+    let mut content = r#"<?xml version="1.0"?>
+<collection xmlns="http://www.loc.gov/MARC21/slim">
+  <record type="Authority">
+    <controlfield tag="001">sh001</controlfield>
+    <datafield tag="150" ind1=" " ind2=" "><subfield code="a">Animals</subfield></datafield>
+    <datafield tag="450" ind1=" " ind2=" "><subfield code="a">Beasts</subfield></datafield>
+    <datafield tag="680" ind1=" " ind2=" ">
+      <subfield code="i">Here are entered works on animals in general.</subfield>
+    </datafield>
+  </record>
+  <record type="Authority">
+    <controlfield tag="001">sh002</controlfield>
+    <datafield tag="150" ind1=" " ind2=" ">
+      <subfield code="a">Animals</subfield><subfield code="x">Behavior</subfield>
+    </datafield>
+    <datafield tag="550" ind1=" " ind2=" ">
+      <subfield code="w">g</subfield><subfield code="a">Animals</subfield>
+    </datafield>
+    <datafield tag="550" ind1=" " ind2=" "><subfield code="a">Ethology</subfield></datafield>
+  </record>
+</collection>"#
+        .as_bytes();
+
+    let scheme = MarcImporter::default()
+        .import(&mut content, &options())
+        .unwrap();
+    assert_eq!(scheme.top_concepts().count(), 1);
+
+    let animals = find(&scheme, "sh001");
+    assert_eq!(labels(&animals, LabelKind::Preferred), vec!["Animals"]);
+    assert_eq!(labels(&animals, LabelKind::Alternative), vec!["Beasts"]);
+    assert!(animals.borrow().has_property(ns::scope_note()));
+    assert!(animals.borrow().has_property(ns::notation()));
+    assert_eq!(
+        narrower(&animals),
+        vec!["http://example.org/animals/sh002".to_string()]
+    );
+
+    // "Ethology" has no record, so the relationship is dropped.
+    let behavior = find(&scheme, "sh002");
+    assert_eq!(
+        labels(&behavior, LabelKind::Preferred),
+        vec!["Animals--Behavior"]
+    );
+    assert_eq!(behavior.borrow().concepts().count(), 0);
+}
+
+#[test]
+fn import_csv() {
+    let mut content = "id,prefLabel@en,prefLabel@fr,altLabel@en,broader,definition@en
+animals,Animals,Animaux,,,
+cats,Cats,Chats,\"Felines|Moggies\",Animals,\"A small, furry \"\"carnivore\"\".\"
+kittens,Kittens,Chatons,,cats,
+"
+    .as_bytes();
+
+    let scheme = CsvImporter::default()
+        .import(&mut content, &options())
+        .unwrap();
+    assert_eq!(scheme.top_concepts().count(), 1);
+    assert_eq!(scheme.concepts_flattened().len(), 3);
+
+    let cats = find(&scheme, "cats");
+    assert_eq!(labels(&cats, LabelKind::Preferred), vec!["Cats", "Chats"]);
+    assert_eq!(
+        labels(&cats, LabelKind::Alternative),
+        vec!["Felines", "Moggies"]
+    );
+    let definition = cats
+        .borrow()
+        .properties()
+        .iter()
+        .find(|p| p.predicate() == ns::definition())
+        .map(|p| p.lexical_form().clone());
+    assert_eq!(
+        definition,
+        Some("A small, furry \"carnivore\".".to_string())
+    );
+    assert_eq!(
+        narrower(&cats),
+        vec!["http://example.org/animals/kittens".to_string()]
+    );
+}
+
+#[test]
+fn import_csv_cycle_is_an_error() {
+    let mut content = "id;broader\na;b\nb;a\n".as_bytes();
+
+    let mut importer = CsvImporter::default();
+    let _ = importer.delimiter(';');
+    assert!(importer.import(&mut content, &options()).is_err());
+}
@@ -0,0 +1,120 @@
+use rdftk_core::model::literal::LanguageTag;
+use rdftk_core::model::statement::StatementRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_iri::{IRIRef, IRI};
+use rdftk_names::{rdf, shacl};
+use rdftk_skos::model::{Labeled, Scheme};
+use rdftk_skos::ns;
+use rdftk_skos::shapes::{to_shapes_graph, ShapeOptions, ShapeTarget};
+use std::str::FromStr;
+
+fn make_scheme() -> Scheme {
+    let mut scheme = Scheme::new(&IRIRef::from(
+        IRI::from_str("http://example.org/colors").unwrap(),
+    ));
+    scheme.add_preferred_label("Colors", "en");
+    let red = scheme.new_top_concept_with_label(
+        &IRIRef::from(IRI::from_str("http://example.org/colors/red").unwrap()),
+        "Red",
+        "en",
+    );
+    let _ = red.borrow_mut().sub_concept_with_label(
+        &IRIRef::from(IRI::from_str("http://example.org/colors/crimson").unwrap()),
+        "Crimson",
+        "en",
+    );
+    scheme
+}
+
+fn has_predicate_object(statements: &[StatementRef], predicate: &IRIRef, object: &IRIRef) -> bool {
+    statements.iter().any(|st| {
+        st.predicate() == predicate && st.object().as_iri().map(|o| o == object).unwrap_or(false)
+    })
+}
+
+#[test]
+fn test_default_shapes() {
+    let scheme = make_scheme();
+
+    let graph = to_shapes_graph(&scheme, &ShapeOptions::default(), &graph_factory());
+    let graph = graph.borrow();
+    let statements: Vec<StatementRef> = graph.statements().cloned().collect();
+
+    assert!(has_predicate_object(
+        &statements,
+        rdf::a_type(),
+        shacl::node_shape()
+    ));
+    assert!(has_predicate_object(
+        &statements,
+        shacl::target_class(),
+        ns::concept()
+    ));
+    assert!(has_predicate_object(
+        &statements,
+        shacl::path(),
+        ns::in_scheme()
+    ));
+    assert!(has_predicate_object(
+        &statements,
+        shacl::has_value(),
+        &IRIRef::from(IRI::from_str("http://example.org/colors").unwrap())
+    ));
+    assert!(!has_predicate_object(
+        &statements,
+        shacl::path(),
+        ns::pref_label()
+    ));
+}
+
+#[test]
+fn test_configured_shapes() {
+    let scheme = make_scheme();
+
+    let options = ShapeOptions::default()
+        .shape_namespace(IRIRef::from(
+            IRI::from_str("http://example.org/shapes/").unwrap(),
+        ))
+        .target(ShapeTarget::SchemeConcepts)
+        .label_language(LanguageTag::from_str("en").unwrap())
+        .label_language(LanguageTag::from_str("fr").unwrap())
+        .notation_pattern("^[A-Z]{3}$")
+        .require_in_scheme(false);
+
+    let graph = to_shapes_graph(&scheme, &options, &graph_factory());
+    let graph = graph.borrow();
+    let statements: Vec<StatementRef> = graph.statements().cloned().collect();
+
+    let shape = IRIRef::from(IRI::from_str("http://example.org/shapes/ConceptShape").unwrap());
+    assert!(statements
+        .iter()
+        .all(|st| !st.subject().is_iri() || st.subject().as_iri() == Some(&shape)));
+
+    assert_eq!(
+        statements
+            .iter()
+            .filter(|st| st.predicate() == shacl::target_node())
+            .count(),
+        2
+    );
+    assert_eq!(
+        statements
+            .iter()
+            .filter(|st| st.predicate() == shacl::qualified_min_count())
+            .count(),
+        2
+    );
+    assert!(statements
+        .iter()
+        .any(|st| st.predicate() == shacl::pattern()
+            && st
+                .object()
+                .as_literal()
+                .map(|lit| lit.lexical_form() == "^[A-Z]{3}$")
+                .unwrap_or(false)));
+    assert!(!has_predicate_object(
+        &statements,
+        shacl::path(),
+        ns::in_scheme()
+    ));
+}
@@ -0,0 +1,123 @@
+use rdftk_fixtures::terms::iri_in;
+use rdftk_iri::IRIRef;
+use rdftk_skos::model::concept::ConceptRelation;
+use rdftk_skos::model::{validate, Labeled, Scheme, Severity, ViolationKind};
+
+const COLORS: &str = "http://example.org/colors/";
+
+fn make_scheme() -> Scheme {
+    let mut scheme = Scheme::new_with_label(&iri_in(COLORS, "scheme"), "Colors", "en");
+    let red = scheme.new_top_concept_with_label(&iri_in(COLORS, "red"), "Red", "en");
+    red.borrow_mut().add_preferred_label("Rouge", "fr");
+    red.borrow_mut().add_alternative_label("Scarlet", "en");
+    let _ = red
+        .borrow_mut()
+        .sub_concept_with_label(&iri_in(COLORS, "crimson"), "Crimson", "en");
+    let _ = scheme.new_top_concept_with_label(&iri_in(COLORS, "blue"), "Blue", "en");
+    scheme
+}
+
+#[test]
+fn test_valid_scheme() {
+    assert!(validate(&make_scheme()).is_empty());
+}
+
+#[test]
+fn test_multiple_preferred_labels() {
+    let scheme = make_scheme();
+    let red = scheme.top_concepts().next().unwrap().clone();
+    red.borrow_mut().add_preferred_label("Vermilion", "en");
+    red.borrow_mut().add_preferred_label("Rot", "de");
+
+    let violations = validate(&scheme);
+    assert_eq!(violations.len(), 1);
+    let violation = &violations[0];
+    assert_eq!(violation.severity(), Severity::Error);
+    assert_eq!(violation.subject(), &iri_in(COLORS, "red"));
+    match violation.kind() {
+        ViolationKind::MultiplePreferredLabels { language, texts } => {
+            assert_eq!(language.as_ref().unwrap().to_string(), "en");
+            assert_eq!(texts, &vec!["Red".to_string(), "Vermilion".to_string()]);
+        }
+        kind => panic!("unexpected violation kind {:?}", kind),
+    }
+}
+
+#[test]
+fn test_overlapping_labels() {
+    let scheme = make_scheme();
+    let red = scheme.top_concepts().next().unwrap().clone();
+    red.borrow_mut().add_hidden_label("Scarlet", "en");
+    // the same text in a different language is fine.
+    red.borrow_mut().add_hidden_label("Red", "de");
+
+    let violations = validate(&scheme);
+    assert_eq!(violations.len(), 1);
+    assert!(matches!(
+        violations[0].kind(),
+        ViolationKind::OverlappingLabels { text, .. } if text == "Scarlet"
+    ));
+    assert_eq!(
+        violations[0].to_string(),
+        "error: <http://example.org/colors/red> label \"Scarlet\"@en is used as both \
+         http://www.w3.org/2004/02/skos/core#altLabel and \
+         http://www.w3.org/2004/02/skos/core#hiddenLabel"
+    );
+}
+
+#[test]
+fn test_related_to_broader() {
+    let scheme = make_scheme();
+    let red = scheme.top_concepts().next().unwrap().clone();
+    let crimson = red.borrow().concepts().next().unwrap().1.clone();
+    crimson.borrow_mut().add_related(red.clone());
+
+    let violations = validate(&scheme);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].kind(), &ViolationKind::RelatedToBroader);
+    assert_eq!(violations[0].subject(), &iri_in(COLORS, "crimson"));
+    assert_eq!(violations[0].related(), &vec![iri_in(COLORS, "red")]);
+}
+
+#[test]
+fn test_broader_cycle() {
+    let scheme = make_scheme();
+    let red = scheme.top_concepts().next().unwrap().clone();
+    let crimson = red.borrow().concepts().next().unwrap().1.clone();
+    crimson
+        .borrow_mut()
+        .add_related_concept(ConceptRelation::Narrower, red.clone());
+
+    let violations = validate(&scheme);
+    assert_eq!(violations.len(), 2);
+    assert!(violations
+        .iter()
+        .all(|v| v.kind() == &ViolationKind::BroaderCycle && v.severity() == Severity::Warning));
+    assert_eq!(violations[0].subject(), &iri_in(COLORS, "crimson"));
+    assert_eq!(violations[0].related(), &vec![iri_in(COLORS, "red")]);
+    assert_eq!(violations[1].subject(), &iri_in(COLORS, "red"));
+}
+
+#[test]
+fn test_resource_type_clashes() {
+    let mut scheme = make_scheme();
+    let _ = scheme.new_top_concept_with_label(&iri_in(COLORS, "scheme"), "Scheme", "en");
+    let _ = scheme.new_top_collection_with_label(&iri_in(COLORS, "blue"), false, "Blues", "en");
+    let _ = scheme.new_top_concept(&iri_in(COLORS, "green"));
+
+    let kinds: Vec<(ViolationKind, IRIRef)> = validate(&scheme)
+        .into_iter()
+        .map(|v| (v.kind().clone(), v.subject().clone()))
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (ViolationKind::SchemeIsConcept, iri_in(COLORS, "scheme")),
+            (ViolationKind::CollectionIsConcept, iri_in(COLORS, "blue")),
+            (
+                ViolationKind::MissingPreferredLabel,
+                iri_in(COLORS, "green")
+            ),
+        ]
+    );
+}
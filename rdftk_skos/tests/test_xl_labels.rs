@@ -0,0 +1,131 @@
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::graph_factory;
+use rdftk_fixtures::terms::iri_in;
+use rdftk_iri::IRIRef;
+use rdftk_names::rdf;
+use rdftk_skos::model::properties::LabelKind;
+use rdftk_skos::model::{
+    from_rdf_graph, to_rdf_graph, to_rdf_graph_with_options, Label, Labeled, LiteralProperty,
+    Propertied, RdfOptions, Scheme,
+};
+use rdftk_skos::ns;
+
+const COLORS: &str = "http://example.org/colors/";
+
+fn make_scheme() -> Scheme {
+    let mut scheme = Scheme::new_with_label(&iri_in(COLORS, "scheme"), "Colors", "en");
+    let red = scheme.new_top_concept_with_label(&iri_in(COLORS, "red"), "Red", "en");
+    let mut label = Label::xl(
+        &iri_in(COLORS, "red-fr"),
+        LabelKind::Preferred,
+        "Rouge",
+        "fr",
+    );
+    label.add_property(LiteralProperty::note("From the French glossary."));
+    red.borrow_mut().add_label(label);
+    red.borrow_mut().add_label(Label::xl(
+        &iri_in(COLORS, "red-alt"),
+        LabelKind::Alternative,
+        "Scarlet",
+        "en",
+    ));
+    red.borrow_mut()
+        .add_label(Label::other(rdftk_names::rdfs::label().clone(), "red", ""));
+    scheme
+}
+
+fn xl_options() -> RdfOptions {
+    let mut options = RdfOptions::default();
+    let _ = options.xl_labels(true);
+    options
+}
+
+fn count(graph: &GraphRef, predicate: &IRIRef) -> usize {
+    graph
+        .borrow()
+        .statements()
+        .filter(|st| st.predicate() == predicate)
+        .count()
+}
+
+#[test]
+fn test_plain_labels_by_default() {
+    let graph = to_rdf_graph(&make_scheme(), None, &graph_factory());
+
+    assert_eq!(count(&graph, ns::pref_label()), 3);
+    assert_eq!(count(&graph, ns::alt_label()), 1);
+    assert_eq!(count(&graph, ns::xl::pref_label()), 0);
+    assert_eq!(count(&graph, ns::xl::literal_form()), 0);
+    assert_eq!(count(&graph, ns::note()), 0);
+}
+
+#[test]
+fn test_reified_labels() {
+    let graph = to_rdf_graph_with_options(&make_scheme(), None, &graph_factory(), &xl_options());
+
+    assert_eq!(count(&graph, ns::pref_label()), 0);
+    assert_eq!(count(&graph, ns::xl::pref_label()), 3);
+    assert_eq!(count(&graph, ns::xl::alt_label()), 1);
+    assert_eq!(count(&graph, ns::xl::literal_form()), 4);
+    assert_eq!(count(&graph, rdftk_names::rdfs::label()), 1);
+
+    let graph = graph.borrow();
+    let label = graph
+        .statements()
+        .find(|st| st.subject().as_iri() == Some(&iri_in(COLORS, "red-fr")))
+        .map(|st| st.subject().clone())
+        .unwrap();
+    let about: Vec<_> = graph
+        .statements()
+        .filter(|st| st.subject() == &label)
+        .collect();
+    assert_eq!(about.len(), 3);
+    assert!(
+        about
+            .iter()
+            .any(|st| st.predicate() == rdf::a_type()
+                && st.object().as_iri() == Some(ns::xl::label()))
+    );
+    let literal_form = about
+        .iter()
+        .find(|st| st.predicate() == ns::xl::literal_form())
+        .and_then(|st| st.object().as_literal())
+        .unwrap();
+    assert_eq!(literal_form.lexical_form(), "Rouge");
+    assert_eq!(literal_form.language().unwrap().to_string(), "fr");
+    assert!(about.iter().any(|st| st.predicate() == ns::note()));
+}
+
+#[test]
+fn test_round_trip() {
+    let original = make_scheme();
+    let graph = to_rdf_graph_with_options(&original, None, &graph_factory(), &xl_options());
+
+    let schemes = from_rdf_graph(&graph);
+    assert_eq!(schemes.len(), 1);
+    assert_eq!(schemes[0].labels(), original.labels());
+
+    let red = schemes[0].top_concepts().next().unwrap().borrow();
+    let labels = red.labels();
+    assert_eq!(labels.len(), 3);
+    assert!(red.has_property(rdftk_names::rdfs::label()));
+    let rouge = labels.iter().find(|label| label.text() == "Rouge").unwrap();
+    assert_eq!(rouge.uri(), Some(&iri_in(COLORS, "red-fr")));
+    assert_eq!(rouge.kind(), &LabelKind::Preferred);
+    assert_eq!(red.external_relations().count(), 0);
+    assert_eq!(
+        rouge.properties(),
+        &vec![LiteralProperty::note("From the French glossary.")]
+    );
+    let scarlet = labels
+        .iter()
+        .find(|label| label.text() == "Scarlet")
+        .unwrap();
+    assert_eq!(scarlet.uri(), Some(&iri_in(COLORS, "red-alt")));
+    assert_eq!(scarlet.kind(), &LabelKind::Alternative);
+    let plain = labels.iter().find(|label| label.text() == "Red").unwrap();
+    assert!(!plain.is_xl());
+
+    let again = to_rdf_graph_with_options(&schemes[0], None, &graph_factory(), &xl_options());
+    assert_eq!(again.borrow().len(), graph.borrow().len());
+}
@@ -0,0 +1,103 @@
+/*!
+Provides functions to load graphs and data sets from fixture files, the reader is selected by the
+file extension. These functions panic on failure, as is appropriate in tests.
+
+| Extension       | Reader                                       |
+|-----------------|----------------------------------------------|
+| `json`          | `rdftk_io::json::reader::JsonReader`         |
+| `jsonld`        | `rdftk_io::json_ld::reader::JsonLdReader`    |
+| `nq`            | `rdftk_io::nq::reader::NQuadDataSetReader`   |
+| `nt`            | `rdftk_io::nt::reader::NTriplesReader`       |
+| `rdf` or `xml`  | `rdftk_io::xml::reader::XmlReader`           |
+| `ttl`           | `rdftk_io::turtle::reader::TurtleReader`     |
+
+*/
+
+use rdftk_core::model::data_set::DataSetRef;
+use rdftk_core::model::graph::GraphRef;
+use rdftk_core::simple::data_set::data_set_factory;
+use rdftk_core::simple::graph_factory;
+use rdftk_io::json::reader::JsonReader;
+use rdftk_io::json_ld::reader::JsonLdReader;
+use rdftk_io::nq::reader::NQuadDataSetReader;
+use rdftk_io::nt::reader::NTriplesReader;
+use rdftk_io::turtle::reader::TurtleReader;
+use rdftk_io::xml::reader::XmlReader;
+use rdftk_io::{DataSetReader, GraphReader};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The directory, relative to the crate manifest, in which fixture files are stored.
+///
+pub const FIXTURE_DIRECTORY: &str = "tests/fixtures";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the path of the named fixture file for the crate whose manifest is in `manifest_dir`.
+///
+pub fn fixture_path(manifest_dir: &str, file_name: &str) -> PathBuf {
+    PathBuf::from(manifest_dir)
+        .join(FIXTURE_DIRECTORY)
+        .join(file_name)
+}
+
+///
+/// Load a graph from the file at `path`, the format is determined by the file's extension.
+///
+pub fn load_graph(path: &Path) -> GraphRef {
+    let mut file =
+        File::open(path).unwrap_or_else(|e| panic!("could not open fixture {:?}; {}", path, e));
+    read_graph(&mut file, &extension(path))
+}
+
+///
+/// Load a graph from the string `text`, the format is determined by `extension`.
+///
+pub fn load_graph_from_str(text: &str, extension: &str) -> GraphRef {
+    read_graph(&mut text.as_bytes(), extension)
+}
+
+///
+/// Load a data set from the file at `path`, the format is determined by the file's extension.
+///
+pub fn load_data_set(path: &Path) -> DataSetRef {
+    let mut file =
+        File::open(path).unwrap_or_else(|e| panic!("could not open fixture {:?}; {}", path, e));
+    match extension(path).as_str() {
+        "nq" => NQuadDataSetReader::default()
+            .read(&mut file, data_set_factory())
+            .unwrap_or_else(|e| panic!("could not read fixture {:?}; {}", path, e)),
+        ext => panic!("no data set reader for the extension {:?}", ext),
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn extension(path: &Path) -> String {
+    path.extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default()
+}
+
+fn read_graph(r: &mut impl Read, extension: &str) -> GraphRef {
+    let result = match extension {
+        "json" => JsonReader::default().read(r, graph_factory()),
+        "jsonld" => JsonLdReader::default().read(r, graph_factory()),
+        "nt" => NTriplesReader::default().read(r, graph_factory()),
+        "rdf" | "xml" => XmlReader::default().read(r, graph_factory()),
+        "ttl" => TurtleReader::default().read(r, graph_factory()),
+        ext => panic!("no graph reader for the extension {:?}", ext),
+    };
+    result.unwrap_or_else(|e| panic!("could not read {} fixture; {}", extension, e))
+}
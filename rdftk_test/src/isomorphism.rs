@@ -0,0 +1,51 @@
+/*!
+Provides graph isomorphism and a canonical serialization of a graph, for use in assertions, based
+upon the [RDF Dataset Canonicalization](https://www.w3.org/TR/rdf-canon/) algorithm, RDFC-1.0, of
+`rdftk_core::model::graph::canonical`.
+
+The canonical form is the sorted list of N-Triples statements in which blank nodes are labeled
+`_:c14n0`, `_:c14n1`, and so on. Two graphs are therefore isomorphic if, and only if, their
+canonical forms are equal.
+
+*/
+
+use rdftk_core::model::graph::canonical::canonical_n_triples;
+use rdftk_core::model::graph::GraphRef;
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the canonical N-Triples serialization of the graph, each statement is terminated by a
+/// newline. This panics if the graph cannot be canonicalized, for example if it contains embedded
+/// (RDF-star) statements.
+///
+pub fn canonical_ntriples(graph: &GraphRef) -> String {
+    canonical_n_triples(&*graph.borrow())
+        .expect("graph could not be canonicalized")
+        .into_iter()
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+///
+/// Returns `true` if the two graphs contain the same statements once blank node labels are
+/// ignored, else `false`.
+///
+pub fn is_isomorphic(lhs: &GraphRef, rhs: &GraphRef) -> bool {
+    lhs.borrow().len() == rhs.borrow().len() && canonical_ntriples(lhs) == canonical_ntriples(rhs)
+}
+
+///
+/// Panic, displaying a diff of the canonical forms, if the two graphs are not isomorphic. This is
+/// the implementation of the `assert_graph_isomorphic!` macro.
+///
+pub fn assert_isomorphic(lhs: &GraphRef, rhs: &GraphRef, message: Option<String>) {
+    let lhs = canonical_ntriples(lhs);
+    let rhs = canonical_ntriples(rhs);
+    match message {
+        None => pretty_assertions::assert_eq!(lhs, rhs, "graphs are not isomorphic"),
+        Some(message) => pretty_assertions::assert_eq!(lhs, rhs, "{}", message),
+    }
+}
@@ -0,0 +1,398 @@
+/*!
+Unit tests for SHACL shapes, so that vocabulary maintainers can ship regression tests alongside
+their shapes.
+
+A `ShapeTestSuite` pairs a shapes graph with a set of fixture data graphs, each of which is expected
+either to conform to the shapes or to violate them, optionally naming the constraint components
+that must be reported. Running the suite validates each fixture, with
+`rdftk_ontology::shacl::validate`, and produces a `ShapeTestReport` that displays a pass/fail
+matrix.
+
+# Example
+
+```rust
+#[macro_use]
+extern crate rdftk_test;
+
+use rdftk_ontology::shacl::ConstraintComponent;
+use rdftk_test::fixtures::load_graph_from_str;
+use rdftk_test::shapes::ShapeTestSuite;
+
+# fn main() {
+let shapes = load_graph_from_str(
+    r#"@prefix sh: <http://www.w3.org/ns/shacl#> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+<http://example.org/PersonShape> sh:targetClass foaf:Person ;
+    sh:property [ sh:path foaf:name ; sh:minCount 1 ] .
+"#,
+    "ttl",
+);
+
+let mut suite = ShapeTestSuite::new("person", shapes);
+let _ = suite
+    .conforming(
+        "named person",
+        load_graph_from_str(
+            "<http://example.org/alice> a <http://xmlns.com/foaf/0.1/Person> ;
+                <http://xmlns.com/foaf/0.1/name> \"Alice\" .",
+            "ttl",
+        ),
+    )
+    .violating_with(
+        "anonymous person",
+        load_graph_from_str(
+            "<http://example.org/bob> a <http://xmlns.com/foaf/0.1/Person> .",
+            "ttl",
+        ),
+        &[ConstraintComponent::MinCount],
+    );
+
+assert_shape_tests!(suite);
+# }
+```
+
+*/
+
+use rdftk_core::model::graph::GraphRef;
+use rdftk_ontology::shacl::{validate, ConstraintComponent, Severity, ValidationReport};
+use std::fmt::{Display, Formatter};
+
+// ------------------------------------------------------------------------------------------------
+// Public Types
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The expected outcome of validating a fixture.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expectation {
+    /// The fixture conforms, validation produces no results.
+    Conforms,
+    /// The fixture violates the shapes, each listed component must be reported as a violation; if
+    /// the list is empty any violation will do.
+    Violates(Vec<ConstraintComponent>),
+}
+
+///
+/// A set of fixtures to validate against a single shapes graph.
+///
+#[derive(Clone, Debug)]
+pub struct ShapeTestSuite {
+    name: String,
+    shapes: GraphRef,
+    cases: Vec<ShapeTestCase>,
+}
+
+///
+/// The result of validating a single fixture.
+///
+#[derive(Clone, Debug)]
+pub struct ShapeTestOutcome {
+    case: String,
+    expectation: Expectation,
+    report: ValidationReport,
+}
+
+///
+/// The results of running a `ShapeTestSuite`; the `Display` implementation is a pass/fail matrix.
+///
+#[derive(Clone, Debug)]
+pub struct ShapeTestReport {
+    suite: String,
+    outcomes: Vec<ShapeTestOutcome>,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Types
+// ------------------------------------------------------------------------------------------------
+
+type MatrixRow = (String, String, String, &'static str);
+
+#[derive(Clone, Debug)]
+struct ShapeTestCase {
+    name: String,
+    data: GraphRef,
+    expectation: Expectation,
+}
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Run `suite` and panic, displaying the pass/fail matrix and the validation results of failed
+/// cases, if any case failed.
+///
+pub fn assert_shape_tests(suite: &ShapeTestSuite) {
+    let report = suite.run();
+    if !report.passed() {
+        let mut message = report.to_string();
+        for outcome in report.failures() {
+            message.push_str(&format!("\n{}:\n{}", outcome.case, outcome.report));
+        }
+        panic!("shape tests failed\n{}", message);
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Implementations
+// ------------------------------------------------------------------------------------------------
+
+impl Display for Expectation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Conforms => write!(f, "conforms"),
+            Self::Violates(components) if components.is_empty() => write!(f, "violates"),
+            Self::Violates(components) => write!(f, "violates {}", join(components.iter())),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ShapeTestSuite {
+    ///
+    /// Construct a new, empty, suite named `name` for the shapes in `shapes`.
+    ///
+    pub fn new(name: &str, shapes: GraphRef) -> Self {
+        Self {
+            name: name.to_string(),
+            shapes,
+            cases: Default::default(),
+        }
+    }
+
+    ///
+    /// Add a fixture that is expected to conform to the shapes.
+    ///
+    pub fn conforming(&mut self, name: &str, data: GraphRef) -> &mut Self {
+        self.case(name, data, Expectation::Conforms)
+    }
+
+    ///
+    /// Add a fixture that is expected to produce at least one violation.
+    ///
+    pub fn violating(&mut self, name: &str, data: GraphRef) -> &mut Self {
+        self.case(name, data, Expectation::Violates(Default::default()))
+    }
+
+    ///
+    /// Add a fixture that is expected to produce violations from each of `components`.
+    ///
+    pub fn violating_with(
+        &mut self,
+        name: &str,
+        data: GraphRef,
+        components: &[ConstraintComponent],
+    ) -> &mut Self {
+        self.case(name, data, Expectation::Violates(components.to_vec()))
+    }
+
+    ///
+    /// Add a fixture with the provided expectation.
+    ///
+    pub fn case(&mut self, name: &str, data: GraphRef, expectation: Expectation) -> &mut Self {
+        self.cases.push(ShapeTestCase {
+            name: name.to_string(),
+            data,
+            expectation,
+        });
+        self
+    }
+
+    ///
+    /// Return the name of this suite.
+    ///
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    ///
+    /// Validate each fixture and return the report.
+    ///
+    pub fn run(&self) -> ShapeTestReport {
+        let shapes = self.shapes.borrow();
+        ShapeTestReport {
+            suite: self.name.clone(),
+            outcomes: self
+                .cases
+                .iter()
+                .map(|case| ShapeTestOutcome {
+                    case: case.name.clone(),
+                    expectation: case.expectation.clone(),
+                    report: validate(&*shapes, &*case.data.borrow()),
+                })
+                .collect(),
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl ShapeTestOutcome {
+    ///
+    /// The name of the fixture.
+    ///
+    pub fn case(&self) -> &String {
+        &self.case
+    }
+
+    ///
+    /// The expected outcome.
+    ///
+    pub fn expectation(&self) -> &Expectation {
+        &self.expectation
+    }
+
+    ///
+    /// The validation report for the fixture.
+    ///
+    pub fn report(&self) -> &ValidationReport {
+        &self.report
+    }
+
+    ///
+    /// Returns `true` if the validation report met the expectation.
+    ///
+    pub fn passed(&self) -> bool {
+        match &self.expectation {
+            Expectation::Conforms => self.report.conforms(),
+            Expectation::Violates(components) => {
+                let violated = self.violated();
+                !violated.is_empty() && components.iter().all(|c| violated.contains(c))
+            }
+        }
+    }
+
+    fn violated(&self) -> Vec<ConstraintComponent> {
+        let mut violated: Vec<ConstraintComponent> = Default::default();
+        for result in self.report.violations() {
+            if !violated.contains(&result.component()) {
+                violated.push(result.component());
+            }
+        }
+        violated
+    }
+
+    fn actual(&self) -> String {
+        if self.report.conforms() {
+            "conforms".to_string()
+        } else if self.report.violations().next().is_none() {
+            let severity = self
+                .report
+                .results()
+                .iter()
+                .map(|result| result.severity())
+                .max()
+                .unwrap_or(Severity::Info);
+            format!("{} only", severity.to_string().to_lowercase())
+        } else {
+            format!("violates {}", join(self.violated().iter()))
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Display for ShapeTestReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<MatrixRow> = self
+            .outcomes
+            .iter()
+            .map(|outcome| {
+                (
+                    outcome.case.clone(),
+                    outcome.expectation.to_string(),
+                    outcome.actual(),
+                    if outcome.passed() { "PASS" } else { "FAIL" },
+                )
+            })
+            .collect();
+        let width = |header: &str, column: &dyn Fn(&MatrixRow) -> usize| {
+            rows.iter().map(column).max().unwrap_or(0).max(header.len())
+        };
+        let case_width = width("case", &|row| row.0.len());
+        let expected_width = width("expected", &|row| row.1.len());
+        let actual_width = width("actual", &|row| row.2.len());
+
+        writeln!(
+            f,
+            "shape tests: {} ({} of {} passed)",
+            self.suite,
+            self.outcomes.iter().filter(|o| o.passed()).count(),
+            self.outcomes.len()
+        )?;
+        writeln!(
+            f,
+            "{:cw$} | {:ew$} | {:aw$} | result",
+            "case",
+            "expected",
+            "actual",
+            cw = case_width,
+            ew = expected_width,
+            aw = actual_width
+        )?;
+        writeln!(
+            f,
+            "{}-|-{}-|-{}-|-------",
+            "-".repeat(case_width),
+            "-".repeat(expected_width),
+            "-".repeat(actual_width)
+        )?;
+        for (case, expected, actual, result) in &rows {
+            writeln!(
+                f,
+                "{:cw$} | {:ew$} | {:aw$} | {}",
+                case,
+                expected,
+                actual,
+                result,
+                cw = case_width,
+                ew = expected_width,
+                aw = actual_width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl ShapeTestReport {
+    ///
+    /// The name of the suite that was run.
+    ///
+    pub fn suite(&self) -> &String {
+        &self.suite
+    }
+
+    ///
+    /// Returns `true` if every case met its expectation.
+    ///
+    pub fn passed(&self) -> bool {
+        self.outcomes.iter().all(|outcome| outcome.passed())
+    }
+
+    ///
+    /// Return the outcome of every case, in the order they were added to the suite.
+    ///
+    pub fn outcomes(&self) -> impl Iterator<Item = &ShapeTestOutcome> {
+        self.outcomes.iter()
+    }
+
+    ///
+    /// Return the outcome of every case that did not meet its expectation.
+    ///
+    pub fn failures(&self) -> impl Iterator<Item = &ShapeTestOutcome> {
+        self.outcomes.iter().filter(|outcome| !outcome.passed())
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Private Functions
+// ------------------------------------------------------------------------------------------------
+
+fn join<'a>(components: impl Iterator<Item = &'a ConstraintComponent>) -> String {
+    components
+        .map(|component| component.to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
@@ -0,0 +1,65 @@
+/*!
+Provides snapshot file handling for the `assert_turtle_snapshot!` macro.
+
+Snapshot files are stored in the directory `tests/snapshots` of the crate under test. A missing
+snapshot file is created from the actual value, and all snapshot files are overwritten rather than
+compared if the environment variable named by `UPDATE_SNAPSHOTS_VARIABLE` is set.
+
+*/
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// ------------------------------------------------------------------------------------------------
+// Public Values
+// ------------------------------------------------------------------------------------------------
+
+///
+/// The name of the environment variable that, when set, causes snapshot files to be overwritten.
+///
+pub const UPDATE_SNAPSHOTS_VARIABLE: &str = "RDFTK_UPDATE_SNAPSHOTS";
+
+///
+/// The directory, relative to the crate manifest, in which snapshot files are stored.
+///
+pub const SNAPSHOT_DIRECTORY: &str = "tests/snapshots";
+
+// ------------------------------------------------------------------------------------------------
+// Public Functions
+// ------------------------------------------------------------------------------------------------
+
+///
+/// Return the path of the named snapshot file for the crate whose manifest is in `manifest_dir`.
+///
+pub fn snapshot_path(manifest_dir: &str, file_name: &str) -> PathBuf {
+    PathBuf::from(manifest_dir)
+        .join(SNAPSHOT_DIRECTORY)
+        .join(file_name)
+}
+
+///
+/// Compare `actual` with the content of the snapshot file at `path`, panicking with a diff if they
+/// differ. Line endings are normalized before comparison.
+///
+pub fn assert_snapshot(path: &Path, actual: &str) {
+    if !path.exists() || std::env::var_os(UPDATE_SNAPSHOTS_VARIABLE).is_some() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap_or_else(|e| {
+                panic!("could not create snapshot directory {:?}; {}", parent, e)
+            });
+        }
+        fs::write(path, actual)
+            .unwrap_or_else(|e| panic!("could not write snapshot file {:?}; {}", path, e));
+    } else {
+        let expected = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("could not read snapshot file {:?}; {}", path, e))
+            .replace("\r\n", "\n");
+        pretty_assertions::assert_eq!(
+            expected,
+            actual.replace("\r\n", "\n"),
+            "snapshot {:?} does not match, set {} to update",
+            path,
+            UPDATE_SNAPSHOTS_VARIABLE
+        );
+    }
+}
@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate rdftk_test;
+
+use rdftk_test::fixtures::{fixture_path, load_graph, load_graph_from_str};
+use rdftk_test::isomorphism::{canonical_ntriples, is_isomorphic};
+
+#[test]
+fn test_isomorphic_relabeled() {
+    let left = load_graph_from_str(
+        r#"_:a <http://example.org/p> "one" .
+_:a <http://example.org/q> _:b .
+_:b <http://example.org/p> "two" .
+"#,
+        "nt",
+    );
+    let right = load_graph_from_str(
+        r#"_:n2 <http://example.org/p> "two" .
+_:n1 <http://example.org/q> _:n2 .
+_:n1 <http://example.org/p> "one" .
+"#,
+        "nt",
+    );
+    assert_graph_isomorphic!(left, right);
+}
+
+#[test]
+fn test_isomorphic_symmetric_cycle() {
+    let left = load_graph_from_str(
+        r#"_:a <http://example.org/next> _:b .
+_:b <http://example.org/next> _:c .
+_:c <http://example.org/next> _:a .
+"#,
+        "nt",
+    );
+    let right = load_graph_from_str(
+        r#"_:z <http://example.org/next> _:x .
+_:y <http://example.org/next> _:z .
+_:x <http://example.org/next> _:y .
+"#,
+        "nt",
+    );
+    assert_graph_isomorphic!(left, right, "three node cycles should match");
+}
+
+#[test]
+fn test_not_isomorphic() {
+    let left = load_graph_from_str(
+        r#"_:a <http://example.org/next> _:b .
+_:b <http://example.org/next> _:a .
+"#,
+        "nt",
+    );
+    let right = load_graph_from_str(
+        r#"_:a <http://example.org/next> _:a .
+_:b <http://example.org/next> _:b .
+"#,
+        "nt",
+    );
+    assert!(!is_isomorphic(&left, &right));
+}
+
+#[test]
+#[should_panic]
+fn test_assert_not_isomorphic() {
+    let left = load_graph_from_str(
+        "<http://example.org/s> <http://example.org/p> \"one\" .\n",
+        "nt",
+    );
+    let right = load_graph_from_str(
+        "<http://example.org/s> <http://example.org/p> \"two\" .\n",
+        "nt",
+    );
+    assert_graph_isomorphic!(left, right);
+}
+
+#[test]
+fn test_canonical_labels() {
+    let graph = load_graph_from_str(
+        "<http://example.org/s> <http://example.org/p> _:something .\n",
+        "nt",
+    );
+    assert_eq!(
+        canonical_ntriples(&graph),
+        "<http://example.org/s> <http://example.org/p> _:c14n0 .\n"
+    );
+}
+
+#[test]
+fn test_fixture_snapshot() {
+    let graph = load_graph(&fixture_path(env!("CARGO_MANIFEST_DIR"), "tony_benn.nt"));
+    assert_eq!(graph.borrow().len(), 5);
+    assert_turtle_snapshot!(graph, "tony_benn.ttl");
+}
+
+#[test]
+fn test_turtle_fixture() {
+    let expected = load_graph(&fixture_path(env!("CARGO_MANIFEST_DIR"), "tony_benn.nt"));
+    let actual = load_graph_from_str(
+        r#"@prefix dc: <http://purl.org/dc/elements/1.1/> .
+@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+<http://en.wikipedia.org/wiki/Tony_Benn>
+    dc:title "Tony Benn" ;
+    dc:publisher "Wikipedia" ;
+    dc:description [ a foaf:Person ; foaf:name "Tony Benn" ] .
+"#,
+        "ttl",
+    );
+    assert_graph_isomorphic!(actual, expected);
+}
@@ -0,0 +1,128 @@
+#[macro_use]
+extern crate rdftk_test;
+
+use rdftk_ontology::shacl::ConstraintComponent;
+use rdftk_test::fixtures::{fixture_path, load_graph, load_graph_from_str};
+use rdftk_test::shapes::{Expectation, ShapeTestSuite};
+
+const PREFIXES: &str = r#"@prefix foaf: <http://xmlns.com/foaf/0.1/> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix ex: <http://example.org/people/> .
+"#;
+
+fn data(body: &str) -> rdftk_core::model::graph::GraphRef {
+    load_graph_from_str(&format!("{}{}", PREFIXES, body), "ttl")
+}
+
+fn person_suite() -> ShapeTestSuite {
+    let mut suite = ShapeTestSuite::new(
+        "person",
+        load_graph(&fixture_path(
+            env!("CARGO_MANIFEST_DIR"),
+            "shapes/person.ttl",
+        )),
+    );
+    let _ = suite
+        .conforming(
+            "complete person",
+            data(
+                r#"ex:alice a foaf:Person ; foaf:name "Alice" ; foaf:age 42 ; foaf:knows ex:bob .
+ex:bob a foaf:Person ; foaf:name "Bob" ."#,
+            ),
+        )
+        .conforming("no people", data(r#"ex:thing foaf:name "Thing" ."#))
+        .violating_with(
+            "missing name",
+            data("ex:alice a foaf:Person ."),
+            &[ConstraintComponent::MinCount],
+        )
+        .violating_with(
+            "two names",
+            data(r#"ex:alice a foaf:Person ; foaf:name "Alice", "Ally" ."#),
+            &[ConstraintComponent::MaxCount],
+        )
+        .violating_with(
+            "long name",
+            data(r#"ex:alice a foaf:Person ; foaf:name "Alice Pleasance Liddell" ."#),
+            &[ConstraintComponent::MaxLength],
+        )
+        .violating_with(
+            "negative age",
+            data(r#"ex:alice a foaf:Person ; foaf:name "Alice" ; foaf:age -1 ."#),
+            &[ConstraintComponent::MinInclusive],
+        )
+        .violating(
+            "knows a non-person",
+            data(r#"ex:alice a foaf:Person ; foaf:name "Alice" ; foaf:knows ex:rock ."#),
+        );
+    suite
+}
+
+#[test]
+fn test_person_shapes() {
+    assert_shape_tests!(person_suite());
+}
+
+#[test]
+fn test_report_matrix() {
+    let report = person_suite().run();
+    assert!(report.passed());
+    assert_eq!(report.outcomes().count(), 7);
+    let matrix = report.to_string();
+    println!("{}", matrix);
+    assert!(matrix.starts_with("shape tests: person (7 of 7 passed)\n"));
+    let lines: Vec<&str> = matrix.lines().collect();
+    assert_eq!(lines.len(), 10);
+    assert!(lines[1].starts_with("case "));
+    assert!(lines[1].ends_with("| result"));
+    assert!(lines[5].starts_with("missing name "));
+    assert!(lines[5].contains("| violates sh:MinCountConstraintComponent "));
+    assert!(lines[5].ends_with("| PASS"));
+    // every column separator is aligned.
+    let columns = |line: &str| -> Vec<usize> { line.match_indices('|').map(|(i, _)| i).collect() };
+    assert!(lines[1..]
+        .iter()
+        .all(|line| columns(line) == columns(lines[1])));
+}
+
+#[test]
+fn test_failed_expectations() {
+    let mut suite = ShapeTestSuite::new(
+        "person",
+        load_graph(&fixture_path(
+            env!("CARGO_MANIFEST_DIR"),
+            "shapes/person.ttl",
+        )),
+    );
+    let _ = suite
+        .violating(
+            "actually conforms",
+            data(r#"ex:alice a foaf:Person ; foaf:name "Alice" ."#),
+        )
+        .conforming("actually violates", data("ex:alice a foaf:Person ."))
+        .case(
+            "wrong component",
+            data("ex:alice a foaf:Person ."),
+            Expectation::Violates(vec![ConstraintComponent::MaxCount]),
+        );
+    let report = suite.run();
+    assert!(!report.passed());
+    assert_eq!(report.failures().count(), 3);
+    assert!(report
+        .to_string()
+        .starts_with("shape tests: person (0 of 3 passed)\n"));
+}
+
+#[test]
+#[should_panic(expected = "shape tests failed")]
+fn test_assert_shape_tests_panics() {
+    let mut suite = ShapeTestSuite::new(
+        "person",
+        load_graph(&fixture_path(
+            env!("CARGO_MANIFEST_DIR"),
+            "shapes/person.ttl",
+        )),
+    );
+    let _ = suite.conforming("actually violates", data("ex:alice a foaf:Person ."));
+    assert_shape_tests!(suite);
+}